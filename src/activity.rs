@@ -0,0 +1,387 @@
+//! Wallet activity anomaly detection
+//!
+//! Treasury and other watched wallets rarely move funds, so any outgoing
+//! transfer to an unknown recipient, a large drop of a balance, or a new authz
+//! grant is worth an immediate look. [`activity_alerts`] reads those out of a
+//! transaction's events and [`balance_drops`] compares balances against the
+//! previous check.
+//!
+//! [`ActivityWatcher`] checks a set of addresses in the background, typically
+//! watch-only wallets, and delivers each alert once, marked high priority,
+//! through the sinks of an [`EventBridge`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cosmrs::rpc::query::Query;
+use cosmrs::tendermint::abci::Event;
+use cosmrs::tendermint::crypto::{default::Sha256, Sha256 as _};
+use cosmrs::AccountId;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::EventBridge;
+use crate::client::MantraDexClient;
+use crate::error::Error;
+use crate::wallet::ACCOUNT_PREFIX;
+
+/// Share of a balance, in percent, whose loss between checks raises an alert
+pub const DEFAULT_BALANCE_DROP_PERCENT: u64 = 20;
+
+/// Default delay between checks of the watched addresses
+pub const DEFAULT_ACTIVITY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Transactions requested per page
+const PAGE_SIZE: u8 = 50;
+
+/// Event emitted by the authz module when a grant is created
+const GRANT_EVENT: &str = "cosmos.authz.v1beta1.EventGrant";
+
+/// Unusual activity on a watched address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "alert", rename_all = "snake_case")]
+pub enum ActivityAlert {
+    /// Funds left the address for a recipient that isn't expected
+    OutgoingTransfer {
+        address: String,
+        recipient: String,
+        /// Transferred coins, as written in the transfer event
+        amount: String,
+        tx_hash: String,
+        height: u64,
+    },
+    /// A balance fell by more than the threshold since the previous check
+    BalanceDrop {
+        address: String,
+        previous: Coin,
+        current: Coin,
+    },
+    /// The address granted another account permission to act on its behalf
+    AuthzGrant {
+        address: String,
+        grantee: String,
+        /// Message type the grantee may send, when the grant is restricted to one
+        msg_type_url: Option<String>,
+        tx_hash: String,
+        height: u64,
+    },
+}
+
+impl ActivityAlert {
+    /// Identifies the alert so it is delivered once rather than on every check
+    pub fn key(&self) -> String {
+        match self {
+            ActivityAlert::OutgoingTransfer {
+                tx_hash,
+                recipient,
+                amount,
+                ..
+            } => format!("transfer:{}:{}:{}", tx_hash, recipient, amount),
+            ActivityAlert::BalanceDrop {
+                address, current, ..
+            } => format!("drop:{}:{}:{}", address, current.denom, current.amount),
+            ActivityAlert::AuthzGrant {
+                tx_hash, grantee, ..
+            } => format!("grant:{}:{}", tx_hash, grantee),
+        }
+    }
+
+    /// Watched address the alert is about
+    pub fn address(&self) -> &str {
+        match self {
+            ActivityAlert::OutgoingTransfer { address, .. }
+            | ActivityAlert::BalanceDrop { address, .. }
+            | ActivityAlert::AuthzGrant { address, .. } => address,
+        }
+    }
+}
+
+impl fmt::Display for ActivityAlert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActivityAlert::OutgoingTransfer {
+                address,
+                recipient,
+                amount,
+                tx_hash,
+                ..
+            } => write!(
+                f,
+                "{} sent {} to unexpected recipient {} (tx {})",
+                address, amount, recipient, tx_hash
+            ),
+            ActivityAlert::BalanceDrop {
+                address,
+                previous,
+                current,
+            } => write!(
+                f,
+                "{} balance fell to {}, was {}",
+                address, current, previous
+            ),
+            ActivityAlert::AuthzGrant {
+                address,
+                grantee,
+                msg_type_url,
+                tx_hash,
+                ..
+            } => write!(
+                f,
+                "{} granted {} authority to send {} (tx {})",
+                address,
+                grantee,
+                msg_type_url.as_deref().unwrap_or("any message"),
+                tx_hash
+            ),
+        }
+    }
+}
+
+/// Address of the fee collector module, which receives every transaction fee
+pub fn fee_collector_address() -> String {
+    let hash = Sha256::digest(b"fee_collector");
+    AccountId::new(ACCOUNT_PREFIX, &hash[..20])
+        .expect("20 bytes is a valid account id")
+        .to_string()
+}
+
+/// Alerts raised by the events of a transaction, for the watched `address`
+///
+/// Transfers to an `expected` recipient, such as the fee collector or the DEX
+/// contracts, are not reported.
+pub fn activity_alerts(
+    address: &str,
+    height: u64,
+    tx_hash: &str,
+    events: &[Event],
+    expected: &BTreeSet<String>,
+) -> Vec<ActivityAlert> {
+    let mut alerts = Vec::new();
+    for event in events {
+        // Typed events, such as the authz ones, quote their attribute values as JSON strings
+        let attributes: BTreeMap<String, String> = event
+            .attributes
+            .iter()
+            .filter_map(|attr| {
+                Some((
+                    attr.key_str().ok()?.to_string(),
+                    attr.value_str().ok()?.trim_matches('"').to_string(),
+                ))
+            })
+            .collect();
+        let attribute = |key: &str| attributes.get(key).map(String::as_str);
+
+        if event.kind == "transfer" && attribute("sender") == Some(address) {
+            let recipient = attribute("recipient").unwrap_or_default();
+            if recipient != address && !expected.contains(recipient) {
+                alerts.push(ActivityAlert::OutgoingTransfer {
+                    address: address.to_string(),
+                    recipient: recipient.to_string(),
+                    amount: attribute("amount").unwrap_or_default().to_string(),
+                    tx_hash: tx_hash.to_string(),
+                    height,
+                });
+            }
+        } else if event.kind == GRANT_EVENT && attribute("granter") == Some(address) {
+            alerts.push(ActivityAlert::AuthzGrant {
+                address: address.to_string(),
+                grantee: attribute("grantee").unwrap_or_default().to_string(),
+                msg_type_url: attribute("msg_type_url")
+                    .filter(|url| !url.is_empty())
+                    .map(str::to_string),
+                tx_hash: tx_hash.to_string(),
+                height,
+            });
+        }
+    }
+    alerts
+}
+
+/// Balances of `address` that lost more than `threshold` of their amount
+///
+/// A denom missing from `current` counts as an empty balance.
+pub fn balance_drops(
+    address: &str,
+    previous: &[Coin],
+    current: &[Coin],
+    threshold: Decimal,
+) -> Vec<ActivityAlert> {
+    previous
+        .iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .filter_map(|coin| {
+            let amount = current
+                .iter()
+                .find(|current| current.denom == coin.denom)
+                .map_or(Uint128::zero(), |current| current.amount);
+            let lost = coin.amount.saturating_sub(amount);
+            (Decimal::from_ratio(lost, coin.amount) > threshold).then(|| {
+                ActivityAlert::BalanceDrop {
+                    address: address.to_string(),
+                    previous: coin.clone(),
+                    current: Coin {
+                        denom: coin.denom.clone(),
+                        amount,
+                    },
+                }
+            })
+        })
+        .collect()
+}
+
+/// Checks a set of addresses periodically and delivers new activity alerts
+pub struct ActivityWatcher {
+    client: Arc<MantraDexClient>,
+    addresses: Vec<String>,
+    expected: BTreeSet<String>,
+    balance_drop: Decimal,
+    interval: Duration,
+    synced_height: Option<u64>,
+    balances: BTreeMap<String, Vec<Coin>>,
+    delivered: BTreeSet<String>,
+}
+
+impl ActivityWatcher {
+    /// Watch `addresses`
+    ///
+    /// Transfers to the fee collector and to the DEX contracts of the client's
+    /// network are expected.
+    pub fn new(client: Arc<MantraDexClient>, addresses: Vec<String>) -> Self {
+        let contracts = &client.config().contracts;
+        let expected = std::iter::once(fee_collector_address())
+            .chain(std::iter::once(contracts.pool_manager.clone()))
+            .chain(contracts.farm_manager.clone())
+            .chain(contracts.fee_collector.clone())
+            .chain(contracts.skip_entry_point.clone())
+            .filter(|address| !address.is_empty())
+            .collect();
+        Self {
+            client,
+            addresses,
+            expected,
+            balance_drop: Decimal::percent(DEFAULT_BALANCE_DROP_PERCENT),
+            interval: DEFAULT_ACTIVITY_INTERVAL,
+            synced_height: None,
+            balances: BTreeMap::new(),
+            delivered: BTreeSet::new(),
+        }
+    }
+
+    /// Also expect transfers to `recipients`
+    pub fn with_expected_recipients(
+        mut self,
+        recipients: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.expected.extend(recipients);
+        self
+    }
+
+    /// Alert when a balance loses more than `threshold` between checks
+    pub fn with_balance_drop(mut self, threshold: Decimal) -> Self {
+        self.balance_drop = threshold;
+        self
+    }
+
+    /// Set the delay between checks
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Addresses being watched
+    pub fn addresses(&self) -> &[String] {
+        &self.addresses
+    }
+
+    /// Alerts raised since the previous check
+    ///
+    /// The first check records balances and the latest block, so activity is
+    /// reported from the second check on.
+    pub async fn check(&mut self) -> Result<Vec<ActivityAlert>, Error> {
+        let latest = self.client.get_last_block_height().await?;
+        let mut alerts = Vec::new();
+
+        for address in &self.addresses {
+            let balances = self.client.get_balances_for_address(address).await?;
+            if let Some(previous) = self.balances.get(address) {
+                alerts.extend(balance_drops(
+                    address,
+                    previous,
+                    &balances,
+                    self.balance_drop,
+                ));
+            }
+            self.balances.insert(address.clone(), balances);
+
+            let Some(from_height) = self.synced_height.map(|height| height + 1) else {
+                continue;
+            };
+            if from_height > latest {
+                continue;
+            }
+            // Transfers made through an authz grant are sent by the grantee, so
+            // they are found by their sender rather than the signer
+            for key in ["transfer.sender", "message.sender"] {
+                let query = Query::eq(key, address.as_str())
+                    .and_gte("tx.height", from_height)
+                    .and_lte("tx.height", latest);
+                let txs: Vec<_> = self
+                    .client
+                    .txs_stream(query, PAGE_SIZE)
+                    .try_collect()
+                    .await?;
+                for tx in txs {
+                    alerts.extend(activity_alerts(
+                        address,
+                        tx.height.value(),
+                        &tx.hash.to_string(),
+                        &tx.tx_result.events,
+                        &self.expected,
+                    ));
+                }
+            }
+        }
+
+        self.synced_height = Some(latest);
+        Ok(alerts
+            .into_iter()
+            .filter(|alert| self.delivered.insert(alert.key()))
+            .collect())
+    }
+
+    /// Check and deliver new alerts through the bridge's sinks, forever
+    ///
+    /// Alerts are marked high priority. `on_error` gets every failed check and
+    /// delivery, which do not stop the watcher.
+    ///
+    /// # Errors
+    ///
+    /// Only returns, with its error, once `on_error` fails
+    pub async fn run<F>(&mut self, bridge: &EventBridge, mut on_error: F) -> Result<(), Error>
+    where
+        F: FnMut(Error) -> Result<(), Error>,
+    {
+        loop {
+            match self.check().await {
+                Ok(alerts) => {
+                    for alert in alerts {
+                        let payload = serde_json::json!({
+                            "kind": "activity_alert",
+                            "priority": "high",
+                            "message": alert.to_string(),
+                            "alert": alert,
+                        });
+                        if let Err(e) = bridge.deliver(&serde_json::to_vec(&payload)?).await {
+                            on_error(e)?;
+                        }
+                    }
+                }
+                Err(e) => on_error(e)?,
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
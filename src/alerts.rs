@@ -0,0 +1,317 @@
+//! Farm lifecycle alerts
+//!
+//! Farms pay a fixed emission per epoch until their end epoch, and their owners
+//! can change the emission rate while they run. [`farm_alerts`] compares the
+//! farm manager's state against what was seen before and raises an alert when a
+//! farm is about to close, its emission rate changed, or every farm paying an
+//! address's open position is about to end.
+//!
+//! [`FarmWatcher`] evaluates the alerts in the background and delivers each one
+//! once through the sinks of an [`EventBridge`]; the TUI shows the same alerts
+//! as a banner on its Rewards screen.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cosmwasm_std::{Coin, Uint128};
+use futures::TryStreamExt;
+use mantra_dex_std::farm_manager::{Farm, Position, PositionsBy};
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::EventBridge;
+use crate::client::MantraDexClient;
+use crate::error::Error;
+
+/// Epochs of rewards left from which a farm or position counts as ending soon
+pub const DEFAULT_CLOSING_EPOCHS: u64 = 2;
+
+/// Default delay between checks of the farm manager
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Farms and positions requested per page
+const PAGE_SIZE: u32 = 100;
+
+/// A farm lifecycle event worth telling the user about
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "alert", rename_all = "snake_case")]
+pub enum FarmAlert {
+    /// A running farm pays rewards for its last few epochs
+    FarmClosingSoon {
+        farm_id: String,
+        lp_denom: String,
+        /// Last epoch the farm pays rewards in, unless extended
+        last_epoch: u64,
+        /// Epochs of rewards left, the current one included
+        epochs_left: u64,
+    },
+    /// A farm's emission rate differs from the one seen before
+    EmissionRateChanged {
+        farm_id: String,
+        lp_denom: String,
+        previous: Coin,
+        current: Coin,
+    },
+    /// Every farm paying an open position ends within a few epochs
+    PositionExpiring {
+        position_id: String,
+        lp_asset: Coin,
+        /// Last epoch any farm pays the position's LP token in
+        last_epoch: u64,
+        epochs_left: u64,
+    },
+}
+
+impl FarmAlert {
+    /// Identifies the alert so it is delivered once rather than on every check
+    pub fn key(&self) -> String {
+        match self {
+            FarmAlert::FarmClosingSoon {
+                farm_id,
+                last_epoch,
+                ..
+            } => format!("closing:{}:{}", farm_id, last_epoch),
+            FarmAlert::EmissionRateChanged {
+                farm_id, current, ..
+            } => format!("emission:{}:{}", farm_id, current.amount),
+            FarmAlert::PositionExpiring {
+                position_id,
+                last_epoch,
+                ..
+            } => format!("position:{}:{}", position_id, last_epoch),
+        }
+    }
+}
+
+impl fmt::Display for FarmAlert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let epochs = |count: &u64| if *count == 1 { "epoch" } else { "epochs" };
+        match self {
+            FarmAlert::FarmClosingSoon {
+                farm_id,
+                last_epoch,
+                epochs_left,
+                ..
+            } => write!(
+                f,
+                "Farm {} closes after epoch {} ({} {} of rewards left)",
+                farm_id,
+                last_epoch,
+                epochs_left,
+                epochs(epochs_left)
+            ),
+            FarmAlert::EmissionRateChanged {
+                farm_id,
+                previous,
+                current,
+                ..
+            } => write!(
+                f,
+                "Farm {} now emits {} per epoch, was {}",
+                farm_id, current, previous
+            ),
+            FarmAlert::PositionExpiring {
+                position_id,
+                lp_asset,
+                last_epoch,
+                epochs_left,
+            } => write!(
+                f,
+                "Position {} ({}) stops earning after epoch {} ({} {} left)",
+                position_id,
+                lp_asset,
+                last_epoch,
+                epochs_left,
+                epochs(epochs_left)
+            ),
+        }
+    }
+}
+
+/// Emission rate of every farm, to detect changes between checks
+pub type EmissionRates = BTreeMap<String, Uint128>;
+
+/// Emission rates of `farms`
+pub fn emission_rates(farms: &[Farm]) -> EmissionRates {
+    farms
+        .iter()
+        .map(|farm| (farm.identifier.clone(), farm.emission_rate))
+        .collect()
+}
+
+/// Alerts raised by the farm manager's state at `current_epoch`
+///
+/// `positions` are the open positions to watch, and `previous` the emission
+/// rates of the last check, if any. A farm or position is ending soon once it has
+/// `closing_epochs` or fewer epochs of rewards left.
+pub fn farm_alerts(
+    farms: &[Farm],
+    positions: &[Position],
+    current_epoch: u64,
+    previous: Option<&EmissionRates>,
+    closing_epochs: u64,
+) -> Vec<FarmAlert> {
+    let mut alerts = Vec::new();
+    // Farms pay in every epoch from their start epoch up to, but not in, their end epoch
+    let epochs_left = |farm: &Farm| farm.preliminary_end_epoch.saturating_sub(current_epoch);
+
+    for farm in farms {
+        let running =
+            farm.start_epoch <= current_epoch && current_epoch < farm.preliminary_end_epoch;
+        if running && epochs_left(farm) <= closing_epochs {
+            alerts.push(FarmAlert::FarmClosingSoon {
+                farm_id: farm.identifier.clone(),
+                lp_denom: farm.lp_denom.clone(),
+                last_epoch: farm.preliminary_end_epoch - 1,
+                epochs_left: epochs_left(farm),
+            });
+        }
+
+        let previous_rate = previous.and_then(|rates| rates.get(&farm.identifier));
+        if let Some(previous_rate) = previous_rate.filter(|rate| **rate != farm.emission_rate) {
+            alerts.push(FarmAlert::EmissionRateChanged {
+                farm_id: farm.identifier.clone(),
+                lp_denom: farm.lp_denom.clone(),
+                previous: Coin {
+                    denom: farm.farm_asset.denom.clone(),
+                    amount: *previous_rate,
+                },
+                current: Coin {
+                    denom: farm.farm_asset.denom.clone(),
+                    amount: farm.emission_rate,
+                },
+            });
+        }
+    }
+
+    for position in positions.iter().filter(|position| position.open) {
+        let end_epoch = farms
+            .iter()
+            .filter(|farm| {
+                farm.lp_denom == position.lp_asset.denom
+                    && farm.preliminary_end_epoch > current_epoch
+            })
+            .map(|farm| farm.preliminary_end_epoch)
+            .max();
+        if let Some(end_epoch) = end_epoch {
+            let left = end_epoch - current_epoch;
+            if left <= closing_epochs {
+                alerts.push(FarmAlert::PositionExpiring {
+                    position_id: position.identifier.clone(),
+                    lp_asset: position.lp_asset.clone(),
+                    last_epoch: end_epoch - 1,
+                    epochs_left: left,
+                });
+            }
+        }
+    }
+
+    alerts
+}
+
+/// Checks the farm manager periodically and delivers new farm alerts
+pub struct FarmWatcher {
+    client: Arc<MantraDexClient>,
+    address: Option<String>,
+    closing_epochs: u64,
+    interval: Duration,
+    rates: Option<EmissionRates>,
+    delivered: BTreeSet<String>,
+}
+
+impl FarmWatcher {
+    /// Watch every farm, and the open positions of `address` if given
+    pub fn new(client: Arc<MantraDexClient>, address: Option<String>) -> Self {
+        Self {
+            client,
+            address,
+            closing_epochs: DEFAULT_CLOSING_EPOCHS,
+            interval: DEFAULT_CHECK_INTERVAL,
+            rates: None,
+            delivered: BTreeSet::new(),
+        }
+    }
+
+    /// Alert when `closing_epochs` or fewer epochs of rewards are left
+    pub fn with_closing_epochs(mut self, closing_epochs: u64) -> Self {
+        self.closing_epochs = closing_epochs;
+        self
+    }
+
+    /// Set the delay between checks
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Alerts raised since the previous check
+    ///
+    /// The first check records emission rates, so rate changes are reported from
+    /// the second check on.
+    pub async fn check(&mut self) -> Result<Vec<FarmAlert>, Error> {
+        let farms: Vec<Farm> = self
+            .client
+            .farms_stream(None, PAGE_SIZE)
+            .try_collect()
+            .await?;
+        let positions: Vec<Position> = match &self.address {
+            Some(address) => {
+                self.client
+                    .positions_stream(
+                        Some(PositionsBy::Receiver(address.clone())),
+                        Some(true),
+                        PAGE_SIZE,
+                    )
+                    .try_collect()
+                    .await?
+            }
+            None => Vec::new(),
+        };
+        let current_epoch = self.client.get_current_epoch().await?;
+
+        let alerts = farm_alerts(
+            &farms,
+            &positions,
+            current_epoch,
+            self.rates.as_ref(),
+            self.closing_epochs,
+        );
+        self.rates = Some(emission_rates(&farms));
+        Ok(alerts
+            .into_iter()
+            .filter(|alert| self.delivered.insert(alert.key()))
+            .collect())
+    }
+
+    /// Check and deliver new alerts through the bridge's sinks, forever
+    ///
+    /// `on_error` gets every failed check and delivery, which do not stop the watcher.
+    ///
+    /// # Errors
+    ///
+    /// Only returns, with its error, once `on_error` fails
+    pub async fn run<F>(&mut self, bridge: &EventBridge, mut on_error: F) -> Result<(), Error>
+    where
+        F: FnMut(Error) -> Result<(), Error>,
+    {
+        loop {
+            match self.check().await {
+                Ok(alerts) => {
+                    for alert in alerts {
+                        let payload = serde_json::json!({
+                            "kind": "farm_alert",
+                            "message": alert.to_string(),
+                            "alert": alert,
+                        });
+                        if let Err(e) = bridge.deliver(&serde_json::to_vec(&payload)?).await {
+                            on_error(e)?;
+                        }
+                    }
+                }
+                Err(e) => on_error(e)?,
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Locale-aware amount input
+//!
+//! Amounts typed by users follow their locale: `1,234.56` in English, `1.234,56`
+//! in most of Europe, `1 234,56` in French or `1_234.56` as in Rust literals.
+//! [`parse_amount`] reads any of them under a [`NumberLocale`] and
+//! [`canonicalize_amount`] rewrites them as `1234.56`, the only form message
+//! builders accept. [`format_amount`] goes the other way for display.
+//!
+//! Grouping separators must sit between groups of three digits, so `1,5` is
+//! rejected under the point locale instead of being read as fifteen.
+
+use std::fmt;
+use std::str::FromStr;
+
+use cosmwasm_std::{Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Grouping separators accepted under every locale, besides the locale's own
+const COMMON_GROUPING: [char; 4] = [' ', '\u{a0}', '\u{202f}', '\''];
+
+/// Languages whose decimal separator is a comma
+const COMMA_LANGUAGES: [&str; 28] = [
+    "bg", "ca", "cs", "da", "de", "el", "es", "et", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv",
+    "nb", "nl", "nn", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "tr", "uk",
+];
+
+/// How a locale writes the decimal point and groups thousands
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberLocale {
+    /// `1,234.56`
+    #[default]
+    Point,
+    /// `1.234,56`
+    Comma,
+}
+
+impl NumberLocale {
+    /// Separator between the whole and the fractional part
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            NumberLocale::Point => '.',
+            NumberLocale::Comma => ',',
+        }
+    }
+
+    /// Separator between groups of thousands
+    pub fn grouping_separator(&self) -> char {
+        match self {
+            NumberLocale::Point => ',',
+            NumberLocale::Comma => '.',
+        }
+    }
+
+    /// Locale of a POSIX locale name such as `de_DE.UTF-8`
+    pub fn from_locale_name(name: &str) -> Self {
+        let language = name
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if COMMA_LANGUAGES.contains(&language.as_str()) {
+            NumberLocale::Comma
+        } else {
+            NumberLocale::Point
+        }
+    }
+
+    /// Locale of the environment, from `LC_ALL`, `LC_NUMERIC` or `LANG`
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.is_empty())
+            .map_or(NumberLocale::Point, |name| Self::from_locale_name(&name))
+    }
+}
+
+impl fmt::Display for NumberLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberLocale::Point => f.write_str("point"),
+            NumberLocale::Comma => f.write_str("comma"),
+        }
+    }
+}
+
+impl FromStr for NumberLocale {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "point" | "." => Ok(NumberLocale::Point),
+            "comma" | "," => Ok(NumberLocale::Comma),
+            other => Err(Error::Config(format!(
+                "Unknown number locale '{}', expected point or comma",
+                other
+            ))),
+        }
+    }
+}
+
+/// Whole and fractional digits of `input`, read under `locale`
+fn split_amount(input: &str, locale: NumberLocale) -> Result<(String, String), Error> {
+    let invalid = |reason: &str| Error::Other(format!("Invalid amount '{}': {}", input, reason));
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(invalid("empty"));
+    }
+    if trimmed.starts_with('-') {
+        return Err(invalid("amounts can't be negative"));
+    }
+    let trimmed = trimmed.strip_prefix('+').unwrap_or(trimmed);
+
+    let (whole, fraction) = match trimmed.split_once(locale.decimal_separator()) {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (trimmed, ""),
+    };
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid("no digits"));
+    }
+
+    // Underscores may go anywhere between digits, other separators only between thousands
+    let mut digits = String::with_capacity(whole.len());
+    let mut group = 0;
+    let mut grouped = false;
+    let mut previous_digit = false;
+    for c in whole.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            group += 1;
+            previous_digit = true;
+        } else if c == '_' && previous_digit {
+            previous_digit = false;
+        } else if (c == locale.grouping_separator() || COMMON_GROUPING.contains(&c))
+            && previous_digit
+        {
+            if (grouped && group != 3) || (!grouped && group > 3) {
+                return Err(invalid(&format!(
+                    "'{}' must separate groups of three digits",
+                    c
+                )));
+            }
+            grouped = true;
+            group = 0;
+            previous_digit = false;
+        } else if c == locale.decimal_separator() || c == locale.grouping_separator() {
+            return Err(invalid(&format!("misplaced '{}'", c)));
+        } else {
+            return Err(invalid(&format!("unexpected character '{}'", c)));
+        }
+    }
+    if !whole.is_empty() && !previous_digit {
+        return Err(invalid("ends with a separator"));
+    }
+    if grouped && group != 3 {
+        return Err(invalid("the last group must have three digits"));
+    }
+
+    if let Some(c) = fraction.chars().find(|c| !c.is_ascii_digit() && *c != '_') {
+        return Err(invalid(&format!(
+            "unexpected character '{}' in decimals",
+            c
+        )));
+    }
+    let fraction: String = fraction.chars().filter(char::is_ascii_digit).collect();
+    Ok((digits, fraction))
+}
+
+/// Read an amount typed under `locale`
+///
+/// # Errors
+///
+/// Returns [`Error::Other`] if the input isn't a non-negative number written
+/// with the locale's separators, or has more than 18 decimals
+pub fn parse_amount(input: &str, locale: NumberLocale) -> Result<Decimal, Error> {
+    let (whole, fraction) = split_amount(input, locale)?;
+    let canonical = if fraction.is_empty() {
+        whole
+    } else {
+        format!("0{}.{}", whole, fraction)
+    };
+    Decimal::from_str(&canonical)
+        .map_err(|e| Error::Other(format!("Invalid amount '{}': {}", input, e)))
+}
+
+/// Rewrite an amount typed under `locale` as plain digits with a `.` decimal point
+///
+/// # Errors
+///
+/// Returns [`Error::Other`] if the input can't be read, see [`parse_amount`]
+pub fn canonicalize_amount(input: &str, locale: NumberLocale) -> Result<String, Error> {
+    parse_amount(input, locale).map(|amount| amount.to_string())
+}
+
+/// Read an amount of whole tokens typed under `locale` in base units of a
+/// token with `decimals` decimals
+///
+/// With `decimals` of zero the input is already in base units.
+///
+/// # Errors
+///
+/// Returns [`Error::Other`] if the input can't be read or has more decimals
+/// than the token
+pub fn parse_base_units(
+    input: &str,
+    decimals: u32,
+    locale: NumberLocale,
+) -> Result<Uint128, Error> {
+    let (whole, fraction) = split_amount(input, locale)?;
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(Error::Other(format!(
+            "Invalid amount '{}': more than {} decimals",
+            input, decimals
+        )));
+    }
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(Uint128::zero());
+    }
+    Uint128::from_str(digits)
+        .map_err(|e| Error::Other(format!("Invalid amount '{}': {}", input, e)))
+}
+
+/// Write `amount` with the separators of `locale`, grouping thousands
+pub fn format_amount(amount: Decimal, locale: NumberLocale) -> String {
+    let canonical = amount.to_string();
+    let (whole, fraction) = canonical.split_once('.').unwrap_or((&canonical, ""));
+
+    let mut formatted = String::with_capacity(canonical.len() + whole.len() / 3);
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index) % 3 == 0 {
+            formatted.push(locale.grouping_separator());
+        }
+        formatted.push(digit);
+    }
+    if !fraction.is_empty() {
+        formatted.push(locale.decimal_separator());
+        formatted.push_str(fraction);
+    }
+    formatted
+}
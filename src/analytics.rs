@@ -0,0 +1,216 @@
+//! Execution analytics over an address's swap history
+//!
+//! Swaps are read back from the chain's transaction index rather than a local
+//! store, so the numbers cover every swap the address made, from any client.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::DexEvent;
+
+/// A single executed swap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwapFill {
+    /// Block height the swap was included at
+    pub height: u64,
+    /// Hash of the swap transaction
+    pub tx_hash: String,
+    /// Pool the swap went through, if the contract reported it
+    pub pool_id: Option<String>,
+    /// Denom that was sold
+    pub offer_denom: String,
+    /// Denom that was bought
+    pub ask_denom: String,
+    /// Amount sold
+    pub offer_amount: Uint128,
+    /// Amount received, after fees
+    pub return_amount: Uint128,
+    /// Swap, protocol, burn and extra fees, in the ask denom
+    pub fee_amount: Uint128,
+}
+
+impl SwapFill {
+    /// Read a fill from a pool manager `swap` event
+    ///
+    /// Returns `None` for any other event, or if a required attribute is missing.
+    pub fn from_event(event: &DexEvent) -> Option<Self> {
+        if event.action.as_deref() != Some("swap") {
+            return None;
+        }
+
+        let amount = |key: &str| {
+            event
+                .attributes
+                .get(key)
+                .and_then(|value| Uint128::from_str(value).ok())
+        };
+        let fee_amount = [
+            "swap_fee_amount",
+            "protocol_fee_amount",
+            "burn_fee_amount",
+            "extra_fees_amount",
+        ]
+        .iter()
+        .filter_map(|key| amount(key))
+        .fold(Uint128::zero(), |total, fee| total.saturating_add(fee));
+
+        Some(Self {
+            height: event.height,
+            tx_hash: event.tx_hash.clone(),
+            pool_id: event.attributes.get("pool_identifier").cloned(),
+            offer_denom: event.attributes.get("offer_denom")?.clone(),
+            ask_denom: event.attributes.get("ask_denom")?.clone(),
+            offer_amount: amount("offer_amount")?,
+            return_amount: amount("return_amount")?,
+            fee_amount,
+        })
+    }
+}
+
+/// Execution summary for one direction of a trading pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PairExecution {
+    /// Denom that was sold
+    pub offer_denom: String,
+    /// Denom that was bought
+    pub ask_denom: String,
+    /// Number of swaps
+    pub trades: u64,
+    /// Total amount sold
+    pub total_offer: Uint128,
+    /// Total amount received
+    pub total_return: Uint128,
+    /// Total fees paid, in the ask denom
+    pub total_fees: Uint128,
+    /// Volume-weighted price: ask units received per offer unit sold
+    pub average_price: Decimal,
+    /// First and last block height with a swap
+    pub height_range: (u64, u64),
+}
+
+/// Summarise fills per pair direction, ordered by offer then ask denom
+pub fn execution_by_pair(fills: &[SwapFill]) -> Vec<PairExecution> {
+    let mut pairs: BTreeMap<(String, String), PairExecution> = BTreeMap::new();
+
+    for fill in fills {
+        let pair = pairs
+            .entry((fill.offer_denom.clone(), fill.ask_denom.clone()))
+            .or_insert_with(|| PairExecution {
+                offer_denom: fill.offer_denom.clone(),
+                ask_denom: fill.ask_denom.clone(),
+                trades: 0,
+                total_offer: Uint128::zero(),
+                total_return: Uint128::zero(),
+                total_fees: Uint128::zero(),
+                average_price: Decimal::zero(),
+                height_range: (fill.height, fill.height),
+            });
+
+        pair.trades += 1;
+        pair.total_offer = pair.total_offer.saturating_add(fill.offer_amount);
+        pair.total_return = pair.total_return.saturating_add(fill.return_amount);
+        pair.total_fees = pair.total_fees.saturating_add(fill.fee_amount);
+        pair.height_range = (
+            pair.height_range.0.min(fill.height),
+            pair.height_range.1.max(fill.height),
+        );
+    }
+
+    pairs
+        .into_values()
+        .map(|mut pair| {
+            pair.average_price = Decimal::checked_from_ratio(pair.total_return, pair.total_offer)
+                .unwrap_or_default();
+            pair
+        })
+        .collect()
+}
+
+/// Open, high, low and close price of a pair over a range of blocks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Candle {
+    /// First block height of the range
+    pub start_height: u64,
+    /// Number of blocks in the range
+    pub interval_blocks: u64,
+    /// Prices in quote units per base unit, after fees
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// Amount of the base denom traded
+    pub volume: Uint128,
+    /// Number of swaps
+    pub trades: u64,
+}
+
+/// Bucket swaps between `base` and `quote` into candles of `interval_blocks` blocks
+///
+/// Swaps in either direction count; other pairs are ignored. Ranges without swaps
+/// are left out.
+pub fn candles(fills: &[SwapFill], base: &str, quote: &str, interval_blocks: u64) -> Vec<Candle> {
+    let interval_blocks = interval_blocks.max(1);
+    let mut fills: Vec<&SwapFill> = fills.iter().collect();
+    fills.sort_by_key(|fill| fill.height);
+
+    let mut candles: Vec<Candle> = Vec::new();
+    for fill in fills {
+        let (price, volume) = if fill.offer_denom == base && fill.ask_denom == quote {
+            (
+                Decimal::checked_from_ratio(fill.return_amount, fill.offer_amount),
+                fill.offer_amount,
+            )
+        } else if fill.offer_denom == quote && fill.ask_denom == base {
+            (
+                Decimal::checked_from_ratio(fill.offer_amount, fill.return_amount),
+                fill.return_amount,
+            )
+        } else {
+            continue;
+        };
+        let Ok(price) = price else {
+            continue;
+        };
+
+        let start_height = fill.height - fill.height % interval_blocks;
+        match candles.last_mut() {
+            Some(candle) if candle.start_height == start_height => {
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.close = price;
+                candle.volume = candle.volume.saturating_add(volume);
+                candle.trades += 1;
+            }
+            _ => candles.push(Candle {
+                start_height,
+                interval_blocks,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume,
+                trades: 1,
+            }),
+        }
+    }
+    candles
+}
+
+/// Total amount sold per denom, ordered by denom
+pub fn volume_by_denom(fills: &[SwapFill]) -> Vec<Coin> {
+    let mut volumes: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for fill in fills {
+        let volume = volumes.entry(&fill.offer_denom).or_default();
+        *volume = volume.saturating_add(fill.offer_amount);
+    }
+    volumes
+        .into_iter()
+        .map(|(denom, amount)| Coin {
+            denom: denom.to_string(),
+            amount,
+        })
+        .collect()
+}
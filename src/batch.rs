@@ -0,0 +1,244 @@
+//! Several operations in one transaction
+//!
+//! A [`TxBatchBuilder`], from [`MantraDexClient::batch`], queues operations,
+//! e.g. claim rewards, withdraw liquidity and swap the proceeds, and sends them
+//! as the messages of a single transaction. The batch pays one fee and lands in
+//! one block, and either every operation succeeds or none does.
+//!
+//! Operations are described by [`BatchOp`], which is also the format of the
+//! batch files read by [`parse_batch`]: a JSON array of operations tagged by
+//! `type`, e.g.
+//!
+//! ```json
+//! [
+//!   {"type": "claim_rewards"},
+//!   {"type": "withdraw_liquidity", "pool_id": "o.uom.uusdc", "lp_amount": "1000"},
+//!   {"type": "swap", "pool_id": "o.uom.uusdc",
+//!    "offer_asset": {"denom": "uom", "amount": "500"}, "ask_asset_denom": "uusdc"}
+//! ]
+//! ```
+
+use std::fmt;
+
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::Any;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::client::MantraDexClient;
+use crate::error::Error;
+use crate::gas::GasEstimate;
+
+/// One operation of a batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BatchOp {
+    /// Swap in a pool
+    Swap {
+        pool_id: String,
+        offer_asset: Coin,
+        ask_asset_denom: String,
+        /// Maximum slippage tolerance, the wallet default if not set
+        #[serde(default)]
+        max_slippage: Option<Decimal>,
+    },
+    /// Provide liquidity to a pool
+    ProvideLiquidity {
+        pool_id: String,
+        assets: Vec<Coin>,
+        #[serde(default)]
+        liquidity_max_slippage: Option<Decimal>,
+        #[serde(default)]
+        swap_max_slippage: Option<Decimal>,
+    },
+    /// Withdraw liquidity from a pool
+    WithdrawLiquidity { pool_id: String, lp_amount: Uint128 },
+    /// Claim farm rewards
+    ClaimRewards {
+        /// Last epoch to claim for, every epoch if not set
+        #[serde(default)]
+        until_epoch: Option<u64>,
+    },
+}
+
+impl fmt::Display for BatchOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchOp::Swap {
+                pool_id,
+                offer_asset,
+                ask_asset_denom,
+                ..
+            } => write!(
+                f,
+                "Swap {} for {} in pool {}",
+                offer_asset, ask_asset_denom, pool_id
+            ),
+            BatchOp::ProvideLiquidity {
+                pool_id, assets, ..
+            } => {
+                let assets: Vec<String> = assets.iter().map(Coin::to_string).collect();
+                write!(f, "Provide {} to pool {}", assets.join(", "), pool_id)
+            }
+            BatchOp::WithdrawLiquidity { pool_id, lp_amount } => {
+                write!(f, "Withdraw {} LP tokens from pool {}", lp_amount, pool_id)
+            }
+            BatchOp::ClaimRewards { until_epoch: None } => write!(f, "Claim rewards"),
+            BatchOp::ClaimRewards {
+                until_epoch: Some(epoch),
+            } => write!(f, "Claim rewards until epoch {}", epoch),
+        }
+    }
+}
+
+/// Parse a batch file: a JSON array of [`BatchOp`]s
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if the JSON isn't a list of operations or the
+/// list is empty
+pub fn parse_batch(json: &str) -> Result<Vec<BatchOp>, Error> {
+    let ops: Vec<BatchOp> = serde_json::from_str(json)
+        .map_err(|e| Error::Config(format!("Invalid batch file: {}", e)))?;
+    if ops.is_empty() {
+        return Err(Error::Config("Batch file lists no operations".to_string()));
+    }
+    Ok(ops)
+}
+
+/// Operations queued to be sent in one transaction
+///
+/// Each operation is validated as its single-message counterpart on the client
+/// is, when the batch is estimated or broadcast.
+pub struct TxBatchBuilder<'a> {
+    client: &'a MantraDexClient,
+    ops: Vec<BatchOp>,
+    messages: Vec<Any>,
+}
+
+impl<'a> TxBatchBuilder<'a> {
+    /// Start an empty batch sent from `client`'s wallet
+    pub fn new(client: &'a MantraDexClient) -> Self {
+        Self {
+            client,
+            ops: Vec::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Queue an operation
+    pub fn with_op(mut self, op: BatchOp) -> Self {
+        self.ops.push(op);
+        self
+    }
+
+    /// Queue several operations, in order
+    pub fn with_ops(mut self, ops: impl IntoIterator<Item = BatchOp>) -> Self {
+        self.ops.extend(ops);
+        self
+    }
+
+    /// Queue a swap
+    pub fn with_swap(
+        self,
+        pool_id: impl Into<String>,
+        offer_asset: Coin,
+        ask_asset_denom: impl Into<String>,
+        max_slippage: Option<Decimal>,
+    ) -> Self {
+        self.with_op(BatchOp::Swap {
+            pool_id: pool_id.into(),
+            offer_asset,
+            ask_asset_denom: ask_asset_denom.into(),
+            max_slippage,
+        })
+    }
+
+    /// Queue a liquidity provision
+    pub fn with_provide_liquidity(
+        self,
+        pool_id: impl Into<String>,
+        assets: Vec<Coin>,
+        liquidity_max_slippage: Option<Decimal>,
+        swap_max_slippage: Option<Decimal>,
+    ) -> Self {
+        self.with_op(BatchOp::ProvideLiquidity {
+            pool_id: pool_id.into(),
+            assets,
+            liquidity_max_slippage,
+            swap_max_slippage,
+        })
+    }
+
+    /// Queue a liquidity withdrawal
+    pub fn with_withdraw_liquidity(self, pool_id: impl Into<String>, lp_amount: Uint128) -> Self {
+        self.with_op(BatchOp::WithdrawLiquidity {
+            pool_id: pool_id.into(),
+            lp_amount,
+        })
+    }
+
+    /// Queue a reward claim
+    pub fn with_claim_rewards(self, until_epoch: Option<u64>) -> Self {
+        self.with_op(BatchOp::ClaimRewards { until_epoch })
+    }
+
+    /// Queue a message built elsewhere, sent after the queued operations
+    pub fn with_message(mut self, msg: Any) -> Self {
+        self.messages.push(msg);
+        self
+    }
+
+    /// Queued operations, in order
+    pub fn ops(&self) -> &[BatchOp] {
+        &self.ops
+    }
+
+    /// Number of messages the transaction will carry
+    pub fn len(&self) -> usize {
+        self.ops.len() + self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Validate the queued operations and build the transaction's messages
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the batch is empty
+    /// * Returns any error validating an operation, e.g. an unavailable pool
+    pub async fn messages(&self) -> Result<Vec<Any>, Error> {
+        if self.is_empty() {
+            return Err(Error::Other("Batch cannot be empty".to_string()));
+        }
+        let mut msgs = Vec::with_capacity(self.len());
+        for op in &self.ops {
+            msgs.push(self.client.batch_op_msg(op).await?);
+        }
+        msgs.extend(self.messages.iter().cloned());
+        Ok(msgs)
+    }
+
+    /// Estimate the gas and fee of the batch by simulating its transaction
+    ///
+    /// # Errors
+    ///
+    /// * Returns any error from [`messages`](Self::messages)
+    /// * Returns any error from [`MantraDexClient::estimate_gas`]
+    pub async fn estimate(&self) -> Result<GasEstimate, Error> {
+        self.client.estimate_gas(self.messages().await?).await
+    }
+
+    /// Sign and broadcast the batch as one transaction
+    ///
+    /// # Errors
+    ///
+    /// * Returns any error from [`messages`](Self::messages)
+    /// * Returns error if the transaction fails; no operation of the batch is applied
+    pub async fn broadcast(self) -> Result<TxResponse, Error> {
+        let msgs = self.messages().await?;
+        self.client.broadcast_tx(msgs).await
+    }
+}
@@ -0,0 +1,5369 @@
+//! MANTRA DEX SDK - Command Line Interface
+//!
+//! Entry point for MANTRA DEX operations that don't need the full TUI, such as
+//! first-time wallet setup on headless machines or running the event bridge.
+
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use futures::TryStreamExt;
+use mantra_dex_sdk::{
+    activity::{ActivityWatcher, DEFAULT_ACTIVITY_INTERVAL, DEFAULT_BALANCE_DROP_PERCENT},
+    alerts::{FarmWatcher, DEFAULT_CHECK_INTERVAL, DEFAULT_CLOSING_EPOCHS},
+    amount, batch,
+    bridge::{BridgeSink, EventBridge},
+    bulk::{BulkAction, BulkOutcome, BulkPlan, DEFAULT_BULK_BATCH_SIZE},
+    claim::DEFAULT_CLAIM_GAS_BUDGET,
+    config::{Config, PricingConfig, Priority, PriorityFeeConfig},
+    convert::USD,
+    dca::{self, DcaBook, DcaEnd, DcaOutcome, DcaPlan, DcaStatus, NewDcaPlan},
+    earn::DEFAULT_LOOKBACK_BLOCKS,
+    epoch::{self, DEFAULT_CALENDAR_EPOCHS},
+    error::Error,
+    orders::{self, LimitOrder, NewOrder, OrderBook, PriceCondition},
+    output::{self, CommandOutput, OutputFormat},
+    policy::DEFAULT_MAX_RETRY_SLIPPAGE,
+    preferences::{Preferences, PreferencesStore},
+    price_history::{self, PriceCandle, PriceHistory},
+    profile::{Profile, ProfilePaths, RestoreSummary},
+    report::{PortfolioReporter, ReportFormat, ReportSchedule, DEFAULT_PRICE_MOVE_PERCENT},
+    risk::{concentration_flags, RiskThresholds},
+    router::DEFAULT_MAX_HOPS,
+    routing::DEFAULT_DEPTH_STEPS,
+    scheduler::{self, Schedule, ScheduledAction, Trigger},
+    secret::SecretString,
+    simulation::{self, DryRunReport},
+    storage,
+    wallet::{
+        address as wallet_address,
+        wizard::{NetworkEnvironment, WizardState, WizardStep},
+        MantraWallet, MultisigAccount, MultisigTx, PartialSignature, WalletDefaults,
+        WalletMetadata, WalletStorage,
+    },
+    BulkReport, CalendarEntry, ClaimReport, Conversion, CreatePoolOutcome, DepthCurve, Diagnosis,
+    EarnOpportunity, EpochWindow, FarmPosition, GasEstimate, HistoryEntry, HistoryFilter,
+    LpPosition, MantraDexClient, MantraDexClientBuilder, OperationType, PageCursor, PairExecution,
+    PoolComparison, PoolOwnership, PoolSpec, PoolTemplate, PortfolioValuation, PriceImpact,
+    ScheduledTx, SlippageBumpPolicy, SwapRequest, SwapRetry, SwapRoute, TokenMetadata, TxHistory,
+    WithdrawalPreview,
+};
+use mantra_dex_std::farm_manager::{Farm, Position};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use serde::Serialize;
+use std::fmt;
+use std::future::Future;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Farms requested per page when listing the farm manager's farms
+const FARM_PAGE_SIZE: u32 = 100;
+
+/// Routes `swap route` shows by default
+const DEFAULT_ROUTE_CANDIDATES: usize = 5;
+
+#[derive(Parser)]
+#[command(name = "mantra-dex")]
+#[command(about = "MANTRA DEX SDK - Command Line Interface")]
+#[command(version)]
+struct Cli {
+    /// How errors are reported on stderr
+    #[arg(long, global = true, value_enum, default_value_t = ErrorFormat::Text)]
+    error_format: ErrorFormat,
+
+    /// How results are printed on stdout: text, json or yaml. Prompts, reviews
+    /// and progress go to stderr, so stdout holds only the result
+    #[arg(short = 'o', long, global = true, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
+
+    /// Shorthand for --output json
+    #[arg(long, global = true, hide = true)]
+    json: bool,
+
+    /// Priority of the transactions sent: normal, high, or auto to pay the
+    /// priority fee only while the network is congested [default: saved setting]
+    #[arg(long, global = true)]
+    priority: Option<Priority>,
+
+    /// Multiplier applied to simulated gas to size gas limits, for operations
+    /// without enough gas history to calibrate it [default: network setting]
+    #[arg(long, global = true, value_parser = parse_gas_adjustment)]
+    gas_adjustment: Option<f64>,
+
+    /// Denom the transactions pay fees in, the native denom or one of the
+    /// network's fee tokens [default: network setting]
+    #[arg(long, global = true)]
+    fee_denom: Option<String>,
+
+    /// Simulate the swap, liquidity, farm and pool transactions instead of
+    /// sending them, printing their messages, gas, balance changes and events
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ErrorFormat {
+    /// Human readable message
+    Text,
+    /// One JSON object per error
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Wallet management
+    Wallet {
+        #[command(subcommand)]
+        command: WalletCommand,
+    },
+    /// Forward DEX contract events as JSON to webhooks or a NATS subject
+    Bridge {
+        /// Network to follow
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+        /// Event destination: http(s)://... or nats://host:port/subject (repeatable)
+        #[arg(long = "sink", required = true)]
+        sinks: Vec<String>,
+        /// Contract address to watch instead of the configured DEX contracts (repeatable)
+        #[arg(long = "contract")]
+        contracts: Vec<String>,
+        /// First block height to process (defaults to the next block)
+        #[arg(long)]
+        from_height: Option<u64>,
+        /// Delay between checks for new blocks, in milliseconds
+        #[arg(long, default_value_t = 2000)]
+        poll_interval_ms: u64,
+        #[command(flatten)]
+        report: ReportArgs,
+        #[command(flatten)]
+        alerts: AlertArgs,
+        #[command(flatten)]
+        activity: ActivityArgs,
+    },
+    /// Average execution price, volume and fees per pair for an address's swaps
+    Report {
+        /// Address whose swaps are analysed
+        address: String,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+        /// First block height of the period
+        #[arg(long)]
+        from_height: Option<u64>,
+        /// Last block height of the period
+        #[arg(long)]
+        to_height: Option<u64>,
+    },
+    /// List an address's past DEX transactions, syncing them from the chain first
+    History {
+        /// Address whose transactions are listed
+        address: String,
+        /// Only list one operation type: swap, provide_liquidity, withdraw_liquidity,
+        /// create_pool, claim_rewards, farm or other
+        #[arg(long = "type")]
+        operation: Option<OperationType>,
+        /// Only list transactions that used this pool
+        #[arg(long)]
+        pool: Option<String>,
+        /// Transactions listed per page
+        #[arg(long, default_value_t = 20)]
+        limit: u32,
+        /// List the page after this transaction hash
+        #[arg(long)]
+        after: Option<String>,
+        /// List the stored history without syncing new transactions
+        #[arg(long)]
+        offline: bool,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Sample a pool's output against input size and print it as CSV
+    Depth {
+        /// Pool to sample
+        pool_id: String,
+        /// Denom being sold
+        #[arg(long)]
+        offer_denom: String,
+        /// Denom being bought
+        #[arg(long)]
+        ask_denom: String,
+        /// Largest offer to sample, in base units of the offer denom
+        #[arg(long, value_parser = base_units)]
+        max_amount: Uint128,
+        /// Number of sizes sampled between zero and the largest offer
+        #[arg(long, default_value_t = DEFAULT_DEPTH_STEPS)]
+        steps: u32,
+        /// Write the CSV to this file instead of stdout
+        #[arg(long)]
+        out_file: Option<PathBuf>,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Show the current epoch, the time until the next one and the upcoming epochs
+    Epoch {
+        /// Number of epochs to list, the current one included
+        #[arg(long, default_value_t = DEFAULT_CALENDAR_EPOCHS)]
+        epochs: usize,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Rank the pools an asset could be provided to by estimated fee and incentive APR
+    Earn {
+        /// Rank the balances held by this address
+        #[arg(long, required_unless_present = "amount")]
+        address: Option<String>,
+        /// Only rank this denom
+        #[arg(long)]
+        denom: Option<String>,
+        /// Amount to deposit instead of a balance, in base units of --denom
+        #[arg(long, value_parser = base_units, requires = "denom", conflicts_with = "address")]
+        amount: Option<Uint128>,
+        /// Swap history window, in blocks, used to estimate fee income
+        #[arg(long, default_value_t = DEFAULT_LOOKBACK_BLOCKS)]
+        lookback_blocks: u64,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Show the pool automatic routing picks for a swap and whether its pinned route is stale
+    Route {
+        /// Denom being sold
+        #[arg(long)]
+        offer_denom: String,
+        /// Amount sold, in base units of the offer denom
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Denom being bought
+        #[arg(long)]
+        ask_denom: String,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Swap tokens in a pool, signing with a saved wallet, or through several
+    /// pools with `swap route`
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+    Swap {
+        #[command(subcommand)]
+        command: Option<SwapCommand>,
+        #[command(flatten)]
+        args: Option<SwapArgs>,
+    },
+    /// Estimate the spot price, execution price and price impact of a swap
+    Impact {
+        /// Pool to swap in
+        pool_id: String,
+        /// Denom being sold
+        #[arg(long)]
+        offer_denom: String,
+        /// Amount sold, in base units of the offer denom
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Denom being bought
+        #[arg(long)]
+        ask_denom: String,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Queue transactions to execute at a future time, block height or epoch
+    Schedule {
+        #[command(subcommand)]
+        command: ScheduleCommand,
+    },
+    /// Place limit orders, swapped once a pool's price is reached
+    Order {
+        #[command(subcommand)]
+        command: OrderCommand,
+    },
+    /// Record pool spot prices locally and aggregate them into candles
+    Prices {
+        #[command(subcommand)]
+        command: PriceCommand,
+    },
+    /// Repeat a swap at a fixed interval (dollar-cost averaging)
+    Dca {
+        #[command(subcommand)]
+        command: DcaCommand,
+    },
+    /// Provide or withdraw the same share of several liquidity positions at once
+    Bulk {
+        /// provide or withdraw
+        action: BulkAction,
+        /// Share of each position, as a fraction, e.g. 0.5 to withdraw half
+        #[arg(long)]
+        share: Decimal,
+        /// Pool to include (repeatable, defaults to every pool the wallet holds LP tokens of)
+        #[arg(long = "pool")]
+        pools: Vec<String>,
+        /// Maximum slippage of each deposit, as a fraction (defaults to the pool manager's)
+        #[arg(long)]
+        slippage: Option<Decimal>,
+        /// Pools sent per transaction
+        #[arg(long, default_value_t = DEFAULT_BULK_BATCH_SIZE)]
+        batch_size: usize,
+        /// Execute the plan without asking for confirmation
+        #[arg(long)]
+        yes: bool,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network to operate on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Express an amount of one denom in another, or in US dollars, at pool prices
+    Convert {
+        /// Amount to convert, in base units of --from
+        #[arg(value_parser = base_units)]
+        amount: Uint128,
+        /// Denom of the amount, or "usd" for millionths of a dollar
+        #[arg(long)]
+        from: String,
+        /// Denom to express the amount in, or "usd" for millionths of a dollar
+        /// (defaults to the preferred display denom)
+        #[arg(long)]
+        to: Option<String>,
+        /// USD stablecoin to value "usd" through (repeatable, defaults to the saved ones)
+        #[arg(long = "usd-denom")]
+        usd_denoms: Vec<String>,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Value an address's balances, LP positions and pending rewards at pool prices
+    Portfolio {
+        /// Address to value the holdings of
+        address: String,
+        /// Denom to value the portfolio in, or "usd"
+        /// (defaults to the preferred display denom, then "usd")
+        #[arg(long)]
+        quote: Option<String>,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Show the symbol, decimals and origin of denoms, as resolved from the chain
+    Token {
+        /// Denoms to resolve
+        #[arg(required = true)]
+        denoms: Vec<String>,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Create pools from templates for common pair types
+    Pool {
+        #[command(subcommand)]
+        command: PoolCommand,
+    },
+    /// Inspect and withdraw liquidity positions
+    Liquidity {
+        #[command(subcommand)]
+        command: LiquidityCommand,
+    },
+    /// Inspect transactions and sign transactions from multisig accounts
+    Tx {
+        #[command(subcommand)]
+        command: TxCommand,
+    },
+    /// Inspect, close and expand farm positions
+    Farm {
+        #[command(subcommand)]
+        command: FarmCommand,
+    },
+    /// Show or change the preferences shared with the TUI
+    Prefs {
+        #[command(subcommand)]
+        command: PrefsCommand,
+    },
+    /// Move configuration, preferences and wallets to another machine
+    Profile {
+        #[command(subcommand)]
+        command: ProfileCommand,
+    },
+}
+
+/// Scheduled portfolio summaries sent through the bridge's sinks
+#[derive(clap::Args)]
+struct ReportArgs {
+    /// Address to send portfolio summaries for
+    #[arg(long, requires = "report_schedule")]
+    report_address: Option<String>,
+    /// When to send summaries, in UTC: daily@HH:MM, weekly@HH:MM or <weekday>@HH:MM
+    #[arg(long, requires = "report_address")]
+    report_schedule: Option<String>,
+    /// Format of the summary text
+    #[arg(long, value_enum, default_value_t = SummaryFormat::Markdown)]
+    report_format: SummaryFormat,
+    /// Template file with {{placeholder}} fields to render summaries from
+    #[arg(long)]
+    report_template: Option<PathBuf>,
+    /// Denom to value the portfolio in (defaults to the native denom)
+    #[arg(long)]
+    report_quote_denom: Option<String>,
+    /// Price change, in percent, from which a held asset's move is reported
+    #[arg(long, default_value_t = DEFAULT_PRICE_MOVE_PERCENT)]
+    report_price_move_percent: u64,
+}
+
+/// Farm lifecycle alerts sent through the bridge's sinks
+#[derive(clap::Args)]
+struct AlertArgs {
+    /// Send alerts when farms are about to close or change their emission rate
+    #[arg(long)]
+    farm_alerts: bool,
+    /// Also alert when every farm paying this address's open positions is about to end
+    #[arg(long, requires = "farm_alerts")]
+    alert_address: Option<String>,
+    /// Epochs of rewards left from which a farm or position counts as ending soon
+    #[arg(long, default_value_t = DEFAULT_CLOSING_EPOCHS)]
+    alert_closing_epochs: u64,
+    /// Delay between checks of the farm manager, in seconds
+    #[arg(long, default_value_t = DEFAULT_CHECK_INTERVAL.as_secs())]
+    alert_interval_secs: u64,
+}
+
+/// Wallet activity alerts sent through the bridge's sinks
+#[derive(clap::Args)]
+struct ActivityArgs {
+    /// Address to watch for outgoing transfers, balance drops and authz grants (repeatable)
+    #[arg(long = "watch-address")]
+    watch_addresses: Vec<String>,
+    /// Saved wallet to watch, watch-only wallets included (repeatable)
+    #[arg(long = "watch-wallet")]
+    watch_wallets: Vec<String>,
+    /// Recipient whose transfers are expected and not reported (repeatable)
+    #[arg(long = "expected-recipient")]
+    expected_recipients: Vec<String>,
+    /// Balance loss, in percent, from which a drop between checks is reported
+    #[arg(long, default_value_t = DEFAULT_BALANCE_DROP_PERCENT)]
+    balance_drop_percent: u64,
+    /// Delay between checks of the watched addresses, in seconds
+    #[arg(long, default_value_t = DEFAULT_ACTIVITY_INTERVAL.as_secs())]
+    activity_interval_secs: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SummaryFormat {
+    Markdown,
+    Html,
+}
+
+impl From<SummaryFormat> for ReportFormat {
+    fn from(format: SummaryFormat) -> Self {
+        match format {
+            SummaryFormat::Markdown => ReportFormat::Markdown,
+            SummaryFormat::Html => ReportFormat::Html,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum WalletCommand {
+    /// Interactive wallet setup (same steps as the TUI wizard)
+    Setup,
+    /// List saved wallets
+    List,
+    /// Save an address as a watch-only wallet, which can be viewed but not sign
+    Watch {
+        /// Name to save the wallet under
+        name: String,
+        /// Address to watch
+        address: String,
+    },
+    /// Print a saved wallet's public key, to share with the other members of a multisig
+    PublicKey {
+        /// Name of the saved wallet
+        name: String,
+    },
+    /// Print an address with another chain's bech32 prefix, for chains sharing the key
+    ConvertAddress {
+        /// Address to convert, with any prefix
+        address: String,
+        /// Bech32 prefix to convert to, e.g. osmo or cosmos
+        #[arg(long, default_value = "mantra")]
+        prefix: String,
+    },
+    /// Manage multisig accounts
+    Multisig {
+        #[command(subcommand)]
+        command: MultisigCommand,
+    },
+    /// Show or change the defaults applied when a wallet is active
+    Config {
+        /// Name of the saved wallet
+        name: String,
+        /// Default maximum slippage as a fraction, e.g. 0.01 for 1%
+        #[arg(long)]
+        slippage: Option<Decimal>,
+        /// Default gas price in the native denom
+        #[arg(long)]
+        gas_price: Option<f64>,
+        /// Default transaction memo
+        #[arg(long)]
+        memo: Option<String>,
+        /// Pool to prefer when routing swaps (repeatable, replaces the current list)
+        #[arg(long = "preferred-pool")]
+        preferred_pools: Vec<String>,
+        /// Remove all defaults before applying the options above
+        #[arg(long)]
+        clear: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum MultisigCommand {
+    /// Derive a multisig account from its members' public keys and save it as a
+    /// watch-only wallet
+    Create {
+        /// Name to save the wallet under
+        name: String,
+        /// Number of members who must sign each transaction
+        #[arg(long)]
+        threshold: u32,
+        /// Public key of a member, as printed by `wallet public-key` (repeatable)
+        #[arg(long = "pubkey", required = true)]
+        public_keys: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScheduleCommand {
+    /// Queue a swap
+    Swap {
+        /// Pool to swap in
+        pool_id: String,
+        /// Denom being sold
+        #[arg(long)]
+        offer_denom: String,
+        /// Amount sold, in base units of the offer denom
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Denom being bought
+        #[arg(long)]
+        ask_denom: String,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Maximum slippage as a fraction, e.g. 0.01 for 1% (defaults to the wallet's)
+        #[arg(long)]
+        slippage: Option<Decimal>,
+        /// Least amount of the ask denom to accept, checked by simulating right before sending
+        #[arg(long, value_parser = base_units)]
+        min_return: Option<Uint128>,
+        /// When to execute: height:<block>, epoch:<epoch> or an RFC 3339 time
+        #[arg(long)]
+        at: Trigger,
+    },
+    /// Queue a farm rewards claim
+    Claim {
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Last epoch to claim for (defaults to every epoch)
+        #[arg(long)]
+        until_epoch: Option<u64>,
+        /// When to execute: height:<block>, epoch:<epoch> or an RFC 3339 time
+        #[arg(long)]
+        at: Trigger,
+    },
+    /// List scheduled transactions
+    List,
+    /// Cancel a pending transaction
+    Cancel {
+        /// Identifier shown by `schedule list`
+        id: u64,
+    },
+    /// Execute a wallet's transactions as they come due
+    Run {
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Seconds between checks for due transactions
+        #[arg(long, default_value_t = 30)]
+        poll_interval_secs: u64,
+        /// Execute what is due now and exit
+        #[arg(long)]
+        once: bool,
+        /// Network to trade on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DcaCommand {
+    /// Create a plan swapping the same amount at every interval
+    Create {
+        /// Pool to swap in
+        pool_id: String,
+        /// Denom being sold
+        #[arg(long)]
+        offer_denom: String,
+        /// Amount sold by each swap, in base units of the offer denom
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Denom being bought
+        #[arg(long)]
+        ask_denom: String,
+        /// Time between swaps in minutes, hours, days or weeks, e.g. 6h
+        #[arg(long, value_parser = dca::parse_interval)]
+        every: u64,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Maximum slippage as a fraction, e.g. 0.01 for 1% (defaults to the wallet's)
+        #[arg(long)]
+        slippage: Option<Decimal>,
+        /// Stop after this many swaps went through
+        #[arg(long)]
+        executions: Option<u32>,
+        /// RFC 3339 time from which to stop swapping
+        #[arg(long)]
+        until: Option<DateTime<Utc>>,
+        /// RFC 3339 time of the first swap (defaults to now)
+        #[arg(long)]
+        start: Option<DateTime<Utc>>,
+    },
+    /// List DCA plans with their next executions
+    List,
+    /// Stop executing a plan until it is resumed
+    Pause {
+        /// Identifier shown by `dca list`
+        id: u64,
+    },
+    /// Resume a paused plan
+    Resume {
+        /// Identifier shown by `dca list`
+        id: u64,
+    },
+    /// Stop a plan for good
+    Cancel {
+        /// Identifier shown by `dca list`
+        id: u64,
+    },
+    /// Execute a wallet's plans as they come due
+    Run {
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Seconds between checks for due swaps
+        #[arg(long, default_value_t = 30)]
+        poll_interval_secs: u64,
+        /// Execute what is due now and exit
+        #[arg(long)]
+        once: bool,
+        /// Network to trade on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum OrderCommand {
+    /// Place an order to swap once the pool's price of the offer denom is reached
+    Create {
+        /// Pool to swap in, whose price triggers the order
+        pool_id: String,
+        /// Denom being sold
+        #[arg(long)]
+        offer_denom: String,
+        /// Amount sold, in base units of the offer denom
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Denom being bought
+        #[arg(long)]
+        ask_denom: String,
+        /// Price of the offer denom in the ask denom to swap at: >=<price> or <=<price>
+        #[arg(long)]
+        when: PriceCondition,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Maximum slippage as a fraction, e.g. 0.01 for 1% (defaults to the wallet's)
+        #[arg(long)]
+        slippage: Option<Decimal>,
+        /// RFC 3339 time after which the order lapses (defaults to never)
+        #[arg(long)]
+        expires: Option<DateTime<Utc>>,
+    },
+    /// List limit orders
+    List,
+    /// Cancel a pending order
+    Cancel {
+        /// Identifier shown by `order list`
+        id: u64,
+    },
+    /// Watch pool prices and execute a wallet's orders as they are reached
+    Run {
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Seconds between price checks while chain events can't be streamed
+        #[arg(long, default_value_t = orders::DEFAULT_POLL_INTERVAL.as_secs())]
+        poll_interval_secs: u64,
+        /// Execute the orders whose price is reached now and exit
+        #[arg(long)]
+        once: bool,
+        /// Network to trade on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum PriceCommand {
+    /// Sample every pool's spot price at the configured interval
+    Record {
+        /// Sample once and exit
+        #[arg(long)]
+        once: bool,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Aggregate a pool's recorded prices into open, high, low and close candles
+    Candles {
+        /// Pool whose prices to aggregate
+        pool_id: String,
+        /// Denom to price in the pool's other denom (defaults to the pool's first asset)
+        #[arg(long)]
+        denom: Option<String>,
+        /// Length of each candle in minutes, hours, days or weeks, e.g. 1h
+        #[arg(long, default_value = "1h", value_parser = dca::parse_interval)]
+        width: u64,
+        /// How far back to go, in minutes, hours, days or weeks
+        #[arg(long, default_value = "1d", value_parser = dca::parse_interval)]
+        since: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum PoolCommand {
+    /// Create a pool, signing with a saved wallet
+    Create(Box<PoolCreateArgs>),
+    /// List the pool templates with their type and fees
+    Templates,
+    /// Show a pool with its creator, creation block and LP concentration
+    Info {
+        /// Pool identifier
+        pool_id: String,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Compare pools side by side: fees, TVL, 24h volume, APR, price and position
+    Compare {
+        /// Pool identifiers, in the order to show them
+        #[arg(required = true, num_args = 2..)]
+        pool_ids: Vec<String>,
+        /// Include the LP positions held by this address
+        #[arg(long)]
+        address: Option<String>,
+        /// Denom to value TVL, volume and positions in, or "usd"
+        /// (defaults to the preferred display denom, then "usd")
+        #[arg(long)]
+        quote: Option<String>,
+        /// Swap history window, in blocks, used to estimate volume and fee income
+        #[arg(long, default_value_t = DEFAULT_LOOKBACK_BLOCKS)]
+        lookback_blocks: u64,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum LiquidityCommand {
+    /// Show an address's LP positions with their value, cost basis and PnL
+    Positions {
+        /// Address holding the LP tokens
+        address: String,
+        /// Denom to value positions in, or "usd"
+        /// (defaults to the preferred display denom, then "usd")
+        #[arg(long)]
+        quote: Option<String>,
+        /// Use the stored transaction history without syncing new transactions
+        #[arg(long)]
+        offline: bool,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Withdraw LP tokens from a pool, showing the assets refunded and the fee first
+    Withdraw {
+        /// Pool to withdraw from
+        pool_id: String,
+        /// LP tokens to burn, in base units
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Only print the assets refunded and the fee, without withdrawing
+        #[arg(long)]
+        preview: bool,
+        /// Withdraw without asking for confirmation
+        #[arg(long, conflicts_with = "preview")]
+        yes: bool,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network the pool is on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum TxCommand {
+    /// Explain why a transaction failed and how to fix it
+    Diagnose {
+        /// Hash of the transaction
+        hash: String,
+        /// Also print the raw log of the failed transaction
+        #[arg(long)]
+        raw_log: bool,
+        /// Network the transaction was sent on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Send several operations in one transaction, paying a single fee
+    Send {
+        /// JSON file listing the operations, in order, each tagged by type:
+        /// swap, provide_liquidity, withdraw_liquidity or claim_rewards
+        #[arg(long)]
+        batch: PathBuf,
+        /// Only simulate the transaction and print its estimated gas and fee
+        #[arg(long)]
+        estimate_gas: bool,
+        /// Send without asking for confirmation
+        #[arg(long)]
+        yes: bool,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network to send on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Build a transaction from a saved multisig wallet for its members to sign
+    Prepare {
+        /// Saved multisig wallet sending the transaction
+        #[arg(long)]
+        multisig: String,
+        /// JSON file listing the contract executions to send, each
+        /// {"contract": ..., "msg": {...}, "funds": [{"denom": ..., "amount": ...}]}
+        #[arg(long)]
+        msgs: PathBuf,
+        /// File to write the unsigned transaction to
+        #[arg(long)]
+        out_file: PathBuf,
+        /// Network to send on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Sign a multisig transaction as one of the account's members
+    SignPartial {
+        /// Unsigned transaction written by `tx prepare`
+        tx_file: PathBuf,
+        /// Saved wallet of the member signing
+        #[arg(long)]
+        wallet: String,
+        /// File to write the signature to, instead of stdout
+        #[arg(long)]
+        out_file: Option<PathBuf>,
+        /// Sign without asking for confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Combine members' signatures of a multisig transaction and broadcast it
+    Combine {
+        /// Unsigned transaction written by `tx prepare`
+        tx_file: PathBuf,
+        /// Signatures written by `tx sign-partial`
+        #[arg(required = true)]
+        signatures: Vec<PathBuf>,
+        /// Broadcast without asking for confirmation
+        #[arg(long)]
+        yes: bool,
+        /// Network to send on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum FarmCommand {
+    /// List an address's farm positions with their size and start and unlock epochs
+    Positions {
+        /// Address owning the positions
+        address: String,
+        /// Use the stored transaction history without syncing new transactions
+        #[arg(long)]
+        offline: bool,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Show one farm position
+    Position {
+        /// Identifier of the position
+        identifier: String,
+        /// Network to query
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Close a farm position, starting its unlocking period
+    Close {
+        /// Identifier of the position
+        identifier: String,
+        /// LP tokens to close, splitting them off the position; the rest stays open
+        /// (defaults to the whole position)
+        #[arg(long, value_parser = base_units)]
+        amount: Option<Uint128>,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network the position is on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Add LP tokens from the wallet to an open farm position
+    Expand {
+        /// Identifier of the position
+        identifier: String,
+        /// LP tokens to add
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network the position is on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Withdraw the LP tokens of a closed farm position
+    Withdraw {
+        /// Identifier of the position
+        identifier: String,
+        /// Withdraw before the unlocking period ends, paying the emergency unlock penalty
+        #[arg(long)]
+        emergency_unlock: bool,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network the position is on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+    /// Claim the rewards of every farm the wallet has positions in, split into
+    /// as many transactions as their gas needs
+    Claim {
+        /// Simulated gas each claim transaction may use
+        #[arg(long, default_value_t = DEFAULT_CLAIM_GAS_BUDGET)]
+        max_gas: u64,
+        /// Saved wallet to sign with
+        #[arg(long)]
+        wallet: String,
+        /// Network the farms are on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+/// A farm position transaction sent by the CLI
+enum PositionTx {
+    Close(Option<Uint128>),
+    Expand(Uint128),
+    Withdraw { emergency_unlock: bool },
+}
+
+#[derive(Subcommand)]
+enum PrefsCommand {
+    /// Show the current preferences
+    Show,
+    /// Set a preference, e.g. `prefs set default_slippage 0.01`
+    Set {
+        /// Preference to set
+        key: String,
+        /// New value, as TOML (`true`, `30`, `["p1", "p2"]`) or plain text
+        value: String,
+    },
+    /// Reset a preference to its default
+    Unset {
+        /// Preference to reset
+        key: String,
+    },
+    /// Mark a pool as a favorite, listed first in the TUI
+    Favorite {
+        /// Pool identifier
+        pool_id: String,
+        /// Remove the pool from the favorites instead
+        #[arg(long)]
+        remove: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// Write the configuration and preferences to a password-encrypted archive
+    Export {
+        /// Archive to write
+        path: PathBuf,
+        /// Include the saved wallets, still encrypted with their own passwords
+        #[arg(long)]
+        include_wallets: bool,
+        /// Replace the archive if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Restore a profile archive on this machine
+    Import {
+        /// Archive to read
+        path: PathBuf,
+        /// Replace the configuration, preferences and wallets this machine already has
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
+
+/// A swap in one pool
+#[derive(clap::Args)]
+struct SwapArgs {
+    /// Pool to swap in
+    pool_id: String,
+    /// Denom being sold
+    #[arg(long)]
+    offer_denom: String,
+    /// Amount sold, in base units of the offer denom
+    #[arg(long, value_parser = base_units)]
+    amount: Uint128,
+    /// Denom being bought
+    #[arg(long)]
+    ask_denom: String,
+    /// Saved wallet to sign with
+    #[arg(long)]
+    wallet: String,
+    /// Maximum slippage as a fraction, e.g. 0.01 for 1% (defaults to the wallet's)
+    #[arg(long)]
+    slippage: Option<Decimal>,
+    /// Retry with a higher slippage tolerance while the swap fails on slippage
+    #[arg(long)]
+    auto_retry_slippage: bool,
+    /// Highest slippage tolerance a retry may use, as a fraction
+    #[arg(long, default_value_t = DEFAULT_MAX_RETRY_SLIPPAGE)]
+    max_retry_slippage: Decimal,
+    /// Refuse the swap if its estimated price impact exceeds this fraction
+    #[arg(long)]
+    max_price_impact: Option<Decimal>,
+    /// Only simulate the swap and print its estimated gas and fee
+    #[arg(long, conflicts_with = "auto_retry_slippage")]
+    estimate_gas: bool,
+    /// Network to trade on
+    #[arg(long, default_value = "mantra-dukong")]
+    network: String,
+    /// Override the RPC endpoint
+    #[arg(long)]
+    rpc_url: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum SwapCommand {
+    /// Show the candidate routes of a swap through up to several pools, and
+    /// execute the one picked in a single transaction
+    Route {
+        /// Denom being sold
+        #[arg(long)]
+        from: String,
+        /// Denom being bought
+        #[arg(long)]
+        to: String,
+        /// Amount sold, in base units of the sold denom
+        #[arg(long, value_parser = base_units)]
+        amount: Uint128,
+        /// Most pools a route may go through
+        #[arg(long, default_value_t = DEFAULT_MAX_HOPS)]
+        max_hops: usize,
+        /// Number of candidate routes to show
+        #[arg(long, default_value_t = DEFAULT_ROUTE_CANDIDATES)]
+        candidates: usize,
+        /// Execute this candidate (1 for the best) instead of asking
+        #[arg(long, requires = "wallet")]
+        pick: Option<usize>,
+        /// Saved wallet to sign with; without one the routes are only shown
+        #[arg(long)]
+        wallet: Option<String>,
+        /// Maximum slippage of the route's output as a fraction (defaults to the wallet's)
+        #[arg(long)]
+        slippage: Option<Decimal>,
+        /// Network to trade on
+        #[arg(long, default_value = "mantra-dukong")]
+        network: String,
+        /// Override the RPC endpoint
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
+}
+
+/// Pool to create and the overrides of its template
+#[derive(clap::Args)]
+struct PoolCreateArgs {
+    /// Denoms of the pool's assets (repeatable)
+    #[arg(long = "asset", required = true)]
+    assets: Vec<String>,
+    /// Decimals of each asset, in the same order (defaults to 6 for every asset)
+    #[arg(long = "decimals")]
+    decimals: Vec<u8>,
+    /// Template to take the pool type and fees from: stable, volatile or exotic
+    #[arg(long, default_value = "volatile")]
+    template: PoolTemplate,
+    /// Override the template's swap fee, as a fraction, e.g. 0.003 for 0.3%
+    #[arg(long)]
+    swap_fee: Option<Decimal>,
+    /// Override the template's protocol fee, as a fraction
+    #[arg(long)]
+    protocol_fee: Option<Decimal>,
+    /// Override the template's burn fee, as a fraction
+    #[arg(long)]
+    burn_fee: Option<Decimal>,
+    /// Create a stable swap pool with this amplification instead of the template's type
+    #[arg(long)]
+    amp: Option<u64>,
+    /// Create a constant product pool instead of the template's type
+    #[arg(long, conflicts_with = "amp")]
+    constant_product: bool,
+    /// Identifier of the pool (generated by the pool manager if omitted)
+    #[arg(long)]
+    identifier: Option<String>,
+    /// Create the pool even if one with the same assets, type and fees exists
+    #[arg(long)]
+    allow_duplicate: bool,
+    /// Only simulate the pool creation and print its estimated gas and fee
+    #[arg(long)]
+    estimate_gas: bool,
+    /// Saved wallet to sign with
+    #[arg(long)]
+    wallet: String,
+    /// Network to create the pool on
+    #[arg(long, default_value = "mantra-dukong")]
+    network: String,
+    /// Override the RPC endpoint
+    #[arg(long)]
+    rpc_url: Option<String>,
+}
+
+impl PoolCreateArgs {
+    /// Pool type and fees of the template with the overrides applied
+    fn spec(&self) -> PoolSpec {
+        let mut spec = self.template.spec();
+        if let Some(swap_fee) = self.swap_fee {
+            spec = spec.with_swap_fee(swap_fee);
+        }
+        if let Some(protocol_fee) = self.protocol_fee {
+            spec = spec.with_protocol_fee(protocol_fee);
+        }
+        if let Some(burn_fee) = self.burn_fee {
+            spec = spec.with_burn_fee(burn_fee);
+        }
+        if let Some(amp) = self.amp {
+            spec = spec.with_pool_type(PoolType::StableSwap { amp });
+        } else if self.constant_product {
+            spec = spec.with_pool_type(PoolType::ConstantProduct);
+        }
+        spec
+    }
+}
+
+/// Read a line from stdin after printing `prompt` on stderr
+fn prompt(prompt: &str) -> Result<String, Error> {
+    eprint!("{}", prompt);
+    io::stderr().flush()?;
+
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Err(Error::Other(
+            "Input closed before setup finished".to_string(),
+        ));
+    }
+    Ok(line.trim().to_string())
+}
+
+/// Valuation settings of the saved configuration, if any
+fn saved_pricing() -> PricingConfig {
+    Config::load(&Config::default_path())
+        .map(|saved| saved.pricing)
+        .unwrap_or_default()
+}
+
+/// Priority fee settings of the saved configuration, with the priority overridden if given
+fn priority_fee(priority: Option<Priority>) -> PriorityFeeConfig {
+    let saved = Config::load(&Config::default_path())
+        .map(|saved| saved.network.priority_fee)
+        .unwrap_or_default();
+    PriorityFeeConfig {
+        priority: priority.unwrap_or(saved.priority),
+        ..saved
+    }
+}
+
+/// Settings given on the command line for the transactions sent
+#[derive(Clone)]
+struct TxArgs {
+    priority: Option<Priority>,
+    gas_adjustment: Option<f64>,
+    fee_denom: Option<String>,
+    /// Only simulate the transactions
+    dry_run: bool,
+}
+
+impl TxArgs {
+    /// Apply the saved priority fee settings and the overrides to `builder`
+    fn apply(&self, builder: MantraDexClientBuilder) -> MantraDexClientBuilder {
+        let mut builder = builder.with_priority_fee(priority_fee(self.priority));
+        if let Some(gas_adjustment) = self.gas_adjustment {
+            builder = builder.with_gas_adjustment(gas_adjustment);
+        }
+        if let Some(fee_denom) = &self.fee_denom {
+            builder = builder.with_fee_denom(fee_denom.clone());
+        }
+        builder
+    }
+}
+
+fn parse_gas_adjustment(value: &str) -> Result<f64, String> {
+    let adjustment: f64 = value.parse().map_err(|e| format!("{}", e))?;
+    if !adjustment.is_finite() || adjustment < 1.0 {
+        return Err(format!("must be at least 1.0, got {}", value));
+    }
+    Ok(adjustment)
+}
+
+/// Base units typed with the separators of the preferred number locale, e.g. `1,000,000`
+fn base_units(input: &str) -> Result<Uint128, String> {
+    amount::parse_base_units(input, 0, saved_preferences().number_locale())
+        .map_err(|e| e.to_string())
+}
+
+/// Preferences shared with the TUI, migrated from the saved configuration on first use
+fn saved_preferences() -> Preferences {
+    PreferencesStore::open_default()
+        .map(|store| store.get().clone())
+        .unwrap_or_default()
+}
+
+/// Defaults of a saved wallet, completed from the shared preferences
+fn wallet_defaults(storage: &WalletStorage, name: &str) -> Result<WalletDefaults, Error> {
+    Ok(saved_preferences().fill_wallet_defaults(storage.wallet_defaults(name)?))
+}
+
+/// Read a line from the terminal without echoing it
+fn prompt_secret(prompt: &str) -> Result<SecretString, Error> {
+    rpassword::prompt_password(prompt)
+        .map(SecretString::new)
+        .map_err(Error::Io)
+}
+
+/// Ask a yes/no question, returning `default` on empty input
+fn confirm(question: &str, default: bool) -> Result<bool, Error> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    loop {
+        let answer = prompt(&format!("{} {} ", question, hint))?;
+        match answer.to_lowercase().as_str() {
+            "" => return Ok(default),
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => eprintln!("Please answer 'y' or 'n'."),
+        }
+    }
+}
+
+/// A command's result, printed by `main` in the chosen output format
+type Output = Box<dyn CommandOutput>;
+
+/// Print a result on stdout in `format`
+fn print_output(output: &dyn CommandOutput, format: OutputFormat) -> Result<(), Error> {
+    print!("{}", output::render(output, format)?);
+    io::stdout().flush()?;
+    Ok(())
+}
+
+/// A status message, for results with nothing more to report
+#[derive(Serialize)]
+struct Message {
+    message: String,
+}
+
+impl Message {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// The user declined to go ahead
+    fn cancelled() -> Self {
+        Self::new("Cancelled.")
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// A transaction included in a block
+#[derive(Serialize)]
+struct Included {
+    /// What the transaction did, e.g. "Withdrawal included"
+    #[serde(skip)]
+    summary: String,
+    height: i64,
+    txhash: String,
+}
+
+impl Included {
+    fn new(summary: impl Into<String>, tx: &TxResponse) -> Self {
+        Self {
+            summary: summary.into(),
+            height: tx.height,
+            txhash: tx.txhash.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Included {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in block {}: {}",
+            self.summary, self.height, self.txhash
+        )
+    }
+}
+
+fn print_step(wizard: &WizardState) {
+    let (current, total) = wizard.current_step.step_number();
+    eprintln!();
+    eprintln!("[{}/{}] {}", current, total, wizard.current_step.title());
+}
+
+/// A wallet created by the setup wizard
+#[derive(Serialize)]
+struct NewWallet {
+    /// Name the wallet was saved under, if it was saved
+    name: Option<String>,
+    address: String,
+}
+
+impl fmt::Display for NewWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            writeln!(f, "✓ Wallet '{}' saved", name)?;
+        }
+        write!(f, "✓ Wallet address: {}", self.address)
+    }
+}
+
+/// Run the wallet setup wizard with plain terminal prompts
+fn run_wallet_setup() -> Result<Output, Error> {
+    let mut wizard = WizardState::new();
+
+    loop {
+        if wizard.current_step != WizardStep::Complete {
+            print_step(&wizard);
+        }
+
+        match wizard.current_step {
+            WizardStep::Welcome => {
+                eprintln!("This will set up a wallet for use with MANTRA DEX.");
+                eprintln!("You can import an existing mnemonic or generate a new one.");
+            }
+            WizardStep::NetworkSelection => {
+                for (index, network) in [NetworkEnvironment::Testnet, NetworkEnvironment::Mainnet]
+                    .iter()
+                    .enumerate()
+                {
+                    eprintln!(
+                        "  {}) {} - {}",
+                        index + 1,
+                        network.display_name(),
+                        network.description()
+                    );
+                }
+                wizard.selected_network = match prompt("Select network [1]: ")?.as_str() {
+                    "" | "1" => NetworkEnvironment::Testnet,
+                    "2" => NetworkEnvironment::Mainnet,
+                    other => {
+                        eprintln!("Invalid choice '{}'.", other);
+                        continue;
+                    }
+                };
+            }
+            WizardStep::WalletSetup => {
+                eprintln!("  1) Import an existing mnemonic");
+                eprintln!("  2) Generate a new wallet");
+                let generate = match prompt("Choose an option [1]: ")?.as_str() {
+                    "" | "1" => false,
+                    "2" => true,
+                    other => {
+                        eprintln!("Invalid choice '{}'.", other);
+                        continue;
+                    }
+                };
+                if generate == wizard.import_existing {
+                    wizard.toggle_wallet_mode()?;
+                }
+
+                if wizard.import_existing {
+                    wizard.mnemonic_input =
+                        prompt_secret("Enter your mnemonic phrase (input hidden): ")?;
+                } else if let Some(mnemonic) = &wizard.generated_mnemonic {
+                    eprintln!();
+                    eprintln!("Your new recovery phrase:");
+                    eprintln!();
+                    eprintln!("  {}", mnemonic.expose_secret());
+                    eprintln!();
+                    eprintln!(
+                        "Write it down and store it somewhere safe. It will not be shown again."
+                    );
+                }
+
+                if !wizard.can_proceed() {
+                    eprintln!("A mnemonic of at least 12 words is required.");
+                    continue;
+                }
+                if let Some(mnemonic) = wizard.current_mnemonic() {
+                    if let Err(e) = MantraWallet::from_mnemonic(mnemonic, 0) {
+                        eprintln!("{}", e);
+                        continue;
+                    }
+                }
+            }
+            WizardStep::SecurityWarning => {
+                eprintln!("  • Never share your mnemonic with anyone.");
+                eprintln!("  • Anyone with your mnemonic has full control of your funds.");
+                eprintln!("  • Lost mnemonics cannot be recovered.");
+                wizard.security_acknowledged = confirm("I understand these risks", false)?;
+                if !wizard.can_proceed() {
+                    eprintln!("You must acknowledge the security information to continue.");
+                    continue;
+                }
+            }
+            WizardStep::WalletSave => {
+                if wizard.save_wallet
+                    != confirm("Save the wallet encrypted on this machine?", true)?
+                {
+                    wizard.toggle_save_wallet();
+                }
+
+                if wizard.save_wallet {
+                    wizard.wallet_name = prompt("Wallet name: ")?;
+                    wizard.save_password = prompt_secret("Password: ")?;
+                    wizard.save_password_confirm = prompt_secret("Confirm password: ")?;
+
+                    let errors = wizard.validate_wallet_save();
+                    if !errors.is_empty() {
+                        for error in &errors {
+                            eprintln!("  ✗ {}", error);
+                        }
+                        wizard.clear_wallet_save_data();
+                        continue;
+                    }
+                }
+            }
+            WizardStep::Confirmation => {
+                eprintln!("  Network: {}", wizard.selected_network.display_name());
+                eprintln!(
+                    "  Wallet:  {}",
+                    if wizard.import_existing {
+                        "Imported"
+                    } else {
+                        "Newly generated"
+                    }
+                );
+                if wizard.save_wallet {
+                    eprintln!("  Save as: {}", wizard.wallet_name);
+                } else {
+                    eprintln!("  Save as: (not saved)");
+                }
+                if !confirm("Proceed?", true)? {
+                    wizard.previous_step();
+                    continue;
+                }
+            }
+            WizardStep::Complete => {
+                let mnemonic = SecretString::from(
+                    wizard
+                        .current_mnemonic()
+                        .ok_or_else(|| Error::Wallet("No mnemonic available".to_string()))?,
+                );
+                let wallet = MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0)?;
+                let address = wallet.address()?.to_string();
+
+                let name = wizard.save_wallet.then(|| wizard.wallet_name.clone());
+                if let Some(name) = &name {
+                    WalletStorage::new()?.save_wallet(
+                        name,
+                        mnemonic.expose_secret(),
+                        wizard.save_password.expose_secret(),
+                        &address,
+                    )?;
+                    wizard.clear_wallet_save_data();
+                }
+
+                wizard.finish_wizard();
+                return Ok(Box::new(NewWallet { name, address }));
+            }
+        }
+
+        wizard.next_step();
+    }
+}
+
+/// Saved wallets, one per line
+#[derive(Serialize)]
+#[serde(transparent)]
+struct WalletList(Vec<WalletMetadata>);
+
+impl fmt::Display for WalletList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(
+                f,
+                "No saved wallets. Run `mantra-dex wallet setup` to create one."
+            );
+        }
+
+        for wallet in &self.0 {
+            let kind = match (&wallet.multisig, wallet.watch_only) {
+                (Some(account), _) => format!(
+                    "\tmultisig {}/{}",
+                    account.threshold(),
+                    account.public_keys().len()
+                ),
+                (None, true) => "\twatch-only".to_string(),
+                (None, false) => String::new(),
+            };
+            writeln!(
+                f,
+                "{}\t{}\t{}{}",
+                wallet.name, wallet.address, wallet.created_at, kind
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run_wallet_list() -> Result<Output, Error> {
+    Ok(Box::new(WalletList(WalletStorage::new()?.list_wallets()?)))
+}
+
+/// A saved watch-only wallet
+#[derive(Serialize)]
+struct WatchedWallet {
+    name: String,
+    address: String,
+}
+
+impl fmt::Display for WatchedWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "✓ Watching {} as '{}'", self.address, self.name)?;
+        write!(
+            f,
+            "  Balances and positions can be viewed; transactions can't be signed."
+        )
+    }
+}
+
+/// Asks for a saved wallet's password and derives its signing key. Watch-only
+/// wallets are refused before the prompt, since they have nothing to unlock.
+fn run_wallet_watch(name: &str, address: &str) -> Result<Output, Error> {
+    WalletStorage::new()?.save_watch_only_wallet(name, address)?;
+    Ok(Box::new(WatchedWallet {
+        name: name.to_string(),
+        address: address.to_string(),
+    }))
+}
+
+/// A wallet's public key, as the JSON its multisig partners pass to `--pubkey`
+#[derive(Serialize)]
+struct WalletPublicKey {
+    name: String,
+    public_key: serde_json::Value,
+}
+
+impl fmt::Display for WalletPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.public_key)
+    }
+}
+
+fn run_wallet_public_key(name: &str) -> Result<Output, Error> {
+    let wallet = unlock_wallet(&WalletStorage::new()?, name)?;
+    Ok(Box::new(WalletPublicKey {
+        name: name.to_string(),
+        public_key: serde_json::from_str(&wallet.public_key()?.to_json())?,
+    }))
+}
+
+/// An address with another bech32 prefix
+#[derive(Serialize)]
+struct ConvertedAddress {
+    address: String,
+}
+
+impl fmt::Display for ConvertedAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+fn run_wallet_convert_address(address: &str, prefix: &str) -> Result<Output, Error> {
+    Ok(Box::new(ConvertedAddress {
+        address: wallet_address::convert_prefix(address, prefix)?,
+    }))
+}
+
+/// A multisig account saved as a watch-only wallet
+#[derive(Serialize)]
+struct NewMultisig {
+    name: String,
+    threshold: u32,
+    members: usize,
+    address: String,
+}
+
+impl fmt::Display for NewMultisig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "✓ Multisig '{}' ({} of {}): {}",
+            self.name, self.threshold, self.members, self.address
+        )?;
+        write!(
+            f,
+            "  Fund the address before preparing transactions with `tx prepare`."
+        )
+    }
+}
+
+fn run_multisig_create(
+    name: &str,
+    threshold: u32,
+    public_keys: &[String],
+) -> Result<Output, Error> {
+    let public_keys = public_keys
+        .iter()
+        .map(|key| {
+            PublicKey::from_json(key)
+                .map_err(|e| Error::Wallet(format!("Invalid public key '{}': {}", key, e)))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let account = MultisigAccount::new(threshold, public_keys)?;
+    WalletStorage::new()?.save_multisig_wallet(name, &account)?;
+
+    Ok(Box::new(NewMultisig {
+        name: name.to_string(),
+        threshold: account.threshold(),
+        members: account.public_keys().len(),
+        address: account.address()?.to_string(),
+    }))
+}
+
+fn unlock_wallet(storage: &WalletStorage, wallet_name: &str) -> Result<MantraWallet, Error> {
+    if storage.wallet_metadata(wallet_name)?.watch_only {
+        return Err(Error::Wallet(format!(
+            "Wallet '{}' is watch-only and cannot sign transactions",
+            wallet_name
+        )));
+    }
+
+    let password = prompt_secret(&format!("Password for wallet '{}': ", wallet_name))?;
+    let mnemonic = storage.load_wallet(wallet_name, password.expose_secret())?;
+    MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0)
+}
+
+/// The defaults of a saved wallet
+#[derive(Serialize)]
+struct WalletConfig {
+    name: String,
+    defaults: WalletDefaults,
+}
+
+impl fmt::Display for WalletConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let defaults = &self.defaults;
+        if defaults.is_empty() {
+            return write!(f, "No defaults set for wallet '{}'.", self.name);
+        }
+
+        let unset = || "(not set)".to_string();
+        writeln!(f, "Defaults for wallet '{}':", self.name)?;
+        writeln!(
+            f,
+            "  slippage:        {}",
+            defaults.slippage.map_or_else(unset, |s| s.to_string())
+        )?;
+        writeln!(
+            f,
+            "  gas price:       {}",
+            defaults.gas_price.map_or_else(unset, |g| g.to_string())
+        )?;
+        writeln!(
+            f,
+            "  memo:            {}",
+            defaults.memo.clone().unwrap_or_else(unset)
+        )?;
+        write!(
+            f,
+            "  preferred pools: {}",
+            if defaults.preferred_pools.is_empty() {
+                unset()
+            } else {
+                defaults.preferred_pools.join(", ")
+            }
+        )
+    }
+}
+
+fn run_wallet_config(
+    name: &str,
+    slippage: Option<Decimal>,
+    gas_price: Option<f64>,
+    memo: Option<String>,
+    preferred_pools: Vec<String>,
+    clear: bool,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let mut defaults = if clear {
+        WalletDefaults::default()
+    } else {
+        storage.wallet_defaults(name)?
+    };
+
+    let changed = clear
+        || slippage.is_some()
+        || gas_price.is_some()
+        || memo.is_some()
+        || !preferred_pools.is_empty();
+    if changed {
+        if let Some(slippage) = slippage {
+            if slippage >= Decimal::one() {
+                return Err(Error::Config(format!(
+                    "Slippage must be a fraction below 1, got {}",
+                    slippage
+                )));
+            }
+            defaults.slippage = Some(slippage);
+        }
+        if let Some(gas_price) = gas_price {
+            if !gas_price.is_finite() || gas_price <= 0.0 {
+                return Err(Error::Config(format!(
+                    "Gas price must be positive, got {}",
+                    gas_price
+                )));
+            }
+            defaults.gas_price = Some(gas_price);
+        }
+        if let Some(memo) = memo {
+            defaults.memo = Some(memo).filter(|memo| !memo.is_empty());
+        }
+        if !preferred_pools.is_empty() {
+            defaults.preferred_pools = preferred_pools;
+        }
+        storage.set_wallet_defaults(name, defaults.clone())?;
+    }
+
+    Ok(Box::new(WalletConfig {
+        name: name.to_string(),
+        defaults,
+    }))
+}
+
+/// Average execution price, volume and fees per pair of an address's swaps
+#[derive(Serialize)]
+#[serde(transparent)]
+struct ExecutionReport {
+    #[serde(skip)]
+    address: String,
+    pairs: Vec<PairExecution>,
+}
+
+impl fmt::Display for ExecutionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.pairs.is_empty() {
+            return write!(f, "No swaps found for {} in this period.", self.address);
+        }
+
+        writeln!(f, "PAIR\tTRADES\tSOLD\tRECEIVED\tAVG PRICE\tFEES\tBLOCKS")?;
+        for pair in &self.pairs {
+            writeln!(
+                f,
+                "{}/{}\t{}\t{}\t{}\t{}\t{}{}\t{}-{}",
+                pair.offer_denom,
+                pair.ask_denom,
+                pair.trades,
+                pair.total_offer,
+                pair.total_return,
+                pair.average_price,
+                pair.total_fees,
+                pair.ask_denom,
+                pair.height_range.0,
+                pair.height_range.1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_report(
+    address: &str,
+    network: &str,
+    rpc_url: Option<String>,
+    from_height: Option<u64>,
+    to_height: Option<u64>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let pairs = client
+        .execution_report(address, from_height, to_height)
+        .await?;
+    Ok(Box::new(ExecutionReport {
+        address: address.to_string(),
+        pairs,
+    }))
+}
+
+/// A page of an address's stored transactions
+#[derive(Serialize)]
+struct HistoryPage {
+    #[serde(skip)]
+    address: String,
+    /// Transactions synced from the chain before listing
+    synced: usize,
+    transactions: Vec<HistoryEntry>,
+    /// Hash to pass to `--after` for the next page, if there is one
+    next_after: Option<String>,
+}
+
+impl fmt::Display for HistoryPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.synced > 0 {
+            writeln!(f, "Synced {} new transactions", self.synced)?;
+        }
+        if self.transactions.is_empty() {
+            return write!(f, "No transactions found for {}.", self.address);
+        }
+
+        writeln!(f, "HEIGHT\tTIME\tTYPE\tPOOL\tSTATUS\tHASH")?;
+        for entry in &self.transactions {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                entry.height,
+                entry.timestamp.map_or_else(
+                    || "-".to_string(),
+                    |timestamp| timestamp.format("%Y-%m-%d %H:%M").to_string()
+                ),
+                entry.operation,
+                entry.pool_id.as_deref().unwrap_or("-"),
+                if entry.success { "ok" } else { "failed" },
+                entry.tx_hash
+            )?;
+        }
+        if let Some(next) = &self.next_after {
+            writeln!(f, "More transactions: --after {}", next)?;
+        }
+        Ok(())
+    }
+}
+
+/// List the stored history of `address`, syncing it over `connection` first if given
+async fn run_history(
+    address: &str,
+    filter: &HistoryFilter,
+    cursor: &PageCursor,
+    connection: Option<(&str, Option<String>)>,
+) -> Result<Output, Error> {
+    let history = TxHistory::open_in(storage::shared()?)?;
+    let mut synced = 0;
+    if let Some((network, rpc_url)) = connection {
+        let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+        synced = client.sync_history(&history, address).await?;
+    }
+
+    let page = history.entries(address, filter, cursor);
+    let next_after = page.next_page().and_then(|next| next.start_after.clone());
+    Ok(Box::new(HistoryPage {
+        address: address.to_string(),
+        synced,
+        transactions: page.items,
+        next_after,
+    }))
+}
+
+/// A pool's depth curve, as CSV in text
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Depth(DepthCurve);
+
+impl fmt::Display for Depth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.to_csv())
+    }
+}
+
+/// A depth curve written to a CSV file
+#[derive(Serialize)]
+struct DepthFile {
+    pool_id: String,
+    samples: usize,
+    path: PathBuf,
+}
+
+impl fmt::Display for DepthFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Wrote {} sample(s) for pool {} to {}",
+            self.samples,
+            self.pool_id,
+            self.path.display()
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_depth(
+    pool_id: &str,
+    offer_denom: String,
+    ask_denom: &str,
+    max_amount: Uint128,
+    steps: u32,
+    out_file: Option<PathBuf>,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let max_offer = Coin {
+        denom: offer_denom,
+        amount: max_amount,
+    };
+    let curve = client
+        .depth_curve(pool_id, &max_offer, ask_denom, steps)
+        .await?;
+
+    let Some(path) = out_file else {
+        return Ok(Box::new(Depth(curve)));
+    };
+    std::fs::write(&path, curve.to_csv())?;
+    Ok(Box::new(DepthFile {
+        pool_id: pool_id.to_string(),
+        samples: curve.points.len(),
+        path,
+    }))
+}
+
+/// The current epoch and the ones after it, with the farms starting and ending
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Calendar {
+    #[serde(skip)]
+    current: EpochWindow,
+    /// Time left until the next epoch, for display
+    #[serde(skip)]
+    next_in: String,
+    entries: Vec<CalendarEntry>,
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Epoch {} started {}, next epoch in {} ({})",
+            self.current.id,
+            self.current.start.format("%Y-%m-%d %H:%M UTC"),
+            self.next_in,
+            self.current.end.format("%Y-%m-%d %H:%M UTC")
+        )?;
+        writeln!(f)?;
+        writeln!(f, "EPOCH\tSTART\tEND\tFARMS STARTING\tFARMS ENDING")?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}",
+                entry.window.id,
+                entry.window.start.format("%Y-%m-%d %H:%M"),
+                entry.window.end.format("%Y-%m-%d %H:%M"),
+                entry.farms_starting.join(","),
+                entry.farms_ending.join(",")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_epoch(epochs: usize, network: &str, rpc_url: Option<String>) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let calendar = client.get_epoch_calendar().await?;
+    let farms: Vec<Farm> = match client.config().contracts.farm_manager {
+        Some(_) => {
+            client
+                .farms_stream(None, FARM_PAGE_SIZE)
+                .try_collect()
+                .await?
+        }
+        None => Vec::new(),
+    };
+    let now = chrono::Utc::now();
+    Ok(Box::new(Calendar {
+        current: calendar.window(calendar.epoch_at(now)),
+        next_in: epoch::format_countdown(calendar.time_until_next(now)),
+        entries: epoch::calendar(&calendar, now, epochs.max(1), &farms),
+    }))
+}
+
+/// Pools to earn on with the given assets, best first
+#[derive(Serialize)]
+#[serde(transparent)]
+struct EarnRanking(Vec<EarnOpportunity>);
+
+impl fmt::Display for EarnRanking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No pools found for the given assets.");
+        }
+
+        writeln!(
+            f,
+            "ASSET\tPOOL\tFEE APR\tINCENTIVE APR\tTOTAL APR\tPAIRING\tPROJECTED/YEAR"
+        )?;
+        for opportunity in &self.0 {
+            let pairing: Vec<String> = opportunity
+                .pairing
+                .iter()
+                .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                .collect();
+            writeln!(
+                f,
+                "{}{}\t{}\t{:.2}%\t{:.2}%\t{:.2}%\t{}\t{}{}",
+                opportunity.deposit,
+                opportunity.asset,
+                opportunity.pool_id,
+                percent(opportunity.fee_apr),
+                percent(opportunity.incentive_apr),
+                percent(opportunity.total_apr()),
+                pairing.join(","),
+                opportunity.projected_return,
+                opportunity.asset
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_earn(
+    address: Option<String>,
+    denom: Option<String>,
+    amount: Option<Uint128>,
+    lookback_blocks: u64,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let holdings = match (address, denom, amount) {
+        (_, Some(denom), Some(amount)) => vec![Coin { denom, amount }],
+        (Some(address), Some(denom), None) => {
+            vec![client.get_balance_for_address(&address, &denom).await?]
+        }
+        (Some(address), None, _) => client.get_balances_for_address(&address).await?,
+        (None, _, _) => {
+            return Err(Error::Config(
+                "Either --address or --denom with --amount is required".to_string(),
+            ))
+        }
+    };
+    let holdings: Vec<Coin> = holdings
+        .into_iter()
+        .filter(|holding| !holding.amount.is_zero())
+        .collect();
+
+    let opportunities = client
+        .earn_opportunities(&holdings, lookback_blocks)
+        .await?;
+    Ok(Box::new(EarnRanking(opportunities)))
+}
+
+/// The pool automatic routing picks for a swap, and how a pinned route compares
+#[derive(Serialize)]
+struct RouteSummary {
+    #[serde(skip)]
+    offer_asset: Coin,
+    #[serde(skip)]
+    ask_denom: String,
+    pool_id: String,
+    return_amount: Uint128,
+    pinned_pool: Option<String>,
+    pinned: bool,
+    pin_is_stale: bool,
+    better_pool: Option<String>,
+    better_return_amount: Option<Uint128>,
+}
+
+impl fmt::Display for RouteSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{} -> {}{} via pool {}{}",
+            self.offer_asset.amount,
+            self.offer_asset.denom,
+            self.return_amount,
+            self.ask_denom,
+            self.pool_id,
+            if self.pinned { " (pinned)" } else { "" }
+        )
+    }
+}
+
+async fn run_route(
+    offer_asset: Coin,
+    ask_denom: &str,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let route = client.select_route(&offer_asset, ask_denom).await?;
+
+    match (&route.pinned_pool, &route.better) {
+        (Some(_), Some(better)) => eprintln!(
+            "Pinned route is no longer optimal: pool {} would return {}{}",
+            better.pool_id,
+            better.return_amount(),
+            ask_denom
+        ),
+        (Some(pinned), None) if !route.is_pinned() => eprintln!(
+            "Pinned pool {} can't quote this swap, routed to the best available pool",
+            pinned
+        ),
+        _ => {}
+    }
+    Ok(Box::new(RouteSummary {
+        offer_asset,
+        ask_denom: ask_denom.to_string(),
+        pool_id: route.quote.pool_id.clone(),
+        return_amount: route.quote.return_amount(),
+        pinned_pool: route.pinned_pool.clone(),
+        pinned: route.is_pinned(),
+        pin_is_stale: route.pin_is_stale(),
+        better_pool: route.better.as_ref().map(|better| better.pool_id.clone()),
+        better_return_amount: route.better.as_ref().map(|better| better.return_amount()),
+    }))
+}
+
+/// Candidate routes of a swap, best first
+#[derive(Serialize)]
+#[serde(transparent)]
+struct RouteCandidates(Vec<SwapRoute>);
+
+impl fmt::Display for RouteCandidates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#\tPATH\tPOOLS\tRETURN\tPRICE IMPACT")?;
+        for (index, route) in self.0.iter().enumerate() {
+            let pools: Vec<&str> = route.hops.iter().map(|hop| hop.pool_id.as_str()).collect();
+            write!(
+                f,
+                "\n{}\t{}\t{}\t{}{}\t{:.2}%",
+                index + 1,
+                route.path().join(" -> "),
+                pools.join(", "),
+                route.return_amount,
+                route.ask_denom,
+                percent(route.price_impact)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_swap_route(
+    offer_asset: Coin,
+    ask_denom: &str,
+    max_hops: usize,
+    candidates: usize,
+    pick: Option<usize>,
+    wallet_name: Option<&str>,
+    slippage: Option<Decimal>,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let mut builder = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_simulate_only(tx_args.dry_run);
+    if let Some(wallet_name) = wallet_name {
+        let storage = WalletStorage::new()?;
+        builder = builder
+            .with_wallet(unlock_wallet(&storage, wallet_name)?)
+            .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?);
+    }
+    let client = builder.build().await?;
+
+    let routes = client
+        .find_routes(&offer_asset, ask_denom, max_hops, candidates.max(1))
+        .await?;
+    let candidates = RouteCandidates(routes);
+    if wallet_name.is_none() {
+        return Ok(Box::new(candidates));
+    }
+
+    eprintln!("{}", candidates);
+    let count = candidates.0.len();
+    let index = match pick {
+        Some(index) => index,
+        // A dry run doesn't ask, like the other commands
+        None if tx_args.dry_run => 1,
+        None => {
+            let answer = prompt(&format!(
+                "Route to execute [1-{}, empty to cancel]: ",
+                count
+            ))?;
+            if answer.is_empty() {
+                return Ok(Box::new(Message::cancelled()));
+            }
+            answer
+                .parse()
+                .map_err(|_| Error::Config(format!("Invalid route number '{}'", answer)))?
+        }
+    };
+    let route = index
+        .checked_sub(1)
+        .and_then(|index| candidates.0.get(index))
+        .ok_or_else(|| {
+            Error::Config(format!(
+                "There is no route {}, pick one from 1 to {}",
+                index, count
+            ))
+        })?;
+    if let Some(warning) = route.warning() {
+        eprintln!("Warning: {}", warning);
+    }
+
+    let tx = client.execute_route(route, slippage).await?;
+    if let Some(report) = dry_run_report(&client, &tx)? {
+        return Ok(Box::new(report));
+    }
+    Ok(Box::new(Included::new(
+        format!(
+            "Swapped {} for about {}{} via {}",
+            route.offer_asset,
+            route.return_amount,
+            route.ask_denom,
+            route.path().join(" -> ")
+        ),
+        &tx,
+    )))
+}
+
+/// A fraction as a percentage, for display
+fn percent(fraction: Decimal) -> f64 {
+    fraction.to_string().parse::<f64>().unwrap_or(0.0) * 100.0
+}
+
+/// The gas and fee of a transaction, estimated by simulating it
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Estimate(GasEstimate);
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Simulated {} gas; would send with a limit of {} ({:.2}x) and pay {}{}",
+            self.0.simulated,
+            self.0.gas_limit,
+            self.0.adjustment(),
+            self.0.fee.amount,
+            self.0.fee.denom
+        )
+    }
+}
+
+/// What a dry run's transaction would have done, or `None` if `tx` was sent
+fn dry_run_report(
+    client: &MantraDexClient,
+    tx: &TxResponse,
+) -> Result<Option<DryRunReport>, Error> {
+    if !simulation::is_simulated(tx) {
+        return Ok(None);
+    }
+    let sender = client.wallet()?.address()?.to_string();
+    Ok(Some(DryRunReport::from_response(tx, &sender)?))
+}
+
+/// Spot price, execution price and price impact of a swap
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Impact(PriceImpact);
+
+impl fmt::Display for Impact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let impact = &self.0;
+        writeln!(
+            f,
+            "{} -> {}{} in pool {}",
+            impact.offer_asset, impact.return_amount, impact.ask_denom, impact.pool_id
+        )?;
+        writeln!(f, "Spot price: {}", impact.spot_price)?;
+        writeln!(f, "Execution price: {}", impact.execution_price)?;
+        writeln!(f, "Price impact: {:.2}%", percent(impact.price_impact))?;
+        write!(f, "Fees: {}{}", impact.fee_amount, impact.ask_denom)
+    }
+}
+
+async fn run_impact(
+    pool_id: &str,
+    offer_asset: Coin,
+    ask_denom: &str,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let impact = client
+        .estimate_price_impact(pool_id, &offer_asset, ask_denom)
+        .await?;
+    if let Some(warning) = impact.warning() {
+        eprintln!("Warning: {}", warning);
+    }
+    Ok(Box::new(Impact(impact)))
+}
+
+/// A swap included in a block
+#[derive(Serialize)]
+struct SwapDone {
+    height: i64,
+    txhash: String,
+    /// Slippage tolerance the swap went through with
+    max_slippage: Option<Decimal>,
+    /// Tolerances of the attempts that failed on slippage, oldest first
+    failed_slippages: Vec<Decimal>,
+}
+
+impl fmt::Display for SwapDone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max_slippage {
+            Some(slippage) if !self.failed_slippages.is_empty() => write!(
+                f,
+                "Swap included in block {} with {} slippage tolerance: {}",
+                self.height, slippage, self.txhash
+            ),
+            _ => write!(f, "Swap included in block {}: {}", self.height, self.txhash),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_swap(
+    pool_id: &str,
+    offer_asset: Coin,
+    ask_denom: &str,
+    wallet_name: &str,
+    slippage: Option<Decimal>,
+    retry_policy: Option<SlippageBumpPolicy>,
+    max_price_impact: Option<Decimal>,
+    estimate_gas: bool,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+
+    match client
+        .estimate_price_impact(pool_id, &offer_asset, ask_denom)
+        .await
+    {
+        Ok(impact) => {
+            if let Some(warning) = impact.warning() {
+                eprintln!("Warning: {}", warning);
+            }
+            if let Some(max) = max_price_impact.filter(|max| impact.price_impact > *max) {
+                return Err(Error::Policy(format!(
+                    "Estimated price impact {:.2}% exceeds the {:.2}% limit",
+                    percent(impact.price_impact),
+                    percent(max)
+                )));
+            }
+        }
+        // Without a limit, a swap the local math can't estimate still goes ahead
+        Err(e) if max_price_impact.is_some() => return Err(e),
+        Err(_) => {}
+    }
+
+    if estimate_gas {
+        let estimate = client
+            .estimate_swap(pool_id, offer_asset, ask_denom, slippage)
+            .await?;
+        return Ok(Box::new(Estimate(estimate)));
+    }
+
+    let mut request = SwapRequest::new(pool_id, offer_asset, ask_denom);
+    request.max_slippage = slippage;
+    let swap = match retry_policy {
+        Some(policy) => client.swap_with_slippage_retries(request, &policy).await?,
+        None => {
+            let tx = client
+                .swap(
+                    &request.pool_id,
+                    request.offer_asset.clone(),
+                    &request.ask_asset_denom,
+                    request.max_slippage,
+                )
+                .await?;
+            SwapRetry {
+                request,
+                failed_slippages: Vec::new(),
+                tx,
+            }
+        }
+    };
+
+    for failed in &swap.failed_slippages {
+        eprintln!(
+            "Swap failed at {} slippage tolerance, retried higher",
+            failed
+        );
+    }
+    if let Some(report) = dry_run_report(&client, &swap.tx)? {
+        return Ok(Box::new(report));
+    }
+    Ok(Box::new(SwapDone {
+        height: swap.tx.height,
+        txhash: swap.tx.txhash,
+        max_slippage: swap.request.max_slippage,
+        failed_slippages: swap.failed_slippages,
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_bridge(
+    network: &str,
+    rpc_url: Option<String>,
+    sinks: &[String],
+    contracts: Vec<String>,
+    from_height: Option<u64>,
+    poll_interval_ms: u64,
+    report: ReportArgs,
+    alerts: AlertArgs,
+    activity: ActivityArgs,
+) -> Result<(), Error> {
+    let sinks = sinks
+        .iter()
+        .map(|sink| sink.parse())
+        .collect::<Result<Vec<BridgeSink>, Error>>()?;
+
+    let mut watched = activity.watch_addresses;
+    if !activity.watch_wallets.is_empty() {
+        let storage = WalletStorage::new()?;
+        for name in &activity.watch_wallets {
+            watched.push(storage.wallet_metadata(name)?.address);
+        }
+    }
+
+    let client = Arc::new(MantraDexClient::connect(network, rpc_url)?.build().await?);
+    let reporter = match (report.report_address, report.report_schedule) {
+        (Some(address), Some(schedule)) => {
+            let schedule: ReportSchedule = schedule.parse()?;
+            let mut reporter = PortfolioReporter::new(client.clone(), address, schedule)
+                .with_format(report.report_format.into())
+                .with_price_move_threshold(Decimal::percent(report.report_price_move_percent));
+            if let Some(path) = report.report_template {
+                reporter = reporter.with_template(std::fs::read_to_string(path)?);
+            }
+            if let Some(quote_denom) = report
+                .report_quote_denom
+                .or_else(|| saved_preferences().display_denom)
+            {
+                reporter = reporter.with_quote_denom(quote_denom);
+            }
+            Some(reporter)
+        }
+        _ => None,
+    };
+
+    let watcher = alerts.farm_alerts.then(|| {
+        FarmWatcher::new(client.clone(), alerts.alert_address)
+            .with_closing_epochs(alerts.alert_closing_epochs)
+            .with_interval(Duration::from_secs(alerts.alert_interval_secs.max(1)))
+    });
+    let activity_watcher = (!watched.is_empty()).then(|| {
+        ActivityWatcher::new(client.clone(), watched)
+            .with_expected_recipients(activity.expected_recipients)
+            .with_balance_drop(Decimal::percent(activity.balance_drop_percent))
+            .with_interval(Duration::from_secs(activity.activity_interval_secs.max(1)))
+    });
+
+    let mut bridge =
+        EventBridge::new(client, sinks).with_poll_interval(Duration::from_millis(poll_interval_ms));
+    if !contracts.is_empty() {
+        bridge = bridge.with_contracts(contracts);
+    }
+
+    eprintln!(
+        "Forwarding events from {} contract(s) on {}",
+        bridge.contracts().len(),
+        network
+    );
+    let reporting = async {
+        match reporter {
+            Some(mut reporter) => {
+                eprintln!("Sending portfolio summaries {}", reporter.schedule());
+                let address = reporter.address().to_string();
+                reporter
+                    .run(&bridge, |sent| {
+                        if let Err(e) = sent {
+                            eprintln!("Failed to send portfolio summary for {}: {}", address, e);
+                        }
+                        Ok(())
+                    })
+                    .await
+            }
+            None => std::future::pending().await,
+        }
+    };
+    let alerting = async {
+        match watcher {
+            Some(mut watcher) => {
+                eprintln!("Sending farm alerts");
+                watcher
+                    .run(&bridge, |e| {
+                        eprintln!("Sending farm alerts failed: {}", e);
+                        Ok(())
+                    })
+                    .await
+            }
+            None => std::future::pending().await,
+        }
+    };
+    let watching = async {
+        match activity_watcher {
+            Some(mut watcher) => {
+                eprintln!(
+                    "Watching activity of {} address(es)",
+                    watcher.addresses().len()
+                );
+                watcher
+                    .run(&bridge, |e| {
+                        eprintln!("Watching wallet activity failed: {}", e);
+                        Ok(())
+                    })
+                    .await
+            }
+            None => std::future::pending().await,
+        }
+    };
+    tokio::select! {
+        result = bridge.run(from_height, |e| {
+            eprintln!("Forwarding events failed: {}", e);
+            Ok(())
+        }) => result,
+        result = reporting => result,
+        result = alerting => result,
+        result = watching => result,
+    }
+}
+
+/// Coins as a comma separated list
+fn coin_list(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Table of the legs and skipped pools of a bulk plan
+fn bulk_plan_review(plan: &BulkPlan, batch_size: usize) -> String {
+    let mut review = format!(
+        "Plan: {} {}% of {} positions in {} transactions\n",
+        plan.action,
+        plan.share * Decimal::percent(10_000),
+        plan.legs.len(),
+        plan.transactions(batch_size)
+    );
+    if !plan.legs.is_empty() {
+        review.push_str("POOL\tLP BALANCE\tLP AMOUNT\tASSETS\n");
+        for leg in &plan.legs {
+            review.push_str(&format!(
+                "{}\t{}\t{}\t{}\n",
+                leg.pool_id,
+                leg.lp_balance,
+                leg.lp_amount,
+                coin_list(&leg.assets)
+            ));
+        }
+        review.push_str(&format!("Total: {}\n", coin_list(&plan.total_assets())));
+    }
+    for skipped in &plan.skipped {
+        review.push_str(&format!(
+            "Skipped {}: {}\n",
+            skipped.pool_id, skipped.reason
+        ));
+    }
+    review
+}
+
+/// The outcome of each pool of an executed bulk plan
+#[derive(Serialize)]
+#[serde(transparent)]
+struct BulkResult {
+    report: BulkReport,
+    #[serde(skip)]
+    dry_run: bool,
+}
+
+impl fmt::Display for BulkResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "POOL\tSTATUS\tTX\tDETAIL")?;
+        for result in &self.report.results {
+            match &result.outcome {
+                BulkOutcome::Executed { .. } if self.dry_run => writeln!(
+                    f,
+                    "{}\tsimulated\t-\t{}",
+                    result.leg.pool_id,
+                    coin_list(&result.leg.assets)
+                )?,
+                BulkOutcome::Executed { txhash, height } => writeln!(
+                    f,
+                    "{}\texecuted\t{}\tblock {}, {}",
+                    result.leg.pool_id,
+                    txhash,
+                    height,
+                    coin_list(&result.leg.assets)
+                )?,
+                BulkOutcome::Failed { error } => {
+                    writeln!(f, "{}\tfailed\t-\t{}", result.leg.pool_id, error)?
+                }
+            }
+        }
+        write!(f, "{}", self.report)?;
+        if self.dry_run {
+            write!(f, "\nDry run: nothing was signed or broadcast")?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_bulk(
+    action: BulkAction,
+    share: Decimal,
+    pools: &[String],
+    slippage: Option<Decimal>,
+    batch_size: usize,
+    yes: bool,
+    wallet_name: &str,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+    format: OutputFormat,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+
+    let mut plan = client.plan_bulk_liquidity(action, share, pools).await?;
+    if let Some(slippage) = slippage {
+        plan = plan.with_max_slippage(slippage);
+    }
+
+    eprint!("{}", bulk_plan_review(&plan, batch_size));
+    if plan.legs.is_empty() {
+        return Ok(Box::new(Message::new(format!("Nothing to {}.", action))));
+    }
+    if !yes && !tx_args.dry_run && !confirm("Execute the plan?", false)? {
+        return Ok(Box::new(Message::cancelled()));
+    }
+
+    let report = client.execute_bulk_plan(&plan, batch_size).await?;
+    let failed = report.failed();
+    let total = report.results.len();
+    let result = BulkResult {
+        report,
+        dry_run: tx_args.dry_run,
+    };
+    if failed > 0 {
+        // The pools that went through are still reported before failing
+        print_output(&result, format)?;
+        return Err(Error::Tx(format!("{} of {} pools failed", failed, total)));
+    }
+    Ok(Box::new(result))
+}
+
+fn pool_type_label(pool_type: &PoolType) -> String {
+    match pool_type {
+        PoolType::ConstantProduct => "constant product".to_string(),
+        PoolType::StableSwap { amp } => format!("stable swap (amp {})", amp),
+    }
+}
+
+/// A pool template with its type and fees
+#[derive(Serialize)]
+struct TemplateInfo {
+    name: &'static str,
+    description: &'static str,
+    spec: PoolSpec,
+}
+
+/// Every pool template
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Templates(Vec<TemplateInfo>);
+
+impl fmt::Display for Templates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "NAME\tPOOL TYPE\tSWAP FEE\tPROTOCOL FEE\tBURN FEE\tTOTAL FEE\tDESCRIPTION"
+        )?;
+        for template in &self.0 {
+            let spec = &template.spec;
+            writeln!(
+                f,
+                "{}\t{}\t{:.3}%\t{:.3}%\t{:.3}%\t{:.3}%\t{}",
+                template.name,
+                pool_type_label(&spec.pool_type),
+                percent(spec.swap_fee),
+                percent(spec.protocol_fee),
+                percent(spec.burn_fee),
+                percent(spec.total_fee()),
+                template.description
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run_pool_templates() -> Result<Output, Error> {
+    let templates = PoolTemplate::ALL
+        .iter()
+        .map(|template| TemplateInfo {
+            name: template.name(),
+            description: template.description(),
+            spec: template.spec(),
+        })
+        .collect();
+    Ok(Box::new(Templates(templates)))
+}
+
+/// Pools side by side
+#[derive(Serialize)]
+#[serde(transparent)]
+struct PoolComparisons {
+    /// Denom values are expressed in
+    #[serde(skip)]
+    quote: String,
+    pools: Vec<PoolComparison>,
+}
+
+impl fmt::Display for PoolComparisons {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quote = &self.quote;
+        let amount = |amount: Option<Uint128>| {
+            amount.map_or_else(|| "-".to_string(), |amount| amount.to_string())
+        };
+        writeln!(
+            f,
+            "POOL\tASSETS\tSWAP FEE\tTOTAL FEE\tTVL ({quote})\tVOLUME 24H ({quote})\t\
+             FEE APR\tINCENTIVE APR\tTOTAL APR\tPRICE\tPOSITION"
+        )?;
+        for comparison in &self.pools {
+            let price = match (comparison.price, comparison.assets.as_slice()) {
+                (Some(price), [base, counter, ..]) => {
+                    format!("1{} = {}{}", base.denom, price, counter.denom)
+                }
+                _ => "-".to_string(),
+            };
+            let position = comparison.position.as_ref().map_or_else(
+                || "-".to_string(),
+                |position| {
+                    format!(
+                        "{} LP ({:.2}%, {})",
+                        position.lp_amount,
+                        percent(position.share),
+                        amount(position.value)
+                    )
+                },
+            );
+            writeln!(
+                f,
+                "{}\t{}\t{:.2}%\t{:.2}%\t{}\t{}\t{:.2}%\t{:.2}%\t{:.2}%\t{}\t{}",
+                comparison.pool_id,
+                coin_list(&comparison.assets),
+                percent(comparison.swap_fee),
+                percent(comparison.total_fee),
+                amount(comparison.tvl),
+                amount(comparison.volume_24h),
+                percent(comparison.fee_apr),
+                percent(comparison.incentive_apr),
+                percent(comparison.total_apr()),
+                price,
+                position
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_pool_compare(
+    pool_ids: &[String],
+    address: Option<String>,
+    quote: Option<String>,
+    lookback_blocks: u64,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let quote = quote
+        .or_else(|| saved_preferences().display_denom)
+        .unwrap_or_else(|| USD.to_string());
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let pools = client
+        .compare_pools(pool_ids, &quote, address.as_deref(), lookback_blocks)
+        .await?;
+    Ok(Box::new(PoolComparisons { quote, pools }))
+}
+
+/// The assets a withdrawal refunds and its fee, before withdrawing
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Withdrawal(WithdrawalPreview);
+
+impl fmt::Display for Withdrawal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", withdrawal_review(&self.0))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_liquidity_withdraw(
+    pool_id: &str,
+    lp_amount: Uint128,
+    preview_only: bool,
+    yes: bool,
+    wallet_name: &str,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+
+    let preview = client
+        .preview_withdraw_liquidity(pool_id, lp_amount)
+        .await?;
+    if preview_only {
+        return Ok(Box::new(Withdrawal(preview)));
+    }
+    eprint!("{}", withdrawal_review(&preview));
+    if !yes && !tx_args.dry_run && !confirm("Withdraw?", false)? {
+        return Ok(Box::new(Message::cancelled()));
+    }
+
+    let tx = client.withdraw_liquidity(pool_id, lp_amount).await?;
+    if let Some(report) = dry_run_report(&client, &tx)? {
+        return Ok(Box::new(report));
+    }
+    Ok(Box::new(Included::new("Withdrawal included", &tx)))
+}
+
+/// The refund and fee of a withdrawal, one line each
+fn withdrawal_review(preview: &WithdrawalPreview) -> String {
+    let mut review = format!(
+        "Withdraw {}{} ({:.4}% of pool {}):\n",
+        preview.lp_amount.amount,
+        preview.lp_amount.denom,
+        percent(preview.share),
+        preview.pool_id
+    );
+    for asset in &preview.assets {
+        review.push_str(&format!("  receive\t{}{}\n", asset.amount, asset.denom));
+    }
+    review.push_str("  withdrawal fee\tnone\n");
+    if let Some(gas) = &preview.gas {
+        review.push_str(&format!(
+            "  transaction fee\t{}{} ({} gas limit)\n",
+            gas.fee.amount, gas.fee.denom, gas.gas_limit
+        ));
+        let fee_denom_refunded = preview
+            .assets
+            .iter()
+            .any(|asset| asset.denom == gas.fee.denom);
+        if fee_denom_refunded {
+            review.push_str(&format!(
+                "  net {}\t{}\n",
+                gas.fee.denom,
+                preview.net_amount(&gas.fee.denom)
+            ));
+        }
+    }
+    review
+}
+
+/// An address's LP positions with their value, cost basis and PnL
+#[derive(Serialize)]
+#[serde(transparent)]
+struct LpPositions {
+    #[serde(skip)]
+    address: String,
+    /// Denom values are expressed in
+    #[serde(skip)]
+    quote: String,
+    positions: Vec<LpPosition>,
+}
+
+impl fmt::Display for LpPositions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.positions.is_empty() {
+            return write!(f, "No LP positions found for {}.", self.address);
+        }
+
+        let quote = &self.quote;
+        let amount = |amount: Option<Uint128>| {
+            amount.map_or_else(|| "-".to_string(), |amount| amount.to_string())
+        };
+        writeln!(
+            f,
+            "POOL\tLP TOKENS\tLOCKED\tSHARE\tASSETS\tDEPOSITED\tVALUE ({quote})\t\
+             DEPOSIT VALUE ({quote})\tPNL\tIMPERMANENT LOSS\tFEES EARNED ({quote})"
+        )?;
+        for position in &self.positions {
+            let pnl = match (position.pnl(), position.pnl_ratio()) {
+                (Some(pnl), Some(ratio)) => format!("{} ({:+.2}%)", pnl, ratio * 100.0),
+                (Some(pnl), None) => pnl.to_string(),
+                _ => "-".to_string(),
+            };
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{:.4}%\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                position.pool_id,
+                position.lp_amount,
+                position.locked_amount,
+                percent(position.share),
+                coin_list(&position.assets),
+                position
+                    .deposited
+                    .as_deref()
+                    .map_or_else(|| "unknown".to_string(), coin_list),
+                amount(position.value),
+                amount(position.deposit_value),
+                pnl,
+                position
+                    .impermanent_loss
+                    .map_or_else(|| "-".to_string(), |loss| format!("{:.2}%", percent(loss))),
+                amount(position.fees_earned)
+            )?;
+        }
+
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        let locks: Vec<_> = self
+            .positions
+            .iter()
+            .flat_map(|position| position.locks.iter().map(move |lock| (position, lock)))
+            .collect();
+        if !locks.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Locked in farm positions:")?;
+            for (position, lock) in locks {
+                writeln!(
+                    f,
+                    "  {}: {} LP tokens in position {}, {}",
+                    position.pool_id,
+                    lock.amount,
+                    lock.position_id,
+                    lock.unlock_status(now)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn run_liquidity_positions(
+    address: &str,
+    quote: Option<String>,
+    offline: bool,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let quote = quote
+        .or_else(|| saved_preferences().display_denom)
+        .unwrap_or_else(|| USD.to_string());
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let history = TxHistory::open_in(storage::shared()?)?;
+    if !offline {
+        // Positions are still shown, without a cost basis for what couldn't be synced
+        if let Err(e) = client.sync_history(&history, address).await {
+            eprintln!("Warning: failed to sync the transaction history: {}", e);
+        }
+    }
+    let positions = client
+        .get_lp_positions(address, Some(&history), &quote)
+        .await?;
+    Ok(Box::new(LpPositions {
+        address: address.to_string(),
+        quote,
+        positions,
+    }))
+}
+
+/// Why a transaction failed and how to fix it, if it failed
+#[derive(Serialize)]
+#[serde(transparent)]
+struct TxDiagnosis {
+    #[serde(skip)]
+    hash: String,
+    /// Whether the text includes the raw log
+    #[serde(skip)]
+    raw_log: bool,
+    diagnosis: Option<Diagnosis>,
+}
+
+impl fmt::Display for TxDiagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(diagnosis) = &self.diagnosis else {
+            return write!(
+                f,
+                "Transaction {} succeeded, there is nothing to diagnose.",
+                self.hash
+            );
+        };
+
+        writeln!(f, "Transaction {} failed: {}", self.hash, diagnosis.cause)?;
+        if let (Some(codespace), Some(code)) = (&diagnosis.codespace, diagnosis.code) {
+            writeln!(f, "Error code: {} {}", codespace, code)?;
+        }
+        if let (Some(wanted), Some(used)) = (diagnosis.gas_wanted, diagnosis.gas_used) {
+            writeln!(f, "Gas: {} used of {}", used, wanted)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{}", diagnosis.explanation())?;
+        writeln!(f)?;
+        writeln!(f, "Suggested fixes:")?;
+        for (i, suggestion) in diagnosis.suggestions().iter().enumerate() {
+            writeln!(f, "  {}. {}", i + 1, suggestion)?;
+        }
+        if let Some(command) = diagnosis.retry_command() {
+            writeln!(f)?;
+            writeln!(f, "Next: {}", command)?;
+        }
+        if self.raw_log && !diagnosis.log.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Raw log:")?;
+            writeln!(f, "{}", diagnosis.log)?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_tx_diagnose(
+    hash: &str,
+    raw_log: bool,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    Ok(Box::new(TxDiagnosis {
+        hash: hash.to_string(),
+        raw_log,
+        diagnosis: client.diagnose_transaction(hash).await?,
+    }))
+}
+
+/// A contract execution listed in the messages file of `tx prepare`
+#[derive(serde::Deserialize)]
+struct ContractExecution {
+    contract: String,
+    msg: serde_json::Value,
+    #[serde(default)]
+    funds: Vec<Coin>,
+}
+
+async fn run_tx_send(
+    batch_path: &Path,
+    estimate_gas: bool,
+    yes: bool,
+    wallet_name: &str,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let ops = batch::parse_batch(&std::fs::read_to_string(batch_path)?)?;
+
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+
+    let batch = client.batch().with_ops(ops);
+    eprintln!("Batch of {} operations in one transaction:", batch.len());
+    for (i, op) in batch.ops().iter().enumerate() {
+        eprintln!("  {}. {}", i + 1, op);
+    }
+    let estimate = Estimate(batch.estimate().await?);
+    if estimate_gas {
+        return Ok(Box::new(estimate));
+    }
+    eprintln!("{}", estimate);
+    if !yes && !tx_args.dry_run && !confirm("Send the batch?", false)? {
+        return Ok(Box::new(Message::cancelled()));
+    }
+
+    let tx = batch.broadcast().await?;
+    if let Some(report) = dry_run_report(&client, &tx)? {
+        return Ok(Box::new(report));
+    }
+    Ok(Box::new(Included::new("Batch included", &tx)))
+}
+
+/// An unsigned multisig transaction, written for the members to sign
+#[derive(Serialize)]
+struct PreparedTx {
+    /// Address of the multisig account sending it
+    address: String,
+    path: PathBuf,
+    gas_limit: u64,
+    fee: Vec<Coin>,
+    /// Signatures needed out of the members'
+    threshold: u32,
+    members: usize,
+}
+
+impl fmt::Display for PreparedTx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "✓ Wrote the transaction from {} to {} (gas limit {}, fee {})",
+            self.address,
+            self.path.display(),
+            self.gas_limit,
+            coin_list(&self.fee)
+        )?;
+        write!(
+            f,
+            "  {} of {} members must sign it with `tx sign-partial`.",
+            self.threshold, self.members
+        )
+    }
+}
+
+async fn run_tx_prepare(
+    multisig: &str,
+    msgs_path: &Path,
+    out_file: &Path,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let account = storage.multisig_account(multisig)?;
+    let executions: Vec<ContractExecution> =
+        serde_json::from_str(&std::fs::read_to_string(msgs_path)?)?;
+    let msgs = executions
+        .iter()
+        .map(|execution| {
+            account.execute_contract_msg(&execution.contract, &execution.msg, &execution.funds)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet_defaults(wallet_defaults(&storage, multisig)?)
+        .build()
+        .await?;
+    let tx = client.prepare_multisig_tx(&account, msgs).await?;
+    std::fs::write(out_file, serde_json::to_string_pretty(&tx)?)?;
+
+    Ok(Box::new(PreparedTx {
+        address: account.address()?.to_string(),
+        path: out_file.to_path_buf(),
+        gas_limit: tx.gas_limit,
+        fee: tx.fee,
+        threshold: account.threshold(),
+        members: account.public_keys().len(),
+    }))
+}
+
+fn read_multisig_tx(path: &Path) -> Result<MultisigTx, Error> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+/// The sign doc of a multisig transaction, pretty-printed for review
+fn multisig_review(tx: &MultisigTx) -> Result<String, Error> {
+    let sign_doc: serde_json::Value = serde_json::from_slice(&tx.sign_bytes()?)?;
+    Ok(format!(
+        "Transaction from {} ({} of {} signatures needed):\n{}\n",
+        tx.account.address()?,
+        tx.account.threshold(),
+        tx.account.public_keys().len(),
+        serde_json::to_string_pretty(&sign_doc)?
+    ))
+}
+
+/// A member's signature of a multisig transaction, as JSON in text too
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Signature(PartialSignature);
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string_pretty(&self.0).map_err(|_| fmt::Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+/// A signature written to a file
+#[derive(Serialize)]
+struct SignatureFile {
+    path: PathBuf,
+}
+
+impl fmt::Display for SignatureFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "✓ Wrote the signature to {}", self.path.display())
+    }
+}
+
+fn run_tx_sign_partial(
+    tx_file: &Path,
+    wallet_name: &str,
+    out_file: Option<&Path>,
+    yes: bool,
+) -> Result<Output, Error> {
+    let tx = read_multisig_tx(tx_file)?;
+    eprint!("{}", multisig_review(&tx)?);
+    if !yes && !confirm("Sign?", false)? {
+        return Ok(Box::new(Message::cancelled()));
+    }
+
+    let wallet = unlock_wallet(&WalletStorage::new()?, wallet_name)?;
+    let signature = tx.sign_partial(&wallet)?;
+    let Some(path) = out_file else {
+        return Ok(Box::new(Signature(signature)));
+    };
+    std::fs::write(path, serde_json::to_string_pretty(&signature)?)?;
+    Ok(Box::new(SignatureFile {
+        path: path.to_path_buf(),
+    }))
+}
+
+async fn run_tx_combine(
+    tx_file: &Path,
+    signature_files: &[PathBuf],
+    yes: bool,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let tx = read_multisig_tx(tx_file)?;
+    let signatures = signature_files
+        .iter()
+        .map(|path| Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?))
+        .collect::<Result<Vec<PartialSignature>, Error>>()?;
+    let signed = tx.combine(&signatures)?;
+
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    if client.config().chain_id != tx.chain_id {
+        return Err(Error::Config(format!(
+            "The transaction is for chain {}, but network '{}' is chain {}",
+            tx.chain_id,
+            network,
+            client.config().chain_id
+        )));
+    }
+
+    eprint!("{}", multisig_review(&tx)?);
+    eprintln!("Combined {} signatures.", signatures.len());
+    if !yes && !confirm("Broadcast?", false)? {
+        return Ok(Box::new(Message::cancelled()));
+    }
+
+    let response = client.broadcast_signed_tx(signed).await?;
+    Ok(Box::new(Included::new("Transaction included", &response)))
+}
+
+/// An address's farm positions
+#[derive(Serialize)]
+#[serde(transparent)]
+struct FarmPositions {
+    #[serde(skip)]
+    address: String,
+    positions: Vec<FarmPosition>,
+}
+
+impl fmt::Display for FarmPositions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.positions.is_empty() {
+            return write!(f, "{} has no farm positions", self.address);
+        }
+
+        let epoch = |epoch: Option<u64>| epoch.map_or_else(|| "-".to_string(), |e| e.to_string());
+        let now = chrono::Utc::now();
+        writeln!(
+            f,
+            "POSITION\tLP TOKENS\tSTATUS\tSTART EPOCH\tUNLOCK EPOCH\tUNLOCKS AT\tLP DENOM"
+        )?;
+        for position in &self.positions {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                position.identifier,
+                position.lp_asset.amount,
+                position.status(now),
+                epoch(position.start_epoch),
+                epoch(position.unlock_epoch),
+                position.unlocks_at.map_or_else(
+                    || "-".to_string(),
+                    |time| time.format("%Y-%m-%d %H:%M").to_string()
+                ),
+                position.lp_asset.denom,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_farm_positions(
+    address: &str,
+    offline: bool,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let history = TxHistory::open_in(storage::shared()?)?;
+    if !offline {
+        // Positions are still listed, without start epochs for what couldn't be synced
+        if let Err(e) = client.sync_history(&history, address).await {
+            eprintln!("Warning: failed to sync the transaction history: {}", e);
+        }
+    }
+    let positions = client.get_farm_positions(address, Some(&history)).await?;
+    Ok(Box::new(FarmPositions {
+        address: address.to_string(),
+        positions,
+    }))
+}
+
+/// One farm position as the farm manager reports it, as JSON in text too
+#[derive(Serialize)]
+#[serde(transparent)]
+struct FarmPositionInfo(Position);
+
+impl fmt::Display for FarmPositionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string_pretty(&self.0).map_err(|_| fmt::Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+async fn run_farm_position(
+    identifier: &str,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    Ok(Box::new(FarmPositionInfo(
+        client.get_position(identifier).await?,
+    )))
+}
+
+async fn run_position_tx(
+    identifier: &str,
+    tx: PositionTx,
+    wallet_name: &str,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+    let (response, done) = match tx {
+        PositionTx::Close(amount) => (
+            client.close_position(identifier, amount).await?,
+            match amount {
+                Some(amount) => format!("Closed {} LP tokens of position {}", amount, identifier),
+                None => format!("Closed position {}", identifier),
+            },
+        ),
+        PositionTx::Expand(amount) => (
+            client.expand_position(identifier, amount).await?,
+            format!("Added {} LP tokens to position {}", amount, identifier),
+        ),
+        PositionTx::Withdraw { emergency_unlock } => (
+            client
+                .withdraw_position(identifier, emergency_unlock)
+                .await?,
+            format!("Withdrew position {}", identifier),
+        ),
+    };
+    if let Some(report) = dry_run_report(&client, &response)? {
+        return Ok(Box::new(report));
+    }
+    Ok(Box::new(Included::new(done, &response)))
+}
+
+/// The transactions of a chunked reward claim and the total paid out
+#[derive(Serialize)]
+#[serde(transparent)]
+struct ClaimResult {
+    report: ClaimReport,
+    #[serde(skip)]
+    dry_run: bool,
+}
+
+impl fmt::Display for ClaimResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.report.chunks.is_empty() {
+            writeln!(f, "UNTIL EPOCH\tGAS\tTX\tREWARDS")?;
+        }
+        for chunk in &self.report.chunks {
+            let tx = if self.dry_run {
+                "simulated"
+            } else {
+                chunk.txhash.as_str()
+            };
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                chunk.until_epoch,
+                chunk.gas,
+                tx,
+                coin_list(&chunk.rewards)
+            )?;
+        }
+        write!(f, "{}", self.report)?;
+        if self.dry_run {
+            write!(
+                f,
+                "\nDry run: only the first transaction was simulated, the next ones are \
+                 planned once it is executed"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_farm_claim(
+    max_gas: u64,
+    wallet_name: &str,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+    format: OutputFormat,
+) -> Result<Output, Error> {
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+
+    let report = client
+        .claim_rewards_chunked(max_gas, |chunk| {
+            if !tx_args.dry_run {
+                eprintln!(
+                    "Claimed {} up to epoch {}: {}",
+                    coin_list(&chunk.rewards),
+                    chunk.until_epoch,
+                    chunk.txhash
+                );
+            }
+        })
+        .await?;
+    let error = report.error.clone();
+    let result = ClaimResult {
+        report,
+        dry_run: tx_args.dry_run,
+    };
+    if let Some(error) = error {
+        // The chunks that went through are still reported before failing
+        print_output(&result, format)?;
+        return Err(Error::Tx(error));
+    }
+    Ok(Box::new(result))
+}
+
+/// A pool with its creator, creation block and LP concentration
+#[derive(Serialize)]
+struct PoolDetails {
+    pool: PoolInfoResponse,
+    ownership: PoolOwnership,
+}
+
+impl fmt::Display for PoolDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = &self.pool.pool_info;
+        let ownership = &self.ownership;
+        let unknown = || "unknown".to_string();
+        writeln!(f, "Pool: {}", info.pool_identifier)?;
+        writeln!(f, "Type: {}", pool_type_label(&info.pool_type))?;
+        writeln!(f, "Assets: {}", coin_list(&info.assets))?;
+        writeln!(f, "LP denom: {}", info.lp_denom)?;
+        writeln!(f, "LP supply: {}", self.pool.total_share.amount)?;
+        writeln!(
+            f,
+            "Creator: {}",
+            ownership.creator.clone().unwrap_or_else(unknown)
+        )?;
+        writeln!(
+            f,
+            "Created: {}",
+            match (ownership.created_height, ownership.created_at) {
+                (Some(height), Some(at)) =>
+                    format!("block {} ({})", height, at.format("%Y-%m-%d %H:%M UTC")),
+                (Some(height), None) => format!("block {}", height),
+                _ => unknown(),
+            }
+        )?;
+        writeln!(
+            f,
+            "Creation tx: {}",
+            ownership.creation_tx.clone().unwrap_or_else(unknown)
+        )?;
+
+        let Some(concentration) = &ownership.lp_concentration else {
+            return write!(f, "LP holders: unknown");
+        };
+        writeln!(f, "LP holders: {}", concentration.holders)?;
+        if !concentration.top_holders.is_empty() {
+            writeln!(
+                f,
+                "Top {} share: {:.2}%",
+                concentration.top_holders.len(),
+                percent(concentration.top_share())
+            )?;
+            writeln!(
+                f,
+                "Gini: {:.3}",
+                concentration.gini.to_string().parse::<f64>().unwrap_or(0.0)
+            )?;
+            for flag in concentration_flags(
+                &info.pool_identifier,
+                concentration,
+                &RiskThresholds::default(),
+            ) {
+                writeln!(f, "Warning: {}", flag)?;
+            }
+            writeln!(f)?;
+            writeln!(f, "HOLDER\tLP AMOUNT\tSHARE")?;
+            for holder in &concentration.top_holders {
+                writeln!(
+                    f,
+                    "{}\t{}\t{:.2}%",
+                    holder.address,
+                    holder.amount,
+                    percent(holder.share)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn run_pool_info(
+    pool_id: &str,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    let pool = client.get_pool(pool_id).await?;
+    let ownership = client.get_pool_ownership(&pool).await;
+    Ok(Box::new(PoolDetails { pool, ownership }))
+}
+
+/// An amount expressed in another denom, with the pools priced through
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Converted(Conversion);
+
+impl fmt::Display for Converted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.0.from, self.0.to)?;
+        for hop in &self.0.hops {
+            write!(
+                f,
+                "\n  via pool {}: 1{} = {}{}",
+                hop.pool_id, hop.from_denom, hop.price, hop.to_denom
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_convert(
+    amount: Uint128,
+    from: &str,
+    to: Option<String>,
+    usd_denoms: Vec<String>,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let to = to
+        .or_else(|| saved_preferences().display_denom)
+        .ok_or_else(|| {
+            Error::Config("No --to denom given and no display_denom preference set".to_string())
+        })?;
+    let usd_denoms = if usd_denoms.is_empty() {
+        saved_pricing().usd_denoms
+    } else {
+        usd_denoms
+    };
+    let client = MantraDexClient::connect(network, rpc_url)?
+        .with_usd_denoms(usd_denoms)
+        .build()
+        .await?;
+    Ok(Box::new(Converted(
+        client.convert_amount(amount, from, &to).await?,
+    )))
+}
+
+/// An address's holdings valued in one denom
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Portfolio(PortfolioValuation);
+
+impl fmt::Display for Portfolio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let portfolio = &self.0;
+        let quote = &portfolio.quote;
+        let amount = |amount: Option<Uint128>| {
+            amount.map_or_else(|| "-".to_string(), |amount| amount.to_string())
+        };
+        writeln!(f, "Portfolio of {} in {}", portfolio.address, quote)?;
+        writeln!(f, "  wallet balances\t{}", portfolio.balances_value())?;
+        writeln!(f, "  LP positions\t{}", portfolio.positions_value())?;
+        writeln!(f, "  pending rewards\t{}", portfolio.rewards_value())?;
+        writeln!(f, "  total\t{}", portfolio.total_value())?;
+
+        if !portfolio.balances.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "BALANCE\tVALUE ({quote})")?;
+            for holding in &portfolio.balances {
+                writeln!(f, "{}\t{}", holding.coin, amount(holding.value))?;
+            }
+        }
+        if !portfolio.positions.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "POOL\tLP TOKENS\tLOCKED\tSHARE\tASSETS\tVALUE ({quote})")?;
+            for position in &portfolio.positions {
+                writeln!(
+                    f,
+                    "{}\t{}\t{}\t{:.4}%\t{}\t{}",
+                    position.pool_id,
+                    position.lp_amount,
+                    position.locked_amount,
+                    percent(position.share),
+                    coin_list(&position.assets),
+                    amount(position.value)
+                )?;
+            }
+        }
+        if !portfolio.pending_rewards.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "PENDING REWARD\tVALUE ({quote})")?;
+            for holding in &portfolio.pending_rewards {
+                writeln!(f, "{}\t{}", holding.coin, amount(holding.value))?;
+            }
+        }
+
+        let unpriced = portfolio.unpriced();
+        if !unpriced.is_empty() {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "No pools price {} in {}; left out of the totals.",
+                unpriced.join(", "),
+                quote
+            )?;
+        }
+        Ok(())
+    }
+}
+
+async fn run_portfolio(
+    address: &str,
+    quote: Option<String>,
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let quote = quote
+        .or_else(|| saved_preferences().display_denom)
+        .unwrap_or_else(|| USD.to_string());
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    Ok(Box::new(Portfolio(
+        client.get_portfolio_summary(address, &quote).await?,
+    )))
+}
+
+/// Metadata of denoms, as resolved from the chain
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Tokens(Vec<TokenMetadata>);
+
+impl fmt::Display for Tokens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.0 {
+            writeln!(f, "{}", token.denom)?;
+            writeln!(f, "  symbol:   {}", token.symbol)?;
+            writeln!(f, "  decimals: {}", token.decimals)?;
+            if let Some(name) = &token.name {
+                writeln!(f, "  name:     {}", name)?;
+            }
+            writeln!(f, "  source:   {:?}", token.source)?;
+            if let Some(trace) = &token.ibc_trace {
+                writeln!(f, "  origin:   {} via {}", trace.base_denom, trace.path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn run_token(
+    denoms: &[String],
+    network: &str,
+    rpc_url: Option<String>,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+    Ok(Box::new(Tokens(client.resolve_tokens(denoms).await?)))
+}
+
+/// The preferences shared with the TUI
+#[derive(Serialize)]
+#[serde(transparent)]
+struct PreferencesOutput {
+    preferences: Preferences,
+    /// File they are saved in, shown by `prefs show`
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl fmt::Display for PreferencesOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.preferences)?;
+        if let Some(path) = &self.path {
+            write!(f, "\n\n# {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// The favorite pools, listed first in the TUI
+#[derive(Serialize)]
+#[serde(transparent)]
+struct Favorites(Vec<String>);
+
+impl fmt::Display for Favorites {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "No favorite pools")
+        } else {
+            write!(f, "Favorite pools: {}", self.0.join(", "))
+        }
+    }
+}
+
+fn run_prefs(command: PrefsCommand) -> Result<Output, Error> {
+    let mut store = PreferencesStore::open_default()?;
+    let mut path = None;
+    match command {
+        PrefsCommand::Show => path = store.path(),
+        PrefsCommand::Set { key, value } => {
+            let mut result = Ok(());
+            store.update(|preferences| result = preferences.set(&key, &value))?;
+            result?;
+        }
+        PrefsCommand::Unset { key } => {
+            let mut result = Ok(());
+            store.update(|preferences| result = preferences.unset(&key))?;
+            result?;
+        }
+        PrefsCommand::Favorite { pool_id, remove } => {
+            store.update(|preferences| {
+                if preferences.is_favorite(&pool_id) == remove {
+                    preferences.toggle_favorite(&pool_id);
+                }
+            })?;
+            return Ok(Box::new(Favorites(store.get().favorite_pools.clone())));
+        }
+    }
+    Ok(Box::new(PreferencesOutput {
+        preferences: store.get().clone(),
+        path,
+    }))
+}
+
+/// A profile archive written by `profile export`
+#[derive(Serialize)]
+struct ProfileExported {
+    path: PathBuf,
+    configuration: bool,
+    preferences: bool,
+    /// Number of saved wallets included
+    wallets: usize,
+}
+
+impl fmt::Display for ProfileExported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.configuration {
+            parts.push("configuration".to_string());
+        }
+        if self.preferences {
+            parts.push("preferences".to_string());
+        }
+        if self.wallets > 0 {
+            parts.push(format!("{} wallet(s)", self.wallets));
+        }
+        if parts.is_empty() {
+            write!(
+                f,
+                "Nothing saved on this machine yet; wrote an empty profile"
+            )
+        } else {
+            write!(
+                f,
+                "Exported {} to {}",
+                parts.join(", "),
+                self.path.display()
+            )
+        }
+    }
+}
+
+fn run_profile_export(path: &Path, include_wallets: bool, force: bool) -> Result<Output, Error> {
+    if path.exists() && !force {
+        return Err(Error::Config(format!(
+            "{} already exists; pass --force to replace it",
+            path.display()
+        )));
+    }
+    let paths = ProfilePaths::defaults()?;
+    let profile = Profile::collect(&paths, include_wallets)?;
+
+    let password = prompt_secret("Password for the archive: ")?;
+    WalletStorage::new()?.validate_password(password.expose_secret())?;
+    if prompt_secret("Confirm password: ")? != password {
+        return Err(Error::Wallet("Passwords do not match".to_string()));
+    }
+    profile.export(path, password.expose_secret())?;
+
+    Ok(Box::new(ProfileExported {
+        path: path.to_path_buf(),
+        configuration: profile.config.is_some(),
+        preferences: profile.preferences.is_some(),
+        wallets: profile.wallets.len(),
+    }))
+}
+
+/// What `profile import` restored, and what it left alone
+#[derive(Serialize)]
+struct ProfileImported {
+    /// When the profile was exported, RFC 3339
+    created_at: String,
+    #[serde(flatten)]
+    summary: RestoreSummary,
+}
+
+impl fmt::Display for ProfileImported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Profile exported {}", self.created_at)?;
+        for part in &self.summary.restored {
+            write!(f, "\n  restored {}", part)?;
+        }
+        for part in &self.summary.skipped {
+            write!(f, "\n  skipped {} (already on this machine)", part)?;
+        }
+        if !self.summary.skipped.is_empty() {
+            write!(f, "\nPass --overwrite to replace what is already here.")?;
+        }
+        Ok(())
+    }
+}
+
+fn run_profile_import(path: &Path, overwrite: bool) -> Result<Output, Error> {
+    let password = prompt_secret("Password for the archive: ")?;
+    let profile = Profile::import(path, password.expose_secret())?;
+    let summary = profile.restore(&ProfilePaths::defaults()?, overwrite)?;
+    Ok(Box::new(ProfileImported {
+        created_at: profile.created_at,
+        summary,
+    }))
+}
+
+/// A pool that wasn't created because an identical one exists
+#[derive(Serialize)]
+struct ExistingPool {
+    pool_id: String,
+}
+
+impl fmt::Display for ExistingPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Pool {} already has these assets, type and fees; nothing was created",
+            self.pool_id
+        )
+    }
+}
+
+async fn run_pool_create(args: PoolCreateArgs, tx_args: TxArgs) -> Result<Output, Error> {
+    let spec = args.spec();
+    let PoolCreateArgs {
+        assets,
+        decimals,
+        identifier,
+        allow_duplicate,
+        estimate_gas,
+        wallet: wallet_name,
+        network,
+        rpc_url,
+        ..
+    } = args;
+    let decimals = match decimals.len() {
+        0 => vec![6; assets.len()],
+        len if len == assets.len() => decimals,
+        len => {
+            return Err(Error::Config(format!(
+                "Got decimals for {} assets, the pool has {}",
+                len,
+                assets.len()
+            )))
+        }
+    };
+    // Checked before asking for the password
+    let pool_fees = spec.pool_fees()?;
+
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, &wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(&network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, &wallet_name)?)
+        .with_simulate_only(tx_args.dry_run)
+        .build()
+        .await?;
+
+    if estimate_gas {
+        let estimate = client
+            .estimate_create_pool(assets, decimals, pool_fees, spec.pool_type, identifier)
+            .await?;
+        return Ok(Box::new(Estimate(estimate)));
+    }
+
+    eprintln!(
+        "Creating {} pool with {:.3}% swap, {:.3}% protocol and {:.3}% burn fees",
+        pool_type_label(&spec.pool_type),
+        percent(spec.swap_fee),
+        percent(spec.protocol_fee),
+        percent(spec.burn_fee)
+    );
+    match client
+        .create_pool_idempotent(
+            assets,
+            decimals,
+            pool_fees,
+            spec.pool_type,
+            identifier,
+            allow_duplicate,
+        )
+        .await?
+    {
+        CreatePoolOutcome::Created(tx) => match dry_run_report(&client, &tx)? {
+            Some(report) => Ok(Box::new(report)),
+            None => Ok(Box::new(Included::new("Pool created", &tx))),
+        },
+        CreatePoolOutcome::PoolAlreadyExists { pool_id } => Ok(Box::new(ExistingPool { pool_id })),
+    }
+}
+
+/// A transaction added to the schedule
+#[derive(Serialize)]
+struct NewScheduledTx {
+    id: u64,
+    wallet: String,
+    action: ScheduledAction,
+    trigger: Trigger,
+}
+
+impl fmt::Display for NewScheduledTx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Scheduled transaction {}: {} at {}",
+            self.id, self.action, self.trigger
+        )
+    }
+}
+
+fn run_schedule_add(
+    wallet: &str,
+    action: ScheduledAction,
+    trigger: Trigger,
+) -> Result<Output, Error> {
+    if !WalletStorage::new()?
+        .list_wallets()?
+        .iter()
+        .any(|saved| saved.name == wallet)
+    {
+        return Err(Error::Wallet(format!("No saved wallet named '{}'", wallet)));
+    }
+
+    let id = Schedule::open_in(storage::shared()?)?.add(wallet, action.clone(), trigger)?;
+    Ok(Box::new(NewScheduledTx {
+        id,
+        wallet: wallet.to_string(),
+        action,
+        trigger,
+    }))
+}
+
+/// Scheduled transactions, whatever their status
+#[derive(Serialize)]
+#[serde(transparent)]
+struct ScheduledTxs(Vec<ScheduledTx>);
+
+impl fmt::Display for ScheduledTxs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No scheduled transactions.");
+        }
+
+        writeln!(f, "ID\tWALLET\tTRIGGER\tSTATUS\tACTION")?;
+        for tx in &self.0 {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}",
+                tx.id, tx.wallet, tx.trigger, tx.status, tx.action
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run_schedule_list() -> Result<Output, Error> {
+    let schedule = Schedule::open_in(storage::shared()?)?;
+    Ok(Box::new(ScheduledTxs(schedule.txs().to_vec())))
+}
+
+/// A scheduled transaction that was cancelled
+#[derive(Serialize)]
+struct CancelledTx {
+    id: u64,
+}
+
+impl fmt::Display for CancelledTx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cancelled scheduled transaction {}", self.id)
+    }
+}
+
+fn run_schedule_cancel(id: u64) -> Result<Output, Error> {
+    Schedule::open_in(storage::shared()?)?.cancel(id)?;
+    Ok(Box::new(CancelledTx { id }))
+}
+
+/// Scheduled transactions that came due and were executed, or failed
+#[derive(Serialize)]
+#[serde(transparent)]
+struct ExecutedTxs(Vec<ScheduledTx>);
+
+impl fmt::Display for ExecutedTxs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No scheduled transactions were due.");
+        }
+        for tx in &self.0 {
+            writeln!(
+                f,
+                "Scheduled transaction {} {}: {}",
+                tx.id, tx.status, tx.action
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Run `task` while dropping the client's cached routes through pools whose
+/// reserves moved, if it caches routes
+async fn watching_route_cache<T>(
+    client: &MantraDexClient,
+    task: impl Future<Output = Result<T, Error>>,
+) -> Result<T, Error> {
+    if client.route_cache().is_none() {
+        return task.await;
+    }
+    let watcher = client.watch_route_cache(|check| {
+        // Nodes come and go; check again at the next interval
+        if let Err(e) = check {
+            eprintln!("Checking the pools on cached routes failed: {}", e);
+        }
+        Ok(())
+    });
+    tokio::select! {
+        result = task => result,
+        Err(e) = watcher => Err(e),
+    }
+}
+
+/// Execute the wallet's due transactions, printing each check's in `format`;
+/// returns those of the one check made with `once`
+async fn run_schedule(
+    wallet_name: &str,
+    poll_interval_secs: u64,
+    once: bool,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+    format: OutputFormat,
+) -> Result<Output, Error> {
+    // A simulated run would still mark the transactions as executed
+    if tx_args.dry_run {
+        return Err(Error::Config(
+            "Scheduled transactions can't be dry run; simulate each with its own command"
+                .to_string(),
+        ));
+    }
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .build()
+        .await?;
+
+    let mut schedule = Schedule::open_in(storage::shared()?)?;
+    let mut interval = tokio::time::interval(Duration::from_secs(poll_interval_secs.max(1)));
+    let done = watching_route_cache(&client, async {
+        loop {
+            interval.tick().await;
+            match scheduler::run_due(&client, &mut schedule, wallet_name).await {
+                Ok(done) if once => return Ok(done),
+                // One document per check that executed something, e.g. a JSON stream
+                Ok(done) if !done.is_empty() => print_output(&ExecutedTxs(done), format)?,
+                Ok(_) => {}
+                // Nodes come and go; try again on the next check
+                Err(e) if !once => eprintln!("Checking scheduled transactions failed: {}", e),
+                Err(e) => return Err(e),
+            }
+        }
+    })
+    .await?;
+    Ok(Box::new(ExecutedTxs(done)))
+}
+
+/// A limit order that was placed
+#[derive(Serialize)]
+#[serde(transparent)]
+struct PlacedOrder(LimitOrder);
+
+impl fmt::Display for PlacedOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Placed limit order {}: {}", self.0.id, self.0)?;
+        if let Some(expires_at) = self.0.expires_at {
+            write!(f, ", until {}", expires_at.to_rfc3339())?;
+        }
+        Ok(())
+    }
+}
+
+fn run_order_create(wallet: &str, order: NewOrder) -> Result<Output, Error> {
+    if !WalletStorage::new()?
+        .list_wallets()?
+        .iter()
+        .any(|saved| saved.name == wallet)
+    {
+        return Err(Error::Wallet(format!("No saved wallet named '{}'", wallet)));
+    }
+    if order.offer_asset.denom == order.ask_asset_denom {
+        return Err(Error::Config(
+            "The offer and ask denoms of an order must differ".to_string(),
+        ));
+    }
+
+    let mut book = OrderBook::open_in(storage::shared()?)?;
+    let id = book.add(wallet, order)?;
+    let placed = book
+        .get(id)
+        .cloned()
+        .ok_or_else(|| Error::Other(format!("No limit order {}", id)))?;
+    Ok(Box::new(PlacedOrder(placed)))
+}
+
+/// Limit orders, whatever their status
+#[derive(Serialize)]
+#[serde(transparent)]
+struct LimitOrders(Vec<LimitOrder>);
+
+impl fmt::Display for LimitOrders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No limit orders.");
+        }
+
+        writeln!(f, "ID\tWALLET\tSTATUS\tORDER")?;
+        for order in &self.0 {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                order.id, order.wallet, order.status, order
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run_order_list() -> Result<Output, Error> {
+    let book = OrderBook::open_in(storage::shared()?)?;
+    Ok(Box::new(LimitOrders(book.orders().to_vec())))
+}
+
+/// A limit order that was cancelled
+#[derive(Serialize)]
+struct CancelledOrder {
+    id: u64,
+}
+
+impl fmt::Display for CancelledOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cancelled limit order {}", self.id)
+    }
+}
+
+fn run_order_cancel(id: u64) -> Result<Output, Error> {
+    OrderBook::open_in(storage::shared()?)?.cancel(id)?;
+    Ok(Box::new(CancelledOrder { id }))
+}
+
+/// Limit orders that were executed, failed or expired
+#[derive(Serialize)]
+#[serde(transparent)]
+struct TriggeredOrders(Vec<LimitOrder>);
+
+impl fmt::Display for TriggeredOrders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No limit order prices were reached.");
+        }
+        for order in &self.0 {
+            writeln!(f, "Limit order {} {}: {}", order.id, order.status, order)?;
+        }
+        Ok(())
+    }
+}
+
+/// Execute the wallet's orders as their prices are reached, printing each check's
+/// in `format`; returns those of the one check made with `once`
+async fn run_order_watch(
+    wallet_name: &str,
+    poll_interval_secs: u64,
+    once: bool,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+    format: OutputFormat,
+) -> Result<Output, Error> {
+    // A simulated run would still mark the orders as executed
+    if tx_args.dry_run {
+        return Err(Error::Config(
+            "Limit orders can't be dry run; simulate the swap with `impact`".to_string(),
+        ));
+    }
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .build()
+        .await?;
+
+    let mut book = OrderBook::open_in(storage::shared()?)?;
+    if once {
+        let done = orders::run_triggered(&client, &mut book, wallet_name).await?;
+        return Ok(Box::new(TriggeredOrders(done)));
+    }
+
+    let poll_interval = Duration::from_secs(poll_interval_secs.max(1));
+    let watch = orders::watch(&client, &mut book, wallet_name, poll_interval, |pass| {
+        match pass {
+            // One document per check that executed something, e.g. a JSON stream
+            Ok(done) if !done.is_empty() => print_output(&TriggeredOrders(done), format),
+            Ok(_) => Ok(()),
+            // Nodes come and go; try again on the next check
+            Err(e) => {
+                eprintln!("Checking limit orders failed: {}", e);
+                Ok(())
+            }
+        }
+    });
+    watching_route_cache(&client, watch).await?;
+    Ok(Box::new(TriggeredOrders(Vec::new())))
+}
+
+/// The price history of the storage, with the saved sampling settings
+fn saved_price_history() -> Result<PriceHistory, Error> {
+    let settings = Config::load(&Config::default_path())
+        .map(|saved| saved.price_history)
+        .unwrap_or_default();
+    Ok(PriceHistory::open_in(storage::shared()?)?.with_settings(&settings))
+}
+
+/// Pools whose spot price was sampled
+#[derive(Serialize)]
+struct SampledPrices {
+    pools: usize,
+}
+
+impl fmt::Display for SampledPrices {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sampled the spot price of {} pools", self.pools)
+    }
+}
+
+/// Sample pool prices at the configured interval, printing each round's count
+/// in `format`; returns the count of the one round made with `once`
+async fn run_prices_record(
+    once: bool,
+    network: &str,
+    rpc_url: Option<String>,
+    format: OutputFormat,
+) -> Result<Output, Error> {
+    let client = MantraDexClient::connect(network, rpc_url)?.build().await?;
+
+    let mut history = saved_price_history()?;
+    if once {
+        let pools = price_history::record_pools(&client, &mut history).await?;
+        return Ok(Box::new(SampledPrices { pools }));
+    }
+
+    price_history::record_every(&client, &mut history, |round| match round {
+        // One document per round that sampled something, e.g. a JSON stream
+        Ok(pools) if pools > 0 => print_output(&SampledPrices { pools }, format),
+        Ok(_) => Ok(()),
+        // Nodes come and go; try again on the next round
+        Err(e) => {
+            eprintln!("Sampling pool prices failed: {}", e);
+            Ok(())
+        }
+    })
+    .await?;
+    Ok(Box::new(SampledPrices { pools: 0 }))
+}
+
+/// Candles of a pool's recorded spot price
+#[derive(Serialize)]
+struct PriceCandles {
+    pool_id: String,
+    denom: String,
+    quote: String,
+    candles: Vec<PriceCandle>,
+}
+
+impl fmt::Display for PriceCandles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.candles.is_empty() {
+            return write!(
+                f,
+                "No prices of pool {} were recorded in the window; record them with `prices record`.",
+                self.pool_id
+            );
+        }
+
+        writeln!(
+            f,
+            "Spot price of {} in {} in pool {}",
+            self.denom, self.quote, self.pool_id
+        )?;
+        writeln!(f, "START\tOPEN\tHIGH\tLOW\tCLOSE\tSAMPLES")?;
+        for candle in &self.candles {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                candle.start.format("%Y-%m-%d %H:%M"),
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.samples
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run_prices_candles(
+    pool_id: &str,
+    denom: Option<String>,
+    width_secs: u64,
+    since_secs: u64,
+) -> Result<Output, Error> {
+    let history = saved_price_history()?;
+    let series = history.series(pool_id).ok_or_else(|| {
+        Error::Other(format!(
+            "No prices recorded for pool {}; record them with `prices record`",
+            pool_id
+        ))
+    })?;
+    let denom = denom.unwrap_or_else(|| series.base.clone());
+    let quote = if denom == series.quote {
+        series.base.clone()
+    } else {
+        series.quote.clone()
+    };
+
+    let to = Utc::now();
+    let from = to - chrono::Duration::seconds(since_secs as i64);
+    let candles = history.candles(pool_id, &denom, from, to, Duration::from_secs(width_secs))?;
+    Ok(Box::new(PriceCandles {
+        pool_id: pool_id.to_string(),
+        denom,
+        quote,
+        candles,
+    }))
+}
+
+/// A DCA plan that was created
+#[derive(Serialize)]
+#[serde(transparent)]
+struct CreatedDcaPlan(DcaPlan);
+
+impl fmt::Display for CreatedDcaPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Created DCA plan {}: {}, first at {}",
+            self.0.id,
+            self.0,
+            self.0.next_at.to_rfc3339()
+        )
+    }
+}
+
+fn run_dca_create(wallet: &str, plan: NewDcaPlan) -> Result<Output, Error> {
+    if !WalletStorage::new()?
+        .list_wallets()?
+        .iter()
+        .any(|saved| saved.name == wallet)
+    {
+        return Err(Error::Wallet(format!("No saved wallet named '{}'", wallet)));
+    }
+    if plan.offer_asset.denom == plan.ask_asset_denom {
+        return Err(Error::Config(
+            "The offer and ask denoms of a DCA plan must differ".to_string(),
+        ));
+    }
+
+    let mut book = DcaBook::open_in(storage::shared()?)?;
+    let id = book.add(wallet, plan, Utc::now())?;
+    let created = book
+        .get(id)
+        .cloned()
+        .ok_or_else(|| Error::Other(format!("No DCA plan {}", id)))?;
+    Ok(Box::new(CreatedDcaPlan(created)))
+}
+
+/// DCA plans, whatever their status
+#[derive(Serialize)]
+#[serde(transparent)]
+struct DcaPlans(Vec<DcaPlan>);
+
+impl fmt::Display for DcaPlans {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No DCA plans.");
+        }
+
+        writeln!(f, "ID\tWALLET\tSTATUS\tSWAPS\tSPENT\tNEXT\tPLAN")?;
+        let now = Utc::now();
+        for plan in &self.0 {
+            let next = plan
+                .upcoming(1, now)
+                .first()
+                .map_or_else(|| "-".to_string(), |at| at.to_rfc3339());
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}{}\t{}\t{}",
+                plan.id,
+                plan.wallet,
+                plan.status,
+                plan.swaps(),
+                plan.spent(),
+                plan.offer_asset.denom,
+                next,
+                plan
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run_dca_list() -> Result<Output, Error> {
+    let book = DcaBook::open_in(storage::shared()?)?;
+    Ok(Box::new(DcaPlans(book.plans().to_vec())))
+}
+
+/// A DCA plan whose status was changed
+#[derive(Serialize)]
+struct UpdatedDcaPlan {
+    id: u64,
+    status: DcaStatus,
+}
+
+impl fmt::Display for UpdatedDcaPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DCA plan {} is now {}", self.id, self.status)
+    }
+}
+
+/// Apply `change` to a plan and report its new status
+fn run_dca_update(
+    id: u64,
+    change: impl FnOnce(&mut DcaBook) -> Result<(), Error>,
+) -> Result<Output, Error> {
+    let mut book = DcaBook::open_in(storage::shared()?)?;
+    change(&mut book)?;
+    let status = book
+        .get(id)
+        .map(|plan| plan.status)
+        .ok_or_else(|| Error::Other(format!("No DCA plan {}", id)))?;
+    Ok(Box::new(UpdatedDcaPlan { id, status }))
+}
+
+/// DCA plans that swapped, failed to or completed
+#[derive(Serialize)]
+#[serde(transparent)]
+struct ExecutedDcaPlans(Vec<DcaPlan>);
+
+impl fmt::Display for ExecutedDcaPlans {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No DCA swaps were due.");
+        }
+        for plan in &self.0 {
+            write!(f, "DCA plan {} {}: ", plan.id, plan.status)?;
+            match plan.executions.last().map(|execution| &execution.outcome) {
+                Some(DcaOutcome::Swapped { tx_hash, height }) => {
+                    writeln!(f, "swapped in {} at height {}", tx_hash, height)?
+                }
+                Some(DcaOutcome::Failed { reason }) => writeln!(f, "failed: {}", reason)?,
+                Some(DcaOutcome::Unconfirmed { tx_hash, reason }) => {
+                    writeln!(f, "unconfirmed {}: {}", tx_hash, reason)?
+                }
+                None => writeln!(f, "{}", plan)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute the wallet's due DCA swaps, printing each check's in `format`;
+/// returns those of the one check made with `once`
+async fn run_dca(
+    wallet_name: &str,
+    poll_interval_secs: u64,
+    once: bool,
+    tx_args: TxArgs,
+    network: &str,
+    rpc_url: Option<String>,
+    format: OutputFormat,
+) -> Result<Output, Error> {
+    // A simulated run would still record the swaps as executed
+    if tx_args.dry_run {
+        return Err(Error::Config(
+            "DCA plans can't be dry run; simulate the swap with `impact`".to_string(),
+        ));
+    }
+    let storage = WalletStorage::new()?;
+    let wallet = unlock_wallet(&storage, wallet_name)?;
+
+    let client = tx_args
+        .apply(MantraDexClient::connect(network, rpc_url)?)
+        .with_wallet(wallet)
+        .with_wallet_defaults(wallet_defaults(&storage, wallet_name)?)
+        .build()
+        .await?;
+
+    let mut book = DcaBook::open_in(storage::shared()?)?;
+    let mut interval = tokio::time::interval(Duration::from_secs(poll_interval_secs.max(1)));
+    let done = watching_route_cache(&client, async {
+        loop {
+            interval.tick().await;
+            match dca::run_due(&client, &mut book, wallet_name).await {
+                Ok(done) if once => return Ok(done),
+                // One document per check that executed something, e.g. a JSON stream
+                Ok(done) if !done.is_empty() => print_output(&ExecutedDcaPlans(done), format)?,
+                Ok(_) => {}
+                // Nodes come and go; try again on the next check
+                Err(e) if !once => eprintln!("Checking DCA plans failed: {}", e),
+                Err(e) => return Err(e),
+            }
+        }
+    })
+    .await?;
+    Ok(Box::new(ExecutedDcaPlans(done)))
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    let tx_args = TxArgs {
+        priority: cli.priority,
+        gas_adjustment: cli.gas_adjustment,
+        fee_denom: cli.fee_denom,
+        dry_run: cli.dry_run,
+    };
+    let format = if cli.json {
+        OutputFormat::Json
+    } else {
+        cli.output
+    };
+
+    let result = match cli.command {
+        Command::Wallet { command } => match command {
+            WalletCommand::Setup => run_wallet_setup(),
+            WalletCommand::List => run_wallet_list(),
+            WalletCommand::Watch { name, address } => run_wallet_watch(&name, &address),
+            WalletCommand::PublicKey { name } => run_wallet_public_key(&name),
+            WalletCommand::ConvertAddress { address, prefix } => {
+                run_wallet_convert_address(&address, &prefix)
+            }
+            WalletCommand::Multisig { command } => match command {
+                MultisigCommand::Create {
+                    name,
+                    threshold,
+                    public_keys,
+                } => run_multisig_create(&name, threshold, &public_keys),
+            },
+            WalletCommand::Config {
+                name,
+                slippage,
+                gas_price,
+                memo,
+                preferred_pools,
+                clear,
+            } => run_wallet_config(&name, slippage, gas_price, memo, preferred_pools, clear),
+        },
+        Command::Bridge {
+            network,
+            rpc_url,
+            sinks,
+            contracts,
+            from_height,
+            poll_interval_ms,
+            report,
+            alerts,
+            activity,
+        } => {
+            let bridge = run_bridge(
+                &network,
+                rpc_url,
+                &sinks,
+                contracts,
+                from_height,
+                poll_interval_ms,
+                report,
+                alerts,
+                activity,
+            )
+            .await;
+            // Events go to the sinks; there is no result to print
+            match bridge {
+                Ok(()) => return,
+                Err(e) => Err(e),
+            }
+        }
+        Command::Report {
+            address,
+            network,
+            rpc_url,
+            from_height,
+            to_height,
+        } => run_report(&address, &network, rpc_url, from_height, to_height).await,
+        Command::History {
+            address,
+            operation,
+            pool,
+            limit,
+            after,
+            offline,
+            network,
+            rpc_url,
+        } => {
+            let filter = HistoryFilter {
+                operation,
+                pool_id: pool,
+                ..HistoryFilter::default()
+            };
+            let cursor = match after {
+                Some(tx_hash) => PageCursor::after(tx_hash, limit),
+                None => PageCursor::first(limit),
+            };
+            let connection = (!offline).then_some((network.as_str(), rpc_url));
+            run_history(&address, &filter, &cursor, connection).await
+        }
+        Command::Depth {
+            pool_id,
+            offer_denom,
+            ask_denom,
+            max_amount,
+            steps,
+            out_file,
+            network,
+            rpc_url,
+        } => {
+            run_depth(
+                &pool_id,
+                offer_denom,
+                &ask_denom,
+                max_amount,
+                steps,
+                out_file,
+                &network,
+                rpc_url,
+            )
+            .await
+        }
+        Command::Epoch {
+            epochs,
+            network,
+            rpc_url,
+        } => run_epoch(epochs, &network, rpc_url).await,
+        Command::Earn {
+            address,
+            denom,
+            amount,
+            lookback_blocks,
+            network,
+            rpc_url,
+        } => run_earn(address, denom, amount, lookback_blocks, &network, rpc_url).await,
+        Command::Route {
+            offer_denom,
+            amount,
+            ask_denom,
+            network,
+            rpc_url,
+        } => {
+            run_route(
+                Coin {
+                    denom: offer_denom,
+                    amount,
+                },
+                &ask_denom,
+                &network,
+                rpc_url,
+            )
+            .await
+        }
+        Command::Swap {
+            command:
+                Some(SwapCommand::Route {
+                    from,
+                    to,
+                    amount,
+                    max_hops,
+                    candidates,
+                    pick,
+                    wallet,
+                    slippage,
+                    network,
+                    rpc_url,
+                }),
+            ..
+        } => {
+            run_swap_route(
+                Coin {
+                    denom: from,
+                    amount,
+                },
+                &to,
+                max_hops,
+                candidates,
+                pick,
+                wallet.as_deref(),
+                slippage,
+                tx_args,
+                &network,
+                rpc_url,
+            )
+            .await
+        }
+        Command::Swap { args: None, .. } => Err(Error::Config(
+            "Give a pool to swap in, or use `swap route`".to_string(),
+        )),
+        Command::Swap {
+            args:
+                Some(SwapArgs {
+                    pool_id,
+                    offer_denom,
+                    amount,
+                    ask_denom,
+                    wallet,
+                    slippage,
+                    auto_retry_slippage,
+                    max_retry_slippage,
+                    max_price_impact,
+                    estimate_gas,
+                    network,
+                    rpc_url,
+                }),
+            ..
+        } => {
+            let retry_policy = auto_retry_slippage.then(|| SlippageBumpPolicy {
+                max_slippage: max_retry_slippage,
+                ..SlippageBumpPolicy::default()
+            });
+            run_swap(
+                &pool_id,
+                Coin {
+                    denom: offer_denom,
+                    amount,
+                },
+                &ask_denom,
+                &wallet,
+                slippage,
+                retry_policy,
+                max_price_impact,
+                estimate_gas,
+                tx_args,
+                &network,
+                rpc_url,
+            )
+            .await
+        }
+        Command::Impact {
+            pool_id,
+            offer_denom,
+            amount,
+            ask_denom,
+            network,
+            rpc_url,
+        } => {
+            run_impact(
+                &pool_id,
+                Coin {
+                    denom: offer_denom,
+                    amount,
+                },
+                &ask_denom,
+                &network,
+                rpc_url,
+            )
+            .await
+        }
+        Command::Schedule { command } => match command {
+            ScheduleCommand::Swap {
+                pool_id,
+                offer_denom,
+                amount,
+                ask_denom,
+                wallet,
+                slippage,
+                min_return,
+                at,
+            } => run_schedule_add(
+                &wallet,
+                ScheduledAction::Swap {
+                    pool_id,
+                    offer_asset: Coin {
+                        denom: offer_denom,
+                        amount,
+                    },
+                    ask_asset_denom: ask_denom,
+                    max_slippage: slippage,
+                    min_return,
+                },
+                at,
+            ),
+            ScheduleCommand::Claim {
+                wallet,
+                until_epoch,
+                at,
+            } => run_schedule_add(&wallet, ScheduledAction::ClaimRewards { until_epoch }, at),
+            ScheduleCommand::List => run_schedule_list(),
+            ScheduleCommand::Cancel { id } => run_schedule_cancel(id),
+            ScheduleCommand::Run {
+                wallet,
+                poll_interval_secs,
+                once,
+                network,
+                rpc_url,
+            } => {
+                run_schedule(
+                    &wallet,
+                    poll_interval_secs,
+                    once,
+                    tx_args,
+                    &network,
+                    rpc_url,
+                    format,
+                )
+                .await
+            }
+        },
+        Command::Dca { command } => match command {
+            DcaCommand::Create {
+                pool_id,
+                offer_denom,
+                amount,
+                ask_denom,
+                every,
+                wallet,
+                slippage,
+                executions,
+                until,
+                start,
+            } => run_dca_create(
+                &wallet,
+                NewDcaPlan {
+                    pool_id,
+                    offer_asset: Coin {
+                        denom: offer_denom,
+                        amount,
+                    },
+                    ask_asset_denom: ask_denom,
+                    interval_secs: every,
+                    max_slippage: slippage,
+                    end: DcaEnd {
+                        max_executions: executions,
+                        until,
+                    },
+                    start_at: start,
+                },
+            ),
+            DcaCommand::List => run_dca_list(),
+            DcaCommand::Pause { id } => run_dca_update(id, |book| book.pause(id)),
+            DcaCommand::Resume { id } => run_dca_update(id, |book| book.resume(id, Utc::now())),
+            DcaCommand::Cancel { id } => run_dca_update(id, |book| book.cancel(id)),
+            DcaCommand::Run {
+                wallet,
+                poll_interval_secs,
+                once,
+                network,
+                rpc_url,
+            } => {
+                run_dca(
+                    &wallet,
+                    poll_interval_secs,
+                    once,
+                    tx_args,
+                    &network,
+                    rpc_url,
+                    format,
+                )
+                .await
+            }
+        },
+        Command::Order { command } => match command {
+            OrderCommand::Create {
+                pool_id,
+                offer_denom,
+                amount,
+                ask_denom,
+                when,
+                wallet,
+                slippage,
+                expires,
+            } => run_order_create(
+                &wallet,
+                NewOrder {
+                    pool_id,
+                    offer_asset: Coin {
+                        denom: offer_denom,
+                        amount,
+                    },
+                    ask_asset_denom: ask_denom,
+                    condition: when,
+                    max_slippage: slippage,
+                    expires_at: expires,
+                },
+            ),
+            OrderCommand::List => run_order_list(),
+            OrderCommand::Cancel { id } => run_order_cancel(id),
+            OrderCommand::Run {
+                wallet,
+                poll_interval_secs,
+                once,
+                network,
+                rpc_url,
+            } => {
+                run_order_watch(
+                    &wallet,
+                    poll_interval_secs,
+                    once,
+                    tx_args,
+                    &network,
+                    rpc_url,
+                    format,
+                )
+                .await
+            }
+        },
+        Command::Prices { command } => match command {
+            PriceCommand::Record {
+                once,
+                network,
+                rpc_url,
+            } => run_prices_record(once, &network, rpc_url, format).await,
+            PriceCommand::Candles {
+                pool_id,
+                denom,
+                width,
+                since,
+            } => run_prices_candles(&pool_id, denom, width, since),
+        },
+        Command::Bulk {
+            action,
+            share,
+            pools,
+            slippage,
+            batch_size,
+            yes,
+            wallet,
+            network,
+            rpc_url,
+        } => {
+            run_bulk(
+                action, share, &pools, slippage, batch_size, yes, &wallet, tx_args, &network,
+                rpc_url, format,
+            )
+            .await
+        }
+        Command::Convert {
+            amount,
+            from,
+            to,
+            usd_denoms,
+            network,
+            rpc_url,
+        } => run_convert(amount, &from, to, usd_denoms, &network, rpc_url).await,
+        Command::Portfolio {
+            address,
+            quote,
+            network,
+            rpc_url,
+        } => run_portfolio(&address, quote, &network, rpc_url).await,
+        Command::Token {
+            denoms,
+            network,
+            rpc_url,
+        } => run_token(&denoms, &network, rpc_url).await,
+        Command::Liquidity { command } => match command {
+            LiquidityCommand::Positions {
+                address,
+                quote,
+                offline,
+                network,
+                rpc_url,
+            } => run_liquidity_positions(&address, quote, offline, &network, rpc_url).await,
+            LiquidityCommand::Withdraw {
+                pool_id,
+                amount,
+                preview,
+                yes,
+                wallet,
+                network,
+                rpc_url,
+            } => {
+                run_liquidity_withdraw(
+                    &pool_id, amount, preview, yes, &wallet, tx_args, &network, rpc_url,
+                )
+                .await
+            }
+        },
+        Command::Tx { command } => match command {
+            TxCommand::Diagnose {
+                hash,
+                raw_log,
+                network,
+                rpc_url,
+            } => run_tx_diagnose(&hash, raw_log, &network, rpc_url).await,
+            TxCommand::Send {
+                batch,
+                estimate_gas,
+                yes,
+                wallet,
+                network,
+                rpc_url,
+            } => {
+                run_tx_send(
+                    &batch,
+                    estimate_gas,
+                    yes,
+                    &wallet,
+                    tx_args,
+                    &network,
+                    rpc_url,
+                )
+                .await
+            }
+            TxCommand::Prepare {
+                multisig,
+                msgs,
+                out_file,
+                network,
+                rpc_url,
+            } => run_tx_prepare(&multisig, &msgs, &out_file, tx_args, &network, rpc_url).await,
+            TxCommand::SignPartial {
+                tx_file,
+                wallet,
+                out_file,
+                yes,
+            } => run_tx_sign_partial(&tx_file, &wallet, out_file.as_deref(), yes),
+            TxCommand::Combine { .. } if tx_args.dry_run => Err(Error::Config(
+                "A signed multisig transaction can't be dry run; simulate it with `tx prepare`"
+                    .to_string(),
+            )),
+            TxCommand::Combine {
+                tx_file,
+                signatures,
+                yes,
+                network,
+                rpc_url,
+            } => run_tx_combine(&tx_file, &signatures, yes, &network, rpc_url).await,
+        },
+        Command::Farm { command } => match command {
+            FarmCommand::Positions {
+                address,
+                offline,
+                network,
+                rpc_url,
+            } => run_farm_positions(&address, offline, &network, rpc_url).await,
+            FarmCommand::Position {
+                identifier,
+                network,
+                rpc_url,
+            } => run_farm_position(&identifier, &network, rpc_url).await,
+            FarmCommand::Close {
+                identifier,
+                amount,
+                wallet,
+                network,
+                rpc_url,
+            } => {
+                let tx = PositionTx::Close(amount);
+                run_position_tx(&identifier, tx, &wallet, tx_args, &network, rpc_url).await
+            }
+            FarmCommand::Expand {
+                identifier,
+                amount,
+                wallet,
+                network,
+                rpc_url,
+            } => {
+                let tx = PositionTx::Expand(amount);
+                run_position_tx(&identifier, tx, &wallet, tx_args, &network, rpc_url).await
+            }
+            FarmCommand::Withdraw {
+                identifier,
+                emergency_unlock,
+                wallet,
+                network,
+                rpc_url,
+            } => {
+                let tx = PositionTx::Withdraw { emergency_unlock };
+                run_position_tx(&identifier, tx, &wallet, tx_args, &network, rpc_url).await
+            }
+            FarmCommand::Claim {
+                max_gas,
+                wallet,
+                network,
+                rpc_url,
+            } => run_farm_claim(max_gas, &wallet, tx_args, &network, rpc_url, format).await,
+        },
+        Command::Pool { command } => match command {
+            PoolCommand::Create(args) => run_pool_create(*args, tx_args).await,
+            PoolCommand::Templates => run_pool_templates(),
+            PoolCommand::Info {
+                pool_id,
+                network,
+                rpc_url,
+            } => run_pool_info(&pool_id, &network, rpc_url).await,
+            PoolCommand::Compare {
+                pool_ids,
+                address,
+                quote,
+                lookback_blocks,
+                network,
+                rpc_url,
+            } => {
+                run_pool_compare(
+                    &pool_ids,
+                    address,
+                    quote,
+                    lookback_blocks,
+                    &network,
+                    rpc_url,
+                )
+                .await
+            }
+        },
+        Command::Prefs { command } => run_prefs(command),
+        Command::Profile { command } => match command {
+            ProfileCommand::Export {
+                path,
+                include_wallets,
+                force,
+            } => run_profile_export(&path, include_wallets, force),
+            ProfileCommand::Import { path, overwrite } => run_profile_import(&path, overwrite),
+        },
+    };
+
+    if let Err(e) = result.and_then(|output| print_output(output.as_ref(), format)) {
+        match cli.error_format {
+            ErrorFormat::Text => eprintln!("Error: {}", e),
+            ErrorFormat::Json => eprintln!("{}", e.to_json()),
+        }
+        std::process::exit(e.exit_code());
+    }
+}
@@ -8,7 +8,7 @@
 use clap::Parser;
 #[cfg(feature = "tui")]
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture},
+    event::{self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -17,17 +17,25 @@ use mantra_dex_sdk::{
     client::MantraDexClient,
     config::MantraNetworkConfig,
     error::Error,
+    secret::SecretString,
     tui::{
         app::{App, Screen},
-        events::EventHandler,
+        events::{EventBus, EventHandler},
         ui::render_ui,
+        utils::session::SessionReplayer,
     },
     wallet::MantraWallet,
 };
 #[cfg(feature = "tui")]
 use ratatui::{backend::CrosstermBackend, Terminal};
 #[cfg(feature = "tui")]
-use std::{fs, io::stdout, panic, path::PathBuf, time::Duration};
+use std::{
+    fs,
+    io::stdout,
+    panic,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 #[cfg(feature = "tui")]
 use tokio::{sync::mpsc, time::interval};
 
@@ -60,24 +68,44 @@ struct Args {
     /// Custom refresh interval in seconds (default: 30)
     #[arg(long, default_value = "30")]
     refresh_interval: u64,
+
+    /// Seconds without input before background refreshes pause and redraws slow
+    /// down, as they do while the terminal is unfocused (0: only when unfocused)
+    #[arg(long, default_value = "120")]
+    idle_timeout: u64,
+
+    /// Record key events and state transitions to a file (passwords and mnemonics are redacted)
+    #[arg(long, value_name = "PATH")]
+    record_session: Option<PathBuf>,
+
+    /// Replay a previously recorded session file
+    #[arg(long, value_name = "PATH", conflicts_with = "record_session")]
+    replay_session: Option<PathBuf>,
+
+    /// Playback speed multiplier for --replay-session
+    #[arg(long, default_value = "1.0")]
+    replay_speed: f64,
 }
 
 #[cfg(feature = "tui")]
 #[derive(serde::Deserialize)]
 struct WalletConfig {
-    mnemonic: String,
+    mnemonic: SecretString,
     derivation_path: Option<u32>,
-    passphrase: Option<String>,
+    passphrase: Option<SecretString>,
+}
+
+#[cfg(feature = "tui")]
+fn default_wallet_config_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".mantra-dex")
+        .join("wallet.toml")
 }
 
 #[cfg(feature = "tui")]
 async fn load_wallet_from_config(config_path: Option<PathBuf>) -> Result<MantraWallet, Error> {
-    let config_path = config_path.unwrap_or_else(|| {
-        dirs::home_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join(".mantra-dex")
-            .join("wallet.toml")
-    });
+    let config_path = config_path.unwrap_or_else(default_wallet_config_path);
 
     if !config_path.exists() {
         eprintln!(
@@ -94,13 +122,14 @@ async fn load_wallet_from_config(config_path: Option<PathBuf>) -> Result<MantraW
     }
 
     let config_content = fs::read_to_string(&config_path)
+        .map(SecretString::new)
         .map_err(|e| Error::Wallet(format!("Failed to read wallet config: {}", e)))?;
 
-    let wallet_config: WalletConfig = toml::from_str(&config_content)
+    let wallet_config: WalletConfig = toml::from_str(config_content.expose_secret())
         .map_err(|e| Error::Wallet(format!("Failed to parse wallet config: {}", e)))?;
 
     let derivation_path = wallet_config.derivation_path.unwrap_or(0);
-    MantraWallet::from_mnemonic(&wallet_config.mnemonic, derivation_path)
+    MantraWallet::from_mnemonic(wallet_config.mnemonic.expose_secret(), derivation_path)
 }
 
 #[cfg(feature = "tui")]
@@ -121,16 +150,23 @@ async fn setup_client_and_wallet(args: &Args) -> Result<(MantraDexClient, ()), E
         config.rpc_url = rpc_url.clone();
     }
 
-    // Create client
-    let client = MantraDexClient::new(config).await?;
+    // Create client with the saved settings and gas history, as every frontend does
+    let builder = MantraDexClient::builder(config).with_saved_settings();
 
-    // Load wallet
-    let wallet = load_wallet_from_config(args.wallet_config.clone()).await?;
-    let wallet_address = wallet.address()?;
-    let client = client.with_wallet(wallet);
+    // Without a wallet file the TUI runs read-only: queries work, signing is disabled.
+    // An explicitly requested wallet file must load.
+    let builder = if args.wallet_config.is_some() || default_wallet_config_path().exists() {
+        let wallet = load_wallet_from_config(args.wallet_config.clone()).await?;
+        let wallet_address = wallet.address()?;
+        println!("✓ Wallet address: {}", wallet_address);
+        builder.with_wallet(wallet)
+    } else {
+        println!("! No wallet configured, starting in read-only mode");
+        builder
+    };
+    let client = builder.build().await?;
 
     println!("✓ Connected to {} network", args.network);
-    println!("✓ Wallet address: {}", wallet_address);
 
     Ok((client, ()))
 }
@@ -149,24 +185,41 @@ async fn run_tui_app(args: Args) -> Result<(), Error> {
     let original_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic| {
         let _ = disable_raw_mode();
-        let _ = execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        let _ = execute!(
+            stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableFocusChange
+        );
         original_hook(panic);
     }));
 
     // Initialize terminal
     enable_raw_mode()?;
     let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableFocusChange
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create event channel
     let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_bus = EventBus::new(event_tx);
 
     // Create application
     let config = client.config().clone();
     let mut app = App::new(client, config);
 
+    // Preferences shared with the CLI, including whether the tour was taken
+    match mantra_dex_sdk::preferences::PreferencesStore::open_default() {
+        Ok(store) => app.set_preferences(store),
+        Err(e) => eprintln!("Warning: Failed to load preferences: {}", e),
+    }
+
     // Extract wallet address from client and set it in app state
     if let Ok(wallet) = app.client.wallet() {
         if let Ok(address) = wallet.address() {
@@ -174,7 +227,11 @@ async fn run_tui_app(args: Args) -> Result<(), Error> {
         }
     }
 
-    app.initialize_background_tasks(event_tx.clone());
+    app.initialize_background_tasks(event_bus.clone());
+
+    if let Some(path) = &args.record_session {
+        app.enable_session_recording(path)?;
+    }
 
     // Configure sync settings
     if !args.no_realtime {
@@ -187,9 +244,12 @@ async fn run_tui_app(args: Args) -> Result<(), Error> {
             network_timeout: Duration::from_secs(10),
             retry_attempts: 3,
             retry_delay: Duration::from_secs(5),
+            subscription_fallback_interval: Duration::from_secs(300),
+            idle_timeout: Duration::from_secs(args.idle_timeout),
         };
         app.update_sync_config(sync_config);
     }
+    app.set_idle_timeout(Duration::from_secs(args.idle_timeout));
 
     // Setup event handler
     let event_handler = EventHandler::new();
@@ -215,16 +275,40 @@ async fn run_tui_app(args: Args) -> Result<(), Error> {
         app.set_status("Welcome to MANTRA DEX! Let's set up your wallet.".to_string());
     }
 
+    // Feed a recorded session into the event loop
+    let _replay_task = match &args.replay_session {
+        Some(path) => {
+            let replayer = SessionReplayer::load(path)?;
+            let redacted = replayer.redacted_count();
+            if redacted > 0 {
+                app.set_status(format!(
+                    "Replaying session ({} redacted keystrokes will be skipped)",
+                    redacted
+                ));
+            } else {
+                app.set_status("Replaying session...".to_string());
+            }
+            Some(replayer.spawn(event_bus.clone(), args.replay_speed))
+        }
+        None => None,
+    };
+
     // Main application loop
     let mut tick_interval = interval(Duration::from_millis(250));
+    let mut last_draw: Option<Instant> = None;
 
     loop {
-        // Render UI
-        terminal.draw(|f| {
-            if let Err(e) = render_ui(f, &mut app) {
-                app.set_error(format!("Render error: {}", e));
-            }
-        })?;
+        // Render UI, while idle only once per idle tick unless an event arrived
+        let tick_rate = app.tick_rate();
+        event_handler.set_idle(app.is_idle());
+        if !app.is_idle() || last_draw.is_none_or(|at| at.elapsed() >= tick_rate) {
+            terminal.draw(|f| {
+                if let Err(e) = render_ui(f, &mut app) {
+                    app.set_error(format!("Render error: {}", e));
+                }
+            })?;
+            last_draw = Some(Instant::now());
+        }
 
         // Handle events
         tokio::select! {
@@ -234,7 +318,7 @@ async fn run_tui_app(args: Args) -> Result<(), Error> {
                     if crossterm_event {
                         if let Ok(event) = event::read() {
                             if let Some(app_event) = event_handler.handle_crossterm_event(event) {
-                                if let Err(e) = event_tx.send(app_event) {
+                                if let Err(e) = event_bus.send(app_event) {
                                     eprintln!("Failed to send event: {}", e);
                                 }
                             }
@@ -248,6 +332,7 @@ async fn run_tui_app(args: Args) -> Result<(), Error> {
                 if let Err(e) = app.handle_event(event).await {
                     app.set_error(format!("Error handling event: {}", e));
                 }
+                last_draw = None;
 
                 // Check if we should quit
                 if app.state.should_quit {
@@ -51,6 +51,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .help("Enable debug logging")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("simulate-only")
+                .long("simulate-only")
+                .help("Run execute tools in simulation only, without signing or broadcasting")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("public")
+                .long("public")
+                .help("Serve only public data: no wallet, query tools only, rate-limited HTTP")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("rate-limit")
+                .long("rate-limit")
+                .value_name("PER_MINUTE")
+                .help("Requests a minute each client may make in public mode")
+                .value_parser(clap::value_parser!(u32)),
+        )
         .arg(
             Arg::new("log-format")
                 .long("log-format")
@@ -79,6 +98,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let port = *matches.get_one::<u16>("port").unwrap();
     let network = matches.get_one::<String>("network").unwrap();
     let debug_mode = matches.get_flag("debug");
+    let simulate_only = matches.get_flag("simulate-only");
+    let public_mode = matches.get_flag("public");
+    let rate_limit = matches.get_one::<u32>("rate-limit").copied();
     let log_format = matches.get_one::<String>("log-format").unwrap();
     let log_file = matches.get_one::<String>("log-file");
     let disable_colors = matches.get_flag("disable-colors");
@@ -136,6 +158,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         config.debug = debug_mode;
     }
 
+    // Override simulate-only mode from CLI if provided
+    if simulate_only {
+        config.simulate_only = true;
+        tracing::info!("Simulate-only mode: execute tools will not sign or broadcast");
+    }
+
+    // Override public-data mode and its rate limit from CLI if provided
+    if public_mode {
+        config.public_mode = true;
+        tracing::info!("Public mode: no wallet is loaded and only query tools are served");
+    }
+    if let Some(rate_limit) = rate_limit {
+        config.rate_limit_per_minute = rate_limit;
+    }
+
     // Override host and port from CLI if using HTTP transport
     if transport == "http" {
         config.http_host = host;
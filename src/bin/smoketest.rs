@@ -0,0 +1,360 @@
+//! MANTRA DEX SDK - End-to-end smoke test
+//!
+//! Runs a scripted sequence of queries and transactions against a network,
+//! usually testnet after a release, and writes a JSON or JUnit report of the
+//! steps. The transactions are signed by the wallet whose mnemonic is in
+//! `MANTRA_SMOKETEST_MNEMONIC`.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, ValueEnum};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::{
+    error::Error,
+    smoketest::{SmokeReport, StepResult, StepStatus},
+    CreatePoolOutcome, MantraDexClient, MantraWallet, PoolTemplate,
+};
+
+/// Environment variable holding the mnemonic of the funded test wallet
+const MNEMONIC_ENV: &str = "MANTRA_SMOKETEST_MNEMONIC";
+
+#[derive(Parser)]
+#[command(name = "mantra-dex-smoketest")]
+#[command(about = "Run an end-to-end smoke test of the MANTRA DEX SDK against a network")]
+#[command(version)]
+struct Args {
+    /// Network to test
+    #[arg(long, default_value = "mantra-dukong")]
+    network: String,
+    /// Override the RPC endpoint
+    #[arg(long)]
+    rpc_url: Option<String>,
+    /// Denom the test wallet swaps and provides
+    #[arg(long, default_value = "uom")]
+    offer_denom: String,
+    /// Denom the swap asks for, the other asset of the tested pool
+    #[arg(long)]
+    ask_denom: String,
+    /// Pool to test; defaults to the first pool of the pair
+    #[arg(long)]
+    pool: Option<String>,
+    /// Create a volatile pool for the pair first, if none exists (needs the
+    /// pool creation fee)
+    #[arg(long)]
+    create_pool: bool,
+    /// Smallest offer denom balance the test wallet must hold
+    #[arg(long, default_value_t = Uint128::new(1_000_000))]
+    min_balance: Uint128,
+    /// Offer denom amount to swap
+    #[arg(long, default_value_t = Uint128::new(10_000))]
+    swap_amount: Uint128,
+    /// Offer denom amount to provide as liquidity
+    #[arg(long, default_value_t = Uint128::new(10_000))]
+    liquidity_amount: Uint128,
+    /// Maximum slippage of the swap and liquidity steps, in percent
+    #[arg(long, default_value_t = 5)]
+    slippage_percent: u64,
+    /// Report format
+    #[arg(long, value_enum, default_value_t = Format::Json)]
+    format: Format,
+    /// Write the report to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Json,
+    Junit,
+}
+
+/// Run `step`, timing it
+async fn timed<T>(step: impl Future<Output = Result<T, Error>>) -> (Duration, Result<T, Error>) {
+    let start = Instant::now();
+    let result = step.await;
+    (start.elapsed(), result)
+}
+
+/// Build a client for the network under test
+async fn connect(args: &Args) -> Result<MantraDexClient, Error> {
+    MantraDexClient::connect(&args.network, args.rpc_url.clone())?
+        .build()
+        .await
+}
+
+/// Amount of `ask_denom` worth `offer` at the pool's reserve ratio
+async fn matching_amount(
+    client: &MantraDexClient,
+    pool_id: &str,
+    offer: &Coin,
+    ask_denom: &str,
+) -> Result<Uint128, Error> {
+    let pool = client.get_pool(pool_id).await?;
+    let reserve = |denom: &str| {
+        pool.pool_info
+            .assets
+            .iter()
+            .find(|asset| asset.denom == denom)
+            .map(|asset| asset.amount)
+            .filter(|amount| !amount.is_zero())
+            .ok_or_else(|| Error::Other(format!("Pool {} has no {} reserve", pool_id, denom)))
+    };
+    Ok(offer
+        .amount
+        .multiply_ratio(reserve(ask_denom)?, reserve(&offer.denom)?))
+}
+
+/// Run the smoke test sequence, recording every step in `report`
+async fn run(args: &Args, report: &mut SmokeReport) {
+    let (elapsed, result) = timed(async {
+        let (wallet, _mnemonic) = MantraWallet::generate()?;
+        wallet.address()
+    })
+    .await;
+    report.push(match result {
+        Ok(address) => StepResult::passed("create_wallet", elapsed, address.to_string()),
+        Err(e) => StepResult::failed("create_wallet", elapsed, e),
+    });
+
+    let (elapsed, result) = timed(connect(args)).await;
+    let client = match result {
+        Ok(client) => client,
+        Err(e) => {
+            report.push(StepResult::failed("network", elapsed, e));
+            return;
+        }
+    };
+    let (elapsed, result) = timed(client.get_last_block_height()).await;
+    report.push(match result {
+        Ok(height) => StepResult::passed("network", elapsed, format!("block {}", height)),
+        Err(e) => StepResult::failed("network", elapsed, e),
+    });
+
+    let (elapsed, result) = timed(async {
+        let mnemonic = std::env::var(MNEMONIC_ENV)
+            .map_err(|_| Error::Config(format!("{} is not set", MNEMONIC_ENV)))?;
+        let wallet = MantraWallet::from_mnemonic(mnemonic.trim(), 0)?;
+        let client = client.with_wallet(wallet);
+        let balance = client.get_balance(&args.offer_denom).await?;
+        if balance.amount < args.min_balance {
+            return Err(Error::Wallet(format!(
+                "Test wallet holds {}{}, needs at least {}{}",
+                balance.amount, balance.denom, args.min_balance, args.offer_denom
+            )));
+        }
+        Ok((client, balance))
+    })
+    .await;
+    let client = match result {
+        Ok((client, balance)) => {
+            report.push(StepResult::passed(
+                "fund_check",
+                elapsed,
+                balance.to_string(),
+            ));
+            client
+        }
+        Err(e) => {
+            report.push(StepResult::failed("fund_check", elapsed, e));
+            for step in [
+                "create_pool",
+                "swap",
+                "provide_liquidity",
+                "withdraw_liquidity",
+                "claim_rewards",
+            ] {
+                report.push(StepResult::skipped(step, "test wallet is not funded"));
+            }
+            return;
+        }
+    };
+
+    let mut pool_id = args.pool.clone();
+    if args.create_pool {
+        let (elapsed, result) = timed(async {
+            let denoms = vec![args.offer_denom.clone(), args.ask_denom.clone()];
+            let mut decimals = Vec::with_capacity(denoms.len());
+            for denom in &denoms {
+                decimals.push(client.get_asset_decimals(denom).await.unwrap_or(6));
+            }
+            let spec = PoolTemplate::Volatile.spec();
+            client
+                .create_pool_idempotent(
+                    denoms,
+                    decimals,
+                    spec.pool_fees()?,
+                    spec.pool_type,
+                    None,
+                    false,
+                )
+                .await
+        })
+        .await;
+        report.push(match result {
+            Ok(CreatePoolOutcome::Created(tx)) => {
+                StepResult::passed("create_pool", elapsed, format!("block {}", tx.height))
+                    .with_tx_hash(tx.txhash)
+            }
+            Ok(CreatePoolOutcome::PoolAlreadyExists { pool_id: existing }) => {
+                let detail = format!("pool {} already exists", existing);
+                pool_id.get_or_insert(existing);
+                StepResult::passed("create_pool", elapsed, detail)
+            }
+            Err(e) => StepResult::failed("create_pool", elapsed, e),
+        });
+    } else {
+        report.push(StepResult::skipped(
+            "create_pool",
+            "--create-pool not given",
+        ));
+    }
+
+    let pool_id = match pool_id {
+        Some(pool_id) => pool_id,
+        None => match client
+            .get_pools_for_pair(&args.offer_denom, &args.ask_denom)
+            .await
+        {
+            Ok(pools) if !pools.is_empty() => pools[0].pool_info.pool_identifier.clone(),
+            Ok(_) => {
+                let reason = format!("no {}/{} pool", args.offer_denom, args.ask_denom);
+                for step in ["swap", "provide_liquidity", "withdraw_liquidity"] {
+                    report.push(StepResult::failed(step, Duration::ZERO, &reason));
+                }
+                report.push(StepResult::skipped("claim_rewards", reason));
+                return;
+            }
+            Err(e) => {
+                for step in ["swap", "provide_liquidity", "withdraw_liquidity"] {
+                    report.push(StepResult::failed(step, Duration::ZERO, &e));
+                }
+                report.push(StepResult::skipped(
+                    "claim_rewards",
+                    "pools could not be queried",
+                ));
+                return;
+            }
+        },
+    };
+    let slippage = Decimal::percent(args.slippage_percent);
+
+    let offer = Coin::new(args.swap_amount, &args.offer_denom);
+    let (elapsed, result) =
+        timed(client.swap(&pool_id, offer, &args.ask_denom, Some(slippage))).await;
+    report.push(match result {
+        Ok(tx) => {
+            StepResult::passed("swap", elapsed, format!("pool {}", pool_id)).with_tx_hash(tx.txhash)
+        }
+        Err(e) => StepResult::failed("swap", elapsed, e),
+    });
+
+    let (elapsed, result) = timed(async {
+        let pool = client.get_pool(&pool_id).await?;
+        let lp_denom = pool.pool_info.lp_denom;
+        let before = client.get_balance(&lp_denom).await?.amount;
+        let offer = Coin::new(args.liquidity_amount, &args.offer_denom);
+        let ask_amount = matching_amount(&client, &pool_id, &offer, &args.ask_denom).await?;
+        let tx = client
+            .provide_liquidity(
+                &pool_id,
+                vec![offer, Coin::new(ask_amount, &args.ask_denom)],
+                Some(slippage),
+                Some(slippage),
+            )
+            .await?;
+        let minted = client
+            .get_balance(&lp_denom)
+            .await?
+            .amount
+            .saturating_sub(before);
+        if minted.is_zero() {
+            return Err(Error::Other(format!("No {} was minted", lp_denom)));
+        }
+        Ok((tx, Coin::new(minted, lp_denom)))
+    })
+    .await;
+    let minted = match result {
+        Ok((tx, minted)) => {
+            report.push(
+                StepResult::passed("provide_liquidity", elapsed, format!("minted {}", minted))
+                    .with_tx_hash(tx.txhash),
+            );
+            Some(minted)
+        }
+        Err(e) => {
+            report.push(StepResult::failed("provide_liquidity", elapsed, e));
+            None
+        }
+    };
+
+    match minted {
+        Some(minted) => {
+            let (elapsed, result) = timed(client.withdraw_liquidity(&pool_id, minted.amount)).await;
+            report.push(match result {
+                Ok(tx) => {
+                    StepResult::passed("withdraw_liquidity", elapsed, format!("burned {}", minted))
+                        .with_tx_hash(tx.txhash)
+                }
+                Err(e) => StepResult::failed("withdraw_liquidity", elapsed, e),
+            });
+        }
+        None => report.push(StepResult::skipped(
+            "withdraw_liquidity",
+            "no liquidity was provided",
+        )),
+    }
+
+    let (elapsed, result) = timed(async {
+        let address = client.wallet()?.address()?.to_string();
+        let pending = client.get_pending_rewards(&address).await?;
+        if pending.is_empty() {
+            return Ok(None);
+        }
+        let tx = client.claim_rewards_all().await?;
+        Ok(Some((tx, pending)))
+    })
+    .await;
+    report.push(match result {
+        Ok(Some((tx, pending))) => {
+            let claimed: Vec<String> = pending.iter().map(Coin::to_string).collect();
+            StepResult::passed("claim_rewards", elapsed, claimed.join(", ")).with_tx_hash(tx.txhash)
+        }
+        Ok(None) => StepResult::skipped("claim_rewards", "no rewards pending"),
+        Err(e) => StepResult::failed("claim_rewards", elapsed, e),
+    });
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    let mut report = SmokeReport::new(&args.network);
+    run(&args, &mut report).await;
+
+    let rendered = match args.format {
+        Format::Json => report.to_json(),
+        Format::Junit => Ok(report.to_junit()),
+    };
+    let written = rendered.and_then(|rendered| match &args.output {
+        Some(path) => std::fs::write(path, rendered).map_err(Error::from),
+        None => {
+            println!("{}", rendered);
+            Ok(())
+        }
+    });
+    if let Err(e) = written {
+        eprintln!("Error: {}", e);
+        std::process::exit(2);
+    }
+
+    eprintln!(
+        "{} passed, {} failed, {} skipped",
+        report.count(StepStatus::Passed),
+        report.count(StepStatus::Failed),
+        report.count(StepStatus::Skipped)
+    );
+    if !report.passed() {
+        std::process::exit(1);
+    }
+}
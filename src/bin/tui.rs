@@ -54,7 +54,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create a default configuration and client
     let config = MantraNetworkConfig::default();
-    let client = MantraDexClient::new(config.clone()).await?;
+    let client = MantraDexClient::builder(config.clone())
+        .with_saved_settings()
+        .build()
+        .await?;
 
     println!("🚀 Starting MANTRA DEX TUI...");
     println!("📁 Make sure your wallet config is at ~/.mantra-dex/wallet.toml");
@@ -0,0 +1,353 @@
+//! Contract event bridge
+//!
+//! Follows the chain block by block, picks out events emitted by the DEX contracts
+//! and forwards each one as JSON to HTTP webhooks or a NATS subject. External
+//! systems can consume DEX activity this way without running their own indexer.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cosmrs::tendermint::abci::Event;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::OwnedWriteHalf;
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+
+use crate::client::MantraDexClient;
+use crate::error::Error;
+
+/// Default delay between checks for new blocks
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A single event emitted by one of the DEX contracts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DexEvent {
+    /// Block height the transaction was included at
+    pub height: u64,
+    /// Hash of the transaction that emitted the event
+    pub tx_hash: String,
+    /// Address of the emitting contract
+    pub contract: String,
+    /// Event type, e.g. `wasm` or `wasm-swap`
+    pub event_type: String,
+    /// Value of the `action` attribute, if present
+    pub action: Option<String>,
+    /// All event attributes
+    pub attributes: BTreeMap<String, String>,
+}
+
+/// Pick the events emitted by any of `contracts` out of a transaction's events
+pub fn extract_dex_events(
+    height: u64,
+    tx_hash: &str,
+    events: &[Event],
+    contracts: &[String],
+) -> Vec<DexEvent> {
+    events
+        .iter()
+        .filter(|event| event.kind == "wasm" || event.kind.starts_with("wasm-"))
+        .filter_map(|event| {
+            let attributes: BTreeMap<String, String> = event
+                .attributes
+                .iter()
+                .filter_map(|attr| {
+                    Some((
+                        attr.key_str().ok()?.to_string(),
+                        attr.value_str().ok()?.to_string(),
+                    ))
+                })
+                .collect();
+
+            let contract = attributes.get("_contract_address")?.clone();
+            if !contracts.contains(&contract) {
+                return None;
+            }
+
+            Some(DexEvent {
+                height,
+                tx_hash: tx_hash.to_string(),
+                contract,
+                event_type: event.kind.clone(),
+                action: attributes.get("action").cloned(),
+                attributes,
+            })
+        })
+        .collect()
+}
+
+/// Where the bridge forwards events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeSink {
+    /// POST each event as JSON to an HTTP endpoint
+    Http(String),
+    /// Publish each event as JSON to a NATS subject
+    Nats {
+        /// `host:port` of the NATS server
+        address: String,
+        /// Subject to publish on
+        subject: String,
+    },
+}
+
+impl FromStr for BridgeSink {
+    type Err = Error;
+
+    /// Parse `http(s)://...` or `nats://host:port/subject`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            return Ok(BridgeSink::Http(s.to_string()));
+        }
+
+        if let Some(rest) = s.strip_prefix("nats://") {
+            return match rest.split_once('/') {
+                Some((address, subject)) if !address.is_empty() && !subject.is_empty() => {
+                    Ok(BridgeSink::Nats {
+                        address: address.to_string(),
+                        subject: subject.replace('/', "."),
+                    })
+                }
+                _ => Err(Error::Config(format!(
+                    "NATS sink must look like nats://host:port/subject, got '{}'",
+                    s
+                ))),
+            };
+        }
+
+        Err(Error::Config(format!(
+            "Unsupported sink '{}': use http(s)://... or nats://host:port/subject",
+            s
+        )))
+    }
+}
+
+/// Minimal NATS publisher speaking the plain-text client protocol
+struct NatsPublisher {
+    writer: Arc<Mutex<OwnedWriteHalf>>,
+}
+
+impl NatsPublisher {
+    async fn connect(address: &str) -> Result<Self, Error> {
+        let stream = TcpStream::connect(address)
+            .await
+            .map_err(|e| Error::Network(format!("Failed to connect to NATS {}: {}", address, e)))?;
+        let (reader, writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+
+        // The server greets with an INFO line before accepting CONNECT
+        let mut info = String::new();
+        reader.read_line(&mut info).await?;
+        if !info.starts_with("INFO") {
+            return Err(Error::Network(format!(
+                "Unexpected NATS greeting from {}: {}",
+                address,
+                info.trim()
+            )));
+        }
+
+        let writer = Arc::new(Mutex::new(writer));
+        writer
+            .lock()
+            .await
+            .write_all(b"CONNECT {\"verbose\":false,\"pedantic\":false}\r\n")
+            .await?;
+
+        // Answer server keep-alives so the connection isn't dropped
+        let ping_writer = writer.clone();
+        tokio::spawn(async move {
+            let mut line = String::new();
+            while matches!(reader.read_line(&mut line).await, Ok(n) if n > 0) {
+                if line.starts_with("PING") {
+                    let _ = ping_writer.lock().await.write_all(b"PONG\r\n").await;
+                }
+                line.clear();
+            }
+        });
+
+        Ok(Self { writer })
+    }
+
+    async fn publish(&self, subject: &str, payload: &[u8]) -> Result<(), Error> {
+        let mut writer = self.writer.lock().await;
+        writer
+            .write_all(format!("PUB {} {}\r\n", subject, payload.len()).as_bytes())
+            .await?;
+        writer.write_all(payload).await?;
+        writer.write_all(b"\r\n").await?;
+        Ok(())
+    }
+}
+
+/// Forwards DEX contract events to the configured sinks
+pub struct EventBridge {
+    client: Arc<MantraDexClient>,
+    contracts: Vec<String>,
+    sinks: Vec<BridgeSink>,
+    poll_interval: Duration,
+    http: reqwest::Client,
+    nats: Mutex<BTreeMap<String, NatsPublisher>>,
+}
+
+impl EventBridge {
+    /// Create a bridge watching every DEX contract in the client's configuration
+    pub fn new(client: Arc<MantraDexClient>, sinks: Vec<BridgeSink>) -> Self {
+        let contracts = client.config().contracts.dex_contracts();
+
+        Self {
+            client,
+            contracts,
+            sinks,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            http: reqwest::Client::new(),
+            nats: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Watch these contract addresses instead of the configured DEX contracts
+    pub fn with_contracts(mut self, contracts: Vec<String>) -> Self {
+        self.contracts = contracts;
+        self
+    }
+
+    /// Set the delay between checks for new blocks
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Contract addresses whose events are forwarded
+    pub fn contracts(&self) -> &[String] {
+        &self.contracts
+    }
+
+    /// Collect the DEX events included at `height`
+    pub async fn events_at_height(&self, height: u64) -> Result<Vec<DexEvent>, Error> {
+        let txs = self.client.get_txs_at_height(height).await?;
+        Ok(txs
+            .iter()
+            .flat_map(|tx| {
+                extract_dex_events(
+                    height,
+                    &tx.hash.to_string(),
+                    &tx.tx_result.events,
+                    &self.contracts,
+                )
+            })
+            .collect())
+    }
+
+    /// Send one event to every sink
+    ///
+    /// Every sink is attempted even if an earlier one fails; the first failure is returned.
+    pub async fn forward(&self, event: &DexEvent) -> Result<(), Error> {
+        self.deliver(&serde_json::to_vec(event)?).await
+    }
+
+    /// Send a JSON payload to every sink, as [`forward`](Self::forward) does for events
+    pub async fn deliver(&self, payload: &[u8]) -> Result<(), Error> {
+        let mut first_error = None;
+
+        for sink in &self.sinks {
+            let result = match sink {
+                BridgeSink::Http(url) => self.post(url, payload).await,
+                BridgeSink::Nats { address, subject } => {
+                    self.publish(address, subject, payload).await
+                }
+            };
+            if let Err(e) = result {
+                first_error.get_or_insert(e);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Follow the chain and forward events until an unrecoverable error occurs
+    ///
+    /// Starts at `start_height`, or at the next block if `None`. `on_error` gets
+    /// every failed block read and delivery, which do not stop the bridge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the starting height can't be read, and otherwise only
+    /// returns, with its error, once `on_error` fails
+    pub async fn run<F>(&self, start_height: Option<u64>, mut on_error: F) -> Result<(), Error>
+    where
+        F: FnMut(Error) -> Result<(), Error>,
+    {
+        let mut next_height = match start_height {
+            Some(height) => height,
+            None => self.client.get_last_block_height().await? + 1,
+        };
+
+        loop {
+            let latest = match self.client.get_last_block_height().await {
+                Ok(latest) => latest,
+                Err(e) => {
+                    on_error(e)?;
+                    tokio::time::sleep(self.poll_interval).await;
+                    continue;
+                }
+            };
+
+            while next_height <= latest {
+                let events = match self.events_at_height(next_height).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        // Retry the same height on the next poll
+                        on_error(e)?;
+                        break;
+                    }
+                };
+
+                for event in &events {
+                    if let Err(e) = self.forward(event).await {
+                        on_error(e)?;
+                    }
+                }
+                next_height += 1;
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    async fn post(&self, url: &str, payload: &[u8]) -> Result<(), Error> {
+        let response = self
+            .http
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(payload.to_vec())
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("Webhook {} failed: {}", url, e)))?;
+
+        if !response.status().is_success() {
+            return Err(Error::Network(format!(
+                "Webhook {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+
+    async fn publish(&self, address: &str, subject: &str, payload: &[u8]) -> Result<(), Error> {
+        let mut connections = self.nats.lock().await;
+
+        if let Some(publisher) = connections.get(address) {
+            if publisher.publish(subject, payload).await.is_ok() {
+                return Ok(());
+            }
+            // Drop the broken connection and retry once on a fresh one
+            connections.remove(address);
+        }
+
+        let publisher = NatsPublisher::connect(address).await?;
+        publisher.publish(subject, payload).await?;
+        connections.insert(address.to_string(), publisher);
+        Ok(())
+    }
+}
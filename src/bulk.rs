@@ -0,0 +1,391 @@
+//! Bulk liquidity operations
+//!
+//! [`plan_bulk`] turns "withdraw half of every position" or "grow every
+//! position by 10%" into a [`BulkPlan`]: one [`BulkLeg`] per pool with the LP
+//! tokens and assets it moves, and the pools left out with the reason why. The
+//! plan is meant to be reviewed before
+//! [`MantraDexClient::execute_bulk_plan`](crate::MantraDexClient::execute_bulk_plan)
+//! sends it.
+//!
+//! Legs are sent in batches of several pools per transaction. A failing batch
+//! is retried one pool at a time, so a single paused or drained pool doesn't
+//! hold back the others, and the [`BulkReport`] records what happened to each.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Pools sent per transaction by default
+pub const DEFAULT_BULK_BATCH_SIZE: usize = 5;
+
+/// Direction of a bulk operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAction {
+    /// Deposit more of each position's assets
+    Provide,
+    /// Withdraw part of each position
+    Withdraw,
+}
+
+impl fmt::Display for BulkAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BulkAction::Provide => write!(f, "provide"),
+            BulkAction::Withdraw => write!(f, "withdraw"),
+        }
+    }
+}
+
+impl FromStr for BulkAction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "provide" => Ok(BulkAction::Provide),
+            "withdraw" => Ok(BulkAction::Withdraw),
+            _ => Err(Error::Config(format!(
+                "Unknown bulk action '{}', expected provide or withdraw",
+                s
+            ))),
+        }
+    }
+}
+
+/// One pool's part of a bulk operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkLeg {
+    pub pool_id: String,
+    pub lp_denom: String,
+    /// LP tokens held before the operation
+    pub lp_balance: Uint128,
+    /// LP tokens withdrawn, zero when providing
+    pub lp_amount: Uint128,
+    /// Assets deposited when providing, or expected back when withdrawing
+    pub assets: Vec<Coin>,
+}
+
+/// A pool left out of a bulk operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedPool {
+    pub pool_id: String,
+    pub reason: String,
+}
+
+/// Bulk operation to review before executing it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkPlan {
+    pub action: BulkAction,
+    /// Share of each position withdrawn, or added when providing
+    pub share: Decimal,
+    pub legs: Vec<BulkLeg>,
+    pub skipped: Vec<SkippedPool>,
+    /// Maximum slippage of each deposit, unused when withdrawing
+    pub max_slippage: Option<Decimal>,
+}
+
+impl BulkPlan {
+    /// Set the maximum slippage of each deposit
+    pub fn with_max_slippage(mut self, max_slippage: Decimal) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    /// Number of transactions the legs are sent in, before any retry
+    pub fn transactions(&self, batch_size: usize) -> usize {
+        self.legs.len().div_ceil(batch_size.max(1))
+    }
+
+    /// Assets deposited or expected back across all legs, per denom
+    pub fn total_assets(&self) -> Vec<Coin> {
+        sum_assets(self.legs.iter())
+    }
+}
+
+/// Plan moving `share` of each of `positions` in or out of its pool
+///
+/// Each position is a pool and the LP tokens held in it; its underlying assets
+/// are its share of the pool's reserves. Withdrawing takes `share` of the LP
+/// tokens. Providing deposits `share` of the underlying assets, paid from
+/// `balances` in order: a pool the remaining balances can't pay for is skipped,
+/// as are pools without a position and pools with the operation disabled.
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if `share` is zero, or above one when withdrawing
+pub fn plan_bulk(
+    action: BulkAction,
+    share: Decimal,
+    positions: &[(PoolInfoResponse, Uint128)],
+    balances: &[Coin],
+) -> Result<BulkPlan, Error> {
+    if share.is_zero() || (action == BulkAction::Withdraw && share > Decimal::one()) {
+        return Err(Error::Config(format!(
+            "Share to {} must be above 0{}, got {}",
+            action,
+            if action == BulkAction::Withdraw {
+                " and at most 1"
+            } else {
+                ""
+            },
+            share
+        )));
+    }
+
+    let mut remaining: BTreeMap<&str, Uint128> = balances
+        .iter()
+        .map(|coin| (coin.denom.as_str(), coin.amount))
+        .collect();
+    let mut plan = BulkPlan {
+        action,
+        share,
+        legs: Vec::new(),
+        skipped: Vec::new(),
+        max_slippage: None,
+    };
+
+    for (pool, lp_balance) in positions {
+        let info = &pool.pool_info;
+        let skip = |reason: String| SkippedPool {
+            pool_id: info.pool_identifier.clone(),
+            reason,
+        };
+        let total_share = pool.total_share.amount;
+        if lp_balance.is_zero() || total_share.is_zero() {
+            plan.skipped.push(skip("No position".to_string()));
+            continue;
+        }
+        let enabled = match action {
+            BulkAction::Provide => info.status.deposits_enabled,
+            BulkAction::Withdraw => info.status.withdrawals_enabled,
+        };
+        if !enabled {
+            plan.skipped
+                .push(skip(format!("Pool has {} disabled", action_noun(action))));
+            continue;
+        }
+
+        let lp_amount = match action {
+            BulkAction::Provide => Uint128::zero(),
+            BulkAction::Withdraw => lp_balance.mul_floor(share),
+        };
+        let assets: Vec<Coin> = info
+            .assets
+            .iter()
+            .map(|reserve| Coin {
+                denom: reserve.denom.clone(),
+                amount: match action {
+                    BulkAction::Provide => reserve
+                        .amount
+                        .multiply_ratio(*lp_balance, total_share)
+                        .mul_floor(share),
+                    BulkAction::Withdraw => reserve.amount.multiply_ratio(lp_amount, total_share),
+                },
+            })
+            .collect();
+        if assets.iter().all(|coin| coin.amount.is_zero()) {
+            plan.skipped
+                .push(skip("Position too small to split".to_string()));
+            continue;
+        }
+
+        if action == BulkAction::Provide {
+            let short = assets.iter().find_map(|coin| {
+                let available = remaining
+                    .get(coin.denom.as_str())
+                    .copied()
+                    .unwrap_or_default();
+                (available < coin.amount).then(|| {
+                    format!(
+                        "Insufficient {}: needs {}, {} left",
+                        coin.denom, coin.amount, available
+                    )
+                })
+            });
+            if let Some(reason) = short {
+                plan.skipped.push(skip(reason));
+                continue;
+            }
+            for coin in &assets {
+                if let Some(available) = remaining.get_mut(coin.denom.as_str()) {
+                    *available -= coin.amount;
+                }
+            }
+        }
+
+        plan.legs.push(BulkLeg {
+            pool_id: info.pool_identifier.clone(),
+            lp_denom: info.lp_denom.clone(),
+            lp_balance: *lp_balance,
+            lp_amount,
+            assets,
+        });
+    }
+
+    Ok(plan)
+}
+
+fn action_noun(action: BulkAction) -> &'static str {
+    match action {
+        BulkAction::Provide => "deposits",
+        BulkAction::Withdraw => "withdrawals",
+    }
+}
+
+/// What happened to one leg of an executed plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BulkOutcome {
+    Executed { txhash: String, height: i64 },
+    Failed { error: String },
+}
+
+/// A leg of an executed plan and its outcome
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkLegResult {
+    pub leg: BulkLeg,
+    pub outcome: BulkOutcome,
+}
+
+impl BulkLegResult {
+    pub fn is_executed(&self) -> bool {
+        matches!(self.outcome, BulkOutcome::Executed { .. })
+    }
+}
+
+/// Summary of an executed bulk plan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkReport {
+    pub action: BulkAction,
+    pub share: Decimal,
+    pub results: Vec<BulkLegResult>,
+    /// Pools the plan left out
+    pub skipped: Vec<SkippedPool>,
+}
+
+impl BulkReport {
+    /// Number of legs executed
+    pub fn executed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.is_executed())
+            .count()
+    }
+
+    /// Number of legs that failed
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.executed()
+    }
+
+    /// Assets deposited or expected back by the executed legs, per denom
+    pub fn total_assets(&self) -> Vec<Coin> {
+        sum_assets(
+            self.results
+                .iter()
+                .filter(|result| result.is_executed())
+                .map(|result| &result.leg),
+        )
+    }
+}
+
+impl fmt::Display for BulkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verb = match self.action {
+            BulkAction::Provide => "Provided",
+            BulkAction::Withdraw => "Withdrew",
+        };
+        write!(
+            f,
+            "{} {}% in {} of {} pools",
+            verb,
+            self.share * Decimal::percent(10_000),
+            self.executed(),
+            self.results.len()
+        )?;
+        if self.failed() > 0 {
+            write!(f, ", {} failed", self.failed())?;
+        }
+        if !self.skipped.is_empty() {
+            write!(f, ", {} skipped", self.skipped.len())?;
+        }
+        let total: Vec<String> = self
+            .total_assets()
+            .iter()
+            .map(|coin| coin.to_string())
+            .collect();
+        if !total.is_empty() {
+            write!(f, ": {}", total.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Send `legs` in batches of `batch_size` with `send`, isolating failures
+///
+/// A batch that fails is sent again one leg at a time, so only the legs that
+/// fail on their own are reported failed.
+pub async fn execute_isolated<F, Fut>(
+    legs: &[BulkLeg],
+    batch_size: usize,
+    mut send: F,
+) -> Vec<BulkLegResult>
+where
+    F: FnMut(Vec<BulkLeg>) -> Fut,
+    Fut: Future<Output = Result<TxResponse, Error>>,
+{
+    let executed = |leg: &BulkLeg, tx: &TxResponse| BulkLegResult {
+        leg: leg.clone(),
+        outcome: BulkOutcome::Executed {
+            txhash: tx.txhash.clone(),
+            height: tx.height,
+        },
+    };
+
+    let mut results = Vec::with_capacity(legs.len());
+    for batch in legs.chunks(batch_size.max(1)) {
+        match send(batch.to_vec()).await {
+            Ok(tx) => results.extend(batch.iter().map(|leg| executed(leg, &tx))),
+            Err(e) if batch.len() == 1 => results.push(BulkLegResult {
+                leg: batch[0].clone(),
+                outcome: BulkOutcome::Failed {
+                    error: e.to_string(),
+                },
+            }),
+            Err(_) => {
+                for leg in batch {
+                    let outcome = match send(vec![leg.clone()]).await {
+                        Ok(tx) => executed(leg, &tx).outcome,
+                        Err(e) => BulkOutcome::Failed {
+                            error: e.to_string(),
+                        },
+                    };
+                    results.push(BulkLegResult {
+                        leg: leg.clone(),
+                        outcome,
+                    });
+                }
+            }
+        }
+    }
+    results
+}
+
+fn sum_assets<'a>(legs: impl Iterator<Item = &'a BulkLeg>) -> Vec<Coin> {
+    let mut totals: BTreeMap<String, Uint128> = BTreeMap::new();
+    for coin in legs.flat_map(|leg| &leg.assets) {
+        *totals.entry(coin.denom.clone()).or_default() += coin.amount;
+    }
+    totals
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin { denom, amount })
+        .collect()
+}
@@ -0,0 +1,169 @@
+//! Canonical JSON for signing and hashing
+//!
+//! JSON that ends up signed or hashed must serialize to the same bytes on every
+//! platform and in every release, whatever the field order of the struct or map
+//! it came from. [`to_vec`] writes it in the JSON Canonicalization Scheme of
+//! RFC 8785: object members sorted by the UTF-16 code units of their names, no
+//! whitespace, strings escaped only where JSON requires it and numbers written
+//! as ECMAScript does (`1e+21`, `0.000001`, `5e-324`).
+//!
+//! Integers that fit an `i64` or `u64` are written exactly rather than rounded
+//! to a double first; amounts beyond 2^53 are strings in CosmWasm messages
+//! anyway, so both agree on every payload the SDK builds.
+
+use std::cmp::Ordering;
+use std::fmt::Write as _;
+
+use cosmrs::tendermint::crypto::{default::Sha256, Sha256 as _};
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+use crate::error::Error;
+
+/// Canonical JSON bytes of `value`
+///
+/// # Errors
+///
+/// Returns an error if `value` doesn't serialize to JSON, e.g. a map with
+/// non-string keys
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Error> {
+    to_string(value).map(String::into_bytes)
+}
+
+/// Canonical JSON text of `value`
+///
+/// # Errors
+///
+/// Returns an error if `value` doesn't serialize to JSON
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    Ok(canonicalize(&serde_json::to_value(value)?))
+}
+
+/// Canonical JSON text of an already parsed `value`
+pub fn canonicalize(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+/// SHA-256 of the canonical JSON bytes of `value`
+///
+/// # Errors
+///
+/// Returns an error if `value` doesn't serialize to JSON
+pub fn digest<T: Serialize + ?Sized>(value: &T) -> Result<[u8; 32], Error> {
+    Ok(Sha256::digest(to_vec(value)?))
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(fields) => {
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_by(|(a, _), (b, _)| utf16_cmp(a, b));
+            out.push('{');
+            for (i, (name, field)) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(name, out);
+                out.push(':');
+                write_value(field, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Order of member names: UTF-16 code units, which differs from the order of
+/// `str` for characters beyond U+FFFF
+fn utf16_cmp(a: &str, b: &str) -> Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_number(n: &Number, out: &mut String) {
+    if let Some(i) = n.as_i64() {
+        let _ = write!(out, "{}", i);
+    } else if let Some(u) = n.as_u64() {
+        let _ = write!(out, "{}", u);
+    } else if let Some(f) = n.as_f64() {
+        write_f64(f, out);
+    }
+}
+
+/// ECMAScript's `Number.prototype.toString` of a finite double
+fn write_f64(f: f64, out: &mut String) {
+    if f == 0.0 {
+        // Negative zero too
+        out.push('0');
+        return;
+    }
+    if f < 0.0 {
+        out.push('-');
+    }
+    // Shortest digits reading back as the same double, ties to even like
+    // ECMAScript; ryu writes them as `1.5e-7`, `1e21` or `123.45`
+    let mut buffer = ryu::Buffer::new();
+    let formatted = buffer.format_finite(f.abs());
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((formatted, "0"));
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exponent: i32 = exponent.parse().expect("ryu writes an integer exponent");
+    let all = format!("{}{}", whole, fraction);
+    let significant = all.trim_start_matches('0');
+    let digits = significant.trim_end_matches('0');
+    let k = digits.len() as i32;
+    // The value is 0.<digits> times 10^n
+    let n = whole.len() as i32 + exponent - (all.len() - significant.len()) as i32;
+
+    if k <= n && n <= 21 {
+        out.push_str(digits);
+        out.extend(std::iter::repeat_n('0', (n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        let (whole, fraction) = digits.split_at(n as usize);
+        let _ = write!(out, "{}.{}", whole, fraction);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat_n('0', (-n) as usize));
+        out.push_str(digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        out.push_str(first);
+        if !rest.is_empty() {
+            let _ = write!(out, ".{}", rest);
+        }
+        let _ = write!(out, "e{}{}", if n > 0 { '+' } else { '-' }, (n - 1).abs());
+    }
+}
@@ -0,0 +1,341 @@
+//! Circuit breaker for RPC endpoints
+//!
+//! After a number of consecutive failures an endpoint's circuit opens and requests
+//! are routed to the next endpoint instead of waiting on one that is down. Once the
+//! cool-down has passed the endpoint is tried again; a success closes the circuit,
+//! a failure opens it for another cool-down.
+//!
+//! Among the endpoints whose circuit allows it, the breaker prefers the one with
+//! the lowest measured latency, scaled by its configured weight (see
+//! [`EndpointSelectionConfig`]). Until an endpoint has been measured it is tried
+//! after the measured ones, by weight and then in configured order. An endpoint
+//! whose block height lags more than
+//! [`max_block_lag`](EndpointSelectionConfig::max_block_lag) behind the highest
+//! measured one is only used once every endpoint that keeps up is failing.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::config::{CircuitBreakerConfig, EndpointSelectionConfig};
+use crate::error::Error;
+
+/// Number of state changes buffered for slow subscribers
+const EVENT_CAPACITY: usize = 64;
+
+/// Weight given to the newest latency sample in the moving average
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+/// State of one endpoint's circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests go to the endpoint
+    Closed,
+    /// The endpoint is skipped until its cool-down has passed
+    Open,
+    /// The cool-down has passed and the next request decides the state
+    HalfOpen,
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Emitted whenever an endpoint's circuit changes state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitEvent {
+    /// URL of the endpoint
+    pub endpoint: String,
+    /// State before the change
+    pub from: CircuitState,
+    /// State after the change
+    pub to: CircuitState,
+}
+
+/// Snapshot of one endpoint's health
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointHealth {
+    /// URL of the endpoint
+    pub endpoint: String,
+    /// Current circuit state
+    pub state: CircuitState,
+    /// Failures since the last success
+    pub consecutive_failures: u32,
+    /// Moving average of the measured latency, once measured
+    pub latency_ms: Option<u64>,
+    /// Region the endpoint is hinted to be in
+    pub region: Option<String>,
+    /// Latest block height the endpoint reported, once measured
+    #[serde(default)]
+    pub block_height: Option<u64>,
+    /// Whether the endpoint lags too far behind the highest measured block height
+    #[serde(default)]
+    pub lagging: bool,
+    /// Whether the endpoint is the one requests currently go to
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug)]
+struct EndpointCircuit {
+    endpoint: String,
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    latency: Option<Duration>,
+    weight: f64,
+    region: Option<String>,
+    block_height: Option<u64>,
+}
+
+impl EndpointCircuit {
+    /// Latency scaled by weight; lower is preferred
+    fn score(&self) -> Option<f64> {
+        self.latency
+            .map(|latency| latency.as_secs_f64() / self.weight)
+    }
+}
+
+/// Tracks the health of a list of endpoints and picks the one to use
+///
+/// Endpoints are tried in order, so the first one is preferred whenever its circuit
+/// allows it.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    latency_aware: bool,
+    max_block_lag: u64,
+    circuits: Mutex<Vec<EndpointCircuit>>,
+    events: broadcast::Sender<CircuitEvent>,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker for `endpoints`, all starting closed
+    pub fn new(endpoints: Vec<String>, config: CircuitBreakerConfig) -> Self {
+        let circuits = endpoints
+            .into_iter()
+            .map(|endpoint| EndpointCircuit {
+                endpoint,
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                latency: None,
+                weight: 1.0,
+                region: None,
+                block_height: None,
+            })
+            .collect();
+
+        Self {
+            config,
+            latency_aware: false,
+            max_block_lag: EndpointSelectionConfig::default().max_block_lag,
+            circuits: Mutex::new(circuits),
+            events: broadcast::channel(EVENT_CAPACITY).0,
+        }
+    }
+
+    /// Prefer endpoints by latency, weights and region hints as configured
+    pub fn with_selection(mut self, selection: &EndpointSelectionConfig) -> Self {
+        self.latency_aware = selection.latency_aware;
+        self.max_block_lag = selection.max_block_lag;
+        for circuit in self.lock().iter_mut() {
+            circuit.weight = selection.weight(&circuit.endpoint);
+            circuit.region = selection
+                .hint(&circuit.endpoint)
+                .and_then(|hint| hint.region.clone());
+        }
+        self
+    }
+
+    /// Receive an event for every state change from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<CircuitEvent> {
+        self.events.subscribe()
+    }
+
+    /// Current state of every endpoint, in configured order
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let circuits = self.lock();
+        let lagging = self.lagging(&circuits);
+        let active = self.pick(&circuits);
+        circuits
+            .iter()
+            .enumerate()
+            .map(|(index, circuit)| EndpointHealth {
+                endpoint: circuit.endpoint.clone(),
+                state: circuit.state,
+                consecutive_failures: circuit.consecutive_failures,
+                latency_ms: circuit
+                    .latency
+                    .map(|latency| latency.as_millis().try_into().unwrap_or(u64::MAX)),
+                region: circuit.region.clone(),
+                block_height: circuit.block_height,
+                lagging: lagging[index],
+                active: active == Some(index),
+            })
+            .collect()
+    }
+
+    /// Index of the endpoint the next request would use, if any is available
+    pub fn active(&self) -> Option<usize> {
+        self.pick(&self.lock())
+    }
+
+    /// Indices of the endpoints in the order they are tried
+    pub fn preference_order(&self) -> Vec<usize> {
+        let circuits = self.lock();
+        let lagging = self.lagging(&circuits);
+        Self::order(&circuits, &lagging, self.latency_aware)
+    }
+
+    fn order(circuits: &[EndpointCircuit], lagging: &[bool], latency_aware: bool) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..circuits.len()).collect();
+        // A stable sort keeps the configured order between equals
+        order.sort_by(|&a, &b| {
+            let by_lag = lagging[a].cmp(&lagging[b]);
+            let (a, b) = (&circuits[a], &circuits[b]);
+            let by_latency = match (latency_aware, a.score(), b.score()) {
+                (true, Some(a), Some(b)) => a.total_cmp(&b),
+                (true, Some(_), None) => Ordering::Less,
+                (true, None, Some(_)) => Ordering::Greater,
+                _ => Ordering::Equal,
+            };
+            by_lag.then(by_latency).then(b.weight.total_cmp(&a.weight))
+        });
+        order
+    }
+
+    /// Whether each endpoint lags too far behind the highest measured block height
+    fn lagging(&self, circuits: &[EndpointCircuit]) -> Vec<bool> {
+        let highest = circuits
+            .iter()
+            .filter_map(|circuit| circuit.block_height)
+            .max();
+        circuits
+            .iter()
+            .map(|circuit| match (highest, circuit.block_height) {
+                (Some(highest), Some(height)) => highest - height > self.max_block_lag,
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// The endpoint [`acquire`](Self::acquire) would pick, without changing any state
+    fn pick(&self, circuits: &[EndpointCircuit]) -> Option<usize> {
+        let lagging = self.lagging(circuits);
+        Self::order(circuits, &lagging, self.latency_aware)
+            .into_iter()
+            .find(|&index| self.available(&circuits[index]))
+    }
+
+    /// Whether a request may go to the endpoint now
+    fn available(&self, circuit: &EndpointCircuit) -> bool {
+        match circuit.state {
+            CircuitState::Closed | CircuitState::HalfOpen => true,
+            CircuitState::Open => circuit
+                .opened_at
+                .is_none_or(|opened| opened.elapsed() >= self.config.cooldown()),
+        }
+    }
+
+    /// Record the latest block height the endpoint reported
+    pub fn record_block_height(&self, index: usize, height: u64) {
+        if let Some(circuit) = self.lock().get_mut(index) {
+            circuit.block_height = Some(height);
+        }
+    }
+
+    /// Record how long a request to the endpoint took
+    pub fn record_latency(&self, index: usize, latency: Duration) {
+        let mut circuits = self.lock();
+        if let Some(circuit) = circuits.get_mut(index) {
+            circuit.latency = Some(match circuit.latency {
+                Some(average) => {
+                    average.mul_f64(1.0 - LATENCY_SMOOTHING) + latency.mul_f64(LATENCY_SMOOTHING)
+                }
+                None => latency,
+            });
+        }
+    }
+
+    /// Pick the endpoint the next request should use
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Network`] if every endpoint's circuit is open
+    pub fn acquire(&self) -> Result<usize, Error> {
+        let mut circuits = self.lock();
+
+        if let Some(index) = self.pick(&circuits) {
+            let circuit = &mut circuits[index];
+            if circuit.state == CircuitState::Open {
+                self.transition(circuit, CircuitState::HalfOpen);
+            }
+            return Ok(index);
+        }
+
+        Err(Error::Network(format!(
+            "All {} RPC endpoint(s) are failing; retrying after the {}s cool-down",
+            circuits.len(),
+            self.config.cooldown_secs
+        )))
+    }
+
+    /// Record a successful request, closing the endpoint's circuit
+    pub fn record_success(&self, index: usize) {
+        let mut circuits = self.lock();
+        if let Some(circuit) = circuits.get_mut(index) {
+            circuit.consecutive_failures = 0;
+            circuit.opened_at = None;
+            self.transition(circuit, CircuitState::Closed);
+        }
+    }
+
+    /// Record a failed request, opening the circuit once the threshold is reached
+    pub fn record_failure(&self, index: usize) {
+        let mut circuits = self.lock();
+        if let Some(circuit) = circuits.get_mut(index) {
+            circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+
+            let trips = circuit.state == CircuitState::HalfOpen
+                || circuit.consecutive_failures >= self.config.failure_threshold.max(1);
+            if trips {
+                circuit.opened_at = Some(Instant::now());
+                self.transition(circuit, CircuitState::Open);
+            }
+        }
+    }
+
+    fn transition(&self, circuit: &mut EndpointCircuit, to: CircuitState) {
+        if circuit.state == to {
+            return;
+        }
+
+        let event = CircuitEvent {
+            endpoint: circuit.endpoint.clone(),
+            from: circuit.state,
+            to,
+        };
+        circuit.state = to;
+        // Nobody listening is fine
+        let _ = self.events.send(event);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<EndpointCircuit>> {
+        self.circuits
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
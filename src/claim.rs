@@ -0,0 +1,148 @@
+//! Reward claims split by gas
+//!
+//! The farm manager pays out every farm of every position in a single `claim`,
+//! up to an epoch. A wallet that hasn't claimed for a while, across many pools,
+//! can need more gas than one transaction may use. A chunked claim sends it in
+//! several transactions instead, each claiming up to a later epoch:
+//! [`chunk_end`] finds the furthest epoch whose claim fits the gas budget by
+//! simulating claims from the chain's current state, and
+//! [`MantraDexClient::claim_rewards_chunked`](crate::MantraDexClient::claim_rewards_chunked)
+//! sends the chunks one after another and sums what they paid in a
+//! [`ClaimReport`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::diagnose::{Diagnosis, FailureCause};
+use crate::error::Error;
+
+/// Simulated gas a chunk may use by default, leaving room for the gas
+/// adjustment under the client's largest gas limit
+pub const DEFAULT_CLAIM_GAS_BUDGET: u64 = 1_500_000;
+
+/// One transaction of a chunked claim
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimChunk {
+    /// Last epoch claimed by the transaction
+    pub until_epoch: u64,
+    /// Simulated gas of the claim
+    pub gas: u64,
+    /// Rewards the claim pays out
+    pub rewards: Vec<Coin>,
+    pub txhash: String,
+    pub height: i64,
+}
+
+/// Summary of a chunked claim
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ClaimReport {
+    /// Chunks sent, in order
+    pub chunks: Vec<ClaimChunk>,
+    /// Why claiming stopped before the current epoch, if it did
+    pub error: Option<String>,
+}
+
+impl ClaimReport {
+    /// Rewards paid out by all chunks, per denom
+    pub fn total_claimed(&self) -> Vec<Coin> {
+        let mut totals: BTreeMap<String, Uint128> = BTreeMap::new();
+        for coin in self.chunks.iter().flat_map(|chunk| &chunk.rewards) {
+            *totals.entry(coin.denom.clone()).or_default() += coin.amount;
+        }
+        totals
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(denom, amount)| Coin { denom, amount })
+            .collect()
+    }
+
+    /// Simulated gas of all chunks
+    pub fn total_gas(&self) -> u64 {
+        self.chunks.iter().map(|chunk| chunk.gas).sum()
+    }
+}
+
+impl fmt::Display for ClaimReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total: Vec<String> = self
+            .total_claimed()
+            .iter()
+            .map(|coin| coin.to_string())
+            .collect();
+        if total.is_empty() {
+            write!(f, "No rewards claimed")?;
+        } else {
+            write!(
+                f,
+                "Claimed {} in {} transaction{}",
+                total.join(", "),
+                self.chunks.len(),
+                if self.chunks.len() == 1 { "" } else { "s" }
+            )?;
+        }
+        if let Some(error) = &self.error {
+            write!(f, ", then stopped: {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// The furthest epoch from `from_epoch` to `to_epoch` whose claim fits `max_gas`
+///
+/// `simulate` returns the gas of a claim up to an epoch. The whole range is
+/// tried first, then the end is found by bisection; a simulation that runs out
+/// of gas counts as too large. Returns the epoch with its simulated gas.
+///
+/// # Errors
+///
+/// * Returns [`Error::Tx`] if claiming `from_epoch` alone exceeds `max_gas`
+/// * Returns the simulation's error if it fails for another reason
+pub async fn chunk_end<F, Fut>(
+    from_epoch: u64,
+    to_epoch: u64,
+    max_gas: u64,
+    mut simulate: F,
+) -> Result<(u64, u64), Error>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<u64, Error>>,
+{
+    if let Some(gas) = fits(&mut simulate, to_epoch, max_gas).await? {
+        return Ok((to_epoch, gas));
+    }
+    let (mut low, mut high) = (from_epoch, to_epoch);
+    let mut best = None;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match fits(&mut simulate, mid, max_gas).await? {
+            Some(gas) => {
+                best = Some((mid, gas));
+                low = mid + 1;
+            }
+            None => high = mid,
+        }
+    }
+    best.ok_or_else(|| {
+        Error::Tx(format!(
+            "Claiming the rewards of epoch {} alone needs more than {} gas",
+            from_epoch, max_gas
+        ))
+    })
+}
+
+/// The gas of a claim up to `epoch` if it fits `max_gas`
+async fn fits<F, Fut>(simulate: &mut F, epoch: u64, max_gas: u64) -> Result<Option<u64>, Error>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<u64, Error>>,
+{
+    match simulate(epoch).await {
+        Ok(gas) => Ok((gas <= max_gas).then_some(gas)),
+        Err(e) if Diagnosis::from_error(&e).cause == FailureCause::OutOfGas => Ok(None),
+        Err(e) => Err(e),
+    }
+}
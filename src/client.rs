@@ -1,35 +1,109 @@
+use std::future::Future;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use base64::{engine::general_purpose, Engine};
 use chrono;
 use cosmos_sdk_proto::{
     cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
-    cosmos::bank::v1beta1::{QueryAllBalancesRequest, QueryAllBalancesResponse},
-    cosmwasm::wasm::v1::QuerySmartContractStateResponse,
+    cosmos::bank::v1beta1::{
+        Metadata, QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest,
+        QueryBalanceResponse, QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+        QueryDenomOwnersRequest, QueryDenomOwnersResponse,
+    },
+    cosmos::base::abci::v1beta1::Result as AbciResult,
+    cosmos::base::query::v1beta1::PageRequest,
+    cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse, TxRaw},
+    cosmwasm::wasm::v1::{
+        QueryRawContractStateRequest, QueryRawContractStateResponse,
+        QuerySmartContractStateResponse,
+    },
 };
 use cosmrs::{
     proto::{
         cosmos::base::{abci::v1beta1::TxResponse, v1beta1::Coin as CosmosCoin},
         cosmwasm::wasm::v1::{MsgExecuteContract, QuerySmartContractStateRequest},
+        tendermint::abci::{Event, EventAttribute},
     },
-    rpc::{Client as RpcClient, HttpClient},
-    tendermint::{chain::Id, Hash},
-    tx::{Body, MessageExt, SignDoc, SignerInfo},
-    Any,
+    rpc::{
+        endpoint::{broadcast::tx_sync::Response as SyncTxResponse, tx::Response as TxInfo},
+        query::Query,
+        Client as RpcClient, HttpClient, Order,
+    },
+    tendermint::crypto::{default::Sha256, Sha256 as _},
+    tendermint::Hash,
+    crypto::PublicKey,
+    tx::{AuthInfo, Body, Fee, MessageExt, Raw},
+    Any, Denom,
 };
-use cosmwasm_std::{Coin, Decimal, Uint128};
+use cosmwasm_std::{Coin, Decimal, Timestamp, Uint128};
+use futures::future;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use hex;
+use mantra_dex_std::epoch_manager::{self, EpochConfig};
+use mantra_dex_std::farm_manager::{
+    self, Farm, FarmsBy, FarmsResponse, Position, PositionsBy, PositionsResponse, RewardsResponse,
+};
 use mantra_dex_std::pool_manager::{
     self, PoolInfoResponse, PoolsResponse, SimulationResponse, SwapOperation,
 };
 use prost::Message;
 use serde::de::DeserializeOwned;
-use tokio::sync::Mutex;
-
-use crate::config::MantraNetworkConfig;
-use crate::error::Error;
-use crate::wallet::MantraWallet;
+use tokio::sync::broadcast;
+
+use crate::analytics::{self, PairExecution, SwapFill};
+use crate::batch::{BatchOp, TxBatchBuilder};
+use crate::bridge::extract_dex_events;
+use crate::bulk::{self, BulkAction, BulkLeg, BulkPlan, BulkReport, SkippedPool};
+use crate::canonical;
+use crate::circuit_breaker::{CircuitBreaker, EndpointHealth};
+use crate::claim::{self, ClaimChunk, ClaimReport};
+use crate::compare::{self, PoolComparison, PoolRanking, PoolTvl};
+use crate::config::{
+    AssetFilterConfig, BroadcastRetryConfig, Config, IndexerConfig, MantraNetworkConfig,
+    NetworkConstants, OperationClass, Priority, PriorityFeeConfig, RoutingConfig,
+};
+use crate::congestion::{self, Congestion, InclusionLatencies};
+use crate::contract_schema::{self, ContractVersion, CONTRACT_INFO_KEY};
+use crate::convert::{Conversion, PriceBook};
+use crate::diagnose::Diagnosis;
+use crate::earn::{self, EarnOpportunity, YieldContext};
+use crate::epoch::EpochCalendar;
+use crate::error::{Error, ErrorClass};
+use crate::gas::{self, GasEstimate, GasHistory, GasSample};
+use crate::history::{HistoryEntry, HistoryFilter, TxHistory};
+use crate::indexer::{self, Indexer};
+use crate::math;
+use crate::middleware::{AuditLog, Middleware, ProgressReporter, TradePolicy, TxContext};
+use crate::ownership::{
+    creator_from_events, DenomHolder, LpConcentration, PoolOwnership, TOP_LP_HOLDERS,
+};
+use crate::pagination::{paginate, PageCursor, Paginated};
+use crate::paper::{PaperPortfolio, PaperTrade, PaperValuation};
+use crate::policy::{SlippageBumpPolicy, TradeSizeGuard, TradeValueLimit, DEFAULT_SWAP_SLIPPAGE};
+use crate::portfolio::PortfolioValuation;
+use crate::positions::{
+    self, FarmPosition, LiquidityChange, LpBalance, LpLock, LpPosition, WithdrawalPreview,
+};
+use crate::pricing::{self, PriceImpact};
+use crate::quote::{FeeBreakdown, Quote, QuoteRequest};
+use crate::route_cache::{RouteCache, RouteCacheConfig, RouteKey};
+use crate::router::{self, AssetGraph, SwapRoute};
+use crate::risk::{concentration_flags, DenomMetadata, DenomProbe, RiskReport, RiskThresholds};
+use crate::routing::{
+    self, DepthCurve, PoolCurve, PoolQuote, PoolSelection, RouteChoice, SimulationSweep,
+    SplitPlan, SweepSource,
+};
+use crate::simulation;
+use crate::storage;
+use crate::subscriptions::{self, EventSubscriptions, SubscriptionFilter};
+use crate::token_registry::{
+    self, IbcTrace, QueryDenomTraceRequest, QueryDenomTraceResponse, TokenMetadata, TokenRegistry,
+};
+use crate::tx::{SignMode, TxBuilder, UnsignedTx};
+use crate::wallet::{MantraWallet, MultisigAccount, MultisigTx, WalletDefaults, ACCOUNT_PREFIX};
 
 /// Pool status enum for validation
 #[derive(Debug, Clone, PartialEq)]
@@ -51,22 +125,562 @@ impl PoolStatus {
     }
 }
 
+/// What an operation needs from the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// Only reads chain state; works without a wallet
+    ReadOnly,
+    /// Signs and broadcasts a transaction; needs a wallet
+    Signing,
+}
+
+/// Result of an idempotent pool creation
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum CreatePoolOutcome {
+    /// A new pool was created
+    Created(TxResponse),
+    /// A pool with the same assets, type and fees already exists; nothing was created
+    PoolAlreadyExists {
+        /// Identifier of the existing pool
+        pool_id: String,
+    },
+}
+
+/// Page size used when walking the full pool list
+const POOL_PAGE_LIMIT: u32 = 100;
+
+/// Page size used when walking an address's farm positions
+const POSITION_PAGE_LIMIT: u32 = 50;
+
+/// Page size used when walking transaction search results
+const TX_PAGE_LIMIT: u8 = 100;
+
+/// Largest gas limit of any transaction sent by the client
+const TX_GAS_LIMIT: u64 = 2_000_000;
+
+/// Delay between checks for a broadcast transaction's inclusion
+const INCLUSION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Number of transaction progress updates buffered for slow subscribers
+const TX_PROGRESS_CAPACITY: usize = 64;
+
+/// Identifier of the next transaction sent by any client in the process
+static NEXT_TX_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Default number of simulations run concurrently by [`MantraDexClient::simulate_many`]
+pub const DEFAULT_SIMULATION_CONCURRENCY: usize = 8;
+
+/// A candidate swap to simulate
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapRequest {
+    /// The identifier of the pool to swap in
+    pub pool_id: String,
+    /// The asset being offered for swap
+    pub offer_asset: Coin,
+    /// The denomination of the asset being requested
+    pub ask_asset_denom: String,
+    /// Maximum slippage tolerance, if not the wallet or pool manager default
+    pub max_slippage: Option<Decimal>,
+}
+
+impl SwapRequest {
+    /// Create a new swap request
+    pub fn new(
+        pool_id: impl Into<String>,
+        offer_asset: Coin,
+        ask_asset_denom: impl Into<String>,
+    ) -> Self {
+        Self {
+            pool_id: pool_id.into(),
+            offer_asset,
+            ask_asset_denom: ask_asset_denom.into(),
+            max_slippage: None,
+        }
+    }
+
+    /// Set the maximum slippage tolerance of the swap
+    pub fn with_max_slippage(mut self, max_slippage: Decimal) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+}
+
+/// A swap executed after retrying with a higher slippage tolerance
+#[derive(Debug, Clone)]
+pub struct SwapRetry {
+    /// The swap as executed, with the tolerance that succeeded
+    pub request: SwapRequest,
+    /// Tolerances of the attempts that failed on slippage, oldest first
+    pub failed_slippages: Vec<Decimal>,
+    /// Transaction response of the executed swap
+    pub tx: TxResponse,
+}
+
+/// Stage a transaction has reached in the client's signing pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TxStage {
+    /// Messages, fee and signer info are assembled
+    Built,
+    /// The unsigned transaction executed successfully in a chain simulation
+    Simulated,
+    /// The wallet signed the transaction
+    Signed,
+    /// The node accepted the transaction into its mempool
+    Broadcast,
+    /// The transaction was included in a block
+    Included,
+}
+
+impl TxStage {
+    /// Every stage, in pipeline order
+    pub const ALL: [TxStage; 5] = [
+        TxStage::Built,
+        TxStage::Simulated,
+        TxStage::Signed,
+        TxStage::Broadcast,
+        TxStage::Included,
+    ];
+
+    /// Position of the stage in the pipeline, starting at 1
+    pub fn step(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// Share of the pipeline completed once the stage is reached, from 0.2 to 1.0
+    pub fn fraction(&self) -> f32 {
+        self.step() as f32 / Self::ALL.len() as f32
+    }
+
+    /// Short human-readable description of the stage
+    pub fn description(&self) -> &'static str {
+        match self {
+            TxStage::Built => "Transaction built",
+            TxStage::Simulated => "Simulation passed",
+            TxStage::Signed => "Transaction signed",
+            TxStage::Broadcast => "Broadcast, waiting for inclusion",
+            TxStage::Included => "Included in a block",
+        }
+    }
+}
+
+/// Emitted each time a transaction reaches a new [`TxStage`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxProgress {
+    /// Identifies the transaction; unique within the process
+    pub id: u64,
+    /// Stage just reached
+    pub stage: TxStage,
+    /// Hash of the transaction, known once it has been broadcast
+    pub tx_hash: Option<String>,
+}
+
+/// Outcome of one attempt at sending a transaction
+enum Submission {
+    /// Response of a simulate-only client, which doesn't sign
+    Simulated(Box<TxResponse>),
+    /// The node accepted the transaction into its mempool; carries the simulated gas
+    Accepted(SyncTxResponse, u64),
+}
+
 /// Mantra DEX client for interacting with the network
 ///
 /// This client provides methods to interact with the Mantra DEX v3.0.0,
 /// including pool operations, swapping, liquidity provision, and rewards management.
 #[derive(Debug)]
 pub struct MantraDexClient {
-    /// RPC client for the Mantra network
-    rpc_client: Arc<Mutex<HttpClient>>,
+    /// RPC clients for the primary endpoint followed by the fallbacks
+    rpc_clients: Vec<HttpClient>,
+    /// Health of each RPC endpoint, used to route around failing ones
+    circuit_breaker: CircuitBreaker,
     /// Network configuration
     config: MantraNetworkConfig,
-    /// Wallet for signing transactions
-    wallet: Option<MantraWallet>,
+    /// Wallet for signing transactions, shared with the clients reconnected from this one
+    wallet: Option<Arc<MantraWallet>>,
+    /// Guard against swaps that take too large a share of a pool
+    trade_size_guard: TradeSizeGuard,
+    /// Highest value a swap may offer, if limited
+    trade_value_limit: Option<TradeValueLimit>,
+    /// Stablecoins amounts are valued in US dollars through, in order of preference
+    usd_denoms: Vec<String>,
+    /// Defaults of the active wallet
+    wallet_defaults: WalletDefaults,
+    /// Reports the progress of transactions through the signing pipeline
+    progress: ProgressReporter,
+    /// Record of every transaction sent, if kept
+    audit_log: Option<Arc<AuditLog>>,
+    /// Hooks registered on top of the built-in middleware, in order
+    middleware: Vec<Arc<dyn Middleware>>,
+    /// Gas used by past transactions, used to size gas limits
+    gas_history: Arc<GasHistory>,
+    /// Time recent transactions took to be included, used to detect congestion
+    inclusion_latencies: Arc<InclusionLatencies>,
+    /// Denoms and pools hidden from listings, balances and routing
+    asset_filter: AssetFilterConfig,
+    /// Pools excluded from and pinned for automatic routing
+    routing: RoutingConfig,
+    /// Routes selected for recent swaps, if cached
+    route_cache: Option<Arc<RouteCache>>,
+    /// External source of historical data, instead of the node's transaction index
+    indexer: Option<Arc<dyn Indexer>>,
+    /// Whether transactions are only simulated, never signed or broadcast
+    simulate_only: bool,
+    /// Symbols and decimals of the denoms resolved so far
+    token_registry: Arc<TokenRegistry>,
+}
+
+/// Builds a [`MantraDexClient`] from a network configuration and optional settings
+///
+/// Every frontend builds its clients through [`with_saved_settings`](Self::with_saved_settings),
+/// so the CLI, TUI and MCP server hide the same assets, route and value trades the
+/// same way and calibrate gas limits from the same history.
+#[derive(Debug)]
+pub struct MantraDexClientBuilder {
+    config: MantraNetworkConfig,
+    wallet: Option<Arc<MantraWallet>>,
+    trade_size_guard: TradeSizeGuard,
+    trade_value_limit: Option<TradeValueLimit>,
+    usd_denoms: Vec<String>,
+    wallet_defaults: WalletDefaults,
+    tx_progress: Option<broadcast::Sender<TxProgress>>,
+    audit_log: Option<Arc<AuditLog>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    gas_history: Option<Arc<GasHistory>>,
+    asset_filter: AssetFilterConfig,
+    routing: RoutingConfig,
+    route_cache: Option<Arc<RouteCache>>,
+    indexer: Option<Arc<dyn Indexer>>,
+    /// Indexer settings resolved into `indexer` when the client is built
+    indexer_config: Option<IndexerConfig>,
+    simulate_only: bool,
+    token_registry: Option<Arc<TokenRegistry>>,
+}
+
+impl MantraDexClientBuilder {
+    /// Start building a client for the network described by `config`
+    pub fn new(config: MantraNetworkConfig) -> Self {
+        Self {
+            config,
+            wallet: None,
+            trade_size_guard: TradeSizeGuard::default(),
+            trade_value_limit: None,
+            usd_denoms: Vec::new(),
+            wallet_defaults: WalletDefaults::default(),
+            tx_progress: None,
+            audit_log: None,
+            middleware: Vec::new(),
+            gas_history: None,
+            asset_filter: AssetFilterConfig::default(),
+            routing: RoutingConfig::default(),
+            route_cache: None,
+            indexer: None,
+            indexer_config: None,
+            simulate_only: false,
+            token_registry: None,
+        }
+    }
+
+    /// Apply the asset filter, routing, route cache, indexer and valuation settings of `saved`
+    ///
+    /// Settings set after this call take precedence.
+    pub fn with_settings(mut self, saved: &Config) -> Self {
+        self.asset_filter = saved.asset_filter.clone();
+        self.routing = saved.routing.clone();
+        self.route_cache = saved
+            .routing
+            .route_cache
+            .map(|config| Arc::new(RouteCache::new(config)));
+        self.indexer = None;
+        self.indexer_config = Some(saved.indexer.clone());
+        self.usd_denoms = saved.pricing.usd_denoms.clone();
+        self.trade_value_limit = saved.pricing.max_trade_value.clone();
+        self
+    }
+
+    /// Apply the settings of the saved configuration, calibrate gas limits from
+    /// the saved gas history and keep resolved tokens in the saved token registry
+    ///
+    /// Reads [`Config::default_path`], and the gas history and token registry from
+    /// the [shared storage](crate::storage::shared), falling back to defaults for a
+    /// missing or unreadable document.
+    pub fn with_saved_settings(self) -> Self {
+        let saved = Config::load(&Config::default_path()).unwrap_or_default();
+        let gas_history = storage::shared()
+            .and_then(GasHistory::open_in)
+            .unwrap_or_default();
+        let token_registry = storage::shared()
+            .and_then(TokenRegistry::open_in)
+            .unwrap_or_default();
+        self.with_settings(&saved)
+            .with_gas_history(Arc::new(gas_history))
+            .with_token_registry(Arc::new(token_registry))
+    }
+
+    /// Replace the network configuration the builder started from
+    pub fn with_network_config(mut self, config: MantraNetworkConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Connect to `rpc_url` instead of the configured primary RPC endpoint
+    ///
+    /// The fallback endpoints of the configuration are kept.
+    pub fn with_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.config.rpc_url = rpc_url.into();
+        self
+    }
+
+    /// Sign transactions with `wallet`
+    ///
+    /// A watch-only wallet makes the client read-only, like no wallet at all.
+    pub fn with_wallet(mut self, wallet: MantraWallet) -> Self {
+        self.wallet = Some(Arc::new(wallet));
+        self
+    }
+
+    /// Apply the defaults of the active wallet
+    ///
+    /// The slippage is used by swaps that don't specify one, the gas price and
+    /// memo by every transaction, and the preferred pools by automatic pool
+    /// selection. Explicit arguments always take precedence.
+    pub fn with_wallet_defaults(mut self, defaults: WalletDefaults) -> Self {
+        self.wallet_defaults = defaults;
+        self
+    }
+
+    /// Set the trade size guard enforced by the swap convenience methods,
+    /// or [`TradeSizeGuard::disabled`]
+    pub fn with_trade_size_guard(mut self, guard: TradeSizeGuard) -> Self {
+        self.trade_size_guard = guard;
+        self
+    }
+
+    /// Set the highest value the swap convenience methods may offer, `None` for no limit
+    ///
+    /// Offers are valued with [`MantraDexClient::convert_amount`], so a limit in
+    /// [`USD`](crate::convert::USD) needs the USD stablecoins set with
+    /// [`with_usd_denoms`](Self::with_usd_denoms).
+    pub fn with_trade_value_limit(mut self, limit: Option<TradeValueLimit>) -> Self {
+        self.trade_value_limit = limit;
+        self
+    }
+
+    /// Set the stablecoins amounts are valued in US dollars through, in order of preference
+    pub fn with_usd_denoms(mut self, usd_denoms: Vec<String>) -> Self {
+        self.usd_denoms = usd_denoms;
+        self
+    }
+
+    /// Report transaction progress on `sender` instead of the client's own channel
+    ///
+    /// Lets several clients feed the same subscribers, e.g. when a client is
+    /// created per request.
+    pub fn with_tx_progress(mut self, sender: broadcast::Sender<TxProgress>) -> Self {
+        self.tx_progress = Some(sender);
+        self
+    }
+
+    /// Run `middleware` for every transaction the client sends
+    ///
+    /// Middleware runs in the order it is registered, after the client's
+    /// built-in progress reporting, trade policies and audit log; see
+    /// [`crate::middleware`].
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Record every transaction the client sends in `log`, which several clients may share
+    pub fn with_audit_log(mut self, log: Arc<AuditLog>) -> Self {
+        self.audit_log = Some(log);
+        self
+    }
+
+    /// Record gas usage in, and size gas limits from, `history`
+    ///
+    /// Lets several clients, or several runs via [`GasHistory::open`], calibrate
+    /// from the same transactions.
+    pub fn with_gas_history(mut self, history: Arc<GasHistory>) -> Self {
+        self.gas_history = Some(history);
+        self
+    }
+
+    /// Hide denoms and pools from pool listings, balances and routing
+    ///
+    /// Hidden pools are left out of [`MantraDexClient::get_pools`] and
+    /// [`MantraDexClient::pools_stream`], so also out of automatic pool
+    /// selection; querying or trading them directly fails with [`Error::Policy`].
+    pub fn with_asset_filter(mut self, filter: AssetFilterConfig) -> Self {
+        self.asset_filter = filter;
+        self
+    }
+
+    /// Exclude pools from, and pin pools for, automatic pool selection
+    ///
+    /// Excluded pools are skipped by [`MantraDexClient::select_route`] and
+    /// split plans but stay listed and tradable directly, unlike pools hidden
+    /// by the [asset filter](Self::with_asset_filter).
+    pub fn with_routing(mut self, routing: RoutingConfig) -> Self {
+        self.routing = routing;
+        self
+    }
+
+    /// Cache the routes [`MantraDexClient::select_route`] picks
+    ///
+    /// Later swaps of the same pair and size bucket only query and simulate the
+    /// cached pool, until its reserves move beyond the configured threshold; see
+    /// [`crate::route_cache`].
+    pub fn with_route_cache(mut self, config: RouteCacheConfig) -> Self {
+        self.route_cache = Some(Arc::new(RouteCache::new(config)));
+        self
+    }
+
+    /// Cache the routes [`MantraDexClient::select_route`] picks in `cache`, which
+    /// several clients may share
+    pub fn with_shared_route_cache(mut self, cache: Arc<RouteCache>) -> Self {
+        self.route_cache = Some(cache);
+        self
+    }
+
+    /// Read historical data from an external indexer, `None` for the node's transaction index
+    ///
+    /// Swap history, candles, volumes and transaction history then come from
+    /// `indexer`; see [`crate::indexer`].
+    pub fn with_indexer(mut self, indexer: Option<Arc<dyn Indexer>>) -> Self {
+        self.indexer = indexer;
+        self.indexer_config = None;
+        self
+    }
+
+    /// Only simulate transactions instead of signing and broadcasting them
+    ///
+    /// Every method that sends a transaction then returns the simulated response,
+    /// see [`crate::simulation`]. The wallet is still needed for its address and
+    /// public key.
+    pub fn with_simulate_only(mut self, simulate_only: bool) -> Self {
+        self.simulate_only = simulate_only;
+        self
+    }
+
+    /// Keep the tokens [`MantraDexClient::resolve_tokens`] resolves in `registry`,
+    /// which several clients may share
+    pub fn with_token_registry(mut self, registry: Arc<TokenRegistry>) -> Self {
+        self.token_registry = Some(registry);
+        self
+    }
+
+    /// Set how transactions rejected for a transient reason are retried;
+    /// `max_retries` of 0 disables retrying
+    pub fn with_broadcast_retry(mut self, retry: BroadcastRetryConfig) -> Self {
+        self.config.broadcast_retry = retry;
+        self
+    }
+
+    /// Set when transactions pay a higher gas price to be included sooner
+    pub fn with_priority_fee(mut self, priority_fee: PriorityFeeConfig) -> Self {
+        self.config.priority_fee = priority_fee;
+        self
+    }
+
+    /// Set the priority of the transactions sent, keeping the other priority fee settings
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.config.priority_fee.priority = priority;
+        self
+    }
+
+    /// Set the multiplier applied to simulated gas to size gas limits, e.g. 1.5
+    ///
+    /// Applies to operation kinds without enough gas history to calibrate their
+    /// own adjustment, see [`GasHistory::gas_limit`].
+    pub fn with_gas_adjustment(mut self, gas_adjustment: f64) -> Self {
+        self.config.gas_adjustment = gas_adjustment;
+        self
+    }
+
+    /// Pay fees in `fee_denom` instead of the native denom
+    pub fn with_fee_denom(mut self, fee_denom: impl Into<String>) -> Self {
+        self.config.fee_denom = Some(fee_denom.into());
+        self
+    }
+
+    /// Create the client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an RPC client or the configured indexer cannot be created
+    pub async fn build(self) -> Result<MantraDexClient, Error> {
+        let endpoints = self.config.rpc_endpoints();
+        let rpc_clients = endpoints
+            .iter()
+            .map(|url| {
+                HttpClient::new(url.as_str())
+                    .map_err(|e| Error::Rpc(format!("Failed to create RPC client: {}", e)))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let indexer = match &self.indexer_config {
+            Some(config) => indexer::from_config(config)?,
+            None => self.indexer,
+        };
+        let tx_progress = self
+            .tx_progress
+            .unwrap_or_else(|| broadcast::channel(TX_PROGRESS_CAPACITY).0);
+
+        Ok(MantraDexClient {
+            rpc_clients,
+            circuit_breaker: CircuitBreaker::new(endpoints, self.config.circuit_breaker)
+                .with_selection(&self.config.endpoint_selection),
+            config: self.config,
+            wallet: self.wallet,
+            trade_size_guard: self.trade_size_guard,
+            trade_value_limit: self.trade_value_limit,
+            usd_denoms: self.usd_denoms,
+            wallet_defaults: self.wallet_defaults,
+            progress: ProgressReporter::new(tx_progress),
+            audit_log: self.audit_log,
+            middleware: self.middleware,
+            gas_history: self.gas_history.unwrap_or_default(),
+            inclusion_latencies: Arc::new(InclusionLatencies::default()),
+            asset_filter: self.asset_filter,
+            routing: self.routing,
+            route_cache: self.route_cache,
+            indexer,
+            simulate_only: self.simulate_only,
+            token_registry: self.token_registry.unwrap_or_default(),
+        })
+    }
 }
 
 impl MantraDexClient {
-    /// Create a new client with the given configuration
+    /// Start building a client for the network described by `config`
+    ///
+    /// See [`MantraDexClientBuilder`] for the available settings.
+    pub fn builder(config: MantraNetworkConfig) -> MantraDexClientBuilder {
+        MantraDexClientBuilder::new(config)
+    }
+
+    /// Start building a client for the named `network` with the saved settings,
+    /// connecting to `rpc_url` instead of the network's primary endpoint if given
+    ///
+    /// The CLI commands and the smoke test build their clients from this.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the network's constants can't be loaded
+    pub fn connect(
+        network: &str,
+        rpc_url: Option<String>,
+    ) -> Result<MantraDexClientBuilder, Error> {
+        let constants =
+            NetworkConstants::load(network).map_err(|e| Error::Config(e.to_string()))?;
+        let builder =
+            Self::builder(MantraNetworkConfig::from_constants(&constants)?).with_saved_settings();
+        Ok(match rpc_url {
+            Some(rpc_url) => builder.with_rpc_url(rpc_url),
+            None => builder,
+        })
+    }
+
+    /// Create a new client with the given configuration and default settings
     ///
     /// # Arguments
     ///
@@ -80,14 +694,50 @@ impl MantraDexClient {
     ///
     /// Returns an error if the RPC client cannot be created
     pub async fn new(config: MantraNetworkConfig) -> Result<Self, Error> {
-        let rpc_client = HttpClient::new(config.rpc_url.as_str())
-            .map_err(|e| Error::Rpc(format!("Failed to create RPC client: {}", e)))?;
+        Self::builder(config).build().await
+    }
 
-        Ok(Self {
-            rpc_client: Arc::new(Mutex::new(rpc_client)),
-            config,
+    /// Start building a client with the settings of this one, but no wallet
+    ///
+    /// The new client shares this one's progress channel, audit log, middleware,
+    /// gas history, indexer and token registry; its route cache starts empty.
+    pub fn to_builder(&self) -> MantraDexClientBuilder {
+        MantraDexClientBuilder {
+            config: self.config.clone(),
             wallet: None,
-        })
+            trade_size_guard: self.trade_size_guard,
+            trade_value_limit: self.trade_value_limit.clone(),
+            usd_denoms: self.usd_denoms.clone(),
+            wallet_defaults: self.wallet_defaults.clone(),
+            tx_progress: Some(self.progress.sender()),
+            audit_log: self.audit_log.clone(),
+            middleware: self.middleware.clone(),
+            gas_history: Some(Arc::clone(&self.gas_history)),
+            asset_filter: self.asset_filter.clone(),
+            routing: self.routing.clone(),
+            route_cache: self
+                .route_cache
+                .as_ref()
+                .map(|cache| Arc::new(RouteCache::new(*cache.config()))),
+            indexer: self.indexer.clone(),
+            indexer_config: None,
+            simulate_only: self.simulate_only,
+            token_registry: Some(Arc::clone(&self.token_registry)),
+        }
+    }
+
+    /// Build a client for `config` with the settings and wallet of this one
+    ///
+    /// Used when network settings such as the RPC endpoint change; the wallet is
+    /// shared with this client rather than copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client can't be built for `config`
+    pub async fn reconnect(&self, config: MantraNetworkConfig) -> Result<Self, Error> {
+        let mut builder = self.to_builder().with_network_config(config);
+        builder.wallet = self.wallet.clone();
+        builder.build().await
     }
 
     /// Set the wallet for signing transactions
@@ -100,14 +750,124 @@ impl MantraDexClient {
     ///
     /// The client instance with the wallet configured
     pub fn with_wallet(mut self, wallet: MantraWallet) -> Self {
-        self.wallet = Some(wallet);
+        self.wallet = Some(Arc::new(wallet));
         self
     }
 
+    /// Get the trade size guard enforced by the swap convenience methods
+    pub fn trade_size_guard(&self) -> &TradeSizeGuard {
+        &self.trade_size_guard
+    }
+
+    /// Get the highest value the swap convenience methods may offer, if limited
+    pub fn trade_value_limit(&self) -> Option<&TradeValueLimit> {
+        self.trade_value_limit.as_ref()
+    }
+
+    /// Get the stablecoins amounts are valued in US dollars through
+    pub fn usd_denoms(&self) -> &[String] {
+        &self.usd_denoms
+    }
+
+    /// Get the defaults of the active wallet
+    pub fn wallet_defaults(&self) -> &WalletDefaults {
+        &self.wallet_defaults
+    }
+
+    /// Receive an update for every stage reached by transactions from now on
+    ///
+    /// Updates are only sent while a transaction is in flight, so a subscriber can
+    /// drive a progress indicator from [`TxStage::fraction`].
+    pub fn subscribe_tx_progress(&self) -> broadcast::Receiver<TxProgress> {
+        self.progress.subscribe()
+    }
+
+    /// Built-in middleware followed by the registered middleware, in order
+    fn middleware(&self) -> impl Iterator<Item = &dyn Middleware> {
+        let builtin: [&dyn Middleware; 2] = [&self.progress, &TradePolicy];
+        builtin
+            .into_iter()
+            .chain(self.audit_log.as_deref().map(|log| log as &dyn Middleware))
+            .chain(self.middleware.iter().map(|middleware| middleware.as_ref()))
+    }
+
+    /// Get the gas history used to size gas limits
+    pub fn gas_history(&self) -> &Arc<GasHistory> {
+        &self.gas_history
+    }
+
+    /// Get the denoms and pools hidden from listings, balances and routing
+    pub fn asset_filter(&self) -> &AssetFilterConfig {
+        &self.asset_filter
+    }
+
+    /// Get the pools excluded from and pinned for automatic routing
+    pub fn routing(&self) -> &RoutingConfig {
+        &self.routing
+    }
+
+    /// Get the route cache, if routes are cached
+    ///
+    /// Run [`watch_route_cache`](Self::watch_route_cache) to notice pools
+    /// moving between swaps.
+    pub fn route_cache(&self) -> Option<&RouteCache> {
+        self.route_cache.as_deref()
+    }
+
+    /// Get the external indexer historical data is read from, if any
+    pub fn external_indexer(&self) -> Option<&Arc<dyn Indexer>> {
+        self.indexer.as_ref()
+    }
+
+    /// Get the source of historical data: the external indexer, or this client
+    /// reading the node's transaction index
+    pub fn indexer(&self) -> &dyn Indexer {
+        match &self.indexer {
+            Some(indexer) => indexer.as_ref(),
+            None => self,
+        }
+    }
+
+    /// Whether transactions are only simulated, never signed or broadcast
+    pub fn is_simulate_only(&self) -> bool {
+        self.simulate_only
+    }
+
+    /// Get the symbols and decimals of the denoms resolved so far
+    pub fn token_registry(&self) -> &Arc<TokenRegistry> {
+        &self.token_registry
+    }
+
+    /// Get the circuit breaker tracking the health of the RPC endpoints
+    ///
+    /// Subscribe to it to be notified when an endpoint starts or stops failing.
+    pub fn circuit_breaker(&self) -> &CircuitBreaker {
+        &self.circuit_breaker
+    }
+
+    /// Whether the client can only run queries because no wallet is configured,
+    /// or the configured wallet is watch-only
+    pub fn is_read_only(&self) -> bool {
+        self.wallet.as_deref().is_none_or(MantraWallet::is_watch_only)
+    }
+
+    /// Check the client can perform an operation with the given access
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] for [`Access::Signing`] when no wallet is configured
+    /// or the wallet is watch-only
+    pub fn require(&self, access: Access) -> Result<(), Error> {
+        match access {
+            Access::ReadOnly => Ok(()),
+            Access::Signing => self.wallet()?.signing_key().map(|_| ()),
+        }
+    }
+
     /// Get the wallet if available
     pub fn wallet(&self) -> Result<&MantraWallet, Error> {
         self.wallet
-            .as_ref()
+            .as_deref()
             .ok_or_else(|| Error::Wallet("No wallet configured".to_string()))
     }
 
@@ -140,45 +900,290 @@ impl MantraDexClient {
 
     /// Get last block height
     pub async fn get_last_block_height(&self) -> Result<u64, Error> {
-        let rpc_client = self.rpc_client.lock().await;
-        let height = rpc_client
-            .latest_block()
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to get last block height: {}", e)))?;
+        let height = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .latest_block()
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get last block height: {}", e)))
+            })
+            .await?;
         Ok(height.block.header.height.value() as u64)
     }
 
-    /// Get the Wallet balances
-    pub async fn get_balances(&self) -> Result<Vec<Coin>, Error> {
-        let wallet = self.wallet()?;
-        let address = wallet.address().unwrap().to_string();
-        self.get_balances_for_address(&address).await
+    /// Get the time of a block, in seconds since the Unix epoch
+    pub async fn get_block_timestamp(&self, height: u64) -> Result<u64, Error> {
+        let block = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .block(height as u32)
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get block {}: {}", height, e)))
+            })
+            .await?;
+        Ok(block.block.header.time.unix_timestamp().max(0) as u64)
     }
 
-    /// Get balances for a specific address
-    pub async fn get_balances_for_address(&self, address: &str) -> Result<Vec<Coin>, Error> {
-        let rpc_client = self.rpc_client.lock().await;
+    /// Get every transaction included at a block height
+    pub async fn get_txs_at_height(&self, height: u64) -> Result<Vec<TxInfo>, Error> {
+        self.txs_stream(Query::eq("tx.height", height), TX_PAGE_LIMIT)
+            .try_collect()
+            .await
+    }
 
-        // Create a request to get all balances
-        let request = QueryAllBalancesRequest {
-            address: address.to_string(),
-            pagination: None,
-            resolve_denom: false,
-        };
+    /// Stream the transactions matching a Tendermint event query
+    ///
+    /// Pages of `page_size` transactions are fetched lazily, oldest first, as the
+    /// stream is consumed.
+    pub fn txs_stream(
+        &self,
+        query: Query,
+        page_size: u8,
+    ) -> BoxStream<'_, Result<TxInfo, Error>> {
+        let page_size = page_size.max(1);
+        paginate(1u32, move |page| {
+            let query = query.clone();
+            async move {
+                let response = self
+                    .rpc_call(OperationClass::Query, |rpc_client| async move {
+                        rpc_client
+                            .tx_search(query.clone(), false, page, page_size, Order::Ascending)
+                            .await
+                            .map_err(|e| {
+                                Error::Rpc(format!("Failed to search txs ({}): {}", query, e))
+                            })
+                    })
+                    .await?;
 
-        // Encode the request to protobuf
-        let encoded_request = request.encode_to_vec();
+                let seen = (page as u64 - 1) * page_size as u64 + response.txs.len() as u64;
+                let next = (response.txs.len() == page_size as usize
+                    && seen < response.total_count as u64)
+                    .then_some(page + 1);
+                Ok((response.txs, next))
+            }
+        })
+    }
 
-        // Execute the query
-        let response = rpc_client
-            .abci_query(
-                Some("/cosmos.bank.v1beta1.Query/AllBalances".to_string()),
-                encoded_request,
-                None,
-                false,
+    /// Stream new blocks and the transactions `filter` selects over the node's WebSocket
+    ///
+    /// The WebSocket endpoint is derived from the RPC URL, see
+    /// [`subscriptions::websocket_url`]. Events arrive on the broadcast channel
+    /// of [`EventSubscriptions::subscribe`] until the connection drops.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Network`] if the connection or a subscription fails
+    pub async fn subscribe_events(
+        &self,
+        filter: &SubscriptionFilter,
+    ) -> Result<EventSubscriptions, Error> {
+        let url = subscriptions::websocket_url(&self.config.rpc_url)?;
+        EventSubscriptions::connect(&url, filter).await
+    }
+
+    /// Stream the farm reward claims made by an address, oldest first
+    pub fn rewards_history_stream(
+        &self,
+        address: &str,
+        page_size: u8,
+    ) -> BoxStream<'_, Result<TxInfo, Error>> {
+        let farm_manager = match &self.config.contracts.farm_manager {
+            Some(farm_manager) => farm_manager.clone(),
+            None => {
+                return stream::once(async {
+                    Err(Error::Other(
+                        "Farm manager contract address not configured".to_string(),
+                    ))
+                })
+                .boxed()
+            }
+        };
+
+        let query = Query::eq("message.sender", address)
+            .and_eq("wasm._contract_address", farm_manager)
+            .and_eq("wasm.action", "claim");
+        self.txs_stream(query, page_size)
+    }
+
+    /// Stream the swaps made by an address through the pool manager, oldest first
+    ///
+    /// `from_height` and `to_height` bound the period, inclusive.
+    pub fn swap_history_stream(
+        &self,
+        address: &str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        page_size: u8,
+    ) -> BoxStream<'_, Result<SwapFill, Error>> {
+        let mut query = Query::eq("message.sender", address)
+            .and_eq(
+                "wasm._contract_address",
+                self.config.contracts.pool_manager.as_str(),
             )
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to get balances: {}", e)))?;
+            .and_eq("wasm.action", "swap");
+        if let Some(from_height) = from_height {
+            query = query.and_gte("tx.height", from_height);
+        }
+        if let Some(to_height) = to_height {
+            query = query.and_lte("tx.height", to_height);
+        }
+
+        self.swap_fills_stream(query, page_size)
+    }
+
+    /// Stream the swaps made through a pool, oldest first
+    ///
+    /// `from_height` and `to_height` bound the period, inclusive. Swaps of a
+    /// multi-hop route only count their hop through this pool.
+    pub fn pool_swaps_stream(
+        &self,
+        pool_id: &str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+        page_size: u8,
+    ) -> BoxStream<'_, Result<SwapFill, Error>> {
+        let mut query = Query::eq(
+            "wasm._contract_address",
+            self.config.contracts.pool_manager.as_str(),
+        )
+        .and_eq("wasm.action", "swap")
+        .and_eq("wasm.pool_identifier", pool_id);
+        if let Some(from_height) = from_height {
+            query = query.and_gte("tx.height", from_height);
+        }
+        if let Some(to_height) = to_height {
+            query = query.and_lte("tx.height", to_height);
+        }
+
+        let pool_id = pool_id.to_string();
+        self.swap_fills_stream(query, page_size)
+            .try_filter(move |fill| {
+                future::ready(fill.pool_id.as_deref() == Some(pool_id.as_str()))
+            })
+            .boxed()
+    }
+
+    /// Stream the pool manager swaps in the transactions matching `query`
+    fn swap_fills_stream(
+        &self,
+        query: Query,
+        page_size: u8,
+    ) -> BoxStream<'_, Result<SwapFill, Error>> {
+        let contracts = vec![self.config.contracts.pool_manager.clone()];
+        self.txs_stream(query, page_size)
+            .map_ok(move |tx| {
+                let fills: Vec<Result<SwapFill, Error>> = extract_dex_events(
+                    tx.height.value(),
+                    &tx.hash.to_string(),
+                    &tx.tx_result.events,
+                    &contracts,
+                )
+                .iter()
+                .filter_map(SwapFill::from_event)
+                .map(Ok)
+                .collect();
+                stream::iter(fills)
+            })
+            .try_flatten()
+            .boxed()
+    }
+
+    /// Average execution price, volume and fees per pair for an address's swaps
+    ///
+    /// `from_height` and `to_height` bound the period, inclusive. Swaps are read
+    /// through [`indexer`](Self::indexer).
+    pub async fn execution_report(
+        &self,
+        address: &str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> Result<Vec<PairExecution>, Error> {
+        let fills = self
+            .indexer()
+            .address_swaps(address, from_height, to_height)
+            .await?;
+        Ok(analytics::execution_by_pair(&fills))
+    }
+
+    /// Bring the stored transaction history of `address` up to the latest block
+    ///
+    /// Only blocks after the history's synced height are read, through
+    /// [`indexer`](Self::indexer). Each new transaction is stamped with the time
+    /// of its block; transactions whose block time can't be read are stored
+    /// without one.
+    ///
+    /// # Returns
+    ///
+    /// Number of transactions added to the history
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the latest block height or the transactions can't be queried
+    /// * Returns error if the history can't be saved
+    pub async fn sync_history(&self, history: &TxHistory, address: &str) -> Result<usize, Error> {
+        let latest = self.get_last_block_height().await?;
+        let from_height = history.synced_height(address) + 1;
+        if from_height > latest {
+            return Ok(0);
+        }
+
+        let records = self
+            .indexer()
+            .address_txs(address, Some(from_height), Some(latest))
+            .await?;
+        let mut block_times = std::collections::HashMap::new();
+        let mut entries = Vec::with_capacity(records.len());
+        for record in records {
+            let timestamp = match block_times.get(&record.height) {
+                Some(timestamp) => *timestamp,
+                None => {
+                    let timestamp = self
+                        .get_block_timestamp(record.height)
+                        .await
+                        .ok()
+                        .and_then(|secs| chrono::DateTime::from_timestamp(secs as i64, 0));
+                    block_times.insert(record.height, timestamp);
+                    timestamp
+                }
+            };
+            entries.push(HistoryEntry::from_record(record, timestamp));
+        }
+        history.record(address, entries, latest)
+    }
+
+    /// Get the Wallet balances
+    pub async fn get_balances(&self) -> Result<Vec<Coin>, Error> {
+        let wallet = self.wallet()?;
+        let address = wallet.address().unwrap().to_string();
+        self.get_balances_for_address(&address).await
+    }
+
+    /// Get balances for a specific address
+    pub async fn get_balances_for_address(&self, address: &str) -> Result<Vec<Coin>, Error> {
+        // Create a request to get all balances
+        let request = QueryAllBalancesRequest {
+            address: address.to_string(),
+            pagination: None,
+            resolve_denom: false,
+        };
+
+        // Encode the request to protobuf
+        let encoded_request = request.encode_to_vec();
+
+        // Execute the query
+        let response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmos.bank.v1beta1.Query/AllBalances".to_string()),
+                        encoded_request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get balances: {}", e)))
+            })
+            .await?;
 
         if !response.code.is_ok() {
             return Err(Error::Rpc(format!("Query failed: {}", response.log)));
@@ -188,10 +1193,11 @@ impl MantraDexClient {
         let balances_response = QueryAllBalancesResponse::decode(response.value.as_slice())
             .map_err(|e| Error::Rpc(format!("Failed to decode balances response: {}", e)))?;
 
-        // Convert from cosmos proto coins to cosmwasm coins
+        // Convert from cosmos proto coins to cosmwasm coins, hiding filtered denoms
         let balances = balances_response
             .balances
             .into_iter()
+            .filter(|coin| self.asset_filter.allows_denom(&coin.denom))
             .map(|coin| Coin {
                 denom: coin.denom,
                 amount: Uint128::from_str(&coin.amount).unwrap_or_default(),
@@ -201,24 +1207,460 @@ impl MantraDexClient {
         Ok(balances)
     }
 
+    /// Get the balance of one denom held by an address
+    ///
+    /// Unlike [`get_balances_for_address`](Self::get_balances_for_address) this is
+    /// not subject to the asset filter.
+    pub async fn get_balance_for_address(&self, address: &str, denom: &str) -> Result<Coin, Error> {
+        let encoded_request = QueryBalanceRequest {
+            address: address.to_string(),
+            denom: denom.to_string(),
+        }
+        .encode_to_vec();
+
+        let response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmos.bank.v1beta1.Query/Balance".to_string()),
+                        encoded_request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get balance: {}", e)))
+            })
+            .await?;
+
+        if !response.code.is_ok() {
+            return Err(Error::Rpc(format!("Query failed: {}", response.log)));
+        }
+
+        let balance_response = QueryBalanceResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode balance response: {}", e)))?;
+        let amount = balance_response
+            .balance
+            .map(|coin| Uint128::from_str(&coin.amount).unwrap_or_default())
+            .unwrap_or_default();
+        Ok(Coin {
+            denom: denom.to_string(),
+            amount,
+        })
+    }
+
     /// Get the network configuration
     pub fn config(&self) -> &MantraNetworkConfig {
         &self.config
     }
 
+    /// Whether a pool passes the configured asset filter
+    ///
+    /// Pools that don't are left out of pool listings and routing.
+    pub fn is_pool_allowed(&self, pool: &PoolInfoResponse) -> bool {
+        self.asset_filter.allows_pool(
+            &pool.pool_info.pool_identifier,
+            &pool.pool_info.asset_denoms,
+        )
+    }
+
+    /// Check denoms about to be traded against the configured asset filter
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] for the first denom the filter hides
+    pub fn check_denoms_allowed(&self, denoms: &[&str]) -> Result<(), Error> {
+        match denoms
+            .iter()
+            .find(|denom| !self.asset_filter.allows_denom(denom))
+        {
+            Some(denom) => Err(Error::Policy(format!(
+                "Denom {} is hidden by the asset filter",
+                denom
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the bank metadata of a denom, `None` if it has none
+    pub async fn get_denom_metadata(&self, denom: &str) -> Result<Option<Metadata>, Error> {
+        let encoded_request = QueryDenomMetadataRequest {
+            denom: denom.to_string(),
+        }
+        .encode_to_vec();
+
+        let response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmos.bank.v1beta1.Query/DenomMetadata".to_string()),
+                        encoded_request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get denom metadata: {}", e)))
+            })
+            .await?;
+
+        // The bank module answers "not found" with an error code
+        if !response.code.is_ok() {
+            return Ok(None);
+        }
+
+        let metadata_response = QueryDenomMetadataResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode denom metadata: {}", e)))?;
+        Ok(metadata_response.metadata)
+    }
+
+    /// Get the origin of an IBC voucher from the transfer module's denom trace,
+    /// `None` if the chain has no trace for it
+    ///
+    /// # Arguments
+    ///
+    /// * `denom` - The voucher, `ibc/` followed by the hash of its trace
+    pub async fn get_ibc_trace(&self, denom: &str) -> Result<Option<IbcTrace>, Error> {
+        let Some(hash) = denom.strip_prefix(token_registry::IBC_PREFIX) else {
+            return Ok(None);
+        };
+        let encoded_request = QueryDenomTraceRequest {
+            hash: hash.to_string(),
+        }
+        .encode_to_vec();
+
+        let response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/ibc.applications.transfer.v1.Query/DenomTrace".to_string()),
+                        encoded_request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get denom trace: {}", e)))
+            })
+            .await?;
+
+        // The transfer module answers "not found" with an error code
+        if !response.code.is_ok() {
+            return Ok(None);
+        }
+
+        let trace_response = QueryDenomTraceResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode denom trace: {}", e)))?;
+        Ok(trace_response.denom_trace.map(|trace| IbcTrace {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }))
+    }
+
+    /// Resolve the symbols and decimals of `denoms` not in the token registry yet
+    ///
+    /// Each denom is looked up in the bank module's denom metadata first; IBC
+    /// vouchers without metadata are traced to their base denom, and decimals of
+    /// other denoms are read from a pool trading them. Resolved tokens are added
+    /// to the [token registry](Self::token_registry).
+    ///
+    /// # Returns
+    ///
+    /// The metadata of every denom, in the order given
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query fails; the tokens resolved before are kept
+    pub async fn resolve_tokens(&self, denoms: &[String]) -> Result<Vec<TokenMetadata>, Error> {
+        let mut pool_decimals: Option<std::collections::HashMap<String, u8>> = None;
+        let mut resolved = Vec::with_capacity(denoms.len());
+        for denom in denoms {
+            if let Some(token) = self.token_registry.get(denom) {
+                resolved.push(token);
+                continue;
+            }
+
+            let token = match self.get_denom_metadata(denom).await? {
+                Some(metadata) => TokenMetadata::from_bank_metadata(denom, &metadata),
+                None => None,
+            };
+            let token = match token {
+                Some(token) => token,
+                None => {
+                    if pool_decimals.is_none() {
+                        pool_decimals = Some(self.pool_asset_decimals().await?);
+                    }
+                    let decimals = pool_decimals
+                        .as_ref()
+                        .and_then(|decimals| decimals.get(denom).copied());
+                    let trace = self.get_ibc_trace(denom).await?;
+                    TokenMetadata::derived(denom, trace, decimals)
+                }
+            };
+            self.token_registry.insert([token.clone()]);
+            resolved.push(token);
+        }
+        Ok(resolved)
+    }
+
+    /// Resolve the symbol and decimals of one denom, see [`resolve_tokens`](Self::resolve_tokens)
+    pub async fn resolve_token(&self, denom: &str) -> Result<TokenMetadata, Error> {
+        let mut resolved = self.resolve_tokens(&[denom.to_string()]).await?;
+        Ok(resolved.remove(0))
+    }
+
+    /// Decimals of every asset of every pool, as recorded by the pool manager
+    async fn pool_asset_decimals(&self) -> Result<std::collections::HashMap<String, u8>, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        Ok(pools
+            .iter()
+            .flat_map(|pool| {
+                let info = &pool.pool_info;
+                info.asset_denoms
+                    .iter()
+                    .cloned()
+                    .zip(info.asset_decimals.iter().copied())
+            })
+            .collect())
+    }
+
+    /// Get up to `limit` addresses holding a denom
+    pub async fn get_denom_owners(&self, denom: &str, limit: u64) -> Result<Vec<String>, Error> {
+        let (holders, _) = self.denom_holders_page(denom, Vec::new(), limit).await?;
+        Ok(holders.into_iter().map(|holder| holder.address).collect())
+    }
+
+    /// Stream every address holding a denom, with the amount each holds
+    ///
+    /// Pages of `page_size` holders are fetched lazily as the stream is consumed.
+    pub fn denom_holders_stream<'a>(
+        &'a self,
+        denom: &'a str,
+        page_size: u64,
+    ) -> BoxStream<'a, Result<DenomHolder, Error>> {
+        paginate(Vec::new(), move |key: Vec<u8>| {
+            self.denom_holders_page(denom, key, page_size)
+        })
+    }
+
+    /// Get a page of the holders of a denom, and the key of the next page
+    async fn denom_holders_page(
+        &self,
+        denom: &str,
+        key: Vec<u8>,
+        limit: u64,
+    ) -> Result<(Vec<DenomHolder>, Option<Vec<u8>>), Error> {
+        let encoded_request = QueryDenomOwnersRequest {
+            denom: denom.to_string(),
+            pagination: Some(PageRequest {
+                key,
+                limit,
+                ..Default::default()
+            }),
+        }
+        .encode_to_vec();
+
+        let response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmos.bank.v1beta1.Query/DenomOwners".to_string()),
+                        encoded_request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get denom owners: {}", e)))
+            })
+            .await?;
+
+        if !response.code.is_ok() {
+            return Err(Error::Rpc(format!("Query failed: {}", response.log)));
+        }
+
+        let owners_response = QueryDenomOwnersResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode denom owners: {}", e)))?;
+        let next = owners_response
+            .pagination
+            .map(|pagination| pagination.next_key)
+            .filter(|next_key| !next_key.is_empty());
+        let holders = owners_response
+            .denom_owners
+            .into_iter()
+            .map(|owner| DenomHolder {
+                address: owner.address,
+                amount: owner
+                    .balance
+                    .and_then(|balance| balance.amount.parse().ok())
+                    .unwrap_or_default(),
+            })
+            .collect();
+        Ok((holders, next))
+    }
+
+    /// Get the height at which a token factory denom was created
+    ///
+    /// `None` if the node has no indexed creation transaction for it.
+    pub async fn get_denom_creation_height(&self, denom: &str) -> Result<Option<u64>, Error> {
+        let mut txs = self.txs_stream(Query::eq("create_denom.new_token_denom", denom), 1);
+        match txs.next().await {
+            Some(tx) => Ok(Some(tx?.height.value())),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the creator, creation block and LP concentration of a pool
+    ///
+    /// See [`crate::ownership`]. LP holders are read through
+    /// [`indexer`](Self::indexer). Details whose queries fail are left empty
+    /// rather than failing the whole lookup.
+    pub async fn get_pool_ownership(&self, pool: &PoolInfoResponse) -> PoolOwnership {
+        let lp_denom = &pool.pool_info.lp_denom;
+        let pool_manager = self.config.contracts.pool_manager.as_str();
+        let mut ownership = PoolOwnership::unknown(&pool.pool_info.pool_identifier);
+
+        let creation = async {
+            let mut txs =
+                self.txs_stream(Query::eq("create_denom.new_token_denom", lp_denom.as_str()), 1);
+            txs.next().await.and_then(Result::ok)
+        };
+        let holders = self
+            .indexer()
+            .lp_holders(&pool.pool_info.pool_identifier, lp_denom);
+        let (creation, holders) = future::join(creation, holders).await;
+
+        if let Some(tx) = creation {
+            let height = tx.height.value();
+            ownership.creator = creator_from_events(&tx.tx_result.events, pool_manager);
+            ownership.created_height = Some(height);
+            ownership.creation_tx = Some(tx.hash.to_string());
+            ownership.created_at = self
+                .get_block_timestamp(height)
+                .await
+                .ok()
+                .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp as i64, 0));
+        }
+        ownership.lp_concentration = holders.ok().map(|holders| {
+            LpConcentration::from_holders(
+                &holders,
+                pool.total_share.amount,
+                &[pool_manager],
+                TOP_LP_HOLDERS,
+            )
+        });
+        ownership
+    }
+
+    /// Probe the on-chain facts the risk heuristics judge a denom by
+    ///
+    /// Metadata and age are only checked for token factory denoms; the native
+    /// denom is never flagged. Probes that fail are left out rather than failing
+    /// the whole check.
+    async fn probe_denom(&self, denom: &str, current_height: Option<u64>) -> DenomProbe {
+        let mut probe = DenomProbe::unchecked(denom);
+        if denom == self.config.native_denom {
+            return probe;
+        }
+        probe.denylisted = !self.asset_filter.allows_denom(denom);
+        if !denom.starts_with("factory/") {
+            return probe;
+        }
+
+        let (metadata, created_at) = future::join(
+            self.get_denom_metadata(denom),
+            self.get_denom_creation_height(denom),
+        )
+        .await;
+        probe.metadata = match metadata {
+            Ok(Some(metadata)) => DenomMetadata::Present {
+                symbol: metadata.symbol,
+            },
+            Ok(None) => DenomMetadata::Missing,
+            Err(_) => DenomMetadata::Unchecked,
+        };
+        if let (Some(current), Ok(Some(created))) = (current_height, created_at) {
+            probe.age_blocks = Some(current.saturating_sub(created));
+        }
+        probe
+    }
+
+    /// Screen a pool and its denoms for red flags
+    ///
+    /// See [`crate::risk`] for the heuristics. Checks whose chain queries fail are
+    /// skipped, so a report can be clean because nothing could be checked.
+    pub async fn assess_pool_risk(
+        &self,
+        pool: &PoolInfoResponse,
+        thresholds: &RiskThresholds,
+    ) -> RiskReport {
+        let current_height = self.get_last_block_height().await.ok();
+        let probes = future::join_all(
+            pool.pool_info
+                .asset_denoms
+                .iter()
+                .map(|denom| self.probe_denom(denom, current_height)),
+        );
+        let lp_holders = self
+            .indexer()
+            .lp_holders(&pool.pool_info.pool_identifier, &pool.pool_info.lp_denom);
+        let (probes, lp_holders) = future::join(probes, lp_holders).await;
+        // The pool manager keeps the minimum liquidity locked, so it doesn't count
+        let concentration = lp_holders.ok().map(|holders| {
+            LpConcentration::from_holders(
+                &holders,
+                pool.total_share.amount,
+                &[self.config.contracts.pool_manager.as_str()],
+                TOP_LP_HOLDERS,
+            )
+        });
+
+        let mut report = RiskReport::for_pool(
+            pool,
+            &probes,
+            concentration.as_ref().map(|concentration| concentration.holders),
+            thresholds,
+        );
+        if let Some(concentration) = &concentration {
+            report.flags.extend(concentration_flags(
+                &pool.pool_info.pool_identifier,
+                concentration,
+                thresholds,
+            ));
+        }
+        report
+    }
+
+    /// Screen several pools, at most `concurrency` at a time, in input order
+    pub async fn assess_pools_risk(
+        &self,
+        pools: &[PoolInfoResponse],
+        thresholds: &RiskThresholds,
+        concurrency: usize,
+    ) -> Vec<RiskReport> {
+        // Collected first so the stream holds no closure over borrowed pools,
+        // which would keep the future from being `Send`
+        let assessments: Vec<_> = pools
+            .iter()
+            .map(|pool| self.assess_pool_risk(pool, thresholds))
+            .collect();
+        stream::iter(assessments)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
     /// Query a transaction by hash
     pub async fn query_transaction(&self, tx_hash: &str) -> Result<serde_json::Value, Error> {
-        let rpc_client = self.rpc_client.lock().await;
-        
         // Parse the transaction hash
         let hash = Hash::from_hex_upper(cosmrs::tendermint::hash::Algorithm::Sha256, tx_hash.trim_start_matches("0x"))
             .map_err(|e| Error::Other(format!("Invalid transaction hash: {}", e)))?;
         
         // Query the transaction
-        let tx_response = rpc_client
-            .tx(hash, false)
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to query transaction: {}", e)))?;
+        let tx_response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .tx(hash, false)
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to query transaction: {}", e)))
+            })
+            .await?;
         
         // Create a simplified response structure
         let result = serde_json::json!({
@@ -254,50 +1696,374 @@ impl MantraDexClient {
         Ok(result)
     }
 
-    /// Query a smart contract
-    pub async fn query<Q: serde::Serialize + Clone, R: DeserializeOwned>(
-        &self,
-        contract_addr: &str,
-        query_msg: &Q,
-    ) -> Result<R, Error> {
-        let rpc_client = self.rpc_client.lock().await;
-        let query = QuerySmartContractStateRequest {
-            address: contract_addr.to_string(),
-            query_data: serde_json::to_vec(query_msg)?,
-        };
-
-        // Now that we're using the same Prost version as cosmos-sdk-proto,
-        // we can use the Message trait directly
-        let data = query.encode_to_vec();
-        let result = rpc_client
-            .abci_query(
-                Some("/cosmwasm.wasm.v1.Query/SmartContractState".to_string()),
-                data,
-                None,
-                false,
-            )
-            .await
-            .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))?;
+    /// Explain why a transaction failed
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - Hash of the transaction, with or without a `0x` prefix
+    ///
+    /// # Returns
+    ///
+    /// The transaction's [`Diagnosis`], or `None` if it succeeded
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hash is invalid or the transaction can't be found
+    pub async fn diagnose_transaction(&self, tx_hash: &str) -> Result<Option<Diagnosis>, Error> {
+        let hash = Hash::from_hex_upper(
+            cosmrs::tendermint::hash::Algorithm::Sha256,
+            &tx_hash.trim_start_matches("0x").to_uppercase(),
+        )
+        .map_err(|e| Error::Other(format!("Invalid transaction hash: {}", e)))?;
+        let response = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .tx(hash, false)
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to query transaction: {}", e)))
+            })
+            .await?;
 
-        if !result.code.is_ok() {
-            return Err(Error::Contract(format!(
-                "Contract query failed: {}",
-                String::from_utf8_lossy(result.log.as_bytes())
-            )));
+        let result = response.tx_result;
+        if result.code.is_ok() {
+            return Ok(None);
         }
-        let resp: QuerySmartContractStateResponse =
-            QuerySmartContractStateResponse::decode(result.value.as_slice())
-                .map_err(|e| Error::Rpc(format!("Failed to decode query response: {}", e)))?;
-        serde_json::from_slice::<R>(resp.data.as_slice()).map_err(Into::into)
+        Ok(Some(
+            Diagnosis::new(&result.codespace, result.code.value(), result.log)
+                .with_tx_hash(response.hash.to_string())
+                .with_gas(result.gas_wanted, result.gas_used),
+        ))
     }
 
-    /// Execute a contract message
-    pub async fn execute<T: serde::Serialize>(
+    /// Run an operation under the configured timeout for its class
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the operation does not finish in time
+    pub async fn with_timeout<T, F>(&self, class: OperationClass, operation: F) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        let timeout = self.config.timeouts.for_class(class);
+        tokio::time::timeout(timeout, operation)
+            .await
+            .map_err(|_| {
+                Error::Timeout(format!(
+                    "{:?} operation timed out after {} seconds",
+                    class,
+                    timeout.as_secs()
+                ))
+            })?
+    }
+
+    /// Run an RPC call against the preferred healthy endpoint
+    ///
+    /// The call is bounded by the timeout for `class`. Network failures and
+    /// timeouts count against the endpoint's circuit; any other outcome shows the
+    /// endpoint is reachable.
+    async fn rpc_call<T, F, Fut>(&self, class: OperationClass, call: F) -> Result<T, Error>
+    where
+        F: FnOnce(HttpClient) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let (index, rpc_client) = self.rpc_endpoint()?;
+        let result = self.with_timeout(class, call(rpc_client)).await;
+        match &result {
+            Err(e) if e.class() == ErrorClass::Network => {
+                self.circuit_breaker.record_failure(index)
+            }
+            _ => self.circuit_breaker.record_success(index),
+        }
+        result
+    }
+
+    /// Pick the RPC client for the preferred healthy endpoint
+    fn rpc_endpoint(&self) -> Result<(usize, HttpClient), Error> {
+        let index = self.circuit_breaker.acquire()?;
+        Ok((index, self.rpc_clients[index].clone()))
+    }
+
+    /// Measure the latency and block height of every RPC endpoint
+    ///
+    /// Each endpoint's status query is timed, in parallel, and fed to the circuit
+    /// breaker, which then prefers the fastest healthy endpoint and avoids those
+    /// lagging behind the others' block height. A failed query counts against the
+    /// endpoint's circuit like any other request. Call this every
+    /// [`probe_interval`](crate::config::EndpointSelectionConfig::probe_interval).
+    ///
+    /// # Returns
+    ///
+    /// The health of every endpoint after the measurement
+    pub async fn probe_endpoints(&self) -> Vec<EndpointHealth> {
+        let probes = self.rpc_clients.iter().map(|rpc_client| async move {
+            let started = Instant::now();
+            let result = self
+                .with_timeout(OperationClass::Query, async {
+                    rpc_client
+                        .status()
+                        .await
+                        .map_err(|e| Error::Rpc(format!("Health check failed: {}", e)))
+                })
+                .await;
+            result.map(|status| (started.elapsed(), status.sync_info.latest_block_height.value()))
+        });
+
+        for (index, result) in future::join_all(probes).await.into_iter().enumerate() {
+            match result {
+                Ok((latency, height)) => {
+                    self.circuit_breaker.record_latency(index, latency);
+                    self.circuit_breaker.record_block_height(index, height);
+                    self.circuit_breaker.record_success(index);
+                }
+                Err(_) => self.circuit_breaker.record_failure(index),
+            }
+        }
+        self.circuit_breaker.health()
+    }
+
+    /// Number of unconfirmed transactions in the mempool of the preferred endpoint
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rpc`] if the node can't be queried or its response parsed
+    pub async fn mempool_size(&self) -> Result<u64, Error> {
+        let index = self.circuit_breaker.acquire()?;
+        let url = format!(
+            "{}/num_unconfirmed_txs?limit=1",
+            self.config.rpc_endpoints()[index].trim_end_matches('/')
+        );
+        let result = self
+            .with_timeout(OperationClass::Query, async {
+                let response: serde_json::Value = reqwest::get(&url)
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(|e| Error::Rpc(format!("Failed to query mempool: {}", e)))?
+                    .json()
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to read mempool: {}", e)))?;
+                congestion::parse_unconfirmed_txs(&response)
+                    .ok_or_else(|| Error::Rpc("Mempool response has no n_txs".to_string()))
+            })
+            .await;
+        match result {
+            Ok(_) => self.circuit_breaker.record_success(index),
+            Err(_) => self.circuit_breaker.record_failure(index),
+        }
+        result
+    }
+
+    /// How busy the network looks: the mempool size and recent inclusion latency
+    ///
+    /// The mempool size is left out if the node can't be queried.
+    pub async fn congestion(&self) -> Congestion {
+        Congestion {
+            mempool_txs: self.mempool_size().await.ok(),
+            inclusion_latency: self.inclusion_latencies.median(),
+        }
+    }
+
+    /// Gas price multiplier of the next transaction under the priority fee settings
+    ///
+    /// Congestion is only checked for [`Priority::Auto`].
+    pub async fn fee_multiplier(&self) -> f64 {
+        let priority_fee = &self.config.priority_fee;
+        let congested = match priority_fee.priority {
+            Priority::Auto => self.congestion().await.is_congested(priority_fee),
+            Priority::Normal | Priority::High => false,
+        };
+        priority_fee.fee_multiplier(congested)
+    }
+
+    /// Query a smart contract
+    ///
+    /// Subject to the configured query timeout.
+    pub async fn query<Q: serde::Serialize + Clone, R: DeserializeOwned>(
+        &self,
+        contract_addr: &str,
+        query_msg: &Q,
+    ) -> Result<R, Error> {
+        self.smart_query(OperationClass::Query, contract_addr, query_msg)
+            .await
+    }
+
+    /// Query a smart contract under the timeout for `class`
+    ///
+    /// A response that doesn't fit `R` is reported as [`Error::SchemaMismatch`],
+    /// with the contract's version when it can be read.
+    async fn smart_query<Q: serde::Serialize, R: DeserializeOwned>(
+        &self,
+        class: OperationClass,
+        contract_addr: &str,
+        query_msg: &Q,
+    ) -> Result<R, Error> {
+        let query = QuerySmartContractStateRequest {
+            address: contract_addr.to_string(),
+            query_data: serde_json::to_vec(query_msg)?,
+        };
+
+        // Now that we're using the same Prost version as cosmos-sdk-proto,
+        // we can use the Message trait directly
+        let data = query.encode_to_vec();
+        let result = self
+            .rpc_call(class, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmwasm.wasm.v1.Query/SmartContractState".to_string()),
+                        data,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))
+            })
+            .await?;
+
+        if !result.code.is_ok() {
+            return Err(Error::Contract(format!(
+                "Contract query failed: {}",
+                String::from_utf8_lossy(result.log.as_bytes())
+            )));
+        }
+        let resp: QuerySmartContractStateResponse =
+            QuerySmartContractStateResponse::decode(result.value.as_slice())
+                .map_err(|e| Error::Rpc(format!("Failed to decode query response: {}", e)))?;
+        let query_name = contract_schema::query_name(query_msg);
+        match contract_schema::parse_response(contract_addr, &query_name, &resp.data) {
+            Err(Error::SchemaMismatch(mismatch)) => {
+                let version = self.query_contract_version(contract_addr).await.ok();
+                Err(Error::SchemaMismatch(Box::new(mismatch.with_contract_version(version))))
+            }
+            result => result,
+        }
+    }
+
+    /// Get the name and version a contract records in its cw2 `contract_info` entry
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Contract`] if the contract records no version
+    pub async fn query_contract_version(
+        &self,
+        contract_addr: &str,
+    ) -> Result<ContractVersion, Error> {
+        let data = QueryRawContractStateRequest {
+            address: contract_addr.to_string(),
+            query_data: CONTRACT_INFO_KEY.to_vec(),
+        }
+        .encode_to_vec();
+        let result = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmwasm.wasm.v1.Query/RawContractState".to_string()),
+                        data,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))
+            })
+            .await?;
+
+        if !result.code.is_ok() {
+            return Err(Error::Contract(format!(
+                "Contract state query failed: {}",
+                result.log
+            )));
+        }
+        let resp = QueryRawContractStateResponse::decode(result.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode query response: {}", e)))?;
+        if resp.data.is_empty() {
+            return Err(Error::Contract(format!(
+                "Contract {} records no version",
+                contract_addr
+            )));
+        }
+        Ok(serde_json::from_slice(&resp.data)?)
+    }
+
+    /// Execute a contract message
+    pub async fn execute<T: serde::Serialize>(
         &self,
         contract_addr: &str,
         msg: &T,
         funds: Vec<Coin>,
     ) -> Result<TxResponse, Error> {
+        let execute_msg = self.execute_contract_msg(contract_addr, msg, funds)?;
+        self.broadcast_tx(vec![execute_msg]).await
+    }
+
+    /// Execute several contract messages in a single transaction
+    ///
+    /// Either every message succeeds or the whole transaction fails.
+    pub async fn execute_batch<T: serde::Serialize>(
+        &self,
+        contract_addr: &str,
+        msgs: Vec<(T, Vec<Coin>)>,
+    ) -> Result<TxResponse, Error> {
+        if msgs.is_empty() {
+            return Err(Error::Other("Batch cannot be empty".to_string()));
+        }
+
+        let execute_msgs = msgs
+            .iter()
+            .map(|(msg, funds)| self.execute_contract_msg(contract_addr, msg, funds.clone()))
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.broadcast_tx(execute_msgs).await
+    }
+
+    /// Start a batch of operations sent in a single transaction
+    ///
+    /// See [`TxBatchBuilder`]: the batch pays one fee and lands in one block,
+    /// instead of one transaction per operation.
+    pub fn batch(&self) -> TxBatchBuilder<'_> {
+        TxBatchBuilder::new(self)
+    }
+
+    /// Validate a batch operation and build its message
+    ///
+    /// Runs the same checks as the operation's own method, e.g.
+    /// [`swap`](Self::swap) for [`BatchOp::Swap`].
+    pub(crate) async fn batch_op_msg(&self, op: &BatchOp) -> Result<Any, Error> {
+        match op {
+            BatchOp::Swap {
+                pool_id,
+                offer_asset,
+                ask_asset_denom,
+                max_slippage,
+            } => {
+                self.swap_msg(pool_id, offer_asset.clone(), ask_asset_denom, *max_slippage)
+                    .await
+            }
+            BatchOp::ProvideLiquidity {
+                pool_id,
+                assets,
+                liquidity_max_slippage,
+                swap_max_slippage,
+            } => {
+                self.validate_pool_status(pool_id).await?;
+                self.provide_liquidity_msg(
+                    pool_id,
+                    assets.clone(),
+                    *liquidity_max_slippage,
+                    *swap_max_slippage,
+                )
+            }
+            BatchOp::WithdrawLiquidity { pool_id, lp_amount } => {
+                let pool = self.withdrawable_pool(pool_id, *lp_amount).await?;
+                self.withdraw_liquidity_msg(&pool, *lp_amount)
+            }
+            BatchOp::ClaimRewards { until_epoch } => self.claim_rewards_msg(*until_epoch),
+        }
+    }
+
+    /// Build a `MsgExecuteContract` sent from the configured wallet
+    fn execute_contract_msg<T: serde::Serialize>(
+        &self,
+        contract_addr: &str,
+        msg: &T,
+        funds: Vec<Coin>,
+    ) -> Result<Any, Error> {
         let wallet = self.wallet()?;
         let sender = wallet.address().unwrap().to_string();
 
@@ -311,43 +2077,40 @@ impl MantraDexClient {
         let execute_msg = MsgExecuteContract {
             sender: sender.clone(),
             contract: contract_addr.to_string(),
-            msg: serde_json::to_vec(msg)?,
+            msg: canonical::to_vec(msg)?,
             funds: cosmos_coins,
         };
 
-        self.broadcast_tx(vec![Any {
+        Ok(Any {
             type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
             value: execute_msg.to_bytes().unwrap(),
-        }])
-        .await
+        })
     }
 
-    /// Broadcast a transaction to the network
-    async fn broadcast_tx(&self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
-        let _height = self.get_last_block_height().await?;
-        let wallet = self.wallet()?;
-        let rpc_client = self.rpc_client.lock().await;
-
-        let tx_body = Body::new(msgs, String::new(), 0u32);
-
-        // Get account info for signing
-        let addr = wallet.address().unwrap().to_string();
-
-        // Create request using the proper protobuf type
-        let request = QueryAccountRequest { address: addr };
-
-        // Encode the request to protobuf
+    /// Account number and sequence of `address`, as its next transaction must be signed with
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rpc`] if the account doesn't exist or the query fails
+    pub async fn get_account_sequence(&self, address: &str) -> Result<(u64, u64), Error> {
+        let request = QueryAccountRequest {
+            address: address.to_string(),
+        };
         let encoded_request = request.encode_to_vec();
 
-        let account_info = rpc_client
-            .abci_query(
-                Some("/cosmos.auth.v1beta1.Query/Account".to_string()),
-                encoded_request,
-                None,
-                false,
-            )
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to get account info: {}", e)))?;
+        let account_info = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmos.auth.v1beta1.Query/Account".to_string()),
+                        encoded_request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get account info: {}", e)))
+            })
+            .await?;
 
         if !account_info.code.is_ok() {
             return Err(Error::Rpc(format!(
@@ -361,129 +2124,643 @@ impl MantraDexClient {
             .map_err(|e| Error::Rpc(format!("Failed to decode account response: {}", e)))?;
 
         // Extract the account data - account.value contains a serialized BaseAccount
-        let account_any = account_response.account.unwrap();
+        let account_any = account_response
+            .account
+            .ok_or_else(|| Error::Rpc(format!("Account {} not found", address)))?;
 
         // Decode the BaseAccount from the Any object's value
         let base_account = BaseAccount::decode(account_any.value.as_slice())
             .map_err(|e| Error::Rpc(format!("Failed to decode BaseAccount: {}", e)))?;
 
-        let account_number = base_account.account_number;
-        let sequence = base_account.sequence;
-        // Create the fee
-        let fee = wallet.create_default_fee(2_000_000)?;
-
-        // Create signer info with sequence number
-        let signer_info = SignerInfo::single_direct(Some(wallet.public_key()), sequence);
+        Ok((base_account.account_number, base_account.sequence))
+    }
 
-        // Create auth info with fee
-        let auth_info = signer_info.auth_info(fee);
+    /// Build a transaction of `msgs` for the holder of `public_key` to sign elsewhere
+    ///
+    /// The signer's account is queried and the transaction simulated, and its gas
+    /// limit and fee are set as for transactions the client signs itself. Sign
+    /// [`UnsignedTx::sign_bytes`] and broadcast the bytes of
+    /// [`UnsignedTx::into_signed`]; see [`crate::tx`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account can't be queried or the simulation fails
+    pub async fn prepare_tx(
+        &self,
+        msgs: Vec<Any>,
+        public_key: PublicKey,
+        sign_mode: SignMode,
+    ) -> Result<UnsignedTx, Error> {
+        let (unsigned, _, _) = self.prepare_unsigned_tx(msgs, public_key, sign_mode).await?;
+        Ok(unsigned)
+    }
 
-        let chain_id = Id::try_from(self.config.chain_id.as_str())
-            .map_err(|e| Error::Tx(format!("Invalid chain ID: {}", e)))?;
+    /// Build a transaction of `msgs` from a multisig account for its members to sign
+    ///
+    /// The account is queried and the transaction simulated as if its first
+    /// `threshold` members had signed, and its gas limit and fee are set as for
+    /// transactions the client signs itself. See [`crate::wallet::multisig`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account doesn't exist yet or the simulation fails
+    pub async fn prepare_multisig_tx(
+        &self,
+        account: &MultisigAccount,
+        msgs: Vec<Any>,
+    ) -> Result<MultisigTx, Error> {
+        let kind = gas::operation_kind(&msgs);
+        let multiplier = self.fee_multiplier().await;
+        let address = account.address()?;
+        let (account_number, sequence) = self.get_account_sequence(address.as_ref()).await?;
+        let body = Body::new(
+            msgs,
+            self.wallet_defaults.memo.clone().unwrap_or_default(),
+            0u32,
+        );
+        let multisig_tx = |fee: &Fee| {
+            MultisigTx::new(
+                account.clone(),
+                &body,
+                fee,
+                self.config.chain_id.clone(),
+                account_number,
+                sequence,
+            )
+        };
 
-        let sign_doc = SignDoc::new(&tx_body, &auth_info, &chain_id, account_number)
-            .map_err(|e| Error::Tx(format!("Failed to create sign doc: {}", e)))?;
+        let (draft_body, auth_info, signature) =
+            multisig_tx(&self.tx_fee(TX_GAS_LIMIT, multiplier)?)?.draft()?;
+        let (simulated, _) = self
+            .simulate_tx(&draft_body, &auth_info, signature)
+            .await?;
+        let gas_limit = self.gas_history.gas_limit(
+            &kind,
+            simulated,
+            self.config.gas_adjustment,
+            TX_GAS_LIMIT,
+        );
+        multisig_tx(&self.tx_fee(gas_limit, multiplier)?)
+    }
 
-        // Sign the transaction
-        let tx_raw = sign_doc
-            .sign(wallet.signing_key())
-            .map_err(|e| Error::Tx(format!("Failed to sign transaction: {}", e)))?;
-        // Broadcast the transaction
-        let response = rpc_client
-            .broadcast_tx_commit(tx_raw.to_bytes().unwrap())
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to broadcast transaction: {}", e)))?;
-        // Get the transaction response
-        let tx_response = if response.check_tx.code.is_err() {
+    /// Broadcast a transaction signed elsewhere and wait for it to be included
+    ///
+    /// E.g. a [`MultisigTx`] combined from its members' signatures. Nothing is
+    /// simulated or signed; submission and inclusion are bounded by the
+    /// configured broadcast and inclusion timeouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Contract`] if the node rejects the transaction or its execution fails
+    pub async fn broadcast_signed_tx(&self, tx: Raw) -> Result<TxResponse, Error> {
+        let tx_bytes = tx
+            .to_bytes()
+            .map_err(|e| Error::Tx(format!("Failed to encode transaction: {}", e)))?;
+        let response = self
+            .rpc_call(OperationClass::Broadcast, |rpc_client| async move {
+                rpc_client
+                    .broadcast_tx_sync(tx_bytes)
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to broadcast transaction: {}", e)))
+            })
+            .await?;
+        if response.code.is_err() {
             return Err(Error::Contract(format!(
                 "Transaction check failed: {}",
-                response.check_tx.log
-            )));
-        } else if response.tx_result.code.is_err() {
-            return Err(Error::Contract(format!(
-                "Transaction execution failed: {}",
-                response.tx_result.log
+                response.log
             )));
-        } else {
-            // Query the tx
-            let tx_result = rpc_client
-                .tx(
-                    Hash::try_from(response.hash.as_bytes().to_vec())
-                        .map_err(|e| Error::Tx(format!("Invalid tx hash: {}", e)))?,
-                    false,
-                )
-                .await
-                .map_err(|e| Error::Rpc(format!("Failed to get transaction: {}", e)))?;
-
-            // Transform the response to TxResponse
-            TxResponse {
-                height: tx_result.height.value() as i64,
-                txhash: hex::encode(response.hash.as_bytes()),
-                codespace: "".to_string(),
-                code: 0,
-                data: general_purpose::STANDARD.encode(tx_result.tx_result.data),
-                raw_log: tx_result.tx_result.log.to_string(),
-                logs: vec![],
-                info: "".to_string(),
-                gas_wanted: tx_result.tx_result.gas_wanted,
-                gas_used: tx_result.tx_result.gas_used,
-                tx: None,
-                timestamp: "".to_string(),
-                events: vec![],
-            }
-        };
+        }
 
-        Ok(tx_response)
+        let tx_result = self.await_inclusion(response.hash).await?;
+        Self::included_tx_response(hex::encode(response.hash.as_bytes()), tx_result)
     }
 
-    /// Query asset decimals for a specific asset in a pool
+    /// Simulate a transaction of `msgs` from the wallet and estimate its gas and fee
     ///
-    /// This method uses the pool manager's AssetDecimals query to get accurate
-    /// decimal information for an asset within a specific pool context.
+    /// The transaction is built exactly as it would be sent, so the estimate
+    /// uses the same gas adjustment and priority fee, without signing or
+    /// broadcasting anything.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `pool_identifier` - The pool identifier to query from
-    /// * `denom` - The asset denomination to get decimals for
+    /// * Returns [`Error::Wallet`] if no wallet is configured
+    /// * Returns [`Error::Contract`] if the simulation fails
+    pub async fn estimate_gas(&self, msgs: Vec<Any>) -> Result<GasEstimate, Error> {
+        let wallet = self.wallet()?;
+        let kind = gas::operation_kind(&msgs);
+        let (unsigned, simulated, _) = self
+            .prepare_unsigned_tx(msgs, wallet.public_key()?, SignMode::Direct)
+            .await?;
+        let fee = &unsigned.auth_info.fee;
+        let coin = fee
+            .amount
+            .first()
+            .ok_or_else(|| Error::Tx("Fee has no amount".to_string()))?;
+        Ok(GasEstimate {
+            kind,
+            simulated,
+            gas_limit: fee.gas_limit,
+            fee: Coin {
+                denom: coin.denom.to_string(),
+                amount: Uint128::new(coin.amount),
+            },
+        })
+    }
+
+    /// Build and simulate a transaction, sizing its gas limit from the simulation
     ///
-    /// # Returns
+    /// Returns the transaction with the simulated gas and execution result.
+    async fn prepare_unsigned_tx(
+        &self,
+        msgs: Vec<Any>,
+        public_key: PublicKey,
+        sign_mode: SignMode,
+    ) -> Result<(UnsignedTx, u64, AbciResult), Error> {
+        let kind = gas::operation_kind(&msgs);
+        let multiplier = self.fee_multiplier().await;
+        let address = public_key
+            .account_id(ACCOUNT_PREFIX)
+            .map_err(|e| Error::Wallet(format!("Failed to get account ID: {}", e)))?;
+        let (account_number, sequence) = self.get_account_sequence(address.as_ref()).await?;
+
+        let builder = TxBuilder::new(self.config.chain_id.clone())
+            .with_messages(msgs)
+            .with_memo(self.wallet_defaults.memo.clone().unwrap_or_default())
+            .with_signer(public_key, account_number, sequence)
+            .with_sign_mode(sign_mode);
+
+        // Simulate with the largest limit, then size the limit from the result
+        let draft = builder
+            .clone()
+            .with_fee(self.tx_fee(TX_GAS_LIMIT, multiplier)?)
+            .build()?;
+        let (simulated, result) = self
+            .simulate_tx(&draft.body, &draft.auth_info, Vec::new())
+            .await?;
+        let gas_limit = self.gas_history.gas_limit(
+            &kind,
+            simulated,
+            self.config.gas_adjustment,
+            TX_GAS_LIMIT,
+        );
+        let unsigned = builder
+            .with_fee(self.tx_fee(gas_limit, multiplier)?)
+            .build()?;
+        Ok((unsigned, simulated, result))
+    }
+
+    /// Broadcast a transaction to the network and wait for it to be included
+    ///
+    /// The transaction is simulated before it is signed, so execution errors are
+    /// reported without paying fees. Its gas limit is the simulated gas times the
+    /// adjustment calibrated from past transactions of the same kind, falling back
+    /// to the configured gas adjustment. The transaction passes through the
+    /// client's [`Middleware`], which reports each stage reached to
+    /// [`subscribe_tx_progress`](Self::subscribe_tx_progress) subscribers and may
+    /// refuse it before signing, e.g. a swap blocked by the trade size guard. It
+    /// is also refused if the wallet can't pay its fee in the network's fee denom.
+    /// Submission and inclusion are bounded by the configured broadcast and
+    /// inclusion timeouts respectively. A simulate-only client returns the
+    /// simulated response instead of signing the transaction.
+    pub(crate) async fn broadcast_tx(&self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
+        self.send_tx(msgs, false).await
+    }
+
+    /// Broadcast a transaction, bypassing the trade policies if `force` is set
     ///
-    /// The number of decimal places for the asset
+    /// See [`broadcast_tx`](Self::broadcast_tx). Middleware hears whether the
+    /// transaction was included or failed.
+    async fn send_tx(&self, msgs: Vec<Any>, force: bool) -> Result<TxResponse, Error> {
+        let wallet = self.wallet()?;
+        // A watch-only wallet can't sign; say so before anything reaches the chain
+        wallet.signing_key()?;
+        let mut tx = TxContext {
+            id: NEXT_TX_ID.fetch_add(1, Ordering::Relaxed),
+            kind: gas::operation_kind(&msgs),
+            msgs,
+            signer: wallet.address()?.to_string(),
+            simulated_gas: None,
+            gas_limit: None,
+            tx_hash: None,
+            force,
+        };
+
+        let result = self.run_tx(&mut tx, wallet).await;
+        match &result {
+            Ok(_) if self.simulate_only => {}
+            Ok(response) => self
+                .middleware()
+                .for_each(|middleware| middleware.after_broadcast(&tx, response)),
+            Err(e) => self
+                .middleware()
+                .for_each(|middleware| middleware.on_error(&tx, e)),
+        }
+        result
+    }
+
+    /// Build, simulate, sign and broadcast `tx`, filling it in as it goes
     ///
-    /// # Errors
+    /// The time from broadcast to inclusion is recorded to detect congestion.
     ///
-    /// Returns error if the query fails or the asset is not found in the pool
-    pub async fn query_asset_decimals(
+    /// Attempts rejected before the node accepted the transaction for a
+    /// transient reason, see [`Error::is_retryable_broadcast`], are retried as
+    /// configured in [`BroadcastRetryConfig`]: each retry queries the account's
+    /// sequence again and signs a fresh transaction. Broadcast timeouts and
+    /// timeouts waiting for inclusion aren't retried, as the transaction may
+    /// still be included; they fail with [`Error::Unconfirmed`], carrying its hash.
+    async fn run_tx(
         &self,
-        pool_identifier: &str,
-        denom: &str,
-    ) -> Result<u8, Error> {
-        let query = pool_manager::QueryMsg::AssetDecimals {
-            pool_identifier: pool_identifier.to_string(),
-            denom: denom.to_string(),
+        tx: &mut TxContext,
+        wallet: &MantraWallet,
+    ) -> Result<TxResponse, Error> {
+        let height = self.get_last_block_height().await?;
+        let report = |tx: &TxContext, stage: TxStage| {
+            self.middleware()
+                .for_each(|middleware| middleware.on_stage(tx, stage));
         };
 
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        let retry = self.config.broadcast_retry;
+        let mut retries = 0;
+        let (response, simulated) = loop {
+            match self.submit_tx(tx, wallet, height).await {
+                Ok(Submission::Simulated(response)) => return Ok(*response),
+                Ok(Submission::Accepted(response, simulated)) => break (response, simulated),
+                Err(e) if retries < retry.max_retries && e.is_retryable_broadcast() => {
+                    self.middleware()
+                        .for_each(|middleware| middleware.on_retry(tx, retries + 1, &e));
+                    tokio::time::sleep(retry.backoff(retries)).await;
+                    retries += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        let tx_hash = hex::encode(response.hash.as_bytes());
+        tx.tx_hash = Some(tx_hash.clone());
+        report(tx, TxStage::Broadcast);
+
+        // Wait for the transaction to land in a block
+        let tx_result = self.await_inclusion(response.hash).await?;
+
+        self.gas_history.record(
+            &tx.kind,
+            GasSample {
+                simulated,
+                gas_wanted: tx_result.tx_result.gas_wanted.max(0) as u64,
+                gas_used: tx_result.tx_result.gas_used.max(0) as u64,
+                out_of_gas: tx_result.tx_result.code.is_err()
+                    && tx_result.tx_result.log.contains("out of gas"),
+            },
+        );
 
-        // Query and expect AssetDecimalsResponse
-        let response: serde_json::Value = self.query(&pool_manager_address, &query).await?;
+        let tx_response = Self::included_tx_response(tx_hash, tx_result)?;
+        report(tx, TxStage::Included);
 
-        // Extract decimals from response
-        let decimals = response
-            .get("decimals")
-            .and_then(|v| v.as_u64())
-            .map(|d| d as u8)
-            .ok_or_else(|| Error::Other("Invalid asset decimals response format".to_string()))?;
+        Ok(tx_response)
+    }
 
-        Ok(decimals)
+    /// Wait, within the inclusion timeout, for the transaction with `hash` to be
+    /// included, recording how long it took
+    async fn await_inclusion(&self, hash: Hash) -> Result<TxInfo, Error> {
+        let broadcast_at = Instant::now();
+        let (_, rpc_client) = self.rpc_endpoint()?;
+        let inclusion_timeout = self.config.timeouts.for_class(OperationClass::Inclusion);
+        let tx_result = tokio::time::timeout(
+            inclusion_timeout,
+            Self::wait_for_inclusion(&rpc_client, hash),
+        )
+        .await
+        .map_err(|_| Error::Unconfirmed {
+            tx_hash: hex::encode(hash.as_bytes()),
+            reason: format!(
+                "not included within {} seconds",
+                inclusion_timeout.as_secs()
+            ),
+        })?;
+        self.inclusion_latencies.record(broadcast_at.elapsed());
+        Ok(tx_result)
     }
 
-    /// Get asset decimals from multiple pools for comprehensive mapping
+    /// Response of an included transaction, or [`Error::Contract`] if its execution failed
     ///
-    /// This method queries all available pools and builds a comprehensive map of
-    /// asset denominations to their decimal places using the official AssetDecimals query.
+    /// Carries the events of the transaction's execution, as a simulated response does.
+    fn included_tx_response(tx_hash: String, tx_result: TxInfo) -> Result<TxResponse, Error> {
+        if tx_result.tx_result.code.is_err() {
+            return Err(Error::Contract(format!(
+                "Transaction execution failed: {}",
+                tx_result.tx_result.log
+            )));
+        }
+        let events = tx_result
+            .tx_result
+            .events
+            .iter()
+            .map(|event| Event {
+                r#type: event.kind.clone(),
+                attributes: event
+                    .attributes
+                    .iter()
+                    .map(|attr| EventAttribute {
+                        key: attr.key_str().unwrap_or_default().to_string(),
+                        value: attr.value_str().unwrap_or_default().to_string(),
+                        index: attr.index(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(TxResponse {
+            height: tx_result.height.value() as i64,
+            txhash: tx_hash,
+            codespace: "".to_string(),
+            code: 0,
+            data: general_purpose::STANDARD.encode(tx_result.tx_result.data),
+            raw_log: tx_result.tx_result.log.to_string(),
+            logs: vec![],
+            info: "".to_string(),
+            gas_wanted: tx_result.tx_result.gas_wanted,
+            gas_used: tx_result.tx_result.gas_used,
+            tx: None,
+            timestamp: "".to_string(),
+            events,
+        })
+    }
+
+    /// One attempt at sending `tx`: build, simulate, sign and broadcast it
     ///
-    /// # Returns
+    /// A simulate-only client returns the simulated response instead of signing.
+    async fn submit_tx(
+        &self,
+        tx: &mut TxContext,
+        wallet: &MantraWallet,
+        height: u64,
+    ) -> Result<Submission, Error> {
+        let report = |tx: &TxContext, stage: TxStage| {
+            self.middleware()
+                .for_each(|middleware| middleware.on_stage(tx, stage));
+        };
+
+        report(tx, TxStage::Built);
+        let (unsigned, simulated, result) = self
+            .prepare_unsigned_tx(tx.msgs.clone(), wallet.public_key()?, SignMode::Direct)
+            .await?;
+        let gas_limit = unsigned.auth_info.fee.gas_limit;
+        tx.simulated_gas = Some(simulated);
+        tx.gas_limit = Some(gas_limit);
+        report(tx, TxStage::Simulated);
+        self.check_fee_balance(&tx.signer, &unsigned.auth_info.fee).await?;
+
+        for middleware in self.middleware() {
+            middleware.before_sign(self, tx).await?;
+        }
+
+        if self.simulate_only {
+            let simulated_tx = cosmrs::proto::cosmos::tx::v1beta1::Tx {
+                body: Some(unsigned.body.into_proto()),
+                auth_info: Some(unsigned.auth_info.into_proto()),
+                signatures: vec![],
+            };
+            return Ok(Submission::Simulated(Box::new(TxResponse {
+                height: height as i64,
+                txhash: String::new(),
+                codespace: String::new(),
+                code: 0,
+                data: String::new(),
+                raw_log: result.log,
+                logs: vec![],
+                info: simulation::SIMULATED_TX_INFO.to_string(),
+                gas_wanted: gas_limit as i64,
+                gas_used: simulated as i64,
+                tx: Some(Any {
+                    type_url: simulation::TX_TYPE_URL.to_string(),
+                    value: simulated_tx.encode_to_vec(),
+                }),
+                timestamp: String::new(),
+                events: result.events,
+            })));
+        }
+
+        // Sign the transaction
+        let tx_raw = unsigned.sign(wallet.signing_key()?)?;
+        report(tx, TxStage::Signed);
+
+        // Broadcast the transaction
+        let tx_bytes = tx_raw
+            .to_bytes()
+            .map_err(|e| Error::Tx(format!("Failed to encode transaction: {}", e)))?;
+        // A broadcast that timed out or lost its connection may still have
+        // reached the mempool
+        let tx_hash = hex::encode(Sha256::digest(&tx_bytes));
+        let response = self
+            .rpc_call(OperationClass::Broadcast, |rpc_client| async move {
+                rpc_client
+                    .broadcast_tx_sync(tx_bytes)
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to broadcast transaction: {}", e)))
+            })
+            .await
+            .map_err(|e| match e {
+                Error::Rpc(_) | Error::Timeout(_) => Error::Unconfirmed {
+                    tx_hash,
+                    reason: e.to_string(),
+                },
+                e => e,
+            })?;
+        if response.code.is_err() {
+            return Err(Error::Contract(format!(
+                "Transaction check failed: {}",
+                response.log
+            )));
+        }
+        Ok(Submission::Accepted(response, simulated))
+    }
+
+    /// Refuse a transaction whose fee `address` can't pay
+    ///
+    /// Checked before signing, and before returning a simulated response, so a
+    /// wallet without the network's fee denom hears which denom it needs rather
+    /// than the chain's rejection.
+    async fn check_fee_balance(&self, address: &str, fee: &Fee) -> Result<(), Error> {
+        let balances = self.get_balances_for_address(address).await?;
+        for coin in &fee.amount {
+            let fee = Coin {
+                denom: coin.denom.to_string(),
+                amount: Uint128::new(coin.amount),
+            };
+            self.config.check_fee_funds(&fee, &balances)?;
+        }
+        Ok(())
+    }
+
+    /// Simulate an unsigned transaction against the current chain state
+    ///
+    /// Returns the gas the transaction used and the result of its execution.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Contract`] if execution fails or would use more gas than
+    /// the transaction's limit
+    async fn simulate_tx(
+        &self,
+        tx_body: &Body,
+        auth_info: &AuthInfo,
+        signature: Vec<u8>,
+    ) -> Result<(u64, AbciResult), Error> {
+        let tx_raw = TxRaw {
+            body_bytes: tx_body
+                .clone()
+                .into_bytes()
+                .map_err(|e| Error::Tx(format!("Failed to encode transaction body: {}", e)))?,
+            auth_info_bytes: auth_info
+                .clone()
+                .into_bytes()
+                .map_err(|e| Error::Tx(format!("Failed to encode auth info: {}", e)))?,
+            // Signatures aren't verified in simulation, but each signer needs one
+            signatures: vec![signature],
+        };
+        let request = SimulateRequest {
+            tx_bytes: tx_raw.encode_to_vec(),
+            ..Default::default()
+        }
+        .encode_to_vec();
+
+        let result = self
+            .rpc_call(OperationClass::Query, |rpc_client| async move {
+                rpc_client
+                    .abci_query(
+                        Some("/cosmos.tx.v1beta1.Service/Simulate".to_string()),
+                        request,
+                        None,
+                        false,
+                    )
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to simulate transaction: {}", e)))
+            })
+            .await?;
+
+        if !result.code.is_ok() {
+            return Err(Error::Contract(format!(
+                "Transaction simulation failed: {}",
+                result.log
+            )));
+        }
+
+        let response = SimulateResponse::decode(result.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode simulation response: {}", e)))?;
+        let gas_used = response.gas_info.map_or(0, |gas| gas.gas_used);
+        if gas_used > TX_GAS_LIMIT {
+            return Err(Error::Contract(format!(
+                "Transaction needs {} gas, more than the limit of {}",
+                gas_used, TX_GAS_LIMIT
+            )));
+        }
+
+        Ok((gas_used, response.result.unwrap_or_default()))
+    }
+
+    /// Fee paid by the wallet for a transaction with `gas_limit`
+    ///
+    /// Paid in the network's fee denom. Uses the wallet's gas price if set and
+    /// fees are paid in the native denom, the fee token's otherwise, raised
+    /// by the priority fee multiplier unless the priority is automatic: whether an
+    /// automatic priority applies is only known when the transaction is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if no wallet is configured
+    pub fn estimate_fee(&self, gas_limit: u64) -> Result<Coin, Error> {
+        self.wallet()?;
+        let fee = self.tx_fee(gas_limit, self.config.priority_fee.fee_multiplier(false))?;
+        let coin = fee
+            .amount
+            .first()
+            .ok_or_else(|| Error::Tx("Fee has no amount".to_string()))?;
+        Ok(Coin {
+            denom: coin.denom.to_string(),
+            amount: Uint128::new(coin.amount),
+        })
+    }
+
+    /// Build the fee of a transaction with `gas_limit` in the network's fee denom, at the
+    /// wallet's or the network's gas price times `multiplier`
+    ///
+    /// The wallet's gas price is in the native denom, so it only applies when fees are
+    /// paid in it.
+    fn tx_fee(&self, gas_limit: u64, multiplier: f64) -> Result<Fee, Error> {
+        let fee_token = self.config.fee_token()?;
+        let wallet_gas_price = self
+            .wallet_defaults
+            .gas_price
+            .filter(|_| fee_token.denom == self.config.native_denom);
+        let gas_price = wallet_gas_price
+            .unwrap_or(fee_token.gas_price * self.config.gas_adjustment)
+            * multiplier;
+        let denom = Denom::from_str(&fee_token.denom)
+            .map_err(|e| Error::Config(format!("Invalid fee denom: {}", e)))?;
+        let amount = cosmrs::Coin {
+            amount: (gas_limit as f64 * gas_price) as u128,
+            denom,
+        };
+        Ok(Fee::from_amount_and_gas(amount, gas_limit))
+    }
+
+    /// Poll until the transaction with `hash` is found in a block
+    async fn wait_for_inclusion(rpc_client: &HttpClient, hash: Hash) -> TxInfo {
+        loop {
+            // Lookups fail until the transaction has been indexed
+            if let Ok(tx) = rpc_client.tx(hash, false).await {
+                return tx;
+            }
+            tokio::time::sleep(INCLUSION_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Query asset decimals for a specific asset in a pool
+    ///
+    /// This method uses the pool manager's AssetDecimals query to get accurate
+    /// decimal information for an asset within a specific pool context.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_identifier` - The pool identifier to query from
+    /// * `denom` - The asset denomination to get decimals for
+    ///
+    /// # Returns
+    ///
+    /// The number of decimal places for the asset
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the query fails or the asset is not found in the pool
+    pub async fn query_asset_decimals(
+        &self,
+        pool_identifier: &str,
+        denom: &str,
+    ) -> Result<u8, Error> {
+        let query = pool_manager::QueryMsg::AssetDecimals {
+            pool_identifier: pool_identifier.to_string(),
+            denom: denom.to_string(),
+        };
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+
+        // Query and expect AssetDecimalsResponse
+        let response: serde_json::Value = self.query(&pool_manager_address, &query).await?;
+
+        // Extract decimals from response
+        let decimals = response
+            .get("decimals")
+            .and_then(|v| v.as_u64())
+            .map(|d| d as u8)
+            .ok_or_else(|| Error::Other("Invalid asset decimals response format".to_string()))?;
+
+        Ok(decimals)
+    }
+
+    /// Get asset decimals from multiple pools for comprehensive mapping
+    ///
+    /// This method queries all available pools and builds a comprehensive map of
+    /// asset denominations to their decimal places using the official AssetDecimals query.
+    ///
+    /// # Returns
     ///
     /// A HashMap mapping asset denominations to their decimal places
     ///
@@ -495,7 +2772,7 @@ impl MantraDexClient {
     ) -> Result<std::collections::HashMap<String, u8>, Error> {
         use std::collections::HashMap;
 
-        let pools = self.get_pools(Some(100)).await?; // Query up to 100 pools
+        let pools = self.get_pools(&PageCursor::first(100)).await?.items;
         let mut asset_decimals_map = HashMap::new();
 
         for pool in pools {
@@ -556,7 +2833,7 @@ impl MantraDexClient {
     /// The number of decimal places for the asset
     pub async fn get_asset_decimals(&self, denom: &str) -> Result<u8, Error> {
         // First try to find a pool that contains this asset
-        let pools = self.get_pools(Some(50)).await?;
+        let pools = self.get_pools(&PageCursor::first(50)).await?.items;
 
         for pool in pools {
             let pool_id = &pool.pool_info.pool_identifier;
@@ -592,21 +2869,343 @@ impl MantraDexClient {
         }
 
         let pool = &response.pools[0];
+        if !self.is_pool_allowed(pool) {
+            return Err(Error::Policy(format!(
+                "Pool {} is hidden by the asset filter",
+                pool_id
+            )));
+        }
         Ok(pool.clone())
     }
 
-    /// Get list of pools
-    pub async fn get_pools(&self, limit: Option<u32>) -> Result<Vec<PoolInfoResponse>, Error> {
+    /// Get a page of pools
+    ///
+    /// Pools hidden by the asset filter are left out of the page, so it may hold
+    /// fewer than the cursor's limit and still be followed by another.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The page to fetch, e.g. [`PageCursor::default`] for the first
+    ///
+    /// # Returns
+    ///
+    /// The page's pools and the cursor of the next page, if any
+    pub async fn get_pools(
+        &self,
+        cursor: &PageCursor,
+    ) -> Result<Paginated<PoolInfoResponse>, Error> {
         let query = pool_manager::QueryMsg::Pools {
             pool_identifier: None,
-            start_after: None,
-            limit,
+            start_after: cursor.start_after.clone(),
+            limit: Some(cursor.limit),
         };
+        let response: PoolsResponse = self
+            .query(&self.config.contracts.pool_manager, &query)
+            .await?;
 
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        let response: PoolsResponse = self.query(&pool_manager_address, &query).await?;
+        Ok(
+            Paginated::from_page(response.pools, cursor.clone(), |pool| {
+                pool.pool_info.pool_identifier.clone()
+            })
+            .retain(|pool| self.is_pool_allowed(pool)),
+        )
+    }
+
+    /// Stream every pool, fetching `page_size` pools per query
+    ///
+    /// Pages are fetched lazily as the stream is consumed. Pools hidden by the
+    /// asset filter are skipped.
+    pub fn pools_stream(
+        &self,
+        page_size: u32,
+    ) -> BoxStream<'_, Result<PoolInfoResponse, Error>> {
+        paginate(PageCursor::first(page_size), move |cursor| async move {
+            let page = self.get_pools(&cursor).await?;
+            Ok((page.items, page.next))
+        })
+    }
+
+    /// Get every pool that holds both `denom_a` and `denom_b`
+    ///
+    /// Walks the full pool list page by page, so it also finds pools beyond the
+    /// contract's default query limit.
+    pub async fn get_pools_for_pair(
+        &self,
+        denom_a: &str,
+        denom_b: &str,
+    ) -> Result<Vec<PoolInfoResponse>, Error> {
+        self.pools_stream(POOL_PAGE_LIMIT)
+            .try_filter(|pool| future::ready(routing::pool_has_pair(pool, denom_a, denom_b)))
+            .try_collect()
+            .await
+    }
+
+    /// Simulate a swap in every available pool for the pair
+    ///
+    /// Pools are quoted with local pool math where possible (see [`crate::math`]);
+    /// the rest are simulated on chain. Pools that are disabled or whose simulation
+    /// fails are skipped.
+    pub async fn quote_pools_for_pair(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Result<Vec<PoolQuote>, Error> {
+        let pools = self
+            .available_pools_for_pair(&offer_asset.denom, ask_asset_denom)
+            .await?;
+        Ok(self.quote_pools(&pools, offer_asset, ask_asset_denom).await)
+    }
+
+    /// Pools for the pair whose operations are all enabled
+    async fn available_pools_for_pair(
+        &self,
+        denom_a: &str,
+        denom_b: &str,
+    ) -> Result<Vec<PoolInfoResponse>, Error> {
+        Ok(self
+            .get_pools_for_pair(denom_a, denom_b)
+            .await?
+            .into_iter()
+            .filter(|pool| self.get_pool_status(pool).is_available())
+            .collect())
+    }
+
+    /// Quote a swap in each of `pools`, skipping those whose simulation fails
+    async fn quote_pools(
+        &self,
+        pools: &[PoolInfoResponse],
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Vec<PoolQuote> {
+        let quote = |pool: &PoolInfoResponse, simulation| PoolQuote {
+            pool_id: pool.pool_info.pool_identifier.clone(),
+            simulation,
+            ask_liquidity: routing::pool_liquidity(pool, ask_asset_denom),
+        };
+
+        let mut quotes = Vec::with_capacity(pools.len());
+        let mut remote = Vec::new();
+        for pool in pools {
+            match math::simulate_swap(pool, offer_asset, ask_asset_denom) {
+                Some(simulation) => quotes.push(quote(pool, simulation)),
+                None => remote.push(pool),
+            }
+        }
+        if remote.is_empty() {
+            return quotes;
+        }
+
+        let requests = remote
+            .iter()
+            .map(|pool| {
+                SwapRequest::new(
+                    pool.pool_info.pool_identifier.clone(),
+                    offer_asset.clone(),
+                    ask_asset_denom,
+                )
+            })
+            .collect();
+        let simulations = self.simulate_many(requests).await;
+
+        quotes.extend(
+            remote
+                .into_iter()
+                .zip(simulations)
+                .filter_map(|(pool, simulation)| simulation.ok().map(|s| quote(pool, s))),
+        );
+        quotes
+    }
+
+    /// Quote a swap in every available pool for the pair, ranked best first
+    ///
+    /// The first quote is the pool [`select_route`](Self::select_route) picks,
+    /// honoring pins, exclusions and preferred pools; the others follow by output.
+    /// Excluded pools are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no available, non-excluded pool can quote the pair
+    pub async fn rank_pools_for_pair(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Result<Vec<PoolQuote>, Error> {
+        let quotes: Vec<PoolQuote> = self
+            .quote_pools_for_pair(offer_asset, ask_asset_denom)
+            .await?
+            .into_iter()
+            .filter(|quote| !self.routing.excludes(&quote.pool_id))
+            .collect();
+        let choice = routing::choose_route(
+            &quotes,
+            &self.routing,
+            &self.wallet_defaults.preferred_pools,
+            &offer_asset.denom,
+            ask_asset_denom,
+        )
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "No available pool found for {} -> {}",
+                offer_asset.denom, ask_asset_denom
+            ))
+        })?;
+        Ok(routing::rank_quotes(&quotes, Some(&choice.quote.pool_id)))
+    }
+
+    /// Select the pool giving the best output for this swap size
+    ///
+    /// Ties on output are broken by the deeper ask-side liquidity. If the active
+    /// wallet has preferred pools that can quote the pair, only those are considered.
+    /// Routing exclusions and pins are honored, see [`select_route`](Self::select_route).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no available pool can quote the pair
+    pub async fn select_best_pool(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Result<PoolQuote, Error> {
+        Ok(self.select_route(offer_asset, ask_asset_denom).await?.quote)
+    }
+
+    /// Select the pool for this swap, reporting whether its pinned route is still optimal
+    ///
+    /// Pools excluded by the [routing config](Self::with_routing) are skipped. A pool
+    /// pinned for the pair is used while it can quote the swap; if another pool
+    /// would return more, the returned choice carries that quote in
+    /// [`better`](RouteChoice::better). Without a usable pin this picks like
+    /// [`select_best_pool`](Self::select_best_pool).
+    ///
+    /// With a [route cache](Self::with_route_cache), a route cached for the pair
+    /// and size is reused without quoting the other pools, so its choice never
+    /// carries a [`better`](RouteChoice::better) quote.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no available, non-excluded pool can quote the pair
+    pub async fn select_route(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Result<RouteChoice, Error> {
+        let key = RouteKey::new(offer_asset, ask_asset_denom);
+        if let Some(choice) = self.cached_route(&key, offer_asset, ask_asset_denom).await {
+            return Ok(choice);
+        }
+
+        let pools = self
+            .available_pools_for_pair(&offer_asset.denom, ask_asset_denom)
+            .await?;
+        let quotes = self.quote_pools(&pools, offer_asset, ask_asset_denom).await;
+        let choice = routing::choose_route(
+            &quotes,
+            &self.routing,
+            &self.wallet_defaults.preferred_pools,
+            &offer_asset.denom,
+            ask_asset_denom,
+        )
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "No available pool found for {} -> {}",
+                offer_asset.denom, ask_asset_denom
+            ))
+        })?;
+
+        if let Some(cache) = &self.route_cache {
+            let chosen = pools
+                .iter()
+                .find(|pool| pool.pool_info.pool_identifier == choice.quote.pool_id);
+            if let Some(pool) = chosen {
+                cache.insert(key, pool, choice.pinned_pool.clone());
+            }
+        }
+        Ok(choice)
+    }
+
+    /// Quote the swap through the route cached for `key`, if it is still valid
+    ///
+    /// The route's pool is queried once to check its reserves against the
+    /// cached ones; a route that moved, or whose pool can't quote anymore, is
+    /// dropped so the caller selects a new one.
+    async fn cached_route(
+        &self,
+        key: &RouteKey,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Option<RouteChoice> {
+        let cache = self.route_cache.as_ref()?;
+        let pool = self.get_pool(&cache.get(key)?.pool_id).await.ok()?;
+        cache.observe(&pool);
+        // Still cached unless the pool moved
+        let route = cache.get(key)?;
+        if !self.get_pool_status(&pool).is_available() {
+            cache.invalidate_pool(&route.pool_id);
+            return None;
+        }
+
+        let simulation = match math::simulate_swap(&pool, offer_asset, ask_asset_denom) {
+            Some(simulation) => simulation,
+            None => self
+                .simulate_swap(&route.pool_id, offer_asset.clone(), ask_asset_denom)
+                .await
+                .ok()?,
+        };
+        Some(RouteChoice {
+            quote: PoolQuote {
+                pool_id: route.pool_id,
+                simulation,
+                ask_liquidity: routing::pool_liquidity(&pool, ask_asset_denom),
+            },
+            pinned_pool: route.pinned_pool,
+            better: None,
+        })
+    }
+
+    /// Check the pools on cached routes once, dropping the routes whose pool moved
+    ///
+    /// # Returns
+    ///
+    /// The number of routes dropped; 0 without a route cache
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pool cannot be queried
+    pub async fn refresh_route_cache(&self) -> Result<usize, Error> {
+        let Some(cache) = &self.route_cache else {
+            return Ok(0);
+        };
+        let mut dropped = 0;
+        for pool_id in cache.pools() {
+            dropped += cache.observe(&self.get_pool(&pool_id).await?);
+        }
+        Ok(dropped)
+    }
 
-        Ok(response.pools)
+    /// Check the pools on cached routes at the cache's watch interval, forever
+    ///
+    /// `on_check` gets the outcome of every check: the number of routes dropped,
+    /// or why the pools couldn't be queried. A failed check doesn't stop the
+    /// watcher.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error right away if the client has no route cache, and
+    /// otherwise only returns, with its error, once `on_check` fails
+    pub async fn watch_route_cache<F>(&self, mut on_check: F) -> Result<(), Error>
+    where
+        F: FnMut(Result<usize, Error>) -> Result<(), Error>,
+    {
+        let Some(cache) = &self.route_cache else {
+            return Err(Error::Config(
+                "Routes are not cached, see MantraDexClient::with_route_cache".to_string(),
+            ));
+        };
+        let mut interval = tokio::time::interval(cache.config().watch_interval());
+        loop {
+            interval.tick().await;
+            on_check(self.refresh_route_cache().await)?;
+        }
     }
 
     /// Extract pool status from PoolInfoResponse
@@ -642,6 +3241,8 @@ impl MantraDexClient {
     }
 
     /// Simulate a swap to see the expected amount
+    ///
+    /// Subject to the configured simulation timeout.
     pub async fn simulate_swap(
         &self,
         pool_id: &str,
@@ -655,500 +3256,2567 @@ impl MantraDexClient {
         };
 
         let pool_manager_address = self.config.contracts.pool_manager.clone();
-        self.query(&pool_manager_address, &query).await
+        self.smart_query(OperationClass::Simulate, &pool_manager_address, &query)
+            .await
     }
 
-    /// Swap tokens
-    /// Execute a swap operation on a pool
+    /// Estimate the spot price, execution price and price impact of a swap
     ///
-    /// **v3.0.0 Breaking Change**: The `max_spread` parameter has been renamed to `max_slippage`
+    /// Computed from the pool's current reserves with the local pool math; see
+    /// [`crate::pricing`]. Use [`PriceImpact::warning`] to warn before a large
+    /// trade.
     ///
     /// # Arguments
     ///
-    /// * `pool_id` - The identifier of the pool to swap in
-    /// * `offer_asset` - The asset being offered for swap
-    /// * `ask_asset_denom` - The denomination of the asset being requested
-    /// * `max_slippage` - Optional maximum slippage tolerance (replaces `max_spread` from v2.x)
+    /// * `pool_id` - Pool to swap in
+    /// * `offer_asset` - Asset and amount being sold
+    /// * `ask_asset_denom` - Denom being bought
     ///
     /// # Returns
     ///
-    /// Transaction response containing the swap result
+    /// The swap's prices and impact
     ///
     /// # Errors
     ///
-    /// * Returns error if pool status validation fails (pool must be Available)
-    /// * Returns error if the swap transaction fails
-    /// * Returns error if no wallet is configured
-    pub async fn swap(
+    /// * Returns error if the pool cannot be queried or is not allowed
+    /// * Returns error if the pool doesn't hold both assets or can't be simulated locally
+    pub async fn estimate_price_impact(
         &self,
         pool_id: &str,
-        offer_asset: Coin,
+        offer_asset: &Coin,
         ask_asset_denom: &str,
-        max_slippage: Option<Decimal>,
-    ) -> Result<TxResponse, Error> {
-        // Input validation
-        if pool_id.trim().is_empty() {
-            return Err(Error::Other("Pool ID cannot be empty".to_string()));
-        }
-        if offer_asset.amount.is_zero() {
-            return Err(Error::Other("Offer amount must be greater than zero".to_string()));
-        }
-        if offer_asset.denom.trim().is_empty() {
-            return Err(Error::Other("Offer asset denom cannot be empty".to_string()));
-        }
-        if ask_asset_denom.trim().is_empty() {
-            return Err(Error::Other("Ask asset denom cannot be empty".to_string()));
-        }
-
-        // Validate pool status before executing swap
-        self.validate_pool_status(pool_id).await?;
-
-        let msg = pool_manager::ExecuteMsg::Swap {
-            pool_identifier: pool_id.to_string(),
-            belief_price: None,
-            receiver: None,
-            ask_asset_denom: ask_asset_denom.to_string(),
-            max_slippage: max_slippage.map(|d| {
-                // Convert the Decimal to the version used by mantra_dex_std
-                let decimal_str = d.to_string();
-                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
-            }),
-        };
+    ) -> Result<PriceImpact, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        pricing::price_impact(&pool, offer_asset, ask_asset_denom).ok_or_else(|| {
+            Error::Other(format!(
+                "Cannot estimate the price impact of swapping {} for {} in pool {}",
+                offer_asset, ask_asset_denom, pool_id
+            ))
+        })
+    }
 
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        self.execute(&pool_manager_address, &msg, vec![offer_asset])
+    /// Simulate several candidate swaps concurrently
+    ///
+    /// Runs up to [`DEFAULT_SIMULATION_CONCURRENCY`] simulations at a time.
+    /// See [`simulate_many_with_concurrency`](Self::simulate_many_with_concurrency).
+    pub async fn simulate_many(
+        &self,
+        requests: Vec<SwapRequest>,
+    ) -> Vec<Result<SimulationResponse, Error>> {
+        self.simulate_many_with_concurrency(requests, DEFAULT_SIMULATION_CONCURRENCY)
             .await
     }
 
-    /// Provide liquidity to a pool
-    ///
-    /// **v3.0.0 Breaking Changes**:
-    /// - `slippage_tolerance` parameter renamed to `liquidity_max_slippage`
-    /// - `max_spread` parameter renamed to `swap_max_slippage`
+    /// Simulate several candidate swaps with bounded parallelism
     ///
     /// # Arguments
     ///
-    /// * `pool_id` - The identifier of the pool to provide liquidity to
-    /// * `assets` - Vector of assets to provide as liquidity
-    /// * `liquidity_max_slippage` - Optional maximum slippage for liquidity provision (replaces `slippage_tolerance`)
-    /// * `swap_max_slippage` - Optional maximum slippage for internal swaps (replaces `max_spread`)
+    /// * `requests` - The swaps to simulate
+    /// * `concurrency` - Maximum number of simulations in flight (values below 1 are treated as 1)
     ///
     /// # Returns
     ///
-    /// Transaction response containing the liquidity provision result
-    ///
-    /// # Errors
-    ///
-    /// * Returns error if pool status validation fails (pool must be Available)
-    /// * Returns error if the liquidity provision transaction fails
-    /// * Returns error if no wallet is configured
-    pub async fn provide_liquidity(
+    /// One result per request, in the same order as `requests`. A failed simulation
+    /// does not affect the others.
+    pub async fn simulate_many_with_concurrency(
         &self,
-        pool_id: &str,
-        assets: Vec<Coin>,
-        liquidity_max_slippage: Option<Decimal>,
-        swap_max_slippage: Option<Decimal>,
-    ) -> Result<TxResponse, Error> {
-        // Validate pool status before providing liquidity
-        self.validate_pool_status(pool_id).await?;
-
-        let msg = pool_manager::ExecuteMsg::ProvideLiquidity {
-            pool_identifier: pool_id.to_string(),
-            liquidity_max_slippage: liquidity_max_slippage.map(|d| {
-                // Convert the Decimal to the version used by mantra_dex_std
-                let decimal_str = d.to_string();
-                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
-            }),
-            swap_max_slippage: swap_max_slippage.map(|d| {
-                // Convert the Decimal to the version used by mantra_dex_std
-                let decimal_str = d.to_string();
-                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
-            }),
-            receiver: None,
-            unlocking_duration: None,
-            lock_position_identifier: None,
-        };
-
-        let mut coins: Vec<Coin> = assets
-            .into_iter()
-            .map(|a| Coin {
-                denom: a.denom,
-                amount: a.amount,
+        requests: Vec<SwapRequest>,
+        concurrency: usize,
+    ) -> Vec<Result<SimulationResponse, Error>> {
+        stream::iter(requests)
+            .map(|request| async move {
+                self.simulate_swap(
+                    &request.pool_id,
+                    request.offer_asset,
+                    &request.ask_asset_denom,
+                )
+                .await
             })
-            .collect();
-
-        // Sort coins by denomination as required by Cosmos SDK
-        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
-
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        self.execute(&pool_manager_address, &msg, coins).await
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
-    /// Provide liquidity to a pool without status validation (for creating new pools)
-    ///
-    /// This method bypasses pool status validation and should only be used when creating new pools
-    /// or in scenarios where pool status checking is not required.
+    /// Swap tokens
+    /// Execute a swap operation on a pool
     ///
-    /// **v3.0.0 Breaking Changes**: Same parameter renames as `provide_liquidity`
+    /// **v3.0.0 Breaking Change**: The `max_spread` parameter has been renamed to `max_slippage`
     ///
     /// # Arguments
     ///
-    /// * `pool_id` - The identifier of the pool to provide liquidity to
-    /// * `assets` - Vector of assets to provide as liquidity
-    /// * `liquidity_max_slippage` - Optional maximum slippage for liquidity provision
-    /// * `swap_max_slippage` - Optional maximum slippage for internal swaps
+    /// * `pool_id` - The identifier of the pool to swap in
+    /// * `offer_asset` - The asset being offered for swap
+    /// * `ask_asset_denom` - The denomination of the asset being requested
+    /// * `max_slippage` - Optional maximum slippage tolerance (replaces `max_spread` from v2.x)
     ///
     /// # Returns
     ///
-    /// Transaction response containing the liquidity provision result
+    /// Transaction response containing the swap result
     ///
     /// # Errors
     ///
-    /// * Returns error if the liquidity provision transaction fails
+    /// * Returns error if pool status validation fails (pool must be Available)
+    /// * Returns [`Error::Policy`] if the swap is blocked by the trade size guard
+    /// * Returns error if the swap transaction fails
     /// * Returns error if no wallet is configured
-    pub async fn provide_liquidity_unchecked(
+    pub async fn swap(
         &self,
         pool_id: &str,
-        assets: Vec<Coin>,
-        liquidity_max_slippage: Option<Decimal>,
-        swap_max_slippage: Option<Decimal>,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        max_slippage: Option<Decimal>,
     ) -> Result<TxResponse, Error> {
-        let msg = pool_manager::ExecuteMsg::ProvideLiquidity {
-            pool_identifier: pool_id.to_string(),
-            liquidity_max_slippage: liquidity_max_slippage.map(|d| {
-                // Convert the Decimal to the version used by mantra_dex_std
-                let decimal_str = d.to_string();
-                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
-            }),
-            swap_max_slippage: swap_max_slippage.map(|d| {
-                // Convert the Decimal to the version used by mantra_dex_std
-                let decimal_str = d.to_string();
-                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
-            }),
-            receiver: None,
-            unlocking_duration: None,
-            lock_position_identifier: None,
-        };
-
-        let mut coins: Vec<Coin> = assets
-            .into_iter()
-            .map(|a| Coin {
-                denom: a.denom,
-                amount: a.amount,
-            })
-            .collect();
-
-        // Sort coins by denomination as required by Cosmos SDK
-        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
-
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        self.execute(&pool_manager_address, &msg, coins).await
+        self.swap_inner(pool_id, offer_asset, ask_asset_denom, max_slippage, true)
+            .await
     }
 
-    /// Withdraw liquidity from a pool
-    pub async fn withdraw_liquidity(
+    /// Execute a swap without enforcing the trade size guard
+    ///
+    /// Same as [`swap`](Self::swap) for trades the caller knowingly wants to make
+    /// even though they take a large share of the pool's reserves.
+    pub async fn force_swap(
         &self,
         pool_id: &str,
-        lp_amount: Uint128,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        max_slippage: Option<Decimal>,
     ) -> Result<TxResponse, Error> {
-        // Get pool info and validate status in one call
-        let pool = self.get_pool(pool_id).await?;
-        let status = self.get_pool_status(&pool);
-
-        if !status.is_available() {
-            return Err(Error::Other(format!(
-                "Pool {} is not available for operations (status: {:?})",
-                pool_id, status
-            )));
-        }
-
-        let lp_denom = pool.pool_info.lp_denom;
-        let msg = pool_manager::ExecuteMsg::WithdrawLiquidity {
-            pool_identifier: pool_id.to_string(),
-        };
-        let funds = vec![Coin {
-            denom: lp_denom,
-            amount: lp_amount,
-        }];
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        self.execute(&pool_manager_address, &msg, funds).await
+        self.swap_inner(pool_id, offer_asset, ask_asset_denom, max_slippage, false)
+            .await
     }
 
-    /// Query the pool manager configuration
-    pub async fn get_pool_manager_config(
+    /// Check a swap against the configured trade size guard and value limit
+    ///
+    /// Values the offer in the limit's denom, then simulates the swap and
+    /// compares the expected return with the pool's ask-side reserve.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if the guard or the limit blocks the swap, or
+    /// the offer can't be valued in the limit's denom
+    pub async fn check_trade_size(
         &self,
-    ) -> Result<mantra_dex_std::pool_manager::Config, Error> {
-        let query = pool_manager::QueryMsg::Config {};
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        // The contract returns Config directly, not wrapped in ConfigResponse
-        let config: mantra_dex_std::pool_manager::Config =
-            self.query(&pool_manager_address, &query).await?;
-        Ok(config)
-    }
+        pool_id: &str,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+    ) -> Result<(), Error> {
+        if let Some(limit) = &self.trade_value_limit {
+            let value = self
+                .convert_amount(offer_asset.amount, &offer_asset.denom, &limit.denom)
+                .await
+                .map_err(|e| {
+                    Error::Policy(format!(
+                        "Can't check the trade value limit: {}; use force to override",
+                        e
+                    ))
+                })?;
+            limit.check(offer_asset, value.to.amount)?;
+        }
+        if !self.trade_size_guard.enabled {
+            return Ok(());
+        }
 
-    /// Get the pool creation fee from the pool manager configuration
-    pub async fn get_pool_creation_fee(&self) -> Result<Coin, Error> {
-        let config = self.get_pool_manager_config().await?;
-        Ok(config.pool_creation_fee)
+        let pool = self.get_pool(pool_id).await?;
+        let simulation = self
+            .simulate_swap(pool_id, offer_asset.clone(), ask_asset_denom)
+            .await?;
+        self.trade_size_guard.check(
+            pool_id,
+            ask_asset_denom,
+            simulation.return_amount,
+            routing::pool_liquidity(&pool, ask_asset_denom),
+        )
     }
 
-    /// Create a new pool with the specified assets and configuration
+    /// Retry a swap that failed on slippage with a higher slippage tolerance
     ///
-    /// **v3.0.0 New Feature**: Enhanced fee validation ensures total fees ≤ 20%
+    /// The swap is simulated again against the current pool state, and its
+    /// tolerance raised as `policy` allows: by the policy's step, or to the
+    /// slippage the simulation expects if that is higher, up to the policy's
+    /// ceiling. The trade size guard is enforced as by [`swap`](Self::swap).
     ///
     /// # Arguments
     ///
-    /// * `asset_denoms` - Vector of asset denominations for the pool
-    /// * `asset_decimals` - Vector of decimal places for each asset
-    /// * `pool_fees` - Fee structure for the pool (validated against v3.0.0 requirements)
-    /// * `pool_type` - Type of pool to create
-    /// * `pool_identifier` - Optional custom identifier for the pool
+    /// * `previous` - The swap that failed on slippage
+    /// * `policy` - How far the tolerance may be raised
     ///
     /// # Returns
     ///
-    /// Transaction response containing the pool creation result
+    /// The executed swap, linked to the tolerance of the failed attempt
     ///
     /// # Errors
     ///
-    /// * Returns `FeeValidation` error if pool fees exceed 20% total
-    /// * Returns error if pool creation transaction fails
-    /// * Returns error if no wallet is configured
+    /// * Returns [`Error::Policy`] if the tolerance can't be raised within the ceiling
+    /// * Returns any error from [`swap`](Self::swap)
+    pub async fn retry_swap_with_slippage_bump(
+        &self,
+        previous: &SwapRequest,
+        policy: &SlippageBumpPolicy,
+    ) -> Result<SwapRetry, Error> {
+        let request = self.bump_swap_slippage(previous, policy).await?;
+        let tx = self.swap_request(&request).await?;
+        Ok(SwapRetry {
+            request,
+            failed_slippages: vec![self.effective_slippage(previous)],
+            tx,
+        })
+    }
+
+    /// Execute a swap, retrying with a higher tolerance while it fails on slippage
     ///
-    /// # Notes
+    /// Makes up to `policy.max_retries` retries, each as
+    /// [`retry_swap_with_slippage_bump`](Self::retry_swap_with_slippage_bump).
+    /// Errors other than exceeded slippage are returned immediately.
     ///
-    /// Pool creation requires a fee that is determined by querying the pool manager configuration
-    pub async fn create_pool(
+    /// # Arguments
+    ///
+    /// * `request` - The swap to execute
+    /// * `policy` - How far and how often the tolerance may be raised
+    ///
+    /// # Returns
+    ///
+    /// The executed swap and the tolerances of the attempts that failed, if any
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Policy`] if the tolerance can't be raised within the ceiling
+    /// * Returns the last slippage error once the retries are used up
+    /// * Returns any other error from [`swap`](Self::swap)
+    pub async fn swap_with_slippage_retries(
         &self,
-        asset_denoms: Vec<String>,
-        asset_decimals: Vec<u8>,
-        pool_fees: mantra_dex_std::fee::PoolFee,
-        pool_type: mantra_dex_std::pool_manager::PoolType,
-        pool_identifier: Option<String>,
-    ) -> Result<TxResponse, Error> {
-        // Validate pool fees before creating the pool (v3.0.0 requirement)
-        self.validate_pool_fees(&pool_fees)?;
-
-        let msg = pool_manager::ExecuteMsg::CreatePool {
-            asset_denoms,
-            asset_decimals,
-            pool_fees,
-            pool_type,
-            pool_identifier,
-        };
-
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        request: SwapRequest,
+        policy: &SlippageBumpPolicy,
+    ) -> Result<SwapRetry, Error> {
+        let mut request = request;
+        let mut failed_slippages = Vec::new();
+        loop {
+            match self.swap_request(&request).await {
+                Ok(tx) => {
+                    return Ok(SwapRetry {
+                        request,
+                        failed_slippages,
+                        tx,
+                    })
+                }
+                Err(e)
+                    if e.is_slippage_exceeded()
+                        && failed_slippages.len() < policy.max_retries as usize =>
+                {
+                    failed_slippages.push(self.effective_slippage(&request));
+                    request = self.bump_swap_slippage(&request, policy).await?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
 
-        // Query the actual pool creation fee from the contract configuration
-        let creation_fee = self.get_pool_creation_fee().await?;
+    /// Slippage tolerance a swap request is executed with
+    fn effective_slippage(&self, request: &SwapRequest) -> Decimal {
+        request
+            .max_slippage
+            .or(self.wallet_defaults.slippage)
+            .unwrap_or(DEFAULT_SWAP_SLIPPAGE)
+    }
 
-        // Handle case where contract config shows 0 but contract actually expects 88 OM
-        let pool_creation_fee = if creation_fee.amount.is_zero() {
-            // Fallback to known testnet pool creation fee of 88 OM
-            vec![Coin {
-                denom: "uom".to_string(),
-                amount: Uint128::new(88_000_000), // 88 OM
-            }]
+    /// Re-simulate a swap and raise its tolerance for a retry
+    async fn bump_swap_slippage(
+        &self,
+        previous: &SwapRequest,
+        policy: &SlippageBumpPolicy,
+    ) -> Result<SwapRequest, Error> {
+        let simulation = self
+            .simulate_swap(
+                &previous.pool_id,
+                previous.offer_asset.clone(),
+                &previous.ask_asset_denom,
+            )
+            .await?;
+        let total = simulation
+            .return_amount
+            .saturating_add(simulation.slippage_amount);
+        let simulated = if total.is_zero() {
+            Decimal::zero()
         } else {
-            vec![creation_fee]
+            Decimal::from_ratio(simulation.slippage_amount, total)
         };
 
-        self.execute(&pool_manager_address, &msg, pool_creation_fee)
-            .await
+        let slippage = policy.next_slippage(self.effective_slippage(previous), simulated)?;
+        Ok(previous.clone().with_max_slippage(slippage))
     }
 
-    /// Execute multiple swap operations
-    pub async fn execute_swap_operations(
+    async fn swap_request(&self, request: &SwapRequest) -> Result<TxResponse, Error> {
+        self.swap(
+            &request.pool_id,
+            request.offer_asset.clone(),
+            &request.ask_asset_denom,
+            request.max_slippage,
+        )
+        .await
+    }
+
+    async fn swap_inner(
         &self,
-        operations: Vec<SwapOperation>,
-        amount: Uint128,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        max_slippage: Option<Decimal>,
+        enforce_guard: bool,
     ) -> Result<TxResponse, Error> {
-        let first_op = operations
-            .first()
-            .ok_or_else(|| Error::Other("Swap operations list cannot be empty".to_string()))?;
+        // The trade policy middleware checks the swap before it is signed
+        let msg = self
+            .swap_msg(pool_id, offer_asset, ask_asset_denom, max_slippage)
+            .await?;
+        self.send_tx(vec![msg], !enforce_guard).await
+    }
 
-        // Validate pool status for the first operation
-        self.validate_pool_status(&first_op.get_pool_identifer())
+    /// Estimate the gas and fee of a swap by simulating its transaction
+    ///
+    /// Takes the same arguments as [`swap`](Self::swap) and runs the same checks,
+    /// without signing or broadcasting anything.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the arguments are invalid or the pool is not available
+    /// * Returns any error from [`estimate_gas`](Self::estimate_gas)
+    pub async fn estimate_swap(
+        &self,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        max_slippage: Option<Decimal>,
+    ) -> Result<GasEstimate, Error> {
+        let msg = self
+            .swap_msg(pool_id, offer_asset, ask_asset_denom, max_slippage)
             .await?;
+        self.estimate_gas(vec![msg]).await
+    }
+
+    /// Validate a swap and build its message
+    async fn swap_msg(
+        &self,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        max_slippage: Option<Decimal>,
+    ) -> Result<Any, Error> {
+        // Input validation
+        if pool_id.trim().is_empty() {
+            return Err(Error::Other("Pool ID cannot be empty".to_string()));
+        }
+        if offer_asset.amount.is_zero() {
+            return Err(Error::Other("Offer amount must be greater than zero".to_string()));
+        }
+        if offer_asset.denom.trim().is_empty() {
+            return Err(Error::Other("Offer asset denom cannot be empty".to_string()));
+        }
+        if ask_asset_denom.trim().is_empty() {
+            return Err(Error::Other("Ask asset denom cannot be empty".to_string()));
+        }
+
+        self.check_denoms_allowed(&[&offer_asset.denom, ask_asset_denom])?;
+
+        // Fall back to the active wallet's default slippage
+        let max_slippage = max_slippage.or(self.wallet_defaults.slippage);
+
+        // Validate pool status before executing swap
+        self.validate_pool_status(pool_id).await?;
 
         let msg = pool_manager::ExecuteMsg::Swap {
-            ask_asset_denom: first_op.get_target_asset_info().clone(),
+            pool_identifier: pool_id.to_string(),
             belief_price: None,
-            max_slippage: None,
             receiver: None,
-            pool_identifier: first_op.get_pool_identifer().clone(),
+            ask_asset_denom: ask_asset_denom.to_string(),
+            max_slippage: max_slippage.map(|d| {
+                // Convert the Decimal to the version used by mantra_dex_std
+                let decimal_str = d.to_string();
+                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
+            }),
         };
 
-        let pool_manager_address = self.config.contracts.pool_manager.clone();
-        self.execute(
-            &pool_manager_address,
+        self.execute_contract_msg(
+            &self.config.contracts.pool_manager,
             &msg,
-            vec![Coin {
-                denom: first_op.get_input_asset_info().clone(),
-                amount,
-            }],
+            vec![offer_asset],
+        )
+    }
+
+    /// Swap tokens by asset pair, choosing the pool automatically
+    ///
+    /// With [`PoolSelection::Best`] every available pool for the pair is simulated
+    /// and the one with the best output is used. [`PoolSelection::Pool`] forces a
+    /// specific pool, like [`swap`](Self::swap).
+    ///
+    /// # Arguments
+    ///
+    /// * `offer_asset` - The asset being offered for swap
+    /// * `ask_asset_denom` - The denomination of the asset being requested
+    /// * `max_slippage` - Optional maximum slippage tolerance
+    /// * `selection` - How to choose the pool
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the swap result
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if no available pool can quote the pair
+    /// * Returns any error from [`swap`](Self::swap)
+    pub async fn swap_pair(
+        &self,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        max_slippage: Option<Decimal>,
+        selection: PoolSelection,
+    ) -> Result<TxResponse, Error> {
+        let pool_id = match selection {
+            PoolSelection::Pool(pool_id) => pool_id,
+            PoolSelection::Best => {
+                self.select_best_pool(&offer_asset, ask_asset_denom)
+                    .await?
+                    .pool_id
+            }
+        };
+
+        self.swap(&pool_id, offer_asset, ask_asset_denom, max_slippage)
+            .await
+    }
+
+    /// Quote a swap for later execution with [`execute_quote`](Self::execute_quote)
+    ///
+    /// The swap is simulated in the requested pool, or the best one, and the quote
+    /// stays valid for the request's time to live and number of blocks. With a
+    /// wallet configured the quote is signed with its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The swap to quote, see [`QuoteRequest`]
+    ///
+    /// # Returns
+    ///
+    /// The quote, with the expected return and fee breakdown
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if no available pool can quote the pair
+    /// * Returns error if the simulation fails
+    pub async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, Error> {
+        self.check_denoms_allowed(&[&request.offer_asset.denom, &request.ask_asset_denom])?;
+        let (pool_id, simulation) = match &request.selection {
+            PoolSelection::Pool(pool_id) => {
+                let simulation = self
+                    .simulate_swap(
+                        pool_id,
+                        request.offer_asset.clone(),
+                        &request.ask_asset_denom,
+                    )
+                    .await?;
+                (pool_id.clone(), simulation)
+            }
+            PoolSelection::Best => {
+                let quote = self
+                    .select_best_pool(&request.offer_asset, &request.ask_asset_denom)
+                    .await?;
+                (quote.pool_id, quote.simulation)
+            }
+        };
+
+        let height = self.get_last_block_height().await?;
+        let now = chrono::Utc::now();
+        let ttl = chrono::Duration::from_std(request.ttl)
+            .map_err(|e| Error::Other(format!("Invalid quote time to live: {}", e)))?;
+        let mut quote = Quote {
+            pool_id,
+            offer_asset: request.offer_asset.clone(),
+            ask_asset_denom: request.ask_asset_denom.clone(),
+            expected_return: simulation.return_amount,
+            fees: FeeBreakdown::from_simulation(&simulation, &request.ask_asset_denom),
+            max_slippage: request.max_slippage,
+            price_tolerance: request.price_tolerance,
+            quoted_at: now,
+            quoted_height: height,
+            valid_until: now + ttl,
+            valid_until_height: height + request.valid_blocks,
+            signer: None,
+            signature: None,
+        };
+        if let Some(wallet) = &self.wallet {
+            quote.sign(wallet)?;
+        }
+        Ok(quote)
+    }
+
+    /// Execute a swap quoted by [`get_quote`](Self::get_quote)
+    ///
+    /// The quote must be signed by the configured wallet and unchanged. It is
+    /// simulated again right before sending, and refused if the swap would now
+    /// return less than the quote's tolerance allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote` - The quote to execute
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the swap result
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Policy`] if the quote is not signed by the wallet, expired,
+    ///   or the price moved beyond its tolerance
+    /// * Returns any error from [`swap`](Self::swap)
+    pub async fn execute_quote(&self, quote: &Quote) -> Result<TxResponse, Error> {
+        quote.verify(self.wallet()?)?;
+        let height = self.get_last_block_height().await?;
+        quote.check_expiry(chrono::Utc::now(), height)?;
+
+        let simulation = self
+            .simulate_swap(
+                &quote.pool_id,
+                quote.offer_asset.clone(),
+                &quote.ask_asset_denom,
+            )
+            .await?;
+        quote.check_price(simulation.return_amount)?;
+
+        self.swap(
+            &quote.pool_id,
+            quote.offer_asset.clone(),
+            &quote.ask_asset_denom,
+            quote.max_slippage,
         )
         .await
     }
 
-    // =========================
-    // Farm Manager Functionality
-    // =========================
+    /// Sample a pool's depth curve: the return for offers up to `max_offer`
+    ///
+    /// `max_offer` is divided into `steps` equal sizes, each simulated against the
+    /// pool. The curve stops at the first size the pool can't fill.
+    pub async fn depth_curve(
+        &self,
+        pool_id: &str,
+        max_offer: &Coin,
+        ask_asset_denom: &str,
+        steps: u32,
+    ) -> Result<DepthCurve, Error> {
+        let steps = steps.max(1);
+        let offers: Vec<Uint128> = (1..=steps)
+            .map(|chunks| routing::chunk_amount(max_offer.amount, chunks, steps))
+            .collect();
+
+        let pool = self.get_pool(pool_id).await?;
+        let sweep = self
+            .sweep_pool(&pool, &max_offer.denom, ask_asset_denom, offers)
+            .await?;
+
+        Ok(DepthCurve::from_samples(
+            pool_id,
+            &max_offer.denom,
+            ask_asset_denom,
+            sweep.samples,
+        ))
+    }
+
+    /// Expected outputs of a pool for a range of trade sizes
+    ///
+    /// The pool must hold exactly two assets; the one that isn't `denom_in` is
+    /// returned. Constant product pools are computed locally from a single pool
+    /// query; other pools fall back to one chain simulation per amount.
+    pub async fn simulate_sweep(
+        &self,
+        pool_id: &str,
+        denom_in: &str,
+        amounts: Vec<Uint128>,
+    ) -> Result<SimulationSweep, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        let ask_denoms: Vec<&String> = pool
+            .pool_info
+            .asset_denoms
+            .iter()
+            .filter(|denom| *denom != denom_in)
+            .collect();
+        let ask_denom = match ask_denoms.as_slice() {
+            [ask_denom] => (*ask_denom).clone(),
+            _ => {
+                return Err(Error::Other(format!(
+                    "Pool {} must hold {} and exactly one other asset to sweep",
+                    pool_id, denom_in
+                )))
+            }
+        };
+
+        self.sweep_pool(&pool, denom_in, &ask_denom, amounts).await
+    }
+
+    /// Sweep an already fetched pool, preferring local pool math
+    async fn sweep_pool(
+        &self,
+        pool: &PoolInfoResponse,
+        offer_denom: &str,
+        ask_denom: &str,
+        amounts: Vec<Uint128>,
+    ) -> Result<SimulationSweep, Error> {
+        let pool_id = pool.pool_info.pool_identifier.clone();
+        if !routing::pool_has_pair(pool, offer_denom, ask_denom) {
+            return Err(Error::Other(format!(
+                "Pool {} does not trade {} for {}",
+                pool_id, offer_denom, ask_denom
+            )));
+        }
+
+        let local: Option<Vec<(Uint128, Uint128)>> = amounts
+            .iter()
+            .map(|amount| {
+                let offer_asset = Coin {
+                    denom: offer_denom.to_string(),
+                    amount: *amount,
+                };
+                math::simulate_swap(pool, &offer_asset, ask_denom)
+                    .map(|simulation| (*amount, simulation.return_amount))
+            })
+            .collect();
+        if let Some(samples) = local {
+            return Ok(SimulationSweep {
+                pool_id,
+                offer_denom: offer_denom.to_string(),
+                ask_denom: ask_denom.to_string(),
+                source: SweepSource::PoolMath,
+                samples,
+            });
+        }
+
+        let requests = amounts
+            .iter()
+            .map(|amount| {
+                SwapRequest::new(
+                    pool_id.clone(),
+                    Coin {
+                        denom: offer_denom.to_string(),
+                        amount: *amount,
+                    },
+                    ask_denom,
+                )
+            })
+            .collect();
+
+        let mut samples = Vec::with_capacity(amounts.len());
+        for (amount, simulation) in amounts.into_iter().zip(self.simulate_many(requests).await) {
+            match simulation {
+                Ok(simulation) => samples.push((amount, simulation.return_amount)),
+                Err(e) if samples.is_empty() => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok(SimulationSweep {
+            pool_id,
+            offer_denom: offer_denom.to_string(),
+            ask_denom: ask_denom.to_string(),
+            source: SweepSource::Chain,
+            samples,
+        })
+    }
+
+    /// Plan how to split a large order across the pools for a pair
+    ///
+    /// Each available pool is swept at `steps` increasing sizes (see
+    /// [`Self::simulate_sweep`]), then the order is allocated chunk by chunk to the pool with the best marginal
+    /// return. See [`routing::plan_split`]. Pools excluded from routing are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `offer_asset` - The full order to split
+    /// * `ask_asset_denom` - The denomination of the asset being requested
+    /// * `steps` - Number of chunks the order is divided into
+    ///   (see [`routing::DEFAULT_SPLIT_STEPS`])
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no available pool can quote the pair
+    pub async fn plan_order_split(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+        steps: u32,
+    ) -> Result<SplitPlan, Error> {
+        let steps = steps.max(1);
+        let pools: Vec<PoolInfoResponse> = self
+            .get_pools_for_pair(&offer_asset.denom, ask_asset_denom)
+            .await?
+            .into_iter()
+            .filter(|pool| {
+                self.get_pool_status(pool).is_available()
+                    && !self.routing.excludes(&pool.pool_info.pool_identifier)
+            })
+            .collect();
+        let amounts: Vec<Uint128> = (1..=steps)
+            .map(|chunks| routing::chunk_amount(offer_asset.amount, chunks, steps))
+            .collect();
+
+        // A pool that can't fill even the first chunk gets an empty curve
+        let mut curves = Vec::with_capacity(pools.len());
+        for pool in &pools {
+            let returns = self
+                .sweep_pool(pool, &offer_asset.denom, ask_asset_denom, amounts.clone())
+                .await
+                .map(|sweep| sweep.returns())
+                .unwrap_or_default();
+            curves.push(PoolCurve {
+                pool_id: pool.pool_info.pool_identifier.clone(),
+                returns,
+            });
+        }
+
+        let plan = routing::plan_split(
+            &offer_asset.denom,
+            ask_asset_denom,
+            offer_asset.amount,
+            steps,
+            &curves,
+        );
+        if plan.legs.is_empty() {
+            return Err(Error::Other(format!(
+                "No available pool found for {} -> {}",
+                offer_asset.denom, ask_asset_denom
+            )));
+        }
+        Ok(plan)
+    }
+
+    /// Execute every leg of a split plan in a single transaction
+    ///
+    /// Each leg is sent as its own swap with that leg's `max_slippage`, so the
+    /// transaction fails as a whole if any leg exceeds its limit.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if any leg's pool is not available
+    /// * Returns [`Error::Policy`] if any leg is blocked by the trade size guard
+    /// * Returns error if the batch transaction fails
+    /// * Returns error if no wallet is configured
+    pub async fn execute_split_plan(&self, plan: &SplitPlan) -> Result<TxResponse, Error> {
+        let mut msgs = Vec::with_capacity(plan.legs.len());
+        for leg in &plan.legs {
+            let pool = self.get_pool(&leg.pool_id).await?;
+            if !self.get_pool_status(&pool).is_available() {
+                return Err(Error::Other(format!(
+                    "Pool {} is not available for operations",
+                    leg.pool_id
+                )));
+            }
+
+            let msg = pool_manager::ExecuteMsg::Swap {
+                pool_identifier: leg.pool_id.clone(),
+                belief_price: None,
+                receiver: None,
+                ask_asset_denom: plan.ask_denom.clone(),
+                max_slippage: leg.max_slippage,
+            };
+            let funds = vec![Coin {
+                denom: plan.offer_denom.clone(),
+                amount: leg.offer_amount,
+            }];
+            msgs.push((msg, funds));
+        }
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        self.execute_batch(&pool_manager_address, msgs).await
+    }
+
+    /// Provide liquidity to a pool
+    ///
+    /// **v3.0.0 Breaking Changes**:
+    /// - `slippage_tolerance` parameter renamed to `liquidity_max_slippage`
+    /// - `max_spread` parameter renamed to `swap_max_slippage`
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_id` - The identifier of the pool to provide liquidity to
+    /// * `assets` - Vector of assets to provide as liquidity
+    /// * `liquidity_max_slippage` - Optional maximum slippage for liquidity provision (replaces `slippage_tolerance`)
+    /// * `swap_max_slippage` - Optional maximum slippage for internal swaps (replaces `max_spread`)
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the liquidity provision result
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if pool status validation fails (pool must be Available)
+    /// * Returns error if the liquidity provision transaction fails
+    /// * Returns error if no wallet is configured
+    pub async fn provide_liquidity(
+        &self,
+        pool_id: &str,
+        assets: Vec<Coin>,
+        liquidity_max_slippage: Option<Decimal>,
+        swap_max_slippage: Option<Decimal>,
+    ) -> Result<TxResponse, Error> {
+        // Validate pool status before providing liquidity
+        self.validate_pool_status(pool_id).await?;
+
+        let msg =
+            self.provide_liquidity_msg(pool_id, assets, liquidity_max_slippage, swap_max_slippage)?;
+        self.broadcast_tx(vec![msg]).await
+    }
+
+    /// Estimate the gas and fee of a liquidity provision by simulating its transaction
+    ///
+    /// Takes the same arguments as [`provide_liquidity`](Self::provide_liquidity)
+    /// and validates the pool status, without signing or broadcasting anything.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if pool status validation fails (pool must be Available)
+    /// * Returns any error from [`estimate_gas`](Self::estimate_gas)
+    pub async fn estimate_provide_liquidity(
+        &self,
+        pool_id: &str,
+        assets: Vec<Coin>,
+        liquidity_max_slippage: Option<Decimal>,
+        swap_max_slippage: Option<Decimal>,
+    ) -> Result<GasEstimate, Error> {
+        self.validate_pool_status(pool_id).await?;
+        let msg =
+            self.provide_liquidity_msg(pool_id, assets, liquidity_max_slippage, swap_max_slippage)?;
+        self.estimate_gas(vec![msg]).await
+    }
+
+    /// Provide liquidity to a pool without status validation (for creating new pools)
+    ///
+    /// This method bypasses pool status validation and should only be used when creating new pools
+    /// or in scenarios where pool status checking is not required.
+    ///
+    /// **v3.0.0 Breaking Changes**: Same parameter renames as `provide_liquidity`
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_id` - The identifier of the pool to provide liquidity to
+    /// * `assets` - Vector of assets to provide as liquidity
+    /// * `liquidity_max_slippage` - Optional maximum slippage for liquidity provision
+    /// * `swap_max_slippage` - Optional maximum slippage for internal swaps
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the liquidity provision result
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the liquidity provision transaction fails
+    /// * Returns error if no wallet is configured
+    pub async fn provide_liquidity_unchecked(
+        &self,
+        pool_id: &str,
+        assets: Vec<Coin>,
+        liquidity_max_slippage: Option<Decimal>,
+        swap_max_slippage: Option<Decimal>,
+    ) -> Result<TxResponse, Error> {
+        let msg =
+            self.provide_liquidity_msg(pool_id, assets, liquidity_max_slippage, swap_max_slippage)?;
+        self.broadcast_tx(vec![msg]).await
+    }
+
+    /// Build the message providing `assets` to a pool
+    fn provide_liquidity_msg(
+        &self,
+        pool_id: &str,
+        assets: Vec<Coin>,
+        liquidity_max_slippage: Option<Decimal>,
+        swap_max_slippage: Option<Decimal>,
+    ) -> Result<Any, Error> {
+        let msg = pool_manager::ExecuteMsg::ProvideLiquidity {
+            pool_identifier: pool_id.to_string(),
+            liquidity_max_slippage: liquidity_max_slippage.map(|d| {
+                // Convert the Decimal to the version used by mantra_dex_std
+                let decimal_str = d.to_string();
+                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
+            }),
+            swap_max_slippage: swap_max_slippage.map(|d| {
+                // Convert the Decimal to the version used by mantra_dex_std
+                let decimal_str = d.to_string();
+                cosmwasm_std::Decimal::from_str(&decimal_str).unwrap_or_default()
+            }),
+            receiver: None,
+            unlocking_duration: None,
+            lock_position_identifier: None,
+        };
+
+        let mut coins: Vec<Coin> = assets
+            .into_iter()
+            .map(|a| Coin {
+                denom: a.denom,
+                amount: a.amount,
+            })
+            .collect();
+
+        // Sort coins by denomination as required by Cosmos SDK
+        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        self.execute_contract_msg(&self.config.contracts.pool_manager, &msg, coins)
+    }
+
+    /// Withdraw liquidity from a pool
+    pub async fn withdraw_liquidity(
+        &self,
+        pool_id: &str,
+        lp_amount: Uint128,
+    ) -> Result<TxResponse, Error> {
+        let pool = self.withdrawable_pool(pool_id, lp_amount).await?;
+        let msg = self.withdraw_liquidity_msg(&pool, lp_amount)?;
+        self.broadcast_tx(vec![msg]).await
+    }
+
+    /// Preview a liquidity withdrawal: the assets it refunds and its transaction fee
+    ///
+    /// Takes the same arguments as [`withdraw_liquidity`](Self::withdraw_liquidity)
+    /// and runs the same checks, then computes the refund from the pool's current
+    /// reserves and simulates the transaction for its gas and fee, without signing
+    /// or broadcasting anything.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the pool isn't available or the LP tokens aren't liquid
+    /// * Returns [`Error::Other`] if `lp_amount` is zero or exceeds the pool's LP supply
+    /// * Returns any error from [`estimate_gas`](Self::estimate_gas)
+    pub async fn preview_withdraw_liquidity(
+        &self,
+        pool_id: &str,
+        lp_amount: Uint128,
+    ) -> Result<WithdrawalPreview, Error> {
+        let pool = self.withdrawable_pool(pool_id, lp_amount).await?;
+        let preview = WithdrawalPreview::new(&pool, lp_amount).ok_or_else(|| {
+            Error::Other(format!(
+                "Cannot withdraw {} of pool {}'s {} LP tokens",
+                lp_amount, pool_id, pool.total_share.amount
+            ))
+        })?;
+        let msg = self.withdraw_liquidity_msg(&pool, lp_amount)?;
+        Ok(preview.with_gas(self.estimate_gas(vec![msg]).await?))
+    }
+
+    /// Fetch a pool, checking it's available and the wallet holds `lp_amount` liquid LP tokens
+    async fn withdrawable_pool(
+        &self,
+        pool_id: &str,
+        lp_amount: Uint128,
+    ) -> Result<PoolInfoResponse, Error> {
+        // Get pool info and validate status in one call
+        let pool = self.get_pool(pool_id).await?;
+        let status = self.get_pool_status(&pool);
+
+        if !status.is_available() {
+            return Err(Error::Other(format!(
+                "Pool {} is not available for operations (status: {:?})",
+                pool_id, status
+            )));
+        }
+
+        // LP tokens locked in farms can't be sent; say so instead of failing on chain
+        let address = self.wallet()?.address()?.to_string();
+        self.get_lp_balance(&address, &pool.pool_info.lp_denom)
+            .await?
+            .check_withdrawal(lp_amount)?;
+        Ok(pool)
+    }
+
+    /// Build the message withdrawing `lp_amount` LP tokens from `pool`
+    fn withdraw_liquidity_msg(
+        &self,
+        pool: &PoolInfoResponse,
+        lp_amount: Uint128,
+    ) -> Result<Any, Error> {
+        let msg = pool_manager::ExecuteMsg::WithdrawLiquidity {
+            pool_identifier: pool.pool_info.pool_identifier.clone(),
+        };
+        let funds = vec![Coin {
+            denom: pool.pool_info.lp_denom.clone(),
+            amount: lp_amount,
+        }];
+        self.execute_contract_msg(&self.config.contracts.pool_manager, &msg, funds)
+    }
+
+    /// Plan moving a share of the wallet's liquidity positions in or out of their pools
+    ///
+    /// See [`bulk::plan_bulk`]. The plan isn't executed; review it, then pass it
+    /// to [`Self::execute_bulk_plan`].
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Whether to provide or withdraw liquidity
+    /// * `share` - Share of each position withdrawn, or added when providing
+    /// * `pool_ids` - Pools to include, or every pool the wallet holds LP tokens of if empty
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if no wallet is configured
+    /// * Returns error if the share is out of range
+    /// * Returns error if the balances or pools can't be queried
+    pub async fn plan_bulk_liquidity(
+        &self,
+        action: BulkAction,
+        share: Decimal,
+        pool_ids: &[String],
+    ) -> Result<BulkPlan, Error> {
+        let address = self.wallet()?.address()?.to_string();
+        let balances = self.get_balances_for_address(&address).await?;
+        let lp_balance = |pool: &PoolInfoResponse| {
+            balances
+                .iter()
+                .find(|coin| coin.denom == pool.pool_info.lp_denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default()
+        };
+
+        let mut missing = Vec::new();
+        let positions: Vec<(PoolInfoResponse, Uint128)> = if pool_ids.is_empty() {
+            self.pools_stream(POOL_PAGE_LIMIT)
+                .try_collect::<Vec<_>>()
+                .await?
+                .into_iter()
+                .map(|pool| {
+                    let lp = lp_balance(&pool);
+                    (pool, lp)
+                })
+                .filter(|(_, lp)| !lp.is_zero())
+                .collect()
+        } else {
+            let mut positions = Vec::with_capacity(pool_ids.len());
+            for pool_id in pool_ids {
+                match self.get_pool(pool_id).await {
+                    Ok(pool) => {
+                        let lp = lp_balance(&pool);
+                        positions.push((pool, lp));
+                    }
+                    Err(e) => missing.push(SkippedPool {
+                        pool_id: pool_id.clone(),
+                        reason: e.to_string(),
+                    }),
+                }
+            }
+            positions
+        };
+
+        let mut plan = bulk::plan_bulk(action, share, &positions, &balances)?;
+        plan.skipped.extend(missing);
+        Ok(plan)
+    }
+
+    /// Execute a bulk liquidity plan, `batch_size` pools per transaction
+    ///
+    /// A failed transaction is retried one pool at a time, so a pool that can't
+    /// be operated on fails alone and the others go through; see
+    /// [`bulk::execute_isolated`]. Deposits use the plan's `max_slippage`.
+    ///
+    /// # Returns
+    ///
+    /// The outcome of every leg of the plan
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no wallet is configured; failures of individual pools
+    /// are reported in the [`BulkReport`] instead
+    pub async fn execute_bulk_plan(
+        &self,
+        plan: &BulkPlan,
+        batch_size: usize,
+    ) -> Result<BulkReport, Error> {
+        self.wallet()?;
+        let results = bulk::execute_isolated(&plan.legs, batch_size, |legs| {
+            self.send_bulk_legs(plan, legs)
+        })
+        .await;
+
+        Ok(BulkReport {
+            action: plan.action,
+            share: plan.share,
+            results,
+            skipped: plan.skipped.clone(),
+        })
+    }
+
+    /// Send the legs of a bulk plan in a single transaction
+    async fn send_bulk_legs(
+        &self,
+        plan: &BulkPlan,
+        legs: Vec<BulkLeg>,
+    ) -> Result<TxResponse, Error> {
+        let msgs = legs
+            .into_iter()
+            .map(|leg| match plan.action {
+                BulkAction::Provide => {
+                    let msg = pool_manager::ExecuteMsg::ProvideLiquidity {
+                        pool_identifier: leg.pool_id,
+                        liquidity_max_slippage: plan.max_slippage,
+                        swap_max_slippage: plan.max_slippage,
+                        receiver: None,
+                        unlocking_duration: None,
+                        lock_position_identifier: None,
+                    };
+                    // Coins must be non-zero and sorted by denom
+                    let mut funds: Vec<Coin> = leg
+                        .assets
+                        .into_iter()
+                        .filter(|coin| !coin.amount.is_zero())
+                        .collect();
+                    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+                    (msg, funds)
+                }
+                BulkAction::Withdraw => {
+                    let msg = pool_manager::ExecuteMsg::WithdrawLiquidity {
+                        pool_identifier: leg.pool_id,
+                    };
+                    let funds = vec![Coin {
+                        denom: leg.lp_denom,
+                        amount: leg.lp_amount,
+                    }];
+                    (msg, funds)
+                }
+            })
+            .collect();
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        self.execute_batch(&pool_manager_address, msgs).await
+    }
+
+    /// Query the pool manager configuration
+    pub async fn get_pool_manager_config(
+        &self,
+    ) -> Result<mantra_dex_std::pool_manager::Config, Error> {
+        let query = pool_manager::QueryMsg::Config {};
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        // The contract returns Config directly, not wrapped in ConfigResponse
+        let config: mantra_dex_std::pool_manager::Config =
+            self.query(&pool_manager_address, &query).await?;
+        Ok(config)
+    }
+
+    /// Get the pool creation fee from the pool manager configuration
+    pub async fn get_pool_creation_fee(&self) -> Result<Coin, Error> {
+        let config = self.get_pool_manager_config().await?;
+        Ok(config.pool_creation_fee)
+    }
+
+    /// Create a new pool with the specified assets and configuration
+    ///
+    /// **v3.0.0 New Feature**: Enhanced fee validation ensures total fees ≤ 20%
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_denoms` - Vector of asset denominations for the pool
+    /// * `asset_decimals` - Vector of decimal places for each asset
+    /// * `pool_fees` - Fee structure for the pool (validated against v3.0.0 requirements)
+    /// * `pool_type` - Type of pool to create
+    /// * `pool_identifier` - Optional custom identifier for the pool
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the pool creation result
+    ///
+    /// # Errors
+    ///
+    /// * Returns `FeeValidation` error if pool fees exceed 20% total
+    /// * Returns error if pool creation transaction fails
+    /// * Returns error if no wallet is configured
+    ///
+    /// # Notes
+    ///
+    /// Pool creation requires a fee that is determined by querying the pool manager configuration.
+    /// This does not check for existing pools; use
+    /// [`create_pool_idempotent`](Self::create_pool_idempotent) to avoid duplicates.
+    pub async fn create_pool(
+        &self,
+        asset_denoms: Vec<String>,
+        asset_decimals: Vec<u8>,
+        pool_fees: mantra_dex_std::fee::PoolFee,
+        pool_type: mantra_dex_std::pool_manager::PoolType,
+        pool_identifier: Option<String>,
+    ) -> Result<TxResponse, Error> {
+        let msg = self
+            .create_pool_msg(
+                asset_denoms,
+                asset_decimals,
+                pool_fees,
+                pool_type,
+                pool_identifier,
+            )
+            .await?;
+        self.broadcast_tx(vec![msg]).await
+    }
+
+    /// Estimate the gas and fee of a pool creation by simulating its transaction
+    ///
+    /// Takes the same arguments as [`create_pool`](Self::create_pool) and pays
+    /// the same creation fee in the simulation, without signing or broadcasting
+    /// anything. The returned fee is the transaction fee only.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `FeeValidation` error if pool fees exceed 20% total
+    /// * Returns any error from [`estimate_gas`](Self::estimate_gas)
+    pub async fn estimate_create_pool(
+        &self,
+        asset_denoms: Vec<String>,
+        asset_decimals: Vec<u8>,
+        pool_fees: mantra_dex_std::fee::PoolFee,
+        pool_type: mantra_dex_std::pool_manager::PoolType,
+        pool_identifier: Option<String>,
+    ) -> Result<GasEstimate, Error> {
+        let msg = self
+            .create_pool_msg(
+                asset_denoms,
+                asset_decimals,
+                pool_fees,
+                pool_type,
+                pool_identifier,
+            )
+            .await?;
+        self.estimate_gas(vec![msg]).await
+    }
+
+    /// Validate a pool's fees and build the message creating it, funded with the creation fee
+    async fn create_pool_msg(
+        &self,
+        asset_denoms: Vec<String>,
+        asset_decimals: Vec<u8>,
+        pool_fees: mantra_dex_std::fee::PoolFee,
+        pool_type: mantra_dex_std::pool_manager::PoolType,
+        pool_identifier: Option<String>,
+    ) -> Result<Any, Error> {
+        // Validate pool fees before creating the pool (v3.0.0 requirement)
+        self.validate_pool_fees(&pool_fees)?;
+
+        let msg = pool_manager::ExecuteMsg::CreatePool {
+            asset_denoms,
+            asset_decimals,
+            pool_fees,
+            pool_type,
+            pool_identifier,
+        };
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+
+        // Query the actual pool creation fee from the contract configuration
+        let creation_fee = self.get_pool_creation_fee().await?;
+
+        // Handle case where contract config shows 0 but contract actually expects 88 OM
+        let pool_creation_fee = if creation_fee.amount.is_zero() {
+            // Fallback to known testnet pool creation fee of 88 OM
+            vec![Coin {
+                denom: "uom".to_string(),
+                amount: Uint128::new(88_000_000), // 88 OM
+            }]
+        } else {
+            vec![creation_fee]
+        };
+
+        self.execute_contract_msg(&pool_manager_address, &msg, pool_creation_fee)
+    }
+
+    /// Find an existing pool with the same assets, pool type and fees
+    ///
+    /// # Returns
+    ///
+    /// The identifier of the first matching pool, if any
+    pub async fn find_matching_pool(
+        &self,
+        asset_denoms: &[String],
+        pool_fees: &mantra_dex_std::fee::PoolFee,
+        pool_type: &mantra_dex_std::pool_manager::PoolType,
+    ) -> Result<Option<String>, Error> {
+        let (denom_a, denom_b) = match asset_denoms {
+            [a, b, ..] => (a, b),
+            _ => return Ok(None),
+        };
+
+        Ok(self
+            .get_pools_for_pair(denom_a, denom_b)
+            .await?
+            .into_iter()
+            .find(|pool| {
+                routing::pool_matches_definition(pool, asset_denoms, pool_type, pool_fees)
+            })
+            .map(|pool| pool.pool_info.pool_identifier))
+    }
+
+    /// Create a pool unless an equivalent one already exists
+    ///
+    /// Before creating, looks for a pool with the same assets, pool type and fee
+    /// structure. If one exists, no transaction is sent and its id is returned as
+    /// [`CreatePoolOutcome::PoolAlreadyExists`].
+    ///
+    /// # Arguments
+    ///
+    /// Same as [`create_pool`](Self::create_pool), plus:
+    ///
+    /// * `allow_duplicate` - Skip the duplicate check and always create the pool
+    ///
+    /// # Errors
+    ///
+    /// * Returns any error from [`create_pool`](Self::create_pool)
+    /// * Returns error if the existing pools cannot be queried
+    pub async fn create_pool_idempotent(
+        &self,
+        asset_denoms: Vec<String>,
+        asset_decimals: Vec<u8>,
+        pool_fees: mantra_dex_std::fee::PoolFee,
+        pool_type: mantra_dex_std::pool_manager::PoolType,
+        pool_identifier: Option<String>,
+        allow_duplicate: bool,
+    ) -> Result<CreatePoolOutcome, Error> {
+        if !allow_duplicate {
+            if let Some(pool_id) = self
+                .find_matching_pool(&asset_denoms, &pool_fees, &pool_type)
+                .await?
+            {
+                return Ok(CreatePoolOutcome::PoolAlreadyExists { pool_id });
+            }
+        }
+
+        self.create_pool(
+            asset_denoms,
+            asset_decimals,
+            pool_fees,
+            pool_type,
+            pool_identifier,
+        )
+        .await
+        .map(CreatePoolOutcome::Created)
+    }
+
+    /// Find the route through up to `max_hops` pools returning the most `ask_asset_denom`
+    ///
+    /// Every pool is loaded and priced with the local pool math, see
+    /// [`crate::router`]. Pools that aren't available, are hidden by the asset
+    /// filter or are excluded from routing are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `offer_asset` - Asset and amount being sold
+    /// * `ask_asset_denom` - Denom being bought
+    /// * `max_hops` - Most pools the route may go through, see [`router::DEFAULT_MAX_HOPS`]
+    ///
+    /// # Returns
+    ///
+    /// The route with its hops, expected output and compounded price impact
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the pools can't be loaded
+    /// * Returns error if no route connects the two denoms
+    pub async fn find_best_route(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+        max_hops: usize,
+    ) -> Result<SwapRoute, Error> {
+        let mut routes = self
+            .find_routes(offer_asset, ask_asset_denom, max_hops, 1)
+            .await?;
+        Ok(routes.remove(0))
+    }
+
+    /// Find up to `limit` candidate routes through up to `max_hops` pools, best first
+    ///
+    /// Pools are loaded and filtered like in
+    /// [`find_best_route`](Self::find_best_route), and the routes are ranked
+    /// by expected output, see [`router::find_routes`].
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the pools can't be loaded
+    /// * Returns error if no route connects the two denoms
+    pub async fn find_routes(
+        &self,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+        max_hops: usize,
+        limit: usize,
+    ) -> Result<Vec<SwapRoute>, Error> {
+        self.check_denoms_allowed(&[&offer_asset.denom, ask_asset_denom])?;
+        let pools: Vec<PoolInfoResponse> = self
+            .pools_stream(POOL_PAGE_LIMIT)
+            .try_filter(|pool| {
+                future::ready(
+                    self.get_pool_status(pool).is_available()
+                        && !self.routing.excludes(&pool.pool_info.pool_identifier),
+                )
+            })
+            .try_collect()
+            .await?;
+
+        let graph = AssetGraph::new(&pools);
+        let routes = router::find_routes(&graph, offer_asset, ask_asset_denom, max_hops, limit);
+        if routes.is_empty() {
+            return Err(Error::Other(format!(
+                "No route within {} hops found for {} -> {}",
+                max_hops, offer_asset.denom, ask_asset_denom
+            )));
+        }
+        Ok(routes)
+    }
+
+    /// Execute a route found by [`find_best_route`](Self::find_best_route) in one transaction
+    ///
+    /// The pool manager runs the hops in sequence and refuses the swap if the
+    /// last one returns less than the route's expected output minus
+    /// `max_slippage`, falling back to the wallet's default slippage.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the route has no hops
+    /// * Returns error if the transaction fails
+    pub async fn execute_route(
+        &self,
+        route: &SwapRoute,
+        max_slippage: Option<Decimal>,
+    ) -> Result<TxResponse, Error> {
+        if route.hops.is_empty() {
+            return Err(Error::Other("Route has no hops".to_string()));
+        }
+        let max_slippage = max_slippage
+            .or(self.wallet_defaults.slippage)
+            .unwrap_or(DEFAULT_SWAP_SLIPPAGE);
+
+        let msg = pool_manager::ExecuteMsg::ExecuteSwapOperations {
+            operations: route.operations(),
+            minimum_receive: Some(route.minimum_receive(max_slippage)),
+            receiver: None,
+            max_slippage: Some(max_slippage),
+        };
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        self.execute(
+            &pool_manager_address,
+            &msg,
+            vec![route.offer_asset.clone()],
+        )
+        .await
+    }
+
+    /// Execute multiple swap operations
+    pub async fn execute_swap_operations(
+        &self,
+        operations: Vec<SwapOperation>,
+        amount: Uint128,
+    ) -> Result<TxResponse, Error> {
+        let first_op = operations
+            .first()
+            .ok_or_else(|| Error::Other("Swap operations list cannot be empty".to_string()))?;
+        for operation in &operations {
+            self.check_denoms_allowed(&[
+                operation.get_input_asset_info(),
+                &operation.get_target_asset_info(),
+            ])?;
+        }
+
+        // Validate pool status for the first operation
+        self.validate_pool_status(&first_op.get_pool_identifer())
+            .await?;
+
+        let msg = pool_manager::ExecuteMsg::Swap {
+            ask_asset_denom: first_op.get_target_asset_info().clone(),
+            belief_price: None,
+            max_slippage: None,
+            receiver: None,
+            pool_identifier: first_op.get_pool_identifer().clone(),
+        };
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        self.execute(
+            &pool_manager_address,
+            &msg,
+            vec![Coin {
+                denom: first_op.get_input_asset_info().clone(),
+                amount,
+            }],
+        )
+        .await
+    }
+
+    // =========================
+    // Farm Manager Functionality
+    // =========================
+
+    /// Claim rewards from farm manager with optional epoch parameter
+    ///
+    /// **v3.0.0 New Feature**: Enhanced claim functionality with epoch-based claiming
+    ///
+    /// # Arguments
+    ///
+    /// * `until_epoch` - Optional epoch limit for claiming rewards. If provided, only claims rewards up to that epoch
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the claim result
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if farm manager contract is not configured
+    /// * Returns error if the claim transaction fails
+    /// * Returns error if no wallet is configured
+    ///
+    /// # Backward Compatibility
+    ///
+    /// When `until_epoch` is `None`, behaves like the v2.x parameterless claim
+    pub async fn claim_rewards(&self, until_epoch: Option<u64>) -> Result<TxResponse, Error> {
+        let msg = self.claim_rewards_msg(until_epoch)?;
+        self.broadcast_tx(vec![msg]).await
+    }
+
+    /// Build the message claiming farm rewards, up to `until_epoch` if set
+    fn claim_rewards_msg(&self, until_epoch: Option<u64>) -> Result<Any, Error> {
+        let farm_manager_address =
+            self.config.contracts.farm_manager.as_ref().ok_or_else(|| {
+                Error::Other("Farm manager contract address not configured".to_string())
+            })?;
+
+        let msg = if let Some(epoch) = until_epoch {
+            serde_json::json!({
+                "claim": {
+                    "until_epoch": epoch
+                }
+            })
+        } else {
+            // Backward compatibility: parameterless claim
+            serde_json::json!({
+                "claim": {}
+            })
+        };
+
+        self.execute_contract_msg(farm_manager_address, &msg, vec![])
+    }
+
+    /// Claim rewards without epoch parameter (backward compatibility)
+    ///
+    /// This is a convenience method that calls `claim_rewards(None)` for backward compatibility
+    /// with v2.x code that used parameterless claim methods.
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the claim result
+    pub async fn claim_rewards_all(&self) -> Result<TxResponse, Error> {
+        self.claim_rewards(None).await
+    }
+
+    /// Claim rewards up to a specific epoch
+    ///
+    /// This is a convenience method that calls `claim_rewards(Some(until_epoch))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `until_epoch` - The epoch limit for claiming rewards
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the claim result
+    pub async fn claim_rewards_until_epoch(&self, until_epoch: u64) -> Result<TxResponse, Error> {
+        self.claim_rewards(Some(until_epoch)).await
+    }
+
+    /// Claim the wallet's farm rewards in as many transactions as their gas needs
+    ///
+    /// Claims up to the current epoch, starting from the first epoch of the
+    /// farms the wallet has positions in, in chunks whose simulated gas stays
+    /// under `max_gas` (see [`claim::chunk_end`]). Chunks are sent one after
+    /// another, each planned from the state the previous one left, and
+    /// `on_chunk` is called as each is included. Chunks that would pay nothing
+    /// are skipped. A simulate-only client only simulates the first chunk, since
+    /// the next ones depend on it being executed.
+    ///
+    /// A chunk that fails after others were sent ends the claim, and the report
+    /// keeps the chunks sent and the error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if no wallet is configured
+    /// * Returns error if the farm or epoch manager is not configured or can't be queried
+    /// * Returns error if the first chunk fails
+    pub async fn claim_rewards_chunked(
+        &self,
+        max_gas: u64,
+        mut on_chunk: impl FnMut(&ClaimChunk),
+    ) -> Result<ClaimReport, Error> {
+        let address = self.wallet()?.address()?.to_string();
+        let (positions, farms, current_epoch) = futures::try_join!(
+            self.positions_stream(
+                Some(PositionsBy::Receiver(address.clone())),
+                None,
+                POSITION_PAGE_LIMIT,
+            )
+            .try_collect::<Vec<Position>>(),
+            self.farms_stream(None, POOL_PAGE_LIMIT)
+                .try_collect::<Vec<Farm>>(),
+            self.get_current_epoch()
+        )?;
+        let first_epoch = farms
+            .iter()
+            .filter(|farm| {
+                positions
+                    .iter()
+                    .any(|position| position.lp_asset.denom == farm.lp_denom)
+            })
+            .map(|farm| farm.start_epoch)
+            .min();
+
+        let mut report = ClaimReport::default();
+        let Some(mut from_epoch) = first_epoch else {
+            return Ok(report);
+        };
+        while from_epoch <= current_epoch {
+            match self
+                .send_claim_chunk(&address, from_epoch, current_epoch, max_gas)
+                .await
+            {
+                Ok((until_epoch, chunk)) => {
+                    if let Some(chunk) = chunk {
+                        on_chunk(&chunk);
+                        report.chunks.push(chunk);
+                    }
+                    from_epoch = until_epoch + 1;
+                }
+                Err(e) if report.chunks.is_empty() => return Err(e),
+                Err(e) => {
+                    report.error = Some(e.to_string());
+                    break;
+                }
+            }
+            if self.simulate_only && !report.chunks.is_empty() {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Plan and send the next chunk of a chunked claim
+    ///
+    /// Returns the last epoch the chunk covers, and the chunk if it paid anything.
+    async fn send_claim_chunk(
+        &self,
+        address: &str,
+        from_epoch: u64,
+        to_epoch: u64,
+        max_gas: u64,
+    ) -> Result<(u64, Option<ClaimChunk>), Error> {
+        let (until_epoch, gas) =
+            claim::chunk_end(from_epoch, to_epoch, max_gas, |epoch| async move {
+                let msg = self.claim_rewards_msg(Some(epoch))?;
+                Ok(self.estimate_gas(vec![msg]).await?.simulated)
+            })
+            .await?;
+        let rewards = self.get_rewards_until(address, Some(until_epoch)).await?;
+        if rewards.is_empty() {
+            return Ok((until_epoch, None));
+        }
+
+        let response = self.claim_rewards(Some(until_epoch)).await?;
+        Ok((
+            until_epoch,
+            Some(ClaimChunk {
+                until_epoch,
+                gas,
+                rewards,
+                txhash: response.txhash,
+                height: response.height,
+            }),
+        ))
+    }
+
+    /// Query rewards for an address with optional epoch parameter
+    ///
+    /// **v3.0.0 New Feature**: Enhanced rewards query with epoch range support
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address to query rewards for
+    /// * `until_epoch` - Optional epoch limit for querying rewards. If provided, only returns rewards up to that epoch
+    ///
+    /// # Returns
+    ///
+    /// JSON value containing the rewards information
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if farm manager contract is not configured
+    /// * Returns error if the query fails
+    ///
+    /// # Backward Compatibility
+    ///
+    /// When `until_epoch` is `None`, behaves like the v2.x parameterless rewards query
+    pub async fn query_rewards(
+        &self,
+        address: &str,
+        until_epoch: Option<u64>,
+    ) -> Result<serde_json::Value, Error> {
+        let farm_manager_address =
+            self.config.contracts.farm_manager.as_ref().ok_or_else(|| {
+                Error::Other("Farm manager contract address not configured".to_string())
+            })?;
+
+        let query = if let Some(epoch) = until_epoch {
+            serde_json::json!({
+                "rewards": {
+                    "address": address,
+                    "until_epoch": epoch
+                }
+            })
+        } else {
+            serde_json::json!({
+                "rewards": {
+                    "address": address
+                }
+            })
+        };
+
+        self.query(farm_manager_address, &query).await
+    }
+
+    /// Query all rewards for an address (backward compatibility)
+    pub async fn query_all_rewards(&self, address: &str) -> Result<serde_json::Value, Error> {
+        self.query_rewards(address, None).await
+    }
+
+    /// Rewards an address could claim now, summed over its farms
+    pub async fn get_pending_rewards(&self, address: &str) -> Result<Vec<Coin>, Error> {
+        self.get_rewards_until(address, None).await
+    }
+
+    /// Rewards a claim up to `until_epoch` would pay an address, summed over its farms
+    pub async fn get_rewards_until(
+        &self,
+        address: &str,
+        until_epoch: Option<u64>,
+    ) -> Result<Vec<Coin>, Error> {
+        let response: RewardsResponse =
+            serde_json::from_value(self.query_rewards(address, until_epoch).await?)?;
+        Ok(match response {
+            RewardsResponse::RewardsResponse { total_rewards, .. } => total_rewards,
+            RewardsResponse::QueryRewardsResponse { rewards }
+            | RewardsResponse::ClaimRewards { rewards, .. } => rewards,
+        })
+    }
+
+    /// Query rewards for an address up to a specific epoch
+    pub async fn query_rewards_until_epoch(
+        &self,
+        address: &str,
+        until_epoch: u64,
+    ) -> Result<serde_json::Value, Error> {
+        self.query_rewards(address, Some(until_epoch)).await
+    }
+
+    /// Get a page of the farms in the farm manager, optionally filtered
+    ///
+    /// # Arguments
+    ///
+    /// * `filter_by` - Only farms for this LP denom or with this identifier
+    /// * `cursor` - The page to fetch, e.g. [`PageCursor::default`] for the first
+    ///
+    /// # Returns
+    ///
+    /// The page's farms and the cursor of the next page, if any
+    pub async fn get_farms(
+        &self,
+        filter_by: Option<FarmsBy>,
+        cursor: &PageCursor,
+    ) -> Result<Paginated<Farm>, Error> {
+        let query = farm_manager::QueryMsg::Farms {
+            filter_by,
+            start_after: cursor.start_after.clone(),
+            limit: Some(cursor.limit),
+        };
+        let response: FarmsResponse = self.query(self.farm_manager_address()?, &query).await?;
+        Ok(Paginated::from_page(response.farms, cursor.clone(), |farm| {
+            farm.identifier.clone()
+        }))
+    }
+
+    /// Stream the farms in the farm manager, optionally filtered
+    pub fn farms_stream(
+        &self,
+        filter_by: Option<FarmsBy>,
+        page_size: u32,
+    ) -> BoxStream<'_, Result<Farm, Error>> {
+        paginate(PageCursor::first(page_size), move |cursor| {
+            let filter_by = filter_by.clone();
+            async move {
+                let page = self.get_farms(filter_by, &cursor).await?;
+                Ok((page.items, page.next))
+            }
+        })
+    }
+
+    /// Get a page of farm positions, optionally filtered by position or receiver and open state
+    ///
+    /// # Arguments
+    ///
+    /// * `filter_by` - Only this position, or the positions of this receiver
+    /// * `open_state` - Only open positions if `true`, only closed ones if `false`
+    /// * `cursor` - The page to fetch, e.g. [`PageCursor::default`] for the first
+    ///
+    /// # Returns
+    ///
+    /// The page's positions and the cursor of the next page, if any
+    pub async fn get_positions(
+        &self,
+        filter_by: Option<PositionsBy>,
+        open_state: Option<bool>,
+        cursor: &PageCursor,
+    ) -> Result<Paginated<Position>, Error> {
+        let query = farm_manager::QueryMsg::Positions {
+            filter_by,
+            open_state,
+            start_after: cursor.start_after.clone(),
+            limit: Some(cursor.limit),
+        };
+        let response: PositionsResponse =
+            self.query(self.farm_manager_address()?, &query).await?;
+        Ok(Paginated::from_page(response.positions, cursor.clone(), |position| {
+            position.identifier.clone()
+        }))
+    }
+
+    /// Stream farm positions, optionally filtered by position or receiver and open state
+    pub fn positions_stream(
+        &self,
+        filter_by: Option<PositionsBy>,
+        open_state: Option<bool>,
+        page_size: u32,
+    ) -> BoxStream<'_, Result<Position, Error>> {
+        paginate(PageCursor::first(page_size), move |cursor| {
+            let filter_by = filter_by.clone();
+            async move {
+                let page = self
+                    .get_positions(filter_by, open_state, &cursor)
+                    .await?;
+                Ok((page.items, page.next))
+            }
+        })
+    }
+
+    /// Get one farm position by its identifier
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the farm manager is not configured or the query fails
+    /// * Returns error if no position has the identifier
+    pub async fn get_position(&self, identifier: &str) -> Result<Position, Error> {
+        let page = self
+            .get_positions(
+                Some(PositionsBy::Identifier(identifier.to_string())),
+                None,
+                &PageCursor::first(1),
+            )
+            .await?;
+        page.items
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Other(format!("Farm position {} not found", identifier)))
+    }
+
+    /// Get the farm positions of an address with the epochs they started and unlock in
+    ///
+    /// The start epochs are read from `history`, which should be synced for the
+    /// address; positions it has no opening for have no start epoch.
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the farm manager is not configured
+    /// * Returns error if the positions or the epoch manager cannot be queried
+    pub async fn get_farm_positions(
+        &self,
+        address: &str,
+        history: Option<&TxHistory>,
+    ) -> Result<Vec<FarmPosition>, Error> {
+        let (positions, calendar) = futures::try_join!(
+            self.positions_stream(
+                Some(PositionsBy::Receiver(address.to_string())),
+                None,
+                POSITION_PAGE_LIMIT,
+            )
+            .try_collect::<Vec<Position>>(),
+            self.get_epoch_calendar()
+        )?;
+        let openings = history
+            .map(|history| {
+                positions::position_openings(
+                    &history.all_entries(address, &HistoryFilter::default()),
+                )
+            })
+            .unwrap_or_default();
+        Ok(positions
+            .iter()
+            .map(|position| {
+                let opened_at = openings.get(&position.identifier).copied();
+                FarmPosition::new(position, &calendar, opened_at)
+            })
+            .collect())
+    }
+
+    /// Close a farm position, or split part of it off and close that part
+    ///
+    /// Closing starts the position's unlocking period, after which its LP tokens
+    /// can be withdrawn with [`withdraw_position`](Self::withdraw_position).
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The position to close
+    /// * `lp_amount` - LP tokens to close; the rest of the position stays open.
+    ///   `None` closes the whole position
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the position doesn't exist or holds fewer LP tokens
+    /// * Returns error if the transaction fails
+    pub async fn close_position(
+        &self,
+        identifier: &str,
+        lp_amount: Option<Uint128>,
+    ) -> Result<TxResponse, Error> {
+        let lp_asset = match lp_amount {
+            Some(amount) => {
+                let position = self.get_position(identifier).await?;
+                if amount.is_zero() || amount > position.lp_asset.amount {
+                    return Err(Error::Other(format!(
+                        "Cannot close {} of farm position {}, which holds {}",
+                        amount, identifier, position.lp_asset
+                    )));
+                }
+                Some(Coin {
+                    denom: position.lp_asset.denom,
+                    amount,
+                })
+            }
+            None => None,
+        };
+        let msg = farm_manager::ExecuteMsg::ManagePosition {
+            action: farm_manager::PositionAction::Close {
+                identifier: identifier.to_string(),
+                lp_asset,
+            },
+        };
+        self.execute(self.farm_manager_address()?, &msg, vec![]).await
+    }
+
+    /// Add LP tokens from the wallet to an open farm position
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the position doesn't exist or is closed
+    /// * Returns error if the transaction fails
+    pub async fn expand_position(
+        &self,
+        identifier: &str,
+        lp_amount: Uint128,
+    ) -> Result<TxResponse, Error> {
+        let position = self.get_position(identifier).await?;
+        if !position.open {
+            return Err(Error::Other(format!(
+                "Farm position {} is closed and can't be expanded",
+                identifier
+            )));
+        }
+        let msg = farm_manager::ExecuteMsg::ManagePosition {
+            action: farm_manager::PositionAction::Expand {
+                identifier: identifier.to_string(),
+            },
+        };
+        let funds = vec![Coin {
+            denom: position.lp_asset.denom,
+            amount: lp_amount,
+        }];
+        self.execute(self.farm_manager_address()?, &msg, funds).await
+    }
+
+    /// Withdraw the LP tokens of a closed farm position
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The position to withdraw
+    /// * `emergency_unlock` - Withdraw before the unlocking period ends, paying
+    ///   the farm manager's emergency unlock penalty
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the transaction fails, e.g. because the position is
+    ///   still unlocking without `emergency_unlock`
+    pub async fn withdraw_position(
+        &self,
+        identifier: &str,
+        emergency_unlock: bool,
+    ) -> Result<TxResponse, Error> {
+        let msg = farm_manager::ExecuteMsg::ManagePosition {
+            action: farm_manager::PositionAction::Withdraw {
+                identifier: identifier.to_string(),
+                emergency_unlock: emergency_unlock.then_some(true),
+            },
+        };
+        self.execute(self.farm_manager_address()?, &msg, vec![]).await
+    }
+
+    fn farm_manager_address(&self) -> Result<&str, Error> {
+        self.config
+            .contracts
+            .farm_manager
+            .as_deref()
+            .ok_or_else(|| {
+                Error::Other("Farm manager contract address not configured".to_string())
+            })
+    }
+
+    /// Get current epoch from epoch manager contract
+    pub async fn get_current_epoch(&self) -> Result<u64, Error> {
+        let epoch_manager_address =
+            self.config
+                .contracts
+                .epoch_manager
+                .as_ref()
+                .ok_or_else(|| {
+                    Error::Other("Epoch manager contract address not configured".to_string())
+                })?;
+
+        let query = serde_json::json!({
+            "current_epoch": {}
+        });
+
+        let response: serde_json::Value = self.query(epoch_manager_address, &query).await?;
+
+        // Extract epoch number from response, either a bare number or an epoch object
+        response
+            .get("epoch")
+            .and_then(|epoch| epoch.as_u64().or_else(|| epoch.get("id")?.as_u64()))
+            .ok_or_else(|| Error::Other("Failed to parse epoch from response".to_string()))
+    }
+
+    /// Get the epoch duration and genesis time from the epoch manager contract
+    pub async fn get_epoch_config(&self) -> Result<EpochConfig, Error> {
+        let epoch_manager_address =
+            self.config
+                .contracts
+                .epoch_manager
+                .as_ref()
+                .ok_or_else(|| {
+                    Error::Other("Epoch manager contract address not configured".to_string())
+                })?;
+
+        let response: epoch_manager::ConfigResponse = self
+            .query(epoch_manager_address, &epoch_manager::QueryMsg::Config {})
+            .await?;
+        Ok(response.epoch_config)
+    }
+
+    /// Get the calendar of epochs, anchored on the current epoch
+    ///
+    /// Uses the start time the epoch manager reports for the current epoch, or
+    /// derives it from the genesis time if the response doesn't include one.
+    pub async fn get_epoch_calendar(&self) -> Result<EpochCalendar, Error> {
+        let epoch_manager_address =
+            self.config
+                .contracts
+                .epoch_manager
+                .as_ref()
+                .ok_or_else(|| {
+                    Error::Other("Epoch manager contract address not configured".to_string())
+                })?;
+
+        let (response, config) = future::try_join(
+            self.query::<_, serde_json::Value>(
+                epoch_manager_address,
+                &epoch_manager::QueryMsg::CurrentEpoch {},
+            ),
+            self.get_epoch_config(),
+        )
+        .await?;
+        let epoch = response
+            .get("epoch")
+            .ok_or_else(|| Error::Other("Failed to parse epoch from response".to_string()))?;
+        let id = epoch
+            .as_u64()
+            .or_else(|| epoch.get("id")?.as_u64())
+            .ok_or_else(|| Error::Other("Failed to parse epoch from response".to_string()))?;
+
+        let duration = config.duration.u64().max(1);
+        let start = match epoch
+            .get("start_time")
+            .and_then(|start| serde_json::from_value::<Timestamp>(start.clone()).ok())
+        {
+            Some(start) => start.seconds(),
+            None => {
+                let genesis = config.genesis_epoch.u64();
+                let elapsed = (chrono::Utc::now().timestamp().max(0) as u64).saturating_sub(genesis);
+                genesis + elapsed / duration * duration
+            }
+        };
+        let start = chrono::DateTime::from_timestamp(start as i64, 0)
+            .ok_or_else(|| Error::Other(format!("Invalid epoch start time {}", start)))?;
+        Ok(EpochCalendar::new(id, start, duration))
+    }
 
-    /// Claim rewards from farm manager with optional epoch parameter
+    /// Express an amount of one denom in another at pool spot prices
     ///
-    /// **v3.0.0 New Feature**: Enhanced claim functionality with epoch-based claiming
+    /// Denoms without a pool together are converted through intermediate
+    /// assets; see [`crate::convert`]. Either denom may be
+    /// [`USD`](crate::convert::USD), valued through the stablecoins set with
+    /// [`with_usd_denoms`](Self::with_usd_denoms).
     ///
     /// # Arguments
     ///
-    /// * `until_epoch` - Optional epoch limit for claiming rewards. If provided, only claims rewards up to that epoch
+    /// * `amount` - Amount to convert, in base units of `from_denom`
+    /// * `from_denom` - Denom of the amount
+    /// * `to_denom` - Denom to express the amount in
     ///
     /// # Returns
     ///
-    /// Transaction response containing the claim result
+    /// The converted amount and the pools it was priced through
     ///
     /// # Errors
     ///
-    /// * Returns error if farm manager contract is not configured
-    /// * Returns error if the claim transaction fails
-    /// * Returns error if no wallet is configured
+    /// * Returns error if the pools cannot be queried
+    /// * Returns error if no pools link the two denoms
+    pub async fn convert_amount(
+        &self,
+        amount: Uint128,
+        from_denom: &str,
+        to_denom: &str,
+    ) -> Result<Conversion, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        PriceBook::new(&pools)
+            .with_usd_denoms(&self.usd_denoms)
+            .convert(amount, from_denom, to_denom)
+            .ok_or_else(|| {
+                Error::Other(format!("No pools price {} in {}", from_denom, to_denom))
+            })
+    }
+
+    /// Get the LP tokens an address has locked in farm positions
     ///
-    /// # Backward Compatibility
+    /// Open positions and closed ones whose tokens haven't been withdrawn yet are
+    /// included. Without a farm manager nothing is locked.
     ///
-    /// When `until_epoch` is `None`, behaves like the v2.x parameterless claim
-    pub async fn claim_rewards(&self, until_epoch: Option<u64>) -> Result<TxResponse, Error> {
-        let farm_manager_address =
-            self.config.contracts.farm_manager.as_ref().ok_or_else(|| {
-                Error::Other("Farm manager contract address not configured".to_string())
-            })?;
-
-        let msg = if let Some(epoch) = until_epoch {
-            serde_json::json!({
-                "claim": {
-                    "until_epoch": epoch
-                }
-            })
-        } else {
-            // Backward compatibility: parameterless claim
-            serde_json::json!({
-                "claim": {}
-            })
-        };
+    /// # Errors
+    ///
+    /// * Returns error if the positions cannot be queried
+    pub async fn get_lp_locks(&self, address: &str) -> Result<Vec<LpLock>, Error> {
+        if self.config.contracts.farm_manager.is_none() {
+            return Ok(Vec::new());
+        }
+        let positions: Vec<Position> = self
+            .positions_stream(
+                Some(PositionsBy::Receiver(address.to_string())),
+                None,
+                POSITION_PAGE_LIMIT,
+            )
+            .try_collect()
+            .await?;
+        Ok(positions.iter().map(LpLock::from_position).collect())
+    }
 
-        self.execute(farm_manager_address, &msg, vec![]).await
+    /// Get the liquid and locked LP tokens of `lp_denom` held by an address
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the balances or farm positions cannot be queried
+    pub async fn get_lp_balance(&self, address: &str, lp_denom: &str) -> Result<LpBalance, Error> {
+        let (balances, locks) = futures::try_join!(
+            self.get_balances_for_address(address),
+            self.get_lp_locks(address)
+        )?;
+        Ok(LpBalance {
+            lp_denom: lp_denom.to_string(),
+            liquid: balances
+                .iter()
+                .find(|balance| balance.denom == lp_denom)
+                .map_or(Uint128::zero(), |balance| balance.amount),
+            locks: locks
+                .into_iter()
+                .filter(|lock| lock.lp_denom == lp_denom)
+                .collect(),
+        })
     }
 
-    /// Claim rewards without epoch parameter (backward compatibility)
+    /// Get the LP positions held by an address, valued in `quote`
     ///
-    /// This is a convenience method that calls `claim_rewards(None)` for backward compatibility
-    /// with v2.x code that used parameterless claim methods.
+    /// Positions are the LP tokens in the address's balances and those it locked
+    /// in farm positions, which are listed in [`LpPosition::locks`]. Their cost basis is
+    /// read from the liquidity changes in `history`, which should be synced with
+    /// [`sync_history`](Self::sync_history) first; without a history, or for LP
+    /// tokens it doesn't account for, only the current value is known.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Transaction response containing the claim result
-    pub async fn claim_rewards_all(&self) -> Result<TxResponse, Error> {
-        self.claim_rewards(None).await
+    /// * `address` - Address holding the LP tokens
+    /// * `history` - Transaction history of the address
+    /// * `quote` - Denom to express values in, or [`USD`](crate::convert::USD)
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the balances or pools cannot be queried
+    pub async fn get_lp_positions(
+        &self,
+        address: &str,
+        history: Option<&TxHistory>,
+        quote: &str,
+    ) -> Result<Vec<LpPosition>, Error> {
+        let (balances, locks) = futures::try_join!(
+            self.get_balances_for_address(address),
+            self.get_lp_locks(address)
+        )?;
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        let changes: Vec<LiquidityChange> = history
+            .map(|history| history.all_entries(address, &HistoryFilter::default()))
+            .unwrap_or_default()
+            .iter()
+            .filter(|entry| entry.success)
+            .flat_map(|entry| entry.events.iter().filter_map(LiquidityChange::from_event))
+            .collect();
+
+        let prices = PriceBook::new(&pools).with_usd_denoms(&self.usd_denoms);
+        Ok(positions::lp_positions(&pools, &balances, &locks, &changes, &prices, quote))
     }
 
-    /// Claim rewards up to a specific epoch
+    /// Value everything an address holds in `quote`
     ///
-    /// This is a convenience method that calls `claim_rewards(Some(until_epoch))`.
+    /// Wallet balances, LP positions (liquid and locked in farm positions) and
+    /// pending farm rewards are valued at pool spot prices; see
+    /// [`crate::portfolio`]. Positions carry no cost basis here, see
+    /// [`get_lp_positions`](Self::get_lp_positions) for that.
     ///
     /// # Arguments
     ///
-    /// * `until_epoch` - The epoch limit for claiming rewards
+    /// * `address` - Address to value the holdings of
+    /// * `quote` - Denom to express values in, e.g. a stablecoin, or
+    ///   [`USD`](crate::convert::USD)
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Transaction response containing the claim result
-    pub async fn claim_rewards_until_epoch(&self, until_epoch: u64) -> Result<TxResponse, Error> {
-        self.claim_rewards(Some(until_epoch)).await
+    /// * Returns error if the balances, farm positions or pools cannot be queried
+    pub async fn get_portfolio_summary(
+        &self,
+        address: &str,
+        quote: &str,
+    ) -> Result<PortfolioValuation, Error> {
+        let (balances, locks) = futures::try_join!(
+            self.get_balances_for_address(address),
+            self.get_lp_locks(address)
+        )?;
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        // Addresses without farm positions, or networks without a farm manager, have none
+        let pending_rewards = self
+            .get_pending_rewards(address)
+            .await
+            .unwrap_or_default();
+
+        let prices = PriceBook::new(&pools).with_usd_denoms(&self.usd_denoms);
+        let positions = positions::lp_positions(&pools, &balances, &locks, &[], &prices, quote);
+        Ok(PortfolioValuation::new(
+            address,
+            balances,
+            positions,
+            pending_rewards,
+            &prices,
+            quote,
+            chrono::Utc::now(),
+        ))
     }
 
-    /// Query rewards for an address with optional epoch parameter
+    /// Swap in a paper portfolio at the pool's live simulated return
     ///
-    /// **v3.0.0 New Feature**: Enhanced rewards query with epoch range support
+    /// Nothing is signed or broadcast; see [`crate::paper`].
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `address` - The address to query rewards for
-    /// * `until_epoch` - Optional epoch limit for querying rewards. If provided, only returns rewards up to that epoch
+    /// * Returns error if the pool isn't available or the simulation fails
+    /// * Returns [`Error::Wallet`] if the virtual balance can't cover `offer_asset`
+    pub async fn paper_swap(
+        &self,
+        portfolio: &mut PaperPortfolio,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+    ) -> Result<PaperTrade, Error> {
+        self.get_pool(pool_id).await?;
+        let simulation = self
+            .simulate_swap(pool_id, offer_asset.clone(), ask_asset_denom)
+            .await?;
+        portfolio
+            .swap(pool_id, offer_asset, ask_asset_denom, &simulation)
+            .cloned()
+    }
+
+    /// Provide liquidity in a paper portfolio at the pool's current reserves
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// JSON value containing the rewards information
+    /// * Returns error if the pool cannot be queried
+    /// * Returns any error from [`PaperPortfolio::provide_liquidity`]
+    pub async fn paper_provide_liquidity(
+        &self,
+        portfolio: &mut PaperPortfolio,
+        pool_id: &str,
+        assets: &[Coin],
+    ) -> Result<PaperTrade, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        portfolio.provide_liquidity(&pool, assets).cloned()
+    }
+
+    /// Withdraw liquidity in a paper portfolio at the pool's current reserves
     ///
     /// # Errors
     ///
-    /// * Returns error if farm manager contract is not configured
-    /// * Returns error if the query fails
+    /// * Returns error if the pool cannot be queried
+    /// * Returns any error from [`PaperPortfolio::withdraw_liquidity`]
+    pub async fn paper_withdraw_liquidity(
+        &self,
+        portfolio: &mut PaperPortfolio,
+        pool_id: &str,
+        lp_amount: Uint128,
+    ) -> Result<PaperTrade, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        portfolio.withdraw_liquidity(&pool, lp_amount).cloned()
+    }
+
+    /// Value a paper portfolio in `quote` at current pool prices
     ///
-    /// # Backward Compatibility
+    /// # Errors
     ///
-    /// When `until_epoch` is `None`, behaves like the v2.x parameterless rewards query
-    pub async fn query_rewards(
+    /// * Returns error if the pools cannot be queried
+    pub async fn paper_valuation(
         &self,
-        address: &str,
-        until_epoch: Option<u64>,
-    ) -> Result<serde_json::Value, Error> {
-        let farm_manager_address =
-            self.config.contracts.farm_manager.as_ref().ok_or_else(|| {
-                Error::Other("Farm manager contract address not configured".to_string())
-            })?;
+        portfolio: &PaperPortfolio,
+        quote: &str,
+    ) -> Result<PaperValuation, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        let prices = PriceBook::new(&pools).with_usd_denoms(&self.usd_denoms);
+        Ok(portfolio.valuation(&pools, &prices, quote))
+    }
 
-        let query = if let Some(epoch) = until_epoch {
-            serde_json::json!({
-                "rewards": {
-                    "address": address,
-                    "until_epoch": epoch
-                }
-            })
-        } else {
-            serde_json::json!({
-                "rewards": {
-                    "address": address
-                }
+    /// Rank the pools each holding could be provided to by estimated APR
+    ///
+    /// Fee income is estimated from the swaps of the last `lookback_blocks`
+    /// blocks, incentives from the farms active in the current epoch. See
+    /// [`crate::earn`] for how yields are valued. Without a farm or epoch manager
+    /// only fees are counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `holdings` - Assets to find yield for, with the amount to deposit
+    /// * `lookback_blocks` - Swap history window used to estimate fee income
+    ///
+    /// # Returns
+    ///
+    /// Opportunities for all holdings, best total APR first
+    pub async fn earn_opportunities(
+        &self,
+        holdings: &[Coin],
+        lookback_blocks: u64,
+    ) -> Result<Vec<EarnOpportunity>, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        let candidates: Vec<&PoolInfoResponse> = pools
+            .iter()
+            .filter(|pool| {
+                holdings
+                    .iter()
+                    .any(|holding| pool.pool_info.asset_denoms.contains(&holding.denom))
             })
+            .collect();
+
+        let (from_height, window_seconds) = self.swap_window(lookback_blocks).await?;
+        let (farms, current_epoch, epoch_seconds) = self.active_farm_schedule().await;
+        let context = YieldContext {
+            prices: PriceBook::new(&pools),
+            farms: &farms,
+            current_epoch,
+            epoch_seconds,
+            window_seconds,
         };
 
-        self.query(farm_manager_address, &query).await
+        let mut opportunities = Vec::new();
+        for pool in candidates {
+            let fills = self
+                .indexer()
+                .pool_swaps(&pool.pool_info.pool_identifier, Some(from_height), None)
+                .await?;
+            let staked_lp = self.staked_lp(pool).await;
+            opportunities.extend(
+                holdings
+                    .iter()
+                    .filter_map(|holding| context.opportunity(pool, holding, &fills, staked_lp)),
+            );
+        }
+
+        earn::rank(&mut opportunities);
+        Ok(opportunities)
     }
 
-    /// Query all rewards for an address (backward compatibility)
-    pub async fn query_all_rewards(&self, address: &str) -> Result<serde_json::Value, Error> {
-        self.query_rewards(address, None).await
+    /// Compare pools side by side: fees, liquidity, volume, yield and price
+    ///
+    /// Volume and fee income are taken from the swaps of the last
+    /// `lookback_blocks` blocks, incentives from the farms active in the current
+    /// epoch, as for [`earn_opportunities`](Self::earn_opportunities). See
+    /// [`crate::compare`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_ids` - Pools to compare, in the order to show them
+    /// * `quote` - Denom to value liquidity, volume and positions in; may be
+    ///   [`USD`](crate::convert::USD)
+    /// * `address` - Address whose LP positions to include, if any
+    /// * `lookback_blocks` - Swap history window used to estimate volume and fees
+    ///
+    /// # Returns
+    ///
+    /// One comparison per pool, in the order given
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if a pool does not exist
+    /// * Returns error if the pools, blocks or swap history cannot be queried
+    pub async fn compare_pools(
+        &self,
+        pool_ids: &[String],
+        quote: &str,
+        address: Option<&str>,
+        lookback_blocks: u64,
+    ) -> Result<Vec<PoolComparison>, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        let selected = pool_ids
+            .iter()
+            .map(|id| {
+                pools
+                    .iter()
+                    .find(|pool| &pool.pool_info.pool_identifier == id)
+                    .ok_or_else(|| Error::Other(format!("Pool {} not found", id)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.compare_selected(&pools, selected, quote, address, lookback_blocks)
+            .await
     }
 
-    /// Query rewards for an address up to a specific epoch
-    pub async fn query_rewards_until_epoch(
+    /// Value the reserves of `pool_id` in `quote`
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_id` - Pool to value
+    /// * `quote` - Denom to value the reserves in; may be [`USD`](crate::convert::USD)
+    ///
+    /// # Errors
+    ///
+    /// * Returns error if the pool does not exist
+    /// * Returns error if the pools cannot be queried
+    pub async fn pool_tvl(&self, pool_id: &str, quote: &str) -> Result<PoolTvl, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        let pool = pools
+            .iter()
+            .find(|pool| pool.pool_info.pool_identifier == pool_id)
+            .ok_or_else(|| Error::Other(format!("Pool {} not found", pool_id)))?;
+        let prices = PriceBook::new(&pools).with_usd_denoms(&self.usd_denoms);
+        Ok(compare::pool_tvl(&prices, pool, quote))
+    }
+
+    /// The `limit` pools with the highest TVL, volume or APR
+    ///
+    /// Every pool is compared as by [`compare_pools`](Self::compare_pools), so
+    /// this queries the swap history of each; see [`compare::rank`] for the order.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote` - Denom to value liquidity and volume in; may be
+    ///   [`USD`](crate::convert::USD)
+    /// * `by` - Figure to rank the pools by
+    /// * `limit` - Number of pools to return
+    /// * `lookback_blocks` - Swap history window used to estimate volume and fees
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the pools, blocks or swap history cannot be queried
+    pub async fn top_pools(
         &self,
-        address: &str,
-        until_epoch: u64,
-    ) -> Result<serde_json::Value, Error> {
-        self.query_rewards(address, Some(until_epoch)).await
+        quote: &str,
+        by: PoolRanking,
+        limit: usize,
+        lookback_blocks: u64,
+    ) -> Result<Vec<PoolComparison>, Error> {
+        let pools: Vec<PoolInfoResponse> = self.pools_stream(POOL_PAGE_LIMIT).try_collect().await?;
+        let mut comparisons = self
+            .compare_selected(&pools, pools.iter().collect(), quote, None, lookback_blocks)
+            .await?;
+        compare::rank(&mut comparisons, by);
+        comparisons.truncate(limit);
+        Ok(comparisons)
     }
 
-    /// Get current epoch from epoch manager contract
-    pub async fn get_current_epoch(&self) -> Result<u64, Error> {
-        let epoch_manager_address =
-            self.config
-                .contracts
-                .epoch_manager
-                .as_ref()
-                .ok_or_else(|| {
-                    Error::Other("Epoch manager contract address not configured".to_string())
-                })?;
+    /// Compare `selected`, some of `pools`, in `quote`
+    async fn compare_selected(
+        &self,
+        pools: &[PoolInfoResponse],
+        selected: Vec<&PoolInfoResponse>,
+        quote: &str,
+        address: Option<&str>,
+        lookback_blocks: u64,
+    ) -> Result<Vec<PoolComparison>, Error> {
+        let (from_height, window_seconds) = self.swap_window(lookback_blocks).await?;
+        let (farms, current_epoch, epoch_seconds) = self.active_farm_schedule().await;
+        let context = YieldContext {
+            prices: PriceBook::new(pools).with_usd_denoms(&self.usd_denoms),
+            farms: &farms,
+            current_epoch,
+            epoch_seconds,
+            window_seconds,
+        };
 
-        let query = serde_json::json!({
-            "current_epoch": {}
-        });
+        let mut comparisons = Vec::with_capacity(selected.len());
+        for pool in selected {
+            let fills = self
+                .indexer()
+                .pool_swaps(&pool.pool_info.pool_identifier, Some(from_height), None)
+                .await?;
+            let staked_lp = self.staked_lp(pool).await;
+            let lp_balance = match address {
+                Some(address) => Some(
+                    self.get_balance_for_address(address, &pool.pool_info.lp_denom)
+                        .await?
+                        .amount,
+                ),
+                None => None,
+            };
+            comparisons.push(compare::compare(
+                &context, pool, &fills, staked_lp, lp_balance, quote,
+            ));
+        }
+        Ok(comparisons)
+    }
 
-        let response: serde_json::Value = self.query(epoch_manager_address, &query).await?;
+    /// First block of a `lookback_blocks` swap history window, and its length in seconds
+    async fn swap_window(&self, lookback_blocks: u64) -> Result<(u64, u64), Error> {
+        let latest = self.get_last_block_height().await?;
+        let from_height = latest.saturating_sub(lookback_blocks).max(1);
+        let (now, start) = future::try_join(
+            self.get_block_timestamp(latest),
+            self.get_block_timestamp(from_height),
+        )
+        .await?;
+        Ok((from_height, now.saturating_sub(start)))
+    }
 
-        // Extract epoch number from response
-        response
-            .get("epoch")
-            .and_then(|v| v.as_u64())
-            .ok_or_else(|| Error::Other("Failed to parse epoch from response".to_string()))
+    /// Farms, current epoch and epoch length; empty without a farm or epoch manager
+    async fn active_farm_schedule(&self) -> (Vec<Farm>, u64, u64) {
+        let (farms, current_epoch, epoch_config) = future::join3(
+            self.farms_stream(None, POOL_PAGE_LIMIT)
+                .try_collect::<Vec<Farm>>(),
+            self.get_current_epoch(),
+            self.get_epoch_config(),
+        )
+        .await;
+        match (farms, current_epoch, epoch_config) {
+            (Ok(farms), Ok(epoch), Ok(config)) => (farms, epoch, config.duration.u64()),
+            _ => (Vec::new(), 0, 0),
+        }
+    }
+
+    /// Amount of a pool's LP token staked in the farm manager, if known
+    async fn staked_lp(&self, pool: &PoolInfoResponse) -> Option<Uint128> {
+        let farm_manager = self.config.contracts.farm_manager.as_deref()?;
+        self.get_balance_for_address(farm_manager, &pool.pool_info.lp_denom)
+            .await
+            .ok()
+            .map(|balance| balance.amount)
     }
 
     /// Validate epoch parameter for claim/query operations
@@ -1340,53 +6008,7 @@ impl MantraDexClient {
         &self,
         pool_fees: &mantra_dex_std::fee::PoolFee,
     ) -> Result<(), Error> {
-        let protocol_fee = pool_fees.protocol_fee.share;
-        let swap_fee = pool_fees.swap_fee.share;
-        let burn_fee = pool_fees.burn_fee.share;
-
-        // Calculate total from extra fees
-        let extra_fees_total: cosmwasm_std::Decimal =
-            pool_fees.extra_fees.iter().map(|fee| fee.share).sum();
-
-        // Calculate total fees
-        let total_fees = protocol_fee + swap_fee + burn_fee + extra_fees_total;
-
-        // Maximum allowed total fees is 20% (0.2)
-        let max_total_fees = cosmwasm_std::Decimal::percent(20);
-
-        if total_fees > max_total_fees {
-            return Err(Error::FeeValidation(format!(
-                "Total fees ({}) exceed maximum allowed ({}). Protocol: {}, Swap: {}, Burn: {}, Extra: {}",
-                total_fees,
-                max_total_fees,
-                protocol_fee,
-                swap_fee,
-                burn_fee,
-                extra_fees_total
-            )));
-        }
-
-        // Individual fee validation (each fee should be non-negative)
-        // Note: cosmwasm_std::Decimal is always non-negative by design, so this validation
-        // is primarily for completeness and future-proofing
-        let zero = cosmwasm_std::Decimal::zero();
-        if protocol_fee < zero || swap_fee < zero || burn_fee < zero {
-            return Err(Error::FeeValidation(
-                "Individual fees cannot be negative".to_string(),
-            ));
-        }
-
-        // Validate extra fees
-        for (i, fee) in pool_fees.extra_fees.iter().enumerate() {
-            if fee.share < zero {
-                return Err(Error::FeeValidation(format!(
-                    "Extra fee {} cannot be negative: {}",
-                    i, fee.share
-                )));
-            }
-        }
-
-        Ok(())
+        check_pool_fees(pool_fees)
     }
 
     /// Create a validated PoolFee structure with automatic total fee checking
@@ -1613,6 +6235,46 @@ impl MantraDexClient {
         ).await
     }
 
+    /// Swap through Skip Adapter and send the output to the wallet's own
+    /// account on another chain over IBC
+    ///
+    /// The receiver is the wallet's address with `counterpart_prefix`, and a
+    /// failed transfer is refunded to the wallet on MANTRA. Only chains deriving
+    /// keys on the same coin type share the account; see [`crate::wallet::address`].
+    ///
+    /// # Arguments
+    ///
+    /// * `operations` - Vector of skip swap operations
+    /// * `offer_coin` - The coin to offer for the swap
+    /// * `min_receive_amount` - Minimum amount to receive
+    /// * `source_channel` - IBC channel on MANTRA towards the counterpart chain
+    /// * `counterpart_prefix` - Bech32 prefix of the counterpart chain, e.g. `osmo`
+    ///
+    /// # Returns
+    ///
+    /// Transaction response containing the swap and transfer result
+    pub async fn execute_skip_swap_and_ibc_transfer(
+        &self,
+        operations: Vec<crate::skip_adapter::SkipSwapOperation>,
+        offer_coin: Coin,
+        min_receive_amount: Uint128,
+        source_channel: &str,
+        counterpart_prefix: &str,
+    ) -> Result<TxResponse, Error> {
+        let sender = self.wallet()?.address()?.to_string();
+        let ibc_info = crate::skip_adapter::SkipIbcInfo::to_own_account(
+            source_channel,
+            &sender,
+            counterpart_prefix,
+        )?;
+        let action = crate::skip_adapter::SkipAction::IbcTransfer {
+            ibc_info,
+            fee_swap: None,
+        };
+        self.execute_skip_swap_internal(operations, offer_coin, min_receive_amount, action, vec![])
+            .await
+    }
+
     /// Simulate a swap exact asset in through Skip Adapter
     ///
     /// This method simulates a swap through the Skip adapter system without executing it.
@@ -1822,3 +6484,55 @@ impl MantraDexClient {
         }
     }
 }
+
+/// Check pool fees against the limits of the pool manager, see
+/// [`MantraDexClient::validate_pool_fees`]
+pub(crate) fn check_pool_fees(pool_fees: &mantra_dex_std::fee::PoolFee) -> Result<(), Error> {
+    let protocol_fee = pool_fees.protocol_fee.share;
+    let swap_fee = pool_fees.swap_fee.share;
+    let burn_fee = pool_fees.burn_fee.share;
+
+    // Calculate total from extra fees
+    let extra_fees_total: cosmwasm_std::Decimal =
+        pool_fees.extra_fees.iter().map(|fee| fee.share).sum();
+
+    // Calculate total fees
+    let total_fees = protocol_fee + swap_fee + burn_fee + extra_fees_total;
+
+    // Maximum allowed total fees is 20% (0.2)
+    let max_total_fees = cosmwasm_std::Decimal::percent(20);
+
+    if total_fees > max_total_fees {
+        return Err(Error::FeeValidation(format!(
+            "Total fees ({}) exceed maximum allowed ({}). Protocol: {}, Swap: {}, Burn: {}, Extra: {}",
+            total_fees,
+            max_total_fees,
+            protocol_fee,
+            swap_fee,
+            burn_fee,
+            extra_fees_total
+        )));
+    }
+
+    // Individual fee validation (each fee should be non-negative)
+    // Note: cosmwasm_std::Decimal is always non-negative by design, so this validation
+    // is primarily for completeness and future-proofing
+    let zero = cosmwasm_std::Decimal::zero();
+    if protocol_fee < zero || swap_fee < zero || burn_fee < zero {
+        return Err(Error::FeeValidation(
+            "Individual fees cannot be negative".to_string(),
+        ));
+    }
+
+    // Validate extra fees
+    for (i, fee) in pool_fees.extra_fees.iter().enumerate() {
+        if fee.share < zero {
+            return Err(Error::FeeValidation(format!(
+                "Extra fee {} cannot be negative: {}",
+                i, fee.share
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+//! Side-by-side pool comparison
+//!
+//! Choosing where to provide liquidity means weighing pools holding the same
+//! assets against each other: what they charge, how deep they are, how much
+//! trades through them and what they pay. [`compare`] gathers those figures for
+//! one pool, valued in a common quote denom so the pools line up, and, given an
+//! LP balance, the position already held in it.
+//!
+//! Volume and yields come from the same swap history window and farm data as
+//! [`crate::earn`], so the two views agree. [`rank`] orders compared pools by
+//! TVL, volume or APR to find the top pools of a network.
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::analytics::SwapFill;
+use crate::convert::PriceBook;
+use crate::earn::{pool_price, YieldContext};
+use crate::error::Error;
+use crate::report::HoldingValue;
+
+/// Seconds in a day, used to scale the swap history window to daily volume
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// One pool's figures for a side-by-side comparison
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolComparison {
+    pub pool_id: String,
+    /// Pool reserves
+    pub assets: Vec<Coin>,
+    /// Share of each swap kept by liquidity providers
+    pub swap_fee: Decimal,
+    /// Swap, protocol, burn and extra fees combined
+    pub total_fee: Decimal,
+    /// Denom the TVL, volume and position are valued in
+    pub quote: String,
+    /// Pool liquidity in base units of `quote`, if every asset can be priced
+    pub tvl: Option<Uint128>,
+    /// Swap volume over a day in base units of `quote`, scaled from the window
+    pub volume_24h: Option<Uint128>,
+    /// Estimated yearly swap fee income as a share of liquidity
+    pub fee_apr: Decimal,
+    /// Estimated yearly farm incentives as a share of staked liquidity
+    pub incentive_apr: Decimal,
+    /// Price of the first asset in the second, from the pool's own reserves
+    pub price: Option<Decimal>,
+    /// Position held in the pool, if an LP balance was given
+    pub position: Option<PoolPosition>,
+}
+
+impl PoolComparison {
+    /// Fee and incentive APR combined
+    pub fn total_apr(&self) -> Decimal {
+        self.fee_apr.saturating_add(self.incentive_apr)
+    }
+}
+
+/// A pool's reserves valued in a quote denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolTvl {
+    pub pool_id: String,
+    /// Denom the reserves are valued in
+    pub quote: String,
+    /// Each reserve with its value, if a pool prices it
+    pub reserves: Vec<HoldingValue>,
+    /// Pool liquidity in base units of `quote`, if every reserve is priced
+    pub tvl: Option<Uint128>,
+}
+
+/// Value the reserves of `pool` in `quote` at the prices of `prices`
+pub fn pool_tvl(prices: &PriceBook<'_>, pool: &PoolInfoResponse, quote: &str) -> PoolTvl {
+    let reserves: Vec<HoldingValue> = pool
+        .pool_info
+        .assets
+        .iter()
+        .map(|asset| HoldingValue {
+            coin: asset.clone(),
+            value: prices
+                .price(&asset.denom, quote)
+                .map(|price| asset.amount.mul_floor(price)),
+        })
+        .collect();
+    PoolTvl {
+        pool_id: pool.pool_info.pool_identifier.clone(),
+        quote: quote.to_string(),
+        tvl: reserves.iter().map(|reserve| reserve.value).sum(),
+        reserves,
+    }
+}
+
+/// Figure pools are ranked by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolRanking {
+    /// Liquidity
+    #[default]
+    Tvl,
+    /// Swap volume over a day
+    Volume24h,
+    /// Fee and incentive APR combined
+    Apr,
+}
+
+impl FromStr for PoolRanking {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "tvl" => Ok(PoolRanking::Tvl),
+            "volume_24h" | "volume" => Ok(PoolRanking::Volume24h),
+            "apr" => Ok(PoolRanking::Apr),
+            other => Err(Error::Other(format!(
+                "Unknown pool ranking '{}', expected tvl, volume_24h or apr",
+                other
+            ))),
+        }
+    }
+}
+
+/// Sort `comparisons` by `by`, highest first
+///
+/// Pools whose TVL or volume can't be valued in the quote come last.
+pub fn rank(comparisons: &mut [PoolComparison], by: PoolRanking) {
+    comparisons.sort_by(|a, b| match by {
+        PoolRanking::Tvl => b.tvl.cmp(&a.tvl),
+        PoolRanking::Volume24h => b.volume_24h.cmp(&a.volume_24h),
+        PoolRanking::Apr => b.total_apr().cmp(&a.total_apr()),
+    });
+}
+
+/// LP tokens held in a pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolPosition {
+    pub lp_amount: Uint128,
+    /// Share of the pool's LP supply
+    pub share: Decimal,
+    /// Value in base units of the comparison's quote, if the TVL is known
+    pub value: Option<Uint128>,
+}
+
+/// Compare `pool`, valuing it in `quote`
+///
+/// `fills` are the pool's swaps during the context's window, `staked_lp` the
+/// amount of its LP token staked in farms, and `lp_balance` the LP tokens held,
+/// if a position should be shown.
+pub fn compare(
+    context: &YieldContext<'_>,
+    pool: &PoolInfoResponse,
+    fills: &[SwapFill],
+    staked_lp: Option<Uint128>,
+    lp_balance: Option<Uint128>,
+    quote: &str,
+) -> PoolComparison {
+    let info = &pool.pool_info;
+    let fees = &info.pool_fees;
+    let total_fee = fees.extra_fees.iter().fold(
+        fees.swap_fee
+            .share
+            .saturating_add(fees.protocol_fee.share)
+            .saturating_add(fees.burn_fee.share),
+        |total, fee| total.saturating_add(fee.share),
+    );
+
+    let value = |amount: Uint128, denom: &str| {
+        context
+            .prices
+            .price(denom, quote)
+            .map(|price| amount.mul_floor(price))
+    };
+    let tvl = pool_tvl(&context.prices, pool, quote).tvl;
+    let volume = fills
+        .iter()
+        .map(|fill| value(fill.offer_amount, &fill.offer_denom))
+        .sum::<Option<Uint128>>();
+    let volume_24h = volume.map(|volume| match context.window_seconds {
+        0 => volume,
+        window => volume.multiply_ratio(SECONDS_PER_DAY, window),
+    });
+
+    // APRs are ratios, so any asset the pool holds can stand in for the deposit
+    let yields = info
+        .assets
+        .iter()
+        .filter(|asset| !asset.amount.is_zero())
+        .find_map(|asset| {
+            context.opportunity(pool, &Coin::new(0u128, &asset.denom), fills, staked_lp)
+        });
+    let (fee_apr, incentive_apr) = yields
+        .map(|yields| (yields.fee_apr, yields.incentive_apr))
+        .unwrap_or_default();
+
+    let price = match info.asset_denoms.as_slice() {
+        [base, counter, ..] => pool_price(pool, base, counter),
+        _ => None,
+    };
+
+    let position = lp_balance.map(|lp_amount| {
+        let supply = pool.total_share.amount;
+        let share = Decimal::checked_from_ratio(lp_amount.min(supply), supply).unwrap_or_default();
+        PoolPosition {
+            lp_amount,
+            share,
+            value: tvl.map(|tvl| tvl.mul_floor(share)),
+        }
+    });
+
+    PoolComparison {
+        pool_id: info.pool_identifier.clone(),
+        assets: info.assets.clone(),
+        swap_fee: fees.swap_fee.share,
+        total_fee,
+        quote: quote.to_string(),
+        tvl,
+        volume_24h,
+        fee_apr,
+        incentive_apr,
+        price,
+        position,
+    }
+}
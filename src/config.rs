@@ -2,10 +2,18 @@ use config::{Config as ConfigLoader, ConfigError, File};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
-use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 
 use crate::error::Error;
+use crate::policy::TradeValueLimit;
+use crate::route_cache::RouteCacheConfig;
+use crate::schema::{self, Format, Schema};
+use crate::secret::SecretString;
+
+/// Format of the saved configuration
+const CONFIG_SCHEMA: Schema = Schema::new("configuration", Format::Toml, &[schema::add_version]);
 
 /// Network constants loaded from configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +30,20 @@ pub struct NetworkConstants {
     pub default_gas_adjustment: f64,
     /// Native token denom
     pub native_denom: String,
+    /// Denom fees are paid in, the native denom if not set
+    #[serde(default)]
+    pub fee_denom: Option<String>,
+    /// Tokens besides the native denom the chain accepts fees in
+    #[serde(default)]
+    pub fee_tokens: Vec<FeeToken>,
+}
+
+/// A token the chain accepts transaction fees in, with its gas price
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeeToken {
+    pub denom: String,
+    /// Gas price in `denom`
+    pub gas_price: f64,
 }
 
 impl NetworkConstants {
@@ -57,6 +79,8 @@ impl NetworkConstants {
                 default_gas_price: 0.01,
                 default_gas_adjustment: 1.5,
                 native_denom: "uom".to_string(),
+                fee_denom: None,
+                fee_tokens: Vec::new(),
             }),
             _ => Err(ConfigError::NotFound(format!(
                 "Network configuration for '{}' not found",
@@ -102,6 +126,392 @@ impl Default for ContractAddresses {
     }
 }
 
+impl ContractAddresses {
+    /// Addresses of the pool, farm, fee collector and epoch manager contracts that are set
+    pub fn dex_contracts(&self) -> Vec<String> {
+        std::iter::once(self.pool_manager.clone())
+            .chain(self.farm_manager.clone())
+            .chain(self.fee_collector.clone())
+            .chain(self.epoch_manager.clone())
+            .filter(|address| !address.is_empty())
+            .collect()
+    }
+}
+
+/// Kind of network operation a timeout applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationClass {
+    /// Read-only contract and chain queries
+    Query,
+    /// Swap simulations
+    Simulate,
+    /// Submitting a signed transaction until the node accepts it
+    Broadcast,
+    /// Waiting for an accepted transaction to be included in a block
+    Inclusion,
+}
+
+/// Request timeouts per operation class, in seconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OperationTimeouts {
+    /// Timeout for queries
+    pub query_secs: u64,
+    /// Timeout for swap simulations
+    pub simulate_secs: u64,
+    /// Timeout for broadcasting a transaction
+    pub broadcast_secs: u64,
+    /// Timeout for a broadcast transaction to be included in a block
+    pub inclusion_secs: u64,
+}
+
+impl Default for OperationTimeouts {
+    fn default() -> Self {
+        Self {
+            query_secs: 30,
+            simulate_secs: 30,
+            broadcast_secs: 30,
+            inclusion_secs: 60,
+        }
+    }
+}
+
+impl OperationTimeouts {
+    /// Timeout for the given operation class
+    pub fn for_class(&self, class: OperationClass) -> Duration {
+        let secs = match class {
+            OperationClass::Query => self.query_secs,
+            OperationClass::Simulate => self.simulate_secs,
+            OperationClass::Broadcast => self.broadcast_secs,
+            OperationClass::Inclusion => self.inclusion_secs,
+        };
+        Duration::from_secs(secs)
+    }
+}
+
+/// Circuit breaker settings for RPC endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures after which an endpoint is taken out of rotation
+    pub failure_threshold: u32,
+    /// Seconds an endpoint stays out of rotation before it is tried again
+    pub cooldown_secs: u64,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown_secs: 30,
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Cool-down period as a duration
+    pub fn cooldown(&self) -> Duration {
+        Duration::from_secs(self.cooldown_secs)
+    }
+}
+
+/// How transactions rejected for a transient reason are sent again
+///
+/// A transaction that fails before it is accepted into the mempool because of
+/// an account sequence mismatch or a full mempool is rebuilt with the account's
+/// current sequence, signed again and resent. The wait before each retry
+/// doubles, starting at `initial_backoff_ms`, up to `max_backoff_ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BroadcastRetryConfig {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    /// Milliseconds waited before the first retry
+    pub initial_backoff_ms: u64,
+    /// Longest wait between two attempts, in milliseconds
+    pub max_backoff_ms: u64,
+}
+
+impl Default for BroadcastRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 8_000,
+        }
+    }
+}
+
+impl BroadcastRetryConfig {
+    /// Wait before retry number `retry`, counting from 0
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self
+            .initial_backoff_ms
+            .saturating_mul(1u64.checked_shl(retry).unwrap_or(u64::MAX));
+        Duration::from_millis(backoff.min(self.max_backoff_ms))
+    }
+}
+
+/// How urgently a transaction should be included
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// Pay the configured gas price
+    #[default]
+    Normal,
+    /// Pay the priority gas price to get ahead of other transactions
+    High,
+    /// Pay the priority gas price only while the network is congested
+    Auto,
+}
+
+impl FromStr for Priority {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "normal" => Ok(Priority::Normal),
+            "high" => Ok(Priority::High),
+            "auto" => Ok(Priority::Auto),
+            other => Err(Error::Config(format!(
+                "Unknown priority '{}', expected normal, high or auto",
+                other
+            ))),
+        }
+    }
+}
+
+/// When transactions pay more for gas to be included sooner
+///
+/// The network counts as congested while the node's mempool holds at least
+/// `congested_mempool_txs` unconfirmed transactions, or the client's recent
+/// transactions took `congested_inclusion_secs` or longer to be included.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PriorityFeeConfig {
+    pub priority: Priority,
+    /// Gas price multiplier of prioritized transactions
+    pub multiplier: f64,
+    pub congested_mempool_txs: u64,
+    pub congested_inclusion_secs: u64,
+}
+
+impl Default for PriorityFeeConfig {
+    fn default() -> Self {
+        Self {
+            priority: Priority::Normal,
+            multiplier: 1.5,
+            congested_mempool_txs: 1_000,
+            congested_inclusion_secs: 15,
+        }
+    }
+}
+
+impl PriorityFeeConfig {
+    /// Gas price multiplier of a transaction, given whether the network is congested
+    pub fn fee_multiplier(&self, congested: bool) -> f64 {
+        match self.priority {
+            Priority::High => self.multiplier,
+            Priority::Auto if congested => self.multiplier,
+            Priority::Normal | Priority::Auto => 1.0,
+        }
+    }
+
+    pub fn congested_inclusion(&self) -> Duration {
+        Duration::from_secs(self.congested_inclusion_secs)
+    }
+}
+
+/// How the client picks among the RPC endpoints whose circuit is closed
+///
+/// Each endpoint's latency is measured periodically and the fastest one is
+/// preferred. A hint's weight divides the endpoint's latency, so a weight of 2
+/// prefers it over an endpoint up to twice as fast; endpoints hinted to be in
+/// the client's `region` count double.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EndpointSelectionConfig {
+    /// Whether endpoints are tried fastest first rather than in configured order
+    pub latency_aware: bool,
+    /// Seconds between latency measurements of every endpoint
+    pub probe_interval_secs: u64,
+    /// Region the client runs in, e.g. `eu-west`
+    pub region: Option<String>,
+    /// Static weights and region hints per endpoint
+    pub hints: Vec<EndpointHint>,
+    /// Blocks an endpoint may fall behind the highest measured height before
+    /// it is only used when no endpoint keeps up
+    pub max_block_lag: u64,
+}
+
+/// Static preference for one RPC endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointHint {
+    /// URL of the endpoint, as configured
+    pub url: String,
+    /// Relative preference, 1 by default
+    #[serde(default = "default_endpoint_weight")]
+    pub weight: f64,
+    /// Region the endpoint is in
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+fn default_endpoint_weight() -> f64 {
+    1.0
+}
+
+/// Factor applied to the weight of endpoints in the client's region
+const SAME_REGION_WEIGHT: f64 = 2.0;
+
+impl Default for EndpointSelectionConfig {
+    fn default() -> Self {
+        Self {
+            latency_aware: true,
+            probe_interval_secs: 60,
+            region: None,
+            hints: Vec::new(),
+            max_block_lag: 10,
+        }
+    }
+}
+
+impl EndpointSelectionConfig {
+    /// Interval between latency measurements as a duration
+    pub fn probe_interval(&self) -> Duration {
+        Duration::from_secs(self.probe_interval_secs.max(1))
+    }
+
+    /// Hint configured for `url`, if any
+    pub fn hint(&self, url: &str) -> Option<&EndpointHint> {
+        self.hints.iter().find(|hint| hint.url == url)
+    }
+
+    /// Effective weight of `url`, including the region preference
+    pub fn weight(&self, url: &str) -> f64 {
+        let Some(hint) = self.hint(url) else {
+            return 1.0;
+        };
+        let weight = if hint.weight.is_finite() && hint.weight > 0.0 {
+            hint.weight
+        } else {
+            1.0
+        };
+        let same_region = self.region.is_some() && hint.region == self.region;
+        if same_region {
+            weight * SAME_REGION_WEIGHT
+        } else {
+            weight
+        }
+    }
+}
+
+/// Assets and pools hidden from pool listings, balances and routing
+///
+/// Denied denoms and pools are never shown or traded. In strict mode only
+/// allowlisted ones are. A denom entry ending in `*` matches every denom with
+/// that prefix, e.g. `factory/mantra1scammer/*`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssetFilterConfig {
+    /// Denoms that are hidden, e.g. known scam factory tokens
+    pub denied_denoms: Vec<String>,
+    /// Pools that are hidden
+    pub denied_pools: Vec<String>,
+    /// Whether only allowlisted denoms and pools are shown
+    pub strict: bool,
+    /// Denoms shown in strict mode
+    pub allowed_denoms: Vec<String>,
+    /// Pools shown in strict mode, in addition to pools of only allowed denoms
+    pub allowed_pools: Vec<String>,
+}
+
+impl AssetFilterConfig {
+    /// Whether nothing is filtered
+    pub fn is_empty(&self) -> bool {
+        self.denied_denoms.is_empty() && self.denied_pools.is_empty() && !self.strict
+    }
+
+    /// Whether `denom` is shown
+    pub fn allows_denom(&self, denom: &str) -> bool {
+        if Self::matches(&self.denied_denoms, denom) {
+            return false;
+        }
+        !self.strict || Self::matches(&self.allowed_denoms, denom)
+    }
+
+    /// Whether the pool `pool_id` holding `denoms` is shown
+    ///
+    /// A pool is hidden if it or any of its denoms is denied. In strict mode it
+    /// must also be allowlisted itself or hold only allowed denoms.
+    pub fn allows_pool<S: AsRef<str>>(&self, pool_id: &str, denoms: &[S]) -> bool {
+        if self.denied_pools.iter().any(|denied| denied == pool_id)
+            || denoms
+                .iter()
+                .any(|denom| Self::matches(&self.denied_denoms, denom.as_ref()))
+        {
+            return false;
+        }
+        !self.strict
+            || self.allowed_pools.iter().any(|allowed| allowed == pool_id)
+            || denoms.iter().all(|denom| self.allows_denom(denom.as_ref()))
+    }
+
+    fn matches(patterns: &[String], denom: &str) -> bool {
+        patterns
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => denom.starts_with(prefix),
+                None => pattern == denom,
+            })
+    }
+}
+
+/// Automatic pool selection settings
+///
+/// Excluded pools are skipped when a swap picks its pool automatically, e.g.
+/// low-liquidity duplicates, but can still be listed and traded directly. A
+/// pinned route makes every automatic swap between its two denoms, in either
+/// direction, go through its pool while that pool can quote the swap.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoutingConfig {
+    /// Pools automatic routing never picks
+    pub excluded_pools: Vec<String>,
+    /// Pools automatic routing always picks for their pair
+    pub pinned_routes: Vec<PinnedRoute>,
+    /// Cache of the routes picked, off if not set; see [`crate::route_cache`]
+    pub route_cache: Option<RouteCacheConfig>,
+}
+
+/// A pool pinned for swaps between two denoms
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PinnedRoute {
+    pub denom_a: String,
+    pub denom_b: String,
+    pub pool_id: String,
+}
+
+impl RoutingConfig {
+    /// Whether automatic routing skips `pool_id`
+    pub fn excludes(&self, pool_id: &str) -> bool {
+        self.excluded_pools
+            .iter()
+            .any(|excluded| excluded == pool_id)
+    }
+
+    /// Pool pinned for swaps between `denom_a` and `denom_b`, in either direction
+    pub fn pinned_pool(&self, denom_a: &str, denom_b: &str) -> Option<&str> {
+        self.pinned_routes
+            .iter()
+            .find(|route| {
+                (route.denom_a == denom_a && route.denom_b == denom_b)
+                    || (route.denom_a == denom_b && route.denom_b == denom_a)
+            })
+            .map(|route| route.pool_id.as_str())
+    }
+}
+
 /// Network configuration for Mantra DEX
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MantraNetworkConfig {
@@ -111,14 +521,38 @@ pub struct MantraNetworkConfig {
     pub chain_id: String,
     /// RPC endpoint URL
     pub rpc_url: String,
+    /// RPC endpoints used, in order, while the primary endpoint is failing
+    #[serde(default)]
+    pub fallback_rpc_urls: Vec<String>,
     /// Gas price in native token
     pub gas_price: f64,
     /// Gas adjustment for transactions
     pub gas_adjustment: f64,
     /// Native token denom
     pub native_denom: String,
+    /// Denom fees are paid in, the native denom if not set
+    #[serde(default)]
+    pub fee_denom: Option<String>,
+    /// Tokens besides the native denom the chain accepts fees in
+    #[serde(default)]
+    pub fee_tokens: Vec<FeeToken>,
     /// Contract addresses
     pub contracts: ContractAddresses,
+    /// Request timeouts per operation class
+    #[serde(default)]
+    pub timeouts: OperationTimeouts,
+    /// Circuit breaker settings for the RPC endpoints
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+    /// How the fastest healthy RPC endpoint is picked
+    #[serde(default)]
+    pub endpoint_selection: EndpointSelectionConfig,
+    /// Retries of transactions rejected for a transient reason
+    #[serde(default)]
+    pub broadcast_retry: BroadcastRetryConfig,
+    /// When transactions pay a higher gas price to be included sooner
+    #[serde(default)]
+    pub priority_fee: PriorityFeeConfig,
 }
 
 impl MantraNetworkConfig {
@@ -131,13 +565,108 @@ impl MantraNetworkConfig {
             network_name: constants.network_name.clone(),
             chain_id: constants.chain_id.clone(),
             rpc_url: constants.default_rpc.clone(),
+            fallback_rpc_urls: Vec::new(),
             gas_price: constants.default_gas_price,
             gas_adjustment: constants.default_gas_adjustment,
             native_denom: constants.native_denom.clone(),
+            fee_denom: constants.fee_denom.clone(),
+            fee_tokens: constants.fee_tokens.clone(),
             contracts,
+            timeouts: OperationTimeouts::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            endpoint_selection: EndpointSelectionConfig::default(),
+            broadcast_retry: BroadcastRetryConfig::default(),
+            priority_fee: PriorityFeeConfig::default(),
         })
     }
 
+    /// The primary RPC endpoint followed by the fallbacks
+    pub fn rpc_endpoints(&self) -> Vec<String> {
+        std::iter::once(self.rpc_url.clone())
+            .chain(self.fallback_rpc_urls.iter().cloned())
+            .collect()
+    }
+
+    /// Token transactions pay fees in: the configured fee denom, at its gas
+    /// price, or the native denom at the network gas price
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the fee denom is neither the native denom
+    /// nor one of the fee tokens the network accepts
+    pub fn fee_token(&self) -> Result<FeeToken, Error> {
+        let denom = self.fee_denom.as_deref().unwrap_or(&self.native_denom);
+        if denom == self.native_denom {
+            return Ok(FeeToken {
+                denom: denom.to_string(),
+                gas_price: self.gas_price,
+            });
+        }
+        self.fee_tokens
+            .iter()
+            .find(|token| token.denom == denom)
+            .cloned()
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "Fee denom {} is not accepted on {}; expected one of {}",
+                    denom,
+                    self.network_name,
+                    self.fee_denoms().join(", ")
+                ))
+            })
+    }
+
+    /// Denoms the network accepts fees in, the native denom first
+    pub fn fee_denoms(&self) -> Vec<&str> {
+        std::iter::once(self.native_denom.as_str())
+            .chain(self.fee_tokens.iter().map(|token| token.denom.as_str()))
+            .collect()
+    }
+
+    /// Check that `balances` cover `fee`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if they don't, naming the other accepted fee
+    /// denoms the wallet holds, if any
+    pub fn check_fee_funds(
+        &self,
+        fee: &cosmwasm_std::Coin,
+        balances: &[cosmwasm_std::Coin],
+    ) -> Result<(), Error> {
+        let held = |denom: &str| {
+            balances
+                .iter()
+                .find(|coin| coin.denom == denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default()
+        };
+        if held(&fee.denom) >= fee.amount {
+            return Ok(());
+        }
+        let alternatives: Vec<&str> = self
+            .fee_denoms()
+            .into_iter()
+            .filter(|denom| *denom != fee.denom && !held(denom).is_zero())
+            .collect();
+        let hint = if alternatives.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "; the network also accepts fees in {}, which the wallet holds",
+                alternatives.join(", ")
+            )
+        };
+        Err(Error::Wallet(format!(
+            "Fee of {}{} exceeds the wallet's {}{} balance{}",
+            fee.amount,
+            fee.denom,
+            held(&fee.denom),
+            fee.denom,
+            hint
+        )))
+    }
+
     /// Load contract addresses for the given network from the contracts configuration file.
     /// Returns an error if the contract addresses cannot be loaded.
     fn load_contract_addresses(network: &str) -> Result<ContractAddresses, Error> {
@@ -199,19 +728,35 @@ impl Default for MantraNetworkConfig {
                 network_name: constants.network_name,
                 chain_id: constants.chain_id,
                 rpc_url: constants.default_rpc,
+                fallback_rpc_urls: Vec::new(),
                 gas_price: constants.default_gas_price,
                 gas_adjustment: constants.default_gas_adjustment,
                 native_denom: constants.native_denom,
+                fee_denom: constants.fee_denom,
+                fee_tokens: constants.fee_tokens,
                 contracts: ContractAddresses::default(),
+                timeouts: OperationTimeouts::default(),
+                circuit_breaker: CircuitBreakerConfig::default(),
+                endpoint_selection: EndpointSelectionConfig::default(),
+                broadcast_retry: BroadcastRetryConfig::default(),
+                priority_fee: PriorityFeeConfig::default(),
             }),
             Err(_) => Self {
                 network_name: "mantra-dukong".to_string(),
                 chain_id: "mantra-dukong-1".to_string(),
                 rpc_url: "https://rpc.dukong.mantrachain.io:443".to_string(),
+                fallback_rpc_urls: Vec::new(),
                 gas_price: 0.01,
                 gas_adjustment: 1.5,
                 native_denom: "uom".to_string(),
+                fee_denom: None,
+                fee_tokens: Vec::new(),
                 contracts: ContractAddresses::default(),
+                timeouts: OperationTimeouts::default(),
+                circuit_breaker: CircuitBreakerConfig::default(),
+                endpoint_selection: EndpointSelectionConfig::default(),
+                broadcast_retry: BroadcastRetryConfig::default(),
+                priority_fee: PriorityFeeConfig::default(),
             },
         }
     }
@@ -223,9 +768,136 @@ pub struct Config {
     /// Active network configuration
     pub network: MantraNetworkConfig,
     /// Wallet mnemonic (seed phrase)
-    pub mnemonic: Option<String>,
+    pub mnemonic: Option<SecretString>,
     /// Known tokens and their metadata
     pub tokens: HashMap<String, TokenInfo>,
+    /// Expert mode settings saved before they moved to the
+    /// [preferences](crate::preferences); only read to migrate them
+    #[serde(default, skip_serializing)]
+    pub expert_mode: ExpertModeConfig,
+    /// Denoms and pools hidden from listings, balances and routing
+    #[serde(default)]
+    pub asset_filter: AssetFilterConfig,
+    /// Pools excluded from and pinned for automatic routing
+    #[serde(default)]
+    pub routing: RoutingConfig,
+    /// External indexer serving historical data instead of the node's transaction index
+    #[serde(default)]
+    pub indexer: IndexerConfig,
+    /// How amounts are valued in other assets and in US dollars
+    #[serde(default)]
+    pub pricing: PricingConfig,
+    /// Where history, schedules, orders and preferences are kept
+    #[serde(default)]
+    pub storage: StorageConfig,
+    /// How pool prices are sampled for charts
+    #[serde(default)]
+    pub price_history: PriceHistoryConfig,
+}
+
+/// External indexer API settings
+///
+/// Without a URL, historical data is read from the node's transaction index.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexerConfig {
+    /// Base URL of the indexer's REST API
+    pub url: Option<String>,
+    /// API key sent as a bearer token
+    pub api_key: Option<String>,
+}
+
+/// Storage backend settings
+///
+/// Without a path, each backend keeps its data in the configuration directory,
+/// see [`crate::storage`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// Directory of the `files` backend, or database of the others
+    pub path: Option<PathBuf>,
+    /// Most connections the `sqlite` backend opens at once
+    pub max_connections: Option<u32>,
+}
+
+/// Where persistent state is kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    /// One file per document, as releases before storage backends did
+    #[default]
+    Files,
+    /// One SQLite database, with the `sqlite` feature
+    Sqlite,
+    /// One sled database, with the `sled` feature
+    Sled,
+    /// Nothing is persisted, e.g. for tests
+    Memory,
+}
+
+/// Pool price sampling settings
+///
+/// Unset values fall back to the defaults of [`crate::price_history`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PriceHistoryConfig {
+    /// Seconds between two samples of a pool
+    pub interval_secs: Option<u64>,
+    /// Days samples are kept
+    pub retention_days: Option<u64>,
+    /// Pools to sample, every pool if empty
+    pub pools: Vec<String>,
+}
+
+/// Valuation settings
+///
+/// Amounts are valued at pool spot prices, see [`crate::convert`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PricingConfig {
+    /// USD stablecoins amounts are valued in US dollars through, in order of preference
+    pub usd_denoms: Vec<String>,
+    /// Highest value a swap may offer, e.g. 1000 USD as `max_value = "1000000000"`
+    /// and `denom = "usd"`
+    pub max_trade_value: Option<TradeValueLimit>,
+}
+
+/// Expert mode settings for skipping confirmations on small, low-impact trades
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpertModeConfig {
+    /// Whether low-risk trades skip the confirmation prompt
+    pub enabled: bool,
+    /// Trade value, in USD, below which a trade counts as low risk
+    pub max_trade_value_usd: f64,
+    /// Price impact, in percent, below which a trade counts as low risk
+    pub max_price_impact_percent: f64,
+}
+
+impl Default for ExpertModeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_trade_value_usd: 50.0,
+            max_price_impact_percent: 0.5,
+        }
+    }
+}
+
+impl ExpertModeConfig {
+    /// Whether a trade can execute without confirmation
+    ///
+    /// A trade whose value or price impact is unknown always needs confirming.
+    pub fn skips_confirmation(
+        &self,
+        trade_value_usd: Option<f64>,
+        price_impact_percent: Option<f64>,
+    ) -> bool {
+        self.enabled
+            && trade_value_usd.is_some_and(|value| value < self.max_trade_value_usd)
+            && price_impact_percent.is_some_and(|impact| impact < self.max_price_impact_percent)
+    }
 }
 
 /// Token information
@@ -254,29 +926,24 @@ impl Config {
             network: MantraNetworkConfig::default(),
             mnemonic: None,
             tokens: HashMap::new(),
+            expert_mode: ExpertModeConfig::default(),
+            asset_filter: AssetFilterConfig::default(),
+            routing: RoutingConfig::default(),
+            indexer: IndexerConfig::default(),
+            pricing: PricingConfig::default(),
+            storage: StorageConfig::default(),
+            price_history: PriceHistoryConfig::default(),
         }
     }
 
-    /// Load configuration from a file
-    pub fn load(path: &PathBuf) -> Result<Self, Error> {
-        let content = fs::read_to_string(path)?;
-        let config: Config = toml::from_str(&content)
-            .map_err(|e| Error::Config(format!("Failed to parse config: {}", e)))?;
-        Ok(config)
+    /// Load configuration from a file, migrating it if an older release wrote it
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        schema::read(&CONFIG_SCHEMA, path)
     }
 
     /// Save configuration to a file
-    pub fn save(&self, path: &PathBuf) -> Result<(), Error> {
-        let content = toml::to_string_pretty(self)
-            .map_err(|e| Error::Config(format!("Failed to serialize config: {}", e)))?;
-
-        // Create directory if it doesn't exist
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        fs::write(path, content)?;
-        Ok(())
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        schema::write(&CONFIG_SCHEMA, path, self)
     }
 
     /// Get the default configuration file path
@@ -0,0 +1,87 @@
+//! Detection of congested networks
+//!
+//! When blocks are full, transactions paying the minimum gas price wait in the
+//! mempool for several blocks. A [`Congestion`] snapshot combines the number of
+//! unconfirmed transactions in the node's mempool with how long the client's
+//! recent transactions took to be included, as recorded by
+//! [`InclusionLatencies`]. [`MantraDexClient`] raises the gas price of
+//! transactions sent while the network is congested when the
+//! [`PriorityFeeConfig`] asks for it.
+//!
+//! [`MantraDexClient`]: crate::client::MantraDexClient
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::PriorityFeeConfig;
+
+/// Number of recent inclusions the latency is taken over
+const LATENCY_SAMPLES: usize = 10;
+
+/// Time recent transactions took from broadcast to inclusion, safe to share
+/// between tasks
+#[derive(Debug, Default)]
+pub struct InclusionLatencies {
+    samples: Mutex<VecDeque<Duration>>,
+}
+
+impl InclusionLatencies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that a transaction was included `latency` after it was broadcast
+    pub fn record(&self, latency: Duration) {
+        let mut samples = self.lock();
+        if samples.len() == LATENCY_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(latency);
+    }
+
+    /// Median latency of the recent inclusions, if any were recorded
+    pub fn median(&self) -> Option<Duration> {
+        let mut samples: Vec<Duration> = self.lock().iter().copied().collect();
+        samples.sort();
+        samples.get(samples.len() / 2).copied()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<Duration>> {
+        // A panic while holding the lock can't leave the samples inconsistent
+        self.samples
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// How busy the network looks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Congestion {
+    /// Unconfirmed transactions in the node's mempool, if it could be queried
+    pub mempool_txs: Option<u64>,
+    /// Median time recent transactions took to be included, if any were sent
+    pub inclusion_latency: Option<Duration>,
+}
+
+impl Congestion {
+    /// Whether either signal crosses its threshold in `config`
+    pub fn is_congested(&self, config: &PriorityFeeConfig) -> bool {
+        self.mempool_txs
+            .is_some_and(|txs| txs >= config.congested_mempool_txs)
+            || self
+                .inclusion_latency
+                .is_some_and(|latency| latency >= config.congested_inclusion())
+    }
+}
+
+/// Number of transactions in a node's `num_unconfirmed_txs` response
+pub fn parse_unconfirmed_txs(response: &serde_json::Value) -> Option<u64> {
+    let n_txs = response.pointer("/result/n_txs")?;
+    n_txs
+        .as_str()
+        .and_then(|n| n.parse().ok())
+        .or_else(|| n_txs.as_u64())
+}
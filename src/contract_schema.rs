@@ -0,0 +1,137 @@
+//! Contract query response validation
+//!
+//! Query responses are parsed into the types of `mantra-dex-std`, which are
+//! the schema the SDK expects from each contract. When a contract is upgraded
+//! and renames or drops a field, a plain deserialize error says little more
+//! than "missing field". [`parse_response`] reports a [`SchemaMismatch`]
+//! instead, naming the contract, the query and the path of the offending
+//! field, and the client adds the contract's [`ContractVersion`] so the
+//! mismatch can be traced to an upgrade.
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::error::Category;
+
+use crate::error::Error;
+
+/// Raw storage key under which cw2 contracts record their name and version
+pub const CONTRACT_INFO_KEY: &[u8] = b"contract_info";
+
+/// Name and version a contract records under [`CONTRACT_INFO_KEY`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractVersion {
+    /// Crate name, such as `crates.io:pool-manager`
+    pub contract: String,
+    pub version: String,
+}
+
+impl fmt::Display for ContractVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.contract, self.version)
+    }
+}
+
+/// A contract answered a query with a response that doesn't fit the expected schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaMismatch {
+    /// Address of the contract
+    pub contract: String,
+    /// Name of the query, the top-level key of the query message
+    pub query: String,
+    /// Path of the missing or mismatched field, such as `pools[0].pool_info.pool_identifier`
+    pub field: Option<String>,
+    /// Version the contract reports, when it could be read
+    pub contract_version: Option<ContractVersion>,
+    /// What serde found wrong with the field
+    pub reason: String,
+}
+
+impl SchemaMismatch {
+    /// Record the contract's version in the mismatch
+    pub fn with_contract_version(mut self, version: Option<ContractVersion>) -> Self {
+        self.contract_version = version;
+        self
+    }
+}
+
+impl fmt::Display for SchemaMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "contract {}", self.contract)?;
+        if let Some(version) = &self.contract_version {
+            write!(f, " ({})", version)?;
+        }
+        write!(f, " answered `{}` with an unexpected response", self.query)?;
+        if let Some(field) = &self.field {
+            write!(f, " at field `{}`", field)?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+/// Name of a query, the top-level key of its JSON message
+pub fn query_name<Q: Serialize>(query_msg: &Q) -> String {
+    match serde_json::to_value(query_msg) {
+        Ok(serde_json::Value::Object(map)) => map.keys().next().cloned().unwrap_or_default(),
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+/// Parse the response of `contract` to `query`
+///
+/// # Errors
+///
+/// Returns [`Error::SchemaMismatch`] if the response is valid JSON that doesn't
+/// fit `R`, and [`Error::Serialization`] if it isn't valid JSON at all
+pub fn parse_response<R: DeserializeOwned>(
+    contract: &str,
+    query: &str,
+    data: &[u8],
+) -> Result<R, Error> {
+    let mut deserializer = serde_json::Deserializer::from_slice(data);
+    let error = match serde_path_to_error::deserialize(&mut deserializer) {
+        Ok(response) => {
+            deserializer.end()?;
+            return Ok(response);
+        }
+        Err(error) => error,
+    };
+    if error.inner().classify() != Category::Data {
+        return Err(error.into_inner().into());
+    }
+
+    let path = error.path().to_string();
+    let error = error.into_inner();
+    // Missing fields are reported on the object that should hold them
+    let missing = missing_field(&error.to_string());
+    let field = match (path.as_str(), missing) {
+        (".", None) => None,
+        (".", Some(name)) => Some(name),
+        (path, None) => Some(path.to_string()),
+        (path, Some(name)) => Some(format!("{}.{}", path, name)),
+    };
+    Err(Error::SchemaMismatch(Box::new(SchemaMismatch {
+        contract: contract.to_string(),
+        query: query.to_string(),
+        field,
+        contract_version: None,
+        reason: reason(&error),
+    })))
+}
+
+/// Field named by a missing field error
+fn missing_field(message: &str) -> Option<String> {
+    let rest = message.strip_prefix("missing field `")?;
+    rest.split_once('`').map(|(name, _)| name.to_string())
+}
+
+/// Message of a serde error without its position in the response
+fn reason(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    match message.rfind(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message,
+    }
+}
@@ -0,0 +1,220 @@
+//! Cross-denom amount conversion
+//!
+//! [`PriceBook`] expresses an amount of one denom in another at the spot
+//! prices of pool reserves (see [`pool_price`]). Denoms without a pool of their
+//! own are converted through intermediates, along the path with the fewest
+//! pools, up to [`DEFAULT_MAX_HOPS`] of them.
+//!
+//! [`USD`] stands for the US dollar: an amount is converted into the first
+//! reachable of the configured USD stablecoins, taken at par, and expressed in
+//! millionths of a dollar like the stablecoins' own base units. Portfolio
+//! values, trade value limits and alert thresholds can then be written in
+//! dollars without naming a stablecoin.
+
+use std::collections::{HashSet, VecDeque};
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::earn::pool_price;
+
+/// Pseudo-denom for amounts in US dollars, in millionths of a dollar
+pub const USD: &str = "usd";
+
+/// Decimals of [`USD`] amounts
+pub const USD_DECIMALS: u8 = 6;
+
+/// Most pools a conversion goes through by default
+pub const DEFAULT_MAX_HOPS: usize = 3;
+
+/// One pool a conversion goes through
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversionHop {
+    pub pool_id: String,
+    pub from_denom: String,
+    pub to_denom: String,
+    /// Price of one base unit of `from_denom` in base units of `to_denom`
+    pub price: Decimal,
+}
+
+/// An amount expressed in another denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conversion {
+    pub from: Coin,
+    pub to: Coin,
+    /// Pools the conversion goes through, in order; empty for the same denom
+    pub hops: Vec<ConversionHop>,
+}
+
+impl Conversion {
+    /// Price of one base unit of the source denom in base units of the target
+    pub fn price(&self) -> Decimal {
+        if self.from.amount.is_zero() {
+            return Decimal::zero();
+        }
+        Decimal::from_ratio(self.to.amount, self.from.amount)
+    }
+
+    /// Denoms the conversion passes through, source and target included
+    pub fn path(&self) -> Vec<&str> {
+        let mut path = vec![self.from.denom.as_str()];
+        path.extend(self.hops.iter().map(|hop| hop.to_denom.as_str()));
+        if self.to.denom == USD {
+            path.push(USD);
+        }
+        path
+    }
+}
+
+/// Spot prices across a set of pools
+#[derive(Debug, Clone, Copy)]
+pub struct PriceBook<'a> {
+    pools: &'a [PoolInfoResponse],
+    usd_denoms: &'a [String],
+    max_hops: usize,
+}
+
+impl<'a> PriceBook<'a> {
+    pub fn new(pools: &'a [PoolInfoResponse]) -> Self {
+        Self {
+            pools,
+            usd_denoms: &[],
+            max_hops: DEFAULT_MAX_HOPS,
+        }
+    }
+
+    /// Set the stablecoins [`USD`] is converted through, in order of preference
+    pub fn with_usd_denoms(mut self, usd_denoms: &'a [String]) -> Self {
+        self.usd_denoms = usd_denoms;
+        self
+    }
+
+    /// Set the most pools a conversion may go through
+    pub fn with_max_hops(mut self, max_hops: usize) -> Self {
+        self.max_hops = max_hops;
+        self
+    }
+
+    /// Price of one base unit of `denom` in base units of `quote`
+    ///
+    /// Either may be [`USD`]. `None` if no path of pools links them.
+    pub fn price(&self, denom: &str, quote: &str) -> Option<Decimal> {
+        if denom == quote {
+            return Some(Decimal::one());
+        }
+        if denom == USD {
+            return self
+                .price(quote, USD)
+                .filter(|price| !price.is_zero())
+                .and_then(|price| Decimal::one().checked_div(price).ok());
+        }
+        if quote == USD {
+            let (hops, stable) = self.usd_path(denom)?;
+            return path_price(&hops)?
+                .checked_mul(self.usd_per_unit(stable)?)
+                .ok();
+        }
+        path_price(&self.path(denom, quote)?)
+    }
+
+    /// Express `amount` of `from` in `to`
+    ///
+    /// Either may be [`USD`]. `None` if no path of pools links them.
+    pub fn convert(&self, amount: Uint128, from: &str, to: &str) -> Option<Conversion> {
+        let hops = if from == to || from == USD {
+            Vec::new()
+        } else if to == USD {
+            self.usd_path(from)?.0
+        } else {
+            self.path(from, to)?
+        };
+        let price = self.price(from, to)?;
+
+        Some(Conversion {
+            from: Coin {
+                denom: from.to_string(),
+                amount,
+            },
+            to: Coin {
+                denom: to.to_string(),
+                amount: amount.mul_floor(price),
+            },
+            hops,
+        })
+    }
+
+    /// Shortest path of pools from `from` to `to`, at most `max_hops` long
+    fn path(&self, from: &str, to: &str) -> Option<Vec<ConversionHop>> {
+        if from == to {
+            return Some(Vec::new());
+        }
+        let mut visited: HashSet<&str> = HashSet::from([from]);
+        let mut queue: VecDeque<(&str, Vec<ConversionHop>)> = VecDeque::from([(from, Vec::new())]);
+
+        while let Some((denom, hops)) = queue.pop_front() {
+            if hops.len() >= self.max_hops {
+                continue;
+            }
+            for pool in self.pools {
+                if !pool.pool_info.asset_denoms.iter().any(|held| held == denom) {
+                    continue;
+                }
+                for next in &pool.pool_info.asset_denoms {
+                    if visited.contains(next.as_str()) {
+                        continue;
+                    }
+                    let Some(price) =
+                        pool_price(pool, denom, next).filter(|price| !price.is_zero())
+                    else {
+                        continue;
+                    };
+                    let mut path = hops.clone();
+                    path.push(ConversionHop {
+                        pool_id: pool.pool_info.pool_identifier.clone(),
+                        from_denom: denom.to_string(),
+                        to_denom: next.clone(),
+                        price,
+                    });
+                    if next == to {
+                        return Some(path);
+                    }
+                    visited.insert(next);
+                    queue.push_back((next, path));
+                }
+            }
+        }
+        None
+    }
+
+    /// Shortest path from `denom` to one of the USD stablecoins, and that stablecoin
+    ///
+    /// Paths of equal length are preferred in the order of the stablecoins.
+    fn usd_path(&self, denom: &str) -> Option<(Vec<ConversionHop>, &'a str)> {
+        self.usd_denoms
+            .iter()
+            .filter_map(|stable| Some((self.path(denom, stable)?, stable.as_str())))
+            .min_by_key(|(hops, _)| hops.len())
+    }
+
+    /// Base units of [`USD`] per base unit of the stablecoin `stable`
+    fn usd_per_unit(&self, stable: &str) -> Option<Decimal> {
+        let decimals = self.pools.iter().find_map(|pool| {
+            let info = &pool.pool_info;
+            let index = info.asset_denoms.iter().position(|denom| denom == stable)?;
+            info.asset_decimals.get(index).copied()
+        })?;
+        Decimal::checked_from_ratio(
+            10u128.checked_pow(USD_DECIMALS.into())?,
+            10u128.checked_pow(decimals.into())?,
+        )
+        .ok()
+    }
+}
+
+/// Combined price of a path of hops
+fn path_price(hops: &[ConversionHop]) -> Option<Decimal> {
+    hops.iter().try_fold(Decimal::one(), |price, hop| {
+        price.checked_mul(hop.price).ok()
+    })
+}
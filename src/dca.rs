@@ -0,0 +1,557 @@
+//! Dollar-cost averaging: the same swap repeated at a fixed interval
+//!
+//! A [`DcaPlan`] swaps a fixed amount, e.g. 100 USDC for OM every 6 hours,
+//! until it has swapped a number of times or a time has passed. A [`DcaBook`]
+//! keeps the plans in a JSON file with when each next executes and what every
+//! execution did, so a runner picks up where it left off after a restart, and
+//! [`run_due`] sends the swaps that are due. Executions missed while no runner
+//! was running are skipped rather than caught up.
+//!
+//! As with [`Schedule`](crate::Schedule), every change re-reads the file first,
+//! so a plan paused from one process is not executed by a runner in another.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, Utc};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::client::MantraDexClient;
+use crate::error::{Error, ErrorClass};
+use crate::schema::{Format, Schema};
+use crate::storage::{Document, SharedStorage};
+
+/// Shortest interval between two executions of a plan
+pub const MIN_DCA_INTERVAL_SECS: u64 = 60;
+
+/// Parse an interval such as `30m`, `6h`, `1d` or `2w` into seconds
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if the interval is malformed or shorter than
+/// [`MIN_DCA_INTERVAL_SECS`]
+pub fn parse_interval(s: &str) -> Result<u64, Error> {
+    let invalid = || {
+        Error::Config(format!(
+            "Invalid interval '{}': expected a number of minutes, hours, days or weeks, e.g. 6h",
+            s
+        ))
+    };
+    let s = s.trim();
+    let unit = s.chars().last().ok_or_else(invalid)?;
+    let count: u64 = s[..s.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    let unit_secs = match unit {
+        'm' => 60,
+        'h' => 3_600,
+        'd' => 86_400,
+        'w' => 604_800,
+        _ => return Err(invalid()),
+    };
+    let secs = count.checked_mul(unit_secs).ok_or_else(invalid)?;
+    if secs < MIN_DCA_INTERVAL_SECS {
+        return Err(Error::Config(format!(
+            "Interval '{}' is shorter than {} seconds",
+            s, MIN_DCA_INTERVAL_SECS
+        )));
+    }
+    Ok(secs)
+}
+
+/// Interval in the shortest unit that divides it, e.g. `6h`
+fn interval_text(secs: u64) -> String {
+    [('w', 604_800), ('d', 86_400), ('h', 3_600), ('m', 60)]
+        .into_iter()
+        .find(|(_, unit_secs)| secs.is_multiple_of(*unit_secs))
+        .map(|(unit, unit_secs)| format!("{}{}", secs / unit_secs, unit))
+        .unwrap_or_else(|| format!("{}s", secs))
+}
+
+/// When a plan stops, whichever comes first; it runs until cancelled if neither is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct DcaEnd {
+    /// Successful swaps after which the plan is complete
+    pub max_executions: Option<u32>,
+    /// Time from which the plan no longer executes
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Where a plan stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DcaStatus {
+    /// Executing at its interval
+    Active,
+    /// Skipping executions until resumed
+    Paused,
+    /// Reached one of its end conditions
+    Completed,
+    /// Stopped for good by the user
+    Cancelled,
+}
+
+impl fmt::Display for DcaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DcaStatus::Active => write!(f, "active"),
+            DcaStatus::Paused => write!(f, "paused"),
+            DcaStatus::Completed => write!(f, "completed"),
+            DcaStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// What one execution of a plan did
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum DcaOutcome {
+    /// The swap was included in a block
+    Swapped { tx_hash: String, height: u64 },
+    /// The swap was rejected; the plan carries on at its next execution
+    Failed { reason: String },
+    /// The swap was broadcast but not seen in a block; look `tx_hash` up, as it
+    /// may still have swapped. The plan carries on at its next execution
+    Unconfirmed { tx_hash: String, reason: String },
+}
+
+/// One execution of a plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DcaExecution {
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub outcome: DcaOutcome,
+}
+
+/// A recurring swap kept in a [`DcaBook`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DcaPlan {
+    /// Identifier, unique within its book
+    pub id: u64,
+    /// Saved wallet that signs the swaps
+    pub wallet: String,
+    pub pool_id: String,
+    /// Amount offered by each swap
+    pub offer_asset: Coin,
+    pub ask_asset_denom: String,
+    /// Seconds between two executions
+    pub interval_secs: u64,
+    /// Maximum slippage tolerance, the wallet default if not set
+    pub max_slippage: Option<Decimal>,
+    pub end: DcaEnd,
+    pub created_at: DateTime<Utc>,
+    /// When the plan next executes, while it is active
+    pub next_at: DateTime<Utc>,
+    pub status: DcaStatus,
+    /// Every execution, oldest first
+    pub executions: Vec<DcaExecution>,
+}
+
+impl DcaPlan {
+    pub fn is_active(&self) -> bool {
+        self.status == DcaStatus::Active
+    }
+
+    /// Whether the plan should execute at `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.is_active() && now >= self.next_at && !self.has_ended(now)
+    }
+
+    /// Number of swaps that went through
+    pub fn swaps(&self) -> u32 {
+        self.executions
+            .iter()
+            .filter(|execution| matches!(execution.outcome, DcaOutcome::Swapped { .. }))
+            .count() as u32
+    }
+
+    /// Amount of the offer denom swapped so far
+    pub fn spent(&self) -> Uint128 {
+        self.offer_asset.amount * Uint128::from(self.swaps())
+    }
+
+    /// Whether an end condition is reached for an execution at `at`
+    pub fn has_ended(&self, at: DateTime<Utc>) -> bool {
+        self.end
+            .max_executions
+            .is_some_and(|max_executions| self.swaps() >= max_executions)
+            || self.end.until.is_some_and(|until| at >= until)
+    }
+
+    /// The next `count` execution times from `now` on, within the end conditions
+    ///
+    /// Empty unless the plan is active.
+    pub fn upcoming(&self, count: usize, now: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        if !self.is_active() {
+            return Vec::new();
+        }
+        let remaining = self
+            .end
+            .max_executions
+            .map_or(count, |max_executions| {
+                max_executions.saturating_sub(self.swaps()) as usize
+            })
+            .min(count);
+        let first = self.next_at.max(now);
+        (0..remaining)
+            .map(|n| first + self.interval() * n as i32)
+            .take_while(|at| self.end.until.is_none_or(|until| *at < until))
+            .collect()
+    }
+
+    /// Record an execution at `at`, scheduling the next one after `at`
+    ///
+    /// Executions missed since `next_at` are skipped. The plan completes once an
+    /// end condition is reached.
+    pub fn record(&mut self, outcome: DcaOutcome, at: DateTime<Utc>) {
+        self.executions.push(DcaExecution { at, outcome });
+        let interval = self.interval();
+        while self.next_at <= at {
+            self.next_at += interval;
+        }
+        if self.has_ended(self.next_at) {
+            self.status = DcaStatus::Completed;
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::seconds(self.interval_secs as i64)
+    }
+}
+
+impl fmt::Display for DcaPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Swap {} for {} in pool {} every {}",
+            self.offer_asset,
+            self.ask_asset_denom,
+            self.pool_id,
+            interval_text(self.interval_secs)
+        )?;
+        if let Some(max_executions) = self.end.max_executions {
+            write!(f, ", {} times", max_executions)?;
+        }
+        if let Some(until) = self.end.until {
+            write!(f, ", until {}", until.to_rfc3339())?;
+        }
+        Ok(())
+    }
+}
+
+/// A plan to add to a [`DcaBook`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDcaPlan {
+    pub pool_id: String,
+    pub offer_asset: Coin,
+    pub ask_asset_denom: String,
+    pub interval_secs: u64,
+    pub max_slippage: Option<Decimal>,
+    pub end: DcaEnd,
+    /// First execution, right away if not set
+    pub start_at: Option<DateTime<Utc>>,
+}
+
+/// Format of the DCA plans file
+const DCA_SCHEMA: Schema = Schema::new("dca plans", Format::Json, &[]);
+
+/// Contents of the DCA plans file
+#[derive(Serialize, Deserialize)]
+struct DcaFile<T> {
+    plans: T,
+}
+
+/// Recurring swaps, persisted when opened from a file or a storage
+#[derive(Debug, Default)]
+pub struct DcaBook {
+    plans: Vec<DcaPlan>,
+    document: Option<Document>,
+}
+
+impl DcaBook {
+    /// Create an empty, in-memory book
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the plans stored at `path`, saving back to it on every change
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the plans kept in `storage`, saving back to it on every change
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut book = Self {
+            plans: Vec::new(),
+            document: Some(document),
+        };
+        book.reload()?;
+        Ok(book)
+    }
+
+    /// Key of the plans in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "dca_plans.json";
+
+    /// Get the default DCA plans file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("dca_plans.json");
+        path
+    }
+
+    /// Re-read the plans, picking up changes made by other processes
+    pub fn reload(&mut self) -> Result<(), Error> {
+        let Some(document) = &self.document else {
+            return Ok(());
+        };
+        if let Some(stored) = document.read::<DcaFile<Vec<DcaPlan>>>(&DCA_SCHEMA)? {
+            self.plans = stored.plans;
+        }
+        Ok(())
+    }
+
+    /// Every plan, oldest first
+    pub fn plans(&self) -> &[DcaPlan] {
+        &self.plans
+    }
+
+    pub fn get(&self, id: u64) -> Option<&DcaPlan> {
+        self.plans.iter().find(|plan| plan.id == id)
+    }
+
+    /// Add `plan`, swapping with `wallet` from its start on
+    ///
+    /// # Returns
+    ///
+    /// Identifier of the new plan
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the interval is shorter than
+    /// [`MIN_DCA_INTERVAL_SECS`] or the amount is zero
+    pub fn add(
+        &mut self,
+        wallet: &str,
+        plan: NewDcaPlan,
+        now: DateTime<Utc>,
+    ) -> Result<u64, Error> {
+        if plan.interval_secs < MIN_DCA_INTERVAL_SECS {
+            return Err(Error::Config(format!(
+                "DCA interval must be at least {} seconds",
+                MIN_DCA_INTERVAL_SECS
+            )));
+        }
+        if plan.offer_asset.amount.is_zero() {
+            return Err(Error::Config("DCA amount must be positive".to_string()));
+        }
+
+        self.reload()?;
+        let id = self.plans.iter().map(|plan| plan.id).max().unwrap_or(0) + 1;
+        self.plans.push(DcaPlan {
+            id,
+            wallet: wallet.to_string(),
+            pool_id: plan.pool_id,
+            offer_asset: plan.offer_asset,
+            ask_asset_denom: plan.ask_asset_denom,
+            interval_secs: plan.interval_secs,
+            max_slippage: plan.max_slippage,
+            end: plan.end,
+            created_at: now,
+            next_at: plan.start_at.unwrap_or(now),
+            status: DcaStatus::Active,
+            executions: Vec::new(),
+        });
+        self.save()?;
+        Ok(id)
+    }
+
+    /// Stop executing an active plan until it is resumed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such plan or it isn't active
+    pub fn pause(&mut self, id: u64) -> Result<(), Error> {
+        self.update(id, DcaStatus::Active, |plan| {
+            plan.status = DcaStatus::Paused
+        })
+    }
+
+    /// Resume a paused plan, executing right away if it missed an execution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such plan or it isn't paused
+    pub fn resume(&mut self, id: u64, now: DateTime<Utc>) -> Result<(), Error> {
+        self.update(id, DcaStatus::Paused, |plan| {
+            plan.status = DcaStatus::Active;
+            plan.next_at = plan.next_at.max(now);
+        })
+    }
+
+    /// Stop a plan for good
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such plan or it already completed or was cancelled
+    pub fn cancel(&mut self, id: u64) -> Result<(), Error> {
+        self.reload()?;
+        let plan = self
+            .plans
+            .iter_mut()
+            .find(|plan| plan.id == id)
+            .ok_or_else(|| Error::Other(format!("No DCA plan {}", id)))?;
+        if !matches!(plan.status, DcaStatus::Active | DcaStatus::Paused) {
+            return Err(Error::Other(format!(
+                "DCA plan {} is already {}",
+                id, plan.status
+            )));
+        }
+        plan.status = DcaStatus::Cancelled;
+        self.save()
+    }
+
+    /// Record an execution of a plan at `at`
+    pub fn record(&mut self, id: u64, outcome: DcaOutcome, at: DateTime<Utc>) -> Result<(), Error> {
+        self.reload()?;
+        if let Some(plan) = self.plans.iter_mut().find(|plan| plan.id == id) {
+            plan.record(outcome, at);
+        }
+        self.save()
+    }
+
+    /// Mark the active plans whose end condition is reached at `now` as completed
+    ///
+    /// # Returns
+    ///
+    /// The plans that completed, with their new status
+    pub fn complete_ended(&mut self, now: DateTime<Utc>) -> Result<Vec<DcaPlan>, Error> {
+        self.reload()?;
+        let mut completed = Vec::new();
+        for plan in &mut self.plans {
+            if plan.is_active() && plan.has_ended(plan.next_at.max(now)) {
+                plan.status = DcaStatus::Completed;
+                completed.push(plan.clone());
+            }
+        }
+        if !completed.is_empty() {
+            self.save()?;
+        }
+        Ok(completed)
+    }
+
+    /// Active plans of `wallet` that are due at `now`
+    pub fn due(&self, wallet: &str, now: DateTime<Utc>) -> Vec<DcaPlan> {
+        self.plans
+            .iter()
+            .filter(|plan| plan.wallet == wallet && plan.is_due(now))
+            .cloned()
+            .collect()
+    }
+
+    /// Apply `change` to a plan whose status is `expected`, and save
+    fn update<F>(&mut self, id: u64, expected: DcaStatus, change: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut DcaPlan),
+    {
+        self.reload()?;
+        let plan = self
+            .plans
+            .iter_mut()
+            .find(|plan| plan.id == id)
+            .ok_or_else(|| Error::Other(format!("No DCA plan {}", id)))?;
+        if plan.status != expected {
+            return Err(Error::Other(format!(
+                "DCA plan {} is {}, not {}",
+                id, plan.status, expected
+            )));
+        }
+        change(plan);
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        match &self.document {
+            Some(document) => document.write(&DCA_SCHEMA, &DcaFile { plans: &self.plans }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Execute the plans of `wallet` that are due, signing with the client's wallet
+///
+/// Plans whose end condition was reached are completed first. A swap rejected
+/// by the chain is recorded as a failed execution, and one broadcast but not
+/// seen in a block as an unconfirmed execution with its transaction hash, so it
+/// isn't sent twice; either way the plan carries on at its next execution. A
+/// swap that couldn't reach the node before broadcasting ends the pass and
+/// stays due, so the next pass tries again.
+///
+/// # Returns
+///
+/// The plans executed or completed in this pass, with their new state
+pub async fn run_due(
+    client: &MantraDexClient,
+    book: &mut DcaBook,
+    wallet: &str,
+) -> Result<Vec<DcaPlan>, Error> {
+    let mut done: Vec<DcaPlan> = book
+        .complete_ended(Utc::now())?
+        .into_iter()
+        .filter(|plan| plan.wallet == wallet)
+        .collect();
+
+    for plan in book.due(wallet, Utc::now()) {
+        // It may have been paused or cancelled since the pass started
+        book.reload()?;
+        if !book
+            .get(plan.id)
+            .is_some_and(|plan| plan.is_due(Utc::now()))
+        {
+            continue;
+        }
+
+        let outcome = match client
+            .swap(
+                &plan.pool_id,
+                plan.offer_asset.clone(),
+                &plan.ask_asset_denom,
+                plan.max_slippage,
+            )
+            .await
+        {
+            Ok(response) => DcaOutcome::Swapped {
+                tx_hash: response.txhash,
+                height: response.height as u64,
+            },
+            Err(Error::Unconfirmed { tx_hash, reason }) => {
+                DcaOutcome::Unconfirmed { tx_hash, reason }
+            }
+            Err(e) if e.class() == ErrorClass::Network => return Err(e),
+            Err(e) => DcaOutcome::Failed {
+                reason: e.to_string(),
+            },
+        };
+        book.record(plan.id, outcome, Utc::now())?;
+        if let Some(plan) = book.get(plan.id) {
+            done.push(plan.clone());
+        }
+    }
+    Ok(done)
+}
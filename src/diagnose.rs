@@ -0,0 +1,312 @@
+//! Explanations of failed transactions
+//!
+//! A failed transaction only carries the error code and codespace of the module
+//! that rejected it and the log of the failure. [`Diagnosis`] maps the common
+//! failures of Cosmos SDK and DEX contract transactions to a [`FailureCause`]
+//! with a plain explanation, fixes to try and the command to retry with.
+//! [`MantraDexClient::diagnose_transaction`] diagnoses a transaction by hash;
+//! [`Diagnosis::from_error`] diagnoses an [`Error`] without a chain query.
+//!
+//! [`MantraDexClient::diagnose_transaction`]: crate::client::MantraDexClient::diagnose_transaction
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Codespace of the Cosmos SDK's own errors
+const SDK_CODESPACE: &str = "sdk";
+
+/// Codespace of errors returned by CosmWasm contracts
+const WASM_CODESPACE: &str = "wasm";
+
+/// Why a transaction failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureCause {
+    /// The swap's price moved past its slippage tolerance
+    SlippageExceeded,
+    /// The transaction ran out of the gas it was given
+    OutOfGas,
+    /// The fee is below the minimum gas price of the node
+    InsufficientFee,
+    /// The wallet can't pay the amounts sent or the fee
+    InsufficientFunds,
+    /// The pool is disabled for the operation or doesn't exist
+    PoolUnavailable,
+    /// The transaction was signed with an outdated account sequence
+    SequenceMismatch,
+    /// The signer may not perform the operation
+    Unauthorized,
+    /// The node's mempool has no room for the transaction
+    MempoolFull,
+    /// A contract rejected the transaction for another reason
+    ContractError,
+    Unknown,
+}
+
+impl FailureCause {
+    /// Short name of the cause
+    pub fn title(&self) -> &'static str {
+        match self {
+            FailureCause::SlippageExceeded => "Slippage tolerance exceeded",
+            FailureCause::OutOfGas => "Out of gas",
+            FailureCause::InsufficientFee => "Fee too low",
+            FailureCause::InsufficientFunds => "Insufficient funds",
+            FailureCause::PoolUnavailable => "Pool unavailable",
+            FailureCause::SequenceMismatch => "Account sequence mismatch",
+            FailureCause::Unauthorized => "Unauthorized",
+            FailureCause::MempoolFull => "Mempool full",
+            FailureCause::ContractError => "Contract error",
+            FailureCause::Unknown => "Unknown failure",
+        }
+    }
+
+    /// What the failure means
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            FailureCause::SlippageExceeded => {
+                "The pool price moved further than the swap's slippage tolerance allows \
+                 between the simulation and the execution, so the swap was refused to \
+                 protect you from a worse rate."
+            }
+            FailureCause::OutOfGas => {
+                "The transaction needed more gas than its gas limit. Execution stopped and \
+                 the fee for the gas used was still charged."
+            }
+            FailureCause::InsufficientFee => {
+                "The fee offered is below the minimum gas price the node accepts."
+            }
+            FailureCause::InsufficientFunds => {
+                "The wallet doesn't hold enough of a token to cover the amounts sent and the \
+                 transaction fee."
+            }
+            FailureCause::PoolUnavailable => {
+                "The pool doesn't exist or its owner disabled the operation, e.g. swaps or \
+                 deposits, on it."
+            }
+            FailureCause::SequenceMismatch => {
+                "Another transaction from the same wallet was included first, so this one was \
+                 signed with an outdated account sequence."
+            }
+            FailureCause::Unauthorized => {
+                "The signing wallet isn't allowed to perform this operation, e.g. a pool or \
+                 farm action reserved to its owner."
+            }
+            FailureCause::MempoolFull => {
+                "The node is congested and couldn't accept the transaction into its mempool."
+            }
+            FailureCause::ContractError => {
+                "A DEX contract rejected the transaction; its log has the contract's reason."
+            }
+            FailureCause::Unknown => "The failure doesn't match a known cause; see the raw log.",
+        }
+    }
+
+    /// Fixes to try, most likely first
+    pub fn suggestions(&self) -> &'static [&'static str] {
+        match self {
+            FailureCause::SlippageExceeded => &[
+                "Raise the slippage tolerance, or let the swap retry with a higher one",
+                "Swap a smaller amount to reduce the price impact",
+                "Wait for the market to settle and try again",
+            ],
+            FailureCause::OutOfGas => &[
+                "Raise gas_adjustment in the network configuration",
+                "Split a large batch into several transactions",
+            ],
+            FailureCause::InsufficientFee => &[
+                "Raise gas_price in the network configuration to the node's minimum gas price",
+            ],
+            FailureCause::InsufficientFunds => &[
+                "Fund the wallet with the missing token",
+                "Keep some uom in the wallet to pay for gas",
+                "Lower the amount of the operation",
+            ],
+            FailureCause::PoolUnavailable => &[
+                "Check the pool's status and enabled operations",
+                "Use another pool for the same pair",
+            ],
+            FailureCause::SequenceMismatch => &[
+                "Retry the transaction; the sequence is queried again when it is signed",
+                "Avoid sending from the same wallet in several places at once",
+            ],
+            FailureCause::Unauthorized => &[
+                "Sign with the wallet that owns the pool or farm",
+                "Check the operation's required permissions",
+            ],
+            FailureCause::MempoolFull => &[
+                "Retry in a few seconds",
+                "Retry with --priority high to pay a higher gas price",
+                "Use another RPC endpoint",
+            ],
+            FailureCause::ContractError => &[
+                "Read the contract's message in the raw log",
+                "Simulate the operation before sending it again",
+            ],
+            FailureCause::Unknown => &["Inspect the raw log or the transaction in an explorer"],
+        }
+    }
+
+    /// Command to check or retry with, if one addresses the cause
+    pub fn retry_command(&self) -> Option<&'static str> {
+        match self {
+            FailureCause::SlippageExceeded => Some(
+                "mantra-dex swap <POOL_ID> --offer-denom <DENOM> --amount <AMOUNT> \
+                 --ask-denom <DENOM> --wallet <WALLET> --auto-retry-slippage",
+            ),
+            FailureCause::PoolUnavailable => Some("mantra-dex pool info <POOL_ID>"),
+            FailureCause::InsufficientFunds => Some("mantra-dex wallet list"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FailureCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.title())
+    }
+}
+
+/// A failed transaction's cause and how to address it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnosis {
+    /// Hash of the transaction, if it was diagnosed from the chain
+    #[serde(default)]
+    pub tx_hash: Option<String>,
+    /// Module that rejected the transaction, e.g. `sdk` or `wasm`
+    #[serde(default)]
+    pub codespace: Option<String>,
+    /// Error code within the codespace
+    #[serde(default)]
+    pub code: Option<u32>,
+    /// Raw log or error message of the failure
+    pub log: String,
+    #[serde(default)]
+    pub gas_wanted: Option<i64>,
+    #[serde(default)]
+    pub gas_used: Option<i64>,
+    pub cause: FailureCause,
+}
+
+impl Diagnosis {
+    /// Diagnose a transaction result's codespace, error code and log
+    pub fn new(codespace: &str, code: u32, log: impl Into<String>) -> Self {
+        let log = log.into();
+        Self {
+            tx_hash: None,
+            cause: classify(Some(codespace), Some(code), &log),
+            codespace: Some(codespace.to_string()),
+            code: Some(code),
+            log,
+            gas_wanted: None,
+            gas_used: None,
+        }
+    }
+
+    /// Diagnose an error message, such as one shown by the TUI
+    ///
+    /// The codespace and code are read from the message when it contains a
+    /// broadcast response.
+    pub fn from_message(message: &str) -> Self {
+        let codespace = field(message, "codespace: \"", '"');
+        let code = field(message, "code: Err(", ')').and_then(|code| code.parse().ok());
+        Self {
+            tx_hash: None,
+            cause: classify(codespace.as_deref(), code, message),
+            codespace,
+            code,
+            log: message.to_string(),
+            gas_wanted: None,
+            gas_used: None,
+        }
+    }
+
+    /// Diagnose an error returned by the client
+    pub fn from_error(error: &Error) -> Self {
+        match error {
+            Error::TxBroadcast(response) => Self {
+                tx_hash: Some(response.hash.to_string()),
+                ..Self::new(&response.codespace, response.code.value(), &response.log)
+            },
+            error => Self::from_message(&error.to_string()),
+        }
+    }
+
+    /// Record the transaction's hash
+    pub fn with_tx_hash(mut self, tx_hash: impl Into<String>) -> Self {
+        self.tx_hash = Some(tx_hash.into());
+        self
+    }
+
+    /// Record the gas the transaction was given and used
+    pub fn with_gas(mut self, gas_wanted: i64, gas_used: i64) -> Self {
+        self.gas_wanted = Some(gas_wanted);
+        self.gas_used = Some(gas_used);
+        self
+    }
+
+    pub fn explanation(&self) -> &'static str {
+        self.cause.explanation()
+    }
+
+    pub fn suggestions(&self) -> &'static [&'static str] {
+        self.cause.suggestions()
+    }
+
+    pub fn retry_command(&self) -> Option<&'static str> {
+        self.cause.retry_command()
+    }
+}
+
+/// The text of `message` between `prefix` and the next `end`
+fn field(message: &str, prefix: &str, end: char) -> Option<String> {
+    let start = message.find(prefix)? + prefix.len();
+    let value = &message[start..];
+    Some(value[..value.find(end)?].to_string()).filter(|value| !value.is_empty())
+}
+
+/// Cause of a failure, from the SDK error code where it is unambiguous and the
+/// log otherwise
+fn classify(codespace: Option<&str>, code: Option<u32>, log: &str) -> FailureCause {
+    if codespace == Some(SDK_CODESPACE) {
+        match code {
+            Some(4) => return FailureCause::Unauthorized,
+            Some(5) => return FailureCause::InsufficientFunds,
+            Some(11) => return FailureCause::OutOfGas,
+            Some(13) => return FailureCause::InsufficientFee,
+            Some(20) => return FailureCause::MempoolFull,
+            Some(32) => return FailureCause::SequenceMismatch,
+            _ => {}
+        }
+    }
+
+    let log = log.to_lowercase();
+    let mentions = |terms: &[&str]| terms.iter().any(|term| log.contains(term));
+    if (log.contains("slippage") && mentions(&["exceed", "assertion"]))
+        || mentions(&["max spread", "spread limit"])
+    {
+        FailureCause::SlippageExceeded
+    } else if log.contains("pool")
+        && mentions(&["disabled", "does not exist", "not found", "unexisting"])
+    {
+        FailureCause::PoolUnavailable
+    } else if log.contains("out of gas") {
+        FailureCause::OutOfGas
+    } else if mentions(&["account sequence mismatch", "incorrect account sequence"]) {
+        FailureCause::SequenceMismatch
+    } else if log.contains("insufficient fee") {
+        FailureCause::InsufficientFee
+    } else if log.contains("insufficient funds") {
+        FailureCause::InsufficientFunds
+    } else if mentions(&["unauthorized", "not the owner"]) {
+        FailureCause::Unauthorized
+    } else if log.contains("mempool is full") {
+        FailureCause::MempoolFull
+    } else if codespace == Some(WASM_CODESPACE) || log.contains("execute wasm contract failed") {
+        FailureCause::ContractError
+    } else {
+        FailureCause::Unknown
+    }
+}
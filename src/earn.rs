@@ -0,0 +1,220 @@
+//! Yield ranking for assets a wallet holds
+//!
+//! Providing liquidity earns a share of the pool's swap fees, plus the farm
+//! incentives paid to staked LP tokens. Both are estimated from recent activity:
+//! fees from the swaps seen over a lookback window, incentives from the emission
+//! rates of the farms active in the current epoch. Everything is valued in the
+//! held asset using spot prices read from pool reserves, so no price feed is
+//! needed, and annualized into an APR.
+//!
+//! The estimates assume the recent past continues; volume and incentives change
+//! from day to day, so treat the ranking as a starting point.
+
+use cosmwasm_std::{Coin, Decimal, Uint128, Uint256};
+use mantra_dex_std::farm_manager::Farm;
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use serde::{Deserialize, Serialize};
+
+use crate::analytics::SwapFill;
+pub use crate::convert::PriceBook;
+
+/// Seconds in a (non-leap) year, used to annualize returns
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Default swap history window used to estimate fee income, about a day of blocks
+pub const DEFAULT_LOOKBACK_BLOCKS: u64 = 14_400;
+
+/// A pool to provide a held asset to, with its estimated yield
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EarnOpportunity {
+    /// Held denom being deposited
+    pub asset: String,
+    /// Pool to provide liquidity to
+    pub pool_id: String,
+    /// Amount of the held denom deposited
+    pub deposit: Uint128,
+    /// Other assets that must be deposited alongside, at the current pool ratio
+    pub pairing: Vec<Coin>,
+    /// Pool liquidity, in base units of the held denom
+    pub tvl: Uint128,
+    /// Estimated yearly swap fee income as a share of liquidity
+    pub fee_apr: Decimal,
+    /// Estimated yearly farm incentives as a share of staked liquidity
+    pub incentive_apr: Decimal,
+    /// Active farms paying the incentives
+    pub farms: Vec<String>,
+    /// Projected yearly return on the whole position, in base units of the held denom
+    pub projected_return: Uint128,
+}
+
+impl EarnOpportunity {
+    /// Fee and incentive APR combined
+    pub fn total_apr(&self) -> Decimal {
+        self.fee_apr.saturating_add(self.incentive_apr)
+    }
+}
+
+/// Sort opportunities by total APR, best first
+pub fn rank(opportunities: &mut [EarnOpportunity]) {
+    opportunities.sort_by_key(|opportunity| std::cmp::Reverse(opportunity.total_apr()));
+}
+
+/// Spot price of one base unit of `denom` in base units of `quote`, within one pool
+///
+/// Constant product pools price by their reserve ratio; stable swap pools at par,
+/// adjusted for decimals. `None` if the pool doesn't hold both or is empty.
+pub fn pool_price(pool: &PoolInfoResponse, denom: &str, quote: &str) -> Option<Decimal> {
+    if denom == quote {
+        return Some(Decimal::one());
+    }
+    let info = &pool.pool_info;
+    let index = |wanted: &str| info.asset_denoms.iter().position(|denom| denom == wanted);
+    let (denom_index, quote_index) = (index(denom)?, index(quote)?);
+
+    match info.pool_type {
+        PoolType::ConstantProduct => {
+            let reserve = |denom: &str| {
+                info.assets
+                    .iter()
+                    .find(|asset| asset.denom == denom)
+                    .map(|asset| asset.amount)
+            };
+            Decimal::checked_from_ratio(reserve(quote)?, reserve(denom)?).ok()
+        }
+        PoolType::StableSwap { .. } => {
+            let unit = |index: usize| {
+                info.asset_decimals
+                    .get(index)
+                    .and_then(|decimals| 10u128.checked_pow((*decimals).into()))
+            };
+            Decimal::checked_from_ratio(unit(quote_index)?, unit(denom_index)?).ok()
+        }
+    }
+}
+
+/// Market data the yield estimates are computed from
+#[derive(Debug, Clone, Copy)]
+pub struct YieldContext<'a> {
+    /// Prices used to value fees, reserves and incentives
+    pub prices: PriceBook<'a>,
+    /// Farms of the farm manager; only those active in `current_epoch` count
+    pub farms: &'a [Farm],
+    pub current_epoch: u64,
+    /// Length of an epoch in seconds
+    pub epoch_seconds: u64,
+    /// Length of the swap history window in seconds
+    pub window_seconds: u64,
+}
+
+impl YieldContext<'_> {
+    /// Estimate the yield of providing `holding` to `pool`
+    ///
+    /// `fills` are the pool's swaps during the window, and `staked_lp` the amount
+    /// of its LP token staked in farms, if known. `None` if the pool doesn't hold
+    /// the asset or can't be valued.
+    pub fn opportunity(
+        &self,
+        pool: &PoolInfoResponse,
+        holding: &Coin,
+        fills: &[SwapFill],
+        staked_lp: Option<Uint128>,
+    ) -> Option<EarnOpportunity> {
+        let info = &pool.pool_info;
+        let quote = holding.denom.as_str();
+        let reserve = info
+            .assets
+            .iter()
+            .find(|asset| asset.denom == quote)?
+            .amount;
+        if reserve.is_zero() {
+            return None;
+        }
+
+        let value = |amount: Uint128, price: Decimal| amount.mul_floor(price);
+        let tvl = info
+            .assets
+            .iter()
+            .map(|asset| Some(value(asset.amount, pool_price(pool, &asset.denom, quote)?)))
+            .sum::<Option<Uint128>>()?;
+        if tvl.is_zero() {
+            return None;
+        }
+
+        // Liquidity providers keep the swap fee; protocol and burn fees go elsewhere
+        let swap_fee = info.pool_fees.swap_fee.share;
+        let fees: Uint128 = fills
+            .iter()
+            .filter_map(|fill| {
+                let price = pool_price(pool, &fill.offer_denom, quote)?;
+                Some(value(fill.offer_amount.mul_floor(swap_fee), price))
+            })
+            .sum();
+        let fee_apr = annualize(fees, self.window_seconds, tvl);
+
+        let active_farms: Vec<&Farm> = self
+            .farms
+            .iter()
+            .filter(|farm| {
+                farm.lp_denom == info.lp_denom
+                    && farm.start_epoch <= self.current_epoch
+                    && self.current_epoch < farm.preliminary_end_epoch
+            })
+            .collect();
+        let incentives_per_epoch: Uint128 = active_farms
+            .iter()
+            .filter_map(|farm| {
+                let price = self.prices.price(&farm.farm_asset.denom, quote)?;
+                Some(value(farm.emission_rate, price))
+            })
+            .sum();
+        // Incentives go to staked LP tokens only
+        let staked_tvl = match staked_lp {
+            Some(staked) if !staked.is_zero() && !pool.total_share.amount.is_zero() => {
+                tvl.multiply_ratio(staked.min(pool.total_share.amount), pool.total_share.amount)
+            }
+            _ => tvl,
+        };
+        let incentive_apr = annualize(incentives_per_epoch, self.epoch_seconds, staked_tvl);
+
+        let pairing = info
+            .assets
+            .iter()
+            .filter(|asset| asset.denom != quote)
+            .map(|asset| Coin {
+                denom: asset.denom.clone(),
+                amount: holding.amount.multiply_ratio(asset.amount, reserve),
+            })
+            .collect();
+        // The position is worth the deposit times the pool's value per unit of reserve
+        let position = holding.amount.multiply_ratio(tvl, reserve);
+
+        let mut opportunity = EarnOpportunity {
+            asset: holding.denom.clone(),
+            pool_id: info.pool_identifier.clone(),
+            deposit: holding.amount,
+            pairing,
+            tvl,
+            fee_apr,
+            incentive_apr,
+            farms: active_farms
+                .iter()
+                .map(|farm| farm.identifier.clone())
+                .collect(),
+            projected_return: Uint128::zero(),
+        };
+        opportunity.projected_return = position.mul_floor(opportunity.total_apr());
+        Some(opportunity)
+    }
+}
+
+/// Yearly share of `base` earned by receiving `income` every `period_seconds`
+fn annualize(income: Uint128, period_seconds: u64, base: Uint128) -> Decimal {
+    if period_seconds == 0 || base.is_zero() {
+        return Decimal::zero();
+    }
+    let yearly = income.full_mul(SECONDS_PER_YEAR) / Uint256::from(period_seconds);
+    Uint128::try_from(yearly)
+        .ok()
+        .and_then(|yearly| Decimal::checked_from_ratio(yearly, base).ok())
+        .unwrap_or(Decimal::MAX)
+}
@@ -0,0 +1,133 @@
+//! Epoch calendar and countdowns
+//!
+//! Rewards accrue, and farms start and end, per epoch of the epoch manager.
+//! Epochs have a fixed duration, so once the start of one epoch is known every
+//! other epoch's window follows from it. [`EpochCalendar`] does that arithmetic,
+//! and [`calendar`] lays out the upcoming epochs with the farms that start or
+//! pay for the last time in each.
+
+use chrono::{DateTime, Duration, Utc};
+use mantra_dex_std::farm_manager::Farm;
+use serde::{Deserialize, Serialize};
+
+/// Epochs shown by default in a calendar, the current one included
+pub const DEFAULT_CALENDAR_EPOCHS: usize = 7;
+
+/// Time span of one epoch, `start` inclusive and `end` exclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochWindow {
+    pub id: u64,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Epoch timing, anchored on one epoch whose start is known
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochCalendar {
+    anchor_id: u64,
+    anchor_start: DateTime<Utc>,
+    duration_seconds: u64,
+}
+
+impl EpochCalendar {
+    /// Calendar of epochs lasting `duration_seconds`, where epoch `id` starts at `start`
+    pub fn new(id: u64, start: DateTime<Utc>, duration_seconds: u64) -> Self {
+        Self {
+            anchor_id: id,
+            anchor_start: start,
+            duration_seconds: duration_seconds.max(1),
+        }
+    }
+
+    /// Length of an epoch
+    pub fn duration(&self) -> Duration {
+        Duration::seconds(self.duration_seconds as i64)
+    }
+
+    /// Start and end of epoch `id`
+    pub fn window(&self, id: u64) -> EpochWindow {
+        let offset = id as i64 - self.anchor_id as i64;
+        let start = self.anchor_start + Duration::seconds(offset * self.duration_seconds as i64);
+        EpochWindow {
+            id,
+            start,
+            end: start + self.duration(),
+        }
+    }
+
+    /// Epoch running at `time`; epoch 0 for times before it
+    pub fn epoch_at(&self, time: DateTime<Utc>) -> u64 {
+        let elapsed = (time - self.anchor_start).num_seconds();
+        let epochs = elapsed.div_euclid(self.duration_seconds as i64);
+        (self.anchor_id as i64 + epochs).max(0) as u64
+    }
+
+    /// Time left at `now` until the next epoch starts
+    pub fn time_until_next(&self, now: DateTime<Utc>) -> Duration {
+        self.window(self.epoch_at(now)).end - now
+    }
+
+    /// The epoch running at `now` followed by the next ones, `count` in total
+    pub fn upcoming(&self, now: DateTime<Utc>, count: usize) -> Vec<EpochWindow> {
+        let current = self.epoch_at(now);
+        (current..current + count as u64)
+            .map(|id| self.window(id))
+            .collect()
+    }
+}
+
+/// One epoch of a calendar with the farm milestones falling in it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarEntry {
+    pub window: EpochWindow,
+    /// Farms paying their first rewards in this epoch
+    pub farms_starting: Vec<String>,
+    /// Farms paying their last rewards in this epoch, unless extended
+    pub farms_ending: Vec<String>,
+}
+
+/// The `count` epochs from the one running at `now`, with the farms starting and ending in each
+pub fn calendar(
+    calendar: &EpochCalendar,
+    now: DateTime<Utc>,
+    count: usize,
+    farms: &[Farm],
+) -> Vec<CalendarEntry> {
+    let farms_where = |matches: &dyn Fn(&Farm) -> bool| {
+        farms
+            .iter()
+            .filter(|farm| matches(farm))
+            .map(|farm| farm.identifier.clone())
+            .collect()
+    };
+
+    calendar
+        .upcoming(now, count)
+        .into_iter()
+        .map(|window| CalendarEntry {
+            farms_starting: farms_where(&|farm| farm.start_epoch == window.id),
+            // A farm pays up to, but not in, its end epoch
+            farms_ending: farms_where(&|farm| {
+                farm.preliminary_end_epoch.checked_sub(1) == Some(window.id)
+            }),
+            window,
+        })
+        .collect()
+}
+
+/// Compact countdown such as `2d 03h 15m`, or `4m 05s` under an hour
+pub fn format_countdown(duration: Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    let (days, hours, minutes) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+    );
+    if days > 0 {
+        format!("{}d {:02}h {:02}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m {:02}s", minutes, seconds % 60)
+    }
+}
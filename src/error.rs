@@ -1,6 +1,9 @@
 use cosmrs::rpc::endpoint::broadcast::tx_sync::Response as SyncTxResponse;
 use thiserror::Error;
 
+use crate::contract_schema::SchemaMismatch;
+use crate::diagnose::{Diagnosis, FailureCause};
+
 /// SDK Error type for MANTRA DEX SDK v3.0.0
 ///
 /// This enum represents all possible errors that can occur when using the SDK.
@@ -39,10 +42,19 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    /// Schema mismatch - occurs when a contract answers a query with a response missing a
+    /// field the SDK expects or holding one of another type, usually after a contract upgrade
+    #[error("Schema mismatch: {0}")]
+    SchemaMismatch(Box<SchemaMismatch>),
+
     /// IO error - occurs when file system operations fail
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Storage error - occurs when a [storage backend](crate::storage) fails to read or write
+    #[error("Storage error: {0}")]
+    Storage(String),
+
     /// Fee validation error - **v3.0.0 New**: occurs when pool fees exceed 20% total limit
     ///
     /// This error is thrown when:
@@ -67,4 +79,153 @@ pub enum Error {
     /// Timeout error - occurs when operations exceed their timeout limit
     #[error("Timeout error: {0}")]
     Timeout(String),
+
+    /// Unconfirmed transaction - occurs when a transaction may have reached the mempool but
+    /// wasn't seen in a block; it may still be included, so it must not be sent again
+    #[error("Transaction {tx_hash} unconfirmed: {reason}")]
+    Unconfirmed { tx_hash: String, reason: String },
+
+    /// Policy violation - occurs when an operation is refused by a client-side safety policy
+    #[error("Policy violation: {0}")]
+    Policy(String),
+}
+
+/// Broad classes of SDK errors with stable process exit codes
+///
+/// Scripts wrapping the CLI can branch on these codes without parsing error messages.
+/// The numeric values are part of the public interface and must not change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// Unexpected internal failure (exit code 1)
+    Internal,
+    /// Invalid input, configuration or wallet state (exit code 2)
+    User,
+    /// RPC, connectivity or timeout failure (exit code 3)
+    Network,
+    /// The chain or a contract rejected the operation (exit code 4)
+    ChainRejection,
+    /// A client-side safety policy refused the operation (exit code 5)
+    PolicyViolation,
+}
+
+impl ErrorClass {
+    /// Process exit code for this error class
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorClass::Internal => 1,
+            ErrorClass::User => 2,
+            ErrorClass::Network => 3,
+            ErrorClass::ChainRejection => 4,
+            ErrorClass::PolicyViolation => 5,
+        }
+    }
+
+    /// Stable identifier used in structured error output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorClass::Internal => "internal",
+            ErrorClass::User => "user",
+            ErrorClass::Network => "network",
+            ErrorClass::ChainRejection => "chain_rejection",
+            ErrorClass::PolicyViolation => "policy_violation",
+        }
+    }
+}
+
+impl Error {
+    /// Classify this error for exit codes and structured reporting
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Error::Rpc(_) | Error::Network(_) | Error::Timeout(_) | Error::Unconfirmed { .. } => {
+                ErrorClass::Network
+            }
+            Error::TxBroadcast(_) | Error::TxSimulation(_) | Error::Tx(_) | Error::Contract(_) => {
+                ErrorClass::ChainRejection
+            }
+            Error::Wallet(_) | Error::Config(_) | Error::FeeValidation(_) => ErrorClass::User,
+            Error::Policy(_) => ErrorClass::PolicyViolation,
+            Error::CosmRs(_)
+            | Error::Serialization(_)
+            | Error::SchemaMismatch(_)
+            | Error::Io(_)
+            | Error::Storage(_)
+            | Error::Other(_) => ErrorClass::Internal,
+        }
+    }
+
+    /// Name of the error variant, used as the `kind` of structured errors
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::CosmRs(_) => "CosmRs",
+            Error::Rpc(_) => "Rpc",
+            Error::TxBroadcast(_) => "TxBroadcast",
+            Error::TxSimulation(_) => "TxSimulation",
+            Error::Wallet(_) => "Wallet",
+            Error::Config(_) => "Config",
+            Error::Contract(_) => "Contract",
+            Error::Serialization(_) => "Serialization",
+            Error::SchemaMismatch(_) => "SchemaMismatch",
+            Error::Io(_) => "Io",
+            Error::Storage(_) => "Storage",
+            Error::FeeValidation(_) => "FeeValidation",
+            Error::Other(_) => "Other",
+            Error::Tx(_) => "Tx",
+            Error::Network(_) => "Network",
+            Error::Timeout(_) => "Timeout",
+            Error::Unconfirmed { .. } => "Unconfirmed",
+            Error::Policy(_) => "Policy",
+        }
+    }
+
+    /// Whether the chain rejected a swap for exceeding its slippage tolerance
+    pub fn is_slippage_exceeded(&self) -> bool {
+        if self.class() != ErrorClass::ChainRejection {
+            return false;
+        }
+        let message = self.to_string().to_lowercase();
+        (message.contains("slippage")
+            && (message.contains("exceed") || message.contains("assertion")))
+            || message.contains("max spread")
+    }
+
+    /// Whether a transaction failed for a transient reason a re-signed retry can overcome
+    ///
+    /// True for account sequence mismatches and full mempools. Timeouts aren't
+    /// retryable, as a timed out broadcast may still reach the mempool.
+    pub fn is_retryable_broadcast(&self) -> bool {
+        matches!(
+            Diagnosis::from_error(self).cause,
+            FailureCause::SequenceMismatch | FailureCause::MempoolFull
+        )
+    }
+
+    /// Process exit code for this error, see [`ErrorClass::exit_code`]
+    pub fn exit_code(&self) -> i32 {
+        self.class().exit_code()
+    }
+
+    /// Structured representation of this error for machine consumers
+    ///
+    /// ```json
+    /// {"error": {"class": "network", "kind": "Rpc", "exit_code": 3, "message": "RPC error: ..."}}
+    /// ```
+    ///
+    /// Schema mismatches also carry the contract, query, field and contract
+    /// version under `schema_mismatch`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let class = self.class();
+        let mut json = serde_json::json!({
+            "error": {
+                "class": class.as_str(),
+                "kind": self.kind(),
+                "exit_code": class.exit_code(),
+                "message": self.to_string(),
+            }
+        });
+        if let Error::SchemaMismatch(mismatch) = self {
+            json["error"]["schema_mismatch"] = serde_json::json!(mismatch);
+        }
+        json
+    }
 }
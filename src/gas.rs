@@ -0,0 +1,342 @@
+//! Gas usage history and calibrated gas limits
+//!
+//! Every transaction the client sends records the gas its simulation used, the
+//! limit it was sent with and the gas it actually used, keyed by the kind of
+//! operation. The spread of actual to simulated gas for an operation kind sets the
+//! adjustment applied to later simulations of the same kind, so limits follow what
+//! the chain really charges rather than a fixed worst case.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::Any;
+use cosmwasm_std::Coin;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::schema::{self, Format, Schema};
+use crate::storage::{Document, SharedStorage};
+
+/// Samples kept per operation kind; older ones are dropped first
+pub const DEFAULT_SAMPLES_PER_KIND: usize = 50;
+
+/// Samples needed before an operation kind's history replaces the configured adjustment
+pub const MIN_CALIBRATION_SAMPLES: usize = 5;
+
+/// Quantile of the actual-to-simulated gas ratio that the adjustment covers
+const CALIBRATION_QUANTILE: f64 = 0.95;
+
+/// Headroom added on top of the calibrated quantile
+const CALIBRATION_MARGIN: f64 = 1.05;
+
+/// Factor by which a limit that ran out of gas undershot, at least
+const OUT_OF_GAS_FACTOR: f64 = 1.25;
+
+/// Type URL of a CosmWasm contract execution
+const MSG_EXECUTE_CONTRACT: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+/// Format of the gas history file
+const GAS_HISTORY_SCHEMA: Schema = Schema::new("gas history", Format::Json, &[wrap_samples]);
+
+/// Contents of the gas history file: samples by operation kind
+#[derive(Serialize, Deserialize)]
+struct GasHistoryFile<S> {
+    samples: S,
+}
+
+/// Version 0 was the bare map of samples by kind
+fn wrap_samples(value: &mut serde_json::Value) -> Result<(), Error> {
+    schema::wrap_in(value, "samples");
+    Ok(())
+}
+
+/// Gas figures of one transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasSample {
+    /// Gas used by the transaction's simulation
+    pub simulated: u64,
+    /// Gas limit the transaction was sent with
+    pub gas_wanted: u64,
+    /// Gas the transaction used on chain
+    pub gas_used: u64,
+    /// Whether the transaction failed for running out of gas
+    #[serde(default)]
+    pub out_of_gas: bool,
+}
+
+impl GasSample {
+    /// Actual gas as a multiple of the simulated gas
+    ///
+    /// Running out of gas only shows the limit was too low, so such samples count
+    /// as needing [`OUT_OF_GAS_FACTOR`] times the limit.
+    fn ratio(&self) -> Option<f64> {
+        if self.simulated == 0 {
+            return None;
+        }
+        let needed = if self.out_of_gas {
+            self.gas_wanted as f64 * OUT_OF_GAS_FACTOR
+        } else {
+            self.gas_used as f64
+        };
+        Some(needed / self.simulated as f64)
+    }
+}
+
+/// Gas and fee a transaction would need, from a simulation against the chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasEstimate {
+    /// Operation kind, see [`operation_kind`]
+    pub kind: String,
+    /// Gas used by the simulation
+    pub simulated: u64,
+    /// Gas limit the transaction would be sent with
+    pub gas_limit: u64,
+    /// Fee the transaction would pay
+    pub fee: Coin,
+}
+
+impl GasEstimate {
+    /// Gas limit as a multiple of the simulated gas
+    pub fn adjustment(&self) -> f64 {
+        if self.simulated == 0 {
+            return 1.0;
+        }
+        self.gas_limit as f64 / self.simulated as f64
+    }
+}
+
+/// Summary of the gas history of one operation kind
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GasStats {
+    /// Operation kind, see [`operation_kind`]
+    pub kind: String,
+    /// Number of recorded transactions
+    pub samples: usize,
+    /// Recorded transactions that ran out of gas
+    pub out_of_gas: usize,
+    /// Mean share of the gas limit that was used
+    pub mean_utilization: f64,
+    /// Adjustment applied to simulated gas, if there are enough samples
+    pub calibrated_adjustment: Option<f64>,
+}
+
+/// Recent gas usage per operation kind
+///
+/// Safe to share between clients. A history opened with [`open`](Self::open)
+/// or [`open_in`](Self::open_in) writes itself back after every recorded
+/// transaction.
+#[derive(Debug)]
+pub struct GasHistory {
+    samples: Mutex<BTreeMap<String, VecDeque<GasSample>>>,
+    samples_per_kind: usize,
+    document: Option<Document>,
+}
+
+impl Default for GasHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLES_PER_KIND)
+    }
+}
+
+impl GasHistory {
+    /// Create an empty, in-memory history keeping `samples_per_kind` samples per kind
+    pub fn new(samples_per_kind: usize) -> Self {
+        Self {
+            samples: Mutex::new(BTreeMap::new()),
+            samples_per_kind: samples_per_kind.max(1),
+            document: None,
+        }
+    }
+
+    /// Load the history stored at `path`, saving back to it as transactions are recorded
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the history kept in `storage`, saving back to it as transactions are recorded
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut history = Self::default();
+        if let Some(stored) = document
+            .read::<GasHistoryFile<BTreeMap<String, Vec<GasSample>>>>(&GAS_HISTORY_SCHEMA)?
+        {
+            let samples_per_kind = history.samples_per_kind;
+            *history.lock() = stored
+                .samples
+                .into_iter()
+                .map(|(kind, samples)| {
+                    let skip = samples.len().saturating_sub(samples_per_kind);
+                    (kind, samples.into_iter().skip(skip).collect())
+                })
+                .collect();
+        }
+        history.document = Some(document);
+        Ok(history)
+    }
+
+    /// Key of the history in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "gas_history.json";
+
+    /// Get the default gas history file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("gas_history.json");
+        path
+    }
+
+    /// Record the gas figures of a transaction of `kind`
+    ///
+    /// Saving a history opened from a file or a storage is best effort; a failed write only
+    /// loses the latest samples.
+    pub fn record(&self, kind: &str, sample: GasSample) {
+        let snapshot = {
+            let mut samples = self.lock();
+            let kind_samples = samples.entry(kind.to_string()).or_default();
+            if kind_samples.len() == self.samples_per_kind {
+                kind_samples.pop_front();
+            }
+            kind_samples.push_back(sample);
+            self.document.as_ref().map(|_| samples.clone())
+        };
+
+        if let (Some(document), Some(samples)) = (&self.document, snapshot) {
+            let _ = document.write(&GAS_HISTORY_SCHEMA, &GasHistoryFile { samples });
+        }
+    }
+
+    /// Recorded samples of `kind`, oldest first
+    pub fn samples(&self, kind: &str) -> Vec<GasSample> {
+        self.lock()
+            .get(kind)
+            .map(|samples| samples.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Adjustment to apply to simulated gas for `kind`
+    ///
+    /// Covers the [`CALIBRATION_QUANTILE`] of recorded actual-to-simulated ratios
+    /// plus a margin. `None` until [`MIN_CALIBRATION_SAMPLES`] have been recorded.
+    pub fn calibrated_adjustment(&self, kind: &str) -> Option<f64> {
+        let mut ratios: Vec<f64> = self
+            .lock()
+            .get(kind)?
+            .iter()
+            .filter_map(GasSample::ratio)
+            .collect();
+        if ratios.len() < MIN_CALIBRATION_SAMPLES {
+            return None;
+        }
+
+        ratios.sort_by(f64::total_cmp);
+        let index = ((ratios.len() - 1) as f64 * CALIBRATION_QUANTILE).ceil() as usize;
+        Some((ratios[index] * CALIBRATION_MARGIN).max(1.0))
+    }
+
+    /// Gas limit for a transaction of `kind` whose simulation used `simulated` gas
+    ///
+    /// Uses the calibrated adjustment once there is enough history, and
+    /// `default_adjustment` until then. Never exceeds `max_gas`.
+    pub fn gas_limit(
+        &self,
+        kind: &str,
+        simulated: u64,
+        default_adjustment: f64,
+        max_gas: u64,
+    ) -> u64 {
+        let adjustment = self
+            .calibrated_adjustment(kind)
+            .unwrap_or(default_adjustment);
+        ((simulated as f64 * adjustment).round() as u64).min(max_gas)
+    }
+
+    /// Summary of every recorded operation kind
+    pub fn stats(&self) -> Vec<GasStats> {
+        let kinds: Vec<String> = self.lock().keys().cloned().collect();
+        kinds
+            .into_iter()
+            .filter_map(|kind| self.kind_stats(&kind))
+            .collect()
+    }
+
+    /// Summary of the recorded samples of `kind`
+    pub fn kind_stats(&self, kind: &str) -> Option<GasStats> {
+        let samples = self.samples(kind);
+        if samples.is_empty() {
+            return None;
+        }
+
+        let utilizations: Vec<f64> = samples
+            .iter()
+            .filter(|sample| sample.gas_wanted > 0)
+            .map(|sample| sample.gas_used as f64 / sample.gas_wanted as f64)
+            .collect();
+        let mean_utilization = if utilizations.is_empty() {
+            0.0
+        } else {
+            utilizations.iter().sum::<f64>() / utilizations.len() as f64
+        };
+
+        Some(GasStats {
+            kind: kind.to_string(),
+            samples: samples.len(),
+            out_of_gas: samples.iter().filter(|sample| sample.out_of_gas).count(),
+            mean_utilization,
+            calibrated_adjustment: self.calibrated_adjustment(kind),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, VecDeque<GasSample>>> {
+        // A panic while holding the lock can't leave the samples inconsistent
+        self.samples
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Kind of operation performed by a transaction's messages
+///
+/// Contract executions are named after their execute message, e.g. `swap` or
+/// `provide_liquidity`; other messages by their type URL. Transactions with
+/// several messages join the names with `+`.
+pub fn operation_kind(msgs: &[Any]) -> String {
+    msgs.iter()
+        .map(|msg| {
+            if msg.type_url == MSG_EXECUTE_CONTRACT {
+                if let Some(action) = execute_action(msg) {
+                    return action;
+                }
+            }
+            msg.type_url.clone()
+        })
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Name of the execute message carried by a contract execution
+fn execute_action(msg: &Any) -> Option<String> {
+    let execute = MsgExecuteContract::decode(msg.value.as_slice()).ok()?;
+    let body: serde_json::Value = serde_json::from_slice(&execute.msg).ok()?;
+    match body {
+        serde_json::Value::Object(fields) if fields.len() == 1 => fields.keys().next().cloned(),
+        serde_json::Value::String(action) => Some(action),
+        _ => None,
+    }
+}
@@ -0,0 +1,377 @@
+//! Local transaction history of wallets
+//!
+//! [`MantraDexClient::sync_history`] reads the transactions an address sent
+//! through the client's [`Indexer`](crate::indexer::Indexer), by default the
+//! node's `tx_search` index, classifies each one by the DEX operation its events
+//! show and stores them in a [`TxHistory`]. Later syncs only ask for blocks after
+//! the last synced one, so the full history is read from the chain once and
+//! served from the local file afterwards.
+//!
+//! [`MantraDexClient::sync_history`]: crate::client::MantraDexClient::sync_history
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::DexEvent;
+use crate::error::Error;
+use crate::indexer::TxRecord;
+use crate::pagination::{PageCursor, Paginated};
+use crate::schema::{Format, Schema};
+use crate::storage::{Document, SharedStorage};
+
+/// Format of the transaction history file
+const HISTORY_SCHEMA: Schema = Schema::new("transaction history", Format::Json, &[]);
+
+/// Kind of DEX operation a transaction performed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationType {
+    Swap,
+    ProvideLiquidity,
+    WithdrawLiquidity,
+    CreatePool,
+    ClaimRewards,
+    /// Creating, filling or closing farms and farm positions
+    Farm,
+    /// Anything else, including transactions that touched no DEX contract
+    Other,
+}
+
+impl OperationType {
+    /// Every operation type
+    pub const ALL: [OperationType; 7] = [
+        OperationType::Swap,
+        OperationType::ProvideLiquidity,
+        OperationType::WithdrawLiquidity,
+        OperationType::CreatePool,
+        OperationType::ClaimRewards,
+        OperationType::Farm,
+        OperationType::Other,
+    ];
+
+    /// Name the operation type is selected by
+    pub fn name(&self) -> &'static str {
+        match self {
+            OperationType::Swap => "swap",
+            OperationType::ProvideLiquidity => "provide_liquidity",
+            OperationType::WithdrawLiquidity => "withdraw_liquidity",
+            OperationType::CreatePool => "create_pool",
+            OperationType::ClaimRewards => "claim_rewards",
+            OperationType::Farm => "farm",
+            OperationType::Other => "other",
+        }
+    }
+
+    /// Operation type of a DEX contract event's `action` attribute
+    pub fn from_action(action: &str) -> Self {
+        match action {
+            "swap" => OperationType::Swap,
+            "provide_liquidity" => OperationType::ProvideLiquidity,
+            "withdraw_liquidity" => OperationType::WithdrawLiquidity,
+            "create_pool" => OperationType::CreatePool,
+            "claim" => OperationType::ClaimRewards,
+            "fill_rewards" | "create_farm" | "expand_farm" | "close_farm" | "manage_farm"
+            | "open_position" | "expand_position" | "close_position" | "withdraw_position"
+            | "manage_position" => OperationType::Farm,
+            _ => OperationType::Other,
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for OperationType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().replace('-', "_");
+        OperationType::ALL
+            .into_iter()
+            .find(|operation| operation.name().eq_ignore_ascii_case(&s))
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "Unknown operation type '{}', expected one of: swap, provide_liquidity, \
+                     withdraw_liquidity, create_pool, claim_rewards, farm, other",
+                    s
+                ))
+            })
+    }
+}
+
+/// A transaction sent by a wallet, classified by the DEX operation it performed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Block height the transaction was included at
+    pub height: u64,
+    pub tx_hash: String,
+    /// Time of the block the transaction was included in, if it could be read
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Whether the transaction executed successfully
+    pub success: bool,
+    pub operation: OperationType,
+    /// Pool the operation used, if its events name one
+    #[serde(default)]
+    pub pool_id: Option<String>,
+    /// Events emitted by the DEX contracts
+    pub events: Vec<DexEvent>,
+}
+
+impl HistoryEntry {
+    /// Classify a transaction by the first DEX event with a known action
+    pub fn from_record(record: TxRecord, timestamp: Option<DateTime<Utc>>) -> Self {
+        let operation = record
+            .events
+            .iter()
+            .filter_map(|event| event.action.as_deref())
+            .map(OperationType::from_action)
+            .find(|operation| *operation != OperationType::Other)
+            .unwrap_or(OperationType::Other);
+        let pool_id = record
+            .events
+            .iter()
+            .find_map(|event| event.attributes.get("pool_identifier").cloned());
+        Self {
+            height: record.height,
+            tx_hash: record.tx_hash,
+            timestamp,
+            success: record.success,
+            operation,
+            pool_id,
+            events: record.events,
+        }
+    }
+}
+
+/// Which history entries to list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryFilter {
+    pub operation: Option<OperationType>,
+    pub pool_id: Option<String>,
+    /// Lowest block height, inclusive
+    pub from_height: Option<u64>,
+    /// Highest block height, inclusive
+    pub to_height: Option<u64>,
+}
+
+impl HistoryFilter {
+    /// Only list entries of `operation`
+    pub fn with_operation(mut self, operation: OperationType) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Only list entries that used `pool_id`
+    pub fn with_pool(mut self, pool_id: impl Into<String>) -> Self {
+        self.pool_id = Some(pool_id.into());
+        self
+    }
+
+    /// Only list entries included between the heights, inclusive
+    pub fn with_heights(mut self, from_height: Option<u64>, to_height: Option<u64>) -> Self {
+        self.from_height = from_height;
+        self.to_height = to_height;
+        self
+    }
+
+    /// Whether `entry` passes the filter
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        self.operation.is_none_or(|operation| entry.operation == operation)
+            && self
+                .pool_id
+                .as_ref()
+                .is_none_or(|pool_id| entry.pool_id.as_ref() == Some(pool_id))
+            && self.from_height.is_none_or(|from| entry.height >= from)
+            && self.to_height.is_none_or(|to| entry.height <= to)
+    }
+}
+
+/// History of one address
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AddressHistory {
+    /// Highest block height the history is complete up to
+    synced_height: u64,
+    /// Entries, oldest first
+    entries: Vec<HistoryEntry>,
+}
+
+/// Contents of the transaction history file: histories by address
+#[derive(Serialize, Deserialize)]
+struct HistoryFile {
+    addresses: BTreeMap<String, AddressHistory>,
+}
+
+/// Transaction histories of the wallets used on this machine
+///
+/// Safe to share between clients. A history opened with [`open`](Self::open)
+/// or [`open_in`](Self::open_in) writes itself back after every update.
+#[derive(Debug, Default)]
+pub struct TxHistory {
+    addresses: Mutex<BTreeMap<String, AddressHistory>>,
+    document: Option<Document>,
+}
+
+impl TxHistory {
+    /// Create an empty, in-memory history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the history stored at `path`, saving back to it as it is updated
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the history kept in `storage`, saving back to it as it is updated
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut history = Self::default();
+        if let Some(stored) = document.read::<HistoryFile>(&HISTORY_SCHEMA)? {
+            history.addresses = Mutex::new(stored.addresses);
+        }
+        history.document = Some(document);
+        Ok(history)
+    }
+
+    /// Key of the history in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "tx_history.json";
+
+    /// Get the default transaction history file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("tx_history.json");
+        path
+    }
+
+    /// Highest block height the history of `address` is complete up to, 0 if never synced
+    pub fn synced_height(&self, address: &str) -> u64 {
+        self.lock()
+            .get(address)
+            .map_or(0, |history| history.synced_height)
+    }
+
+    /// Add the transactions of `address` found up to `synced_height`
+    ///
+    /// Entries already in the history are replaced. Returns the number of new
+    /// entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a history opened from a file or a storage can't be saved
+    pub fn record(
+        &self,
+        address: &str,
+        entries: Vec<HistoryEntry>,
+        synced_height: u64,
+    ) -> Result<usize, Error> {
+        let (added, snapshot) = {
+            let mut addresses = self.lock();
+            let history = addresses.entry(address.to_string()).or_default();
+            let mut added = 0;
+            for entry in entries {
+                match history
+                    .entries
+                    .iter_mut()
+                    .find(|known| known.tx_hash == entry.tx_hash)
+                {
+                    Some(known) => *known = entry,
+                    None => {
+                        history.entries.push(entry);
+                        added += 1;
+                    }
+                }
+            }
+            history.entries.sort_by_key(|entry| entry.height);
+            history.synced_height = history.synced_height.max(synced_height);
+            (added, self.document.as_ref().map(|_| addresses.clone()))
+        };
+
+        if let (Some(document), Some(addresses)) = (&self.document, snapshot) {
+            document.write(&HISTORY_SCHEMA, &HistoryFile { addresses })?;
+        }
+        Ok(added)
+    }
+
+    /// A page of the entries of `address` passing `filter`, newest first
+    ///
+    /// Pages are keyed by transaction hash: the cursor's `start_after` is the
+    /// hash of the last entry of the previous page.
+    pub fn entries(
+        &self,
+        address: &str,
+        filter: &HistoryFilter,
+        cursor: &PageCursor,
+    ) -> Paginated<HistoryEntry> {
+        let addresses = self.lock();
+        let matching = addresses
+            .get(address)
+            .into_iter()
+            .flat_map(|history| history.entries.iter().rev())
+            .filter(|entry| filter.matches(entry));
+        let items = match &cursor.start_after {
+            Some(tx_hash) => matching
+                .skip_while(|entry| &entry.tx_hash != tx_hash)
+                .skip(1)
+                .take(cursor.limit as usize)
+                .cloned()
+                .collect(),
+            None => matching.take(cursor.limit as usize).cloned().collect(),
+        };
+        Paginated::from_page(items, cursor.clone(), |entry| entry.tx_hash.clone())
+    }
+
+    /// Every entry of `address` passing `filter`, oldest first
+    pub fn all_entries(&self, address: &str, filter: &HistoryFilter) -> Vec<HistoryEntry> {
+        self.lock()
+            .get(address)
+            .into_iter()
+            .flat_map(|history| history.entries.iter())
+            .filter(|entry| filter.matches(entry))
+            .cloned()
+            .collect()
+    }
+
+    /// Number of entries of `address`
+    pub fn len(&self, address: &str) -> usize {
+        self.lock()
+            .get(address)
+            .map_or(0, |history| history.entries.len())
+    }
+
+    /// Whether `address` has no entries
+    pub fn is_empty(&self, address: &str) -> bool {
+        self.len(address) == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, AddressHistory>> {
+        // A panic while holding the lock can't leave the histories inconsistent
+        self.addresses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,430 @@
+//! Sources of historical DEX data
+//!
+//! Swap history, candles, volumes and transaction history are read through the
+//! [`Indexer`] trait. [`MantraDexClient`] implements it on top of the node's
+//! transaction index, which needs nothing beyond an RPC endpoint but gets slow
+//! over long periods. [`RestIndexer`] reads the same data from an external
+//! indexer's REST API instead; set it with
+//! [`MantraDexClient::with_indexer`] and every history query goes through it.
+//!
+//! Both return the same types. A REST indexer must serve these endpoints, with
+//! the optional `from_height` and `to_height` query parameters bounding the
+//! period, inclusive:
+//!
+//! | Endpoint | Query parameters | Response |
+//! |----------|------------------|----------|
+//! | `GET /swaps` | `address` | `[SwapFill]` |
+//! | `GET /pools/{pool_id}/swaps` | | `[SwapFill]` |
+//! | `GET /pools/{pool_id}/candles` | `base`, `quote`, `interval_blocks` | `[Candle]` |
+//! | `GET /pools/{pool_id}/volume` | | `[Coin]` |
+//! | `GET /txs` | `address` | `[TxRecord]` |
+//! | `GET /pools/{pool_id}/lp_holders` | | `[DenomHolder]` |
+//!
+//! The LP holders endpoint takes no period: it returns the current balances.
+//!
+//! Responses are JSON arrays of the types' serde representation, either bare or
+//! wrapped as `{"data": [...]}`.
+
+use std::fmt;
+use std::sync::Arc;
+
+use cosmrs::rpc::query::Query;
+use cosmwasm_std::Coin;
+use futures::future::BoxFuture;
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::analytics::{self, Candle, SwapFill};
+use crate::bridge::{extract_dex_events, DexEvent};
+use crate::client::MantraDexClient;
+use crate::config::IndexerConfig;
+use crate::error::Error;
+use crate::ownership::DenomHolder;
+
+/// Page size used when reading history from the node's transaction index
+const TX_PAGE_SIZE: u8 = 100;
+
+/// Page size used when reading denom holders from the node
+const HOLDERS_PAGE_SIZE: u64 = 100;
+
+/// A transaction sent by an address, with the DEX events it emitted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxRecord {
+    /// Block height the transaction was included at
+    pub height: u64,
+    /// Hash of the transaction
+    pub tx_hash: String,
+    /// Whether the transaction executed successfully
+    pub success: bool,
+    /// Events emitted by the DEX contracts
+    pub events: Vec<DexEvent>,
+}
+
+/// A source of historical DEX data
+///
+/// `from_height` and `to_height` bound each query, inclusive. Results are
+/// ordered oldest first.
+pub trait Indexer: fmt::Debug + Send + Sync {
+    /// Swaps made by `address` through the pool manager
+    fn address_swaps<'a>(
+        &'a self,
+        address: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>>;
+
+    /// Swaps made through a pool
+    fn pool_swaps<'a>(
+        &'a self,
+        pool_id: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>>;
+
+    /// Transactions sent by `address`
+    fn address_txs<'a>(
+        &'a self,
+        address: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<TxRecord>, Error>>;
+
+    /// Candles of `base` priced in `quote` for a pool
+    ///
+    /// Computed from [`pool_swaps`](Self::pool_swaps) unless overridden.
+    fn candles<'a>(
+        &'a self,
+        pool_id: &'a str,
+        base: &'a str,
+        quote: &'a str,
+        interval_blocks: u64,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, Error>> {
+        Box::pin(async move {
+            let fills = self.pool_swaps(pool_id, from_height, to_height).await?;
+            Ok(analytics::candles(&fills, base, quote, interval_blocks))
+        })
+    }
+
+    /// Amount sold per denom through a pool
+    ///
+    /// Computed from [`pool_swaps`](Self::pool_swaps) unless overridden.
+    fn pool_volume<'a>(
+        &'a self,
+        pool_id: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<Coin>, Error>> {
+        Box::pin(async move {
+            let fills = self.pool_swaps(pool_id, from_height, to_height).await?;
+            Ok(analytics::volume_by_denom(&fills))
+        })
+    }
+
+    /// Addresses currently holding `lp_denom`, the LP token of a pool
+    ///
+    /// Fails unless overridden, for indexers that don't track balances.
+    fn lp_holders<'a>(
+        &'a self,
+        pool_id: &'a str,
+        _lp_denom: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<DenomHolder>, Error>> {
+        Box::pin(async move {
+            Err(Error::Other(format!(
+                "Indexer doesn't serve the LP holders of pool {}",
+                pool_id
+            )))
+        })
+    }
+}
+
+impl Indexer for MantraDexClient {
+    fn address_swaps<'a>(
+        &'a self,
+        address: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>> {
+        Box::pin(
+            self.swap_history_stream(address, from_height, to_height, TX_PAGE_SIZE)
+                .try_collect(),
+        )
+    }
+
+    fn pool_swaps<'a>(
+        &'a self,
+        pool_id: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>> {
+        Box::pin(
+            self.pool_swaps_stream(pool_id, from_height, to_height, TX_PAGE_SIZE)
+                .try_collect(),
+        )
+    }
+
+    fn address_txs<'a>(
+        &'a self,
+        address: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<TxRecord>, Error>> {
+        let mut query = Query::eq("message.sender", address);
+        if let Some(from_height) = from_height {
+            query = query.and_gte("tx.height", from_height);
+        }
+        if let Some(to_height) = to_height {
+            query = query.and_lte("tx.height", to_height);
+        }
+
+        let contracts = self.config().contracts.dex_contracts();
+        Box::pin(
+            self.txs_stream(query, TX_PAGE_SIZE)
+                .map_ok(move |tx| {
+                    let tx_hash = tx.hash.to_string();
+                    TxRecord {
+                        height: tx.height.value(),
+                        events: extract_dex_events(
+                            tx.height.value(),
+                            &tx_hash,
+                            &tx.tx_result.events,
+                            &contracts,
+                        ),
+                        success: tx.tx_result.code.is_ok(),
+                        tx_hash,
+                    }
+                })
+                .try_collect(),
+        )
+    }
+
+    fn lp_holders<'a>(
+        &'a self,
+        _pool_id: &'a str,
+        lp_denom: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<DenomHolder>, Error>> {
+        Box::pin(
+            self.denom_holders_stream(lp_denom, HOLDERS_PAGE_SIZE)
+                .try_collect(),
+        )
+    }
+}
+
+/// Response body of a REST indexer, bare or wrapped in `data`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RestResponse<T> {
+    Bare(Vec<T>),
+    Wrapped { data: Vec<T> },
+}
+
+/// An external indexer serving historical data over REST
+///
+/// See the [module documentation](self) for the endpoints it calls.
+#[derive(Debug, Clone)]
+pub struct RestIndexer {
+    base_url: reqwest::Url,
+    api_key: Option<String>,
+    http: reqwest::Client,
+}
+
+impl RestIndexer {
+    /// Create an indexer client for the API at `base_url`
+    pub fn new(base_url: &str) -> Result<Self, Error> {
+        let base_url = reqwest::Url::parse(base_url)
+            .map_err(|e| Error::Config(format!("Invalid indexer URL '{}': {}", base_url, e)))?;
+        if base_url.cannot_be_a_base() {
+            return Err(Error::Config(format!(
+                "Invalid indexer URL '{}': not a base URL",
+                base_url
+            )));
+        }
+
+        Ok(Self {
+            base_url,
+            api_key: None,
+            http: reqwest::Client::new(),
+        })
+    }
+
+    /// Send `api_key` as a bearer token with every request
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// URL of the endpoint at `path` below the base URL, with `query` parameters
+    pub fn endpoint(&self, path: &[&str], query: &[(&str, Option<String>)]) -> reqwest::Url {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("checked to be a base URL")
+            .pop_if_empty()
+            .extend(path);
+        {
+            let mut pairs = url.query_pairs_mut();
+            for (key, value) in query {
+                if let Some(value) = value {
+                    pairs.append_pair(key, value);
+                }
+            }
+        }
+        if url.query() == Some("") {
+            url.set_query(None);
+        }
+        url
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &[&str],
+        query: &[(&str, Option<String>)],
+    ) -> Result<Vec<T>, Error> {
+        let url = self.endpoint(path, query);
+        let mut request = self.http.get(url.clone());
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("Indexer request {} failed: {}", url, e)))?;
+        if !response.status().is_success() {
+            return Err(Error::Network(format!(
+                "Indexer request {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::Network(format!("Indexer request {} failed: {}", url, e)))?;
+        Ok(match serde_json::from_slice(&body)? {
+            RestResponse::Bare(items) | RestResponse::Wrapped { data: items } => items,
+        })
+    }
+}
+
+/// Query parameters bounding a period
+fn height_range(
+    from_height: Option<u64>,
+    to_height: Option<u64>,
+) -> [(&'static str, Option<String>); 2] {
+    [
+        ("from_height", from_height.map(|height| height.to_string())),
+        ("to_height", to_height.map(|height| height.to_string())),
+    ]
+}
+
+impl Indexer for RestIndexer {
+    fn address_swaps<'a>(
+        &'a self,
+        address: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>> {
+        Box::pin(async move {
+            let [from, to] = height_range(from_height, to_height);
+            self.get(
+                &["swaps"],
+                &[("address", Some(address.to_string())), from, to],
+            )
+            .await
+        })
+    }
+
+    fn pool_swaps<'a>(
+        &'a self,
+        pool_id: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>> {
+        Box::pin(async move {
+            self.get(
+                &["pools", pool_id, "swaps"],
+                &height_range(from_height, to_height),
+            )
+            .await
+        })
+    }
+
+    fn address_txs<'a>(
+        &'a self,
+        address: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<TxRecord>, Error>> {
+        Box::pin(async move {
+            let [from, to] = height_range(from_height, to_height);
+            self.get(
+                &["txs"],
+                &[("address", Some(address.to_string())), from, to],
+            )
+            .await
+        })
+    }
+
+    fn candles<'a>(
+        &'a self,
+        pool_id: &'a str,
+        base: &'a str,
+        quote: &'a str,
+        interval_blocks: u64,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, Error>> {
+        Box::pin(async move {
+            let [from, to] = height_range(from_height, to_height);
+            self.get(
+                &["pools", pool_id, "candles"],
+                &[
+                    ("base", Some(base.to_string())),
+                    ("quote", Some(quote.to_string())),
+                    ("interval_blocks", Some(interval_blocks.to_string())),
+                    from,
+                    to,
+                ],
+            )
+            .await
+        })
+    }
+
+    fn pool_volume<'a>(
+        &'a self,
+        pool_id: &'a str,
+        from_height: Option<u64>,
+        to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<Coin>, Error>> {
+        Box::pin(async move {
+            self.get(
+                &["pools", pool_id, "volume"],
+                &height_range(from_height, to_height),
+            )
+            .await
+        })
+    }
+
+    fn lp_holders<'a>(
+        &'a self,
+        pool_id: &'a str,
+        _lp_denom: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<DenomHolder>, Error>> {
+        Box::pin(async move { self.get(&["pools", pool_id, "lp_holders"], &[]).await })
+    }
+}
+
+/// The external indexer described by `config`, `None` if it has no URL
+pub fn from_config(config: &IndexerConfig) -> Result<Option<Arc<dyn Indexer>>, Error> {
+    let Some(url) = &config.url else {
+        return Ok(None);
+    };
+    let mut indexer = RestIndexer::new(url)?;
+    if let Some(api_key) = &config.api_key {
+        indexer = indexer.with_api_key(api_key.clone());
+    }
+    Ok(Some(Arc::new(indexer)))
+}
@@ -1,7 +1,59 @@
+pub mod activity;
+pub mod amount;
+pub mod alerts;
+pub mod analytics;
+pub mod batch;
+pub mod bridge;
+pub mod bulk;
+pub mod canonical;
+pub mod circuit_breaker;
+pub mod claim;
 pub mod client;
+pub mod compare;
+pub mod congestion;
 pub mod config;
+pub mod contract_schema;
+pub mod convert;
+pub mod dca;
+pub mod diagnose;
+pub mod earn;
+pub mod epoch;
 pub mod error;
+pub mod gas;
+pub mod history;
+pub mod indexer;
+pub mod math;
+pub mod middleware;
+pub mod orders;
+pub mod output;
+pub mod ownership;
+pub mod pagination;
+pub mod paper;
+pub mod policy;
+pub mod pool_template;
+pub mod portfolio;
+pub mod positions;
+pub mod preferences;
+pub mod price_history;
+pub mod pricing;
+pub mod profile;
+pub mod quote;
+pub mod rate_limit;
+pub mod report;
+pub mod risk;
+pub mod route_cache;
+pub mod router;
+pub mod routing;
+pub mod scheduler;
+pub mod schema;
+pub mod secret;
+pub mod simulation;
 pub mod skip_adapter;
+pub mod smoketest;
+pub mod storage;
+pub mod subscriptions;
+pub mod token_registry;
+pub mod tx;
 pub mod wallet;
 
 // TUI module - optional via "tui" feature
@@ -14,15 +66,75 @@ pub mod mcp;
 // Re-export mantra-dex-std for user convenience
 pub use mantra_dex_std;
 
-pub use client::MantraDexClient;
-pub use config::{MantraNetworkConfig, NetworkConstants};
+pub use activity::{ActivityAlert, ActivityWatcher};
+pub use amount::NumberLocale;
+pub use alerts::{FarmAlert, FarmWatcher};
+pub use analytics::{Candle, PairExecution, SwapFill};
+pub use batch::{BatchOp, TxBatchBuilder};
+pub use bulk::{BulkAction, BulkPlan, BulkReport};
+pub use client::{
+    Access, CreatePoolOutcome, MantraDexClient, MantraDexClientBuilder, SwapRequest, SwapRetry,
+    TxProgress, TxStage,
+};
+pub use circuit_breaker::{CircuitBreaker, CircuitEvent, CircuitState};
+pub use claim::{ClaimChunk, ClaimReport};
+pub use compare::{PoolComparison, PoolPosition, PoolRanking, PoolTvl};
+pub use congestion::{Congestion, InclusionLatencies};
+pub use config::{
+    AssetFilterConfig, BroadcastRetryConfig, CircuitBreakerConfig, ExpertModeConfig, IndexerConfig,
+    MantraNetworkConfig, NetworkConstants, OperationClass, OperationTimeouts, PinnedRoute,
+    Priority, PriorityFeeConfig, RoutingConfig, StorageBackend, StorageConfig,
+};
+pub use contract_schema::{ContractVersion, SchemaMismatch};
+pub use convert::{Conversion, PriceBook};
+pub use dca::{DcaBook, DcaEnd, DcaExecution, DcaOutcome, DcaPlan, DcaStatus, NewDcaPlan};
+pub use diagnose::{Diagnosis, FailureCause};
+pub use earn::EarnOpportunity;
+pub use epoch::{CalendarEntry, EpochCalendar, EpochWindow};
 pub use error::Error;
+pub use gas::{GasEstimate, GasHistory, GasSample, GasStats};
+pub use history::{HistoryEntry, HistoryFilter, OperationType, TxHistory};
+pub use indexer::{Indexer, RestIndexer, TxRecord};
+pub use middleware::{AuditLog, Middleware, TxContext};
+pub use orders::{LimitOrder, NewOrder, OrderBook, OrderStatus, PriceCondition};
+pub use output::{CommandOutput, OutputFormat};
+pub use ownership::{LpConcentration, PoolOwnership};
+pub use pagination::{PageCursor, Paginated};
+pub use paper::{PaperPortfolio, PaperTrade, PaperTradeKind, PaperValuation};
+pub use policy::{SlippageBumpPolicy, TradeSizeGuard, TradeValueLimit};
+pub use pool_template::{PoolSpec, PoolTemplate};
+pub use portfolio::PortfolioValuation;
+pub use positions::{
+    FarmPosition, LiquidityChange, LpBalance, LpLock, LpPosition, WithdrawalPreview,
+};
+pub use preferences::{Preferences, PreferencesStore};
+pub use price_history::{PriceCandle, PriceHistory, PricePoint, PriceSeries};
+pub use pricing::PriceImpact;
+pub use profile::{Profile, ProfilePaths};
+pub use quote::{FeeBreakdown, Quote, QuoteRequest};
+pub use rate_limit::RateLimiter;
+pub use report::{PortfolioReporter, PortfolioSummary, ReportFormat, ReportSchedule};
+pub use risk::{RiskFlag, RiskLevel, RiskReport, RiskThresholds};
+pub use route_cache::{RouteCache, RouteCacheConfig};
+pub use router::{AssetGraph, RouteHop, SwapRoute};
+pub use routing::{
+    DepthCurve, DepthPoint, PoolQuote, PoolSelection, RouteChoice, SimulationSweep, SplitLeg,
+    SplitPlan, SweepSource,
+};
+pub use scheduler::{Schedule, ScheduleStatus, ScheduledAction, ScheduledTx, Trigger};
+pub use secret::SecretString;
+pub use simulation::{BalanceChange, DryRunReport};
 pub use skip_adapter::{
     SkipAction, SkipAffiliate, SkipAsset, SkipIbcInfo, SkipRoute, SkipSwap, SkipSwapExactAssetIn, 
     SkipSwapExactAssetOut, SkipSwapOperation, SimulateSwapExactAssetInResponse, 
     SimulateSwapExactAssetOutResponse, SimulateSmartSwapExactAssetInResponse
 };
-pub use wallet::MantraWallet;
+pub use smoketest::{SmokeReport, StepResult, StepStatus};
+pub use storage::{Document, FileStorage, MemoryStorage, SharedStorage, Storage};
+pub use subscriptions::{ChainEvent, EventSubscriptions, SubscriptionFilter};
+pub use token_registry::{TokenMetadata, TokenRegistry, TokenSource};
+pub use tx::{SignMode, TxBuilder, UnsignedTx};
+pub use wallet::{MantraWallet, MultisigAccount, MultisigTx, PartialSignature, WalletDefaults};
 
 // Re-export TUI entry point when feature is enabled
 #[cfg(feature = "tui")]
@@ -0,0 +1,336 @@
+//! Local pool math mirroring the pool manager contract
+//!
+//! Swaps through constant product and stable swap pools can be simulated from
+//! the pool's reserves, decimals, amplification and fees without a chain query.
+//! The formulas follow the pool manager's `compute_swap`, including its rounding,
+//! so results match the contract's `Simulation` query for the same pool state.
+//!
+//! In a stable swap pool of more than two assets, the contract computes the
+//! invariant over the offer and ask reserves only, while the swap equation
+//! counts every asset of the pool; [`stable_swap_in_pool`] does the same.
+
+use cosmwasm_std::{Coin, Decimal256, Uint128, Uint256};
+use mantra_dex_std::fee::PoolFee;
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType, SimulationResponse};
+
+/// Iterations allowed for the stable swap invariant to converge
+const MAX_ITERATIONS: u8 = 64;
+
+/// Simulate a swap against a pool's current reserves
+///
+/// Returns `None` if the pool doesn't hold both assets, has an empty reserve, or
+/// the stable swap invariant fails to converge. Callers should fall back to a
+/// chain simulation in that case.
+pub fn simulate_swap(
+    pool: &PoolInfoResponse,
+    offer_asset: &Coin,
+    ask_denom: &str,
+) -> Option<SimulationResponse> {
+    let info = &pool.pool_info;
+    if offer_asset.denom == ask_denom {
+        return None;
+    }
+
+    let offer_index = info
+        .asset_denoms
+        .iter()
+        .position(|d| *d == offer_asset.denom)?;
+    let ask_index = info.asset_denoms.iter().position(|d| d == ask_denom)?;
+    let reserve = |denom: &str| {
+        info.assets
+            .iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount)
+    };
+    let offer_pool = reserve(&offer_asset.denom)?;
+    let ask_pool = reserve(ask_denom)?;
+    if offer_pool.is_zero() || ask_pool.is_zero() {
+        return None;
+    }
+
+    let (return_amount, slippage_amount) = match info.pool_type {
+        PoolType::ConstantProduct => {
+            constant_product_swap(offer_pool, ask_pool, offer_asset.amount)?
+        }
+        PoolType::StableSwap { amp } => stable_swap_in_pool(
+            info.assets.len(),
+            offer_pool,
+            ask_pool,
+            offer_asset.amount,
+            amp,
+            *info.asset_decimals.get(offer_index)?,
+            *info.asset_decimals.get(ask_index)?,
+        )?,
+    };
+
+    apply_fees(&info.pool_fees, return_amount, slippage_amount)
+}
+
+/// Return and slippage of a constant product swap, before fees
+pub fn constant_product_swap(
+    offer_pool: Uint128,
+    ask_pool: Uint128,
+    offer_amount: Uint128,
+) -> Option<(Uint256, Uint256)> {
+    let offer_pool = Uint256::from(offer_pool);
+    let ask_pool = Uint256::from(ask_pool);
+    let offer_amount = Uint256::from(offer_amount);
+
+    // ask_amount = ask_pool - cp / (offer_pool + offer_amount)
+    let cp = offer_pool.checked_mul(ask_pool).ok()?;
+    let remaining =
+        Decimal256::checked_from_ratio(cp, offer_pool.checked_add(offer_amount).ok()?).ok()?;
+    let return_amount = Decimal256::from_ratio(ask_pool, 1u8)
+        .checked_sub(remaining)
+        .ok()?
+        .to_uint_floor();
+
+    // Slippage is the shortfall against the pre-trade price
+    let slippage_amount = offer_amount
+        .mul_floor(Decimal256::checked_from_ratio(ask_pool, offer_pool).ok()?)
+        .saturating_sub(return_amount);
+
+    Some((return_amount, slippage_amount))
+}
+
+/// Return and slippage of a two-asset stable swap, before fees
+///
+/// Amounts are in base units; `offer_precision` and `ask_precision` are the
+/// assets' decimals.
+pub fn stable_swap(
+    offer_pool: Uint128,
+    ask_pool: Uint128,
+    offer_amount: Uint128,
+    amp: u64,
+    offer_precision: u8,
+    ask_precision: u8,
+) -> Option<(Uint256, Uint256)> {
+    stable_swap_in_pool(
+        2,
+        offer_pool,
+        ask_pool,
+        offer_amount,
+        amp,
+        offer_precision,
+        ask_precision,
+    )
+}
+
+/// Return and slippage of a stable swap in a pool of `n_coins` assets, before fees
+///
+/// Like [`stable_swap`], which is the two-asset case.
+pub fn stable_swap_in_pool(
+    n_coins: usize,
+    offer_pool: Uint128,
+    ask_pool: Uint128,
+    offer_amount: Uint128,
+    amp: u64,
+    offer_precision: u8,
+    ask_precision: u8,
+) -> Option<(Uint256, Uint256)> {
+    if n_coins < 2 {
+        return None;
+    }
+    let offer_pool = with_precision(offer_pool, offer_precision)?;
+    let ask_pool_decimal = with_precision(ask_pool, ask_precision)?;
+    let offer_amount = with_precision(offer_amount, offer_precision)?;
+
+    let new_ask_pool = stable_swap_y(
+        n_coins,
+        offer_pool,
+        ask_pool_decimal,
+        offer_amount,
+        amp,
+        ask_precision,
+    )?;
+    let return_amount = Uint256::from(ask_pool).checked_sub(new_ask_pool).ok()?;
+
+    // Slippage is the loss against a 1:1 conversion
+    let slippage_amount = to_base_units(offer_amount, ask_precision)?.saturating_sub(return_amount);
+
+    Some((return_amount, slippage_amount))
+}
+
+/// Stable swap invariant `D` of the offer and ask reserves
+fn stable_swap_d(
+    offer_pool: Decimal256,
+    ask_pool: Decimal256,
+    amp: u64,
+    precision: u8,
+) -> Option<Decimal256> {
+    let n_coins = Decimal256::from_ratio(2u8, 1u8);
+    let sum_pools = offer_pool.checked_add(ask_pool).ok()?;
+    if sum_pools.is_zero() {
+        return Some(Decimal256::zero());
+    }
+
+    let ann = Decimal256::from_ratio(
+        Uint256::from(amp).checked_mul(Uint256::from(2u8)).ok()?,
+        1u8,
+    );
+    let ann_sum_pools = ann.checked_mul(sum_pools).ok()?;
+    let ann_minus_one = ann.checked_sub(Decimal256::one()).ok()?;
+    let n_coins_plus_one = n_coins.checked_add(Decimal256::one()).ok()?;
+    let tolerance =
+        Decimal256::from_ratio(1u8, Uint256::from(10u8).checked_pow(precision.into()).ok()?);
+
+    let mut d = sum_pools;
+    for _ in 0..MAX_ITERATIONS {
+        let d_product = d
+            .checked_mul(d)
+            .ok()?
+            .checked_div(offer_pool.checked_mul(n_coins).ok()?)
+            .ok()?
+            .checked_mul(d)
+            .ok()?
+            .checked_div(ask_pool.checked_mul(n_coins).ok()?)
+            .ok()?;
+
+        let d_prev = d;
+        let numerator = ann_sum_pools
+            .checked_add(d_product.checked_mul(n_coins).ok()?)
+            .ok()?
+            .checked_mul(d)
+            .ok()?;
+        let denominator = ann_minus_one
+            .checked_mul(d)
+            .ok()?
+            .checked_add(n_coins_plus_one.checked_mul(d_product).ok()?)
+            .ok()?;
+        d = numerator.checked_div(denominator).ok()?;
+
+        if d.abs_diff(d_prev) <= tolerance {
+            return Some(d);
+        }
+    }
+
+    None
+}
+
+/// New ask reserve, in ask base units, after `offer_amount` is added to the offer reserve
+fn stable_swap_y(
+    n_coins: usize,
+    offer_pool: Decimal256,
+    ask_pool: Decimal256,
+    offer_amount: Decimal256,
+    amp: u64,
+    ask_precision: u8,
+) -> Option<Uint256> {
+    let n_coins = Uint256::from(n_coins as u64);
+    let ann = Uint256::from(amp).checked_mul(n_coins).ok()?;
+    let d = to_base_units(
+        stable_swap_d(offer_pool, ask_pool, amp, ask_precision)?,
+        ask_precision,
+    )?;
+    let pool_sum = to_base_units(offer_pool.checked_add(offer_amount).ok()?, ask_precision)?;
+
+    let c = d
+        .checked_multiply_ratio(d, pool_sum.checked_mul(n_coins).ok()?)
+        .ok()?
+        .checked_multiply_ratio(d, ann.checked_mul(n_coins).ok()?)
+        .ok()?;
+    let b = pool_sum.checked_add(d.checked_div(ann).ok()?).ok()?;
+
+    // y = (y^2 + c) / (2y + b - d)
+    let mut y = d;
+    for _ in 0..MAX_ITERATIONS {
+        let y_prev = y;
+        y = y
+            .checked_mul(y)
+            .ok()?
+            .checked_add(c)
+            .ok()?
+            .checked_div(
+                y.checked_add(y)
+                    .ok()?
+                    .checked_add(b)
+                    .ok()?
+                    .checked_sub(d)
+                    .ok()?,
+            )
+            .ok()?;
+
+        if y.abs_diff(y_prev) <= Uint256::one() {
+            return Some(y);
+        }
+    }
+
+    None
+}
+
+/// Take the pool's fees out of the return amount
+fn apply_fees(
+    pool_fees: &PoolFee,
+    return_amount: Uint256,
+    slippage_amount: Uint256,
+) -> Option<SimulationResponse> {
+    let fee = |share: &mantra_dex_std::fee::Fee| share.compute(return_amount).ok();
+    let protocol_fee_amount = fee(&pool_fees.protocol_fee)?;
+    let swap_fee_amount = fee(&pool_fees.swap_fee)?;
+    let burn_fee_amount = fee(&pool_fees.burn_fee)?;
+    let extra_fees_amount = pool_fees
+        .extra_fees
+        .iter()
+        .try_fold(Uint256::zero(), |total, extra| {
+            total.checked_add(fee(extra)?).ok()
+        })?;
+
+    let net_return = return_amount
+        .checked_sub(protocol_fee_amount)
+        .and_then(|amount| amount.checked_sub(swap_fee_amount))
+        .and_then(|amount| amount.checked_sub(burn_fee_amount))
+        .and_then(|amount| amount.checked_sub(extra_fees_amount))
+        .ok()?;
+
+    Some(SimulationResponse {
+        return_amount: net_return.try_into().ok()?,
+        slippage_amount: slippage_amount.try_into().ok()?,
+        swap_fee_amount: swap_fee_amount.try_into().ok()?,
+        protocol_fee_amount: protocol_fee_amount.try_into().ok()?,
+        burn_fee_amount: burn_fee_amount.try_into().ok()?,
+        extra_fees_amount: extra_fees_amount.try_into().ok()?,
+    })
+}
+
+/// Assets refunded for withdrawing `lp_amount` LP tokens from a pool
+///
+/// Mirrors the pool manager's `withdraw_liquidity`: each reserve is multiplied by
+/// the withdrawn share of the LP supply, rounded down, and assets refunding
+/// nothing are left out. The pool manager charges no fee on withdrawals.
+///
+/// Returns `None` if `lp_amount` is zero or exceeds the pool's LP supply.
+pub fn withdrawal_refund(pool: &PoolInfoResponse, lp_amount: Uint128) -> Option<Vec<Coin>> {
+    let total_share = pool.total_share.amount;
+    if lp_amount.is_zero() || lp_amount > total_share {
+        return None;
+    }
+
+    let share_ratio = Decimal256::checked_from_ratio(lp_amount, total_share).ok()?;
+    pool.pool_info
+        .assets
+        .iter()
+        .map(|reserve| {
+            let refund = Decimal256::from_ratio(reserve.amount, 1u8)
+                .checked_mul(share_ratio)
+                .ok()?
+                .to_uint_floor();
+            Some(Coin {
+                denom: reserve.denom.clone(),
+                amount: refund.try_into().ok()?,
+            })
+        })
+        .filter(|coin| coin.as_ref().is_none_or(|coin| !coin.amount.is_zero()))
+        .collect()
+}
+
+/// Base units to a decimal amount with `precision` decimals
+fn with_precision(amount: Uint128, precision: u8) -> Option<Decimal256> {
+    let scale = Uint256::from(10u8).checked_pow(precision.into()).ok()?;
+    Decimal256::checked_from_ratio(amount, scale).ok()
+}
+
+/// Decimal amount to base units with `precision` decimals, rounded down
+fn to_base_units(amount: Decimal256, precision: u8) -> Option<Uint256> {
+    let scale = Uint256::from(10u8).checked_pow(precision.into()).ok()?;
+    Some(scale.mul_floor(amount))
+}
@@ -375,6 +375,8 @@ impl Default for McpClientWrapper {
             default_gas_price: 0.01,
             default_gas_adjustment: 1.5,
             native_denom: "uaum".to_string(),
+            fee_denom: None,
+            fee_tokens: Vec::new(),
         };
 
         let config = MantraNetworkConfig::from_constants(&testnet_constants)
@@ -384,10 +386,18 @@ impl Default for McpClientWrapper {
                     network_name: testnet_constants.network_name.clone(),
                     chain_id: testnet_constants.chain_id.clone(),
                     rpc_url: testnet_constants.default_rpc.clone(),
+                    fallback_rpc_urls: Vec::new(),
                     gas_price: testnet_constants.default_gas_price,
                     gas_adjustment: testnet_constants.default_gas_adjustment,
                     native_denom: testnet_constants.native_denom.clone(),
+                    fee_denom: testnet_constants.fee_denom.clone(),
+                    fee_tokens: testnet_constants.fee_tokens.clone(),
                     contracts: crate::config::ContractAddresses::default(),
+                    timeouts: Default::default(),
+                    circuit_breaker: Default::default(),
+                    endpoint_selection: Default::default(),
+                    broadcast_retry: Default::default(),
+                    priority_fee: Default::default(),
                 }
             });
 
@@ -412,6 +422,8 @@ mod tests {
             default_gas_price: 0.01,
             default_gas_adjustment: 1.5,
             native_denom: "uaum".to_string(),
+            fee_denom: None,
+            fee_tokens: Vec::new(),
         };
         let config = MantraNetworkConfig::from_constants(&testnet_constants)
             .expect("Failed to create network config in test");
@@ -10,14 +10,30 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use chrono;
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 use cosmwasm_std::{Coin, Decimal, Uint128};
 
 use serde_json::Value;
-use tokio::sync::{Mutex, RwLock, Semaphore};
+use tokio::sync::{broadcast, Mutex, RwLock, Semaphore};
 use tracing::{debug, error, info, warn};
 
-use crate::client::MantraDexClient;
-use crate::config::MantraNetworkConfig;
+use crate::client::{
+    CreatePoolOutcome, MantraDexClient, MantraDexClientBuilder, SwapRequest, TxProgress,
+};
+use crate::compare::PoolRanking;
+use crate::config::{AssetFilterConfig, Config, MantraNetworkConfig};
+use crate::convert;
+use crate::earn::DEFAULT_LOOKBACK_BLOCKS;
+use crate::gas::GasHistory;
+use crate::indexer::{self, Indexer};
+use crate::pagination::{PageCursor, DEFAULT_PAGE_LIMIT};
+use crate::policy::SlippageBumpPolicy;
+use crate::risk::{RiskReport, RiskThresholds};
+use crate::route_cache::RouteCache;
+use crate::secret::SecretString;
+use crate::simulation;
+use crate::storage;
+use crate::token_registry::TokenRegistry;
 use crate::wallet::{MantraWallet, WalletInfo};
 
 use super::server::{McpResult, McpServerError};
@@ -128,8 +144,11 @@ impl NetworkConnectionPool {
         }
     }
 
-    /// Get a connection from the pool or create a new one
-    async fn get_connection(&mut self) -> McpResult<MantraDexClient> {
+    /// Get a connection from the pool or create a new one from `builder`
+    async fn get_connection(
+        &mut self,
+        builder: MantraDexClientBuilder,
+    ) -> McpResult<MantraDexClient> {
         // First, try to get a healthy, non-expired connection from the pool
         for (_index, pooled_conn) in self.connections.iter_mut().enumerate() {
             if pooled_conn.is_healthy
@@ -143,7 +162,7 @@ impl NetworkConnectionPool {
                 );
                 // Since MantraDexClient can't be cloned, we need to create a new client
                 // with the same configuration. This is a limitation of the current SDK design.
-                return self.create_new_client().await;
+                return self.create_new_client(builder).await;
             }
         }
 
@@ -156,7 +175,7 @@ impl NetworkConnectionPool {
         })?;
 
         // Create a new client
-        let client = self.create_new_client().await?;
+        let client = self.create_new_client(builder).await?;
 
         // Add to pool for tracking purposes (even though we can't reuse the exact instance)
         let pooled_conn = PooledConnection::new(
@@ -176,14 +195,17 @@ impl NetworkConnectionPool {
         Ok(client)
     }
 
-    /// Create a new client for the network
-    async fn create_new_client(&self) -> McpResult<MantraDexClient> {
+    /// Create a new client for the network from `builder`
+    async fn create_new_client(
+        &self,
+        builder: MantraDexClientBuilder,
+    ) -> McpResult<MantraDexClient> {
         debug!(
             "Creating new DEX client for network: {}",
             self.network_config.chain_id
         );
 
-        match MantraDexClient::new(self.network_config.clone()).await {
+        match builder.build().await {
             Ok(client) => {
                 info!(
                     "Successfully created DEX client for network: {}",
@@ -248,6 +270,21 @@ impl NetworkConnectionPool {
     }
 }
 
+/// Number of transaction progress updates buffered for slow subscribers
+const TX_PROGRESS_CAPACITY: usize = 64;
+
+/// Pools screened for risk at the same time when listing pools
+const RISK_SCREENING_CONCURRENCY: usize = 4;
+
+/// Pools returned by `get_top_pools` when no limit is given
+const TOP_POOLS_DEFAULT_LIMIT: u64 = 10;
+
+/// Largest limit `get_top_pools` accepts
+const TOP_POOLS_MAX_LIMIT: u64 = 100;
+
+/// Longest volume window the pool analytics tools scan, a week of blocks
+const ANALYTICS_MAX_LOOKBACK_BLOCKS: u64 = 7 * DEFAULT_LOOKBACK_BLOCKS;
+
 /// MCP SDK adapter for connection management and wallet state
 #[derive(Debug)]
 pub struct McpSdkAdapter {
@@ -269,11 +306,44 @@ pub struct McpSdkAdapter {
     active_wallet_instance: Arc<Mutex<Option<MantraWallet>>>,
     /// Cache for wallet address to derivation index mappings
     wallet_derivation_cache: Arc<RwLock<HashMap<String, u32>>>,
+    /// Transaction progress shared by every client handed out
+    tx_progress: broadcast::Sender<TxProgress>,
+    /// Gas history shared by every client handed out, persisted across restarts
+    gas_history: Arc<GasHistory>,
+    /// Token metadata shared by every client handed out, persisted across restarts
+    token_registry: Arc<TokenRegistry>,
+    /// Saved configuration every client handed out applies
+    settings: Config,
+    /// External indexer every client handed out reads history from, from the saved configuration
+    indexer: Option<Arc<dyn Indexer>>,
+    /// Whether every client handed out only simulates transactions
+    simulate_only: bool,
+    /// Route cache shared by every client handed out, if enabled in the saved configuration
+    route_cache: Option<Arc<RouteCache>>,
 }
 
 impl McpSdkAdapter {
     /// Create a new MCP SDK adapter with connection pooling
     pub fn new(config: ConnectionPoolConfig) -> Self {
+        let gas_history = storage::shared().and_then(GasHistory::open_in).unwrap_or_else(|e| {
+            warn!("Failed to load gas history, starting empty: {}", e);
+            GasHistory::default()
+        });
+        let token_registry = storage::shared()
+            .and_then(TokenRegistry::open_in)
+            .unwrap_or_else(|e| {
+                warn!("Failed to load token registry, starting empty: {}", e);
+                TokenRegistry::default()
+            });
+        let settings = Config::load(&Config::default_path()).unwrap_or_default();
+        let indexer = indexer::from_config(&settings.indexer).unwrap_or_else(|e| {
+            warn!("Ignoring invalid indexer configuration: {}", e);
+            None
+        });
+        let route_cache = settings
+            .routing
+            .route_cache
+            .map(|config| Arc::new(RouteCache::new(config)));
         let adapter = Self {
             connection_pools: Arc::new(RwLock::new(HashMap::new())),
             cache_ttl: Duration::from_secs(config.connection_ttl_secs),
@@ -284,11 +354,80 @@ impl McpSdkAdapter {
             active_wallet: Arc::new(Mutex::new(None)),
             active_wallet_instance: Arc::new(Mutex::new(None)),
             wallet_derivation_cache: Arc::new(RwLock::new(HashMap::new())),
+            tx_progress: broadcast::channel(TX_PROGRESS_CAPACITY).0,
+            gas_history: Arc::new(gas_history),
+            token_registry: Arc::new(token_registry),
+            settings,
+            indexer,
+            simulate_only: false,
+            route_cache,
         };
 
         adapter
     }
 
+    /// Channel every client handed out reports transaction progress on
+    pub fn tx_progress_sender(&self) -> broadcast::Sender<TxProgress> {
+        self.tx_progress.clone()
+    }
+
+    /// Gas history every client handed out records to and calibrates from
+    pub fn gas_history(&self) -> Arc<GasHistory> {
+        Arc::clone(&self.gas_history)
+    }
+
+    /// Token metadata every client handed out resolves denoms into
+    pub fn token_registry(&self) -> Arc<TokenRegistry> {
+        Arc::clone(&self.token_registry)
+    }
+
+    /// Denoms and pools every client handed out hides
+    pub fn asset_filter(&self) -> AssetFilterConfig {
+        self.settings.asset_filter.clone()
+    }
+
+    /// External indexer every client handed out reads history from, if configured
+    pub fn indexer(&self) -> Option<Arc<dyn Indexer>> {
+        self.indexer.clone()
+    }
+
+    /// Route cache every client handed out shares, if enabled
+    pub fn route_cache(&self) -> Option<Arc<RouteCache>> {
+        self.route_cache.clone()
+    }
+
+    /// Make every client handed out simulate transactions instead of sending them
+    pub fn with_simulate_only(mut self, simulate_only: bool) -> Self {
+        self.simulate_only = simulate_only;
+        self
+    }
+
+    /// Whether every client handed out only simulates transactions
+    pub fn is_simulate_only(&self) -> bool {
+        self.simulate_only
+    }
+
+    /// Start building a client for `network_config` with the settings shared by
+    /// every client handed out
+    pub fn client_builder(&self, network_config: MantraNetworkConfig) -> MantraDexClientBuilder {
+        let builder = MantraDexClient::builder(network_config)
+            .with_settings(&self.settings)
+            .with_tx_progress(self.tx_progress.clone())
+            .with_gas_history(self.gas_history())
+            .with_token_registry(self.token_registry())
+            .with_indexer(self.indexer())
+            .with_simulate_only(self.simulate_only);
+        match self.route_cache() {
+            Some(cache) => builder.with_shared_route_cache(cache),
+            None => builder,
+        }
+    }
+
+    /// Receive the progress of transactions sent by any client from now on
+    pub fn subscribe_tx_progress(&self) -> broadcast::Receiver<TxProgress> {
+        self.tx_progress.subscribe()
+    }
+
     /// Start the background health check task
     pub async fn start_health_checks(&mut self) {
         let pools = Arc::clone(&self.connection_pools);
@@ -348,7 +487,8 @@ impl McpSdkAdapter {
             McpServerError::Internal(format!("Network pool not found: {}", network_id))
         })?;
 
-        pool.get_connection().await
+        pool.get_connection(self.client_builder(network_config.clone()))
+            .await
     }
 
     /// Get a client with wallet attached
@@ -361,6 +501,79 @@ impl McpSdkAdapter {
         Ok(base_client.with_wallet(wallet))
     }
 
+    /// Mark the response to an execute tool as simulated, if `tx` was only simulated
+    ///
+    /// Clears the transaction hash and explorer link, which don't exist, and adds
+    /// the fee, the wallet's balance changes and its resulting balances.
+    async fn simulated_response(
+        &self,
+        client: &MantraDexClient,
+        tx: &TxResponse,
+        mut response: Value,
+    ) -> McpResult<Value> {
+        if !simulation::is_simulated(tx) {
+            return Ok(response);
+        }
+
+        let address = client
+            .get_wallet_address()
+            .await
+            .ok_or(McpServerError::WalletNotConfigured)?;
+        let fee = client
+            .estimate_fee(tx.gas_wanted.max(0) as u64)
+            .map_err(McpServerError::Sdk)?;
+        let balances = client
+            .get_balances_for_address(&address)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let changes = simulation::balance_changes(&tx.events, &address);
+        let resulting_balances = simulation::resulting_balances(&balances, &changes, Some(&fee));
+
+        for key in ["transaction_hash", "explorer_url"] {
+            if let Some(value) = response.get_mut(key) {
+                *value = Value::Null;
+            }
+        }
+        response["status"] = serde_json::json!("simulated");
+        response["simulated"] = serde_json::json!(true);
+        response["mode"] = serde_json::json!("simulate_only");
+        response["simulation"] = serde_json::json!({
+            "message": "Simulate-only mode: nothing was signed or broadcast",
+            "fee": fee,
+            "balance_changes": changes,
+            "resulting_balances": resulting_balances
+        });
+        Ok(response)
+    }
+
+    /// Add the refund and fee breakdown of a withdrawal to its response, in simulate-only mode
+    ///
+    /// Outside simulate-only mode the response is returned unchanged.
+    async fn with_withdrawal_preview(
+        &self,
+        client: &MantraDexClient,
+        pool_id: &str,
+        lp_amount: Uint128,
+        mut response: Value,
+    ) -> McpResult<Value> {
+        if !self.simulate_only {
+            return Ok(response);
+        }
+        let preview = client
+            .preview_withdraw_liquidity(pool_id, lp_amount)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        response["withdrawal_preview"] = serde_json::json!({
+            "lp_amount": preview.lp_amount,
+            "pool_share": preview.share,
+            "assets": preview.assets,
+            "withdrawal_fee": [],
+            "transaction_fee": preview.gas.as_ref().map(|gas| &gas.fee),
+            "gas_limit": preview.gas.as_ref().map(|gas| gas.gas_limit)
+        });
+        Ok(response)
+    }
+
     /// Execute with retry logic
     pub async fn execute_with_retry<F, T>(&self, operation: F) -> McpResult<T>
     where
@@ -463,13 +676,13 @@ impl McpSdkAdapter {
         }
 
         // Try to recreate wallet from environment mnemonic using cached derivation index
-        if let Ok(mnemonic) = env::var("WALLET_MNEMONIC") {
-            if !mnemonic.trim().is_empty() {
+        if let Ok(mnemonic) = env::var("WALLET_MNEMONIC").map(SecretString::new) {
+            if !mnemonic.expose_secret().trim().is_empty() {
                 if let Some(active_addr) = &active_address {
                     // Check cache for derivation index
                     let cache = self.wallet_derivation_cache.read().await;
                     if let Some(&derivation_index) = cache.get(active_addr) {
-                        match MantraWallet::from_mnemonic(&mnemonic, derivation_index) {
+                        match MantraWallet::from_mnemonic(mnemonic.expose_secret(), derivation_index) {
                             Ok(wallet) => {
                                 debug!("Recreated active wallet instance from WALLET_MNEMONIC using cached index {}", derivation_index);
                                 return Ok(Some(wallet));
@@ -480,7 +693,7 @@ impl McpSdkAdapter {
                         }
                     } else {
                         // Fallback to index 0 for backward compatibility if no cache entry exists
-                        match MantraWallet::from_mnemonic(&mnemonic, 0) {
+                        match MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0) {
                             Ok(wallet) => {
                                 debug!("Recreated active wallet instance from WALLET_MNEMONIC using fallback index 0");
                                 return Ok(Some(wallet));
@@ -659,8 +872,8 @@ impl McpSdkAdapter {
         }
 
         // Get environment mnemonic
-        let mnemonic = match env::var("WALLET_MNEMONIC") {
-            Ok(m) if !m.trim().is_empty() => m,
+        let mnemonic = match env::var("WALLET_MNEMONIC").map(SecretString::new) {
+            Ok(m) if !m.expose_secret().trim().is_empty() => m,
             _ => {
                 debug!("No valid WALLET_MNEMONIC found in environment for address: {}", address);
                 return Ok(None);
@@ -671,7 +884,7 @@ impl McpSdkAdapter {
         {
             let cache = self.wallet_derivation_cache.read().await;
             if let Some(&derivation_index) = cache.get(address) {
-                match MantraWallet::from_mnemonic(&mnemonic, derivation_index) {
+                match MantraWallet::from_mnemonic(mnemonic.expose_secret(), derivation_index) {
                     Ok(wallet) => {
                         if wallet.info().address == address {
                             debug!("Retrieved wallet from cache at index {} for address {}", derivation_index, address);
@@ -701,7 +914,7 @@ impl McpSdkAdapter {
         // Search with configurable upper bound to prevent infinite derivation
         let max_index = self.config.max_wallet_derivation_index;
         for index in 0..=max_index {
-            match MantraWallet::from_mnemonic(&mnemonic, index) {
+            match MantraWallet::from_mnemonic(mnemonic.expose_secret(), index) {
                 Ok(wallet) => {
                     if wallet.info().address == address {
                         debug!("Found wallet at derivation index {} for address {}", index, address);
@@ -769,11 +982,21 @@ impl McpSdkAdapter {
             wallet_address
         );
 
+        // Resolve symbols and decimals; unresolved denoms fall back to the
+        // registry's guesses
+        let denoms: Vec<String> = balances.iter().map(|coin| coin.denom.clone()).collect();
+        if let Err(e) = client.resolve_tokens(&denoms).await {
+            warn!("Failed to resolve token metadata: {}", e);
+        }
+        let registry = client.token_registry();
+
         // Convert to JSON format
         let balance_json: Vec<Value> = balances
             .into_iter()
             .map(|coin| {
                 serde_json::json!({
+                    "symbol": registry.symbol(&coin.denom),
+                    "decimals": registry.decimals(&coin.denom),
                     "denom": coin.denom,
                     "amount": coin.amount.to_string()
                 })
@@ -883,8 +1106,9 @@ impl McpSdkAdapter {
         let client = self.get_client(&network_config).await?;
         
         // Get available pools
-        let pools = client.get_pools(Some(10)).await
-            .map_err(|e| McpServerError::Sdk(e))?;
+        let pools = client.get_pools(&PageCursor::first(10)).await
+            .map_err(McpServerError::Sdk)?
+            .items;
         
         if pools.is_empty() {
             return Err(McpServerError::InvalidArguments("No pools available".to_string()));
@@ -964,7 +1188,7 @@ impl McpSdkAdapter {
         );
 
         // Format the response
-        Ok(serde_json::json!({
+        let response = serde_json::json!({
             "status": "success",
             "transaction_hash": swap_result.txhash,
             "swap_details": {
@@ -978,7 +1202,8 @@ impl McpSdkAdapter {
             },
             "block_height": swap_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339()
-        }))
+        });
+        self.simulated_response(&client, &swap_result, response).await
     }
 
     /// Provide liquidity with string parameters (for script execution)
@@ -1117,7 +1342,7 @@ impl McpSdkAdapter {
         );
 
         // Format the response
-        Ok(serde_json::json!({
+        let response = serde_json::json!({
             "status": "success",
             "transaction_hash": withdraw_result.txhash,
             "explorer_url": format!("https://explorer.mantrachain.io/mantra-dukong/tx/{}", withdraw_result.txhash),
@@ -1130,7 +1355,12 @@ impl McpSdkAdapter {
             "block_height": withdraw_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "events": withdraw_result.events
-        }))
+        });
+        let response = self
+            .simulated_response(&client, &withdraw_result, response)
+            .await?;
+        self.with_withdrawal_preview(&client, &pool_id, lp_amount_uint, response)
+            .await
     }
 
     /// Create a pool with string parameters (for script execution)
@@ -1487,8 +1717,31 @@ impl McpSdkAdapter {
             .await
             .map_err(|e| McpServerError::Sdk(e))?;
 
-        // Convert pool info to JSON format
-        let pool_data = serde_json::json!({
+        Ok(Self::pool_json(&pool_info))
+    }
+
+    /// Get a pool with its creator, creation block and LP concentration
+    pub async fn get_pool_with_ownership(&self, pool_id: &str) -> McpResult<Value> {
+        if pool_id.is_empty() {
+            return Err(McpServerError::InvalidArguments(
+                "pool_id cannot be empty".to_string(),
+            ));
+        }
+
+        let client = self
+            .get_client(&self.get_default_network_config().await?)
+            .await?;
+        let pool_info = client.get_pool(pool_id).await.map_err(McpServerError::Sdk)?;
+        let ownership = client.get_pool_ownership(&pool_info).await;
+
+        let mut pool_data = Self::pool_json(&pool_info);
+        pool_data["ownership"] = serde_json::to_value(&ownership)?;
+        Ok(pool_data)
+    }
+
+    /// Convert pool info to JSON format
+    fn pool_json(pool_info: &mantra_dex_std::pool_manager::PoolInfoResponse) -> Value {
+        serde_json::json!({
             "pool_id": pool_info.pool_info.pool_identifier,
             "pool_type": match pool_info.pool_info.pool_type {
                 mantra_dex_std::pool_manager::PoolType::ConstantProduct => "constant_product",
@@ -1507,9 +1760,7 @@ impl McpSdkAdapter {
             },
             "lp_token_denom": pool_info.pool_info.lp_denom,
             "total_share": pool_info.total_share.to_string()
-        });
-
-        Ok(pool_data)
+        })
     }
 
     pub async fn get_pools(&self, args: Value) -> McpResult<Value> {
@@ -1522,21 +1773,38 @@ impl McpSdkAdapter {
             .get("start_after")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let cursor = match &start_after {
+            Some(key) => PageCursor::after(key.clone(), limit),
+            None => PageCursor::first(limit),
+        };
 
         // Get network config and client
         let network_config = self.get_default_network_config().await?;
         let client = self.get_client(&network_config).await?;
 
         // Execute the pools query directly (without retry for now due to client not being Clone)
-        let pools_result = client
-            .get_pools(limit)
+        let page = client
+            .get_pools(&cursor)
             .await
             .map_err(|e| McpServerError::Sdk(e))?;
+        let next_start_after = page.next.and_then(|next| next.start_after);
+        let pools_result = page.items;
+
+        // Screen the pools for red flags so agents see the warnings with the listing
+        let risk_reports = client
+            .assess_pools_risk(
+                &pools_result,
+                &RiskThresholds::default(),
+                RISK_SCREENING_CONCURRENCY,
+            )
+            .await;
 
         // Convert pools to JSON format
         let pools_json: Vec<Value> = pools_result
             .into_iter()
-            .map(|pool| {
+            .zip(risk_reports)
+            .map(|(pool, risk)| {
                 serde_json::json!({
                     "pool_id": pool.pool_info.pool_identifier,
                     "pool_type": match pool.pool_info.pool_type {
@@ -1555,7 +1823,9 @@ impl McpSdkAdapter {
                         "deposits_enabled": pool.pool_info.status.deposits_enabled,
                         "withdrawals_enabled": pool.pool_info.status.withdrawals_enabled
                     },
-                    "total_share": pool.total_share.to_string()
+                    "total_share": pool.total_share.to_string(),
+                    "risk_level": risk.level(),
+                    "risk_warnings": risk.warnings()
                 })
             })
             .collect();
@@ -1566,10 +1836,139 @@ impl McpSdkAdapter {
             "pools": pools_json,
             "count": pools_json.len(),
             "limit": limit,
-            "start_after": start_after
+            "start_after": start_after,
+            "next_start_after": next_start_after
         }))
     }
 
+    /// Quote denom for the pool analytics tools, USD unless `quote` is given
+    fn analytics_quote(args: &Value) -> String {
+        args.get("quote")
+            .and_then(|v| v.as_str())
+            .unwrap_or(convert::USD)
+            .to_string()
+    }
+
+    /// Volume window for the pool analytics tools, a day of blocks by default and a week at most
+    fn analytics_lookback(args: &Value) -> u64 {
+        args.get("lookback_blocks")
+            .and_then(|v| v.as_u64())
+            .filter(|blocks| *blocks > 0)
+            .unwrap_or(DEFAULT_LOOKBACK_BLOCKS)
+            .min(ANALYTICS_MAX_LOOKBACK_BLOCKS)
+    }
+
+    /// Analytics snapshot cached under `key`, computed by `compute` when missing or expired
+    ///
+    /// Pool analytics scan every pool and the recent swap history, so repeated reads
+    /// within the cache TTL are served from the last snapshot.
+    async fn cached_analytics<F>(&self, key: String, compute: F) -> McpResult<Value>
+    where
+        F: std::future::Future<Output = McpResult<Value>>,
+    {
+        if let Some(snapshot) = self.cache_get(&key).await {
+            return Ok(snapshot);
+        }
+        let snapshot = compute.await?;
+        self.cache_set(key, snapshot.clone()).await;
+        Ok(snapshot)
+    }
+
+    pub async fn get_pool_tvl(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Getting pool TVL with args: {:?}", args);
+
+        let pool_id = args
+            .get("pool_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("pool_id is required".to_string()))?;
+        let quote = Self::analytics_quote(&args);
+
+        let key = format!("pool_tvl:{}:{}", pool_id, quote);
+        self.cached_analytics(key, async {
+            let network_config = self.get_default_network_config().await?;
+            let client = self.get_client(&network_config).await?;
+            let tvl = client
+                .pool_tvl(pool_id, &quote)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            Ok(serde_json::to_value(&tvl)?)
+        })
+        .await
+    }
+
+    pub async fn get_pool_volume_24h(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Getting pool 24h volume with args: {:?}", args);
+
+        let pool_id = args
+            .get("pool_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("pool_id is required".to_string()))?;
+        let quote = Self::analytics_quote(&args);
+        let lookback_blocks = Self::analytics_lookback(&args);
+
+        let key = format!("pool_volume:{}:{}:{}", pool_id, quote, lookback_blocks);
+        self.cached_analytics(key, async {
+            let network_config = self.get_default_network_config().await?;
+            let client = self.get_client(&network_config).await?;
+            let comparison = client
+                .compare_pools(&[pool_id.to_string()], &quote, None, lookback_blocks)
+                .await
+                .map_err(McpServerError::Sdk)?
+                .pop()
+                .ok_or_else(|| {
+                    McpServerError::InvalidArguments(format!("Pool {} not found", pool_id))
+                })?;
+
+            Ok(serde_json::json!({
+                "pool_id": comparison.pool_id,
+                "quote": comparison.quote,
+                "volume_24h": comparison.volume_24h,
+                "tvl": comparison.tvl,
+                "fee_apr": comparison.fee_apr,
+                "lookback_blocks": lookback_blocks
+            }))
+        })
+        .await
+    }
+
+    pub async fn get_top_pools(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Getting top pools with args: {:?}", args);
+
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(TOP_POOLS_DEFAULT_LIMIT)
+            .clamp(1, TOP_POOLS_MAX_LIMIT) as usize;
+        let sort_by = match args.get("sort_by").and_then(|v| v.as_str()) {
+            Some(sort_by) => PoolRanking::from_str(sort_by)
+                .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?,
+            None => PoolRanking::default(),
+        };
+        let quote = Self::analytics_quote(&args);
+        let lookback_blocks = Self::analytics_lookback(&args);
+
+        let key = format!(
+            "top_pools:{}:{:?}:{}:{}",
+            quote, sort_by, limit, lookback_blocks
+        );
+        self.cached_analytics(key, async {
+            let network_config = self.get_default_network_config().await?;
+            let client = self.get_client(&network_config).await?;
+            let pools = client
+                .top_pools(&quote, sort_by, limit, lookback_blocks)
+                .await
+                .map_err(McpServerError::Sdk)?;
+
+            Ok(serde_json::json!({
+                "quote": quote,
+                "sort_by": sort_by,
+                "count": pools.len(),
+                "pools": pools
+            }))
+        })
+        .await
+    }
+
     pub async fn validate_pool_status(
         &self,
         pool_id: &str,
@@ -1830,7 +2229,7 @@ impl McpSdkAdapter {
         );
 
         // Format the response
-        Ok(serde_json::json!({
+        let response = serde_json::json!({
             "status": "success",
             "transaction_hash": liquidity_result.txhash,
             "explorer_url": format!("https://explorer.mantrachain.io/mantra-dukong/tx/{}", liquidity_result.txhash),
@@ -1845,7 +2244,8 @@ impl McpSdkAdapter {
             "block_height": liquidity_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "events": liquidity_result.events
-        }))
+        });
+        self.simulated_response(&client, &liquidity_result, response).await
     }
 
     pub async fn provide_liquidity_unchecked(&self, args: Value) -> McpResult<Value> {
@@ -1905,7 +2305,7 @@ impl McpSdkAdapter {
         );
 
         // Format the response
-        Ok(serde_json::json!({
+        let response = serde_json::json!({
             "status": "success",
             "transaction_hash": withdraw_result.txhash,
             "explorer_url": format!("https://explorer.mantrachain.io/mantra-dukong/tx/{}", withdraw_result.txhash),
@@ -1918,7 +2318,12 @@ impl McpSdkAdapter {
             "block_height": withdraw_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "events": withdraw_result.events
-        }))
+        });
+        let response = self
+            .simulated_response(&client, &withdraw_result, response)
+            .await?;
+        self.with_withdrawal_preview(&client, pool_id, lp_amount, response)
+            .await
     }
 
     pub async fn get_liquidity_positions(&self, args: Value) -> McpResult<Value> {
@@ -2052,6 +2457,26 @@ impl McpSdkAdapter {
             .and_then(|v| v.as_str())
             .and_then(|s| Decimal::from_str(s).ok());
 
+        // Parse optional force flag (bypasses the trade size guard)
+        let force = args.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        // Parse optional slippage retry settings
+        let auto_retry_slippage = args
+            .get("auto_retry_slippage")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if auto_retry_slippage && force {
+            return Err(McpServerError::InvalidArguments(
+                "auto_retry_slippage cannot be combined with force".to_string(),
+            ));
+        }
+        let mut retry_policy = SlippageBumpPolicy::default();
+        if let Some(max_retry_slippage) = args.get("max_retry_slippage").and_then(|v| v.as_str()) {
+            retry_policy.max_slippage = Decimal::from_str(max_retry_slippage).map_err(|e| {
+                McpServerError::InvalidArguments(format!("Invalid max_retry_slippage: {}", e))
+            })?;
+        }
+
         // Get wallet (use provided wallet_address or active wallet)
         let wallet = if let Some(wallet_address) = args.get("wallet_address").and_then(|v| v.as_str()) {
             match self.get_wallet_by_address(wallet_address).await? {
@@ -2071,11 +2496,37 @@ impl McpSdkAdapter {
         let network_config = self.get_default_network_config().await?;
         let client = self.get_client_with_wallet(&network_config, wallet).await?;
 
-        // Execute the swap directly (without retry for now due to client not being Clone)
-        let swap_result = client
-            .swap(pool_id, offer_coin, ask_asset_denom, max_slippage)
-            .await
-            .map_err(|e| McpServerError::Sdk(e))?;
+        // Kept to report the expected swap when only simulating
+        let expected_offer = offer_coin.clone();
+
+        // Red flags don't block the swap, they are reported with its result
+        let risk = match client.get_pool(pool_id).await {
+            Ok(pool) => client.assess_pool_risk(&pool, &RiskThresholds::default()).await,
+            Err(_) => RiskReport::default(),
+        };
+
+        // Execute the swap, retrying with a higher tolerance if asked to
+        let (swap_result, max_slippage, failed_slippages) = if auto_retry_slippage {
+            let mut request = SwapRequest::new(pool_id, offer_coin, ask_asset_denom);
+            request.max_slippage = max_slippage;
+            let retry = client
+                .swap_with_slippage_retries(request, &retry_policy)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            (retry.tx, retry.request.max_slippage, retry.failed_slippages)
+        } else {
+            let tx = if force {
+                client
+                    .force_swap(pool_id, offer_coin, ask_asset_denom, max_slippage)
+                    .await
+            } else {
+                client
+                    .swap(pool_id, offer_coin, ask_asset_denom, max_slippage)
+                    .await
+            }
+            .map_err(McpServerError::Sdk)?;
+            (tx, max_slippage, Vec::new())
+        };
 
         info!(
             "Successfully executed swap in pool {} with tx hash: {}",
@@ -2083,7 +2534,7 @@ impl McpSdkAdapter {
         );
 
         // Format the response
-        Ok(serde_json::json!({
+        let mut response = serde_json::json!({
             "status": "success",
             "transaction_hash": swap_result.txhash,
             "explorer_url": format!("https://explorer.mantrachain.io/mantra-dukong/tx/{}", swap_result.txhash),
@@ -2095,13 +2546,26 @@ impl McpSdkAdapter {
                 },
                 "ask_asset_denom": ask_asset_denom,
                 "max_slippage": max_slippage.map(|d| d.to_string()),
+                "forced": force,
+                "failed_slippages": failed_slippages.iter().map(|d| d.to_string()).collect::<Vec<_>>(),
                 "gas_used": swap_result.gas_used,
                 "gas_wanted": swap_result.gas_wanted
             },
+            "risk_level": risk.level(),
+            "risk_warnings": risk.warnings(),
             "block_height": swap_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "events": swap_result.events
-        }))
+        });
+        if simulation::is_simulated(&swap_result) {
+            // Expected return and pool fees, on top of the wallet's balance changes
+            let expected = client
+                .simulate_swap(pool_id, expected_offer, ask_asset_denom)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            response["expected_swap"] = serde_json::json!(expected);
+        }
+        self.simulated_response(&client, &swap_result, response).await
     }
 
     pub async fn get_lp_token_balance(&self, args: Value) -> McpResult<Value> {
@@ -2382,18 +2846,16 @@ impl McpSdkAdapter {
             }));
         }
 
-        // Calculate withdrawal amounts based on pool ratio
-        let total_share = pool_info.total_share;
+        // Calculate withdrawal amounts the way the pool manager refunds them
+        let total_share = pool_info.total_share.clone();
+        let refund = crate::math::withdrawal_refund(&pool_info, lp_amount).unwrap_or_default();
         let mut estimated_amounts = Vec::new();
 
         for asset in &pool_info.pool_info.assets {
-            // Calculate proportional withdrawal amount
-            // withdrawal_amount = (lp_amount / total_share) * asset_amount
-            let withdrawal_amount = if !total_share.amount.is_zero() {
-                asset.amount.multiply_ratio(lp_amount, total_share.amount)
-            } else {
-                Uint128::zero()
-            };
+            let withdrawal_amount = refund
+                .iter()
+                .find(|coin| coin.denom == asset.denom)
+                .map_or(Uint128::zero(), |coin| coin.amount);
 
             estimated_amounts.push(serde_json::json!({
                 "denom": asset.denom,
@@ -2413,6 +2875,7 @@ impl McpSdkAdapter {
             "wallet_address": wallet_address,
             "lp_amount": lp_amount.to_string(),
             "estimated_withdrawal": estimated_amounts,
+            "withdrawal_fee": [],
             "pool_info": {
                 "total_share": total_share.to_string(),
                 "assets": pool_info.pool_info.assets.iter().map(|asset| {
@@ -2528,6 +2991,12 @@ impl McpSdkAdapter {
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
 
+        // Parse optional duplicate override
+        let allow_duplicate = args
+            .get("allow_duplicate")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         // Clone pool_identifier for response formatting
         let pool_identifier_for_response = pool_identifier.clone();
 
@@ -2545,16 +3014,39 @@ impl McpSdkAdapter {
             .map_err(|e| McpServerError::Sdk(e))?;
 
         // Execute pool creation directly (without retry for now due to client not being Clone)
-        let create_result = client
-            .create_pool(
+        let create_result = match client
+            .create_pool_idempotent(
                 asset_denoms,
                 asset_decimals,
                 pool_fees,
                 pool_type,
                 pool_identifier,
+                allow_duplicate,
             )
             .await
-            .map_err(|e| McpServerError::Sdk(e))?;
+            .map_err(McpServerError::Sdk)?
+        {
+            CreatePoolOutcome::Created(tx_response) => tx_response,
+            CreatePoolOutcome::PoolAlreadyExists { pool_id } => {
+                info!("Skipped pool creation, matching pool {} already exists", pool_id);
+                return Ok(serde_json::json!({
+                    "status": "already_exists",
+                    "outcome": "PoolAlreadyExists",
+                    "pool_id": pool_id,
+                    "message": "A pool with the same assets, pool type and fees already exists. Pass allow_duplicate: true to create another one.",
+                    "pool_details": {
+                        "pool_type": pool_type_str,
+                        "assets": assets_json,
+                        "fees": {
+                            "protocol_fee": protocol_fee_str,
+                            "swap_fee": swap_fee_str,
+                            "burn_fee": burn_fee_str
+                        }
+                    },
+                    "timestamp": chrono::Utc::now().to_rfc3339()
+                }));
+            }
+        };
 
         info!(
             "Successfully created pool with tx hash: {}",
@@ -2562,7 +3054,7 @@ impl McpSdkAdapter {
         );
 
         // Format the response
-        Ok(serde_json::json!({
+        let response = serde_json::json!({
             "status": "success",
             "transaction_hash": create_result.txhash,
             "explorer_url": format!("https://explorer.mantrachain.io/mantra-dukong/tx/{}", create_result.txhash),
@@ -2582,7 +3074,8 @@ impl McpSdkAdapter {
             "block_height": create_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "events": create_result.events
-        }))
+        });
+        self.simulated_response(&client, &create_result, response).await
     }
 
     /// Validate tool parameters before execution
@@ -2778,6 +3271,29 @@ mod tests {
         assert_eq!(after_clear, None);
     }
 
+    #[tokio::test]
+    async fn test_analytics_are_capped_and_cached() {
+        let adapter = McpSdkAdapter::default();
+
+        let lookback = serde_json::json!({ "lookback_blocks": u64::MAX });
+        assert_eq!(
+            McpSdkAdapter::analytics_lookback(&lookback),
+            ANALYTICS_MAX_LOOKBACK_BLOCKS
+        );
+
+        // A second read within the TTL is served from the first snapshot
+        let key = "top_pools:usd".to_string();
+        let first = adapter
+            .cached_analytics(key.clone(), async { Ok(serde_json::json!({ "count": 1 })) })
+            .await
+            .unwrap();
+        let second = adapter
+            .cached_analytics(key, async { Ok(serde_json::json!({ "count": 2 })) })
+            .await
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
     #[tokio::test]
     async fn test_cleanup() {
         let adapter = McpSdkAdapter::default();
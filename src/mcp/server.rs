@@ -6,7 +6,13 @@ use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use axum::{extract::State, http::StatusCode, response::Json, routing::post, Router};
+use axum::{
+    extract::{ConnectInfo, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
+    routing::post,
+    Router,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::net::TcpListener;
@@ -23,9 +29,12 @@ use config::{Config, ConfigError, Environment, File, FileFormat};
 // The server implements MCP protocol manually using standard HTTP/JSON-RPC
 // until the rust-mcp-sdk API stabilizes in future versions
 
-use crate::client::MantraDexClient;
+use crate::circuit_breaker::{CircuitState, EndpointHealth};
+use crate::client::{Access, MantraDexClient, TxStage};
 use crate::config::{MantraNetworkConfig, NetworkConstants};
 use crate::error::Error as SdkError;
+use crate::rate_limit::RateLimiter;
+use crate::secret::SecretString;
 use crate::wallet::WalletInfo;
 
 use super::client_wrapper::McpClientWrapper;
@@ -546,6 +555,35 @@ impl JsonRpcResponse {
     }
 }
 
+/// MCP protocol version reported on initialize
+const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Prefix of the per-pool resource URIs, `pool://<pool_id>`
+const POOL_RESOURCE_PREFIX: &str = "pool://";
+
+/// Prefix of the per-pool TVL resource URIs, `pool-tvl://<pool_id>`
+const POOL_TVL_RESOURCE_PREFIX: &str = "pool-tvl://";
+
+/// Prefix of the per-pool 24h volume resource URIs, `pool-volume://<pool_id>`
+const POOL_VOLUME_RESOURCE_PREFIX: &str = "pool-volume://";
+
+/// Top pools by TVL in USD, the public analytics resource
+const TOP_POOLS_RESOURCE: &str = "pools://top";
+
+/// Tools served in public mode: queries over public chain data that need no wallet
+const PUBLIC_TOOLS: &[&str] = &[
+    "get_contract_addresses",
+    "validate_network_connectivity",
+    "get_balances",
+    "get_pools",
+    "get_pool_tvl",
+    "get_pool_volume_24h",
+    "get_top_pools",
+    "get_lp_token_balance",
+    "get_all_lp_token_balances",
+    "estimate_lp_withdrawal_amounts",
+];
+
 // =============================================================================
 // JSON-RPC Error Code Constants
 // =============================================================================
@@ -562,6 +600,7 @@ const NETWORK_CONNECTION_FAILED: i32 = -32002;
 const VALIDATION_ERROR: i32 = -32003;
 const CONFIGURATION_ERROR: i32 = -32004;
 const RESOURCE_NOT_FOUND: i32 = -32005;
+const RATE_LIMITED: i32 = -32006;
 
 // SDK-specific error codes
 const BLOCKCHAIN_RPC_ERROR: i32 = -32100;
@@ -577,6 +616,8 @@ const TOOL_EXECUTION_FAILED: i32 = -32109;
 const FEE_VALIDATION_FAILED: i32 = -32110;
 const TIMEOUT_ERROR: i32 = -32111;
 const IO_ERROR: i32 = -32112;
+const POLICY_VIOLATION: i32 = -32113;
+const SCHEMA_MISMATCH: i32 = -32114;
 
 
 
@@ -763,6 +804,28 @@ pub trait McpServer:
                 let resources = self.get_available_resources();
                 Ok(serde_json::json!({ "resources": resources }))
             }
+            "resources/templates/list" => Ok(serde_json::json!({
+                "resourceTemplates": [
+                    {
+                        "uriTemplate": format!("{}{{pool_id}}", POOL_RESOURCE_PREFIX),
+                        "name": "Pool",
+                        "description": "A pool with its creator, creation block and LP holder concentration",
+                        "mimeType": "application/json"
+                    },
+                    {
+                        "uriTemplate": format!("{}{{pool_id}}", POOL_TVL_RESOURCE_PREFIX),
+                        "name": "Pool TVL",
+                        "description": "A pool's reserves and total value locked in USD",
+                        "mimeType": "application/json"
+                    },
+                    {
+                        "uriTemplate": format!("{}{{pool_id}}", POOL_VOLUME_RESOURCE_PREFIX),
+                        "name": "Pool 24h Volume",
+                        "description": "A pool's swap volume over the last day in USD, with its TVL and fee APR",
+                        "mimeType": "application/json"
+                    }
+                ]
+            })),
             "resources/read" => {
                 if let Some(params) = params {
                     let uri = params.get("uri").and_then(|u| u.as_str()).ok_or_else(|| {
@@ -780,7 +843,7 @@ pub trait McpServer:
                 let mut response = serde_json::Map::new();
                 response.insert(
                     "protocolVersion".to_string(),
-                    serde_json::Value::String("2024-11-05".to_string()),
+                    serde_json::Value::String(MCP_PROTOCOL_VERSION.to_string()),
                 );
                 response.insert("serverInfo".to_string(), self.get_server_info());
                 response.insert("capabilities".to_string(), self.get_capabilities());
@@ -919,6 +982,7 @@ impl McpServerError {
             SdkError::TxBroadcast(_) => TRANSACTION_FAILED,
             SdkError::TxSimulation(_) => TRANSACTION_FAILED,
             SdkError::Tx(_) => TRANSACTION_FAILED,
+            SdkError::Unconfirmed { .. } => TIMEOUT_ERROR,
 
             // Wallet errors with enhanced context detection
             SdkError::Wallet(msg) => {
@@ -958,9 +1022,13 @@ impl McpServerError {
             SdkError::Network(_) => NETWORK_CONNECTION_FAILED,
             SdkError::Timeout(_) => TIMEOUT_ERROR,
 
+            // Client-side policy refusals
+            SdkError::Policy(_) => POLICY_VIOLATION,
+
             // Serialization and IO errors
             SdkError::Serialization(_) => SERIALIZATION_ERROR,
-            SdkError::Io(_) => IO_ERROR,
+            SdkError::SchemaMismatch(_) => SCHEMA_MISMATCH,
+            SdkError::Io(_) | SdkError::Storage(_) => IO_ERROR,
 
             // Generic errors
             SdkError::Other(_) => INTERNAL_ERROR,
@@ -1082,17 +1150,34 @@ impl McpServerError {
                 "Retry with exponential backoff",
                 "Switch to faster RPC endpoint",
             ],
+            SdkError::Unconfirmed { .. } => vec![
+                "Look up the transaction hash before sending it again",
+                "Check network congestion status",
+            ],
             SdkError::Serialization(_) => vec![
                 "Check data format and structure",
                 "Validate JSON syntax",
                 "Ensure all required fields are present",
             ],
+            SdkError::SchemaMismatch(_) => vec![
+                "Check whether the contract was upgraded",
+                "Upgrade the SDK to a release supporting the contract version",
+            ],
             SdkError::Io(_) => vec![
                 "Check file permissions",
                 "Verify file paths are accessible",
                 "Ensure sufficient disk space",
                 "Check directory structure",
             ],
+            SdkError::Storage(_) => vec![
+                "Check the storage backend and path in the configuration",
+                "Ensure no other process holds an exclusive lock on the database",
+                "Ensure sufficient disk space",
+            ],
+            SdkError::Policy(_) => vec![
+                "Review the configured safety limits",
+                "Reduce the operation size or adjust the policy",
+            ],
             SdkError::Other(_) => vec![
                 "Check application logs for details",
                 "Retry the operation",
@@ -1112,9 +1197,13 @@ impl McpServerError {
             SdkError::FeeValidation(_) => "medium",
             SdkError::Network(_) => "medium",
             SdkError::Timeout(_) => "low",
+            SdkError::Unconfirmed { .. } => "high",
             SdkError::Serialization(_) => "medium",
+            SdkError::SchemaMismatch(_) => "high",
             SdkError::Io(_) => "low",
+            SdkError::Storage(_) => "high",
             SdkError::Other(_) => "medium",
+            SdkError::Policy(_) => "medium",
         }
     }
 
@@ -1129,12 +1218,16 @@ impl McpServerError {
             SdkError::Config(_) => "Config",
             SdkError::Contract(_) => "Contract",
             SdkError::Serialization(_) => "Serialization",
+            SdkError::SchemaMismatch(_) => "SchemaMismatch",
             SdkError::Io(_) => "Io",
+            SdkError::Storage(_) => "Storage",
             SdkError::FeeValidation(_) => "FeeValidation",
             SdkError::Other(_) => "Other",
             SdkError::Tx(_) => "Tx",
             SdkError::Network(_) => "Network",
             SdkError::Timeout(_) => "Timeout",
+            SdkError::Unconfirmed { .. } => "Unconfirmed",
+            SdkError::Policy(_) => "Policy",
         }
     }
 
@@ -1203,6 +1296,30 @@ pub struct McpServerConfig {
     pub cache_ttl_secs: u64,
     /// Whether to auto-load .env file
     pub auto_load_env: bool,
+    /// Whether execute tools only simulate transactions, never signing or broadcasting them
+    #[serde(default)]
+    pub simulate_only: bool,
+    /// Serve only public data: no wallet is loaded and only query tools are offered
+    #[serde(default)]
+    pub public_mode: bool,
+    /// Requests a minute each client may make over HTTP in public mode
+    #[serde(default = "default_rate_limit_per_minute")]
+    pub rate_limit_per_minute: u32,
+    /// Requests a client may make at once before the per-minute rate applies
+    #[serde(default = "default_rate_limit_burst")]
+    pub rate_limit_burst: u32,
+    /// Identify clients by the first `X-Forwarded-For` address rather than the
+    /// connecting one; only enable behind a proxy that sets the header
+    #[serde(default)]
+    pub trust_forwarded_for: bool,
+}
+
+fn default_rate_limit_per_minute() -> u32 {
+    60
+}
+
+fn default_rate_limit_burst() -> u32 {
+    20
 }
 
 impl Default for McpServerConfig {
@@ -1218,6 +1335,11 @@ impl Default for McpServerConfig {
             request_timeout_secs: 30,
             cache_ttl_secs: 300,
             auto_load_env: true,
+            simulate_only: false,
+            public_mode: false,
+            rate_limit_per_minute: default_rate_limit_per_minute(),
+            rate_limit_burst: default_rate_limit_burst(),
+            trust_forwarded_for: false,
         }
     }
 }
@@ -1235,6 +1357,11 @@ impl McpServerConfig {
     /// - MCP_REQUEST_TIMEOUT_SECS: Request timeout in seconds
     /// - MCP_CACHE_TTL_SECS: Cache TTL in seconds
     /// - MCP_AUTO_LOAD_ENV: Auto-load .env file (true/false)
+    /// - MCP_SIMULATE_ONLY: Only simulate execute tools (true/false)
+    /// - MCP_PUBLIC_MODE: Serve only public data, without a wallet (true/false)
+    /// - MCP_RATE_LIMIT_PER_MINUTE: Requests a minute per client in public mode
+    /// - MCP_RATE_LIMIT_BURST: Burst of requests per client in public mode
+    /// - MCP_TRUST_FORWARDED_FOR: Key rate limits on X-Forwarded-For (true/false)
     /// - MANTRA_NETWORK: Network name (mainnet/testnet)
     pub fn from_env() -> McpResult<Self> {
         // Load .env file if auto-load is enabled (check env var first)
@@ -1287,6 +1414,28 @@ impl McpServerConfig {
             config.cache_ttl_secs = cache_ttl_str.parse().unwrap_or(300);
         }
 
+        if let Ok(simulate_only_str) = env::var("MCP_SIMULATE_ONLY") {
+            config.simulate_only = simulate_only_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(public_mode_str) = env::var("MCP_PUBLIC_MODE") {
+            config.public_mode = public_mode_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(rate_limit_str) = env::var("MCP_RATE_LIMIT_PER_MINUTE") {
+            config.rate_limit_per_minute = rate_limit_str
+                .parse()
+                .unwrap_or(default_rate_limit_per_minute());
+        }
+
+        if let Ok(burst_str) = env::var("MCP_RATE_LIMIT_BURST") {
+            config.rate_limit_burst = burst_str.parse().unwrap_or(default_rate_limit_burst());
+        }
+
+        if let Ok(trust_str) = env::var("MCP_TRUST_FORWARDED_FOR") {
+            config.trust_forwarded_for = trust_str.parse().unwrap_or(false);
+        }
+
         config.auto_load_env = auto_load_env;
 
         // Load network configuration
@@ -1362,6 +1511,11 @@ impl McpServerConfig {
             ));
         }
 
+        if self.public_mode && (self.rate_limit_per_minute == 0 || self.rate_limit_burst == 0) {
+            return Err(McpServerError::Validation(
+                "Public mode rate limit and burst must be greater than 0".to_string(),
+            ));
+        }
 
         Ok(())
     }
@@ -1438,6 +1592,11 @@ impl McpServerConfig {
             .set_default("request_timeout_secs", 30)?
             .set_default("cache_ttl_secs", 300)?
             .set_default("auto_load_env", true)?
+            .set_default("simulate_only", false)?
+            .set_default("public_mode", false)?
+            .set_default("rate_limit_per_minute", 60)?
+            .set_default("rate_limit_burst", 20)?
+            .set_default("trust_forwarded_for", false)?
             // Add file source
             .add_source(File::new(path.to_str().unwrap(), file_format))
             // Add environment variable overrides with MCP_ prefix
@@ -1542,7 +1701,12 @@ impl McpServerConfig {
             .set_default("http_host", "127.0.0.1")?
             .set_default("request_timeout_secs", 30)?
             .set_default("cache_ttl_secs", 300)?
-            .set_default("auto_load_env", true)?;
+            .set_default("auto_load_env", true)?
+            .set_default("simulate_only", false)?
+            .set_default("public_mode", false)?
+            .set_default("rate_limit_per_minute", 60)?
+            .set_default("rate_limit_burst", 20)?
+            .set_default("trust_forwarded_for", false)?;
 
         // Add file source if provided
         if let Some(path) = config_file {
@@ -1624,6 +1788,17 @@ http_port = {}
 # Environment file loading
 auto_load_env = {}
 
+# Run execute tools in simulation only: nothing is signed or broadcast
+simulate_only = {}
+
+# Public-data mode for hosted deployments: no wallet is loaded, only query
+# tools and public resources are served, and HTTP clients are rate limited
+public_mode = {}
+rate_limit_per_minute = {}
+rate_limit_burst = {}
+# Key rate limits on X-Forwarded-For; only enable behind a trusted proxy
+trust_forwarded_for = {}
+
 # Async runtime configuration
 [runtime_config]
 # Runtime flavor: "CurrentThread" or "MultiThread"
@@ -1652,7 +1827,12 @@ thread_keep_alive = 10
             example_config.cache_ttl_secs,
             example_config.http_host,
             example_config.http_port,
-            example_config.auto_load_env
+            example_config.auto_load_env,
+            example_config.simulate_only,
+            example_config.public_mode,
+            example_config.rate_limit_per_minute,
+            example_config.rate_limit_burst,
+            example_config.trust_forwarded_for
         )
     }
 
@@ -1768,11 +1948,14 @@ pub struct McpServerStateData {
     pub logger: Arc<McpLogger>,
     /// Transaction monitor manager
     pub transaction_monitor_manager: Arc<TransactionMonitorManager>,
+    /// When the server state was created, for uptime reporting
+    pub started_at: Instant,
 }
 
 impl McpServerStateData {
     pub fn new(config: McpServerConfig) -> Self {
-        let sdk_adapter = Arc::new(McpSdkAdapter::default());
+        let sdk_adapter =
+            Arc::new(McpSdkAdapter::default().with_simulate_only(config.simulate_only));
 
         // Initialize logging infrastructure
         let logging_config = LoggingConfig::default();
@@ -1791,6 +1974,7 @@ impl McpServerStateData {
             client_wrapper: Arc::new(Mutex::new(None)),
             logger,
             transaction_monitor_manager,
+            started_at: Instant::now(),
         }
     }
 
@@ -1799,7 +1983,12 @@ impl McpServerStateData {
         info!("Attempting to initialize DEX client...");
         debug!("Network config: {:?}", self.config.network_config);
 
-        let client = match MantraDexClient::new(self.config.network_config.clone()).await {
+        let client = match self
+            .sdk_adapter
+            .client_builder(self.config.network_config.clone())
+            .build()
+            .await
+        {
             Ok(client) => {
                 info!("DEX client created successfully");
                 client
@@ -1900,9 +2089,12 @@ impl McpServerStateData {
         &self,
         network_config: MantraNetworkConfig,
     ) -> McpResult<()> {
-        let client = MantraDexClient::new(network_config.clone())
+        let client = self
+            .sdk_adapter
+            .client_builder(network_config.clone())
+            .build()
             .await
-            .map_err(|e| McpServerError::Sdk(e))?;
+            .map_err(McpServerError::Sdk)?;
 
         {
             let mut client_guard = self.client.lock().await;
@@ -1941,8 +2133,57 @@ impl MantraDexMcpServer {
         info!("Initializing Mantra DEX MCP Server");
         self.state.initialize_client().await?;
 
-        // Auto-load wallet from environment if available
-        self.auto_load_wallet_from_env().await?;
+        // Keep measuring RPC endpoint latency, reported by the `server://health` resource
+        let client = self.state.client.clone();
+        let probe_interval = self
+            .state
+            .config
+            .network_config
+            .endpoint_selection
+            .probe_interval();
+        tokio::spawn(async move {
+            loop {
+                if let Some(client) = client.lock().await.as_ref() {
+                    client.probe_endpoints().await;
+                }
+                sleep(probe_interval).await;
+            }
+        });
+
+        // Drop cached routes through pools that moved, for every client sharing the cache
+        if self.state.sdk_adapter.route_cache().is_some() {
+            let watcher = self
+                .state
+                .sdk_adapter
+                .client_builder(self.state.config.network_config.clone())
+                .build()
+                .await
+                .map_err(McpServerError::Sdk)?;
+            tokio::spawn(async move {
+                let watched = watcher
+                    .watch_route_cache(|check| {
+                        match check {
+                            Ok(dropped) if dropped > 0 => {
+                                debug!("Dropped {} cached routes through pools that moved", dropped)
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Failed to check the pools on cached routes: {}", e),
+                        }
+                        Ok(())
+                    })
+                    .await;
+                if let Err(e) = watched {
+                    warn!("Stopped watching the pools on cached routes: {}", e);
+                }
+            });
+        }
+
+        // Auto-load wallet from environment if available; a public server never holds one
+        if self.state.config.public_mode {
+            info!("Public mode: serving public data only, no wallet is loaded");
+        } else {
+            self.auto_load_wallet_from_env().await?;
+        }
 
         // Initialize client wrapper
         let wrapper = McpClientWrapper::new(
@@ -1960,18 +2201,100 @@ impl MantraDexMcpServer {
         self.state.clone()
     }
 
+    /// Structured diagnostics served by the `server://health` resource
+    pub async fn get_server_diagnostics(&self) -> serde_json::Value {
+        let network_config = &self.state.config.network_config;
+        let (endpoints, trade_size_guard) = match self.state.client.lock().await.as_ref() {
+            Some(client) => (
+                Some(client.circuit_breaker().health()),
+                Some(*client.trade_size_guard()),
+            ),
+            None => (None, None),
+        };
+
+        let status = match &endpoints {
+            None => "disconnected",
+            Some(endpoints)
+                if endpoints
+                    .iter()
+                    .any(|endpoint| endpoint.state != CircuitState::Closed) =>
+            {
+                "degraded"
+            }
+            Some(_) => "healthy",
+        };
+
+        let wallet_configured = self.state.active_wallet.lock().await.is_some();
+        let loaded_wallets = self.state.wallets.read().await.len();
+        let server_cache_entries = self.state.cache.read().await.len();
+        let (adapter_cache_entries, adapter_cache_valid) =
+            self.state.sdk_adapter.get_cache_stats().await;
+
+        serde_json::json!({
+            "status": status,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "uptime_secs": self.state.started_at.elapsed().as_secs(),
+            "version": {
+                "server": self.state.config.version,
+                "sdk": env!("CARGO_PKG_VERSION"),
+                "protocol": MCP_PROTOCOL_VERSION
+            },
+            "network": {
+                "name": network_config.network_name,
+                "chain_id": network_config.chain_id,
+                "connected": endpoints.is_some()
+            },
+            "endpoints": endpoints.unwrap_or_else(|| {
+                network_config
+                    .rpc_endpoints()
+                    .into_iter()
+                    .map(|endpoint| EndpointHealth {
+                        endpoint,
+                        state: CircuitState::Closed,
+                        consecutive_failures: 0,
+                        latency_ms: None,
+                        region: None,
+                        block_height: None,
+                        lagging: false,
+                        active: false,
+                    })
+                    .collect()
+            }),
+            "wallet": {
+                "configured": wallet_configured,
+                "loaded_wallets": loaded_wallets,
+                "mode": if self.state.config.public_mode {
+                    "public"
+                } else if wallet_configured {
+                    "signing"
+                } else {
+                    "read_only"
+                }
+            },
+            "cache": {
+                "server_entries": server_cache_entries,
+                "adapter_entries": adapter_cache_entries,
+                "adapter_valid_entries": adapter_cache_valid,
+                "ttl_secs": self.state.config.cache_ttl_secs
+            },
+            "policy": {
+                "trade_size_guard": trade_size_guard.unwrap_or_default()
+            }
+        })
+    }
+
     /// Auto-load wallet from environment variables
     async fn auto_load_wallet_from_env(&self) -> McpResult<()> {
         use crate::wallet::MantraWallet;
         use std::env;
 
         // Check for wallet mnemonic in environment
-        if let Ok(mnemonic) = env::var("WALLET_MNEMONIC") {
-            if !mnemonic.trim().is_empty() {
+        if let Ok(mnemonic) = env::var("WALLET_MNEMONIC").map(SecretString::new) {
+            if !mnemonic.expose_secret().trim().is_empty() {
                 info!("Auto-loading wallet from WALLET_MNEMONIC environment variable");
 
                 // Create wallet from mnemonic (using account index 0)
-                match MantraWallet::from_mnemonic(&mnemonic, 0) {
+                match MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0) {
                     Ok(wallet) => {
                         let wallet_info = wallet.info();
                         let address = wallet_info.address.clone();
@@ -1982,7 +2305,7 @@ impl MantraDexMcpServer {
                             .await?;
 
                         // Create another wallet instance for the SDK adapter (since MantraWallet doesn't implement Clone)
-                        match MantraWallet::from_mnemonic(&mnemonic, 0) {
+                        match MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0) {
                             Ok(adapter_wallet) => {
                                 // Add wallet with derivation index for caching, then set as active
                                 self.state
@@ -2030,7 +2353,9 @@ impl MantraDexMcpServer {
     pub fn get_server_info(&self) -> serde_json::Value {
         serde_json::json!({
             "name": self.state.config.name,
-            "version": self.state.config.version
+            "version": self.state.config.version,
+            "simulateOnly": self.state.config.simulate_only,
+            "publicMode": self.state.config.public_mode
         })
     }
 
@@ -2077,7 +2402,7 @@ impl McpServerLifecycle for MantraDexMcpServer {
 #[async_trait::async_trait]
 impl McpResourceProvider for MantraDexMcpServer {
     fn get_available_resources(&self) -> Vec<serde_json::Value> {
-        vec![
+        let resources = vec![
             serde_json::json!({
                 "uri": "trades://history",
                 "name": "Trading History",
@@ -2096,10 +2421,34 @@ impl McpResourceProvider for MantraDexMcpServer {
                 "description": "Current and historical liquidity positions",
                 "mimeType": "application/json"
             }),
-        ]
+            serde_json::json!({
+                "uri": "server://health",
+                "name": "Server Health",
+                "description": "Structured server diagnostics: network, RPC endpoint health, wallet, cache, policy, uptime and version",
+                "mimeType": "application/json"
+            }),
+            serde_json::json!({
+                "uri": TOP_POOLS_RESOURCE,
+                "name": "Top Pools",
+                "description": "Pools with the most liquidity, ranked by TVL in USD with their 24h volume and APR",
+                "mimeType": "application/json"
+            }),
+        ];
+
+        if !self.state.config.public_mode {
+            return resources;
+        }
+        resources
+            .into_iter()
+            .filter(|resource| resource["uri"].as_str().is_some_and(is_public_resource))
+            .collect()
     }
 
     async fn handle_resource_read(&self, uri: &str) -> McpResult<serde_json::Value> {
+        if self.state.config.public_mode && !is_public_resource(uri) {
+            return Err(McpServerError::UnknownResource(uri.to_string()));
+        }
+
         match uri {
             "trades://history" => Ok(serde_json::json!({
                 "trades": [],
@@ -2116,15 +2465,37 @@ impl McpResourceProvider for MantraDexMcpServer {
                 "total_value": "0",
                 "message": "Liquidity positions resource not available"
             })),
-            _ => Err(McpServerError::UnknownResource(uri.to_string())),
+            "server://health" => Ok(self.get_server_diagnostics().await),
+            TOP_POOLS_RESOURCE => {
+                self.state
+                    .sdk_adapter
+                    .get_top_pools(serde_json::json!({}))
+                    .await
+            }
+            _ => {
+                if let Some(pool_id) = resource_pool_id(uri, POOL_TVL_RESOURCE_PREFIX) {
+                    let args = serde_json::json!({ "pool_id": pool_id });
+                    self.state.sdk_adapter.get_pool_tvl(args).await
+                } else if let Some(pool_id) = resource_pool_id(uri, POOL_VOLUME_RESOURCE_PREFIX) {
+                    let args = serde_json::json!({ "pool_id": pool_id });
+                    self.state.sdk_adapter.get_pool_volume_24h(args).await
+                } else if let Some(pool_id) = resource_pool_id(uri, POOL_RESOURCE_PREFIX) {
+                    self.state.sdk_adapter.get_pool_with_ownership(pool_id).await
+                } else {
+                    Err(McpServerError::UnknownResource(uri.to_string()))
+                }
+            }
         }
     }
 
     fn validate_resource_uri(&self, uri: &str) -> McpResult<()> {
         match uri {
-            "trades://history" | "trades://pending" | "liquidity://positions" => Ok(()),
+            "trades://history" | "trades://pending" | "liquidity://positions"
+            | "server://health"
+            | TOP_POOLS_RESOURCE => Ok(()),
+            _ if is_pool_resource(uri) => Ok(()),
             _ => Err(McpServerError::Validation(format!(
-                "Invalid resource URI: {}. Available resources: trades://history, trades://pending, liquidity://positions",
+                "Invalid resource URI: {}. Available resources: trades://history, trades://pending, liquidity://positions, server://health, pools://top, pool://{{pool_id}}, pool-tvl://{{pool_id}}, pool-volume://{{pool_id}}",
                 uri
             ))),
         }
@@ -2213,6 +2584,153 @@ impl McpResourceProvider for MantraDexMcpServer {
                     }
                 }
             })),
+            "server://health" => Ok(serde_json::json!({
+                "uri": uri,
+                "name": "Server Health",
+                "description": "Structured server diagnostics: network, RPC endpoint health, wallet, cache, policy, uptime and version",
+                "mimeType": "application/json",
+                "available": true,
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "status": {"type": "string", "enum": ["healthy", "degraded", "disconnected"]},
+                        "timestamp": {"type": "string"},
+                        "uptime_secs": {"type": "integer"},
+                        "version": {
+                            "type": "object",
+                            "properties": {
+                                "server": {"type": "string"},
+                                "sdk": {"type": "string"},
+                                "protocol": {"type": "string"}
+                            }
+                        },
+                        "network": {
+                            "type": "object",
+                            "properties": {
+                                "name": {"type": "string"},
+                                "chain_id": {"type": "string"},
+                                "connected": {"type": "boolean"}
+                            }
+                        },
+                        "endpoints": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "endpoint": {"type": "string"},
+                                    "state": {"type": "string", "enum": ["closed", "open", "half_open"]},
+                                    "consecutive_failures": {"type": "integer"}
+                                }
+                            }
+                        },
+                        "wallet": {
+                            "type": "object",
+                            "properties": {
+                                "configured": {"type": "boolean"},
+                                "loaded_wallets": {"type": "integer"},
+                                "mode": {"type": "string", "enum": ["signing", "read_only"]}
+                            }
+                        },
+                        "cache": {"type": "object"},
+                        "policy": {"type": "object"}
+                    }
+                }
+            })),
+            TOP_POOLS_RESOURCE => Ok(serde_json::json!({
+                "uri": uri,
+                "name": "Top Pools",
+                "description": "Pools with the most liquidity, ranked by TVL in USD with their 24h volume and APR",
+                "mimeType": "application/json",
+                "available": true,
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "quote": {"type": "string"},
+                        "sort_by": {"type": "string", "enum": ["tvl", "volume_24h", "apr"]},
+                        "count": {"type": "integer"},
+                        "pools": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "pool_id": {"type": "string"},
+                                    "assets": {"type": "array"},
+                                    "tvl": {"type": ["string", "null"]},
+                                    "volume_24h": {"type": ["string", "null"]},
+                                    "fee_apr": {"type": "string"},
+                                    "incentive_apr": {"type": "string"}
+                                }
+                            }
+                        }
+                    }
+                }
+            })),
+            _ if resource_pool_id(uri, POOL_TVL_RESOURCE_PREFIX).is_some() => {
+                Ok(serde_json::json!({
+                    "uri": uri,
+                    "name": "Pool TVL",
+                    "description": "A pool's reserves and total value locked in USD",
+                    "mimeType": "application/json",
+                    "available": true,
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "pool_id": {"type": "string"},
+                            "quote": {"type": "string"},
+                            "reserves": {"type": "array"},
+                            "tvl": {"type": ["string", "null"]}
+                        }
+                    }
+                }))
+            }
+            _ if resource_pool_id(uri, POOL_VOLUME_RESOURCE_PREFIX).is_some() => {
+                Ok(serde_json::json!({
+                    "uri": uri,
+                    "name": "Pool 24h Volume",
+                    "description": "A pool's swap volume over the last day in USD, with its TVL and fee APR",
+                    "mimeType": "application/json",
+                    "available": true,
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "pool_id": {"type": "string"},
+                            "quote": {"type": "string"},
+                            "volume_24h": {"type": ["string", "null"]},
+                            "tvl": {"type": ["string", "null"]},
+                            "fee_apr": {"type": "string"},
+                            "lookback_blocks": {"type": "integer"}
+                        }
+                    }
+                }))
+            }
+            _ if self.validate_resource_uri(uri).is_ok() => Ok(serde_json::json!({
+                "uri": uri,
+                "name": "Pool",
+                "description": "A pool with its creator, creation block and LP holder concentration",
+                "mimeType": "application/json",
+                "available": true,
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": {"type": "string"},
+                        "pool_type": {"type": "string"},
+                        "assets": {"type": "array"},
+                        "status": {"type": "object"},
+                        "lp_token_denom": {"type": "string"},
+                        "total_share": {"type": "string"},
+                        "ownership": {
+                            "type": "object",
+                            "properties": {
+                                "creator": {"type": ["string", "null"]},
+                                "created_height": {"type": ["integer", "null"]},
+                                "created_at": {"type": ["string", "null"]},
+                                "creation_tx": {"type": ["string", "null"]},
+                                "lp_concentration": {"type": ["object", "null"]}
+                            }
+                        }
+                    }
+                }
+            })),
             _ => Err(McpServerError::UnknownResource(uri.to_string())),
         }
     }
@@ -2281,11 +2799,13 @@ impl McpServerStateManager for MantraDexMcpServer {
     }
 
     async fn get_health_status(&self) -> serde_json::Value {
-        let client_status = if self.state.client.lock().await.is_some() {
-            "connected"
-        } else {
-            "disconnected"
+        let (client_status, rpc_endpoints) = match self.state.client.lock().await.as_ref() {
+            Some(client) => ("connected", client.circuit_breaker().health()),
+            None => ("disconnected", Vec::new()),
         };
+        let degraded = rpc_endpoints
+            .iter()
+            .any(|endpoint| endpoint.state != CircuitState::Closed);
 
         let active_wallet = self.state.active_wallet.lock().await.is_some();
         let wallet_count = self.state.wallets.read().await.len();
@@ -2300,13 +2820,14 @@ impl McpServerStateManager for MantraDexMcpServer {
 
 
         serde_json::json!({
-            "status": "healthy",
+            "status": if degraded { "degraded" } else { "healthy" },
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "components": {
                 "client": {
                     "status": client_status,
                     "network": self.state.config.network_config.chain_id
                 },
+                "rpc_endpoints": rpc_endpoints,
                 "wallet": {
                     "active": active_wallet,
                     "total_wallets": wallet_count
@@ -2325,7 +2846,7 @@ impl McpServerStateManager for MantraDexMcpServer {
 #[async_trait::async_trait]
 impl McpToolProvider for MantraDexMcpServer {
     fn get_available_tools(&self) -> Vec<serde_json::Value> {
-        vec![
+        let tools = vec![
             // Network Tools
             serde_json::json!({
                 "name": "get_contract_addresses",
@@ -2463,7 +2984,7 @@ impl McpToolProvider for MantraDexMcpServer {
             // Pool Query Tools
             serde_json::json!({
                 "name": "get_pools",
-                "description": "Get information about all available liquidity pools with optional filtering and pagination",
+                "description": "Get information about all available liquidity pools with optional filtering and pagination. Each pool carries risk_level and risk_warnings from token risk screening (missing or impersonating metadata, single liquidity provider, low liquidity, freshly created denoms).",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
@@ -2480,9 +3001,81 @@ impl McpToolProvider for MantraDexMcpServer {
                     }
                 }
             }),
+            serde_json::json!({
+                "name": "get_pool_tvl",
+                "description": "Get a pool's total value locked: each reserve valued in the quote denom through the other pools' prices. tvl is null when a reserve cannot be priced.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": {
+                            "type": "string",
+                            "description": "Pool identifier"
+                        },
+                        "quote": {
+                            "type": "string",
+                            "description": "Denom to value the reserves in (optional, defaults to usd)"
+                        }
+                    },
+                    "required": ["pool_id"]
+                }
+            }),
+            serde_json::json!({
+                "name": "get_pool_volume_24h",
+                "description": "Get a pool's swap volume over a day in the quote denom, derived from the swap events of recent blocks, with its TVL and fee APR.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": {
+                            "type": "string",
+                            "description": "Pool identifier"
+                        },
+                        "quote": {
+                            "type": "string",
+                            "description": "Denom to value the volume in (optional, defaults to usd)"
+                        },
+                        "lookback_blocks": {
+                            "type": "integer",
+                            "description": "Blocks of swap events to scale to a day (optional, defaults to about a day, at most a week)",
+                            "minimum": 1,
+                            "maximum": 100800
+                        }
+                    },
+                    "required": ["pool_id"]
+                }
+            }),
+            serde_json::json!({
+                "name": "get_top_pools",
+                "description": "Rank the pools by TVL, 24h volume or APR and return the top ones with their reserves, fees, TVL, volume and APR. Pools that cannot be valued are ranked last. Results are cached for a few minutes.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "sort_by": {
+                            "type": "string",
+                            "description": "Ranking metric (optional, defaults to tvl)",
+                            "enum": ["tvl", "volume_24h", "apr"]
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum number of pools to return (optional, defaults to 10)",
+                            "minimum": 1,
+                            "maximum": 100
+                        },
+                        "quote": {
+                            "type": "string",
+                            "description": "Denom to value TVL and volume in (optional, defaults to usd)"
+                        },
+                        "lookback_blocks": {
+                            "type": "integer",
+                            "description": "Blocks of swap events to scale to a day (optional, defaults to about a day, at most a week)",
+                            "minimum": 1,
+                            "maximum": 100800
+                        }
+                    }
+                }
+            }),
             serde_json::json!({
                 "name": "execute_swap",
-                "description": "Executes a token swap in a specified pool with slippage protection.",
+                "description": "Executes a token swap in a specified pool with slippage protection. The result reports risk_level and risk_warnings for the pool's tokens.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
@@ -2497,6 +3090,9 @@ impl McpToolProvider for MantraDexMcpServer {
                         },
                         "ask_asset_denom": { "type": "string", "description": "The denomination of the asset to receive." },
                         "max_slippage": { "type": "string", "description": "Maximum allowed slippage percentage (e.g., '1.5'). Defaults to 1%." },
+                        "force": { "type": "boolean", "description": "Execute even if the swap takes more than the allowed share of the pool's ask-side reserve (default: false)" },
+                        "auto_retry_slippage": { "type": "boolean", "description": "Re-simulate and retry with a higher slippage tolerance while the swap fails on slippage (default: false, cannot be combined with force)" },
+                        "max_retry_slippage": { "type": "string", "description": "Highest slippage tolerance a retry may use, as a fraction (default: '0.05')" },
                         "wallet_address": { "type": "string", "description": "Wallet address to use for the swap (optional, uses active wallet if not provided)" }
                     },
                     "required": ["pool_id", "offer_asset", "ask_asset_denom"]
@@ -2542,7 +3138,7 @@ impl McpToolProvider for MantraDexMcpServer {
             }),
             serde_json::json!({
                 "name": "create_pool",
-                "description": "Creates a new liquidity pool (admin only).",
+                "description": "Creates a new liquidity pool (admin only). Returns the existing pool instead if one with the same assets, type and fees already exists.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
@@ -2578,6 +3174,10 @@ impl McpToolProvider for MantraDexMcpServer {
                             "type": "integer",
                             "description": "Amplification parameter for stable swap pools (ignored for constant product)",
                             "minimum": 1
+                        },
+                        "allow_duplicate": {
+                            "type": "boolean",
+                            "description": "Create the pool even if one with the same assets, type and fees already exists (default: false)"
                         }
                     },
                     "required": ["pool_type", "assets"]
@@ -2641,7 +3241,20 @@ impl McpToolProvider for MantraDexMcpServer {
                     "required": ["pool_id"]
                 }
             }),
-        ]
+        ];
+
+        let simulate_only = self.state.config.simulate_only;
+        let public_mode = self.state.config.public_mode;
+        tools
+            .into_iter()
+            .filter(|tool| {
+                !public_mode
+                    || tool["name"]
+                        .as_str()
+                        .is_some_and(|name| PUBLIC_TOOLS.contains(&name))
+            })
+            .map(|tool| annotate_tool_access(tool, simulate_only))
+            .collect()
     }
 
     async fn handle_tool_call(
@@ -2649,6 +3262,18 @@ impl McpToolProvider for MantraDexMcpServer {
         tool_name: &str,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
+        if self.state.config.public_mode && !PUBLIC_TOOLS.contains(&tool_name) {
+            return Err(McpServerError::Validation(format!(
+                "Tool '{}' is not available on a public-data server",
+                tool_name
+            )));
+        }
+
+        // Only tools that sign transactions need a wallet; queries work without one
+        if tool_access(tool_name) == Access::Signing {
+            self.state.sdk_adapter.validate_wallet_exists().await?;
+        }
+
         match tool_name {
             "get_contract_addresses" => self.handle_get_contract_addresses(arguments).await,
             "validate_network_connectivity" => {
@@ -2661,6 +3286,9 @@ impl McpToolProvider for MantraDexMcpServer {
             "add_wallet_from_mnemonic" => self.handle_add_wallet_from_mnemonic(arguments).await,
             "remove_wallet" => self.handle_remove_wallet(arguments).await,
             "get_pools" => self.handle_get_pools(arguments).await,
+            "get_pool_tvl" => self.handle_get_pool_tvl(arguments).await,
+            "get_pool_volume_24h" => self.handle_get_pool_volume_24h(arguments).await,
+            "get_top_pools" => self.handle_get_top_pools(arguments).await,
             "execute_swap" => self.handle_execute_swap(arguments).await,
             "provide_liquidity" => self.handle_provide_liquidity(arguments).await,
             "provide_liquidity_unchecked" => {
@@ -2682,6 +3310,55 @@ impl McpToolProvider for MantraDexMcpServer {
 // Implement the main McpServer trait that combines all sub-traits
 impl McpServer for MantraDexMcpServer {}
 
+/// Whether a tool signs transactions or only reads state
+pub fn tool_access(tool_name: &str) -> Access {
+    match tool_name {
+        "execute_swap"
+        | "provide_liquidity"
+        | "provide_liquidity_unchecked"
+        | "withdraw_liquidity"
+        | "create_pool" => Access::Signing,
+        _ => Access::ReadOnly,
+    }
+}
+
+/// Whether a resource only exposes public data, and so is served in public mode
+fn is_public_resource(uri: &str) -> bool {
+    uri == "server://health" || uri == TOP_POOLS_RESOURCE || is_pool_resource(uri)
+}
+
+/// Whether `uri` names a per-pool resource: the pool itself, its TVL or its volume
+fn is_pool_resource(uri: &str) -> bool {
+    [
+        POOL_RESOURCE_PREFIX,
+        POOL_TVL_RESOURCE_PREFIX,
+        POOL_VOLUME_RESOURCE_PREFIX,
+    ]
+    .iter()
+    .any(|prefix| resource_pool_id(uri, prefix).is_some())
+}
+
+/// Pool ID of a per-pool resource URI starting with `prefix`
+fn resource_pool_id<'a>(uri: &'a str, prefix: &str) -> Option<&'a str> {
+    uri.strip_prefix(prefix).filter(|pool_id| !pool_id.is_empty())
+}
+
+/// Add a `requiresWallet` annotation so clients can tell query tools from signing ones
+///
+/// In simulate-only mode signing tools are also annotated `simulated`, since they
+/// only report what their transaction would do.
+fn annotate_tool_access(mut tool: serde_json::Value, simulate_only: bool) -> serde_json::Value {
+    let requires_wallet = tool
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(|name| tool_access(name) == Access::Signing);
+    tool["annotations"] = serde_json::json!({
+        "requiresWallet": requires_wallet,
+        "simulated": simulate_only && requires_wallet
+    });
+    tool
+}
+
 impl MantraDexMcpServer {
     /// Handle get_contract_addresses tool
     async fn handle_get_contract_addresses(
@@ -3181,12 +3858,17 @@ impl MantraDexMcpServer {
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling add_wallet_from_mnemonic tool call");
+        // The arguments hold the mnemonic, so only the harmless ones are logged
+        info!(
+            derivation_index = ?arguments.get("derivation_index"),
+            "Handling add_wallet_from_mnemonic tool call"
+        );
 
         // Parse arguments
         let mnemonic = arguments
             .get("mnemonic")
             .and_then(|v| v.as_str())
+            .map(SecretString::from)
             .ok_or_else(|| McpServerError::InvalidArguments("mnemonic is required".to_string()))?;
 
         let derivation_index = arguments
@@ -3200,7 +3882,8 @@ impl MantraDexMcpServer {
             .unwrap_or(true);
 
         // Create wallet from mnemonic
-        let wallet = crate::wallet::MantraWallet::from_mnemonic(mnemonic, derivation_index)
+        let wallet =
+            crate::wallet::MantraWallet::from_mnemonic(mnemonic.expose_secret(), derivation_index)
             .map_err(|e| McpServerError::InvalidArguments(format!("Failed to create wallet from mnemonic: {}", e)))?;
 
         let wallet_info = wallet.info();
@@ -3308,6 +3991,54 @@ impl MantraDexMcpServer {
         }
     }
 
+    /// Handle get_pool_tvl tool
+    async fn handle_get_pool_tvl(&self, arguments: serde_json::Value) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_pool_tvl tool call");
+        let result = self.state.sdk_adapter.get_pool_tvl(arguments).await?;
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    /// Handle get_pool_volume_24h tool
+    async fn handle_get_pool_volume_24h(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_pool_volume_24h tool call");
+        let result = self.state.sdk_adapter.get_pool_volume_24h(arguments).await?;
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    /// Handle get_top_pools tool
+    async fn handle_get_top_pools(&self, arguments: serde_json::Value) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_top_pools tool call");
+        let result = self.state.sdk_adapter.get_top_pools(arguments).await?;
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
     /// Handle get_pools tool
     async fn handle_get_pools(&self, arguments: serde_json::Value) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling get_pools tool call");
@@ -3462,6 +4193,13 @@ impl MantraDexMcpServer {
             response_text.push_str("No pools found matching the criteria.\n");
         }
 
+        if let Some(next) = result.get("next_start_after").and_then(|n| n.as_str()) {
+            response_text.push_str(&format!(
+                "**More Pools:** call again with start_after = {}\n",
+                next
+            ));
+        }
+
         response_text.push_str(&format!(
             "**Query Time:** {}\n",
             chrono::Utc::now().to_rfc3339()
@@ -3722,13 +4460,62 @@ struct HttpJsonRpcRequest {
     id: Option<Value>,
 }
 
+/// Shared state of the HTTP transport
+///
+/// Requests carry no session: any instance behind a load balancer can serve any
+/// request, and the only per-client state is the rate limiter's buckets.
+#[derive(Clone)]
+struct HttpState {
+    server: Arc<MantraDexMcpServer>,
+    /// Per-client limits, applied in public mode
+    rate_limiter: Option<Arc<RateLimiter>>,
+    trust_forwarded_for: bool,
+}
+
+/// Key a client is rate limited by: its address, or the first `X-Forwarded-For`
+/// address when the proxy in front of the server is trusted to set it
+fn client_key(headers: &HeaderMap, peer: SocketAddr, trust_forwarded_for: bool) -> String {
+    let forwarded = trust_forwarded_for
+        .then(|| headers.get("x-forwarded-for")?.to_str().ok())
+        .flatten()
+        .and_then(|value| value.split(',').next())
+        .map(str::trim)
+        .filter(|client| !client.is_empty());
+    match forwarded {
+        Some(client) => client.to_string(),
+        None => peer.ip().to_string(),
+    }
+}
+
 /// HTTP handler for JSON-RPC requests
 async fn handle_jsonrpc_request(
-    State(server): State<Arc<MantraDexMcpServer>>,
+    State(state): State<HttpState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(request): Json<HttpJsonRpcRequest>,
-) -> Result<Json<JsonRpcResponse>, StatusCode> {
+) -> Response {
     debug!("HTTP JSON-RPC request: {:?}", request);
 
+    if let Some(rate_limiter) = &state.rate_limiter {
+        let client = client_key(&headers, peer, state.trust_forwarded_for);
+        if let Err(retry_after) = rate_limiter.check(&client) {
+            debug!("Rate limited client {}", client);
+            let retry_after_secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+            let error = JsonRpcError {
+                code: RATE_LIMITED,
+                message: "Rate limit exceeded".to_string(),
+                data: Some(serde_json::json!({ "retryAfterSecs": retry_after_secs })),
+            };
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after_secs.to_string())],
+                Json(JsonRpcResponse::error(request.id.clone(), error)),
+            )
+                .into_response();
+        }
+    }
+    let server = state.server;
+
     // Convert HTTP JSON-RPC to MCP format and process
     let response = match process_mcp_request(&server, &request).await {
         Ok(result) => JsonRpcResponse::success(request.id.clone(), result),
@@ -3736,7 +4523,7 @@ async fn handle_jsonrpc_request(
     };
 
     debug!("HTTP JSON-RPC response: {:?}", response);
-    Ok(Json(response))
+    Json(response).into_response()
 }
 
 /// Process MCP request and return result
@@ -3754,6 +4541,18 @@ async fn process_mcp_request(
 pub async fn create_http_server(config: McpServerConfig) -> McpResult<MantraDexMcpServer> {
     let http_host = config.http_host.clone();
     let http_port = config.http_port;
+    let rate_limiter = if config.public_mode {
+        let limiter = RateLimiter::new(config.rate_limit_per_minute, config.rate_limit_burst)
+            .map_err(|e| McpServerError::Validation(e.to_string()))?;
+        info!(
+            "Public mode: {} requests a minute per client, bursts of {}",
+            config.rate_limit_per_minute, config.rate_limit_burst
+        );
+        Some(Arc::new(limiter))
+    } else {
+        None
+    };
+    let trust_forwarded_for = config.trust_forwarded_for;
     let server = create_mcp_server(config).await?;
 
     info!(
@@ -3765,7 +4564,11 @@ pub async fn create_http_server(config: McpServerConfig) -> McpResult<MantraDexM
     let app = Router::new()
         .route("/", post(handle_jsonrpc_request))
         .route("/jsonrpc", post(handle_jsonrpc_request))
-        .with_state(Arc::new(server.clone()));
+        .with_state(HttpState {
+            server: Arc::new(server.clone()),
+            rate_limiter,
+            trust_forwarded_for,
+        });
 
     // Bind to address
     let addr: SocketAddr = format!("{}:{}", http_host, http_port)
@@ -3780,6 +4583,7 @@ pub async fn create_http_server(config: McpServerConfig) -> McpResult<MantraDexM
 
     // Start the HTTP server
     tokio::spawn(async move {
+        let app = app.into_make_service_with_connect_info::<SocketAddr>();
         if let Err(e) = axum::serve(listener, app).await {
             error!("HTTP server error: {}", e);
         }
@@ -3928,12 +4732,13 @@ impl MantraDexMcpServer {
 
 /// Start the stdio transport layer for MCP communication
 async fn start_stdio_transport(server: MantraDexMcpServer) -> McpResult<()> {
-    use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::io::{self, AsyncBufReadExt, BufReader};
 
     info!("Starting stdio transport for MCP communication");
 
     let stdin = io::stdin();
-    let mut stdout = io::stdout();
+    // Shared with progress notifications sent while a request is handled
+    let stdout = Arc::new(Mutex::new(io::stdout()));
 
     info!("Server is ready and listening for JSON-RPC messages on stdin...");
 
@@ -3963,7 +4768,18 @@ async fn start_stdio_transport(server: MantraDexMcpServer) -> McpResult<()> {
 
                 // Parse JSON-RPC request
                 let response_opt = match serde_json::from_str::<serde_json::Value>(trimmed) {
-                    Ok(request) => handle_json_rpc_request(&server, request).await,
+                    Ok(request) => {
+                        let progress = progress_token(&request).map(|token| {
+                            spawn_progress_notifications(&server, stdout.clone(), token)
+                        });
+                        let response = handle_json_rpc_request(&server, request).await;
+                        // Let pending notifications out before the response
+                        if let Some((done, forwarder)) = progress {
+                            let _ = done.send(());
+                            let _ = forwarder.await;
+                        }
+                        response
+                    }
                     Err(e) => {
                         warn!("Failed to parse JSON-RPC request: {}", e);
                         Some(JsonRpcResponse::error(
@@ -3984,18 +4800,10 @@ async fn start_stdio_transport(server: MantraDexMcpServer) -> McpResult<()> {
                 if let Some(response) = response_opt {
                     match serde_json::to_string(&response) {
                         Ok(response_json) => {
-                            if let Err(e) = stdout.write_all(response_json.as_bytes()).await {
+                            if let Err(e) = write_message(&stdout, &response_json).await {
                                 warn!("Failed to write response to stdout: {} - continuing", e);
                                 continue;
                             }
-                            if let Err(e) = stdout.write_all(b"\n").await {
-                                warn!("Failed to write newline to stdout: {} - continuing", e);
-                                continue;
-                            }
-                            if let Err(e) = stdout.flush().await {
-                                warn!("Failed to flush stdout: {} - continuing", e);
-                                continue;
-                            }
                             // Only log response details in debug mode to reduce noise
                             if tracing::enabled!(tracing::Level::DEBUG) {
                                 debug!("Sent MCP response: {}", response_json);
@@ -4021,6 +4829,84 @@ async fn start_stdio_transport(server: MantraDexMcpServer) -> McpResult<()> {
     Ok(())
 }
 
+/// Write one JSON-RPC message as a line on stdout
+async fn write_message(stdout: &Mutex<tokio::io::Stdout>, message: &str) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut stdout = stdout.lock().await;
+    stdout.write_all(message.as_bytes()).await?;
+    stdout.write_all(b"\n").await?;
+    stdout.flush().await
+}
+
+/// Progress token the client attached to a request, if it wants progress notifications
+fn progress_token(request: &Value) -> Option<Value> {
+    request
+        .get("params")?
+        .get("_meta")?
+        .get("progressToken")
+        .cloned()
+}
+
+/// Send `notifications/progress` for transactions sent while a request is handled
+///
+/// Progress counts the pipeline stages of every transaction, so it keeps increasing
+/// when a tool call sends more than one. Forwarding stops once `done` is signalled
+/// and the updates already received have been sent.
+fn spawn_progress_notifications(
+    server: &MantraDexMcpServer,
+    stdout: Arc<Mutex<tokio::io::Stdout>>,
+    progress_token: Value,
+) -> (
+    tokio::sync::oneshot::Sender<()>,
+    tokio::task::JoinHandle<()>,
+) {
+    use tokio::sync::broadcast::error::RecvError;
+
+    let mut updates = server.state.sdk_adapter.subscribe_tx_progress();
+    let (done_tx, mut done) = tokio::sync::oneshot::channel::<()>();
+
+    let forwarder = tokio::spawn(async move {
+        let stages = TxStage::ALL.len() as u32;
+        let mut transactions: Vec<u64> = Vec::new();
+
+        loop {
+            let update = tokio::select! {
+                biased;
+                update = updates.recv() => match update {
+                    Ok(update) => update,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                },
+                _ = &mut done => break,
+            };
+
+            let index = match transactions.iter().position(|id| *id == update.id) {
+                Some(index) => index,
+                None => {
+                    transactions.push(update.id);
+                    transactions.len() - 1
+                }
+            };
+            let notification = serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/progress",
+                "params": {
+                    "progressToken": progress_token,
+                    "progress": index as u32 * stages + update.stage.step(),
+                    "total": transactions.len() as u32 * stages,
+                    "message": update.stage.description(),
+                }
+            });
+            if let Err(e) = write_message(&stdout, &notification.to_string()).await {
+                warn!("Failed to write progress notification: {}", e);
+            }
+        }
+    });
+
+    (done_tx, forwarder)
+}
+
 /// Handle a JSON-RPC request and return a JSON-RPC response
 async fn handle_json_rpc_request(
     server: &MantraDexMcpServer,
@@ -0,0 +1,333 @@
+//! Hooks into the transaction pipeline
+//!
+//! Every transaction the client sends passes through a chain of [`Middleware`]:
+//! each is told when the transaction reaches a [`TxStage`], may refuse it before
+//! it is signed, and hears how it ended, either included in a block or failed.
+//! Custom logging, policy checks or metrics can be added with
+//! [`MantraDexClient::with_middleware`] without changing the client.
+//!
+//! The client's own behaviour is built from the same hooks and runs ahead of
+//! the registered middleware, in this order:
+//!
+//! * [`ProgressReporter`] sends [`TxProgress`] updates to
+//!   [`subscribe_tx_progress`](MantraDexClient::subscribe_tx_progress) subscribers
+//! * [`TradePolicy`] checks swaps against the trade size guard and value limit
+//! * [`AuditLog`], if set with [`with_audit_log`](MantraDexClient::with_audit_log),
+//!   records every transaction to a file
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::Any;
+use cosmwasm_std::{Coin, Uint128};
+use futures::future::{self, BoxFuture};
+use mantra_dex_std::pool_manager;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::client::{MantraDexClient, TxProgress, TxStage};
+use crate::error::Error;
+
+/// Type URL of a CosmWasm contract execution
+const MSG_EXECUTE_CONTRACT: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+/// A transaction on its way through the pipeline
+#[derive(Debug, Clone)]
+pub struct TxContext {
+    /// Identifies the transaction; unique within the process
+    pub id: u64,
+    /// Operation kind, see [`crate::gas::operation_kind`]
+    pub kind: String,
+    pub msgs: Vec<Any>,
+    /// Address signing the transaction
+    pub signer: String,
+    /// Gas used by the transaction's simulation, once simulated
+    pub simulated_gas: Option<u64>,
+    /// Gas limit the transaction is sent with, once simulated
+    pub gas_limit: Option<u64>,
+    /// Hash of the transaction, once broadcast
+    pub tx_hash: Option<String>,
+    /// Whether the caller asked to bypass the trade policies
+    pub force: bool,
+}
+
+/// Hooks run for every transaction the client sends
+///
+/// Every hook does nothing by default, so a middleware only implements the ones
+/// it needs. Hooks run in registration order, after the client's built-in
+/// middleware.
+pub trait Middleware: fmt::Debug + Send + Sync {
+    /// Inspect a transaction before it is signed; an error refuses it
+    ///
+    /// Runs once the transaction has been built and simulated, also on a
+    /// simulate-only client. `client` is the client sending it, for queries.
+    fn before_sign<'a>(
+        &'a self,
+        client: &'a MantraDexClient,
+        tx: &'a TxContext,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        let _ = (client, tx);
+        Box::pin(future::ready(Ok(())))
+    }
+
+    /// The transaction reached `stage`
+    fn on_stage(&self, tx: &TxContext, stage: TxStage) {
+        let _ = (tx, stage);
+    }
+
+    /// The transaction was broadcast and included in a block
+    fn after_broadcast(&self, tx: &TxContext, response: &TxResponse) {
+        let _ = (tx, response);
+    }
+
+    /// The transaction was refused or failed, before or after broadcast
+    fn on_error(&self, tx: &TxContext, error: &Error) {
+        let _ = (tx, error);
+    }
+
+    /// Sending the transaction failed for a transient reason and is retried
+    ///
+    /// `retry` counts from 1. The transaction is built, simulated and signed
+    /// again, so the stages are reported again from the start.
+    fn on_retry(&self, tx: &TxContext, retry: u32, error: &Error) {
+        let _ = (tx, retry, error);
+    }
+}
+
+/// Sends a [`TxProgress`] update for every stage a transaction reaches
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    sender: broadcast::Sender<TxProgress>,
+}
+
+impl ProgressReporter {
+    /// Report on `sender`, which several clients may share
+    pub fn new(sender: broadcast::Sender<TxProgress>) -> Self {
+        Self { sender }
+    }
+
+    /// Receive the updates sent from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<TxProgress> {
+        self.sender.subscribe()
+    }
+
+    /// Channel the updates are sent on
+    pub fn sender(&self) -> broadcast::Sender<TxProgress> {
+        self.sender.clone()
+    }
+}
+
+impl Middleware for ProgressReporter {
+    fn on_stage(&self, tx: &TxContext, stage: TxStage) {
+        // Nobody may be listening
+        let _ = self.sender.send(TxProgress {
+            id: tx.id,
+            stage,
+            tx_hash: tx.tx_hash.clone(),
+        });
+    }
+}
+
+/// Refuses swaps blocked by the client's trade size guard or value limit
+///
+/// Every swap through the pool manager in the transaction is checked with
+/// [`MantraDexClient::check_trade_size`], unless the caller forced it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TradePolicy;
+
+impl Middleware for TradePolicy {
+    fn before_sign<'a>(
+        &'a self,
+        client: &'a MantraDexClient,
+        tx: &'a TxContext,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        Box::pin(async move {
+            if tx.force {
+                return Ok(());
+            }
+            let pool_manager = client.config().contracts.pool_manager.as_str();
+            let swaps = tx.msgs.iter().filter_map(|msg| swap(msg, pool_manager));
+            for (pool_id, offer, ask_denom) in swaps {
+                client
+                    .check_trade_size(&pool_id, &offer, &ask_denom)
+                    .await?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Pool, offer and ask denom of a swap through `pool_manager`
+fn swap(msg: &Any, pool_manager: &str) -> Option<(String, Coin, String)> {
+    if msg.type_url != MSG_EXECUTE_CONTRACT {
+        return None;
+    }
+    let execute = MsgExecuteContract::decode(msg.value.as_slice()).ok()?;
+    if execute.contract != pool_manager {
+        return None;
+    }
+    let pool_manager::ExecuteMsg::Swap {
+        pool_identifier,
+        ask_asset_denom,
+        ..
+    } = serde_json::from_slice(&execute.msg).ok()?
+    else {
+        return None;
+    };
+    let [funds] = execute.funds.as_slice() else {
+        return None;
+    };
+    let offer = Coin {
+        denom: funds.denom.clone(),
+        amount: Uint128::from_str(&funds.amount).ok()?,
+    };
+    Some((pool_identifier, offer, ask_asset_denom))
+}
+
+/// What happened to a transaction, as recorded in the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEvent {
+    /// About to be signed
+    Signing,
+    /// Included in a block
+    Included,
+    /// Refused or failed
+    Failed,
+}
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub at: DateTime<Utc>,
+    pub tx_id: u64,
+    pub event: AuditEvent,
+    /// Operation kind, see [`crate::gas::operation_kind`]
+    pub kind: String,
+    pub signer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_used: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Appends a JSON line per transaction event to a file
+///
+/// Each transaction is recorded when it is about to be signed, then when it is
+/// included or fails. Writes are best effort; a failed write only loses that
+/// entry.
+#[derive(Debug)]
+pub struct AuditLog {
+    file: Mutex<File>,
+    path: PathBuf,
+}
+
+impl AuditLog {
+    /// Open the log at `path` for appending, creating it and its directory if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be created or opened
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Default location of the audit log in the user's configuration directory
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("audit.log");
+        path
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the entries of the log at `path`, skipping lines that don't parse
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read
+    pub fn read(path: impl AsRef<Path>) -> Result<Vec<AuditEntry>, Error> {
+        Ok(fs::read_to_string(path)?
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+
+    fn record(&self, tx: &TxContext, event: AuditEvent) -> AuditEntry {
+        AuditEntry {
+            at: Utc::now(),
+            tx_id: tx.id,
+            event,
+            kind: tx.kind.clone(),
+            signer: tx.signer.clone(),
+            tx_hash: tx.tx_hash.clone(),
+            height: None,
+            gas_limit: tx.gas_limit,
+            gas_used: None,
+            error: None,
+        }
+    }
+
+    fn write(&self, entry: &AuditEntry) {
+        let Ok(mut line) = serde_json::to_string(entry) else {
+            return;
+        };
+        line.push('\n');
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+impl Middleware for AuditLog {
+    fn before_sign<'a>(
+        &'a self,
+        _client: &'a MantraDexClient,
+        tx: &'a TxContext,
+    ) -> BoxFuture<'a, Result<(), Error>> {
+        self.write(&self.record(tx, AuditEvent::Signing));
+        Box::pin(future::ready(Ok(())))
+    }
+
+    fn after_broadcast(&self, tx: &TxContext, response: &TxResponse) {
+        self.write(&AuditEntry {
+            height: Some(response.height),
+            gas_used: Some(response.gas_used),
+            ..self.record(tx, AuditEvent::Included)
+        });
+    }
+
+    fn on_error(&self, tx: &TxContext, error: &Error) {
+        self.write(&AuditEntry {
+            error: Some(error.to_string()),
+            ..self.record(tx, AuditEvent::Failed)
+        });
+    }
+}
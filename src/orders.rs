@@ -0,0 +1,508 @@
+//! Limit orders executed by the client when a pool's price is reached
+//!
+//! The pool manager only swaps at the current price, so limit orders are
+//! emulated: an [`OrderBook`] keeps "swap X for Y once the price of X is at
+//! least (or at most) P" in a JSON file so they survive restarts, and
+//! [`run_triggered`] signs and sends the swap of each pending order whose
+//! condition holds at the pool's spot price. [`watch`] runs it whenever a
+//! transaction executes the pool manager, streaming chain events over the
+//! node's WebSocket, and falls back to polling while the stream is down.
+//!
+//! As with [`Schedule`](crate::Schedule), every change re-reads the file first,
+//! so an order cancelled from one process is not executed by a watcher in
+//! another.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Decimal};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::client::MantraDexClient;
+use crate::error::{Error, ErrorClass};
+use crate::pricing;
+use crate::schema::{Format, Schema};
+use crate::storage::{Document, SharedStorage};
+use crate::subscriptions::{ChainEvent, EventSubscriptions, SubscriptionFilter};
+
+/// Default delay between price checks while chain events aren't streamed, and
+/// between attempts to stream them again
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Price an order waits for, in units of the ask denom per unit of the offer denom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceCondition {
+    /// The price rose to at least the limit, e.g. to take profit
+    AtLeast(Decimal),
+    /// The price fell to at most the limit, e.g. to stop a loss
+    AtMost(Decimal),
+}
+
+impl PriceCondition {
+    /// Whether `price` meets the condition
+    pub fn is_met(&self, price: Decimal) -> bool {
+        match self {
+            PriceCondition::AtLeast(limit) => price >= *limit,
+            PriceCondition::AtMost(limit) => price <= *limit,
+        }
+    }
+
+    /// The limit price
+    pub fn limit(&self) -> Decimal {
+        match self {
+            PriceCondition::AtLeast(limit) | PriceCondition::AtMost(limit) => *limit,
+        }
+    }
+}
+
+impl fmt::Display for PriceCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriceCondition::AtLeast(limit) => write!(f, ">={}", limit),
+            PriceCondition::AtMost(limit) => write!(f, "<={}", limit),
+        }
+    }
+}
+
+impl FromStr for PriceCondition {
+    type Err = Error;
+
+    /// Parse `>=<price>` or `<=<price>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            Error::Config(format!(
+                "Invalid price condition '{}': expected >=<price> or <=<price>",
+                s
+            ))
+        };
+        let s = s.trim();
+        let (condition, limit): (fn(Decimal) -> PriceCondition, &str) =
+            if let Some(limit) = s.strip_prefix(">=") {
+                (PriceCondition::AtLeast, limit)
+            } else if let Some(limit) = s.strip_prefix("<=") {
+                (PriceCondition::AtMost, limit)
+            } else {
+                return Err(invalid());
+            };
+        let limit: Decimal = limit.trim().parse().map_err(|_| invalid())?;
+        if limit.is_zero() {
+            return Err(invalid());
+        }
+        Ok(condition(limit))
+    }
+}
+
+/// Where a limit order stands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// Waiting for its price
+    Pending,
+    /// Swapped at `price` and included in a block
+    Executed {
+        tx_hash: String,
+        height: u64,
+        /// Spot price that triggered the swap
+        price: Decimal,
+    },
+    /// Rejected when sent
+    Failed { reason: String },
+    /// Broadcast, but not seen in a block; look `tx_hash` up rather than swap again
+    Unconfirmed { tx_hash: String, reason: String },
+    /// Cancelled before its price was reached
+    Cancelled,
+    /// Its price wasn't reached before it expired
+    Expired,
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderStatus::Pending => write!(f, "pending"),
+            OrderStatus::Executed { tx_hash, price, .. } => {
+                write!(f, "executed at {} {}", price, tx_hash)
+            }
+            OrderStatus::Failed { reason } => write!(f, "failed: {}", reason),
+            OrderStatus::Unconfirmed { tx_hash, reason } => {
+                write!(f, "unconfirmed {}: {}", tx_hash, reason)
+            }
+            OrderStatus::Cancelled => write!(f, "cancelled"),
+            OrderStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// A limit order kept in an [`OrderBook`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitOrder {
+    /// Identifier, unique within its book
+    pub id: u64,
+    /// Saved wallet that signs the swap
+    pub wallet: String,
+    /// Pool to swap in, whose spot price triggers the order
+    pub pool_id: String,
+    pub offer_asset: Coin,
+    pub ask_asset_denom: String,
+    pub condition: PriceCondition,
+    /// Maximum slippage tolerance, the wallet default if not set
+    pub max_slippage: Option<Decimal>,
+    pub created_at: DateTime<Utc>,
+    /// When the order lapses if its price wasn't reached, never if not set
+    pub expires_at: Option<DateTime<Utc>>,
+    pub status: OrderStatus,
+}
+
+impl LimitOrder {
+    pub fn is_pending(&self) -> bool {
+        self.status == OrderStatus::Pending
+    }
+
+    /// Whether the order has lapsed at `now`
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// Spot price of the order's offer denom in `pool` if it meets the condition
+    pub fn triggered_by(&self, pool: &PoolInfoResponse) -> Option<Decimal> {
+        pricing::spot_price(pool, &self.offer_asset.denom, &self.ask_asset_denom)
+            .filter(|price| self.condition.is_met(*price))
+    }
+}
+
+impl fmt::Display for LimitOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Swap {} for {} in pool {} when the price is {}",
+            self.offer_asset, self.ask_asset_denom, self.pool_id, self.condition
+        )
+    }
+}
+
+/// An order to place in an [`OrderBook`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrder {
+    pub pool_id: String,
+    pub offer_asset: Coin,
+    pub ask_asset_denom: String,
+    pub condition: PriceCondition,
+    pub max_slippage: Option<Decimal>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Format of the order book file
+const ORDERS_SCHEMA: Schema = Schema::new("limit orders", Format::Json, &[]);
+
+/// Contents of the order book file
+#[derive(Serialize, Deserialize)]
+struct OrdersFile<T> {
+    orders: T,
+}
+
+/// Limit orders, persisted when opened from a file or a storage
+#[derive(Debug, Default)]
+pub struct OrderBook {
+    orders: Vec<LimitOrder>,
+    document: Option<Document>,
+}
+
+impl OrderBook {
+    /// Create an empty, in-memory order book
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the order book stored at `path`, saving back to it on every change
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the order book kept in `storage`, saving back to it on every change
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut book = Self {
+            orders: Vec::new(),
+            document: Some(document),
+        };
+        book.reload()?;
+        Ok(book)
+    }
+
+    /// Key of the order book in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "limit_orders.json";
+
+    /// Get the default order book file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("limit_orders.json");
+        path
+    }
+
+    /// Re-read the orders, picking up changes made by other processes
+    pub fn reload(&mut self) -> Result<(), Error> {
+        let Some(document) = &self.document else {
+            return Ok(());
+        };
+        if let Some(stored) = document.read::<OrdersFile<Vec<LimitOrder>>>(&ORDERS_SCHEMA)? {
+            self.orders = stored.orders;
+        }
+        Ok(())
+    }
+
+    /// Every order, oldest first
+    pub fn orders(&self) -> &[LimitOrder] {
+        &self.orders
+    }
+
+    pub fn get(&self, id: u64) -> Option<&LimitOrder> {
+        self.orders.iter().find(|order| order.id == id)
+    }
+
+    /// Place `order`, to be signed by `wallet` once its price is reached
+    ///
+    /// # Returns
+    ///
+    /// Identifier of the new order
+    pub fn add(&mut self, wallet: &str, order: NewOrder) -> Result<u64, Error> {
+        self.reload()?;
+        let id = self.orders.iter().map(|order| order.id).max().unwrap_or(0) + 1;
+        self.orders.push(LimitOrder {
+            id,
+            wallet: wallet.to_string(),
+            pool_id: order.pool_id,
+            offer_asset: order.offer_asset,
+            ask_asset_denom: order.ask_asset_denom,
+            condition: order.condition,
+            max_slippage: order.max_slippage,
+            created_at: Utc::now(),
+            expires_at: order.expires_at,
+            status: OrderStatus::Pending,
+        });
+        self.save()?;
+        Ok(id)
+    }
+
+    /// Cancel a pending order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such order or it is no longer pending
+    pub fn cancel(&mut self, id: u64) -> Result<(), Error> {
+        self.reload()?;
+        let order = self
+            .orders
+            .iter_mut()
+            .find(|order| order.id == id)
+            .ok_or_else(|| Error::Other(format!("No limit order {}", id)))?;
+        if !order.is_pending() {
+            return Err(Error::Other(format!(
+                "Limit order {} is already {}",
+                id, order.status
+            )));
+        }
+        order.status = OrderStatus::Cancelled;
+        self.save()
+    }
+
+    /// Record the outcome of an order
+    pub fn set_status(&mut self, id: u64, status: OrderStatus) -> Result<(), Error> {
+        self.reload()?;
+        if let Some(order) = self.orders.iter_mut().find(|order| order.id == id) {
+            order.status = status;
+        }
+        self.save()
+    }
+
+    /// Mark the pending orders that lapsed at `now` as expired
+    ///
+    /// # Returns
+    ///
+    /// The orders that expired, with their new status
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Result<Vec<LimitOrder>, Error> {
+        self.reload()?;
+        let mut expired = Vec::new();
+        for order in &mut self.orders {
+            if order.is_pending() && order.is_expired(now) {
+                order.status = OrderStatus::Expired;
+                expired.push(order.clone());
+            }
+        }
+        if !expired.is_empty() {
+            self.save()?;
+        }
+        Ok(expired)
+    }
+
+    /// Pending orders of `wallet`
+    pub fn pending(&self, wallet: &str) -> Vec<LimitOrder> {
+        self.orders
+            .iter()
+            .filter(|order| order.is_pending() && order.wallet == wallet)
+            .cloned()
+            .collect()
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        match &self.document {
+            Some(document) => document.write(
+                &ORDERS_SCHEMA,
+                &OrdersFile {
+                    orders: &self.orders,
+                },
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Execute the orders of `wallet` whose price is reached, signing with the client's wallet
+///
+/// Lapsed orders are marked expired first. The pools of the remaining orders
+/// are queried once each; an order whose condition holds at the spot price is
+/// swapped right away, within its slippage tolerance, and marked failed if the
+/// chain rejects the swap, or unconfirmed with its transaction hash if the swap
+/// was broadcast but not seen in a block, so it isn't sent twice. Failing to
+/// reach the node before broadcasting ends the pass, so the next pass tries
+/// again.
+///
+/// # Returns
+///
+/// The orders executed, failed, unconfirmed or expired in this pass, with their
+/// new status
+pub async fn run_triggered(
+    client: &MantraDexClient,
+    book: &mut OrderBook,
+    wallet: &str,
+) -> Result<Vec<LimitOrder>, Error> {
+    let mut done: Vec<LimitOrder> = book
+        .expire(Utc::now())?
+        .into_iter()
+        .filter(|order| order.wallet == wallet)
+        .collect();
+
+    let mut pools: HashMap<String, PoolInfoResponse> = HashMap::new();
+    for order in book.pending(wallet) {
+        if !pools.contains_key(&order.pool_id) {
+            let pool = client.get_pool(&order.pool_id).await?;
+            pools.insert(order.pool_id.clone(), pool);
+        }
+        let Some(price) = order.triggered_by(&pools[&order.pool_id]) else {
+            continue;
+        };
+
+        // It may have been cancelled since the pass started
+        book.reload()?;
+        if !book.get(order.id).is_some_and(LimitOrder::is_pending) {
+            continue;
+        }
+
+        let status = match client
+            .swap(
+                &order.pool_id,
+                order.offer_asset.clone(),
+                &order.ask_asset_denom,
+                order.max_slippage,
+            )
+            .await
+        {
+            Ok(response) => OrderStatus::Executed {
+                tx_hash: response.txhash,
+                height: response.height as u64,
+                price,
+            },
+            Err(Error::Unconfirmed { tx_hash, reason }) => {
+                OrderStatus::Unconfirmed { tx_hash, reason }
+            }
+            Err(e) if e.class() == ErrorClass::Network => return Err(e),
+            Err(e) => OrderStatus::Failed {
+                reason: e.to_string(),
+            },
+        };
+        book.set_status(order.id, status.clone())?;
+        // The swap moved the pool's price
+        pools.remove(&order.pool_id);
+        done.push(LimitOrder { status, ..order });
+    }
+    Ok(done)
+}
+
+/// Execute the orders of `wallet` as prices move, forever
+///
+/// Orders are checked right away, then whenever a transaction executes the
+/// pool manager, and otherwise every `poll_interval`, which is also how long
+/// the watcher waits before streaming chain events again when the stream can't
+/// be opened or drops. `on_pass` gets the outcome of every check.
+///
+/// # Errors
+///
+/// Only returns, with its error, once `on_pass` fails
+pub async fn watch<F>(
+    client: &MantraDexClient,
+    book: &mut OrderBook,
+    wallet: &str,
+    poll_interval: Duration,
+    mut on_pass: F,
+) -> Result<(), Error>
+where
+    F: FnMut(Result<Vec<LimitOrder>, Error>) -> Result<(), Error>,
+{
+    let filter =
+        SubscriptionFilter::new().with_contract(client.config().contracts.pool_manager.clone());
+    let mut stream: Option<(EventSubscriptions, broadcast::Receiver<ChainEvent>)> = None;
+    let mut interval = tokio::time::interval(poll_interval);
+
+    loop {
+        // `None` when the poll interval elapsed
+        let event = match &mut stream {
+            Some((_, events)) => tokio::select! {
+                _ = interval.tick() => None,
+                event = events.recv() => Some(event),
+            },
+            None => {
+                interval.tick().await;
+                None
+            }
+        };
+        match event {
+            // Blocks come without a pool manager transaction
+            Some(Ok(ChainEvent::NewBlock { .. })) => continue,
+            Some(Ok(ChainEvent::Tx { .. })) | Some(Err(broadcast::error::RecvError::Lagged(_))) => {
+            }
+            Some(Err(broadcast::error::RecvError::Closed)) => {
+                stream = None;
+                continue;
+            }
+            None if stream.is_none() => {
+                if let Ok(subscriptions) = client.subscribe_events(&filter).await {
+                    let events = subscriptions.subscribe();
+                    stream = Some((subscriptions, events));
+                }
+            }
+            None => {}
+        }
+
+        on_pass(run_triggered(client, book, wallet).await)?;
+    }
+}
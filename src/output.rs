@@ -0,0 +1,116 @@
+//! Structured output of the command line tools
+//!
+//! Commands return their result as a [`CommandOutput`]: a value that reads as
+//! text for people and serializes to JSON or YAML for scripts. The format is
+//! picked once, with [`OutputFormat`], and applied by [`render`] at the top
+//! level, so every command supports every format.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+use yaml_rust2::{YamlEmitter, YamlLoader};
+
+use crate::error::Error;
+
+/// How a command's result is printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// Pretty-printed JSON
+    Json,
+    /// A YAML document
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Whether the result is data for a program rather than text for a person
+    pub fn is_structured(self) -> bool {
+        self != OutputFormat::Text
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Yaml => write!(f, "yaml"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" | "yml" => Ok(OutputFormat::Yaml),
+            other => Err(Error::Config(format!(
+                "Unknown output format '{}', expected text, json or yaml",
+                other
+            ))),
+        }
+    }
+}
+
+/// The result of a command: its text rendering, and its data for JSON and YAML
+///
+/// Implemented for every type that is both [`Serialize`] and [`fmt::Display`].
+pub trait CommandOutput: fmt::Display {
+    /// The result as pretty-printed JSON
+    fn to_json(&self) -> Result<String, Error>;
+}
+
+impl<T: Serialize + fmt::Display> CommandOutput for T {
+    fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// Render `output` in `format`, ending with a newline
+///
+/// # Errors
+///
+/// Returns error if the result can't be serialized
+pub fn render(output: &dyn CommandOutput, format: OutputFormat) -> Result<String, Error> {
+    let mut rendered = match format {
+        OutputFormat::Text => output.to_string(),
+        OutputFormat::Json => output.to_json()?,
+        OutputFormat::Yaml => json_to_yaml(&output.to_json()?)?,
+    };
+    if !rendered.ends_with('\n') {
+        rendered.push('\n');
+    }
+    Ok(rendered)
+}
+
+/// A JSON document as a YAML document, starting with `---`
+///
+/// JSON is read as YAML, so fields keep their order and numbers and strings
+/// their types.
+///
+/// # Errors
+///
+/// Returns [`Error::Other`] if `json` isn't one JSON document or the YAML
+/// can't be written
+pub fn json_to_yaml(json: &str) -> Result<String, Error> {
+    let docs = YamlLoader::load_from_str(json)
+        .map_err(|e| Error::Other(format!("Failed to read the result as YAML: {}", e)))?;
+    let [doc] = docs.as_slice() else {
+        return Err(Error::Other(format!(
+            "Expected one JSON document, got {}",
+            docs.len()
+        )));
+    };
+
+    let mut yaml = String::new();
+    YamlEmitter::new(&mut yaml)
+        .dump(doc)
+        .map_err(|e| Error::Other(format!("Failed to write YAML: {}", e)))?;
+    Ok(yaml)
+}
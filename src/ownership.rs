@@ -0,0 +1,189 @@
+//! Pool ownership and creator metadata
+//!
+//! Pools carry no record of who created them, but their LP denom is created by
+//! the transaction that created the pool. [`PoolOwnership`] gathers what that
+//! transaction tells about the pool's origin, its creator and the block it was
+//! included in, along with how concentrated the pool's LP tokens are.
+//!
+//! Everything is best effort: a node that doesn't index old transactions
+//! leaves the creation details empty.
+//!
+//! LP holders are read through the [`Indexer`](crate::indexer::Indexer).
+//! [`LpConcentration`] summarizes how they split the pool: the share of the
+//! largest ones and a Gini coefficient over all of them. A pool dominated by a
+//! single provider can lose most of its depth in one withdrawal, which
+//! [`crate::risk`] flags.
+
+use chrono::{DateTime, Utc};
+use cosmrs::tendermint::abci::Event;
+use cosmwasm_std::{Decimal, Decimal256, Uint128, Uint256};
+use serde::{Deserialize, Serialize};
+
+/// Largest LP holders listed in an [`LpConcentration`]
+pub const TOP_LP_HOLDERS: usize = 10;
+
+/// An address holding some of a denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomHolder {
+    pub address: String,
+    pub amount: Uint128,
+}
+
+/// Where a pool comes from and who holds its liquidity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolOwnership {
+    pub pool_id: String,
+    /// Address that sent the pool creation transaction
+    pub creator: Option<String>,
+    /// Block height the pool was created at
+    pub created_height: Option<u64>,
+    /// Time of the block the pool was created at
+    pub created_at: Option<DateTime<Utc>>,
+    /// Hash of the pool creation transaction
+    pub creation_tx: Option<String>,
+    pub lp_concentration: Option<LpConcentration>,
+}
+
+impl PoolOwnership {
+    /// Ownership of a pool nothing is known about yet
+    pub fn unknown(pool_id: impl Into<String>) -> Self {
+        Self {
+            pool_id: pool_id.into(),
+            creator: None,
+            created_height: None,
+            created_at: None,
+            creation_tx: None,
+            lp_concentration: None,
+        }
+    }
+}
+
+/// An address holding LP tokens of a pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LpHolder {
+    pub address: String,
+    pub amount: Uint128,
+    /// Share of the pool's total LP supply held
+    pub share: Decimal,
+}
+
+/// How concentrated the LP tokens of a pool are
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LpConcentration {
+    /// Number of addresses holding LP tokens
+    pub holders: usize,
+    /// Largest holders, from the largest down
+    pub top_holders: Vec<LpHolder>,
+    /// Gini coefficient of the holdings, from 0 when every holder has the same
+    /// amount towards 1 when one holder has nearly everything
+    pub gini: Decimal,
+}
+
+impl LpConcentration {
+    /// Measure concentration from the holders of `total_share` LP tokens
+    ///
+    /// `excluded` addresses, such as the pool manager keeping the minimum
+    /// liquidity locked, are left out. At most `top` holders are listed.
+    pub fn from_holders(
+        holders: &[DenomHolder],
+        total_share: Uint128,
+        excluded: &[&str],
+        top: usize,
+    ) -> Self {
+        let mut counted: Vec<&DenomHolder> = holders
+            .iter()
+            .filter(|holder| {
+                !holder.amount.is_zero() && !excluded.contains(&holder.address.as_str())
+            })
+            .collect();
+        counted.sort_by(|a, b| {
+            b.amount
+                .cmp(&a.amount)
+                .then_with(|| a.address.cmp(&b.address))
+        });
+
+        let top_holders = counted
+            .iter()
+            .take(top)
+            .map(|holder| LpHolder {
+                address: holder.address.clone(),
+                amount: holder.amount,
+                share: if total_share.is_zero() {
+                    Decimal::zero()
+                } else {
+                    Decimal::from_ratio(holder.amount, total_share)
+                },
+            })
+            .collect();
+        let amounts: Vec<Uint128> = counted.iter().map(|holder| holder.amount).collect();
+
+        Self {
+            holders: counted.len(),
+            top_holders,
+            gini: gini(&amounts),
+        }
+    }
+
+    /// Share of the LP supply held by the largest holder
+    pub fn largest_share(&self) -> Decimal {
+        self.top_holders
+            .first()
+            .map(|holder| holder.share)
+            .unwrap_or_default()
+    }
+
+    /// Share of the LP supply held by the listed top holders together
+    pub fn top_share(&self) -> Decimal {
+        self.top_holders.iter().map(|holder| holder.share).sum()
+    }
+
+    /// The holder of at least `threshold` of the LP supply, among several holders
+    ///
+    /// A sole holder isn't reported here; it is a different red flag.
+    pub fn dominant_holder(&self, threshold: Decimal) -> Option<&LpHolder> {
+        self.top_holders
+            .first()
+            .filter(|holder| self.holders > 1 && holder.share >= threshold)
+    }
+}
+
+/// Gini coefficient of `amounts`, zero for fewer than two non-zero amounts
+///
+/// With the amounts sorted ascending as `x_1..x_n`, it is
+/// `(2 * sum(i * x_i) - (n + 1) * sum(x)) / (n * sum(x))`.
+pub fn gini(amounts: &[Uint128]) -> Decimal {
+    let mut sorted: Vec<Uint256> = amounts
+        .iter()
+        .filter(|amount| !amount.is_zero())
+        .map(|amount| Uint256::from(*amount))
+        .collect();
+    if sorted.len() < 2 {
+        return Decimal::zero();
+    }
+    sorted.sort();
+
+    let n = Uint256::from(sorted.len() as u128);
+    let total: Uint256 = sorted.iter().copied().sum();
+    let weighted: Uint256 = sorted
+        .iter()
+        .enumerate()
+        .map(|(index, amount)| *amount * Uint256::from(index as u128 + 1))
+        .sum();
+    let numerator = (weighted * Uint256::from(2u128)).saturating_sub((n + Uint256::one()) * total);
+    Decimal::try_from(Decimal256::from_ratio(numerator, n * total)).unwrap_or_default()
+}
+
+/// Sender of a pool creation transaction, from its events
+///
+/// The sender is the `message` event's `sender` that isn't `pool_manager`,
+/// which also appears as sender of the messages it dispatches.
+pub fn creator_from_events(events: &[Event], pool_manager: &str) -> Option<String> {
+    events
+        .iter()
+        .filter(|event| event.kind == "message")
+        .flat_map(|event| &event.attributes)
+        .filter(|attr| attr.key_str().ok() == Some("sender"))
+        .filter_map(|attr| attr.value_str().ok())
+        .find(|sender| !sender.is_empty() && *sender != pool_manager)
+        .map(str::to_string)
+}
@@ -0,0 +1,194 @@
+//! Pagination over cursor-based queries
+//!
+//! Contract list queries (pools, farms, positions) page by the key of the last
+//! item of the previous page. A [`PageCursor`] holds that key and the page size,
+//! and every query returns a [`Paginated`] page with the cursor of the following
+//! one. Cursors also remember the pages before them, so a caller can page back
+//! as well as forward. [`collect_all`] walks the pages from a cursor up to a
+//! limit.
+
+use std::future::Future;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Number of items per page unless a cursor asks for another
+pub const DEFAULT_PAGE_LIMIT: u32 = 100;
+
+/// Position and size of a page in a contract list query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    /// Key of the item before the page; `None` for the first page
+    pub start_after: Option<String>,
+    /// Most items the page holds
+    pub limit: u32,
+    /// `start_after` of each earlier page, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    previous: Vec<Option<String>>,
+}
+
+impl Default for PageCursor {
+    fn default() -> Self {
+        Self::first(DEFAULT_PAGE_LIMIT)
+    }
+}
+
+impl PageCursor {
+    /// The first page, holding up to `limit` items
+    pub fn first(limit: u32) -> Self {
+        Self {
+            start_after: None,
+            limit: limit.max(1),
+            previous: Vec::new(),
+        }
+    }
+
+    /// The page starting after the item with `key`, holding up to `limit` items
+    ///
+    /// The cursor has no earlier pages to go back to.
+    pub fn after(key: impl Into<String>, limit: u32) -> Self {
+        Self {
+            start_after: Some(key.into()),
+            ..Self::first(limit)
+        }
+    }
+
+    /// Whether the cursor points at the first page
+    pub fn is_first(&self) -> bool {
+        self.start_after.is_none()
+    }
+
+    /// Cursor of the page after this one, which ended with the item with `key`
+    pub fn next(&self, key: String) -> Self {
+        let mut previous = self.previous.clone();
+        previous.push(self.start_after.clone());
+        Self {
+            start_after: Some(key),
+            limit: self.limit,
+            previous,
+        }
+    }
+
+    /// Cursor of the page before this one, if this cursor was reached by paging
+    pub fn prev(&self) -> Option<Self> {
+        let mut previous = self.previous.clone();
+        let start_after = previous.pop()?;
+        Some(Self {
+            start_after,
+            limit: self.limit,
+            previous,
+        })
+    }
+}
+
+/// One page of a contract list query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    /// Cursor the page was fetched with
+    pub cursor: PageCursor,
+    /// Cursor of the following page, if there may be one
+    pub next: Option<PageCursor>,
+}
+
+impl<T> Paginated<T> {
+    /// Page of `items` fetched with `cursor`, keyed by `key` for the next cursor
+    ///
+    /// A full page may be followed by another; a shorter one is the last.
+    pub fn from_page(items: Vec<T>, cursor: PageCursor, key: impl Fn(&T) -> String) -> Self {
+        let next = match items.last() {
+            Some(last) if items.len() >= cursor.limit as usize => Some(cursor.next(key(last))),
+            _ => None,
+        };
+        Self {
+            items,
+            cursor,
+            next,
+        }
+    }
+
+    /// Cursor of the following page, if there may be one
+    pub fn next_page(&self) -> Option<&PageCursor> {
+        self.next.as_ref()
+    }
+
+    /// Cursor of the preceding page, if this page was reached by paging
+    pub fn prev_page(&self) -> Option<PageCursor> {
+        self.cursor.prev()
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+
+    /// Keep only the items matching `keep`, without changing the next cursor
+    pub fn retain(mut self, keep: impl FnMut(&T) -> bool) -> Self {
+        self.items.retain(keep);
+        self
+    }
+}
+
+/// Fetch pages from `cursor` onwards until the last, or until `max_items` are collected
+///
+/// `fetch` queries the page for a cursor, e.g.
+/// `|cursor| async move { client.get_pools(&cursor).await }`.
+///
+/// # Errors
+///
+/// Returns the first error `fetch` returns
+pub async fn collect_all<T, F, Fut>(
+    cursor: PageCursor,
+    max_items: Option<usize>,
+    mut fetch: F,
+) -> Result<Vec<T>, Error>
+where
+    F: FnMut(PageCursor) -> Fut,
+    Fut: Future<Output = Result<Paginated<T>, Error>>,
+{
+    let mut items = Vec::new();
+    let mut cursor = Some(cursor);
+    while let Some(current) = cursor.take() {
+        if max_items.is_some_and(|max| items.len() >= max) {
+            break;
+        }
+        let page = fetch(current).await?;
+        cursor = page.next;
+        items.extend(page.items);
+    }
+    if let Some(max) = max_items {
+        items.truncate(max);
+    }
+    Ok(items)
+}
+
+/// Turn a page-fetching function into a stream of items
+///
+/// `fetch` is called with the cursor for the next page and returns the page's
+/// items plus the cursor of the following page, or `None` when there are no more
+/// pages. A page is only requested once the consumer has drained the previous
+/// one. After an error the stream yields that error and ends.
+pub(crate) fn paginate<'a, T, C, F, Fut>(first: C, fetch: F) -> BoxStream<'a, Result<T, Error>>
+where
+    T: Send + 'a,
+    C: Send + 'a,
+    F: FnMut(C) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<(Vec<T>, Option<C>), Error>> + Send + 'a,
+{
+    stream::unfold((Some(first), fetch), |(cursor, mut fetch)| async move {
+        let cursor = cursor?;
+        match fetch(cursor).await {
+            Ok((items, next)) => Some((Ok(items), (next, fetch))),
+            Err(e) => Some((Err(e), (None, fetch))),
+        }
+    })
+    .flat_map(|page| {
+        let items: Vec<Result<T, Error>> = match page {
+            Ok(items) => items.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        };
+        stream::iter(items)
+    })
+    .boxed()
+}
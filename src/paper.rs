@@ -0,0 +1,389 @@
+//! Paper trading
+//!
+//! A [`PaperPortfolio`] is a virtual account: its balances, the LP tokens among
+//! them, and the trades that changed them. Trades are priced against live pool
+//! state, swaps by the pool manager's swap simulation and liquidity at the
+//! pool's current reserves, then applied to the virtual balances only; nothing
+//! is signed or broadcast. [`MantraDexClient::paper_swap`] and its siblings do
+//! the pricing.
+//!
+//! The P&L of a portfolio compares its value with that of its starting
+//! balances, both at current prices: what the trades gained or lost over
+//! holding.
+//!
+//! [`MantraDexClient::paper_swap`]: crate::MantraDexClient::paper_swap
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Int128, Uint128, Uint256};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, SimulationResponse};
+use serde::{Deserialize, Serialize};
+
+use crate::convert::PriceBook;
+use crate::error::Error;
+use crate::math;
+use crate::positions::{lp_position, LpPosition};
+use crate::schema::{self, Format, Schema};
+
+const PAPER_SCHEMA: Schema = Schema::new("paper portfolio", Format::Json, &[]);
+
+/// What a paper trade did
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaperTradeKind {
+    Swap,
+    ProvideLiquidity,
+    WithdrawLiquidity,
+}
+
+impl fmt::Display for PaperTradeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PaperTradeKind::Swap => "swap",
+            PaperTradeKind::ProvideLiquidity => "provide liquidity",
+            PaperTradeKind::WithdrawLiquidity => "withdraw liquidity",
+        })
+    }
+}
+
+/// One trade applied to a paper portfolio
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperTrade {
+    pub kind: PaperTradeKind,
+    pub pool_id: String,
+    /// Tokens taken from the virtual balances
+    pub spent: Vec<Coin>,
+    /// Tokens added to the virtual balances
+    pub received: Vec<Coin>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A paper portfolio valued at current prices
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperValuation {
+    /// Denom the values are expressed in
+    pub quote: String,
+    /// See [`PaperPortfolio::value`]
+    pub value: Option<Uint128>,
+    /// See [`PaperPortfolio::starting_value`]
+    pub starting_value: Option<Uint128>,
+    /// See [`PaperPortfolio::pnl`]
+    pub pnl: Option<Int128>,
+    pub positions: Vec<LpPosition>,
+}
+
+/// Virtual balances and the trades that changed them
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperPortfolio {
+    starting_balances: Vec<Coin>,
+    balances: BTreeMap<String, Uint128>,
+    trades: Vec<PaperTrade>,
+}
+
+impl PaperPortfolio {
+    /// Start a portfolio holding `starting_balances`
+    pub fn new(starting_balances: Vec<Coin>) -> Self {
+        let mut balances = BTreeMap::new();
+        for coin in &starting_balances {
+            *balances.entry(coin.denom.clone()).or_default() += coin.amount;
+        }
+        Self {
+            starting_balances,
+            balances,
+            trades: Vec::new(),
+        }
+    }
+
+    /// Get the default paper portfolio file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("paper_portfolio.json");
+        path
+    }
+
+    /// Load a portfolio saved with [`save`](Self::save)
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        schema::read(&PAPER_SCHEMA, path)
+    }
+
+    /// Save the portfolio to a file, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        schema::write(&PAPER_SCHEMA, path, self)
+    }
+
+    /// Balances the portfolio started with
+    pub fn starting_balances(&self) -> &[Coin] {
+        &self.starting_balances
+    }
+
+    /// Current virtual balances, LP tokens included, without empty ones
+    pub fn balances(&self) -> Vec<Coin> {
+        self.balances
+            .iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(denom, amount)| Coin {
+                denom: denom.clone(),
+                amount: *amount,
+            })
+            .collect()
+    }
+
+    /// Current virtual balance of `denom`
+    pub fn balance(&self, denom: &str) -> Uint128 {
+        self.balances.get(denom).copied().unwrap_or_default()
+    }
+
+    /// Trades applied so far, oldest first
+    pub fn trades(&self) -> &[PaperTrade] {
+        &self.trades
+    }
+
+    /// Apply a trade to the virtual balances
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if a balance can't cover what the trade spends;
+    /// the portfolio is left unchanged
+    pub fn record(
+        &mut self,
+        kind: PaperTradeKind,
+        pool_id: &str,
+        spent: Vec<Coin>,
+        received: Vec<Coin>,
+    ) -> Result<&PaperTrade, Error> {
+        for coin in &spent {
+            let available = self.balance(&coin.denom);
+            if available < coin.amount {
+                return Err(Error::Wallet(format!(
+                    "Insufficient paper balance of {}: needs {}, {} left",
+                    coin.denom, coin.amount, available
+                )));
+            }
+        }
+        for coin in &spent {
+            let balance = self.balances.entry(coin.denom.clone()).or_default();
+            *balance -= coin.amount;
+        }
+        for coin in &received {
+            let balance = self.balances.entry(coin.denom.clone()).or_default();
+            *balance = balance.saturating_add(coin.amount);
+        }
+
+        self.trades.push(PaperTrade {
+            kind,
+            pool_id: pool_id.to_string(),
+            spent,
+            received,
+            timestamp: Utc::now(),
+        });
+        Ok(self.trades.last().expect("trade was just recorded"))
+    }
+
+    /// Swap `offer_asset` for the return of `simulation`, a swap simulation in `pool_id`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if the virtual balance can't cover `offer_asset`
+    pub fn swap(
+        &mut self,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        simulation: &SimulationResponse,
+    ) -> Result<&PaperTrade, Error> {
+        let received = Coin {
+            denom: ask_asset_denom.to_string(),
+            amount: simulation.return_amount,
+        };
+        self.record(
+            PaperTradeKind::Swap,
+            pool_id,
+            vec![offer_asset],
+            vec![received],
+        )
+    }
+
+    /// Deposit `assets` into `pool` at its current reserves for LP tokens
+    ///
+    /// The LP tokens are the smallest share of the supply any asset buys, as the
+    /// pool manager mints for a balanced deposit. Only the assets matching the
+    /// pool's ratio at that share are spent; the rest stays in the balances.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Other`] if the pool is empty, or `assets` miss one of its
+    ///   assets or buy no LP tokens
+    /// * Returns [`Error::Wallet`] if the virtual balances can't cover the deposit
+    pub fn provide_liquidity(
+        &mut self,
+        pool: &PoolInfoResponse,
+        assets: &[Coin],
+    ) -> Result<&PaperTrade, Error> {
+        let info = &pool.pool_info;
+        let total_share = pool.total_share.amount;
+        let unavailable = |reason: &str| {
+            Error::Other(format!(
+                "Cannot paper trade liquidity in pool {}: {}",
+                info.pool_identifier, reason
+            ))
+        };
+        if total_share.is_zero() {
+            return Err(unavailable("the pool is empty"));
+        }
+
+        let mut minted: Option<Uint128> = None;
+        for reserve in &info.assets {
+            let offered = assets
+                .iter()
+                .find(|coin| coin.denom == reserve.denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default();
+            if reserve.amount.is_zero() {
+                return Err(unavailable("a reserve is empty"));
+            }
+            let share = offered.multiply_ratio(total_share, reserve.amount);
+            minted = Some(minted.map_or(share, |minted| minted.min(share)));
+        }
+        let minted = minted
+            .filter(|minted| !minted.is_zero())
+            .ok_or_else(|| unavailable("the deposit buys no LP tokens"))?;
+
+        let spent = info
+            .assets
+            .iter()
+            .map(|reserve| Coin {
+                denom: reserve.denom.clone(),
+                amount: ratio_ceil(reserve.amount, minted, total_share),
+            })
+            .filter(|coin| !coin.amount.is_zero())
+            .collect();
+        let lp_tokens = Coin {
+            denom: info.lp_denom.clone(),
+            amount: minted,
+        };
+        self.record(
+            PaperTradeKind::ProvideLiquidity,
+            &info.pool_identifier,
+            spent,
+            vec![lp_tokens],
+        )
+    }
+
+    /// Burn `lp_amount` LP tokens of `pool` for its assets at the current reserves
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Other`] if `lp_amount` is zero or exceeds the pool's LP supply
+    /// * Returns [`Error::Wallet`] if the portfolio holds fewer LP tokens
+    pub fn withdraw_liquidity(
+        &mut self,
+        pool: &PoolInfoResponse,
+        lp_amount: Uint128,
+    ) -> Result<&PaperTrade, Error> {
+        let info = &pool.pool_info;
+        let refund = math::withdrawal_refund(pool, lp_amount).ok_or_else(|| {
+            Error::Other(format!(
+                "Cannot withdraw {} of pool {}'s {} LP tokens",
+                lp_amount, info.pool_identifier, pool.total_share.amount
+            ))
+        })?;
+        let lp_tokens = Coin {
+            denom: info.lp_denom.clone(),
+            amount: lp_amount,
+        };
+        self.record(
+            PaperTradeKind::WithdrawLiquidity,
+            &info.pool_identifier,
+            vec![lp_tokens],
+            refund,
+        )
+    }
+
+    /// Liquidity positions held, valued in `quote`
+    pub fn positions(
+        &self,
+        pools: &[PoolInfoResponse],
+        prices: &PriceBook<'_>,
+        quote: &str,
+    ) -> Vec<LpPosition> {
+        pools
+            .iter()
+            .filter_map(|pool| {
+                let lp_amount = self.balance(&pool.pool_info.lp_denom);
+                (!lp_amount.is_zero()).then(|| lp_position(pool, lp_amount, &[], prices, quote))
+            })
+            .collect()
+    }
+
+    /// Value of the balances in `quote`, LP tokens at their share of the reserves
+    ///
+    /// `None` if a balance can't be priced.
+    pub fn value(
+        &self,
+        pools: &[PoolInfoResponse],
+        prices: &PriceBook<'_>,
+        quote: &str,
+    ) -> Option<Uint128> {
+        self.balances()
+            .iter()
+            .try_fold(Uint128::zero(), |total, coin| {
+                let value = match pools
+                    .iter()
+                    .find(|pool| pool.pool_info.lp_denom == coin.denom)
+                {
+                    Some(pool) => lp_position(pool, coin.amount, &[], prices, quote).value?,
+                    None => prices.convert(coin.amount, &coin.denom, quote)?.to.amount,
+                };
+                Some(total.saturating_add(value))
+            })
+    }
+
+    /// Value of the starting balances in `quote` at current prices
+    pub fn starting_value(&self, prices: &PriceBook<'_>, quote: &str) -> Option<Uint128> {
+        self.starting_balances
+            .iter()
+            .try_fold(Uint128::zero(), |total, coin| {
+                let converted = prices.convert(coin.amount, &coin.denom, quote)?;
+                Some(total.saturating_add(converted.to.amount))
+            })
+    }
+
+    /// Value gained over holding the starting balances, in `quote`
+    pub fn pnl(
+        &self,
+        pools: &[PoolInfoResponse],
+        prices: &PriceBook<'_>,
+        quote: &str,
+    ) -> Option<Int128> {
+        let value = i128::try_from(self.value(pools, prices, quote)?.u128()).ok()?;
+        let starting_value = i128::try_from(self.starting_value(prices, quote)?.u128()).ok()?;
+        Some(Int128::new(value.saturating_sub(starting_value)))
+    }
+
+    /// Value, P&L and positions in `quote`
+    pub fn valuation(
+        &self,
+        pools: &[PoolInfoResponse],
+        prices: &PriceBook<'_>,
+        quote: &str,
+    ) -> PaperValuation {
+        PaperValuation {
+            quote: quote.to_string(),
+            value: self.value(pools, prices, quote),
+            starting_value: self.starting_value(prices, quote),
+            pnl: self.pnl(pools, prices, quote),
+            positions: self.positions(pools, prices, quote),
+        }
+    }
+}
+
+/// `amount * numerator / denominator`, rounded up
+fn ratio_ceil(amount: Uint128, numerator: Uint128, denominator: Uint128) -> Uint128 {
+    let denominator = Uint256::from(denominator);
+    let ceil = (amount.full_mul(numerator) + denominator - Uint256::one()) / denominator;
+    ceil.try_into().unwrap_or(Uint128::MAX)
+}
@@ -0,0 +1,204 @@
+//! Client-side safety policies for trades
+//!
+//! Policies refuse operations that are almost certainly mistakes before they reach
+//! the chain. A refusal is reported as [`Error::Policy`] and can be bypassed
+//! explicitly by the caller.
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Default share of the ask-side reserve a single swap may take
+pub const DEFAULT_MAX_RESERVE_FRACTION: Decimal = Decimal::percent(10);
+
+/// Blocks swaps that would take a large share of a pool's reserves
+///
+/// Such trades move the price so far that execution is nearly always terrible,
+/// which usually means the amount or the pool is wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeSizeGuard {
+    /// Whether the guard is enforced
+    pub enabled: bool,
+    /// Maximum share of the ask-side reserve a swap may return
+    pub max_reserve_fraction: Decimal,
+}
+
+impl Default for TradeSizeGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RESERVE_FRACTION)
+    }
+}
+
+impl TradeSizeGuard {
+    /// Create an enabled guard with the given reserve share limit
+    pub fn new(max_reserve_fraction: Decimal) -> Self {
+        Self {
+            enabled: true,
+            max_reserve_fraction,
+        }
+    }
+
+    /// A guard that allows every trade
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Check a simulated swap against the ask-side reserve of its pool
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if the swap would return more than
+    /// `max_reserve_fraction` of `ask_reserve`
+    pub fn check(
+        &self,
+        pool_id: &str,
+        ask_denom: &str,
+        expected_return: Uint128,
+        ask_reserve: Uint128,
+    ) -> Result<(), Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if ask_reserve.is_zero() {
+            return Err(Error::Policy(format!(
+                "Pool {} has no {} liquidity",
+                pool_id, ask_denom
+            )));
+        }
+
+        let fraction = Decimal::from_ratio(expected_return, ask_reserve);
+        if fraction > self.max_reserve_fraction {
+            return Err(Error::Policy(format!(
+                "Swap would take {}% of the {} reserve in pool {} (limit {}%); use force to override",
+                fraction * Decimal::percent(10_000),
+                ask_denom,
+                pool_id,
+                self.max_reserve_fraction * Decimal::percent(10_000),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Blocks swaps worth more than a set amount of another asset
+///
+/// The offer is valued at pool spot prices (see [`crate::convert`]), so a limit
+/// can be set once in a stablecoin or [`USD`](crate::convert::USD) and apply to
+/// swaps of any asset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeValueLimit {
+    /// Highest value a swap may offer, in base units of `denom`
+    pub max_value: Uint128,
+    /// Denom the offer is valued in
+    pub denom: String,
+}
+
+impl TradeValueLimit {
+    /// Create a limit of `max_value` base units of `denom`
+    pub fn new(max_value: Uint128, denom: impl Into<String>) -> Self {
+        Self {
+            max_value,
+            denom: denom.into(),
+        }
+    }
+
+    /// Check the value of an offer against the limit
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if `value`, the offer valued in `denom`, is
+    /// above `max_value`
+    pub fn check(&self, offer: &Coin, value: Uint128) -> Result<(), Error> {
+        if value > self.max_value {
+            return Err(Error::Policy(format!(
+                "Swap of {} is worth {}{}, above the limit of {}{}; use force to override",
+                offer, value, self.denom, self.max_value, self.denom,
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Slippage tolerance the pool manager applies to swaps that don't set one
+pub const DEFAULT_SWAP_SLIPPAGE: Decimal = Decimal::percent(1);
+
+/// Default slippage tolerance added on each retry of a swap
+pub const DEFAULT_SLIPPAGE_BUMP: Decimal = Decimal::percent(1);
+
+/// Default ceiling retries may raise the slippage tolerance to
+pub const DEFAULT_MAX_RETRY_SLIPPAGE: Decimal = Decimal::percent(5);
+
+/// Default number of retries of a swap that keeps failing on slippage
+pub const DEFAULT_MAX_SLIPPAGE_RETRIES: u32 = 3;
+
+/// How far swaps that failed on slippage may be retried with a higher tolerance
+///
+/// Every retry raises the tolerance by `step`, or to the slippage a fresh
+/// simulation expects if that is higher, but never beyond `max_slippage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlippageBumpPolicy {
+    /// Tolerance added on each retry
+    pub step: Decimal,
+    /// Highest tolerance a retry may use
+    pub max_slippage: Decimal,
+    /// Retries made by automatic retrying before giving up
+    pub max_retries: u32,
+}
+
+impl Default for SlippageBumpPolicy {
+    fn default() -> Self {
+        Self {
+            step: DEFAULT_SLIPPAGE_BUMP,
+            max_slippage: DEFAULT_MAX_RETRY_SLIPPAGE,
+            max_retries: DEFAULT_MAX_SLIPPAGE_RETRIES,
+        }
+    }
+}
+
+impl SlippageBumpPolicy {
+    /// Create a policy with the given step and ceiling and the default retry count
+    pub fn new(step: Decimal, max_slippage: Decimal) -> Self {
+        Self {
+            step,
+            max_slippage,
+            ..Self::default()
+        }
+    }
+
+    /// Tolerance for the retry of a swap that failed with `previous`
+    ///
+    /// `simulated` is the slippage a fresh simulation of the swap expects.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if `previous` already reached the ceiling, or the
+    /// simulated slippage is above it
+    pub fn next_slippage(&self, previous: Decimal, simulated: Decimal) -> Result<Decimal, Error> {
+        let percent = |d: Decimal| d * Decimal::percent(10_000);
+        if previous >= self.max_slippage {
+            return Err(Error::Policy(format!(
+                "Slippage tolerance of {}% already reached the retry limit of {}%",
+                percent(previous),
+                percent(self.max_slippage),
+            )));
+        }
+        if simulated > self.max_slippage {
+            return Err(Error::Policy(format!(
+                "Swap is expected to slip {}%, above the retry limit of {}%",
+                percent(simulated),
+                percent(self.max_slippage),
+            )));
+        }
+
+        Ok(previous
+            .saturating_add(self.step)
+            .max(simulated)
+            .min(self.max_slippage))
+    }
+}
@@ -0,0 +1,179 @@
+//! Pool creation templates
+//!
+//! A [`PoolTemplate`] fills in the pool type and fees suited to a kind of pair:
+//! pegged assets trade in a stable swap pool at a tiny fee, while regular and
+//! exotic pairs trade in constant product pools at fees covering their risk.
+//! The [`PoolSpec`] a template returns can be changed before the pool is
+//! created, and checks its fees the same way the client does.
+
+use std::fmt;
+use std::str::FromStr;
+
+use cosmwasm_std::Decimal;
+use mantra_dex_std::fee::{Fee, PoolFee};
+use mantra_dex_std::pool_manager::PoolType;
+use serde::{Deserialize, Serialize};
+
+use crate::client::check_pool_fees;
+use crate::error::Error;
+
+/// Amplification of the stable swap pools created from templates
+pub const DEFAULT_STABLE_AMP: u64 = 100;
+
+/// A kind of pair pools are commonly created for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolTemplate {
+    /// Assets pegged to each other, 0.01% fee
+    Stable,
+    /// Regular pair, 0.3% fee
+    Volatile,
+    /// Thinly traded or risky pair, 1% fee
+    Exotic,
+}
+
+impl PoolTemplate {
+    /// Every template, from the lowest fee to the highest
+    pub const ALL: [PoolTemplate; 3] = [
+        PoolTemplate::Stable,
+        PoolTemplate::Volatile,
+        PoolTemplate::Exotic,
+    ];
+
+    /// Name the template is selected by
+    pub fn name(&self) -> &'static str {
+        match self {
+            PoolTemplate::Stable => "stable",
+            PoolTemplate::Volatile => "volatile",
+            PoolTemplate::Exotic => "exotic",
+        }
+    }
+
+    /// One line description of the pairs the template suits
+    pub fn description(&self) -> &'static str {
+        match self {
+            PoolTemplate::Stable => "Pegged assets such as stablecoins, stable swap pool",
+            PoolTemplate::Volatile => "Regular pair, constant product pool",
+            PoolTemplate::Exotic => "Thinly traded or risky pair, constant product pool",
+        }
+    }
+
+    /// Pool type and fees of the template
+    ///
+    /// A fifth of the total fee goes to the protocol and the rest to liquidity
+    /// providers; nothing is burned.
+    pub fn spec(&self) -> PoolSpec {
+        let (pool_type, swap_fee, protocol_fee) = match self {
+            PoolTemplate::Stable => (
+                PoolType::StableSwap {
+                    amp: DEFAULT_STABLE_AMP,
+                },
+                Decimal::from_ratio(8u128, 100_000u128),
+                Decimal::from_ratio(2u128, 100_000u128),
+            ),
+            PoolTemplate::Volatile => (
+                PoolType::ConstantProduct,
+                Decimal::from_ratio(24u128, 10_000u128),
+                Decimal::from_ratio(6u128, 10_000u128),
+            ),
+            PoolTemplate::Exotic => (
+                PoolType::ConstantProduct,
+                Decimal::permille(8),
+                Decimal::permille(2),
+            ),
+        };
+        PoolSpec {
+            pool_type,
+            swap_fee,
+            protocol_fee,
+            burn_fee: Decimal::zero(),
+        }
+    }
+}
+
+impl fmt::Display for PoolTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for PoolTemplate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PoolTemplate::ALL
+            .into_iter()
+            .find(|template| template.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "Unknown pool template '{}', expected one of: stable, volatile, exotic",
+                    s
+                ))
+            })
+    }
+}
+
+/// Pool type and fee shares of a pool to create
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoolSpec {
+    pub pool_type: PoolType,
+    /// Share of each swap paid to liquidity providers
+    pub swap_fee: Decimal,
+    /// Share of each swap paid to the protocol
+    pub protocol_fee: Decimal,
+    /// Share of each swap burned
+    pub burn_fee: Decimal,
+}
+
+impl PoolSpec {
+    /// Set the pool type
+    pub fn with_pool_type(mut self, pool_type: PoolType) -> Self {
+        self.pool_type = pool_type;
+        self
+    }
+
+    /// Set the share of each swap paid to liquidity providers
+    pub fn with_swap_fee(mut self, swap_fee: Decimal) -> Self {
+        self.swap_fee = swap_fee;
+        self
+    }
+
+    /// Set the share of each swap paid to the protocol
+    pub fn with_protocol_fee(mut self, protocol_fee: Decimal) -> Self {
+        self.protocol_fee = protocol_fee;
+        self
+    }
+
+    /// Set the share of each swap burned
+    pub fn with_burn_fee(mut self, burn_fee: Decimal) -> Self {
+        self.burn_fee = burn_fee;
+        self
+    }
+
+    /// Sum of the fee shares
+    pub fn total_fee(&self) -> Decimal {
+        self.swap_fee + self.protocol_fee + self.burn_fee
+    }
+
+    /// Fee structure of the pool
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FeeValidation`] if the fees exceed the 20% allowed in total
+    pub fn pool_fees(&self) -> Result<PoolFee, Error> {
+        let pool_fees = PoolFee {
+            protocol_fee: Fee {
+                share: self.protocol_fee,
+            },
+            swap_fee: Fee {
+                share: self.swap_fee,
+            },
+            burn_fee: Fee {
+                share: self.burn_fee,
+            },
+            extra_fees: vec![],
+        };
+        check_pool_fees(&pool_fees)?;
+        Ok(pool_fees)
+    }
+}
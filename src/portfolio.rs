@@ -0,0 +1,128 @@
+//! Portfolio valuation
+//!
+//! A [`PortfolioValuation`] is everything an address holds, valued in one quote
+//! denom at pool spot prices (see [`PriceBook`]): its wallet balances, its LP
+//! positions, liquid and locked in farm positions, and the farm rewards it could
+//! claim now. The quote is any denom pools price, e.g. a USD stablecoin, or
+//! [`USD`](crate::convert::USD) to value through the configured stablecoins.
+//!
+//! LP tokens are valued through their position's share of the pool reserves
+//! rather than as balances, so they are counted once. Holdings no pool prices
+//! are listed with no value and left out of the totals; see
+//! [`PortfolioValuation::unpriced`].
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::convert::PriceBook;
+use crate::positions::LpPosition;
+use crate::report::HoldingValue;
+
+/// An address's holdings valued in one quote denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortfolioValuation {
+    pub address: String,
+    /// Denom the values are expressed in
+    pub quote: String,
+    pub taken_at: DateTime<Utc>,
+    /// Wallet balances, LP tokens excepted
+    pub balances: Vec<HoldingValue>,
+    /// LP positions, valued at their share of the pool reserves
+    pub positions: Vec<LpPosition>,
+    /// Farm rewards the address could claim now
+    pub pending_rewards: Vec<HoldingValue>,
+}
+
+impl PortfolioValuation {
+    /// Value `balances`, `positions` and `pending_rewards` in `quote` at the prices of `prices`
+    ///
+    /// Balances of the positions' LP tokens are left out, since the positions
+    /// value them.
+    pub fn new(
+        address: impl Into<String>,
+        balances: Vec<Coin>,
+        positions: Vec<LpPosition>,
+        pending_rewards: Vec<Coin>,
+        prices: &PriceBook<'_>,
+        quote: &str,
+        taken_at: DateTime<Utc>,
+    ) -> Self {
+        let value = |coin: Coin| HoldingValue {
+            value: prices
+                .convert(coin.amount, &coin.denom, quote)
+                .map(|conversion| conversion.to.amount),
+            coin,
+        };
+        let balances = balances
+            .into_iter()
+            .filter(|coin| {
+                !positions
+                    .iter()
+                    .any(|position| position.lp_denom == coin.denom)
+            })
+            .map(value)
+            .collect();
+        let pending_rewards = pending_rewards.into_iter().map(value).collect();
+
+        Self {
+            address: address.into(),
+            quote: quote.to_string(),
+            taken_at,
+            balances,
+            positions,
+            pending_rewards,
+        }
+    }
+
+    /// Value of the priced wallet balances
+    pub fn balances_value(&self) -> Uint128 {
+        sum(self.balances.iter().filter_map(|holding| holding.value))
+    }
+
+    /// Value of the priced LP positions
+    pub fn positions_value(&self) -> Uint128 {
+        sum(self.positions.iter().filter_map(|position| position.value))
+    }
+
+    /// Value of the priced pending rewards
+    pub fn rewards_value(&self) -> Uint128 {
+        sum(self
+            .pending_rewards
+            .iter()
+            .filter_map(|holding| holding.value))
+    }
+
+    /// Value of everything priced: balances, LP positions and pending rewards
+    pub fn total_value(&self) -> Uint128 {
+        self.balances_value()
+            .saturating_add(self.positions_value())
+            .saturating_add(self.rewards_value())
+    }
+
+    /// Denoms of the holdings no pool prices, left out of the totals
+    ///
+    /// LP positions are listed by their LP denom.
+    pub fn unpriced(&self) -> Vec<&str> {
+        let mut denoms: Vec<&str> = self
+            .balances
+            .iter()
+            .chain(&self.pending_rewards)
+            .filter(|holding| holding.value.is_none() && !holding.coin.amount.is_zero())
+            .map(|holding| holding.coin.denom.as_str())
+            .chain(
+                self.positions
+                    .iter()
+                    .filter(|position| position.value.is_none())
+                    .map(|position| position.lp_denom.as_str()),
+            )
+            .collect();
+        denoms.sort_unstable();
+        denoms.dedup();
+        denoms
+    }
+}
+
+fn sum(values: impl Iterator<Item = Uint128>) -> Uint128 {
+    values.fold(Uint128::zero(), Uint128::saturating_add)
+}
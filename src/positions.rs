@@ -0,0 +1,592 @@
+//! LP positions with their cost basis and performance
+//!
+//! A wallet's positions are the LP tokens it holds. Each is valued from the
+//! pool's current reserves and compared with what was deposited for it, as
+//! recorded by the `provide_liquidity` and `withdraw_liquidity` events in the
+//! wallet's [`TxHistory`](crate::history::TxHistory). Withdrawals reduce the
+//! cost basis in proportion to the LP tokens burned.
+//!
+//! The deposit is valued at current prices, so the difference to the position's
+//! value is what providing liquidity gained over holding the deposited assets.
+//! For two-asset constant product pools that difference is split into
+//! impermanent loss, from the move of the pool price since the deposit, and the
+//! swap fees earned.
+//!
+//! LP tokens locked in farm positions still belong to the position, but can't
+//! be withdrawn from the pool until the farm position is closed and its
+//! unlocking period has passed; see [`LpLock`].
+//!
+//! [`FarmPosition`] describes a farm position by itself, with the epochs it was
+//! opened and unlocks in. The farm manager doesn't record when a position was
+//! opened, so [`position_openings`] reads that from the wallet's history.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Decimal, Decimal256, Int128, Uint128, Uint256};
+use mantra_dex_std::farm_manager::Position;
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::DexEvent;
+use crate::convert::PriceBook;
+use crate::epoch::EpochCalendar;
+use crate::error::Error;
+use crate::gas::GasEstimate;
+use crate::history::HistoryEntry;
+use crate::math;
+
+/// Liquidity added to or removed from a pool by one transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiquidityChange {
+    pub height: u64,
+    pub tx_hash: String,
+    pub pool_id: String,
+    /// Assets deposited, or returned by a withdrawal
+    pub assets: Vec<Coin>,
+    /// LP tokens minted, or burned by a withdrawal
+    pub lp_amount: Uint128,
+    /// Whether liquidity was provided rather than withdrawn
+    pub provided: bool,
+}
+
+impl LiquidityChange {
+    /// Read a pool manager `provide_liquidity` or `withdraw_liquidity` event
+    pub fn from_event(event: &DexEvent) -> Option<Self> {
+        let attribute = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| event.attributes.get(*key))
+                .map(String::as_str)
+        };
+        let (provided, assets, lp_amount) = match event.action.as_deref()? {
+            "provide_liquidity" => (true, attribute(&["assets"])?, attribute(&["share"])?),
+            "withdraw_liquidity" => (
+                false,
+                attribute(&["refund_assets", "return_assets"])?,
+                attribute(&["withdrawn_share", "share"])?,
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            height: event.height,
+            tx_hash: event.tx_hash.clone(),
+            pool_id: event.attributes.get("pool_identifier")?.clone(),
+            assets: parse_coins(assets)?,
+            lp_amount: Uint128::from_str(lp_amount).ok()?,
+            provided,
+        })
+    }
+}
+
+/// Parse a comma separated list of coins such as `100uom, 250uusdc`
+fn parse_coins(value: &str) -> Option<Vec<Coin>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|coin| !coin.is_empty())
+        .map(|coin| Coin::from_str(coin).ok())
+        .collect()
+}
+
+/// LP tokens locked in a farm position
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LpLock {
+    /// Identifier of the farm position
+    pub position_id: String,
+    pub lp_denom: String,
+    pub amount: Uint128,
+    /// Seconds the tokens stay locked once the position is closed
+    pub unlocking_duration: u64,
+    /// When the tokens of a closed position can be withdrawn, in seconds since
+    /// the Unix epoch; `None` while the position is open
+    pub unlocks_at: Option<u64>,
+}
+
+impl LpLock {
+    pub fn from_position(position: &Position) -> Self {
+        Self {
+            position_id: position.identifier.clone(),
+            lp_denom: position.lp_asset.denom.clone(),
+            amount: position.lp_asset.amount,
+            unlocking_duration: position.unlocking_duration,
+            unlocks_at: if position.open {
+                None
+            } else {
+                position.expiring_at
+            },
+        }
+    }
+
+    /// Whether the position was closed and its tokens are waiting to unlock
+    pub fn is_unlocking(&self) -> bool {
+        self.unlocks_at.is_some()
+    }
+
+    /// Whether the tokens can be withdrawn from the farm at `now`, in seconds
+    /// since the Unix epoch
+    pub fn is_unlocked(&self, now: u64) -> bool {
+        self.unlocks_at.is_some_and(|unlocks_at| unlocks_at <= now)
+    }
+
+    /// When the tokens unlock, as of `now` in seconds since the Unix epoch
+    pub fn unlock_status(&self, now: u64) -> String {
+        match self.unlocks_at {
+            None => format!(
+                "locked while the farm position is open, then for {}",
+                duration_text(self.unlocking_duration)
+            ),
+            Some(_) if self.is_unlocked(now) => {
+                "unlocked, withdraw it from the farm position".to_string()
+            }
+            Some(unlocks_at) => DateTime::<Utc>::from_timestamp(unlocks_at as i64, 0).map_or_else(
+                || "unlocking".to_string(),
+                |time| format!("unlocks at {}", time.format("%Y-%m-%d %H:%M UTC")),
+            ),
+        }
+    }
+}
+
+/// A number of seconds in the largest whole unit, e.g. `3 days`
+fn duration_text(seconds: u64) -> String {
+    let (count, unit) = match seconds {
+        s if s >= 86_400 => (s / 86_400, "day"),
+        s if s >= 3_600 => (s / 3_600, "hour"),
+        s if s >= 60 => (s / 60, "minute"),
+        s => (s, "second"),
+    };
+    format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+}
+
+/// A farm position with the epochs it was opened and unlocks in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FarmPosition {
+    /// Identifier of the position, used to query, close and expand it
+    pub identifier: String,
+    pub lp_asset: Coin,
+    /// Seconds the tokens stay locked once the position is closed
+    pub unlocking_duration: u64,
+    pub open: bool,
+    /// Epoch the position was opened in, if the history records its opening
+    pub start_epoch: Option<u64>,
+    /// Epoch the tokens of a closed position unlock in
+    pub unlock_epoch: Option<u64>,
+    /// When the tokens of a closed position unlock
+    pub unlocks_at: Option<DateTime<Utc>>,
+}
+
+impl FarmPosition {
+    /// Describe `position`, opened at `opened_at` if known, with the epochs of `calendar`
+    pub fn new(
+        position: &Position,
+        calendar: &EpochCalendar,
+        opened_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        let unlocks_at = LpLock::from_position(position)
+            .unlocks_at
+            .and_then(|unlocks_at| DateTime::<Utc>::from_timestamp(unlocks_at as i64, 0));
+        Self {
+            identifier: position.identifier.clone(),
+            lp_asset: position.lp_asset.clone(),
+            unlocking_duration: position.unlocking_duration,
+            open: position.open,
+            start_epoch: opened_at.map(|time| calendar.epoch_at(time)),
+            unlock_epoch: unlocks_at.map(|time| calendar.epoch_at(time)),
+            unlocks_at,
+        }
+    }
+
+    /// `open`, `unlocking` or `unlocked` at `now`
+    pub fn status(&self, now: DateTime<Utc>) -> &'static str {
+        match self.unlocks_at {
+            _ if self.open => "open",
+            Some(unlocks_at) if unlocks_at > now => "unlocking",
+            _ => "unlocked",
+        }
+    }
+}
+
+/// When each farm position in `entries` was opened, by position identifier
+///
+/// Read from the farm manager's `open_position` events; positions opened by
+/// transactions the history lacks, or without a known block time, are left out.
+pub fn position_openings(entries: &[HistoryEntry]) -> BTreeMap<String, DateTime<Utc>> {
+    let mut openings = BTreeMap::new();
+    for entry in entries.iter().filter(|entry| entry.success) {
+        let Some(timestamp) = entry.timestamp else {
+            continue;
+        };
+        for event in &entry.events {
+            if event.action.as_deref() != Some("open_position") {
+                continue;
+            }
+            let identifier = ["position_identifier", "identifier"]
+                .iter()
+                .find_map(|key| event.attributes.get(*key));
+            if let Some(identifier) = identifier {
+                openings.entry(identifier.clone()).or_insert(timestamp);
+            }
+        }
+    }
+    openings
+}
+
+/// Liquid and locked LP tokens of one pool held by a wallet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LpBalance {
+    pub lp_denom: String,
+    /// Tokens in the wallet, which can be withdrawn from the pool
+    pub liquid: Uint128,
+    /// Farm positions holding the wallet's other tokens
+    pub locks: Vec<LpLock>,
+}
+
+impl LpBalance {
+    /// Tokens locked in farm positions
+    pub fn locked(&self) -> Uint128 {
+        self.locks.iter().fold(Uint128::zero(), |total, lock| {
+            total.saturating_add(lock.amount)
+        })
+    }
+
+    /// Liquid and locked tokens together
+    pub fn total(&self) -> Uint128 {
+        self.liquid.saturating_add(self.locked())
+    }
+
+    /// Check that `amount` tokens can be withdrawn from the pool
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] naming the locked amount and the earliest unlock
+    /// if `amount` exceeds the liquid tokens
+    pub fn check_withdrawal(&self, amount: Uint128) -> Result<(), Error> {
+        if amount <= self.liquid {
+            return Ok(());
+        }
+        let next_unlock = self
+            .locks
+            .iter()
+            .filter_map(|lock| lock.unlocks_at)
+            .min()
+            .and_then(|unlocks_at| DateTime::<Utc>::from_timestamp(unlocks_at as i64, 0))
+            .map(|time| {
+                format!(
+                    "; the next unlocks at {}",
+                    time.format("%Y-%m-%d %H:%M UTC")
+                )
+            })
+            .unwrap_or_default();
+        Err(Error::Wallet(format!(
+            "Cannot withdraw {}{}: only {} is liquid, {} is locked in farm positions{}",
+            amount,
+            self.lp_denom,
+            self.liquid,
+            self.locked(),
+            next_unlock
+        )))
+    }
+}
+
+/// What withdrawing LP tokens from a pool returns and costs, before signing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WithdrawalPreview {
+    pub pool_id: String,
+    /// LP tokens burned
+    pub lp_amount: Coin,
+    /// Share of the pool's LP supply burned
+    pub share: Decimal,
+    /// Assets refunded at the pool's current reserves
+    pub assets: Vec<Coin>,
+    /// Gas and transaction fee, if the withdrawal was simulated
+    pub gas: Option<GasEstimate>,
+}
+
+impl WithdrawalPreview {
+    /// Preview withdrawing `lp_amount` LP tokens from `pool` at its current reserves
+    ///
+    /// The pool manager charges no withdrawal fee, so the transaction fee is the
+    /// only cost; add it with [`with_gas`](Self::with_gas).
+    /// `None` if `lp_amount` is zero or exceeds the pool's LP supply.
+    pub fn new(pool: &PoolInfoResponse, lp_amount: Uint128) -> Option<Self> {
+        let assets = math::withdrawal_refund(pool, lp_amount)?;
+        Some(Self {
+            pool_id: pool.pool_info.pool_identifier.clone(),
+            lp_amount: Coin {
+                denom: pool.pool_info.lp_denom.clone(),
+                amount: lp_amount,
+            },
+            share: Decimal::from_ratio(lp_amount, pool.total_share.amount),
+            assets,
+            gas: None,
+        })
+    }
+
+    /// Record the gas and fee of the withdrawal's simulation
+    pub fn with_gas(mut self, gas: GasEstimate) -> Self {
+        self.gas = Some(gas);
+        self
+    }
+
+    /// Amount of `denom` refunded, less the transaction fee if paid in it
+    pub fn net_amount(&self, denom: &str) -> Int128 {
+        let refunded = self
+            .assets
+            .iter()
+            .filter(|coin| coin.denom == denom)
+            .fold(0i128, |total, coin| {
+                total.saturating_add(coin.amount.u128().try_into().unwrap_or(i128::MAX))
+            });
+        let fee = self
+            .gas
+            .as_ref()
+            .filter(|gas| gas.fee.denom == denom)
+            .map_or(0, |gas| {
+                gas.fee.amount.u128().try_into().unwrap_or(i128::MAX)
+            });
+        Int128::new(refunded.saturating_sub(fee))
+    }
+}
+
+/// LP tokens of a pool held by a wallet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LpPosition {
+    pub pool_id: String,
+    pub lp_denom: String,
+    /// LP tokens held, liquid and locked in farm positions
+    pub lp_amount: Uint128,
+    /// Part of [`lp_amount`](Self::lp_amount) locked in farm positions
+    #[serde(default)]
+    pub locked_amount: Uint128,
+    /// Farm positions holding the locked tokens
+    #[serde(default)]
+    pub locks: Vec<LpLock>,
+    /// Share of the pool's LP supply
+    pub share: Decimal,
+    /// The position's part of the pool's reserves
+    pub assets: Vec<Coin>,
+    /// Assets deposited for the LP tokens held, if the history covers all of them
+    pub deposited: Option<Vec<Coin>>,
+    /// Denom the values are expressed in
+    pub quote: String,
+    /// Current value of [`assets`](Self::assets), if every asset can be priced
+    pub value: Option<Uint128>,
+    /// Current value of [`deposited`](Self::deposited)
+    pub deposit_value: Option<Uint128>,
+    /// Share of the deposit's value lost to the move of the pool price
+    ///
+    /// Only computed for two-asset constant product pools.
+    pub impermanent_loss: Option<Decimal>,
+    /// Value of the swap fees the position earned
+    pub fees_earned: Option<Uint128>,
+}
+
+impl LpPosition {
+    /// Record the farm positions holding part of the LP tokens
+    pub fn with_locks(mut self, locks: Vec<LpLock>) -> Self {
+        self.locked_amount = locks.iter().fold(Uint128::zero(), |total, lock| {
+            total.saturating_add(lock.amount)
+        });
+        self.locks = locks;
+        self
+    }
+
+    /// LP tokens that can be withdrawn from the pool now
+    pub fn liquid_amount(&self) -> Uint128 {
+        self.lp_amount.saturating_sub(self.locked_amount)
+    }
+
+    /// Value gained over holding the deposited assets
+    pub fn pnl(&self) -> Option<Int128> {
+        let value = i128::try_from(self.value?.u128()).ok()?;
+        let deposit_value = i128::try_from(self.deposit_value?.u128()).ok()?;
+        Some(Int128::new(value.saturating_sub(deposit_value)))
+    }
+
+    /// [`pnl`](Self::pnl) as a share of the deposit value, negative for a loss
+    pub fn pnl_ratio(&self) -> Option<f64> {
+        let deposit_value = self.deposit_value.filter(|value| !value.is_zero())?;
+        Some(self.pnl()?.i128() as f64 / deposit_value.u128() as f64)
+    }
+}
+
+/// The position of `lp_amount` LP tokens of `pool`, valued in `quote`
+///
+/// `changes` are the wallet's liquidity changes, oldest first; those of other
+/// pools are ignored.
+pub fn lp_position(
+    pool: &PoolInfoResponse,
+    lp_amount: Uint128,
+    changes: &[LiquidityChange],
+    prices: &PriceBook<'_>,
+    quote: &str,
+) -> LpPosition {
+    let info = &pool.pool_info;
+    let total_share = pool.total_share.amount;
+    let assets: Vec<Coin> = info
+        .assets
+        .iter()
+        .map(|reserve| Coin {
+            denom: reserve.denom.clone(),
+            amount: if total_share.is_zero() {
+                Uint128::zero()
+            } else {
+                reserve.amount.multiply_ratio(lp_amount, total_share)
+            },
+        })
+        .collect();
+    let deposited = cost_basis(&info.pool_identifier, changes, lp_amount);
+
+    let value = |coins: &[Coin]| {
+        coins.iter().try_fold(Uint128::zero(), |total, coin| {
+            let converted = prices.convert(coin.amount, &coin.denom, quote)?;
+            Some(total.saturating_add(converted.to.amount))
+        })
+    };
+    let position_value = value(&assets);
+    let deposit_value = deposited.as_deref().and_then(value);
+    let impermanent_loss = match (&info.pool_type, deposited.as_deref()) {
+        (PoolType::ConstantProduct, Some(deposited)) => impermanent_loss(pool, deposited),
+        _ => None,
+    };
+    // Without fees the position would be worth the deposit less the impermanent loss
+    let fees_earned = match (position_value, deposit_value, impermanent_loss) {
+        (Some(position_value), Some(deposit_value), Some(loss)) => {
+            Some(position_value.saturating_sub(deposit_value.mul_floor(Decimal::one() - loss)))
+        }
+        _ => None,
+    };
+
+    LpPosition {
+        pool_id: info.pool_identifier.clone(),
+        lp_denom: info.lp_denom.clone(),
+        lp_amount,
+        locked_amount: Uint128::zero(),
+        locks: Vec::new(),
+        share: if total_share.is_zero() {
+            Decimal::zero()
+        } else {
+            Decimal::from_ratio(lp_amount, total_share)
+        },
+        assets,
+        deposited,
+        quote: quote.to_string(),
+        value: position_value,
+        deposit_value,
+        impermanent_loss,
+        fees_earned,
+    }
+}
+
+/// The positions of a wallet holding `balances` and `locks` in `pools`, valued in `quote`
+///
+/// Pools the wallet holds no LP tokens of, liquid or locked, are left out.
+/// `changes` are the wallet's liquidity changes, oldest first, see [`lp_position`].
+pub fn lp_positions(
+    pools: &[PoolInfoResponse],
+    balances: &[Coin],
+    locks: &[LpLock],
+    changes: &[LiquidityChange],
+    prices: &PriceBook<'_>,
+    quote: &str,
+) -> Vec<LpPosition> {
+    pools
+        .iter()
+        .filter_map(|pool| {
+            let lp_denom = &pool.pool_info.lp_denom;
+            let balance = LpBalance {
+                lp_denom: lp_denom.clone(),
+                liquid: balances
+                    .iter()
+                    .find(|balance| &balance.denom == lp_denom)
+                    .map_or(Uint128::zero(), |balance| balance.amount),
+                locks: locks
+                    .iter()
+                    .filter(|lock| &lock.lp_denom == lp_denom)
+                    .cloned()
+                    .collect(),
+            };
+            let total = balance.total();
+            if total.is_zero() {
+                return None;
+            }
+            Some(lp_position(pool, total, changes, prices, quote).with_locks(balance.locks))
+        })
+        .collect()
+}
+
+/// Assets deposited for `lp_amount` LP tokens of `pool_id`
+///
+/// `None` if the changes account for fewer LP tokens than held, e.g. because
+/// some were received from another address.
+fn cost_basis(pool_id: &str, changes: &[LiquidityChange], lp_amount: Uint128) -> Option<Vec<Coin>> {
+    let mut deposited: BTreeMap<String, Uint128> = BTreeMap::new();
+    let mut shares = Uint128::zero();
+    for change in changes.iter().filter(|change| change.pool_id == pool_id) {
+        if change.provided {
+            for coin in &change.assets {
+                let amount = deposited.entry(coin.denom.clone()).or_default();
+                *amount = amount.saturating_add(coin.amount);
+            }
+            shares = shares.saturating_add(change.lp_amount);
+        } else if !shares.is_zero() {
+            let burned = change.lp_amount.min(shares);
+            for amount in deposited.values_mut() {
+                *amount = amount.saturating_sub(amount.multiply_ratio(burned, shares));
+            }
+            shares -= burned;
+        }
+    }
+
+    if shares.is_zero() || shares < lp_amount {
+        return None;
+    }
+    Some(
+        deposited
+            .into_iter()
+            .map(|(denom, amount)| Coin {
+                denom,
+                amount: amount.multiply_ratio(lp_amount, shares),
+            })
+            .collect(),
+    )
+}
+
+/// Impermanent loss of a two-asset constant product pool since `deposited` was provided
+///
+/// With `r` the ratio of the current pool price to the deposit's price, a
+/// position is worth `2·√r / (1 + r)` of the deposit held instead.
+fn impermanent_loss(pool: &PoolInfoResponse, deposited: &[Coin]) -> Option<Decimal> {
+    let [base, counter] = pool.pool_info.assets.as_slice() else {
+        return None;
+    };
+    let deposited_amount = |denom: &str| {
+        deposited
+            .iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| Uint256::from(coin.amount))
+            .filter(|amount| !amount.is_zero())
+    };
+    let (deposited_base, deposited_counter) = (
+        deposited_amount(&base.denom)?,
+        deposited_amount(&counter.denom)?,
+    );
+    if base.amount.is_zero() || counter.amount.is_zero() {
+        return None;
+    }
+
+    let ratio = Decimal256::checked_from_ratio(
+        Uint256::from(counter.amount)
+            .checked_mul(deposited_base)
+            .ok()?,
+        Uint256::from(base.amount)
+            .checked_mul(deposited_counter)
+            .ok()?,
+    )
+    .ok()?;
+    let held_share = (ratio.sqrt() * Decimal256::from_atomics(2u128, 0).ok()?)
+        .checked_div(Decimal256::one() + ratio)
+        .ok()?;
+    Decimal::try_from(Decimal256::one().saturating_sub(held_share)).ok()
+}
@@ -0,0 +1,421 @@
+//! User preferences shared by the CLI and the TUI
+//!
+//! [`Preferences`] hold the settings that follow a user rather than a wallet or
+//! a network: the default slippage, the denom values are displayed in, the
+//! separators amounts are typed with, favorite pools, the TUI theme and refresh
+//! intervals, and expert mode. They live in one file under `~/.mantra-dex/`, or
+//! in the configured [storage](crate::storage) with another backend, so a
+//! setting changed in the TUI applies to the next CLI run and the other way
+//! around.
+//!
+//! A [`PreferencesStore`] owns the document: it saves every update, picks up
+//! changes written by other processes on [`reload`](PreferencesStore::reload),
+//! and notifies subscribers of both. The first time it opens, settings kept in
+//! the old `config.toml` are moved over.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use cosmwasm_std::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::amount::NumberLocale;
+use crate::config::{Config, ExpertModeConfig, StorageBackend};
+use crate::error::Error;
+use crate::schema::{self, Format, Schema};
+use crate::storage::{self, Document, SharedStorage};
+use crate::wallet::WalletDefaults;
+
+/// Keys [`Preferences::set`] and [`Preferences::unset`] accept
+pub const PREFERENCE_KEYS: [&str; 13] = [
+    "default_slippage",
+    "display_denom",
+    "decimal_precision",
+    "number_locale",
+    "favorite_pools",
+    "theme",
+    "auto_refresh",
+    "balance_refresh_secs",
+    "pool_refresh_secs",
+    "expert_mode.enabled",
+    "expert_mode.max_trade_value_usd",
+    "expert_mode.max_price_impact_percent",
+    "tour_completed",
+];
+
+/// Preference updates buffered for each subscriber
+const CHANGES_CAPACITY: usize = 16;
+
+/// Format of the preferences file
+const PREFERENCES_SCHEMA: Schema =
+    Schema::new("preferences", Format::Toml, &[schema::add_version]);
+
+/// Color theme of the TUI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    #[default]
+    Default,
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl Theme {
+    /// Get display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Theme::Default => "Default",
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::HighContrast => "High Contrast",
+        }
+    }
+
+    /// Get all themes
+    pub fn all() -> Vec<Self> {
+        vec![
+            Theme::Default,
+            Theme::Dark,
+            Theme::Light,
+            Theme::HighContrast,
+        ]
+    }
+}
+
+/// Settings shared by every front end
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// Maximum slippage for swaps that set none, unless the wallet has its own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_slippage: Option<Decimal>,
+    /// Denom balances and portfolio values are shown in, e.g. `usd`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_denom: Option<String>,
+    /// Decimals shown for token amounts
+    pub decimal_precision: u8,
+    /// Separators amounts are typed with, detected from the environment if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_locale: Option<NumberLocale>,
+    /// Pools listed first and marked in pool listings
+    pub favorite_pools: Vec<String>,
+    pub theme: Theme,
+    /// Whether the TUI refreshes balances and pools on its own
+    pub auto_refresh: bool,
+    pub balance_refresh_secs: u64,
+    pub pool_refresh_secs: u64,
+    /// Thresholds under which trades execute without a confirmation prompt
+    pub expert_mode: ExpertModeConfig,
+    /// Whether the TUI's onboarding tour was finished or skipped
+    pub tour_completed: bool,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            default_slippage: None,
+            display_denom: None,
+            decimal_precision: 6,
+            number_locale: None,
+            favorite_pools: Vec::new(),
+            theme: Theme::default(),
+            auto_refresh: true,
+            balance_refresh_secs: 30,
+            pool_refresh_secs: 60,
+            expert_mode: ExpertModeConfig::default(),
+            tour_completed: false,
+        }
+    }
+}
+
+impl Preferences {
+    /// Get the default preferences file path (~/.mantra-dex/preferences.toml)
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push(".mantra-dex");
+        path.push("preferences.toml");
+        path
+    }
+
+    /// Load preferences from a file, migrating it if an older release wrote it
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        schema::read(&PREFERENCES_SCHEMA, path)
+    }
+
+    /// Save preferences to a file, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        schema::write(&PREFERENCES_SCHEMA, path, self)
+    }
+
+    /// Preferences carried over from a configuration saved before they had their own file
+    pub fn migrate_from(config: &Config) -> Self {
+        Self {
+            expert_mode: config.expert_mode,
+            ..Self::default()
+        }
+    }
+
+    /// Whether `pool_id` is a favorite
+    pub fn is_favorite(&self, pool_id: &str) -> bool {
+        self.favorite_pools
+            .iter()
+            .any(|favorite| favorite == pool_id)
+    }
+
+    /// Add `pool_id` to the favorites, or remove it if it is one
+    ///
+    /// Returns whether the pool is now a favorite.
+    pub fn toggle_favorite(&mut self, pool_id: &str) -> bool {
+        if self.is_favorite(pool_id) {
+            self.favorite_pools.retain(|favorite| favorite != pool_id);
+            false
+        } else {
+            self.favorite_pools.push(pool_id.to_string());
+            true
+        }
+    }
+
+    /// Separators amounts are typed with
+    pub fn number_locale(&self) -> NumberLocale {
+        self.number_locale.unwrap_or_else(NumberLocale::from_env)
+    }
+
+    /// Fill the defaults a wallet leaves unset from these preferences
+    pub fn fill_wallet_defaults(&self, mut defaults: WalletDefaults) -> WalletDefaults {
+        if defaults.slippage.is_none() {
+            defaults.slippage = self.default_slippage;
+        }
+        defaults
+    }
+
+    /// Set the preference `key`, one of [`PREFERENCE_KEYS`], from text
+    ///
+    /// The value is read as a TOML value, e.g. `true`, `30` or `["p1", "p2"]`,
+    /// or else as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the key is unknown or the value doesn't fit it
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let parsed = toml::from_str::<toml::Table>(&format!("value = {}", value))
+            .ok()
+            .and_then(|mut table| table.remove("value"));
+        if let Some(updated) = parsed.and_then(|parsed| self.with_value(key, Some(parsed)).ok()) {
+            *self = updated;
+            return Ok(());
+        }
+        *self = self.with_value(key, Some(toml::Value::String(value.to_string())))?;
+        Ok(())
+    }
+
+    /// Reset the preference `key`, one of [`PREFERENCE_KEYS`], to its default
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the key is unknown
+    pub fn unset(&mut self, key: &str) -> Result<(), Error> {
+        *self = self.with_value(key, None)?;
+        Ok(())
+    }
+
+    /// These preferences with `key` replaced by `value`, or reset if `None`
+    fn with_value(&self, key: &str, value: Option<toml::Value>) -> Result<Self, Error> {
+        if !PREFERENCE_KEYS.contains(&key) {
+            return Err(Error::Config(format!(
+                "Unknown preference '{}', expected one of: {}",
+                key,
+                PREFERENCE_KEYS.join(", ")
+            )));
+        }
+        let mut table = toml::Table::try_from(self)
+            .map_err(|e| Error::Config(format!("Failed to serialize preferences: {}", e)))?;
+
+        let mut path: Vec<&str> = key.split('.').collect();
+        let field = path.pop().unwrap_or(key);
+        let mut section = &mut table;
+        for name in path {
+            section = section
+                .entry(name)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                .as_table_mut()
+                .ok_or_else(|| Error::Config(format!("Preference '{}' is not a section", name)))?;
+        }
+        match value {
+            Some(value) => section.insert(field.to_string(), value),
+            None => section.remove(field),
+        };
+
+        toml::Value::Table(table)
+            .try_into()
+            .map_err(|e| Error::Config(format!("Invalid value for '{}': {}", key, e)))
+    }
+}
+
+impl fmt::Display for Preferences {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let content = toml::to_string_pretty(self).map_err(|_| fmt::Error)?;
+        f.write_str(content.trim_end())
+    }
+}
+
+/// Preferences kept in a file or a storage and shared with other processes
+#[derive(Debug)]
+pub struct PreferencesStore {
+    preferences: Preferences,
+    document: Option<Document>,
+    /// Modification time of the document when it was last read or written
+    modified: Option<SystemTime>,
+    changes: broadcast::Sender<Preferences>,
+}
+
+impl PreferencesStore {
+    /// Keep preferences in memory only, e.g. for tests
+    pub fn in_memory(preferences: Preferences) -> Self {
+        Self {
+            preferences,
+            document: None,
+            modified: None,
+            changes: broadcast::channel(CHANGES_CAPACITY).0,
+        }
+    }
+
+    /// Open the preferences stored at `path`
+    ///
+    /// If the file doesn't exist yet, the settings of the configuration at
+    /// `legacy_config` are migrated into it, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed, or the
+    /// migrated preferences can't be saved
+    pub fn open(path: impl Into<PathBuf>, legacy_config: &Path) -> Result<Self, Error> {
+        Self::open_document(Document::file(path), legacy_config)
+    }
+
+    /// Open the preferences kept in `storage`, migrating `legacy_config` the first time
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails, its document can't be parsed, or
+    /// the migrated preferences can't be saved
+    pub fn open_in(storage: SharedStorage, legacy_config: &Path) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY), legacy_config)
+    }
+
+    fn open_document(document: Document, legacy_config: &Path) -> Result<Self, Error> {
+        let preferences = match document.read(&PREFERENCES_SCHEMA)? {
+            Some(preferences) => preferences,
+            None => match Config::load(legacy_config) {
+                Ok(config) => {
+                    let migrated = Preferences::migrate_from(&config);
+                    document.write(&PREFERENCES_SCHEMA, &migrated)?;
+                    migrated
+                }
+                Err(_) => Preferences::default(),
+            },
+        };
+
+        Ok(Self {
+            preferences,
+            modified: document.modified(),
+            document: Some(document),
+            changes: broadcast::channel(CHANGES_CAPACITY).0,
+        })
+    }
+
+    /// Key of the preferences in a [`Storage`](crate::storage::Storage)
+    pub const STORAGE_KEY: &'static str = "preferences.toml";
+
+    /// Open the preferences where the saved configuration keeps them, migrating it
+    ///
+    /// With the `files` backend they stay at their default path rather than in
+    /// the storage directory, where earlier releases look for them.
+    pub fn open_default() -> Result<Self, Error> {
+        let legacy_config = Config::default_path();
+        let backend = Config::load(&legacy_config)
+            .map(|saved| saved.storage.backend)
+            .unwrap_or_default();
+        match backend {
+            StorageBackend::Files => Self::open(Preferences::default_path(), &legacy_config),
+            _ => Self::open_in(storage::shared()?, &legacy_config),
+        }
+    }
+
+    /// File the preferences are kept in, `None` if in memory or a database
+    pub fn path(&self) -> Option<PathBuf> {
+        self.document.as_ref().and_then(Document::path)
+    }
+
+    /// Document the preferences are kept in, `None` if only in memory
+    pub fn document(&self) -> Option<&Document> {
+        self.document.as_ref()
+    }
+
+    /// Current preferences
+    pub fn get(&self) -> &Preferences {
+        &self.preferences
+    }
+
+    /// Change the preferences with `change`, saving and announcing the result
+    ///
+    /// Returns whether anything changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the preferences can't be saved; they are left unchanged
+    pub fn update(&mut self, change: impl FnOnce(&mut Preferences)) -> Result<bool, Error> {
+        let mut updated = self.preferences.clone();
+        change(&mut updated);
+        if updated == self.preferences {
+            return Ok(false);
+        }
+
+        if let Some(document) = &self.document {
+            document.write(&PREFERENCES_SCHEMA, &updated)?;
+            self.modified = document.modified();
+        }
+        self.preferences = updated;
+        let _ = self.changes.send(self.preferences.clone());
+        Ok(true)
+    }
+
+    /// Re-read the document if another process changed it since it was last read
+    ///
+    /// Documents of backends that can't tell when they were written are read
+    /// every time. Returns whether the preferences changed; subscribers are
+    /// notified if so.
+    pub fn reload(&mut self) -> Result<bool, Error> {
+        let Some(document) = &self.document else {
+            return Ok(false);
+        };
+        let modified = document.modified();
+        if modified.is_some() && modified == self.modified {
+            return Ok(false);
+        }
+
+        let Some(loaded) = document.read::<Preferences>(&PREFERENCES_SCHEMA)? else {
+            return Ok(false);
+        };
+        self.modified = modified;
+        if loaded == self.preferences {
+            return Ok(false);
+        }
+        self.preferences = loaded;
+        let _ = self.changes.send(self.preferences.clone());
+        Ok(true)
+    }
+
+    /// Receive the preferences after every change
+    pub fn subscribe(&self) -> broadcast::Receiver<Preferences> {
+        self.changes.subscribe()
+    }
+}
+
+/// Modification time of the file at `path`, `None` if it doesn't exist
+pub fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
@@ -0,0 +1,407 @@
+//! Local history of pool spot prices
+//!
+//! A [`PriceHistory`] keeps samples of each pool's spot price, its first asset
+//! priced in its second (see [`pricing::spot_price`]), so prices can be charted
+//! without an indexer. [`PriceHistory::record`] samples a set of pools, skipping
+//! those sampled less than the configured interval ago, and drops samples older
+//! than the retention so the document stays small. [`record_every`] samples
+//! every pool at the interval until stopped.
+//!
+//! [`PriceHistory::candles`] aggregates the samples of a pool into OHLC
+//! [`PriceCandle`]s of any width over any window, in either direction of the pair.
+//! The sampling interval, retention and sampled pools come from the
+//! `[price_history]` section of the saved configuration.
+//!
+//! As with [`OrderBook`](crate::OrderBook), every change re-reads the document
+//! first, so a TUI and a `prices record` process can sample into the same
+//! history.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use cosmwasm_std::Decimal;
+use futures::TryStreamExt;
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::client::MantraDexClient;
+use crate::config::PriceHistoryConfig;
+use crate::error::Error;
+use crate::pricing;
+use crate::schema::{Format, Schema};
+use crate::storage::{Document, SharedStorage};
+
+/// Default time between two samples of a pool
+pub const DEFAULT_SAMPLE_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Default age beyond which samples are dropped
+pub const DEFAULT_RETENTION: Duration = Duration::from_secs(7 * 86_400);
+
+/// Pools fetched per query when sampling every pool
+const POOL_PAGE_SIZE: u32 = 100;
+
+/// A pool's spot price at one time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub at: DateTime<Utc>,
+    pub price: Decimal,
+}
+
+/// Samples of a pool's spot price, oldest first
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceSeries {
+    /// Denom priced, the pool's first asset
+    pub base: String,
+    /// Denom prices are expressed in, the pool's second asset
+    pub quote: String,
+    pub points: Vec<PricePoint>,
+}
+
+impl PriceSeries {
+    /// Prices of `denom` in the other denom of the pair sampled in `[from, to)`
+    ///
+    /// Prices are inverted when `denom` is the series' quote. `None` if `denom`
+    /// isn't one of the pair.
+    pub fn prices(
+        &self,
+        denom: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Option<Vec<PricePoint>> {
+        let invert = if denom == self.base {
+            false
+        } else if denom == self.quote {
+            true
+        } else {
+            return None;
+        };
+        Some(
+            self.points
+                .iter()
+                .filter(|point| from <= point.at && point.at < to)
+                .filter_map(|point| {
+                    let price = if invert {
+                        Decimal::one().checked_div(point.price).ok()?
+                    } else {
+                        point.price
+                    };
+                    Some(PricePoint {
+                        at: point.at,
+                        price,
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Open, high, low and close of the prices sampled in one period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceCandle {
+    /// Start of the period
+    pub start: DateTime<Utc>,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// Samples the candle was built from
+    pub samples: u32,
+}
+
+/// Aggregate `points`, oldest first, into candles `width` long
+///
+/// Periods start at multiples of `width` since the Unix epoch, so candles of
+/// the same width line up whatever the window. Periods without samples have
+/// no candle rather than one repeating the previous close.
+pub fn candles(points: &[PricePoint], width: Duration) -> Vec<PriceCandle> {
+    let width = (width.as_secs() as i64).max(1);
+    let mut candles: Vec<PriceCandle> = Vec::new();
+    for point in points {
+        let secs = point.at.timestamp();
+        let start = secs - secs.rem_euclid(width);
+        let start = Utc.timestamp_opt(start, 0).single().unwrap_or(point.at);
+        match candles.last_mut() {
+            Some(candle) if candle.start == start => {
+                candle.high = candle.high.max(point.price);
+                candle.low = candle.low.min(point.price);
+                candle.close = point.price;
+                candle.samples += 1;
+            }
+            _ => candles.push(PriceCandle {
+                start,
+                open: point.price,
+                high: point.price,
+                low: point.price,
+                close: point.price,
+                samples: 1,
+            }),
+        }
+    }
+    candles
+}
+
+/// Format of the price history file
+const PRICE_HISTORY_SCHEMA: Schema = Schema::new("price history", Format::Json, &[]);
+
+/// Contents of the price history file: series by pool
+#[derive(Serialize, Deserialize)]
+struct PriceHistoryFile<T> {
+    pools: T,
+}
+
+/// Spot price samples by pool, persisted when opened from a file or a storage
+#[derive(Debug)]
+pub struct PriceHistory {
+    series: BTreeMap<String, PriceSeries>,
+    interval: Duration,
+    retention: Duration,
+    /// Pools sampled, every pool if empty
+    pools: Vec<String>,
+    document: Option<Document>,
+}
+
+impl Default for PriceHistory {
+    fn default() -> Self {
+        Self {
+            series: BTreeMap::new(),
+            interval: DEFAULT_SAMPLE_INTERVAL,
+            retention: DEFAULT_RETENTION,
+            pools: Vec::new(),
+            document: None,
+        }
+    }
+}
+
+impl PriceHistory {
+    /// Create an empty, in-memory history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the history stored at `path`, saving back to it as prices are recorded
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the history kept in `storage`, saving back to it as prices are recorded
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut history = Self {
+            document: Some(document),
+            ..Self::default()
+        };
+        history.reload()?;
+        Ok(history)
+    }
+
+    /// Key of the history in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "price_history.json";
+
+    /// Get the default price history file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("price_history.json");
+        path
+    }
+
+    /// Sample at the interval, keep samples for the retention and only sample the
+    /// pools of `config`, where set
+    pub fn with_settings(mut self, config: &PriceHistoryConfig) -> Self {
+        if let Some(secs) = config.interval_secs {
+            self.interval = Duration::from_secs(secs.max(1));
+        }
+        if let Some(days) = config.retention_days {
+            self.retention = Duration::from_secs(days.max(1).saturating_mul(86_400));
+        }
+        self.pools = config.pools.clone();
+        self
+    }
+
+    /// Time between two samples of a pool
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Re-read the samples, picking up those recorded by other processes
+    pub fn reload(&mut self) -> Result<(), Error> {
+        let Some(document) = &self.document else {
+            return Ok(());
+        };
+        if let Some(stored) = document
+            .read::<PriceHistoryFile<BTreeMap<String, PriceSeries>>>(&PRICE_HISTORY_SCHEMA)?
+        {
+            self.series = stored.pools;
+        }
+        Ok(())
+    }
+
+    /// Record the spot price of each of `pools` at `now`
+    ///
+    /// Pools outside the configured set, sampled less than the interval before
+    /// `now`, or that can't be priced are skipped. Samples older than the
+    /// retention are dropped.
+    ///
+    /// # Returns
+    ///
+    /// Number of pools sampled
+    pub fn record(
+        &mut self,
+        pools: &[PoolInfoResponse],
+        now: DateTime<Utc>,
+    ) -> Result<usize, Error> {
+        self.reload()?;
+        let interval = chrono::Duration::from_std(self.interval).unwrap_or_default();
+        let mut recorded = 0;
+        for pool in pools {
+            let info = &pool.pool_info;
+            if !self.pools.is_empty() && !self.pools.contains(&info.pool_identifier) {
+                continue;
+            }
+            let [base, quote, ..] = info.asset_denoms.as_slice() else {
+                continue;
+            };
+            let Some(price) = pricing::spot_price(pool, base, quote) else {
+                continue;
+            };
+            let series = self
+                .series
+                .entry(info.pool_identifier.clone())
+                .or_insert_with(|| PriceSeries {
+                    base: base.clone(),
+                    quote: quote.clone(),
+                    points: Vec::new(),
+                });
+            if series
+                .points
+                .last()
+                .is_some_and(|last| now < last.at + interval)
+            {
+                continue;
+            }
+            series.points.push(PricePoint { at: now, price });
+            recorded += 1;
+        }
+
+        let pruned = self.prune(now);
+        if recorded > 0 || pruned {
+            self.save()?;
+        }
+        Ok(recorded)
+    }
+
+    /// Drop the samples older than the retention; whether any were
+    fn prune(&mut self, now: DateTime<Utc>) -> bool {
+        let Ok(retention) = chrono::Duration::from_std(self.retention) else {
+            return false;
+        };
+        let oldest = now - retention;
+        let mut pruned = false;
+        self.series.retain(|_, series| {
+            let before = series.points.len();
+            series.points.retain(|point| point.at >= oldest);
+            pruned |= series.points.len() != before;
+            !series.points.is_empty()
+        });
+        pruned
+    }
+
+    /// Pools with samples
+    pub fn pool_ids(&self) -> Vec<&str> {
+        self.series.keys().map(String::as_str).collect()
+    }
+
+    /// Samples of `pool_id`
+    pub fn series(&self, pool_id: &str) -> Option<&PriceSeries> {
+        self.series.get(pool_id)
+    }
+
+    /// Candles `width` long of the price of `denom` in `pool_id` sampled in `[from, to)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool has no samples or `denom` isn't one of its
+    /// sampled pair
+    pub fn candles(
+        &self,
+        pool_id: &str,
+        denom: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        width: Duration,
+    ) -> Result<Vec<PriceCandle>, Error> {
+        let series = self
+            .series(pool_id)
+            .ok_or_else(|| Error::Other(format!("No prices recorded for pool {}", pool_id)))?;
+        let prices = series.prices(denom, from, to).ok_or_else(|| {
+            Error::Other(format!(
+                "Pool {} prices {} in {}, not {}",
+                pool_id, series.base, series.quote, denom
+            ))
+        })?;
+        Ok(candles(&prices, width))
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        match &self.document {
+            Some(document) => document.write(
+                &PRICE_HISTORY_SCHEMA,
+                &PriceHistoryFile {
+                    pools: &self.series,
+                },
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Record the spot price of every pool into `history` once
+///
+/// # Returns
+///
+/// Number of pools sampled
+pub async fn record_pools(
+    client: &MantraDexClient,
+    history: &mut PriceHistory,
+) -> Result<usize, Error> {
+    let pools: Vec<PoolInfoResponse> = client.pools_stream(POOL_PAGE_SIZE).try_collect().await?;
+    history.record(&pools, Utc::now())
+}
+
+/// Record the spot price of every pool into `history` at its interval, forever
+///
+/// `on_sample` gets the outcome of every round.
+///
+/// # Errors
+///
+/// Only returns, with its error, once `on_sample` fails
+pub async fn record_every<F>(
+    client: &MantraDexClient,
+    history: &mut PriceHistory,
+    mut on_sample: F,
+) -> Result<(), Error>
+where
+    F: FnMut(Result<usize, Error>) -> Result<(), Error>,
+{
+    let mut interval = tokio::time::interval(history.interval());
+    loop {
+        interval.tick().await;
+        on_sample(record_pools(client, history).await)?;
+    }
+}
@@ -0,0 +1,171 @@
+//! Price impact of a proposed swap
+//!
+//! A swap moves a pool's reserves against the trader: the larger it is
+//! relative to the pool, the further the price it gets falls below the spot
+//! price quoted before the trade. The functions here compute that spot price,
+//! the execution price of a swap and the resulting price impact from the pool's
+//! reserves, using the local pool math of [`crate::math`] for both constant
+//! product and stable swap pools, so a wallet can warn before a large trade
+//! without a chain query.
+//!
+//! Prices are in base units: the amount of the ask denom's base unit paid for
+//! one base unit of the offer denom.
+
+use cosmwasm_std::{Coin, Decimal, Decimal256, Uint128};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType, SimulationResponse};
+use serde::{Deserialize, Serialize};
+
+use crate::math;
+use crate::risk::RiskLevel;
+
+/// Price impact from which a swap is worth a warning
+pub const CAUTION_PRICE_IMPACT: Decimal = Decimal::percent(1);
+
+/// Price impact from which a swap is likely a mistake
+pub const DANGER_PRICE_IMPACT: Decimal = Decimal::percent(5);
+
+/// Share of the offer reserve swapped to measure a stable swap pool's marginal price
+const SPOT_PROBE_DIVISOR: u128 = 10_000;
+
+/// Prices and impact of a proposed swap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceImpact {
+    pub pool_id: String,
+    pub offer_asset: Coin,
+    pub ask_denom: String,
+    /// Marginal price before the swap, without fees
+    pub spot_price: Decimal,
+    /// Price the swap gets, after fees
+    pub execution_price: Decimal,
+    /// Share of the spot value lost to moving the reserves, excluding fees
+    pub price_impact: Decimal,
+    /// Amount of the ask denom received
+    pub return_amount: Uint128,
+    /// Swap, protocol, burn and extra fees, in the ask denom
+    pub fee_amount: Uint128,
+}
+
+impl PriceImpact {
+    /// How worrying the impact is, if at all
+    pub fn level(&self) -> Option<RiskLevel> {
+        impact_level(self.price_impact)
+    }
+
+    /// A warning to show before the swap, if its impact calls for one
+    pub fn warning(&self) -> Option<String> {
+        impact_warning(self.price_impact)
+    }
+}
+
+/// A warning to show before a swap losing `price_impact` of its value, if it calls for one
+pub fn impact_warning(price_impact: Decimal) -> Option<String> {
+    let level = impact_level(price_impact)?;
+    let percent = price_impact.to_string().parse::<f64>().unwrap_or(0.0) * 100.0;
+    Some(format!(
+        "{} price impact: {:.2}% of the swap's value is lost to moving the pool price",
+        match level {
+            RiskLevel::Caution => "High",
+            RiskLevel::Danger => "Very high",
+        },
+        percent
+    ))
+}
+
+/// How worrying a price impact is, if at all
+pub fn impact_level(price_impact: Decimal) -> Option<RiskLevel> {
+    if price_impact >= DANGER_PRICE_IMPACT {
+        Some(RiskLevel::Danger)
+    } else if price_impact >= CAUTION_PRICE_IMPACT {
+        Some(RiskLevel::Caution)
+    } else {
+        None
+    }
+}
+
+/// Marginal price of `offer_denom` in `ask_denom` before any swap, without fees
+///
+/// Constant product pools price by their reserve ratio. Stable swap pools are
+/// measured with a swap of a small share of the offer reserve, since their
+/// price depends on the curve as well as the reserves. `None` if the pool
+/// doesn't hold both denoms, has an empty reserve or can't be simulated.
+pub fn spot_price(pool: &PoolInfoResponse, offer_denom: &str, ask_denom: &str) -> Option<Decimal> {
+    let info = &pool.pool_info;
+    let reserve = |denom: &str| {
+        info.assets
+            .iter()
+            .find(|asset| asset.denom == denom)
+            .map(|asset| asset.amount)
+    };
+    let (offer_pool, ask_pool) = (reserve(offer_denom)?, reserve(ask_denom)?);
+    if offer_denom == ask_denom || offer_pool.is_zero() || ask_pool.is_zero() {
+        return None;
+    }
+
+    match info.pool_type {
+        PoolType::ConstantProduct => Decimal::checked_from_ratio(ask_pool, offer_pool).ok(),
+        PoolType::StableSwap { amp } => {
+            let decimals = |denom: &str| {
+                let index = info.asset_denoms.iter().position(|d| d == denom)?;
+                info.asset_decimals.get(index).copied()
+            };
+            let probe = (offer_pool / Uint128::new(SPOT_PROBE_DIVISOR)).max(Uint128::one());
+            let (return_amount, _) = math::stable_swap_in_pool(
+                info.assets.len(),
+                offer_pool,
+                ask_pool,
+                probe,
+                amp,
+                decimals(offer_denom)?,
+                decimals(ask_denom)?,
+            )?;
+            let price = Decimal256::checked_from_ratio(return_amount, probe).ok()?;
+            Decimal::try_from(price).ok()
+        }
+    }
+}
+
+/// Price of a simulated swap, after fees
+pub fn execution_price(offer_asset: &Coin, simulation: &SimulationResponse) -> Option<Decimal> {
+    Decimal::checked_from_ratio(simulation.return_amount, offer_asset.amount).ok()
+}
+
+/// Spot price, execution price and price impact of swapping `offer_asset` for `ask_denom`
+///
+/// `None` if the pool can't be priced or simulated locally; see [`spot_price`]
+/// and [`math::simulate_swap`].
+pub fn price_impact(
+    pool: &PoolInfoResponse,
+    offer_asset: &Coin,
+    ask_denom: &str,
+) -> Option<PriceImpact> {
+    if offer_asset.amount.is_zero() {
+        return None;
+    }
+    let spot_price = spot_price(pool, &offer_asset.denom, ask_denom)?;
+    let simulation = math::simulate_swap(pool, offer_asset, ask_denom)?;
+
+    let fee_amount = simulation.swap_fee_amount
+        + simulation.protocol_fee_amount
+        + simulation.burn_fee_amount
+        + simulation.extra_fees_amount;
+    // What the swap would return without fees, against its value at the spot price
+    let gross_return = simulation.return_amount + fee_amount;
+    let spot_return = offer_asset.amount.mul_floor(spot_price);
+    let price_impact = if spot_return.is_zero() {
+        Decimal::zero()
+    } else {
+        Decimal::checked_from_ratio(spot_return.saturating_sub(gross_return), spot_return)
+            .unwrap_or_default()
+    };
+
+    Some(PriceImpact {
+        pool_id: pool.pool_info.pool_identifier.clone(),
+        offer_asset: offer_asset.clone(),
+        ask_denom: ask_denom.to_string(),
+        spot_price,
+        execution_price: execution_price(offer_asset, &simulation)?,
+        price_impact,
+        return_amount: simulation.return_amount,
+        fee_amount,
+    })
+}
@@ -0,0 +1,235 @@
+//! Application profile export and import
+//!
+//! A [`Profile`] gathers what a user sets up on a machine: the saved
+//! configuration, the shared [preferences](crate::preferences) with their
+//! favorite pools, and optionally the saved wallets. [`Profile::export`] writes it
+//! to a single archive encrypted with a password (Argon2 key derivation,
+//! AES-256-GCM), and [`Profile::import`] reads it back on another machine, where
+//! [`Profile::restore`] puts each part in place.
+//!
+//! Wallets stay encrypted with their own passwords inside the archive, so
+//! importing a profile never needs them. The mnemonic saved in the configuration
+//! is only exported along with the wallets.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::{generic_array::GenericArray, Aead, OsRng};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use argon2::password_hash::rand_core::RngCore;
+use argon2::Argon2;
+use base64::{engine::general_purpose, Engine};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::preferences::Preferences;
+use crate::schema::{self, Format, Schema};
+use crate::wallet::WalletStorage;
+
+/// Format of the profile inside an archive
+const PROFILE_SCHEMA: Schema = Schema::new("profile", Format::Json, &[schema::add_version]);
+
+/// Format of the encrypted archive around a profile
+const ARCHIVE_SCHEMA: Schema = Schema::new("profile archive", Format::Json, &[schema::add_version]);
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Settings and wallets of one installation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    /// When the profile was exported, RFC 3339
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<Config>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<Preferences>,
+    /// Saved wallet files by name, still encrypted with their own passwords
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub wallets: BTreeMap<String, serde_json::Value>,
+}
+
+/// Where the parts of a profile are kept on this machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfilePaths {
+    pub config: PathBuf,
+    pub preferences: PathBuf,
+    pub wallets: PathBuf,
+}
+
+impl ProfilePaths {
+    /// The paths every front end uses by default
+    pub fn defaults() -> Result<Self, Error> {
+        Ok(Self {
+            config: Config::default_path(),
+            preferences: Preferences::default_path(),
+            wallets: WalletStorage::get_storage_directory()?,
+        })
+    }
+}
+
+/// Parts of a profile put in place by [`Profile::restore`], and those left alone
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RestoreSummary {
+    pub restored: Vec<String>,
+    /// Parts not restored because this machine already has them
+    pub skipped: Vec<String>,
+}
+
+/// Encrypted profile as stored in an archive file
+#[derive(Serialize, Deserialize)]
+struct ProfileArchive {
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl Profile {
+    /// Gather the profile kept at `paths`, with the saved wallets and the configured
+    /// mnemonic if `include_wallets`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a saved file exists but can't be read
+    pub fn collect(paths: &ProfilePaths, include_wallets: bool) -> Result<Self, Error> {
+        let mut config = paths
+            .config
+            .exists()
+            .then(|| Config::load(&paths.config))
+            .transpose()?;
+        if !include_wallets {
+            if let Some(config) = &mut config {
+                config.mnemonic = None;
+            }
+        }
+        let preferences = paths
+            .preferences
+            .exists()
+            .then(|| Preferences::load(&paths.preferences))
+            .transpose()?;
+
+        let mut wallets = BTreeMap::new();
+        if include_wallets && paths.wallets.exists() {
+            let storage = WalletStorage::with_directory(paths.wallets.clone())?;
+            for metadata in storage.list_wallets()? {
+                let wallet = storage.export_wallet(&metadata.name)?;
+                wallets.insert(metadata.name, wallet);
+            }
+        }
+
+        Ok(Self {
+            created_at: chrono::Utc::now().to_rfc3339(),
+            config,
+            preferences,
+            wallets,
+        })
+    }
+
+    /// Put the parts of this profile in place at `paths`
+    ///
+    /// Parts this machine already has are skipped unless `overwrite` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a part can't be written; parts restored before it stay
+    pub fn restore(&self, paths: &ProfilePaths, overwrite: bool) -> Result<RestoreSummary, Error> {
+        let mut summary = RestoreSummary::default();
+
+        if let Some(config) = &self.config {
+            if paths.config.exists() && !overwrite {
+                summary.skipped.push("configuration".to_string());
+            } else {
+                config.save(&paths.config)?;
+                summary.restored.push("configuration".to_string());
+            }
+        }
+        if let Some(preferences) = &self.preferences {
+            if paths.preferences.exists() && !overwrite {
+                summary.skipped.push("preferences".to_string());
+            } else {
+                preferences.save(&paths.preferences)?;
+                summary.restored.push("preferences".to_string());
+            }
+        }
+
+        if !self.wallets.is_empty() {
+            let storage = WalletStorage::with_directory(paths.wallets.clone())?;
+            for (name, wallet) in &self.wallets {
+                let part = format!("wallet '{}'", name);
+                if storage.import_wallet(name, wallet.clone(), overwrite)? {
+                    summary.restored.push(part);
+                } else {
+                    summary.skipped.push(part);
+                }
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Write the profile to `path`, encrypted with `password`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile can't be encrypted or the file can't be written
+    pub fn export(&self, path: &Path, password: &str) -> Result<(), Error> {
+        let plaintext = serde_json::to_vec(&schema::to_value(&PROFILE_SCHEMA, self)?)?;
+
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = cipher(password, &salt)?
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|e| Error::Wallet(format!("Failed to encrypt profile: {}", e)))?;
+
+        let archive = ProfileArchive {
+            salt: general_purpose::STANDARD.encode(salt),
+            nonce: general_purpose::STANDARD.encode(nonce),
+            ciphertext: general_purpose::STANDARD.encode(ciphertext),
+        };
+        schema::write(&ARCHIVE_SCHEMA, path, &archive)
+    }
+
+    /// Read the profile exported to `path` with `password`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if the password is wrong or the archive was
+    /// tampered with, and an error if the file can't be read or parsed
+    pub fn import(path: &Path, password: &str) -> Result<Self, Error> {
+        let archive: ProfileArchive = schema::read(&ARCHIVE_SCHEMA, path)?;
+        let decode = |field: &str| {
+            general_purpose::STANDARD
+                .decode(field)
+                .map_err(|e| Error::Config(format!("Invalid profile archive: {}", e)))
+        };
+        let salt = decode(&archive.salt)?;
+        let nonce = decode(&archive.nonce)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(Error::Config(
+                "Invalid profile archive: bad nonce length".to_string(),
+            ));
+        }
+
+        let plaintext = cipher(password, &salt)?
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                decode(&archive.ciphertext)?.as_slice(),
+            )
+            .map_err(|_| {
+                Error::Wallet("Wrong password, or the profile archive is damaged".to_string())
+            })?;
+        schema::from_value(&PROFILE_SCHEMA, serde_json::from_slice(&plaintext)?)
+    }
+}
+
+/// Cipher keyed by `password` and `salt`
+fn cipher(password: &str, salt: &[u8]) -> Result<Aes256Gcm, Error> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, key.as_mut_slice())
+        .map_err(|e| Error::Wallet(format!("Failed to derive profile key: {}", e)))?;
+    Ok(Aes256Gcm::new(GenericArray::from_slice(key.as_slice())))
+}
@@ -0,0 +1,256 @@
+//! Two-step swap quotes
+//!
+//! [`MantraDexClient::get_quote`](crate::MantraDexClient::get_quote) simulates a
+//! swap and returns a [`Quote`]: the expected output, the pool the swap is
+//! routed through, the fees it pays, and the block height and time it stays
+//! valid until. [`MantraDexClient::execute_quote`](crate::MantraDexClient::execute_quote)
+//! executes it later, refusing once it expired or when the swap would now return
+//! less than the quote's tolerance allows.
+//!
+//! Quotes are signed with the key of the wallet that asked for them, and only
+//! that wallet executes them, unchanged.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::SimulationResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::canonical;
+use crate::error::Error;
+use crate::routing::PoolSelection;
+use crate::wallet::MantraWallet;
+
+/// How long a quote stays valid by default
+pub const DEFAULT_QUOTE_TTL: Duration = Duration::from_secs(30);
+
+/// Blocks a quote stays valid for by default
+pub const DEFAULT_QUOTE_BLOCKS: u64 = 5;
+
+/// Share of the quoted return a swap may lose to price moves by default
+pub const DEFAULT_PRICE_TOLERANCE: Decimal = Decimal::permille(5);
+
+/// A swap to quote
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteRequest {
+    /// The asset being offered for swap
+    pub offer_asset: Coin,
+    /// The denomination of the asset being requested
+    pub ask_asset_denom: String,
+    /// How to choose the pool
+    pub selection: PoolSelection,
+    /// Maximum slippage tolerance the swap is executed with
+    pub max_slippage: Option<Decimal>,
+    /// Share of the quoted return the swap may lose before execution is refused
+    pub price_tolerance: Decimal,
+    /// How long the quote stays valid
+    pub ttl: Duration,
+    /// Blocks the quote stays valid for
+    pub valid_blocks: u64,
+}
+
+impl QuoteRequest {
+    /// Quote a swap in the best pool, with the default validity and tolerance
+    pub fn new(offer_asset: Coin, ask_asset_denom: impl Into<String>) -> Self {
+        Self {
+            offer_asset,
+            ask_asset_denom: ask_asset_denom.into(),
+            selection: PoolSelection::Best,
+            max_slippage: None,
+            price_tolerance: DEFAULT_PRICE_TOLERANCE,
+            ttl: DEFAULT_QUOTE_TTL,
+            valid_blocks: DEFAULT_QUOTE_BLOCKS,
+        }
+    }
+
+    /// Quote the swap in a specific pool
+    pub fn with_pool(mut self, pool_id: impl Into<String>) -> Self {
+        self.selection = PoolSelection::pool(pool_id);
+        self
+    }
+
+    /// Set the maximum slippage tolerance the swap is executed with
+    pub fn with_max_slippage(mut self, max_slippage: Decimal) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    /// Set the share of the quoted return the swap may lose before execution is refused
+    pub fn with_price_tolerance(mut self, price_tolerance: Decimal) -> Self {
+        self.price_tolerance = price_tolerance;
+        self
+    }
+
+    /// Set how long the quote stays valid
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Set how many blocks the quote stays valid for
+    pub fn with_valid_blocks(mut self, valid_blocks: u64) -> Self {
+        self.valid_blocks = valid_blocks;
+        self
+    }
+}
+
+/// Amounts of the ask asset a swap gives up, by cause
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    pub swap_fee: Coin,
+    pub protocol_fee: Coin,
+    pub burn_fee: Coin,
+    pub extra_fees: Coin,
+    /// Lost to price impact rather than fees
+    pub slippage: Coin,
+}
+
+impl FeeBreakdown {
+    /// Fees of a simulated swap returning `ask_asset_denom`
+    pub fn from_simulation(simulation: &SimulationResponse, ask_asset_denom: &str) -> Self {
+        let coin = |amount: Uint128| Coin {
+            denom: ask_asset_denom.to_string(),
+            amount,
+        };
+        Self {
+            swap_fee: coin(simulation.swap_fee_amount),
+            protocol_fee: coin(simulation.protocol_fee_amount),
+            burn_fee: coin(simulation.burn_fee_amount),
+            extra_fees: coin(simulation.extra_fees_amount),
+            slippage: coin(simulation.slippage_amount),
+        }
+    }
+
+    /// Sum of the fees, slippage excluded
+    pub fn total_fees(&self) -> Uint128 {
+        self.swap_fee.amount
+            + self.protocol_fee.amount
+            + self.burn_fee.amount
+            + self.extra_fees.amount
+    }
+}
+
+/// A quoted swap, executable until it expires
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Quote {
+    /// The identifier of the pool the swap is routed through
+    pub pool_id: String,
+    pub offer_asset: Coin,
+    pub ask_asset_denom: String,
+    /// Amount of the ask asset the swap returned when quoted
+    pub expected_return: Uint128,
+    pub fees: FeeBreakdown,
+    /// Maximum slippage tolerance the swap is executed with
+    pub max_slippage: Option<Decimal>,
+    /// Share of `expected_return` the swap may lose before execution is refused
+    pub price_tolerance: Decimal,
+    pub quoted_at: DateTime<Utc>,
+    pub quoted_height: u64,
+    /// Last time the quote can be executed at
+    pub valid_until: DateTime<Utc>,
+    /// Last block height the quote can be executed at
+    pub valid_until_height: u64,
+    /// Address of the wallet that signed the quote
+    pub signer: Option<String>,
+    /// Hex signature of the quote by `signer`'s key
+    pub signature: Option<String>,
+}
+
+impl Quote {
+    /// Least amount of the ask asset the swap may return to be executed
+    pub fn min_return(&self) -> Uint128 {
+        let kept = Decimal::one()
+            .checked_sub(self.price_tolerance)
+            .unwrap_or_default();
+        self.expected_return.mul_floor(kept)
+    }
+
+    /// Whether the quote can no longer be executed at `now` and `height`
+    pub fn is_expired(&self, now: DateTime<Utc>, height: u64) -> bool {
+        now > self.valid_until || height > self.valid_until_height
+    }
+
+    /// Check the quote can still be executed at `now` and `height`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if the quote expired
+    pub fn check_expiry(&self, now: DateTime<Utc>, height: u64) -> Result<(), Error> {
+        if self.is_expired(now, height) {
+            return Err(Error::Policy(format!(
+                "Quote expired: valid until {} or height {}",
+                self.valid_until.to_rfc3339(),
+                self.valid_until_height
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check a swap returning `current_return` is within the quote's tolerance
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if the price moved beyond the tolerance
+    pub fn check_price(&self, current_return: Uint128) -> Result<(), Error> {
+        if current_return < self.min_return() {
+            return Err(Error::Policy(format!(
+                "Price moved beyond tolerance: swap now returns {}{}, quoted {} with at least {}",
+                current_return,
+                self.ask_asset_denom,
+                self.expected_return,
+                self.min_return()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Sign the quote with `wallet`'s key
+    pub fn sign(&mut self, wallet: &MantraWallet) -> Result<(), Error> {
+        self.signer = Some(wallet.address()?.to_string());
+        self.signature = Some(self.signature_by(wallet)?);
+        Ok(())
+    }
+
+    /// Check the quote was signed by `wallet` and not changed since
+    ///
+    /// Signatures are deterministic, so the quote is signed again and compared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Policy`] if the quote is unsigned, signed by another
+    /// wallet, or was changed after signing
+    pub fn verify(&self, wallet: &MantraWallet) -> Result<(), Error> {
+        let signature = self
+            .signature
+            .as_deref()
+            .ok_or_else(|| Error::Policy("Quote is not signed".to_string()))?;
+        let address = wallet.address()?.to_string();
+        if self.signer.as_deref() != Some(address.as_str()) {
+            return Err(Error::Policy(format!(
+                "Quote was signed by {}, not by {}",
+                self.signer.as_deref().unwrap_or("nobody"),
+                address
+            )));
+        }
+        if self.signature_by(wallet)? != signature {
+            return Err(Error::Policy(
+                "Quote signature doesn't match its contents".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Hex signature by `wallet` of the quote's contents and signer
+    fn signature_by(&self, wallet: &MantraWallet) -> Result<String, Error> {
+        let unsigned = Quote {
+            signature: None,
+            ..self.clone()
+        };
+        let signature = wallet
+            .signing_key()?
+            .sign(&canonical::to_vec(&unsigned)?)
+            .map_err(|e| Error::Wallet(format!("Failed to sign quote: {}", e)))?;
+        Ok(hex::encode(signature.to_bytes()))
+    }
+}
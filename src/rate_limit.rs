@@ -0,0 +1,92 @@
+//! Per-client rate limiting
+//!
+//! Each client gets a token bucket holding up to a burst of requests that refills
+//! at a steady rate. A request takes one token; a client with an empty bucket is
+//! told how long to wait for the next one. Buckets that have refilled completely
+//! carry no information and are pruned, so memory stays bounded by the number of
+//! recently active clients.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::error::Error;
+
+/// Buckets are pruned once this many clients are being tracked
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Token-bucket rate limiter keyed by client
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Most requests a client can make at once
+    burst: u32,
+    /// Tokens added per second
+    refill_per_sec: f64,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// Allow `per_minute` requests a minute per client, with bursts of up to `burst`
+    pub fn new(per_minute: u32, burst: u32) -> Result<Self, Error> {
+        if per_minute == 0 || burst == 0 {
+            return Err(Error::Config(
+                "Rate limit and burst must be greater than 0".to_string(),
+            ));
+        }
+        Ok(Self {
+            burst,
+            refill_per_sec: f64::from(per_minute) / 60.0,
+            buckets: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Take a token for `client`, or return how long it has to wait for one
+    pub fn check(&self, client: &str) -> Result<(), Duration> {
+        self.check_at(client, Instant::now())
+    }
+
+    /// [`check`](Self::check) as of `now`
+    pub fn check_at(&self, client: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= PRUNE_THRESHOLD {
+            self.prune(&mut buckets, now);
+        }
+
+        let burst = f64::from(self.burst);
+        let bucket = buckets.entry(client.to_string()).or_insert(Bucket {
+            tokens: burst,
+            updated_at: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.refill_per_sec).min(burst);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait = (1.0 - bucket.tokens) / self.refill_per_sec;
+            Err(Duration::from_secs_f64(wait))
+        }
+    }
+
+    /// Number of clients currently tracked
+    pub fn tracked_clients(&self) -> usize {
+        self.buckets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Drop the buckets of clients that would be back at a full burst by `now`
+    fn prune(&self, buckets: &mut HashMap<String, Bucket>, now: Instant) {
+        let burst = f64::from(self.burst);
+        buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.updated_at);
+            bucket.tokens + elapsed.as_secs_f64() * self.refill_per_sec < burst
+        });
+    }
+}
@@ -0,0 +1,573 @@
+//! Scheduled portfolio summaries
+//!
+//! A [`PortfolioReporter`] runs alongside the [event bridge](crate::bridge) and, at
+//! the configured times, sends a summary of an address's portfolio to the bridge's
+//! sinks: balances valued in a quote denom, the change in value since the previous
+//! summary, pending farm rewards and notable price moves of the held assets.
+//!
+//! Summaries are rendered as Markdown or HTML from a template with `{{placeholder}}`
+//! fields, so the delivered text can be tailored to wherever it ends up: a chat
+//! webhook, or a relay that forwards it as email. Values come from pool spot prices
+//! (see [`PriceBook`]), and the change in value includes deposits and withdrawals
+//! made since the previous summary.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use futures::TryStreamExt;
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::EventBridge;
+use crate::client::MantraDexClient;
+use crate::convert::PriceBook;
+use crate::error::Error;
+
+/// Default price change, in percent, from which a move is reported
+pub const DEFAULT_PRICE_MOVE_PERCENT: u64 = 5;
+
+/// Page size used to read the pool list for pricing
+const POOL_PAGE_SIZE: u32 = 100;
+
+/// Default Markdown template
+pub const DEFAULT_MARKDOWN_TEMPLATE: &str = "# Portfolio summary for {{address}}
+
+{{date}}
+
+**Total value:** {{total_value}} {{quote}}
+**Change since last summary:** {{pnl}}
+
+## Balances
+
+{{balances}}
+
+## Pending rewards
+
+{{rewards}}
+
+## Price moves
+
+{{price_moves}}
+";
+
+/// Default HTML template
+pub const DEFAULT_HTML_TEMPLATE: &str = "<h1>Portfolio summary for {{address}}</h1>
+<p>{{date}}</p>
+<p><strong>Total value:</strong> {{total_value}} {{quote}}<br>
+<strong>Change since last summary:</strong> {{pnl}}</p>
+<h2>Balances</h2>
+{{balances}}
+<h2>Pending rewards</h2>
+{{rewards}}
+<h2>Price moves</h2>
+{{price_moves}}
+";
+
+/// When summaries are sent, in UTC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportSchedule {
+    /// Every day at the given time
+    Daily(NaiveTime),
+    /// Every week on the given day and time
+    Weekly(Weekday, NaiveTime),
+}
+
+impl ReportSchedule {
+    /// First scheduled time strictly after `now`
+    pub fn next_after(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let (at, period, days_ahead) = match *self {
+            ReportSchedule::Daily(at) => (at, Duration::days(1), 0),
+            ReportSchedule::Weekly(weekday, at) => {
+                let days_ahead =
+                    (7 + weekday.num_days_from_monday() - now.weekday().num_days_from_monday()) % 7;
+                (at, Duration::weeks(1), days_ahead)
+            }
+        };
+        let candidate = (now.date_naive() + Duration::days(days_ahead.into()))
+            .and_time(at)
+            .and_utc();
+        if candidate > now {
+            candidate
+        } else {
+            candidate + period
+        }
+    }
+}
+
+impl FromStr for ReportSchedule {
+    type Err = Error;
+
+    /// Parse `daily@HH:MM`, `weekly@HH:MM` (Mondays) or `<weekday>@HH:MM`, e.g. `fri@17:30`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            Error::Config(format!(
+                "Invalid report schedule '{}': use daily@HH:MM, weekly@HH:MM or <weekday>@HH:MM",
+                s
+            ))
+        };
+        let (day, time) = s.split_once('@').ok_or_else(invalid)?;
+        let at = NaiveTime::parse_from_str(time, "%H:%M").map_err(|_| invalid())?;
+
+        match day.to_lowercase().as_str() {
+            "daily" => Ok(ReportSchedule::Daily(at)),
+            "weekly" => Ok(ReportSchedule::Weekly(Weekday::Mon, at)),
+            weekday => weekday
+                .parse()
+                .map(|weekday| ReportSchedule::Weekly(weekday, at))
+                .map_err(|_| invalid()),
+        }
+    }
+}
+
+impl fmt::Display for ReportSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportSchedule::Daily(at) => write!(f, "daily at {} UTC", at.format("%H:%M")),
+            ReportSchedule::Weekly(weekday, at) => {
+                write!(f, "every {} at {} UTC", weekday, at.format("%H:%M"))
+            }
+        }
+    }
+}
+
+/// Output format of a rendered summary
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    /// Template used when none is configured
+    pub fn default_template(&self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => DEFAULT_MARKDOWN_TEMPLATE,
+            ReportFormat::Html => DEFAULT_HTML_TEMPLATE,
+        }
+    }
+}
+
+/// A balance and its value in the quote denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoldingValue {
+    pub coin: Coin,
+    /// Value in base units of the quote denom, `None` if no pool prices the denom
+    pub value: Option<Uint128>,
+}
+
+/// An address's balances and their prices at one point in time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    pub taken_at: DateTime<Utc>,
+    /// Denom values and prices are expressed in
+    pub quote_denom: String,
+    pub holdings: Vec<HoldingValue>,
+    /// Price of one base unit of each held denom in base units of the quote denom
+    pub prices: BTreeMap<String, Decimal>,
+}
+
+impl PortfolioSnapshot {
+    /// Value `balances` in `quote_denom` at the spot prices of `pools`
+    pub fn new(
+        balances: Vec<Coin>,
+        pools: &[PoolInfoResponse],
+        quote_denom: &str,
+        taken_at: DateTime<Utc>,
+    ) -> Self {
+        Self::from_price_book(balances, PriceBook::new(pools), quote_denom, taken_at)
+    }
+
+    /// Value `balances` in `quote_denom` at the prices of `prices`
+    ///
+    /// `quote_denom` may be [`USD`](crate::convert::USD) if `prices` knows the
+    /// USD stablecoins.
+    pub fn from_price_book(
+        balances: Vec<Coin>,
+        prices: PriceBook<'_>,
+        quote_denom: &str,
+        taken_at: DateTime<Utc>,
+    ) -> Self {
+        let mut quotes = BTreeMap::new();
+        let holdings = balances
+            .into_iter()
+            .map(|coin| {
+                let price = prices.price(&coin.denom, quote_denom);
+                if let Some(price) = price {
+                    quotes.insert(coin.denom.clone(), price);
+                }
+                HoldingValue {
+                    value: price.map(|price| coin.amount.mul_floor(price)),
+                    coin,
+                }
+            })
+            .collect();
+
+        Self {
+            taken_at,
+            quote_denom: quote_denom.to_string(),
+            holdings,
+            prices: quotes,
+        }
+    }
+
+    /// Combined value of the holdings that could be priced
+    pub fn total_value(&self) -> Uint128 {
+        self.holdings
+            .iter()
+            .filter_map(|holding| holding.value)
+            .sum()
+    }
+}
+
+/// A held denom whose price changed notably between two summaries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceMove {
+    pub denom: String,
+    pub previous: Decimal,
+    pub current: Decimal,
+}
+
+impl PriceMove {
+    /// Relative change between the two prices, as a fraction
+    pub fn change(&self) -> Decimal {
+        if self.previous.is_zero() {
+            return Decimal::zero();
+        }
+        self.current.abs_diff(self.previous) / self.previous
+    }
+
+    /// Whether the price went up
+    pub fn is_rise(&self) -> bool {
+        self.current >= self.previous
+    }
+}
+
+/// Everything a scheduled summary reports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortfolioSummary {
+    pub address: String,
+    pub snapshot: PortfolioSnapshot,
+    /// Total value at the previous summary, if there was one
+    pub previous_value: Option<Uint128>,
+    /// Rewards the address could claim now
+    pub pending_rewards: Vec<Coin>,
+    /// Prices that moved at least the configured threshold since the previous summary
+    pub price_moves: Vec<PriceMove>,
+}
+
+impl PortfolioSummary {
+    /// Compare `snapshot` with the `previous` one, reporting price moves of at least `threshold`
+    pub fn new(
+        address: impl Into<String>,
+        snapshot: PortfolioSnapshot,
+        previous: Option<&PortfolioSnapshot>,
+        pending_rewards: Vec<Coin>,
+        threshold: Decimal,
+    ) -> Self {
+        let price_moves = previous
+            .filter(|previous| previous.quote_denom == snapshot.quote_denom)
+            .map(|previous| {
+                snapshot
+                    .prices
+                    .iter()
+                    .filter_map(|(denom, current)| {
+                        let price_move = PriceMove {
+                            denom: denom.clone(),
+                            previous: *previous.prices.get(denom)?,
+                            current: *current,
+                        };
+                        (price_move.change() >= threshold).then_some(price_move)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            address: address.into(),
+            previous_value: previous.map(PortfolioSnapshot::total_value),
+            snapshot,
+            pending_rewards,
+            price_moves,
+        }
+    }
+
+    /// Subject line for the delivered message
+    pub fn subject(&self) -> String {
+        format!(
+            "Portfolio summary for {} ({})",
+            self.address,
+            self.snapshot.taken_at.format("%Y-%m-%d")
+        )
+    }
+
+    /// Fill `template` with this summary's fields, formatted for `format`
+    ///
+    /// Supported placeholders: `{{address}}`, `{{date}}`, `{{quote}}`,
+    /// `{{total_value}}`, `{{pnl}}`, `{{balances}}`, `{{rewards}}` and
+    /// `{{price_moves}}`.
+    pub fn render(&self, format: ReportFormat, template: &str) -> String {
+        let quote = &self.snapshot.quote_denom;
+        let total_value = self.snapshot.total_value();
+        let pnl = match self.previous_value {
+            Some(previous) if total_value >= previous => {
+                format!("+{} {}", total_value - previous, quote)
+            }
+            Some(previous) => format!("-{} {}", previous - total_value, quote),
+            None => "n/a (first summary)".to_string(),
+        };
+
+        let balances: Vec<String> = self
+            .snapshot
+            .holdings
+            .iter()
+            .map(|holding| match holding.value {
+                Some(value) => format!(
+                    "{} {} ({} {})",
+                    holding.coin.amount, holding.coin.denom, value, quote
+                ),
+                None => format!("{} {} (unpriced)", holding.coin.amount, holding.coin.denom),
+            })
+            .collect();
+        let rewards: Vec<String> = self
+            .pending_rewards
+            .iter()
+            .map(|coin| format!("{} {}", coin.amount, coin.denom))
+            .collect();
+        let price_moves: Vec<String> = self
+            .price_moves
+            .iter()
+            .map(|price_move| {
+                format!(
+                    "{} {}{} ({} → {} {})",
+                    price_move.denom,
+                    if price_move.is_rise() { "+" } else { "-" },
+                    format_percent(price_move.change()),
+                    price_move.previous,
+                    price_move.current,
+                    quote
+                )
+            })
+            .collect();
+
+        let fields = [
+            ("address", format_text(format, &self.address)),
+            (
+                "date",
+                format_text(
+                    format,
+                    &self
+                        .snapshot
+                        .taken_at
+                        .format("%Y-%m-%d %H:%M UTC")
+                        .to_string(),
+                ),
+            ),
+            ("quote", format_text(format, quote)),
+            ("total_value", total_value.to_string()),
+            ("pnl", format_text(format, &pnl)),
+            ("balances", format_list(format, &balances, "No balances")),
+            (
+                "rewards",
+                format_list(format, &rewards, "No pending rewards"),
+            ),
+            (
+                "price_moves",
+                format_list(format, &price_moves, "No notable price moves"),
+            ),
+        ];
+        fields
+            .iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{{{}}}}}", name), value)
+            })
+    }
+}
+
+/// A fraction as a percentage with two decimals
+fn format_percent(fraction: Decimal) -> String {
+    let basis_points = (fraction * Decimal::from_ratio(10_000u128, 1u128)).to_uint_floor();
+    format!(
+        "{}.{:02}%",
+        basis_points / Uint128::new(100),
+        (basis_points % Uint128::new(100)).u128()
+    )
+}
+
+/// Escape `text` for `format`
+fn format_text(format: ReportFormat, text: &str) -> String {
+    match format {
+        ReportFormat::Markdown => text.to_string(),
+        ReportFormat::Html => text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;"),
+    }
+}
+
+/// A bulleted list of `items`, or `empty` when there are none
+fn format_list(format: ReportFormat, items: &[String], empty: &str) -> String {
+    if items.is_empty() {
+        return match format {
+            ReportFormat::Markdown => format!("_{}_", empty),
+            ReportFormat::Html => format!("<p><em>{}</em></p>", empty),
+        };
+    }
+    match format {
+        ReportFormat::Markdown => items
+            .iter()
+            .map(|item| format!("- {}", item))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ReportFormat::Html => format!(
+            "<ul>\n{}\n</ul>",
+            items
+                .iter()
+                .map(|item| format!("<li>{}</li>", format_text(format, item)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+    }
+}
+
+/// Builds and sends portfolio summaries for an address on a schedule
+pub struct PortfolioReporter {
+    client: Arc<MantraDexClient>,
+    address: String,
+    schedule: ReportSchedule,
+    format: ReportFormat,
+    template: Option<String>,
+    quote_denom: String,
+    threshold: Decimal,
+    previous: Option<PortfolioSnapshot>,
+}
+
+impl PortfolioReporter {
+    /// Create a reporter valuing the portfolio in the network's native denom
+    pub fn new(client: Arc<MantraDexClient>, address: String, schedule: ReportSchedule) -> Self {
+        let quote_denom = client.config().native_denom.clone();
+        Self {
+            client,
+            address,
+            schedule,
+            format: ReportFormat::default(),
+            template: None,
+            quote_denom,
+            threshold: Decimal::percent(DEFAULT_PRICE_MOVE_PERCENT),
+            previous: None,
+        }
+    }
+
+    /// Set the output format
+    pub fn with_format(mut self, format: ReportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Render summaries from this template instead of the format's default
+    pub fn with_template(mut self, template: String) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Value the portfolio in this denom, or [`USD`](crate::convert::USD)
+    pub fn with_quote_denom(mut self, quote_denom: String) -> Self {
+        self.quote_denom = quote_denom;
+        self
+    }
+
+    /// Report price moves of at least this fraction, e.g. `0.05` for 5%
+    pub fn with_price_move_threshold(mut self, threshold: Decimal) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// When summaries are sent
+    pub fn schedule(&self) -> ReportSchedule {
+        self.schedule
+    }
+
+    /// Address summarized
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Build a summary now, comparing against the previous one
+    pub async fn summary(&mut self) -> Result<PortfolioSummary, Error> {
+        let pools: Vec<PoolInfoResponse> = self
+            .client
+            .pools_stream(POOL_PAGE_SIZE)
+            .try_collect()
+            .await?;
+        let balances = self.client.get_balances_for_address(&self.address).await?;
+        // Addresses without farm positions, or networks without a farm manager, have none
+        let pending_rewards = self
+            .client
+            .get_pending_rewards(&self.address)
+            .await
+            .unwrap_or_default();
+
+        let prices = PriceBook::new(&pools).with_usd_denoms(self.client.usd_denoms());
+        let snapshot =
+            PortfolioSnapshot::from_price_book(balances, prices, &self.quote_denom, Utc::now());
+        let summary = PortfolioSummary::new(
+            self.address.clone(),
+            snapshot.clone(),
+            self.previous.as_ref(),
+            pending_rewards,
+            self.threshold,
+        );
+        self.previous = Some(snapshot);
+        Ok(summary)
+    }
+
+    /// Build a summary now and send it to the bridge's sinks
+    ///
+    /// The JSON payload carries the rendered `body` alongside the raw `summary`,
+    /// which is returned once delivered.
+    pub async fn send(&mut self, bridge: &EventBridge) -> Result<PortfolioSummary, Error> {
+        let summary = self.summary().await?;
+        let template = self
+            .template
+            .as_deref()
+            .unwrap_or(self.format.default_template());
+        let payload = serde_json::json!({
+            "kind": "portfolio_summary",
+            "format": self.format,
+            "subject": summary.subject(),
+            "body": summary.render(self.format, template),
+            "summary": summary,
+        });
+        bridge.deliver(&serde_json::to_vec(&payload)?).await?;
+        Ok(summary)
+    }
+
+    /// Send summaries at the scheduled times, forever
+    ///
+    /// `on_send` gets the outcome of every summary, the summary sent or why it
+    /// failed; a failed summary doesn't stop the reporter.
+    ///
+    /// # Errors
+    ///
+    /// Only returns, with its error, once `on_send` fails
+    pub async fn run<F>(&mut self, bridge: &EventBridge, mut on_send: F) -> Result<(), Error>
+    where
+        F: FnMut(Result<PortfolioSummary, Error>) -> Result<(), Error>,
+    {
+        loop {
+            let now = Utc::now();
+            let wait = (self.schedule.next_after(now) - now)
+                .to_std()
+                .unwrap_or_default();
+            tokio::time::sleep(wait).await;
+
+            on_send(self.send(bridge).await)?;
+        }
+    }
+}
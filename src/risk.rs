@@ -0,0 +1,324 @@
+//! Token risk screening heuristics
+//!
+//! Anyone can create a token and a pool for it, so pool listings mix established
+//! assets with throwaway ones. The heuristics here flag the usual red flags of the
+//! latter: factory tokens without bank metadata or claiming a well-known symbol,
+//! denylisted denoms, pools with a single liquidity provider, one provider
+//! holding most of the liquidity or very shallow reserves, and denoms created
+//! only recently. A flag is a warning, not a verdict;
+//! nothing here refuses an operation.
+//!
+//! The checks are pure functions of what [`MantraDexClient::assess_pool_risk`]
+//! probes on chain, so they can be reused with data gathered elsewhere.
+//!
+//! [`MantraDexClient::assess_pool_risk`]: crate::MantraDexClient::assess_pool_risk
+
+use std::fmt;
+
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::ownership::LpConcentration;
+
+/// Default reserve, in whole tokens, below which a pool asset counts as illiquid
+pub const DEFAULT_MIN_RESERVE: u64 = 1_000;
+
+/// Default age, in blocks, under which a denom counts as freshly created (about a week)
+pub const DEFAULT_NEW_DENOM_BLOCKS: u64 = 100_000;
+
+/// Default share of a pool's LP supply above which one provider dominates it
+pub const DEFAULT_DOMINANT_LP_SHARE: Decimal = Decimal::percent(50);
+
+/// Symbols that only the established assets carrying them should use
+pub const DEFAULT_RESERVED_SYMBOLS: &[&str] = &["OM", "USDC", "USDT", "ATOM", "ETH", "BTC", "WBTC"];
+
+/// How serious a risk flag is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    /// Worth a second look before trading
+    Caution,
+    /// Likely a scam or a trap
+    Danger,
+}
+
+impl RiskLevel {
+    /// Short label for display
+    pub fn label(&self) -> &'static str {
+        match self {
+            RiskLevel::Caution => "Caution",
+            RiskLevel::Danger => "Danger",
+        }
+    }
+}
+
+/// A single red flag raised against a denom or pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RiskFlag {
+    /// A factory token without bank metadata
+    MissingMetadata { denom: String },
+    /// A factory token whose metadata claims a reserved symbol
+    ImpersonatedSymbol { denom: String, symbol: String },
+    /// A denom on the asset filter's denylist
+    Denylisted { denom: String },
+    /// A pool whose LP tokens are all held by one account
+    SingleLiquidityProvider { pool_id: String },
+    /// A pool with several providers, one of which holds most of the LP tokens
+    DominantLiquidityProvider {
+        pool_id: String,
+        address: String,
+        share: Decimal,
+    },
+    /// A pool asset whose reserve is below the minimum
+    LowLiquidity {
+        pool_id: String,
+        denom: String,
+        reserve: Uint128,
+    },
+    /// A denom created fewer than the configured number of blocks ago
+    NewDenom { denom: String, age_blocks: u64 },
+}
+
+impl RiskFlag {
+    /// How serious the flag is
+    pub fn level(&self) -> RiskLevel {
+        match self {
+            RiskFlag::ImpersonatedSymbol { .. } | RiskFlag::Denylisted { .. } => RiskLevel::Danger,
+            RiskFlag::MissingMetadata { .. }
+            | RiskFlag::SingleLiquidityProvider { .. }
+            | RiskFlag::DominantLiquidityProvider { .. }
+            | RiskFlag::LowLiquidity { .. }
+            | RiskFlag::NewDenom { .. } => RiskLevel::Caution,
+        }
+    }
+}
+
+impl fmt::Display for RiskFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RiskFlag::MissingMetadata { denom } => write!(f, "{} has no token metadata", denom),
+            RiskFlag::ImpersonatedSymbol { denom, symbol } => {
+                write!(f, "{} claims the symbol {} of another asset", denom, symbol)
+            }
+            RiskFlag::Denylisted { denom } => write!(f, "{} is denylisted", denom),
+            RiskFlag::SingleLiquidityProvider { pool_id } => {
+                write!(f, "Pool {} has a single liquidity provider", pool_id)
+            }
+            RiskFlag::DominantLiquidityProvider {
+                pool_id,
+                address,
+                share,
+            } => write!(
+                f,
+                "{} holds {}% of pool {}'s liquidity; its withdrawal would drain the pool",
+                address,
+                Decimal::from_ratio(Uint128::new(1_000).mul_floor(*share), 10u128),
+                pool_id
+            ),
+            RiskFlag::LowLiquidity {
+                pool_id,
+                denom,
+                reserve,
+            } => write!(
+                f,
+                "Pool {} holds only {} {} (base units)",
+                pool_id, reserve, denom
+            ),
+            RiskFlag::NewDenom { denom, age_blocks } => {
+                write!(f, "{} was created {} blocks ago", denom, age_blocks)
+            }
+        }
+    }
+}
+
+/// Limits the heuristics flag against
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RiskThresholds {
+    /// Reserve, in whole tokens, below which a pool asset counts as illiquid
+    pub min_reserve: u64,
+    /// Age, in blocks, under which a denom counts as freshly created
+    pub new_denom_blocks: u64,
+    /// Share of a pool's LP supply above which one provider dominates it
+    pub dominant_lp_share: Decimal,
+    /// Symbols a factory token may not claim, compared case-insensitively
+    pub reserved_symbols: Vec<String>,
+}
+
+impl Default for RiskThresholds {
+    fn default() -> Self {
+        Self {
+            min_reserve: DEFAULT_MIN_RESERVE,
+            new_denom_blocks: DEFAULT_NEW_DENOM_BLOCKS,
+            dominant_lp_share: DEFAULT_DOMINANT_LP_SHARE,
+            reserved_symbols: DEFAULT_RESERVED_SYMBOLS
+                .iter()
+                .map(|symbol| symbol.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// What is known about a denom's bank metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DenomMetadata {
+    /// Not checked, e.g. for native and IBC denoms
+    Unchecked,
+    /// The denom has no metadata
+    Missing,
+    /// The denom has metadata with this symbol
+    Present { symbol: String },
+}
+
+/// On-chain facts about a denom that the heuristics judge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenomProbe {
+    pub denom: String,
+    /// Whether the asset filter denies the denom
+    pub denylisted: bool,
+    pub metadata: DenomMetadata,
+    /// Blocks since the denom was created, if known
+    pub age_blocks: Option<u64>,
+}
+
+impl DenomProbe {
+    /// A probe that found nothing about `denom`
+    pub fn unchecked(denom: impl Into<String>) -> Self {
+        Self {
+            denom: denom.into(),
+            denylisted: false,
+            metadata: DenomMetadata::Unchecked,
+            age_blocks: None,
+        }
+    }
+
+    /// Flags raised by this denom alone
+    pub fn flags(&self, thresholds: &RiskThresholds) -> Vec<RiskFlag> {
+        let mut flags = Vec::new();
+        if self.denylisted {
+            flags.push(RiskFlag::Denylisted {
+                denom: self.denom.clone(),
+            });
+        }
+        match &self.metadata {
+            DenomMetadata::Unchecked => {}
+            DenomMetadata::Missing => flags.push(RiskFlag::MissingMetadata {
+                denom: self.denom.clone(),
+            }),
+            DenomMetadata::Present { symbol } => {
+                if thresholds
+                    .reserved_symbols
+                    .iter()
+                    .any(|reserved| reserved.eq_ignore_ascii_case(symbol))
+                {
+                    flags.push(RiskFlag::ImpersonatedSymbol {
+                        denom: self.denom.clone(),
+                        symbol: symbol.clone(),
+                    });
+                }
+            }
+        }
+        if let Some(age_blocks) = self.age_blocks {
+            if age_blocks < thresholds.new_denom_blocks {
+                flags.push(RiskFlag::NewDenom {
+                    denom: self.denom.clone(),
+                    age_blocks,
+                });
+            }
+        }
+        flags
+    }
+}
+
+/// Flags for pool assets whose reserves are below the minimum
+///
+/// Reserves are compared in whole tokens using the pool's asset decimals.
+pub fn liquidity_flags(pool: &PoolInfoResponse, thresholds: &RiskThresholds) -> Vec<RiskFlag> {
+    let info = &pool.pool_info;
+    info.assets
+        .iter()
+        .filter(|asset| {
+            let decimals = info
+                .asset_denoms
+                .iter()
+                .position(|denom| *denom == asset.denom)
+                .and_then(|index| info.asset_decimals.get(index).copied())
+                .unwrap_or(6);
+            let min_reserve = 10u128
+                .checked_pow(decimals.into())
+                .and_then(|unit| unit.checked_mul(thresholds.min_reserve.into()))
+                .unwrap_or(u128::MAX);
+            asset.amount.u128() < min_reserve
+        })
+        .map(|asset| RiskFlag::LowLiquidity {
+            pool_id: info.pool_identifier.clone(),
+            denom: asset.denom.clone(),
+            reserve: asset.amount,
+        })
+        .collect()
+}
+
+/// Flag for a pool one of several LP holders dominates
+pub fn concentration_flags(
+    pool_id: &str,
+    concentration: &LpConcentration,
+    thresholds: &RiskThresholds,
+) -> Vec<RiskFlag> {
+    concentration
+        .dominant_holder(thresholds.dominant_lp_share)
+        .map(|holder| RiskFlag::DominantLiquidityProvider {
+            pool_id: pool_id.to_string(),
+            address: holder.address.clone(),
+            share: holder.share,
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Risk flags raised against a pool or denom
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskReport {
+    pub flags: Vec<RiskFlag>,
+}
+
+impl RiskReport {
+    /// Judge a pool from its reserves, probes of its denoms and its LP holder count
+    ///
+    /// `lp_holders` counts the accounts holding the pool's LP token other than the
+    /// pool manager; `None` skips the check.
+    pub fn for_pool(
+        pool: &PoolInfoResponse,
+        denoms: &[DenomProbe],
+        lp_holders: Option<usize>,
+        thresholds: &RiskThresholds,
+    ) -> Self {
+        let mut flags: Vec<RiskFlag> = denoms
+            .iter()
+            .flat_map(|probe| probe.flags(thresholds))
+            .collect();
+        if lp_holders == Some(1) {
+            flags.push(RiskFlag::SingleLiquidityProvider {
+                pool_id: pool.pool_info.pool_identifier.clone(),
+            });
+        }
+        flags.extend(liquidity_flags(pool, thresholds));
+        Self { flags }
+    }
+
+    /// Whether no flag was raised
+    pub fn is_clean(&self) -> bool {
+        self.flags.is_empty()
+    }
+
+    /// Most serious level among the flags, `None` if clean
+    pub fn level(&self) -> Option<RiskLevel> {
+        self.flags.iter().map(RiskFlag::level).max()
+    }
+
+    /// Human-readable warning per flag
+    pub fn warnings(&self) -> Vec<String> {
+        self.flags.iter().map(ToString::to_string).collect()
+    }
+}
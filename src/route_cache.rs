@@ -0,0 +1,211 @@
+//! Cache of selected swap routes
+//!
+//! Selecting a route quotes every pool holding the pair, which takes a page of
+//! pool queries and a simulation per pool. Bots quoting the same pairs over and
+//! over can keep the pools picked in a [`RouteCache`], keyed by offer denom, ask
+//! denom and size bucket: amounts within the same power of two share a route.
+//!
+//! Each route remembers the reserves of its pool when it was picked. Once they
+//! move by more than [`RouteCacheConfig::reserve_threshold`], another pool may
+//! quote better, so every route through the pool is dropped. The move is seen by
+//! [`MantraDexClient::watch_route_cache`], which polls the pools on cached
+//! routes, or when a cached route is next used.
+//!
+//! [`MantraDexClient::watch_route_cache`]: crate::client::MantraDexClient::watch_route_cache
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+/// How long cached routes are used and when pool moves invalidate them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RouteCacheConfig {
+    /// Largest move of a pool reserve, as a fraction, that keeps routes through it
+    pub reserve_threshold: Decimal,
+    /// Seconds a route is used before it is selected again regardless
+    pub ttl_secs: u64,
+    /// Seconds between checks of the pools on cached routes by the watcher
+    pub watch_interval_secs: u64,
+}
+
+impl Default for RouteCacheConfig {
+    fn default() -> Self {
+        Self {
+            reserve_threshold: Decimal::percent(1),
+            ttl_secs: 300,
+            watch_interval_secs: 5,
+        }
+    }
+}
+
+impl RouteCacheConfig {
+    pub fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs)
+    }
+
+    pub fn watch_interval(&self) -> Duration {
+        Duration::from_secs(self.watch_interval_secs.max(1))
+    }
+}
+
+/// Swaps sharing a cached route
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RouteKey {
+    pub offer_denom: String,
+    pub ask_denom: String,
+    /// [`size_bucket`] of the offered amount
+    pub size_bucket: u32,
+}
+
+impl RouteKey {
+    pub fn new(offer_asset: &Coin, ask_denom: &str) -> Self {
+        Self {
+            offer_denom: offer_asset.denom.clone(),
+            ask_denom: ask_denom.to_string(),
+            size_bucket: size_bucket(offer_asset.amount),
+        }
+    }
+}
+
+/// Bucket of swap sizes `amount` falls in: its number of significant bits, so
+/// each bucket spans a power of two
+pub fn size_bucket(amount: Uint128) -> u32 {
+    u128::BITS - amount.u128().leading_zeros()
+}
+
+/// A route served from the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedRoute {
+    /// Pool the route goes through
+    pub pool_id: String,
+    /// Pool pinned for the pair when the route was selected, if any
+    pub pinned_pool: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    route: CachedRoute,
+    /// Reserves of the route's pool when it was selected
+    reserves: Vec<Coin>,
+    cached_at: Instant,
+}
+
+/// Routes selected for recent swaps, safe to share between tasks
+#[derive(Debug, Default)]
+pub struct RouteCache {
+    config: RouteCacheConfig,
+    entries: Mutex<HashMap<RouteKey, Entry>>,
+}
+
+impl RouteCache {
+    pub fn new(config: RouteCacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &RouteCacheConfig {
+        &self.config
+    }
+
+    /// The route cached for `key`, unless it expired
+    pub fn get(&self, key: &RouteKey) -> Option<CachedRoute> {
+        let mut entries = self.lock();
+        match entries.get(key) {
+            Some(entry) if entry.cached_at.elapsed() < self.config.ttl() => {
+                Some(entry.route.clone())
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache the route through `pool` for `key`
+    pub fn insert(&self, key: RouteKey, pool: &PoolInfoResponse, pinned_pool: Option<String>) {
+        let entry = Entry {
+            route: CachedRoute {
+                pool_id: pool.pool_info.pool_identifier.clone(),
+                pinned_pool,
+            },
+            reserves: pool.pool_info.assets.clone(),
+            cached_at: Instant::now(),
+        };
+        self.lock().insert(key, entry);
+    }
+
+    /// Check the current state of `pool` against the routes through it
+    ///
+    /// Routes whose reserves moved beyond the threshold are dropped. Returns
+    /// the number of routes dropped.
+    pub fn observe(&self, pool: &PoolInfoResponse) -> usize {
+        let pool_id = &pool.pool_info.pool_identifier;
+        let threshold = self.config.reserve_threshold;
+        let mut entries = self.lock();
+        let before = entries.len();
+        entries.retain(|_, entry| {
+            &entry.route.pool_id != pool_id
+                || !reserves_moved(&entry.reserves, &pool.pool_info.assets, threshold)
+        });
+        before - entries.len()
+    }
+
+    /// Drop every route through `pool_id`
+    pub fn invalidate_pool(&self, pool_id: &str) {
+        self.lock()
+            .retain(|_, entry| entry.route.pool_id != pool_id);
+    }
+
+    /// Drop every route
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Pools on the cached routes
+    pub fn pools(&self) -> BTreeSet<String> {
+        self.lock()
+            .values()
+            .map(|entry| entry.route.pool_id.clone())
+            .collect()
+    }
+
+    /// Number of cached routes
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<RouteKey, Entry>> {
+        // A panic while holding the lock can't leave the routes inconsistent
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Whether any reserve moved by more than `threshold` of its amount in `before`,
+/// or the pool's assets changed
+fn reserves_moved(before: &[Coin], after: &[Coin], threshold: Decimal) -> bool {
+    before.len() != after.len()
+        || before.iter().any(|old| {
+            let Some(new) = after.iter().find(|coin| coin.denom == old.denom) else {
+                return true;
+            };
+            let change = old.amount.abs_diff(new.amount);
+            if old.amount.is_zero() {
+                return !change.is_zero();
+            }
+            !Decimal::checked_from_ratio(change, old.amount).is_ok_and(|moved| moved <= threshold)
+        })
+}
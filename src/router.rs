@@ -0,0 +1,252 @@
+//! Multi-hop swap route discovery
+//!
+//! Pools form a graph whose nodes are denoms and whose edges are the pools
+//! trading two of them. [`find_routes`] walks every path of up to a given
+//! number of hops between two denoms, never visiting a denom twice, and prices
+//! each path with the local pool math of [`crate::math`], feeding each hop's
+//! output into the next. Routes are ranked by how much of the ask denom they
+//! return, and [`find_best_route`] picks the first.
+//!
+//! Only pools the local math can simulate take part in routes, so the result
+//! is an estimate from the pools' reserves at the time they were loaded.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, SwapOperation};
+use serde::{Deserialize, Serialize};
+
+use crate::pricing::{self, impact_level, impact_warning};
+use crate::risk::RiskLevel;
+
+/// Most hops a route has by default
+pub const DEFAULT_MAX_HOPS: usize = 3;
+
+/// One swap of a route, in one pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteHop {
+    /// The identifier of the pool swapped in
+    pub pool_id: String,
+    /// Asset and amount offered to the pool
+    pub offer_asset: Coin,
+    /// Denom received from the pool
+    pub ask_denom: String,
+    /// Amount of the ask denom received
+    pub return_amount: Uint128,
+    /// Swap, protocol, burn and extra fees, in the ask denom
+    pub fee_amount: Uint128,
+    /// Share of the hop's spot value lost to moving the pool's reserves
+    pub price_impact: Decimal,
+}
+
+/// A swap through one or more pools, with its expected output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwapRoute {
+    /// Asset and amount sold
+    pub offer_asset: Coin,
+    /// Denom bought
+    pub ask_denom: String,
+    /// Hops in execution order; each offers what the previous one returned
+    pub hops: Vec<RouteHop>,
+    /// Amount of the ask denom the last hop returns
+    pub return_amount: Uint128,
+    /// Share of the route's spot value lost to price impact across all hops
+    pub price_impact: Decimal,
+}
+
+impl SwapRoute {
+    /// Denoms the route passes through, from the offer to the ask denom
+    pub fn path(&self) -> Vec<&str> {
+        std::iter::once(self.offer_asset.denom.as_str())
+            .chain(self.hops.iter().map(|hop| hop.ask_denom.as_str()))
+            .collect()
+    }
+
+    /// Swap operations executing the route through the pool manager
+    pub fn operations(&self) -> Vec<SwapOperation> {
+        self.hops
+            .iter()
+            .map(|hop| SwapOperation::MantraSwap {
+                token_in_denom: hop.offer_asset.denom.clone(),
+                token_out_denom: hop.ask_denom.clone(),
+                pool_identifier: hop.pool_id.clone(),
+            })
+            .collect()
+    }
+
+    /// Least the route may return with `max_slippage` of the expected output lost
+    pub fn minimum_receive(&self, max_slippage: Decimal) -> Uint128 {
+        self.return_amount
+            .mul_floor(Decimal::one().saturating_sub(max_slippage))
+    }
+
+    /// How worrying the route's price impact is, if at all
+    pub fn level(&self) -> Option<RiskLevel> {
+        impact_level(self.price_impact)
+    }
+
+    /// A warning to show before executing the route, if its impact calls for one
+    pub fn warning(&self) -> Option<String> {
+        impact_warning(self.price_impact)
+    }
+}
+
+/// Denoms and the pools trading each pair of them
+#[derive(Debug, Clone, Default)]
+pub struct AssetGraph<'a> {
+    /// Pools holding each denom
+    pools_by_denom: BTreeMap<&'a str, Vec<&'a PoolInfoResponse>>,
+}
+
+impl<'a> AssetGraph<'a> {
+    /// Build the graph of `pools`
+    ///
+    /// Pools with swaps disabled are left out.
+    pub fn new(pools: impl IntoIterator<Item = &'a PoolInfoResponse>) -> Self {
+        let mut pools_by_denom: BTreeMap<&str, Vec<&PoolInfoResponse>> = BTreeMap::new();
+        for pool in pools {
+            if !pool.pool_info.status.swaps_enabled {
+                continue;
+            }
+            for denom in &pool.pool_info.asset_denoms {
+                pools_by_denom.entry(denom).or_default().push(pool);
+            }
+        }
+        Self { pools_by_denom }
+    }
+
+    /// Every denom held by a pool of the graph
+    pub fn denoms(&self) -> impl Iterator<Item = &str> {
+        self.pools_by_denom.keys().copied()
+    }
+
+    /// Pools a swap out of `denom` can go through, with the denoms each can return
+    fn edges(&self, denom: &str) -> Vec<(&'a PoolInfoResponse, &'a str)> {
+        let Some(pools) = self.pools_by_denom.get(denom) else {
+            return Vec::new();
+        };
+        pools
+            .iter()
+            .flat_map(|pool| {
+                pool.pool_info
+                    .asset_denoms
+                    .iter()
+                    .filter(|ask| *ask != denom)
+                    .map(|ask| (*pool, ask.as_str()))
+            })
+            .collect()
+    }
+}
+
+/// Find the route returning the most `ask_denom` for `offer_asset`, in up to `max_hops` hops
+///
+/// Ties on output go to the route with fewer hops, then to the first found.
+/// `None` if no path of simulable pools connects the two denoms.
+pub fn find_best_route(
+    graph: &AssetGraph<'_>,
+    offer_asset: &Coin,
+    ask_denom: &str,
+    max_hops: usize,
+) -> Option<SwapRoute> {
+    find_routes(graph, offer_asset, ask_denom, max_hops, 1).pop()
+}
+
+/// Find up to `limit` routes from `offer_asset` to `ask_denom` in up to `max_hops` hops, best first
+///
+/// Routes are ranked like [`find_best_route`] picks them: by output, then by
+/// fewer hops, then in the order found.
+pub fn find_routes(
+    graph: &AssetGraph<'_>,
+    offer_asset: &Coin,
+    ask_denom: &str,
+    max_hops: usize,
+    limit: usize,
+) -> Vec<SwapRoute> {
+    if offer_asset.amount.is_zero() || offer_asset.denom == ask_denom || max_hops == 0 {
+        return Vec::new();
+    }
+
+    let mut routes = Vec::new();
+    let mut visited = BTreeSet::from([offer_asset.denom.clone()]);
+    let mut hops = Vec::new();
+    search(
+        graph,
+        offer_asset,
+        ask_denom,
+        max_hops,
+        &mut visited,
+        &mut hops,
+        &mut routes,
+    );
+    routes.sort_by_key(|route| (std::cmp::Reverse(route.return_amount), route.hops.len()));
+    routes.truncate(limit);
+    routes
+}
+
+/// Extend `hops` from `offer` in every way, recording each complete route in `routes`
+fn search(
+    graph: &AssetGraph<'_>,
+    offer: &Coin,
+    ask_denom: &str,
+    max_hops: usize,
+    visited: &mut BTreeSet<String>,
+    hops: &mut Vec<RouteHop>,
+    routes: &mut Vec<SwapRoute>,
+) {
+    for (pool, next_denom) in graph.edges(&offer.denom) {
+        if visited.contains(next_denom) {
+            continue;
+        }
+        let Some(impact) = pricing::price_impact(pool, offer, next_denom) else {
+            continue;
+        };
+        if impact.return_amount.is_zero() {
+            continue;
+        }
+
+        hops.push(RouteHop {
+            pool_id: pool.pool_info.pool_identifier.clone(),
+            offer_asset: offer.clone(),
+            ask_denom: next_denom.to_string(),
+            return_amount: impact.return_amount,
+            fee_amount: impact.fee_amount,
+            price_impact: impact.price_impact,
+        });
+        if next_denom == ask_denom {
+            routes.push(route(hops.clone()));
+        } else if hops.len() < max_hops {
+            visited.insert(next_denom.to_string());
+            let next_offer = Coin {
+                denom: next_denom.to_string(),
+                amount: impact.return_amount,
+            };
+            search(
+                graph,
+                &next_offer,
+                ask_denom,
+                max_hops,
+                visited,
+                hops,
+                routes,
+            );
+            visited.remove(next_denom);
+        }
+        hops.pop();
+    }
+}
+
+/// Assemble a route from its hops, compounding their price impacts
+fn route(hops: Vec<RouteHop>) -> SwapRoute {
+    let first = &hops[0];
+    let last = &hops[hops.len() - 1];
+    let retained = hops.iter().fold(Decimal::one(), |retained, hop| {
+        retained * Decimal::one().saturating_sub(hop.price_impact)
+    });
+    SwapRoute {
+        offer_asset: first.offer_asset.clone(),
+        ask_denom: last.ask_denom.clone(),
+        return_amount: last.return_amount,
+        price_impact: Decimal::one().saturating_sub(retained),
+        hops,
+    }
+}
@@ -0,0 +1,467 @@
+//! Pool selection and order splitting helpers for swaps
+//!
+//! When several pools trade the same asset pair, the pool that gives the best
+//! output depends on the trade size. These helpers rank simulated quotes so the
+//! client can pick a pool automatically, and split large orders across pools to
+//! reduce price impact.
+
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_std::fee::PoolFee;
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType, SimulationResponse};
+use serde::Serialize;
+
+use crate::config::RoutingConfig;
+
+/// How a swap convenience method chooses the pool to trade in
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PoolSelection {
+    /// Simulate every available pool for the pair and use the best output
+    #[default]
+    Best,
+    /// Always use the given pool
+    Pool(String),
+}
+
+impl PoolSelection {
+    /// Force a specific pool
+    pub fn pool(pool_id: impl Into<String>) -> Self {
+        PoolSelection::Pool(pool_id.into())
+    }
+}
+
+/// A simulated quote for a swap in one pool
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolQuote {
+    /// The identifier of the quoted pool
+    pub pool_id: String,
+    /// Simulation result for the requested offer
+    pub simulation: SimulationResponse,
+    /// Reserve of the ask asset in the pool, used as a tie-break
+    pub ask_liquidity: Uint128,
+}
+
+impl PoolQuote {
+    /// Amount of the ask asset the swap is expected to return
+    pub fn return_amount(&self) -> Uint128 {
+        self.simulation.return_amount
+    }
+}
+
+/// Check whether a pool holds both denoms
+pub fn pool_has_pair(pool: &PoolInfoResponse, denom_a: &str, denom_b: &str) -> bool {
+    let denoms = &pool.pool_info.asset_denoms;
+    denom_a != denom_b && denoms.iter().any(|d| d == denom_a) && denoms.iter().any(|d| d == denom_b)
+}
+
+/// Check whether a pool has exactly these assets, pool type and fees
+///
+/// Asset order doesn't matter. Used to detect duplicates before creating a pool.
+pub fn pool_matches_definition(
+    pool: &PoolInfoResponse,
+    asset_denoms: &[String],
+    pool_type: &PoolType,
+    pool_fees: &PoolFee,
+) -> bool {
+    let mut existing = pool.pool_info.asset_denoms.clone();
+    let mut requested = asset_denoms.to_vec();
+    existing.sort();
+    requested.sort();
+
+    existing == requested
+        && &pool.pool_info.pool_type == pool_type
+        && &pool.pool_info.pool_fees == pool_fees
+}
+
+/// Reserve of `denom` held by the pool, zero if the pool doesn't hold it
+pub fn pool_liquidity(pool: &PoolInfoResponse, denom: &str) -> Uint128 {
+    pool.pool_info
+        .assets
+        .iter()
+        .find(|coin| coin.denom == denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default()
+}
+
+/// How the outputs of a [`SimulationSweep`] were obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SweepSource {
+    /// Computed locally from the pool's reserves and fees, see [`crate::math`]
+    PoolMath,
+    /// Simulated by the pool manager contract
+    Chain,
+}
+
+/// Expected outputs of one pool for a range of trade sizes
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationSweep {
+    /// The identifier of the pool
+    pub pool_id: String,
+    /// Denom of the offered asset
+    pub offer_denom: String,
+    /// Denom of the requested asset
+    pub ask_denom: String,
+    /// How the outputs were obtained
+    pub source: SweepSource,
+    /// `(offer_amount, return_amount)` in the order the amounts were given
+    ///
+    /// Chain simulations stop at the first amount the pool can't fill, so this may
+    /// be shorter than the requested amounts.
+    pub samples: Vec<(Uint128, Uint128)>,
+}
+
+impl SimulationSweep {
+    /// Return amounts only, in sample order
+    pub fn returns(&self) -> Vec<Uint128> {
+        self.samples.iter().map(|(_, returned)| *returned).collect()
+    }
+}
+
+/// Pick the quote with the highest return amount
+///
+/// Ties are broken by the deeper ask-side liquidity, then by the earliest quote.
+pub fn best_quote(quotes: &[PoolQuote]) -> Option<&PoolQuote> {
+    quotes
+        .iter()
+        .rev()
+        .max_by_key(|quote| (quote.return_amount(), quote.ask_liquidity))
+}
+
+/// Pick the best quote among `preferred` pools, or among all quotes if none is preferred
+pub fn best_preferred_quote<'a>(
+    quotes: &'a [PoolQuote],
+    preferred: &[String],
+) -> Option<&'a PoolQuote> {
+    let preferred_quotes: Vec<PoolQuote> = quotes
+        .iter()
+        .filter(|quote| preferred.contains(&quote.pool_id))
+        .cloned()
+        .collect();
+
+    match best_quote(&preferred_quotes) {
+        Some(best) => quotes.iter().find(|quote| quote.pool_id == best.pool_id),
+        None => best_quote(quotes),
+    }
+}
+
+/// Order quotes best first: by return amount, then by ask-side liquidity
+///
+/// `first` is moved to the front if it is quoted, so a pool picked by
+/// [`choose_route`] leads the ranking even when it isn't the best output.
+pub fn rank_quotes(quotes: &[PoolQuote], first: Option<&str>) -> Vec<PoolQuote> {
+    let mut ranked = quotes.to_vec();
+    ranked.sort_by(|a, b| {
+        (b.return_amount(), b.ask_liquidity).cmp(&(a.return_amount(), a.ask_liquidity))
+    });
+    if let Some(index) = first.and_then(|pool_id| ranked.iter().position(|q| q.pool_id == pool_id))
+    {
+        let quote = ranked.remove(index);
+        ranked.insert(0, quote);
+    }
+    ranked
+}
+
+/// The pool automatic routing picked for a swap
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteChoice {
+    /// Quote of the pool the swap goes through
+    pub quote: PoolQuote,
+    /// Pool pinned for the pair in the [`RoutingConfig`], if any
+    pub pinned_pool: Option<String>,
+    /// Best quote of the other pools when it beats the pinned pool's
+    pub better: Option<PoolQuote>,
+}
+
+impl RouteChoice {
+    /// Whether the swap goes through the pinned pool
+    pub fn is_pinned(&self) -> bool {
+        self.pinned_pool.as_deref() == Some(self.quote.pool_id.as_str())
+    }
+
+    /// Whether a route is pinned for the pair but isn't the best one anymore:
+    /// another pool returns more, or the pinned pool can't quote the swap
+    pub fn pin_is_stale(&self) -> bool {
+        self.pinned_pool.is_some() && (!self.is_pinned() || self.better.is_some())
+    }
+}
+
+/// Pick the pool for a swap honoring the routing exclusions and pins
+///
+/// Excluded pools are never picked. A pinned pool that can quote the swap is
+/// picked even when another pool returns more, which is then reported as
+/// [`better`](RouteChoice::better). Otherwise the best quote among `preferred`
+/// pools, or among all quotes, is picked.
+pub fn choose_route(
+    quotes: &[PoolQuote],
+    routing: &RoutingConfig,
+    preferred: &[String],
+    offer_denom: &str,
+    ask_denom: &str,
+) -> Option<RouteChoice> {
+    let candidates: Vec<PoolQuote> = quotes
+        .iter()
+        .filter(|quote| !routing.excludes(&quote.pool_id))
+        .cloned()
+        .collect();
+    let pinned_pool = routing
+        .pinned_pool(offer_denom, ask_denom)
+        .map(str::to_string);
+
+    let pinned_quote = pinned_pool
+        .as_ref()
+        .and_then(|pool_id| candidates.iter().find(|quote| &quote.pool_id == pool_id));
+    if let Some(pinned_quote) = pinned_quote {
+        let better = best_quote(&candidates)
+            .filter(|best| best.return_amount() > pinned_quote.return_amount())
+            .cloned();
+        return Some(RouteChoice {
+            quote: pinned_quote.clone(),
+            pinned_pool,
+            better,
+        });
+    }
+
+    best_preferred_quote(&candidates, preferred).map(|quote| RouteChoice {
+        quote: quote.clone(),
+        pinned_pool,
+        better: None,
+    })
+}
+
+/// Default number of chunks an order is divided into when planning a split
+pub const DEFAULT_SPLIT_STEPS: u32 = 10;
+
+/// Expected outputs of one pool at increasing offer sizes
+///
+/// `returns[k]` is the simulated return for offering `(k + 1)` chunks of the order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolCurve {
+    /// The identifier of the pool
+    pub pool_id: String,
+    /// Simulated return after each additional chunk
+    pub returns: Vec<Uint128>,
+}
+
+/// One leg of a split order
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitLeg {
+    /// The pool this leg swaps in
+    pub pool_id: String,
+    /// Amount of the offer asset sent to this pool
+    pub offer_amount: Uint128,
+    /// Simulated return of the ask asset for this leg
+    pub expected_return: Uint128,
+    /// Maximum slippage for this leg when executed
+    pub max_slippage: Option<Decimal>,
+}
+
+/// A large order split across several pools
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPlan {
+    /// Denom of the offered asset
+    pub offer_denom: String,
+    /// Denom of the requested asset
+    pub ask_denom: String,
+    /// Total amount of the offer asset across all legs
+    pub total_offer: Uint128,
+    /// Legs with a non-zero amount, largest first
+    pub legs: Vec<SplitLeg>,
+    /// Return of the best pool if the whole order went through it alone
+    pub single_pool_return: Uint128,
+}
+
+impl SplitPlan {
+    /// Total simulated return across all legs
+    pub fn expected_return(&self) -> Uint128 {
+        self.legs.iter().map(|leg| leg.expected_return).sum()
+    }
+
+    /// Additional return gained by splitting instead of using the best single pool
+    pub fn improvement(&self) -> Uint128 {
+        self.expected_return()
+            .saturating_sub(self.single_pool_return)
+    }
+
+    /// Apply the same slippage limit to every leg
+    pub fn with_max_slippage(mut self, max_slippage: Decimal) -> Self {
+        for leg in &mut self.legs {
+            leg.max_slippage = Some(max_slippage);
+        }
+        self
+    }
+}
+
+/// Offer amount of `chunks` chunks when `total` is divided into `steps` chunks
+pub fn chunk_amount(total: Uint128, chunks: u32, steps: u32) -> Uint128 {
+    total.multiply_ratio(chunks, steps)
+}
+
+/// Split an order of `total` across pools to maximise the combined return
+///
+/// The order is divided into `steps` equal chunks and each chunk is given to the
+/// pool with the best marginal return for it. Because swap outputs grow with
+/// diminishing returns, this greedy allocation minimises the total price impact.
+///
+/// A curve shorter than `steps` caps how many chunks its pool can take. If the
+/// pools together cannot absorb the whole order, the plan covers only part of it
+/// and `total_offer` is smaller than `total`.
+pub fn plan_split(
+    offer_denom: &str,
+    ask_denom: &str,
+    total: Uint128,
+    steps: u32,
+    curves: &[PoolCurve],
+) -> SplitPlan {
+    let steps = steps.max(1);
+
+    let return_at = |curve: &PoolCurve, chunks: u32| -> Option<Uint128> {
+        match chunks {
+            0 => Some(Uint128::zero()),
+            n => curve.returns.get(n as usize - 1).copied(),
+        }
+    };
+
+    let mut allocation = vec![0u32; curves.len()];
+    for _ in 0..steps {
+        let best = curves
+            .iter()
+            .enumerate()
+            .filter_map(|(index, curve)| {
+                let chunks = allocation[index];
+                let next = return_at(curve, chunks + 1)?;
+                let current = return_at(curve, chunks).unwrap_or_default();
+                Some((index, next.saturating_sub(current)))
+            })
+            .rev()
+            .max_by_key(|(_, marginal)| *marginal);
+        match best {
+            Some((index, _)) => allocation[index] += 1,
+            None => break,
+        }
+    }
+
+    let mut legs: Vec<SplitLeg> = curves
+        .iter()
+        .zip(&allocation)
+        .filter(|(_, chunks)| **chunks > 0)
+        .map(|(curve, chunks)| SplitLeg {
+            pool_id: curve.pool_id.clone(),
+            offer_amount: chunk_amount(total, *chunks, steps),
+            expected_return: return_at(curve, *chunks).unwrap_or_default(),
+            max_slippage: None,
+        })
+        .collect();
+    legs.sort_by_key(|leg| std::cmp::Reverse(leg.offer_amount));
+
+    // Rounding leftovers go to the largest leg so the legs add up to the order
+    if allocation.iter().sum::<u32>() == steps {
+        let allocated: Uint128 = legs.iter().map(|leg| leg.offer_amount).sum();
+        if let Some(largest) = legs.first_mut() {
+            largest.offer_amount += total.saturating_sub(allocated);
+        }
+    }
+
+    let single_pool_return = curves
+        .iter()
+        .filter_map(|curve| return_at(curve, steps))
+        .max()
+        .unwrap_or_default();
+
+    SplitPlan {
+        offer_denom: offer_denom.to_string(),
+        ask_denom: ask_denom.to_string(),
+        total_offer: legs.iter().map(|leg| leg.offer_amount).sum(),
+        legs,
+        single_pool_return,
+    }
+}
+
+/// Default number of samples in a depth curve
+pub const DEFAULT_DEPTH_STEPS: u32 = 20;
+
+/// One sample of a pool's depth curve
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DepthPoint {
+    /// Amount of the offer asset
+    pub offer_amount: Uint128,
+    /// Simulated return of the ask asset
+    pub return_amount: Uint128,
+    /// Effective price: ask units received per offer unit
+    pub price: Decimal,
+    /// Price drop relative to the smallest sampled size
+    pub price_impact: Decimal,
+}
+
+/// Output amount against input size for one pool and direction
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DepthCurve {
+    /// The identifier of the pool
+    pub pool_id: String,
+    /// Denom of the offered asset
+    pub offer_denom: String,
+    /// Denom of the requested asset
+    pub ask_denom: String,
+    /// Samples in increasing offer size
+    pub points: Vec<DepthPoint>,
+}
+
+impl DepthCurve {
+    /// Build a curve from `(offer_amount, return_amount)` samples in increasing offer size
+    ///
+    /// Price impact is measured against the first sample, so the smaller the first
+    /// offer, the closer that reference is to the pool's spot price.
+    pub fn from_samples(
+        pool_id: &str,
+        offer_denom: &str,
+        ask_denom: &str,
+        samples: impl IntoIterator<Item = (Uint128, Uint128)>,
+    ) -> Self {
+        let mut reference: Option<Decimal> = None;
+        let points = samples
+            .into_iter()
+            .map(|(offer_amount, return_amount)| {
+                let price =
+                    Decimal::checked_from_ratio(return_amount, offer_amount).unwrap_or_default();
+                let reference = *reference.get_or_insert(price);
+                let price_impact = if reference.is_zero() {
+                    Decimal::zero()
+                } else {
+                    Decimal::one().saturating_sub(price / reference)
+                };
+                DepthPoint {
+                    offer_amount,
+                    return_amount,
+                    price,
+                    price_impact,
+                }
+            })
+            .collect();
+
+        Self {
+            pool_id: pool_id.to_string(),
+            offer_denom: offer_denom.to_string(),
+            ask_denom: ask_denom.to_string(),
+            points,
+        }
+    }
+
+    /// Largest sampled offer whose price impact stays within `max_impact`
+    pub fn max_offer_within(&self, max_impact: Decimal) -> Option<Uint128> {
+        self.points
+            .iter()
+            .take_while(|point| point.price_impact <= max_impact)
+            .last()
+            .map(|point| point.offer_amount)
+    }
+
+    /// Render the curve as CSV with a header row
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("offer_amount,return_amount,price,price_impact\n");
+        for point in &self.points {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                point.offer_amount, point.return_amount, point.price, point.price_impact
+            ));
+        }
+        csv
+    }
+}
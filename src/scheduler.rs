@@ -0,0 +1,458 @@
+//! Transactions queued to execute at a future time, block height or epoch
+//!
+//! A [`Schedule`] keeps queued transactions in a JSON file so they survive
+//! restarts, and can be changed from the CLI or the TUI while a runner waits for
+//! them to come due. Every change re-reads the file first, so a transaction
+//! cancelled from one process is not executed by a runner in another.
+//!
+//! Right before sending, [`check`] makes sure a due transaction still makes sense
+//! at the current chain state: a swap is simulated again and refused if it would
+//! return less than the minimum recorded when it was queued, and a claim is
+//! refused while no rewards are pending.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::client::MantraDexClient;
+use crate::error::{Error, ErrorClass};
+use crate::schema::{self, Format, Schema};
+use crate::storage::{Document, SharedStorage};
+
+/// When a scheduled transaction comes due
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    /// At or after a point in time
+    At(DateTime<Utc>),
+    /// Once the chain reaches a block height
+    Height(u64),
+    /// Once an epoch has started, e.g. to claim right after a rollover
+    Epoch(u64),
+}
+
+impl Trigger {
+    /// Whether the trigger has fired at `state`
+    pub fn is_due(&self, state: &ChainState) -> bool {
+        match self {
+            Trigger::At(time) => state.time >= *time,
+            Trigger::Height(height) => state.height >= *height,
+            Trigger::Epoch(epoch) => state.epoch.is_some_and(|current| current >= *epoch),
+        }
+    }
+}
+
+impl fmt::Display for Trigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trigger::At(time) => write!(f, "{}", time.to_rfc3339()),
+            Trigger::Height(height) => write!(f, "height:{}", height),
+            Trigger::Epoch(epoch) => write!(f, "epoch:{}", epoch),
+        }
+    }
+}
+
+impl FromStr for Trigger {
+    type Err = Error;
+
+    /// Parse `height:<block>`, `epoch:<epoch>` or an RFC 3339 timestamp
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            Error::Config(format!(
+                "Invalid trigger '{}': expected height:<block>, epoch:<epoch> or an RFC 3339 time",
+                s
+            ))
+        };
+        let s = s.trim();
+        if let Some(height) = s.strip_prefix("height:") {
+            return height.parse().map(Trigger::Height).map_err(|_| invalid());
+        }
+        if let Some(epoch) = s.strip_prefix("epoch:") {
+            return epoch.parse().map(Trigger::Epoch).map_err(|_| invalid());
+        }
+        DateTime::parse_from_rfc3339(s)
+            .map(|time| Trigger::At(time.with_timezone(&Utc)))
+            .map_err(|_| invalid())
+    }
+}
+
+/// Time, height and epoch that triggers are checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainState {
+    pub time: DateTime<Utc>,
+    pub height: u64,
+    /// Current epoch, `None` if the network has no epoch manager
+    pub epoch: Option<u64>,
+}
+
+impl ChainState {
+    /// Read the current height and epoch from the chain
+    pub async fn fetch(client: &MantraDexClient) -> Result<Self, Error> {
+        let height = client.get_last_block_height().await?;
+        let epoch = match client.config().contracts.epoch_manager {
+            Some(_) => Some(client.get_current_epoch().await?),
+            None => None,
+        };
+        Ok(Self {
+            time: Utc::now(),
+            height,
+            epoch,
+        })
+    }
+}
+
+/// Transaction to send when a schedule entry comes due
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduledAction {
+    /// Swap in a pool
+    Swap {
+        pool_id: String,
+        offer_asset: Coin,
+        ask_asset_denom: String,
+        /// Maximum slippage tolerance, the wallet default if not set
+        max_slippage: Option<Decimal>,
+        /// Least amount of the ask denom the swap may return when it executes
+        min_return: Option<Uint128>,
+    },
+    /// Claim farm rewards
+    ClaimRewards {
+        /// Last epoch to claim for, every epoch if not set
+        until_epoch: Option<u64>,
+    },
+}
+
+impl fmt::Display for ScheduledAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduledAction::Swap {
+                pool_id,
+                offer_asset,
+                ask_asset_denom,
+                ..
+            } => write!(
+                f,
+                "Swap {} for {} in pool {}",
+                offer_asset, ask_asset_denom, pool_id
+            ),
+            ScheduledAction::ClaimRewards { until_epoch: None } => write!(f, "Claim rewards"),
+            ScheduledAction::ClaimRewards {
+                until_epoch: Some(epoch),
+            } => write!(f, "Claim rewards until epoch {}", epoch),
+        }
+    }
+}
+
+/// Where a scheduled transaction stands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ScheduleStatus {
+    /// Waiting for its trigger
+    Pending,
+    /// Sent and included in a block
+    Executed { tx_hash: String, height: u64 },
+    /// Refused by the pre-execution check or rejected when sent
+    Failed { reason: String },
+    /// Cancelled before it came due
+    Cancelled,
+}
+
+impl fmt::Display for ScheduleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleStatus::Pending => write!(f, "pending"),
+            ScheduleStatus::Executed { tx_hash, .. } => write!(f, "executed {}", tx_hash),
+            ScheduleStatus::Failed { reason } => write!(f, "failed: {}", reason),
+            ScheduleStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// A transaction queued in a [`Schedule`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledTx {
+    /// Identifier, unique within its schedule
+    pub id: u64,
+    /// Saved wallet that signs the transaction
+    pub wallet: String,
+    pub action: ScheduledAction,
+    pub trigger: Trigger,
+    pub created_at: DateTime<Utc>,
+    pub status: ScheduleStatus,
+}
+
+impl ScheduledTx {
+    pub fn is_pending(&self) -> bool {
+        self.status == ScheduleStatus::Pending
+    }
+}
+
+/// Format of the schedule file
+const SCHEDULE_SCHEMA: Schema = Schema::new("schedule", Format::Json, &[wrap_txs]);
+
+/// Contents of the schedule file
+#[derive(Serialize, Deserialize)]
+struct ScheduleFile<T> {
+    txs: T,
+}
+
+/// Version 0 was the bare list of transactions
+fn wrap_txs(value: &mut serde_json::Value) -> Result<(), Error> {
+    schema::wrap_in(value, "txs");
+    Ok(())
+}
+
+/// Queued transactions, persisted when opened from a file or a storage
+#[derive(Debug, Default)]
+pub struct Schedule {
+    txs: Vec<ScheduledTx>,
+    document: Option<Document>,
+}
+
+impl Schedule {
+    /// Create an empty, in-memory schedule
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the schedule stored at `path`, saving back to it on every change
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the schedule kept in `storage`, saving back to it on every change
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut schedule = Self {
+            txs: Vec::new(),
+            document: Some(document),
+        };
+        schedule.reload()?;
+        Ok(schedule)
+    }
+
+    /// Key of the schedule in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "scheduled_txs.json";
+
+    /// Get the default schedule file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("scheduled_txs.json");
+        path
+    }
+
+    /// Re-read the schedule, picking up changes made by other processes
+    pub fn reload(&mut self) -> Result<(), Error> {
+        let Some(document) = &self.document else {
+            return Ok(());
+        };
+        if let Some(stored) = document.read::<ScheduleFile<Vec<ScheduledTx>>>(&SCHEDULE_SCHEMA)? {
+            self.txs = stored.txs;
+        }
+        Ok(())
+    }
+
+    /// Every scheduled transaction, oldest first
+    pub fn txs(&self) -> &[ScheduledTx] {
+        &self.txs
+    }
+
+    pub fn get(&self, id: u64) -> Option<&ScheduledTx> {
+        self.txs.iter().find(|tx| tx.id == id)
+    }
+
+    /// Queue `action` to be signed by `wallet` once `trigger` fires
+    ///
+    /// # Returns
+    ///
+    /// Identifier of the new entry
+    pub fn add(
+        &mut self,
+        wallet: &str,
+        action: ScheduledAction,
+        trigger: Trigger,
+    ) -> Result<u64, Error> {
+        self.reload()?;
+        let id = self.txs.iter().map(|tx| tx.id).max().unwrap_or(0) + 1;
+        self.txs.push(ScheduledTx {
+            id,
+            wallet: wallet.to_string(),
+            action,
+            trigger,
+            created_at: Utc::now(),
+            status: ScheduleStatus::Pending,
+        });
+        self.save()?;
+        Ok(id)
+    }
+
+    /// Cancel a pending transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such transaction or it is no longer pending
+    pub fn cancel(&mut self, id: u64) -> Result<(), Error> {
+        self.reload()?;
+        let tx = self
+            .txs
+            .iter_mut()
+            .find(|tx| tx.id == id)
+            .ok_or_else(|| Error::Other(format!("No scheduled transaction {}", id)))?;
+        if !tx.is_pending() {
+            return Err(Error::Other(format!(
+                "Scheduled transaction {} is already {}",
+                id, tx.status
+            )));
+        }
+        tx.status = ScheduleStatus::Cancelled;
+        self.save()
+    }
+
+    /// Record the outcome of a transaction
+    pub fn set_status(&mut self, id: u64, status: ScheduleStatus) -> Result<(), Error> {
+        self.reload()?;
+        if let Some(tx) = self.txs.iter_mut().find(|tx| tx.id == id) {
+            tx.status = status;
+        }
+        self.save()
+    }
+
+    /// Pending transactions of `wallet` whose trigger has fired at `state`
+    pub fn due(&self, wallet: &str, state: &ChainState) -> Vec<ScheduledTx> {
+        self.txs
+            .iter()
+            .filter(|tx| tx.is_pending() && tx.wallet == wallet && tx.trigger.is_due(state))
+            .cloned()
+            .collect()
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        match &self.document {
+            Some(document) => document.write(&SCHEDULE_SCHEMA, &ScheduleFile { txs: &self.txs }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Check that `action` still makes sense to send now
+///
+/// # Errors
+///
+/// * Returns [`Error::Policy`] if a swap would return less than its minimum, or a
+///   claim has no rewards to collect
+/// * Returns error if the simulation or rewards query fails
+pub async fn check(client: &MantraDexClient, action: &ScheduledAction) -> Result<(), Error> {
+    match action {
+        ScheduledAction::Swap {
+            pool_id,
+            offer_asset,
+            ask_asset_denom,
+            min_return,
+            ..
+        } => {
+            let simulation = client
+                .simulate_swap(pool_id, offer_asset.clone(), ask_asset_denom)
+                .await?;
+            match min_return {
+                Some(min_return) if simulation.return_amount < *min_return => {
+                    Err(Error::Policy(format!(
+                        "Swap would return {}{}, less than the minimum of {}{}",
+                        simulation.return_amount, ask_asset_denom, min_return, ask_asset_denom
+                    )))
+                }
+                _ => Ok(()),
+            }
+        }
+        ScheduledAction::ClaimRewards { .. } => {
+            let address = client.wallet()?.address()?.to_string();
+            if client.get_pending_rewards(&address).await?.is_empty() {
+                return Err(Error::Policy("No rewards pending to claim".to_string()));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Send `action` with the client's wallet
+pub async fn send(client: &MantraDexClient, action: &ScheduledAction) -> Result<TxResponse, Error> {
+    match action {
+        ScheduledAction::Swap {
+            pool_id,
+            offer_asset,
+            ask_asset_denom,
+            max_slippage,
+            ..
+        } => {
+            client
+                .swap(pool_id, offer_asset.clone(), ask_asset_denom, *max_slippage)
+                .await
+        }
+        ScheduledAction::ClaimRewards { until_epoch } => client.claim_rewards(*until_epoch).await,
+    }
+}
+
+/// Execute the transactions of `wallet` that are due, signing with the client's wallet
+///
+/// Each is checked with [`check`] first. A transaction refused by the check or
+/// rejected by the chain is marked failed; one whose check couldn't reach the
+/// node stays pending and ends the pass, so the next pass tries again.
+///
+/// # Returns
+///
+/// The transactions executed or failed in this pass, with their new status
+pub async fn run_due(
+    client: &MantraDexClient,
+    schedule: &mut Schedule,
+    wallet: &str,
+) -> Result<Vec<ScheduledTx>, Error> {
+    schedule.reload()?;
+    let state = ChainState::fetch(client).await?;
+
+    let mut done = Vec::new();
+    for tx in schedule.due(wallet, &state) {
+        // It may have been cancelled since the pass started
+        schedule.reload()?;
+        if !schedule.get(tx.id).is_some_and(ScheduledTx::is_pending) {
+            continue;
+        }
+
+        let status = match check(client, &tx.action).await {
+            Err(e) if e.class() == ErrorClass::Network => return Err(e),
+            Err(e) => ScheduleStatus::Failed {
+                reason: e.to_string(),
+            },
+            Ok(()) => match send(client, &tx.action).await {
+                Ok(response) => ScheduleStatus::Executed {
+                    tx_hash: response.txhash,
+                    height: response.height as u64,
+                },
+                Err(e) => ScheduleStatus::Failed {
+                    reason: e.to_string(),
+                },
+            },
+        };
+        schedule.set_status(tx.id, status.clone())?;
+        done.push(ScheduledTx { status, ..tx });
+    }
+    Ok(done)
+}
@@ -0,0 +1,226 @@
+//! Versioned file formats
+//!
+//! Every file the SDK persists records the version of its format in a top-level
+//! `version` field. Files written before the field existed are version 0. A
+//! [`Schema`] lists the migrations from each version to the next; [`read`]
+//! applies the ones a file needs before parsing it, so files written by older
+//! releases keep loading after an upgrade.
+//!
+//! A migrated file is rewritten in the current format, after the original is
+//! copied next to it as `<file>.v<version>.bak`. Files written by a newer
+//! release are refused rather than parsed and saved back without the fields
+//! this release doesn't know.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::error::Error;
+
+/// Top-level field holding the version of a file's format
+pub const VERSION_KEY: &str = "version";
+
+/// Turns a file of one version into the next, in its JSON form
+pub type Migration = fn(&mut Value) -> Result<(), Error>;
+
+/// Encoding of a file on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Toml,
+}
+
+/// Format of one kind of persisted file and how to bring old versions up to date
+#[derive(Debug, Clone, Copy)]
+pub struct Schema {
+    /// What the file holds, for error messages
+    pub name: &'static str,
+    pub format: Format,
+    /// `migrations[n]` turns version `n` into version `n + 1`
+    pub migrations: &'static [Migration],
+}
+
+impl Schema {
+    pub const fn new(name: &'static str, format: Format, migrations: &'static [Migration]) -> Self {
+        Self {
+            name,
+            format,
+            migrations,
+        }
+    }
+
+    /// Version files are written in
+    pub fn current_version(&self) -> u32 {
+        self.migrations.len() as u32
+    }
+
+    /// Bring `value` up to the current version, returning the version it had
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `value` is newer than the current version or
+    /// a migration fails
+    pub fn migrate(&self, value: &mut Value) -> Result<u32, Error> {
+        let from = version_of(value);
+        let current = self.current_version();
+        if from > current {
+            return Err(Error::Config(format!(
+                "The {} file has version {}, newer than the {} this release supports",
+                self.name, from, current
+            )));
+        }
+
+        for (version, migration) in self.migrations.iter().enumerate().skip(from as usize) {
+            migration(value).map_err(|e| {
+                Error::Config(format!(
+                    "Failed to migrate the {} file from version {}: {}",
+                    self.name, version, e
+                ))
+            })?;
+        }
+        if let Value::Object(fields) = value {
+            fields.insert(VERSION_KEY.to_string(), current.into());
+        }
+        Ok(from)
+    }
+
+    /// Parse the contents of a file of this schema, without migrating them
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `content` isn't valid in the schema's format
+    pub fn parse(&self, content: &str) -> Result<Value, Error> {
+        let parsed = match self.format {
+            Format::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            Format::Toml => toml::from_str::<toml::Value>(content)
+                .map_err(|e| e.to_string())
+                .and_then(|value| serde_json::to_value(value).map_err(|e| e.to_string())),
+        };
+        parsed.map_err(|e| Error::Config(format!("Failed to parse the {} file: {}", self.name, e)))
+    }
+
+    /// Contents of a file of this schema holding `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `value` can't be represented in the schema's format
+    pub fn render(&self, value: &Value) -> Result<String, Error> {
+        let rendered = match self.format {
+            Format::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+            Format::Toml => toml::to_string_pretty(value).map_err(|e| e.to_string()),
+        };
+        rendered.map_err(|e| {
+            Error::Config(format!("Failed to serialize the {} file: {}", self.name, e))
+        })
+    }
+}
+
+/// Migration to the first versioned format, which only adds the version field
+pub fn add_version(_value: &mut Value) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Migration wrapping a format that wasn't an object under `key`, so it can hold the version
+pub fn wrap_in(value: &mut Value, key: &str) {
+    let inner = value.take();
+    *value = Value::Object(Map::from_iter([(key.to_string(), inner)]));
+}
+
+/// Version of the file `value`, 0 if it has none
+pub fn version_of(value: &Value) -> u32 {
+    value
+        .get(VERSION_KEY)
+        .and_then(Value::as_u64)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+/// Where the original of a file migrated from `version` is kept
+pub fn backup_path(path: &Path, version: u32) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".v{}.bak", version));
+    path.with_file_name(name)
+}
+
+/// Read the file at `path` in the format of `schema`, migrating it if it is older
+///
+/// A migrated file is backed up and rewritten in the current format.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read, is newer than the schema, or
+/// doesn't parse once migrated
+pub fn read<T: DeserializeOwned>(schema: &Schema, path: &Path) -> Result<T, Error> {
+    let content = fs::read_to_string(path)?;
+    let mut value = schema.parse(&content)?;
+    let from = schema.migrate(&mut value)?;
+    if from < schema.current_version() {
+        let backup = backup_path(path, from);
+        if !backup.exists() {
+            fs::write(&backup, &content)?;
+        }
+        fs::write(path, schema.render(&value)?)?;
+    }
+    from_value(schema, value)
+}
+
+/// Write `data` to `path` in the current format of `schema`, creating its directory if needed
+///
+/// # Errors
+///
+/// Returns an error if `data` doesn't serialize to an object or the file can't be written
+pub fn write<T: Serialize>(schema: &Schema, path: &Path, data: &T) -> Result<(), Error> {
+    let value = to_value(schema, data)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, schema.render(&value)?)?;
+    Ok(())
+}
+
+/// `data` as the contents of a file of the current version of `schema`
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if `data` doesn't serialize to an object
+pub fn to_value<T: Serialize>(schema: &Schema, data: &T) -> Result<Value, Error> {
+    let mut value = match schema.format {
+        Format::Json => serde_json::to_value(data)?,
+        // Going through TOML leaves out the `None`s it can't represent
+        Format::Toml => toml::Value::try_from(data)
+            .map_err(|e| e.to_string())
+            .and_then(|value| serde_json::to_value(value).map_err(|e| e.to_string()))
+            .map_err(|e| {
+                Error::Config(format!(
+                    "Failed to serialize the {} file: {}",
+                    schema.name, e
+                ))
+            })?,
+    };
+    let Value::Object(fields) = &mut value else {
+        return Err(Error::Config(format!(
+            "The {} file must hold an object to carry a version",
+            schema.name
+        )));
+    };
+    fields.insert(VERSION_KEY.to_string(), schema.current_version().into());
+    Ok(value)
+}
+
+/// Parse the contents of a file of `schema`, migrating them if they are older
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if `value` is newer than the schema or doesn't
+/// parse once migrated
+pub fn from_value<T: DeserializeOwned>(schema: &Schema, mut value: Value) -> Result<T, Error> {
+    schema.migrate(&mut value)?;
+    if let Value::Object(fields) = &mut value {
+        fields.remove(VERSION_KEY);
+    }
+    serde_json::from_value(value)
+        .map_err(|e| Error::Config(format!("Failed to parse the {} file: {}", schema.name, e)))
+}
@@ -0,0 +1,162 @@
+//! Secrets held in memory
+//!
+//! Mnemonics and passwords pass through wallet storage, the setup wizard, the
+//! TUI's input forms and the configuration. [`SecretString`] holds them on the
+//! way: its bytes are overwritten with zeros when it is dropped, and it prints
+//! as `[REDACTED]` in `Debug` and `Display`, so a logged state or error never
+//! shows one. The value is only reachable through
+//! [`expose_secret`](SecretString::expose_secret), which marks each place a
+//! secret is actually used.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// What a secret prints as
+const REDACTED: &str = "[REDACTED]";
+
+/// A string scrubbed from memory when dropped and redacted when printed
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: String) -> Self {
+        Self(secret)
+    }
+
+    /// The secret itself
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Length in bytes
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Number of characters, e.g. to draw a mask of the same width
+    pub fn char_count(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    pub fn push(&mut self, c: char) {
+        let mut buf = [0u8; 4];
+        self.push_str(c.encode_utf8(&mut buf));
+    }
+
+    /// Append `s`, scrubbing the old buffer if it has to grow
+    pub fn push_str(&mut self, s: &str) {
+        let needed = self.0.len() + s.len();
+        if needed > self.0.capacity() {
+            // Growing in place would leave a copy of the secret in the freed buffer
+            let mut grown = String::with_capacity(needed.max(self.0.capacity() * 2).max(64));
+            grown.push_str(&self.0);
+            self.0.zeroize();
+            self.0 = grown;
+        }
+        self.0.push_str(s);
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.0.chars().next_back()?;
+        // `String::pop` only moves the length; scrub the bytes it would leave behind
+        let len = self.0.len() - c.len_utf8();
+        let mut bytes = std::mem::take(&mut self.0).into_bytes();
+        bytes[len..].zeroize();
+        bytes.truncate(len);
+        self.0 = String::from_utf8(bytes).expect("truncated at a char boundary");
+        Some(c)
+    }
+
+    /// Insert `c` before the character at `index`, or at the end past the last one
+    pub fn insert(&mut self, index: usize, c: char) {
+        let at = self.byte_offset(index);
+        let mut edited = String::with_capacity(self.0.len() + c.len_utf8());
+        edited.push_str(&self.0[..at]);
+        edited.push(c);
+        edited.push_str(&self.0[at..]);
+        // The old buffer is scrubbed as it drops
+        *self = Self(edited);
+    }
+
+    /// Remove the character at `index`
+    pub fn remove(&mut self, index: usize) -> Option<char> {
+        let at = self.byte_offset(index);
+        let c = self.0[at..].chars().next()?;
+        let mut edited = String::with_capacity(self.0.len());
+        edited.push_str(&self.0[..at]);
+        edited.push_str(&self.0[at + c.len_utf8()..]);
+        *self = Self(edited);
+        Some(c)
+    }
+
+    /// Byte offset of the character at `index`
+    fn byte_offset(&self, index: usize) -> usize {
+        self.0
+            .char_indices()
+            .nth(index)
+            .map_or(self.0.len(), |(offset, _)| offset)
+    }
+
+    /// Empty the secret, scrubbing its bytes
+    pub fn clear(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Zeroize for SecretString {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretString {}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretString({})", REDACTED)
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Serializes as the plain string: files that store a secret, such as the
+/// configuration's mnemonic, keep their format
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
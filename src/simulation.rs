@@ -0,0 +1,284 @@
+//! Simulate-only execution
+//!
+//! A client built with
+//! [`with_simulate_only`](crate::MantraDexClient::with_simulate_only) runs every
+//! transaction through the node's simulation endpoint and stops there: nothing
+//! is signed or broadcast. The transaction response it returns carries the
+//! simulated gas, data and events, has no hash, and is marked so that
+//! [`is_simulated`] tells it apart from an executed one.
+//!
+//! [`balance_changes`] reads from those events what the transaction would have
+//! moved in and out of an address, and [`resulting_balances`] applies the
+//! changes and the fee to the address's current balances.
+//!
+//! [`DryRunReport`] gathers everything a simulated transaction would do for
+//! review: its decoded messages, gas and fee, the balance changes and the events
+//! its messages would emit.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::tx::v1beta1::Tx;
+use cosmrs::proto::tendermint::abci::Event;
+use cosmwasm_std::{Coin, Uint128};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::Error;
+use crate::tx::amino_json;
+
+/// `info` of a transaction response that was simulated rather than executed
+pub const SIMULATED_TX_INFO: &str = "simulated";
+
+/// Type URL of the transaction a simulated response carries in its `tx`
+pub const TX_TYPE_URL: &str = "/cosmos.tx.v1beta1.Tx";
+
+/// Whether `tx` comes from a simulate-only client and was never broadcast
+pub fn is_simulated(tx: &TxResponse) -> bool {
+    tx.info == SIMULATED_TX_INFO
+}
+
+/// Amount of one denom a transaction moves out of and into an address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    pub denom: String,
+    pub spent: Uint128,
+    pub received: Uint128,
+}
+
+/// Tokens the messages of a transaction spend from and send to `address`, per denom
+///
+/// Only events emitted by the messages count: they carry a `msg_index` attribute,
+/// while the fee is deducted by the ante handler, whose events don't.
+pub fn balance_changes(events: &[Event], address: &str) -> Vec<BalanceChange> {
+    let mut changes: BTreeMap<String, BalanceChange> = BTreeMap::new();
+
+    for event in events {
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.as_str())
+        };
+        if attribute("msg_index").is_none() {
+            continue;
+        }
+        let (account, spent) = match event.r#type.as_str() {
+            "coin_spent" => (attribute("spender"), true),
+            "coin_received" => (attribute("receiver"), false),
+            _ => continue,
+        };
+        if account != Some(address) {
+            continue;
+        }
+
+        let coins = attribute("amount").unwrap_or_default();
+        for coin in coins
+            .split(',')
+            .filter_map(|coin| Coin::from_str(coin).ok())
+        {
+            let change = changes
+                .entry(coin.denom.clone())
+                .or_insert_with(|| BalanceChange {
+                    denom: coin.denom,
+                    spent: Uint128::zero(),
+                    received: Uint128::zero(),
+                });
+            if spent {
+                change.spent += coin.amount;
+            } else {
+                change.received += coin.amount;
+            }
+        }
+    }
+
+    changes.into_values().collect()
+}
+
+/// Balances left after applying `changes` and paying `fee`, without empty ones
+pub fn resulting_balances(
+    balances: &[Coin],
+    changes: &[BalanceChange],
+    fee: Option<&Coin>,
+) -> Vec<Coin> {
+    let mut resulting: BTreeMap<String, Uint128> = balances
+        .iter()
+        .map(|coin| (coin.denom.clone(), coin.amount))
+        .collect();
+
+    for change in changes {
+        let balance = resulting.entry(change.denom.clone()).or_default();
+        *balance = (*balance + change.received).saturating_sub(change.spent);
+    }
+    if let Some(fee) = fee {
+        let balance = resulting.entry(fee.denom.clone()).or_default();
+        *balance = balance.saturating_sub(fee.amount);
+    }
+
+    resulting
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin { denom, amount })
+        .collect()
+}
+
+/// An event emitted by one of the messages of a transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageEvent {
+    /// Index of the message that emitted it
+    pub msg_index: u32,
+    pub kind: String,
+    /// Attributes in emitted order, without `msg_index`
+    pub attributes: Vec<(String, String)>,
+}
+
+impl fmt::Display for MessageEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[msg {}] {}", self.msg_index, self.kind)?;
+        for (key, value) in &self.attributes {
+            write!(f, "\n    {} = {}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Events emitted by the messages of a transaction, in order
+///
+/// The ante handler's events, such as the fee deduction, are left out, as in
+/// [`balance_changes`].
+pub fn message_events(events: &[Event]) -> Vec<MessageEvent> {
+    events
+        .iter()
+        .filter_map(|event| {
+            let msg_index = event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "msg_index")?
+                .value
+                .parse()
+                .ok()?;
+            Some(MessageEvent {
+                msg_index,
+                kind: event.r#type.clone(),
+                attributes: event
+                    .attributes
+                    .iter()
+                    .filter(|attr| attr.key != "msg_index")
+                    .map(|attr| (attr.key.clone(), attr.value.clone()))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// What a simulated transaction would do, had it been broadcast
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DryRunReport {
+    /// Messages in amino JSON, or just their `type` when it has no amino form
+    pub messages: Vec<Value>,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub fee: Vec<Coin>,
+    /// Tokens the messages would move out of and into the sender's account
+    pub balance_changes: Vec<BalanceChange>,
+    pub events: Vec<MessageEvent>,
+}
+
+impl DryRunReport {
+    /// Report on a response from a simulate-only client, sent from `sender`
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`Error::Tx`] if `tx` wasn't simulated
+    /// * Returns [`Error::Tx`] if the transaction it carries can't be decoded
+    pub fn from_response(tx: &TxResponse, sender: &str) -> Result<Self, Error> {
+        if !is_simulated(tx) {
+            return Err(Error::Tx(
+                "Transaction was broadcast, not simulated".to_string(),
+            ));
+        }
+        let decoded = match &tx.tx {
+            Some(any) if any.type_url == TX_TYPE_URL => Tx::decode(any.value.as_slice())
+                .map_err(|e| Error::Tx(format!("Invalid simulated transaction: {}", e)))?,
+            _ => Tx::default(),
+        };
+
+        let messages = decoded
+            .body
+            .map(|body| body.messages)
+            .unwrap_or_default()
+            .iter()
+            .map(|msg| amino_json(msg).unwrap_or_else(|_| json!({ "type": msg.type_url })))
+            .collect();
+        let fee = decoded
+            .auth_info
+            .and_then(|auth_info| auth_info.fee)
+            .map(|fee| fee.amount)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|coin| {
+                Ok(Coin {
+                    amount: coin
+                        .amount
+                        .parse()
+                        .map_err(|e| Error::Tx(format!("Invalid fee amount: {}", e)))?,
+                    denom: coin.denom,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            messages,
+            gas_used: tx.gas_used.max(0) as u64,
+            gas_limit: tx.gas_wanted.max(0) as u64,
+            fee,
+            balance_changes: balance_changes(&tx.events, sender),
+            events: message_events(&tx.events),
+        })
+    }
+}
+
+impl fmt::Display for DryRunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Dry run: nothing was signed or broadcast")?;
+        writeln!(f, "Messages:")?;
+        for (index, msg) in self.messages.iter().enumerate() {
+            let pretty = serde_json::to_string_pretty(msg).unwrap_or_else(|_| msg.to_string());
+            writeln!(f, "  [{}] {}", index, pretty.replace('\n', "\n      "))?;
+        }
+        let fee: Vec<String> = self.fee.iter().map(Coin::to_string).collect();
+        writeln!(
+            f,
+            "Gas: {} estimated, limit {}, fee {}",
+            self.gas_used,
+            self.gas_limit,
+            if fee.is_empty() {
+                "none".to_string()
+            } else {
+                fee.join(", ")
+            }
+        )?;
+        if !self.balance_changes.is_empty() {
+            writeln!(f, "Balance changes:")?;
+            for change in &self.balance_changes {
+                if !change.spent.is_zero() {
+                    writeln!(f, "  -{} {}", change.spent, change.denom)?;
+                }
+                if !change.received.is_zero() {
+                    writeln!(f, "  +{} {}", change.received, change.denom)?;
+                }
+            }
+        }
+        if !self.events.is_empty() {
+            writeln!(f, "Events:")?;
+            for event in &self.events {
+                writeln!(f, "  {}", event)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 use cosmwasm_std::{Coin, Decimal, Uint128};
 use serde::{Deserialize, Serialize};
 
+use crate::error::Error;
+use crate::wallet::address;
+
 /// Skip Adapter types and message structures
 /// Based on Skip Go CosmWasm contracts for cross-chain operations
 
@@ -171,6 +174,29 @@ pub struct SkipIbcInfo {
     pub recover_address: Option<String>,
 }
 
+impl SkipIbcInfo {
+    /// Transfer to the sender's own account on the counterpart chain
+    ///
+    /// The receiver is `sender` with the counterpart chain's bech32 prefix, and
+    /// a failed transfer is refunded to `sender` on MANTRA.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if `sender` or `counterpart_prefix` is invalid
+    pub fn to_own_account(
+        source_channel: &str,
+        sender: &str,
+        counterpart_prefix: &str,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            source_channel: source_channel.to_string(),
+            receiver: address::convert_prefix(sender, counterpart_prefix)?,
+            memo: None,
+            recover_address: Some(address::to_mantra(sender)?),
+        })
+    }
+}
+
 /// Skip fee swap info
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkipFeeSwap {
@@ -0,0 +1,212 @@
+//! Reports of end-to-end smoke test runs
+//!
+//! The `mantra-dex-smoketest` binary (built with `--features smoketest`) runs a
+//! scripted sequence against a network after a release: create a wallet, check
+//! the test wallet's funds, optionally create a pool, swap, provide and withdraw
+//! liquidity and claim rewards. Each step is recorded as a [`StepResult`] in a
+//! [`SmokeReport`], which renders as JSON or as a JUnit XML test suite so CI can
+//! show the run like any other test results.
+
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Name of the JUnit test suite and prefix of its test case class names
+pub const SUITE_NAME: &str = "mantra-dex-smoketest";
+
+/// Outcome of one step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Passed,
+    Failed,
+    /// Not run, because it wasn't requested or a step it needs failed
+    Skipped,
+}
+
+impl fmt::Display for StepStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StepStatus::Passed => "passed",
+            StepStatus::Failed => "failed",
+            StepStatus::Skipped => "skipped",
+        })
+    }
+}
+
+/// One step of a smoke test run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepResult {
+    pub name: String,
+    pub status: StepStatus,
+    /// Time the step took, in milliseconds
+    pub duration_ms: u64,
+    /// What the step found, why it failed or why it was skipped
+    pub detail: String,
+    /// Hash of the transaction the step sent, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+}
+
+impl StepResult {
+    pub fn passed(name: impl Into<String>, duration: Duration, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: StepStatus::Passed,
+            duration_ms: duration.as_millis() as u64,
+            detail: detail.into(),
+            tx_hash: None,
+        }
+    }
+
+    pub fn failed(name: impl Into<String>, duration: Duration, error: impl fmt::Display) -> Self {
+        Self {
+            status: StepStatus::Failed,
+            detail: error.to_string(),
+            ..Self::passed(name, duration, "")
+        }
+    }
+
+    pub fn skipped(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            status: StepStatus::Skipped,
+            ..Self::passed(name, Duration::ZERO, reason)
+        }
+    }
+
+    /// Record the hash of the transaction the step sent
+    pub fn with_tx_hash(mut self, tx_hash: impl Into<String>) -> Self {
+        self.tx_hash = Some(tx_hash.into());
+        self
+    }
+}
+
+/// Every step of a smoke test run against one network
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmokeReport {
+    /// Network the run targeted
+    pub network: String,
+    pub started_at: DateTime<Utc>,
+    pub steps: Vec<StepResult>,
+}
+
+impl SmokeReport {
+    /// Start a report for a run against `network`
+    pub fn new(network: impl Into<String>) -> Self {
+        Self {
+            network: network.into(),
+            started_at: Utc::now(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, step: StepResult) {
+        self.steps.push(step);
+    }
+
+    /// Number of steps with `status`
+    pub fn count(&self, status: StepStatus) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| step.status == status)
+            .count()
+    }
+
+    /// Whether no step failed
+    pub fn passed(&self) -> bool {
+        self.count(StepStatus::Failed) == 0
+    }
+
+    /// Time all steps took together
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.steps.iter().map(|step| step.duration_ms).sum())
+    }
+
+    /// Render the report as pretty-printed JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the report can't be serialized
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Render the report as a JUnit XML test suite with a test case per step
+    pub fn to_junit(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\" \
+             timestamp=\"{}\">",
+            SUITE_NAME,
+            self.steps.len(),
+            self.count(StepStatus::Failed),
+            self.count(StepStatus::Skipped),
+            self.duration().as_secs_f64(),
+            self.started_at.format("%Y-%m-%dT%H:%M:%S"),
+        );
+        let _ = writeln!(
+            xml,
+            "  <properties>\n    <property name=\"network\" value=\"{}\"/>\n  </properties>",
+            escape_xml(&self.network)
+        );
+        for step in &self.steps {
+            let _ = write!(
+                xml,
+                "  <testcase name=\"{}\" classname=\"{}.{}\" time=\"{:.3}\"",
+                escape_xml(&step.name),
+                SUITE_NAME,
+                escape_xml(&self.network),
+                step.duration_ms as f64 / 1000.0,
+            );
+            let detail = escape_xml(&step.detail);
+            match step.status {
+                StepStatus::Passed => xml.push_str(">\n"),
+                StepStatus::Failed => {
+                    let _ = writeln!(
+                        xml,
+                        ">\n    <failure message=\"{}\">{}</failure>",
+                        detail, detail
+                    );
+                }
+                StepStatus::Skipped => {
+                    let _ = writeln!(xml, ">\n    <skipped message=\"{}\"/>", detail);
+                }
+            }
+            let output = match (&step.status, &step.tx_hash) {
+                (StepStatus::Passed, Some(tx_hash)) => {
+                    format!("{}\ntx: {}", step.detail, tx_hash)
+                }
+                (StepStatus::Passed, None) => step.detail.clone(),
+                (_, Some(tx_hash)) => format!("tx: {}", tx_hash),
+                (_, None) => String::new(),
+            };
+            if !output.is_empty() {
+                let _ = writeln!(xml, "    <system-out>{}</system-out>", escape_xml(&output));
+            }
+            xml.push_str("  </testcase>\n");
+        }
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+}
+
+/// Escape text for use in XML content and attribute values
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,589 @@
+//! Pluggable storage for state kept between runs
+//!
+//! The transaction [history](crate::history), [schedules](crate::scheduler),
+//! limit [orders](crate::orders), [DCA plans](crate::dca), the
+//! [gas history](crate::gas), the [token registry](crate::token_registry), the
+//! [price history](crate::price_history) and the
+//! [preferences](crate::preferences) keep their state as documents in a
+//! [`Storage`], each under its own key. The `[storage]` section of the saved
+//! configuration picks the backend:
+//!
+//! - `files`, the default: a file per document in a directory, as releases
+//!   before storage backends did
+//! - `sqlite`: a SQLite database behind a pool of connections, with the `sqlite`
+//!   feature
+//! - `sled`: a sled database, with the `sled` feature
+//! - `memory`: nothing is persisted, e.g. for tests
+//!
+//! [`shared`] opens the configured backend once per process and hands out
+//! clones of the same [`SharedStorage`], so every subsystem uses one database
+//! and its connections. Documents are versioned with the same [`Schema`]
+//! migrations as files: [`Document::read`] brings an older document up to date
+//! and stores it back, keeping the original under `<key>.v<version>.bak`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::config::{Config, StorageBackend, StorageConfig};
+use crate::error::Error;
+use crate::schema::{self, Schema};
+
+/// Key-value store of text documents
+///
+/// Implementations are shared between threads and subsystems, so every method
+/// takes `&self`; a write is visible to every handle once it returns.
+pub trait Storage: fmt::Debug + Send + Sync {
+    /// Contents stored under `key`, `None` if there are none
+    fn get(&self, key: &str) -> Result<Option<String>, Error>;
+
+    /// Store `value` under `key`, replacing what was there
+    fn put(&self, key: &str, value: &str) -> Result<(), Error>;
+
+    /// Remove what is stored under `key`, if anything
+    fn remove(&self, key: &str) -> Result<(), Error>;
+
+    /// Every key, sorted
+    fn keys(&self) -> Result<Vec<String>, Error>;
+
+    /// When `key` was last written, if the backend can tell without reading it
+    ///
+    /// Lets readers skip documents nobody changed; those of backends returning
+    /// `None` are read again every time.
+    fn modified(&self, _key: &str) -> Option<SystemTime> {
+        None
+    }
+
+    /// File `key` is kept in, for backends keeping documents in files
+    fn file_path(&self, _key: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Handle to a storage shared by every subsystem of a process
+pub type SharedStorage = Arc<dyn Storage>;
+
+/// Open the storage described by `config`
+///
+/// # Errors
+///
+/// Returns an error if the database can't be opened or migrated, or its backend
+/// wasn't compiled in
+pub fn open(config: &StorageConfig) -> Result<SharedStorage, Error> {
+    let path = config
+        .path
+        .clone()
+        .unwrap_or_else(|| default_path(config.backend));
+    match config.backend {
+        StorageBackend::Files => Ok(Arc::new(FileStorage::new(path))),
+        StorageBackend::Memory => Ok(Arc::new(MemoryStorage::new())),
+        #[cfg(feature = "sqlite")]
+        StorageBackend::Sqlite => Ok(Arc::new(SqliteStorage::open(
+            path,
+            config
+                .max_connections
+                .unwrap_or(SqliteStorage::DEFAULT_MAX_CONNECTIONS),
+        )?)),
+        #[cfg(feature = "sled")]
+        StorageBackend::Sled => Ok(Arc::new(SledStorage::open(path)?)),
+        #[allow(unreachable_patterns)]
+        backend => Err(Error::Config(format!(
+            "The {:?} storage backend needs the SDK built with its feature enabled",
+            backend
+        ))),
+    }
+}
+
+/// Where `backend` keeps its data when the configuration sets no path
+pub fn default_path(backend: StorageBackend) -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("mantra-dex");
+    match backend {
+        StorageBackend::Files | StorageBackend::Memory => {}
+        StorageBackend::Sqlite => path.push("state.sqlite3"),
+        StorageBackend::Sled => path.push("state.sled"),
+    }
+    path
+}
+
+/// The storage of the saved configuration, opened on first use
+///
+/// # Errors
+///
+/// Returns an error if the storage can't be opened; it is tried again on the
+/// next call
+pub fn shared() -> Result<SharedStorage, Error> {
+    static SHARED: OnceLock<SharedStorage> = OnceLock::new();
+    if let Some(storage) = SHARED.get() {
+        return Ok(storage.clone());
+    }
+    let config = Config::load(&Config::default_path())
+        .map(|saved| saved.storage)
+        .unwrap_or_default();
+    let storage = open(&config)?;
+    // Another thread may have opened it meanwhile; everyone keeps the first
+    Ok(SHARED.get_or_init(|| storage).clone())
+}
+
+/// One document of a [`Storage`], read and written in the format of a [`Schema`]
+#[derive(Debug, Clone)]
+pub struct Document {
+    storage: SharedStorage,
+    key: String,
+}
+
+impl Document {
+    pub fn new(storage: SharedStorage, key: impl Into<String>) -> Self {
+        Self {
+            storage,
+            key: key.into(),
+        }
+    }
+
+    /// The document kept in the file at `path`
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let root = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let key = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self::new(Arc::new(FileStorage::new(root)), key)
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn storage(&self) -> &SharedStorage {
+        &self.storage
+    }
+
+    /// File the document is kept in, for the `files` backend
+    pub fn path(&self) -> Option<PathBuf> {
+        self.storage.file_path(&self.key)
+    }
+
+    /// When the document was last written, if the backend can tell
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.storage.modified(&self.key)
+    }
+
+    /// Read the document, migrating it if an older release wrote it
+    ///
+    /// A migrated document is stored back in the current format, after the
+    /// original is kept under `<key>.v<version>.bak`.
+    ///
+    /// # Returns
+    ///
+    /// `None` if nothing is stored under the document's key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails, or the document is newer than the
+    /// schema or doesn't parse once migrated
+    pub fn read<T: DeserializeOwned>(&self, schema: &Schema) -> Result<Option<T>, Error> {
+        let Some(content) = self.storage.get(&self.key)? else {
+            return Ok(None);
+        };
+        let mut value = schema.parse(&content)?;
+        let from = schema.migrate(&mut value)?;
+        if from < schema.current_version() {
+            let backup = format!("{}.v{}.bak", self.key, from);
+            if self.storage.get(&backup)?.is_none() {
+                self.storage.put(&backup, &content)?;
+            }
+            self.storage.put(&self.key, &schema.render(&value)?)?;
+        }
+        schema::from_value(schema, value).map(Some)
+    }
+
+    /// Store `data` as the document, in the current format of `schema`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` doesn't serialize to an object or the storage fails
+    pub fn write<T: Serialize>(&self, schema: &Schema, data: &T) -> Result<(), Error> {
+        let value = schema::to_value(schema, data)?;
+        self.storage.put(&self.key, &schema.render(&value)?)
+    }
+}
+
+/// Documents kept as files named after their keys in a directory
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    root: PathBuf,
+}
+
+impl FileStorage {
+    /// Keep documents in `root`, created on the first write
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn path_of(&self, key: &str) -> Result<PathBuf, Error> {
+        // A key names a file in the directory, never one elsewhere
+        if key.is_empty() || key == ".." || key.contains(['/', '\\']) {
+            return Err(Error::Storage(format!("Invalid document key '{}'", key)));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+impl Storage for FileStorage {
+    fn get(&self, key: &str) -> Result<Option<String>, Error> {
+        let path = self.path_of(key)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(fs::read_to_string(path)?))
+    }
+
+    fn put(&self, key: &str, value: &str) -> Result<(), Error> {
+        let path = self.path_of(key)?;
+        fs::create_dir_all(&self.root)?;
+        fs::write(path, value)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        let path = self.path_of(key)?;
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, Error> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                keys.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn modified(&self, key: &str) -> Option<SystemTime> {
+        let path = self.path_of(key).ok()?;
+        fs::metadata(path).and_then(|meta| meta.modified()).ok()
+    }
+
+    fn file_path(&self, key: &str) -> Option<PathBuf> {
+        self.path_of(key).ok()
+    }
+}
+
+/// Documents kept in memory, lost when the last handle is dropped
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    documents: Mutex<BTreeMap<String, String>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, String>> {
+        // The map is never left half-updated, so a panic elsewhere can't corrupt it
+        self.documents
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> Result<Option<String>, Error> {
+        Ok(self.lock().get(key).cloned())
+    }
+
+    fn put(&self, key: &str, value: &str) -> Result<(), Error> {
+        self.lock().insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        self.lock().remove(key);
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, Error> {
+        Ok(self.lock().keys().cloned().collect())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStorage;
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use r2d2::Pool;
+    use r2d2_sqlite::SqliteConnectionManager;
+    use rusqlite::{params, OptionalExtension};
+
+    use super::Storage;
+    use crate::error::Error;
+
+    /// `MIGRATIONS[n]` turns a database of version `n` into version `n + 1`
+    ///
+    /// The version is kept in SQLite's `user_version`.
+    const MIGRATIONS: &[&str] = &["CREATE TABLE documents (
+            key TEXT PRIMARY KEY NOT NULL,
+            value TEXT NOT NULL,
+            modified_at INTEGER NOT NULL
+        )"];
+
+    /// How long a connection waits for another to release its lock
+    const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn storage_error(e: impl std::fmt::Display) -> Error {
+        Error::Storage(e.to_string())
+    }
+
+    /// Documents kept in a SQLite database, through a pool of connections
+    #[derive(Debug, Clone)]
+    pub struct SqliteStorage {
+        pool: Pool<SqliteConnectionManager>,
+        path: PathBuf,
+    }
+
+    impl SqliteStorage {
+        /// Connections opened at most when the configuration sets none
+        pub const DEFAULT_MAX_CONNECTIONS: u32 = 4;
+
+        /// Open the database at `path`, creating and migrating it as needed
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Storage`] if the database can't be opened or was
+        /// created by a newer release
+        pub fn open(path: impl Into<PathBuf>, max_connections: u32) -> Result<Self, Error> {
+            let path = path.into();
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let manager = SqliteConnectionManager::file(&path).with_init(|connection| {
+                connection.busy_timeout(BUSY_TIMEOUT)?;
+                // Readers don't block the writer, e.g. the TUI while a runner records
+                connection.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))
+            });
+            let pool = Pool::builder()
+                .max_size(max_connections.max(1))
+                .build(manager)
+                .map_err(storage_error)?;
+            let storage = Self { pool, path };
+            storage.migrate()?;
+            Ok(storage)
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+
+        /// Version of the database's tables
+        pub fn version(&self) -> Result<u32, Error> {
+            self.connection()?
+                .pragma_query_value(None, "user_version", |row| row.get(0))
+                .map_err(storage_error)
+        }
+
+        fn migrate(&self) -> Result<(), Error> {
+            let mut connection = self.connection()?;
+            let from: u32 = connection
+                .pragma_query_value(None, "user_version", |row| row.get(0))
+                .map_err(storage_error)?;
+            if from as usize > MIGRATIONS.len() {
+                return Err(Error::Storage(format!(
+                    "The database at {} has version {}, newer than the {} this release supports",
+                    self.path.display(),
+                    from,
+                    MIGRATIONS.len()
+                )));
+            }
+            for (version, migration) in MIGRATIONS.iter().enumerate().skip(from as usize) {
+                let transaction = connection.transaction().map_err(storage_error)?;
+                transaction
+                    .execute_batch(migration)
+                    .and_then(|_| transaction.pragma_update(None, "user_version", version + 1))
+                    .and_then(|_| transaction.commit())
+                    .map_err(|e| {
+                        Error::Storage(format!(
+                            "Failed to migrate the database from version {}: {}",
+                            version, e
+                        ))
+                    })?;
+            }
+            Ok(())
+        }
+
+        fn connection(&self) -> Result<r2d2::PooledConnection<SqliteConnectionManager>, Error> {
+            self.pool.get().map_err(storage_error)
+        }
+    }
+
+    impl Storage for SqliteStorage {
+        fn get(&self, key: &str) -> Result<Option<String>, Error> {
+            self.connection()?
+                .query_row(
+                    "SELECT value FROM documents WHERE key = ?1",
+                    params![key],
+                    |row| row.get(0),
+                )
+                .optional()
+                .map_err(storage_error)
+        }
+
+        fn put(&self, key: &str, value: &str) -> Result<(), Error> {
+            let modified_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as i64;
+            self.connection()?
+                .execute(
+                    "INSERT INTO documents (key, value, modified_at) VALUES (?1, ?2, ?3)
+                     ON CONFLICT (key) DO UPDATE SET
+                        value = excluded.value, modified_at = excluded.modified_at",
+                    params![key, value, modified_at],
+                )
+                .map_err(storage_error)?;
+            Ok(())
+        }
+
+        fn remove(&self, key: &str) -> Result<(), Error> {
+            self.connection()?
+                .execute("DELETE FROM documents WHERE key = ?1", params![key])
+                .map_err(storage_error)?;
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>, Error> {
+            let connection = self.connection()?;
+            let mut statement = connection
+                .prepare("SELECT key FROM documents ORDER BY key")
+                .map_err(storage_error)?;
+            let keys = statement
+                .query_map([], |row| row.get(0))
+                .and_then(|rows| rows.collect())
+                .map_err(storage_error)?;
+            Ok(keys)
+        }
+
+        fn modified(&self, key: &str) -> Option<SystemTime> {
+            let nanos: i64 = self
+                .connection()
+                .ok()?
+                .query_row(
+                    "SELECT modified_at FROM documents WHERE key = ?1",
+                    params![key],
+                    |row| row.get(0),
+                )
+                .ok()?;
+            Some(UNIX_EPOCH + Duration::from_nanos(nanos as u64))
+        }
+    }
+}
+
+#[cfg(feature = "sled")]
+pub use self::sled_backend::SledStorage;
+
+#[cfg(feature = "sled")]
+mod sled_backend {
+    use std::path::{Path, PathBuf};
+
+    use super::Storage;
+    use crate::error::Error;
+
+    fn storage_error(e: impl std::fmt::Display) -> Error {
+        Error::Storage(e.to_string())
+    }
+
+    /// Documents kept in a sled database
+    ///
+    /// sled serves any number of threads from one handle, so clones share it.
+    #[derive(Debug, Clone)]
+    pub struct SledStorage {
+        db: sled::Db,
+        path: PathBuf,
+    }
+
+    impl SledStorage {
+        /// Open the database at `path`, creating it if needed
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::Storage`] if the database can't be opened, e.g. while
+        /// another process has it open
+        pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+            let path = path.into();
+            // Every write flushes, so no background flusher holds the database open
+            let db = sled::Config::new()
+                .path(&path)
+                .flush_every_ms(None)
+                .open()
+                .map_err(storage_error)?;
+            Ok(Self { db, path })
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Storage for SledStorage {
+        fn get(&self, key: &str) -> Result<Option<String>, Error> {
+            self.db
+                .get(key)
+                .map_err(storage_error)?
+                .map(|value| String::from_utf8(value.to_vec()).map_err(storage_error))
+                .transpose()
+        }
+
+        fn put(&self, key: &str, value: &str) -> Result<(), Error> {
+            self.db
+                .insert(key, value.as_bytes())
+                .map_err(storage_error)?;
+            // Durable once written, like the other backends
+            self.db.flush().map_err(storage_error)?;
+            Ok(())
+        }
+
+        fn remove(&self, key: &str) -> Result<(), Error> {
+            self.db.remove(key).map_err(storage_error)?;
+            self.db.flush().map_err(storage_error)?;
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>, Error> {
+            self.db
+                .iter()
+                .keys()
+                .map(|key| {
+                    key.map_err(storage_error)
+                        .and_then(|key| String::from_utf8(key.to_vec()).map_err(storage_error))
+                })
+                .collect()
+        }
+    }
+}
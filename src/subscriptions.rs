@@ -0,0 +1,243 @@
+//! Streaming chain events over the node's WebSocket
+//!
+//! Instead of polling the RPC endpoint every few seconds, an
+//! [`EventSubscriptions`] keeps one WebSocket connection to the node open and
+//! subscribes to new blocks and to the transactions a [`SubscriptionFilter`]
+//! selects: those sent by or paying a wallet address, and those executing a
+//! pool or farm contract. Each arrives once as a typed [`ChainEvent`] on a tokio
+//! broadcast channel, so any number of consumers can listen.
+//!
+//! When the connection drops, every receiver sees the channel close; consumers
+//! fall back to polling and may connect again.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use cosmrs::rpc::event::{Event as RpcEvent, EventData};
+use cosmrs::rpc::query::{EventType, Query};
+use cosmrs::rpc::{SubscriptionClient, WebSocketClient};
+use cosmrs::tendermint::abci::Event;
+use cosmrs::tendermint::crypto::{default::Sha256, Sha256 as _};
+use futures::stream::{self, StreamExt};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use crate::error::Error;
+
+/// Chain events buffered for each receiver
+const EVENTS_CAPACITY: usize = 256;
+
+/// Transaction hashes remembered to deliver a transaction matching several queries once
+const RECENT_TXS: usize = 256;
+
+/// An event streamed from the node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainEvent {
+    /// A block was committed
+    NewBlock { height: u64 },
+    /// A transaction matching the filter was included in a block
+    Tx {
+        height: u64,
+        tx_hash: String,
+        events: Vec<Event>,
+    },
+}
+
+impl ChainEvent {
+    /// Typed event of an RPC subscription event, if it is a block or a transaction
+    pub fn from_rpc(event: &RpcEvent) -> Option<Self> {
+        match &event.data {
+            EventData::NewBlock { block, .. } | EventData::LegacyNewBlock { block, .. } => {
+                block.as_ref().map(|block| ChainEvent::NewBlock {
+                    height: block.header.height.value(),
+                })
+            }
+            EventData::Tx { tx_result } => Some(ChainEvent::Tx {
+                height: tx_result.height.max(0) as u64,
+                tx_hash: hex::encode_upper(Sha256::digest(&tx_result.tx)),
+                events: tx_result.result.events.clone(),
+            }),
+            EventData::GenericJsonEvent(_) => None,
+        }
+    }
+
+    /// Height of the block the event belongs to
+    pub fn height(&self) -> u64 {
+        match self {
+            ChainEvent::NewBlock { height } | ChainEvent::Tx { height, .. } => *height,
+        }
+    }
+
+    /// Whether a transaction event carries an attribute of `key` equal to `value`
+    ///
+    /// Block events never match.
+    pub fn has_attribute(&self, key: &str, value: &str) -> bool {
+        let ChainEvent::Tx { events, .. } = self else {
+            return false;
+        };
+        events.iter().any(|event| {
+            event.attributes.iter().any(|attr| {
+                attr.key_str().is_ok_and(|k| k == key) && attr.value_str().is_ok_and(|v| v == value)
+            })
+        })
+    }
+
+    /// Whether a transaction event involves `address`, as sender, signer or recipient
+    pub fn involves_address(&self, address: &str) -> bool {
+        ["sender", "recipient", "spender", "receiver"]
+            .iter()
+            .any(|key| self.has_attribute(key, address))
+    }
+
+    /// Whether a transaction event executed `contract`
+    pub fn involves_contract(&self, contract: &str) -> bool {
+        self.has_attribute("_contract_address", contract)
+    }
+}
+
+/// The transactions to stream, besides every new block
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriptionFilter {
+    addresses: BTreeSet<String>,
+    contracts: BTreeSet<String>,
+}
+
+impl SubscriptionFilter {
+    /// Stream new blocks only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also stream the transactions sent by or paying `address`
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.addresses.insert(address.into());
+        self
+    }
+
+    /// Also stream the transactions executing `contract`
+    pub fn with_contract(mut self, contract: impl Into<String>) -> Self {
+        self.contracts.insert(contract.into());
+        self
+    }
+
+    /// Event queries to subscribe to, the new block query first
+    pub fn queries(&self) -> Vec<Query> {
+        let tx = || Query::from(EventType::Tx);
+        let mut queries = vec![Query::from(EventType::NewBlock)];
+        for address in &self.addresses {
+            queries.push(tx().and_eq("message.sender", address.as_str()));
+            queries.push(tx().and_eq("transfer.recipient", address.as_str()));
+        }
+        for contract in &self.contracts {
+            queries.push(tx().and_eq("wasm._contract_address", contract.as_str()));
+        }
+        queries
+    }
+}
+
+/// WebSocket endpoint of the node serving `rpc_url`
+///
+/// `http(s)://host:port` becomes `ws(s)://host:port/websocket`; WebSocket URLs
+/// are kept as they are.
+pub fn websocket_url(rpc_url: &str) -> Result<String, Error> {
+    let rpc_url = rpc_url.trim_end_matches('/');
+    if rpc_url.starts_with("ws://") || rpc_url.starts_with("wss://") {
+        return Ok(rpc_url.to_string());
+    }
+    let url = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        return Err(Error::Config(format!(
+            "RPC URL '{}' has no http(s) or ws(s) scheme",
+            rpc_url
+        )));
+    };
+    Ok(format!("{}/websocket", url))
+}
+
+/// An open WebSocket connection streaming [`ChainEvent`]s
+///
+/// Dropping it closes the connection.
+#[derive(Debug)]
+pub struct EventSubscriptions {
+    sender: broadcast::WeakSender<ChainEvent>,
+    task: JoinHandle<()>,
+}
+
+impl EventSubscriptions {
+    /// Connect to the node's WebSocket at `url` and subscribe to the events `filter` selects
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Network`] if the connection or a subscription fails
+    pub async fn connect(url: &str, filter: &SubscriptionFilter) -> Result<Self, Error> {
+        let (client, driver) = WebSocketClient::new(url)
+            .await
+            .map_err(|e| Error::Network(format!("Failed to connect to {}: {}", url, e)))?;
+        let driver = tokio::spawn(driver.run());
+
+        let mut subscriptions = Vec::new();
+        for query in filter.queries() {
+            match client.subscribe(query.clone()).await {
+                Ok(subscription) => subscriptions.push(subscription),
+                Err(e) => {
+                    client.close().ok();
+                    driver.abort();
+                    return Err(Error::Network(format!(
+                        "Failed to subscribe to {}: {}",
+                        query, e
+                    )));
+                }
+            }
+        }
+
+        let (sender, _) = broadcast::channel(EVENTS_CAPACITY);
+        let weak = sender.downgrade();
+        let task = tokio::spawn(async move {
+            let mut events = stream::select_all(subscriptions);
+            let mut recent = VecDeque::with_capacity(RECENT_TXS);
+            while let Some(Ok(event)) = events.next().await {
+                let Some(event) = ChainEvent::from_rpc(&event) else {
+                    continue;
+                };
+                if let ChainEvent::Tx { tx_hash, .. } = &event {
+                    if recent.contains(tx_hash) {
+                        continue;
+                    }
+                    if recent.len() == RECENT_TXS {
+                        recent.pop_front();
+                    }
+                    recent.push_back(tx_hash.clone());
+                }
+                // Nobody listening is fine, receivers can subscribe later
+                let _ = sender.send(event);
+            }
+            client.close().ok();
+            let _ = driver.await;
+        });
+
+        Ok(Self { sender: weak, task })
+    }
+
+    /// Receive the events streamed from now on
+    ///
+    /// The receiver closes when the connection drops.
+    pub fn subscribe(&self) -> broadcast::Receiver<ChainEvent> {
+        match self.sender.upgrade() {
+            Some(sender) => sender.subscribe(),
+            None => broadcast::channel(1).1,
+        }
+    }
+
+    /// Whether the connection has dropped
+    pub fn is_closed(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for EventSubscriptions {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,290 @@
+//! Token metadata resolved from the chain
+//!
+//! Symbols and decimals of denoms come from the chain rather than a hardcoded
+//! list: the bank module's denom metadata when the issuer registered it, the IBC
+//! transfer module's denom trace for `ibc/` denoms, and the subdenom of
+//! `factory/` denoms, with decimals read from the pools trading them.
+//! [`MantraDexClient::resolve_tokens`] stores what it resolves in the client's
+//! [`TokenRegistry`], which the TUI, CLI and MCP server all read symbols and
+//! decimals from. A registry opened from a file keeps its tokens across runs,
+//! so each denom is only resolved once.
+//!
+//! [`MantraDexClient::resolve_tokens`]: crate::client::MantraDexClient::resolve_tokens
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use cosmos_sdk_proto::cosmos::bank::v1beta1::Metadata;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::schema::{Format, Schema};
+use crate::storage::{Document, SharedStorage};
+
+/// Decimals assumed for a denom nothing could be resolved for
+pub const DEFAULT_DECIMALS: u8 = 6;
+
+/// Prefix of IBC vouchers, followed by the hash of their denom trace
+pub const IBC_PREFIX: &str = "ibc/";
+
+/// Prefix of token factory denoms, followed by the creator and the subdenom
+pub const FACTORY_PREFIX: &str = "factory/";
+
+/// Format of the token registry file
+const TOKEN_REGISTRY_SCHEMA: Schema = Schema::new("token registry", Format::Json, &[]);
+
+/// Where a token's metadata was resolved from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenSource {
+    /// Denom metadata registered with the bank module
+    Bank,
+    /// Denom trace of an IBC voucher, with decimals from a pool trading it
+    IbcTrace,
+    /// Subdenom of a token factory denom, with decimals from a pool trading it
+    Factory,
+    /// Decimals from a pool trading the denom, symbol derived from the denom
+    Pool,
+    /// Nothing on chain describes the denom; symbol and decimals are guessed
+    Fallback,
+}
+
+/// Origin of an IBC voucher
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IbcTrace {
+    /// Ports and channels the token travelled through, e.g. `transfer/channel-0`
+    pub path: String,
+    /// Denom on the chain the token comes from
+    pub base_denom: String,
+}
+
+/// Display metadata of a denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub denom: String,
+    /// Symbol shown to users, e.g. `OM` for `uom`
+    pub symbol: String,
+    /// Decimals of the display unit; base units are divided by 10^decimals
+    pub decimals: u8,
+    /// Full name, if the issuer registered one
+    pub name: Option<String>,
+    pub source: TokenSource,
+    /// Origin of an IBC voucher
+    pub ibc_trace: Option<IbcTrace>,
+}
+
+impl TokenMetadata {
+    /// Metadata of `denom` from its bank module registration
+    ///
+    /// The decimals are the exponent of the display unit. `None` if the
+    /// registration names neither a symbol nor a display unit.
+    pub fn from_bank_metadata(denom: &str, metadata: &Metadata) -> Option<Self> {
+        let symbol = [&metadata.symbol, &metadata.display]
+            .into_iter()
+            .find(|symbol| !symbol.is_empty())?;
+        let decimals = metadata
+            .denom_units
+            .iter()
+            .find(|unit| unit.denom == metadata.display)
+            .or_else(|| metadata.denom_units.iter().max_by_key(|unit| unit.exponent))
+            .map_or(DEFAULT_DECIMALS, |unit| {
+                unit.exponent.min(u8::MAX as u32) as u8
+            });
+        Some(Self {
+            denom: denom.to_string(),
+            symbol: symbol.clone(),
+            decimals,
+            name: Some(metadata.name.clone()).filter(|name| !name.is_empty()),
+            source: TokenSource::Bank,
+            ibc_trace: None,
+        })
+    }
+
+    /// Metadata of `denom` from what can be told without the bank module
+    ///
+    /// `trace` is the denom trace of an IBC voucher and `pool_decimals` the
+    /// decimals of a pool trading the denom, when known.
+    pub fn derived(denom: &str, trace: Option<IbcTrace>, pool_decimals: Option<u8>) -> Self {
+        let symbol = match &trace {
+            Some(trace) => fallback_symbol(&trace.base_denom),
+            None => fallback_symbol(denom),
+        };
+        let source = match (&trace, pool_decimals) {
+            (Some(_), _) => TokenSource::IbcTrace,
+            (None, Some(_)) if denom.starts_with(FACTORY_PREFIX) => TokenSource::Factory,
+            (None, Some(_)) => TokenSource::Pool,
+            (None, None) => TokenSource::Fallback,
+        };
+        Self {
+            denom: denom.to_string(),
+            symbol,
+            decimals: pool_decimals.unwrap_or(DEFAULT_DECIMALS),
+            name: None,
+            source,
+            ibc_trace: trace,
+        }
+    }
+}
+
+/// Symbol derived from a denom alone
+///
+/// Takes the last path segment and drops the `u` prefix of micro units,
+/// upper-casing all-lowercase names: `uom` is `OM`, `factory/mantra1../uUSDC`
+/// is `USDC`.
+pub fn fallback_symbol(denom: &str) -> String {
+    let name = denom.rsplit('/').next().unwrap_or(denom);
+    let name = match name.strip_prefix('u') {
+        Some(rest) if !rest.is_empty() => rest,
+        _ => name,
+    };
+    if name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        name.to_ascii_uppercase()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Request of the IBC transfer module's `DenomTrace` query
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct QueryDenomTraceRequest {
+    /// Hash of the denom trace, the part of the denom after `ibc/`
+    #[prost(string, tag = "1")]
+    pub hash: String,
+}
+
+/// Response of the IBC transfer module's `DenomTrace` query
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct QueryDenomTraceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub denom_trace: Option<DenomTrace>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct DenomTrace {
+    #[prost(string, tag = "1")]
+    pub path: String,
+    #[prost(string, tag = "2")]
+    pub base_denom: String,
+}
+
+/// Contents of the token registry file
+#[derive(Serialize, Deserialize)]
+struct TokenRegistryFile {
+    tokens: BTreeMap<String, TokenMetadata>,
+}
+
+/// Resolved metadata of denoms, shared by every frontend
+#[derive(Debug, Default)]
+pub struct TokenRegistry {
+    tokens: Mutex<BTreeMap<String, TokenMetadata>>,
+    document: Option<Document>,
+}
+
+impl TokenRegistry {
+    /// Create an empty, in-memory registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the registry stored at `path`, saving back to it as tokens are resolved
+    ///
+    /// Starts empty if the file doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        Self::open_document(Document::file(path))
+    }
+
+    /// Load the registry kept in `storage`, saving back to it as tokens are resolved
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage fails or its document can't be parsed
+    pub fn open_in(storage: SharedStorage) -> Result<Self, Error> {
+        Self::open_document(Document::new(storage, Self::STORAGE_KEY))
+    }
+
+    fn open_document(document: Document) -> Result<Self, Error> {
+        let mut registry = Self::default();
+        if let Some(stored) = document.read::<TokenRegistryFile>(&TOKEN_REGISTRY_SCHEMA)? {
+            registry.tokens = Mutex::new(stored.tokens);
+        }
+        registry.document = Some(document);
+        Ok(registry)
+    }
+
+    /// Key of the registry in a [`Storage`](crate::storage::Storage), its file name with
+    /// the `files` backend
+    pub const STORAGE_KEY: &'static str = "token_registry.json";
+
+    /// Get the default token registry file path
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("token_registry.json");
+        path
+    }
+
+    /// Resolved metadata of `denom`, if any
+    pub fn get(&self, denom: &str) -> Option<TokenMetadata> {
+        self.lock().get(denom).cloned()
+    }
+
+    /// Whether `denom` has been resolved
+    pub fn contains(&self, denom: &str) -> bool {
+        self.lock().contains_key(denom)
+    }
+
+    /// Every resolved token, by denom
+    pub fn tokens(&self) -> Vec<TokenMetadata> {
+        self.lock().values().cloned().collect()
+    }
+
+    /// Symbol of `denom`, derived from the denom until it is resolved
+    pub fn symbol(&self, denom: &str) -> String {
+        self.lock()
+            .get(denom)
+            .map_or_else(|| fallback_symbol(denom), |token| token.symbol.clone())
+    }
+
+    /// Decimals of `denom`, [`DEFAULT_DECIMALS`] until it is resolved
+    pub fn decimals(&self, denom: &str) -> u8 {
+        self.lock()
+            .get(denom)
+            .map_or(DEFAULT_DECIMALS, |token| token.decimals)
+    }
+
+    /// Add resolved tokens, replacing earlier metadata of the same denoms
+    ///
+    /// Tokens from [`TokenSource::Fallback`] are skipped so they are resolved
+    /// again once the chain describes them. Saving a registry opened from a
+    /// file or a storage is best effort; a failed write only means resolving again next run.
+    pub fn insert(&self, tokens: impl IntoIterator<Item = TokenMetadata>) {
+        let snapshot = {
+            let mut stored = self.lock();
+            for token in tokens {
+                if token.source != TokenSource::Fallback {
+                    stored.insert(token.denom.clone(), token);
+                }
+            }
+            self.document.as_ref().map(|_| stored.clone())
+        };
+
+        if let (Some(document), Some(tokens)) = (&self.document, snapshot) {
+            let _ = document.write(&TOKEN_REGISTRY_SCHEMA, &TokenRegistryFile { tokens });
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, TokenMetadata>> {
+        // A panic while holding the lock can't leave the tokens inconsistent
+        self.tokens
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -3,31 +3,79 @@
 //! This module manages the global application state for the TUI, including
 //! screen navigation, data caching, and state transitions.
 
+#[cfg(feature = "tui")]
+use crate::circuit_breaker::CircuitState;
+use crate::config::OperationClass;
+use crate::dca::DcaBook;
+use crate::diagnose::{Diagnosis, FailureCause};
+use crate::history::{HistoryFilter, TxHistory};
+use crate::ownership::PoolOwnership;
+use crate::pagination::PageCursor;
+use crate::paper::{PaperPortfolio, PaperTrade, PaperValuation};
+use crate::portfolio::PortfolioValuation;
+use crate::preferences::{Preferences, PreferencesStore};
+use crate::price_history::PriceHistory;
+#[cfg(feature = "tui")]
+use crate::risk::{RiskReport, RiskThresholds};
+use crate::scheduler::{Schedule, ScheduledAction, Trigger};
+use crate::secret::SecretString;
+use crate::storage;
 #[cfg(feature = "tui")]
 use crate::tui::components::modals::{ErrorType, ModalState};
+use crate::tui::components::simple_list::{ListLoadState, SimpleList};
 #[cfg(feature = "tui")]
-use crate::tui::events::Event;
+use crate::tui::components::tables::TableExport;
+#[cfg(feature = "tui")]
+use crate::tui::events::{DataEvent, Event, EventBus, FocusDirection, TxEvent, UiEvent};
 #[cfg(feature = "tui")]
 use crate::tui::screens::liquidity::{self, LiquidityMode};
+use crate::tui::screens::pools::ComparisonView;
 #[cfg(feature = "tui")]
-use crate::tui::utils::focus_manager::FocusManager;
+use crate::tui::screens::Screen as _;
 #[cfg(feature = "tui")]
-use crate::{Error, MantraDexClient, MantraNetworkConfig};
+use crate::tui::utils::async_ops::{ActivityTracker, NetworkState, DEFAULT_IDLE_TIMEOUT};
+use crate::tui::utils::focus_manager::FocusManager;
+use crate::tui::utils::loader::{DataKey, DataLoader};
+use crate::{Error, MantraDexClient, MantraNetworkConfig, SlippageBumpPolicy, SwapRequest};
 #[cfg(feature = "tui")]
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 #[cfg(feature = "tui")]
 use cosmwasm_std::Uint128;
 #[cfg(feature = "tui")]
+use futures::TryStreamExt;
+#[cfg(feature = "tui")]
+use mantra_dex_std::farm_manager::PositionsBy;
+#[cfg(feature = "tui")]
 use mantra_dex_std::pool_manager::{PoolInfoResponse, SimulationResponse};
 #[cfg(feature = "tui")]
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "tui")]
+use std::path::{Path, PathBuf};
 #[cfg(feature = "tui")]
 use std::sync::Arc;
 #[cfg(feature = "tui")]
 use std::time::Duration;
 
-#[cfg(feature = "tui")]
-use tokio::sync::mpsc;
+/// Transactions kept in the history shown on the transaction screen
+const MAX_RECENT_TRANSACTIONS: usize = 1000;
+
+/// Title of the confirmation offering to retry a swap rejected on slippage
+const SLIPPAGE_RETRY_TITLE: &str = "Increase Slippage and Retry?";
+
+/// Title of the confirmation explaining the last failed transaction
+const DIAGNOSIS_TITLE: &str = "Transaction Diagnosis";
+
+/// Action of error modals that offer a diagnosis of the failure
+const DIAGNOSE_ACTION: &str = "Diagnose";
+
+/// Pools screened for risk at the same time
+const RISK_SCREENING_CONCURRENCY: usize = 4;
+
+/// Delay between checks of the preferences file for changes made by other processes
+const PREFERENCES_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Farms and positions requested per page when loading the epoch calendar and farm alerts
+const FARM_PAGE_SIZE: u32 = 100;
 
 /// Available screens in the TUI application
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,6 +89,7 @@ pub enum Screen {
     Rewards,
     Admin,
     Settings,
+    Analytics,
     TransactionDetails,
 }
 
@@ -66,6 +115,7 @@ impl Screen {
             Screen::Rewards => "Rewards",
             Screen::Admin => "Admin",
             Screen::Settings => "Settings",
+            Screen::Analytics => "Analytics",
             Screen::TransactionDetails => "Transaction",
         }
     }
@@ -81,6 +131,7 @@ impl Screen {
             Screen::Rewards,
             Screen::Admin,
             Screen::Settings,
+            Screen::Analytics,
         ]
     }
 }
@@ -233,6 +284,46 @@ pub struct TransactionInfo {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub gas_used: Option<i64>,
     pub gas_wanted: Option<i64>,
+    /// Failed attempt this transaction retried, e.g. a swap rejected on slippage
+    pub retry_of: Option<String>,
+}
+
+/// A swap rejected for exceeding its slippage tolerance, kept for a retry
+#[derive(Debug, Clone)]
+pub struct FailedSwap {
+    /// The swap as sent, including its slippage tolerance
+    pub request: SwapRequest,
+    pub from_asset: String,
+    pub to_asset: String,
+    pub amount: String,
+}
+
+/// Diagnosis of the last failed transaction
+#[derive(Debug, Clone)]
+pub struct FailedTransaction {
+    /// Operation that failed, e.g. `execute_swap`
+    pub operation: String,
+    pub diagnosis: Diagnosis,
+}
+
+impl FailedTransaction {
+    /// Screen to fix the failure or retry the operation on
+    pub fn retry_screen(&self) -> Screen {
+        match self.diagnosis.cause {
+            FailureCause::OutOfGas | FailureCause::InsufficientFee => Screen::Settings,
+            FailureCause::InsufficientFunds => Screen::Dashboard,
+            FailureCause::PoolUnavailable => Screen::Pools,
+            _ => match self.operation.as_str() {
+                "execute_swap" | "swap" => Screen::Swap,
+                "provide_liquidity" | "withdraw_liquidity" => Screen::Liquidity,
+                "create_pool" | "update_pool_features" => Screen::Admin,
+                operation if operation.contains("reward") || operation.contains("claim") => {
+                    Screen::Rewards
+                }
+                _ => Screen::Dashboard,
+            },
+        }
+    }
 }
 
 /// Transaction status enum
@@ -260,6 +351,8 @@ pub struct SwapState {
     pub slippage: String,
     pub simulation_result: Option<SimulationResponse>,
     pub selected_pool_id: Option<String>,
+    /// Depth curve of the selected pool, sampled up to twice the entered amount
+    pub depth_curve: Option<crate::routing::DepthCurve>,
 }
 
 /// Current liquidity operation state
@@ -293,6 +386,7 @@ impl Default for SwapState {
             slippage: "1.0".to_string(), // Default 1% slippage
             simulation_result: None,
             selected_pool_id: None,
+            depth_curve: None,
         }
     }
 }
@@ -327,6 +421,8 @@ pub struct AppState {
     pub status_message: Option<String>,
     /// Modal state for comprehensive dialogs
     pub modal_state: Option<ModalState>,
+    /// Onboarding tour in progress, if any
+    pub tour: Option<crate::tui::components::tour::TourState>,
     /// Selected pool ID (if any)
     pub selected_pool_id: Option<u64>,
     /// User token balances cache
@@ -337,12 +433,22 @@ pub struct AppState {
     pub block_height: Option<u64>,
     /// Connected wallet address
     pub wallet_address: Option<String>,
+    /// Name of the connected wallet, if it was loaded from storage
+    pub wallet_name: Option<String>,
     /// Whether the app should quit
     pub should_quit: bool,
     /// Current tab selection for navigation
     pub current_tab: usize,
     /// Cached pool information
     pub pool_cache: HashMap<String, PoolCacheEntry>,
+    /// Risk screening results by pool ID
+    pub pool_risks: HashMap<String, RiskReport>,
+    /// Pools whose risk screening is in progress
+    pub pool_risks_pending: HashSet<String>,
+    /// Creator, creation block and LP concentration by pool ID
+    pub pool_ownership: HashMap<String, PoolOwnership>,
+    /// Pools whose ownership lookup is in progress
+    pub pool_ownership_pending: HashSet<String>,
     /// Current swap operation state
     pub swap_state: SwapState,
     /// Current liquidity operation state
@@ -353,6 +459,16 @@ pub struct AppState {
     pub claimable_rewards: HashMap<String, Uint128>,
     /// Rewards screen state
     pub rewards_state: crate::tui::screens::rewards::RewardsState,
+    /// Analytics screen state
+    pub analytics_state: crate::tui::screens::analytics::AnalyticsState,
+    /// Swap screen state
+    pub swap_screen_state: crate::tui::screens::swap::SwapScreenState,
+    /// Pools screen state
+    pub pools_screen_state: crate::tui::screens::pools::PoolsScreenState,
+    /// Multi-hop swap screen state
+    pub multihop_screen_state: crate::tui::screens::multihop::MultiHopScreenState,
+    /// Liquidity screen state
+    pub liquidity_screen_state: crate::tui::screens::liquidity::LiquidityScreenState,
     /// Admin screen state  
     pub admin_screen_state: crate::tui::screens::admin::AdminScreenState,
     /// Settings screen state
@@ -371,6 +487,22 @@ pub struct AppState {
     pub wizard_state: crate::tui::screens::wizard::WizardState,
     /// Asset decimals cache (denom -> decimal places)
     pub asset_decimals_cache: HashMap<String, u8>,
+    /// Outcome of the last pool fetch, shown by empty pool dropdowns
+    pub pool_load_state: ListLoadState,
+    /// Last swap rejected on slippage, offered for a retry with a higher tolerance
+    pub failed_swap: Option<FailedSwap>,
+    /// Last failed transaction, offered for a diagnosis from its error modal
+    pub failed_transaction: Option<FailedTransaction>,
+    /// Virtual portfolio trades are applied to while paper trading, `None` otherwise
+    pub paper_portfolio: Option<PaperPortfolio>,
+    /// Latest valuation of the paper portfolio at live prices
+    pub paper_valuation: Option<PaperValuation>,
+    /// Table rows captured for the export modal, written once its path is confirmed
+    pub pending_export: Option<TableExport>,
+    /// Holdings of the connected wallet valued at pool prices
+    pub portfolio: Option<PortfolioValuation>,
+    /// Pool spot prices sampled as pools are refreshed, opened on the first refresh
+    pub price_history: Option<PriceHistory>,
 }
 
 /// Pending operation tracking for comprehensive loading states
@@ -393,6 +525,10 @@ pub struct NetworkInfo {
     pub last_block_height: Option<u64>,
     pub connection_latency: Option<Duration>,
     pub retry_count: u32,
+    /// RPC endpoints whose circuit breaker is currently open
+    pub failing_endpoints: Vec<String>,
+    /// Latest state and measured latency of every RPC endpoint
+    pub endpoints: Vec<crate::circuit_breaker::EndpointHealth>,
 }
 
 impl Default for NetworkInfo {
@@ -406,6 +542,8 @@ impl Default for NetworkInfo {
             last_block_height: None,
             connection_latency: None,
             retry_count: 0,
+            failing_endpoints: Vec::new(),
+            endpoints: Vec::new(),
         }
     }
 }
@@ -419,19 +557,30 @@ impl Default for AppState {
             error_message: None,
             status_message: None,
             modal_state: None,
+            tour: None,
             selected_pool_id: None,
             balances: HashMap::new(),
             recent_transactions: Vec::new(),
             block_height: None,
             wallet_address: None,
+            wallet_name: None,
             should_quit: false,
             current_tab: 0,
             pool_cache: HashMap::new(),
+            pool_risks: HashMap::new(),
+            pool_risks_pending: HashSet::new(),
+            pool_ownership: HashMap::new(),
+            pool_ownership_pending: HashSet::new(),
             swap_state: SwapState::default(),
             liquidity_state: LiquidityState::default(),
             current_epoch: None,
             claimable_rewards: HashMap::new(),
             rewards_state: crate::tui::screens::rewards::RewardsState::default(),
+            analytics_state: crate::tui::screens::analytics::AnalyticsState::default(),
+            swap_screen_state: crate::tui::screens::swap::SwapScreenState::default(),
+            pools_screen_state: crate::tui::screens::pools::PoolsScreenState::default(),
+            multihop_screen_state: crate::tui::screens::multihop::MultiHopScreenState::default(),
+            liquidity_screen_state: crate::tui::screens::liquidity::LiquidityScreenState::default(),
             admin_screen_state: crate::tui::screens::admin::AdminScreenState::default(),
             settings_state: crate::tui::screens::settings::SettingsState::default(),
             transaction_state: crate::tui::screens::transaction::TransactionState::default(),
@@ -447,6 +596,14 @@ impl Default for AppState {
                 wizard
             },
             asset_decimals_cache: HashMap::new(),
+            pool_load_state: ListLoadState::Loading,
+            failed_swap: None,
+            failed_transaction: None,
+            paper_portfolio: None,
+            paper_valuation: None,
+            pending_export: None,
+            portfolio: None,
+            price_history: None,
         }
     }
 }
@@ -460,13 +617,28 @@ pub struct App {
     /// Configuration
     pub config: MantraNetworkConfig,
     /// Event sender for background task communication
-    event_sender: Option<mpsc::UnboundedSender<Event>>,
+    event_sender: Option<EventBus>,
     /// Enhanced background task coordinator
     background_coordinator: Option<crate::tui::utils::async_ops::BackgroundTaskCoordinator>,
+    /// Watcher of the pools on the client's cached routes, if routes are cached
+    route_cache_watcher: Option<tokio::task::JoinHandle<Result<(), Error>>>,
+    /// Optional session recorder for debugging user-reported issues
+    session_recorder: Option<crate::tui::utils::session::SessionRecorder>,
+    /// Preferences shared with the CLI
+    preferences: PreferencesStore,
+    /// Freshness of the data each screen shows
+    data_loader: DataLoader,
+    /// Whether the user is at the TUI
+    activity: ActivityTracker,
+    /// Whether background tasks were last told the TUI is idle
+    idle: bool,
 }
 
 impl App {
     /// Create a new application instance
+    ///
+    /// Preferences are kept in memory until [`set_preferences`](Self::set_preferences)
+    /// gives the app a file to share them through.
     pub fn new(client: MantraDexClient, config: MantraNetworkConfig) -> Self {
         Self {
             state: AppState::default(),
@@ -474,11 +646,432 @@ impl App {
             config,
             event_sender: None,
             background_coordinator: None,
+            route_cache_watcher: None,
+            session_recorder: None,
+            preferences: PreferencesStore::in_memory(Preferences::default()),
+            data_loader: DataLoader::new(),
+            activity: ActivityTracker::new(DEFAULT_IDLE_TIMEOUT, tokio::time::Instant::now()),
+            idle: false,
+        }
+    }
+
+    /// Use `store` for preferences and show them
+    pub fn set_preferences(&mut self, store: PreferencesStore) {
+        self.preferences = store;
+        self.apply_preferences();
+    }
+
+    /// Current preferences
+    pub fn preferences(&self) -> &Preferences {
+        self.preferences.get()
+    }
+
+    /// Show the current preferences in the settings and swap screens, and read
+    /// amounts with the preferred separators
+    fn apply_preferences(&mut self) {
+        let preferences = self.preferences.get().clone();
+        self.state.settings_state.load_preferences(&preferences);
+        let locale = preferences.number_locale();
+        let swap = &mut self.state.swap_screen_state;
+        let liquidity = &mut self.state.liquidity_screen_state;
+        let multihop = &mut self.state.multihop_screen_state;
+        for input in [
+            &mut swap.from_amount_input,
+            &mut swap.slippage_input,
+            &mut liquidity.first_asset_input,
+            &mut liquidity.second_asset_input,
+            &mut liquidity.slippage_input,
+            &mut liquidity.withdraw_amount_input,
+            &mut multihop.amount_input,
+            &mut multihop.slippage_input,
+        ] {
+            input.set_locale(locale);
+        }
+        if let Some(slippage) = preferences.default_slippage {
+            let percent = slippage * cosmwasm_std::Decimal::percent(10_000);
+            self.state
+                .swap_screen_state
+                .slippage_input
+                .set_value(&percent.to_string());
+        }
+    }
+
+    /// Add the highlighted pool to the favorites, or remove it
+    fn toggle_highlighted_favorite(&mut self) {
+        let Some(pool_id) = self
+            .state
+            .pools_screen_state
+            .highlighted_pool()
+            .map(|pool| pool.pool_id.clone())
+        else {
+            return;
+        };
+        let mut favorite = false;
+        match self
+            .preferences
+            .update(|preferences| favorite = preferences.toggle_favorite(&pool_id))
+        {
+            Ok(_) if favorite => self.set_status(format!("Pool {} added to favorites", pool_id)),
+            Ok(_) => self.set_status(format!("Pool {} removed from favorites", pool_id)),
+            Err(e) => self.set_error(format!("Failed to save favorites: {}", e)),
+        }
+    }
+
+    /// Mark the highlighted pool for comparison, or unmark it
+    fn toggle_highlighted_compare(&mut self) {
+        match self.state.pools_screen_state.toggle_compare_highlighted() {
+            Some((pool_id, true)) => {
+                self.set_status(format!("Pool {} marked for comparison", pool_id))
+            }
+            Some((pool_id, false)) => {
+                self.set_status(format!("Pool {} no longer compared", pool_id))
+            }
+            None => {}
+        }
+    }
+
+    /// Open the comparison of the marked pools, or close it
+    ///
+    /// The figures are fetched in the background and arrive as
+    /// [`DataEvent::PoolsCompared`].
+    fn toggle_pool_comparison(&mut self) {
+        if self.state.pools_screen_state.comparison.take().is_some() {
+            return;
+        }
+        let pool_ids = self.state.pools_screen_state.compare_selection.clone();
+        if pool_ids.len() < 2 {
+            self.set_error("Mark at least two pools with 'c' to compare them".to_string());
+            return;
+        }
+        let Some(sender) = self.event_sender.clone() else {
+            return;
+        };
+        self.state.pools_screen_state.comparison = Some(ComparisonView::Loading);
+
+        let client = Arc::clone(&self.client);
+        let quote = self
+            .preferences()
+            .display_denom
+            .clone()
+            .unwrap_or_else(|| crate::convert::USD.to_string());
+        let address = self.state.wallet_address.clone();
+        tokio::spawn(async move {
+            let result = client
+                .compare_pools(
+                    &pool_ids,
+                    &quote,
+                    address.as_deref(),
+                    crate::earn::DEFAULT_LOOKBACK_BLOCKS,
+                )
+                .await
+                .map_err(|e| e.to_string());
+            let _ = sender.send(DataEvent::PoolsCompared { result });
+        });
+    }
+
+    /// Whether execute actions apply to the paper portfolio instead of the chain
+    pub fn is_paper_trading(&self) -> bool {
+        self.state.paper_portfolio.is_some()
+    }
+
+    /// Turn paper trading on or off
+    ///
+    /// The portfolio is kept in [`PaperPortfolio::default_path`] between
+    /// sessions; the first one starts from the wallet's balances.
+    pub fn toggle_paper_trading(&mut self) {
+        if self.state.paper_portfolio.take().is_some() {
+            self.state.paper_valuation = None;
+            self.set_status("Paper trading off: actions execute on chain again".to_string());
+            return;
+        }
+
+        let path = PaperPortfolio::default_path();
+        let portfolio = if path.exists() {
+            match PaperPortfolio::load(&path) {
+                Ok(portfolio) => portfolio,
+                Err(e) => {
+                    self.set_error(format!("Failed to load the paper portfolio: {}", e));
+                    return;
+                }
+            }
+        } else {
+            let balances = self
+                .state
+                .balances
+                .iter()
+                .filter_map(|(denom, amount)| {
+                    Some(cosmwasm_std::Coin {
+                        denom: denom.clone(),
+                        amount: amount.parse().ok()?,
+                    })
+                })
+                .filter(|coin| !coin.amount.is_zero())
+                .collect();
+            PaperPortfolio::new(balances)
+        };
+        self.state.paper_portfolio = Some(portfolio);
+        self.value_paper_portfolio();
+        self.set_status(
+            "Paper trading on: swaps and liquidity are simulated at live prices (Ctrl+P to stop)"
+                .to_string(),
+        );
+    }
+
+    /// Value the paper portfolio in the background
+    ///
+    /// The result arrives as [`DataEvent::PaperPortfolioValued`].
+    fn value_paper_portfolio(&self) {
+        let (Some(portfolio), Some(sender)) = (
+            self.state.paper_portfolio.clone(),
+            self.event_sender.clone(),
+        ) else {
+            return;
+        };
+        let client = Arc::clone(&self.client);
+        let quote = self
+            .preferences()
+            .display_denom
+            .clone()
+            .unwrap_or_else(|| crate::convert::USD.to_string());
+        tokio::spawn(async move {
+            let result = client
+                .paper_valuation(&portfolio, &quote)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = sender.send(DataEvent::PaperPortfolioValued { result });
+        });
+    }
+
+    /// Apply a transaction request to the paper portfolio
+    ///
+    /// Swaps and liquidity changes are priced at live pool state; other
+    /// transactions are refused. Returns `false` for events that aren't
+    /// transaction requests.
+    async fn handle_paper_tx_event(&mut self, event: &TxEvent) -> bool {
+        let Some(mut portfolio) = self.state.paper_portfolio.take() else {
+            return false;
+        };
+        let result = match event {
+            TxEvent::ExecuteSwap {
+                from_asset,
+                to_asset,
+                amount,
+                pool_id,
+                ..
+            } => {
+                self.paper_swap(
+                    &mut portfolio,
+                    from_asset,
+                    to_asset,
+                    amount,
+                    pool_id.as_deref(),
+                )
+                .await
+            }
+            TxEvent::ProvideLiquidity {
+                pool_id,
+                asset_1_amount,
+                asset_2_amount,
+                ..
+            } => {
+                self.paper_provide_liquidity(
+                    &mut portfolio,
+                    pool_id,
+                    asset_1_amount,
+                    asset_2_amount,
+                )
+                .await
+            }
+            TxEvent::WithdrawLiquidity {
+                pool_id,
+                lp_token_amount,
+                ..
+            } => match lp_token_amount.parse() {
+                Ok(lp_amount) => {
+                    self.client
+                        .paper_withdraw_liquidity(&mut portfolio, pool_id, lp_amount)
+                        .await
+                }
+                Err(e) => Err(Error::Other(format!("Invalid LP token amount: {}", e))),
+            },
+            TxEvent::ClaimRewards { .. }
+            | TxEvent::RetrySwapWithSlippageBump
+            | TxEvent::ExecuteMultiHopSwap { .. }
+            | TxEvent::CreatePool { .. }
+            | TxEvent::UpdatePoolFeatures { .. } => Err(Error::Other(
+                "Only swaps and liquidity can be paper traded; press Ctrl+P to trade for real"
+                    .to_string(),
+            )),
+            _ => {
+                self.state.paper_portfolio = Some(portfolio);
+                return false;
+            }
+        };
+
+        match result {
+            Ok(trade) => {
+                if let Err(e) = portfolio.save(&PaperPortfolio::default_path()) {
+                    crate::tui::utils::logger::log_warning(&format!(
+                        "Failed to save the paper portfolio: {}",
+                        e
+                    ));
+                }
+                self.state.paper_portfolio = Some(portfolio);
+                self.record_paper_trade(&trade);
+                self.value_paper_portfolio();
+            }
+            Err(e) => {
+                self.state.paper_portfolio = Some(portfolio);
+                self.set_error(format!("Paper trade failed: {}", e));
+            }
+        }
+        true
+    }
+
+    async fn paper_swap(
+        &self,
+        portfolio: &mut PaperPortfolio,
+        from_asset: &str,
+        to_asset: &str,
+        amount: &str,
+        pool_id: Option<&str>,
+    ) -> Result<PaperTrade, Error> {
+        let pool_id =
+            pool_id.ok_or_else(|| Error::Other("No pool selected for swap".to_string()))?;
+        let pool = &self
+            .state
+            .pool_cache
+            .get(pool_id)
+            .ok_or_else(|| Error::Other(format!("Pool {} is not loaded", pool_id)))?
+            .pool_info;
+        let from_denom = self.map_display_name_to_denom(from_asset, &pool.pool_info.assets);
+        let to_denom = self.map_display_name_to_denom(to_asset, &pool.pool_info.assets);
+        let offer_asset = cosmwasm_std::Coin {
+            amount: self.parse_paper_amount(amount, &from_denom)?,
+            denom: from_denom,
+        };
+        self.client
+            .paper_swap(portfolio, pool_id, offer_asset, &to_denom)
+            .await
+    }
+
+    async fn paper_provide_liquidity(
+        &self,
+        portfolio: &mut PaperPortfolio,
+        pool_id: &str,
+        asset_1_amount: &str,
+        asset_2_amount: &str,
+    ) -> Result<PaperTrade, Error> {
+        let pool = self.client.get_pool(pool_id).await?;
+        let [asset_1, asset_2, ..] = pool.pool_info.assets.as_slice() else {
+            return Err(Error::Other(format!(
+                "Pool {} has fewer than two assets",
+                pool_id
+            )));
+        };
+        let assets = [
+            cosmwasm_std::Coin {
+                denom: asset_1.denom.clone(),
+                amount: self.parse_paper_amount(asset_1_amount, &asset_1.denom)?,
+            },
+            cosmwasm_std::Coin {
+                denom: asset_2.denom.clone(),
+                amount: self.parse_paper_amount(asset_2_amount, &asset_2.denom)?,
+            },
+        ];
+        portfolio.provide_liquidity(&pool, &assets).cloned()
+    }
+
+    /// Base units of an amount entered in the forms, which send it in canonical form
+    fn parse_paper_amount(&self, amount: &str, denom: &str) -> Result<Uint128, Error> {
+        crate::amount::parse_base_units(
+            amount,
+            self.get_token_decimals(denom).into(),
+            crate::amount::NumberLocale::Point,
+        )
+    }
+
+    /// Show a paper trade and add it to the transaction history
+    fn record_paper_trade(&mut self, trade: &PaperTrade) {
+        let coins = |coins: &[cosmwasm_std::Coin]| {
+            coins
+                .iter()
+                .map(|coin| self.format_token_display(&coin.amount.to_string(), &coin.denom))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let summary = format!(
+            "Paper {}: spent {}, received {}",
+            trade.kind,
+            coins(&trade.spent),
+            coins(&trade.received)
+        );
+        self.add_transaction(TransactionInfo {
+            hash: format!("paper-{}", trade.timestamp.timestamp_millis()),
+            status: TransactionStatus::Success,
+            operation_type: format!("Paper {}", trade.kind),
+            timestamp: trade.timestamp,
+            gas_used: None,
+            gas_wanted: None,
+            retry_of: None,
+        });
+        self.state.loading_state = LoadingState::Idle;
+        self.set_status(summary);
+    }
+
+    /// Start recording user input and resulting state transitions to `path`
+    pub fn enable_session_recording(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        let recorder =
+            crate::tui::utils::session::SessionRecorder::create(path, &self.config.network_name)?;
+        self.session_recorder = Some(recorder);
+        Ok(())
+    }
+
+    /// Whether a session recording is in progress
+    pub fn is_recording_session(&self) -> bool {
+        self.session_recorder.is_some()
+    }
+
+    /// Whether keystrokes are currently going into a password or mnemonic field
+    ///
+    /// Asks whatever receives typed keys first, in dispatch order, whether its
+    /// focused input is masked.
+    pub fn is_sensitive_input_active(&self) -> bool {
+        if let Some(modal) = &self.state.modal_state {
+            if modal.is_sensitive_input_focused() {
+                return true;
+            }
+        }
+        if self.state.wizard_state.show_wizard {
+            return self.state.wizard_state.is_sensitive_input_focused();
+        }
+        match self.state.current_screen {
+            Screen::Settings => self.state.settings_state.is_sensitive_input_focused(),
+            Screen::WalletSelection => self
+                .state
+                .wallet_selection_state
+                .is_sensitive_input_focused(),
+            _ => false,
+        }
+    }
+
+    /// Capture the parts of the state that matter when replaying a session
+    pub fn state_snapshot(&self) -> crate::tui::utils::session::StateSnapshot {
+        let wizard = &self.state.wizard_state;
+        crate::tui::utils::session::StateSnapshot {
+            screen: self.state.current_screen.display_name().to_string(),
+            navigation_mode: format!("{:?}", self.state.navigation_mode),
+            wizard_step: wizard
+                .show_wizard
+                .then(|| wizard.current_step.title().to_string()),
+            modal_open: self.state.modal_state.is_some(),
+            status_message: self.state.status_message.clone(),
+            error_message: self.state.error_message.clone(),
         }
     }
 
     /// Initialize background tasks for data synchronization with enhanced coordination
-    pub fn initialize_background_tasks(&mut self, event_sender: mpsc::UnboundedSender<Event>) {
+    pub fn initialize_background_tasks(&mut self, event_sender: EventBus) {
         // Create enhanced background task coordinator
         let client_arc = Arc::clone(&self.client);
         let mut coordinator = crate::tui::utils::async_ops::BackgroundTaskCoordinator::new(
@@ -492,9 +1085,114 @@ impl App {
             coordinator.set_wallet_address(wallet_address.clone());
         }
 
+        // Prioritize the data shown on the current screen
+        coordinator.set_active_screen(self.state.current_screen);
+        coordinator.set_idle(self.idle);
+
         // Start background coordination
         coordinator.start();
 
+        // Forward RPC endpoint circuit changes to the network indicator
+        let mut circuit_events = self.client.circuit_breaker().subscribe();
+        let circuit_sender = event_sender.clone();
+        tokio::spawn(async move {
+            loop {
+                match circuit_events.recv().await {
+                    Ok(event) => {
+                        let change = DataEvent::CircuitStateChanged {
+                            endpoint: event.endpoint,
+                            state: event.to,
+                        };
+                        if circuit_sender.send(change).is_err() {
+                            break;
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        // Measure RPC endpoint latency so requests go to the fastest healthy endpoint,
+        // until the client is replaced
+        let probe_client = Arc::downgrade(&self.client);
+        let probe_sender = event_sender.clone();
+        let probe_interval = self.config.endpoint_selection.probe_interval();
+        tokio::spawn(async move {
+            while let Some(client) = probe_client.upgrade() {
+                let endpoints = client.probe_endpoints().await;
+                drop(client);
+                if probe_sender
+                    .send(DataEvent::EndpointsProbed { endpoints })
+                    .is_err()
+                {
+                    break;
+                }
+                tokio::time::sleep(probe_interval).await;
+            }
+        });
+
+        // Drop cached routes through pools that moved, reporting the dropped ones.
+        // A failed check is retried at the next interval.
+        if self.client.route_cache().is_some() {
+            let client = Arc::clone(&self.client);
+            let cache_sender = event_sender.clone();
+            self.route_cache_watcher = Some(tokio::spawn(async move {
+                client
+                    .watch_route_cache(|check| match check {
+                        Ok(dropped) if dropped > 0 => cache_sender
+                            .send(DataEvent::RoutesInvalidated { dropped })
+                            .map_err(|e| Error::Other(e.to_string())),
+                        _ => Ok(()),
+                    })
+                    .await
+            }));
+        }
+
+        // Drive loading indicators from the stages of in-flight transactions
+        let mut tx_progress = self.client.subscribe_tx_progress();
+        let progress_sender = event_sender.clone();
+        tokio::spawn(async move {
+            loop {
+                match tx_progress.recv().await {
+                    Ok(update) => {
+                        let progress = TxEvent::BlockchainProgress {
+                            operation: "Transaction".to_string(),
+                            status: update.stage.description().to_string(),
+                            progress: Some(update.stage.fraction()),
+                        };
+                        if progress_sender.send(progress).is_err() {
+                            break;
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        // Pick up preferences changed by the CLI or another TUI
+        if let Some(document) = self.preferences.document().cloned() {
+            let preferences_sender = event_sender.clone();
+            tokio::spawn(async move {
+                let mut modified = document.modified();
+                loop {
+                    tokio::time::sleep(PREFERENCES_POLL_INTERVAL).await;
+                    let current = document.modified();
+                    if current == modified {
+                        continue;
+                    }
+                    modified = current;
+                    if preferences_sender
+                        .send(DataEvent::PreferencesFileChanged)
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+        }
+
         self.background_coordinator = Some(coordinator);
         self.event_sender = Some(event_sender);
     }
@@ -504,15 +1202,131 @@ impl App {
         if let Some(mut coordinator) = self.background_coordinator.take() {
             coordinator.stop();
         }
+        if let Some(watcher) = self.route_cache_watcher.take() {
+            watcher.abort();
+        }
+    }
+
+    /// Prioritize background refreshes for the data shown on `screen`
+    fn reprioritize_background_tasks(&mut self, screen: Screen) {
+        if let Some(coordinator) = &mut self.background_coordinator {
+            coordinator.set_active_screen(screen);
+        }
+    }
+
+    /// Screen cached pools that have no risk report yet, in the background
+    ///
+    /// Each pool is screened once per session; reports arrive as
+    /// [`DataEvent::PoolRisksAssessed`].
+    fn screen_pool_risks(&mut self) {
+        let Some(sender) = self.event_sender.clone() else {
+            return;
+        };
+        let pools: Vec<PoolInfoResponse> = self
+            .state
+            .pool_cache
+            .iter()
+            .filter(|(pool_id, _)| {
+                !self.state.pool_risks.contains_key(*pool_id)
+                    && !self.state.pool_risks_pending.contains(*pool_id)
+            })
+            .map(|(_, entry)| entry.pool_info.clone())
+            .collect();
+        if pools.is_empty() {
+            return;
+        }
+        self.state.pool_risks_pending.extend(
+            pools
+                .iter()
+                .map(|pool| pool.pool_info.pool_identifier.clone()),
+        );
+
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let reports = client
+                .assess_pools_risk(
+                    &pools,
+                    &RiskThresholds::default(),
+                    RISK_SCREENING_CONCURRENCY,
+                )
+                .await;
+            let reports = pools
+                .into_iter()
+                .map(|pool| pool.pool_info.pool_identifier)
+                .zip(reports)
+                .collect();
+            let _ = sender.send(DataEvent::PoolRisksAssessed { reports });
+        });
+    }
+
+    /// Look up the ownership of a cached pool in the background, once per session
+    ///
+    /// The result arrives as [`DataEvent::PoolOwnershipLoaded`].
+    fn load_pool_ownership(&mut self, pool_id: &str) {
+        let Some(sender) = self.event_sender.clone() else {
+            return;
+        };
+        if self.state.pool_ownership.contains_key(pool_id)
+            || self.state.pool_ownership_pending.contains(pool_id)
+        {
+            return;
+        }
+        let Some(pool) = self.get_cached_pool(pool_id).cloned() else {
+            return;
+        };
+        self.state
+            .pool_ownership_pending
+            .insert(pool_id.to_string());
+
+        let client = Arc::clone(&self.client);
+        tokio::spawn(async move {
+            let ownership = client.get_pool_ownership(&pool).await;
+            let _ = sender.send(DataEvent::PoolOwnershipLoaded { ownership });
+        });
     }
 
     /// Update sync configuration
     pub fn update_sync_config(&mut self, config: crate::tui::utils::async_ops::SyncConfig) {
+        self.activity.set_idle_timeout(config.idle_timeout);
         if let Some(coordinator) = &mut self.background_coordinator {
             coordinator.update_config(config);
         }
     }
 
+    /// How long the event loop may wait before redrawing
+    ///
+    /// Call once per loop iteration: no event reports that input stopped, so this
+    /// is where the TUI notices it went idle and pauses background refreshes.
+    pub fn tick_rate(&mut self) -> Duration {
+        let now = tokio::time::Instant::now();
+        self.sync_idle_state(now);
+        self.activity.tick_rate(now)
+    }
+
+    /// Change the time without input before background refreshes pause
+    ///
+    /// Zero keeps them running until the terminal loses focus.
+    pub fn set_idle_timeout(&mut self, idle_timeout: Duration) {
+        self.activity.set_idle_timeout(idle_timeout);
+    }
+
+    /// Whether the TUI is idle, as of the last event or tick
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Tell background tasks when the TUI goes idle or becomes active again
+    fn sync_idle_state(&mut self, now: tokio::time::Instant) {
+        let idle = self.activity.is_idle(now);
+        if idle == self.idle {
+            return;
+        }
+        self.idle = idle;
+        if let Some(coordinator) = &mut self.background_coordinator {
+            coordinator.set_idle(idle);
+        }
+    }
+
     /// Check if real-time synchronization is active
     pub fn is_real_time_sync_active(&self) -> bool {
         self.background_coordinator
@@ -531,14 +1345,17 @@ impl App {
     }
 
     /// Get event sender for internal use
-    pub fn get_event_sender(&self) -> Option<&mpsc::UnboundedSender<Event>> {
+    pub fn get_event_sender(&self) -> Option<&EventBus> {
         self.event_sender.as_ref()
     }
 
     /// Execute async operation with comprehensive error handling
+    ///
+    /// The operation is bounded by the client's configured timeout for `class`.
     pub async fn execute_async_operation<F, Fut, T>(
         &mut self,
         operation_name: &str,
+        class: OperationClass,
         operation: F,
     ) -> Result<T, Error>
     where
@@ -546,7 +1363,7 @@ impl App {
         Fut: std::future::Future<Output = Result<T, Error>>,
     {
         // Set loading state
-        self.set_loading_with_progress(format!("Executing {}...", operation_name), Some(0.1), true);
+        self.set_loading_with_progress(format!("Executing {}...", operation_name), None, true);
 
         let start_time = std::time::Instant::now();
 
@@ -575,15 +1392,17 @@ impl App {
             _ => {}
         }
 
-        // Update progress
-        self.update_loading_progress(25.0, Some(format!("Executing {}...", operation_name)));
-
-        // Execute the operation with timeout
-        let result = tokio::time::timeout(
-            std::time::Duration::from_secs(30), // 30 second timeout
-            operation(),
-        )
-        .await;
+        // Execute the operation with the configured timeout for its class
+        let timeouts = self.client.config().timeouts;
+        let timeout = match class {
+            // Transactions are broadcast and then waited on until included
+            OperationClass::Broadcast => {
+                timeouts.for_class(OperationClass::Broadcast)
+                    + timeouts.for_class(OperationClass::Inclusion)
+            }
+            class => timeouts.for_class(class),
+        };
+        let result = tokio::time::timeout(timeout, operation()).await;
 
         match result {
             Ok(Ok(value)) => {
@@ -621,13 +1440,17 @@ impl App {
                 Err(e)
             }
             Err(_) => {
-                let error_msg = format!("{} timed out after 30 seconds", operation_name);
+                let error_msg = format!(
+                    "{} timed out after {} seconds",
+                    operation_name,
+                    timeout.as_secs()
+                );
                 self.set_error_with_retry(
                     error_msg.clone(),
                     ErrorType::Timeout,
                     "retry_operation".to_string(),
                 );
-                Err(Error::Rpc(error_msg))
+                Err(Error::Timeout(error_msg))
             }
         }
     }
@@ -692,74 +1515,214 @@ impl App {
         }
     }
 
-    /// Handle async blockchain operations with comprehensive status updates
+    /// Handle an event, recording it first if session recording is enabled
     pub async fn handle_event(&mut self, event: Event) -> Result<bool, Error> {
-        // Handle network state changes
-        if let Event::Custom(ref custom_event) = event {
-            if custom_event.starts_with("network_state_changed:") {
-                let parts: Vec<&str> = custom_event.split(':').collect();
-                if parts.len() >= 3 {
-                    let new_state = parts[2];
-                    match new_state {
-                        "connected" => {
-                            self.state.network_info.connection_state =
-                                crate::tui::utils::async_ops::NetworkState::Connected;
-                            self.set_status("Network connection restored".to_string());
-                        }
-                        "disconnected" => {
-                            self.state.network_info.connection_state =
-                                crate::tui::utils::async_ops::NetworkState::Disconnected;
-                            self.set_error_with_type(
-                                "Network disconnected. Some features may be unavailable."
-                                    .to_string(),
-                                ErrorType::Network,
-                            );
-                        }
-                        "error" => {
-                            self.state.network_info.connection_state =
-                                crate::tui::utils::async_ops::NetworkState::Error(
-                                    "Network error".to_string(),
-                                );
-                            self.set_error_with_retry(
-                                "Network error detected. Attempting to reconnect...".to_string(),
-                                ErrorType::Network,
-                                "retry_connection".to_string(),
-                            );
-                        }
-                        _ => {}
-                    }
-                }
+        if let Event::Ui(ui_event) = &event {
+            let now = tokio::time::Instant::now();
+            match ui_event {
+                UiEvent::TerminalFocus(focused) => self.activity.set_focused(*focused, now),
+                _ => self.activity.record_input(now),
+            }
+            self.sync_idle_state(now);
+            if let UiEvent::TerminalFocus(_) = ui_event {
                 return Ok(false);
             }
         }
 
-        // Handle blockchain progress events
-        if let Event::BlockchainProgress {
-            operation,
-            status,
-            progress,
-        } = &event
-        {
-            self.update_loading_progress(
-                progress.unwrap_or(0.0) as f64 * 100.0,
-                Some(format!("{}: {}", operation, status)),
-            );
-            return Ok(false);
-        }
+        let recorded_input = match self.session_recorder {
+            Some(_) => crate::tui::utils::session::RecordedInput::from_event(
+                &event,
+                self.is_sensitive_input_active(),
+            ),
+            None => None,
+        };
 
-        // Handle blockchain success events
-        if let Event::BlockchainSuccess {
-            operation,
-            result,
-            transaction_hash,
-            enhanced_data,
-        } = &event
-        {
-            // Clear loading state first
-            self.state.loading_state = LoadingState::Idle;
+        let result = self.dispatch_event(event).await;
 
-            // Create operation-specific success titles and details
-            let (success_title, formatted_details) = match operation.as_str() {
+        if let Some(input) = recorded_input {
+            let snapshot = self.state_snapshot();
+            if let Some(recorder) = self.session_recorder.as_mut() {
+                if let Err(e) = recorder.record(input, snapshot) {
+                    crate::tui::utils::logger::log_warning(&format!(
+                        "Session recording stopped: {}",
+                        e
+                    ));
+                    self.session_recorder = None;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Route an event to the handler for its domain
+    async fn dispatch_event(&mut self, event: Event) -> Result<bool, Error> {
+        match event {
+            Event::Ui(event) => self.handle_ui_event(event).await,
+            Event::Data(event) => self.handle_data_event(event).await,
+            Event::Tx(event) => self.handle_tx_event(event).await,
+        }
+    }
+
+    /// Handle background data refreshes and connectivity changes
+    async fn handle_data_event(&mut self, event: DataEvent) -> Result<bool, Error> {
+        match event {
+            DataEvent::NetworkStateChanged { to, .. } => match to {
+                NetworkState::Connected => {
+                    self.state.network_info.connection_state = NetworkState::Connected;
+                    self.set_status("Network connection restored".to_string());
+                }
+                NetworkState::Disconnected => {
+                    self.state.network_info.connection_state = NetworkState::Disconnected;
+                    self.set_error_with_type(
+                        "Network disconnected. Some features may be unavailable.".to_string(),
+                        ErrorType::Network,
+                    );
+                }
+                NetworkState::Error(_) => {
+                    self.state.network_info.connection_state =
+                        NetworkState::Error("Network error".to_string());
+                    self.set_error_with_retry(
+                        "Network error detected. Attempting to reconnect...".to_string(),
+                        ErrorType::Network,
+                        "retry_connection".to_string(),
+                    );
+                }
+                NetworkState::Reconnecting => {}
+            },
+            DataEvent::CircuitStateChanged { endpoint, state } => {
+                let failing = &mut self.state.network_info.failing_endpoints;
+                failing.retain(|failing_endpoint| *failing_endpoint != endpoint);
+                match state {
+                    CircuitState::Open => {
+                        failing.push(endpoint.clone());
+                        self.set_status(format!(
+                            "RPC endpoint {} is failing, using fallback endpoints",
+                            endpoint
+                        ));
+                    }
+                    CircuitState::Closed => {
+                        self.set_status(format!("RPC endpoint {} recovered", endpoint));
+                    }
+                    CircuitState::HalfOpen => {}
+                }
+                // Requests may have moved to another endpoint
+                self.state.network_info.endpoints = self.client.circuit_breaker().health();
+            }
+            DataEvent::EndpointsProbed { endpoints } => {
+                self.state.network_info.endpoints = endpoints;
+            }
+            DataEvent::RoutesInvalidated { dropped } => {
+                self.set_status(format!(
+                    "Dropped {} cached route(s) through pools that moved",
+                    dropped
+                ));
+            }
+            DataEvent::PoolRisksAssessed { reports } => {
+                for (pool_id, report) in reports {
+                    self.state.pool_risks_pending.remove(&pool_id);
+                    self.state.pool_risks.insert(pool_id, report);
+                }
+            }
+            DataEvent::PoolOwnershipLoaded { ownership } => {
+                self.state.pool_ownership_pending.remove(&ownership.pool_id);
+                self.state
+                    .pool_ownership
+                    .insert(ownership.pool_id.clone(), ownership);
+            }
+            DataEvent::PoolsCompared { result } => {
+                // Ignore a comparison that was closed while it loaded
+                if self.state.pools_screen_state.comparison.is_some() {
+                    self.state.pools_screen_state.comparison = Some(match result {
+                        Ok(comparisons) => ComparisonView::Loaded(comparisons),
+                        Err(e) => ComparisonView::Failed(e),
+                    });
+                }
+            }
+            DataEvent::PaperPortfolioValued { result } => match result {
+                // Ignore a valuation that arrives after paper trading was turned off
+                Ok(valuation) if self.is_paper_trading() => {
+                    self.state.paper_valuation = Some(valuation);
+                }
+                Ok(_) => {}
+                Err(e) => crate::tui::utils::logger::log_warning(&format!(
+                    "Failed to value the paper portfolio: {}",
+                    e
+                )),
+            },
+            DataEvent::PreferencesFileChanged => match self.preferences.reload() {
+                Ok(true) => {
+                    self.apply_preferences();
+                    self.set_status("Preferences updated from another session".to_string());
+                }
+                Ok(false) => {}
+                Err(e) => self.set_error(format!("Failed to reload preferences: {}", e)),
+            },
+            DataEvent::DataRefresh {
+                data_type,
+                success,
+                error,
+            } => {
+                let result = self
+                    .handle_data_refresh(data_type.clone(), success, error)
+                    .await;
+                if let Some(key) = DataKey::from_data_type(&data_type) {
+                    self.data_loader.finish(
+                        key,
+                        tokio::time::Instant::now(),
+                        success && result.is_ok(),
+                    );
+                    // Push the revalidated data to the screen showing it
+                    let screen = self.state.current_screen;
+                    if DataKey::for_screen(screen).contains(&key) {
+                        self.show_cached_data(screen);
+                    }
+                }
+                // Report refresh failures with the data type that failed
+                if let Err(e) = result {
+                    self.set_error_with_type(
+                        format!("Failed to refresh {}: {}", data_type, e),
+                        ErrorType::Unknown,
+                    );
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Handle transaction requests and their progress
+    async fn handle_tx_event(&mut self, event: TxEvent) -> Result<bool, Error> {
+        // Handle blockchain progress events
+        if let TxEvent::BlockchainProgress {
+            operation,
+            status,
+            progress,
+        } = &event
+        {
+            let message = format!("{}: {}", operation, status);
+            match progress {
+                Some(progress) => {
+                    self.update_loading_progress(*progress as f64 * 100.0, Some(message))
+                }
+                // Only the status changed; keep the last reported progress
+                None => self.update_loading_message(message),
+            }
+            return Ok(false);
+        }
+
+        // Handle blockchain success events
+        if let TxEvent::BlockchainSuccess {
+            operation,
+            result,
+            transaction_hash,
+            enhanced_data,
+        } = &event
+        {
+            // Clear loading state first
+            self.state.loading_state = LoadingState::Idle;
+
+            // Create operation-specific success titles and details
+            let (success_title, formatted_details) = match operation.as_str() {
                 "provide_liquidity" => {
                     let title = "Liquidity Provided Successfully!".to_string();
                     let details = self.create_liquidity_success_details(
@@ -781,7 +1744,8 @@ impl App {
                 }
                 "claim_rewards" => {
                     let title = "Rewards Claimed Successfully!".to_string();
-                    let details = self.create_basic_success_details(result, transaction_hash);
+                    let details =
+                        self.create_claim_success_details(result, transaction_hash, enhanced_data);
                     (title, details)
                 }
                 "create_pool" => {
@@ -821,7 +1785,7 @@ impl App {
         }
 
         // Handle blockchain error events
-        if let Event::BlockchainError { operation, error } = &event {
+        if let TxEvent::BlockchainError { operation, error } = &event {
             // Clear the loading state first
             self.state.loading_state = LoadingState::Idle;
 
@@ -856,20 +1820,26 @@ impl App {
                 _ => &format!("{} Failed", operation.replace('_', " ")),
             };
 
+            let diagnosis = Diagnosis::from_message(error);
             self.state.modal_state = Some(crate::tui::components::modals::ModalState::error(
                 operation_title.to_string(),
                 user_friendly_error.clone(),
                 error_type,
                 Some(vec![
                     format!("Operation: {}", operation),
+                    format!("Likely Cause: {}", diagnosis.cause),
                     format!("Error Details: {}", error),
                     format!(
                         "Timestamp: {}",
                         chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
                     ),
                 ]),
-                Some("retry_operation".to_string()),
+                Some(DIAGNOSE_ACTION.to_string()),
             ));
+            self.state.failed_transaction = Some(FailedTransaction {
+                operation: operation.clone(),
+                diagnosis,
+            });
 
             crate::tui::utils::logger::log_info(&format!(
                 "Blockchain error modal displayed for operation: {}",
@@ -879,42 +1849,14 @@ impl App {
             return Ok(false);
         }
 
-        // Handle data refresh events with enhanced error reporting
-        if let Event::DataRefresh {
-            data_type,
-            success,
-            error,
-        } = &event
-        {
-            match self
-                .handle_data_refresh(data_type.clone(), *success, error.clone())
-                .await
-            {
-                Ok(_) => {}
-                Err(e) => {
-                    self.set_error_with_type(
-                        format!("Failed to refresh {}: {}", data_type, e),
-                        ErrorType::Unknown,
-                    );
-                }
-            }
-            return Ok(false);
-        }
-
-        // Handle retry with increased slippage event
-        if let Event::RetryWithIncreasedSlippage = &event {
-            if let Err(e) = self.handle_slippage_retry().await {
-                crate::tui::utils::logger::log_error(&format!(
-                    "Failed to handle slippage retry: {}",
-                    e
-                ));
-            }
+        // While paper trading, execute actions never reach the chain
+        if self.is_paper_trading() && self.handle_paper_tx_event(&event).await {
             return Ok(false);
         }
 
         // Handle blockchain action events with comprehensive async processing
         match &event {
-            Event::ExecuteSwap {
+            TxEvent::ExecuteSwap {
                 from_asset,
                 to_asset,
                 amount,
@@ -930,7 +1872,7 @@ impl App {
                 // Execute the swap asynchronously by sending it to the background
                 if let Some(sender) = &self.event_sender {
                     // Send the swap execution to be handled in the background
-                    let _ = sender.send(Event::ExecuteSwapAsync {
+                    let _ = sender.send(TxEvent::ExecuteSwapAsync {
                         from_asset: from_asset.clone(),
                         to_asset: to_asset.clone(),
                         amount: amount.clone(),
@@ -940,7 +1882,7 @@ impl App {
                 }
                 return Ok(false);
             }
-            Event::ExecuteSwapAsync {
+            TxEvent::ExecuteSwapAsync {
                 from_asset,
                 to_asset,
                 amount,
@@ -958,7 +1900,11 @@ impl App {
                 .await?;
                 return Ok(false);
             }
-            Event::ProvideLiquidity {
+            TxEvent::RetrySwapWithSlippageBump => {
+                self.retry_swap_with_increased_slippage().await?;
+                return Ok(false);
+            }
+            TxEvent::ProvideLiquidity {
                 pool_id,
                 asset_1_amount,
                 asset_2_amount,
@@ -975,7 +1921,7 @@ impl App {
                 // Show loading modal for liquidity provision
                 self.set_loading_with_progress(
                     format!("Providing liquidity to pool {}", pool_id),
-                    Some(5.0),
+                    None,
                     true,
                 );
 
@@ -1014,7 +1960,7 @@ impl App {
 
                 return Ok(false);
             }
-            Event::WithdrawLiquidity {
+            TxEvent::WithdrawLiquidity {
                 pool_id,
                 lp_token_amount,
                 slippage_tolerance,
@@ -1030,7 +1976,7 @@ impl App {
                 // Show loading modal for liquidity withdrawal
                 self.set_loading_with_progress(
                     format!("Withdrawing liquidity from pool {}", pool_id),
-                    Some(5.0),
+                    None,
                     true,
                 );
 
@@ -1063,7 +2009,7 @@ impl App {
 
                 return Ok(false);
             }
-            Event::ClaimRewards {
+            TxEvent::ClaimRewards {
                 pool_id,
                 epochs,
                 claim_all,
@@ -1077,88 +2023,137 @@ impl App {
                     "Claiming rewards".to_string()
                 };
 
-                self.set_loading_with_progress(operation_description.clone(), Some(10.0), true);
+                self.set_loading_with_progress(operation_description, None, true);
+
+                // Use the async blockchain processor to execute the real transaction
+                if let Some(event_sender) = &self.event_sender {
+                    let blockchain_processor =
+                        crate::tui::events::AsyncBlockchainProcessor::with_client(
+                            event_sender.clone(),
+                            self.client.clone(),
+                        );
+
+                    let pool_id_clone = pool_id.clone();
+                    let epochs_clone = epochs.clone();
+                    let claim_all_clone = *claim_all;
 
-                let operation_name = "claim_rewards";
-                let pool_id_val = pool_id.clone();
-                let epochs_val = epochs.clone();
-                let claim_all_val = *claim_all;
+                    // Spawn the async operation
+                    tokio::spawn(async move {
+                        blockchain_processor
+                            .claim_rewards(pool_id_clone, epochs_clone, claim_all_clone)
+                            .await;
+                    });
+                } else {
+                    crate::tui::utils::logger::log_error(
+                        "No event sender available for async blockchain operation",
+                    );
+                    self.set_error("Failed to initiate rewards claim: no event sender".to_string());
+                }
 
-                let result = self
-                    .execute_async_operation(operation_name, || async {
-                        // TODO: Implement actual rewards claiming
-                        // Simulate the process
-                        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-
-                        // For now, create a mock successful response
-                        // In real implementation, this would call self.client.claim_rewards()
-                        let mock_tx_hash = format!(
-                            "0x{:x}",
-                            std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .unwrap()
-                                .as_secs()
-                                + 1
+                return Ok(false);
+            }
+            TxEvent::CreatePool {
+                asset_1,
+                asset_2,
+                swap_fee,
+                exit_fee,
+                pool_features,
+            } => {
+                // Execute pool creation
+                self.set_loading_with_progress(
+                    format!("Creating pool for {} / {}", asset_1, asset_2),
+                    None,
+                    true,
+                );
+
+                // Use the async blockchain processor to execute the real transaction
+                if let Some(event_sender) = &self.event_sender {
+                    let blockchain_processor =
+                        crate::tui::events::AsyncBlockchainProcessor::with_client(
+                            event_sender.clone(),
+                            self.client.clone(),
                         );
 
-                        Ok(mock_tx_hash)
-                    })
-                    .await;
+                    let asset_1_clone = asset_1.clone();
+                    let asset_2_clone = asset_2.clone();
+                    let swap_fee_clone = swap_fee.clone();
+                    let exit_fee_clone = exit_fee.clone();
+                    let pool_features_clone = pool_features.clone();
 
-                match result {
-                    Ok(tx_hash) => {
-                        // Show success modal for rewards claiming
-                        let mut transaction_details = vec![
-                            ("Transaction Hash".to_string(), tx_hash.clone()),
-                            ("Operation Type".to_string(), "Claim Rewards".to_string()),
-                        ];
-
-                        if claim_all_val {
-                            transaction_details.push((
-                                "Claim Type".to_string(),
-                                "All Available Rewards".to_string(),
-                            ));
-                        } else if let Some(pool_id_val) = pool_id_val {
-                            transaction_details.push(("Pool ID".to_string(), pool_id_val));
-                        }
+                    // Spawn the async operation for pool creation
+                    tokio::spawn(async move {
+                        blockchain_processor
+                            .create_pool(
+                                asset_1_clone,
+                                asset_2_clone,
+                                swap_fee_clone,
+                                exit_fee_clone,
+                                pool_features_clone,
+                            )
+                            .await;
+                    });
+                } else {
+                    self.set_error("No event sender available for pool creation".to_string());
+                }
 
-                        if let Some(epochs_val) = epochs_val {
-                            let epochs_str = epochs_val
-                                .iter()
-                                .map(|e| e.to_string())
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            transaction_details.push(("Epochs".to_string(), epochs_str));
-                        }
+                return Ok(true);
+            }
+            TxEvent::UpdatePoolFeatures {
+                pool_id,
+                features,
+                enabled,
+            } => {
+                // Execute pool feature update
+                let operation_desc = if *enabled {
+                    format!("Enabling features for pool {}", pool_id)
+                } else {
+                    format!("Disabling features for pool {}", pool_id)
+                };
 
-                        transaction_details.extend(vec![
-                            ("Estimated Rewards".to_string(), "~0.5 OM".to_string()), // Mock value
-                            (
-                                "Status".to_string(),
-                                "✅ Completed Successfully".to_string(),
-                            ),
-                        ]);
+                self.set_loading_with_progress(operation_desc, None, true);
 
-                        self.state.modal_state = Some(
-                            crate::tui::components::modals::ModalState::transaction_details(
-                                tx_hash,
-                                "Rewards Claimed Successfully".to_string(),
-                                transaction_details,
-                            ),
+                // Use the async blockchain processor to execute the real transaction
+                if let Some(event_sender) = &self.event_sender {
+                    let blockchain_processor =
+                        crate::tui::events::AsyncBlockchainProcessor::with_client(
+                            event_sender.clone(),
+                            self.client.clone(),
                         );
-                    }
-                    Err(e) => {
-                        crate::tui::utils::logger::log_error(&format!(
-                            "Rewards claiming failed: {}",
-                            e
-                        ));
-                    }
+
+                    let pool_id_clone = pool_id.clone();
+                    let features_clone = features.clone();
+
+                    // Spawn the async operation for feature update
+                    tokio::spawn(async move {
+                        blockchain_processor
+                            .update_pool_features(pool_id_clone, features_clone)
+                            .await;
+                    });
+                } else {
+                    self.set_error("No event sender available for feature update".to_string());
                 }
-                return Ok(false);
+
+                return Ok(true);
             }
             _ => {}
         }
 
+        Ok(false)
+    }
+
+    /// Handle keyboard input and UI actions
+    async fn handle_ui_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        // Handle retry with increased slippage event
+        if let UiEvent::RetryWithIncreasedSlippage = &event {
+            if let Err(e) = self.handle_slippage_retry().await {
+                crate::tui::utils::logger::log_error(&format!(
+                    "Failed to handle slippage retry: {}",
+                    e
+                ));
+            }
+            return Ok(false);
+        }
+
         // Handle modal events FIRST - they take priority over everything else
         if self.state.modal_state.is_some() {
             if self.handle_modal_event(&event) {
@@ -1171,6 +2166,16 @@ impl App {
             return self.handle_wizard_event(event).await;
         }
 
+        // The onboarding tour takes the keys it uses while it runs
+        if self.state.tour.is_some() && self.handle_tour_event(&event) {
+            return Ok(true);
+        }
+
+        if event == UiEvent::Ctrl('p') {
+            self.toggle_paper_trading();
+            return Ok(true);
+        }
+
         // Handle focus management events
         let mut focus_handled = false;
 
@@ -1187,12 +2192,12 @@ impl App {
 
         // Handle standard navigation events
         match event {
-            Event::Quit => {
+            UiEvent::Quit => {
                 // Show quit confirmation modal instead of immediately quitting
                 self.show_quit_confirmation();
                 return Ok(true);
             }
-            Event::Tab => {
+            UiEvent::Tab => {
                 match self.state.navigation_mode {
                     NavigationMode::ScreenLevel => {
                         // Navigate to next screen tab
@@ -1201,14 +2206,14 @@ impl App {
                     NavigationMode::WithinScreen => {
                         // Handle within-screen tab navigation
                         if let Some(focused_component) =
-                            self.state.focus_manager.handle_event(&Event::FocusNext)
+                            self.state.focus_manager.handle_event(&UiEvent::FocusNext)
                         {
                             self.update_component_focus(&focused_component);
                         }
                     }
                 }
             }
-            Event::BackTab => {
+            UiEvent::BackTab => {
                 match self.state.navigation_mode {
                     NavigationMode::ScreenLevel => {
                         // Navigate to previous screen tab
@@ -1216,22 +2221,24 @@ impl App {
                     }
                     NavigationMode::WithinScreen => {
                         // Handle within-screen reverse tab navigation
-                        if let Some(focused_component) =
-                            self.state.focus_manager.handle_event(&Event::FocusPrevious)
+                        if let Some(focused_component) = self
+                            .state
+                            .focus_manager
+                            .handle_event(&UiEvent::FocusPrevious)
                         {
                             self.update_component_focus(&focused_component);
                         }
                     }
                 }
             }
-            Event::Escape => {
+            UiEvent::Escape => {
                 // Handle escape key - close modals or return to screen-level navigation
                 if self.state.modal_state.is_some() {
                     self.state.modal_state = None;
                     return Ok(true); // Event was handled - modal was closed
                 } else if self.state.navigation_mode == NavigationMode::WithinScreen {
                     // First, give screen-specific handlers a chance to handle ESC
-                    if self.handle_screen_specific_event(Event::Escape).await? {
+                    if self.handle_screen_specific_event(UiEvent::Escape).await? {
                         // Screen handled the ESC event, now switch navigation modes
                         self.state.navigation_mode = NavigationMode::ScreenLevel;
                         self.state.focus_manager.clear_focus();
@@ -1249,18 +2256,18 @@ impl App {
                     return Ok(true); // Event was handled - showed quit confirmation
                 }
             }
-            Event::Help => {
+            UiEvent::Help => {
                 self.show_help();
             }
-            Event::Refresh => {
-                self.refresh_current_screen_data().await?;
+            UiEvent::Refresh => {
+                self.load_screen_data(true);
             }
-            Event::Enter => {
+            UiEvent::Enter => {
                 // Handle enter key based on navigation mode
                 if self.state.current_screen == Screen::WalletSelection {
                     // Special case: Wallet selection screen should handle Enter directly
                     // without needing to switch to WithinScreen mode first
-                    self.handle_screen_specific_event(Event::Enter).await?;
+                    self.handle_screen_specific_event(UiEvent::Enter).await?;
                 } else if self.state.navigation_mode == NavigationMode::ScreenLevel {
                     // Enter within-screen navigation mode
                     self.state.navigation_mode = NavigationMode::WithinScreen;
@@ -1270,20 +2277,20 @@ impl App {
                     self.handle_enter_key().await?;
 
                     // If the general handler didn't handle it, try screen-specific handler
-                    if !self.handle_screen_specific_event(Event::Enter).await? {
+                    if !self.handle_screen_specific_event(UiEvent::Enter).await? {
                         // Event wasn't handled by either
                     }
                 }
             }
-            Event::MoveFocus(direction) => {
+            UiEvent::MoveFocus(direction) => {
                 // MoveFocus events should already be handled by screen-specific handlers above
                 // If we reach here, it means the screen didn't handle it, so we fall back to global navigation
                 if self.state.navigation_mode == NavigationMode::WithinScreen && !focus_handled {
                     // Convert Up/Down arrows to Tab/Shift+Tab behavior for consistent navigation
                     let focus_event = match direction {
-                        crate::tui::events::FocusDirection::Up => Event::FocusPrevious,
-                        crate::tui::events::FocusDirection::Down => Event::FocusNext,
-                        _ => Event::MoveFocus(direction.clone()), // Keep other directions as-is
+                        crate::tui::events::FocusDirection::Up => UiEvent::FocusPrevious,
+                        crate::tui::events::FocusDirection::Down => UiEvent::FocusNext,
+                        _ => UiEvent::MoveFocus(direction.clone()), // Keep other directions as-is
                     };
 
                     if let Some(focused_component) =
@@ -1293,19 +2300,19 @@ impl App {
                     }
                 }
             }
-            Event::ContextAction => {
+            UiEvent::ContextAction => {
                 // Handle space bar for context-sensitive actions
                 self.handle_context_action().await?;
             }
-            Event::F(1) => {
+            UiEvent::F(1) => {
                 self.show_help();
             }
-            Event::F(5) => {
-                self.refresh_current_screen_data().await?;
+            UiEvent::F(5) => {
+                self.load_screen_data(true);
             }
             _ => {
                 // Handle character events that need special context-aware processing
-                if let Event::Char(c) = &event {
+                if let UiEvent::Char(c) = &event {
                     // Handle 'q' as quit only when in ScreenLevel mode (not in text input)
                     if *c == 'q' && self.state.navigation_mode == NavigationMode::ScreenLevel {
                         // Show quit confirmation modal instead of immediately quitting
@@ -1340,7 +2347,7 @@ impl App {
         // individual screen modules can visually highlight the focused widget.
         match self.state.current_screen {
             Screen::Swap => {
-                let swap_state = crate::tui::screens::swap::get_swap_screen_state();
+                let swap_state = &mut self.state.swap_screen_state;
                 // Clear previous internal focus first
                 swap_state.reset_focus();
 
@@ -1356,23 +2363,20 @@ impl App {
                         }
                         _ => {}
                     },
-                    FocusableComponent::Dropdown(id) => {
-                        match id.as_str() {
-                            "swap_pool" => {
-                                swap_state.input_focus =
-                                    crate::tui::screens::swap::SwapInputFocus::Pool
-                            }
-                            "swap_from_asset" => {
-                                swap_state.input_focus =
-                                    crate::tui::screens::swap::SwapInputFocus::FromToken
-                            }
-                            "swap_to_asset" => {
-                                swap_state.input_focus =
-                                    crate::tui::screens::swap::SwapInputFocus::FromToken
-                            } // Fallback for old references
-                            _ => {}
+                    FocusableComponent::Dropdown(id) => match id.as_str() {
+                        "swap_pool" => {
+                            swap_state.input_focus = crate::tui::screens::swap::SwapInputFocus::Pool
                         }
-                    }
+                        "swap_from_asset" => {
+                            swap_state.input_focus =
+                                crate::tui::screens::swap::SwapInputFocus::FromToken
+                        }
+                        "swap_to_asset" => {
+                            swap_state.input_focus =
+                                crate::tui::screens::swap::SwapInputFocus::ToToken
+                        }
+                        _ => {}
+                    },
                     FocusableComponent::Button(id) => {
                         if id == "swap_execute" {
                             swap_state.input_focus =
@@ -1386,7 +2390,7 @@ impl App {
                 swap_state.apply_focus();
             }
             Screen::Liquidity => {
-                let liquidity_state = crate::tui::screens::liquidity::get_liquidity_screen_state();
+                let liquidity_state = &mut self.state.liquidity_screen_state;
                 // Clear previous internal focus first
                 liquidity_state.reset_focus();
 
@@ -1430,7 +2434,7 @@ impl App {
                 liquidity_state.apply_focus();
             }
             Screen::Admin => {
-                let admin_state = crate::tui::screens::admin::get_admin_screen_state();
+                let admin_state = &mut self.state.admin_screen_state;
                 // Clear previous internal focus first
                 admin_state.reset_focus();
 
@@ -1467,6 +2471,10 @@ impl App {
                             admin_state.input_focus =
                                 crate::tui::screens::admin::AdminInputFocus::PoolSelection
                         }
+                        "admin_template" => {
+                            admin_state.input_focus =
+                                crate::tui::screens::admin::AdminInputFocus::Template
+                        }
                         "admin_pool_type" => {
                             admin_state.input_focus =
                                 crate::tui::screens::admin::AdminInputFocus::PoolType
@@ -1517,15 +2525,16 @@ impl App {
             Screen::Dashboard => vec![dashboard_refresh_button(), dashboard_transactions_table()],
             Screen::Pools => vec![pools_search_input(), pools_table()],
             Screen::Swap => vec![
-                swap_pool_dropdown(),       // Pool selection (maps to SwapInputFocus::Pool)
                 swap_from_asset_dropdown(), // From token selection (maps to SwapInputFocus::FromToken)
+                swap_to_asset_dropdown(),   // To token selection (maps to SwapInputFocus::ToToken)
                 swap_amount_input(), // From amount input (maps to SwapInputFocus::FromAmount)
+                swap_pool_dropdown(), // Pool selection (maps to SwapInputFocus::Pool)
                 swap_slippage_input(), // Slippage tolerance (maps to SwapInputFocus::Slippage)
                 swap_execute_button(), // Execute button (maps to SwapInputFocus::Execute)
             ],
             Screen::Liquidity => {
                 // Initialize liquidity screen specific focus
-                liquidity::initialize_liquidity_screen_focus();
+                liquidity::initialize_liquidity_screen_focus(&mut self.state);
 
                 vec![
                     liquidity_pool_dropdown(),
@@ -1551,6 +2560,7 @@ impl App {
                     "admin_management_execute".to_string(),
                 ),
                 // Pool Creation tab components
+                crate::tui::events::FocusableComponent::Dropdown("admin_template".to_string()),
                 crate::tui::events::FocusableComponent::TextInput("admin_first_asset".to_string()),
                 crate::tui::events::FocusableComponent::TextInput("admin_second_asset".to_string()),
                 crate::tui::events::FocusableComponent::TextInput("admin_swap_fee".to_string()),
@@ -1610,6 +2620,14 @@ impl App {
                     "settings_decimal_precision".to_string(),
                 ),
                 crate::tui::events::FocusableComponent::Button("settings_auto_refresh".to_string()),
+                // Trading section components
+                crate::tui::events::FocusableComponent::Button("settings_expert_mode".to_string()),
+                crate::tui::events::FocusableComponent::TextInput(
+                    "settings_expert_max_value".to_string(),
+                ),
+                crate::tui::events::FocusableComponent::TextInput(
+                    "settings_expert_max_impact".to_string(),
+                ),
                 // Action buttons
                 settings_save_button(),
                 settings_reset_button(),
@@ -1633,74 +2651,362 @@ impl App {
         self.state.focus_manager.focus_first();
     }
 
-    /// Handle refresh for current screen
-    async fn refresh_current_screen_data(&mut self) -> Result<(), Error> {
-        match self.state.current_screen {
-            Screen::Dashboard => {
-                // Refresh dashboard data
-                self.refresh_dashboard_data().await?;
-            }
-            Screen::Pools => {
-                // Refresh pool data
-                if let Some(sender) = &self.event_sender {
-                    let _ = sender.send(Event::DataRefresh {
-                        data_type: "pools".to_string(),
-                        success: true,
-                        error: None,
-                    });
-                }
-            }
-            Screen::Swap => {
-                // Refresh pool data for swap screen and populate dropdowns
-                if let Some(sender) = &self.event_sender {
-                    let _ = sender.send(Event::DataRefresh {
-                        data_type: "pools".to_string(),
-                        success: true,
-                        error: None,
-                    });
+    /// Show the current screen's cached data and revalidate what is stale in the background
+    ///
+    /// The screen's stale data is requested as `DataRefresh` events and pushed to
+    /// the screen as it arrives. With `force` everything the screen shows is
+    /// fetched again.
+    fn load_screen_data(&mut self, force: bool) {
+        let screen = self.state.current_screen;
+        self.show_cached_data(screen);
+        if force {
+            self.data_loader.invalidate(screen);
+        }
+
+        let Some(sender) = self.event_sender.clone() else {
+            return;
+        };
+        for key in self.data_loader.stale(screen, tokio::time::Instant::now()) {
+            self.data_loader.begin(key);
+            let _ = sender.send(DataEvent::DataRefresh {
+                data_type: key.data_type().to_string(),
+                success: true,
+                error: None,
+            });
+        }
+    }
+
+    /// Show `screen` with the data already cached
+    fn show_cached_data(&mut self, screen: Screen) {
+        match screen {
+            Screen::Swap => self.update_swap_screen_pools(),
+            Screen::Liquidity => self.update_liquidity_screen_pools(),
+            Screen::Admin => self.update_admin_screen_pools(),
+            _ => {}
+        }
+    }
+
+    /// Reload the active wallet's LP positions, with their cost basis from the synced history
+    async fn refresh_lp_positions(&mut self) {
+        let Some(address) = self.state.wallet_address.clone() else {
+            return;
+        };
+        // Without a history the positions are still shown, just without a cost basis
+        let history = storage::shared().and_then(TxHistory::open_in).ok();
+        if let Some(history) = &history {
+            let _ = self.client.sync_history(history, &address).await;
+        }
+        match self
+            .client
+            .get_lp_positions(&address, history.as_ref(), crate::convert::USD)
+            .await
+        {
+            Ok(positions) => liquidity::update_liquidity_positions(
+                &mut self.state,
+                positions
+                    .iter()
+                    .map(liquidity::LiquidityPosition::from_lp_position)
+                    .collect(),
+            ),
+            Err(e) => self.set_error(format!("Failed to load LP positions: {}", e)),
+        }
+    }
+
+    /// Sample the spot price of `pools` into the local price history
+    ///
+    /// The history is opened from the storage on first use, with the saved
+    /// sampling settings; pools sampled less than the interval ago are skipped.
+    fn record_pool_prices(&mut self, pools: &[PoolInfoResponse]) {
+        if self.state.price_history.is_none() {
+            let settings = crate::config::Config::load(&crate::config::Config::default_path())
+                .map(|saved| saved.price_history)
+                .unwrap_or_default();
+            match storage::shared().and_then(PriceHistory::open_in) {
+                Ok(history) => self.state.price_history = Some(history.with_settings(&settings)),
+                Err(e) => {
+                    crate::tui::utils::logger::log_warning(&format!(
+                        "Failed to open the price history: {}",
+                        e
+                    ));
+                    return;
                 }
-                // Update swap screen pool dropdown with cached pools
-                self.update_swap_screen_pools();
             }
-            Screen::Liquidity => {
-                // Initialize focus for liquidity screen
-                crate::tui::screens::liquidity::initialize_liquidity_screen_focus();
+        }
+        if let Some(history) = &mut self.state.price_history {
+            if let Err(e) = history.record(pools, chrono::Utc::now()) {
+                crate::tui::utils::logger::log_warning(&format!(
+                    "Failed to record pool prices: {}",
+                    e
+                ));
+            }
+        }
+    }
 
-                // Refresh pool data for liquidity screen
-                if let Some(sender) = &self.event_sender {
-                    let _ = sender.send(Event::DataRefresh {
-                        data_type: "pools".to_string(),
-                        success: true,
-                        error: None,
-                    });
-                }
-                // Update liquidity screen pool dropdown with cached pools
-                self.update_liquidity_screen_pools();
+    /// Value the active wallet's balances, LP positions and pending rewards
+    ///
+    /// They are valued in the preferred display denom, or in USD. The last
+    /// valuation is kept if this one fails.
+    async fn refresh_portfolio(&mut self) {
+        let Some(address) = self.state.wallet_address.clone() else {
+            return;
+        };
+        let quote = self
+            .preferences()
+            .display_denom
+            .clone()
+            .unwrap_or_else(|| crate::convert::USD.to_string());
+        match self.client.get_portfolio_summary(&address, &quote).await {
+            Ok(portfolio) => self.state.portfolio = Some(portfolio),
+            Err(e) => crate::tui::utils::logger::log_warning(&format!(
+                "Failed to value the portfolio: {}",
+                e
+            )),
+        }
+    }
+
+    /// Sync the active wallet's past transactions and merge them into the transaction history
+    ///
+    /// Transactions sent this session keep their gas figures; their status is
+    /// taken from the chain.
+    async fn refresh_tx_history(&mut self) {
+        let Some(address) = self.state.wallet_address.clone() else {
+            return;
+        };
+        let history = match storage::shared().and_then(TxHistory::open_in) {
+            Ok(history) => history,
+            Err(e) => {
+                self.set_error(format!("Failed to load the transaction history: {}", e));
+                return;
             }
-            Screen::Admin => {
-                // Initialize focus for admin screen
-                crate::tui::screens::admin::initialize_admin_screen_focus(&mut self.state);
+        };
+        // The stored history is still shown if the chain can't be reached
+        if let Err(e) = self.client.sync_history(&history, &address).await {
+            self.set_error(format!("Failed to sync the transaction history: {}", e));
+        }
 
-                // Refresh pool data for admin screen
-                if let Some(sender) = &self.event_sender {
-                    let _ = sender.send(Event::DataRefresh {
-                        data_type: "pools".to_string(),
-                        success: true,
-                        error: None,
-                    });
+        let page = history.entries(
+            &address,
+            &HistoryFilter::default(),
+            &PageCursor::first(MAX_RECENT_TRANSACTIONS as u32),
+        );
+        for entry in page.items {
+            let status = if entry.success {
+                TransactionStatus::Success
+            } else {
+                TransactionStatus::Failed
+            };
+            match self
+                .state
+                .recent_transactions
+                .iter_mut()
+                .find(|tx| tx.hash == entry.tx_hash)
+            {
+                Some(tx) => tx.status = status,
+                None => self.state.recent_transactions.push(TransactionInfo {
+                    hash: entry.tx_hash,
+                    status,
+                    operation_type: entry.operation.to_string(),
+                    timestamp: entry.timestamp.unwrap_or_default(),
+                    gas_used: None,
+                    gas_wanted: None,
+                    retry_of: None,
+                }),
+            }
+        }
+        self.state
+            .recent_transactions
+            .sort_by_key(|tx| std::cmp::Reverse(tx.timestamp));
+        self.state
+            .recent_transactions
+            .truncate(MAX_RECENT_TRANSACTIONS);
+    }
+
+    /// Reload the scheduled transactions and the current epoch they may wait for
+    async fn refresh_scheduled_txs(&mut self) {
+        match storage::shared().and_then(Schedule::open_in) {
+            Ok(schedule) => self
+                .state
+                .rewards_state
+                .set_scheduled(schedule.txs().to_vec()),
+            Err(e) => self.set_error(format!("Failed to load scheduled transactions: {}", e)),
+        }
+        if let Ok(epoch) = self.client.get_current_epoch().await {
+            self.state.current_epoch = Some(epoch);
+        }
+    }
+
+    /// Reload the recurring swaps shown on the swap screen
+    fn refresh_dca_plans(&mut self) {
+        match storage::shared().and_then(DcaBook::open_in) {
+            Ok(book) => self.state.swap_screen_state.dca_plans = book.plans().to_vec(),
+            Err(e) => self.set_error(format!("Failed to load DCA plans: {}", e)),
+        }
+    }
+
+    /// Reload the epoch timing, and the farms and positions the calendar and alerts are built from
+    async fn refresh_epoch_calendar(&mut self) {
+        let calendar = match self.client.get_epoch_calendar().await {
+            Ok(calendar) => calendar,
+            Err(e) => {
+                self.set_error(format!("Failed to load the epoch calendar: {}", e));
+                return;
+            }
+        };
+        self.state.current_epoch = Some(calendar.epoch_at(chrono::Utc::now()));
+        self.state.rewards_state.epoch_calendar = Some(calendar);
+
+        if self.client.config().contracts.farm_manager.is_some() {
+            match self
+                .client
+                .farms_stream(None, FARM_PAGE_SIZE)
+                .try_collect()
+                .await
+            {
+                Ok(farms) => self.state.rewards_state.farms = farms,
+                Err(e) => {
+                    self.set_error(format!("Failed to load farms: {}", e));
+                    return;
                 }
-                // Update admin screen pool dropdown with cached pools
-                self.update_admin_screen_pools();
             }
-            _ => {}
+            let positions = match &self.state.wallet_address {
+                Some(address) => self
+                    .client
+                    .positions_stream(
+                        Some(PositionsBy::Receiver(address.clone())),
+                        Some(true),
+                        FARM_PAGE_SIZE,
+                    )
+                    .try_collect()
+                    .await
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            };
+            self.state
+                .rewards_state
+                .update_farm_alerts(&positions, calendar.epoch_at(chrono::Utc::now()));
+
+            if let Some(address) = self.state.wallet_address.clone() {
+                // Start epochs come from the stored history; without one they're unknown
+                let history = storage::shared().and_then(TxHistory::open_in).ok();
+                match self
+                    .client
+                    .get_farm_positions(&address, history.as_ref())
+                    .await
+                {
+                    Ok(positions) => self.state.rewards_state.farm_positions = positions,
+                    Err(e) => self.set_error(format!("Failed to load farm positions: {}", e)),
+                }
+            }
+        }
+    }
+
+    /// Queue a rewards claim for when the epoch after the current one starts
+    async fn schedule_claim_next_epoch(&mut self) {
+        let Some(wallet_name) = self.state.wallet_name.clone() else {
+            self.set_error("Load a saved wallet to schedule transactions".to_string());
+            return;
+        };
+        let epoch = match self.client.get_current_epoch().await {
+            Ok(epoch) => epoch,
+            Err(e) => {
+                self.set_error(format!("Failed to read the current epoch: {}", e));
+                return;
+            }
+        };
+
+        let trigger = Trigger::Epoch(epoch + 1);
+        let added = storage::shared()
+            .and_then(Schedule::open_in)
+            .and_then(|mut schedule| {
+                schedule.add(
+                    &wallet_name,
+                    ScheduledAction::ClaimRewards { until_epoch: None },
+                    trigger,
+                )
+            });
+        match added {
+            Ok(id) => self.set_success(format!(
+                "Scheduled transaction {}: claim rewards at {}. Run `mantra-dex schedule run --wallet {}` to execute it",
+                id, trigger, wallet_name
+            )),
+            Err(e) => self.set_error(format!("Failed to schedule the claim: {}", e)),
+        }
+        self.refresh_scheduled_txs().await;
+    }
+
+    /// Cancel the highlighted scheduled transaction
+    async fn cancel_selected_scheduled_tx(&mut self) {
+        let Some(id) = self
+            .state
+            .rewards_state
+            .selected_scheduled()
+            .map(|tx| tx.id)
+        else {
+            return;
+        };
+        let cancelled = storage::shared()
+            .and_then(Schedule::open_in)
+            .and_then(|mut schedule| schedule.cancel(id));
+        match cancelled {
+            Ok(()) => self.set_success(format!("Cancelled scheduled transaction {}", id)),
+            Err(e) => self.set_error(e.to_string()),
+        }
+        self.refresh_scheduled_txs().await;
+    }
+
+    /// Reload the analytics report for the active wallet
+    async fn refresh_analytics_data(&mut self) -> Result<(), Error> {
+        let Some(address) = self.state.wallet_address.clone() else {
+            self.set_error("Load a wallet to see its execution analytics".to_string());
+            return Ok(());
+        };
+
+        self.set_loading("Loading swap history...".to_string());
+        let pairs = match self.client.execution_report(&address, None, None).await {
+            Ok(report) => {
+                let pairs = report.len();
+                self.state.analytics_state.report = report;
+                self.state.analytics_state.updated_at = Some(chrono::Utc::now());
+                pairs
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to load swap history: {}", e));
+                return Ok(());
+            }
+        };
+
+        self.set_loading("Ranking pools for your assets...".to_string());
+        let earn = match self.client.get_balances_for_address(&address).await {
+            Ok(balances) => {
+                let holdings: Vec<_> = balances
+                    .into_iter()
+                    .filter(|balance| !balance.amount.is_zero())
+                    .collect();
+                self.client
+                    .earn_opportunities(&holdings, crate::earn::DEFAULT_LOOKBACK_BLOCKS)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        match earn {
+            Ok(opportunities) => {
+                let pools = opportunities.len();
+                self.state.analytics_state.earn = opportunities;
+                self.set_success(format!(
+                    "Loaded execution analytics for {} pair(s) and {} pool(s) to earn in",
+                    pairs, pools
+                ));
+            }
+            Err(e) => {
+                self.state.analytics_state.earn.clear();
+                self.set_error(format!("Failed to rank pools for your assets: {}", e));
+            }
         }
         Ok(())
     }
 
     /// Update swap screen pools dropdown with available pools
     fn update_swap_screen_pools(&mut self) {
-        let swap_state = crate::tui::screens::swap::get_swap_screen_state();
-
         // Extract available pools from cache for swap operations
         let available_pools: Vec<(String, String)> = self
             .state
@@ -1773,7 +3079,14 @@ impl App {
         }
 
         // Update the pool dropdown with available pools
-        swap_state.update_available_pools(available_pools);
+        let available_pools_for_multihop = available_pools.clone();
+        self.state
+            .swap_screen_state
+            .update_available_pools(available_pools);
+        show_pool_load_state(
+            &mut self.state.swap_screen_state.pool_dropdown,
+            &self.state.pool_load_state,
+        );
 
         // Also update available tokens from the pools
         let mut available_tokens: std::collections::HashSet<String> =
@@ -1799,7 +3112,10 @@ impl App {
             );
         }
 
-        swap_state.initialize_tokens(tokens_vec);
+        self.state
+            .multihop_screen_state
+            .update_available_options(tokens_vec.clone(), available_pools_for_multihop);
+        self.state.swap_screen_state.initialize_tokens(tokens_vec);
 
         // Note: Real balances should be loaded from blockchain via refresh_balances()
         // The hardcoded test balances have been removed to show actual wallet balances
@@ -1863,6 +3179,15 @@ impl App {
 
         // Update the admin screen with available pools
         crate::tui::screens::admin::update_admin_pools(&mut self.state, available_pools);
+        let admin_state = &mut self.state.admin_screen_state;
+        show_pool_load_state(
+            &mut admin_state.pool_management.pool_selection_dropdown,
+            &self.state.pool_load_state,
+        );
+        show_pool_load_state(
+            &mut admin_state.feature_control.pool_selection_dropdown,
+            &self.state.pool_load_state,
+        );
     }
 
     /// Update liquidity screen pools dropdown with available pools
@@ -1936,7 +3261,11 @@ impl App {
         }
 
         // Update the liquidity screen with available pools
-        crate::tui::screens::liquidity::update_liquidity_pools(available_pools);
+        crate::tui::screens::liquidity::update_liquidity_pools(&mut self.state, available_pools);
+        show_pool_load_state(
+            &mut self.state.liquidity_screen_state.pool_dropdown,
+            &self.state.pool_load_state,
+        );
     }
 
     /// Handle enter key based on current focus
@@ -1984,7 +3313,7 @@ impl App {
                     (&swap_state.from_asset, &swap_state.to_asset)
                 {
                     if let Some(sender) = &self.event_sender {
-                        let _ = sender.send(Event::ExecuteSwap {
+                        let _ = sender.send(TxEvent::ExecuteSwap {
                             from_asset: from_asset.clone(),
                             to_asset: to_asset.clone(),
                             amount: swap_state.amount,
@@ -2000,7 +3329,7 @@ impl App {
                 if let Some(pool_id_str) = &liquidity_state.selected_pool_id {
                     if let Ok(pool_id) = pool_id_str.parse::<u64>() {
                         if let Some(sender) = &self.event_sender {
-                            let _ = sender.send(Event::ProvideLiquidity {
+                            let _ = sender.send(TxEvent::ProvideLiquidity {
                                 pool_id: pool_id.to_string(),
                                 asset_1_amount: liquidity_state.first_asset_amount,
                                 asset_2_amount: liquidity_state.second_asset_amount,
@@ -2013,7 +3342,7 @@ impl App {
             "rewards_claim_all" => {
                 // Claim all rewards
                 if let Some(sender) = &self.event_sender {
-                    let _ = sender.send(Event::ClaimRewards {
+                    let _ = sender.send(TxEvent::ClaimRewards {
                         pool_id: None,
                         epochs: None,
                         claim_all: true,
@@ -2021,7 +3350,7 @@ impl App {
                 }
             }
             "dashboard_refresh" => {
-                self.refresh_current_screen_data().await?;
+                self.load_screen_data(true);
             }
             _ => {}
         }
@@ -2065,47 +3394,66 @@ impl App {
     }
 
     /// Handle screen-specific events
-    async fn handle_screen_specific_event(&mut self, event: Event) -> Result<bool, Error> {
+    async fn handle_screen_specific_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        if event == UiEvent::Char('x')
+            && self.state.navigation_mode == NavigationMode::WithinScreen
+            && self.start_table_export()
+        {
+            return Ok(true);
+        }
         match self.state.current_screen {
             Screen::WalletSelection => self.handle_wallet_selection_event(event).await,
+            Screen::Pools => self.handle_pools_screen_event(event).await,
             Screen::Swap => self.handle_swap_screen_event(event).await,
+            Screen::MultiHop => self.handle_multihop_screen_event(event).await,
             Screen::Liquidity => self.handle_liquidity_screen_event(event).await,
             Screen::Admin => self.handle_admin_screen_event(event).await,
             Screen::Settings => self.handle_settings_screen_event(event).await,
+            Screen::Rewards => self.handle_rewards_screen_event(event).await,
+            Screen::TransactionDetails => {
+                let navigation_mode = self.state.navigation_mode;
+                Ok(self
+                    .state
+                    .transaction_state
+                    .handle_event(&event, navigation_mode))
+            }
             _ => Ok(false),
         }
     }
 
     /// Handle wallet selection screen specific events. Returns `true` if the event was handled.
-    async fn handle_wallet_selection_event(&mut self, event: Event) -> Result<bool, Error> {
+    async fn handle_wallet_selection_event(&mut self, event: UiEvent) -> Result<bool, Error> {
         use crate::tui::screens::wallet_selection::{WalletSelectionAction, WalletSelectionState};
 
         match event {
-            Event::MoveFocus(crate::tui::events::FocusDirection::Up) => {
+            UiEvent::MoveFocus(crate::tui::events::FocusDirection::Up) => {
                 if self.state.wallet_selection_state.state == WalletSelectionState::SelectingWallet
                 {
                     self.state.wallet_selection_state.move_selection_up();
                     return Ok(true);
                 }
             }
-            Event::MoveFocus(crate::tui::events::FocusDirection::Down) => {
+            UiEvent::MoveFocus(crate::tui::events::FocusDirection::Down) => {
                 if self.state.wallet_selection_state.state == WalletSelectionState::SelectingWallet
                 {
                     self.state.wallet_selection_state.move_selection_down();
                     return Ok(true);
                 }
             }
-            Event::Enter => {
+            UiEvent::Enter => {
                 let action = self.state.wallet_selection_state.handle_selection();
                 return self.handle_wallet_selection_action(action).await;
             }
-            Event::Escape => {
+            UiEvent::Escape => {
                 let action = self.state.wallet_selection_state.handle_escape();
                 return self.handle_wallet_selection_action(action).await;
             }
-            Event::Char(c) => {
-                if self.state.wallet_selection_state.state == WalletSelectionState::EnteringPassword
-                {
+            UiEvent::Char(c) => {
+                if matches!(
+                    self.state.wallet_selection_state.state,
+                    WalletSelectionState::EnteringPassword
+                        | WalletSelectionState::EnteringWatchAddress
+                ) {
                     self.state.wallet_selection_state.handle_char(c);
                     return Ok(true);
                 } else if c == 'n' || c == 'N' {
@@ -2128,14 +3476,24 @@ impl App {
                     }
                 }
             }
-            Event::Backspace => {
-                if self.state.wallet_selection_state.state == WalletSelectionState::EnteringPassword
-                {
+            UiEvent::Backspace => {
+                if matches!(
+                    self.state.wallet_selection_state.state,
+                    WalletSelectionState::EnteringPassword
+                        | WalletSelectionState::EnteringWatchAddress
+                ) {
                     self.state.wallet_selection_state.handle_backspace();
                     return Ok(true);
                 }
             }
-            Event::F(1) => {
+            UiEvent::Tab | UiEvent::BackTab
+                if self.state.wallet_selection_state.state
+                    == WalletSelectionState::EnteringWatchAddress =>
+            {
+                self.state.wallet_selection_state.toggle_watch_field();
+                return Ok(true);
+            }
+            UiEvent::F(1) => {
                 // Toggle password visibility
                 self.state
                     .wallet_selection_state
@@ -2191,20 +3549,27 @@ impl App {
                     Error::Wallet(format!("Failed to initialize wallet storage: {}", e))
                 })?;
 
-                match storage.load_wallet(&wallet_name, &password) {
+                match storage.load_wallet(&wallet_name, password.expose_secret()) {
                     Ok(mnemonic) => {
                         self.state
                             .wallet_selection_state
                             .handle_authentication_success(wallet_name.clone(), mnemonic.clone());
 
                         // Load the wallet into the application
-                        match crate::wallet::MantraWallet::from_mnemonic(&mnemonic, 0) {
+                        match crate::wallet::MantraWallet::from_mnemonic(
+                            mnemonic.expose_secret(),
+                            0,
+                        ) {
                             Ok(wallet) => {
                                 match wallet.address() {
                                     Ok(address) => {
                                         self.set_wallet_address(address.to_string());
-                                        // Reconfigure the client with the loaded wallet
-                                        self.configure_client_wallet(wallet).await?;
+                                        self.state.wallet_name = Some(wallet_name.clone());
+                                        // Reconfigure the client with the loaded wallet and its saved defaults
+                                        let defaults = storage
+                                            .wallet_defaults(&wallet_name)
+                                            .unwrap_or_default();
+                                        self.configure_client_wallet(wallet, defaults).await?;
 
                                         // Navigate to dashboard and hide wizard
                                         self.state.wizard_state.show_wizard = false;
@@ -2243,6 +3608,26 @@ impl App {
                 }
                 Ok(true)
             }
+            WalletSelectionAction::OpenWatchOnly { wallet_name } => {
+                self.open_watch_only_wallet(&wallet_name).await?;
+                Ok(true)
+            }
+            WalletSelectionAction::AddWatchOnly {
+                wallet_name,
+                address,
+            } => {
+                let storage = crate::wallet::WalletStorage::new().map_err(|e| {
+                    Error::Wallet(format!("Failed to initialize wallet storage: {}", e))
+                })?;
+                match storage.save_watch_only_wallet(&wallet_name, &address) {
+                    Ok(()) => self.open_watch_only_wallet(&wallet_name).await?,
+                    Err(e) => self
+                        .state
+                        .wallet_selection_state
+                        .handle_watch_failure(e.to_string()),
+                }
+                Ok(true)
+            }
             WalletSelectionAction::WalletLoaded {
                 wallet_name: _,
                 mnemonic: _,
@@ -2257,183 +3642,194 @@ impl App {
         }
     }
 
-    /// Handle swap screen specific events. Returns `true` if the event was handled.
-    async fn handle_swap_screen_event(&mut self, event: Event) -> Result<bool, Error> {
-        let swap_state = crate::tui::screens::swap::get_swap_screen_state();
+    /// Open a saved watch-only wallet: its balances and positions load as usual,
+    /// and anything that needs a signature fails with a watch-only error
+    async fn open_watch_only_wallet(&mut self, wallet_name: &str) -> Result<(), Error> {
+        let storage = crate::wallet::WalletStorage::new()
+            .map_err(|e| Error::Wallet(format!("Failed to initialize wallet storage: {}", e)))?;
 
-        // Handle MoveFocus events directly for better arrow key navigation
-        match &event {
-            Event::MoveFocus(direction) => {
-                // Only handle arrow keys when we're in content mode and focused on interactive elements
-                if self.state.navigation_mode == NavigationMode::WithinScreen {
-                    match direction {
-                        crate::tui::events::FocusDirection::Up => {
-                            // Check if we're in a dropdown that should handle up/down
-                            if matches!(
-                                swap_state.input_focus,
-                                crate::tui::screens::swap::SwapInputFocus::Pool
-                                    | crate::tui::screens::swap::SwapInputFocus::FromToken
-                            ) && swap_state.is_any_list_editing()
-                            {
-                                // Let the dropdown handle the navigation
-                                let key = crossterm::event::KeyEvent::new(
-                                    crossterm::event::KeyCode::Up,
-                                    crossterm::event::KeyModifiers::NONE,
-                                );
-                                if swap_state.handle_key_event(key, self.state.navigation_mode) {
-                                    self.sync_swap_state_to_app(swap_state);
-                                    return Ok(true);
-                                }
-                            }
-                            // Otherwise, let global focus management handle it
-                            return Ok(false);
-                        }
-                        crate::tui::events::FocusDirection::Down => {
-                            // Check if we're in a dropdown that should handle up/down
-                            if matches!(
-                                swap_state.input_focus,
-                                crate::tui::screens::swap::SwapInputFocus::Pool
-                                    | crate::tui::screens::swap::SwapInputFocus::FromToken
-                            ) && swap_state.is_any_list_editing()
-                            {
-                                // Let the dropdown handle the navigation
-                                let key = crossterm::event::KeyEvent::new(
-                                    crossterm::event::KeyCode::Down,
-                                    crossterm::event::KeyModifiers::NONE,
-                                );
-                                if swap_state.handle_key_event(key, self.state.navigation_mode) {
-                                    self.sync_swap_state_to_app(swap_state);
-                                    return Ok(true);
-                                }
-                            }
-                            // Otherwise, let global focus management handle it
-                            return Ok(false);
-                        }
-                        _ => return Ok(false), // Let global focus handle other directions
-                    }
-                }
-                return Ok(false);
+        let wallet = match storage.load_watch_only_wallet(wallet_name) {
+            Ok(wallet) => wallet,
+            Err(e) => {
+                self.set_error(format!("Failed to open wallet '{}': {}", wallet_name, e));
+                return Ok(());
             }
-            _ => {}
+        };
+
+        self.set_wallet_address(wallet.address()?.to_string());
+        self.state.wallet_name = Some(wallet_name.to_string());
+        let defaults = storage.wallet_defaults(wallet_name).unwrap_or_default();
+        self.configure_client_wallet(wallet, defaults).await?;
+
+        self.state.wallet_selection_state.state =
+            crate::tui::screens::wallet_selection::WalletSelectionState::SelectingWallet;
+        self.state.wizard_state.show_wizard = false;
+        self.navigate_to(Screen::Dashboard);
+        self.set_success(format!(
+            "Watch-only wallet '{}' opened: balances can be viewed, transactions can't be signed",
+            wallet_name
+        ));
+        Ok(())
+    }
+
+    /// Handle swap screen specific events. Returns `true` if the event was handled.
+    async fn handle_swap_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        if let UiEvent::TriggerSimulation = event {
+            self.run_swap_simulation().await;
+            return Ok(true);
         }
 
-        // Handle other swap-specific events
-        match event {
-            Event::Tab => {
-                // Only handle Tab navigation between form fields when in WithinScreen mode
-                if self.state.navigation_mode == NavigationMode::WithinScreen {
-                    swap_state.next_focus();
-                    self.sync_swap_state_to_app(swap_state);
-                    return Ok(true);
-                } else {
-                    // In ScreenLevel mode, let global navigation handle Tab for screen switching
-                    return Ok(false);
-                }
-            }
-            Event::BackTab => {
-                // Only handle Shift+Tab (reverse navigation) between form fields when in WithinScreen mode
-                if self.state.navigation_mode == NavigationMode::WithinScreen {
-                    swap_state.previous_focus();
-                    self.sync_swap_state_to_app(swap_state);
-                    return Ok(true);
-                } else {
-                    // In ScreenLevel mode, let global navigation handle BackTab for screen switching
-                    return Ok(false);
-                }
-            }
-            Event::Enter => {
-                // Handle selection for currently focused list or execute button
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Enter,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if swap_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    self.sync_swap_state_to_app(swap_state);
-                    // Check if execute button was pressed by examining the current focus
-                    if matches!(
-                        swap_state.input_focus,
-                        crate::tui::screens::swap::SwapInputFocus::Execute
-                    ) {
-                        // Trigger swap confirmation
-                        if let Err(e) = self.handle_swap_execute_confirmation() {
-                            self.set_error(format!("Swap preparation failed: {}", e));
-                        }
-                    }
-                    return Ok(true);
-                }
-            }
-            Event::Escape => {
-                // ESC in WithinScreen mode should return to ScreenLevel mode
-                // First, let the swap screen handle the ESC key event for any internal state cleanup
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Esc,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                swap_state.handle_key_event(key_event, self.state.navigation_mode);
+        let navigation_mode = self.state.navigation_mode;
+        let pair = self.state.swap_screen_state.selected_pair();
+        if !self
+            .state
+            .swap_screen_state
+            .handle_event(&event, navigation_mode)
+        {
+            return Ok(false);
+        }
+        // A new token pair gets its pools ranked and the best one selected
+        if self.state.swap_screen_state.selected_pair() != pair {
+            self.rank_swap_pools().await;
+        }
+        self.sync_swap_state_to_app();
 
-                // Return false to let the main app handle the navigation mode switch
-                return Ok(false);
-            }
-            Event::Char(c) => {
-                // Handle character input for text fields
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Char(c),
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if swap_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    self.sync_swap_state_to_app(swap_state);
-                    return Ok(true);
-                }
+        // Enter on the execute button starts the confirmation flow
+        if matches!(event, UiEvent::Enter)
+            && matches!(
+                self.state.swap_screen_state.input_focus,
+                crate::tui::screens::swap::SwapInputFocus::Execute
+            )
+        {
+            if let Err(e) = self.handle_swap_execute_confirmation() {
+                self.set_error(format!("Swap preparation failed: {}", e));
             }
-            Event::Backspace => {
-                // Handle backspace for text fields
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Backspace,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if swap_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    self.sync_swap_state_to_app(swap_state);
-                    return Ok(true);
-                }
+        }
+
+        Ok(true)
+    }
+
+    /// Rank the pools trading the swap screen's token pair by output
+    ///
+    /// Uses the client's pool selection, so routing pins, exclusions and the
+    /// wallet's preferred pools decide which pool leads; it is selected unless
+    /// the user picked another one. Without an entered amount one whole token
+    /// is quoted.
+    async fn rank_swap_pools(&mut self) {
+        let swap_state = &self.state.swap_screen_state;
+        let Some((from_token, to_token)) = swap_state.selected_pair() else {
+            return;
+        };
+        let (Some(offer_denom), Some(ask_denom)) = (
+            self.map_token_name_to_denom(&from_token),
+            self.map_token_name_to_denom(&to_token),
+        ) else {
+            return;
+        };
+        let amount = swap_state
+            .from_amount_input
+            .value()
+            .parse::<f64>()
+            .ok()
+            .filter(|amount| *amount > 0.0)
+            .unwrap_or(1.0);
+        let decimals = self.get_token_decimals(&offer_denom);
+        let offer = cosmwasm_std::Coin {
+            denom: offer_denom,
+            amount: Uint128::new((amount * 10f64.powi(decimals.into())) as u128),
+        };
+
+        let quotes = match self.client.rank_pools_for_pair(&offer, &ask_denom).await {
+            Ok(quotes) => quotes,
+            Err(e) => {
+                crate::tui::utils::logger::log_warning(&format!(
+                    "Failed to rank pools for {} -> {}: {}",
+                    from_token, to_token, e
+                ));
+                return;
             }
-            Event::TriggerSimulation => {
-                // Only run simulation if we have valid input
-                if !swap_state.from_amount_input.value().is_empty()
-                    && swap_state.pool_dropdown.get_selected_value().is_some()
-                    && swap_state
-                        .from_token_dropdown
-                        .get_selected_value()
-                        .is_some()
-                {
-                    // Trigger swap simulation
-                    let from_amount = swap_state.from_amount_input.value();
-                    let from_token = swap_state
-                        .from_token_dropdown
-                        .get_selected_value()
-                        .unwrap_or("");
-                    let pool_text = swap_state.pool_dropdown.get_selected_label().unwrap_or("");
-
-                    self.set_loading("Running swap simulation...".to_string());
-
-                    // For now, just simulate some delay and show success
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    self.set_success(format!(
-                        "Simulation complete: {} {} via {}",
-                        from_amount, from_token, pool_text
-                    ));
+        };
+        let ranked = quotes
+            .iter()
+            .map(|quote| {
+                let name = self
+                    .state
+                    .swap_screen_state
+                    .available_pools
+                    .iter()
+                    .find(|(pool_id, _)| *pool_id == quote.pool_id)
+                    .map_or_else(
+                        || format!("Pool {}", quote.pool_id),
+                        |(_, name)| name.clone(),
+                    );
+                let output =
+                    self.micro_to_token_amount(&quote.return_amount().to_string(), &ask_denom);
+                (
+                    quote.pool_id.clone(),
+                    format!("{} → {} {}", name, output, to_token),
+                )
+            })
+            .collect();
+        self.state.swap_screen_state.set_ranked_pools(ranked);
+    }
 
-                    swap_state.reset_simulation_timer();
-                }
-                return Ok(true);
+    /// Simulate the swap currently entered on the swap screen
+    async fn run_swap_simulation(&mut self) {
+        // The best pool can change with the trade size
+        if self.state.swap_screen_state.pool_auto_selected {
+            self.rank_swap_pools().await;
+            self.sync_swap_state_to_app();
+        }
+
+        let swap_state = &self.state.swap_screen_state;
+        let from_amount = swap_state.from_amount_input.value().to_string();
+        let from_token = swap_state
+            .from_token_dropdown
+            .get_selected_value()
+            .map(str::to_string);
+        let pool_id = swap_state
+            .pool_dropdown
+            .get_selected_value()
+            .map(str::to_string);
+        let pool_text = swap_state
+            .pool_dropdown
+            .get_selected_label()
+            .unwrap_or("")
+            .to_string();
+        let to_token = swap_state.to_token();
+
+        // Only run simulation if we have valid input
+        let (Some(pool_id), Some(from_token), Some(to_token)) = (pool_id, from_token, to_token)
+        else {
+            return;
+        };
+        if from_amount.is_empty() {
+            return;
+        }
+
+        self.set_loading("Running swap simulation...".to_string());
+
+        // Sample the pool's depth so the trade can be sized against it
+        match self
+            .load_swap_depth_curve(&pool_id, &from_token, &to_token, &from_amount)
+            .await
+        {
+            Ok(()) => self.set_success(format!(
+                "Simulation complete: {} {} via {}",
+                from_amount, from_token, pool_text
+            )),
+            Err(e) => {
+                self.state.swap_state.depth_curve = None;
+                self.set_error(format!("Simulation failed: {}", e));
             }
-            _ => return Ok(false),
         }
 
-        Ok(false)
+        self.state.swap_screen_state.reset_simulation_timer();
     }
 
     /// Sync swap screen state back to app state
-    fn sync_swap_state_to_app(&mut self, swap_state: &crate::tui::screens::swap::SwapScreenState) {
+    fn sync_swap_state_to_app(&mut self) {
+        let swap_state = &self.state.swap_screen_state;
         // Update app state with changes from swap screen
         if let Some(selected_value) = swap_state.pool_dropdown.get_selected_value() {
             if let Ok(pool_id) = selected_value.parse::<u64>() {
@@ -2443,499 +3839,301 @@ impl App {
         if let Some(selected_token) = swap_state.from_token_dropdown.get_selected_value() {
             self.state.swap_state.from_asset = Some(selected_token.to_string());
         }
+        if let Some(to_token) = swap_state.to_token() {
+            self.state.swap_state.to_asset = Some(to_token);
+        }
         self.state.swap_state.amount = swap_state.from_amount_input.value().to_string();
         self.state.swap_state.slippage = swap_state.slippage_input.value().to_string();
     }
 
     /// Handle liquidity screen specific events. Returns `true` if the event was handled.
-    async fn handle_liquidity_screen_event(&mut self, event: Event) -> Result<bool, Error> {
-        let liquidity_state = liquidity::get_liquidity_screen_state();
-
-        // Convert Event to KeyEvent for the new key system (similar to swap screen)
-        let key_event = match &event {
-            Event::MoveFocus(direction) => {
-                // Convert focus events to direct key events for navigation
-                match direction {
-                    crate::tui::events::FocusDirection::Up => {
-                        Some(crossterm::event::KeyEvent::new(
-                            crossterm::event::KeyCode::Up,
-                            crossterm::event::KeyModifiers::NONE,
-                        ))
-                    }
-                    crate::tui::events::FocusDirection::Down => {
-                        Some(crossterm::event::KeyEvent::new(
-                            crossterm::event::KeyCode::Down,
-                            crossterm::event::KeyModifiers::NONE,
-                        ))
-                    }
-                    crate::tui::events::FocusDirection::Left => {
-                        Some(crossterm::event::KeyEvent::new(
-                            crossterm::event::KeyCode::Left,
-                            crossterm::event::KeyModifiers::NONE,
-                        ))
-                    }
-                    crate::tui::events::FocusDirection::Right => {
-                        Some(crossterm::event::KeyEvent::new(
-                            crossterm::event::KeyCode::Right,
-                            crossterm::event::KeyModifiers::NONE,
-                        ))
-                    }
-                    _ => None,
-                }
-            }
-            Event::Char(c) => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char(*c),
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            Event::Enter => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Enter,
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            Event::Tab => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Tab,
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            Event::Backspace => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Backspace,
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            Event::Delete => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Delete,
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            Event::Home => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Home,
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            Event::End => Some(crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::End,
-                crossterm::event::KeyModifiers::NONE,
-            )),
-            _ => None,
-        };
-
-        // Handle the event using the new key event system (similar to swap screen)
-        let mut key_handled = false;
-        let mut pool_changed = false;
-        if let Some(key_event) = key_event {
-            key_handled = liquidity_state.handle_key_event(key_event, self.state.navigation_mode);
-            if key_handled {
-                // For navigation keys that might change pool selection, check if pool changed
-                if matches!(
-                    key_event.code,
-                    crossterm::event::KeyCode::Up
-                        | crossterm::event::KeyCode::Down
-                        | crossterm::event::KeyCode::Enter
-                ) {
-                    pool_changed = true;
-                }
-
-                // Check if execute button was pressed by examining the current focus
-                if matches!(
-                    liquidity_state.input_focus,
-                    crate::tui::screens::liquidity::LiquidityInputFocus::Execute
-                ) && matches!(event, Event::Enter)
-                {
-                    // Trigger liquidity confirmation
-                    if let Err(e) = self.handle_liquidity_execute_confirmation() {
-                        self.set_error(format!("Liquidity preparation failed: {}", e));
-                    }
-                }
-            }
+    async fn handle_liquidity_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        let navigation_mode = self.state.navigation_mode;
+        if !self
+            .state
+            .liquidity_screen_state
+            .handle_event(&event, navigation_mode)
+        {
+            return Ok(false);
         }
 
-        if key_handled {
-            // If pool might have changed, fetch reserves for proportional calculations
-            if pool_changed {
-                if let Some(pool_id) = liquidity_state.pool_dropdown.get_selected_value() {
-                    self.fetch_pool_reserves_for_liquidity(&pool_id).await?;
-                }
+        // Enter on the execute button starts the confirmation flow
+        if matches!(event, UiEvent::Enter)
+            && matches!(
+                self.state.liquidity_screen_state.input_focus,
+                crate::tui::screens::liquidity::LiquidityInputFocus::Execute
+            )
+        {
+            if let Err(e) = self.handle_liquidity_execute_confirmation() {
+                self.set_error(format!("Liquidity preparation failed: {}", e));
             }
-            return Ok(true);
         }
 
-        // Handle specific events that don't need key conversion
-        match event {
-            Event::MoveFocus(crate::tui::events::FocusDirection::Right) => {
-                // Handle direct right arrow for tab switching
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Right,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if liquidity_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::MoveFocus(crate::tui::events::FocusDirection::Left) => {
-                // Handle direct left arrow for tab switching
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Left,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if liquidity_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::MoveFocus(crate::tui::events::FocusDirection::Up) => {
-                // Handle up arrow for lists
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Up,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if liquidity_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::MoveFocus(crate::tui::events::FocusDirection::Down) => {
-                // Handle down arrow for lists
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Down,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if liquidity_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
+        // Navigating or selecting may have changed the pool, so refresh its reserves
+        // for proportional calculations
+        if matches!(
+            event,
+            UiEvent::MoveFocus(crate::tui::events::FocusDirection::Up)
+                | UiEvent::MoveFocus(crate::tui::events::FocusDirection::Down)
+                | UiEvent::Enter
+        ) {
+            if let Some(pool_id) = self
+                .state
+                .liquidity_screen_state
+                .pool_dropdown
+                .get_selected_value()
+                .map(str::to_string)
+            {
+                self.fetch_pool_reserves_for_liquidity(&pool_id).await?;
             }
-            _ => {}
         }
 
-        Ok(false)
+        Ok(true)
     }
 
     /// Handle admin screen specific events. Returns `true` if the event was handled.
-    async fn handle_admin_screen_event(&mut self, event: Event) -> Result<bool, Error> {
-        let admin_state = crate::tui::screens::admin::get_admin_screen_state();
-
-        // Handle character events (including admin tab switching 1-3)
-        if let Event::Char(c) = &event {
-            if !c.is_control() {
-                // Let admin screen handle all character input, including tab switching
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Char(*c),
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
+    async fn handle_admin_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        let navigation_mode = self.state.navigation_mode;
+        if !self
+            .state
+            .admin_screen_state
+            .handle_event(&event, navigation_mode)
+        {
+            return Ok(false);
         }
 
-        // Handle MoveFocus events directly for better arrow key navigation
-        // But first check if we're in a dropdown that should handle its own navigation
-        match &event {
-            Event::MoveFocus(direction) => match direction {
-                crate::tui::events::FocusDirection::Up
-                | crate::tui::events::FocusDirection::Down => {
-                    // Check if we're focused on a dropdown that should handle its own navigation
-                    let should_handle_in_dropdown = match admin_state.input_focus {
-                        crate::tui::screens::admin::AdminInputFocus::PoolSelection => {
-                            admin_state
-                                .pool_management
-                                .pool_selection_dropdown
-                                .is_active
-                        }
-                        crate::tui::screens::admin::AdminInputFocus::PoolType => {
-                            admin_state.pool_creation.pool_type_dropdown.is_active
-                        }
-                        _ => false,
-                    };
-
-                    if should_handle_in_dropdown {
-                        // Let the dropdown handle the event
-                        let key_event = crossterm::event::KeyEvent::new(
-                            match direction {
-                                crate::tui::events::FocusDirection::Up => {
-                                    crossterm::event::KeyCode::Up
-                                }
-                                crate::tui::events::FocusDirection::Down => {
-                                    crossterm::event::KeyCode::Down
-                                }
-                                _ => unreachable!(),
-                            },
-                            crossterm::event::KeyModifiers::NONE,
-                        );
-                        if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                            return Ok(true);
-                        }
-                    } else {
-                        // Handle normal focus navigation
-                        match direction {
-                            crate::tui::events::FocusDirection::Up => {
-                                admin_state.previous_focus();
-                                return Ok(true);
-                            }
-                            crate::tui::events::FocusDirection::Down => {
-                                admin_state.next_focus();
-                                return Ok(true);
-                            }
-                            _ => {}
-                        }
+        // Enter on an execute button starts the matching confirmation flow
+        if matches!(event, UiEvent::Enter) {
+            match self.state.admin_screen_state.input_focus {
+                crate::tui::screens::admin::AdminInputFocus::CreationExecute => {
+                    if let Err(e) = self.handle_pool_creation_execute_confirmation() {
+                        self.set_error(format!("Pool creation preparation failed: {}", e));
                     }
                 }
-                crate::tui::events::FocusDirection::Next => {
-                    admin_state.next_focus();
-                    return Ok(true);
-                }
-                crate::tui::events::FocusDirection::Previous => {
-                    admin_state.previous_focus();
-                    return Ok(true);
-                }
-                _ => {}
-            },
-            _ => {}
-        }
-
-        // Handle Enter key for admin screen navigation and selections
-        if let Event::Enter = &event {
-            let key_event = crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Enter,
-                crossterm::event::KeyModifiers::NONE,
-            );
-            if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                // Check if execute button was pressed by examining the current focus
-                match admin_state.input_focus {
-                    crate::tui::screens::admin::AdminInputFocus::CreationExecute => {
-                        // Trigger pool creation confirmation
-                        if let Err(e) = self.handle_pool_creation_execute_confirmation() {
-                            self.set_error(format!("Pool creation preparation failed: {}", e));
-                        }
-                    }
-                    crate::tui::screens::admin::AdminInputFocus::ManagementExecute => {
-                        // Trigger pool management confirmation
-                        if let Err(e) = self.handle_pool_management_execute_confirmation() {
-                            self.set_error(format!("Pool management preparation failed: {}", e));
-                        }
+                crate::tui::screens::admin::AdminInputFocus::ManagementExecute => {
+                    if let Err(e) = self.handle_pool_management_execute_confirmation() {
+                        self.set_error(format!("Pool management preparation failed: {}", e));
                     }
-                    _ => {}
                 }
-                return Ok(true);
+                _ => {}
             }
         }
 
-        // Handle other key events
-        match event {
-            Event::Tab => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Tab,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::BackTab => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::BackTab,
-                    crossterm::event::KeyModifiers::SHIFT,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::Backspace => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Backspace,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::Delete => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Delete,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::Char(c) => {
-                // Convert character to key event for admin screen
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Char(c),
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
-            }
-            Event::MoveFocus(crate::tui::events::FocusDirection::Left) => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Left,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(true);
-                }
+        Ok(true)
+    }
+
+    /// Handle pools screen specific events. Returns `true` if the event was handled.
+    async fn handle_pools_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        let navigation_mode = self.state.navigation_mode;
+        if let UiEvent::Char('f') = event {
+            if navigation_mode != NavigationMode::WithinScreen {
+                return Ok(false);
             }
-            Event::MoveFocus(crate::tui::events::FocusDirection::Right) => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Right,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
+            self.toggle_highlighted_favorite();
+            return Ok(true);
+        }
+        if navigation_mode == NavigationMode::WithinScreen {
+            match event {
+                UiEvent::Char('c') => {
+                    self.toggle_highlighted_compare();
                     return Ok(true);
                 }
-            }
-            Event::Home => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Home,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
+                UiEvent::Char('v') => {
+                    self.toggle_pool_comparison();
                     return Ok(true);
                 }
-            }
-            Event::End => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::End,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
+                UiEvent::Escape if self.state.pools_screen_state.comparison.is_some() => {
+                    self.state.pools_screen_state.comparison = None;
                     return Ok(true);
                 }
+                _ => {}
             }
-            Event::Paste(text) => {
-                for ch in text.chars() {
-                    let key_event = crossterm::event::KeyEvent::new(
-                        crossterm::event::KeyCode::Char(ch),
-                        crossterm::event::KeyModifiers::NONE,
-                    );
-                    admin_state.handle_key_event(key_event, self.state.navigation_mode);
-                }
-                return Ok(true);
+        }
+        if let UiEvent::Enter = event {
+            if navigation_mode != NavigationMode::WithinScreen {
+                return Ok(false);
             }
-            Event::Escape => {
-                let key_event = crossterm::event::KeyEvent::new(
-                    crossterm::event::KeyCode::Esc,
-                    crossterm::event::KeyModifiers::NONE,
-                );
-                if admin_state.handle_key_event(key_event, self.state.navigation_mode) {
-                    return Ok(false); // Let main app handle escape for navigation mode switching
+            let pool_id = self
+                .state
+                .pools_screen_state
+                .highlighted_pool()
+                .and_then(|pool| pool.pool_id.parse().ok());
+            return Ok(match pool_id {
+                Some(pool_id) => {
+                    self.select_pool(pool_id);
+                    true
                 }
-            }
-            _ => {}
+                None => false,
+            });
         }
 
-        // Handle specific admin events for blockchain operations
-        match &event {
-            Event::CreatePool {
-                asset_1,
-                asset_2,
-                swap_fee,
-                exit_fee,
-                pool_features,
-            } => {
-                // Execute pool creation
-                self.set_loading_with_progress(
-                    format!("Creating pool for {} / {}", asset_1, asset_2),
-                    Some(10.0),
-                    true,
-                );
-
-                // Use the async blockchain processor to execute the real transaction
-                if let Some(event_sender) = &self.event_sender {
-                    let blockchain_processor =
-                        crate::tui::events::AsyncBlockchainProcessor::with_client(
-                            event_sender.clone(),
-                            self.client.clone(),
-                        );
-
-                    let asset_1_clone = asset_1.clone();
-                    let asset_2_clone = asset_2.clone();
-                    let swap_fee_clone = swap_fee.clone();
-                    let exit_fee_clone = exit_fee.clone();
-                    let pool_features_clone = pool_features.clone();
-
-                    // Spawn the async operation for pool creation
-                    tokio::spawn(async move {
-                        blockchain_processor
-                            .create_pool(
-                                asset_1_clone,
-                                asset_2_clone,
-                                swap_fee_clone,
-                                exit_fee_clone,
-                                pool_features_clone,
-                            )
-                            .await;
-                    });
-                } else {
-                    self.set_error("No event sender available for pool creation".to_string());
-                }
+        Ok(self
+            .state
+            .pools_screen_state
+            .handle_event(&event, navigation_mode))
+    }
 
-                return Ok(true);
+    /// Handle rewards screen specific events. Returns `true` if the event was handled.
+    async fn handle_rewards_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+        match event {
+            UiEvent::MoveFocus(FocusDirection::Up) => {
+                self.state.rewards_state.move_scheduled_selection(-1)
             }
-            Event::UpdatePoolFeatures {
-                pool_id,
-                features,
-                enabled,
-            } => {
-                // Execute pool feature update
-                let operation_desc = if *enabled {
-                    format!("Enabling features for pool {}", pool_id)
-                } else {
-                    format!("Disabling features for pool {}", pool_id)
-                };
-
-                self.set_loading_with_progress(operation_desc, Some(10.0), true);
-
-                // Use the async blockchain processor to execute the real transaction
-                if let Some(event_sender) = &self.event_sender {
-                    let blockchain_processor =
-                        crate::tui::events::AsyncBlockchainProcessor::with_client(
-                            event_sender.clone(),
-                            self.client.clone(),
-                        );
-
-                    let pool_id_clone = pool_id.clone();
-                    let features_clone = features.clone();
-                    let enabled_clone = *enabled;
-                    let event_sender_clone = event_sender.clone();
+            UiEvent::MoveFocus(FocusDirection::Down) => {
+                self.state.rewards_state.move_scheduled_selection(1)
+            }
+            UiEvent::Char('e') => self.schedule_claim_next_epoch().await,
+            UiEvent::Char('x') | UiEvent::Delete => self.cancel_selected_scheduled_tx().await,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
 
-                    // Spawn the async operation for feature update
-                    tokio::spawn(async move {
-                        // TODO: Implement actual pool feature update via blockchain_processor
-                        // For now, simulate the operation
-                        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-
-                        // Send success event
-                        let _ =
-                            event_sender_clone.send(crate::tui::events::Event::BlockchainSuccess {
-                                operation: "update_pool_features".to_string(),
-                                result: format!("Features updated for pool {}", pool_id_clone),
-                                transaction_hash: Some(format!(
-                                    "0x{:x}",
-                                    chrono::Utc::now().timestamp()
-                                )),
-                                enhanced_data: Some(format!(
-                                    "Features: {:?}, Enabled: {}",
-                                    features_clone, enabled_clone
-                                )),
-                            });
-                    });
-                } else {
-                    self.set_error("No event sender available for feature update".to_string());
-                }
+    /// Handle multi-hop screen specific events. Returns `true` if the event was handled.
+    async fn handle_multihop_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
+        if let UiEvent::Enter = event {
+            if self.state.navigation_mode != NavigationMode::WithinScreen {
+                return Ok(false);
+            }
+            return Ok(
+                match crate::tui::screens::multihop::handle_multihop_screen_action(&mut self.state)
+                {
+                    Some(message) => {
+                        self.set_status(message);
+                        true
+                    }
+                    None => false,
+                },
+            );
+        }
 
+        if let UiEvent::Char('r') = event {
+            let typing = matches!(
+                self.state.multihop_screen_state.input_focus,
+                crate::tui::screens::multihop::MultiHopInputFocus::Amount
+            );
+            if self.state.navigation_mode == NavigationMode::WithinScreen && !typing {
+                self.find_multihop_route();
                 return Ok(true);
             }
-            _ => {}
         }
 
-        Ok(false)
+        let navigation_mode = self.state.navigation_mode;
+        Ok(self
+            .state
+            .multihop_screen_state
+            .handle_event(&event, navigation_mode))
+    }
+
+    /// Replace the multi-hop route with the best one through the cached pools
+    ///
+    /// Routes from the selected from token to the selected to token for the
+    /// entered amount, skipping pools excluded from routing.
+    fn find_multihop_route(&mut self) {
+        let screen = &self.state.multihop_screen_state;
+        let (Some(from_token), Some(to_token)) = (
+            screen.from_token_dropdown.selected_value().cloned(),
+            screen.to_token_dropdown.selected_value().cloned(),
+        ) else {
+            self.set_status("Select the from and to tokens to find a route".to_string());
+            return;
+        };
+        let Some(amount) = screen
+            .amount_input
+            .value()
+            .parse::<f64>()
+            .ok()
+            .filter(|amount| *amount > 0.0)
+        else {
+            self.set_status("Enter an amount to find a route".to_string());
+            return;
+        };
+        let (Some(offer_denom), Some(ask_denom)) = (
+            self.map_token_name_to_denom(&from_token),
+            self.map_token_name_to_denom(&to_token),
+        ) else {
+            self.set_status(format!("No pools trade {} or {}", from_token, to_token));
+            return;
+        };
+
+        let offer_decimals = self.get_token_decimals(&offer_denom);
+        let offer_asset = cosmwasm_std::Coin {
+            denom: offer_denom,
+            amount: cosmwasm_std::Uint128::new(
+                (amount * 10f64.powi(offer_decimals.into())) as u128,
+            ),
+        };
+        let routing = self.client.routing();
+        let graph = crate::router::AssetGraph::new(
+            self.state
+                .pool_cache
+                .values()
+                .map(|entry| &entry.pool_info)
+                .filter(|pool| !routing.excludes(&pool.pool_info.pool_identifier)),
+        );
+        let Some(route) = crate::router::find_best_route(
+            &graph,
+            &offer_asset,
+            &ask_denom,
+            crate::router::DEFAULT_MAX_HOPS,
+        ) else {
+            self.set_status(format!("No route from {} to {}", from_token, to_token));
+            return;
+        };
+
+        let human = |amount: cosmwasm_std::Uint128, denom: &str| {
+            let decimals = self.get_token_decimals(denom);
+            format!("{:.6}", amount.u128() as f64 / 10f64.powi(decimals.into()))
+        };
+        let hops = route
+            .hops
+            .iter()
+            .map(|hop| {
+                let pool = self.get_cached_pool(&hop.pool_id);
+                crate::tui::screens::multihop::SwapHop {
+                    from_asset: self.denom_to_symbol(&hop.offer_asset.denom),
+                    to_asset: self.denom_to_symbol(&hop.ask_denom),
+                    pool_id: hop.pool_id.clone(),
+                    pool_name: format!("Pool {}", hop.pool_id),
+                    amount_in: human(hop.offer_asset.amount, &hop.offer_asset.denom),
+                    estimated_amount_out: human(hop.return_amount, &hop.ask_denom),
+                    price_impact: decimal_percent(hop.price_impact),
+                    fee_amount: human(hop.fee_amount, &hop.ask_denom),
+                    fee_rate: pool
+                        .map(|pool| decimal_percent(pool.pool_info.pool_fees.swap_fee.share))
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+        let path = route
+            .path()
+            .iter()
+            .map(|denom| self.denom_to_symbol(denom))
+            .collect::<Vec<_>>()
+            .join(" → ");
+        let message = format!(
+            "Best route: {} ({} hop(s), {:.2}% price impact)",
+            path,
+            route.hops.len(),
+            decimal_percent(route.price_impact)
+        );
+        self.state.multihop_screen_state.set_route(hops);
+        self.set_status(message);
     }
 
     /// Handle settings screen specific events. Returns `true` if the event was handled.
-    async fn handle_settings_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+    async fn handle_settings_screen_event(&mut self, event: UiEvent) -> Result<bool, Error> {
         // Check if we're in content mode and handle navigation accordingly
         if matches!(self.state.navigation_mode, NavigationMode::WithinScreen) {
             match event {
-                Event::Char(c) => {
+                UiEvent::Char(c) => {
                     self.handle_settings_input(c).await?;
                     return Ok(true);
                 }
-                Event::MoveFocus(direction) => {
+                UiEvent::MoveFocus(direction) => {
                     // Handle focus movement within settings screen content
                     match direction {
                         crate::tui::events::FocusDirection::Next => {
@@ -2976,7 +4174,7 @@ impl App {
                     }
                     return Ok(true);
                 }
-                Event::Enter => {
+                UiEvent::Enter => {
                     // Handle enter key in settings
                     if let Some(focused) = self.state.focus_manager.current_focus() {
                         match focused {
@@ -2997,6 +4195,9 @@ impl App {
                                     "settings_auto_refresh" => {
                                         self.state.settings_state.toggle_auto_refresh();
                                     }
+                                    "settings_expert_mode" => {
+                                        self.state.settings_state.toggle_expert_mode();
+                                    }
                                     _ => {}
                                 }
                             }
@@ -3032,6 +4233,10 @@ impl App {
                                         self.state.settings_state.display_form.form_state.editing =
                                             true;
                                     }
+                                    crate::tui::screens::settings::SettingsSection::Trading => {
+                                        self.state.settings_state.trading_form.form_state.editing =
+                                            true;
+                                    }
                                 }
                             }
                             _ => {}
@@ -3039,7 +4244,7 @@ impl App {
                     }
                     return Ok(true);
                 }
-                Event::Escape => {
+                UiEvent::Escape => {
                     // Handle escape key - close confirmation modal or go back to tab mode
                     if self.state.settings_state.show_confirmation {
                         self.state.settings_state.show_confirmation = false;
@@ -3051,7 +4256,7 @@ impl App {
                     }
                     return Ok(true);
                 }
-                Event::Backspace => {
+                UiEvent::Backspace => {
                     // Handle backspace for text input fields
                     if let Some(focused) = self.state.focus_manager.current_focus() {
                         if let crate::tui::events::FocusableComponent::TextInput(field_id) = focused
@@ -3071,13 +4276,16 @@ impl App {
                                 | "settings_decimal_precision" => {
                                     let _ = self.state.settings_state.handle_backspace();
                                 }
+                                "settings_expert_max_value" | "settings_expert_max_impact" => {
+                                    let _ = self.state.settings_state.handle_backspace();
+                                }
                                 _ => {}
                             }
                         }
                     }
                     return Ok(true);
                 }
-                Event::ContextAction => {
+                UiEvent::ContextAction => {
                     // Handle space bar context actions
                     match self.state.settings_state.current_section {
                         crate::tui::screens::settings::SettingsSection::Network => {
@@ -3089,6 +4297,9 @@ impl App {
                         crate::tui::screens::settings::SettingsSection::Wallet => {
                             self.state.settings_state.toggle_import_mode();
                         }
+                        crate::tui::screens::settings::SettingsSection::Trading => {
+                            self.state.settings_state.toggle_expert_mode();
+                        }
                     }
                     return Ok(true);
                 }
@@ -3190,6 +4401,20 @@ impl App {
         ));
     }
 
+    /// Update the loading message, keeping the current progress
+    pub fn update_loading_message(&mut self, message: String) {
+        if let LoadingState::Loading {
+            message: ref mut m, ..
+        } = self.state.loading_state
+        {
+            *m = message.clone();
+        }
+
+        if let Some(ref mut modal) = self.state.modal_state {
+            modal.update_loading_message(message);
+        }
+    }
+
     /// Update loading progress
     pub fn update_loading_progress(&mut self, progress: f64, message: Option<String>) {
         if let LoadingState::Loading {
@@ -3268,26 +4493,65 @@ impl App {
     }
 
     /// Handle modal events (navigation, confirmation, etc.)
-    pub fn handle_modal_event(&mut self, event: &Event) -> bool {
+    pub fn handle_modal_event(&mut self, event: &UiEvent) -> bool {
+        let in_help = matches!(
+            self.state.modal_state,
+            Some(ModalState {
+                modal_type: crate::tui::components::modals::ModalType::Help { .. },
+                ..
+            })
+        );
+        if in_help && *event == UiEvent::Char('t') {
+            self.state.modal_state = None;
+            self.start_tour();
+            return true;
+        }
+
+        // The export modal takes every key while its path is edited
+        if let Some(ModalState {
+            modal_type: crate::tui::components::modals::ModalType::Export { path, .. },
+            ..
+        }) = &self.state.modal_state
+        {
+            match event {
+                UiEvent::Enter => {
+                    let path = PathBuf::from(path.trim());
+                    self.state.modal_state = None;
+                    self.finish_table_export(&path);
+                }
+                UiEvent::Escape => {
+                    self.state.modal_state = None;
+                    self.state.pending_export = None;
+                    self.set_status("Export cancelled".to_string());
+                }
+                _ => {
+                    if let Some(modal) = self.state.modal_state.as_mut() {
+                        modal.edit_export_path(event);
+                    }
+                }
+            }
+            return true;
+        }
+
         if let Some(ref mut modal) = self.state.modal_state {
             match event {
-                Event::MoveFocus(crate::tui::events::FocusDirection::Up) => {
+                UiEvent::MoveFocus(crate::tui::events::FocusDirection::Up) => {
                     modal.scroll_up();
                     return true;
                 }
-                Event::MoveFocus(crate::tui::events::FocusDirection::Down) => {
+                UiEvent::MoveFocus(crate::tui::events::FocusDirection::Down) => {
                     modal.scroll_down();
                     return true;
                 }
-                Event::MoveFocus(crate::tui::events::FocusDirection::Left) => {
+                UiEvent::MoveFocus(crate::tui::events::FocusDirection::Left) => {
                     modal.select_previous();
                     return true;
                 }
-                Event::MoveFocus(crate::tui::events::FocusDirection::Right) => {
+                UiEvent::MoveFocus(crate::tui::events::FocusDirection::Right) => {
                     modal.select_next();
                     return true;
                 }
-                Event::Enter => {
+                UiEvent::Enter => {
                     // For confirmation modals, Enter should execute the currently selected option
                     match &modal.modal_type {
                         crate::tui::components::modals::ModalType::Confirmation { .. } => {
@@ -3301,11 +4565,12 @@ impl App {
                                 self.state.modal_state = None;
                                 if self.state.current_screen == Screen::Swap {
                                     let _ = crate::tui::screens::swap::handle_confirmation_response(
+                                        &mut self.state,
                                         false,
                                     );
                                 } else if self.state.current_screen == Screen::Liquidity {
                                     let _ = crate::tui::screens::liquidity::handle_liquidity_confirmation_response(
-                                        false,
+                                        &mut self.state, false,
                                     );
                                 }
                                 self.set_status("Action cancelled".to_string());
@@ -3315,11 +4580,15 @@ impl App {
                             retry_action, ..
                         } => {
                             let should_retry = retry_action.is_some() && modal.selected_option == 0;
+                            let diagnose =
+                                should_retry && retry_action.as_deref() == Some(DIAGNOSE_ACTION);
 
                             // Clear modal first
                             self.state.modal_state = None;
 
-                            if should_retry {
+                            if diagnose {
+                                self.show_diagnosis();
+                            } else if should_retry {
                                 // Implement retry logic based on the last failed operation
                                 self.retry_last_operation();
                             }
@@ -3332,7 +4601,7 @@ impl App {
                     }
                     return true;
                 }
-                Event::Escape => {
+                UiEvent::Escape => {
                     self.state.modal_state = None;
                     return true;
                 }
@@ -3342,6 +4611,63 @@ impl App {
         false
     }
 
+    /// Offer to export the table of the current screen as shown
+    ///
+    /// Returns `false` if the screen shows no exportable table.
+    fn start_table_export(&mut self) -> bool {
+        let state = &mut self.state;
+        let export = match state.current_screen {
+            Screen::Pools if state.pools_screen_state.comparison.is_none() => {
+                state.pools_screen_state.export()
+            }
+            Screen::TransactionDetails
+                if state.transaction_state.view_mode
+                    == crate::tui::screens::transaction::TransactionViewMode::History =>
+            {
+                state.transaction_state.export(&state.recent_transactions)
+            }
+            Screen::Liquidity
+                if state.liquidity_screen_state.mode
+                    == crate::tui::screens::liquidity::LiquidityMode::Positions =>
+            {
+                state.liquidity_screen_state.export_positions()
+            }
+            _ => return false,
+        };
+        if export.rows.is_empty() {
+            self.set_error(format!("The {} table has no rows to export", export.name));
+            return true;
+        }
+
+        self.state.modal_state = Some(ModalState::export(
+            format!("Export {}", export.name),
+            export.default_path().display().to_string(),
+            export.rows.len(),
+        ));
+        self.state.pending_export = Some(export);
+        true
+    }
+
+    /// Write the table captured by [`start_table_export`](Self::start_table_export) to `path`
+    fn finish_table_export(&mut self, path: &Path) {
+        let Some(export) = self.state.pending_export.take() else {
+            return;
+        };
+        if path.as_os_str().is_empty() {
+            self.set_error("Enter a file to export to".to_string());
+            return;
+        }
+        match export.write(path) {
+            Ok(()) => self.set_status(format!(
+                "Exported {} {} to {}",
+                export.rows.len(),
+                export.name,
+                path.display()
+            )),
+            Err(e) => self.set_error(format!("Failed to export {}: {}", export.name, e)),
+        }
+    }
+
     /// Retry the last failed operation
     fn retry_last_operation(&mut self) {
         // Check if the last error was slippage-related
@@ -3356,7 +4682,7 @@ impl App {
             {
                 // For slippage errors, send an event to trigger async slippage retry
                 if let Some(sender) = self.event_sender.as_ref() {
-                    let _ = sender.send(crate::tui::events::Event::RetryWithIncreasedSlippage);
+                    let _ = sender.send(crate::tui::events::UiEvent::RetryWithIncreasedSlippage);
                 }
                 return;
             }
@@ -3368,6 +4694,41 @@ impl App {
         self.set_status("You can try the operation again".to_string());
     }
 
+    /// Explain the last failed transaction, offering to go where it can be fixed
+    fn show_diagnosis(&mut self) {
+        let Some(failed) = self.state.failed_transaction.as_ref() else {
+            self.set_status("No failed transaction to diagnose".to_string());
+            return;
+        };
+
+        let diagnosis = &failed.diagnosis;
+        let mut message = format!(
+            "{}\n\n{}\n\nSuggested fixes:",
+            diagnosis.cause,
+            diagnosis.explanation()
+        );
+        for suggestion in diagnosis.suggestions() {
+            message.push_str(&format!("\n• {}", suggestion));
+        }
+        if let (Some(codespace), Some(code)) = (&diagnosis.codespace, diagnosis.code) {
+            message.push_str(&format!("\n\nError code: {} {}", codespace, code));
+        }
+
+        let confirm_text = if diagnosis.cause == FailureCause::SlippageExceeded
+            && self.state.failed_swap.is_some()
+        {
+            "Retry with Higher Slippage".to_string()
+        } else {
+            format!("Go to {}", failed.retry_screen().display_name())
+        };
+        self.show_confirmation(
+            DIAGNOSIS_TITLE.to_string(),
+            message,
+            Some(confirm_text),
+            Some("Close".to_string()),
+        );
+    }
+
     /// Handle confirmation actions
     fn handle_confirmation(&mut self) {
         // Clone the modal state to check its contents before clearing it
@@ -3384,6 +4745,29 @@ impl App {
                     self.state.modal_state = None;
                     return;
                 }
+                if title == SLIPPAGE_RETRY_TITLE {
+                    self.state.modal_state = None;
+                    if let Some(sender) = self.event_sender.as_ref() {
+                        let _ = sender.send(TxEvent::RetrySwapWithSlippageBump);
+                    }
+                    return;
+                }
+                if title == DIAGNOSIS_TITLE {
+                    self.state.modal_state = None;
+                    let Some(failed) = self.state.failed_transaction.take() else {
+                        return;
+                    };
+                    if failed.diagnosis.cause == FailureCause::SlippageExceeded
+                        && self.state.failed_swap.is_some()
+                    {
+                        if let Some(sender) = self.event_sender.as_ref() {
+                            let _ = sender.send(UiEvent::RetryWithIncreasedSlippage);
+                        }
+                    } else {
+                        self.navigate_to(failed.retry_screen());
+                    }
+                    return;
+                }
             }
 
             // Check if this is a swap confirmation modal
@@ -3393,7 +4777,7 @@ impl App {
 
                 // Handle swap confirmation
                 if let Some(swap_event) =
-                    crate::tui::screens::swap::handle_confirmation_response(true)
+                    crate::tui::screens::swap::handle_confirmation_response(&mut self.state, true)
                 {
                     // Process the swap event immediately
                     if let Some(sender) = self.event_sender.as_ref() {
@@ -3406,7 +4790,10 @@ impl App {
 
                 // Handle liquidity confirmation
                 if let Some(liquidity_event) =
-                    crate::tui::screens::liquidity::handle_liquidity_confirmation_response(true)
+                    crate::tui::screens::liquidity::handle_liquidity_confirmation_response(
+                        &mut self.state,
+                        true,
+                    )
                 {
                     // Process the liquidity event immediately
                     if let Some(sender) = self.event_sender.as_ref() {
@@ -3557,6 +4944,7 @@ impl App {
 
         self.state.current_screen = screen;
         self.state.navigation_mode = NavigationMode::ScreenLevel;
+        self.reprioritize_background_tasks(screen);
 
         // Only clear messages when actually changing screens, not when staying on the same screen
         if is_changing_screen {
@@ -3565,27 +4953,86 @@ impl App {
             // Keep error messages and modals when changing screens
         }
 
-        // Update screen-specific data when navigating
+        // Initialize focus state for screens that need it
         match screen {
-            Screen::Swap => {
-                // Update swap pools when entering swap screen
-                self.update_swap_screen_pools();
-            }
             Screen::Liquidity => {
-                // Initialize liquidity screen focus state
-                crate::tui::screens::liquidity::initialize_liquidity_screen_focus();
-                // Update liquidity screen pools when entering screen
-                self.update_liquidity_screen_pools();
+                crate::tui::screens::liquidity::initialize_liquidity_screen_focus(&mut self.state);
             }
             Screen::Admin => {
-                // Initialize admin screen focus state
                 crate::tui::screens::admin::initialize_admin_screen_focus(&mut self.state);
-                // Update admin screen pools when entering screen
-                self.update_admin_screen_pools();
             }
             _ => {}
         }
+        // Show cached data straight away and revalidate what is stale
+        self.load_screen_data(false);
         // Don't initialize focus here - it will be done when user presses Enter
+
+        // Offer the tour the first time a wallet's screens are reached
+        if screen != Screen::WalletSelection
+            && self.state.tour.is_none()
+            && !self.preferences.get().tour_completed
+        {
+            self.start_tour();
+        }
+    }
+
+    /// Start the onboarding tour from its first step
+    pub fn start_tour(&mut self) {
+        self.state.tour = Some(crate::tui::components::tour::TourState::new());
+        self.show_tour_step();
+    }
+
+    /// Switch to the screen the current tour step describes
+    fn show_tour_step(&mut self) {
+        let Some(step) = self.state.tour.as_ref().map(|tour| tour.current()) else {
+            return;
+        };
+        self.navigate_to(step.screen);
+        if step.screen == Screen::Pools {
+            // The tour's first action: a read-only pool query
+            self.load_screen_data(true);
+        }
+    }
+
+    /// Handle a key while the tour runs. Returns `true` if the tour used it.
+    fn handle_tour_event(&mut self, event: &UiEvent) -> bool {
+        let Some(tour) = self.state.tour.as_mut() else {
+            return false;
+        };
+        match event {
+            UiEvent::Enter | UiEvent::MoveFocus(crate::tui::events::FocusDirection::Right) => {
+                if tour.advance() {
+                    self.show_tour_step();
+                } else {
+                    self.finish_tour();
+                    self.set_status("Tour complete. Press h for help at any time".to_string());
+                }
+            }
+            UiEvent::MoveFocus(crate::tui::events::FocusDirection::Left) => {
+                tour.previous();
+                self.show_tour_step();
+            }
+            UiEvent::Escape => {
+                self.finish_tour();
+                self.set_status("Tour skipped. Press h, then t, to take it later".to_string());
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// End the tour and remember not to show it again
+    fn finish_tour(&mut self) {
+        self.state.tour = None;
+        if let Err(e) = self
+            .preferences
+            .update(|preferences| preferences.tour_completed = true)
+        {
+            crate::tui::utils::logger::log_warning(&format!(
+                "Failed to save tour completion: {}",
+                e
+            ));
+        }
     }
 
     /// Navigate to the next tab
@@ -3595,30 +5042,23 @@ impl App {
         let new_screen = screens[self.state.current_tab];
         self.state.current_screen = new_screen;
         self.state.navigation_mode = NavigationMode::ScreenLevel;
+        self.reprioritize_background_tasks(new_screen);
 
         // Don't clear error messages when navigating tabs - let them persist
         self.state.status_message = None;
 
-        // Update screen-specific data when navigating
+        // Initialize focus state for screens that need it
         match new_screen {
-            Screen::Swap => {
-                // Update swap pools when entering swap screen
-                self.update_swap_screen_pools();
-            }
             Screen::Liquidity => {
-                // Initialize liquidity screen focus state
-                crate::tui::screens::liquidity::initialize_liquidity_screen_focus();
-                // Update liquidity screen pools when entering screen
-                self.update_liquidity_screen_pools();
+                crate::tui::screens::liquidity::initialize_liquidity_screen_focus(&mut self.state);
             }
             Screen::Admin => {
-                // Initialize admin screen focus state
                 crate::tui::screens::admin::initialize_admin_screen_focus(&mut self.state);
-                // Update admin screen pools when entering screen
-                self.update_admin_screen_pools();
             }
             _ => {}
         }
+        // Show cached data straight away and revalidate what is stale
+        self.load_screen_data(false);
         // Don't initialize focus here - it will be done when user presses Enter
     }
 
@@ -3633,30 +5073,23 @@ impl App {
         let new_screen = screens[self.state.current_tab];
         self.state.current_screen = new_screen;
         self.state.navigation_mode = NavigationMode::ScreenLevel;
+        self.reprioritize_background_tasks(new_screen);
 
         // Don't clear error messages when navigating tabs - let them persist
         self.state.status_message = None;
 
-        // Update screen-specific data when navigating
+        // Initialize focus state for screens that need it
         match new_screen {
-            Screen::Swap => {
-                // Update swap pools when entering swap screen
-                self.update_swap_screen_pools();
-            }
             Screen::Liquidity => {
-                // Initialize liquidity screen focus state
-                crate::tui::screens::liquidity::initialize_liquidity_screen_focus();
-                // Update liquidity screen pools when entering screen
-                self.update_liquidity_screen_pools();
+                crate::tui::screens::liquidity::initialize_liquidity_screen_focus(&mut self.state);
             }
             Screen::Admin => {
-                // Initialize admin screen focus state
                 crate::tui::screens::admin::initialize_admin_screen_focus(&mut self.state);
-                // Update admin screen pools when entering screen
-                self.update_admin_screen_pools();
             }
             _ => {}
         }
+        // Show cached data straight away and revalidate what is stale
+        self.load_screen_data(false);
         // Don't initialize focus here - it will be done when user presses Enter
     }
 
@@ -3692,29 +5125,27 @@ impl App {
             }
             "pools" => {
                 // Refresh pool data
-                if let Ok(pools) = self.client.get_pools(None).await {
-                    for pool in pools {
-                        let pool_id = pool.pool_info.pool_identifier.clone();
-                        let cache_entry = PoolCacheEntry {
-                            pool_info: pool,
-                            cached_at: chrono::Utc::now(),
-                        };
-                        self.state.pool_cache.insert(pool_id, cache_entry);
-                    }
-
-                    // Update swap screen pools if currently on swap screen
-                    if self.state.current_screen == Screen::Swap {
-                        self.update_swap_screen_pools();
+                // Refresh pool data; on failure the pool dropdowns show the error
+                let pools = match self.client.get_pools(&PageCursor::default()).await {
+                    Ok(pools) => {
+                        self.state.pool_load_state = ListLoadState::Ready;
+                        pools.items
                     }
-                    // Update liquidity screen pools if currently on liquidity screen
-                    if self.state.current_screen == Screen::Liquidity {
-                        self.update_liquidity_screen_pools();
-                    }
-                    // Update admin screen pools if currently on admin screen
-                    if self.state.current_screen == Screen::Admin {
-                        self.update_admin_screen_pools();
+                    Err(e) => {
+                        self.state.pool_load_state = ListLoadState::Failed(e.to_string());
+                        Vec::new()
                     }
+                };
+                self.record_pool_prices(&pools);
+                for pool in pools {
+                    let pool_id = pool.pool_info.pool_identifier.clone();
+                    let cache_entry = PoolCacheEntry {
+                        pool_info: pool,
+                        cached_at: chrono::Utc::now(),
+                    };
+                    self.state.pool_cache.insert(pool_id, cache_entry);
                 }
+                self.screen_pool_risks();
             }
             "transactions" => {
                 // Refresh transaction status for pending transactions
@@ -3755,6 +5186,19 @@ impl App {
                 // For now, just update the last sync time
                 self.state.network_info.last_sync_time = Some(chrono::Utc::now());
             }
+            "asset_decimals" => {
+                self.refresh_asset_decimals_cache().await?;
+            }
+            "analytics" => {
+                // Rebuild the execution report from the wallet's swap history
+                self.refresh_analytics_data().await?;
+            }
+            "epoch_calendar" => self.refresh_epoch_calendar().await,
+            "scheduled_txs" => self.refresh_scheduled_txs().await,
+            "dca_plans" => self.refresh_dca_plans(),
+            "tx_history" => self.refresh_tx_history().await,
+            "lp_positions" => self.refresh_lp_positions().await,
+            "portfolio" => self.refresh_portfolio().await,
             _ => {
                 // Unknown data type, log but don't error
                 crate::tui::utils::logger::log_warning(&format!(
@@ -3769,6 +5213,14 @@ impl App {
 
     /// Update wallet address
     pub fn set_wallet_address(&mut self, address: String) {
+        // Sync the new wallet's data in the background too
+        if let Some(coordinator) = &mut self.background_coordinator {
+            coordinator.set_wallet_address(address.clone());
+        }
+        // The valuation was of the previous wallet
+        if self.state.wallet_address.as_ref() != Some(&address) {
+            self.state.portfolio = None;
+        }
         self.state.wallet_address = Some(address);
     }
 
@@ -3786,9 +5238,11 @@ impl App {
     /// Add a recent transaction with full details
     pub fn add_transaction(&mut self, tx_info: TransactionInfo) {
         self.state.recent_transactions.insert(0, tx_info);
-        // Keep only the last 50 transactions
-        if self.state.recent_transactions.len() > 50 {
-            self.state.recent_transactions.truncate(50);
+        // The history table is windowed, so a long history stays cheap to render
+        if self.state.recent_transactions.len() > MAX_RECENT_TRANSACTIONS {
+            self.state
+                .recent_transactions
+                .truncate(MAX_RECENT_TRANSACTIONS);
         }
     }
 
@@ -3801,6 +5255,7 @@ impl App {
             timestamp: chrono::Utc::now(),
             gas_used: None,
             gas_wanted: None,
+            retry_of: None,
         };
         self.add_transaction(tx_info);
     }
@@ -3808,6 +5263,7 @@ impl App {
     /// Select a pool
     pub fn select_pool(&mut self, pool_id: u64) {
         self.state.selected_pool_id = Some(pool_id);
+        self.load_pool_ownership(&pool_id.to_string());
     }
 
     /// Clear pool selection
@@ -3842,233 +5298,82 @@ impl App {
     /// Update network information
     pub fn update_network_info(&mut self, chain_id: Option<String>, is_syncing: bool) {
         self.state.network_info.chain_id = chain_id;
-        self.state.network_info.is_syncing = is_syncing;
-        self.state.network_info.last_sync_time = Some(chrono::Utc::now());
-    }
-
-    /// Process transaction response and update state
-    pub async fn process_transaction_response(
-        &mut self,
-        tx_response: TxResponse,
-        operation_type: String,
-    ) {
-        let status = if tx_response.code == 0 {
-            TransactionStatus::Success
-        } else {
-            TransactionStatus::Failed
-        };
-
-        let tx_info = TransactionInfo {
-            hash: tx_response.txhash.clone(),
-            status: status.clone(),
-            operation_type: operation_type.clone(),
-            timestamp: chrono::Utc::now(),
-            gas_used: Some(tx_response.gas_used),
-            gas_wanted: Some(tx_response.gas_wanted),
-        };
-
-        self.add_transaction(tx_info);
-
-        match status {
-            TransactionStatus::Success => {
-                self.set_success(format!(
-                    "{} successful: {}",
-                    operation_type, tx_response.txhash
-                ));
-            }
-            TransactionStatus::Failed => {
-                self.set_error(format!(
-                    "{} failed: {}",
-                    operation_type, tx_response.raw_log
-                ));
-            }
-            _ => {}
-        }
-
-        // Trigger data refresh after successful operations
-        if status == TransactionStatus::Success {
-            let _ = self.refresh_current_screen_data().await;
-        }
-    }
-
-    /// Refresh settings data
-    async fn refresh_dashboard_data(&mut self) -> Result<(), Error> {
-        // Only refresh data if we have a connected wallet, otherwise just refresh network info
-        if self.state.wallet_address.is_none() {
-            // No wallet connected - only refresh basic network info
-            self.set_loading_with_progress(
-                "Refreshing network data...".to_string(),
-                Some(10.0),
-                false,
-            );
-
-            let mut errors = Vec::new();
-
-            // Refresh network info only
-            match self.client.get_last_block_height().await {
-                Ok(height) => {
-                    self.state.block_height = Some(height);
-                    self.state.network_info.last_sync_time = Some(chrono::Utc::now());
-                    self.state.network_info.is_syncing = false;
-                }
-                Err(e) => {
-                    errors.push(format!("Failed to fetch block height: {}", e));
-                }
-            }
-
-            // Update progress - fetching pool data
-            self.update_loading_progress(80.0, Some("Fetching pool information...".to_string()));
-
-            // Refresh pool data (limited to avoid overwhelming)
-            match self.client.get_pools(Some(20)).await {
-                Ok(pools) => {
-                    for pool in pools {
-                        let pool_id = pool.pool_info.pool_identifier.clone();
-                        let cache_entry = PoolCacheEntry {
-                            pool_info: pool,
-                            cached_at: chrono::Utc::now(),
-                        };
-                        self.state.pool_cache.insert(pool_id, cache_entry);
-                    }
-                }
-                Err(e) => {
-                    errors.push(format!("Failed to fetch pools: {}", e));
-                }
-            }
-
-            if errors.is_empty() {
-                self.set_success("Network data refreshed successfully".to_string());
-            } else {
-                let error_summary = if errors.len() == 1 {
-                    errors[0].clone()
-                } else {
-                    format!("Multiple errors occurred: {}", errors.join("; "))
-                };
-                self.set_error_with_type(
-                    format!("Network refresh completed with warnings: {}", error_summary),
-                    crate::tui::components::modals::ErrorType::Network,
-                );
-            }
-
-            return Ok(());
-        }
-
-        // Refresh balances, network info, and other dashboard data (wallet connected)
-        self.set_loading_with_progress(
-            "Refreshing dashboard data...".to_string(),
-            Some(10.0),
-            false,
-        );
-
-        let mut errors = Vec::new();
-
-        // Update progress - fetching asset decimals
-        self.update_loading_progress(20.0, Some("Fetching asset decimals...".to_string()));
-
-        // Refresh asset decimals cache (needed for proper balance formatting)
-        match self.refresh_asset_decimals_cache().await {
-            Ok(_) => {
-                // Successfully refreshed decimals cache
-            }
-            Err(e) => {
-                errors.push(format!("Failed to refresh asset decimals: {}", e));
-                // Continue with default decimals - not critical for basic functionality
-            }
-        }
-
-        // Update progress - fetching balances
-        self.update_loading_progress(40.0, Some("Fetching wallet balances...".to_string()));
-
-        // Refresh balances if wallet is connected
-        if let Some(address) = &self.state.wallet_address.clone() {
-            match self.client.get_balances().await {
-                Ok(balances) => {
-                    // Clear existing balances
-                    self.state.balances.clear();
-                    // Update with new balances
-                    for balance in balances {
-                        self.state
-                            .balances
-                            .insert(balance.denom, balance.amount.to_string());
-                    }
-                }
-                Err(e) => {
-                    errors.push(format!("Failed to fetch balances: {}", e));
-                }
-            }
-        }
+        self.state.network_info.is_syncing = is_syncing;
+        self.state.network_info.last_sync_time = Some(chrono::Utc::now());
+    }
 
-        // Update progress - fetching network info
-        self.update_loading_progress(70.0, Some("Fetching network information...".to_string()));
+    /// Process transaction response and update state
+    pub async fn process_transaction_response(
+        &mut self,
+        tx_response: TxResponse,
+        operation_type: String,
+    ) {
+        let status = if tx_response.code == 0 {
+            TransactionStatus::Success
+        } else {
+            TransactionStatus::Failed
+        };
 
-        // Refresh network info
-        match self.client.get_last_block_height().await {
-            Ok(height) => {
-                self.state.block_height = Some(height);
-                self.state.network_info.last_sync_time = Some(chrono::Utc::now());
-                self.state.network_info.is_syncing = false;
-            }
-            Err(e) => {
-                errors.push(format!("Failed to fetch block height: {}", e));
-            }
-        }
+        let tx_info = TransactionInfo {
+            hash: tx_response.txhash.clone(),
+            status: status.clone(),
+            operation_type: operation_type.clone(),
+            timestamp: chrono::Utc::now(),
+            gas_used: Some(tx_response.gas_used),
+            gas_wanted: Some(tx_response.gas_wanted),
+            retry_of: None,
+        };
 
-        // Refresh pool data (limited to avoid overwhelming)
-        match self.client.get_pools(Some(20)).await {
-            Ok(pools) => {
-                for pool in pools {
-                    let pool_id = pool.pool_info.pool_identifier.clone();
-                    let cache_entry = PoolCacheEntry {
-                        pool_info: pool,
-                        cached_at: chrono::Utc::now(),
-                    };
-                    self.state.pool_cache.insert(pool_id, cache_entry);
-                }
-            }
-            Err(e) => {
-                errors.push(format!("Failed to fetch pools: {}", e));
-            }
-        }
+        self.add_transaction(tx_info);
 
-        // Update background coordinator with wallet address for future syncing
-        if let Some(address) = &self.state.wallet_address {
-            if let Some(coordinator) = &mut self.background_coordinator {
-                coordinator.set_wallet_address(address.clone());
+        match status {
+            TransactionStatus::Success => {
+                self.set_success(format!(
+                    "{} successful: {}",
+                    operation_type, tx_response.txhash
+                ));
+            }
+            TransactionStatus::Failed => {
+                self.set_error(format!(
+                    "{} failed: {}",
+                    operation_type, tx_response.raw_log
+                ));
             }
+            _ => {}
         }
 
-        if errors.is_empty() {
-            self.set_success("Dashboard refreshed successfully".to_string());
-        } else {
-            // Show partial success with warnings
-            let error_summary = if errors.len() == 1 {
-                errors[0].clone()
-            } else {
-                format!("Multiple errors occurred: {}", errors.join("; "))
-            };
-            self.set_error_with_type(
-                format!(
-                    "Dashboard refresh completed with warnings: {}",
-                    error_summary
-                ),
-                crate::tui::components::modals::ErrorType::Network,
-            );
+        // Trigger data refresh after successful operations
+        if status == TransactionStatus::Success {
+            self.load_screen_data(true);
         }
-
-        Ok(())
     }
 
     async fn refresh_settings_data(&mut self) -> Result<(), Error> {
         // Initialize settings state with current config if needed
         if self.state.settings_state.current_config.mnemonic.is_none() {
             // Load current config into settings state
+            let saved = crate::config::Config::load(&crate::config::Config::default_path())
+                .unwrap_or_default();
             let current_config = crate::config::Config {
                 network: self.config.clone(),
                 mnemonic: None, // We don't store mnemonic in memory for security
                 tokens: std::collections::HashMap::new(),
+                expert_mode: self.state.settings_state.current_config.expert_mode,
+                asset_filter: self.client.asset_filter().clone(),
+                routing: self.client.routing().clone(),
+                indexer: saved.indexer,
+                pricing: crate::config::PricingConfig {
+                    usd_denoms: self.client.usd_denoms().to_vec(),
+                    max_trade_value: self.client.trade_value_limit().cloned(),
+                },
+                storage: saved.storage,
+                price_history: saved.price_history,
             };
             self.state.settings_state =
                 crate::tui::screens::settings::SettingsState::new(current_config);
+            self.state
+                .settings_state
+                .load_preferences(self.preferences.get());
         }
         Ok(())
     }
@@ -4078,21 +5383,43 @@ impl App {
         // If showing confirmation, handle confirmation
         if self.state.settings_state.show_confirmation {
             // Save settings
+            let reconnect = self
+                .state
+                .settings_state
+                .pending_changes()
+                .iter()
+                .any(|change| change.reconnect);
             match self.state.settings_state.save_settings() {
                 Ok(new_config) => {
                     // Update application config
                     self.config = new_config.network;
                     self.state.settings_state.show_confirmation = false;
-                    self.set_success("Settings saved successfully!".to_string());
+                    let settings = &self.state.settings_state;
+                    match self
+                        .preferences
+                        .update(|preferences| settings.apply_to_preferences(preferences))
+                    {
+                        Ok(_) => {
+                            let preferences = self.preferences.get().clone();
+                            self.state.settings_state.load_preferences(&preferences);
+                            self.set_success("Settings saved successfully!".to_string());
+                        }
+                        Err(e) => self.set_error(format!("Failed to save preferences: {}", e)),
+                    }
+                    if reconnect {
+                        if let Err(e) = self.reconnect_client().await {
+                            self.set_error(format!("Failed to reconnect: {}", e));
+                        }
+                    }
                 }
                 Err(e) => {
                     self.set_error(format!("Failed to save settings: {}", e));
                     self.state.settings_state.show_confirmation = false;
                 }
             }
-        } else if self.state.settings_state.has_changes {
-            // Show confirmation dialog
-            self.state.settings_state.show_confirmation = true;
+        } else if self.state.settings_state.has_changes() {
+            // Show the pending changes to confirm
+            self.state.settings_state.request_save();
         } else {
             // No changes, just refresh
             self.refresh_settings_data().await?;
@@ -4108,12 +5435,7 @@ impl App {
         match c {
             // Ctrl+S - Save settings
             '\x13' => {
-                if self.state.settings_state.has_changes {
-                    self.state.settings_state.show_confirmation = true;
-                } else {
-                    self.state.settings_state.message =
-                        Some(("No changes to save".to_string(), false));
-                }
+                self.state.settings_state.request_save();
             }
             // Ctrl+R - Reset settings
             '\x12' => {
@@ -4152,6 +5474,13 @@ impl App {
                     self.state.settings_state.toggle_mnemonic_visibility();
                 }
             }
+            'x' => {
+                if self.state.settings_state.current_section
+                    == crate::tui::screens::settings::SettingsSection::Trading
+                {
+                    self.state.settings_state.toggle_expert_mode();
+                }
+            }
             // Escape key handling
             '\x1b' => {
                 if self.state.settings_state.show_confirmation {
@@ -4175,9 +5504,9 @@ impl App {
     }
 
     /// Handle wizard-specific events
-    async fn handle_wizard_event(&mut self, event: Event) -> Result<bool, Error> {
+    async fn handle_wizard_event(&mut self, event: UiEvent) -> Result<bool, Error> {
         match event {
-            Event::Quit => {
+            UiEvent::Quit => {
                 // If we're in mnemonic input mode, treat 'q' as a regular character
                 if self.state.wizard_state.current_step
                     == crate::tui::screens::wizard::WizardStep::WalletSetup
@@ -4189,7 +5518,7 @@ impl App {
                     return Ok(true);
                 }
             }
-            Event::Escape => {
+            UiEvent::Escape => {
                 // Go back a step or exit wizard
                 if self.state.wizard_state.current_step
                     == crate::tui::screens::wizard::WizardStep::Welcome
@@ -4200,7 +5529,7 @@ impl App {
                     self.state.wizard_state.previous_step();
                 }
             }
-            Event::Enter => {
+            UiEvent::Enter => {
                 // Proceed to next step or finish wizard
                 if self.state.wizard_state.can_proceed() {
                     if self.state.wizard_state.current_step
@@ -4218,7 +5547,7 @@ impl App {
                 }
             }
             // Add MoveFocus event handling for wizard navigation
-            Event::MoveFocus(direction) => {
+            UiEvent::MoveFocus(direction) => {
                 match self.state.wizard_state.current_step {
                     crate::tui::screens::wizard::WizardStep::NetworkSelection => {
                         // Handle Up/Down arrows for network selection
@@ -4248,14 +5577,19 @@ impl App {
                     _ => {} // Other steps don't need MoveFocus handling
                 }
             }
-            Event::Tab => {
+            UiEvent::Tab => {
                 // Navigate between options in current step
                 match self.state.wizard_state.current_step {
                     crate::tui::screens::wizard::WizardStep::NetworkSelection => {
                         self.state.wizard_state.toggle_network();
                     }
                     crate::tui::screens::wizard::WizardStep::WalletSetup => {
-                        self.state.wizard_state.toggle_wallet_mode();
+                        if let Err(e) = self.state.wizard_state.toggle_wallet_mode() {
+                            self.set_error_with_type(
+                                format!("Failed to generate a wallet: {}", e),
+                                ErrorType::Wallet,
+                            );
+                        }
                     }
                     crate::tui::screens::wizard::WizardStep::WalletSave => {
                         self.state.wizard_state.wallet_save_focus_next();
@@ -4263,14 +5597,19 @@ impl App {
                     _ => {}
                 }
             }
-            Event::BackTab => {
+            UiEvent::BackTab => {
                 // Navigate between options in current step (reverse)
                 match self.state.wizard_state.current_step {
                     crate::tui::screens::wizard::WizardStep::NetworkSelection => {
                         self.state.wizard_state.toggle_network();
                     }
                     crate::tui::screens::wizard::WizardStep::WalletSetup => {
-                        self.state.wizard_state.toggle_wallet_mode();
+                        if let Err(e) = self.state.wizard_state.toggle_wallet_mode() {
+                            self.set_error_with_type(
+                                format!("Failed to generate a wallet: {}", e),
+                                ErrorType::Wallet,
+                            );
+                        }
                     }
                     crate::tui::screens::wizard::WizardStep::WalletSave => {
                         self.state.wizard_state.wallet_save_focus_previous();
@@ -4278,7 +5617,7 @@ impl App {
                     _ => {}
                 }
             }
-            Event::Char(c) => {
+            UiEvent::Char(c) => {
                 // Handle character input for mnemonic or other text fields
                 match self.state.wizard_state.current_step {
                     crate::tui::screens::wizard::WizardStep::WalletSetup => {
@@ -4299,7 +5638,7 @@ impl App {
                     _ => {}
                 }
             }
-            Event::Backspace => {
+            UiEvent::Backspace => {
                 // Handle backspace for text input
                 match self.state.wizard_state.current_step {
                     crate::tui::screens::wizard::WizardStep::WalletSetup => {
@@ -4314,7 +5653,7 @@ impl App {
                 }
             }
             // Treat context action (space bar) as a space character when typing mnemonic
-            Event::ContextAction => {
+            UiEvent::ContextAction => {
                 if self.state.wizard_state.current_step
                     == crate::tui::screens::wizard::WizardStep::WalletSetup
                     && self.state.wizard_state.import_existing
@@ -4328,7 +5667,7 @@ impl App {
                 }
             }
             // Handle paste events (bracketed paste) for mnemonic field
-            Event::Paste(text) => {
+            UiEvent::Paste(text) => {
                 if self.state.wizard_state.current_step
                     == crate::tui::screens::wizard::WizardStep::WalletSetup
                     && self.state.wizard_state.import_existing
@@ -4359,7 +5698,7 @@ impl App {
         {
             // Import wallet from mnemonic
             match crate::wallet::MantraWallet::from_mnemonic(
-                &self.state.wizard_state.mnemonic_input,
+                self.state.wizard_state.mnemonic_input.expose_secret(),
                 0,
             ) {
                 Ok(wallet) => {
@@ -4367,7 +5706,11 @@ impl App {
                         Ok(address) => {
                             self.set_wallet_address(address.to_string());
                             // Reconfigure the client so all future calls have the wallet attached
-                            self.configure_client_wallet(wallet).await?;
+                            self.configure_client_wallet(
+                                wallet,
+                                crate::wallet::WalletDefaults::default(),
+                            )
+                            .await?;
                             self.set_status("Wallet imported successfully".to_string());
                         }
                         Err(e) => {
@@ -4384,13 +5727,17 @@ impl App {
         } else if !self.state.wizard_state.import_existing {
             // Create new wallet
             if let Some(mnemonic) = &self.state.wizard_state.generated_mnemonic {
-                match crate::wallet::MantraWallet::from_mnemonic(mnemonic, 0) {
+                match crate::wallet::MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0) {
                     Ok(wallet) => {
                         match wallet.address() {
                             Ok(address) => {
                                 self.set_wallet_address(address.to_string());
                                 // Reconfigure the client with the newly generated wallet
-                                self.configure_client_wallet(wallet).await?;
+                                self.configure_client_wallet(
+                                    wallet,
+                                    crate::wallet::WalletDefaults::default(),
+                                )
+                                .await?;
                                 self.set_status("New wallet created successfully".to_string());
                             }
                             Err(e) => {
@@ -4417,19 +5764,17 @@ impl App {
             }
         }
 
-        // Trigger dashboard refresh to reflect new wallet and network state
-        if let Err(e) = self.refresh_dashboard_data().await {
-            crate::tui::utils::logger::log_warning(&format!(
-                "Failed to refresh dashboard data: {}",
-                e
-            ));
+        // Refetch the dashboard's data to reflect the new wallet and network state
+        self.data_loader.invalidate(Screen::Dashboard);
+        if self.state.current_screen == Screen::Dashboard {
+            self.load_screen_data(false);
         }
 
         Ok(())
     }
 
     /// Get the current wallet mnemonic from wizard state
-    fn get_current_wallet_mnemonic(&self) -> Option<String> {
+    fn get_current_wallet_mnemonic(&self) -> Option<SecretString> {
         if self.state.wizard_state.import_existing {
             // For imported wallets, return the entered mnemonic
             if !self.state.wizard_state.mnemonic_input.is_empty() {
@@ -4446,7 +5791,7 @@ impl App {
     /// Save wallet from wizard with user's chosen settings
     async fn save_wallet_from_wizard(
         &mut self,
-        mnemonic: String,
+        mnemonic: SecretString,
         address: String,
     ) -> Result<(), Error> {
         self.set_loading("Saving wallet...".to_string());
@@ -4456,8 +5801,8 @@ impl App {
 
         match storage.save_wallet(
             &self.state.wizard_state.wallet_name,
-            &mnemonic,
-            &self.state.wizard_state.save_password,
+            mnemonic.expose_secret(),
+            self.state.wizard_state.save_password.expose_secret(),
             &address,
         ) {
             Ok(()) => {
@@ -4477,34 +5822,24 @@ impl App {
         Ok(())
     }
 
-    /// Show modal to save wallet with password protection
-    fn show_wallet_save_modal(&mut self, mnemonic: String, address: String) {
-        // Create wallet save modal with password input
-        let title = "Save Wallet".to_string();
-        let message = format!(
-            "Would you like to save this wallet for future use?\n\n\
-            Wallet Address: {}\n\n\
-            Saving your wallet allows you to quickly access it next time without \
-            re-entering your mnemonic. Your wallet will be encrypted with a strong password.",
-            &address[..20] // Show partial address for security
-        );
-
-        self.state.modal_state = Some(crate::tui::components::modals::ModalState::wallet_save(
-            title, message, mnemonic, address,
-        ));
-    }
-
     /// Update the underlying client with a newly provided wallet and restart background tasks
     async fn configure_client_wallet(
         &mut self,
         wallet: crate::wallet::MantraWallet,
+        defaults: crate::wallet::WalletDefaults,
     ) -> Result<(), Error> {
         // Stop any currently running background sync tasks so they don't keep using the stale client
         self.stop_background_tasks();
 
-        // Re-create a fresh client instance that includes the wallet
-        let mut new_client = MantraDexClient::new(self.config.clone()).await?;
-        new_client = new_client.with_wallet(wallet);
+        // Re-create a fresh client instance that includes the wallet, keeping the other settings
+        let new_client = self
+            .client
+            .to_builder()
+            .with_network_config(self.config.clone())
+            .with_wallet(wallet)
+            .with_wallet_defaults(defaults)
+            .build()
+            .await?;
 
         // Replace the old Arc so all subsequent operations use the updated client
         self.client = std::sync::Arc::new(new_client);
@@ -4517,6 +5852,81 @@ impl App {
         Ok(())
     }
 
+    /// Reconnect the client to the network settings just saved and restart background tasks
+    ///
+    /// Cached chain data came from the previous network, so every screen reloads.
+    async fn reconnect_client(&mut self) -> Result<(), Error> {
+        self.stop_background_tasks();
+        let new_client = self.client.reconnect(self.config.clone()).await?;
+        self.client = std::sync::Arc::new(new_client);
+        if let Some(sender) = self.event_sender.clone() {
+            self.initialize_background_tasks(sender);
+        }
+        self.data_loader.invalidate_all();
+        self.load_screen_data(false);
+        Ok(())
+    }
+
+    /// Sample the depth curve of `pool_id` up to twice the entered amount
+    async fn load_swap_depth_curve(
+        &mut self,
+        pool_id: &str,
+        from_token: &str,
+        to_token: &str,
+        amount: &str,
+    ) -> Result<(), Error> {
+        let pool = match self.state.pool_cache.get(pool_id) {
+            Some(entry) => entry.pool_info.clone(),
+            None => return Err(Error::Other(format!("Pool {} is not loaded", pool_id))),
+        };
+        let assets = pool.pool_info.assets.clone();
+
+        let offer_denom = self.map_display_name_to_denom(from_token, &assets);
+        // Ask for the token chosen on the swap screen, or the first other asset
+        let to_denom = self.map_display_name_to_denom(to_token, &assets);
+        let ask_denom = assets
+            .iter()
+            .map(|asset| asset.denom.clone())
+            .filter(|denom| *denom != offer_denom)
+            .min_by_key(|denom| *denom != to_denom)
+            .ok_or_else(|| Error::Other(format!("Pool {} has no ask asset", pool_id)))?;
+
+        // Amounts are entered in whole tokens of the offer asset
+        let decimals = assets
+            .iter()
+            .position(|asset| asset.denom == offer_denom)
+            .and_then(|index| pool.pool_info.asset_decimals.get(index).copied())
+            .unwrap_or(6);
+        let scale = 10f64.powi(decimals as i32);
+        let amount = amount
+            .parse::<f64>()
+            .map_err(|_| Error::Other(format!("Invalid amount: {}", amount)))?;
+        let offer = cosmwasm_std::Coin {
+            denom: offer_denom.clone(),
+            amount: Uint128::new((amount * scale) as u128),
+        };
+        let max_offer = cosmwasm_std::Coin {
+            denom: offer_denom,
+            amount: Uint128::new((amount * 2.0 * scale) as u128),
+        };
+
+        // Preview the swap from the cached reserves before sampling the curve
+        self.state.swap_state.simulation_result =
+            crate::math::simulate_swap(&pool, &offer, &ask_denom);
+
+        let curve = self
+            .client
+            .depth_curve(
+                pool_id,
+                &max_offer,
+                &ask_denom,
+                crate::routing::DEFAULT_DEPTH_STEPS,
+            )
+            .await?;
+        self.state.swap_state.depth_curve = Some(curve);
+        Ok(())
+    }
+
     /// Map display name back to actual denomination
     fn map_display_name_to_denom(
         &self,
@@ -4562,49 +5972,18 @@ impl App {
         display_name.to_string()
     }
 
-    /// Convert token denomination to display symbol
-    /// Maps micro denominations (uUSDC, uom) to their symbols (USDC, OM)
+    /// Convert token denomination to display symbol, as resolved by the client's
+    /// token registry (OM for uom, USDC for a factory USDC denom)
     pub fn denom_to_symbol(&self, denom: &str) -> String {
-        // Handle common token mappings
-        match denom {
-            "uom" => "OM".to_string(),
-            d if d.starts_with("factory/") && d.contains("/uUSDC") => "USDC".to_string(),
-            d if d.starts_with("factory/") && d.contains("/uUSDT") => "USDT".to_string(),
-            d if d.starts_with("factory/") && d.contains("/uUSDY") => "USDY".to_string(),
-            d if d.starts_with("factory/") && d.contains("/aUSDY") => "aUSDY".to_string(),
-            d if d.starts_with("factory/") && d.contains("/uATOM") => "ATOM".to_string(),
-            d if d.starts_with("factory/") && d.contains("/uOSMO") => "OSMO".to_string(),
-            _ => {
-                // For other factory tokens, try to extract the last part
-                if let Some(last_part) = denom.split('/').last() {
-                    // Remove 'u' prefix if it exists and the rest looks like a symbol
-                    if last_part.starts_with('u') && last_part.len() > 1 {
-                        last_part[1..].to_string()
-                    } else {
-                        last_part.to_string()
-                    }
-                } else {
-                    denom.to_string()
-                }
-            }
-        }
+        self.client.token_registry().symbol(denom)
     }
 
     /// Get token decimals for a given denomination
-    /// Most Mantra tokens use 6 decimals
+    /// Pool-recorded decimals take precedence over the token registry
     pub fn get_token_decimals(&self, denom: &str) -> u8 {
-        // Check cache first
-        if let Some(&decimals) = self.state.asset_decimals_cache.get(denom) {
-            return decimals;
-        }
-
-        // Fallback to hardcoded values if not in cache
-        match denom {
-            "uom" => 6,
-            d if d.starts_with("factory/") => 6, // Most factory tokens use 6 decimals
-            d if d.starts_with("ibc/") => 6,     // Most IBC tokens use 6 decimals
-            d if d.starts_with("pool/") || d.contains("/lp/") => 6, // LP tokens
-            _ => 6,                              // Default to 6 decimals
+        match self.state.asset_decimals_cache.get(denom) {
+            Some(&decimals) => decimals,
+            None => self.client.token_registry().decimals(denom),
         }
     }
 
@@ -4612,6 +5991,17 @@ impl App {
     pub async fn refresh_asset_decimals_cache(&mut self) -> Result<(), Error> {
         match self.client.get_asset_decimals_from_pools().await {
             Ok(decimals_map) => {
+                // Resolve symbols of the pool assets and held tokens for display
+                let mut denoms: Vec<String> = decimals_map.keys().cloned().collect();
+                denoms.extend(self.state.balances.keys().cloned());
+                denoms.sort();
+                denoms.dedup();
+                if let Err(e) = self.client.resolve_tokens(&denoms).await {
+                    crate::tui::utils::logger::log_warning(&format!(
+                        "Failed to resolve token metadata: {}",
+                        e
+                    ));
+                }
                 self.state.asset_decimals_cache = decimals_map;
                 crate::tui::utils::logger::log_info(&format!(
                     "Asset decimals cache refreshed with {} entries",
@@ -4788,7 +6178,7 @@ impl App {
             let parsed_slippage = slippage_str
                 .parse::<f64>()
                 .ok()
-                .map(|s| cosmwasm_std::Decimal::percent((s * 100.0) as u64));
+                .map(|s| cosmwasm_std::Decimal::permille((s * 10.0).round() as u64));
             crate::tui::utils::logger::log_info(&format!(
                 "Slippage parsed: {}% -> {:?}",
                 slippage_str, parsed_slippage
@@ -4796,7 +6186,7 @@ impl App {
             parsed_slippage
         } else {
             crate::tui::utils::logger::log_info("Using default slippage: 1%");
-            Some(cosmwasm_std::Decimal::percent(100)) // 1% default slippage
+            Some(cosmwasm_std::Decimal::percent(1))
         };
 
         // Create the offer asset coin using the actual denomination
@@ -4825,7 +6215,12 @@ impl App {
         let swap_start_time = std::time::Instant::now();
         match self
             .client
-            .swap(&pool_id_str, offer_asset, &actual_to_denom, slippage)
+            .swap(
+                &pool_id_str,
+                offer_asset.clone(),
+                &actual_to_denom,
+                slippage,
+            )
             .await
         {
             Ok(tx_response) => {
@@ -4900,6 +6295,8 @@ impl App {
                     &execution_time,
                 );
 
+                self.state.failed_swap = None;
+
                 // Add to transaction history
                 let tx_info = TransactionInfo {
                     hash: tx_response.txhash.clone(),
@@ -4908,11 +6305,12 @@ impl App {
                     timestamp: chrono::Utc::now(),
                     gas_used: Some(tx_response.gas_used),
                     gas_wanted: Some(tx_response.gas_wanted),
+                    retry_of: None,
                 };
                 self.add_transaction(tx_info);
 
                 // Reset swap form
-                crate::tui::screens::swap::reset_swap_form();
+                crate::tui::screens::swap::reset_swap_form(&mut self.state);
 
                 // Refresh swap screen pools to ensure they remain available
                 if self.state.current_screen == Screen::Swap {
@@ -4965,6 +6363,18 @@ impl App {
                 crate::tui::utils::logger::log_error(&format!("  Error: {:?}", e));
                 crate::tui::utils::logger::log_error(&format!("  Error string: {}", e));
 
+                // Keep swaps rejected on slippage for a retry with a higher tolerance
+                let slippage_exceeded = e.is_slippage_exceeded();
+                self.state.failed_swap = slippage_exceeded.then(|| FailedSwap {
+                    request: SwapRequest {
+                        max_slippage: slippage,
+                        ..SwapRequest::new(pool_id_str.clone(), offer_asset, &actual_to_denom)
+                    },
+                    from_asset: from_asset.clone(),
+                    to_asset: to_asset.clone(),
+                    amount: amount.clone(),
+                });
+
                 // Check if this is a network/connection error
                 let error_str = e.to_string().to_lowercase();
                 if error_str.contains("connection")
@@ -4978,7 +6388,7 @@ impl App {
                     crate::tui::utils::logger::log_error(
                         "This appears to be an INSUFFICIENT FUNDS error",
                     );
-                } else if error_str.contains("slippage") && error_str.contains("exceeded") {
+                } else if slippage_exceeded {
                     crate::tui::utils::logger::log_error(
                         "This appears to be a SLIPPAGE LIMIT EXCEEDED error",
                     );
@@ -4993,10 +6403,7 @@ impl App {
                 }
 
                 // Determine error type and create user-friendly error handling
-                let (error_type, error_title, user_message, suggestions) = if error_str
-                    .contains("slippage")
-                    && error_str.contains("exceeded")
-                {
+                let (error_type, error_title, user_message, suggestions) = if slippage_exceeded {
                     (
                             crate::tui::components::modals::ErrorType::Validation,
                             "Slippage Limit Exceeded".to_string(),
@@ -5095,7 +6502,7 @@ impl App {
                 ];
 
                 // Show appropriate modal based on error type
-                if error_str.contains("slippage") && error_str.contains("exceeded") {
+                if slippage_exceeded {
                     // Create validation error modal for slippage issues to provide better guidance
                     self.state.modal_state = Some(
                         crate::tui::components::modals::ModalState::validation_error(
@@ -5113,9 +6520,13 @@ impl App {
                             user_message.clone(),
                             error_type.clone(),
                             Some(error_details),
-                            Some("Try again".to_string()),
+                            Some(DIAGNOSE_ACTION.to_string()),
                         ));
                 }
+                self.state.failed_transaction = Some(FailedTransaction {
+                    operation: "execute_swap".to_string(),
+                    diagnosis: Diagnosis::from_error(&e),
+                });
 
                 // Update loading state to error with persistent message
                 self.state.loading_state = LoadingState::error_with_retry(
@@ -5134,7 +6545,7 @@ impl App {
 
     /// Handle swap execute button - show confirmation modal
     pub fn handle_swap_execute_confirmation(&mut self) -> Result<(), Error> {
-        let swap_state = crate::tui::screens::swap::get_swap_screen_state();
+        let swap_state = &mut self.state.swap_screen_state;
 
         // Check if any pools are available
         if self.state.pool_cache.is_empty() {
@@ -5156,9 +6567,9 @@ impl App {
                 "Swap Validation".to_string(),
                 "Please fill in all required fields".to_string(),
                 vec![
-                    "Select a pool".to_string(),
-                    "Select from token".to_string(),
+                    "Select the tokens to swap from and to".to_string(),
                     "Enter swap amount".to_string(),
+                    "Select a pool".to_string(),
                     "Set slippage tolerance".to_string(),
                 ],
             );
@@ -5166,52 +6577,115 @@ impl App {
         }
 
         // Get swap details for confirmation
-        let from_amount = swap_state.from_amount_input.value();
+        let from_amount = swap_state.from_amount_input.value().to_string();
         let from_token = swap_state
             .from_token_dropdown
             .get_selected_value()
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .to_string();
         let pool_id = swap_state
             .pool_dropdown
             .get_selected_value()
-            .unwrap_or_default();
-        let slippage = swap_state.slippage_input.value();
+            .unwrap_or_default()
+            .to_string();
+        let pool_name = swap_state
+            .pool_dropdown
+            .get_selected_label()
+            .unwrap_or_default()
+            .to_string();
+        let slippage = swap_state.slippage_input.value().to_string();
+
+        // Get the "to" token chosen for the swap
+        let to_token = swap_state
+            .to_token()
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        // Simulate against the cached pool reserves when possible
+        let estimate = self.estimate_swap(&pool_id, &from_token, &to_token, &from_amount);
+        let from_amount_value = from_amount.parse::<f64>().unwrap_or(0.0);
+
+        // Expected output, falling back to a rough estimate without a simulation
+        let expected_output = format!(
+            "{:.6}",
+            estimate
+                .as_ref()
+                .map_or(from_amount_value * 0.95, |(output, _)| *output)
+        );
 
-        // Get the "to" token from the selected pool
-        let to_token = if let Some(pool_name) = swap_state.pool_dropdown.get_selected_label() {
-            crate::tui::screens::swap::determine_to_token_from_pool(&pool_name, &from_token)
-        } else {
-            "Unknown".to_string()
-        };
+        // Price impact (placeholder without a simulation)
+        let price_impact = estimate.as_ref().map_or(0.05, |(_, impact)| {
+            crate::tui::screens::swap::impact_percent(impact)
+        });
+
+        // Calculate fees (placeholder - would use real pool data)
+        let fee_amount = format!("{:.6}", from_amount_value * 0.003);
 
-        // Calculate expected output (placeholder - would use simulation result)
-        let expected_output = format!("{:.6}", from_amount.parse::<f64>().unwrap_or(0.0) * 0.95);
+        // Red flags from the pool's risk screening, if it has finished
+        let mut risk_warnings = self
+            .state
+            .pool_risks
+            .get(&pool_id)
+            .map(RiskReport::warnings)
+            .unwrap_or_default();
 
-        // Calculate price impact (placeholder - would use real simulation data)
-        let price_impact = 0.05; // 0.05%
+        // Expert mode executes small, low-impact swaps straight away, unless the
+        // pool was flagged
+        let trade_value_usd =
+            crate::tui::screens::swap::stablecoin_value(&from_token, from_amount_value).or_else(
+                || {
+                    estimate.as_ref().and_then(|(output, _)| {
+                        crate::tui::screens::swap::stablecoin_value(&to_token, *output)
+                    })
+                },
+            );
+        if risk_warnings.is_empty()
+            && self
+                .state
+                .settings_state
+                .current_config
+                .expert_mode
+                .skips_confirmation(trade_value_usd, estimate.is_some().then_some(price_impact))
+        {
+            crate::tui::utils::logger::log_info(&format!(
+                "Expert mode: executing swap of {} {} without confirmation",
+                from_amount, from_token
+            ));
+            if let Some(swap_event) =
+                crate::tui::screens::swap::handle_confirmation_response(&mut self.state, true)
+            {
+                if let Some(sender) = self.event_sender.as_ref() {
+                    let _ = sender.send(swap_event);
+                }
+            }
+            self.set_status(format!(
+                "Expert mode: swapping {} {} for ~{} {}",
+                from_amount, from_token, expected_output, to_token
+            ));
+            return Ok(());
+        }
 
-        // Calculate fees (placeholder - would use real pool data)
-        let fee_amount = format!("{:.6}", from_amount.parse::<f64>().unwrap_or(0.0) * 0.003);
+        // Warn about a swap that moves the price a lot
+        risk_warnings.extend(estimate.as_ref().and_then(|(_, impact)| impact.warning()));
 
         // Create swap details for confirmation
         let swap_details = crate::tui::screens::swap::SwapDetails {
-            from_amount: from_amount.to_string(),
-            from_token: from_token.to_string(),
+            from_amount,
+            from_token,
             to_amount: expected_output.clone(),
-            to_token: to_token.clone(),
-            pool_name: swap_state
-                .pool_dropdown
-                .get_selected_label()
-                .unwrap_or_default()
-                .to_string(),
-            slippage: slippage.to_string(),
-            expected_output: expected_output.clone(),
+            to_token,
+            pool_name,
+            slippage,
+            expected_output,
             price_impact,
             fee_amount,
+            risk_warnings,
         };
 
         // Show global confirmation modal
-        let confirmation_message = swap_state.show_confirmation_modal(&swap_details);
+        let confirmation_message = self
+            .state
+            .swap_screen_state
+            .show_confirmation_modal(&swap_details);
 
         self.show_confirmation(
             "Confirm Swap".to_string(),
@@ -5223,6 +6697,43 @@ impl App {
         Ok(())
     }
 
+    /// Expected output and price impact of a swap through a cached pool
+    ///
+    /// Uses the local pool math, so returns `None` when the pool isn't cached or
+    /// can't be simulated locally.
+    pub fn estimate_swap(
+        &self,
+        pool_id: &str,
+        from_token: &str,
+        to_token: &str,
+        from_amount: &str,
+    ) -> Option<(f64, crate::pricing::PriceImpact)> {
+        let pool = self.get_cached_pool(pool_id)?;
+        let info = &pool.pool_info;
+        let offer_denom = self.map_display_name_to_denom(from_token, &info.assets);
+        let ask_denom = self.map_display_name_to_denom(to_token, &info.assets);
+        let decimals = |denom: &str| {
+            info.asset_denoms
+                .iter()
+                .position(|d| d == denom)
+                .and_then(|index| info.asset_decimals.get(index).copied())
+        };
+        let offer_decimals = decimals(&offer_denom)?;
+        let ask_decimals = decimals(&ask_denom)?;
+
+        let amount = from_amount.parse::<f64>().ok()?;
+        let offer_asset = cosmwasm_std::Coin {
+            denom: offer_denom,
+            amount: cosmwasm_std::Uint128::new(
+                (amount * 10f64.powi(offer_decimals.into())) as u128,
+            ),
+        };
+        let impact = crate::pricing::price_impact(pool, &offer_asset, &ask_denom)?;
+
+        let output = impact.return_amount.u128() as f64 / 10f64.powi(ask_decimals.into());
+        Some((output, impact))
+    }
+
     /// Map display name to actual denomination using available pool assets
     /// This is a public utility for balance lookups
     pub fn map_token_name_to_denom(&self, token_name: &str) -> Option<String> {
@@ -5299,34 +6810,47 @@ impl App {
         None
     }
 
-    /// Handle slippage error retry with automatic slippage increase
+    /// Offer to retry the last swap rejected on slippage with a higher tolerance
     pub async fn handle_slippage_retry(&mut self) -> Result<(), Error> {
-        // Get current swap parameters
-        let swap_state = crate::tui::screens::swap::get_swap_screen_state();
-
-        let current_slippage = swap_state
-            .slippage_input
-            .value()
-            .parse::<f64>()
-            .unwrap_or(1.0);
-        let suggested_slippage = if current_slippage < 2.0 {
-            2.0
-        } else if current_slippage < 5.0 {
-            5.0
-        } else {
-            current_slippage + 2.0 // Increase by 2% if already high
+        let Some(failed) = self.state.failed_swap.as_ref() else {
+            self.state.modal_state = None;
+            self.set_status("No swap to retry".to_string());
+            return Ok(());
         };
 
-        // Show confirmation for increased slippage
+        let policy = SlippageBumpPolicy::default();
+        let percent = |d: cosmwasm_std::Decimal| d * cosmwasm_std::Decimal::percent(10_000);
+        let current = failed
+            .request
+            .max_slippage
+            .or(self.client.wallet_defaults().slippage)
+            .unwrap_or(crate::policy::DEFAULT_SWAP_SLIPPAGE);
+        if current >= policy.max_slippage {
+            self.show_validation_error(
+                "Slippage Limit Reached".to_string(),
+                format!(
+                    "The swap already used the highest slippage tolerance retries allow ({}%).",
+                    percent(policy.max_slippage)
+                ),
+                vec![
+                    "Try a smaller swap amount".to_string(),
+                    "Wait for better market conditions".to_string(),
+                ],
+            );
+            return Ok(());
+        }
+
+        // The retry re-simulates the swap, so the final tolerance is only a bound here
         self.show_confirmation(
-            "Increase Slippage and Retry?".to_string(),
+            SLIPPAGE_RETRY_TITLE.to_string(),
             format!(
                 "Would you like to retry the swap with increased slippage tolerance?\n\n\
-                Current: {:.1}%\n\
-                Suggested: {:.1}%\n\n\
+                Current: {}%\n\
+                Retry: {}% or what a fresh simulation expects, at most {}%\n\n\
                 Higher slippage tolerance increases the chance of success but may result in less favorable rates.",
-                current_slippage,
-                suggested_slippage
+                percent(current),
+                percent(current.saturating_add(policy.step).min(policy.max_slippage)),
+                percent(policy.max_slippage)
             ),
             Some("Retry with Higher Slippage".to_string()),
             Some("Cancel".to_string()),
@@ -5335,61 +6859,71 @@ impl App {
         Ok(())
     }
 
-    /// Apply suggested slippage and retry swap
+    /// Retry the last swap rejected on slippage through the client's slippage bump
     pub async fn retry_swap_with_increased_slippage(&mut self) -> Result<(), Error> {
-        let swap_state = crate::tui::screens::swap::get_swap_screen_state();
-
-        let current_slippage = swap_state
-            .slippage_input
-            .value()
-            .parse::<f64>()
-            .unwrap_or(1.0);
-        let suggested_slippage = if current_slippage < 2.0 {
-            2.0
-        } else if current_slippage < 5.0 {
-            5.0
-        } else {
-            current_slippage + 2.0
+        // Taken so a swap is retried at most once from the same failure
+        let Some(failed) = self.state.failed_swap.take() else {
+            return Ok(());
         };
 
-        // Update the slippage input
-        swap_state
-            .slippage_input
-            .set_value(&format!("{:.1}", suggested_slippage));
-
-        // Clear previous error modal
         self.state.modal_state = None;
         self.state.error_message = None;
+        self.set_status("Re-simulating swap with a higher slippage tolerance...".to_string());
 
-        // Show info message
-        self.set_status(format!(
-            "Increased slippage tolerance to {:.1}% - retrying swap...",
-            suggested_slippage
-        ));
-
-        // Retry the swap with the same parameters but higher slippage
-        if let Some(from_asset) = swap_state.from_token_dropdown.get_selected_value() {
-            // Get the to_asset from the pool selection
-            let to_asset = if let Some(pool_name) = swap_state.pool_dropdown.get_selected_label() {
-                crate::tui::screens::swap::determine_to_token_from_pool(&pool_name, &from_asset)
-            } else {
-                "Unknown".to_string()
-            };
-            if let Some(pool_id) = swap_state.pool_dropdown.get_selected_value() {
-                let amount = swap_state.from_amount_input.value().to_string();
+        let start = std::time::Instant::now();
+        match self
+            .client
+            .retry_swap_with_slippage_bump(&failed.request, &SlippageBumpPolicy::default())
+            .await
+        {
+            Ok(retry) => {
+                let percent = |d: cosmwasm_std::Decimal| d * cosmwasm_std::Decimal::percent(10_000);
+                if let Some(slippage) = retry.request.max_slippage {
+                    self.state
+                        .swap_screen_state
+                        .slippage_input
+                        .set_value(&percent(slippage).to_string());
+                }
 
-                self.execute_real_swap(
-                    from_asset.to_string(),
-                    to_asset,
-                    amount,
-                    Some(pool_id.to_string()),
-                    Some(format!("{:.1}", suggested_slippage)),
-                )
-                .await?;
+                let execution_time = format!("{:.2}s", start.elapsed().as_secs_f64());
+                self.show_swap_success_modal(
+                    &retry.tx,
+                    &failed.from_asset,
+                    &failed.to_asset,
+                    &failed.amount,
+                    &execution_time,
+                );
+                self.add_transaction(TransactionInfo {
+                    hash: retry.tx.txhash.clone(),
+                    status: TransactionStatus::Success,
+                    operation_type: "Swap".to_string(),
+                    timestamp: chrono::Utc::now(),
+                    gas_used: Some(retry.tx.gas_used),
+                    gas_wanted: Some(retry.tx.gas_wanted),
+                    retry_of: retry.failed_slippages.first().map(|slippage| {
+                        format!("Swap rejected at {}% slippage", percent(*slippage))
+                    }),
+                });
+                crate::tui::screens::swap::reset_swap_form(&mut self.state);
+                Ok(())
+            }
+            Err(e) => {
+                crate::tui::utils::logger::log_error(&format!("Slippage retry failed: {}", e));
+                let error_type = match e.class() {
+                    crate::error::ErrorClass::Network => ErrorType::Network,
+                    crate::error::ErrorClass::PolicyViolation => ErrorType::Validation,
+                    _ => ErrorType::Contract,
+                };
+                self.state.modal_state = Some(ModalState::error(
+                    "Swap Retry Failed".to_string(),
+                    e.to_string(),
+                    error_type,
+                    None,
+                    None,
+                ));
+                Err(e)
             }
         }
-
-        Ok(())
     }
 
     /// Show swap success modal with comprehensive transaction details
@@ -5497,7 +7031,7 @@ impl App {
 
     /// Handle pool creation execute button - show confirmation modal
     pub fn handle_pool_creation_execute_confirmation(&mut self) -> Result<(), Error> {
-        let admin_state = crate::tui::screens::admin::get_admin_screen_state();
+        let admin_state = &mut self.state.admin_screen_state;
 
         // Validate pool creation inputs
         if !admin_state.validate() {
@@ -5549,7 +7083,7 @@ impl App {
 
     /// Handle pool management execute button - show confirmation modal
     pub fn handle_pool_management_execute_confirmation(&mut self) -> Result<(), Error> {
-        let admin_state = crate::tui::screens::admin::get_admin_screen_state();
+        let admin_state = &mut self.state.admin_screen_state;
 
         // Validate pool management inputs
         if !admin_state.validate() {
@@ -5595,8 +7129,6 @@ impl App {
 
     /// Handle liquidity execute button - show confirmation modal (similar to swap screen)
     pub fn handle_liquidity_execute_confirmation(&mut self) -> Result<(), Error> {
-        let liquidity_state = crate::tui::screens::liquidity::get_liquidity_screen_state();
-
         // Check if any pools are available
         if self.state.pool_cache.is_empty() {
             self.show_validation_error(
@@ -5612,8 +7144,12 @@ impl App {
         }
 
         // Validate liquidity inputs
-        if !liquidity_state.validate() {
-            let errors = liquidity_state.clone().get_validation_errors();
+        if !self.state.liquidity_screen_state.validate() {
+            let errors = self
+                .state
+                .liquidity_screen_state
+                .clone()
+                .get_validation_errors();
             self.show_validation_error(
                 "Liquidity Validation".to_string(),
                 "Please fill in all required fields".to_string(),
@@ -5623,7 +7159,10 @@ impl App {
         }
 
         // Execute the enhanced liquidity operation confirmation
-        crate::tui::screens::liquidity::execute_liquidity_operation_with_confirmation();
+        crate::tui::screens::liquidity::execute_liquidity_operation_with_confirmation(
+            &mut self.state,
+        );
+        let liquidity_state = &self.state.liquidity_screen_state;
 
         // Get liquidity details for confirmation based on mode
         let confirmation_message = match liquidity_state.mode {
@@ -5652,9 +7191,41 @@ impl App {
                     .get_selected_label()
                     .unwrap_or("Unknown Pool");
 
+                // Exact refund at the cached reserves; the pool manager takes no withdrawal fee
+                let preview = liquidity_state
+                    .pool_dropdown
+                    .get_selected_value()
+                    .and_then(|pool_id| self.get_cached_pool(pool_id))
+                    .zip(lp_amount.parse::<cosmwasm_std::Uint128>().ok())
+                    .and_then(|(pool, amount)| crate::WithdrawalPreview::new(pool, amount));
+                let breakdown = match preview {
+                    Some(preview) => {
+                        let assets: String = preview
+                            .assets
+                            .iter()
+                            .map(|asset| {
+                                format!(
+                                    "\n  - {} {}",
+                                    self.micro_to_token_amount(
+                                        &asset.amount.to_string(),
+                                        &asset.denom
+                                    ),
+                                    self.denom_to_symbol(&asset.denom)
+                                )
+                            })
+                            .collect();
+                        format!(
+                            "\n• Share of Pool: {:.4}%\n• You Receive:{}\n• Withdrawal Fee: none (network fee only)",
+                            decimal_percent(preview.share),
+                            assets
+                        )
+                    }
+                    None => String::new(),
+                };
+
                 format!(
-                    "Confirm Withdraw Liquidity:\n\n• LP Token Amount: {}\n• Pool: {}\n\nProceed with transaction?",
-                    lp_amount, pool_name
+                    "Confirm Withdraw Liquidity:\n\n• LP Token Amount: {}\n• Pool: {}{}\n\nProceed with transaction?",
+                    lp_amount, pool_name, breakdown
                 )
             }
             crate::tui::screens::liquidity::LiquidityMode::Positions => {
@@ -5730,7 +7301,7 @@ impl App {
         ));
 
         // Update liquidity screen with reserves
-        crate::tui::screens::liquidity::update_liquidity_pool_reserves(reserves);
+        crate::tui::screens::liquidity::update_liquidity_pool_reserves(&mut self.state, reserves);
 
         Ok(())
     }
@@ -5917,6 +7488,46 @@ impl App {
         None
     }
 
+    /// Create success details for a rewards claim, listing the claimed amounts
+    fn create_claim_success_details(
+        &self,
+        result: &str,
+        transaction_hash: &Option<String>,
+        enhanced_data: &Option<String>,
+    ) -> Vec<(String, String)> {
+        let mut details = self.create_basic_success_details(result, transaction_hash);
+
+        let Some(claim) = enhanced_data.as_deref().and_then(|json| {
+            serde_json::from_str::<crate::tui::events::ClaimResultWrapper>(json).ok()
+        }) else {
+            return details;
+        };
+
+        details.push((
+            "Claimed Up To".to_string(),
+            claim
+                .until_epoch
+                .map_or("Current epoch".to_string(), |epoch| {
+                    format!("Epoch {}", epoch)
+                }),
+        ));
+        if claim.claimed.is_empty() {
+            details.push(("Rewards".to_string(), "None pending".to_string()));
+        }
+        for coin in &claim.claimed {
+            let amount = self.format_token_amount_for_display(
+                &coin.amount,
+                self.get_token_decimals(&coin.denom),
+            );
+            details.push((
+                format!("Claimed {}", self.denom_to_symbol(&coin.denom)),
+                amount,
+            ));
+        }
+
+        details
+    }
+
     /// Create basic success details for non-enhanced operations
     fn create_basic_success_details(
         &self,
@@ -5950,3 +7561,15 @@ impl App {
         }
     }
 }
+
+/// Show the last pool fetch outcome on a pool dropdown that has nothing to list
+fn show_pool_load_state(list: &mut SimpleList, state: &ListLoadState) {
+    if list.options.is_empty() {
+        list.load_state = state.clone();
+    }
+}
+
+/// A fraction as a percentage, e.g. `0.003` as `0.3`
+fn decimal_percent(fraction: cosmwasm_std::Decimal) -> f64 {
+    fraction.to_string().parse::<f64>().unwrap_or_default() * 100.0
+}
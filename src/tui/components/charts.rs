@@ -6,11 +6,16 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    widgets::{Block, Borders, Gauge, Paragraph},
+    symbols,
+    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, Paragraph},
     Frame,
 };
 use std::time::SystemTime;
 
+use cosmwasm_std::Decimal;
+
+use crate::price_history::PriceCandle;
+use crate::routing::DepthCurve;
 use crate::tui::app::{AppState, LoadingState, TransactionInfo, TransactionStatus};
 
 /// Progress bar styles for different types of operations
@@ -353,9 +358,172 @@ pub fn render_loading_progress(f: &mut Frame, loading_state: &LoadingState, area
     render_enhanced_progress_bar(f, &config, area);
 }
 
+/// Points of a depth curve as `(offer, return)` pairs for plotting
+pub fn depth_chart_data(curve: &DepthCurve) -> Vec<(f64, f64)> {
+    std::iter::once((0.0, 0.0))
+        .chain(curve.points.iter().map(|point| {
+            (
+                point.offer_amount.u128() as f64,
+                point.return_amount.u128() as f64,
+            )
+        }))
+        .collect()
+}
+
+/// Render a pool's depth curve: output amount against input size
+pub fn render_depth_chart(f: &mut Frame, curve: &DepthCurve, area: Rect) {
+    let data = depth_chart_data(curve);
+    let (max_offer, max_return) = data.last().copied().unwrap_or((0.0, 0.0));
+    let max_impact = curve
+        .points
+        .last()
+        .and_then(|point| point.price_impact.to_string().parse::<f64>().ok())
+        .unwrap_or_default()
+        * 100.0;
+
+    let dataset = Dataset::default()
+        .name(format!("{} → {}", curve.offer_denom, curve.ask_denom))
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&data);
+
+    let chart = Chart::new(vec![dataset])
+        .block(
+            Block::default()
+                .title(format!(
+                    "Depth: pool {} (impact at max {:.2}%)",
+                    curve.pool_id, max_impact
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta)),
+        )
+        .x_axis(
+            Axis::default()
+                .title("Input")
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_offer.max(1.0)])
+                .labels(["0".to_string(), format!("{:.0}", max_offer)]),
+        )
+        .y_axis(
+            Axis::default()
+                .title("Output")
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_return.max(1.0)])
+                .labels(["0".to_string(), format!("{:.0}", max_return)]),
+        );
+
+    f.render_widget(chart, area);
+}
+
+/// Closing prices of `candles` as `(minutes since the first candle, close)` pairs for plotting
+pub fn price_chart_data(candles: &[PriceCandle]) -> Vec<(f64, f64)> {
+    let Some(first) = candles.first() else {
+        return Vec::new();
+    };
+    candles
+        .iter()
+        .map(|candle| {
+            let minutes = (candle.start - first.start).num_seconds() as f64 / 60.0;
+            (minutes, decimal_f64(candle.close))
+        })
+        .collect()
+}
+
+/// Change from the first candle's open to the last candle's close, in percent
+pub fn price_change_percent(candles: &[PriceCandle]) -> Option<f64> {
+    let open = decimal_f64(candles.first()?.open);
+    let close = decimal_f64(candles.last()?.close);
+    (open > 0.0).then(|| (close / open - 1.0) * 100.0)
+}
+
+fn decimal_f64(value: Decimal) -> f64 {
+    value.to_string().parse().unwrap_or_default()
+}
+
+/// Render recorded spot prices: each candle's close as a line, with its high
+/// and low as dots around it
+pub fn render_price_chart(f: &mut Frame, candles: &[PriceCandle], title: &str, area: Rect) {
+    let closes = price_chart_data(candles);
+    let extremes: Vec<(f64, f64)> = closes
+        .iter()
+        .zip(candles)
+        .flat_map(|((minutes, _), candle)| {
+            [
+                (*minutes, decimal_f64(candle.high)),
+                (*minutes, decimal_f64(candle.low)),
+            ]
+        })
+        .collect();
+    let low = extremes
+        .iter()
+        .map(|(_, price)| *price)
+        .fold(f64::INFINITY, f64::min);
+    let high = extremes
+        .iter()
+        .map(|(_, price)| *price)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (low, high) = if low <= high { (low, high) } else { (0.0, 1.0) };
+    // Keep a flat price off the chart's edges
+    let margin = ((high - low) * 0.05)
+        .max(high.abs() * 0.001)
+        .max(f64::EPSILON);
+    let span = closes.last().map_or(0.0, |(minutes, _)| *minutes);
+
+    let change = price_change_percent(candles)
+        .map(|change| format!(" ({:+.2}%)", change))
+        .unwrap_or_default();
+    let last = candles
+        .last()
+        .map(|candle| candle.close.to_string())
+        .unwrap_or_default();
+    let time = |index: usize| {
+        candles
+            .get(index)
+            .map(|candle| candle.start.format("%H:%M").to_string())
+            .unwrap_or_default()
+    };
+
+    let datasets = vec![
+        Dataset::default()
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(Color::DarkGray))
+            .data(&extremes),
+        Dataset::default()
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&closes),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(format!("{}: {}{}", title, last, change))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, span.max(1.0)])
+                .labels([time(0), time(candles.len().saturating_sub(1))]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([low - margin, high + margin])
+                .labels([format!("{:.4}", low), format!("{:.4}", high)]),
+        );
+
+    f.render_widget(chart, area);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cosmwasm_std::Uint128;
     use std::time::Duration;
 
     #[test]
@@ -421,4 +589,47 @@ mod tests {
         config.update(-50.0, None);
         assert_eq!(config.progress, 0.0);
     }
+
+    #[test]
+    fn test_depth_chart_data_starts_at_origin() {
+        let curve = DepthCurve::from_samples(
+            "1",
+            "uom",
+            "uusdc",
+            [(100u128, 200u128), (200, 380)]
+                .map(|(offer, ret)| (Uint128::new(offer), Uint128::new(ret))),
+        );
+
+        assert_eq!(
+            depth_chart_data(&curve),
+            vec![(0.0, 0.0), (100.0, 200.0), (200.0, 380.0)]
+        );
+    }
+
+    #[test]
+    fn test_price_chart_data_plots_closes_over_minutes() {
+        use chrono::{TimeZone, Utc};
+
+        let candle = |minute: u32, open: u64, close: u64| PriceCandle {
+            start: Utc.with_ymd_and_hms(2026, 10, 17, 12, minute, 0).unwrap(),
+            open: Decimal::percent(open),
+            high: Decimal::percent(open.max(close)),
+            low: Decimal::percent(open.min(close)),
+            close: Decimal::percent(close),
+            samples: 3,
+        };
+        let candles = [
+            candle(0, 200, 250),
+            candle(15, 250, 220),
+            candle(45, 220, 300),
+        ];
+
+        assert_eq!(
+            price_chart_data(&candles),
+            vec![(0.0, 2.5), (15.0, 2.2), (45.0, 3.0)]
+        );
+        assert_eq!(price_change_percent(&candles), Some(50.0));
+        assert!(price_chart_data(&[]).is_empty());
+        assert_eq!(price_change_percent(&[]), None);
+    }
 }
@@ -3,6 +3,10 @@
 //! This module provides reusable form input components including text inputs
 //! with validation, dropdowns for selections, and checkboxes for toggles.
 //!
+//! Screens describe their fields declaratively with [`Form`] and implement
+//! [`FormFields`] to get focus order, key handling, validation and dirty
+//! tracking without repeating per-field code.
+//!
 //! # Example Usage
 //!
 //! ```rust
@@ -34,6 +38,10 @@
 //! }
 //! ```
 
+use super::simple_list::{ListEvent, SimpleList};
+use crate::amount::NumberLocale;
+use crate::tui::utils::validation;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -41,6 +49,7 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use std::str::FromStr;
 use tui_input::{Input, InputRequest, InputResponse};
 
 /// Text input component with validation for addresses, amounts, and pool IDs
@@ -60,6 +69,10 @@ pub struct TextInput {
     required: bool,
     /// Placeholder text
     placeholder: String,
+    /// Separators amounts are typed with
+    locale: NumberLocale,
+    /// Amount as plain digits with a `.` decimal point, once the input reads as one
+    canonical: Option<String>,
 }
 
 /// Input validation types
@@ -90,6 +103,8 @@ impl TextInput {
             input_type: InputType::Text,
             required: false,
             placeholder: String::new(),
+            locale: NumberLocale::default(),
+            canonical: None,
         }
     }
 
@@ -112,14 +127,47 @@ impl TextInput {
     }
 
     /// Set the current value
+    ///
+    /// Amounts are given in canonical form, e.g. `1234.5`, and shown with the
+    /// input's decimal separator.
     pub fn with_value(mut self, value: impl Into<String>) -> Self {
-        self.input = Input::default().with_value(value.into());
+        self.input = Input::default().with_value(self.localize(value.into()));
+        self.update_canonical();
         self
     }
 
     /// Set the current value of the input field.
+    ///
+    /// Amounts are given in canonical form, as for [`with_value`](Self::with_value).
     pub fn set_value(&mut self, value: &str) {
-        self.input = self.input.clone().with_value(value.to_string());
+        self.input = self
+            .input
+            .clone()
+            .with_value(self.localize(value.to_string()));
+        self.update_canonical();
+    }
+
+    /// Read amounts with the separators of `locale`
+    ///
+    /// An amount already entered is rewritten with the new separators.
+    pub fn set_locale(&mut self, locale: NumberLocale) {
+        let canonical = self.canonical.take();
+        self.locale = locale;
+        match canonical {
+            Some(canonical) => self.set_value(&canonical),
+            None => self.update_canonical(),
+        }
+    }
+
+    /// Canonical `value` of an amount input written with the input's decimal separator
+    fn localize(&self, value: String) -> String {
+        let canonical =
+            self.input_type == InputType::Amount && cosmwasm_std::Decimal::from_str(&value).is_ok();
+        if canonical && self.locale.decimal_separator() != '.' {
+            value.replace('.', &self.locale.decimal_separator().to_string())
+        } else {
+            value
+        }
     }
 
     /// Set focus state
@@ -133,7 +181,17 @@ impl TextInput {
     }
 
     /// Get the current value
+    ///
+    /// Amounts are canonicalized, e.g. `1.234,5` typed under the comma locale
+    /// reads as `1234.5`, so the value can be parsed and put in messages as is.
     pub fn value(&self) -> &str {
+        self.canonical
+            .as_deref()
+            .unwrap_or_else(|| self.input.value())
+    }
+
+    /// Get the text as typed
+    pub fn raw_value(&self) -> &str {
         self.input.value()
     }
 
@@ -141,11 +199,23 @@ impl TextInput {
     pub fn clear(&mut self) {
         self.input = Input::default();
         self.error = None;
+        self.canonical = None;
+    }
+
+    /// Canonical form of an amount input's text, if it reads as an amount
+    fn update_canonical(&mut self) {
+        self.canonical = match self.input_type {
+            InputType::Amount => validation::validate_amount(self.input.value(), self.locale)
+                .ok()
+                .map(|amount| amount.to_string()),
+            _ => None,
+        };
     }
 
     /// Handle keyboard input
     pub fn handle_input(&mut self, request: InputRequest) -> InputResponse {
         let response = self.input.handle(request);
+        self.update_canonical();
         // Clear error when user starts typing
         if matches!(request, InputRequest::InsertChar(_)) {
             self.error = None;
@@ -171,13 +241,21 @@ impl TextInput {
             return true;
         }
 
-        match self.input_type {
-            InputType::Text => true,
-            InputType::Address => self.validate_address(&value),
-            InputType::Amount => self.validate_amount(&value),
-            InputType::PoolId => self.validate_pool_id(&value),
-            InputType::Email => self.validate_email(&value),
-            InputType::Password => self.validate_password(&value),
+        let result = match self.input_type {
+            InputType::Text => Ok(()),
+            InputType::Address => validation::validate_address(&value),
+            InputType::Amount => validation::validate_amount(&value, self.locale).map(|_| ()),
+            InputType::PoolId => validation::validate_pool_id(&value).map(|_| ()),
+            InputType::Email => validation::validate_email(&value),
+            InputType::Password => validation::validate_password(&value),
+        };
+
+        match result {
+            Ok(()) => true,
+            Err(error) => {
+                self.error = Some(error);
+                false
+            }
         }
     }
 
@@ -191,58 +269,6 @@ impl TextInput {
         self.error.is_none()
     }
 
-    fn validate_address(&mut self, value: &str) -> bool {
-        // Basic address validation (mantra addresses start with "mantra")
-        if value.starts_with("mantra") && value.len() >= 40 {
-            true
-        } else {
-            self.error = Some("Invalid address format (should start with 'mantra')".to_string());
-            false
-        }
-    }
-
-    fn validate_amount(&mut self, value: &str) -> bool {
-        match value.parse::<f64>() {
-            Ok(amount) if amount >= 0.0 => true,
-            Ok(_) => {
-                self.error = Some("Amount must be positive".to_string());
-                false
-            }
-            Err(_) => {
-                self.error = Some("Invalid amount format".to_string());
-                false
-            }
-        }
-    }
-
-    fn validate_pool_id(&mut self, value: &str) -> bool {
-        match value.parse::<u64>() {
-            Ok(_) => true,
-            Err(_) => {
-                self.error = Some("Pool ID must be a number".to_string());
-                false
-            }
-        }
-    }
-
-    fn validate_email(&mut self, value: &str) -> bool {
-        if value.contains('@') && value.contains('.') {
-            true
-        } else {
-            self.error = Some("Invalid email format".to_string());
-            false
-        }
-    }
-
-    fn validate_password(&mut self, value: &str) -> bool {
-        if value.len() >= 8 {
-            true
-        } else {
-            self.error = Some("Password must be at least 8 characters".to_string());
-            false
-        }
-    }
-
     /// Render the text input
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
@@ -527,6 +553,21 @@ impl<T: Clone> Dropdown<T> {
         }
     }
 
+    /// Replace the options, keeping the selected value if it is still offered
+    pub fn set_options(&mut self, options: Vec<DropdownOption<T>>)
+    where
+        T: PartialEq,
+    {
+        let selected = self.selected_value().cloned();
+        self.options = options;
+        self.selected = None;
+        self.open = false;
+        self.highlighted = None;
+        if let Some(value) = selected {
+            self.select_by_value(&value);
+        }
+    }
+
     /// Render the dropdown
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
@@ -796,6 +837,352 @@ impl Toggle {
     }
 }
 
+/// Kind of control behind a form field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Free text input
+    Text,
+    /// Numeric text input that only accepts digits and a decimal point
+    Amount,
+    /// Selection list
+    Dropdown,
+    /// Boolean toggle, flipped with Enter or Space
+    Checkbox,
+    /// Submit button, activated with Enter or Space once the form validates
+    Submit,
+    /// Field whose keys are handled by the owning screen
+    Custom,
+}
+
+/// Declarative description of a single form field
+#[derive(Debug, Clone)]
+pub struct FieldSpec<K> {
+    pub key: K,
+    pub kind: FieldKind,
+    /// Error reported when the field is left empty
+    pub missing: Option<&'static str>,
+    /// Error reported when the field holds an invalid value
+    pub invalid: Option<&'static str>,
+}
+
+/// Ordered field list for a form, plus whether it has unsaved edits
+///
+/// Field order is the focus order; navigation wraps at both ends.
+#[derive(Debug, Clone)]
+pub struct Form<K> {
+    fields: Vec<FieldSpec<K>>,
+    dirty: bool,
+}
+
+impl<K> Default for Form<K> {
+    fn default() -> Self {
+        Self {
+            fields: Vec::new(),
+            dirty: false,
+        }
+    }
+}
+
+impl<K: Copy + PartialEq> Form<K> {
+    /// Create an empty form
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn field(
+        mut self,
+        key: K,
+        kind: FieldKind,
+        missing: Option<&'static str>,
+        invalid: Option<&'static str>,
+    ) -> Self {
+        self.fields.push(FieldSpec {
+            key,
+            kind,
+            missing,
+            invalid,
+        });
+        self
+    }
+
+    /// Add a text field
+    pub fn text(self, key: K, missing: &'static str, invalid: &'static str) -> Self {
+        self.field(key, FieldKind::Text, Some(missing), Some(invalid))
+    }
+
+    /// Add an amount field
+    pub fn amount(self, key: K, missing: &'static str, invalid: &'static str) -> Self {
+        self.field(key, FieldKind::Amount, Some(missing), Some(invalid))
+    }
+
+    /// Add a dropdown that must have a selection
+    pub fn dropdown(self, key: K, missing: &'static str) -> Self {
+        self.field(key, FieldKind::Dropdown, Some(missing), None)
+    }
+
+    /// Add a dropdown that may be left without a selection
+    pub fn optional_dropdown(self, key: K) -> Self {
+        self.field(key, FieldKind::Dropdown, None, None)
+    }
+
+    /// Add a checkbox
+    pub fn checkbox(self, key: K) -> Self {
+        self.field(key, FieldKind::Checkbox, None, None)
+    }
+
+    /// Add a field handled by the owning screen
+    pub fn custom(self, key: K) -> Self {
+        self.field(key, FieldKind::Custom, None, None)
+    }
+
+    /// Add the submit button
+    pub fn submit(self, key: K) -> Self {
+        self.field(key, FieldKind::Submit, None, None)
+    }
+
+    /// Field specs in focus order
+    pub fn fields(&self) -> &[FieldSpec<K>] {
+        &self.fields
+    }
+
+    /// Kind of the field with `key`, if it is part of this form
+    pub fn kind(&self, key: K) -> Option<FieldKind> {
+        self.position(key).map(|index| self.fields[index].kind)
+    }
+
+    /// First field in focus order
+    pub fn first(&self) -> Option<K> {
+        self.fields.first().map(|field| field.key)
+    }
+
+    /// Field after `key`, or the first field if `key` isn't part of this form
+    pub fn next(&self, key: K) -> Option<K> {
+        let index = match self.position(key) {
+            Some(index) => (index + 1) % self.fields.len(),
+            None => 0,
+        };
+        self.fields.get(index).map(|field| field.key)
+    }
+
+    /// Field before `key`, or the last field if `key` isn't part of this form
+    pub fn previous(&self, key: K) -> Option<K> {
+        let index = match self.position(key) {
+            Some(0) | None => self.fields.len().checked_sub(1)?,
+            Some(index) => index - 1,
+        };
+        self.fields.get(index).map(|field| field.key)
+    }
+
+    /// Whether any field changed since the form was last marked clean
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Record an edit
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Forget recorded edits, e.g. after saving or submitting
+    pub fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
+
+    fn position(&self, key: K) -> Option<usize> {
+        self.fields.iter().position(|field| field.key == key)
+    }
+}
+
+/// Mutable access to the control backing a form field
+pub enum FieldMut<'a> {
+    Text(&'a mut TextInput),
+    Dropdown(&'a mut SimpleList),
+    Checkbox(&'a mut Checkbox),
+    /// Submit buttons and custom fields have no backing control
+    None,
+}
+
+/// Outcome of passing a key to a form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormEvent {
+    /// The focused field didn't use the key
+    Ignored,
+    /// The key was consumed without changing a value
+    Handled,
+    /// A text value or checkbox changed
+    Changed,
+    /// A dropdown selection was confirmed; focus moved to the next field
+    Selected,
+    /// A dropdown was closed without selecting; focus moved to the next field
+    Cancelled,
+    /// The submit button was activated and the form is valid
+    Submit,
+    /// The submit button was activated but validation failed
+    Invalid,
+}
+
+/// Screen state built on a declarative [`Form`]
+///
+/// Implementors map field keys to their controls; focus movement, key
+/// handling, validation and dirty tracking come from the provided methods.
+pub trait FormFields {
+    type Key: Copy + PartialEq;
+
+    /// The form currently being edited
+    fn form(&self) -> &Form<Self::Key>;
+    fn form_mut(&mut self) -> &mut Form<Self::Key>;
+    fn focused_field(&self) -> Self::Key;
+    fn set_focused_field(&mut self, key: Self::Key);
+    fn field_mut(&mut self, key: Self::Key) -> FieldMut<'_>;
+
+    /// Move focus to the next field
+    fn focus_next_field(&mut self) {
+        if let Some(key) = self.form().next(self.focused_field()) {
+            self.set_focused_field(key);
+        }
+        self.apply_form_focus();
+    }
+
+    /// Move focus to the previous field
+    fn focus_previous_field(&mut self) {
+        if let Some(key) = self.form().previous(self.focused_field()) {
+            self.set_focused_field(key);
+        }
+        self.apply_form_focus();
+    }
+
+    /// Sync each control's focus flag with the focused field
+    fn apply_form_focus(&mut self) {
+        let focused = self.focused_field();
+        let keys: Vec<Self::Key> = self.form().fields().iter().map(|f| f.key).collect();
+        for key in keys {
+            let is_focused = key == focused;
+            match self.field_mut(key) {
+                FieldMut::Text(input) => input.set_focused(is_focused),
+                FieldMut::Dropdown(list) => list.set_active(is_focused),
+                FieldMut::Checkbox(checkbox) => checkbox.set_focused(is_focused),
+                FieldMut::None => {}
+            }
+        }
+    }
+
+    /// Pass a key to the focused field
+    ///
+    /// Tab and Shift+Tab move between fields; every other key goes to the
+    /// focused control.
+    fn handle_form_key(&mut self, key: KeyEvent) -> FormEvent {
+        match key.code {
+            KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.focus_previous_field();
+                return FormEvent::Handled;
+            }
+            KeyCode::Tab => {
+                self.focus_next_field();
+                return FormEvent::Handled;
+            }
+            KeyCode::BackTab => {
+                self.focus_previous_field();
+                return FormEvent::Handled;
+            }
+            _ => {}
+        }
+
+        let focused = self.focused_field();
+        let Some(kind) = self.form().kind(focused) else {
+            return FormEvent::Ignored;
+        };
+
+        let event = match self.field_mut(focused) {
+            // Swallow characters an amount can't contain so they don't reach global shortcuts
+            FieldMut::Text(_)
+                if kind == FieldKind::Amount
+                    && matches!(key.code, KeyCode::Char(c) if !(c.is_ascii_digit() || c == '.')) =>
+            {
+                FormEvent::Handled
+            }
+            FieldMut::Text(input) => match text_request(key) {
+                Some(request) if input.handle_input(request).is_some() => FormEvent::Changed,
+                _ => FormEvent::Ignored,
+            },
+            FieldMut::Dropdown(list) => match list.handle_key_event(key) {
+                ListEvent::SelectionMade => FormEvent::Selected,
+                ListEvent::SelectionCancelled => FormEvent::Cancelled,
+                ListEvent::Handled => FormEvent::Handled,
+                ListEvent::Ignored => FormEvent::Ignored,
+            },
+            FieldMut::Checkbox(checkbox) => match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    checkbox.toggle();
+                    FormEvent::Changed
+                }
+                _ => FormEvent::Ignored,
+            },
+            FieldMut::None => FormEvent::Ignored,
+        };
+
+        let event = match (kind, key.code) {
+            (FieldKind::Submit, KeyCode::Enter | KeyCode::Char(' ')) => {
+                if self.validate_form() {
+                    FormEvent::Submit
+                } else {
+                    FormEvent::Invalid
+                }
+            }
+            _ => event,
+        };
+
+        if matches!(event, FormEvent::Changed | FormEvent::Selected) {
+            self.form_mut().mark_dirty();
+        }
+        if matches!(event, FormEvent::Selected | FormEvent::Cancelled) {
+            self.focus_next_field();
+        }
+        event
+    }
+
+    /// Whether every field in the form is valid
+    fn validate_form(&mut self) -> bool {
+        self.form_errors().is_empty()
+    }
+
+    /// User-facing messages for every invalid field, in focus order
+    fn form_errors(&mut self) -> Vec<String> {
+        let fields = self.form().fields().to_vec();
+        let mut errors = Vec::new();
+        for field in fields {
+            let error = match self.field_mut(field.key) {
+                FieldMut::Text(input) => {
+                    if input.validate() {
+                        None
+                    } else if input.value().trim().is_empty() {
+                        field.missing.or(field.invalid)
+                    } else {
+                        field.invalid.or(field.missing)
+                    }
+                }
+                FieldMut::Dropdown(list) if list.get_selected_value().is_none() => field.missing,
+                _ => None,
+            };
+            errors.extend(error.map(str::to_string));
+        }
+        errors
+    }
+}
+
+/// Map a key to a text input request
+pub fn text_request(key: KeyEvent) -> Option<InputRequest> {
+    match key.code {
+        KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
+        KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
+        KeyCode::Delete => Some(InputRequest::DeleteNextChar),
+        KeyCode::Left => Some(InputRequest::GoToPrevChar),
+        KeyCode::Right => Some(InputRequest::GoToNextChar),
+        KeyCode::Home => Some(InputRequest::GoToStart),
+        KeyCode::End => Some(InputRequest::GoToEnd),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -814,6 +1201,29 @@ mod tests {
         assert!(input.error().unwrap().contains("Invalid amount format"));
     }
 
+    #[test]
+    fn test_amount_input_canonical_value() {
+        let mut input = TextInput::new("Test").with_type(InputType::Amount);
+        input.set_locale(NumberLocale::Comma);
+        for c in "1.234,5".chars() {
+            input.handle_input(InputRequest::InsertChar(c));
+        }
+        assert_eq!(input.raw_value(), "1.234,5");
+        assert_eq!(input.value(), "1234.5");
+
+        // Values set by the program are canonical and shown in the locale
+        input.set_value("0.25");
+        assert_eq!(input.raw_value(), "0,25");
+        assert_eq!(input.value(), "0.25");
+        input.set_locale(NumberLocale::Point);
+        assert_eq!(input.raw_value(), "0.25");
+
+        // Text that isn't an amount is left as typed for validation to report
+        input.set_value("1,2,3");
+        assert_eq!(input.value(), "1,2,3");
+        assert!(!input.validate());
+    }
+
     #[test]
     fn test_dropdown_selection() {
         let mut dropdown = Dropdown::new("Test")
@@ -829,6 +1239,150 @@ mod tests {
         assert_eq!(dropdown.selected_text(), Some("Option 2"));
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Field {
+        Pool,
+        Amount,
+        Confirm,
+        Execute,
+    }
+
+    struct TestForm {
+        form: Form<Field>,
+        focus: Field,
+        pool: SimpleList,
+        amount: TextInput,
+        confirm: Checkbox,
+    }
+
+    impl TestForm {
+        fn new() -> Self {
+            let mut state = Self {
+                form: Form::new()
+                    .dropdown(Field::Pool, "Select a pool")
+                    .amount(Field::Amount, "Enter an amount", "Enter a valid amount")
+                    .checkbox(Field::Confirm)
+                    .submit(Field::Execute),
+                focus: Field::Pool,
+                pool: SimpleList::new("Pool").with_options(vec![
+                    crate::tui::components::simple_list::SimpleListOption::new("Pool 1", "1"),
+                ]),
+                amount: TextInput::new("Amount")
+                    .with_type(InputType::Amount)
+                    .required(),
+                confirm: Checkbox::new("Confirm"),
+            };
+            state.apply_form_focus();
+            state
+        }
+    }
+
+    impl FormFields for TestForm {
+        type Key = Field;
+
+        fn form(&self) -> &Form<Field> {
+            &self.form
+        }
+
+        fn form_mut(&mut self) -> &mut Form<Field> {
+            &mut self.form
+        }
+
+        fn focused_field(&self) -> Field {
+            self.focus
+        }
+
+        fn set_focused_field(&mut self, key: Field) {
+            self.focus = key;
+        }
+
+        fn field_mut(&mut self, key: Field) -> FieldMut<'_> {
+            match key {
+                Field::Pool => FieldMut::Dropdown(&mut self.pool),
+                Field::Amount => FieldMut::Text(&mut self.amount),
+                Field::Confirm => FieldMut::Checkbox(&mut self.confirm),
+                Field::Execute => FieldMut::None,
+            }
+        }
+    }
+
+    fn press(state: &mut TestForm, code: KeyCode) -> FormEvent {
+        state.handle_form_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_form_focus_order_wraps() {
+        let form = TestForm::new().form;
+        assert_eq!(form.next(Field::Execute), Some(Field::Pool));
+        assert_eq!(form.previous(Field::Pool), Some(Field::Execute));
+
+        let empty: Form<Field> = Form::new();
+        assert_eq!(empty.next(Field::Pool), None);
+    }
+
+    #[test]
+    fn test_form_key_handling() {
+        let mut state = TestForm::new();
+        assert!(state.pool.is_active);
+
+        // Opening and confirming the dropdown moves on to the amount
+        assert_eq!(press(&mut state, KeyCode::Enter), FormEvent::Handled);
+        assert_eq!(press(&mut state, KeyCode::Enter), FormEvent::Selected);
+        assert_eq!(state.focus, Field::Amount);
+        assert!(state.amount.is_focused() && !state.pool.is_active);
+
+        // Amounts swallow letters
+        assert_eq!(press(&mut state, KeyCode::Char('x')), FormEvent::Handled);
+        assert_eq!(press(&mut state, KeyCode::Char('5')), FormEvent::Changed);
+        assert_eq!(state.amount.value(), "5");
+
+        assert_eq!(press(&mut state, KeyCode::Tab), FormEvent::Handled);
+        assert_eq!(press(&mut state, KeyCode::Char(' ')), FormEvent::Changed);
+        assert!(state.confirm.is_checked());
+
+        let back_tab = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+        assert_eq!(state.handle_form_key(back_tab), FormEvent::Handled);
+        assert_eq!(state.focus, Field::Amount);
+    }
+
+    #[test]
+    fn test_form_validation_and_submit() {
+        let mut state = TestForm::new();
+        assert_eq!(
+            state.form_errors(),
+            vec!["Select a pool".to_string(), "Enter an amount".to_string()]
+        );
+
+        state.set_focused_field(Field::Execute);
+        assert_eq!(press(&mut state, KeyCode::Enter), FormEvent::Invalid);
+
+        state.pool.selected_index = Some(0);
+        state.amount.set_value("abc");
+        assert_eq!(
+            state.form_errors(),
+            vec!["Enter a valid amount".to_string()]
+        );
+
+        state.amount.set_value("1.5");
+        assert_eq!(press(&mut state, KeyCode::Enter), FormEvent::Submit);
+    }
+
+    #[test]
+    fn test_form_dirty_tracking() {
+        let mut state = TestForm::new();
+        assert!(!state.form.is_dirty());
+
+        // Moving focus isn't an edit
+        press(&mut state, KeyCode::Tab);
+        assert!(!state.form.is_dirty());
+
+        press(&mut state, KeyCode::Char('1'));
+        assert!(state.form.is_dirty());
+
+        state.form_mut().mark_clean();
+        assert!(!state.form.is_dirty());
+    }
+
     #[test]
     fn test_checkbox_toggle() {
         let mut checkbox = Checkbox::new("Test");
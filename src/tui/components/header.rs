@@ -23,23 +23,36 @@ pub fn render_header(f: &mut Frame, app_state: &AppState, area: Rect) {
         ])
         .split(area);
 
-    // Title section
+    // Title section, flagged while paper trading so it can't be mistaken for the real wallet
+    let title_block = if app_state.paper_portfolio.is_some() {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta))
+            .title(" PAPER TRADING ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            )
+    } else {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue))
+    };
     let title = Paragraph::new("🕉️  MANTRA DEX SDK")
         .style(
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Blue)),
-        );
+        .block(title_block);
     f.render_widget(title, header_chunks[0]);
 
     // Network status section
     let network_status = get_network_status_text(app_state);
-    let network_color = if app_state.network_info.is_syncing {
+    let network_color = if !app_state.network_info.failing_endpoints.is_empty() {
+        Color::Red
+    } else if app_state.network_info.is_syncing {
         Color::Yellow
     } else {
         Color::Green
@@ -110,10 +123,13 @@ fn get_network_status_text(app_state: &AppState) -> String {
         .as_ref()
         .unwrap_or(&default_chain_id);
 
-    let sync_status = if app_state.network_info.is_syncing {
-        "Syncing"
+    let failing = app_state.network_info.failing_endpoints.len();
+    let sync_status = if failing > 0 {
+        format!("{} RPC failing", failing)
+    } else if app_state.network_info.is_syncing {
+        "Syncing".to_string()
     } else {
-        "Synced"
+        "Synced".to_string()
     };
 
     // Truncate chain ID if it's too long
@@ -156,5 +172,11 @@ mod tests {
         let status = get_network_status_text(&app_state);
         assert!(status.starts_with("very-long-ch..."));
         assert!(status.contains("Syncing"));
+
+        // Failing RPC endpoints take precedence over the sync status
+        app_state.network_info.failing_endpoints =
+            vec!["https://rpc.example.com:443".to_string()];
+        let status = get_network_status_text(&app_state);
+        assert!(status.ends_with("1 RPC failing"));
     }
 }
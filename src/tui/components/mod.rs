@@ -8,12 +8,14 @@ pub mod header;
 pub mod modals;
 pub mod navigation;
 pub mod status_bar;
+pub mod tour;
 
 // Re-export components for easy access
 pub use header::*;
 pub use modals::*;
 pub use navigation::*;
 pub use status_bar::*;
+pub use tour::*;
 
 // Data display components - implemented in Task 3.2
 pub mod charts;
@@ -8,6 +8,12 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Gauge, List, ListItem, Padding, Paragraph, Wrap},
 };
 
+use std::path::Path;
+
+use crate::secret::SecretString;
+use crate::tui::components::tables::is_json_path;
+use crate::tui::events::UiEvent;
+
 /// Modal types for different use cases
 #[derive(Debug, Clone)]
 pub enum ModalType {
@@ -52,14 +58,20 @@ pub enum ModalType {
     WalletSave {
         title: String,
         message: String,
-        mnemonic: String,
+        mnemonic: SecretString,
         address: String,
         wallet_name: String,
-        password: String,
-        confirm_password: String,
+        password: SecretString,
+        confirm_password: SecretString,
         current_field: WalletSaveField,
         show_password: bool,
     },
+    /// Destination of a table export, edited before the file is written
+    Export {
+        title: String,
+        path: String,
+        rows: usize,
+    },
 }
 
 /// Error types for better error categorization and handling
@@ -166,6 +178,13 @@ pub enum WalletSaveField {
     CancelButton,
 }
 
+impl WalletSaveField {
+    /// Whether the field masks what is typed into it
+    pub fn is_sensitive(&self) -> bool {
+        matches!(self, Self::Password | Self::ConfirmPassword)
+    }
+}
+
 /// Help section for help modal
 #[derive(Debug, Clone)]
 pub struct HelpSection {
@@ -304,7 +323,12 @@ impl ModalState {
     }
 
     /// Create a new wallet save modal
-    pub fn wallet_save(title: String, message: String, mnemonic: String, address: String) -> Self {
+    pub fn wallet_save(
+        title: String,
+        message: String,
+        mnemonic: SecretString,
+        address: String,
+    ) -> Self {
         Self {
             modal_type: ModalType::WalletSave {
                 title,
@@ -312,8 +336,8 @@ impl ModalState {
                 mnemonic,
                 address,
                 wallet_name: String::new(),
-                password: String::new(),
-                confirm_password: String::new(),
+                password: SecretString::default(),
+                confirm_password: SecretString::default(),
                 current_field: WalletSaveField::WalletName,
                 show_password: false,
             },
@@ -323,6 +347,32 @@ impl ModalState {
         }
     }
 
+    /// Create a modal confirming where `rows` table rows are exported, starting at `path`
+    pub fn export(title: String, path: String, rows: usize) -> Self {
+        Self {
+            modal_type: ModalType::Export { title, path, rows },
+            is_visible: true,
+            selected_option: 0,
+            scroll_offset: 0,
+        }
+    }
+
+    /// Edit the destination of an export modal. Returns `true` if the event was consumed.
+    pub fn edit_export_path(&mut self, event: &UiEvent) -> bool {
+        let ModalType::Export { path, .. } = &mut self.modal_type else {
+            return false;
+        };
+        match event {
+            UiEvent::Char(c) => path.push(*c),
+            UiEvent::Paste(text) => path.push_str(text.trim_end_matches(['\r', '\n'])),
+            UiEvent::Backspace => {
+                path.pop();
+            }
+            _ => return false,
+        }
+        true
+    }
+
     /// Hide the modal
     pub fn hide(&mut self) {
         self.is_visible = false;
@@ -418,6 +468,14 @@ impl ModalState {
             *m = message;
         }
     }
+
+    /// Whether typed characters go into a masked input, such as a password
+    pub fn is_sensitive_input_focused(&self) -> bool {
+        match &self.modal_type {
+            ModalType::WalletSave { current_field, .. } => current_field.is_sensitive(),
+            _ => false,
+        }
+    }
 }
 
 /// Create a comprehensive help modal with all keyboard shortcuts
@@ -439,7 +497,16 @@ pub fn create_comprehensive_help() -> ModalState {
             items: vec![
                 ("q".to_string(), "Quit application".to_string()),
                 ("h, F1".to_string(), "Show this help".to_string()),
+                ("t".to_string(), "Take the onboarding tour".to_string()),
                 ("r, F5".to_string(), "Refresh current screen".to_string()),
+                (
+                    "x".to_string(),
+                    "Export the pools, transactions or positions table".to_string(),
+                ),
+                (
+                    "Ctrl+P".to_string(),
+                    "Toggle paper trading (simulated swaps and liquidity)".to_string(),
+                ),
                 ("Ctrl+C".to_string(), "Force quit".to_string()),
             ],
         },
@@ -591,9 +658,56 @@ pub fn render_modal(f: &mut Frame, modal_state: &ModalState, area: Rect) {
             *show_password,
             modal_area,
         ),
+        ModalType::Export { title, path, rows } => {
+            render_export_modal(f, title, path, *rows, modal_area)
+        }
     }
 }
 
+/// Render the destination of a table export
+fn render_export_modal(f: &mut Frame, title: &str, path: &str, rows: usize, area: Rect) {
+    let format = if is_json_path(Path::new(path)) {
+        "JSON"
+    } else {
+        "CSV"
+    };
+    let text = vec![
+        Line::from(format!("Export {} rows as {} to:", rows, format)),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                path.to_string(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("▏", Style::default().fg(Color::Yellow)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Paths ending in .json are written as JSON, any other as CSV.",
+            Style::default().fg(Color::Gray),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter: export · Esc: cancel",
+            Style::default().fg(Color::Yellow),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green))
+                .title(title)
+                .padding(Padding::uniform(1)),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
 /// Render confirmation modal
 fn render_confirmation_modal(
     f: &mut Frame,
@@ -1072,8 +1186,8 @@ fn render_wallet_save_modal(
     title: &str,
     message: &str,
     wallet_name: &str,
-    password: &str,
-    confirm_password: &str,
+    password: &SecretString,
+    confirm_password: &SecretString,
     current_field: &WalletSaveField,
     show_password: bool,
     area: Rect,
@@ -1127,9 +1241,9 @@ fn render_wallet_save_modal(
     };
 
     let password_display = if show_password {
-        password.to_string()
+        password.expose_secret().to_string()
     } else {
-        "*".repeat(password.len())
+        "*".repeat(password.char_count())
     };
 
     let password_input = Paragraph::new(password_display)
@@ -1151,9 +1265,9 @@ fn render_wallet_save_modal(
     };
 
     let confirm_display = if show_password {
-        confirm_password.to_string()
+        confirm_password.expose_secret().to_string()
     } else {
-        "*".repeat(confirm_password.len())
+        "*".repeat(confirm_password.char_count())
     };
 
     let confirm_input = Paragraph::new(confirm_display).style(confirm_style).block(
@@ -1244,6 +1358,23 @@ mod tests {
         assert_eq!(modal.selected_option, 0);
     }
 
+    #[test]
+    fn test_export_path_editing() {
+        let mut modal = ModalState::export("Export".to_string(), "pools.csv".to_string(), 3);
+        for _ in 0..3 {
+            assert!(modal.edit_export_path(&UiEvent::Backspace));
+        }
+        assert!(modal.edit_export_path(&UiEvent::Paste("json\n".to_string())));
+        assert!(!modal.edit_export_path(&UiEvent::Enter));
+        match modal.modal_type {
+            ModalType::Export { path, rows, .. } => {
+                assert_eq!(path, "pools.json");
+                assert_eq!(rows, 3);
+            }
+            _ => panic!("Expected Export modal type"),
+        }
+    }
+
     #[test]
     fn test_error_type_suggestions() {
         let network_error = ErrorType::Network;
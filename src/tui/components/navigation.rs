@@ -32,6 +32,7 @@ fn create_navigation_tabs(app_state: &AppState) -> Tabs {
                 Screen::Rewards => "6:Rewards",
                 Screen::Admin => "7:Admin",
                 Screen::Settings => "8:Settings",
+                Screen::Analytics => "Analytics",
                 Screen::TransactionDetails => "9:Transaction",
             };
             Line::from(shortcut_name)
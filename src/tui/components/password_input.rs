@@ -1,13 +1,16 @@
 //! Password Input Component with Strength Validation
 //!
 //! This component provides a secure password input field with real-time
-//! strength validation and visual feedback.
+//! strength validation and visual feedback. The password is kept in a
+//! [`SecretString`], edited in place rather than through `tui_input`, so no
+//! copy of it outlives the field.
 
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Gauge, Paragraph},
 };
-use tui_input::Input;
+
+use crate::secret::SecretString;
 
 /// Password strength levels
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -62,8 +65,10 @@ pub struct PasswordValidation {
 /// Password input component with validation and visual feedback
 #[derive(Clone)]
 pub struct PasswordInput {
-    /// The input field
-    input: Input,
+    /// The password entered
+    value: SecretString,
+    /// Cursor position, in characters
+    cursor: usize,
     /// Whether password is currently visible
     is_visible: bool,
     /// Current validation result
@@ -80,7 +85,8 @@ impl PasswordInput {
     /// Create a new password input
     pub fn new(title: String) -> Self {
         Self {
-            input: Input::default(),
+            value: SecretString::default(),
+            cursor: 0,
             is_visible: false,
             validation: None,
             is_focused: false,
@@ -92,7 +98,8 @@ impl PasswordInput {
     /// Create a simple password input without strength indicator
     pub fn simple(title: String) -> Self {
         Self {
-            input: Input::default(),
+            value: SecretString::default(),
+            cursor: 0,
             is_visible: false,
             validation: None,
             is_focused: false,
@@ -112,48 +119,54 @@ impl PasswordInput {
     }
 
     /// Get the password value
-    pub fn value(&self) -> &str {
-        self.input.value()
+    pub fn value(&self) -> &SecretString {
+        &self.value
     }
 
     /// Set the password value
-    pub fn set_value(&mut self, value: String) {
-        self.input = Input::default().with_value(value);
+    pub fn set_value(&mut self, value: SecretString) {
+        self.cursor = value.char_count();
+        self.value = value;
         self.update_validation();
     }
 
     /// Clear the password
     pub fn clear(&mut self) {
-        self.input = Input::default();
+        self.value.clear();
+        self.cursor = 0;
         self.validation = None;
     }
 
     /// Handle character input
     pub fn handle_char(&mut self, c: char) {
-        self.input.handle(tui_input::InputRequest::InsertChar(c));
+        self.value.insert(self.cursor, c);
+        self.cursor += 1;
         self.update_validation();
     }
 
     /// Handle backspace
     pub fn handle_backspace(&mut self) {
-        self.input.handle(tui_input::InputRequest::DeletePrevChar);
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.value.remove(self.cursor);
+        }
         self.update_validation();
     }
 
     /// Handle delete key
     pub fn handle_delete(&mut self) {
-        self.input.handle(tui_input::InputRequest::DeleteNextChar);
+        self.value.remove(self.cursor);
         self.update_validation();
     }
 
     /// Move cursor left
     pub fn move_cursor_left(&mut self) {
-        self.input.handle(tui_input::InputRequest::GoToPrevChar);
+        self.cursor = self.cursor.saturating_sub(1);
     }
 
     /// Move cursor right
     pub fn move_cursor_right(&mut self) {
-        self.input.handle(tui_input::InputRequest::GoToNextChar);
+        self.cursor = (self.cursor + 1).min(self.value.char_count());
     }
 
     /// Get current validation result
@@ -171,7 +184,7 @@ impl PasswordInput {
 
     /// Update password validation
     fn update_validation(&mut self) {
-        let password = self.input.value();
+        let password = self.value.expose_secret();
         if password.is_empty() {
             self.validation = None;
             return;
@@ -279,9 +292,9 @@ impl PasswordInput {
 
         // Render input field
         let display_value = if self.is_visible {
-            self.input.value().to_string()
+            self.value.expose_secret().to_string()
         } else {
-            "*".repeat(self.input.value().len())
+            "*".repeat(self.value.char_count())
         };
 
         let border_color = if self.is_focused {
@@ -303,7 +316,7 @@ impl PasswordInput {
 
         // Show cursor if focused
         if self.is_focused {
-            let cursor_x = chunks[0].x + 1 + self.input.visual_cursor() as u16;
+            let cursor_x = chunks[0].x + 1 + self.cursor as u16;
             let cursor_y = chunks[0].y + 1;
             if cursor_x < chunks[0].right() {
                 buf[(cursor_x, cursor_y)]
@@ -12,11 +12,13 @@ use ratatui::{
     Frame,
 };
 
+use crate::secret::SecretString;
+
 /// Password prompt modal for secure wallet authentication
 #[derive(Clone)]
 pub struct PasswordPrompt {
     /// Current password input
-    pub password: String,
+    pub password: SecretString,
     /// Whether password is currently visible (unmasked)
     pub password_visible: bool,
     /// Current error message if any
@@ -36,7 +38,7 @@ pub struct PasswordPrompt {
 impl Default for PasswordPrompt {
     fn default() -> Self {
         Self {
-            password: String::new(),
+            password: SecretString::default(),
             password_visible: false,
             error_message: None,
             failed_attempts: 0,
@@ -52,7 +54,7 @@ impl Default for PasswordPrompt {
 #[derive(Debug, Clone)]
 pub enum PasswordPromptResult {
     /// User entered password and confirmed
-    Password(String),
+    Password(SecretString),
     /// User cancelled the operation
     Cancelled,
     /// User exceeded maximum attempts
@@ -86,13 +88,6 @@ impl PasswordPrompt {
 
     /// Clear sensitive data from memory
     pub fn clear_sensitive_data(&mut self) {
-        // Overwrite password with zeros for security
-        unsafe {
-            let password_bytes = self.password.as_bytes_mut();
-            for byte in password_bytes {
-                *byte = 0;
-            }
-        }
         self.password.clear();
     }
 
@@ -173,9 +168,9 @@ impl PasswordPrompt {
     /// Get display string for password (masked or visible)
     fn get_password_display(&self) -> String {
         if self.password_visible {
-            self.password.clone()
+            self.password.expose_secret().to_string()
         } else {
-            "•".repeat(self.password.len())
+            "•".repeat(self.password.char_count())
         }
     }
 
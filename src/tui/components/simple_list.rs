@@ -16,6 +16,21 @@ pub enum ListEvent {
     SelectionCancelled,
 }
 
+/// Population state of a list whose options load asynchronously
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ListLoadState {
+    /// Options are populated (possibly empty)
+    #[default]
+    Ready,
+    /// Options are still being fetched
+    Loading,
+    /// Fetching the options failed
+    Failed(String),
+}
+
+/// Rows moved by PageUp/PageDown while selecting
+const PAGE_SIZE: usize = 10;
+
 #[derive(Debug, Clone)]
 pub struct SimpleListOption {
     pub label: String,
@@ -43,8 +58,14 @@ pub struct SimpleList {
     pub is_active: bool,
     /// Whether the user is currently navigating the list options
     pub is_editing: bool,
-    /// List state for rendering
+    /// List state for rendering; the highlighted row is an index into `options`
     pub list_state: ListState,
+    /// Type-ahead filter typed while selecting
+    pub filter: String,
+    /// Whether the options have finished loading
+    pub load_state: ListLoadState,
+    /// First visible row of the filtered options
+    scroll_offset: usize,
 }
 
 impl SimpleList {
@@ -56,6 +77,9 @@ impl SimpleList {
             is_active: false,
             is_editing: false,
             list_state: ListState::default(),
+            filter: String::new(),
+            load_state: ListLoadState::Ready,
+            scroll_offset: 0,
         }
     }
 
@@ -80,6 +104,101 @@ impl SimpleList {
         self.is_active = active;
         if !active {
             self.is_editing = false; // Exit editing mode when not active
+            self.filter.clear();
+        }
+    }
+
+    /// Replace the options once they have loaded
+    ///
+    /// The current selection is kept if its value is still present, so a
+    /// background refresh doesn't throw away the user's choice.
+    pub fn set_options(&mut self, options: Vec<SimpleListOption>) {
+        let selected_value = self.get_selected_value().map(str::to_string);
+        self.options = options;
+        self.load_state = ListLoadState::Ready;
+        self.filter.clear();
+        self.scroll_offset = 0;
+        self.selected_index =
+            selected_value.and_then(|value| self.options.iter().position(|o| o.value == value));
+        let highlight = match self.selected_index {
+            Some(index) => Some(index),
+            None if self.options.is_empty() => None,
+            None => Some(0),
+        };
+        self.list_state.select(highlight);
+    }
+
+    /// Mark the options as being fetched
+    pub fn set_loading(&mut self) {
+        self.load_state = ListLoadState::Loading;
+    }
+
+    /// Record that fetching the options failed
+    pub fn set_load_error(&mut self, error: impl Into<String>) {
+        self.load_state = ListLoadState::Failed(error.into());
+    }
+
+    /// Indices into `options` of the rows matching the type-ahead filter
+    pub fn visible_indices(&self) -> Vec<usize> {
+        if self.filter.is_empty() {
+            return (0..self.options.len()).collect();
+        }
+        let needle = self.filter.to_lowercase();
+        self.options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.label.to_lowercase().contains(&needle))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Move the highlight `delta` rows through the filtered options
+    fn move_highlight(&mut self, delta: isize, wrap: bool) {
+        let visible = self.visible_indices();
+        if visible.is_empty() {
+            return;
+        }
+        let last = visible.len() as isize - 1;
+        let next = match self
+            .list_state
+            .selected()
+            .and_then(|current| visible.iter().position(|&index| index == current))
+        {
+            None => 0,
+            Some(position) => {
+                let target = position as isize + delta;
+                match (wrap, target) {
+                    (true, t) if t < 0 => last,
+                    (true, t) if t > last => 0,
+                    (_, t) => t.clamp(0, last),
+                }
+            }
+        };
+        self.list_state.select(Some(visible[next as usize]));
+    }
+
+    /// Keep the highlight on a row that matches the filter
+    fn refilter(&mut self) {
+        let visible = self.visible_indices();
+        let highlighted_visible = self
+            .list_state
+            .selected()
+            .is_some_and(|current| visible.contains(&current));
+        if !highlighted_visible {
+            self.list_state.select(visible.first().copied());
+        }
+        self.scroll_offset = 0;
+    }
+
+    /// Select the option with `value`, returning whether there is one
+    pub fn select_value(&mut self, value: &str) -> bool {
+        match self.options.iter().position(|o| o.value == value) {
+            Some(index) => {
+                self.selected_index = Some(index);
+                self.list_state.select(Some(index));
+                true
+            }
+            None => false,
         }
     }
 
@@ -108,38 +227,57 @@ impl SimpleList {
         if self.is_editing {
             match key.code {
                 KeyCode::Enter => {
-                    if !self.options.is_empty() {
-                        if let Some(highlighted_idx) = self.list_state.selected() {
-                            self.selected_index = Some(highlighted_idx);
-                        }
+                    let visible = self.visible_indices();
+                    let highlighted = self
+                        .list_state
+                        .selected()
+                        .filter(|current| visible.contains(current))
+                        .or_else(|| visible.first().copied());
+                    if let Some(index) = highlighted {
+                        self.selected_index = Some(index);
+                        self.list_state.select(Some(index));
                     }
                     self.is_editing = false;
+                    self.filter.clear();
                     ListEvent::SelectionMade
                 }
                 KeyCode::Esc => {
                     self.is_editing = false;
+                    self.filter.clear();
                     ListEvent::SelectionCancelled
                 }
                 KeyCode::Up => {
-                    if !self.options.is_empty() {
-                        let current = self.list_state.selected().unwrap_or(0);
-                        if current > 0 {
-                            self.list_state.select(Some(current - 1));
-                        } else {
-                            self.list_state.select(Some(self.options.len() - 1));
-                        }
-                    }
+                    self.move_highlight(-1, true);
                     ListEvent::Handled
                 }
                 KeyCode::Down => {
-                    if !self.options.is_empty() {
-                        let current = self.list_state.selected().unwrap_or(0);
-                        if current + 1 < self.options.len() {
-                            self.list_state.select(Some(current + 1));
-                        } else {
-                            self.list_state.select(Some(0));
-                        }
-                    }
+                    self.move_highlight(1, true);
+                    ListEvent::Handled
+                }
+                KeyCode::PageUp => {
+                    self.move_highlight(-(PAGE_SIZE as isize), false);
+                    ListEvent::Handled
+                }
+                KeyCode::PageDown => {
+                    self.move_highlight(PAGE_SIZE as isize, false);
+                    ListEvent::Handled
+                }
+                KeyCode::Home => {
+                    self.move_highlight(isize::MIN / 2, false);
+                    ListEvent::Handled
+                }
+                KeyCode::End => {
+                    self.move_highlight(isize::MAX / 2, false);
+                    ListEvent::Handled
+                }
+                KeyCode::Char(c) => {
+                    self.filter.push(c);
+                    self.refilter();
+                    ListEvent::Handled
+                }
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                    self.refilter();
                     ListEvent::Handled
                 }
                 _ => ListEvent::Handled, // Absorb other keys to prevent them from propagating
@@ -156,25 +294,11 @@ impl SimpleList {
                 }
                 // Allow up/down to work even when not in edit mode for quick navigation
                 KeyCode::Up => {
-                    if !self.options.is_empty() {
-                        let current = self.list_state.selected().unwrap_or(0);
-                        if current > 0 {
-                            self.list_state.select(Some(current - 1));
-                        } else {
-                            self.list_state.select(Some(self.options.len() - 1));
-                        }
-                    }
+                    self.move_highlight(-1, true);
                     ListEvent::Handled
                 }
                 KeyCode::Down => {
-                    if !self.options.is_empty() {
-                        let current = self.list_state.selected().unwrap_or(0);
-                        if current + 1 < self.options.len() {
-                            self.list_state.select(Some(current + 1));
-                        } else {
-                            self.list_state.select(Some(0));
-                        }
-                    }
+                    self.move_highlight(1, true);
                     ListEvent::Handled
                 }
                 _ => ListEvent::Ignored, // Let the parent handle other keys like Tab
@@ -183,35 +307,9 @@ impl SimpleList {
     }
 
     /// Render the list (always visible)
+    ///
+    /// Only the rows that fit in `area` are built, so long lists stay cheap to draw.
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
-        if self.options.is_empty() {
-            // Render empty state
-            let empty_list = List::new(vec![ListItem::new("No options available")]).block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(self.label.as_str())
-                    .border_style(Style::default().fg(Color::DarkGray)),
-            );
-            f.render_widget(empty_list, area);
-            return;
-        }
-
-        // Create list items
-        let items: Vec<ListItem> = self
-            .options
-            .iter()
-            .enumerate()
-            .map(|(idx, opt)| {
-                let text = if Some(idx) == self.selected_index {
-                    format!("✓ {}", opt.label)
-                } else {
-                    format!("  {}", opt.label)
-                };
-                ListItem::new(text)
-            })
-            .collect();
-
-        // Create and render the list
         let border_color = if self.is_editing {
             Color::Green // Green when in editing/selection mode
         } else if self.is_active {
@@ -220,7 +318,9 @@ impl SimpleList {
             Color::Gray // Gray when not focused
         };
 
-        let title = if self.is_editing {
+        let title = if self.is_editing && !self.filter.is_empty() {
+            format!("{} [SELECTING] filter: {}", self.label, self.filter)
+        } else if self.is_editing {
             format!("{} [SELECTING]", self.label)
         } else if self.is_active {
             format!("{} [FOCUSED]", self.label)
@@ -228,6 +328,63 @@ impl SimpleList {
             self.label.clone()
         };
 
+        let visible = self.visible_indices();
+        let placeholder = match &self.load_state {
+            ListLoadState::Loading => Some(("Loading...".to_string(), Color::Yellow)),
+            ListLoadState::Failed(error) => {
+                Some((format!("Failed to load: {}", error), Color::Red))
+            }
+            ListLoadState::Ready if self.options.is_empty() => {
+                Some(("No options available".to_string(), Color::DarkGray))
+            }
+            ListLoadState::Ready if visible.is_empty() => {
+                Some((format!("No matches for '{}'", self.filter), Color::DarkGray))
+            }
+            ListLoadState::Ready => None,
+        };
+
+        if let Some((message, color)) = placeholder {
+            let placeholder_list = List::new(vec![ListItem::new(message)])
+                .style(Style::default().fg(color))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title.as_str())
+                        .border_style(Style::default().fg(border_color)),
+                );
+            f.render_widget(placeholder_list, area);
+            return;
+        }
+
+        // Window the filtered rows around the highlight
+        let rows = (area.height.saturating_sub(2) as usize).max(1);
+        let highlighted = self
+            .list_state
+            .selected()
+            .and_then(|current| visible.iter().position(|&index| index == current));
+        if let Some(position) = highlighted {
+            if position < self.scroll_offset {
+                self.scroll_offset = position;
+            } else if position >= self.scroll_offset + rows {
+                self.scroll_offset = position + 1 - rows;
+            }
+        }
+        self.scroll_offset = self.scroll_offset.min(visible.len().saturating_sub(rows));
+        let window = &visible[self.scroll_offset..(self.scroll_offset + rows).min(visible.len())];
+
+        let items: Vec<ListItem> = window
+            .iter()
+            .map(|&idx| {
+                let label = &self.options[idx].label;
+                let text = if Some(idx) == self.selected_index {
+                    format!("✓ {}", label)
+                } else {
+                    format!("  {}", label)
+                };
+                ListItem::new(text)
+            })
+            .collect();
+
         let list = List::new(items)
             .block(
                 Block::default()
@@ -242,6 +399,59 @@ impl SimpleList {
                     .add_modifier(Modifier::BOLD),
             );
 
-        f.render_stateful_widget(list, area, &mut self.list_state);
+        let mut window_state =
+            ListState::default().with_selected(highlighted.map(|p| p - self.scroll_offset));
+        f.render_stateful_widget(list, area, &mut window_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(list: &mut SimpleList, code: KeyCode) -> ListEvent {
+        list.handle_key_event(KeyEvent::new(code, crossterm::event::KeyModifiers::NONE))
+    }
+
+    fn pools(count: usize) -> Vec<SimpleListOption> {
+        (1..=count)
+            .map(|id| SimpleListOption::new(format!("Pool {}: OM / USDC", id), id.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_type_ahead_filter_selects_matching_option() {
+        let mut list = SimpleList::new("Pools").with_options(pools(200));
+        list.set_active(true);
+        press(&mut list, KeyCode::Enter);
+
+        for c in "pool 15".chars() {
+            assert_eq!(press(&mut list, KeyCode::Char(c)), ListEvent::Handled);
+        }
+        // "Pool 15", "Pool 150" ... "Pool 159"
+        assert_eq!(list.visible_indices().len(), 11);
+
+        press(&mut list, KeyCode::Down);
+        assert_eq!(press(&mut list, KeyCode::Enter), ListEvent::SelectionMade);
+        assert_eq!(list.get_selected_value(), Some("150"));
+        assert!(list.filter.is_empty());
+    }
+
+    #[test]
+    fn test_set_options_keeps_selection_and_clears_loading() {
+        let mut list = SimpleList::new("Pools");
+        list.set_loading();
+        assert_eq!(list.load_state, ListLoadState::Loading);
+
+        list.set_options(pools(3));
+        assert_eq!(list.load_state, ListLoadState::Ready);
+        list.selected_index = Some(2);
+
+        // A refresh that reorders the pools keeps the chosen one
+        let mut refreshed = pools(4);
+        refreshed.reverse();
+        list.set_options(refreshed);
+        assert_eq!(list.get_selected_value(), Some("3"));
+        assert_eq!(list.list_state.selected(), list.selected_index);
     }
 }
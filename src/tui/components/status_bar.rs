@@ -163,14 +163,15 @@ fn get_context_help(app_state: &AppState) -> String {
     let screen_help = match app_state.current_screen {
         crate::tui::app::Screen::WalletSelection => "↑↓:Select | Enter:Load | n:New | r:Recover",
         crate::tui::app::Screen::Dashboard => "Enter:Refresh",
-        crate::tui::app::Screen::Pools => "↑↓:Select | Enter:Details | r:Refresh",
+        crate::tui::app::Screen::Pools => "↑↓:Select | Enter:Details | f:Favorite | c:Mark | v:Compare | s:Sort | 1-5:Columns",
         crate::tui::app::Screen::Swap => "Enter:Execute | s:Simulate | r:Reset",
         crate::tui::app::Screen::MultiHop => "a:Add hop | d:Delete | Enter:Execute",
         crate::tui::app::Screen::Liquidity => "p:Provide | w:Withdraw | Enter:Execute",
         crate::tui::app::Screen::Rewards => "c:Claim | a:Claim all | Enter:Details",
         crate::tui::app::Screen::Admin => "n:New pool | e:Edit | t:Toggle",
         crate::tui::app::Screen::Settings => "s:Save | r:Reset | Enter:Edit",
-        crate::tui::app::Screen::TransactionDetails => "Esc:Back | r:Refresh",
+        crate::tui::app::Screen::Analytics => "F5:Refresh",
+        crate::tui::app::Screen::TransactionDetails => "↑↓:Select | s:Sort | Esc:Back",
     };
 
     format!("{} | {}", base_help, screen_help)
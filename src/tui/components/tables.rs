@@ -2,13 +2,23 @@
 //!
 //! This module contains components for displaying data in tables, cards, and progress bars.
 //! These components are used across different screens to present information consistently.
+//!
+//! [`render_data_table`] draws large datasets: only the rows in view are built each
+//! frame, the header stays put while scrolling, and columns can be sorted and hidden
+//! through a [`DataTableState`]. [`DataTableState::export`] captures the rows in
+//! their displayed order and columns as a [`TableExport`], written as CSV or JSON.
 
+use crate::error::Error;
 use crate::tui::app::{AppState, LoadingState, TransactionInfo, TransactionStatus};
+use crate::tui::events::{FocusDirection, UiEvent};
 use mantra_dex_std::pool_manager::PoolInfoResponse;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, TableState, Wrap},
 };
+use std::borrow::Cow;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
 /// Render a balance table showing user token balances
 pub fn render_balance_table(f: &mut Frame, app_state: &AppState, area: Rect) {
@@ -286,6 +296,441 @@ pub fn render_progress_bar_with_percent(
     f.render_widget(gauge, area);
 }
 
+/// Sort direction of a table column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    fn indicator(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        }
+    }
+}
+
+/// Value a row is ordered by in one column
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortKey {
+    Number(u128),
+    Text(String),
+}
+
+/// A row that can be shown in a data table
+pub trait TableRow {
+    /// Cell to display in `column`
+    fn cell(&self, column: usize) -> Cell<'_>;
+
+    /// Value used when the table is sorted by `column`
+    fn sort_key(&self, column: usize) -> SortKey;
+
+    /// Value written for `column` when the table is exported, in full rather
+    /// than abbreviated to fit the cell
+    fn export_value(&self, column: usize) -> String;
+}
+
+impl<T: TableRow> TableRow for &T {
+    fn cell(&self, column: usize) -> Cell<'_> {
+        (**self).cell(column)
+    }
+
+    fn sort_key(&self, column: usize) -> SortKey {
+        (**self).sort_key(column)
+    }
+
+    fn export_value(&self, column: usize) -> String {
+        (**self).export_value(column)
+    }
+}
+
+/// Column definition of a data table
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    pub title: &'static str,
+    pub width: Constraint,
+    pub visible: bool,
+}
+
+impl TableColumn {
+    pub fn new(title: &'static str, width: Constraint) -> Self {
+        Self {
+            title,
+            width,
+            visible: true,
+        }
+    }
+
+    /// Start the column hidden
+    pub fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+}
+
+/// Selection, scroll position, sorting and column visibility of a data table
+///
+/// The sorted row order is cached and only rebuilt when the sort changes, the row
+/// count changes or [`invalidate`](Self::invalidate) is called, so scrolling through
+/// thousands of rows doesn't re-sort them every frame.
+#[derive(Debug, Clone, Default)]
+pub struct DataTableState {
+    pub columns: Vec<TableColumn>,
+    /// Sorted column and direction; `None` keeps the rows in their given order
+    pub sort: Option<(usize, SortDirection)>,
+    /// Selected position in the sorted view
+    selected: usize,
+    /// First sorted position in view
+    offset: usize,
+    /// Body height of the last render, used for paging
+    page_size: usize,
+    /// Row indices in display order
+    order: Vec<usize>,
+    stale: bool,
+}
+
+impl DataTableState {
+    pub fn new(columns: Vec<TableColumn>) -> Self {
+        Self {
+            columns,
+            page_size: 1,
+            stale: true,
+            ..Default::default()
+        }
+    }
+
+    /// Rebuild the row order on the next render, after the rows have changed
+    pub fn invalidate(&mut self) {
+        self.stale = true;
+    }
+
+    /// Index of the selected row in the slice last rendered
+    pub fn selected_row(&self) -> Option<usize> {
+        self.order.get(self.selected).copied()
+    }
+
+    /// Sort by `column`, or flip the direction if it's already the sorted column
+    pub fn sort_by(&mut self, column: usize) {
+        self.sort = match self.sort {
+            Some((current, SortDirection::Ascending)) if current == column => {
+                Some((column, SortDirection::Descending))
+            }
+            _ => Some((column, SortDirection::Ascending)),
+        };
+        self.stale = true;
+    }
+
+    /// Sort by the next visible column, going back to the unsorted order after the last
+    pub fn cycle_sort(&mut self) {
+        let after = self.sort.map_or(0, |(column, _)| column + 1);
+        self.sort = (after..self.columns.len())
+            .find(|&column| self.columns[column].visible)
+            .map(|column| (column, SortDirection::Ascending));
+        self.stale = true;
+    }
+
+    /// Flip the direction of the current sort
+    pub fn reverse_sort(&mut self) {
+        if let Some((column, direction)) = self.sort {
+            self.sort = Some((
+                column,
+                match direction {
+                    SortDirection::Ascending => SortDirection::Descending,
+                    SortDirection::Descending => SortDirection::Ascending,
+                },
+            ));
+            self.stale = true;
+        }
+    }
+
+    /// Show or hide `column`. The last visible column can't be hidden.
+    pub fn toggle_column(&mut self, column: usize) -> bool {
+        let visible = self.visible_columns().len();
+        match self.columns.get_mut(column) {
+            Some(col) if !col.visible || visible > 1 => {
+                col.visible = !col.visible;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.move_selection(1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.move_selection(-1);
+    }
+
+    pub fn page_down(&mut self) {
+        self.move_selection(self.page_size as isize);
+    }
+
+    pub fn page_up(&mut self) {
+        self.move_selection(-(self.page_size as isize));
+    }
+
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.order.len().saturating_sub(1);
+    }
+
+    /// Apply a navigation, sort or column key. Returns `true` if the event was consumed.
+    ///
+    /// `s` cycles the sorted column, `S` reverses it and `1`-`9` show or hide columns.
+    pub fn handle_event(&mut self, event: &UiEvent) -> bool {
+        match event {
+            UiEvent::MoveFocus(FocusDirection::Up) => self.select_previous(),
+            UiEvent::MoveFocus(FocusDirection::Down) => self.select_next(),
+            UiEvent::PageUp => self.page_up(),
+            UiEvent::PageDown => self.page_down(),
+            UiEvent::Home => self.select_first(),
+            UiEvent::End => self.select_last(),
+            UiEvent::Char('s') => self.cycle_sort(),
+            UiEvent::Char('S') => self.reverse_sort(),
+            UiEvent::Char(c @ '1'..='9') => {
+                return self.toggle_column(*c as usize - '1' as usize);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Rows in their displayed order, with the visible columns only
+    pub fn export<R: TableRow>(&mut self, name: &str, rows: &[R]) -> TableExport {
+        self.sync(rows);
+        let columns = self.visible_columns();
+        TableExport::new(
+            name,
+            columns
+                .iter()
+                .map(|&column| self.columns[column].title.to_string())
+                .collect(),
+            self.order
+                .iter()
+                .map(|&index| {
+                    columns
+                        .iter()
+                        .map(|&column| rows[index].export_value(column))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    fn visible_columns(&self) -> Vec<usize> {
+        (0..self.columns.len())
+            .filter(|&column| self.columns[column].visible)
+            .collect()
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let last = self.order.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// Rebuild the row order if it's out of date, keeping the selected row selected
+    fn sync<R: TableRow>(&mut self, rows: &[R]) {
+        if !self.stale && self.order.len() == rows.len() {
+            return;
+        }
+
+        let selected_row = self.selected_row();
+        self.order = match self.sort {
+            Some((column, direction)) => {
+                let mut keyed: Vec<(SortKey, usize)> = rows
+                    .iter()
+                    .enumerate()
+                    .map(|(index, row)| (row.sort_key(column), index))
+                    .collect();
+                keyed.sort_by(|a, b| match direction {
+                    SortDirection::Ascending => a.0.cmp(&b.0),
+                    SortDirection::Descending => b.0.cmp(&a.0),
+                });
+                keyed.into_iter().map(|(_, index)| index).collect()
+            }
+            None => (0..rows.len()).collect(),
+        };
+        self.stale = false;
+
+        self.selected = selected_row
+            .and_then(|row| self.order.iter().position(|&index| index == row))
+            .unwrap_or(self.selected)
+            .min(self.order.len().saturating_sub(1));
+    }
+
+    /// Sorted positions in view for a body `height` rows tall, scrolled to the selection
+    fn window(&mut self, height: usize) -> Range<usize> {
+        self.page_size = height.max(1);
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + self.page_size {
+            self.offset = self.selected + 1 - self.page_size;
+        }
+        self.offset = self
+            .offset
+            .min(self.order.len().saturating_sub(self.page_size));
+        self.offset..(self.offset + self.page_size).min(self.order.len())
+    }
+}
+
+/// Rows of a table captured for writing to a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableExport {
+    /// Table name, used for the default file name
+    pub name: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl TableExport {
+    pub fn new(name: &str, headers: Vec<String>, rows: Vec<Vec<String>>) -> Self {
+        Self {
+            name: name.to_string(),
+            headers,
+            rows,
+        }
+    }
+
+    /// Timestamped CSV file in the working directory, e.g. `pools_20261017_120000.csv`
+    pub fn default_path(&self) -> PathBuf {
+        PathBuf::from(format!(
+            "{}_{}.csv",
+            self.name,
+            chrono::Local::now().format("%Y%m%d_%H%M%S")
+        ))
+    }
+
+    /// The header and rows as CSV, quoting fields as RFC 4180 does
+    pub fn to_csv(&self) -> String {
+        let line = |fields: &[String]| {
+            let fields: Vec<Cow<str>> = fields.iter().map(|field| csv_field(field)).collect();
+            fields.join(",") + "\n"
+        };
+        std::iter::once(&self.headers)
+            .chain(&self.rows)
+            .map(|fields| line(fields))
+            .collect()
+    }
+
+    /// The rows as a JSON array of objects keyed by column title
+    pub fn to_json(&self) -> serde_json::Value {
+        self.rows
+            .iter()
+            .map(|row| {
+                self.headers
+                    .iter()
+                    .cloned()
+                    .zip(row.iter().cloned().map(serde_json::Value::String))
+                    .collect::<serde_json::Map<_, _>>()
+            })
+            .collect()
+    }
+
+    /// Write the table to `path`, as JSON if it ends in `.json` and CSV otherwise
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file can't be written
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let contents = if is_json_path(path) {
+            serde_json::to_string_pretty(&self.to_json())?
+        } else {
+            self.to_csv()
+        };
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// Whether an export to `path` is written as JSON
+pub fn is_json_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+}
+
+/// Quote a CSV field if it holds a separator, quote or line break
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Render a sortable, scrollable table, building only the rows in view
+pub fn render_data_table<R: TableRow>(
+    f: &mut Frame,
+    area: Rect,
+    title: &str,
+    rows: &[R],
+    state: &mut DataTableState,
+    is_focused: bool,
+) {
+    state.sync(rows);
+
+    // Borders and the header row take three lines
+    let window = state.window(area.height.saturating_sub(3) as usize);
+    let columns = state.visible_columns();
+
+    let header = Row::new(columns.iter().map(|&column| {
+        let col = &state.columns[column];
+        let title = match state.sort {
+            Some((sorted, direction)) if sorted == column => {
+                format!("{} {}", col.title, direction.indicator())
+            }
+            _ => col.title.to_string(),
+        };
+        Cell::from(title).style(Style::default().add_modifier(Modifier::BOLD))
+    }))
+    .style(Style::default().bg(Color::DarkGray));
+
+    let body: Vec<Row> = state.order[window.clone()]
+        .iter()
+        .map(|&index| Row::new(columns.iter().map(|&column| rows[index].cell(column))))
+        .collect();
+
+    let title = if window.len() < rows.len() {
+        format!(
+            "{} ({}-{} of {})",
+            title,
+            window.start + 1,
+            window.end,
+            rows.len()
+        )
+    } else {
+        format!("{} ({} total)", title, rows.len())
+    };
+
+    let table = Table::new(
+        body,
+        columns.iter().map(|&column| state.columns[column].width),
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if is_focused {
+                Color::Yellow
+            } else {
+                Color::Blue
+            }))
+            .title(title),
+    )
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut table_state =
+        TableState::default().with_selected(state.selected.checked_sub(window.start));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
 /// Format balance for display (truncate very large numbers)
 fn format_balance(balance: &str) -> String {
     if let Ok(amount) = balance.parse::<u128>() {
@@ -343,6 +788,119 @@ mod tests {
         assert_eq!(format_balance("invalid"), "invalid");
     }
 
+    struct TestRow(u128, &'static str);
+
+    impl TableRow for TestRow {
+        fn cell(&self, column: usize) -> Cell<'_> {
+            match column {
+                0 => Cell::from(self.0.to_string()),
+                _ => Cell::from(self.1),
+            }
+        }
+
+        fn sort_key(&self, column: usize) -> SortKey {
+            match column {
+                0 => SortKey::Number(self.0),
+                _ => SortKey::Text(self.1.to_string()),
+            }
+        }
+
+        fn export_value(&self, column: usize) -> String {
+            match column {
+                0 => self.0.to_string(),
+                _ => self.1.to_string(),
+            }
+        }
+    }
+
+    fn test_table() -> DataTableState {
+        DataTableState::new(vec![
+            TableColumn::new("Amount", Constraint::Length(10)),
+            TableColumn::new("Name", Constraint::Min(0)),
+        ])
+    }
+
+    #[test]
+    fn test_data_table_sorting_keeps_selection() {
+        let rows = vec![TestRow(30, "c"), TestRow(10, "a"), TestRow(20, "b")];
+        let mut state = test_table();
+        state.sync(&rows);
+        state.select_next();
+        assert_eq!(state.selected_row(), Some(1));
+
+        state.sort_by(0);
+        state.sync(&rows);
+        assert_eq!(state.order, vec![1, 2, 0]);
+        assert_eq!(state.selected_row(), Some(1));
+
+        state.sort_by(0);
+        state.sync(&rows);
+        assert_eq!(state.order, vec![0, 2, 1]);
+        assert_eq!(state.selected_row(), Some(1));
+
+        state.cycle_sort();
+        assert_eq!(state.sort, Some((1, SortDirection::Ascending)));
+        state.cycle_sort();
+        assert_eq!(state.sort, None);
+    }
+
+    #[test]
+    fn test_data_table_window_follows_selection() {
+        let rows: Vec<TestRow> = (0..5000).map(|i| TestRow(i, "row")).collect();
+        let mut state = test_table();
+        state.sync(&rows);
+
+        assert_eq!(state.window(20), 0..20);
+        state.page_down();
+        state.page_down();
+        assert_eq!(state.window(20), 21..41);
+        state.select_last();
+        assert_eq!(state.window(20), 4980..5000);
+        state.select_previous();
+        assert_eq!(state.window(20), 4980..5000);
+        state.select_first();
+        assert_eq!(state.window(20), 0..20);
+    }
+
+    #[test]
+    fn test_data_table_column_visibility() {
+        let mut state = test_table();
+        assert!(state.handle_event(&UiEvent::Char('1')));
+        assert_eq!(state.visible_columns(), vec![1]);
+        // The last visible column stays
+        assert!(!state.handle_event(&UiEvent::Char('2')));
+        assert!(!state.handle_event(&UiEvent::Char('9')));
+        assert!(state.handle_event(&UiEvent::Char('1')));
+        assert_eq!(state.visible_columns(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_data_table_export_follows_view() {
+        let rows = vec![
+            TestRow(30, "c, d"),
+            TestRow(10, "a"),
+            TestRow(20, "say \"b\""),
+        ];
+        let mut state = test_table();
+        state.sort_by(0);
+
+        let export = state.export("test", &rows);
+        assert_eq!(export.headers, vec!["Amount", "Name"]);
+        assert_eq!(
+            export.to_csv(),
+            "Amount,Name\n10,a\n20,\"say \"\"b\"\"\"\n30,\"c, d\"\n"
+        );
+
+        state.toggle_column(0);
+        let export = state.export("test", &rows);
+        assert_eq!(
+            export.to_json(),
+            serde_json::json!([{"Name": "a"}, {"Name": "say \"b\""}, {"Name": "c, d"}])
+        );
+        assert!(is_json_path(Path::new("out.JSON")));
+        assert!(!is_json_path(&export.default_path()));
+    }
+
     #[test]
     fn test_determine_pool_type() {
         let constant_product = mantra_dex_std::pool_manager::PoolType::ConstantProduct;
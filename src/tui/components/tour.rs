@@ -0,0 +1,184 @@
+//! Onboarding Tour Component
+//!
+//! A guided overlay shown on first run that walks through the main screens and
+//! their key bindings. Each step switches to the screen it describes, and the
+//! pools step loads the pool list as a safe first action: a read-only query
+//! that needs no signature. Finishing or skipping the tour records it in the
+//! preferences; the help menu restarts it.
+
+use crate::tui::app::Screen;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// One step of the tour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TourStep {
+    /// Screen shown behind the step
+    pub screen: Screen,
+    pub title: &'static str,
+    pub lines: &'static [&'static str],
+}
+
+/// Steps of the tour, in order
+pub const TOUR_STEPS: [TourStep; 6] = [
+    TourStep {
+        screen: Screen::Dashboard,
+        title: "Welcome to MANTRA DEX",
+        lines: &[
+            "This short tour shows the main screens and how to move around.",
+            "The dashboard summarizes your balances, positions and recent transactions.",
+        ],
+    },
+    TourStep {
+        screen: Screen::Dashboard,
+        title: "Getting around",
+        lines: &[
+            "Tab / Shift+Tab switch screens, Enter works inside the current one.",
+            "Esc goes back, r or F5 refreshes, h or F1 shows every shortcut, q quits.",
+        ],
+    },
+    TourStep {
+        screen: Screen::Pools,
+        title: "Your first action: browse pools",
+        lines: &[
+            "The pool list was just queried from the chain. Queries only read data:",
+            "nothing is signed and no fees are paid. Use ↑/↓ to look through the pools.",
+        ],
+    },
+    TourStep {
+        screen: Screen::Swap,
+        title: "Swapping",
+        lines: &[
+            "Pick the tokens and amount, then press p to preview the output and price",
+            "impact before s executes the swap. Every swap is confirmed before signing.",
+        ],
+    },
+    TourStep {
+        screen: Screen::Liquidity,
+        title: "Liquidity and rewards",
+        lines: &[
+            "Provide (l) or withdraw (w) liquidity here, and claim farm rewards",
+            "on the Rewards screen (1 claims everything pending).",
+        ],
+    },
+    TourStep {
+        screen: Screen::Settings,
+        title: "Settings and help",
+        lines: &[
+            "Network, slippage and display preferences live in Settings.",
+            "Press h at any time for help, and t in the help to take this tour again.",
+        ],
+    },
+];
+
+/// Progress through the tour
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TourState {
+    step: usize,
+}
+
+impl TourState {
+    /// Start at the first step
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the current step
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Current step
+    pub fn current(&self) -> &'static TourStep {
+        &TOUR_STEPS[self.step]
+    }
+
+    /// Whether the current step is the last one
+    pub fn is_last(&self) -> bool {
+        self.step + 1 == TOUR_STEPS.len()
+    }
+
+    /// Move to the next step; returns `false` if the tour is over
+    pub fn advance(&mut self) -> bool {
+        if self.is_last() {
+            return false;
+        }
+        self.step += 1;
+        true
+    }
+
+    /// Move back a step, staying on the first one
+    pub fn previous(&mut self) {
+        self.step = self.step.saturating_sub(1);
+    }
+}
+
+/// Render the current tour step over the bottom of the screen
+pub fn render_tour(f: &mut Frame, tour: &TourState, area: Rect) {
+    let step = tour.current();
+    let height = (step.lines.len() as u16 + 5).min(area.height);
+    let width = (area.width * 4 / 5).max(40).min(area.width);
+    let overlay = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height - height,
+        width,
+        height,
+    };
+
+    let mut text: Vec<Line> = step.lines.iter().map(|line| Line::from(*line)).collect();
+    text.push(Line::from(""));
+    let next = if tour.is_last() { "finish" } else { "next" };
+    text.push(Line::from(Span::styled(
+        format!("→/Enter {} · ← back · Esc skip the tour", next),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .title(format!(
+            " Tour {}/{}: {} ",
+            tour.step() + 1,
+            TOUR_STEPS.len(),
+            step.title
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_widget(Clear, overlay);
+    f.render_widget(
+        Paragraph::new(text).block(block).wrap(Wrap { trim: true }),
+        overlay,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tour_steps_forward_and_back() {
+        let mut tour = TourState::new();
+        assert_eq!(tour.current().screen, Screen::Dashboard);
+
+        tour.previous();
+        assert_eq!(tour.step(), 0);
+
+        while tour.advance() {}
+        assert!(tour.is_last());
+        assert_eq!(tour.step(), TOUR_STEPS.len() - 1);
+
+        tour.previous();
+        assert!(!tour.is_last());
+    }
+
+    #[test]
+    fn test_tour_visits_pools() {
+        assert!(TOUR_STEPS.iter().any(|step| step.screen == Screen::Pools));
+    }
+}
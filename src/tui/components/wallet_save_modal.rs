@@ -10,6 +10,7 @@ use ratatui::{
 use tui_input::Input;
 
 use super::password_input::{PasswordInput, PasswordValidation};
+use crate::secret::SecretString;
 use crate::wallet::WalletStorage;
 
 /// Current step in the wallet save process
@@ -45,18 +46,18 @@ pub enum WalletSaveState {
 #[derive(Debug, Clone)]
 pub struct WalletSaveForm {
     pub wallet_name: String,
-    pub password: String,
-    pub password_confirm: String,
-    pub mnemonic: String,
+    pub password: SecretString,
+    pub password_confirm: SecretString,
+    pub mnemonic: SecretString,
     pub address: String,
 }
 
 impl WalletSaveForm {
-    pub fn new(mnemonic: String, address: String) -> Self {
+    pub fn new(mnemonic: SecretString, address: String) -> Self {
         Self {
             wallet_name: String::new(),
-            password: String::new(),
-            password_confirm: String::new(),
+            password: SecretString::default(),
+            password_confirm: SecretString::default(),
             mnemonic,
             address,
         }
@@ -130,7 +131,7 @@ pub struct WalletSaveModal {
 
 impl WalletSaveModal {
     /// Create a new wallet save modal
-    pub fn new(mnemonic: String, address: String) -> Self {
+    pub fn new(mnemonic: SecretString, address: String) -> Self {
         Self {
             is_visible: false,
             current_step: WalletSaveStep::WalletName,
@@ -188,11 +189,11 @@ impl WalletSaveModal {
             }
             WalletSaveStep::Password => {
                 self.password_input.handle_char(c);
-                self.form.password = self.password_input.value().to_string();
+                self.form.password = self.password_input.value().clone();
             }
             WalletSaveStep::PasswordConfirm => {
                 self.password_confirm_input.handle_char(c);
-                self.form.password_confirm = self.password_confirm_input.value().to_string();
+                self.form.password_confirm = self.password_confirm_input.value().clone();
             }
             _ => {}
         }
@@ -210,11 +211,11 @@ impl WalletSaveModal {
             }
             WalletSaveStep::Password => {
                 self.password_input.handle_backspace();
-                self.form.password = self.password_input.value().to_string();
+                self.form.password = self.password_input.value().clone();
             }
             WalletSaveStep::PasswordConfirm => {
                 self.password_confirm_input.handle_backspace();
-                self.form.password_confirm = self.password_confirm_input.value().to_string();
+                self.form.password_confirm = self.password_confirm_input.value().clone();
             }
             _ => {}
         }
@@ -772,6 +773,6 @@ impl InputHandler for PasswordInput {
     }
 
     fn value(&self) -> &str {
-        self.value()
+        PasswordInput::value(self).expose_secret()
     }
 }
@@ -6,10 +6,14 @@
 #[cfg(feature = "tui")]
 use crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers};
 
+#[cfg(feature = "tui")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "tui")]
+use std::sync::Arc;
 #[cfg(feature = "tui")]
 use std::time::Duration;
 #[cfg(feature = "tui")]
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 
 /// Focus direction for keyboard navigation
 #[derive(Debug, Clone, PartialEq)]
@@ -47,9 +51,9 @@ pub enum FocusableComponent {
     Custom(String),
 }
 
-/// Application events that can be handled
+/// User input and UI-level events
 #[derive(Debug, Clone, PartialEq)]
-pub enum Event {
+pub enum UiEvent {
     /// Quit the application
     Quit,
     /// Navigate to next tab
@@ -90,6 +94,8 @@ pub enum Event {
     Mouse,
     /// Pasted text (bracketed paste)
     Paste(String),
+    /// The terminal gained (`true`) or lost (`false`) focus
+    TerminalFocus(bool),
     /// Custom application events
     Custom(String),
 
@@ -113,7 +119,20 @@ pub enum Event {
     /// Context-sensitive action (Space bar)
     ContextAction,
 
-    // === DEX-specific Action Events ===
+    // === Screen Events ===
+    /// Trigger simulation based on input changes
+    TriggerSimulation,
+
+    /// Retry swap with increased slippage tolerance
+    RetryWithIncreasedSlippage,
+
+    /// Show swap confirmation modal
+    ShowSwapConfirmation,
+}
+
+/// Transaction requests and their progress
+#[derive(Debug, Clone, PartialEq)]
+pub enum TxEvent {
     /// Execute a swap operation
     ExecuteSwap {
         from_asset: String,
@@ -149,6 +168,8 @@ pub enum Event {
         epochs: Option<Vec<u64>>,
         claim_all: bool,
     },
+    /// Retry the last swap rejected on slippage with a higher tolerance
+    RetrySwapWithSlippageBump,
     /// Execute multi-hop swap
     ExecuteMultiHopSwap { operations: Vec<SwapOperation> },
     /// Create a new pool (admin)
@@ -179,7 +200,7 @@ pub enum Event {
         asset_2_amount: String,
     },
 
-    // === Async Blockchain Events ===
+    // === Results ===
     /// Blockchain operation completed successfully
     BlockchainSuccess {
         operation: String,
@@ -195,20 +216,144 @@ pub enum Event {
         status: String,
         progress: Option<f32>, // 0.0 to 1.0
     },
+}
+
+/// Background data and connectivity updates
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataEvent {
     /// Data refresh completed
     DataRefresh {
         data_type: String,
         success: bool,
         error: Option<String>,
     },
-    /// Trigger simulation based on input changes
-    TriggerSimulation,
+    /// The node health check moved to a new state
+    NetworkStateChanged {
+        from: crate::tui::utils::async_ops::NetworkState,
+        to: crate::tui::utils::async_ops::NetworkState,
+    },
+    /// An RPC endpoint's circuit breaker changed state
+    CircuitStateChanged {
+        endpoint: String,
+        state: crate::circuit_breaker::CircuitState,
+    },
+    /// RPC endpoint latencies were measured
+    EndpointsProbed {
+        endpoints: Vec<crate::circuit_breaker::EndpointHealth>,
+    },
+    /// Cached routes were dropped as the reserves of their pools moved
+    RoutesInvalidated { dropped: usize },
+    /// Risk screening of pools finished, reports by pool ID
+    PoolRisksAssessed {
+        reports: Vec<(String, crate::risk::RiskReport)>,
+    },
+    /// Ownership lookup of a pool finished
+    PoolOwnershipLoaded {
+        ownership: crate::ownership::PoolOwnership,
+    },
+    /// Comparison of the marked pools finished
+    PoolsCompared {
+        result: Result<Vec<crate::compare::PoolComparison>, String>,
+    },
+    /// Valuation of the paper portfolio finished
+    PaperPortfolioValued {
+        result: Result<crate::paper::PaperValuation, String>,
+    },
+    /// The preferences file was written, possibly by another process
+    PreferencesFileChanged,
+}
 
-    /// Retry swap with increased slippage tolerance
-    RetryWithIncreasedSlippage,
+/// Application events, grouped by domain
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// Keyboard input and UI actions
+    Ui(UiEvent),
+    /// Background data refreshes and connectivity changes
+    Data(DataEvent),
+    /// Transaction requests, progress and results
+    Tx(TxEvent),
+}
 
-    /// Show swap confirmation modal
-    ShowSwapConfirmation,
+impl From<UiEvent> for Event {
+    fn from(event: UiEvent) -> Self {
+        Event::Ui(event)
+    }
+}
+
+impl From<DataEvent> for Event {
+    fn from(event: DataEvent) -> Self {
+        Event::Data(event)
+    }
+}
+
+impl From<TxEvent> for Event {
+    fn from(event: TxEvent) -> Self {
+        Event::Tx(event)
+    }
+}
+
+/// Buffered events per domain subscriber before the slowest one starts lagging
+const SUBSCRIBER_CAPACITY: usize = 256;
+
+/// Dispatches events to the application loop and to per-domain subscribers
+///
+/// Every event reaches the app's main loop. Features that only care about one
+/// domain can subscribe to it instead of matching on every event.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    /// Channel feeding the application's main loop
+    app: mpsc::UnboundedSender<Event>,
+    ui: broadcast::Sender<UiEvent>,
+    data: broadcast::Sender<DataEvent>,
+    tx: broadcast::Sender<TxEvent>,
+}
+
+impl EventBus {
+    /// Create a bus that feeds the application loop through `app`
+    pub fn new(app: mpsc::UnboundedSender<Event>) -> Self {
+        Self {
+            app,
+            ui: broadcast::channel(SUBSCRIBER_CAPACITY).0,
+            data: broadcast::channel(SUBSCRIBER_CAPACITY).0,
+            tx: broadcast::channel(SUBSCRIBER_CAPACITY).0,
+        }
+    }
+
+    /// Publish an event to its domain's subscribers and the application loop
+    ///
+    /// Fails only once the application loop has stopped receiving.
+    pub fn send(&self, event: impl Into<Event>) -> Result<(), Box<mpsc::error::SendError<Event>>> {
+        let event = event.into();
+        // Copies are only made for domains that have subscribers
+        match &event {
+            Event::Ui(ui) if self.ui.receiver_count() > 0 => {
+                let _ = self.ui.send(ui.clone());
+            }
+            Event::Data(data) if self.data.receiver_count() > 0 => {
+                let _ = self.data.send(data.clone());
+            }
+            Event::Tx(tx) if self.tx.receiver_count() > 0 => {
+                let _ = self.tx.send(tx.clone());
+            }
+            _ => {}
+        }
+        self.app.send(event).map_err(Box::new)
+    }
+
+    /// Receive user input and UI actions
+    pub fn subscribe_ui(&self) -> broadcast::Receiver<UiEvent> {
+        self.ui.subscribe()
+    }
+
+    /// Receive data refreshes and connectivity changes
+    pub fn subscribe_data(&self) -> broadcast::Receiver<DataEvent> {
+        self.data.subscribe()
+    }
+
+    /// Receive transaction requests, progress and results
+    pub fn subscribe_tx(&self) -> broadcast::Receiver<TxEvent> {
+        self.tx.subscribe()
+    }
 }
 
 /// Swap operation details for multi-hop swaps
@@ -233,27 +378,39 @@ pub struct ProvideResultWrapper {
     pub pool_total_supply: Option<cosmwasm_std::Uint128>,
 }
 
+/// Wrapper for claim rewards result
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClaimResultWrapper {
+    pub txhash: String,
+    /// Rewards the transaction paid to the wallet
+    pub claimed: Vec<cosmwasm_std::Coin>,
+    /// Last epoch claimed, `None` for every epoch up to the current one
+    pub until_epoch: Option<u64>,
+}
+
 /// Event handler for processing terminal events
 pub struct EventHandler {
     /// Receiver for events
     receiver: mpsc::UnboundedReceiver<Event>,
-    /// Sender for events (for custom events)
-    sender: mpsc::UnboundedSender<Event>,
+    /// Bus publishing to the receiver and domain subscribers
+    sender: EventBus,
     /// Handle for the background terminal event processing task
     _terminal_task: tokio::task::JoinHandle<()>,
+    /// Whether the TUI is idle, polling the terminal less often
+    idle: Arc<AtomicBool>,
 }
 
 /// Async blockchain processor for handling blockchain operations
 pub struct AsyncBlockchainProcessor {
     /// Event sender to communicate with the main event loop
-    event_sender: mpsc::UnboundedSender<Event>,
+    event_sender: EventBus,
     /// Optional client reference for real blockchain operations
     client: Option<std::sync::Arc<crate::client::MantraDexClient>>,
 }
 
 impl AsyncBlockchainProcessor {
     /// Create a new async blockchain processor with event sender only
-    pub fn new(event_sender: mpsc::UnboundedSender<Event>) -> Self {
+    pub fn new(event_sender: EventBus) -> Self {
         Self {
             event_sender,
             client: None,
@@ -262,7 +419,7 @@ impl AsyncBlockchainProcessor {
 
     /// Create a new async blockchain processor with both event sender and client
     pub fn with_client(
-        event_sender: mpsc::UnboundedSender<Event>,
+        event_sender: EventBus,
         client: std::sync::Arc<crate::client::MantraDexClient>,
     ) -> Self {
         Self {
@@ -283,28 +440,28 @@ impl AsyncBlockchainProcessor {
         let operation = "swap".to_string();
 
         // Send progress event
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
             status: "Initiating swap...".to_string(),
-            progress: Some(0.1),
+            progress: None,
         });
 
         // TODO: Replace with actual SDK call
         // For now, simulate async operation
         tokio::time::sleep(Duration::from_millis(500)).await;
 
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
             status: "Broadcasting transaction...".to_string(),
-            progress: Some(0.5),
+            progress: None,
         });
 
         tokio::time::sleep(Duration::from_millis(1000)).await;
 
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
             status: "Confirming transaction...".to_string(),
-            progress: Some(0.8),
+            progress: None,
         });
 
         tokio::time::sleep(Duration::from_millis(500)).await;
@@ -313,7 +470,7 @@ impl AsyncBlockchainProcessor {
         let success = true; // TODO: Replace with actual SDK call result
 
         if success {
-            let _ = self.event_sender.send(Event::BlockchainSuccess {
+            let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
                 operation: operation.clone(),
                 result: format!(
                     "Swapped {} {} for {} {}",
@@ -323,7 +480,7 @@ impl AsyncBlockchainProcessor {
                 enhanced_data: None,
             });
         } else {
-            let _ = self.event_sender.send(Event::BlockchainError {
+            let _ = self.event_sender.send(TxEvent::BlockchainError {
                 operation: operation.clone(),
                 error: "Insufficient liquidity in pool".to_string(),
             });
@@ -341,10 +498,10 @@ impl AsyncBlockchainProcessor {
         let operation = "provide_liquidity".to_string();
 
         // Send initial progress
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
             status: "Preparing liquidity transaction...".to_string(),
-            progress: Some(0.1),
+            progress: None,
         });
 
         // Get the client from the shared state (we'll need to implement this)
@@ -372,7 +529,7 @@ impl AsyncBlockchainProcessor {
 
                 // Send enhanced success event with LP token details
                 let tx_hash = tx_response.txhash.clone();
-                let _ = self.event_sender.send(Event::BlockchainSuccess {
+                let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
                     operation: operation.clone(),
                     result: success_message,
                     transaction_hash: Some(tx_hash),
@@ -381,7 +538,7 @@ impl AsyncBlockchainProcessor {
                 });
             }
             Err(e) => {
-                let _ = self.event_sender.send(Event::BlockchainError {
+                let _ = self.event_sender.send(TxEvent::BlockchainError {
                     operation: operation.clone(),
                     error: format!("Failed to provide liquidity: {}", e),
                 });
@@ -407,10 +564,10 @@ impl AsyncBlockchainProcessor {
         use std::str::FromStr;
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "provide_liquidity".to_string(),
             status: "Converting amounts and denominations...".to_string(),
-            progress: Some(0.2),
+            progress: None,
         });
 
         // Get the actual asset denominations from the selected pool's info
@@ -449,10 +606,10 @@ impl AsyncBlockchainProcessor {
         };
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "provide_liquidity".to_string(),
             status: "Preparing assets for liquidity provision...".to_string(),
-            progress: Some(0.4),
+            progress: None,
         });
 
         // Create the assets vector with correct denominations and micro amounts
@@ -473,10 +630,10 @@ impl AsyncBlockchainProcessor {
         ));
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "provide_liquidity".to_string(),
             status: "Broadcasting transaction to blockchain...".to_string(),
-            progress: Some(0.7),
+            progress: None,
         });
 
         // Execute actual blockchain transaction if client is available
@@ -508,10 +665,10 @@ impl AsyncBlockchainProcessor {
             {
                 Ok(tx_response) => {
                     // Send final progress update
-                    let _ = self.event_sender.send(Event::BlockchainProgress {
+                    let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                         operation: "provide_liquidity".to_string(),
                         status: "Transaction confirmed, processing results...".to_string(),
-                        progress: Some(0.9),
+                        progress: None,
                     });
 
                     crate::tui::utils::logger::log_info(&format!(
@@ -564,10 +721,10 @@ impl AsyncBlockchainProcessor {
             tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
 
             // Send final progress update
-            let _ = self.event_sender.send(Event::BlockchainProgress {
+            let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                 operation: "provide_liquidity".to_string(),
                 status: "Transaction confirmed, processing results...".to_string(),
-                progress: Some(0.9),
+                progress: None,
             });
 
             // Return mock success
@@ -731,8 +888,6 @@ impl AsyncBlockchainProcessor {
             .parse::<f64>()
             .map_err(|e| format!("Invalid amount format: {}", e))?;
 
-        // Most tokens on Mantra use 6 decimals as the standard
-        // This includes OM (uom), USDC (factory tokens), and most other assets
         let decimals = self.get_token_decimals_for_denom(denom);
 
         // Convert to micro amount
@@ -746,27 +901,14 @@ impl AsyncBlockchainProcessor {
         Ok(cosmwasm_std::Uint128::new(micro_amount))
     }
 
-    /// Get the number of decimal places for a given denomination
-    /// Most Mantra network tokens use 6 decimals
+    /// Get the number of decimal places for a given denomination from the
+    /// client's token registry
     fn get_token_decimals_for_denom(&self, denom: &str) -> u8 {
-        match denom {
-            // Native OM token
-            "uom" => 6,
-            // Factory tokens (USDC, USDT, etc.) typically use 6 decimals
-            d if d.starts_with("factory/") => {
-                // Could be enhanced to read from config in the future
-                // For now, use the standard 6 decimals for all factory tokens
-                6
-            }
-            // IBC tokens - most use 6 decimals but could vary
-            d if d.starts_with("ibc/") => {
-                // Could be enhanced to maintain an IBC token registry
-                // For now, default to 6 decimals
-                6
-            }
-            // Default case
-            _ => 6,
-        }
+        self.client
+            .as_ref()
+            .map_or(crate::token_registry::DEFAULT_DECIMALS, |client| {
+                client.token_registry().decimals(denom)
+            })
     }
 
     /// Withdraw liquidity from a pool asynchronously
@@ -779,10 +921,10 @@ impl AsyncBlockchainProcessor {
         let operation = "withdraw_liquidity".to_string();
 
         // Send initial progress
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
             status: "Preparing liquidity withdrawal...".to_string(),
-            progress: Some(0.1),
+            progress: None,
         });
 
         let result = self
@@ -795,7 +937,7 @@ impl AsyncBlockchainProcessor {
 
         match result {
             Ok(tx_response) => {
-                let _ = self.event_sender.send(Event::BlockchainSuccess {
+                let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
                     operation: operation.clone(),
                     result: format!(
                         "Successfully withdrew liquidity from pool {}. Assets received: {}",
@@ -807,7 +949,7 @@ impl AsyncBlockchainProcessor {
                 });
             }
             Err(e) => {
-                let _ = self.event_sender.send(Event::BlockchainError {
+                let _ = self.event_sender.send(TxEvent::BlockchainError {
                     operation: operation.clone(),
                     error: format!("Failed to withdraw liquidity: {}", e),
                 });
@@ -826,10 +968,10 @@ impl AsyncBlockchainProcessor {
         use std::str::FromStr;
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "withdraw_liquidity".to_string(),
             status: "Converting LP token amount...".to_string(),
-            progress: Some(0.2),
+            progress: None,
         });
 
         // Parse LP token amount
@@ -837,17 +979,17 @@ impl AsyncBlockchainProcessor {
             .map_err(|e| format!("Invalid LP token amount: {}", e))?;
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "withdraw_liquidity".to_string(),
             status: "Calculating withdrawal amounts...".to_string(),
-            progress: Some(0.4),
+            progress: None,
         });
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "withdraw_liquidity".to_string(),
             status: "Broadcasting withdrawal transaction...".to_string(),
-            progress: Some(0.7),
+            progress: None,
         });
 
         // Execute actual blockchain transaction if client is available
@@ -855,10 +997,10 @@ impl AsyncBlockchainProcessor {
             match client.withdraw_liquidity(&pool_id, lp_amount).await {
                 Ok(tx_response) => {
                     // Send final progress update
-                    let _ = self.event_sender.send(Event::BlockchainProgress {
+                    let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                         operation: "withdraw_liquidity".to_string(),
                         status: "Transaction confirmed, processing results...".to_string(),
-                        progress: Some(0.9),
+                        progress: None,
                     });
 
                     Ok(ProvideResultWrapper {
@@ -883,10 +1025,10 @@ impl AsyncBlockchainProcessor {
             tokio::time::sleep(std::time::Duration::from_millis(1200)).await;
 
             // Send final progress update
-            let _ = self.event_sender.send(Event::BlockchainProgress {
+            let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                 operation: "withdraw_liquidity".to_string(),
                 status: "Transaction confirmed, processing results...".to_string(),
-                progress: Some(0.9),
+                progress: None,
             });
 
             // Return mock success
@@ -903,45 +1045,136 @@ impl AsyncBlockchainProcessor {
     }
 
     /// Claim rewards asynchronously
+    ///
+    /// The farm manager pays out the rewards of every farm position at once, up
+    /// to the last of `epochs` or, with `claim_all` or no epochs, up to the
+    /// current epoch. The stages of the transaction reach the loading modal
+    /// through the client's progress stream; the claimed amounts are read from
+    /// the coins the transaction sent to the wallet.
     pub async fn claim_rewards(
         &self,
         pool_id: Option<String>,
-        _epochs: Option<Vec<u64>>,
+        epochs: Option<Vec<u64>>,
         claim_all: bool,
     ) {
         let operation = "claim_rewards".to_string();
 
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
-            status: "Calculating claimable rewards...".to_string(),
-            progress: Some(0.4),
+            status: "Preparing rewards claim...".to_string(),
+            progress: None,
         });
 
-        tokio::time::sleep(Duration::from_millis(400)).await;
+        let until_epoch = if claim_all {
+            None
+        } else {
+            epochs
+                .as_ref()
+                .and_then(|epochs| epochs.iter().max().copied())
+        };
 
-        let success = true; // TODO: Replace with actual SDK call result
+        match self.execute_claim_rewards_transaction(until_epoch).await {
+            Ok(claim) => {
+                let mut result = if claim.claimed.is_empty() {
+                    "No rewards were pending".to_string()
+                } else {
+                    format!("Claimed {}", self.format_coins(&claim.claimed))
+                };
+                if let Some(pool) = pool_id {
+                    // Claims aren't scoped to a pool on chain
+                    result.push_str(&format!(
+                        " (includes every farm position, not only pool {})",
+                        pool
+                    ));
+                }
 
-        if success {
-            let result = if claim_all {
-                "Claimed all available rewards".to_string()
-            } else if let Some(pool) = pool_id {
-                format!("Claimed rewards from pool {}", pool)
-            } else {
-                "Claimed rewards for specified epochs".to_string()
-            };
+                let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
+                    operation: operation.clone(),
+                    result,
+                    transaction_hash: Some(claim.txhash.clone()),
+                    enhanced_data: Some(serde_json::to_string(&claim).unwrap_or_default()),
+                });
+            }
+            Err(e) => {
+                let _ = self.event_sender.send(TxEvent::BlockchainError {
+                    operation: operation.clone(),
+                    error: format!("Failed to claim rewards: {}", e),
+                });
+            }
+        }
+    }
 
-            let _ = self.event_sender.send(Event::BlockchainSuccess {
-                operation: operation.clone(),
-                result,
-                transaction_hash: Some("0x1111222233334444...".to_string()),
-                enhanced_data: None, // No enhanced data for rewards operations yet
-            });
-        } else {
-            let _ = self.event_sender.send(Event::BlockchainError {
-                operation: operation.clone(),
-                error: "No rewards available to claim".to_string(),
-            });
+    /// Execute the claim rewards transaction using the SDK client
+    async fn execute_claim_rewards_transaction(
+        &self,
+        until_epoch: Option<u64>,
+    ) -> Result<ClaimResultWrapper, String> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| "No client connected".to_string())?;
+        let wallet_address = client
+            .get_wallet_address()
+            .await
+            .ok_or_else(|| "No wallet connected".to_string())?;
+
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
+            operation: "claim_rewards".to_string(),
+            status: "Broadcasting claim transaction...".to_string(),
+            progress: None,
+        });
+
+        let tx_response = match until_epoch {
+            Some(epoch) => client.claim_rewards_until_epoch(epoch).await,
+            None => client.claim_rewards_all().await,
         }
+        .map_err(|e| format!("Blockchain transaction failed: {}", e))?;
+
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
+            operation: "claim_rewards".to_string(),
+            status: "Transaction confirmed, reading claimed rewards...".to_string(),
+            progress: None,
+        });
+
+        let claimed = crate::simulation::balance_changes(&tx_response.events, &wallet_address)
+            .into_iter()
+            .filter(|change| !change.received.is_zero())
+            .map(|change| cosmwasm_std::Coin {
+                denom: change.denom,
+                amount: change.received,
+            })
+            .collect();
+
+        Ok(ClaimResultWrapper {
+            txhash: tx_response.txhash,
+            claimed,
+            until_epoch,
+        })
+    }
+
+    /// Coins in display units with their symbols, e.g. `1.5 OM, 0.25 USDC`
+    fn format_coins(&self, coins: &[cosmwasm_std::Coin]) -> String {
+        coins
+            .iter()
+            .map(|coin| {
+                let (symbol, decimals) = match &self.client {
+                    Some(client) => (
+                        client.token_registry().symbol(&coin.denom),
+                        client.token_registry().decimals(&coin.denom),
+                    ),
+                    None => (
+                        crate::token_registry::fallback_symbol(&coin.denom),
+                        crate::token_registry::DEFAULT_DECIMALS,
+                    ),
+                };
+                format!(
+                    "{} {}",
+                    self.format_token_amount(&coin.amount, decimals),
+                    symbol
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 
     /// Create a new pool asynchronously
@@ -956,10 +1189,10 @@ impl AsyncBlockchainProcessor {
         let operation = "create_pool".to_string();
 
         // Send initial progress
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation.clone(),
             status: "Preparing pool creation...".to_string(),
-            progress: Some(0.1),
+            progress: None,
         });
 
         let result = self
@@ -974,7 +1207,7 @@ impl AsyncBlockchainProcessor {
 
         match result {
             Ok(tx_response) => {
-                let _ = self.event_sender.send(Event::BlockchainSuccess {
+                let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
                     operation: operation.clone(),
                     result: format!("Successfully created pool for {} / {}", asset_1, asset_2),
                     transaction_hash: Some(tx_response.txhash),
@@ -982,7 +1215,7 @@ impl AsyncBlockchainProcessor {
                 });
             }
             Err(e) => {
-                let _ = self.event_sender.send(Event::BlockchainError {
+                let _ = self.event_sender.send(TxEvent::BlockchainError {
                     operation: operation.clone(),
                     error: format!("Failed to create pool: {}", e),
                 });
@@ -990,28 +1223,79 @@ impl AsyncBlockchainProcessor {
         }
     }
 
+    /// Update which operations a pool allows asynchronously
+    ///
+    /// `features` lists the operations left enabled (`withdrawals`, `deposits`
+    /// and `swaps`); the ones missing are disabled.
+    pub async fn update_pool_features(&self, pool_id: String, features: Vec<String>) {
+        let operation = "update_pool_features".to_string();
+
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
+            operation: operation.clone(),
+            status: "Broadcasting pool feature update...".to_string(),
+            progress: None,
+        });
+
+        let enabled = |feature: &str| Some(features.iter().any(|f| f == feature));
+        let result = match &self.client {
+            Some(client) => client
+                .update_pool_features(
+                    &pool_id,
+                    enabled("withdrawals"),
+                    enabled("deposits"),
+                    enabled("swaps"),
+                )
+                .await
+                .map_err(|e| format!("Blockchain transaction failed: {}", e)),
+            None => Err("No client connected".to_string()),
+        };
+
+        match result {
+            Ok(tx_response) => {
+                let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
+                    operation,
+                    result: format!("Features updated for pool {}", pool_id),
+                    transaction_hash: Some(tx_response.txhash),
+                    enhanced_data: Some(format!("Enabled features: {:?}", features)),
+                });
+            }
+            Err(e) => {
+                let _ = self.event_sender.send(TxEvent::BlockchainError {
+                    operation,
+                    error: format!("Failed to update pool features: {}", e),
+                });
+            }
+        }
+    }
+
     /// Execute the actual create pool transaction using the SDK client
     async fn execute_create_pool_transaction(
         &self,
         asset_1: String,
         asset_2: String,
         swap_fee: String,
-        _exit_fee: String,
+        exit_fee: String,
         pool_features: Vec<String>,
     ) -> Result<ProvideResultWrapper, String> {
         use cosmwasm_std::Decimal;
         use std::str::FromStr;
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "create_pool".to_string(),
             status: "Parsing pool parameters...".to_string(),
-            progress: Some(0.2),
+            progress: None,
         });
 
-        // Parse swap fee
+        // Fees are entered as percentages
+        let percent_share =
+            |percent: &str| Decimal::from_str(percent).map(|percent| percent * Decimal::percent(1));
+
+        // Parse swap and burn fees
         let swap_fee_decimal =
-            Decimal::from_str(&swap_fee).map_err(|e| format!("Invalid swap fee: {}", e))?;
+            percent_share(&swap_fee).map_err(|e| format!("Invalid swap fee: {}", e))?;
+        let burn_fee_decimal =
+            percent_share(&exit_fee).map_err(|e| format!("Invalid burn fee: {}", e))?;
 
         // Parse protocol fee from pool features
         let mut protocol_fee_decimal = Decimal::zero();
@@ -1019,7 +1303,7 @@ impl AsyncBlockchainProcessor {
 
         for feature in &pool_features {
             if let Some(protocol_fee_str) = feature.strip_prefix("protocol_fee:") {
-                protocol_fee_decimal = Decimal::from_str(protocol_fee_str)
+                protocol_fee_decimal = percent_share(protocol_fee_str)
                     .map_err(|e| format!("Invalid protocol fee: {}", e))?;
             } else if let Some(pool_type_val) = feature.strip_prefix("pool_type:") {
                 pool_type_str = pool_type_val.to_string();
@@ -1027,10 +1311,10 @@ impl AsyncBlockchainProcessor {
         }
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "create_pool".to_string(),
             status: "Preparing pool configuration...".to_string(),
-            progress: Some(0.4),
+            progress: None,
         });
 
         // Create pool fees structure
@@ -1042,7 +1326,7 @@ impl AsyncBlockchainProcessor {
                 share: swap_fee_decimal,
             },
             burn_fee: mantra_dex_std::fee::Fee {
-                share: cosmwasm_std::Decimal::zero(),
+                share: burn_fee_decimal,
             },
             extra_fees: vec![], // No extra fees for basic pool creation
         };
@@ -1051,16 +1335,16 @@ impl AsyncBlockchainProcessor {
         let pool_type = match pool_type_str.as_str() {
             "ConstantProduct" => mantra_dex_std::pool_manager::PoolType::ConstantProduct,
             "StableSwap" => mantra_dex_std::pool_manager::PoolType::StableSwap {
-                amp: 100, // Default amplification parameter
+                amp: crate::pool_template::DEFAULT_STABLE_AMP,
             },
             _ => mantra_dex_std::pool_manager::PoolType::ConstantProduct,
         };
 
         // Send progress update
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: "create_pool".to_string(),
             status: "Broadcasting pool creation transaction...".to_string(),
-            progress: Some(0.7),
+            progress: None,
         });
 
         // Execute actual blockchain transaction if client is available
@@ -1071,21 +1355,32 @@ impl AsyncBlockchainProcessor {
             ));
 
             match client
-                .create_pool(
+                .create_pool_idempotent(
                     vec![asset_1.clone(), asset_2.clone()],
                     vec![6, 6], // Default to 6 decimals for both assets
                     pool_fees,
                     pool_type,
-                    None, // No custom pool identifier
+                    None,  // No custom pool identifier
+                    false, // Refuse to create duplicates
                 )
                 .await
             {
-                Ok(tx_response) => {
+                Ok(crate::client::CreatePoolOutcome::PoolAlreadyExists { pool_id }) => {
+                    crate::tui::utils::logger::log_warning(&format!(
+                        "Pool creation skipped, matching pool already exists: {}",
+                        pool_id
+                    ));
+                    Err(format!(
+                        "Pool {} already has assets {} / {} with the same type and fees",
+                        pool_id, asset_1, asset_2
+                    ))
+                }
+                Ok(crate::client::CreatePoolOutcome::Created(tx_response)) => {
                     // Send final progress update
-                    let _ = self.event_sender.send(Event::BlockchainProgress {
+                    let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                         operation: "create_pool".to_string(),
                         status: "Transaction confirmed, pool created successfully!".to_string(),
-                        progress: Some(0.9),
+                        progress: None,
                     });
 
                     crate::tui::utils::logger::log_info(&format!(
@@ -1124,10 +1419,10 @@ impl AsyncBlockchainProcessor {
             tokio::time::sleep(std::time::Duration::from_millis(2000)).await;
 
             // Send final progress update
-            let _ = self.event_sender.send(Event::BlockchainProgress {
+            let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                 operation: "create_pool".to_string(),
                 status: "Transaction confirmed, pool created successfully!".to_string(),
-                progress: Some(0.9),
+                progress: None,
             });
 
             // Return mock success
@@ -1152,7 +1447,7 @@ impl AsyncBlockchainProcessor {
 
         let success = true; // TODO: Replace with actual SDK call result (90% success rate in real implementation)
 
-        let _ = self.event_sender.send(Event::DataRefresh {
+        let _ = self.event_sender.send(DataEvent::DataRefresh {
             data_type: data_type.clone(),
             success,
             error: if success {
@@ -1167,14 +1462,23 @@ impl AsyncBlockchainProcessor {
 impl EventHandler {
     /// Create a new event handler
     pub fn new() -> Self {
-        let (sender, receiver) = mpsc::unbounded_channel();
+        let (app_sender, receiver) = mpsc::unbounded_channel();
+        let sender = EventBus::new(app_sender);
 
         // Spawn a task to handle terminal events
         let event_sender = sender.clone();
+        let idle = Arc::new(AtomicBool::new(false));
+        let poll_idle = Arc::clone(&idle);
         let terminal_task = tokio::spawn(async move {
             loop {
-                // Poll for events with a timeout to avoid blocking
-                if event::poll(Duration::from_millis(50)).unwrap_or(false) {
+                // Poll for events with a timeout to avoid blocking; input still
+                // returns at once while idle
+                let poll_timeout = if poll_idle.load(Ordering::Relaxed) {
+                    Duration::from_millis(250)
+                } else {
+                    Duration::from_millis(50)
+                };
+                if event::poll(poll_timeout).unwrap_or(false) {
                     if let Ok(terminal_event) = event::read() {
                         if let Some(app_event) = Self::convert_terminal_event(terminal_event) {
                             if event_sender.send(app_event).is_err() {
@@ -1193,9 +1497,15 @@ impl EventHandler {
             receiver,
             sender,
             _terminal_task: terminal_task,
+            idle,
         }
     }
 
+    /// Poll the terminal less often while the TUI is idle
+    pub fn set_idle(&self, idle: bool) {
+        self.idle.store(idle, Ordering::Relaxed);
+    }
+
     /// Get the next event
     pub async fn next(&mut self) -> Result<Event, Box<dyn std::error::Error + Send + Sync>> {
         self.receiver
@@ -1205,18 +1515,19 @@ impl EventHandler {
     }
 
     /// Convert a terminal event to an application event
-    fn convert_terminal_event(terminal_event: event::Event) -> Option<Event> {
+    fn convert_terminal_event(terminal_event: event::Event) -> Option<UiEvent> {
         match terminal_event {
             event::Event::Key(key_event) => Self::convert_key_event(key_event),
-            event::Event::Mouse(_) => Some(Event::Mouse),
+            event::Event::Mouse(_) => Some(UiEvent::Mouse),
             event::Event::Resize(_, _) => None, // Handle resize events if needed
-            event::Event::Paste(data) => Some(Event::Paste(data)),
-            _ => None,
+            event::Event::Paste(data) => Some(UiEvent::Paste(data)),
+            event::Event::FocusGained => Some(UiEvent::TerminalFocus(true)),
+            event::Event::FocusLost => Some(UiEvent::TerminalFocus(false)),
         }
     }
 
     /// Convert a key event to an application event
-    fn convert_key_event(key_event: KeyEvent) -> Option<Event> {
+    fn convert_key_event(key_event: KeyEvent) -> Option<UiEvent> {
         match key_event {
             // Quit events - removed automatic 'q' conversion to prevent interference with text input
             // 'q' will be handled by the application based on context
@@ -1224,109 +1535,109 @@ impl EventHandler {
                 code: KeyCode::Char('c'),
                 modifiers: KeyModifiers::CONTROL,
                 ..
-            } => Some(Event::Quit),
+            } => Some(UiEvent::Quit),
 
             // Tab navigation - Enhanced for focus management
             KeyEvent {
                 code: KeyCode::Tab,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Tab), // Screen navigation
+            } => Some(UiEvent::Tab), // Screen navigation
 
             KeyEvent {
                 code: KeyCode::BackTab,
                 modifiers: KeyModifiers::SHIFT,
                 ..
-            } => Some(Event::BackTab), // Reverse screen navigation
+            } => Some(UiEvent::BackTab), // Reverse screen navigation
 
             // Action keys
             KeyEvent {
                 code: KeyCode::Enter,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Enter),
+            } => Some(UiEvent::Enter),
 
             KeyEvent {
                 code: KeyCode::Esc,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Escape),
+            } => Some(UiEvent::Escape),
 
             // Arrow keys - Enhanced for directional focus movement
             KeyEvent {
                 code: KeyCode::Up,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::MoveFocus(FocusDirection::Up)),
+            } => Some(UiEvent::MoveFocus(FocusDirection::Up)),
 
             KeyEvent {
                 code: KeyCode::Down,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::MoveFocus(FocusDirection::Down)),
+            } => Some(UiEvent::MoveFocus(FocusDirection::Down)),
 
             KeyEvent {
                 code: KeyCode::Left,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::MoveFocus(FocusDirection::Left)),
+            } => Some(UiEvent::MoveFocus(FocusDirection::Left)),
 
             KeyEvent {
                 code: KeyCode::Right,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::MoveFocus(FocusDirection::Right)),
+            } => Some(UiEvent::MoveFocus(FocusDirection::Right)),
 
             // Navigation keys with enhanced focus support
             KeyEvent {
                 code: KeyCode::Home,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::FocusFirst),
+            } => Some(UiEvent::FocusFirst),
 
             KeyEvent {
                 code: KeyCode::End,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::FocusLast),
+            } => Some(UiEvent::FocusLast),
 
             // Space bar for context-sensitive actions
             KeyEvent {
                 code: KeyCode::Char(' '),
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::ContextAction),
+            } => Some(UiEvent::ContextAction),
 
             // Editing keys
             KeyEvent {
                 code: KeyCode::Backspace,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Backspace),
+            } => Some(UiEvent::Backspace),
 
             KeyEvent {
                 code: KeyCode::Delete,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Delete),
+            } => Some(UiEvent::Delete),
 
             KeyEvent {
                 code: KeyCode::PageUp,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::PageUp),
+            } => Some(UiEvent::PageUp),
 
             KeyEvent {
                 code: KeyCode::PageDown,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::PageDown),
+            } => Some(UiEvent::PageDown),
 
             KeyEvent {
                 code: KeyCode::Insert,
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Insert),
+            } => Some(UiEvent::Insert),
 
             // Function keys with enhanced shortcuts
             KeyEvent {
@@ -1334,9 +1645,9 @@ impl EventHandler {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => match n {
-                1 => Some(Event::Help),
-                5 => Some(Event::Refresh),
-                _ => Some(Event::F(n)),
+                1 => Some(UiEvent::Help),
+                5 => Some(UiEvent::Refresh),
+                _ => Some(UiEvent::F(n)),
             },
 
             // Enhanced keyboard shortcuts for accessibility
@@ -1345,27 +1656,27 @@ impl EventHandler {
                 code: KeyCode::Home,
                 modifiers: KeyModifiers::CONTROL,
                 ..
-            } => Some(Event::FocusFirst),
+            } => Some(UiEvent::FocusFirst),
 
             KeyEvent {
                 code: KeyCode::End,
                 modifiers: KeyModifiers::CONTROL,
                 ..
-            } => Some(Event::FocusLast),
+            } => Some(UiEvent::FocusLast),
 
             // Character input - including shifted characters
             KeyEvent {
                 code: KeyCode::Char(c),
                 modifiers: KeyModifiers::NONE,
                 ..
-            } => Some(Event::Char(c)),
+            } => Some(UiEvent::Char(c)),
 
             // Shift + character combinations (for uppercase letters and symbols)
             KeyEvent {
                 code: KeyCode::Char(c),
                 modifiers: KeyModifiers::SHIFT,
                 ..
-            } => Some(Event::Char(c)),
+            } => Some(UiEvent::Char(c)),
 
             // Ctrl + character combinations
             KeyEvent {
@@ -1375,7 +1686,7 @@ impl EventHandler {
             } => {
                 // Skip 'c' since it's already handled as quit
                 if c != 'c' {
-                    Some(Event::Ctrl(c))
+                    Some(UiEvent::Ctrl(c))
                 } else {
                     None
                 }
@@ -1386,7 +1697,7 @@ impl EventHandler {
                 code: KeyCode::Char(c),
                 modifiers: KeyModifiers::ALT,
                 ..
-            } => Some(Event::Alt(c)),
+            } => Some(UiEvent::Alt(c)),
 
             // Ignore other key combinations
             _ => None,
@@ -1394,12 +1705,15 @@ impl EventHandler {
     }
 
     /// Send a custom event
-    pub fn send_custom_event(&self, event: Event) -> Result<(), mpsc::error::SendError<Event>> {
+    pub fn send_custom_event(
+        &self,
+        event: impl Into<Event>,
+    ) -> Result<(), Box<mpsc::error::SendError<Event>>> {
         self.sender.send(event)
     }
 
     /// Get a clone of the event sender for use in background tasks
-    pub fn get_sender(&self) -> mpsc::UnboundedSender<Event> {
+    pub fn get_sender(&self) -> EventBus {
         self.sender.clone()
     }
 
@@ -1407,7 +1721,7 @@ impl EventHandler {
     pub fn handle_crossterm_event(
         &self,
         crossterm_event: crossterm::event::Event,
-    ) -> Option<Event> {
+    ) -> Option<UiEvent> {
         Self::convert_terminal_event(crossterm_event)
     }
 
@@ -1421,25 +1735,25 @@ impl EventHandler {
         let processor = self.get_blockchain_processor();
 
         match event {
-            Event::ExecuteSwap {
+            Event::Tx(TxEvent::ExecuteSwap {
                 from_asset,
                 to_asset,
                 amount,
                 pool_id,
                 slippage_tolerance,
-            } => {
+            }) => {
                 tokio::spawn(async move {
                     processor
                         .execute_swap(from_asset, to_asset, amount, pool_id, slippage_tolerance)
                         .await;
                 });
             }
-            Event::ProvideLiquidity {
+            Event::Tx(TxEvent::ProvideLiquidity {
                 pool_id,
                 asset_1_amount,
                 asset_2_amount,
                 slippage_tolerance,
-            } => {
+            }) => {
                 tokio::spawn(async move {
                     processor
                         .provide_liquidity(
@@ -1451,40 +1765,40 @@ impl EventHandler {
                         .await;
                 });
             }
-            Event::WithdrawLiquidity {
+            Event::Tx(TxEvent::WithdrawLiquidity {
                 pool_id,
                 lp_token_amount,
                 slippage_tolerance,
-            } => {
+            }) => {
                 tokio::spawn(async move {
                     processor
                         .withdraw_liquidity(pool_id, lp_token_amount, slippage_tolerance)
                         .await;
                 });
             }
-            Event::ClaimRewards {
+            Event::Tx(TxEvent::ClaimRewards {
                 pool_id,
                 epochs,
                 claim_all,
-            } => {
+            }) => {
                 tokio::spawn(async move {
                     processor.claim_rewards(pool_id, epochs, claim_all).await;
                 });
             }
-            Event::CreatePool {
+            Event::Tx(TxEvent::CreatePool {
                 asset_1,
                 asset_2,
                 swap_fee,
                 exit_fee,
                 pool_features,
-            } => {
+            }) => {
                 tokio::spawn(async move {
                     processor
                         .create_pool(asset_1, asset_2, swap_fee, exit_fee, pool_features)
                         .await;
                 });
             }
-            Event::Refresh => {
+            Event::Ui(UiEvent::Refresh) => {
                 tokio::spawn(async move {
                     processor.refresh_data("all".to_string()).await;
                 });
@@ -1498,18 +1812,21 @@ impl EventHandler {
 
     /// Check if an event is a blockchain action that requires async processing
     pub fn is_blockchain_action(event: &Event) -> bool {
+        let Event::Tx(event) = event else {
+            return false;
+        };
         matches!(
             event,
-            Event::ExecuteSwap { .. }
-                | Event::ExecuteSwapAsync { .. }
-                | Event::ProvideLiquidity { .. }
-                | Event::WithdrawLiquidity { .. }
-                | Event::ClaimRewards { .. }
-                | Event::ExecuteMultiHopSwap { .. }
-                | Event::CreatePool { .. }
-                | Event::UpdatePoolFeatures { .. }
-                | Event::SimulateSwap { .. }
-                | Event::SimulateLiquidity { .. }
+            TxEvent::ExecuteSwap { .. }
+                | TxEvent::ExecuteSwapAsync { .. }
+                | TxEvent::ProvideLiquidity { .. }
+                | TxEvent::WithdrawLiquidity { .. }
+                | TxEvent::ClaimRewards { .. }
+                | TxEvent::ExecuteMultiHopSwap { .. }
+                | TxEvent::CreatePool { .. }
+                | TxEvent::UpdatePoolFeatures { .. }
+                | TxEvent::SimulateSwap { .. }
+                | TxEvent::SimulateLiquidity { .. }
         )
     }
 
@@ -1517,10 +1834,11 @@ impl EventHandler {
     pub fn is_blockchain_response(event: &Event) -> bool {
         matches!(
             event,
-            Event::BlockchainSuccess { .. }
-                | Event::BlockchainError { .. }
-                | Event::BlockchainProgress { .. }
-                | Event::DataRefresh { .. }
+            Event::Tx(
+                TxEvent::BlockchainSuccess { .. }
+                    | TxEvent::BlockchainError { .. }
+                    | TxEvent::BlockchainProgress { .. }
+            ) | Event::Data(DataEvent::DataRefresh { .. })
         )
     }
 }
@@ -1546,7 +1864,7 @@ mod tests {
         };
         assert_eq!(
             EventHandler::convert_key_event(quit_q),
-            Some(Event::Char('q'))
+            Some(UiEvent::Char('q'))
         );
 
         let quit_ctrl_c = KeyEvent {
@@ -1557,7 +1875,7 @@ mod tests {
         };
         assert_eq!(
             EventHandler::convert_key_event(quit_ctrl_c),
-            Some(Event::Quit)
+            Some(UiEvent::Quit)
         );
 
         // Test navigation
@@ -1567,7 +1885,7 @@ mod tests {
             kind: event::KeyEventKind::Press,
             state: event::KeyEventState::NONE,
         };
-        assert_eq!(EventHandler::convert_key_event(tab), Some(Event::Tab));
+        assert_eq!(EventHandler::convert_key_event(tab), Some(UiEvent::Tab));
 
         // Test character input
         let char_a = KeyEvent {
@@ -1578,65 +1896,65 @@ mod tests {
         };
         assert_eq!(
             EventHandler::convert_key_event(char_a),
-            Some(Event::Char('a'))
+            Some(UiEvent::Char('a'))
         );
     }
 
     #[test]
     fn test_blockchain_action_detection() {
         // Test DEX action events
-        let swap_event = Event::ExecuteSwap {
+        let swap_event = TxEvent::ExecuteSwap {
             from_asset: "USDC".to_string(),
             to_asset: "OM".to_string(),
             amount: "100".to_string(),
             pool_id: Some("o.uom.usdc.pool".to_string()),
             slippage_tolerance: Some("0.01".to_string()),
         };
-        assert!(EventHandler::is_blockchain_action(&swap_event));
+        assert!(EventHandler::is_blockchain_action(&swap_event.into()));
 
-        let liquidity_event = Event::ProvideLiquidity {
+        let liquidity_event = TxEvent::ProvideLiquidity {
             pool_id: "1".to_string(),
             asset_1_amount: "100".to_string(),
             asset_2_amount: "50".to_string(),
             slippage_tolerance: Some("0.01".to_string()),
         };
-        assert!(EventHandler::is_blockchain_action(&liquidity_event));
+        assert!(EventHandler::is_blockchain_action(&liquidity_event.into()));
 
         // Test non-blockchain events
-        let quit_event = Event::Quit;
-        assert!(!EventHandler::is_blockchain_action(&quit_event));
+        let quit_event = UiEvent::Quit;
+        assert!(!EventHandler::is_blockchain_action(&quit_event.into()));
 
-        let char_event = Event::Char('a');
-        assert!(!EventHandler::is_blockchain_action(&char_event));
+        let char_event = UiEvent::Char('a');
+        assert!(!EventHandler::is_blockchain_action(&char_event.into()));
     }
 
     #[test]
     fn test_blockchain_response_detection() {
         // Test blockchain response events
-        let success_event = Event::BlockchainSuccess {
+        let success_event = TxEvent::BlockchainSuccess {
             operation: "swap".to_string(),
             result: "Success".to_string(),
             transaction_hash: Some("0x123".to_string()),
             enhanced_data: None,
         };
-        assert!(EventHandler::is_blockchain_response(&success_event));
+        assert!(EventHandler::is_blockchain_response(&success_event.into()));
 
-        let error_event = Event::BlockchainError {
+        let error_event = TxEvent::BlockchainError {
             operation: "swap".to_string(),
             error: "Failed".to_string(),
         };
-        assert!(EventHandler::is_blockchain_response(&error_event));
+        assert!(EventHandler::is_blockchain_response(&error_event.into()));
 
-        let progress_event = Event::BlockchainProgress {
+        let progress_event = TxEvent::BlockchainProgress {
             operation: "swap".to_string(),
             status: "In progress".to_string(),
             progress: Some(0.5),
         };
-        assert!(EventHandler::is_blockchain_response(&progress_event));
+        assert!(EventHandler::is_blockchain_response(&progress_event.into()));
 
         // Test non-response events
-        let quit_event = Event::Quit;
-        assert!(!EventHandler::is_blockchain_response(&quit_event));
+        let quit_event = UiEvent::Quit;
+        assert!(!EventHandler::is_blockchain_response(&quit_event.into()));
     }
 
     #[test]
@@ -1657,7 +1975,7 @@ mod tests {
     #[tokio::test]
     async fn test_async_blockchain_processor() {
         let (sender, mut receiver) = mpsc::unbounded_channel();
-        let processor = AsyncBlockchainProcessor::new(sender);
+        let processor = AsyncBlockchainProcessor::new(EventBus::new(sender));
 
         // Test that async operations send events
         tokio::spawn(async move {
@@ -1667,11 +1985,37 @@ mod tests {
         // Should receive a DataRefresh event
         if let Some(event) = receiver.recv().await {
             match event {
-                Event::DataRefresh { data_type, .. } => {
+                Event::Data(DataEvent::DataRefresh { data_type, .. }) => {
                     assert_eq!(data_type, "pools");
                 }
                 _ => panic!("Expected DataRefresh event"),
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_event_bus_routes_by_domain() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let bus = EventBus::new(sender);
+        let mut data_events = bus.subscribe_data();
+        let mut tx_events = bus.subscribe_tx();
+
+        bus.send(UiEvent::Char('a')).unwrap();
+        bus.send(DataEvent::DataRefresh {
+            data_type: "pools".to_string(),
+            success: true,
+            error: None,
+        })
+        .unwrap();
+
+        // The app loop sees every event, subscribers only their own domain
+        assert_eq!(receiver.recv().await, Some(Event::Ui(UiEvent::Char('a'))));
+        assert!(matches!(receiver.recv().await, Some(Event::Data(_))));
+        assert!(matches!(
+            data_events.try_recv(),
+            Ok(DataEvent::DataRefresh { .. })
+        ));
+        assert!(data_events.try_recv().is_err());
+        assert!(tx_events.try_recv().is_err());
+    }
 }
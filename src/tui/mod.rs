@@ -20,7 +20,7 @@ pub mod utils;
 #[cfg(feature = "tui")]
 pub use app::{App, AppState};
 #[cfg(feature = "tui")]
-pub use events::{Event, EventHandler};
+pub use events::{DataEvent, Event, EventBus, EventHandler, TxEvent, UiEvent};
 #[cfg(feature = "tui")]
 pub use ui::render_ui;
 
@@ -32,7 +32,9 @@ use crate::config::MantraNetworkConfig;
 use crate::{Error, MantraDexClient};
 #[cfg(feature = "tui")]
 use crossterm::{
-    cursor, execute,
+    cursor,
+    event::{DisableFocusChange, EnableFocusChange},
+    execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 #[cfg(feature = "tui")]
@@ -57,7 +59,7 @@ static TERMINAL_NEEDS_CLEANUP: AtomicBool = AtomicBool::new(false);
 pub fn init_terminal() -> Result<TuiTerminal, Error> {
     enable_raw_mode().map_err(Error::Io)?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen).map_err(Error::Io)?;
+    execute!(stdout, EnterAlternateScreen, EnableFocusChange).map_err(Error::Io)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).map_err(Error::Io)?;
 
@@ -79,7 +81,12 @@ pub fn restore_terminal(terminal: &mut TuiTerminal) -> Result<(), Error> {
     // Only restore if cleanup is needed
     if TERMINAL_NEEDS_CLEANUP.load(Ordering::SeqCst) {
         disable_raw_mode().map_err(Error::Io)?;
-        execute!(terminal.backend_mut(), LeaveAlternateScreen).map_err(Error::Io)?;
+        execute!(
+            terminal.backend_mut(),
+            DisableFocusChange,
+            LeaveAlternateScreen
+        )
+        .map_err(Error::Io)?;
         terminal.show_cursor().map_err(Error::Io)?;
 
         // Mark cleanup as complete
@@ -97,7 +104,7 @@ fn emergency_terminal_cleanup() {
     if TERMINAL_NEEDS_CLEANUP.load(Ordering::SeqCst) {
         // Ignore errors during emergency cleanup
         let _ = disable_raw_mode();
-        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        let _ = execute!(io::stdout(), DisableFocusChange, LeaveAlternateScreen);
         let _ = execute!(io::stdout(), cursor::Show);
         TERMINAL_NEEDS_CLEANUP.store(false, Ordering::SeqCst);
     }
@@ -153,6 +160,13 @@ pub async fn run_tui(client: MantraDexClient, config: MantraNetworkConfig) -> Re
 
     // Create application state
     let mut app = App::new(client, config);
+
+    // Restore the preferences shared with the CLI, moving over those saved in the old config
+    match crate::preferences::PreferencesStore::open_default() {
+        Ok(store) => app.set_preferences(store),
+        Err(e) => eprintln!("Warning: Failed to load preferences: {}", e),
+    }
+
     let mut event_handler = EventHandler::new();
 
     // Initialize background tasks with event communication
@@ -219,10 +233,11 @@ async fn run_app_loop(
             })
             .map_err(Error::Io)?;
 
-        // Handle events with timeout to allow for periodic updates
-        match tokio::time::timeout(std::time::Duration::from_millis(100), event_handler.next())
-            .await
-        {
+        // Handle events with timeout to allow for periodic updates, less often
+        // while idle
+        let tick_rate = app.tick_rate();
+        event_handler.set_idle(app.is_idle());
+        match tokio::time::timeout(tick_rate, event_handler.next()).await {
             Ok(Ok(event)) => match app.handle_event(event).await {
                 Ok(_event_was_handled) => {
                     // Event was processed successfully
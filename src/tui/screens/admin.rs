@@ -3,16 +3,21 @@
 //! This module provides the admin view for the MANTRA DEX SDK TUI,
 //! allowing pool creation, feature management, and administrative operations.
 
+use super::Screen;
+use crate::pool_template::PoolTemplate;
 use crate::tui::{
-    app::{App, LoadingState},
+    app::{App, LoadingState, NavigationMode},
     components::{
-        forms::{InputType, TextInput},
+        forms::{FieldMut, Form, FormEvent, FormFields, InputType, TextInput},
         header::render_header,
         navigation::render_navigation,
-        simple_list::{ListEvent, SimpleList, SimpleListOption},
+        simple_list::{SimpleList, SimpleListOption},
         status_bar::render_status_bar,
     },
+    events::{FocusDirection, UiEvent},
 };
+use cosmwasm_std::Decimal;
+use mantra_dex_std::pool_manager::PoolType;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -39,6 +44,7 @@ pub enum AdminInputFocus {
     ManagementExecute,
 
     // Pool Creation
+    Template,
     FirstAssetDenom,
     SecondAssetDenom,
     SwapFee,
@@ -53,16 +59,10 @@ pub enum AdminInputFocus {
     ControlsExecute,
 }
 
-/// Validation result containing both boolean status and error messages
-#[derive(Debug, Clone)]
-struct ValidationResult {
-    is_valid: bool,
-    errors: Vec<String>,
-}
-
 /// Pool creation form state (simplified like swap/liquidity screens)
 #[derive(Debug, Clone)]
 pub struct PoolCreationState {
+    pub template_dropdown: SimpleList,
     pub first_asset_input: TextInput,
     pub second_asset_input: TextInput,
     pub swap_fee_input: TextInput,
@@ -74,6 +74,23 @@ pub struct PoolCreationState {
 
 impl Default for PoolCreationState {
     fn default() -> Self {
+        let template_options = PoolTemplate::ALL
+            .iter()
+            .map(|template| {
+                SimpleListOption::new(
+                    format!(
+                        "{} ({}% fee)",
+                        template,
+                        fee_percent(template.spec().total_fee())
+                    ),
+                    template.name(),
+                )
+            })
+            .collect();
+        // No template selected until the user picks one, the fields keep their defaults
+        let template_dropdown =
+            SimpleList::new("Template (optional)").with_options(template_options);
+
         let first_asset_input = TextInput::new("First Asset Denomination")
             .required()
             .with_placeholder("e.g., uom");
@@ -109,6 +126,7 @@ impl Default for PoolCreationState {
         pool_type_dropdown.selected_index = Some(0);
 
         Self {
+            template_dropdown,
             first_asset_input,
             second_asset_input,
             swap_fee_input,
@@ -120,6 +138,50 @@ impl Default for PoolCreationState {
     }
 }
 
+impl PoolCreationState {
+    /// Fill in the pool type and fees of `template`, which stay editable
+    pub fn apply_template(&mut self, template: PoolTemplate) {
+        let spec = template.spec();
+        self.swap_fee_input.set_value(&fee_percent(spec.swap_fee));
+        self.protocol_fee_input
+            .set_value(&fee_percent(spec.protocol_fee));
+        self.burn_fee_input.set_value(&fee_percent(spec.burn_fee));
+        let pool_type = match spec.pool_type {
+            PoolType::ConstantProduct => "ConstantProduct",
+            PoolType::StableSwap { .. } => "StableSwap",
+        };
+        self.pool_type_dropdown.select_value(pool_type);
+    }
+
+    /// Name of the selected template, marked when fields were changed since
+    pub fn template_label(&self) -> String {
+        let Some(template) = self
+            .template_dropdown
+            .get_selected_value()
+            .and_then(|name| name.parse::<PoolTemplate>().ok())
+        else {
+            return "Custom".to_string();
+        };
+        let mut applied = self.clone();
+        applied.apply_template(template);
+        let modified = applied.swap_fee_input.value() != self.swap_fee_input.value()
+            || applied.protocol_fee_input.value() != self.protocol_fee_input.value()
+            || applied.burn_fee_input.value() != self.burn_fee_input.value()
+            || applied.pool_type_dropdown.get_selected_value()
+                != self.pool_type_dropdown.get_selected_value();
+        if modified {
+            format!("{} (modified)", template)
+        } else {
+            template.to_string()
+        }
+    }
+}
+
+/// A fee share as the percentage shown in the fee fields
+fn fee_percent(share: Decimal) -> String {
+    (share * Decimal::percent(10_000)).to_string()
+}
+
 /// Pool feature control state (simplified like swap/liquidity screens)
 #[derive(Debug, Clone)]
 pub struct PoolFeatureState {
@@ -131,7 +193,8 @@ pub struct PoolFeatureState {
 
 impl Default for PoolFeatureState {
     fn default() -> Self {
-        let pool_selection_dropdown = SimpleList::new("Select Pool to Manage");
+        let mut pool_selection_dropdown = SimpleList::new("Select Pool to Manage");
+        pool_selection_dropdown.set_loading();
 
         Self {
             pool_selection_dropdown,
@@ -151,7 +214,8 @@ pub struct PoolManagementState {
 
 impl Default for PoolManagementState {
     fn default() -> Self {
-        let pool_selection_dropdown = SimpleList::new("Select Pool");
+        let mut pool_selection_dropdown = SimpleList::new("Select Pool");
+        pool_selection_dropdown.set_loading();
 
         Self {
             pool_selection_dropdown,
@@ -167,6 +231,8 @@ pub struct AdminScreenState {
     pub mode: AdminMode,
     /// Current input focus
     pub input_focus: AdminInputFocus,
+    /// Fields of the current mode's form
+    pub form: Form<AdminInputFocus>,
     /// Pool management state
     pub pool_management: PoolManagementState,
     /// Pool creation state
@@ -184,6 +250,7 @@ impl Default for AdminScreenState {
         let mut instance = Self {
             mode: AdminMode::PoolManagement,
             input_focus: AdminInputFocus::PoolSelection,
+            form: AdminScreenState::form_for(AdminMode::PoolManagement),
             pool_management: PoolManagementState::default(),
             pool_creation: PoolCreationState::default(),
             feature_control: PoolFeatureState::default(),
@@ -207,65 +274,67 @@ impl AdminScreenState {
 
         self.available_pools = pools.clone();
 
-        // Update all pool dropdowns while preserving focus state
-        {
-            let was_active = self.pool_management.pool_selection_dropdown.is_active;
-            let was_editing = self.pool_management.pool_selection_dropdown.is_editing;
-
-            let options: Vec<SimpleListOption> = pools
-                .iter()
-                .map(|(pool_id, display_name)| {
-                    SimpleListOption::new(display_name.clone(), pool_id.clone())
-                })
-                .collect();
-
-            self.pool_management.pool_selection_dropdown.options = options;
-            self.pool_management.pool_selection_dropdown.is_active = was_active;
-            self.pool_management.pool_selection_dropdown.is_editing = was_editing;
-
-            // Reset selection
-            self.pool_management.pool_selection_dropdown.selected_index = None;
-            if !pools.is_empty() {
-                self.pool_management
-                    .pool_selection_dropdown
-                    .list_state
-                    .select(Some(0));
-            } else {
-                self.pool_management
-                    .pool_selection_dropdown
-                    .list_state
-                    .select(None);
-            }
-        }
+        // Update both pool dropdowns while preserving focus and selection
+        let options: Vec<SimpleListOption> = pools
+            .iter()
+            .map(|(pool_id, display_name)| {
+                SimpleListOption::new(display_name.clone(), pool_id.clone())
+            })
+            .collect();
+        self.pool_management
+            .pool_selection_dropdown
+            .set_options(options.clone());
+        self.feature_control
+            .pool_selection_dropdown
+            .set_options(options);
+    }
 
-        {
-            let was_active = self.feature_control.pool_selection_dropdown.is_active;
-            let was_editing = self.feature_control.pool_selection_dropdown.is_editing;
-
-            let options: Vec<SimpleListOption> = pools
-                .iter()
-                .map(|(pool_id, display_name)| {
-                    SimpleListOption::new(display_name.clone(), pool_id.clone())
-                })
-                .collect();
-
-            self.feature_control.pool_selection_dropdown.options = options;
-            self.feature_control.pool_selection_dropdown.is_active = was_active;
-            self.feature_control.pool_selection_dropdown.is_editing = was_editing;
-
-            // Reset selection
-            self.feature_control.pool_selection_dropdown.selected_index = None;
-            if !pools.is_empty() {
-                self.feature_control
-                    .pool_selection_dropdown
-                    .list_state
-                    .select(Some(0));
-            } else {
-                self.feature_control
-                    .pool_selection_dropdown
-                    .list_state
-                    .select(None);
-            }
+    /// Fields shown in each admin mode, in focus order
+    fn form_for(mode: AdminMode) -> Form<AdminInputFocus> {
+        match mode {
+            AdminMode::PoolManagement => Form::new()
+                .dropdown(
+                    AdminInputFocus::PoolSelection,
+                    "Please select a pool to manage",
+                )
+                .custom(AdminInputFocus::FeatureToggles)
+                .submit(AdminInputFocus::ManagementExecute),
+            AdminMode::PoolCreation => Form::new()
+                .optional_dropdown(AdminInputFocus::Template)
+                .text(
+                    AdminInputFocus::FirstAssetDenom,
+                    "Please enter a valid first asset denomination",
+                    "Please enter a valid first asset denomination",
+                )
+                .text(
+                    AdminInputFocus::SecondAssetDenom,
+                    "Please enter a valid second asset denomination",
+                    "Please enter a valid second asset denomination",
+                )
+                .amount(
+                    AdminInputFocus::SwapFee,
+                    "Please enter a valid swap fee (0-20%)",
+                    "Please enter a valid swap fee (0-20%)",
+                )
+                .amount(
+                    AdminInputFocus::ProtocolFee,
+                    "Please enter a valid protocol fee (0-20%)",
+                    "Please enter a valid protocol fee (0-20%)",
+                )
+                .amount(
+                    AdminInputFocus::BurnFee,
+                    "Please enter a valid burn fee (0-20%)",
+                    "Please enter a valid burn fee (0-20%)",
+                )
+                .dropdown(AdminInputFocus::PoolType, "Please select a pool type")
+                .submit(AdminInputFocus::CreationExecute),
+            AdminMode::FeatureControls => Form::new()
+                .dropdown(
+                    AdminInputFocus::TargetPoolId,
+                    "Please select a pool to control",
+                )
+                .custom(AdminInputFocus::FeatureControls)
+                .submit(AdminInputFocus::ControlsExecute),
         }
     }
 
@@ -273,14 +342,13 @@ impl AdminScreenState {
     pub fn set_mode(&mut self, mode: AdminMode) {
         if self.mode != mode {
             self.mode = mode;
+            self.form = Self::form_for(mode);
             self.clear_focus();
 
-            // Reset focus to appropriate first input for the new mode
-            self.input_focus = match mode {
-                AdminMode::PoolManagement => AdminInputFocus::PoolSelection,
-                AdminMode::PoolCreation => AdminInputFocus::FirstAssetDenom,
-                AdminMode::FeatureControls => AdminInputFocus::TargetPoolId,
-            };
+            // Reset focus to the first input for the new mode
+            if let Some(first) = self.form.first() {
+                self.input_focus = first;
+            }
 
             self.apply_focus();
             crate::tui::utils::logger::log_info(&format!("Admin mode switched to {:?}", mode));
@@ -289,62 +357,12 @@ impl AdminScreenState {
 
     /// Move focus to next input
     pub fn next_focus(&mut self) {
-        self.input_focus = match self.mode {
-            AdminMode::PoolManagement => match self.input_focus {
-                AdminInputFocus::PoolSelection => AdminInputFocus::FeatureToggles,
-                AdminInputFocus::FeatureToggles => AdminInputFocus::ManagementExecute,
-                AdminInputFocus::ManagementExecute => AdminInputFocus::PoolSelection,
-                _ => AdminInputFocus::PoolSelection,
-            },
-            AdminMode::PoolCreation => match self.input_focus {
-                AdminInputFocus::FirstAssetDenom => AdminInputFocus::SecondAssetDenom,
-                AdminInputFocus::SecondAssetDenom => AdminInputFocus::SwapFee,
-                AdminInputFocus::SwapFee => AdminInputFocus::ProtocolFee,
-                AdminInputFocus::ProtocolFee => AdminInputFocus::BurnFee,
-                AdminInputFocus::BurnFee => AdminInputFocus::PoolType,
-                AdminInputFocus::PoolType => AdminInputFocus::CreationExecute,
-                AdminInputFocus::CreationExecute => AdminInputFocus::FirstAssetDenom,
-                _ => AdminInputFocus::FirstAssetDenom,
-            },
-            AdminMode::FeatureControls => match self.input_focus {
-                AdminInputFocus::TargetPoolId => AdminInputFocus::FeatureControls,
-                AdminInputFocus::FeatureControls => AdminInputFocus::ControlsExecute,
-                AdminInputFocus::ControlsExecute => AdminInputFocus::TargetPoolId,
-                _ => AdminInputFocus::TargetPoolId,
-            },
-        };
-        self.clear_focus();
-        self.set_focus();
+        self.focus_next_field();
     }
 
     /// Move focus to previous input
     pub fn previous_focus(&mut self) {
-        self.input_focus = match self.mode {
-            AdminMode::PoolManagement => match self.input_focus {
-                AdminInputFocus::PoolSelection => AdminInputFocus::ManagementExecute,
-                AdminInputFocus::FeatureToggles => AdminInputFocus::PoolSelection,
-                AdminInputFocus::ManagementExecute => AdminInputFocus::FeatureToggles,
-                _ => AdminInputFocus::ManagementExecute,
-            },
-            AdminMode::PoolCreation => match self.input_focus {
-                AdminInputFocus::FirstAssetDenom => AdminInputFocus::CreationExecute,
-                AdminInputFocus::SecondAssetDenom => AdminInputFocus::FirstAssetDenom,
-                AdminInputFocus::SwapFee => AdminInputFocus::SecondAssetDenom,
-                AdminInputFocus::ProtocolFee => AdminInputFocus::SwapFee,
-                AdminInputFocus::BurnFee => AdminInputFocus::ProtocolFee,
-                AdminInputFocus::PoolType => AdminInputFocus::BurnFee,
-                AdminInputFocus::CreationExecute => AdminInputFocus::PoolType,
-                _ => AdminInputFocus::CreationExecute,
-            },
-            AdminMode::FeatureControls => match self.input_focus {
-                AdminInputFocus::TargetPoolId => AdminInputFocus::ControlsExecute,
-                AdminInputFocus::FeatureControls => AdminInputFocus::TargetPoolId,
-                AdminInputFocus::ControlsExecute => AdminInputFocus::FeatureControls,
-                _ => AdminInputFocus::ControlsExecute,
-            },
-        };
-        self.clear_focus();
-        self.set_focus();
+        self.focus_previous_field();
     }
 
     /// Clear focus from all inputs
@@ -355,6 +373,7 @@ impl AdminScreenState {
             .set_active(false);
 
         // Pool creation
+        self.pool_creation.template_dropdown.set_active(false);
         self.pool_creation.first_asset_input.set_focused(false);
         self.pool_creation.second_asset_input.set_focused(false);
         self.pool_creation.swap_fee_input.set_focused(false);
@@ -373,68 +392,10 @@ impl AdminScreenState {
         self.clear_focus();
     }
 
-    /// Set focus on current input
-    fn set_focus(&mut self) {
-        match self.input_focus {
-            // Pool Management
-            AdminInputFocus::PoolSelection => {
-                self.pool_management
-                    .pool_selection_dropdown
-                    .set_active(true);
-            }
-            AdminInputFocus::FeatureToggles => {} // Special handling for feature toggles
-            AdminInputFocus::ManagementExecute => {} // Button focus handled separately
-
-            // Pool Creation
-            AdminInputFocus::FirstAssetDenom => {
-                self.pool_creation.first_asset_input.set_focused(true);
-            }
-            AdminInputFocus::SecondAssetDenom => {
-                self.pool_creation.second_asset_input.set_focused(true);
-            }
-            AdminInputFocus::SwapFee => {
-                self.pool_creation.swap_fee_input.set_focused(true);
-            }
-            AdminInputFocus::ProtocolFee => {
-                self.pool_creation.protocol_fee_input.set_focused(true);
-            }
-            AdminInputFocus::BurnFee => {
-                self.pool_creation.burn_fee_input.set_focused(true);
-            }
-            AdminInputFocus::PoolType => {
-                self.pool_creation.pool_type_dropdown.set_active(true);
-                // Ensure list state is properly initialized for navigation
-                if self
-                    .pool_creation
-                    .pool_type_dropdown
-                    .list_state
-                    .selected()
-                    .is_none()
-                    && !self.pool_creation.pool_type_dropdown.options.is_empty()
-                {
-                    self.pool_creation
-                        .pool_type_dropdown
-                        .list_state
-                        .select(Some(0));
-                }
-            }
-            AdminInputFocus::CreationExecute => {} // Button focus handled separately
-
-            // Feature Controls
-            AdminInputFocus::TargetPoolId => {
-                self.feature_control
-                    .pool_selection_dropdown
-                    .set_active(true);
-            }
-            AdminInputFocus::FeatureControls => {} // Special handling for feature controls
-            AdminInputFocus::ControlsExecute => {} // Button focus handled separately
-        }
-    }
-
     /// Public wrapper to apply focus
     pub fn apply_focus(&mut self) {
         self.clear_focus();
-        self.set_focus();
+        self.apply_form_focus();
     }
 
     /// Mark input change
@@ -445,98 +406,19 @@ impl AdminScreenState {
     /// Check if any list is currently in editing mode
     pub fn is_any_list_editing(&self) -> bool {
         self.pool_management.pool_selection_dropdown.is_editing
+            || self.pool_creation.template_dropdown.is_editing
             || self.pool_creation.pool_type_dropdown.is_editing
             || self.feature_control.pool_selection_dropdown.is_editing
     }
 
-    /// Perform validation checks and return detailed results
-    fn validate_internal(&mut self) -> ValidationResult {
-        match self.mode {
-            AdminMode::PoolManagement => {
-                let pool_selected = self
-                    .pool_management
-                    .pool_selection_dropdown
-                    .get_selected_value()
-                    .is_some();
-
-                ValidationResult {
-                    is_valid: pool_selected,
-                    errors: if pool_selected {
-                        Vec::new()
-                    } else {
-                        vec!["Please select a pool to manage".to_string()]
-                    },
-                }
-            }
-            AdminMode::PoolCreation => {
-                let first_valid = self.pool_creation.first_asset_input.validate();
-                let second_valid = self.pool_creation.second_asset_input.validate();
-                let swap_fee_valid = self.pool_creation.swap_fee_input.validate();
-                let protocol_fee_valid = self.pool_creation.protocol_fee_input.validate();
-                let burn_fee_valid = self.pool_creation.burn_fee_input.validate();
-                let pool_type_valid = self
-                    .pool_creation
-                    .pool_type_dropdown
-                    .get_selected_value()
-                    .is_some();
-
-                let mut errors = Vec::new();
-                if !first_valid {
-                    errors.push("Please enter a valid first asset denomination".to_string());
-                }
-                if !second_valid {
-                    errors.push("Please enter a valid second asset denomination".to_string());
-                }
-                if !swap_fee_valid {
-                    errors.push("Please enter a valid swap fee (0-20%)".to_string());
-                }
-                if !protocol_fee_valid {
-                    errors.push("Please enter a valid protocol fee (0-20%)".to_string());
-                }
-                if !burn_fee_valid {
-                    errors.push("Please enter a valid burn fee (0-20%)".to_string());
-                }
-                if !pool_type_valid {
-                    errors.push("Please select a pool type".to_string());
-                }
-
-                ValidationResult {
-                    is_valid: first_valid
-                        && second_valid
-                        && swap_fee_valid
-                        && protocol_fee_valid
-                        && burn_fee_valid
-                        && pool_type_valid,
-                    errors,
-                }
-            }
-            AdminMode::FeatureControls => {
-                let pool_selected = self
-                    .feature_control
-                    .pool_selection_dropdown
-                    .get_selected_value()
-                    .is_some();
-
-                ValidationResult {
-                    is_valid: pool_selected,
-                    errors: if pool_selected {
-                        Vec::new()
-                    } else {
-                        vec!["Please select a pool to control".to_string()]
-                    },
-                }
-            }
-        }
-    }
-
     /// Validate current form inputs
     pub fn validate(&mut self) -> bool {
-        self.validate_internal().is_valid
+        self.validate_form()
     }
 
     /// Get detailed validation errors
     pub fn get_validation_errors(&mut self) -> Vec<String> {
-        self.validate_internal().errors
+        self.form_errors()
     }
 
     /// Handle keyboard input using direct key events (like swap/liquidity screens)
@@ -548,6 +430,7 @@ impl AdminScreenState {
         use crossterm::event::KeyCode;
 
         // Handle admin internal tab switching (1-3) only when NOT in a text input field
+        // or typing a dropdown filter
         // This prevents tab switching when typing/pasting text that contains numbers
         let is_text_input_focused = matches!(
             self.input_focus,
@@ -558,7 +441,7 @@ impl AdminScreenState {
                 | AdminInputFocus::BurnFee
         );
 
-        if !is_text_input_focused {
+        if !is_text_input_focused && !self.is_any_list_editing() {
             match key.code {
                 KeyCode::Char('1') => {
                     self.set_mode(AdminMode::PoolManagement);
@@ -586,19 +469,6 @@ impl AdminScreenState {
             return true; // Let the main app handle switching navigation modes
         }
 
-        // Handle Tab navigation between fields
-        if matches!(key.code, KeyCode::Tab) {
-            if key
-                .modifiers
-                .contains(crossterm::event::KeyModifiers::SHIFT)
-            {
-                self.previous_focus();
-            } else {
-                self.next_focus();
-            }
-            return true;
-        }
-
         // Log significant key events for admin operations
         if matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
             && matches!(
@@ -613,18 +483,18 @@ impl AdminScreenState {
             crate::tui::utils::logger::log_debug(&format!("Current focus: {:?}", self.input_focus));
         }
 
-        // Handle regular input focus
-        match self.input_focus {
-            // Pool Management
-            AdminInputFocus::PoolSelection => {
-                let list_event = self
-                    .pool_management
-                    .pool_selection_dropdown
-                    .handle_key_event(key);
-
-                if list_event == ListEvent::SelectionMade {
-                    self.mark_input_change();
-                    // Load pool features when a pool is selected
+        let focused = self.input_focus;
+        match self.handle_form_key(key) {
+            FormEvent::Ignored => self.handle_feature_key(focused, key),
+            FormEvent::Handled | FormEvent::Cancelled => true,
+            FormEvent::Changed => {
+                self.mark_input_change();
+                true
+            }
+            FormEvent::Selected => {
+                self.mark_input_change();
+                // Load pool features when a pool is selected for management
+                if focused == AdminInputFocus::PoolSelection {
                     if let Some(pool_id) = self
                         .pool_management
                         .pool_selection_dropdown
@@ -638,251 +508,65 @@ impl AdminScreenState {
                         self.pool_management.selected_pool_features = Some((true, true, true));
                     }
                 }
-
-                if list_event == ListEvent::SelectionMade
-                    || list_event == ListEvent::SelectionCancelled
-                {
-                    self.next_focus();
-                }
-
-                list_event != ListEvent::Ignored
-            }
-
-            AdminInputFocus::FeatureToggles => {
-                // Handle feature toggle keys (W, D, S)
-                match key.code {
-                    KeyCode::Char('w') | KeyCode::Char('W') => {
-                        // Toggle withdrawals
-                        crate::tui::utils::logger::log_info("Toggling withdrawal feature");
-                        self.mark_input_change();
-                        true
-                    }
-                    KeyCode::Char('d') | KeyCode::Char('D') => {
-                        // Toggle deposits
-                        crate::tui::utils::logger::log_info("Toggling deposit feature");
-                        self.mark_input_change();
-                        true
-                    }
-                    KeyCode::Char('s') | KeyCode::Char('S') => {
-                        // Toggle swaps
-                        crate::tui::utils::logger::log_info("Toggling swap feature");
-                        self.mark_input_change();
-                        true
-                    }
-                    _ => false,
-                }
-            }
-
-            AdminInputFocus::ManagementExecute => {
-                match key.code {
-                    KeyCode::Enter | KeyCode::Char(' ') => {
-                        if self.validate() {
-                            self.mark_input_change();
-                            crate::tui::utils::logger::log_info(
-                                "Pool management execute button pressed",
-                            );
-                            return true;
-                        }
-                    }
-                    _ => {}
-                }
-                false
-            }
-
-            // Pool Creation
-            AdminInputFocus::FirstAssetDenom => {
-                let input_request = self.map_key_to_input_request(key);
-                if let Some(request) = input_request {
-                    if self
-                        .pool_creation
-                        .first_asset_input
-                        .handle_input(request)
-                        .is_some()
-                    {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
-                false
-            }
-
-            AdminInputFocus::SecondAssetDenom => {
-                let input_request = self.map_key_to_input_request(key);
-                if let Some(request) = input_request {
-                    if self
+                // Pre-fill the pool type and fees of the selected template
+                if focused == AdminInputFocus::Template {
+                    if let Some(template) = self
                         .pool_creation
-                        .second_asset_input
-                        .handle_input(request)
-                        .is_some()
+                        .template_dropdown
+                        .get_selected_value()
+                        .and_then(|name| name.parse::<PoolTemplate>().ok())
                     {
-                        self.mark_input_change();
-                        return true;
+                        self.pool_creation.apply_template(template);
                     }
                 }
-                false
+                true
             }
-
-            AdminInputFocus::SwapFee => {
-                let input_request = self.map_key_to_input_request(key);
-                if let Some(request) = input_request {
-                    if self
-                        .pool_creation
-                        .swap_fee_input
-                        .handle_input(request)
-                        .is_some()
-                    {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
-                false
-            }
-
-            AdminInputFocus::ProtocolFee => {
-                let input_request = self.map_key_to_input_request(key);
-                if let Some(request) = input_request {
-                    if self
-                        .pool_creation
-                        .protocol_fee_input
-                        .handle_input(request)
-                        .is_some()
-                    {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
-                false
+            FormEvent::Submit => {
+                self.mark_input_change();
+                crate::tui::utils::logger::log_info(&format!(
+                    "Admin {:?} execute button pressed - validation passed",
+                    self.mode
+                ));
+                true // Event will be handled by app to show confirmation
             }
-
-            AdminInputFocus::BurnFee => {
-                let input_request = self.map_key_to_input_request(key);
-                if let Some(request) = input_request {
-                    if self
-                        .pool_creation
-                        .burn_fee_input
-                        .handle_input(request)
-                        .is_some()
-                    {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
+            FormEvent::Invalid => {
+                crate::tui::utils::logger::log_warning(&format!(
+                    "Admin {:?} validation failed - please check all fields",
+                    self.mode
+                ));
                 false
             }
+        }
+    }
 
-            AdminInputFocus::PoolType => {
-                let list_event = self.pool_creation.pool_type_dropdown.handle_key_event(key);
-
-                if list_event == ListEvent::SelectionMade {
-                    self.mark_input_change();
-                    // When a selection is made, sync the selected_index with the highlighted item
-                    if let Some(highlighted_idx) =
-                        self.pool_creation.pool_type_dropdown.list_state.selected()
-                    {
-                        self.pool_creation.pool_type_dropdown.selected_index =
-                            Some(highlighted_idx);
-                    }
-                }
-
-                if list_event == ListEvent::SelectionMade
-                    || list_event == ListEvent::SelectionCancelled
-                {
-                    self.next_focus();
-                }
+    /// Handle keys for the feature toggle and bulk control fields
+    fn handle_feature_key(
+        &mut self,
+        focused: AdminInputFocus,
+        key: crossterm::event::KeyEvent,
+    ) -> bool {
+        use crossterm::event::KeyCode;
 
-                list_event != ListEvent::Ignored
+        let action = match (focused, key.code) {
+            (AdminInputFocus::FeatureToggles, KeyCode::Char('w' | 'W')) => {
+                "Toggling withdrawal feature"
             }
-
-            AdminInputFocus::CreationExecute => {
-                match key.code {
-                    KeyCode::Enter | KeyCode::Char(' ') => {
-                        if self.validate() {
-                            self.mark_input_change();
-                            crate::tui::utils::logger::log_info(
-                                "Pool creation execute button pressed - validation passed",
-                            );
-                            // Trigger pool creation confirmation
-                            return true; // Event will be handled by app to show confirmation
-                        } else {
-                            crate::tui::utils::logger::log_warning(
-                                "Pool creation validation failed - please check all fields",
-                            );
-                        }
-                    }
-                    _ => {}
-                }
-                false
+            (AdminInputFocus::FeatureToggles, KeyCode::Char('d' | 'D')) => {
+                "Toggling deposit feature"
             }
-
-            // Feature Controls
-            AdminInputFocus::TargetPoolId => {
-                let list_event = self
-                    .feature_control
-                    .pool_selection_dropdown
-                    .handle_key_event(key);
-
-                if list_event == ListEvent::SelectionMade {
-                    self.mark_input_change();
-                }
-
-                if list_event == ListEvent::SelectionMade
-                    || list_event == ListEvent::SelectionCancelled
-                {
-                    self.next_focus();
-                }
-
-                list_event != ListEvent::Ignored
+            (AdminInputFocus::FeatureToggles, KeyCode::Char('s' | 'S')) => "Toggling swap feature",
+            (AdminInputFocus::FeatureControls, KeyCode::Char('1')) => {
+                "Enable all features requested"
             }
-
-            AdminInputFocus::FeatureControls => {
-                // Handle bulk feature control keys
-                match key.code {
-                    KeyCode::Char('1') => {
-                        crate::tui::utils::logger::log_info("Enable all features requested");
-                        self.mark_input_change();
-                        true
-                    }
-                    KeyCode::Char('2') => {
-                        crate::tui::utils::logger::log_info("Disable all features requested");
-                        self.mark_input_change();
-                        true
-                    }
-                    _ => false,
-                }
-            }
-
-            AdminInputFocus::ControlsExecute => {
-                match key.code {
-                    KeyCode::Enter | KeyCode::Char(' ') => {
-                        if self.validate() {
-                            self.mark_input_change();
-                            crate::tui::utils::logger::log_info(
-                                "Feature controls execute button pressed",
-                            );
-                            return true;
-                        }
-                    }
-                    _ => {}
-                }
-                false
+            (AdminInputFocus::FeatureControls, KeyCode::Char('2')) => {
+                "Disable all features requested"
             }
-        }
-    }
-
-    /// Map key event to input request (helper method)
-    fn map_key_to_input_request(&self, key: crossterm::event::KeyEvent) -> Option<InputRequest> {
-        use crossterm::event::KeyCode;
+            _ => return false,
+        };
 
-        match key.code {
-            KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-            KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-            KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-            KeyCode::Left => Some(InputRequest::GoToPrevChar),
-            KeyCode::Right => Some(InputRequest::GoToNextChar),
-            KeyCode::Home => Some(InputRequest::GoToStart),
-            KeyCode::End => Some(InputRequest::GoToEnd),
-            _ => None,
-        }
+        crate::tui::utils::logger::log_info(action);
+        self.mark_input_change();
+        true
     }
 
     /// Handle keyboard input (legacy method - kept for compatibility)
@@ -912,21 +596,137 @@ pub struct FeatureManagementDetails {
     pub swaps_enabled: bool,
 }
 
-// Global admin screen state - like swap and liquidity screens
-static mut ADMIN_SCREEN_STATE: Option<AdminScreenState> = None;
+impl FormFields for AdminScreenState {
+    type Key = AdminInputFocus;
+
+    fn form(&self) -> &Form<AdminInputFocus> {
+        &self.form
+    }
+
+    fn form_mut(&mut self) -> &mut Form<AdminInputFocus> {
+        &mut self.form
+    }
+
+    fn focused_field(&self) -> AdminInputFocus {
+        self.input_focus
+    }
+
+    fn set_focused_field(&mut self, key: AdminInputFocus) {
+        self.input_focus = key;
+    }
+
+    fn field_mut(&mut self, key: AdminInputFocus) -> FieldMut<'_> {
+        match key {
+            AdminInputFocus::PoolSelection => {
+                FieldMut::Dropdown(&mut self.pool_management.pool_selection_dropdown)
+            }
+            AdminInputFocus::Template => {
+                FieldMut::Dropdown(&mut self.pool_creation.template_dropdown)
+            }
+            AdminInputFocus::FirstAssetDenom => {
+                FieldMut::Text(&mut self.pool_creation.first_asset_input)
+            }
+            AdminInputFocus::SecondAssetDenom => {
+                FieldMut::Text(&mut self.pool_creation.second_asset_input)
+            }
+            AdminInputFocus::SwapFee => FieldMut::Text(&mut self.pool_creation.swap_fee_input),
+            AdminInputFocus::ProtocolFee => {
+                FieldMut::Text(&mut self.pool_creation.protocol_fee_input)
+            }
+            AdminInputFocus::BurnFee => FieldMut::Text(&mut self.pool_creation.burn_fee_input),
+            AdminInputFocus::PoolType => {
+                FieldMut::Dropdown(&mut self.pool_creation.pool_type_dropdown)
+            }
+            AdminInputFocus::TargetPoolId => {
+                FieldMut::Dropdown(&mut self.feature_control.pool_selection_dropdown)
+            }
+            AdminInputFocus::FeatureToggles
+            | AdminInputFocus::FeatureControls
+            | AdminInputFocus::ManagementExecute
+            | AdminInputFocus::CreationExecute
+            | AdminInputFocus::ControlsExecute => FieldMut::None,
+        }
+    }
+}
+
+impl Screen for AdminScreenState {
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-/// Get or initialize the admin screen state
-pub(crate) fn get_admin_screen_state() -> &'static mut AdminScreenState {
-    unsafe {
-        if ADMIN_SCREEN_STATE.is_none() {
-            ADMIN_SCREEN_STATE = Some(AdminScreenState::default());
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        match event {
+            UiEvent::MoveFocus(direction @ (FocusDirection::Up | FocusDirection::Down)) => {
+                // An open dropdown takes up/down; otherwise they move between fields
+                let dropdown_open = match self.input_focus {
+                    AdminInputFocus::PoolSelection => {
+                        self.pool_management.pool_selection_dropdown.is_active
+                    }
+                    AdminInputFocus::Template => self.pool_creation.template_dropdown.is_active,
+                    AdminInputFocus::PoolType => self.pool_creation.pool_type_dropdown.is_active,
+                    _ => false,
+                };
+                let up = *direction == FocusDirection::Up;
+                if dropdown_open {
+                    let code = if up { KeyCode::Up } else { KeyCode::Down };
+                    self.handle_key_event(key(code), navigation_mode)
+                } else {
+                    if up {
+                        self.previous_focus();
+                    } else {
+                        self.next_focus();
+                    }
+                    true
+                }
+            }
+            UiEvent::MoveFocus(FocusDirection::Next) => {
+                self.next_focus();
+                true
+            }
+            UiEvent::MoveFocus(FocusDirection::Previous) => {
+                self.previous_focus();
+                true
+            }
+            UiEvent::MoveFocus(FocusDirection::Left) => {
+                self.handle_key_event(key(KeyCode::Left), navigation_mode)
+            }
+            UiEvent::MoveFocus(FocusDirection::Right) => {
+                self.handle_key_event(key(KeyCode::Right), navigation_mode)
+            }
+            // Character input includes the admin tab switching keys (1-3)
+            UiEvent::Char(c) => self.handle_key_event(key(KeyCode::Char(*c)), navigation_mode),
+            UiEvent::Enter => self.handle_key_event(key(KeyCode::Enter), navigation_mode),
+            UiEvent::Tab => self.handle_key_event(key(KeyCode::Tab), navigation_mode),
+            UiEvent::BackTab => self.handle_key_event(
+                KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+                navigation_mode,
+            ),
+            UiEvent::Backspace => self.handle_key_event(key(KeyCode::Backspace), navigation_mode),
+            UiEvent::Delete => self.handle_key_event(key(KeyCode::Delete), navigation_mode),
+            UiEvent::Home => self.handle_key_event(key(KeyCode::Home), navigation_mode),
+            UiEvent::End => self.handle_key_event(key(KeyCode::End), navigation_mode),
+            UiEvent::Paste(text) => {
+                for ch in text.chars() {
+                    self.handle_key_event(key(KeyCode::Char(ch)), navigation_mode);
+                }
+                true
+            }
+            UiEvent::Escape => {
+                // Let the form clean up, but leave the navigation mode switch to the app
+                self.handle_key_event(key(KeyCode::Esc), navigation_mode);
+                false
+            }
+            _ => false,
         }
-        ADMIN_SCREEN_STATE.as_mut().unwrap()
+    }
+
+    fn render(&mut self, f: &mut Frame, app: &App) {
+        render_admin(f, app, self);
     }
 }
 
 /// Render the complete admin screen (consistent with swap/liquidity screens)
-pub fn render_admin(f: &mut Frame, app: &App) {
+pub fn render_admin(f: &mut Frame, app: &App, admin_state: &mut AdminScreenState) {
     let size = f.area();
 
     // Create main layout: header, nav, content, status
@@ -945,21 +745,19 @@ pub fn render_admin(f: &mut Frame, app: &App) {
     render_navigation(f, &app.state, chunks[1]);
 
     // Render admin content
-    render_admin_content(f, chunks[2], app);
+    render_admin_content(f, chunks[2], app, admin_state);
 
     // Render status bar
     render_status_bar(f, &app.state, chunks[3]);
 
     // Render validation overlay if needed
     if app.state.current_screen == crate::tui::app::Screen::Admin {
-        render_validation_overlay(f, size, app);
+        render_validation_overlay(f, size, app, admin_state);
     }
 }
 
 /// Render the main admin content area (consistent with swap/liquidity screens)
-fn render_admin_content(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_admin_content(f: &mut Frame, area: Rect, app: &App, admin_state: &mut AdminScreenState) {
     // Create vertical layout: tabs + content
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -993,36 +791,47 @@ fn render_admin_content(f: &mut Frame, area: Rect, app: &App) {
 
     // Render content based on current mode
     match admin_state.mode {
-        AdminMode::PoolManagement => render_pool_management_panel(f, main_chunks[1], app),
-        AdminMode::PoolCreation => render_pool_creation_panel(f, main_chunks[1], app),
-        AdminMode::FeatureControls => render_feature_controls_panel(f, main_chunks[1], app),
+        AdminMode::PoolManagement => {
+            render_pool_management_panel(f, main_chunks[1], app, admin_state)
+        }
+        AdminMode::PoolCreation => render_pool_creation_panel(f, main_chunks[1], app, admin_state),
+        AdminMode::FeatureControls => {
+            render_feature_controls_panel(f, main_chunks[1], app, admin_state)
+        }
     }
 }
 
 /// Render pool creation panel (consistent with swap/liquidity form patterns)
-fn render_pool_creation_panel(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_pool_creation_panel(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Left side: Creation form
-    render_pool_creation_form(f, chunks[0], app);
+    render_pool_creation_form(f, chunks[0], app, admin_state);
 
     // Right side: Preview and validation
-    render_pool_creation_preview(f, chunks[1], app);
+    render_pool_creation_preview(f, chunks[1], admin_state);
 }
 
 /// Render pool creation form (like swap/liquidity input forms)
-fn render_pool_creation_form(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_pool_creation_form(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
+            Constraint::Length(8), // Template dropdown
             Constraint::Length(5), // First asset
             Constraint::Length(5), // Second asset
             Constraint::Length(5), // Swap fee
@@ -1043,40 +852,41 @@ fn render_pool_creation_form(f: &mut Frame, area: Rect, app: &App) {
     // Render input fields
     admin_state
         .pool_creation
-        .first_asset_input
+        .template_dropdown
         .render(f, chunks[0]);
     admin_state
         .pool_creation
-        .second_asset_input
+        .first_asset_input
         .render(f, chunks[1]);
     admin_state
         .pool_creation
-        .swap_fee_input
+        .second_asset_input
         .render(f, chunks[2]);
     admin_state
         .pool_creation
-        .protocol_fee_input
+        .swap_fee_input
         .render(f, chunks[3]);
     admin_state
         .pool_creation
-        .burn_fee_input
+        .protocol_fee_input
         .render(f, chunks[4]);
+    admin_state
+        .pool_creation
+        .burn_fee_input
+        .render(f, chunks[5]);
 
     // Pool type dropdown
-    let admin_state_mut = get_admin_screen_state();
-    admin_state_mut
+    admin_state
         .pool_creation
         .pool_type_dropdown
-        .render(f, chunks[5]);
+        .render(f, chunks[6]);
 
     // Execute button
-    render_creation_execute_button(f, chunks[6], app);
+    render_creation_execute_button(f, chunks[7], app, admin_state);
 }
 
 /// Render pool creation preview
-fn render_pool_creation_preview(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_pool_creation_preview(f: &mut Frame, area: Rect, admin_state: &mut AdminScreenState) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green))
@@ -1095,14 +905,16 @@ fn render_pool_creation_preview(f: &mut Frame, area: Rect, app: &App) {
         .pool_type_dropdown
         .get_selected_label()
         .unwrap_or("Not selected");
+    let template = admin_state.pool_creation.template_label();
 
     let preview_content = if first_asset.is_empty() || second_asset.is_empty() {
         "Enter asset denominations to see preview".to_string()
     } else {
         format!(
-            "Pool Preview:\n\n• Asset Pair: {} / {}\n• Pool Type: {}\n\nFee Structure:\n• Swap Fee: {}%\n• Protocol Fee: {}%\n• Burn Fee: {}%\n• Total Fee: {:.2}%\n\nThis pool will be created on the Mantra DEX\nwith the specified configuration.",
+            "Pool Preview:\n\n• Asset Pair: {} / {}\n• Template: {}\n• Pool Type: {}\n\nFee Structure:\n• Swap Fee: {}%\n• Protocol Fee: {}%\n• Burn Fee: {}%\n• Total Fee: {:.2}%\n\nThis pool will be created on the Mantra DEX\nwith the specified configuration.",
             first_asset,
             second_asset,
+            template,
             pool_type,
             swap_fee,
             protocol_fee,
@@ -1121,8 +933,12 @@ fn render_pool_creation_preview(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render creation execute button (like swap/liquidity execute buttons)
-fn render_creation_execute_button(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
+fn render_creation_execute_button(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let is_focused = matches!(admin_state.input_focus, AdminInputFocus::CreationExecute);
     let is_valid = admin_state.clone().validate();
 
@@ -1210,23 +1026,31 @@ fn render_creation_execute_button(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render pool management panel (consistent with swap/liquidity patterns)
-fn render_pool_management_panel(f: &mut Frame, area: Rect, app: &App) {
+fn render_pool_management_panel(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Left: Pool list and selection
-    render_pool_management_form(f, chunks[0], app);
+    render_pool_management_form(f, chunks[0], app, admin_state);
 
     // Right: Pool details and feature status
-    render_pool_management_details(f, chunks[1], app);
+    render_pool_management_details(f, chunks[1], admin_state);
 }
 
 /// Render pool management form
-fn render_pool_management_form(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_pool_management_form(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -1245,22 +1069,20 @@ fn render_pool_management_form(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
 
     // Pool selection dropdown
-    let admin_state_mut = get_admin_screen_state();
-    admin_state_mut
+    admin_state
         .pool_management
         .pool_selection_dropdown
         .render(f, chunks[0]);
 
     // Feature toggles
-    render_pool_feature_toggles(f, chunks[1], app);
+    render_pool_feature_toggles(f, chunks[1], admin_state);
 
     // Execute button
-    render_management_execute_button(f, chunks[2], app);
+    render_management_execute_button(f, chunks[2], app, admin_state);
 }
 
 /// Render pool feature toggles
-fn render_pool_feature_toggles(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
+fn render_pool_feature_toggles(f: &mut Frame, area: Rect, admin_state: &mut AdminScreenState) {
     let is_focused = matches!(admin_state.input_focus, AdminInputFocus::FeatureToggles);
 
     let border_style = if is_focused {
@@ -1330,8 +1152,12 @@ fn render_pool_feature_toggles(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render management execute button
-fn render_management_execute_button(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
+fn render_management_execute_button(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let is_focused = matches!(admin_state.input_focus, AdminInputFocus::ManagementExecute);
     let is_valid = admin_state.clone().validate();
 
@@ -1385,9 +1211,7 @@ fn render_management_execute_button(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render pool management details
-fn render_pool_management_details(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_pool_management_details(f: &mut Frame, area: Rect, admin_state: &mut AdminScreenState) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green))
@@ -1424,23 +1248,31 @@ fn render_pool_management_details(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render feature controls panel
-fn render_feature_controls_panel(f: &mut Frame, area: Rect, app: &App) {
+fn render_feature_controls_panel(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Left: Bulk feature controls
-    render_feature_controls_form(f, chunks[0], app);
+    render_feature_controls_form(f, chunks[0], app, admin_state);
 
     // Right: Bulk operations help
     render_feature_controls_details(f, chunks[1], app);
 }
 
 /// Render feature controls form
-fn render_feature_controls_form(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_feature_controls_form(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -1459,22 +1291,20 @@ fn render_feature_controls_form(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
 
     // Pool selection
-    let admin_state_mut = get_admin_screen_state();
-    admin_state_mut
+    admin_state
         .feature_control
         .pool_selection_dropdown
         .render(f, chunks[0]);
 
     // Bulk controls
-    render_bulk_feature_controls(f, chunks[1], app);
+    render_bulk_feature_controls(f, chunks[1], admin_state);
 
     // Execute button
-    render_controls_execute_button(f, chunks[2], app);
+    render_controls_execute_button(f, chunks[2], app, admin_state);
 }
 
 /// Render bulk feature controls
-fn render_bulk_feature_controls(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
+fn render_bulk_feature_controls(f: &mut Frame, area: Rect, admin_state: &mut AdminScreenState) {
     let is_focused = matches!(admin_state.input_focus, AdminInputFocus::FeatureControls);
 
     let border_style = if is_focused {
@@ -1521,8 +1351,12 @@ fn render_bulk_feature_controls(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render controls execute button
-fn render_controls_execute_button(f: &mut Frame, area: Rect, app: &App) {
-    let admin_state = get_admin_screen_state();
+fn render_controls_execute_button(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     let is_focused = matches!(admin_state.input_focus, AdminInputFocus::ControlsExecute);
     let is_valid = admin_state.clone().validate();
 
@@ -1884,9 +1718,12 @@ fn render_bulk_operations(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render validation error overlay (like swap/liquidity screens)
-fn render_validation_overlay(f: &mut Frame, area: Rect, _app: &App) {
-    let admin_state = get_admin_screen_state();
-
+fn render_validation_overlay(
+    f: &mut Frame,
+    area: Rect,
+    _app: &App,
+    admin_state: &mut AdminScreenState,
+) {
     // Only show validation errors when execute button is focused and validation fails
     if !matches!(
         admin_state.input_focus,
@@ -2099,7 +1936,7 @@ pub fn execute_pool_management_with_confirmation(app_state: &mut crate::tui::app
 pub fn handle_pool_creation_confirmation_response(
     app_state: &mut crate::tui::app::AppState,
     confirmed: bool,
-) -> Option<crate::tui::events::Event> {
+) -> Option<crate::tui::events::TxEvent> {
     crate::tui::utils::logger::log_info(&format!(
         "=== POOL CREATION CONFIRMATION RESPONSE: {} ===",
         if confirmed { "CONFIRMED" } else { "CANCELLED" }
@@ -2120,7 +1957,7 @@ pub fn handle_pool_creation_confirmation_response(
             .unwrap_or_default();
 
         // Create pool creation event
-        Some(crate::tui::events::Event::CreatePool {
+        Some(crate::tui::events::TxEvent::CreatePool {
             asset_1: first_asset.to_string(),
             asset_2: second_asset.to_string(),
             swap_fee: swap_fee.to_string(),
@@ -2139,7 +1976,7 @@ pub fn handle_pool_creation_confirmation_response(
 pub fn handle_pool_management_confirmation_response(
     app_state: &mut crate::tui::app::AppState,
     confirmed: bool,
-) -> Option<crate::tui::events::Event> {
+) -> Option<crate::tui::events::TxEvent> {
     crate::tui::utils::logger::log_info(&format!(
         "=== POOL MANAGEMENT CONFIRMATION RESPONSE: {} ===",
         if confirmed { "CONFIRMED" } else { "CANCELLED" }
@@ -2159,7 +1996,7 @@ pub fn handle_pool_management_confirmation_response(
             .unwrap_or((true, true, true));
 
         // Create pool management event
-        Some(crate::tui::events::Event::UpdatePoolFeatures {
+        Some(crate::tui::events::TxEvent::UpdatePoolFeatures {
             pool_id: pool_id.to_string(),
             features: vec![
                 if features.0 {
@@ -2204,6 +2041,7 @@ pub fn reset_admin_forms(app_state: &mut crate::tui::app::AppState) {
         .protocol_fee_input
         .set_value("0.01");
     admin_state.pool_creation.burn_fee_input.set_value("0.0");
+    admin_state.pool_creation.template_dropdown.selected_index = None;
 
     // Reset pool management
     admin_state.pool_management.selected_pool_features = None;
@@ -2213,3 +2051,28 @@ pub fn reset_admin_forms(app_state: &mut crate::tui::app::AppState) {
 
     crate::tui::utils::logger::log_info("Admin forms reset completed");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_prefills_editable_fields() {
+        let mut creation = PoolCreationState::default();
+        assert_eq!(creation.template_label(), "Custom");
+
+        creation.template_dropdown.select_value("stable");
+        creation.apply_template(PoolTemplate::Stable);
+        assert_eq!(creation.swap_fee_input.value(), "0.008");
+        assert_eq!(creation.protocol_fee_input.value(), "0.002");
+        assert_eq!(creation.burn_fee_input.value(), "0");
+        assert_eq!(
+            creation.pool_type_dropdown.get_selected_value(),
+            Some("StableSwap")
+        );
+        assert_eq!(creation.template_label(), "stable");
+
+        creation.swap_fee_input.set_value("0.05");
+        assert_eq!(creation.template_label(), "stable (modified)");
+    }
+}
@@ -0,0 +1,317 @@
+//! Analytics Screen Implementation
+//!
+//! This module provides the analytics view for the MANTRA DEX SDK TUI,
+//! summarising the active wallet's swap history per trading pair and ranking
+//! the pools its balances could earn yield in.
+
+use crate::analytics::PairExecution;
+use crate::earn::EarnOpportunity;
+use crate::tui::{
+    app::{App, LoadingState},
+    components::{
+        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+    },
+};
+use cosmwasm_std::{Decimal, Uint128};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// Analytics screen state
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsState {
+    /// Execution summary per pair, from the last refresh
+    pub report: Vec<PairExecution>,
+    /// Pools the wallet's balances could be provided to, best APR first
+    pub earn: Vec<EarnOpportunity>,
+    /// When the report was last refreshed
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Render the complete analytics screen
+pub fn render_analytics(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Navigation
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Status bar
+        ])
+        .split(f.area());
+
+    render_header(f, &app.state, chunks[0]);
+    render_navigation(f, &app.state, chunks[1]);
+    let content = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[2]);
+    render_execution_table(f, content[0], app);
+    render_earn_table(f, content[1], app);
+    render_status_bar(f, &app.state, chunks[3]);
+}
+
+/// Render the per-pair execution table
+fn render_execution_table(f: &mut Frame, area: Rect, app: &App) {
+    let state = &app.state.analytics_state;
+
+    if state.report.is_empty() {
+        render_empty_report(f, area, app);
+        return;
+    }
+
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let header = Row::new(vec![
+        Cell::from("Pair").style(bold),
+        Cell::from("Trades").style(bold),
+        Cell::from("Sold").style(bold),
+        Cell::from("Received").style(bold),
+        Cell::from("Avg Price").style(bold),
+        Cell::from("Fees").style(bold),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let rows: Vec<Row> = state
+        .report
+        .iter()
+        .enumerate()
+        .map(|(index, pair)| {
+            let style = if index % 2 == 0 {
+                Style::default()
+            } else {
+                Style::default().bg(Color::DarkGray)
+            };
+            Row::new(execution_row(pair).into_iter().map(Cell::from)).style(style)
+        })
+        .collect();
+
+    let title = match state.updated_at {
+        Some(updated_at) => format!(
+            "Execution by Pair (updated {})",
+            updated_at.format("%H:%M:%S")
+        ),
+        None => "Execution by Pair".to_string(),
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(25), // Pair
+            Constraint::Length(8),      // Trades
+            Constraint::Percentage(15), // Sold
+            Constraint::Percentage(15), // Received
+            Constraint::Percentage(15), // Avg Price
+            Constraint::Percentage(20), // Fees
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue))
+            .title(title),
+    );
+
+    f.render_widget(table, area);
+}
+
+/// Cell texts for one pair
+fn execution_row(pair: &PairExecution) -> Vec<String> {
+    vec![
+        format!(
+            "{} → {}",
+            short_denom(&pair.offer_denom),
+            short_denom(&pair.ask_denom)
+        ),
+        pair.trades.to_string(),
+        pair.total_offer.to_string(),
+        pair.total_return.to_string(),
+        pair.average_price.to_string(),
+        format!("{} {}", pair.total_fees, short_denom(&pair.ask_denom)),
+    ]
+}
+
+/// Render the yield ranking for the wallet's balances
+fn render_earn_table(f: &mut Frame, area: Rect, app: &App) {
+    let state = &app.state.analytics_state;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .title("Best Yield for Your Assets");
+
+    if state.earn.is_empty() {
+        let message = if state.updated_at.is_some() {
+            "No pools found for the assets in this wallet."
+        } else {
+            "Press F5 to rank pools for your assets."
+        };
+        let paragraph = Paragraph::new(message)
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(block);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let header = Row::new(vec![
+        Cell::from("Asset").style(bold),
+        Cell::from("Pool").style(bold),
+        Cell::from("Fee APR").style(bold),
+        Cell::from("Incentive APR").style(bold),
+        Cell::from("Total APR").style(bold),
+        Cell::from("Pair With").style(bold),
+        Cell::from("Projected / Year").style(bold),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let rows: Vec<Row> = state
+        .earn
+        .iter()
+        .enumerate()
+        .map(|(index, opportunity)| {
+            let style = if index % 2 == 0 {
+                Style::default()
+            } else {
+                Style::default().bg(Color::DarkGray)
+            };
+            Row::new(earn_row(opportunity).into_iter().map(Cell::from)).style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(10), // Asset
+            Constraint::Percentage(15), // Pool
+            Constraint::Length(9),      // Fee APR
+            Constraint::Length(14),     // Incentive APR
+            Constraint::Length(10),     // Total APR
+            Constraint::Percentage(25), // Pair With
+            Constraint::Percentage(20), // Projected
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    f.render_widget(table, area);
+}
+
+/// Cell texts for one yield opportunity
+fn earn_row(opportunity: &EarnOpportunity) -> Vec<String> {
+    let pairing: Vec<String> = opportunity
+        .pairing
+        .iter()
+        .map(|coin| format!("{} {}", coin.amount, short_denom(&coin.denom)))
+        .collect();
+    vec![
+        short_denom(&opportunity.asset).to_string(),
+        opportunity.pool_id.clone(),
+        percent(opportunity.fee_apr),
+        percent(opportunity.incentive_apr),
+        percent(opportunity.total_apr()),
+        pairing.join(", "),
+        format!(
+            "{} {}",
+            opportunity.projected_return,
+            short_denom(&opportunity.asset)
+        ),
+    ]
+}
+
+/// A fraction as a percentage with two decimals
+fn percent(fraction: Decimal) -> String {
+    let basis_points = (fraction * Decimal::from_ratio(10_000u128, 1u128)).to_uint_floor();
+    format!(
+        "{}.{:02}%",
+        basis_points / Uint128::new(100),
+        (basis_points % Uint128::new(100)).u128()
+    )
+}
+
+/// Last path segment of a denom, e.g. `factory/addr/token` → `token`
+fn short_denom(denom: &str) -> &str {
+    denom.rsplit('/').next().unwrap_or(denom)
+}
+
+/// Render the message shown when there is nothing to display
+fn render_empty_report(f: &mut Frame, area: Rect, app: &App) {
+    let (message, color) = match (&app.state.loading_state, &app.state.wallet_address) {
+        (LoadingState::Loading { .. }, _) => ("Loading swap history...", Color::Yellow),
+        (_, None) => ("Load a wallet to see its execution analytics.", Color::Gray),
+        (_, Some(_)) if app.state.analytics_state.updated_at.is_none() => {
+            ("Press F5 to load swap history.", Color::Gray)
+        }
+        _ => ("No swaps found for this wallet.", Color::Gray),
+    };
+
+    let paragraph = Paragraph::new(message)
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue))
+                .title("Execution by Pair"),
+        );
+
+    f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Coin;
+
+    #[test]
+    fn test_execution_row() {
+        let pair = PairExecution {
+            offer_denom: "uom".to_string(),
+            ask_denom: "factory/mantra1abc/uusdc".to_string(),
+            trades: 3,
+            total_offer: Uint128::new(4000),
+            total_return: Uint128::new(5000),
+            total_fees: Uint128::new(20),
+            average_price: Decimal::from_ratio(5u128, 4u128),
+            height_range: (10, 20),
+        };
+
+        assert_eq!(
+            execution_row(&pair),
+            vec!["uom → uusdc", "3", "4000", "5000", "1.25", "20 uusdc"]
+        );
+    }
+
+    #[test]
+    fn test_earn_row() {
+        let opportunity = EarnOpportunity {
+            asset: "uom".to_string(),
+            pool_id: "o.uom.uusdc".to_string(),
+            deposit: Uint128::new(1_000),
+            pairing: vec![Coin::new(2_000u128, "factory/mantra1abc/uusdc")],
+            tvl: Uint128::new(1_000_000),
+            fee_apr: Decimal::percent(12),
+            incentive_apr: Decimal::permille(35),
+            farms: vec!["f1".to_string()],
+            projected_return: Uint128::new(310),
+        };
+
+        assert_eq!(
+            earn_row(&opportunity),
+            vec![
+                "uom",
+                "o.uom.uusdc",
+                "12.00%",
+                "3.50%",
+                "15.50%",
+                "2000 uusdc",
+                "310 uom"
+            ]
+        );
+    }
+}
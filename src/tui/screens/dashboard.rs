@@ -3,6 +3,8 @@
 //! This module provides the main dashboard view for the MANTRA DEX SDK TUI,
 //! displaying portfolio overview, quick stats, recent transactions, and network health.
 
+use crate::circuit_breaker::{CircuitState, EndpointHealth};
+use crate::portfolio::PortfolioValuation;
 use crate::tui::{
     app::{App, LoadingState, TransactionStatus},
     components::{
@@ -12,6 +14,7 @@ use crate::tui::{
         status_bar::render_status_bar,
     },
 };
+use cosmwasm_std::Uint128;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -19,7 +22,6 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Padding, Paragraph, Wrap},
     Frame,
 };
-use std::collections::HashMap;
 
 /// Render the complete dashboard screen
 pub fn render_dashboard(f: &mut Frame, app: &App) {
@@ -72,7 +74,11 @@ fn render_dashboard_content(f: &mut Frame, area: Rect, app: &App) {
     // Render components with focus awareness
     render_overview_panel(f, top_chunks[0], app);
     render_quick_stats(f, top_chunks[1], app);
-    render_token_balances(f, middle_chunks[0], app);
+    if app.is_paper_trading() {
+        render_paper_portfolio(f, middle_chunks[0], app);
+    } else {
+        render_token_balances(f, middle_chunks[0], app);
+    }
     render_network_health(f, middle_chunks[1], app);
     render_recent_transactions(f, main_chunks[2], app);
 
@@ -149,9 +155,6 @@ fn render_overview_panel(f: &mut Frame, area: Rect, app: &App) {
         .border_style(Style::default().fg(Color::Cyan))
         .padding(Padding::uniform(1));
 
-    // Calculate portfolio metrics
-    let total_value = calculate_total_portfolio_value(&app.state.balances);
-    let active_positions = count_active_positions(&app.state.pool_cache);
     let recent_activity_count = app.state.recent_transactions.len();
 
     let content = if matches!(app.state.loading_state, LoadingState::Loading { .. }) {
@@ -160,26 +163,12 @@ fn render_overview_panel(f: &mut Frame, area: Rect, app: &App) {
             Style::default().fg(Color::Yellow),
         )])]
     } else {
-        vec![
-            Line::from(vec![
-                Span::styled("Total Portfolio Value: ", Style::default().fg(Color::White)),
-                Span::styled(
-                    format!("${:.2}", total_value),
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD),
-                ),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("Active Positions: ", Style::default().fg(Color::White)),
-                Span::styled(
-                    format!("{}", active_positions),
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                ),
-            ]),
+        let portfolio = app.state.portfolio.as_ref();
+        let mut content = portfolio_lines(portfolio, |value| {
+            let quote = portfolio.map_or("", |portfolio| portfolio.quote.as_str());
+            app.format_token_display(&value.to_string(), quote)
+        });
+        content.extend([
             Line::from(""),
             Line::from(vec![
                 Span::styled("Recent Activity: ", Style::default().fg(Color::White)),
@@ -202,7 +191,8 @@ fn render_overview_panel(f: &mut Frame, area: Rect, app: &App) {
                     Style::default().fg(Color::Magenta),
                 ),
             ]),
-        ]
+        ]);
+        content
     };
 
     let paragraph = Paragraph::new(Text::from(content))
@@ -395,6 +385,86 @@ fn render_token_balances(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, inner_area);
 }
 
+/// Render the virtual balances, value and P&L of the paper portfolio
+fn render_paper_portfolio(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .title("Paper Portfolio (Ctrl+P to stop)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .padding(Padding::uniform(1));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let Some(portfolio) = app.state.paper_portfolio.as_ref() else {
+        return;
+    };
+
+    let mut content_lines = Vec::new();
+    match app.state.paper_valuation.as_ref() {
+        Some(valuation) => {
+            let value = valuation.value.map_or_else(
+                || "unpriced".to_string(),
+                |value| app.format_token_display(&value.to_string(), &valuation.quote),
+            );
+            content_lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<12} ", "Value"),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(value, Style::default().add_modifier(Modifier::BOLD)),
+            ]));
+            if let Some(pnl) = valuation.pnl {
+                let (sign, color) = if pnl.is_negative() {
+                    ("-", Color::Red)
+                } else {
+                    ("+", Color::Green)
+                };
+                let amount =
+                    app.format_token_display(&pnl.unsigned_abs().to_string(), &valuation.quote);
+                content_lines.push(Line::from(vec![
+                    Span::styled(format!("{:<12} ", "P&L"), Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        format!("{}{}", sign, amount),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                ]));
+            }
+        }
+        None => content_lines.push(Line::from(Span::styled(
+            "Valuing at live prices...",
+            Style::default().fg(Color::Yellow),
+        ))),
+    }
+    content_lines.push(Line::from(Span::styled(
+        format!("{} trades", portfolio.trades().len()),
+        Style::default().fg(Color::Gray),
+    )));
+    content_lines.push(Line::from(""));
+
+    let max_tokens = (inner_area.height as usize).saturating_sub(content_lines.len());
+    for coin in portfolio.balances().iter().take(max_tokens) {
+        content_lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<12} ", app.denom_to_symbol(&coin.denom)),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                app.micro_to_token_amount(&coin.amount.to_string(), &coin.denom),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(Text::from(content_lines))
+        .wrap(Wrap { trim: true })
+        .alignment(Alignment::Left);
+
+    f.render_widget(paragraph, inner_area);
+}
+
 /// Render recent transactions with enhanced progress visualization for pending ones
 fn render_recent_transactions(f: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
@@ -522,7 +592,7 @@ fn render_network_health(f: &mut Frame, area: Rect, app: &App) {
         .map(|t| t.format("%H:%M:%S").to_string())
         .unwrap_or_else(|| "Never".to_string());
 
-    let status_content = vec![
+    let mut status_content = vec![
         Line::from(vec![
             Span::styled("Status: ", Style::default().fg(Color::White)),
             Span::styled(
@@ -555,59 +625,180 @@ fn render_network_health(f: &mut Frame, area: Rect, app: &App) {
         ]),
     ];
 
+    status_content.extend(app.state.network_info.endpoints.iter().map(endpoint_line));
+
     let status_paragraph = Paragraph::new(Text::from(status_content)).wrap(Wrap { trim: true });
 
     f.render_widget(status_paragraph, health_chunks[2]);
 }
 
-/// Calculate total portfolio value from balances
-fn calculate_total_portfolio_value(balances: &HashMap<String, String>) -> f64 {
-    // In a real implementation, you would fetch current prices and calculate
-    // the total value. For now, we'll return a mock value.
-    if balances.is_empty() {
-        0.0
+/// One RPC endpoint's circuit state, latency, block height and region
+///
+/// The endpoint requests currently go to is marked with an arrow.
+fn endpoint_line(endpoint: &EndpointHealth) -> Line<'_> {
+    let color = match endpoint.state {
+        CircuitState::Closed if endpoint.lagging => Color::Yellow,
+        CircuitState::Closed => Color::Green,
+        CircuitState::HalfOpen => Color::Yellow,
+        CircuitState::Open => Color::Red,
+    };
+    let latency = endpoint
+        .latency_ms
+        .map_or_else(|| "unmeasured".to_string(), |ms| format!("{} ms", ms));
+    let region = endpoint
+        .region
+        .as_ref()
+        .map(|region| format!(" [{}]", region))
+        .unwrap_or_default();
+    let height = endpoint
+        .block_height
+        .map(|height| format!(", #{}", height))
+        .unwrap_or_default();
+    let lag = if endpoint.lagging { ", lagging" } else { "" };
+    let (marker, name_style) = if endpoint.active {
+        (
+            "▶ ",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
     } else {
-        // Mock calculation - sum up balance values
-        balances
-            .values()
-            .filter_map(|balance_str| balance_str.parse::<f64>().ok())
-            .sum::<f64>()
-            * 0.25 // Mock price multiplier
-    }
+        ("  ", Style::default().fg(Color::White))
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("{}{}{}: ", marker, endpoint.endpoint, region),
+            name_style,
+        ),
+        Span::styled(
+            format!("{} ({}{}{})", latency, endpoint.state, height, lag),
+            Style::default().fg(color),
+        ),
+    ])
 }
 
-/// Count active liquidity positions
-fn count_active_positions(pool_cache: &HashMap<String, crate::tui::app::PoolCacheEntry>) -> usize {
-    // In a real implementation, you would check which pools the user has LP positions in
-    // For now, we'll return the number of cached pools as a proxy
-    pool_cache.len()
+/// Lines of the overview panel valuing `portfolio`
+///
+/// `format` writes an amount of the portfolio's quote denom for display.
+fn portfolio_lines(
+    portfolio: Option<&PortfolioValuation>,
+    format: impl Fn(Uint128) -> String,
+) -> Vec<Line<'static>> {
+    let Some(portfolio) = portfolio else {
+        return vec![Line::from(Span::styled(
+            "Valuing holdings at pool prices...",
+            Style::default().fg(Color::Yellow),
+        ))];
+    };
+
+    let part = |label: &str, value: Uint128| {
+        Line::from(vec![
+            Span::styled(format!("  {:<16}", label), Style::default().fg(Color::Gray)),
+            Span::styled(format(value), Style::default().fg(Color::White)),
+        ])
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Total Portfolio Value: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format(portfolio.total_value()),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        part("Balances", portfolio.balances_value()),
+        part("LP Positions", portfolio.positions_value()),
+        part("Pending Rewards", portfolio.rewards_value()),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Active Positions: ", Style::default().fg(Color::White)),
+            Span::styled(
+                portfolio.positions.len().to_string(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    let unpriced = portfolio.unpriced();
+    if !unpriced.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Not priced: {}", unpriced.join(", ")),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    lines
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::positions::LpPosition;
+    use crate::report::HoldingValue;
+    use cosmwasm_std::{Coin, Decimal};
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect()
+    }
 
-    #[test]
-    fn test_calculate_total_portfolio_value() {
-        let mut balances = HashMap::new();
-        balances.insert("ATOM".to_string(), "100.0".to_string());
-        balances.insert("USDC".to_string(), "50.0".to_string());
-
-        let total = calculate_total_portfolio_value(&balances);
-        assert_eq!(total, 37.5); // (100 + 50) * 0.25
+    fn holding(amount: u128, denom: &str, value: Option<u128>) -> HoldingValue {
+        HoldingValue {
+            coin: Coin::new(amount, denom),
+            value: value.map(Uint128::new),
+        }
     }
 
     #[test]
-    fn test_calculate_total_portfolio_value_empty() {
-        let balances = HashMap::new();
-        let total = calculate_total_portfolio_value(&balances);
-        assert_eq!(total, 0.0);
+    fn test_portfolio_lines_show_the_breakdown() {
+        let portfolio = PortfolioValuation {
+            address: "mantra1abc".to_string(),
+            quote: "uusdc".to_string(),
+            taken_at: chrono::Utc::now(),
+            balances: vec![
+                holding(1_000_000, "uom", Some(2_000_000)),
+                holding(5, "ibc/unknown", None),
+            ],
+            positions: vec![LpPosition {
+                pool_id: "p.1".to_string(),
+                lp_denom: "factory/pool/p.1.LP".to_string(),
+                lp_amount: Uint128::new(100),
+                locked_amount: Uint128::zero(),
+                locks: Vec::new(),
+                share: Decimal::percent(1),
+                assets: vec![Coin::new(1_000_000u128, "uom")],
+                deposited: None,
+                quote: "uusdc".to_string(),
+                value: Some(Uint128::new(3_000_000)),
+                deposit_value: None,
+                impermanent_loss: None,
+                fees_earned: None,
+            }],
+            pending_rewards: vec![holding(500_000, "uom", Some(1_000_000))],
+        };
+
+        let lines = text(&portfolio_lines(Some(&portfolio), |value| {
+            value.to_string()
+        }));
+        assert_eq!(lines[0], "Total Portfolio Value: 6000000");
+        assert_eq!(lines[1], format!("  {:<16}2000000", "Balances"));
+        assert_eq!(lines[2], format!("  {:<16}3000000", "LP Positions"));
+        assert_eq!(lines[3], format!("  {:<16}1000000", "Pending Rewards"));
+        assert_eq!(lines[5], "Active Positions: 1");
+        assert_eq!(lines[6], "Not priced: ibc/unknown");
     }
 
     #[test]
-    fn test_count_active_positions() {
-        let pool_cache = HashMap::new();
-        let count = count_active_positions(&pool_cache);
-        assert_eq!(count, 0);
+    fn test_portfolio_lines_before_the_first_valuation() {
+        let lines = text(&portfolio_lines(None, |value| value.to_string()));
+        assert_eq!(lines, vec!["Valuing holdings at pool prices..."]);
     }
 }
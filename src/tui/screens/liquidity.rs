@@ -4,19 +4,23 @@
 //! allowing users to provide liquidity to pools, withdraw liquidity, view current positions,
 //! and analyze position performance with PnL calculations.
 
+use super::Screen;
+use crate::positions::LpPosition;
 use crate::tui::{
-    app::{App, LoadingState},
+    app::{App, LoadingState, NavigationMode},
     components::{
-        forms::{InputType, TextInput},
+        forms::{FieldMut, Form, FormEvent, FormFields, InputType, TextInput},
         header::render_header,
         modals::{render_modal, ModalState},
         navigation::render_navigation,
-        simple_list::{ListEvent, SimpleList, SimpleListOption},
+        simple_list::{SimpleList, SimpleListOption},
         status_bar::render_status_bar,
+        tables::TableExport,
         // tables::format_large_number, // We'll define our own
     },
+    events::{FocusDirection, UiEvent},
 };
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Uint128};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -60,6 +64,77 @@ pub struct LiquidityPosition {
     pub second_asset_amount: Uint128,
     pub first_asset_denom: String,
     pub second_asset_denom: String,
+    /// Share of the deposit's value lost to the pool price move, if known
+    pub impermanent_loss_percentage: Option<f64>,
+    /// Swap fees the position earned, if known
+    pub fees_earned_usd: Option<f64>,
+    /// Part of the LP tokens locked in farm positions
+    pub locked_lp_amount: Uint128,
+    /// When each farm position's locked tokens unlock
+    pub unlock_times: Vec<String>,
+}
+
+impl LiquidityPosition {
+    /// Show an LP position valued in [`USD`](crate::convert::USD)
+    ///
+    /// A position without a known cost basis shows its current value as the
+    /// initial one.
+    pub fn from_lp_position(position: &LpPosition) -> Self {
+        let usd = |amount: Uint128| {
+            amount.u128() as f64 / 10f64.powi(crate::convert::USD_DECIMALS as i32)
+        };
+        let percent =
+            |fraction: Decimal| fraction.to_string().parse::<f64>().unwrap_or(0.0) * 100.0;
+        let estimated_value_usd = position.value.map_or(0.0, usd);
+        let initial_value_usd = position.deposit_value.map_or(estimated_value_usd, usd);
+        let asset = |index: usize| {
+            position
+                .assets
+                .get(index)
+                .map(|coin| (coin.amount, coin.denom.clone()))
+                .unwrap_or_default()
+        };
+        let (first_asset_amount, first_asset_denom) = asset(0);
+        let (second_asset_amount, second_asset_denom) = asset(1);
+
+        Self {
+            pool_id: position.pool_id.clone(),
+            asset_pair: position
+                .assets
+                .iter()
+                .map(|coin| coin.denom.as_str())
+                .collect::<Vec<_>>()
+                .join("/"),
+            lp_token_amount: position.lp_amount,
+            estimated_value_usd,
+            initial_value_usd,
+            pnl_percentage: position.pnl_ratio().map_or(0.0, |ratio| ratio * 100.0),
+            pnl_usd: estimated_value_usd - initial_value_usd,
+            share_percentage: percent(position.share),
+            first_asset_amount,
+            second_asset_amount,
+            first_asset_denom,
+            second_asset_denom,
+            impermanent_loss_percentage: position.impermanent_loss.map(percent),
+            fees_earned_usd: position.fees_earned.map(usd),
+            locked_lp_amount: position.locked_amount,
+            unlock_times: {
+                let now = chrono::Utc::now().timestamp().max(0) as u64;
+                position
+                    .locks
+                    .iter()
+                    .map(|lock| {
+                        format!(
+                            "{} in position {}: {}",
+                            format_large_number(&lock.amount.to_string()),
+                            lock.position_id,
+                            lock.unlock_status(now)
+                        )
+                    })
+                    .collect()
+            },
+        }
+    }
 }
 
 /// Liquidity screen state (simplified like swap screen)
@@ -69,6 +144,8 @@ pub struct LiquidityScreenState {
     pub mode: LiquidityMode,
     /// Current input focus
     pub input_focus: LiquidityInputFocus,
+    /// Fields of the current mode's form
+    pub form: Form<LiquidityInputFocus>,
     /// Pool selection dropdown
     pub pool_dropdown: SimpleList,
     /// First asset amount input (for providing liquidity)
@@ -100,7 +177,8 @@ pub struct LiquidityScreenState {
 
 impl Default for LiquidityScreenState {
     fn default() -> Self {
-        let pool_dropdown = SimpleList::new("Select Pool");
+        let mut pool_dropdown = SimpleList::new("Select Pool");
+        pool_dropdown.set_loading();
 
         let first_asset_input = TextInput::new("First Asset Amount")
             .with_type(InputType::Amount)
@@ -125,6 +203,7 @@ impl Default for LiquidityScreenState {
         let mut instance = Self {
             mode: LiquidityMode::Provide,
             input_focus: LiquidityInputFocus::Pool,
+            form: LiquidityScreenState::form_for(LiquidityMode::Provide),
             pool_dropdown,
             first_asset_input,
             second_asset_input,
@@ -156,20 +235,14 @@ impl LiquidityScreenState {
 
         self.available_pools = pools.clone();
 
-        // Update pool list while preserving focus state
-        let was_active = self.pool_dropdown.is_active;
-        let was_editing = self.pool_dropdown.is_editing;
-
-        let mut dropdown = SimpleList::new("Select Pool");
+        // Update pool list while preserving focus and selection
         let options: Vec<SimpleListOption> = pools
             .iter()
             .map(|(pool_id, display_name)| {
                 SimpleListOption::new(display_name.clone(), pool_id.clone())
             })
             .collect();
-        dropdown = dropdown.with_options(options);
-        dropdown.set_active(was_active);
-        self.pool_dropdown = dropdown;
+        self.pool_dropdown.set_options(options);
 
         crate::tui::utils::logger::log_info("Liquidity pool dropdown updated successfully");
     }
@@ -185,10 +258,48 @@ impl LiquidityScreenState {
         }
     }
 
+    /// Fields shown in each operation mode, in focus order
+    fn form_for(mode: LiquidityMode) -> Form<LiquidityInputFocus> {
+        match mode {
+            LiquidityMode::Provide => Form::new()
+                .dropdown(LiquidityInputFocus::Pool, "Please select a liquidity pool")
+                .amount(
+                    LiquidityInputFocus::FirstAssetAmount,
+                    "Please enter first asset amount",
+                    "Please enter a valid first asset amount",
+                )
+                .amount(
+                    LiquidityInputFocus::SecondAssetAmount,
+                    "Please enter second asset amount",
+                    "Please enter a valid second asset amount",
+                )
+                .amount(
+                    LiquidityInputFocus::SlippageAmount,
+                    "Please enter valid slippage tolerance (0.1-20%)",
+                    "Please enter valid slippage tolerance (0.1-20%)",
+                )
+                .submit(LiquidityInputFocus::Execute),
+            LiquidityMode::Withdraw => Form::new()
+                .dropdown(
+                    LiquidityInputFocus::Pool,
+                    "Please select a pool to withdraw from",
+                )
+                .amount(
+                    LiquidityInputFocus::WithdrawAmount,
+                    "Please enter LP token amount to withdraw",
+                    "Please enter a valid LP token amount",
+                )
+                .submit(LiquidityInputFocus::Execute),
+            // Positions is a read-only view
+            LiquidityMode::Positions => Form::new(),
+        }
+    }
+
     /// Switch operation mode (simplified like swap screen)
     pub fn set_mode(&mut self, mode: LiquidityMode) {
         if self.mode != mode {
             self.mode = mode;
+            self.form = Self::form_for(mode);
             self.clear_focus();
             // Reset to first input for the new mode
             self.input_focus = LiquidityInputFocus::Pool;
@@ -198,50 +309,14 @@ impl LiquidityScreenState {
         }
     }
 
-    /// Move focus to next input (fixed to match swap screen pattern)
+    /// Move focus to next input
     pub fn next_focus(&mut self) {
-        self.input_focus = match self.mode {
-            LiquidityMode::Provide => match self.input_focus {
-                LiquidityInputFocus::Pool => LiquidityInputFocus::FirstAssetAmount,
-                LiquidityInputFocus::FirstAssetAmount => LiquidityInputFocus::SecondAssetAmount,
-                LiquidityInputFocus::SecondAssetAmount => LiquidityInputFocus::SlippageAmount,
-                LiquidityInputFocus::SlippageAmount => LiquidityInputFocus::Execute,
-                LiquidityInputFocus::Execute => LiquidityInputFocus::Pool,
-                _ => LiquidityInputFocus::Pool,
-            },
-            LiquidityMode::Withdraw => match self.input_focus {
-                LiquidityInputFocus::Pool => LiquidityInputFocus::WithdrawAmount,
-                LiquidityInputFocus::WithdrawAmount => LiquidityInputFocus::Execute,
-                LiquidityInputFocus::Execute => LiquidityInputFocus::Pool,
-                _ => LiquidityInputFocus::Pool,
-            },
-            LiquidityMode::Positions => LiquidityInputFocus::Pool, // No navigation in positions mode
-        };
-        self.clear_focus();
-        self.set_focus();
+        self.focus_next_field();
     }
 
-    /// Move focus to previous input (fixed to match swap screen pattern)
+    /// Move focus to previous input
     pub fn previous_focus(&mut self) {
-        self.input_focus = match self.mode {
-            LiquidityMode::Provide => match self.input_focus {
-                LiquidityInputFocus::Pool => LiquidityInputFocus::Execute,
-                LiquidityInputFocus::FirstAssetAmount => LiquidityInputFocus::Pool,
-                LiquidityInputFocus::SecondAssetAmount => LiquidityInputFocus::FirstAssetAmount,
-                LiquidityInputFocus::SlippageAmount => LiquidityInputFocus::SecondAssetAmount,
-                LiquidityInputFocus::Execute => LiquidityInputFocus::SlippageAmount,
-                _ => LiquidityInputFocus::Execute,
-            },
-            LiquidityMode::Withdraw => match self.input_focus {
-                LiquidityInputFocus::Pool => LiquidityInputFocus::Execute,
-                LiquidityInputFocus::WithdrawAmount => LiquidityInputFocus::Pool,
-                LiquidityInputFocus::Execute => LiquidityInputFocus::WithdrawAmount,
-                _ => LiquidityInputFocus::Execute,
-            },
-            LiquidityMode::Positions => LiquidityInputFocus::Pool, // No navigation in positions mode
-        };
-        self.clear_focus();
-        self.set_focus();
+        self.focus_previous_field();
     }
 
     /// Clear focus from all inputs (simplified like swap screen)
@@ -258,22 +333,10 @@ impl LiquidityScreenState {
         self.clear_focus();
     }
 
-    /// Set focus on current input (simplified like swap screen)
-    fn set_focus(&mut self) {
-        match self.input_focus {
-            LiquidityInputFocus::Pool => self.pool_dropdown.set_active(true),
-            LiquidityInputFocus::FirstAssetAmount => self.first_asset_input.set_focused(true),
-            LiquidityInputFocus::SecondAssetAmount => self.second_asset_input.set_focused(true),
-            LiquidityInputFocus::SlippageAmount => self.slippage_input.set_focused(true),
-            LiquidityInputFocus::WithdrawAmount => self.withdraw_amount_input.set_focused(true),
-            LiquidityInputFocus::Execute => {} // Button focus handled separately
-        }
-    }
-
     /// Public wrapper to apply focus based on `input_focus` value (used by external modules)
     pub fn apply_focus(&mut self) {
         self.clear_focus();
-        self.set_focus();
+        self.apply_form_focus();
     }
 
     /// Mark input change for calculations
@@ -479,19 +542,6 @@ impl LiquidityScreenState {
             return true; // Let the main app handle switching navigation modes
         }
 
-        // Handle Tab navigation between fields
-        if matches!(key.code, KeyCode::Tab) {
-            if key
-                .modifiers
-                .contains(crossterm::event::KeyModifiers::SHIFT)
-            {
-                self.previous_focus();
-            } else {
-                self.next_focus();
-            }
-            return true;
-        }
-
         // Log significant key events for liquidity execution
         if matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
             && matches!(self.input_focus, LiquidityInputFocus::Execute)
@@ -501,125 +551,36 @@ impl LiquidityScreenState {
             crate::tui::utils::logger::log_debug(&format!("Current focus: {:?}", self.input_focus));
         }
 
-        // Handle regular input focus
-        match self.input_focus {
-            LiquidityInputFocus::Pool => {
-                let list_event = self.pool_dropdown.handle_key_event(key);
-
-                if list_event == ListEvent::SelectionMade {
-                    self.mark_input_change();
-                }
-
-                if list_event == ListEvent::SelectionMade
-                    || list_event == ListEvent::SelectionCancelled
-                {
-                    self.next_focus();
-                }
-
-                list_event != ListEvent::Ignored
-            }
-            LiquidityInputFocus::FirstAssetAmount => {
-                let input_request = match key.code {
-                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
-                    KeyCode::Right => Some(InputRequest::GoToNextChar),
-                    KeyCode::Home => Some(InputRequest::GoToStart),
-                    KeyCode::End => Some(InputRequest::GoToEnd),
-                    _ => None,
-                };
-
-                if let Some(request) = input_request {
-                    if self.first_asset_input.handle_input(request).is_some() {
-                        self.mark_input_change();
-                        // Trigger proportional calculation for the other asset
-                        self.calculate_proportional_amount(LiquidityInputFocus::FirstAssetAmount);
-                        return true;
-                    }
-                }
-                false
-            }
-            LiquidityInputFocus::SecondAssetAmount => {
-                let input_request = match key.code {
-                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
-                    KeyCode::Right => Some(InputRequest::GoToNextChar),
-                    KeyCode::Home => Some(InputRequest::GoToStart),
-                    KeyCode::End => Some(InputRequest::GoToEnd),
-                    _ => None,
-                };
-
-                if let Some(request) = input_request {
-                    if self.second_asset_input.handle_input(request).is_some() {
-                        self.mark_input_change();
-                        // Trigger proportional calculation for the other asset
-                        self.calculate_proportional_amount(LiquidityInputFocus::SecondAssetAmount);
-                        return true;
-                    }
+        let focused = self.input_focus;
+        match self.handle_form_key(key) {
+            FormEvent::Ignored => false,
+            FormEvent::Handled | FormEvent::Cancelled => true,
+            FormEvent::Changed => {
+                self.mark_input_change();
+                if matches!(
+                    focused,
+                    LiquidityInputFocus::FirstAssetAmount | LiquidityInputFocus::SecondAssetAmount
+                ) {
+                    // Keep the other asset amount in proportion to the pool reserves
+                    self.calculate_proportional_amount(focused);
                 }
-                false
+                true
             }
-            LiquidityInputFocus::SlippageAmount => {
-                let input_request = match key.code {
-                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
-                    KeyCode::Right => Some(InputRequest::GoToNextChar),
-                    KeyCode::Home => Some(InputRequest::GoToStart),
-                    KeyCode::End => Some(InputRequest::GoToEnd),
-                    _ => None,
-                };
-
-                if let Some(request) = input_request {
-                    if self.slippage_input.handle_input(request).is_some() {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
-                false
+            FormEvent::Selected => {
+                self.mark_input_change();
+                true
             }
-            LiquidityInputFocus::WithdrawAmount => {
-                let input_request = match key.code {
-                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
-                    KeyCode::Right => Some(InputRequest::GoToNextChar),
-                    KeyCode::Home => Some(InputRequest::GoToStart),
-                    KeyCode::End => Some(InputRequest::GoToEnd),
-                    _ => None,
-                };
-
-                if let Some(request) = input_request {
-                    if self.withdraw_amount_input.handle_input(request).is_some() {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
-                false
+            FormEvent::Submit => {
+                self.mark_input_change();
+                crate::tui::utils::logger::log_info(
+                    "Liquidity execute button pressed - validation passed",
+                );
+                true // Event will be handled by app
             }
-            LiquidityInputFocus::Execute => {
-                // Handle execute button activation
-                match key.code {
-                    KeyCode::Enter | KeyCode::Char(' ') => {
-                        if self.validate() {
-                            self.mark_input_change();
-                            crate::tui::utils::logger::log_info(
-                                "Liquidity execute button pressed - validation passed",
-                            );
-                            return true; // Event will be handled by app
-                        } else {
-                            crate::tui::utils::logger::log_warning(
-                                "Liquidity validation failed - please check all fields",
-                            );
-                        }
-                    }
-                    _ => {}
-                }
+            FormEvent::Invalid => {
+                crate::tui::utils::logger::log_warning(
+                    "Liquidity validation failed - please check all fields",
+                );
                 false
             }
         }
@@ -650,6 +611,41 @@ impl LiquidityScreenState {
         }
     }
 
+    /// The positions table, for writing to a file
+    ///
+    /// Amounts are in base units and values in USD, unrounded.
+    pub fn export_positions(&self) -> TableExport {
+        let headers = [
+            "Pool",
+            "Asset Pair",
+            "LP Tokens",
+            "Locked LP Tokens",
+            "Value (USD)",
+            "PnL (USD)",
+            "PnL %",
+            "Share %",
+        ];
+        TableExport::new(
+            "positions",
+            headers.iter().map(|header| header.to_string()).collect(),
+            self.positions
+                .iter()
+                .map(|position| {
+                    vec![
+                        position.pool_id.clone(),
+                        position.asset_pair.clone(),
+                        position.lp_token_amount.to_string(),
+                        position.locked_lp_amount.to_string(),
+                        position.estimated_value_usd.to_string(),
+                        position.pnl_usd.to_string(),
+                        position.pnl_percentage.to_string(),
+                        position.share_percentage.to_string(),
+                    ]
+                })
+                .collect(),
+        )
+    }
+
     /// Navigate positions list
     pub fn navigate_positions(&mut self, next: bool) {
         if self.positions.is_empty() {
@@ -676,74 +672,12 @@ impl LiquidityScreenState {
 
     /// Validate current form inputs (simplified like swap screen)
     pub fn validate(&mut self) -> bool {
-        match self.mode {
-            LiquidityMode::Provide => {
-                let pool_valid = self.pool_dropdown.get_selected_value().is_some();
-                let first_valid = self.first_asset_input.validate();
-                let second_valid = self.second_asset_input.validate();
-                let slippage_valid = self.slippage_input.validate();
-
-                pool_valid && first_valid && second_valid && slippage_valid
-            }
-            LiquidityMode::Withdraw => {
-                let pool_valid = self.pool_dropdown.get_selected_value().is_some();
-                let withdraw_valid = self.withdraw_amount_input.validate();
-
-                pool_valid && withdraw_valid
-            }
-            LiquidityMode::Positions => true, // No validation needed for positions view
-        }
+        self.validate_form()
     }
 
     /// Get detailed validation errors for user feedback (simplified like swap screen)
     pub fn get_validation_errors(&mut self) -> Vec<String> {
-        let mut errors = Vec::new();
-
-        match self.mode {
-            LiquidityMode::Provide => {
-                if self.pool_dropdown.get_selected_value().is_none() {
-                    errors.push("Please select a liquidity pool".to_string());
-                }
-
-                if !self.first_asset_input.validate() {
-                    if self.first_asset_input.value().is_empty() {
-                        errors.push("Please enter first asset amount".to_string());
-                    } else {
-                        errors.push("Please enter a valid first asset amount".to_string());
-                    }
-                }
-
-                if !self.second_asset_input.validate() {
-                    if self.second_asset_input.value().is_empty() {
-                        errors.push("Please enter second asset amount".to_string());
-                    } else {
-                        errors.push("Please enter a valid second asset amount".to_string());
-                    }
-                }
-
-                if !self.slippage_input.validate() {
-                    errors.push("Please enter valid slippage tolerance (0.1-20%)".to_string());
-                }
-            }
-            LiquidityMode::Withdraw => {
-                if self.pool_dropdown.get_selected_value().is_none() {
-                    errors.push("Please select a pool to withdraw from".to_string());
-                }
-
-                if !self.withdraw_amount_input.validate() {
-                    if self.withdraw_amount_input.value().is_empty() {
-                        errors.push("Please enter LP token amount to withdraw".to_string());
-                    } else {
-                        errors.push("Please enter a valid LP token amount".to_string());
-                    }
-                }
-            }
-            LiquidityMode::Positions => {
-                // No validation needed for positions view
-            }
-        }
-
-        errors
+        self.form_errors()
     }
 
     /// Show confirmation modal using global app state (like swap screen)
@@ -796,15 +730,66 @@ pub struct LiquidityOperationDetails {
     pub expected_assets: Option<String>,
 }
 
-// Global state for the liquidity screen
-static mut LIQUIDITY_SCREEN_STATE: Option<LiquidityScreenState> = None;
+impl FormFields for LiquidityScreenState {
+    type Key = LiquidityInputFocus;
+
+    fn form(&self) -> &Form<LiquidityInputFocus> {
+        &self.form
+    }
+
+    fn form_mut(&mut self) -> &mut Form<LiquidityInputFocus> {
+        &mut self.form
+    }
+
+    fn focused_field(&self) -> LiquidityInputFocus {
+        self.input_focus
+    }
+
+    fn set_focused_field(&mut self, key: LiquidityInputFocus) {
+        self.input_focus = key;
+    }
+
+    fn field_mut(&mut self, key: LiquidityInputFocus) -> FieldMut<'_> {
+        match key {
+            LiquidityInputFocus::Pool => FieldMut::Dropdown(&mut self.pool_dropdown),
+            LiquidityInputFocus::FirstAssetAmount => FieldMut::Text(&mut self.first_asset_input),
+            LiquidityInputFocus::SecondAssetAmount => FieldMut::Text(&mut self.second_asset_input),
+            LiquidityInputFocus::SlippageAmount => FieldMut::Text(&mut self.slippage_input),
+            LiquidityInputFocus::WithdrawAmount => FieldMut::Text(&mut self.withdraw_amount_input),
+            LiquidityInputFocus::Execute => FieldMut::None,
+        }
+    }
+}
+
+impl Screen for LiquidityScreenState {
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let code = match event {
+            UiEvent::MoveFocus(FocusDirection::Up) => KeyCode::Up,
+            UiEvent::MoveFocus(FocusDirection::Down) => KeyCode::Down,
+            UiEvent::MoveFocus(FocusDirection::Left) => KeyCode::Left,
+            UiEvent::MoveFocus(FocusDirection::Right) => KeyCode::Right,
+            UiEvent::Char(c) => KeyCode::Char(*c),
+            UiEvent::Enter => KeyCode::Enter,
+            UiEvent::Tab => KeyCode::Tab,
+            UiEvent::Backspace => KeyCode::Backspace,
+            UiEvent::Delete => KeyCode::Delete,
+            UiEvent::Home => KeyCode::Home,
+            UiEvent::End => KeyCode::End,
+            _ => return false,
+        };
+
+        self.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE), navigation_mode)
+    }
 
-pub fn get_liquidity_screen_state() -> &'static mut LiquidityScreenState {
-    unsafe { LIQUIDITY_SCREEN_STATE.get_or_insert_with(LiquidityScreenState::default) }
+    fn render(&mut self, f: &mut Frame, app: &App) {
+        render_liquidity(f, app, self);
+    }
 }
 
 /// Main render function for the liquidity screen (simplified like swap screen)
-pub fn render_liquidity(f: &mut Frame, app: &App) {
+pub fn render_liquidity(f: &mut Frame, app: &App, liquidity_state: &mut LiquidityScreenState) {
     let size = f.area();
 
     // Create main layout: header, navigation, content, status
@@ -824,18 +809,21 @@ pub fn render_liquidity(f: &mut Frame, app: &App) {
     render_status_bar(f, &app.state, main_chunks[3]);
 
     // Render liquidity content
-    render_liquidity_content(f, main_chunks[2], app);
+    render_liquidity_content(f, main_chunks[2], app, liquidity_state);
 
     // Render validation overlay if needed
     if app.state.current_screen == crate::tui::app::Screen::Liquidity {
-        render_validation_overlay(f, size, app);
+        render_validation_overlay(f, size, app, liquidity_state);
     }
 }
 
 /// Render the main liquidity content (simplified like swap screen)
-fn render_liquidity_content(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_liquidity_content(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     // Create simple tab layout
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -870,30 +858,42 @@ fn render_liquidity_content(f: &mut Frame, area: Rect, app: &App) {
 
     // Render content based on current mode
     match liquidity_state.mode {
-        LiquidityMode::Provide => render_provide_liquidity_panel(f, chunks[1], app),
-        LiquidityMode::Withdraw => render_withdraw_liquidity_panel(f, chunks[1], app),
-        LiquidityMode::Positions => render_positions_panel(f, chunks[1], app),
+        LiquidityMode::Provide => {
+            render_provide_liquidity_panel(f, chunks[1], app, liquidity_state)
+        }
+        LiquidityMode::Withdraw => {
+            render_withdraw_liquidity_panel(f, chunks[1], app, liquidity_state)
+        }
+        LiquidityMode::Positions => render_positions_panel(f, chunks[1], app, liquidity_state),
     }
 }
 
 /// Render the provide liquidity panel
-fn render_provide_liquidity_panel(f: &mut Frame, area: Rect, app: &App) {
+fn render_provide_liquidity_panel(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Left side: Input form
-    render_provide_liquidity_form(f, chunks[0], app);
+    render_provide_liquidity_form(f, chunks[0], app, liquidity_state);
 
     // Right side: Preview and expected results
-    render_provide_liquidity_preview(f, chunks[1], app);
+    render_provide_liquidity_preview(f, chunks[1], app, liquidity_state);
 }
 
 /// Render the provide liquidity form (updated to match swap screen)
-fn render_provide_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_provide_liquidity_form(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -914,20 +914,19 @@ fn render_provide_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
 
     // Render input fields in order
-    let liquidity_state_mut = get_liquidity_screen_state();
-    liquidity_state_mut.pool_dropdown.render(f, chunks[0]);
+    liquidity_state.pool_dropdown.render(f, chunks[0]);
 
     // Render first asset input with balance display (like swap screen)
-    render_first_asset_input_with_balance(f, chunks[1], app);
+    render_first_asset_input_with_balance(f, chunks[1], app, liquidity_state);
 
     // Render second asset input with balance display (like swap screen)
-    render_second_asset_input_with_balance(f, chunks[2], app);
+    render_second_asset_input_with_balance(f, chunks[2], app, liquidity_state);
 
     // Render slippage input
     liquidity_state.slippage_input.render(f, chunks[3]);
 
     // Render execute button (fixed like swap screen)
-    render_provide_execute_button(f, chunks[4], app);
+    render_provide_execute_button(f, chunks[4], app, liquidity_state);
 }
 
 /// Helper function to render asset input with balance display
@@ -937,11 +936,10 @@ fn render_asset_input_with_balance<F>(
     app: &App,
     input_widget: &TextInput,
     token_extractor: F,
+    liquidity_state: &LiquidityScreenState,
 ) where
     F: Fn(&str) -> String,
 {
-    let liquidity_state = get_liquidity_screen_state();
-
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
@@ -1034,26 +1032,36 @@ fn render_asset_input_with_balance<F>(
 }
 
 /// Render first asset input with balance display (like swap screen)
-fn render_first_asset_input_with_balance(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
+fn render_first_asset_input_with_balance(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     render_asset_input_with_balance(
         f,
         area,
         app,
         &liquidity_state.first_asset_input,
         extract_first_token_from_pool,
+        liquidity_state,
     );
 }
 
 /// Render second asset input with balance display (like swap screen)
-fn render_second_asset_input_with_balance(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
+fn render_second_asset_input_with_balance(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     render_asset_input_with_balance(
         f,
         area,
         app,
         &liquidity_state.second_asset_input,
         extract_second_token_from_pool,
+        liquidity_state,
     );
 }
 
@@ -1112,8 +1120,12 @@ fn extract_second_token_from_pool(pool_label: &str) -> String {
 }
 
 /// Render provide execute button (fixed to match swap screen)
-fn render_provide_execute_button(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
+fn render_provide_execute_button(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let is_focused = matches!(liquidity_state.input_focus, LiquidityInputFocus::Execute);
     let is_valid = liquidity_state.clone().validate();
 
@@ -1205,9 +1217,12 @@ fn render_provide_execute_button(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render the provide liquidity preview
-fn render_provide_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_provide_liquidity_preview(
+    f: &mut Frame,
+    area: Rect,
+    _app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green))
@@ -1257,23 +1272,31 @@ fn render_provide_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render the withdraw liquidity panel
-fn render_withdraw_liquidity_panel(f: &mut Frame, area: Rect, app: &App) {
+fn render_withdraw_liquidity_panel(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Left side: Input form
-    render_withdraw_liquidity_form(f, chunks[0], app);
+    render_withdraw_liquidity_form(f, chunks[0], app, liquidity_state);
 
     // Right side: Expected assets
-    render_withdraw_liquidity_preview(f, chunks[1], app);
+    render_withdraw_liquidity_preview(f, chunks[1], app, liquidity_state);
 }
 
 /// Render the withdraw liquidity form (simplified like swap screen)
-fn render_withdraw_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_withdraw_liquidity_form(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -1292,19 +1315,21 @@ fn render_withdraw_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
 
     // Render input fields in order
-    let liquidity_state_mut = get_liquidity_screen_state();
-    liquidity_state_mut.pool_dropdown.render(f, chunks[0]);
+    liquidity_state.pool_dropdown.render(f, chunks[0]);
 
     liquidity_state.withdraw_amount_input.render(f, chunks[1]);
 
     // Render execute button (fixed like swap screen)
-    render_withdraw_execute_button(f, chunks[2], app);
+    render_withdraw_execute_button(f, chunks[2], app, liquidity_state);
 }
 
 /// Render the withdraw liquidity preview
-fn render_withdraw_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_withdraw_liquidity_preview(
+    f: &mut Frame,
+    area: Rect,
+    _app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green))
@@ -1353,23 +1378,31 @@ fn render_withdraw_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render the positions panel
-fn render_positions_panel(f: &mut Frame, area: Rect, app: &App) {
+fn render_positions_panel(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
 
     // Top: Positions table
-    render_positions_table(f, chunks[0], app);
+    render_positions_table(f, chunks[0], app, liquidity_state);
 
     // Bottom: Position details
-    render_position_details(f, chunks[1], app);
+    render_position_details(f, chunks[1], app, liquidity_state);
 }
 
 /// Render the current positions table
-fn render_positions_table(f: &mut Frame, area: Rect, _app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_positions_table(
+    f: &mut Frame,
+    area: Rect,
+    _app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue))
@@ -1443,10 +1476,33 @@ fn render_positions_table(f: &mut Frame, area: Rect, _app: &App) {
     f.render_widget(table, area);
 }
 
-/// Render detailed information for the selected position
-fn render_position_details(f: &mut Frame, area: Rect, _app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
+/// Liquid and locked parts of a position's LP tokens and their unlock times
+fn lock_details(position: &LiquidityPosition) -> String {
+    if position.locked_lp_amount.is_zero() {
+        return String::new();
+    }
+    let liquid = position
+        .lp_token_amount
+        .saturating_sub(position.locked_lp_amount);
+    let mut details = format!(
+        " ({} liquid, {} locked in farm positions)",
+        format_large_number(&liquid.to_string()),
+        format_large_number(&position.locked_lp_amount.to_string())
+    );
+    for unlock_time in &position.unlock_times {
+        details.push_str("\n• ");
+        details.push_str(unlock_time);
+    }
+    details
+}
 
+/// Render detailed information for the selected position
+fn render_position_details(
+    f: &mut Frame,
+    area: Rect,
+    _app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green))
@@ -1454,8 +1510,11 @@ fn render_position_details(f: &mut Frame, area: Rect, _app: &App) {
 
     if let Some(selected_index) = liquidity_state.selected_position {
         if let Some(position) = liquidity_state.positions.get(selected_index) {
+            let optional = |value: Option<f64>, format: fn(f64) -> String| {
+                value.map_or_else(|| "unknown".to_string(), format)
+            };
             let details = format!(
-                "Pool ID: {}\n\nAsset Composition:\n• {}: {}\n• {}: {}\n\nPerformance:\n• Initial Value: ${:.2}\n• Current Value: ${:.2}\n• PnL: {:.2}% (${:.2})\n• Pool Share: {:.2}%\n\nLP Token Balance: {}",
+                "Pool ID: {}\n\nAsset Composition:\n• {}: {}\n• {}: {}\n\nPerformance:\n• Initial Value: ${:.2}\n• Current Value: ${:.2}\n• PnL: {:.2}% (${:.2})\n• Impermanent Loss: {}\n• Fees Earned: {}\n• Pool Share: {:.2}%\n\nLP Token Balance: {}{}",
                 position.pool_id,
                 position.first_asset_denom,
                 format_large_number(&position.first_asset_amount.to_string()),
@@ -1465,8 +1524,11 @@ fn render_position_details(f: &mut Frame, area: Rect, _app: &App) {
                 position.estimated_value_usd,
                 position.pnl_percentage,
                 position.pnl_usd,
+                optional(position.impermanent_loss_percentage, |loss| format!("{:.2}%", loss)),
+                optional(position.fees_earned_usd, |fees| format!("${:.2}", fees)),
                 position.share_percentage,
                 format_large_number(&position.lp_token_amount.to_string()),
+                lock_details(position),
             );
 
             let paragraph = Paragraph::new(details)
@@ -1491,14 +1553,16 @@ fn render_position_details(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Handle liquidity screen input
-pub fn handle_liquidity_screen_input(input: InputRequest) -> bool {
-    let liquidity_state = get_liquidity_screen_state();
-    liquidity_state.handle_input(input)
+pub fn handle_liquidity_screen_input(
+    app_state: &mut crate::tui::app::AppState,
+    input: InputRequest,
+) -> bool {
+    app_state.liquidity_screen_state.handle_input(input)
 }
 
 /// Handle liquidity screen navigation
-pub fn handle_liquidity_screen_navigation(next: bool) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn handle_liquidity_screen_navigation(app_state: &mut crate::tui::app::AppState, next: bool) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
 
     match liquidity_state.mode {
         LiquidityMode::Positions => {
@@ -1515,14 +1579,14 @@ pub fn handle_liquidity_screen_navigation(next: bool) {
 }
 
 /// Switch liquidity mode (simplified like swap screen)
-pub fn switch_liquidity_mode(mode: LiquidityMode) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn switch_liquidity_mode(app_state: &mut crate::tui::app::AppState, mode: LiquidityMode) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.set_mode(mode);
 }
 
 /// Execute liquidity operation with confirmation (enhanced to match swap screen)
-pub fn execute_liquidity_operation_with_confirmation() {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn execute_liquidity_operation_with_confirmation(app_state: &mut crate::tui::app::AppState) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
 
     crate::tui::utils::logger::log_info("=== LIQUIDITY EXECUTION ATTEMPT ===");
 
@@ -1672,9 +1736,10 @@ pub fn extract_assets_from_pool_label(pool_label: &str) -> (String, String) {
 
 /// Handle confirmation response (like swap screen)
 pub fn handle_liquidity_confirmation_response(
+    app_state: &mut crate::tui::app::AppState,
     confirmed: bool,
-) -> Option<crate::tui::events::Event> {
-    let liquidity_state = get_liquidity_screen_state();
+) -> Option<crate::tui::events::TxEvent> {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.hide_confirmation_modal();
 
     crate::tui::utils::logger::log_info(&format!(
@@ -1709,7 +1774,7 @@ pub fn handle_liquidity_confirmation_response(
                     return None;
                 }
 
-                let event = crate::tui::events::Event::ProvideLiquidity {
+                let event = crate::tui::events::TxEvent::ProvideLiquidity {
                     asset_1_amount: first_amount.to_string(),
                     asset_2_amount: second_amount.to_string(),
                     pool_id: pool_id_str.to_string(),
@@ -1739,7 +1804,7 @@ pub fn handle_liquidity_confirmation_response(
                     return None;
                 }
 
-                Some(crate::tui::events::Event::WithdrawLiquidity {
+                Some(crate::tui::events::TxEvent::WithdrawLiquidity {
                     lp_token_amount: lp_amount.to_string(),
                     pool_id: pool_id_str.to_string(),
                     slippage_tolerance: None, // Optional for withdraw
@@ -1753,8 +1818,8 @@ pub fn handle_liquidity_confirmation_response(
 }
 
 /// Reset liquidity forms (like swap screen)
-pub fn reset_liquidity_forms() {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn reset_liquidity_forms(app_state: &mut crate::tui::app::AppState) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
 
     // Preserve pool data before reset
     let available_pools = liquidity_state.available_pools.clone();
@@ -1773,38 +1838,53 @@ pub fn reset_liquidity_forms() {
 }
 
 /// Update expected LP tokens from calculation
-pub fn update_expected_lp_tokens(amount: Uint128) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn update_expected_lp_tokens(app_state: &mut crate::tui::app::AppState, amount: Uint128) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.expected_lp_tokens = Some(amount);
 }
 
 /// Update expected assets from withdrawal calculation
-pub fn update_expected_assets(amount1: Uint128, amount2: Uint128, denom1: String, denom2: String) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn update_expected_assets(
+    app_state: &mut crate::tui::app::AppState,
+    amount1: Uint128,
+    amount2: Uint128,
+    denom1: String,
+    denom2: String,
+) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.expected_assets = Some((amount1, amount2, denom1, denom2));
 }
 
 /// Update liquidity positions
-pub fn update_liquidity_positions(positions: Vec<LiquidityPosition>) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn update_liquidity_positions(
+    app_state: &mut crate::tui::app::AppState,
+    positions: Vec<LiquidityPosition>,
+) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.update_positions(positions);
 }
 
 /// Update available pools for liquidity operations
-pub fn update_liquidity_pools(pools: Vec<(String, String)>) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn update_liquidity_pools(
+    app_state: &mut crate::tui::app::AppState,
+    pools: Vec<(String, String)>,
+) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.update_available_pools(pools);
 }
 
 /// Update pool reserves for proportional calculations
-pub fn update_liquidity_pool_reserves(reserves: Vec<(Uint128, String)>) {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn update_liquidity_pool_reserves(
+    app_state: &mut crate::tui::app::AppState,
+    reserves: Vec<(Uint128, String)>,
+) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.update_pool_reserves(reserves);
 }
 
 /// Initialize focus for the liquidity screen (called when entering the screen)
-pub fn initialize_liquidity_screen_focus() {
-    let liquidity_state = get_liquidity_screen_state();
+pub fn initialize_liquidity_screen_focus(app_state: &mut crate::tui::app::AppState) {
+    let liquidity_state = &mut app_state.liquidity_screen_state;
     liquidity_state.input_focus = LiquidityInputFocus::Pool;
     liquidity_state.apply_focus();
 
@@ -1865,6 +1945,10 @@ mod tests {
                 second_asset_amount: Uint128::new(500),
                 first_asset_denom: "USDC".to_string(),
                 second_asset_denom: "USDT".to_string(),
+                impermanent_loss_percentage: None,
+                fees_earned_usd: None,
+                locked_lp_amount: Uint128::zero(),
+                unlock_times: Vec::new(),
             },
             LiquidityPosition {
                 pool_id: "2".to_string(),
@@ -1879,6 +1963,10 @@ mod tests {
                 second_asset_amount: Uint128::new(1000),
                 first_asset_denom: "ATOM".to_string(),
                 second_asset_denom: "OSMO".to_string(),
+                impermanent_loss_percentage: None,
+                fees_earned_usd: None,
+                locked_lp_amount: Uint128::zero(),
+                unlock_times: Vec::new(),
             },
         ];
 
@@ -1897,9 +1985,12 @@ mod tests {
 }
 
 /// Render validation error overlay for immediate feedback
-fn render_validation_overlay(f: &mut Frame, area: Rect, _app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
-
+fn render_validation_overlay(
+    f: &mut Frame,
+    area: Rect,
+    _app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     // Only show validation errors when the execute button is focused and validation fails
     if !matches!(liquidity_state.input_focus, LiquidityInputFocus::Execute) {
         return;
@@ -1954,8 +2045,12 @@ fn render_validation_overlay(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render withdraw execute button (fixed to match swap screen)
-fn render_withdraw_execute_button(f: &mut Frame, area: Rect, app: &App) {
-    let liquidity_state = get_liquidity_screen_state();
+fn render_withdraw_execute_button(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    liquidity_state: &mut LiquidityScreenState,
+) {
     let is_focused = matches!(liquidity_state.input_focus, LiquidityInputFocus::Execute);
     let is_valid = liquidity_state.clone().validate();
 
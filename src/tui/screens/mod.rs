@@ -5,6 +5,7 @@
 
 // Re-export screens when they are implemented
 pub mod admin;
+pub mod analytics;
 pub mod dashboard;
 pub mod liquidity;
 pub mod multihop;
@@ -17,6 +18,7 @@ pub mod wallet_selection;
 pub mod wizard;
 
 pub use admin::*;
+pub use analytics::*;
 pub use dashboard::*;
 pub use liquidity::*;
 pub use multihop::*;
@@ -27,4 +29,20 @@ pub use swap::*;
 pub use transaction::*;
 pub use wallet_selection::*;
 
+use crate::tui::app::{App, NavigationMode};
+use crate::tui::events::UiEvent;
+use ratatui::Frame;
+
+/// A full-screen view that owns its form state
+///
+/// Screen state is stored in [`AppState`](crate::tui::app::AppState) rather than in
+/// module statics, so every instance is independent and can be driven directly in tests.
+pub trait Screen {
+    /// Apply a UI event to the screen's state. Returns `true` if the event was consumed.
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool;
+
+    /// Render the screen, including header, navigation and status bar
+    fn render(&mut self, f: &mut Frame, app: &App);
+}
+
 // Placeholder - screens will be implemented in future tasks
@@ -4,8 +4,9 @@
 //! allowing users to build complex swap routes with multiple hops, analyze the
 //! complete route with price impact and fees, and execute multi-hop transactions.
 
+use super::Screen;
 use crate::tui::{
-    app::{App, LoadingState},
+    app::{App, LoadingState, NavigationMode},
     components::{
         forms::{Dropdown, DropdownOption, InputType, TextInput},
         header::render_header,
@@ -13,7 +14,7 @@ use crate::tui::{
         navigation::render_navigation,
         status_bar::render_status_bar,
     },
-    events::SwapOperation,
+    events::{FocusDirection, SwapOperation, UiEvent},
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -204,6 +205,32 @@ impl MultiHopScreenState {
         }
     }
 
+    /// Offer `tokens` and `pools`, as `(pool_id, display_name)`, in the route builder
+    pub fn update_available_options(&mut self, tokens: Vec<String>, pools: Vec<(String, String)>) {
+        let token_options: Vec<DropdownOption<String>> = tokens
+            .iter()
+            .map(|token| DropdownOption::new(token.clone(), token.clone()))
+            .collect();
+        self.from_token_dropdown.set_options(token_options.clone());
+        self.to_token_dropdown.set_options(token_options);
+        self.pool_dropdown.set_options(
+            pools
+                .iter()
+                .map(|(pool_id, name)| DropdownOption::new(name.clone(), pool_id.clone()))
+                .collect(),
+        );
+        self.available_tokens = tokens;
+        self.available_pools = pools;
+    }
+
+    /// Replace the route with hops found automatically
+    pub fn set_route(&mut self, hops: Vec<SwapHop>) {
+        self.route = hops;
+        self.route_list_state
+            .select((!self.route.is_empty()).then_some(0));
+        self.update_route_analysis();
+    }
+
     /// Remove the selected hop from the route
     pub fn remove_selected_hop(&mut self) {
         if let Some(selected) = self.route_list_state.selected() {
@@ -541,21 +568,42 @@ impl MultiHopScreenState {
     }
 }
 
-// Global state for the multi-hop screen
-static mut MULTIHOP_SCREEN_STATE: Option<MultiHopScreenState> = None;
-
-/// Get or initialize the multi-hop screen state
-fn get_multihop_screen_state() -> &'static mut MultiHopScreenState {
-    unsafe {
-        if MULTIHOP_SCREEN_STATE.is_none() {
-            MULTIHOP_SCREEN_STATE = Some(MultiHopScreenState::default());
+impl Screen for MultiHopScreenState {
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool {
+        if navigation_mode != NavigationMode::WithinScreen {
+            return false;
         }
-        MULTIHOP_SCREEN_STATE.as_mut().unwrap()
+
+        // Dropdowns are driven through word-movement requests, see `handle_input`
+        let input = match event {
+            UiEvent::Tab => {
+                self.next_focus();
+                return true;
+            }
+            UiEvent::BackTab => {
+                self.previous_focus();
+                return true;
+            }
+            UiEvent::MoveFocus(FocusDirection::Up) => InputRequest::GoToPrevWord,
+            UiEvent::MoveFocus(FocusDirection::Down) => InputRequest::GoToNextWord,
+            UiEvent::Char(c) => InputRequest::InsertChar(*c),
+            UiEvent::Backspace => InputRequest::DeletePrevChar,
+            UiEvent::Delete => InputRequest::DeleteNextChar,
+            UiEvent::Home => InputRequest::GoToStart,
+            UiEvent::End => InputRequest::GoToEnd,
+            _ => return false,
+        };
+
+        self.handle_input(input)
+    }
+
+    fn render(&mut self, f: &mut Frame, app: &App) {
+        render_multihop(f, app, self);
     }
 }
 
 /// Main render function for the multi-hop swap screen
-pub fn render_multihop(f: &mut Frame, app: &App) {
+pub fn render_multihop(f: &mut Frame, app: &App, state: &mut MultiHopScreenState) {
     let size = f.area();
 
     // Create main layout: header, nav, content, status
@@ -574,13 +622,12 @@ pub fn render_multihop(f: &mut Frame, app: &App) {
     render_navigation(f, &app.state, chunks[1]);
 
     // Render main content
-    render_multihop_content(f, chunks[2], app);
+    render_multihop_content(f, chunks[2], app, state);
 
     // Render status bar
     render_status_bar(f, &app.state, chunks[3]);
 
     // Render modal if shown
-    let state = get_multihop_screen_state();
     if state.show_confirmation {
         if let Some(modal_state) = &state.modal_state {
             render_modal(f, modal_state, size);
@@ -589,7 +636,7 @@ pub fn render_multihop(f: &mut Frame, app: &App) {
 }
 
 /// Render the main multi-hop content area
-fn render_multihop_content(f: &mut Frame, area: Rect, app: &App) {
+fn render_multihop_content(f: &mut Frame, area: Rect, app: &App, state: &mut MultiHopScreenState) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -598,12 +645,12 @@ fn render_multihop_content(f: &mut Frame, area: Rect, app: &App) {
         ])
         .split(area);
 
-    render_route_builder(f, chunks[0], app);
-    render_route_analysis(f, chunks[1], app);
+    render_route_builder(f, chunks[0], app, state);
+    render_route_analysis(f, chunks[1], app, state);
 }
 
 /// Render the route builder panel
-fn render_route_builder(f: &mut Frame, area: Rect, _app: &App) {
+fn render_route_builder(f: &mut Frame, area: Rect, _app: &App, state: &mut MultiHopScreenState) {
     let block = Block::default()
         .title("Route Builder")
         .borders(Borders::ALL)
@@ -625,8 +672,6 @@ fn render_route_builder(f: &mut Frame, area: Rect, _app: &App) {
         ])
         .split(inner);
 
-    let state = get_multihop_screen_state();
-
     // From token dropdown
     let from_style = if matches!(state.input_focus, MultiHopInputFocus::FromToken) {
         Style::default()
@@ -721,7 +766,11 @@ fn render_route_builder(f: &mut Frame, area: Rect, _app: &App) {
         "Fill all fields to add hop"
     };
     let add_button = Paragraph::new(add_button_text)
-        .block(Block::default().borders(Borders::ALL).title("Add Hop"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Add Hop (r: find best route)"),
+        )
         .style(add_style)
         .alignment(Alignment::Center);
     f.render_widget(add_button, chunks[4]);
@@ -739,7 +788,7 @@ fn render_route_builder(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render the route analysis panel
-fn render_route_analysis(f: &mut Frame, area: Rect, app: &App) {
+fn render_route_analysis(f: &mut Frame, area: Rect, app: &App, state: &mut MultiHopScreenState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -748,14 +797,12 @@ fn render_route_analysis(f: &mut Frame, area: Rect, app: &App) {
         ])
         .split(area);
 
-    render_route_list(f, chunks[0], app);
-    render_analysis_summary(f, chunks[1], app);
+    render_route_list(f, chunks[0], app, state);
+    render_analysis_summary(f, chunks[1], app, state);
 }
 
 /// Render the current route list
-fn render_route_list(f: &mut Frame, area: Rect, _app: &App) {
-    let state = get_multihop_screen_state();
-
+fn render_route_list(f: &mut Frame, area: Rect, _app: &App, state: &mut MultiHopScreenState) {
     let list_style = if matches!(state.input_focus, MultiHopInputFocus::RouteList) {
         Style::default().fg(Color::Yellow)
     } else {
@@ -828,9 +875,7 @@ fn render_route_list(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render the analysis summary panel
-fn render_analysis_summary(f: &mut Frame, area: Rect, app: &App) {
-    let state = get_multihop_screen_state();
-
+fn render_analysis_summary(f: &mut Frame, area: Rect, app: &App, state: &mut MultiHopScreenState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -981,14 +1026,16 @@ fn render_analysis_summary(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Handle input for the multi-hop screen
-pub fn handle_multihop_screen_input(input: InputRequest) -> bool {
-    let state = get_multihop_screen_state();
-    state.handle_input(input)
+pub fn handle_multihop_screen_input(
+    app_state: &mut crate::tui::app::AppState,
+    input: InputRequest,
+) -> bool {
+    app_state.multihop_screen_state.handle_input(input)
 }
 
 /// Handle navigation for the multi-hop screen
-pub fn handle_multihop_screen_navigation(next: bool) {
-    let state = get_multihop_screen_state();
+pub fn handle_multihop_screen_navigation(app_state: &mut crate::tui::app::AppState, next: bool) {
+    let state = &mut app_state.multihop_screen_state;
     if next {
         state.next_focus();
     } else {
@@ -997,8 +1044,8 @@ pub fn handle_multihop_screen_navigation(next: bool) {
 }
 
 /// Handle Enter key for the multi-hop screen actions
-pub fn handle_multihop_screen_action() -> Option<String> {
-    let state = get_multihop_screen_state();
+pub fn handle_multihop_screen_action(app_state: &mut crate::tui::app::AppState) -> Option<String> {
+    let state = &mut app_state.multihop_screen_state;
 
     match state.input_focus {
         MultiHopInputFocus::AddHop => {
@@ -1030,8 +1077,10 @@ pub fn handle_multihop_screen_action() -> Option<String> {
 }
 
 /// Execute the multi-hop swap with confirmation
-pub fn execute_multihop_swap_with_confirmation() -> Option<Vec<SwapOperation>> {
-    let state = get_multihop_screen_state();
+pub fn execute_multihop_swap_with_confirmation(
+    app_state: &mut crate::tui::app::AppState,
+) -> Option<Vec<SwapOperation>> {
+    let state = &mut app_state.multihop_screen_state;
     if state.validate_route() {
         let operations = state.get_swap_operations();
         state.hide_confirmation_modal();
@@ -1042,8 +1091,11 @@ pub fn execute_multihop_swap_with_confirmation() -> Option<Vec<SwapOperation>> {
 }
 
 /// Handle confirmation response for multi-hop execution
-pub fn handle_multihop_confirmation_response(confirmed: bool) -> bool {
-    let state = get_multihop_screen_state();
+pub fn handle_multihop_confirmation_response(
+    app_state: &mut crate::tui::app::AppState,
+    confirmed: bool,
+) -> bool {
+    let state = &mut app_state.multihop_screen_state;
     if confirmed {
         // Execute the multi-hop swap
         state.hide_confirmation_modal();
@@ -1055,8 +1107,8 @@ pub fn handle_multihop_confirmation_response(confirmed: bool) -> bool {
 }
 
 /// Reset the multi-hop form
-pub fn reset_multihop_form() {
-    let state = get_multihop_screen_state();
+pub fn reset_multihop_form(app_state: &mut crate::tui::app::AppState) {
+    let state = &mut app_state.multihop_screen_state;
     state.clear_route();
     state.from_token_dropdown.clear_selection();
     state.to_token_dropdown.clear_selection();
@@ -3,12 +3,23 @@
 //! This module provides the pools view for the MANTRA DEX SDK TUI,
 //! displaying pool listings, details, search functionality, and status indicators.
 
+use crate::compare::PoolComparison;
+use crate::ownership::PoolOwnership;
+use crate::price_history::PriceCandle;
+use crate::risk::{RiskLevel, RiskReport};
 use crate::tui::{
-    app::{App, LoadingState, PoolCacheEntry},
+    app::{App, LoadingState, NavigationMode, PoolCacheEntry},
     components::{
-        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+        charts::render_price_chart,
+        header::render_header,
+        navigation::render_navigation,
+        status_bar::render_status_bar,
+        tables::{render_data_table, DataTableState, SortKey, TableColumn, TableExport, TableRow},
     },
+    events::UiEvent,
+    screens::Screen,
 };
+use cosmwasm_std::{Decimal, Uint128};
 use mantra_dex_std::pool_manager::PoolInfoResponse;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -18,6 +29,13 @@ use ratatui::{
     Frame,
 };
 use std::collections::HashMap;
+use std::time::Duration;
+
+/// How far back the details panel charts a pool's recorded price
+const PRICE_CHART_WINDOW_SECS: i64 = 86_400;
+
+/// Length of each candle of the details panel's price chart
+const PRICE_CHART_CANDLE: Duration = Duration::from_secs(900);
 
 /// Pool sorting criteria
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,9 +70,27 @@ pub struct PoolDisplayData {
     pub pool_id: String,
     pub asset_pair: String,
     pub tvl: String,
+    /// Unformatted TVL, used for sorting
+    pub tvl_amount: u128,
     pub apy: String,
     pub status: PoolDisplayStatus,
     pub pool_info: PoolInfoResponse,
+    /// Risk screening result, `None` until screening finishes
+    pub risk: Option<RiskReport>,
+    /// Whether the pool is one of the user's favorites
+    pub favorite: bool,
+}
+
+impl PoolDisplayData {
+    /// Risk column text and color
+    fn risk_display(&self) -> (&'static str, Color) {
+        match self.risk.as_ref().map(RiskReport::level) {
+            None => ("…", Color::Gray),
+            Some(None) => ("OK", Color::Green),
+            Some(Some(RiskLevel::Caution)) => (RiskLevel::Caution.label(), Color::Yellow),
+            Some(Some(RiskLevel::Danger)) => (RiskLevel::Danger.label(), Color::Red),
+        }
+    }
 }
 
 /// Pool status for display purposes
@@ -83,8 +119,173 @@ impl PoolDisplayStatus {
     }
 }
 
+impl TableRow for PoolDisplayData {
+    fn cell(&self, column: usize) -> Cell<'_> {
+        match column {
+            0 if self.favorite => {
+                Cell::from(format!("★ {}", self.pool_id)).style(Style::default().fg(Color::Yellow))
+            }
+            0 => Cell::from(self.pool_id.as_str()),
+            1 => Cell::from(self.asset_pair.as_str()),
+            2 => Cell::from(self.tvl.as_str()),
+            3 => Cell::from(self.status.display_text()).style(
+                Style::default()
+                    .fg(self.status.color())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            4 => {
+                let (text, color) = self.risk_display();
+                Cell::from(text).style(Style::default().fg(color))
+            }
+            _ => Cell::from(determine_pool_type(&self.pool_info.pool_info.pool_type)),
+        }
+    }
+
+    fn sort_key(&self, column: usize) -> SortKey {
+        match column {
+            0 => self
+                .pool_id
+                .parse()
+                .map(SortKey::Number)
+                .unwrap_or_else(|_| SortKey::Text(self.pool_id.clone())),
+            1 => SortKey::Text(self.asset_pair.to_lowercase()),
+            2 => SortKey::Number(self.tvl_amount),
+            3 => SortKey::Text(self.status.display_text().to_string()),
+            4 => SortKey::Number(match self.risk.as_ref().map(RiskReport::level) {
+                None => 0,
+                Some(None) => 1,
+                Some(Some(RiskLevel::Caution)) => 2,
+                Some(Some(RiskLevel::Danger)) => 3,
+            }),
+            _ => {
+                SortKey::Text(determine_pool_type(&self.pool_info.pool_info.pool_type).to_string())
+            }
+        }
+    }
+
+    fn export_value(&self, column: usize) -> String {
+        match column {
+            0 => self.pool_id.clone(),
+            1 => self.asset_pair.clone(),
+            2 => self.tvl_amount.to_string(),
+            3 => self.status.display_text().to_string(),
+            4 => self.risk_display().0.to_string(),
+            _ => determine_pool_type(&self.pool_info.pool_info.pool_type).to_string(),
+        }
+    }
+}
+
+/// Pools screen state
+///
+/// Display rows are rebuilt only when the pool cache changes, not on every frame.
+#[derive(Debug, Clone)]
+pub struct PoolsScreenState {
+    /// Pool table selection, sorting and columns
+    pub table: DataTableState,
+    rows: Vec<PoolDisplayData>,
+    /// Pool count, newest cache time and risk report count the rows were built from
+    synced_with: (usize, Option<chrono::DateTime<chrono::Utc>>, usize),
+    /// Favorite pools the rows were built with
+    synced_favorites: Vec<String>,
+    /// Pools marked for a side-by-side comparison, in the order marked
+    pub compare_selection: Vec<String>,
+    /// Comparison of the marked pools, shown in place of the pool list while open
+    pub comparison: Option<ComparisonView>,
+}
+
+/// State of the pool comparison view
+#[derive(Debug, Clone)]
+pub enum ComparisonView {
+    Loading,
+    Loaded(Vec<PoolComparison>),
+    Failed(String),
+}
+
+impl Default for PoolsScreenState {
+    fn default() -> Self {
+        Self {
+            table: DataTableState::new(vec![
+                TableColumn::new("Pool ID", Constraint::Length(8)),
+                TableColumn::new("Asset Pair", Constraint::Percentage(40)),
+                TableColumn::new("TVL", Constraint::Percentage(25)),
+                TableColumn::new("Status", Constraint::Length(12)),
+                TableColumn::new("Risk", Constraint::Length(8)),
+                TableColumn::new("Type", Constraint::Length(16)).hidden(),
+            ]),
+            rows: Vec::new(),
+            synced_with: (0, None, 0),
+            synced_favorites: Vec::new(),
+            compare_selection: Vec::new(),
+            comparison: None,
+        }
+    }
+}
+
+impl PoolsScreenState {
+    /// Rebuild the display rows if the pool cache, risk reports or favorites
+    /// changed since the last sync
+    pub fn sync(
+        &mut self,
+        pool_cache: &HashMap<String, PoolCacheEntry>,
+        pool_risks: &HashMap<String, RiskReport>,
+        favorites: &[String],
+    ) {
+        let signature = (
+            pool_cache.len(),
+            pool_cache.values().map(|entry| entry.cached_at).max(),
+            pool_risks.len(),
+        );
+        if signature != self.synced_with || favorites != self.synced_favorites.as_slice() {
+            self.rows = prepare_pool_display_data(pool_cache, pool_risks, favorites);
+            self.synced_with = signature;
+            self.synced_favorites = favorites.to_vec();
+            self.table.invalidate();
+        }
+    }
+
+    /// Pool under the table cursor
+    pub fn highlighted_pool(&self) -> Option<&PoolDisplayData> {
+        self.table
+            .selected_row()
+            .and_then(|index| self.rows.get(index))
+    }
+
+    /// The pool table as shown, for writing to a file
+    pub fn export(&mut self) -> TableExport {
+        self.table.export("pools", &self.rows)
+    }
+
+    /// Mark the highlighted pool for comparison, or unmark it
+    ///
+    /// Returns the pool and whether it is now marked.
+    pub fn toggle_compare_highlighted(&mut self) -> Option<(String, bool)> {
+        let pool_id = self.highlighted_pool()?.pool_id.clone();
+        let marked = match self.compare_selection.iter().position(|id| *id == pool_id) {
+            Some(index) => {
+                self.compare_selection.remove(index);
+                false
+            }
+            None => {
+                self.compare_selection.push(pool_id.clone());
+                true
+            }
+        };
+        Some((pool_id, marked))
+    }
+}
+
+impl Screen for PoolsScreenState {
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool {
+        navigation_mode == NavigationMode::WithinScreen && self.table.handle_event(event)
+    }
+
+    fn render(&mut self, f: &mut Frame, app: &App) {
+        render_pools(f, app, self);
+    }
+}
+
 /// Render the complete pools screen
-pub fn render_pools(f: &mut Frame, app: &App) {
+pub fn render_pools(f: &mut Frame, app: &App, state: &mut PoolsScreenState) {
     let size = f.area();
 
     // Create main layout: header, nav, content, status
@@ -103,14 +304,24 @@ pub fn render_pools(f: &mut Frame, app: &App) {
     render_navigation(f, &app.state, chunks[1]);
 
     // Render pools content
-    render_pools_content(f, chunks[2], app);
+    state.sync(
+        &app.state.pool_cache,
+        &app.state.pool_risks,
+        &app.preferences().favorite_pools,
+    );
+    render_pools_content(f, chunks[2], app, state);
 
     // Render status bar
     render_status_bar(f, &app.state, chunks[3]);
 }
 
 /// Render the main pools content area
-fn render_pools_content(f: &mut Frame, area: Rect, app: &App) {
+fn render_pools_content(f: &mut Frame, area: Rect, app: &App, state: &mut PoolsScreenState) {
+    if let Some(view) = &state.comparison {
+        render_pool_comparison(f, area, view);
+        return;
+    }
+
     // Create horizontal layout: pools list | pool details
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -130,8 +341,8 @@ fn render_pools_content(f: &mut Frame, area: Rect, app: &App) {
     // Render components
     render_pool_search(f, left_chunks[0], app);
     render_pool_filters(f, left_chunks[1], app);
-    render_pool_list_table(f, left_chunks[2], app);
-    render_pool_details_panel(f, main_chunks[1], app);
+    render_pool_list_table(f, left_chunks[2], app, state);
+    render_pool_details_panel(f, main_chunks[1], app, state);
 }
 
 /// Render the pool search bar
@@ -179,70 +390,161 @@ fn render_pool_filters(f: &mut Frame, area: Rect, _app: &App) {
 }
 
 /// Render the pool list table
-fn render_pool_list_table(f: &mut Frame, area: Rect, app: &App) {
-    // Prepare pool data
-    let pool_data = prepare_pool_display_data(&app.state.pool_cache);
-
-    if pool_data.is_empty() {
+fn render_pool_list_table(f: &mut Frame, area: Rect, app: &App, state: &mut PoolsScreenState) {
+    if state.rows.is_empty() {
         render_empty_pool_list(f, area, app);
         return;
     }
 
-    let header = Row::new(vec![
-        Cell::from("Pool ID").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Asset Pair").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("TVL").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Status").style(Style::default().add_modifier(Modifier::BOLD)),
-    ])
-    .style(Style::default().bg(Color::DarkGray));
+    let is_focused = app.state.navigation_mode == NavigationMode::WithinScreen;
+    let title = if state.compare_selection.is_empty() {
+        "Pools".to_string()
+    } else {
+        format!(
+            "Pools - comparing {} (v: view)",
+            state.compare_selection.join(", ")
+        )
+    };
+    render_data_table(f, area, &title, &state.rows, &mut state.table, is_focused);
+}
 
-    let rows: Vec<Row> = pool_data
-        .iter()
-        .enumerate()
-        .map(|(index, pool)| {
-            let style = if app.state.selected_pool_id == Some(pool.pool_id.parse().unwrap_or(0)) {
-                Style::default().bg(Color::Blue).fg(Color::White)
-            } else if index % 2 == 0 {
-                Style::default()
-            } else {
-                Style::default().bg(Color::DarkGray)
-            };
-
-            Row::new(vec![
-                Cell::from(pool.pool_id.clone()),
-                Cell::from(pool.asset_pair.clone()),
-                Cell::from(pool.tvl.clone()),
-                Cell::from(pool.status.display_text()).style(
-                    Style::default()
-                        .fg(pool.status.color())
-                        .add_modifier(Modifier::BOLD),
-                ),
-            ])
-            .style(style)
-        })
-        .collect();
+/// Render the marked pools side by side, one column per pool
+fn render_pool_comparison(f: &mut Frame, area: Rect, view: &ComparisonView) {
+    let block = Block::default()
+        .title("Pool Comparison (v/Esc: back)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
 
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Length(8),      // Pool ID
-            Constraint::Percentage(40), // Asset Pair
-            Constraint::Percentage(25), // TVL
-            Constraint::Length(12),     // Status
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Blue))
-            .title(format!("Pools ({} total)", pool_data.len())),
-    )
-    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let comparisons = match view {
+        ComparisonView::Loaded(comparisons) if !comparisons.is_empty() => comparisons,
+        ComparisonView::Loaded(_) => {
+            render_no_pool_details(f, area, "No pools to compare");
+            return;
+        }
+        ComparisonView::Loading => {
+            let loading = Paragraph::new("Comparing pools...")
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(loading, area);
+            return;
+        }
+        ComparisonView::Failed(error) => {
+            let failed = Paragraph::new(format!("Comparison failed: {}", error))
+                .style(Style::default().fg(Color::Red))
+                .wrap(Wrap { trim: true })
+                .block(block);
+            f.render_widget(failed, area);
+            return;
+        }
+    };
 
+    let quote = &comparisons[0].quote;
+    let best_apr = comparisons.iter().map(PoolComparison::total_apr).max();
+    let amount = |amount: Option<Uint128>| {
+        amount.map_or_else(
+            || "-".to_string(),
+            |amount| format_large_number(&amount.to_string()),
+        )
+    };
+    let metric = |label: String, cells: Vec<Cell<'static>>| {
+        let mut row = vec![Cell::from(label).style(Style::default().fg(Color::Gray))];
+        row.extend(cells);
+        Row::new(row)
+    };
+    let cells = |value: &dyn Fn(&PoolComparison) -> String| -> Vec<Cell<'static>> {
+        comparisons
+            .iter()
+            .map(|comparison| Cell::from(value(comparison)))
+            .collect()
+    };
+
+    let rows = vec![
+        metric(
+            "Assets".to_string(),
+            cells(&|c| create_asset_pair_string(&c.assets)),
+        ),
+        metric("Swap fee".to_string(), cells(&|c| percent(c.swap_fee))),
+        metric("Total fee".to_string(), cells(&|c| percent(c.total_fee))),
+        metric(format!("TVL ({})", quote), cells(&|c| amount(c.tvl))),
+        metric(
+            format!("24h volume ({})", quote),
+            cells(&|c| amount(c.volume_24h)),
+        ),
+        metric("Fee APR".to_string(), cells(&|c| percent(c.fee_apr))),
+        metric(
+            "Incentive APR".to_string(),
+            cells(&|c| percent(c.incentive_apr)),
+        ),
+        metric(
+            "Total APR".to_string(),
+            comparisons
+                .iter()
+                .map(|comparison| {
+                    let style = if Some(comparison.total_apr()) == best_apr {
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+                    Cell::from(percent(comparison.total_apr())).style(style)
+                })
+                .collect(),
+        ),
+        metric(
+            "Price".to_string(),
+            cells(&|c| {
+                c.price
+                    .map_or_else(|| "-".to_string(), |price| price.to_string())
+            }),
+        ),
+        metric(
+            format!("My position ({})", quote),
+            cells(&|c| match &c.position {
+                Some(position) => {
+                    format!("{} ({})", amount(position.value), percent(position.share))
+                }
+                None => "-".to_string(),
+            }),
+        ),
+    ];
+
+    let mut header = vec![Cell::from("")];
+    header.extend(
+        comparisons
+            .iter()
+            .map(|comparison| Cell::from(format!("Pool {}", comparison.pool_id))),
+    );
+    let mut widths = vec![Constraint::Length(22)];
+    widths.extend(
+        comparisons
+            .iter()
+            .map(|_| Constraint::Ratio(1, comparisons.len() as u32)),
+    );
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(header).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .block(block);
     f.render_widget(table, area);
 }
 
+/// A fraction as a percentage with two decimals
+fn percent(fraction: Decimal) -> String {
+    let basis_points = (fraction * Decimal::from_ratio(10_000u128, 1u128)).to_uint_floor();
+    format!(
+        "{}.{:02}%",
+        basis_points / Uint128::new(100),
+        (basis_points % Uint128::new(100)).u128()
+    )
+}
+
 /// Render empty pool list message
 fn render_empty_pool_list(f: &mut Frame, area: Rect, app: &App) {
     let message = match app.state.loading_state {
@@ -271,11 +573,46 @@ fn render_empty_pool_list(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(empty_msg, area);
 }
 
+/// Title and candles of the price of `pool_id`'s first asset recorded over the
+/// chart window, `None` until there are two candles to draw a line between
+fn pool_price_chart(app: &App, pool_id: &str) -> Option<(String, Vec<PriceCandle>)> {
+    let history = app.state.price_history.as_ref()?;
+    let series = history.series(pool_id)?;
+    let to = chrono::Utc::now();
+    let from = to - chrono::Duration::seconds(PRICE_CHART_WINDOW_SECS);
+    let candles = history
+        .candles(pool_id, &series.base, from, to, PRICE_CHART_CANDLE)
+        .ok()?;
+    let title = format!(
+        "{} in {} (24h)",
+        app.denom_to_symbol(&series.base),
+        app.denom_to_symbol(&series.quote)
+    );
+    (candles.len() >= 2).then_some((title, candles))
+}
+
 /// Render the pool details panel
-fn render_pool_details_panel(f: &mut Frame, area: Rect, app: &App) {
-    if let Some(pool_id) = app.state.selected_pool_id {
-        if let Some(pool_cache_entry) = app.state.pool_cache.get(&pool_id.to_string()) {
-            render_selected_pool_details(f, area, &pool_cache_entry.pool_info);
+fn render_pool_details_panel(f: &mut Frame, area: Rect, app: &App, state: &PoolsScreenState) {
+    if let Some(pool) = state.highlighted_pool() {
+        render_selected_pool_details(
+            f,
+            area,
+            &pool.pool_info,
+            pool.risk.as_ref(),
+            app.state.pool_ownership.get(&pool.pool_id),
+            pool_price_chart(app, &pool.pool_id),
+        );
+    } else if let Some(pool_id) = app.state.selected_pool_id {
+        let pool_id = pool_id.to_string();
+        if let Some(pool_cache_entry) = app.state.pool_cache.get(&pool_id) {
+            render_selected_pool_details(
+                f,
+                area,
+                &pool_cache_entry.pool_info,
+                app.state.pool_risks.get(&pool_id),
+                app.state.pool_ownership.get(&pool_id),
+                pool_price_chart(app, &pool_id),
+            );
         } else {
             render_no_pool_details(f, area, "Pool details not available");
         }
@@ -285,20 +622,133 @@ fn render_pool_details_panel(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render details for the selected pool
-fn render_selected_pool_details(f: &mut Frame, area: Rect, pool_info: &PoolInfoResponse) {
+fn render_selected_pool_details(
+    f: &mut Frame,
+    area: Rect,
+    pool_info: &PoolInfoResponse,
+    risk: Option<&RiskReport>,
+    ownership: Option<&PoolOwnership>,
+    price_chart: Option<(String, Vec<PriceCandle>)>,
+) {
+    let warnings = risk.map(RiskReport::warnings).unwrap_or_default();
+
     // Split details panel into sections
     let detail_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(8),  // Basic info
             Constraint::Length(10), // Assets composition
+            Constraint::Length(match price_chart {
+                Some(_) => 10,
+                None => 0,
+            }), // Recorded prices
+            Constraint::Length(match ownership {
+                Some(_) => 6,
+                None => 0,
+            }), // Ownership
+            Constraint::Length(if warnings.is_empty() {
+                0
+            } else {
+                warnings.len() as u16 + 2
+            }), // Risk warnings
             Constraint::Min(0),     // Fee structure and features
         ])
         .split(area);
 
     render_pool_basic_info(f, detail_chunks[0], pool_info);
     render_pool_composition(f, detail_chunks[1], pool_info);
-    render_pool_features(f, detail_chunks[2], pool_info);
+    if let Some((title, candles)) = &price_chart {
+        render_price_chart(f, candles, title, detail_chunks[2]);
+    }
+    if let Some(ownership) = ownership {
+        render_pool_ownership(f, detail_chunks[3], ownership);
+    }
+    if let Some(level) = risk.and_then(RiskReport::level) {
+        render_pool_risk_warnings(f, detail_chunks[4], level, &warnings);
+    }
+    render_pool_features(f, detail_chunks[5], pool_info);
+}
+
+/// Render who created the pool and how concentrated its LP tokens are
+fn render_pool_ownership(f: &mut Frame, area: Rect, ownership: &PoolOwnership) {
+    let unknown = || "unknown".to_string();
+    let created = match (ownership.created_height, ownership.created_at) {
+        (Some(height), Some(at)) => format!("block {} ({})", height, at.format("%Y-%m-%d")),
+        (Some(height), None) => format!("block {}", height),
+        _ => unknown(),
+    };
+    let fraction = |share: Decimal| share.to_string().parse::<f64>().unwrap_or(0.0);
+    let (holders, concentration) = match &ownership.lp_concentration {
+        Some(concentration) => (
+            format!(
+                "{} holders, largest {:.1}%",
+                concentration.holders,
+                fraction(concentration.largest_share()) * 100.0
+            ),
+            format!(
+                "top {} {:.1}%, Gini {:.2}",
+                concentration.top_holders.len(),
+                fraction(concentration.top_share()) * 100.0,
+                fraction(concentration.gini)
+            ),
+        ),
+        None => (unknown(), unknown()),
+    };
+
+    let line = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::White)),
+            Span::styled(value, Style::default().fg(Color::Cyan)),
+        ])
+    };
+    let content = vec![
+        line(
+            "Creator: ",
+            ownership.creator.clone().unwrap_or_else(unknown),
+        ),
+        line("Created: ", created),
+        line("LP: ", holders),
+        line("Concentration: ", concentration),
+    ];
+
+    let block = Block::default()
+        .title("Ownership")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue));
+
+    let paragraph = Paragraph::new(Text::from(content))
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
+
+/// Render the red flags raised by the pool's risk screening
+fn render_pool_risk_warnings(f: &mut Frame, area: Rect, level: RiskLevel, warnings: &[String]) {
+    let color = match level {
+        RiskLevel::Caution => Color::Yellow,
+        RiskLevel::Danger => Color::Red,
+    };
+    let content: Vec<Line> = warnings
+        .iter()
+        .map(|warning| {
+            Line::from(Span::styled(
+                format!("⚠ {}", warning),
+                Style::default().fg(color),
+            ))
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(format!("Risk: {}", level.label()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color));
+
+    let paragraph = Paragraph::new(Text::from(content))
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
 }
 
 /// Render basic pool information
@@ -474,12 +924,17 @@ fn render_no_pool_details(f: &mut Frame, area: Rect, message: &str) {
 }
 
 /// Prepare pool data for display in the table
-fn prepare_pool_display_data(pool_cache: &HashMap<String, PoolCacheEntry>) -> Vec<PoolDisplayData> {
+fn prepare_pool_display_data(
+    pool_cache: &HashMap<String, PoolCacheEntry>,
+    pool_risks: &HashMap<String, RiskReport>,
+    favorites: &[String],
+) -> Vec<PoolDisplayData> {
     let mut pools: Vec<PoolDisplayData> = pool_cache
         .values()
         .map(|cache_entry| {
             let pool_info = &cache_entry.pool_info;
             let asset_pair = create_asset_pair_string(&pool_info.pool_info.assets);
+            let tvl_amount = total_pool_assets(&pool_info.pool_info.assets);
             let tvl = calculate_pool_tvl(&pool_info.pool_info.assets);
             let status = determine_pool_status(&pool_info.pool_info.status);
 
@@ -487,19 +942,26 @@ fn prepare_pool_display_data(pool_cache: &HashMap<String, PoolCacheEntry>) -> Ve
                 pool_id: pool_info.pool_info.pool_identifier.to_string(),
                 asset_pair,
                 tvl,
+                tvl_amount,
                 apy: "N/A".to_string(), // APY calculation would require historical data
                 status,
+                risk: pool_risks
+                    .get(&pool_info.pool_info.pool_identifier)
+                    .cloned(),
+                favorite: favorites.contains(&pool_info.pool_info.pool_identifier),
                 pool_info: pool_info.clone(),
             }
         })
         .collect();
 
-    // Sort by pool ID by default
+    // Favorites first, then by pool ID
     pools.sort_by(|a, b| {
-        a.pool_id
-            .parse::<u64>()
-            .unwrap_or(0)
-            .cmp(&b.pool_id.parse::<u64>().unwrap_or(0))
+        b.favorite.cmp(&a.favorite).then_with(|| {
+            a.pool_id
+                .parse::<u64>()
+                .unwrap_or(0)
+                .cmp(&b.pool_id.parse::<u64>().unwrap_or(0))
+        })
     });
 
     pools
@@ -538,7 +1000,7 @@ fn calculate_pool_tvl(assets: &[cosmwasm_std::Coin]) -> String {
     }
 
     // Simplified TVL calculation - in reality, this would require price data
-    let total_assets: u128 = assets.iter().map(|asset| asset.amount.u128()).sum();
+    let total_assets = total_pool_assets(assets);
 
     if total_assets == 0 {
         "0".to_string()
@@ -547,6 +1009,13 @@ fn calculate_pool_tvl(assets: &[cosmwasm_std::Coin]) -> String {
     }
 }
 
+/// Sum of the pool's reserves across assets
+fn total_pool_assets(assets: &[cosmwasm_std::Coin]) -> u128 {
+    assets.iter().fold(0u128, |total, asset| {
+        total.saturating_add(asset.amount.u128())
+    })
+}
+
 /// Determine pool status based on enabled operations
 fn determine_pool_status(status: &mantra_dex_std::pool_manager::PoolStatus) -> PoolDisplayStatus {
     let enabled_count = [
@@ -1,8 +1,13 @@
 //! Rewards Screen Implementation
 //!
 //! This module provides the rewards view for the MANTRA DEX SDK TUI,
-//! displaying claimable rewards, claim interface, rewards history, and epoch timeline.
+//! displaying farm alerts, claimable rewards, farm positions, claim interface,
+//! rewards history, scheduled transactions and the epoch calendar.
 
+use crate::alerts::{self, EmissionRates, FarmAlert, DEFAULT_CLOSING_EPOCHS};
+use crate::epoch::{self, EpochCalendar, DEFAULT_CALENDAR_EPOCHS};
+use crate::positions::FarmPosition;
+use crate::scheduler::{ScheduleStatus, ScheduledTx};
 use crate::tui::{
     app::{App, LoadingState},
     components::{
@@ -10,15 +15,19 @@ use crate::tui::{
     },
 };
 use cosmwasm_std::Uint128;
+use mantra_dex_std::farm_manager::{Farm, Position};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Padding, Paragraph, Row, Table, Wrap},
     Frame,
 };
 use std::collections::HashMap;
 
+/// Alerts shown in the banner at most; the title counts all of them
+const MAX_BANNER_ALERTS: usize = 3;
+
 /// Current rewards screen mode
 #[derive(Debug, Clone, PartialEq)]
 pub enum RewardsMode {
@@ -37,6 +46,20 @@ pub struct RewardsState {
     pub show_claim_confirmation: bool,
     pub table_selected: usize,
     pub history_scroll: usize,
+    /// Transactions in the schedule file, oldest first
+    pub scheduled: Vec<ScheduledTx>,
+    /// Highlighted row of the scheduled transactions panel
+    pub scheduled_selected: usize,
+    /// Epoch timing, once loaded from the epoch manager
+    pub epoch_calendar: Option<EpochCalendar>,
+    /// Farms of the farm manager, for their start and end epochs
+    pub farms: Vec<Farm>,
+    /// Farm lifecycle alerts shown as a banner
+    pub alerts: Vec<FarmAlert>,
+    /// Emission rates of the previous refresh, to spot changes
+    pub emission_rates: Option<EmissionRates>,
+    /// Farm positions of the active wallet, open and closed
+    pub farm_positions: Vec<FarmPosition>,
 }
 
 impl Default for RewardsState {
@@ -48,7 +71,68 @@ impl Default for RewardsState {
             show_claim_confirmation: false,
             table_selected: 0,
             history_scroll: 0,
+            scheduled: Vec::new(),
+            scheduled_selected: 0,
+            epoch_calendar: None,
+            farms: Vec::new(),
+            alerts: Vec::new(),
+            emission_rates: None,
+            farm_positions: Vec::new(),
+        }
+    }
+}
+
+impl RewardsState {
+    /// Replace the scheduled transactions, keeping the highlight in range
+    pub fn set_scheduled(&mut self, scheduled: Vec<ScheduledTx>) {
+        self.scheduled = scheduled;
+        self.scheduled_selected = self
+            .scheduled_selected
+            .min(self.scheduled.len().saturating_sub(1));
+    }
+
+    /// Move the scheduled transaction highlight by `delta` rows
+    pub fn move_scheduled_selection(&mut self, delta: isize) {
+        let last = self.scheduled.len().saturating_sub(1);
+        self.scheduled_selected = self
+            .scheduled_selected
+            .saturating_add_signed(delta)
+            .min(last);
+    }
+
+    /// Re-evaluate the farm alerts for the loaded farms and the wallet's open `positions`
+    ///
+    /// Emission rate changes are reported against the previous call, and stay in
+    /// the banner until the rate changes again.
+    pub fn update_farm_alerts(&mut self, positions: &[Position], current_epoch: u64) {
+        let mut alerts = alerts::farm_alerts(
+            &self.farms,
+            positions,
+            current_epoch,
+            self.emission_rates.as_ref(),
+            DEFAULT_CLOSING_EPOCHS,
+        );
+        // Keep showing rate changes spotted earlier that are still current
+        for alert in &self.alerts {
+            if let FarmAlert::EmissionRateChanged {
+                farm_id, current, ..
+            } = alert
+            {
+                let still_current = self.farms.iter().any(|farm| {
+                    &farm.identifier == farm_id && farm.emission_rate == current.amount
+                });
+                if still_current && !alerts.contains(alert) {
+                    alerts.push(alert.clone());
+                }
+            }
         }
+        self.alerts = alerts;
+        self.emission_rates = Some(alerts::emission_rates(&self.farms));
+    }
+
+    /// The highlighted scheduled transaction
+    pub fn selected_scheduled(&self) -> Option<&ScheduledTx> {
+        self.scheduled.get(self.scheduled_selected)
     }
 }
 
@@ -80,6 +164,21 @@ pub fn render_rewards(f: &mut Frame, app: &App) {
 
 /// Render the main rewards content area
 fn render_rewards_content(f: &mut Frame, area: Rect, app: &App) {
+    let alerts = &app.state.rewards_state.alerts;
+    let area = if alerts.is_empty() {
+        area
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(alerts.len().min(MAX_BANNER_ALERTS) as u16 + 2),
+                Constraint::Min(0),
+            ])
+            .split(area);
+        render_farm_alerts(f, chunks[0], alerts);
+        chunks[1]
+    };
+
     // Create a 2x2 grid layout for the rewards screen
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -88,19 +187,54 @@ fn render_rewards_content(f: &mut Frame, area: Rect, app: &App) {
 
     let top_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .constraints([
+            Constraint::Percentage(40),
+            Constraint::Percentage(35),
+            Constraint::Percentage(25),
+        ])
         .split(main_chunks[0]);
 
     let bottom_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+        ])
         .split(main_chunks[1]);
 
     // Render components
     render_rewards_dashboard(f, top_chunks[0], app);
-    render_claim_interface(f, top_chunks[1], app);
+    render_farm_positions(f, top_chunks[1], &app.state.rewards_state.farm_positions);
+    render_claim_interface(f, top_chunks[2], app);
     render_rewards_history(f, bottom_chunks[0], app);
-    render_epoch_timeline(f, bottom_chunks[1], app);
+    render_scheduled_transactions(f, bottom_chunks[1], app);
+    render_epoch_timeline(f, bottom_chunks[2], app);
+}
+
+/// Render the farm alerts banner
+fn render_farm_alerts(f: &mut Frame, area: Rect, alerts: &[FarmAlert]) {
+    let block = Block::default()
+        .title(format!("Farm Alerts ({})", alerts.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let lines: Vec<Line> = alerts
+        .iter()
+        .take(MAX_BANNER_ALERTS)
+        .map(|alert| {
+            let color = match alert {
+                FarmAlert::EmissionRateChanged { .. } => Color::Yellow,
+                _ => Color::Red,
+            };
+            Line::from(vec![
+                Span::styled("⚠ ", Style::default().fg(color)),
+                Span::styled(alert.to_string(), Style::default().fg(Color::White)),
+            ])
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(Text::from(lines)).block(block), area);
 }
 
 /// Render the rewards dashboard panel
@@ -234,6 +368,59 @@ fn render_claim_interface(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+/// Render the farm positions table
+fn render_farm_positions(f: &mut Frame, area: Rect, positions: &[FarmPosition]) {
+    let block = Block::default()
+        .title(format!("Farm Positions ({})", positions.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    if positions.is_empty() {
+        let paragraph = Paragraph::new("No farm positions")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let now = chrono::Utc::now();
+    let epoch = |epoch: Option<u64>| epoch.map_or_else(|| "-".to_string(), |e| e.to_string());
+    let header = Row::new(vec!["Position", "LP Tokens", "Status", "Start", "Unlock"]).style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    let rows = positions.iter().map(|position| {
+        let status = position.status(now);
+        let status_color = match status {
+            "open" => Color::Green,
+            "unlocking" => Color::Yellow,
+            _ => Color::Cyan,
+        };
+        Row::new(vec![
+            Cell::from(position.identifier.clone()),
+            Cell::from(position.lp_asset.amount.to_string()),
+            Cell::from(status).style(Style::default().fg(status_color)),
+            Cell::from(epoch(position.start_epoch)),
+            Cell::from(epoch(position.unlock_epoch)),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(17),
+            Constraint::Percentage(14),
+            Constraint::Percentage(14),
+        ],
+    )
+    .header(header)
+    .block(block);
+    f.render_widget(table, area);
+}
+
 /// Render the rewards history panel
 fn render_rewards_history(f: &mut Frame, area: Rect, _app: &App) {
     let block = Block::default()
@@ -283,81 +470,78 @@ fn render_rewards_history(f: &mut Frame, area: Rect, _app: &App) {
     f.render_widget(paragraph, area);
 }
 
-/// Render the epoch timeline panel
-fn render_epoch_timeline(f: &mut Frame, area: Rect, app: &App) {
+/// Render the scheduled transactions panel
+fn render_scheduled_transactions(f: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
-        .title("Epoch Timeline")
+        .title("Scheduled Transactions")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Magenta))
+        .border_style(Style::default().fg(Color::Blue))
         .padding(Padding::uniform(1));
 
-    let current_epoch = app.state.current_epoch.unwrap_or(0);
-
-    // Create a visual timeline of recent epochs
-    let mut content = vec![
-        Line::from(vec![Span::styled(
-            "Epoch Timeline:",
-            Style::default().fg(Color::Yellow),
-        )]),
-        Line::from(""),
-    ];
-
-    // Show last 10 epochs with current epoch highlighted
-    let start_epoch = if current_epoch >= 10 {
-        current_epoch - 9
+    let state = &app.state.rewards_state;
+    let mut lines: Vec<Line> = if state.scheduled.is_empty() {
+        vec![Line::from(Span::styled(
+            "No scheduled transactions",
+            Style::default().fg(Color::Gray),
+        ))]
     } else {
-        1
+        state
+            .scheduled
+            .iter()
+            .enumerate()
+            .map(|(index, tx)| scheduled_line(tx, index == state.scheduled_selected))
+            .collect()
     };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "e: claim when the next epoch starts | x: cancel",
+        Style::default().fg(Color::Gray),
+    )));
 
-    for epoch in start_epoch..=current_epoch {
-        let is_current = epoch == current_epoch;
-        let has_rewards = app.state.claimable_rewards.len() > 0; // Simplified check
+    let paragraph = Paragraph::new(Text::from(lines))
+        .block(block)
+        .wrap(Wrap { trim: true });
 
-        let epoch_line = if is_current {
-            Line::from(vec![
-                Span::styled("►", Style::default().fg(Color::Green)),
-                Span::styled(
-                    format!(" Epoch {}", epoch),
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(" (Current)", Style::default().fg(Color::Green)),
-            ])
-        } else {
-            let color = if has_rewards {
-                Color::Cyan
-            } else {
-                Color::Gray
-            };
-            Line::from(vec![
-                Span::styled("  ", Style::default()),
-                Span::styled(format!("Epoch {}", epoch), Style::default().fg(color)),
-                if has_rewards {
-                    Span::styled(" ✓", Style::default().fg(Color::Green))
-                } else {
-                    Span::styled("", Style::default())
-                },
-            ])
-        };
+    f.render_widget(paragraph, area);
+}
 
-        content.push(epoch_line);
+/// One row of the scheduled transactions panel
+fn scheduled_line(tx: &ScheduledTx, selected: bool) -> Line<'static> {
+    let status_color = match tx.status {
+        ScheduleStatus::Pending => Color::Yellow,
+        ScheduleStatus::Executed { .. } => Color::Green,
+        ScheduleStatus::Failed { .. } => Color::Red,
+        ScheduleStatus::Cancelled => Color::Gray,
+    };
+    let mut style = Style::default();
+    if selected {
+        style = style.add_modifier(Modifier::REVERSED);
     }
 
-    // Add legend
-    content.push(Line::from(""));
-    content.push(Line::from(vec![Span::styled(
-        "Legend:",
-        Style::default().fg(Color::White),
-    )]));
-    content.push(Line::from(vec![
-        Span::styled("► ", Style::default().fg(Color::Green)),
-        Span::styled("Current Epoch", Style::default().fg(Color::White)),
-    ]));
-    content.push(Line::from(vec![
-        Span::styled("✓ ", Style::default().fg(Color::Green)),
-        Span::styled("Has Rewards", Style::default().fg(Color::White)),
-    ]));
+    Line::from(vec![
+        Span::styled(format!("#{} ", tx.id), style.fg(Color::Cyan)),
+        Span::styled(format!("{} ", tx.action), style),
+        Span::styled(format!("at {} ", tx.trigger), style.fg(Color::Magenta)),
+        Span::styled(tx.status.to_string(), style.fg(status_color)),
+    ])
+}
+
+/// Render the epoch calendar panel
+fn render_epoch_timeline(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .title("Epoch Calendar")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .padding(Padding::uniform(1));
+
+    let state = &app.state.rewards_state;
+    let content = match &state.epoch_calendar {
+        Some(calendar) => epoch_calendar_lines(calendar, &state.farms, chrono::Utc::now()),
+        None => vec![Line::from(Span::styled(
+            "Epoch calendar not loaded, press F5 to refresh",
+            Style::default().fg(Color::Gray),
+        ))],
+    };
 
     let paragraph = Paragraph::new(Text::from(content))
         .block(block)
@@ -366,6 +550,62 @@ fn render_epoch_timeline(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+/// Countdown to the next epoch followed by the upcoming epochs and their farm milestones
+fn epoch_calendar_lines(
+    calendar: &EpochCalendar,
+    farms: &[Farm],
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Next epoch in ", Style::default().fg(Color::White)),
+            Span::styled(
+                epoch::format_countdown(calendar.time_until_next(now)),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    let entries = epoch::calendar(calendar, now, DEFAULT_CALENDAR_EPOCHS, farms);
+    for (index, entry) in entries.iter().enumerate() {
+        let (marker, style) = if index == 0 {
+            (
+                "► ",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            ("  ", Style::default().fg(Color::Cyan))
+        };
+        let mut spans = vec![
+            Span::styled(marker, style),
+            Span::styled(format!("Epoch {}", entry.window.id), style),
+            Span::styled(
+                format!(" {}", entry.window.start.format("%m-%d %H:%M")),
+                Style::default().fg(Color::Gray),
+            ),
+        ];
+        if !entry.farms_starting.is_empty() {
+            spans.push(Span::styled(
+                format!(" +{} farm(s)", entry.farms_starting.len()),
+                Style::default().fg(Color::Green),
+            ));
+        }
+        if !entry.farms_ending.is_empty() {
+            spans.push(Span::styled(
+                format!(" last epoch of {} farm(s)", entry.farms_ending.len()),
+                Style::default().fg(Color::Red),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
 /// Helper function to calculate total claimable rewards
 fn calculate_total_claimable_rewards(rewards: &HashMap<String, Uint128>) -> u64 {
     rewards.values().map(|amount| amount.u128() as u64).sum()
@@ -474,4 +714,78 @@ mod tests {
         assert_eq!(state.claim_input, "");
         assert_eq!(state.show_claim_confirmation, false);
     }
+
+    #[test]
+    fn test_epoch_calendar_lines_count_down_to_next_epoch() {
+        use chrono::TimeZone;
+
+        let start = chrono::Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+        let calendar = EpochCalendar::new(40, start, 86_400);
+        let now = start + chrono::Duration::hours(21);
+
+        let lines = epoch_calendar_lines(&calendar, &[], now);
+        assert_eq!(lines[0].to_string(), "Next epoch in 3h 00m");
+        assert_eq!(lines.len(), 2 + DEFAULT_CALENDAR_EPOCHS);
+        assert_eq!(lines[2].to_string(), "► Epoch 40 10-16 00:00");
+        assert_eq!(lines[3].to_string(), "  Epoch 41 10-17 00:00");
+    }
+
+    #[test]
+    fn test_emission_change_alert_stays_while_current() {
+        let farm = |emission_rate: u128| Farm {
+            identifier: "f1".to_string(),
+            owner: cosmwasm_std::Addr::unchecked("mantra1owner"),
+            lp_denom: "factory/pool/p1.LP".to_string(),
+            farm_asset: cosmwasm_std::Coin::new(1_000_000u128, "uom"),
+            claimed_amount: Uint128::zero(),
+            emission_rate: Uint128::new(emission_rate),
+            curve: mantra_dex_std::farm_manager::Curve::Linear,
+            start_epoch: 1,
+            preliminary_end_epoch: 100,
+        };
+
+        let mut state = RewardsState {
+            farms: vec![farm(1_000)],
+            ..RewardsState::default()
+        };
+        state.update_farm_alerts(&[], 10);
+        assert!(state.alerts.is_empty());
+
+        state.farms = vec![farm(500)];
+        state.update_farm_alerts(&[], 10);
+        assert_eq!(state.alerts.len(), 1);
+        state.update_farm_alerts(&[], 11);
+        assert_eq!(state.alerts.len(), 1);
+
+        state.farms = vec![farm(700)];
+        state.update_farm_alerts(&[], 12);
+        assert_eq!(
+            state.alerts[0].to_string(),
+            "Farm f1 now emits 700uom per epoch, was 500uom"
+        );
+        assert_eq!(state.alerts.len(), 1);
+    }
+
+    #[test]
+    fn test_scheduled_selection_stays_in_range() {
+        let scheduled = |id| ScheduledTx {
+            id,
+            wallet: "main".to_string(),
+            action: crate::scheduler::ScheduledAction::ClaimRewards { until_epoch: None },
+            trigger: crate::scheduler::Trigger::Epoch(10),
+            created_at: chrono::Utc::now(),
+            status: ScheduleStatus::Pending,
+        };
+
+        let mut state = RewardsState::default();
+        assert!(state.selected_scheduled().is_none());
+        state.set_scheduled(vec![scheduled(1), scheduled(2), scheduled(3)]);
+        state.move_scheduled_selection(5);
+        assert_eq!(state.selected_scheduled().map(|tx| tx.id), Some(3));
+
+        state.set_scheduled(vec![scheduled(1)]);
+        assert_eq!(state.selected_scheduled().map(|tx| tx.id), Some(1));
+        state.move_scheduled_selection(-1);
+        assert_eq!(state.scheduled_selected, 0);
+    }
 }
@@ -1,16 +1,22 @@
 //! Settings Screen Implementation
 //!
 //! This screen provides configuration options for network settings,
-//! wallet management, and display preferences.
+//! wallet management, display preferences and trading confirmations.
 
-use crate::config::{Config, MantraNetworkConfig};
+use crate::config::{Config, ExpertModeConfig, MantraNetworkConfig};
+use crate::preferences::Preferences;
+pub use crate::preferences::Theme;
+use crate::secret::SecretString;
+use crate::tui::components::forms::Form;
 use crate::Error;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
+use zeroize::Zeroize;
 
 /// Settings screen sections
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,6 +24,7 @@ pub enum SettingsSection {
     Network,
     Wallet,
     Display,
+    Trading,
 }
 
 impl SettingsSection {
@@ -27,6 +34,7 @@ impl SettingsSection {
             SettingsSection::Network => "Network",
             SettingsSection::Wallet => "Wallet",
             SettingsSection::Display => "Display",
+            SettingsSection::Trading => "Trading",
         }
     }
 
@@ -36,6 +44,7 @@ impl SettingsSection {
             SettingsSection::Network,
             SettingsSection::Wallet,
             SettingsSection::Display,
+            SettingsSection::Trading,
         ]
     }
 }
@@ -68,65 +77,90 @@ impl NetworkEnvironment {
     }
 }
 
-/// Theme options for display preferences
+/// Editable fields across the settings sections
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Theme {
-    Default,
-    Dark,
-    Light,
-    HighContrast,
+pub enum SettingsField {
+    NetworkName,
+    NetworkRpc,
+    GasPrice,
+    GasAdjustment,
+    Mnemonic,
+    BalanceRefresh,
+    PoolRefresh,
+    DecimalPrecision,
+    MaxTradeValue,
+    MaxPriceImpact,
 }
 
-impl Theme {
-    /// Get display name
-    pub fn display_name(&self) -> &'static str {
+impl SettingsField {
+    /// Focus manager id of the field's text input
+    pub fn id(&self) -> &'static str {
         match self {
-            Theme::Default => "Default",
-            Theme::Dark => "Dark",
-            Theme::Light => "Light",
-            Theme::HighContrast => "High Contrast",
+            SettingsField::NetworkName => "settings_network_name",
+            SettingsField::NetworkRpc => "settings_network_rpc",
+            SettingsField::GasPrice => "settings_gas_price",
+            SettingsField::GasAdjustment => "settings_gas_adjustment",
+            SettingsField::Mnemonic => "settings_wallet_mnemonic",
+            SettingsField::BalanceRefresh => "settings_balance_refresh",
+            SettingsField::PoolRefresh => "settings_pool_refresh",
+            SettingsField::DecimalPrecision => "settings_decimal_precision",
+            SettingsField::MaxTradeValue => "settings_expert_max_value",
+            SettingsField::MaxPriceImpact => "settings_expert_max_impact",
         }
     }
-
-    /// Get all themes
-    pub fn all() -> Vec<Self> {
-        vec![
-            Theme::Default,
-            Theme::Dark,
-            Theme::Light,
-            Theme::HighContrast,
-        ]
-    }
 }
 
-/// Simple form state for managing current field focus
+/// Field focus and edit state for one settings section
 #[derive(Debug, Clone)]
 pub struct FormState {
-    pub current_field: usize,
+    /// Section fields in focus order, with the section's dirty flag
+    pub fields: Form<SettingsField>,
+    pub current_field: SettingsField,
     pub editing: bool,
 }
 
-impl Default for FormState {
-    fn default() -> Self {
+impl FormState {
+    /// Start with focus on the first of `fields`
+    pub fn new(fields: Form<SettingsField>) -> Self {
+        let current_field = fields
+            .first()
+            .expect("settings sections have at least one field");
         Self {
-            current_field: 0,
+            fields,
+            current_field,
             editing: false,
         }
     }
-}
 
-impl FormState {
     pub fn is_editing(&self) -> bool {
         self.editing
     }
 
-    pub fn handle_char(&mut self, _c: char) {
-        // Basic character handling
+    /// Whether `field` is focused and being edited
+    pub fn is_editing_field(&self, field: SettingsField) -> bool {
+        self.editing && self.current_field == field
+    }
+
+    /// Move focus to the next field, wrapping to the first
+    pub fn next_field(&mut self) {
+        if let Some(field) = self.fields.next(self.current_field) {
+            self.current_field = field;
+        }
+    }
+
+    /// Move focus to the previous field, wrapping to the last
+    pub fn previous_field(&mut self) {
+        if let Some(field) = self.fields.previous(self.current_field) {
+            self.current_field = field;
+        }
     }
 }
 
 /// Simple input field structure
-#[derive(Debug, Clone)]
+///
+/// A sensitive field's value is scrubbed when it is replaced, cleared or
+/// dropped, and left out of its `Debug` output.
+#[derive(Clone)]
 pub struct InputField {
     pub label: String,
     pub value: String,
@@ -143,11 +177,16 @@ impl InputField {
     }
 
     pub fn set_value(&mut self, value: &str) {
-        self.value = value.to_string();
+        self.clear();
+        self.value.push_str(value);
     }
 
     pub fn clear(&mut self) {
-        self.value.clear();
+        if self.is_sensitive {
+            self.value.zeroize();
+        } else {
+            self.value.clear();
+        }
     }
 
     pub fn handle_char(&mut self, c: char) {
@@ -159,6 +198,29 @@ impl InputField {
     }
 }
 
+impl std::fmt::Debug for InputField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value: &dyn std::fmt::Debug = if self.is_sensitive {
+            &"[REDACTED]"
+        } else {
+            &self.value
+        };
+        f.debug_struct("InputField")
+            .field("label", &self.label)
+            .field("value", value)
+            .field("is_sensitive", &self.is_sensitive)
+            .finish()
+    }
+}
+
+impl Drop for InputField {
+    fn drop(&mut self) {
+        if self.is_sensitive {
+            self.value.zeroize();
+        }
+    }
+}
+
 /// Network configuration form state
 #[derive(Debug, Clone)]
 pub struct NetworkConfigForm {
@@ -182,7 +244,13 @@ impl Default for NetworkConfigForm {
             ),
             gas_price: InputField::new("Gas Price", "0.025", false),
             gas_adjustment: InputField::new("Gas Adjustment", "1.3", false),
-            form_state: FormState::default(),
+            form_state: FormState::new(
+                Form::new()
+                    .custom(SettingsField::NetworkName)
+                    .custom(SettingsField::NetworkRpc)
+                    .custom(SettingsField::GasPrice)
+                    .custom(SettingsField::GasAdjustment),
+            ),
         }
     }
 }
@@ -200,7 +268,7 @@ impl Default for WalletForm {
     fn default() -> Self {
         Self {
             mnemonic_input: InputField::new("Mnemonic Phrase", "", true), // sensitive
-            form_state: FormState::default(),
+            form_state: FormState::new(Form::new().custom(SettingsField::Mnemonic)),
             show_mnemonic: false,
             import_mode: false,
         }
@@ -226,11 +294,86 @@ impl Default for DisplayForm {
             refresh_interval_pools: InputField::new("Pool Refresh (seconds)", "60", false),
             decimal_precision: InputField::new("Decimal Precision", "6", false),
             auto_refresh: true,
-            form_state: FormState::default(),
+            form_state: FormState::new(
+                Form::new()
+                    .custom(SettingsField::BalanceRefresh)
+                    .custom(SettingsField::PoolRefresh)
+                    .custom(SettingsField::DecimalPrecision),
+            ),
         }
     }
 }
 
+/// Expert mode form state
+#[derive(Debug, Clone)]
+pub struct TradingForm {
+    pub expert_mode: bool,
+    pub max_trade_value: InputField,
+    pub max_price_impact: InputField,
+    pub form_state: FormState,
+}
+
+impl Default for TradingForm {
+    fn default() -> Self {
+        let defaults = ExpertModeConfig::default();
+        Self {
+            expert_mode: defaults.enabled,
+            max_trade_value: InputField::new(
+                "Max Trade Value (USD)",
+                &defaults.max_trade_value_usd.to_string(),
+                false,
+            ),
+            max_price_impact: InputField::new(
+                "Max Price Impact (%)",
+                &defaults.max_price_impact_percent.to_string(),
+                false,
+            ),
+            form_state: FormState::new(
+                Form::new()
+                    .custom(SettingsField::MaxTradeValue)
+                    .custom(SettingsField::MaxPriceImpact),
+            ),
+        }
+    }
+}
+
+/// A setting that saving would change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingChange {
+    pub setting: &'static str,
+    pub old: String,
+    pub new: String,
+    /// Whether the client has to reconnect for the change to apply
+    pub reconnect: bool,
+}
+
+/// Add a change of `setting` to `changes` if `old` and `new` differ
+fn push_change(
+    changes: &mut Vec<SettingChange>,
+    setting: &'static str,
+    old: impl ToString,
+    new: impl ToString,
+    reconnect: bool,
+) {
+    let (old, new) = (old.to_string(), new.to_string());
+    if old != new {
+        changes.push(SettingChange {
+            setting,
+            old,
+            new,
+            reconnect,
+        });
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
 /// Settings screen state
 #[derive(Debug, Clone)]
 pub struct SettingsState {
@@ -244,10 +387,12 @@ pub struct SettingsState {
     pub wallet_form: WalletForm,
     /// Display preferences form
     pub display_form: DisplayForm,
+    /// Expert mode form
+    pub trading_form: TradingForm,
     /// Current configuration
     pub current_config: Config,
-    /// Whether changes have been made
-    pub has_changes: bool,
+    /// Shared preferences the display and trading sections were loaded from
+    pub preferences: Preferences,
     /// Confirmation modal state
     pub show_confirmation: bool,
     /// Success/error messages
@@ -262,8 +407,9 @@ impl Default for SettingsState {
             network_form: NetworkConfigForm::default(),
             wallet_form: WalletForm::default(),
             display_form: DisplayForm::default(),
+            trading_form: TradingForm::default(),
             current_config: Config::default(),
-            has_changes: false,
+            preferences: Preferences::default(),
             show_confirmation: false,
             message: None,
         };
@@ -310,6 +456,8 @@ impl SettingsState {
             NetworkEnvironment::Custom
         };
 
+        self.load_expert_mode(config.expert_mode);
+
         // Load wallet config (but don't show mnemonic for security)
         if config.mnemonic.is_some() {
             self.wallet_form
@@ -318,6 +466,53 @@ impl SettingsState {
         }
     }
 
+    /// Show saved expert mode settings, e.g. restored from the config file at startup
+    pub fn load_expert_mode(&mut self, expert_mode: ExpertModeConfig) {
+        self.current_config.expert_mode = expert_mode;
+        self.trading_form.expert_mode = expert_mode.enabled;
+        self.trading_form
+            .max_trade_value
+            .set_value(&expert_mode.max_trade_value_usd.to_string());
+        self.trading_form
+            .max_price_impact
+            .set_value(&expert_mode.max_price_impact_percent.to_string());
+    }
+
+    /// Show the shared preferences in the display and trading sections
+    pub fn load_preferences(&mut self, preferences: &Preferences) {
+        self.preferences = preferences.clone();
+        self.load_expert_mode(preferences.expert_mode);
+        self.display_form.theme = preferences.theme;
+        self.display_form.auto_refresh = preferences.auto_refresh;
+        self.display_form
+            .refresh_interval_balances
+            .set_value(&preferences.balance_refresh_secs.to_string());
+        self.display_form
+            .refresh_interval_pools
+            .set_value(&preferences.pool_refresh_secs.to_string());
+        self.display_form
+            .decimal_precision
+            .set_value(&preferences.decimal_precision.to_string());
+    }
+
+    /// Copy the display and trading settings into `preferences`
+    ///
+    /// Inputs that don't parse keep the preference they would replace.
+    pub fn apply_to_preferences(&self, preferences: &mut Preferences) {
+        preferences.expert_mode = self.current_config.expert_mode;
+        preferences.theme = self.display_form.theme;
+        preferences.auto_refresh = self.display_form.auto_refresh;
+        if let Ok(secs) = self.display_form.refresh_interval_balances.value.parse() {
+            preferences.balance_refresh_secs = secs;
+        }
+        if let Ok(secs) = self.display_form.refresh_interval_pools.value.parse() {
+            preferences.pool_refresh_secs = secs;
+        }
+        if let Ok(precision) = self.display_form.decimal_precision.value.parse() {
+            preferences.decimal_precision = precision;
+        }
+    }
+
     /// Navigate to next section
     pub fn next_section(&mut self) {
         let sections = SettingsSection::all();
@@ -346,80 +541,96 @@ impl SettingsState {
         self.section_list_state.select(Some(prev_index));
     }
 
+    /// Whether any section has unsaved changes
+    pub fn has_changes(&self) -> bool {
+        self.network_form.form_state.fields.is_dirty()
+            || self.wallet_form.form_state.fields.is_dirty()
+            || self.display_form.form_state.fields.is_dirty()
+            || self.trading_form.form_state.fields.is_dirty()
+    }
+
+    /// Focus and edit state of the current section
+    fn form_state(&self) -> &FormState {
+        match self.current_section {
+            SettingsSection::Network => &self.network_form.form_state,
+            SettingsSection::Wallet => &self.wallet_form.form_state,
+            SettingsSection::Display => &self.display_form.form_state,
+            SettingsSection::Trading => &self.trading_form.form_state,
+        }
+    }
+
+    /// Mutable focus and edit state of the current section
+    fn current_form_state(&mut self) -> &mut FormState {
+        match self.current_section {
+            SettingsSection::Network => &mut self.network_form.form_state,
+            SettingsSection::Wallet => &mut self.wallet_form.form_state,
+            SettingsSection::Display => &mut self.display_form.form_state,
+            SettingsSection::Trading => &mut self.trading_form.form_state,
+        }
+    }
+
+    /// Input backing `field`
+    fn input(&self, field: SettingsField) -> &InputField {
+        match field {
+            SettingsField::NetworkName => &self.network_form.custom_name,
+            SettingsField::NetworkRpc => &self.network_form.custom_rpc,
+            SettingsField::GasPrice => &self.network_form.gas_price,
+            SettingsField::GasAdjustment => &self.network_form.gas_adjustment,
+            SettingsField::Mnemonic => &self.wallet_form.mnemonic_input,
+            SettingsField::BalanceRefresh => &self.display_form.refresh_interval_balances,
+            SettingsField::PoolRefresh => &self.display_form.refresh_interval_pools,
+            SettingsField::DecimalPrecision => &self.display_form.decimal_precision,
+            SettingsField::MaxTradeValue => &self.trading_form.max_trade_value,
+            SettingsField::MaxPriceImpact => &self.trading_form.max_price_impact,
+        }
+    }
+
+    /// Mutable input backing `field`
+    fn input_field(&mut self, field: SettingsField) -> &mut InputField {
+        match field {
+            SettingsField::NetworkName => &mut self.network_form.custom_name,
+            SettingsField::NetworkRpc => &mut self.network_form.custom_rpc,
+            SettingsField::GasPrice => &mut self.network_form.gas_price,
+            SettingsField::GasAdjustment => &mut self.network_form.gas_adjustment,
+            SettingsField::Mnemonic => &mut self.wallet_form.mnemonic_input,
+            SettingsField::BalanceRefresh => &mut self.display_form.refresh_interval_balances,
+            SettingsField::PoolRefresh => &mut self.display_form.refresh_interval_pools,
+            SettingsField::DecimalPrecision => &mut self.display_form.decimal_precision,
+            SettingsField::MaxTradeValue => &mut self.trading_form.max_trade_value,
+            SettingsField::MaxPriceImpact => &mut self.trading_form.max_price_impact,
+        }
+    }
+
+    /// Field being edited in the current section, if any
+    fn editing_field(&self) -> Option<SettingsField> {
+        // The mnemonic can only be typed while importing a wallet
+        if self.current_section == SettingsSection::Wallet && !self.wallet_form.import_mode {
+            return None;
+        }
+        let form_state = self.form_state();
+        form_state.is_editing().then_some(form_state.current_field)
+    }
+
+    /// Whether typed characters go into a sensitive input, such as the mnemonic
+    pub fn is_sensitive_input_focused(&self) -> bool {
+        self.editing_field()
+            .is_some_and(|field| self.input(field).is_sensitive)
+    }
+
     /// Handle character input
     pub fn handle_char_input(&mut self, c: char) -> Result<(), Error> {
-        match self.current_section {
-            SettingsSection::Network => {
-                if self.network_form.form_state.is_editing() {
-                    self.network_form.form_state.handle_char(c);
-
-                    // Update the appropriate field based on current focus
-                    match self.network_form.form_state.current_field {
-                        0 => self.network_form.custom_name.handle_char(c),
-                        1 => self.network_form.custom_rpc.handle_char(c),
-                        2 => self.network_form.gas_price.handle_char(c),
-                        3 => self.network_form.gas_adjustment.handle_char(c),
-                        _ => {}
-                    }
-                    self.has_changes = true;
-                }
-            }
-            SettingsSection::Wallet => {
-                if self.wallet_form.form_state.is_editing() && self.wallet_form.import_mode {
-                    self.wallet_form.mnemonic_input.handle_char(c);
-                    self.has_changes = true;
-                }
-            }
-            SettingsSection::Display => {
-                if self.display_form.form_state.is_editing() {
-                    match self.display_form.form_state.current_field {
-                        0 => self.display_form.refresh_interval_balances.handle_char(c),
-                        1 => self.display_form.refresh_interval_pools.handle_char(c),
-                        2 => self.display_form.decimal_precision.handle_char(c),
-                        _ => {}
-                    }
-                    self.has_changes = true;
-                }
-            }
+        if let Some(field) = self.editing_field() {
+            self.input_field(field).handle_char(c);
+            self.current_form_state().fields.mark_dirty();
         }
         Ok(())
     }
 
     /// Handle backspace
     pub fn handle_backspace(&mut self) -> Result<(), Error> {
-        match self.current_section {
-            SettingsSection::Network => {
-                if self.network_form.form_state.is_editing() {
-                    match self.network_form.form_state.current_field {
-                        0 => self.network_form.custom_name.handle_backspace(),
-                        1 => self.network_form.custom_rpc.handle_backspace(),
-                        2 => self.network_form.gas_price.handle_backspace(),
-                        3 => self.network_form.gas_adjustment.handle_backspace(),
-                        _ => {}
-                    }
-                    self.has_changes = true;
-                }
-            }
-            SettingsSection::Wallet => {
-                if self.wallet_form.form_state.is_editing() && self.wallet_form.import_mode {
-                    self.wallet_form.mnemonic_input.handle_backspace();
-                    self.has_changes = true;
-                }
-            }
-            SettingsSection::Display => {
-                if self.display_form.form_state.is_editing() {
-                    match self.display_form.form_state.current_field {
-                        0 => self
-                            .display_form
-                            .refresh_interval_balances
-                            .handle_backspace(),
-                        1 => self.display_form.refresh_interval_pools.handle_backspace(),
-                        2 => self.display_form.decimal_precision.handle_backspace(),
-                        _ => {}
-                    }
-                    self.has_changes = true;
-                }
-            }
+        if let Some(field) = self.editing_field() {
+            self.input_field(field).handle_backspace();
+            self.current_form_state().fields.mark_dirty();
         }
         Ok(())
     }
@@ -433,7 +644,7 @@ impl SettingsState {
             .unwrap_or(0);
         let next_index = (current_index + 1) % environments.len();
         self.network_form.environment = environments[next_index];
-        self.has_changes = true;
+        self.network_form.form_state.fields.mark_dirty();
     }
 
     /// Toggle theme
@@ -445,13 +656,19 @@ impl SettingsState {
             .unwrap_or(0);
         let next_index = (current_index + 1) % themes.len();
         self.display_form.theme = themes[next_index];
-        self.has_changes = true;
+        self.display_form.form_state.fields.mark_dirty();
     }
 
     /// Toggle auto refresh
     pub fn toggle_auto_refresh(&mut self) {
         self.display_form.auto_refresh = !self.display_form.auto_refresh;
-        self.has_changes = true;
+        self.display_form.form_state.fields.mark_dirty();
+    }
+
+    /// Toggle expert mode
+    pub fn toggle_expert_mode(&mut self) {
+        self.trading_form.expert_mode = !self.trading_form.expert_mode;
+        self.trading_form.form_state.fields.mark_dirty();
     }
 
     /// Toggle wallet import mode
@@ -467,44 +684,65 @@ impl SettingsState {
         self.wallet_form.show_mnemonic = !self.wallet_form.show_mnemonic;
     }
 
-    /// Save current settings
-    pub fn save_settings(&mut self) -> Result<Config, Error> {
+    /// Configuration the forms describe, as saving would write it
+    ///
+    /// The network section only replaces the saved network once edited, so saving
+    /// another section never resets the network to its environment's defaults.
+    pub fn pending_config(&self) -> Config {
         let mut new_config = self.current_config.clone();
 
         // Update network configuration
-        match self.network_form.environment {
-            NetworkEnvironment::Mainnet => {
-                new_config.network = MantraNetworkConfig {
-                    network_name: "mantra-mainnet".to_string(),
-                    chain_id: "mantra-mainnet-1".to_string(),
-                    rpc_url: "https://rpc.mantrachain.io/".to_string(),
-                    gas_price: 0.025,
-                    gas_adjustment: 1.3,
-                    native_denom: "uom".to_string(),
-                    contracts: new_config.network.contracts.clone(),
-                };
-            }
-            NetworkEnvironment::Testnet => {
-                new_config.network = MantraNetworkConfig {
-                    network_name: "mantra-dukong".to_string(),
-                    chain_id: "mantra-dukong-1".to_string(),
-                    rpc_url: "https://rpc.dukong.mantrachain.io/".to_string(),
-                    gas_price: 0.025,
-                    gas_adjustment: 1.3,
-                    native_denom: "uom".to_string(),
-                    contracts: new_config.network.contracts.clone(),
-                };
-            }
-            NetworkEnvironment::Custom => {
-                new_config.network.network_name = self.network_form.custom_name.value.clone();
-                new_config.network.rpc_url = self.network_form.custom_rpc.value.clone();
-
-                // Parse gas price and adjustment
-                if let Ok(gas_price) = self.network_form.gas_price.value.parse::<f64>() {
-                    new_config.network.gas_price = gas_price;
+        if self.network_form.form_state.fields.is_dirty() {
+            match self.network_form.environment {
+                NetworkEnvironment::Mainnet => {
+                    new_config.network = MantraNetworkConfig {
+                        network_name: "mantra-mainnet".to_string(),
+                        chain_id: "mantra-mainnet-1".to_string(),
+                        rpc_url: "https://rpc.mantrachain.io/".to_string(),
+                        fallback_rpc_urls: Vec::new(),
+                        gas_price: 0.025,
+                        gas_adjustment: 1.3,
+                        native_denom: "uom".to_string(),
+                        fee_denom: None,
+                        fee_tokens: Vec::new(),
+                        contracts: new_config.network.contracts.clone(),
+                        timeouts: new_config.network.timeouts,
+                        circuit_breaker: new_config.network.circuit_breaker,
+                        endpoint_selection: new_config.network.endpoint_selection.clone(),
+                        broadcast_retry: new_config.network.broadcast_retry,
+                        priority_fee: new_config.network.priority_fee,
+                    };
                 }
-                if let Ok(gas_adj) = self.network_form.gas_adjustment.value.parse::<f64>() {
-                    new_config.network.gas_adjustment = gas_adj;
+                NetworkEnvironment::Testnet => {
+                    new_config.network = MantraNetworkConfig {
+                        network_name: "mantra-dukong".to_string(),
+                        chain_id: "mantra-dukong-1".to_string(),
+                        rpc_url: "https://rpc.dukong.mantrachain.io/".to_string(),
+                        fallback_rpc_urls: Vec::new(),
+                        gas_price: 0.025,
+                        gas_adjustment: 1.3,
+                        native_denom: "uom".to_string(),
+                        fee_denom: None,
+                        fee_tokens: Vec::new(),
+                        contracts: new_config.network.contracts.clone(),
+                        timeouts: new_config.network.timeouts,
+                        circuit_breaker: new_config.network.circuit_breaker,
+                        endpoint_selection: new_config.network.endpoint_selection.clone(),
+                        broadcast_retry: new_config.network.broadcast_retry,
+                        priority_fee: new_config.network.priority_fee,
+                    };
+                }
+                NetworkEnvironment::Custom => {
+                    new_config.network.network_name = self.network_form.custom_name.value.clone();
+                    new_config.network.rpc_url = self.network_form.custom_rpc.value.clone();
+
+                    // Parse gas price and adjustment
+                    if let Ok(gas_price) = self.network_form.gas_price.value.parse::<f64>() {
+                        new_config.network.gas_price = gas_price;
+                    }
+                    if let Ok(gas_adj) = self.network_form.gas_adjustment.value.parse::<f64>() {
+                        new_config.network.gas_adjustment = gas_adj;
+                    }
                 }
             }
         }
@@ -514,15 +752,173 @@ impl SettingsState {
             && !self.wallet_form.mnemonic_input.value.is_empty()
             && self.wallet_form.mnemonic_input.value != "*** MNEMONIC SET ***"
         {
-            new_config.mnemonic = Some(self.wallet_form.mnemonic_input.value.clone());
+            new_config.mnemonic = Some(SecretString::from(
+                self.wallet_form.mnemonic_input.value.as_str(),
+            ));
+        }
+
+        // Update expert mode, keeping the saved thresholds if an input doesn't parse
+        new_config.expert_mode.enabled = self.trading_form.expert_mode;
+        if let Ok(max_value) = self.trading_form.max_trade_value.value.parse::<f64>() {
+            new_config.expert_mode.max_trade_value_usd = max_value;
+        }
+        if let Ok(max_impact) = self.trading_form.max_price_impact.value.parse::<f64>() {
+            new_config.expert_mode.max_price_impact_percent = max_impact;
+        }
+        new_config
+    }
+
+    /// Preferences the display section describes
+    pub fn pending_preferences(&self) -> Preferences {
+        let mut preferences = self.preferences.clone();
+        self.apply_to_preferences(&mut preferences);
+        preferences
+    }
+
+    /// Settings that saving would change, old and new values
+    ///
+    /// Network settings are used by the client, so changing any of them
+    /// reconnects it. The mnemonic is never shown, only whether it is replaced.
+    pub fn pending_changes(&self) -> Vec<SettingChange> {
+        let old = &self.current_config;
+        let new = self.pending_config();
+        let mut changes = Vec::new();
+
+        let (old_network, new_network) = (&old.network, &new.network);
+        push_change(
+            &mut changes,
+            "Network",
+            &old_network.network_name,
+            &new_network.network_name,
+            true,
+        );
+        push_change(
+            &mut changes,
+            "Chain ID",
+            &old_network.chain_id,
+            &new_network.chain_id,
+            true,
+        );
+        push_change(
+            &mut changes,
+            "RPC endpoint",
+            &old_network.rpc_url,
+            &new_network.rpc_url,
+            true,
+        );
+        push_change(
+            &mut changes,
+            "Gas price",
+            old_network.gas_price,
+            new_network.gas_price,
+            true,
+        );
+        push_change(
+            &mut changes,
+            "Gas adjustment",
+            old_network.gas_adjustment,
+            new_network.gas_adjustment,
+            true,
+        );
+
+        if old.mnemonic != new.mnemonic {
+            push_change(
+                &mut changes,
+                "Mnemonic",
+                if old.mnemonic.is_some() {
+                    "Set"
+                } else {
+                    "Not set"
+                },
+                "Replaced",
+                false,
+            );
         }
 
+        let (old_expert, new_expert) = (&old.expert_mode, &new.expert_mode);
+        push_change(
+            &mut changes,
+            "Expert mode",
+            on_off(old_expert.enabled),
+            on_off(new_expert.enabled),
+            false,
+        );
+        push_change(
+            &mut changes,
+            "Max trade value (USD)",
+            old_expert.max_trade_value_usd,
+            new_expert.max_trade_value_usd,
+            false,
+        );
+        push_change(
+            &mut changes,
+            "Max price impact (%)",
+            old_expert.max_price_impact_percent,
+            new_expert.max_price_impact_percent,
+            false,
+        );
+
+        let (old_prefs, new_prefs) = (&self.preferences, self.pending_preferences());
+        push_change(
+            &mut changes,
+            "Theme",
+            old_prefs.theme.display_name(),
+            new_prefs.theme.display_name(),
+            false,
+        );
+        push_change(
+            &mut changes,
+            "Auto refresh",
+            on_off(old_prefs.auto_refresh),
+            on_off(new_prefs.auto_refresh),
+            false,
+        );
+        push_change(
+            &mut changes,
+            "Balance refresh (s)",
+            old_prefs.balance_refresh_secs,
+            new_prefs.balance_refresh_secs,
+            false,
+        );
+        push_change(
+            &mut changes,
+            "Pool refresh (s)",
+            old_prefs.pool_refresh_secs,
+            new_prefs.pool_refresh_secs,
+            false,
+        );
+        push_change(
+            &mut changes,
+            "Decimal precision",
+            old_prefs.decimal_precision,
+            new_prefs.decimal_precision,
+            false,
+        );
+        changes
+    }
+
+    /// Ask to confirm the pending changes, or say there are none
+    pub fn request_save(&mut self) {
+        if self.pending_changes().is_empty() {
+            self.message = Some(("No changes to save".to_string(), false));
+        } else {
+            self.show_confirmation = true;
+        }
+    }
+
+    /// Save current settings
+    pub fn save_settings(&mut self) -> Result<Config, Error> {
+        let new_config = self.pending_config();
+
         // Save to file
         let config_path = Config::default_path();
         new_config.save(&config_path)?;
 
         self.current_config = new_config.clone();
-        self.has_changes = false;
+        self.network_form.form_state.fields.mark_clean();
+        self.wallet_form.form_state.fields.mark_clean();
+        self.display_form.form_state.fields.mark_clean();
+        self.trading_form.form_state.fields.mark_clean();
         self.message = Some(("Settings saved successfully!".to_string(), false));
 
         Ok(new_config)
@@ -541,43 +937,12 @@ impl SettingsState {
 
     /// Move focus to next field in current section
     pub fn next_field(&mut self) {
-        match self.current_section {
-            SettingsSection::Network => {
-                self.network_form.form_state.current_field =
-                    (self.network_form.form_state.current_field + 1) % 4; // 4 fields in network section
-            }
-            SettingsSection::Wallet => {
-                self.wallet_form.form_state.current_field =
-                    (self.wallet_form.form_state.current_field + 1) % 1; // 1 field in wallet section
-            }
-            SettingsSection::Display => {
-                self.display_form.form_state.current_field =
-                    (self.display_form.form_state.current_field + 1) % 3; // 3 fields in display section
-            }
-        }
+        self.current_form_state().next_field();
     }
 
     /// Move focus to previous field in current section
     pub fn previous_field(&mut self) {
-        match self.current_section {
-            SettingsSection::Network => {
-                if self.network_form.form_state.current_field == 0 {
-                    self.network_form.form_state.current_field = 3; // wrap to last field
-                } else {
-                    self.network_form.form_state.current_field -= 1;
-                }
-            }
-            SettingsSection::Wallet => {
-                // Only one field, no change needed
-            }
-            SettingsSection::Display => {
-                if self.display_form.form_state.current_field == 0 {
-                    self.display_form.form_state.current_field = 2; // wrap to last field
-                } else {
-                    self.display_form.form_state.current_field -= 1;
-                }
-            }
-        }
+        self.current_form_state().previous_field();
     }
 
     /// Check if currently focused field is editable
@@ -591,8 +956,8 @@ impl SettingsState {
                 // Wallet field is editable when in import mode
                 self.wallet_form.import_mode
             }
-            SettingsSection::Display => {
-                // Display fields are always editable
+            SettingsSection::Display | SettingsSection::Trading => {
+                // Display and trading fields are always editable
                 true
             }
         }
@@ -600,22 +965,13 @@ impl SettingsState {
 
     /// Get the current field name for focus identification
     pub fn get_current_field_id(&self) -> Option<String> {
-        match self.current_section {
-            SettingsSection::Network => match self.network_form.form_state.current_field {
-                0 => Some("settings_network_name".to_string()),
-                1 => Some("settings_network_rpc".to_string()),
-                2 => Some("settings_gas_price".to_string()),
-                3 => Some("settings_gas_adjustment".to_string()),
-                _ => None,
-            },
-            SettingsSection::Wallet => Some("settings_wallet_mnemonic".to_string()),
-            SettingsSection::Display => match self.display_form.form_state.current_field {
-                0 => Some("settings_balance_refresh".to_string()),
-                1 => Some("settings_pool_refresh".to_string()),
-                2 => Some("settings_decimal_precision".to_string()),
-                _ => None,
-            },
-        }
+        let form_state = match self.current_section {
+            SettingsSection::Network => &self.network_form.form_state,
+            SettingsSection::Wallet => &self.wallet_form.form_state,
+            SettingsSection::Display => &self.display_form.form_state,
+            SettingsSection::Trading => &self.trading_form.form_state,
+        };
+        Some(form_state.current_field.id().to_string())
     }
 }
 
@@ -658,6 +1014,7 @@ pub fn render_settings(frame: &mut Frame, area: Rect, state: &mut SettingsState)
         SettingsSection::Network => render_network_settings(frame, chunks[1], state),
         SettingsSection::Wallet => render_wallet_settings(frame, chunks[1], state),
         SettingsSection::Display => render_display_settings(frame, chunks[1], state),
+        SettingsSection::Trading => render_trading_settings(frame, chunks[1], state),
     }
 
     // Render confirmation modal if needed
@@ -793,29 +1150,37 @@ fn render_network_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
             frame,
             form_chunks[0],
             &state.network_form.custom_name,
-            state.network_form.form_state.current_field == 0
-                && state.network_form.form_state.is_editing(),
+            state
+                .network_form
+                .form_state
+                .is_editing_field(SettingsField::NetworkName),
         );
         render_input_field(
             frame,
             form_chunks[1],
             &state.network_form.custom_rpc,
-            state.network_form.form_state.current_field == 1
-                && state.network_form.form_state.is_editing(),
+            state
+                .network_form
+                .form_state
+                .is_editing_field(SettingsField::NetworkRpc),
         );
         render_input_field(
             frame,
             form_chunks[2],
             &state.network_form.gas_price,
-            state.network_form.form_state.current_field == 2
-                && state.network_form.form_state.is_editing(),
+            state
+                .network_form
+                .form_state
+                .is_editing_field(SettingsField::GasPrice),
         );
         render_input_field(
             frame,
             form_chunks[3],
             &state.network_form.gas_adjustment,
-            state.network_form.form_state.current_field == 3
-                && state.network_form.form_state.is_editing(),
+            state
+                .network_form
+                .form_state
+                .is_editing_field(SettingsField::GasAdjustment),
         );
     } else {
         // Show current network info for mainnet/testnet
@@ -852,13 +1217,13 @@ fn render_network_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
     }
 
     // Actions
-    let actions_text = if state.has_changes {
+    let actions_text = if state.has_changes() {
         "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | Tab: Navigate | Changes pending..."
     } else {
         "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | Tab: Navigate Fields"
     };
 
-    let actions_style = if state.has_changes {
+    let actions_style = if state.has_changes() {
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD)
@@ -986,13 +1351,13 @@ fn render_wallet_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSta
     }
 
     // Actions
-    let actions_text = if state.has_changes {
+    let actions_text = if state.has_changes() {
         "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | Tab: Navigate | Changes pending..."
     } else {
         "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | i: Import | m: Show Mnemonic"
     };
 
-    let actions_style = if state.has_changes {
+    let actions_style = if state.has_changes() {
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD)
@@ -1076,32 +1441,38 @@ fn render_display_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
         frame,
         form_chunks[0],
         &state.display_form.refresh_interval_balances,
-        state.display_form.form_state.current_field == 0
-            && state.display_form.form_state.is_editing(),
+        state
+            .display_form
+            .form_state
+            .is_editing_field(SettingsField::BalanceRefresh),
     );
     render_input_field(
         frame,
         form_chunks[1],
         &state.display_form.refresh_interval_pools,
-        state.display_form.form_state.current_field == 1
-            && state.display_form.form_state.is_editing(),
+        state
+            .display_form
+            .form_state
+            .is_editing_field(SettingsField::PoolRefresh),
     );
     render_input_field(
         frame,
         form_chunks[2],
         &state.display_form.decimal_precision,
-        state.display_form.form_state.current_field == 2
-            && state.display_form.form_state.is_editing(),
+        state
+            .display_form
+            .form_state
+            .is_editing_field(SettingsField::DecimalPrecision),
     );
 
     // Actions
-    let actions_text = if state.has_changes {
+    let actions_text = if state.has_changes() {
         "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | Tab: Navigate | Changes pending..."
     } else {
         "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | t: Theme | a: Auto-refresh"
     };
 
-    let actions_style = if state.has_changes {
+    let actions_style = if state.has_changes() {
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD)
@@ -1121,6 +1492,107 @@ fn render_display_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
     frame.render_widget(actions_paragraph, chunks[4]);
 }
 
+/// Render expert mode settings
+fn render_trading_settings(frame: &mut Frame, area: Rect, state: &mut SettingsState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(6), // Expert mode toggle
+            Constraint::Min(6),    // Form fields
+            Constraint::Length(3), // Actions
+        ])
+        .split(area);
+
+    // Title
+    let title = Paragraph::new("Trading Preferences")
+        .block(Block::default().borders(Borders::ALL))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(title, chunks[0]);
+
+    // Expert mode toggle
+    let expert_text = format!(
+        "Expert mode: {} (Press 'x' to toggle)\n\
+         Swaps below both thresholds execute without a confirmation prompt.\n\
+         Multi-hop, liquidity and admin operations are always confirmed.",
+        if state.trading_form.expert_mode {
+            "ON"
+        } else {
+            "OFF"
+        }
+    );
+    let expert_style = if state.trading_form.expert_mode {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let expert_paragraph = Paragraph::new(expert_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirmations"),
+        )
+        .style(expert_style)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(expert_paragraph, chunks[1]);
+
+    // Thresholds
+    let form_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Length(3)])
+        .split(chunks[2]);
+
+    render_input_field(
+        frame,
+        form_chunks[0],
+        &state.trading_form.max_trade_value,
+        state
+            .trading_form
+            .form_state
+            .is_editing_field(SettingsField::MaxTradeValue),
+    );
+    render_input_field(
+        frame,
+        form_chunks[1],
+        &state.trading_form.max_price_impact,
+        state
+            .trading_form
+            .form_state
+            .is_editing_field(SettingsField::MaxPriceImpact),
+    );
+
+    // Actions
+    let actions_text = if state.has_changes() {
+        "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | Tab: Navigate | Changes pending..."
+    } else {
+        "Actions: Ctrl+S: Save Changes | Ctrl+R: Reset | x: Expert mode"
+    };
+
+    let actions_style = if state.has_changes() {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Cyan)
+    };
+
+    let actions_paragraph = Paragraph::new(actions_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Keyboard Shortcuts"),
+        )
+        .style(actions_style)
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(actions_paragraph, chunks[3]);
+}
+
 /// Render input field helper
 fn render_input_field(frame: &mut Frame, area: Rect, field: &InputField, is_focused: bool) {
     let style = if is_focused {
@@ -1161,9 +1633,41 @@ fn render_input_field(frame: &mut Frame, area: Rect, field: &InputField, is_focu
     frame.render_widget(paragraph, area);
 }
 
-/// Render confirmation modal
-fn render_confirmation_modal(frame: &mut Frame, area: Rect, _state: &SettingsState) {
-    let popup_area = centered_rect(50, 30, area);
+/// Lines of the confirmation modal: each pending change, old and new value
+fn change_lines(changes: &[SettingChange]) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = changes
+        .iter()
+        .map(|change| {
+            let mut spans = vec![
+                Span::styled(
+                    format!("{}: ", change.setting),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(change.old.clone(), Style::default().fg(Color::Red)),
+                Span::raw(" → "),
+                Span::styled(change.new.clone(), Style::default().fg(Color::Green)),
+            ];
+            if change.reconnect {
+                spans.push(Span::styled(" *", Style::default().fg(Color::Yellow)));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    lines.push(Line::from(""));
+    if changes.iter().any(|change| change.reconnect) {
+        lines.push(Line::from(Span::styled(
+            "* Reconnects the client and restarts background sync",
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    lines.push(Line::from("Press Enter to save, Esc to cancel"));
+    lines
+}
+
+/// Render confirmation modal with the pending changes
+fn render_confirmation_modal(frame: &mut Frame, area: Rect, state: &SettingsState) {
+    let popup_area = centered_rect(70, 60, area);
 
     frame.render_widget(Clear, popup_area);
 
@@ -1179,11 +1683,8 @@ fn render_confirmation_modal(frame: &mut Frame, area: Rect, _state: &SettingsSta
         vertical: 1,
     });
 
-    let text =
-        "Are you sure you want to save these changes?\n\nPress Enter to confirm, Esc to cancel";
-    let paragraph = Paragraph::new(text)
+    let paragraph = Paragraph::new(change_lines(&state.pending_changes()))
         .style(Style::default().fg(Color::White))
-        .alignment(Alignment::Center)
         .wrap(Wrap { trim: true });
 
     frame.render_widget(paragraph, inner_area);
@@ -1422,5 +1923,91 @@ pub fn get_focusable_components_for_section(section: SettingsSection) -> Vec<Str
             "settings_decimal_precision".to_string(),
             "settings_auto_refresh".to_string(),
         ],
+        SettingsSection::Trading => vec![
+            "settings_expert_mode".to_string(),
+            "settings_expert_max_value".to_string(),
+            "settings_expert_max_impact".to_string(),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded() -> SettingsState {
+        let mut state = SettingsState::new(Config::default());
+        state.load_preferences(&Preferences::default());
+        state
+    }
+
+    #[test]
+    fn test_pending_changes_list_old_and_new_values() {
+        let mut state = loaded();
+        assert!(state.pending_changes().is_empty());
+
+        state.toggle_theme();
+        state.display_form.decimal_precision.set_value("2");
+        state.display_form.form_state.fields.mark_dirty();
+        let changes = state.pending_changes();
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.setting, change.new.as_str(), change.reconnect))
+                .collect::<Vec<_>>(),
+            vec![("Theme", "Dark", false), ("Decimal precision", "2", false)]
+        );
+        // Saving another section leaves the network as it is
+        assert_eq!(
+            state.pending_config().network.rpc_url,
+            state.current_config.network.rpc_url
+        );
+    }
+
+    #[test]
+    fn test_network_changes_reconnect() {
+        let mut state = loaded();
+        // Testnet, then custom with the same values: nothing changes yet
+        state.toggle_network_environment();
+        assert!(state.pending_changes().is_empty());
+
+        state.toggle_network_environment();
+        let changes = state.pending_changes();
+        let network = changes
+            .iter()
+            .find(|change| change.setting == "Network")
+            .unwrap();
+        assert_eq!(
+            (network.old.as_str(), network.new.as_str()),
+            ("mantra-dukong", "mantra-mainnet")
+        );
+        assert!(changes.iter().all(|change| change.reconnect));
+
+        let text: Vec<String> = change_lines(&changes)
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert!(text.contains(&"Network: mantra-dukong → mantra-mainnet *".to_string()));
+        assert!(text.contains(&"* Reconnects the client and restarts background sync".to_string()));
+    }
+
+    #[test]
+    fn test_typing_the_mnemonic_is_sensitive() {
+        let mut state = loaded();
+        state.current_section = SettingsSection::Wallet;
+        state.wallet_form.form_state.editing = true;
+        // Not typed into outside of import mode
+        assert!(!state.is_sensitive_input_focused());
+        state.wallet_form.import_mode = true;
+        assert!(state.is_sensitive_input_focused());
+
+        state.current_section = SettingsSection::Display;
+        state.display_form.form_state.editing = true;
+        assert!(!state.is_sensitive_input_focused());
     }
 }
@@ -3,16 +3,25 @@
 //! This module provides the swap interface for the MANTRA DEX SDK TUI,
 //! allowing users to perform token swaps with price impact calculations,
 //! slippage settings, and transaction execution.
+//!
+//! Users pick the tokens to swap first. The pool list then narrows to the
+//! pools trading the pair, ranked by expected output with the client's pool
+//! selection, and the best one is selected; any other pool can still be picked.
 
+use super::Screen;
+use crate::dca::{DcaPlan, DcaStatus};
+use crate::risk::RiskLevel;
 use crate::tui::{
-    app::{App, LoadingState, SwapState},
+    app::{App, LoadingState, NavigationMode, SwapState},
     components::{
-        forms::{InputType, TextInput},
+        charts::render_depth_chart,
+        forms::{FieldMut, Form, FormEvent, FormFields, InputType, TextInput},
         header::render_header,
         navigation::render_navigation,
-        simple_list::{ListEvent, SimpleList, SimpleListOption},
+        simple_list::{SimpleList, SimpleListOption},
         status_bar::render_status_bar,
     },
+    events::{FocusDirection, UiEvent},
 };
 use mantra_dex_std::pool_manager::SimulationResponse;
 use ratatui::{
@@ -29,6 +38,7 @@ use tui_input::InputRequest;
 pub enum SwapInputFocus {
     Pool,
     FromToken,
+    ToToken,
     FromAmount,
     Slippage,
     Execute,
@@ -39,10 +49,14 @@ pub enum SwapInputFocus {
 pub struct SwapScreenState {
     /// Current input focus
     pub input_focus: SwapInputFocus,
+    /// Field order, validation messages and dirty flag
+    pub form: Form<SwapInputFocus>,
     /// Pool selection list
     pub pool_dropdown: SimpleList,
     /// From token list
     pub from_token_dropdown: SimpleList,
+    /// To token list
+    pub to_token_dropdown: SimpleList,
     /// From amount input
     pub from_amount_input: TextInput,
     /// Slippage tolerance input
@@ -52,21 +66,28 @@ pub struct SwapScreenState {
     pub available_tokens: Vec<String>,
     /// Available pools for the selected token pair
     pub available_pools: Vec<(String, String)>, // (pool_id, display_name)
+    /// Whether the selected pool was picked automatically for the token pair,
+    /// rather than by the user
+    pub pool_auto_selected: bool,
     /// Timer for simulation trigger
     pub simulation_timer: Option<std::time::Instant>,
     /// Last input change time for simulation delay
     pub last_input_change: Option<std::time::Instant>,
+    /// Recurring swaps, shown with their upcoming executions
+    pub dca_plans: Vec<DcaPlan>,
 }
 
 impl Default for SwapScreenState {
     fn default() -> Self {
         let mut pool_dropdown = SimpleList::new("Available Pools");
+        pool_dropdown.set_loading();
 
         // Pool data will be loaded from the blockchain via the app's pool cache
         // No hardcoded test data since it doesn't exist on the actual blockchain
 
         // Start with empty token list - will be populated when a pool is selected
         let from_token_dropdown = SimpleList::new("Pool Tokens");
+        let to_token_dropdown = SimpleList::new("To Token");
 
         let mut from_amount_input = TextInput::new("From Amount")
             .with_type(InputType::Amount)
@@ -81,16 +102,39 @@ impl Default for SwapScreenState {
         // Set initial focus
         from_amount_input.set_focused(false);
 
+        let form = Form::new()
+            .dropdown(
+                SwapInputFocus::FromToken,
+                "Please select a token to swap from",
+            )
+            .dropdown(SwapInputFocus::ToToken, "Please select a token to swap to")
+            .amount(
+                SwapInputFocus::FromAmount,
+                "Please enter an amount to swap",
+                "Please enter a valid amount (numbers only)",
+            )
+            .dropdown(SwapInputFocus::Pool, "Please select a trading pool")
+            .amount(
+                SwapInputFocus::Slippage,
+                "Please set slippage tolerance",
+                "Please enter a valid slippage tolerance (0.1-20%)",
+            )
+            .submit(SwapInputFocus::Execute);
+
         let mut instance = Self {
-            input_focus: SwapInputFocus::Pool,
+            input_focus: SwapInputFocus::FromToken,
+            form,
             pool_dropdown,
             from_token_dropdown,
+            to_token_dropdown,
             from_amount_input,
             slippage_input,
             available_tokens: Vec::new(), // Will be populated when pool is selected
             available_pools: Vec::new(),  // Will be populated from blockchain data
+            pool_auto_selected: false,
             simulation_timer: None,
             last_input_change: None,
+            dca_plans: Vec::new(),
         };
 
         // Apply initial focus
@@ -104,16 +148,14 @@ impl SwapScreenState {
     pub fn initialize_tokens(&mut self, tokens: Vec<String>) {
         self.available_tokens = tokens.clone();
 
-        // Update from token list while preserving focus state
-        let was_active = self.from_token_dropdown.is_active;
-        let mut dropdown = SimpleList::new("Available Tokens");
+        // Update from token list while preserving focus and selection
         let options: Vec<SimpleListOption> = tokens
             .iter()
             .map(|token| SimpleListOption::new(token.clone(), token.clone()))
             .collect();
-        dropdown = dropdown.with_options(options);
-        dropdown.set_active(was_active);
-        self.from_token_dropdown = dropdown;
+        self.from_token_dropdown.label = "Available Tokens".to_string();
+        self.from_token_dropdown.set_options(options.clone());
+        self.to_token_dropdown.set_options(options);
     }
 
     /// Update available pools based on selected tokens
@@ -130,22 +172,85 @@ impl SwapScreenState {
             ));
         }
 
-        self.available_pools = pools.clone();
+        self.available_pools = pools;
 
-        // Update pool list while preserving focus state
-        let was_active = self.pool_dropdown.is_active;
-        let mut dropdown = SimpleList::new("Available Pools");
-        let options: Vec<SimpleListOption> = pools
+        // Update pool list while preserving focus and selection
+        self.update_pools_for_pair();
+
+        crate::tui::utils::logger::log_info("Pool dropdown updated successfully");
+    }
+
+    /// Token pair chosen in the from and to lists
+    pub fn selected_pair(&self) -> Option<(String, String)> {
+        let from = self.from_token_dropdown.get_selected_value()?;
+        let to = self.to_token_dropdown.get_selected_value()?;
+        (from != to).then(|| (from.to_string(), to.to_string()))
+    }
+
+    /// Token bought by the swap: the chosen to token, or else the other token
+    /// of the selected pool
+    pub fn to_token(&self) -> Option<String> {
+        if let Some(to_token) = self.to_token_dropdown.get_selected_value() {
+            return Some(to_token.to_string());
+        }
+        let from_token = self.from_token_dropdown.get_selected_value()?;
+        let pool_name = self.pool_dropdown.get_selected_label()?;
+        let to_token = determine_to_token_from_pool(pool_name, from_token);
+        (to_token != "Unknown").then_some(to_token)
+    }
+
+    /// List the pools trading the chosen pair, or every pool without a pair
+    ///
+    /// The current pool stays selected if it trades the pair; otherwise the
+    /// first one is selected automatically. The app then ranks the pair's pools
+    /// by output, see [`set_ranked_pools`](Self::set_ranked_pools).
+    pub fn update_pools_for_pair(&mut self) {
+        let pair = self.selected_pair();
+        let options: Vec<SimpleListOption> = self
+            .available_pools
             .iter()
+            .filter(|(_, display_name)| {
+                pair.as_ref().is_none_or(|(from, to)| {
+                    let tokens = pool_label_tokens(display_name);
+                    tokens.contains(from) && tokens.contains(to)
+                })
+            })
             .map(|(pool_id, display_name)| {
                 SimpleListOption::new(display_name.clone(), pool_id.clone())
             })
             .collect();
-        dropdown = dropdown.with_options(options);
-        dropdown.set_active(was_active);
-        self.pool_dropdown = dropdown;
+        self.pool_dropdown.set_options(options);
 
-        crate::tui::utils::logger::log_info("Pool dropdown updated successfully");
+        if pair.is_some() && self.pool_dropdown.selected_index.is_none() {
+            self.select_first_pool();
+        }
+    }
+
+    /// Show the pair's pools ranked best first, as `(pool_id, display_name)`
+    ///
+    /// The best pool is selected unless the user picked another one.
+    pub fn set_ranked_pools(&mut self, ranked: Vec<(String, String)>) {
+        let options = ranked
+            .into_iter()
+            .map(|(pool_id, display_name)| SimpleListOption::new(display_name, pool_id))
+            .collect();
+        self.pool_dropdown.set_options(options);
+        if self.pool_auto_selected || self.pool_dropdown.selected_index.is_none() {
+            self.select_first_pool();
+        }
+    }
+
+    /// Select the first listed pool on the user's behalf
+    fn select_first_pool(&mut self) {
+        let first = self
+            .pool_dropdown
+            .options
+            .first()
+            .map(|option| option.value.clone());
+        if let Some(pool_id) = first {
+            self.pool_dropdown.select_value(&pool_id);
+            self.pool_auto_selected = true;
+        }
     }
 
     /// Update token list based on selected pool
@@ -212,30 +317,16 @@ impl SwapScreenState {
             tokens_for_pool
         };
 
-        // Preserve current state
-        let was_active = self.from_token_dropdown.is_active;
-        let was_editing = self.from_token_dropdown.is_editing;
-
-        // Update the options while preserving state
         let options: Vec<SimpleListOption> = tokens_for_pool
             .iter()
             .map(|token| SimpleListOption::new(token.clone(), token.clone()))
             .collect();
-
-        self.from_token_dropdown.options = options;
         self.from_token_dropdown.label = "Pool Tokens".to_string();
-
-        // Restore state
-        self.from_token_dropdown.is_active = was_active;
-        self.from_token_dropdown.is_editing = was_editing;
-
-        // Reset selection and list state
+        self.from_token_dropdown.set_options(options.clone());
+        self.to_token_dropdown.set_options(options);
+        // A different pool means a different token pair, so start over
         self.from_token_dropdown.selected_index = None;
-        if !tokens_for_pool.is_empty() {
-            self.from_token_dropdown.list_state.select(Some(0));
-        } else {
-            self.from_token_dropdown.list_state.select(None);
-        }
+        self.to_token_dropdown.selected_index = None;
 
         crate::tui::utils::logger::log_info(&format!(
             "Token dropdown updated with {} tokens",
@@ -247,6 +338,7 @@ impl SwapScreenState {
     fn clear_focus(&mut self) {
         self.pool_dropdown.set_active(false);
         self.from_token_dropdown.set_active(false);
+        self.to_token_dropdown.set_active(false);
         self.from_amount_input.set_focused(false);
         self.slippage_input.set_focused(false);
     }
@@ -256,24 +348,9 @@ impl SwapScreenState {
         self.clear_focus();
     }
 
-    /// Set focus on current input
-    fn set_focus(&mut self) {
-        match self.input_focus {
-            SwapInputFocus::Pool => {
-                self.pool_dropdown.set_active(true);
-            }
-            SwapInputFocus::FromToken => {
-                self.from_token_dropdown.set_active(true);
-            }
-            SwapInputFocus::FromAmount => self.from_amount_input.set_focused(true),
-            SwapInputFocus::Slippage => self.slippage_input.set_focused(true),
-            SwapInputFocus::Execute => {} // Button focus handled separately
-        }
-    }
-
     /// Public wrapper to apply focus based on `input_focus` value (used by external modules)
     pub fn apply_focus(&mut self) {
-        self.set_focus();
+        self.apply_form_focus();
     }
 
     /// Mark input change for simulation trigger
@@ -299,7 +376,9 @@ impl SwapScreenState {
 
     /// Check if any list is currently in editing mode
     pub fn is_any_list_editing(&self) -> bool {
-        self.pool_dropdown.is_editing || self.from_token_dropdown.is_editing
+        self.pool_dropdown.is_editing
+            || self.from_token_dropdown.is_editing
+            || self.to_token_dropdown.is_editing
     }
 
     /// Handle keyboard input using direct key events
@@ -331,109 +410,55 @@ impl SwapScreenState {
             crate::tui::utils::logger::log_debug(&format!("Current focus: {:?}", self.input_focus));
         }
 
-        // Handle regular input focus
-        match self.input_focus {
-            SwapInputFocus::Pool => {
-                let old_selection = self.pool_dropdown.selected_index;
-                let list_event = self.pool_dropdown.handle_key_event(key);
-
-                // Only update tokens when selection is confirmed, not during navigation
-                if list_event == ListEvent::SelectionMade {
-                    if let Some(selected_pool_value) = self
-                        .pool_dropdown
-                        .get_selected_value()
-                        .map(|v| v.to_string())
-                    {
-                        self.update_tokens_for_pool(&selected_pool_value);
-                    }
-                    self.mark_input_change();
-                }
-
-                if list_event == ListEvent::SelectionMade
-                    || list_event == ListEvent::SelectionCancelled
-                {
-                    self.next_focus();
-                }
-
-                list_event != ListEvent::Ignored
-            }
-            SwapInputFocus::FromToken => {
-                let old_selection = self.from_token_dropdown.selected_index;
-                let list_event = self.from_token_dropdown.handle_key_event(key);
-
-                if self.from_token_dropdown.selected_index != old_selection {
-                    self.mark_input_change();
-                }
-
-                if list_event == ListEvent::SelectionMade
-                    || list_event == ListEvent::SelectionCancelled
-                {
-                    self.next_focus();
-                }
-
-                list_event != ListEvent::Ignored
-            }
-            SwapInputFocus::FromAmount => {
-                let input_request = match key.code {
-                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
-                    KeyCode::Right => Some(InputRequest::GoToNextChar),
-                    KeyCode::Home => Some(InputRequest::GoToStart),
-                    KeyCode::End => Some(InputRequest::GoToEnd),
-                    _ => None,
-                };
-
-                if let Some(request) = input_request {
-                    if self.from_amount_input.handle_input(request).is_some() {
-                        self.mark_input_change();
-                        return true;
-                    }
-                }
-                false
+        let focused = self.input_focus;
+        match self.handle_form_key(key) {
+            FormEvent::Ignored => false,
+            FormEvent::Handled | FormEvent::Cancelled => true,
+            FormEvent::Changed => {
+                self.mark_input_change();
+                true
             }
-            SwapInputFocus::Slippage => {
-                let input_request = match key.code {
-                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
-                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
-                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
-                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
-                    KeyCode::Right => Some(InputRequest::GoToNextChar),
-                    KeyCode::Home => Some(InputRequest::GoToStart),
-                    KeyCode::End => Some(InputRequest::GoToEnd),
-                    _ => None,
-                };
-
-                if let Some(request) = input_request {
-                    if self.slippage_input.handle_input(request).is_some() {
-                        self.mark_input_change();
-                        return true;
+            FormEvent::Selected => {
+                // Only update tokens when selection is confirmed, not during navigation
+                match focused {
+                    SwapInputFocus::Pool => {
+                        self.pool_auto_selected = false;
+                        // Picking a pool before the tokens narrows the tokens to its pair
+                        if self.selected_pair().is_none() {
+                            if let Some(selected_pool_value) = self
+                                .pool_dropdown
+                                .get_selected_value()
+                                .map(|v| v.to_string())
+                            {
+                                self.update_tokens_for_pool(&selected_pool_value);
+                            }
+                        }
                     }
-                }
-                false
-            }
-            SwapInputFocus::Execute => {
-                // Handle execute button activation
-                match key.code {
-                    KeyCode::Enter | KeyCode::Char(' ') => {
-                        if self.validate() {
-                            // Mark that we're starting a swap process for better UX feedback
-                            self.mark_input_change();
-                            // Send ShowSwapConfirmation event to trigger the global modal
-                            // This event will be handled by the main app event loop
-                            crate::tui::utils::logger::log_info(
-                                "Swap execute button pressed - validation passed",
-                            );
-                            return true; // Event will be handled by app
-                        } else {
-                            crate::tui::utils::logger::log_warning(
-                                "Swap validation failed - please check all fields",
-                            );
+                    SwapInputFocus::FromToken | SwapInputFocus::ToToken => {
+                        // A new pair gets its best pool picked again
+                        if self.selected_pair().is_some() {
+                            self.pool_dropdown.selected_index = None;
                         }
+                        self.update_pools_for_pair();
                     }
                     _ => {}
                 }
+                self.mark_input_change();
+                true
+            }
+            FormEvent::Submit => {
+                // Mark that we're starting a swap process for better UX feedback
+                self.mark_input_change();
+                // The app shows the global confirmation modal for this event
+                crate::tui::utils::logger::log_info(
+                    "Swap execute button pressed - validation passed",
+                );
+                true
+            }
+            FormEvent::Invalid => {
+                crate::tui::utils::logger::log_warning(
+                    "Swap validation failed - please check all fields",
+                );
                 false
             }
         }
@@ -448,69 +473,22 @@ impl SwapScreenState {
 
     /// Move to next focus (for testing/compatibility)
     pub fn next_focus(&mut self) {
-        self.input_focus = match self.input_focus {
-            SwapInputFocus::Pool => SwapInputFocus::FromToken,
-            SwapInputFocus::FromToken => SwapInputFocus::FromAmount,
-            SwapInputFocus::FromAmount => SwapInputFocus::Slippage,
-            SwapInputFocus::Slippage => SwapInputFocus::Execute,
-            SwapInputFocus::Execute => SwapInputFocus::Pool,
-        };
-        self.clear_focus();
-        self.set_focus();
+        self.focus_next_field();
     }
 
     /// Move to previous focus
     pub fn previous_focus(&mut self) {
-        self.input_focus = match self.input_focus {
-            SwapInputFocus::Pool => SwapInputFocus::Execute,
-            SwapInputFocus::FromToken => SwapInputFocus::Pool,
-            SwapInputFocus::FromAmount => SwapInputFocus::FromToken,
-            SwapInputFocus::Slippage => SwapInputFocus::FromAmount,
-            SwapInputFocus::Execute => SwapInputFocus::Slippage,
-        };
-        self.clear_focus();
-        self.set_focus();
+        self.focus_previous_field();
     }
 
     /// Validate all inputs
     pub fn validate(&mut self) -> bool {
-        let pool_valid = self.pool_dropdown.get_selected_value().is_some();
-        let from_token_valid = self.from_token_dropdown.get_selected_value().is_some();
-        let amount_valid = self.from_amount_input.validate();
-        let slippage_valid = self.slippage_input.validate();
-
-        pool_valid && from_token_valid && amount_valid && slippage_valid
+        self.validate_form()
     }
 
     /// Get detailed validation errors for user feedback
     pub fn get_validation_errors(&mut self) -> Vec<String> {
-        let mut errors = Vec::new();
-
-        if self.pool_dropdown.get_selected_value().is_none() {
-            errors.push("Please select a trading pool".to_string());
-        }
-
-        if self.from_token_dropdown.get_selected_value().is_none() {
-            errors.push("Please select a token to swap from".to_string());
-        }
-
-        if !self.from_amount_input.validate() {
-            if self.from_amount_input.value().is_empty() {
-                errors.push("Please enter an amount to swap".to_string());
-            } else {
-                errors.push("Please enter a valid amount (numbers only)".to_string());
-            }
-        }
-
-        if !self.slippage_input.validate() {
-            if self.slippage_input.value().is_empty() {
-                errors.push("Please set slippage tolerance".to_string());
-            } else {
-                errors.push("Please enter a valid slippage tolerance (0.1-20%)".to_string());
-            }
-        }
-
-        errors
+        self.form_errors()
     }
 
     /// Show confirmation modal using global app state
@@ -529,9 +507,16 @@ impl SwapScreenState {
             swap_details.fee_amount,
             swap_details.from_token
         );
+        if swap_details.risk_warnings.is_empty() {
+            return message;
+        }
 
-        // Return the message for the global app to handle
-        message
+        let warnings: Vec<String> = swap_details
+            .risk_warnings
+            .iter()
+            .map(|warning| format!("⚠ {}", warning))
+            .collect();
+        format!("{}\n\nRisk warnings:\n{}", message, warnings.join("\n"))
     }
 
     /// Hide confirmation modal (now handled by global app state)
@@ -553,23 +538,93 @@ pub struct SwapDetails {
     pub expected_output: String,
     pub price_impact: f64,
     pub fee_amount: String,
+    /// Red flags raised by the pool's risk screening
+    pub risk_warnings: Vec<String>,
 }
 
-// Global swap screen state - in a real implementation this would be part of the app state
-static mut SWAP_SCREEN_STATE: Option<SwapScreenState> = None;
+impl FormFields for SwapScreenState {
+    type Key = SwapInputFocus;
+
+    fn form(&self) -> &Form<SwapInputFocus> {
+        &self.form
+    }
+
+    fn form_mut(&mut self) -> &mut Form<SwapInputFocus> {
+        &mut self.form
+    }
+
+    fn focused_field(&self) -> SwapInputFocus {
+        self.input_focus
+    }
 
-/// Get or initialize the swap screen state
-pub(crate) fn get_swap_screen_state() -> &'static mut SwapScreenState {
-    unsafe {
-        if SWAP_SCREEN_STATE.is_none() {
-            SWAP_SCREEN_STATE = Some(SwapScreenState::default());
+    fn set_focused_field(&mut self, key: SwapInputFocus) {
+        self.input_focus = key;
+    }
+
+    fn field_mut(&mut self, key: SwapInputFocus) -> FieldMut<'_> {
+        match key {
+            SwapInputFocus::Pool => FieldMut::Dropdown(&mut self.pool_dropdown),
+            SwapInputFocus::FromToken => FieldMut::Dropdown(&mut self.from_token_dropdown),
+            SwapInputFocus::ToToken => FieldMut::Dropdown(&mut self.to_token_dropdown),
+            SwapInputFocus::FromAmount => FieldMut::Text(&mut self.from_amount_input),
+            SwapInputFocus::Slippage => FieldMut::Text(&mut self.slippage_input),
+            SwapInputFocus::Execute => FieldMut::None,
         }
-        SWAP_SCREEN_STATE.as_mut().unwrap()
+    }
+}
+
+impl Screen for SwapScreenState {
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let within_screen = navigation_mode == NavigationMode::WithinScreen;
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        match event {
+            UiEvent::MoveFocus(direction @ (FocusDirection::Up | FocusDirection::Down)) => {
+                // Up/down only belong to the screen while a dropdown is open;
+                // otherwise global focus management moves between fields
+                let dropdown_open = matches!(
+                    self.input_focus,
+                    SwapInputFocus::Pool | SwapInputFocus::FromToken | SwapInputFocus::ToToken
+                ) && self.is_any_list_editing();
+                if !within_screen || !dropdown_open {
+                    return false;
+                }
+                let code = if *direction == FocusDirection::Up {
+                    KeyCode::Up
+                } else {
+                    KeyCode::Down
+                };
+                self.handle_key_event(key(code), navigation_mode)
+            }
+            UiEvent::Tab if within_screen => {
+                self.next_focus();
+                true
+            }
+            UiEvent::BackTab if within_screen => {
+                self.previous_focus();
+                true
+            }
+            UiEvent::Enter => self.handle_key_event(key(KeyCode::Enter), navigation_mode),
+            UiEvent::Char(c) => self.handle_key_event(key(KeyCode::Char(*c)), navigation_mode),
+            UiEvent::Backspace => self.handle_key_event(key(KeyCode::Backspace), navigation_mode),
+            UiEvent::Escape => {
+                // Let the form clean up, but leave the navigation mode switch to the app
+                self.handle_key_event(key(KeyCode::Esc), navigation_mode);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, app: &App) {
+        render_swap(f, app, self);
     }
 }
 
 /// Render the complete swap screen
-pub fn render_swap(f: &mut Frame, app: &App) {
+pub fn render_swap(f: &mut Frame, app: &App, swap_state: &mut SwapScreenState) {
     let size = f.area();
 
     // Create main layout: header, nav, content, status
@@ -588,16 +643,15 @@ pub fn render_swap(f: &mut Frame, app: &App) {
     render_navigation(f, &app.state, chunks[1]);
 
     // Check for simulation trigger (this should ideally be in the main event loop, but putting here for now)
-    let swap_state = get_swap_screen_state();
     if swap_state.should_trigger_simulation() {
         // Reset the timer to prevent repeated triggers
         if let Some(sender) = app.get_event_sender() {
-            let _ = sender.send(crate::tui::events::Event::TriggerSimulation);
+            let _ = sender.send(crate::tui::events::UiEvent::TriggerSimulation);
         }
     }
 
     // Render swap content
-    render_swap_content(f, chunks[2], app);
+    render_swap_content(f, chunks[2], app, swap_state);
 
     // Render status bar with enhanced error information
     render_status_bar(f, &app.state, chunks[3]);
@@ -605,12 +659,12 @@ pub fn render_swap(f: &mut Frame, app: &App) {
     // Modal rendering is now handled by the global app modal system
     // But we can also check for validation errors and display them as temporary overlays
     if app.state.current_screen == crate::tui::app::Screen::Swap {
-        render_validation_overlay(f, size, app);
+        render_validation_overlay(f, size, swap_state);
     }
 }
 
 /// Render the main swap content area
-fn render_swap_content(f: &mut Frame, area: Rect, app: &App) {
+fn render_swap_content(f: &mut Frame, area: Rect, app: &App, swap_state: &mut SwapScreenState) {
     // Create a horizontal layout: swap interface | simulation results only
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -624,13 +678,72 @@ fn render_swap_content(f: &mut Frame, area: Rect, app: &App) {
         .split(main_chunks[0]);
 
     // Render components
-    render_swap_interface(f, left_chunks[0], app);
-    render_execute_button(f, left_chunks[1], app);
-    render_simulation_results(f, main_chunks[1], app);
+    render_swap_interface(f, left_chunks[0], app, swap_state);
+    render_execute_button(f, left_chunks[1], app, swap_state);
+
+    // Split the right side: simulation results on top, recurring swaps below
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(main_chunks[1]);
+    render_simulation_results(f, right_chunks[0], app, swap_state);
+    render_dca_plans(f, right_chunks[1], swap_state);
+}
+
+/// Number of upcoming executions listed per DCA plan
+const DCA_UPCOMING: usize = 3;
+
+/// Render the recurring swaps panel
+fn render_dca_plans(f: &mut Frame, area: Rect, swap_state: &SwapScreenState) {
+    let block = Block::default()
+        .title("Recurring Swaps (DCA)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .padding(Padding::uniform(1));
+
+    let paragraph = Paragraph::new(Text::from(dca_plan_lines(
+        &swap_state.dca_plans,
+        chrono::Utc::now(),
+    )))
+    .block(block)
+    .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
+
+/// Active and paused plans, each followed by its next executions
+fn dca_plan_lines(plans: &[DcaPlan], now: chrono::DateTime<chrono::Utc>) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for plan in plans {
+        let status_color = match plan.status {
+            DcaStatus::Active => Color::Green,
+            DcaStatus::Paused => Color::Yellow,
+            DcaStatus::Completed | DcaStatus::Cancelled => continue,
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("#{} ", plan.id), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{} ", plan)),
+            Span::styled(plan.status.to_string(), Style::default().fg(status_color)),
+        ]));
+        for at in plan.upcoming(DCA_UPCOMING, now) {
+            lines.push(Line::from(Span::styled(
+                format!("  next {}", at.format("%Y-%m-%d %H:%M UTC")),
+                Style::default().fg(Color::Magenta),
+            )));
+        }
+    }
+
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No recurring swaps. Create one with `mantra-dex dca create`",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    lines
 }
 
 /// Render the swap input interface
-fn render_swap_interface(f: &mut Frame, area: Rect, app: &App) {
+fn render_swap_interface(f: &mut Frame, area: Rect, app: &App, swap_state: &mut SwapScreenState) {
     let block = Block::default()
         .title("Swap Interface")
         .borders(Borders::ALL)
@@ -641,19 +754,22 @@ fn render_swap_interface(f: &mut Frame, area: Rect, app: &App) {
     let input_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(8), // Pool selection list
-            Constraint::Length(8), // Token selection list
+            Constraint::Length(8), // From and to token lists
             Constraint::Length(5), // From amount input (increased for better visibility)
+            Constraint::Length(8), // Pool selection list, ranked for the pair
             Constraint::Length(5), // Slippage tolerance (increased for better visibility)
         ])
         .split(block.inner(area));
-
-    let swap_state = get_swap_screen_state();
+    let token_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(input_chunks[0]);
 
     // Render form inputs
-    render_pool_selection(f, input_chunks[0], app, swap_state);
-    render_from_token_input(f, input_chunks[1], app, swap_state);
-    render_from_amount_input(f, input_chunks[2], app, swap_state);
+    render_from_token_input(f, token_chunks[0], app, swap_state);
+    render_to_token_input(f, token_chunks[1], app, swap_state);
+    render_from_amount_input(f, input_chunks[1], app, swap_state);
+    render_pool_selection(f, input_chunks[2], app, swap_state);
     render_slippage_input(f, input_chunks[3], app, swap_state);
 
     f.render_widget(block, area);
@@ -674,6 +790,11 @@ fn render_from_token_input(
     swap_state.from_token_dropdown.render(f, area);
 }
 
+/// Render to token selection list
+fn render_to_token_input(f: &mut Frame, area: Rect, _app: &App, swap_state: &mut SwapScreenState) {
+    swap_state.to_token_dropdown.render(f, area);
+}
+
 /// Render from amount input with balance display
 fn render_from_amount_input(
     f: &mut Frame,
@@ -776,8 +897,7 @@ fn render_slippage_input(f: &mut Frame, area: Rect, _app: &App, swap_state: &mut
 }
 
 /// Render execute button
-fn render_execute_button(f: &mut Frame, area: Rect, app: &App) {
-    let swap_state = get_swap_screen_state();
+fn render_execute_button(f: &mut Frame, area: Rect, app: &App, swap_state: &mut SwapScreenState) {
     let is_focused = matches!(swap_state.input_focus, SwapInputFocus::Execute);
     let is_valid = swap_state.clone().validate();
 
@@ -869,14 +989,12 @@ fn render_execute_button(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render swap preview panel
-fn _render_swap_preview(f: &mut Frame, area: Rect, app: &App) {
+fn _render_swap_preview(f: &mut Frame, area: Rect, app: &App, swap_state: &mut SwapScreenState) {
     let block = Block::default()
         .title("Swap Preview")
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue))
         .padding(Padding::uniform(1));
-
-    let swap_state = get_swap_screen_state();
     let from_amount = swap_state.from_amount_input.value();
     let from_token = swap_state
         .from_token_dropdown
@@ -953,6 +1071,23 @@ fn _render_swap_preview(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+/// Token symbols of a pool list label, "Pool X: TokenA (amount) / TokenB (amount)"
+fn pool_label_tokens(pool_info: &str) -> Vec<String> {
+    let Some(pair_part) = pool_info.split(": ").nth(1) else {
+        return Vec::new();
+    };
+    pair_part
+        .split(" / ")
+        .map(|token_with_amount| {
+            let token_with_amount = token_with_amount.trim();
+            match token_with_amount.find('(') {
+                Some(paren_pos) => token_with_amount[..paren_pos].trim().to_string(),
+                None => token_with_amount.to_string(),
+            }
+        })
+        .collect()
+}
+
 /// Determine the "to token" from the selected pool and from token
 pub fn determine_to_token_from_pool(pool_info: &str, from_token: &str) -> String {
     // Extract the asset pair from pool display name (format: "Pool X: TokenA (amount) / TokenB (amount)")
@@ -983,18 +1118,23 @@ pub fn determine_to_token_from_pool(pool_info: &str, from_token: &str) -> String
 }
 
 /// Render simulation results panel
-fn render_simulation_results(f: &mut Frame, area: Rect, app: &App) {
+fn render_simulation_results(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    swap_state: &mut SwapScreenState,
+) {
     let block = Block::default()
         .title("Simulation Results")
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Magenta))
         .padding(Padding::uniform(1));
 
-    let swap_state = get_swap_screen_state();
-
     // Enhanced loading state display for swap operations
     let content = if let Some(ref simulation) = app.state.swap_state.simulation_result {
-        render_simulation_details(simulation)
+        let mut lines = render_simulation_details(simulation);
+        lines.extend(render_price_impact(app, swap_state));
+        lines
     } else if matches!(app.state.loading_state, LoadingState::Loading { .. }) {
         // Show detailed loading information
         if let LoadingState::Loading { message, .. } = &app.state.loading_state {
@@ -1068,7 +1208,62 @@ fn render_simulation_results(f: &mut Frame, area: Rect, app: &App) {
         .block(block)
         .wrap(Wrap { trim: true });
 
-    f.render_widget(paragraph, area);
+    // Show the selected pool's depth curve below the results once it has been sampled
+    match &app.state.swap_state.depth_curve {
+        Some(curve) if !curve.points.is_empty() => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            f.render_widget(paragraph, chunks[0]);
+            render_depth_chart(f, curve, chunks[1]);
+        }
+        _ => f.render_widget(paragraph, area),
+    }
+}
+
+/// Render the price impact of the swap in the form, with a warning if it is high
+///
+/// Empty when the selected pool can't be simulated locally.
+fn render_price_impact(app: &App, swap_state: &SwapScreenState) -> Vec<Line<'static>> {
+    let pool_id = swap_state.pool_dropdown.get_selected_value();
+    let from_token = swap_state.from_token_dropdown.get_selected_value();
+    let (Some(pool_id), Some(from_token), Some(to_token)) =
+        (pool_id, from_token, swap_state.to_token())
+    else {
+        return Vec::new();
+    };
+    let Some((_, impact)) = app.estimate_swap(
+        pool_id,
+        from_token,
+        &to_token,
+        swap_state.from_amount_input.value(),
+    ) else {
+        return Vec::new();
+    };
+
+    let color = match impact.level() {
+        Some(RiskLevel::Danger) => Color::Red,
+        Some(RiskLevel::Caution) => Color::Yellow,
+        None => Color::Green,
+    };
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Price Impact: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format!("{:.2}%", impact_percent(&impact)),
+                Style::default().fg(color),
+            ),
+        ]),
+    ];
+    if let Some(warning) = impact.warning() {
+        lines.push(Line::from(vec![Span::styled(
+            format!("⚠ {}", warning),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )]));
+    }
+    lines
 }
 
 /// Render detailed simulation results
@@ -1146,15 +1341,48 @@ fn _calculate_price_impact(amount: &str, _swap_state: &SwapState) -> f64 {
     }
 }
 
+/// Price impact of a simulated swap, in percent
+///
+/// Measured as the slippage against the swap's return at the pre-trade price.
+pub fn price_impact_percent(simulation: &SimulationResponse) -> f64 {
+    let ideal_return = simulation.return_amount
+        + simulation.slippage_amount
+        + simulation.swap_fee_amount
+        + simulation.protocol_fee_amount
+        + simulation.burn_fee_amount
+        + simulation.extra_fees_amount;
+    if ideal_return.is_zero() {
+        return 0.0;
+    }
+    simulation.slippage_amount.u128() as f64 / ideal_return.u128() as f64 * 100.0
+}
+
+/// Price impact of a swap estimate, in percent
+pub fn impact_percent(impact: &crate::pricing::PriceImpact) -> f64 {
+    impact
+        .price_impact
+        .to_string()
+        .parse::<f64>()
+        .unwrap_or(0.0)
+        * 100.0
+}
+
+/// USD value of `amount` of `token`, known only for USD stablecoins
+pub fn stablecoin_value(token: &str, amount: f64) -> Option<f64> {
+    matches!(token, "USDC" | "USDT").then_some(amount)
+}
+
 /// Handle input for the swap screen (delegated from app)
-pub fn handle_swap_screen_input(input: InputRequest) -> bool {
-    let swap_state = get_swap_screen_state();
-    swap_state.handle_input(input)
+pub fn handle_swap_screen_input(
+    app_state: &mut crate::tui::app::AppState,
+    input: InputRequest,
+) -> bool {
+    app_state.swap_screen_state.handle_input(input)
 }
 
 /// Execute swap with confirmation
-pub fn execute_swap_with_confirmation() {
-    let swap_state = get_swap_screen_state();
+pub fn execute_swap_with_confirmation(app_state: &mut crate::tui::app::AppState) {
+    let swap_state = &mut app_state.swap_screen_state;
 
     // Log swap execution attempt
     crate::tui::utils::logger::log_info("=== SWAP EXECUTION ATTEMPT ===");
@@ -1196,13 +1424,9 @@ pub fn execute_swap_with_confirmation() {
     let slippage = swap_state.slippage_input.value();
     crate::tui::utils::logger::log_info(&format!("  Slippage Tolerance: {}%", slippage));
 
-    // Get the "to" token from the selected pool
-    let to_token = if let Some(pool_name) = swap_state.pool_dropdown.get_selected_label() {
-        determine_to_token_from_pool(&pool_name, &from_token)
-    } else {
-        crate::tui::utils::logger::log_error(
-            "Swap failed: No pool name available for token determination",
-        );
+    // Get the "to" token chosen for the swap
+    let Some(to_token) = swap_state.to_token() else {
+        crate::tui::utils::logger::log_error("Swap failed: No token selected to swap to");
         return;
     };
 
@@ -1253,6 +1477,11 @@ pub fn execute_swap_with_confirmation() {
         expected_output: expected_output.clone(),
         price_impact,
         fee_amount,
+        risk_warnings: app_state
+            .pool_risks
+            .get(pool_id_str)
+            .map(crate::risk::RiskReport::warnings)
+            .unwrap_or_default(),
     };
 
     // Show confirmation modal using global app state
@@ -1274,8 +1503,11 @@ pub fn execute_swap_with_confirmation() {
 }
 
 /// Handle confirmation modal response
-pub fn handle_confirmation_response(confirmed: bool) -> Option<crate::tui::events::Event> {
-    let swap_state = get_swap_screen_state();
+pub fn handle_confirmation_response(
+    app_state: &mut crate::tui::app::AppState,
+    confirmed: bool,
+) -> Option<crate::tui::events::TxEvent> {
+    let swap_state = &mut app_state.swap_screen_state;
     swap_state.hide_confirmation_modal();
 
     crate::tui::utils::logger::log_info(&format!(
@@ -1305,12 +1537,10 @@ pub fn handle_confirmation_response(confirmed: bool) -> Option<crate::tui::event
 
         let slippage = swap_state.slippage_input.value();
 
-        // Get the "to" token from the selected pool
-        let to_token = if let Some(pool_name) = swap_state.pool_dropdown.get_selected_label() {
-            determine_to_token_from_pool(&pool_name, &from_token)
-        } else {
+        // Get the "to" token chosen for the swap
+        let Some(to_token) = swap_state.to_token() else {
             crate::tui::utils::logger::log_error(
-                "Swap execution failed: No pool name available for token determination",
+                "Swap execution failed: No token selected to swap to",
             );
             return None;
         };
@@ -1324,7 +1554,7 @@ pub fn handle_confirmation_response(confirmed: bool) -> Option<crate::tui::event
         crate::tui::utils::logger::log_info(&format!("  Slippage Tolerance: {}%", slippage));
 
         // Return the ExecuteSwap event to be processed by the main app
-        let execute_event = crate::tui::events::Event::ExecuteSwap {
+        let execute_event = crate::tui::events::TxEvent::ExecuteSwap {
             from_asset: from_token.to_string(),
             to_asset: to_token,
             amount: from_amount.to_string(),
@@ -1342,11 +1572,11 @@ pub fn handle_confirmation_response(confirmed: bool) -> Option<crate::tui::event
 }
 
 /// Reset swap form
-pub fn reset_swap_form() {
+pub fn reset_swap_form(app_state: &mut crate::tui::app::AppState) {
     crate::tui::utils::logger::log_info("=== SWAP FORM RESET ===");
     crate::tui::utils::logger::log_info("Resetting swap form to default state");
 
-    let swap_state = get_swap_screen_state();
+    let swap_state = &mut app_state.swap_screen_state;
 
     // Preserve pool data before reset
     let available_pools = swap_state.available_pools.clone();
@@ -1373,9 +1603,7 @@ pub fn reset_swap_form() {
 }
 
 /// Render validation error overlay for immediate feedback
-fn render_validation_overlay(f: &mut Frame, area: Rect, app: &App) {
-    let swap_state = get_swap_screen_state();
-
+fn render_validation_overlay(f: &mut Frame, area: Rect, swap_state: &mut SwapScreenState) {
     // Only show validation errors when the execute button is focused and validation fails
     if !matches!(swap_state.input_focus, SwapInputFocus::Execute) {
         return;
@@ -1434,16 +1662,128 @@ fn render_validation_overlay(f: &mut Frame, area: Rect, app: &App) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_dca_panel_lists_upcoming_executions_of_live_plans() {
+        use crate::dca::{DcaBook, DcaEnd, NewDcaPlan};
+        use chrono::TimeZone;
+
+        let now = chrono::Utc
+            .with_ymd_and_hms(2026, 10, 17, 12, 0, 0)
+            .unwrap();
+        let plan = |max_executions| NewDcaPlan {
+            pool_id: "p1".to_string(),
+            offer_asset: cosmwasm_std::Coin::new(100_000_000u128, "uusdc"),
+            ask_asset_denom: "uom".to_string(),
+            interval_secs: 6 * 3600,
+            max_slippage: None,
+            end: DcaEnd {
+                max_executions: Some(max_executions),
+                until: None,
+            },
+            start_at: None,
+        };
+        let mut book = DcaBook::new();
+        book.add("main", plan(2), now).unwrap();
+        let cancelled = book.add("main", plan(5), now).unwrap();
+        book.cancel(cancelled).unwrap();
+
+        let text: Vec<String> = dca_plan_lines(book.plans(), now)
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(text.len(), 3);
+        assert!(text[0].starts_with("#1 ") && text[0].ends_with("active"));
+        assert_eq!(text[1], "  next 2026-10-17 12:00 UTC");
+        assert_eq!(text[2], "  next 2026-10-17 18:00 UTC");
+
+        assert_eq!(dca_plan_lines(&[], now).len(), 1);
+    }
+
     #[test]
     fn test_swap_screen_state_navigation() {
         let mut state = SwapScreenState::default();
-        assert_eq!(state.input_focus, SwapInputFocus::Pool);
+        assert_eq!(state.input_focus, SwapInputFocus::FromToken);
 
         state.next_focus();
-        assert_eq!(state.input_focus, SwapInputFocus::FromToken);
+        assert_eq!(state.input_focus, SwapInputFocus::ToToken);
 
         state.next_focus();
         assert_eq!(state.input_focus, SwapInputFocus::FromAmount);
+
+        state.next_focus();
+        assert_eq!(state.input_focus, SwapInputFocus::Pool);
+    }
+
+    #[test]
+    fn test_screen_events_only_touch_their_own_instance() {
+        let mut first = SwapScreenState::default();
+        let second = SwapScreenState::default();
+
+        // Tab is left to screen switching at the screen level
+        assert!(!first.handle_event(&UiEvent::Tab, NavigationMode::ScreenLevel));
+        assert_eq!(first.input_focus, SwapInputFocus::FromToken);
+
+        assert!(first.handle_event(&UiEvent::Tab, NavigationMode::WithinScreen));
+        assert_eq!(first.input_focus, SwapInputFocus::ToToken);
+        assert_eq!(second.input_focus, SwapInputFocus::FromToken);
+    }
+
+    #[test]
+    fn test_pools_follow_token_pair() {
+        let mut state = SwapScreenState::default();
+        state.initialize_tokens(vec![
+            "OM".to_string(),
+            "USDC".to_string(),
+            "ATOM".to_string(),
+        ]);
+        state.update_available_pools(vec![
+            ("p1".to_string(), "Pool p1: OM (10) / USDC (20)".to_string()),
+            ("p2".to_string(), "Pool p2: OM (5) / ATOM (1)".to_string()),
+            ("p3".to_string(), "Pool p3: USDC (9) / OM (3)".to_string()),
+        ]);
+        assert_eq!(state.pool_dropdown.options.len(), 3);
+        assert_eq!(state.pool_dropdown.get_selected_value(), None);
+
+        state.from_token_dropdown.select_value("OM");
+        state.to_token_dropdown.select_value("USDC");
+        state.update_pools_for_pair();
+        let listed: Vec<&str> = state
+            .pool_dropdown
+            .options
+            .iter()
+            .map(|option| option.value.as_str())
+            .collect();
+        assert_eq!(listed, ["p1", "p3"]);
+        assert_eq!(state.pool_dropdown.get_selected_value(), Some("p1"));
+        assert!(state.pool_auto_selected);
+        assert_eq!(state.to_token().as_deref(), Some("USDC"));
+
+        // Ranking moves the selection to the best pool
+        state.set_ranked_pools(vec![
+            (
+                "p3".to_string(),
+                "Pool p3: USDC (9) / OM (3) → 2 USDC".to_string(),
+            ),
+            (
+                "p1".to_string(),
+                "Pool p1: OM (10) / USDC (20) → 1 USDC".to_string(),
+            ),
+        ]);
+        assert_eq!(state.pool_dropdown.get_selected_value(), Some("p3"));
+
+        // A pool the user picked is kept when the ranking changes
+        state.pool_dropdown.select_value("p1");
+        state.pool_auto_selected = false;
+        state.set_ranked_pools(vec![
+            ("p3".to_string(), "Pool p3".to_string()),
+            ("p1".to_string(), "Pool p1".to_string()),
+        ]);
+        assert_eq!(state.pool_dropdown.get_selected_value(), Some("p1"));
     }
 
     #[test]
@@ -1474,6 +1814,22 @@ mod tests {
         assert_eq!(result, "99.7000");
     }
 
+    #[test]
+    fn test_price_impact_from_simulation() {
+        let simulation = SimulationResponse {
+            return_amount: 980u128.into(),
+            slippage_amount: 10u128.into(),
+            swap_fee_amount: 10u128.into(),
+            protocol_fee_amount: 0u128.into(),
+            burn_fee_amount: 0u128.into(),
+            extra_fees_amount: 0u128.into(),
+        };
+        assert!((price_impact_percent(&simulation) - 1.0).abs() < 1e-9);
+
+        assert_eq!(stablecoin_value("USDC", 25.0), Some(25.0));
+        assert_eq!(stablecoin_value("OM", 25.0), None);
+    }
+
     #[test]
     fn test_calculate_price_impact() {
         let swap_state = SwapState::default();
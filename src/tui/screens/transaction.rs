@@ -6,17 +6,24 @@
 //! - Export functionality for transaction data
 
 use crate::tui::{
-    app::{App, TransactionInfo, TransactionStatus},
+    app::{App, NavigationMode, TransactionInfo, TransactionStatus},
     components::{
-        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
-        tables::format_large_number,
+        header::render_header,
+        navigation::render_navigation,
+        status_bar::render_status_bar,
+        tables::{
+            format_large_number, render_data_table, DataTableState, SortKey, TableColumn,
+            TableExport, TableRow,
+        },
     },
+    events::UiEvent,
+    screens::Screen,
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Clear, List, ListItem, Padding, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, Padding, Paragraph, Wrap},
     Frame,
 };
 
@@ -31,8 +38,8 @@ pub struct TransactionState {
     pub filters: TransactionFilters,
     /// Search input for transaction hash
     pub search_input: String,
-    /// Currently selected transaction index in history
-    pub selected_index: usize,
+    /// Transaction history table selection, sorting and columns
+    pub history_table: DataTableState,
     /// Whether the export modal is shown
     pub show_export_modal: bool,
     /// Export format selection
@@ -95,7 +102,13 @@ impl Default for TransactionState {
             selected_transaction: None,
             filters: TransactionFilters::default(),
             search_input: String::new(),
-            selected_index: 0,
+            history_table: DataTableState::new(vec![
+                TableColumn::new("Hash", Constraint::Percentage(25)),
+                TableColumn::new("Type", Constraint::Percentage(20)),
+                TableColumn::new("Status", Constraint::Percentage(15)),
+                TableColumn::new("Time", Constraint::Percentage(20)),
+                TableColumn::new("Gas Used", Constraint::Percentage(20)),
+            ]),
             show_export_modal: false,
             export_format: ExportFormat::Json,
             input_mode: TransactionInputMode::None,
@@ -158,8 +171,93 @@ impl ExportFormat {
     }
 }
 
+impl TableRow for TransactionInfo {
+    fn cell(&self, column: usize) -> Cell<'_> {
+        match column {
+            0 => Cell::from(if self.hash.len() > 16 {
+                format!(
+                    "{}...{}",
+                    &self.hash[..8],
+                    &self.hash[self.hash.len() - 8..]
+                )
+            } else {
+                self.hash.clone()
+            }),
+            1 => Cell::from(self.operation_type.as_str()),
+            2 => {
+                let (status_text, status_color) = match self.status {
+                    TransactionStatus::Pending => ("Pending", Color::Yellow),
+                    TransactionStatus::Success => ("Success", Color::Green),
+                    TransactionStatus::Failed => ("Failed", Color::Red),
+                    TransactionStatus::Unknown => ("Unknown", Color::Gray),
+                };
+                Cell::from(status_text).style(Style::default().fg(status_color))
+            }
+            3 => Cell::from(self.timestamp.format("%m/%d %H:%M:%S").to_string()),
+            _ => Cell::from(match (self.gas_used, self.gas_wanted) {
+                (Some(used), Some(wanted)) => format!(
+                    "{}/{}",
+                    format_large_number(&used.to_string()),
+                    format_large_number(&wanted.to_string())
+                ),
+                (Some(used), None) => format_large_number(&used.to_string()),
+                _ => "N/A".to_string(),
+            }),
+        }
+    }
+
+    fn sort_key(&self, column: usize) -> SortKey {
+        match column {
+            0 => SortKey::Text(self.hash.clone()),
+            1 => SortKey::Text(self.operation_type.to_lowercase()),
+            2 => SortKey::Text(format!("{:?}", self.status)),
+            3 => SortKey::Number(self.timestamp.timestamp_millis().max(0) as u128),
+            _ => SortKey::Number(self.gas_used.unwrap_or(0).max(0) as u128),
+        }
+    }
+
+    fn export_value(&self, column: usize) -> String {
+        match column {
+            0 => self.hash.clone(),
+            1 => self.operation_type.clone(),
+            2 => format!("{:?}", self.status),
+            3 => self.timestamp.to_rfc3339(),
+            _ => match (self.gas_used, self.gas_wanted) {
+                (Some(used), Some(wanted)) => format!("{}/{}", used, wanted),
+                (Some(used), None) => used.to_string(),
+                _ => String::new(),
+            },
+        }
+    }
+}
+
+impl TransactionState {
+    /// The transaction history as filtered and sorted, for writing to a file
+    pub fn export(&mut self, transactions: &[TransactionInfo]) -> TableExport {
+        let filtered = filter_transactions(transactions, &self.filters, &self.search_input);
+        self.history_table.invalidate();
+        self.history_table.export("transactions", &filtered)
+    }
+}
+
+impl Screen for TransactionState {
+    fn handle_event(&mut self, event: &UiEvent, navigation_mode: NavigationMode) -> bool {
+        navigation_mode == NavigationMode::WithinScreen
+            && self.view_mode == TransactionViewMode::History
+            && self.history_table.handle_event(event)
+    }
+
+    fn render(&mut self, f: &mut Frame, app: &App) {
+        render_transaction_screen(f, app, self);
+    }
+}
+
 /// Render the complete transaction details screen
-pub fn render_transaction_screen(f: &mut Frame, app: &App, transaction_state: &TransactionState) {
+pub fn render_transaction_screen(
+    f: &mut Frame,
+    app: &App,
+    transaction_state: &mut TransactionState,
+) {
     let size = f.area();
 
     // Create main layout: header, nav, content, status
@@ -204,7 +302,7 @@ fn render_transaction_history(
     f: &mut Frame,
     area: Rect,
     app: &App,
-    transaction_state: &TransactionState,
+    transaction_state: &mut TransactionState,
 ) {
     let filtered_transactions = filter_transactions(
         &app.state.recent_transactions,
@@ -227,88 +325,17 @@ fn render_transaction_history(
         return;
     }
 
-    let header = Row::new(vec![
-        Cell::from("Hash").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Type").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Status").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Time").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Gas Used").style(Style::default().add_modifier(Modifier::BOLD)),
-    ])
-    .style(Style::default().bg(Color::DarkGray));
-
-    let rows: Vec<Row> = filtered_transactions
-        .iter()
-        .enumerate()
-        .map(|(index, tx)| {
-            let hash_display = if tx.hash.len() > 16 {
-                format!("{}...{}", &tx.hash[..8], &tx.hash[tx.hash.len() - 8..])
-            } else {
-                tx.hash.clone()
-            };
-
-            let (status_text, status_color) = match tx.status {
-                TransactionStatus::Pending => ("Pending", Color::Yellow),
-                TransactionStatus::Success => ("Success", Color::Green),
-                TransactionStatus::Failed => ("Failed", Color::Red),
-                TransactionStatus::Unknown => ("Unknown", Color::Gray),
-            };
-
-            let time_display = tx.timestamp.format("%m/%d %H:%M:%S").to_string();
-
-            let gas_display = match (tx.gas_used, tx.gas_wanted) {
-                (Some(used), Some(wanted)) => format!(
-                    "{}/{}",
-                    format_large_number(&used.to_string()),
-                    format_large_number(&wanted.to_string())
-                ),
-                (Some(used), None) => format_large_number(&used.to_string()),
-                _ => "N/A".to_string(),
-            };
-
-            let row = Row::new(vec![
-                Cell::from(hash_display),
-                Cell::from(tx.operation_type.clone()),
-                Cell::from(status_text).style(Style::default().fg(status_color)),
-                Cell::from(time_display),
-                Cell::from(gas_display),
-            ]);
-
-            // Highlight selected row
-            if index == transaction_state.selected_index {
-                row.style(
-                    Style::default()
-                        .bg(Color::DarkGray)
-                        .add_modifier(Modifier::BOLD),
-                )
-            } else {
-                row
-            }
-        })
-        .collect();
-
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Percentage(25),
-            Constraint::Percentage(20),
-            Constraint::Percentage(15),
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Blue))
-            .title(format!(
-                "Transaction History ({} total)",
-                filtered_transactions.len()
-            )),
+    // Filters can change the rows without changing their count
+    transaction_state.history_table.invalidate();
+    render_data_table(
+        f,
+        area,
+        "Transaction History",
+        &filtered_transactions,
+        &mut transaction_state.history_table,
+        app.state.navigation_mode == NavigationMode::WithinScreen,
     );
 
-    f.render_widget(table, area);
-
     // Instructions
     let instructions_area = Rect {
         x: area.x + 1,
@@ -318,7 +345,7 @@ fn render_transaction_history(
     };
 
     let instructions = Paragraph::new(
-        "↑/↓: Navigate | Enter: View Details | E: Export | /: Search | F: Filter | C: Clear",
+        "↑/↓: Navigate | s/S: Sort | 1-5: Columns | Enter: View Details | x: Export | /: Search",
     )
     .style(Style::default().fg(Color::Gray));
 
@@ -391,7 +418,7 @@ fn render_transaction_main_details(f: &mut Frame, area: Rect, transaction: &Tran
         (None, None) => "Gas Information: Not Available".to_string(),
     };
 
-    let details_text = format!(
+    let mut details_text = format!(
         "Transaction Hash:\n{}\n\nOperation Type:\n{}\n\nStatus: {}\n\nTimestamp:\n{}\n\n{}",
         transaction.hash,
         transaction.operation_type,
@@ -399,6 +426,9 @@ fn render_transaction_main_details(f: &mut Frame, area: Rect, transaction: &Tran
         transaction.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
         gas_info
     );
+    if let Some(retry_of) = &transaction.retry_of {
+        details_text.push_str(&format!("\n\nRetry Of:\n{}", retry_of));
+    }
 
     let details_paragraph = Paragraph::new(details_text)
         .style(Style::default().fg(Color::White))
@@ -903,6 +933,7 @@ mod tests {
             timestamp: Utc::now(),
             gas_used: Some(100000),
             gas_wanted: Some(150000),
+            retry_of: None,
         }
     }
 
@@ -1,13 +1,15 @@
 //! Wallet Selection Screen
 //!
 //! This screen is shown on startup when saved wallets exist. Users can select
-//! an existing wallet, create a new one, or recover from mnemonic.
+//! an existing wallet, create a new one, recover from mnemonic, or watch an
+//! address without its mnemonic.
 
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
 
+use crate::secret::SecretString;
 use crate::tui::components::password_input::PasswordInput;
 use crate::wallet::{WalletMetadata, WalletStorage};
 use crate::Error;
@@ -23,6 +25,8 @@ pub enum WalletSelectionState {
     CreatingWallet,
     /// Recovering wallet (goes to wizard)
     RecoveringWallet,
+    /// Entering the name and address of a watch-only wallet
+    EnteringWatchAddress,
     /// Loading wallet in progress
     Loading,
     /// Error state
@@ -52,6 +56,12 @@ pub struct WalletSelectionScreen {
     pub is_locked_out: bool,
     /// Lockout time remaining (seconds)
     pub lockout_time_remaining: u32,
+    /// Name typed for a new watch-only wallet
+    pub watch_name: String,
+    /// Address typed for a new watch-only wallet
+    pub watch_address: String,
+    /// Whether the address field, rather than the name, has focus
+    pub watch_address_focused: bool,
 }
 
 impl Default for WalletSelectionScreen {
@@ -74,6 +84,9 @@ impl WalletSelectionScreen {
             max_attempts: 3,
             is_locked_out: false,
             lockout_time_remaining: 0,
+            watch_name: String::new(),
+            watch_address: String::new(),
+            watch_address_focused: false,
         }
     }
 
@@ -95,7 +108,7 @@ impl WalletSelectionScreen {
     /// Move selection up
     pub fn move_selection_up(&mut self) {
         if self.state == WalletSelectionState::SelectingWallet {
-            let total_options = self.available_wallets.len() + 2; // +2 for Create/Recover options
+            let total_options = self.available_wallets.len() + 3; // +3 for Create/Recover/Watch options
             if self.selected_index == 0 {
                 self.selected_index = total_options - 1; // Wrap to last option
             } else {
@@ -107,7 +120,7 @@ impl WalletSelectionScreen {
     /// Move selection down
     pub fn move_selection_down(&mut self) {
         if self.state == WalletSelectionState::SelectingWallet {
-            let total_options = self.available_wallets.len() + 2; // +2 for Create/Recover options
+            let total_options = self.available_wallets.len() + 3; // +3 for Create/Recover/Watch options
             self.selected_index = (self.selected_index + 1) % total_options;
         }
     }
@@ -117,9 +130,15 @@ impl WalletSelectionScreen {
         match self.state {
             WalletSelectionState::SelectingWallet => {
                 if self.selected_index < self.available_wallets.len() {
+                    let wallet = self.available_wallets[self.selected_index].clone();
+                    if wallet.watch_only {
+                        // Nothing to decrypt, so no password
+                        return WalletSelectionAction::OpenWatchOnly {
+                            wallet_name: wallet.name,
+                        };
+                    }
                     // Selected an existing wallet - prompt for password
-                    self.selected_wallet =
-                        Some(self.available_wallets[self.selected_index].clone());
+                    self.selected_wallet = Some(wallet);
                     self.state = WalletSelectionState::EnteringPassword;
                     self.password_input.set_focused(true);
                     WalletSelectionAction::None
@@ -127,10 +146,26 @@ impl WalletSelectionScreen {
                     // Create new wallet
                     self.state = WalletSelectionState::CreatingWallet;
                     WalletSelectionAction::CreateNewWallet
-                } else {
+                } else if self.selected_index == self.available_wallets.len() + 1 {
                     // Recover wallet
                     self.state = WalletSelectionState::RecoveringWallet;
                     WalletSelectionAction::RecoverWallet
+                } else {
+                    // Watch an address
+                    self.start_watch_address();
+                    WalletSelectionAction::None
+                }
+            }
+            WalletSelectionState::EnteringWatchAddress => {
+                let name = self.watch_name.trim();
+                let address = self.watch_address.trim();
+                if name.is_empty() || address.is_empty() {
+                    self.error_message = Some("Enter both a name and an address".to_string());
+                    return WalletSelectionAction::None;
+                }
+                WalletSelectionAction::AddWatchOnly {
+                    wallet_name: name.to_string(),
+                    address: address.to_string(),
                 }
             }
             WalletSelectionState::EnteringPassword => {
@@ -139,7 +174,7 @@ impl WalletSelectionScreen {
                     if !self.password_input.value().is_empty() {
                         self.attempt_authentication(
                             wallet.name.clone(),
-                            self.password_input.value().to_string(),
+                            self.password_input.value().clone(),
                         )
                     } else {
                         WalletSelectionAction::None
@@ -156,7 +191,7 @@ impl WalletSelectionScreen {
     fn attempt_authentication(
         &mut self,
         wallet_name: String,
-        password: String,
+        password: SecretString,
     ) -> WalletSelectionAction {
         if self.is_locked_out {
             self.error_message = Some(format!(
@@ -181,7 +216,7 @@ impl WalletSelectionScreen {
     pub fn handle_authentication_success(
         &mut self,
         wallet_name: String,
-        mnemonic: String,
+        mnemonic: SecretString,
     ) -> WalletSelectionAction {
         self.failed_attempts = 0;
         self.error_message = None;
@@ -207,21 +242,60 @@ impl WalletSelectionScreen {
         }
     }
 
+    /// Open the form for adding a watch-only wallet
+    pub fn start_watch_address(&mut self) {
+        self.state = WalletSelectionState::EnteringWatchAddress;
+        self.watch_name.clear();
+        self.watch_address.clear();
+        self.watch_address_focused = false;
+        self.error_message = None;
+    }
+
+    /// Switch focus between the watch-only name and address fields
+    pub fn toggle_watch_field(&mut self) {
+        if self.state == WalletSelectionState::EnteringWatchAddress {
+            self.watch_address_focused = !self.watch_address_focused;
+        }
+    }
+
+    /// Show why adding a watch-only wallet failed, keeping what was typed
+    pub fn handle_watch_failure(&mut self, error: String) {
+        self.state = WalletSelectionState::EnteringWatchAddress;
+        self.error_message = Some(error);
+    }
+
     /// Handle character input for password
     pub fn handle_char(&mut self, c: char) {
-        if self.state == WalletSelectionState::EnteringPassword {
-            self.password_input.handle_char(c);
-            // Clear error message when user starts typing
-            if self.error_message.is_some() {
-                self.error_message = None;
+        match self.state {
+            WalletSelectionState::EnteringPassword => self.password_input.handle_char(c),
+            WalletSelectionState::EnteringWatchAddress if self.watch_address_focused => {
+                self.watch_address.push(c)
             }
+            WalletSelectionState::EnteringWatchAddress => self.watch_name.push(c),
+            _ => return,
         }
+        // Clear error message when user starts typing
+        if self.error_message.is_some() {
+            self.error_message = None;
+        }
+    }
+
+    /// Whether typed characters go into a masked input, i.e. the password
+    pub fn is_sensitive_input_focused(&self) -> bool {
+        self.state == WalletSelectionState::EnteringPassword
     }
 
     /// Handle backspace for password
     pub fn handle_backspace(&mut self) {
-        if self.state == WalletSelectionState::EnteringPassword {
-            self.password_input.handle_backspace();
+        match self.state {
+            WalletSelectionState::EnteringPassword => self.password_input.handle_backspace(),
+            WalletSelectionState::EnteringWatchAddress if self.watch_address_focused => {
+                self.watch_address.pop();
+            }
+            WalletSelectionState::EnteringWatchAddress => {
+                self.watch_name.pop();
+            }
+            _ => {}
         }
     }
 
@@ -237,6 +311,11 @@ impl WalletSelectionScreen {
                 self.error_message = None;
                 WalletSelectionAction::None
             }
+            WalletSelectionState::EnteringWatchAddress => {
+                self.state = WalletSelectionState::SelectingWallet;
+                self.error_message = None;
+                WalletSelectionAction::None
+            }
             WalletSelectionState::Error => {
                 // Return to wallet selection if not locked out
                 if !self.is_locked_out {
@@ -274,6 +353,10 @@ impl WalletSelectionScreen {
         match self.state {
             WalletSelectionState::SelectingWallet => self.render_wallet_list(area, buf),
             WalletSelectionState::EnteringPassword => self.render_password_prompt(area, buf),
+            WalletSelectionState::EnteringWatchAddress => {
+                self.render_wallet_list(area, buf);
+                self.render_watch_address_form(area, buf);
+            }
             WalletSelectionState::Loading => self.render_loading(area, buf),
             WalletSelectionState::Error => self.render_error(area, buf),
             _ => {} // Other states handled by parent
@@ -312,9 +395,17 @@ impl WalletSelectionScreen {
 
             let last_accessed = wallet.last_accessed.as_deref().unwrap_or("Never");
 
+            let (icon, kind) = if wallet.watch_only {
+                ("👁", " (watch-only)")
+            } else {
+                ("📱", "")
+            };
+
             let item = ListItem::new(format!(
-                "📱 {} ({}...{}) - Last: {}",
+                "{} {}{} ({}...{}) - Last: {}",
+                icon,
                 wallet.name,
+                kind,
                 &wallet.address[..8],
                 &wallet.address[wallet.address.len() - 8..],
                 last_accessed
@@ -339,6 +430,14 @@ impl WalletSelectionScreen {
         };
         items.push(ListItem::new("🔄 Recover Existing Wallet").style(recover_style));
 
+        // Add "Watch an Address" option
+        let watch_style = if self.selected_index == self.available_wallets.len() + 2 {
+            Style::default().bg(Color::Magenta).fg(Color::Black)
+        } else {
+            Style::default().fg(Color::Magenta)
+        };
+        items.push(ListItem::new("👁 Watch an Address (no mnemonic)").style(watch_style));
+
         let list = List::new(items).block(
             Block::default()
                 .borders(Borders::ALL)
@@ -418,6 +517,54 @@ impl WalletSelectionScreen {
         help.render(chunks[3], buf);
     }
 
+    /// Render the form for adding a watch-only wallet
+    fn render_watch_address_form(&self, area: Rect, buf: &mut Buffer) {
+        let modal_area = self.center_modal(area, 70, 14);
+        Clear.render(modal_area, buf);
+
+        let main_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta))
+            .title("Watch an Address");
+
+        let inner_area = main_block.inner(modal_area);
+        main_block.render(modal_area, buf);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Name
+                Constraint::Length(3), // Address
+                Constraint::Length(2), // Error message
+                Constraint::Length(2), // Help text
+            ])
+            .split(inner_area);
+
+        let field = |title: &'static str, value: &str, focused: bool| {
+            let border = if focused { Color::Yellow } else { Color::Gray };
+            Paragraph::new(value.to_string()).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border))
+                    .title(title),
+            )
+        };
+        field("Name", &self.watch_name, !self.watch_address_focused).render(chunks[0], buf);
+        field("Address", &self.watch_address, self.watch_address_focused).render(chunks[1], buf);
+
+        if let Some(error) = &self.error_message {
+            Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .wrap(ratatui::widgets::Wrap { trim: true })
+                .render(chunks[2], buf);
+        }
+
+        Paragraph::new("Tab to switch fields • Enter to save • Esc to cancel\nWatch-only wallets can view balances but can't sign")
+            .style(Style::default().fg(Color::Gray))
+            .render(chunks[3], buf);
+    }
+
     /// Render loading state
     fn render_loading(&self, area: Rect, buf: &mut Buffer) {
         let modal_area = self.center_modal(area, 40, 6);
@@ -489,12 +636,19 @@ pub enum WalletSelectionAction {
     /// Attempt to authenticate with the given wallet
     AuthenticateWallet {
         wallet_name: String,
-        password: String,
+        password: SecretString,
+    },
+    /// Open a saved watch-only wallet, which needs no password
+    OpenWatchOnly { wallet_name: String },
+    /// Save an address as a watch-only wallet and open it
+    AddWatchOnly {
+        wallet_name: String,
+        address: String,
     },
     /// Wallet was successfully loaded
     WalletLoaded {
         wallet_name: String,
-        mnemonic: String,
+        mnemonic: SecretString,
     },
     /// User wants to quit the application
     Quit,
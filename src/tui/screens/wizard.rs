@@ -16,347 +16,7 @@ use ratatui::{
 };
 use std::borrow::Cow;
 
-/// Wizard steps for first-time setup
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum WizardStep {
-    Welcome,
-    NetworkSelection,
-    WalletSetup,
-    SecurityWarning,
-    WalletSave,
-    Confirmation,
-    Complete,
-}
-
-impl WizardStep {
-    pub fn all() -> Vec<Self> {
-        vec![
-            Self::Welcome,
-            Self::NetworkSelection,
-            Self::WalletSetup,
-            Self::SecurityWarning,
-            Self::WalletSave,
-            Self::Confirmation,
-            Self::Complete,
-        ]
-    }
-
-    pub fn title(&self) -> &'static str {
-        match self {
-            Self::Welcome => "Welcome to MANTRA DEX",
-            Self::NetworkSelection => "Network Configuration",
-            Self::WalletSetup => "Wallet Setup",
-            Self::SecurityWarning => "Security Information",
-            Self::WalletSave => "Save Wallet",
-            Self::Confirmation => "Confirm Settings",
-            Self::Complete => "Setup Complete",
-        }
-    }
-
-    pub fn step_number(&self) -> (usize, usize) {
-        let all = Self::all();
-        let current = all.iter().position(|&s| s == *self).unwrap() + 1;
-        (current, all.len())
-    }
-}
-
-/// Network environment options
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum NetworkEnvironment {
-    Mainnet,
-    Testnet,
-}
-
-impl NetworkEnvironment {
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Self::Mainnet => "Mainnet (Production)",
-            Self::Testnet => "Testnet (Development)",
-        }
-    }
-
-    pub fn description(&self) -> &'static str {
-        match self {
-            Self::Mainnet => "Real transactions with real assets. Use with caution.",
-            Self::Testnet => "Test environment with fake assets. Safe for learning.",
-        }
-    }
-}
-
-/// Wallet setup wizard state
-#[derive(Debug, Clone)]
-pub struct WizardState {
-    /// Current wizard step
-    pub current_step: WizardStep,
-    /// Selected network environment
-    pub selected_network: NetworkEnvironment,
-    /// Mnemonic input for wallet setup
-    pub mnemonic_input: String,
-    /// Whether user has acknowledged security warnings
-    pub security_acknowledged: bool,
-    /// Whether user wants to create new wallet or import existing
-    pub import_existing: bool,
-    /// Generated mnemonic (if creating new wallet)
-    pub generated_mnemonic: Option<String>,
-    /// Whether to show the wizard
-    pub show_wizard: bool,
-    /// Wallet save fields
-    pub wallet_name: String,
-    pub save_password: String,
-    pub save_password_confirm: String,
-    /// Whether user wants to save the wallet
-    pub save_wallet: bool,
-    /// Whether wallet save modal is currently shown
-    pub show_save_modal: bool,
-    /// Validation errors for wallet save form
-    pub wallet_save_errors: Vec<String>,
-    /// Current focused field in wallet save step
-    pub wallet_save_focus: WalletSaveField,
-}
-
-/// Fields that can be focused in the wallet save step
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum WalletSaveField {
-    SaveToggle,
-    WalletName,
-    Password,
-    PasswordConfirm,
-}
-
-impl Default for WizardState {
-    fn default() -> Self {
-        Self {
-            current_step: WizardStep::Welcome,
-            selected_network: NetworkEnvironment::Testnet,
-            mnemonic_input: String::new(),
-            security_acknowledged: false,
-            import_existing: true,
-            generated_mnemonic: None,
-            show_wizard: true,
-            wallet_name: String::new(),
-            save_password: String::new(),
-            save_password_confirm: String::new(),
-            save_wallet: true, // Default to saving wallet for convenience
-            show_save_modal: false,
-            wallet_save_errors: Vec::new(),
-            wallet_save_focus: WalletSaveField::SaveToggle,
-        }
-    }
-}
-
-impl WizardState {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub fn next_step(&mut self) {
-        self.current_step = match self.current_step {
-            WizardStep::Welcome => WizardStep::NetworkSelection,
-            WizardStep::NetworkSelection => WizardStep::WalletSetup,
-            WizardStep::WalletSetup => WizardStep::SecurityWarning,
-            WizardStep::SecurityWarning => WizardStep::WalletSave,
-            WizardStep::WalletSave => WizardStep::Confirmation,
-            WizardStep::Confirmation => WizardStep::Complete,
-            WizardStep::Complete => WizardStep::Complete,
-        };
-    }
-
-    pub fn previous_step(&mut self) {
-        self.current_step = match self.current_step {
-            WizardStep::Welcome => WizardStep::Welcome,
-            WizardStep::NetworkSelection => WizardStep::Welcome,
-            WizardStep::WalletSetup => WizardStep::NetworkSelection,
-            WizardStep::SecurityWarning => WizardStep::WalletSetup,
-            WizardStep::WalletSave => WizardStep::SecurityWarning,
-            WizardStep::Confirmation => WizardStep::WalletSave,
-            WizardStep::Complete => WizardStep::Confirmation,
-        };
-    }
-
-    pub fn toggle_network(&mut self) {
-        self.selected_network = match self.selected_network {
-            NetworkEnvironment::Mainnet => NetworkEnvironment::Testnet,
-            NetworkEnvironment::Testnet => NetworkEnvironment::Mainnet,
-        };
-    }
-
-    pub fn toggle_wallet_mode(&mut self) {
-        self.import_existing = !self.import_existing;
-        if !self.import_existing {
-            // Generate new mnemonic when switching to create mode
-            self.generated_mnemonic = Some(generate_mnemonic());
-        }
-    }
-
-    pub fn finish_wizard(&mut self) {
-        self.show_wizard = false;
-    }
-
-    pub fn reset(&mut self) {
-        *self = Self::default();
-    }
-
-    pub fn can_proceed(&self) -> bool {
-        match self.current_step {
-            WizardStep::Welcome => true,
-            WizardStep::NetworkSelection => true,
-            WizardStep::WalletSetup => {
-                if self.import_existing {
-                    !self.mnemonic_input.is_empty()
-                        && self.mnemonic_input.split_whitespace().count() >= 12
-                } else {
-                    true // Generated mnemonic is always valid
-                }
-            }
-            WizardStep::SecurityWarning => self.security_acknowledged,
-            WizardStep::WalletSave => {
-                if self.save_wallet {
-                    self.validate_wallet_save().is_empty()
-                } else {
-                    true // Can skip saving
-                }
-            }
-            WizardStep::Confirmation => true,
-            WizardStep::Complete => true,
-        }
-    }
-
-    /// Validate wallet save form and return errors
-    pub fn validate_wallet_save(&self) -> Vec<String> {
-        let mut errors = Vec::new();
-
-        // Validate wallet name
-        if self.wallet_name.trim().is_empty() {
-            errors.push("Wallet name is required".to_string());
-        } else if self.wallet_name.trim().len() < 3 {
-            errors.push("Wallet name must be at least 3 characters".to_string());
-        } else if self.wallet_name.contains('/') || self.wallet_name.contains('\\') {
-            errors.push("Wallet name cannot contain path separators".to_string());
-        }
-
-        // Validate password strength
-        if let Err(validation_error) =
-            crate::wallet::WalletStorage::default().validate_password(&self.save_password)
-        {
-            errors.push(format!("Password error: {}", validation_error));
-        }
-
-        // Validate password confirmation
-        if self.save_password != self.save_password_confirm {
-            errors.push("Passwords do not match".to_string());
-        }
-
-        errors
-    }
-
-    /// Toggle wallet save option
-    pub fn toggle_save_wallet(&mut self) {
-        self.save_wallet = !self.save_wallet;
-        if !self.save_wallet {
-            // Clear form when disabling save
-            self.wallet_name.clear();
-            self.save_password.clear();
-            self.save_password_confirm.clear();
-            self.wallet_save_errors.clear();
-        }
-    }
-
-    /// Clear sensitive wallet save data from memory
-    pub fn clear_wallet_save_data(&mut self) {
-        // Overwrite passwords with zeros for security
-        unsafe {
-            let password_bytes = self.save_password.as_bytes_mut();
-            for byte in password_bytes {
-                *byte = 0;
-            }
-            let confirm_bytes = self.save_password_confirm.as_bytes_mut();
-            for byte in confirm_bytes {
-                *byte = 0;
-            }
-        }
-        self.save_password.clear();
-        self.save_password_confirm.clear();
-    }
-
-    /// Navigate to next field in wallet save step
-    pub fn wallet_save_focus_next(&mut self) {
-        if !self.save_wallet {
-            // If save wallet is disabled, only toggle field is available
-            self.wallet_save_focus = WalletSaveField::SaveToggle;
-            return;
-        }
-
-        self.wallet_save_focus = match self.wallet_save_focus {
-            WalletSaveField::SaveToggle => WalletSaveField::WalletName,
-            WalletSaveField::WalletName => WalletSaveField::Password,
-            WalletSaveField::Password => WalletSaveField::PasswordConfirm,
-            WalletSaveField::PasswordConfirm => WalletSaveField::SaveToggle,
-        };
-    }
-
-    /// Navigate to previous field in wallet save step
-    pub fn wallet_save_focus_previous(&mut self) {
-        if !self.save_wallet {
-            // If save wallet is disabled, only toggle field is available
-            self.wallet_save_focus = WalletSaveField::SaveToggle;
-            return;
-        }
-
-        self.wallet_save_focus = match self.wallet_save_focus {
-            WalletSaveField::SaveToggle => WalletSaveField::PasswordConfirm,
-            WalletSaveField::WalletName => WalletSaveField::SaveToggle,
-            WalletSaveField::Password => WalletSaveField::WalletName,
-            WalletSaveField::PasswordConfirm => WalletSaveField::Password,
-        };
-    }
-
-    /// Handle character input for the currently focused field
-    pub fn wallet_save_handle_char(&mut self, c: char) {
-        match self.wallet_save_focus {
-            WalletSaveField::SaveToggle => {
-                // Handle toggle with space or y/n
-                if c == ' ' || c == 'y' || c == 'Y' {
-                    self.save_wallet = true;
-                } else if c == 'n' || c == 'N' {
-                    self.save_wallet = false;
-                }
-            }
-            WalletSaveField::WalletName => {
-                if c.is_alphanumeric() || c == '_' || c == '-' || c == ' ' {
-                    self.wallet_name.push(c);
-                }
-            }
-            WalletSaveField::Password => {
-                if c.is_ascii_graphic() || c == ' ' {
-                    self.save_password.push(c);
-                }
-            }
-            WalletSaveField::PasswordConfirm => {
-                if c.is_ascii_graphic() || c == ' ' {
-                    self.save_password_confirm.push(c);
-                }
-            }
-        }
-    }
-
-    /// Handle backspace for the currently focused field
-    pub fn wallet_save_handle_backspace(&mut self) {
-        match self.wallet_save_focus {
-            WalletSaveField::SaveToggle => {
-                // No backspace handling for toggle
-            }
-            WalletSaveField::WalletName => {
-                self.wallet_name.pop();
-            }
-            WalletSaveField::Password => {
-                self.save_password.pop();
-            }
-            WalletSaveField::PasswordConfirm => {
-                self.save_password_confirm.pop();
-            }
-        }
-    }
-}
+pub use crate::wallet::wizard::{NetworkEnvironment, WalletSaveField, WizardState, WizardStep};
 
 /// Render the wallet setup wizard
 pub fn render_wizard(frame: &mut Frame, app: &App) {
@@ -610,18 +270,6 @@ fn render_network_step(frame: &mut Frame, area: Rect, wizard_state: &WizardState
     frame.render_widget(controls_widget, chunks[2]);
 }
 
-/// Generate a mock mnemonic (in real implementation, use proper crypto library)
-fn generate_mnemonic() -> String {
-    match crate::wallet::MantraWallet::generate() {
-        Ok((_, mnemonic)) => mnemonic,
-        Err(_) => {
-            // Fallback to a test mnemonic if generation fails
-            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
-        .to_string()
-        }
-    }
-}
-
 /// Helper function to create centered rectangle
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -699,7 +347,7 @@ fn render_wallet_step(frame: &mut Frame, area: Rect, wizard_state: &WizardState)
         let mnemonic_display = if wizard_state.mnemonic_input.is_empty() {
             Cow::Borrowed("<type here>")
         } else {
-            Cow::Owned(wizard_state.mnemonic_input.clone())
+            Cow::Borrowed(wizard_state.mnemonic_input.expose_secret())
         };
 
         let paragraph = Paragraph::new(mnemonic_display)
@@ -711,7 +359,8 @@ fn render_wallet_step(frame: &mut Frame, area: Rect, wizard_state: &WizardState)
         // Creating new wallet – show the generated mnemonic nicely formatted
         let mnemonic = wizard_state
             .generated_mnemonic
-            .as_deref()
+            .as_ref()
+            .map(|mnemonic| mnemonic.expose_secret())
             .unwrap_or("<error generating mnemonic>");
 
         // Split into 3-4 word chunks per line for readability
@@ -959,7 +608,11 @@ fn render_confirmation_step(frame: &mut Frame, area: Rect, wizard_state: &Wizard
 
     // Wallet configuration
     if wizard_state.import_existing {
-        let word_count = wizard_state.mnemonic_input.split_whitespace().count();
+        let word_count = wizard_state
+            .mnemonic_input
+            .expose_secret()
+            .split_whitespace()
+            .count();
         summary_lines.extend(vec![
             Line::from(vec![
                 Span::styled("💼 Wallet: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -1044,33 +697,6 @@ fn render_confirmation_step(frame: &mut Frame, area: Rect, wizard_state: &Wizard
     frame.render_widget(controls_widget, chunks[2]);
 }
 
-// Implement Drop for secure memory cleanup
-impl Drop for WizardState {
-    fn drop(&mut self) {
-        self.clear_wallet_save_data();
-
-        // Also clear mnemonic input for security
-        unsafe {
-            let mnemonic_bytes = self.mnemonic_input.as_bytes_mut();
-            for byte in mnemonic_bytes {
-                *byte = 0;
-            }
-        }
-        self.mnemonic_input.clear();
-
-        // Clear generated mnemonic if present
-        if let Some(ref mut generated) = self.generated_mnemonic {
-            unsafe {
-                let generated_bytes = generated.as_bytes_mut();
-                for byte in generated_bytes {
-                    *byte = 0;
-                }
-            }
-            generated.clear();
-        }
-    }
-}
-
 /// Render wallet save step
 fn render_wallet_save_step(frame: &mut Frame, area: Rect, wizard_state: &WizardState) {
     let block = Block::default()
@@ -1200,7 +826,7 @@ fn render_wallet_save_step(frame: &mut Frame, area: Rect, wizard_state: &WizardS
 
         // Password input (masked)
         let password_focused = wizard_state.wallet_save_focus == WalletSaveField::Password;
-        let password_display = "•".repeat(wizard_state.save_password.len());
+        let password_display = "•".repeat(wizard_state.save_password.char_count());
         let password_input = Paragraph::new(if wizard_state.save_password.is_empty() {
             "Enter password..."
         } else {
@@ -1231,7 +857,7 @@ fn render_wallet_save_step(frame: &mut Frame, area: Rect, wizard_state: &WizardS
 
         // Confirm password input (masked)
         let confirm_focused = wizard_state.wallet_save_focus == WalletSaveField::PasswordConfirm;
-        let confirm_display = "•".repeat(wizard_state.save_password_confirm.len());
+        let confirm_display = "•".repeat(wizard_state.save_password_confirm.char_count());
         let confirm_input = Paragraph::new(if wizard_state.save_password_confirm.is_empty() {
             "Confirm password..."
         } else {
@@ -4,24 +4,18 @@
 //! the main UI layout and screen rendering with responsive design capabilities.
 
 #[cfg(feature = "tui")]
-use crate::tui::app::App;
+use crate::tui::app::{App, AppState};
 #[cfg(feature = "tui")]
 use crate::tui::components::modals::render_modal;
 #[cfg(feature = "tui")]
-use crate::tui::screens::dashboard::render_dashboard;
-#[cfg(feature = "tui")]
-use crate::tui::screens::liquidity::render_liquidity;
-#[cfg(feature = "tui")]
-use crate::tui::screens::multihop::render_multihop;
+use crate::tui::screens::analytics::render_analytics;
 #[cfg(feature = "tui")]
-use crate::tui::screens::pools::render_pools;
+use crate::tui::screens::dashboard::render_dashboard;
 #[cfg(feature = "tui")]
 use crate::tui::screens::rewards::render_rewards;
 #[cfg(feature = "tui")]
 use crate::tui::screens::settings::render_settings_screen;
 #[cfg(feature = "tui")]
-use crate::tui::screens::swap::render_swap;
-#[cfg(feature = "tui")]
 use crate::tui::utils::responsive::{create_size_warning_popup, LayoutConfig};
 #[cfg(feature = "tui")]
 use crate::Error;
@@ -59,25 +53,37 @@ pub fn render_ui(frame: &mut Frame, app: &mut App) -> Result<(), Error> {
                 .render(frame.area(), frame.buffer_mut());
         }
         crate::tui::app::Screen::Dashboard => render_dashboard(frame, app),
-        crate::tui::app::Screen::Pools => render_pools(frame, app),
-        crate::tui::app::Screen::Swap => render_swap(frame, app),
-        crate::tui::app::Screen::MultiHop => render_multihop(frame, app),
-        crate::tui::app::Screen::Liquidity => render_liquidity(frame, app),
+        crate::tui::app::Screen::Pools => {
+            render_owned_screen(frame, app, |state| &mut state.pools_screen_state)
+        }
+        crate::tui::app::Screen::Swap => {
+            render_owned_screen(frame, app, |state| &mut state.swap_screen_state)
+        }
+        crate::tui::app::Screen::MultiHop => {
+            render_owned_screen(frame, app, |state| &mut state.multihop_screen_state)
+        }
+        crate::tui::app::Screen::Liquidity => {
+            render_owned_screen(frame, app, |state| &mut state.liquidity_screen_state)
+        }
         crate::tui::app::Screen::Rewards => render_rewards(frame, app),
-        crate::tui::app::Screen::Admin => crate::tui::screens::admin::render_admin(frame, app),
+        crate::tui::app::Screen::Admin => {
+            render_owned_screen(frame, app, |state| &mut state.admin_screen_state)
+        }
+        crate::tui::app::Screen::Analytics => render_analytics(frame, app),
         crate::tui::app::Screen::Settings => {
             // Use enhanced settings screen with focus indicators
             crate::tui::screens::settings::render_settings_screen_with_focus(frame, app);
         }
         crate::tui::app::Screen::TransactionDetails => {
-            crate::tui::screens::transaction::render_transaction_screen(
-                frame,
-                app,
-                &app.state.transaction_state,
-            );
+            render_owned_screen(frame, app, |state| &mut state.transaction_state)
         }
     }
 
+    // Render the onboarding tour over the screen it describes
+    if let Some(ref tour) = app.state.tour {
+        crate::tui::components::tour::render_tour(frame, tour, size);
+    }
+
     // Render modal overlay if present
     if let Some(ref modal_state) = app.state.modal_state {
         render_modal(frame, modal_state, size);
@@ -138,6 +144,7 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, layout_config: &Layou
             ("Rew", crate::tui::app::Screen::Rewards),
             ("Admin", crate::tui::app::Screen::Admin),
             ("Set", crate::tui::app::Screen::Settings),
+            ("Stats", crate::tui::app::Screen::Analytics),
         ]
     } else {
         crate::tui::app::Screen::all()
@@ -213,6 +220,20 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, layout_config: &Layou
     frame.render_widget(info, header_chunks[2]);
 }
 
+/// Render a screen whose state is owned by [`AppState`]
+///
+/// The state is moved out for the duration of the draw so the screen can mutate it
+/// while reading the rest of the app.
+fn render_owned_screen<S: crate::tui::screens::Screen + Default>(
+    frame: &mut Frame,
+    app: &mut App,
+    state: fn(&mut AppState) -> &mut S,
+) {
+    let mut screen = std::mem::take(state(&mut app.state));
+    screen.render(frame, app);
+    *state(&mut app.state) = screen;
+}
+
 /// Render the main content area based on current screen (responsive)
 fn render_main_content(
     frame: &mut Frame,
@@ -241,19 +262,19 @@ fn render_main_content(
         }
         crate::tui::app::Screen::Pools => {
             // Pass layout config to pools (will need updating)
-            render_pools(frame, app);
+            render_owned_screen(frame, app, |state| &mut state.pools_screen_state);
         }
         crate::tui::app::Screen::Swap => {
             // Pass layout config to swap (will need updating)
-            render_swap(frame, app);
+            render_owned_screen(frame, app, |state| &mut state.swap_screen_state);
         }
         crate::tui::app::Screen::MultiHop => {
             // Pass layout config to multihop (will need updating)
-            render_multihop(frame, app);
+            render_owned_screen(frame, app, |state| &mut state.multihop_screen_state);
         }
         crate::tui::app::Screen::Liquidity => {
             // Pass layout config to liquidity (will need updating)
-            render_liquidity(frame, app);
+            render_owned_screen(frame, app, |state| &mut state.liquidity_screen_state);
         }
         crate::tui::app::Screen::Rewards => {
             // Pass layout config to rewards (will need updating)
@@ -261,19 +282,19 @@ fn render_main_content(
         }
         crate::tui::app::Screen::Admin => {
             // Pass layout config to admin (will need updating)
-            crate::tui::screens::admin::render_admin(frame, app);
+            render_owned_screen(frame, app, |state| &mut state.admin_screen_state);
         }
         crate::tui::app::Screen::Settings => {
             // Pass layout config to settings (will need updating)
             render_settings_screen(frame, app);
         }
+        crate::tui::app::Screen::Analytics => {
+            // Pass layout config to analytics (will need updating)
+            render_analytics(frame, app);
+        }
         crate::tui::app::Screen::TransactionDetails => {
             // Pass layout config to transaction (will need updating)
-            crate::tui::screens::transaction::render_transaction_screen(
-                frame,
-                app,
-                &app.state.transaction_state,
-            );
+            render_owned_screen(frame, app, |state| &mut state.transaction_state);
         }
     }
 
@@ -3,12 +3,14 @@
 //! This module provides utilities for handling background async operations
 //! and real-time data updates in the TUI application without blocking the UI.
 
-use crate::tui::events::Event;
-use crate::{Error, MantraDexClient};
-use std::sync::Arc;
+use crate::subscriptions::SubscriptionFilter;
+use crate::tui::app::Screen;
+use crate::tui::events::{DataEvent, EventBus, TxEvent};
+use crate::{Error, MantraDexClient, PageCursor};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tokio::sync::mpsc;
-use tokio::time::interval;
+use tokio::sync::{broadcast, Notify};
+use tokio::time::{interval, Instant};
 use tokio_util::sync::CancellationToken;
 
 /// Background sync configuration
@@ -30,6 +32,12 @@ pub struct SyncConfig {
     pub retry_attempts: u32,
     /// Retry delay between attempts (default: 5 seconds)
     pub retry_delay: Duration,
+    /// Interval for balance and pool refresh while chain events are streamed
+    /// and trigger them instead (default: 5 minutes)
+    pub subscription_fallback_interval: Duration,
+    /// Time without input before the TUI counts as idle; zero leaves it active
+    /// until the terminal loses focus (default: 2 minutes)
+    pub idle_timeout: Duration,
 }
 
 impl Default for SyncConfig {
@@ -43,6 +51,8 @@ impl Default for SyncConfig {
             network_timeout: Duration::from_secs(10),
             retry_attempts: 3,
             retry_delay: Duration::from_secs(5),
+            subscription_fallback_interval: Duration::from_secs(300),
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
         }
     }
 }
@@ -91,10 +101,351 @@ impl OperationResult {
     }
 }
 
+/// How long the scheduler sleeps when no task can run
+const IDLE_WAKE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Delay before reconnecting after the event subscriptions dropped or failed
+const SUBSCRIPTION_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Time without input before the TUI counts as idle
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Redraw interval of the TUI while the user is at it
+pub const ACTIVE_TICK_RATE: Duration = Duration::from_millis(100);
+
+/// Redraw interval of the TUI while it is idle
+pub const IDLE_TICK_RATE: Duration = Duration::from_secs(1);
+
+/// Tracks whether the user is at the TUI
+///
+/// The TUI is idle while its terminal is unfocused, or once no input arrived
+/// for the idle timeout. Input, or the terminal regaining focus, makes it
+/// active again at once.
+#[derive(Debug, Clone)]
+pub struct ActivityTracker {
+    last_input: Instant,
+    focused: bool,
+    idle_timeout: Duration,
+}
+
+impl ActivityTracker {
+    /// Create a tracker for a focused terminal that last saw input at `now`
+    pub fn new(idle_timeout: Duration, now: Instant) -> Self {
+        Self {
+            last_input: now,
+            focused: true,
+            idle_timeout,
+        }
+    }
+
+    /// Record user input at `now`
+    ///
+    /// Input implies focus, for terminals that only report losing it.
+    pub fn record_input(&mut self, now: Instant) {
+        self.last_input = now;
+        self.focused = true;
+    }
+
+    /// Record the terminal gaining or losing focus at `now`
+    pub fn set_focused(&mut self, focused: bool, now: Instant) {
+        self.focused = focused;
+        if focused {
+            self.last_input = now;
+        }
+    }
+
+    /// Change the time without input before the TUI counts as idle
+    pub fn set_idle_timeout(&mut self, idle_timeout: Duration) {
+        self.idle_timeout = idle_timeout;
+    }
+
+    /// Whether the TUI is idle at `now`
+    pub fn is_idle(&self, now: Instant) -> bool {
+        !self.focused
+            || (!self.idle_timeout.is_zero()
+                && now.saturating_duration_since(self.last_input) >= self.idle_timeout)
+    }
+
+    /// How often the TUI should redraw at `now`
+    pub fn tick_rate(&self, now: Instant) -> Duration {
+        if self.is_idle(now) {
+            IDLE_TICK_RATE
+        } else {
+            ACTIVE_TICK_RATE
+        }
+    }
+}
+
+/// A periodic background refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncTask {
+    Balances,
+    Pools,
+    Transactions,
+    NetworkInfo,
+    Prices,
+}
+
+impl SyncTask {
+    /// Every background refresh task
+    pub const ALL: [SyncTask; 5] = [
+        SyncTask::Balances,
+        SyncTask::Pools,
+        SyncTask::Transactions,
+        SyncTask::NetworkInfo,
+        SyncTask::Prices,
+    ];
+
+    /// Data type reported in the task's `DataRefresh` event
+    pub fn data_type(&self) -> &'static str {
+        match self {
+            SyncTask::Balances => "balances",
+            SyncTask::Pools => "pools",
+            SyncTask::Transactions => "transactions",
+            SyncTask::NetworkInfo => "network_info",
+            SyncTask::Prices => "prices",
+        }
+    }
+
+    /// How often the task is due
+    pub fn interval(&self, config: &SyncConfig) -> Duration {
+        match self {
+            SyncTask::Balances => config.balance_refresh_interval,
+            SyncTask::Pools => config.pool_data_refresh_interval,
+            SyncTask::Transactions => config.transaction_status_interval,
+            SyncTask::NetworkInfo => config.network_info_interval,
+            SyncTask::Prices => config.price_update_interval,
+        }
+    }
+
+    /// Whether chain events can trigger the task instead of its interval
+    pub fn follows_events(&self) -> bool {
+        matches!(self, SyncTask::Balances | SyncTask::Pools)
+    }
+
+    /// Priority of the task while `screen` is shown
+    pub fn priority_on(&self, screen: Screen) -> TaskPriority {
+        match (self, screen) {
+            (SyncTask::Balances, Screen::Dashboard) => TaskPriority::High,
+            (SyncTask::Balances, Screen::Swap | Screen::Liquidity | Screen::Rewards) => {
+                TaskPriority::Normal
+            }
+            (SyncTask::Pools, Screen::Pools | Screen::Swap) => TaskPriority::High,
+            (SyncTask::Pools, Screen::MultiHop | Screen::Liquidity | Screen::Admin) => {
+                TaskPriority::Normal
+            }
+            (SyncTask::Transactions, Screen::TransactionDetails) => TaskPriority::High,
+            (SyncTask::NetworkInfo, _) => TaskPriority::Normal,
+            _ => TaskPriority::Low,
+        }
+    }
+}
+
+/// Scheduling priority of a background task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TaskPriority {
+    /// Paused while the network is degraded
+    Low,
+    /// Paused while the TUI is idle
+    Normal,
+    High,
+}
+
+/// A background task waiting in the queue
+#[derive(Debug, Clone)]
+pub struct ScheduledTask {
+    pub task: SyncTask,
+    pub priority: TaskPriority,
+    /// When the task is next due
+    pub deadline: Instant,
+}
+
+/// Queue of periodic background tasks, ordered by priority and deadline
+#[derive(Debug, Clone)]
+pub struct TaskQueue {
+    tasks: Vec<ScheduledTask>,
+    /// Whether chain events are streamed, triggering the tasks that follow them
+    subscribed: bool,
+    /// Whether the TUI is idle, pausing all but high priority tasks
+    idle: bool,
+}
+
+impl TaskQueue {
+    /// Create a queue with every task due at `now`, prioritized for `screen`
+    pub fn new(screen: Screen, now: Instant) -> Self {
+        Self {
+            tasks: SyncTask::ALL
+                .iter()
+                .map(|task| ScheduledTask {
+                    task: *task,
+                    priority: task.priority_on(screen),
+                    deadline: now,
+                })
+                .collect(),
+            subscribed: false,
+            idle: false,
+        }
+    }
+
+    /// Reprioritize every task for the screen being shown
+    pub fn reprioritize(&mut self, screen: Screen) {
+        for scheduled in &mut self.tasks {
+            scheduled.priority = scheduled.task.priority_on(screen);
+        }
+    }
+
+    /// Current priority of a task
+    pub fn priority(&self, task: SyncTask) -> Option<TaskPriority> {
+        self.find(task).map(|scheduled| scheduled.priority)
+    }
+
+    /// Current deadline of a task
+    pub fn deadline(&self, task: SyncTask) -> Option<Instant> {
+        self.find(task).map(|scheduled| scheduled.deadline)
+    }
+
+    /// The task to run now, if any is due
+    ///
+    /// Higher priority wins, then the earlier deadline. Low priority tasks are
+    /// skipped while the network is degraded, and all but high priority tasks
+    /// while the TUI is idle.
+    pub fn next_due(&self, now: Instant, degraded: bool) -> Option<SyncTask> {
+        self.runnable(degraded)
+            .filter(|scheduled| scheduled.deadline <= now)
+            .max_by(|a, b| {
+                a.priority
+                    .cmp(&b.priority)
+                    .then_with(|| b.deadline.cmp(&a.deadline))
+            })
+            .map(|scheduled| scheduled.task)
+    }
+
+    /// Earliest deadline among the tasks that may run
+    pub fn next_deadline(&self, degraded: bool) -> Option<Instant> {
+        self.runnable(degraded)
+            .map(|scheduled| scheduled.deadline)
+            .min()
+    }
+
+    /// Record that a task ran at `now`, scheduling its next run
+    ///
+    /// While chain events are streamed, tasks that follow them wait for the
+    /// longer fallback interval.
+    pub fn complete(&mut self, task: SyncTask, now: Instant, config: &SyncConfig) {
+        let interval = if self.subscribed && task.follows_events() {
+            config.subscription_fallback_interval
+        } else {
+            task.interval(config)
+        };
+        if let Some(scheduled) = self.tasks.iter_mut().find(|s| s.task == task) {
+            scheduled.deadline = now + interval;
+        }
+    }
+
+    /// Make a task due at `now`, unless it already is
+    pub fn expedite(&mut self, task: SyncTask, now: Instant) {
+        if let Some(scheduled) = self.tasks.iter_mut().find(|s| s.task == task) {
+            scheduled.deadline = scheduled.deadline.min(now);
+        }
+    }
+
+    /// Record whether chain events are streamed
+    ///
+    /// When the stream stops, the tasks following it are due at `now`, as events
+    /// may have been missed.
+    pub fn set_subscribed(&mut self, subscribed: bool, now: Instant) {
+        if self.subscribed && !subscribed {
+            for task in SyncTask::ALL.iter().filter(|task| task.follows_events()) {
+                self.expedite(*task, now);
+            }
+        }
+        self.subscribed = subscribed;
+    }
+
+    /// Whether chain events are streamed
+    pub fn is_subscribed(&self) -> bool {
+        self.subscribed
+    }
+
+    /// Record whether the TUI is idle
+    ///
+    /// Paused tasks keep their deadlines, so those that came due while idle run
+    /// as soon as it is active again.
+    pub fn set_idle(&mut self, idle: bool) {
+        self.idle = idle;
+    }
+
+    /// Whether the TUI is idle
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    fn find(&self, task: SyncTask) -> Option<&ScheduledTask> {
+        self.tasks.iter().find(|scheduled| scheduled.task == task)
+    }
+
+    fn runnable(&self, degraded: bool) -> impl Iterator<Item = &ScheduledTask> {
+        let min_priority = if self.idle {
+            TaskPriority::High
+        } else if degraded {
+            TaskPriority::Normal
+        } else {
+            TaskPriority::Low
+        };
+        self.tasks
+            .iter()
+            .filter(move |scheduled| scheduled.priority >= min_priority)
+    }
+}
+
+/// Run one background task, retrying failed queries
+async fn run_sync_task(task: SyncTask, client: &MantraDexClient, config: &SyncConfig) -> DataEvent {
+    let error = match task {
+        SyncTask::Balances => refresh_with_retry(config, || client.get_balances()).await,
+        SyncTask::Pools => {
+            let cursor = PageCursor::first(50);
+            refresh_with_retry(config, || client.get_pools(&cursor)).await
+        }
+        SyncTask::NetworkInfo => {
+            refresh_with_retry(config, || client.get_last_block_height()).await
+        }
+        // Nothing to fetch yet: the app refreshes these when it handles the event
+        SyncTask::Transactions | SyncTask::Prices => None,
+    };
+
+    DataEvent::DataRefresh {
+        data_type: task.data_type().to_string(),
+        success: error.is_none(),
+        error,
+    }
+}
+
+/// Run a query up to `retry_attempts` times, returning the last error if all fail
+async fn refresh_with_retry<F, Fut, T>(config: &SyncConfig, operation: F) -> Option<String>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<T, Error>>,
+{
+    let mut error_message = None;
+
+    for attempt in 0..config.retry_attempts {
+        match tokio::time::timeout(config.network_timeout, operation()).await {
+            Ok(Ok(_)) => return None,
+            Ok(Err(e)) => error_message = Some(e.to_string()),
+            Err(_) => error_message = Some("Operation timeout".to_string()),
+        }
+        if attempt < config.retry_attempts - 1 {
+            tokio::time::sleep(config.retry_delay).await;
+        }
+    }
+
+    error_message
+}
+
 /// Background sync manager for real-time updates
 pub struct SyncManager {
     /// Event sender for communicating with the main app
-    event_sender: mpsc::UnboundedSender<Event>,
+    event_sender: EventBus,
     /// Background task handles
     task_handles: Vec<tokio::task::JoinHandle<()>>,
     /// Sync configuration
@@ -107,12 +458,22 @@ pub struct SyncManager {
     cancellation_token: CancellationToken,
     /// Network state tracking
     network_state: Arc<tokio::sync::RwLock<NetworkState>>,
+    /// Screen the task priorities are set for
+    active_screen: Screen,
+    /// Scheduled background tasks
+    task_queue: Arc<Mutex<TaskQueue>>,
+    /// Wakes the scheduler when priorities or the network state change
+    wake: Arc<Notify>,
+    /// Task streaming chain events, restarted when the wallet changes
+    subscription_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Whether the TUI is idle, pausing non-essential refreshes
+    idle: bool,
 }
 
 impl SyncManager {
     /// Create a new sync manager
     pub fn new(
-        event_sender: mpsc::UnboundedSender<Event>,
+        event_sender: EventBus,
         client: Arc<MantraDexClient>,
         config: Option<SyncConfig>,
     ) -> Self {
@@ -124,22 +485,67 @@ impl SyncManager {
             wallet_address: None,
             cancellation_token: CancellationToken::new(),
             network_state: Arc::new(tokio::sync::RwLock::new(NetworkState::Connected)),
+            active_screen: Screen::Dashboard,
+            task_queue: Arc::new(Mutex::new(TaskQueue::new(
+                Screen::Dashboard,
+                Instant::now(),
+            ))),
+            wake: Arc::new(Notify::new()),
+            subscription_handle: None,
+            idle: false,
         }
     }
 
     /// Start all background sync tasks
     pub fn start_background_sync(&mut self) {
-        self.start_balance_sync();
-        self.start_pool_data_sync();
-        self.start_transaction_status_sync();
-        self.start_network_info_sync();
-        self.start_price_sync();
+        // A stopped manager needs a fresh token to run again
+        if self.cancellation_token.is_cancelled() {
+            self.cancellation_token = CancellationToken::new();
+        }
+        let mut queue = TaskQueue::new(self.active_screen, Instant::now());
+        queue.set_idle(self.idle);
+        *self.task_queue.lock().unwrap() = queue;
+
+        self.start_task_scheduler();
         self.start_network_health_monitor();
+        self.start_event_subscriptions();
+    }
+
+    /// Reprioritize the task queue for the screen being shown
+    pub fn set_active_screen(&mut self, screen: Screen) {
+        if self.active_screen == screen {
+            return;
+        }
+        self.active_screen = screen;
+        self.task_queue.lock().unwrap().reprioritize(screen);
+        self.wake.notify_one();
+    }
+
+    /// Pause non-essential refreshes while the TUI is idle
+    ///
+    /// Only high priority tasks and chain event subscriptions keep running, and
+    /// the network health check is skipped. Refreshes that came due meanwhile
+    /// run as soon as the TUI is active again.
+    pub fn set_idle(&mut self, idle: bool) {
+        if self.idle == idle {
+            return;
+        }
+        self.idle = idle;
+        self.task_queue.lock().unwrap().set_idle(idle);
+        self.wake.notify_one();
     }
 
     /// Set wallet address for balance syncing
+    ///
+    /// Running event subscriptions are reopened to follow the new address.
     pub fn set_wallet_address(&mut self, address: String) {
+        if self.wallet_address.as_ref() == Some(&address) {
+            return;
+        }
         self.wallet_address = Some(address);
+        if self.subscription_handle.is_some() {
+            self.start_event_subscriptions();
+        }
     }
 
     /// Get current network state
@@ -154,6 +560,8 @@ impl SyncManager {
         let network_state = Arc::clone(&self.network_state);
         let cancellation_token = self.cancellation_token.clone();
         let config = self.config.clone();
+        let wake = Arc::clone(&self.wake);
+        let queue = Arc::clone(&self.task_queue);
 
         let handle = tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(15)); // Check every 15 seconds
@@ -162,6 +570,11 @@ impl SyncManager {
                 tokio::select! {
                     _ = cancellation_token.cancelled() => break,
                     _ = interval.tick() => {
+                        // Nobody is watching the connection indicator
+                        if queue.lock().unwrap().is_idle() {
+                            continue;
+                        }
+
                         let start_time = std::time::Instant::now();
 
                         // Test network connectivity with timeout
@@ -192,22 +605,14 @@ impl SyncManager {
                             *state_guard = new_state.clone();
                             drop(state_guard);
 
+                            // Paused tasks may run again, or must now wait
+                            wake.notify_one();
+
                             // Send network state change event
-                            let _ = sender.send(Event::Custom(format!(
-                                "network_state_changed:{}:{}",
-                                match old_state {
-                                    NetworkState::Connected => "connected",
-                                    NetworkState::Disconnected => "disconnected",
-                                    NetworkState::Reconnecting => "reconnecting",
-                                    NetworkState::Error(_) => "error",
-                                },
-                                match new_state {
-                                    NetworkState::Connected => "connected",
-                                    NetworkState::Disconnected => "disconnected",
-                                    NetworkState::Reconnecting => "reconnecting",
-                                    NetworkState::Error(_) => "error",
-                                }
-                            )));
+                            let _ = sender.send(DataEvent::NetworkStateChanged {
+                                from: old_state,
+                                to: new_state,
+                            });
                         }
                     }
                 }
@@ -247,14 +652,14 @@ impl SyncManager {
 
             // Send progress update for retries
             if attempt > 0 {
-                let _ = self.event_sender.send(Event::BlockchainProgress {
+                let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                     operation: operation_name.to_string(),
                     status: format!(
                         "Retrying operation (attempt {} of {})",
                         attempt + 1,
                         self.config.retry_attempts + 1
                     ),
-                    progress: Some(attempt as f32 / (self.config.retry_attempts + 1) as f32),
+                    progress: None,
                 });
 
                 // Wait before retry
@@ -299,246 +704,135 @@ impl SyncManager {
         )
     }
 
-    /// Start balance refresh task with enhanced error handling
-    fn start_balance_sync(&mut self) {
-        let sender = self.event_sender.clone();
-        let client = Arc::clone(&self.client);
-        let interval_duration = self.config.balance_refresh_interval;
-        let cancellation_token = self.cancellation_token.clone();
-        let retry_attempts = self.config.retry_attempts;
-        let retry_delay = self.config.retry_delay;
-        let network_timeout = self.config.network_timeout;
-
-        let handle = tokio::spawn(async move {
-            let mut interval = interval(interval_duration);
-
-            loop {
-                tokio::select! {
-                    _ = cancellation_token.cancelled() => break,
-                    _ = interval.tick() => {
-                        // Execute balance refresh with retry logic
-                        let start_time = std::time::Instant::now();
-                        let mut success = false;
-                        let mut error_message = None;
-                        let mut retry_count = 0;
-
-                        for attempt in 0..retry_attempts {
-                            retry_count = attempt;
-
-                            match tokio::time::timeout(network_timeout, client.get_balances()).await {
-                                Ok(Ok(_)) => {
-                                    success = true;
-                                    break;
-                                }
-                                Ok(Err(e)) => {
-                                    error_message = Some(e.to_string());
-                                    if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
-                                    }
-                                }
-                                Err(_) => {
-                                    error_message = Some("Operation timeout".to_string());
-                                    if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
-                                    }
-                                }
-                            }
-                        }
-
-                        // Send appropriate event based on result
-                        let event = Event::DataRefresh {
-                            data_type: "balances".to_string(),
-                            success,
-                            error: error_message,
-                        };
-
-                        if sender.send(event).is_err() {
-                            break; // Channel closed, stop task
-                        }
-                    }
-                }
-            }
-        });
-
-        self.task_handles.push(handle);
-    }
+    /// Stream chain events and refresh balances and pools when they change
+    ///
+    /// Transactions involving the wallet make the balance refresh due, and
+    /// transactions executing the pool manager the pool refresh. While events
+    /// stream, both otherwise only run at the fallback interval; when the
+    /// connection drops or can't be opened, they are polled at their usual
+    /// intervals until it is reopened.
+    fn start_event_subscriptions(&mut self) {
+        if let Some(handle) = self.subscription_handle.take() {
+            handle.abort();
+        }
 
-    /// Start pool data refresh task with enhanced error handling
-    fn start_pool_data_sync(&mut self) {
-        let sender = self.event_sender.clone();
         let client = Arc::clone(&self.client);
-        let interval_duration = self.config.pool_data_refresh_interval;
+        let queue = Arc::clone(&self.task_queue);
+        let wake = Arc::clone(&self.wake);
         let cancellation_token = self.cancellation_token.clone();
-        let retry_attempts = self.config.retry_attempts;
-        let retry_delay = self.config.retry_delay;
-        let network_timeout = self.config.network_timeout;
+        let wallet_address = self.wallet_address.clone();
+        let pool_manager = client.config().contracts.pool_manager.clone();
+        let mut filter = SubscriptionFilter::new().with_contract(pool_manager.clone());
+        if let Some(address) = &wallet_address {
+            filter = filter.with_address(address.clone());
+        }
 
         let handle = tokio::spawn(async move {
-            let mut interval = interval(interval_duration);
-
             loop {
-                tokio::select! {
+                let subscriptions = tokio::select! {
                     _ = cancellation_token.cancelled() => break,
-                    _ = interval.tick() => {
-                        // Execute pool data refresh with retry logic
-                        let mut success = false;
-                        let mut error_message = None;
-
-                        for attempt in 0..retry_attempts {
-                            match tokio::time::timeout(network_timeout, client.get_pools(Some(50))).await {
-                                Ok(Ok(_)) => {
-                                    success = true;
-                                    break;
-                                }
-                                Ok(Err(e)) => {
-                                    error_message = Some(e.to_string());
-                                    if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
-                                    }
+                    subscriptions = client.subscribe_events(&filter) => subscriptions,
+                };
+                match subscriptions {
+                    Ok(subscriptions) => {
+                        let mut events = subscriptions.subscribe();
+                        queue.lock().unwrap().set_subscribed(true, Instant::now());
+                        loop {
+                            let event = tokio::select! {
+                                _ = cancellation_token.cancelled() => return,
+                                event = events.recv() => event,
+                            };
+                            let (balances, pools) = match &event {
+                                Ok(event) => (
+                                    wallet_address
+                                        .as_deref()
+                                        .is_some_and(|address| event.involves_address(address)),
+                                    event.involves_contract(&pool_manager),
+                                ),
+                                // Missed events may have changed either
+                                Err(broadcast::error::RecvError::Lagged(_)) => (true, true),
+                                Err(broadcast::error::RecvError::Closed) => break,
+                            };
+                            if balances || pools {
+                                let mut queue = queue.lock().unwrap();
+                                let now = Instant::now();
+                                if balances {
+                                    queue.expedite(SyncTask::Balances, now);
                                 }
-                                Err(_) => {
-                                    error_message = Some("Operation timeout".to_string());
-                                    if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
-                                    }
+                                if pools {
+                                    queue.expedite(SyncTask::Pools, now);
                                 }
+                                drop(queue);
+                                wake.notify_one();
                             }
                         }
-
-                        let event = Event::DataRefresh {
-                            data_type: "pools".to_string(),
-                            success,
-                            error: error_message,
-                        };
-
-                        if sender.send(event).is_err() {
-                            break;
-                        }
+                        crate::tui::utils::logger::log_warning(
+                            "Chain event subscriptions dropped, polling instead",
+                        );
+                        queue.lock().unwrap().set_subscribed(false, Instant::now());
+                        wake.notify_one();
                     }
+                    Err(e) => crate::tui::utils::logger::log_warning(&format!(
+                        "Chain event subscriptions unavailable, polling instead: {}",
+                        e
+                    )),
                 }
-            }
-        });
-
-        self.task_handles.push(handle);
-    }
 
-    /// Start transaction status check task
-    fn start_transaction_status_sync(&mut self) {
-        let sender = self.event_sender.clone();
-        let client = Arc::clone(&self.client);
-        let interval_duration = self.config.transaction_status_interval;
-        let cancellation_token = self.cancellation_token.clone();
-
-        let handle = tokio::spawn(async move {
-            let mut interval = interval(interval_duration);
-
-            loop {
                 tokio::select! {
                     _ = cancellation_token.cancelled() => break,
-                    _ = interval.tick() => {
-                        // Send transaction status refresh event
-                        let event = Event::DataRefresh {
-                            data_type: "transactions".to_string(),
-                            success: true,
-                            error: None,
-                        };
-
-                        if sender.send(event).is_err() {
-                            break;
-                        }
-                    }
+                    _ = tokio::time::sleep(SUBSCRIPTION_RETRY_DELAY) => {}
                 }
             }
         });
 
-        self.task_handles.push(handle);
+        self.subscription_handle = Some(handle);
     }
 
-    /// Start network info refresh task
-    fn start_network_info_sync(&mut self) {
+    /// Start the scheduler that runs the periodic refresh tasks
+    ///
+    /// Tasks run one at a time: the most urgent due task first, highest priority
+    /// before earliest deadline. Low priority tasks wait while the network is
+    /// degraded, and all but high priority tasks while the TUI is idle.
+    fn start_task_scheduler(&mut self) {
         let sender = self.event_sender.clone();
         let client = Arc::clone(&self.client);
-        let interval_duration = self.config.network_info_interval;
+        let config = self.config.clone();
+        let queue = Arc::clone(&self.task_queue);
+        let wake = Arc::clone(&self.wake);
+        let network_state = Arc::clone(&self.network_state);
         let cancellation_token = self.cancellation_token.clone();
-        let retry_attempts = self.config.retry_attempts;
-        let retry_delay = self.config.retry_delay;
-        let network_timeout = self.config.network_timeout;
 
         let handle = tokio::spawn(async move {
-            let mut interval = interval(interval_duration);
-
             loop {
-                tokio::select! {
-                    _ = cancellation_token.cancelled() => break,
-                    _ = interval.tick() => {
-                        // Execute network info refresh with retry logic
-                        let mut success = false;
-                        let mut error_message = None;
-
-                        for attempt in 0..retry_attempts {
-                            match tokio::time::timeout(network_timeout, client.get_last_block_height()).await {
-                                Ok(Ok(_)) => {
-                                    success = true;
-                                    break;
-                                }
-                                Ok(Err(e)) => {
-                                    error_message = Some(e.to_string());
-                                    if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
-                                    }
-                                }
-                                Err(_) => {
-                                    error_message = Some("Operation timeout".to_string());
-                                    if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
-                                    }
-                                }
-                            }
-                        }
-
-                        let event = Event::DataRefresh {
-                            data_type: "network_info".to_string(),
-                            success,
-                            error: error_message,
-                        };
+                let degraded = *network_state.read().await != NetworkState::Connected;
+                let now = Instant::now();
+                let (due, next_deadline) = {
+                    let queue = queue.lock().unwrap();
+                    (queue.next_due(now, degraded), queue.next_deadline(degraded))
+                };
+
+                if let Some(task) = due {
+                    let event = tokio::select! {
+                        _ = cancellation_token.cancelled() => break,
+                        event = run_sync_task(task, &client, &config) => event,
+                    };
+                    queue
+                        .lock()
+                        .unwrap()
+                        .complete(task, Instant::now(), &config);
 
-                        if sender.send(event).is_err() {
-                            break;
-                        }
+                    if sender.send(event).is_err() {
+                        break; // Channel closed, stop task
                     }
+                    continue;
                 }
-            }
-        });
-
-        self.task_handles.push(handle);
-    }
-
-    /// Start price update task
-    fn start_price_sync(&mut self) {
-        let sender = self.event_sender.clone();
-        let client = Arc::clone(&self.client);
-        let interval_duration = self.config.price_update_interval;
-        let cancellation_token = self.cancellation_token.clone();
-
-        let handle = tokio::spawn(async move {
-            let mut interval = interval(interval_duration);
 
-            loop {
+                // Sleep until the next deadline, a reprioritization or a network change
+                let wake_at = next_deadline.unwrap_or(now + IDLE_WAKE_INTERVAL);
                 tokio::select! {
                     _ = cancellation_token.cancelled() => break,
-                    _ = interval.tick() => {
-                        // For now, just send a placeholder price refresh event
-                        // This can be enhanced with actual price data sources
-                        let event = Event::DataRefresh {
-                            data_type: "prices".to_string(),
-                            success: true,
-                            error: None,
-                        };
-
-                        if sender.send(event).is_err() {
-                            break;
-                        }
-                    }
+                    _ = tokio::time::sleep_until(wake_at) => {}
+                    _ = wake.notified() => {}
                 }
             }
         });
@@ -555,6 +849,9 @@ impl SyncManager {
         for handle in self.task_handles.drain(..) {
             handle.abort();
         }
+        if let Some(handle) = self.subscription_handle.take() {
+            handle.abort();
+        }
     }
 
     /// Update sync configuration
@@ -575,13 +872,13 @@ impl Drop for SyncManager {
 /// Enhanced async data refresher with comprehensive error handling
 pub struct AsyncDataRefresher {
     client: Arc<MantraDexClient>,
-    event_sender: mpsc::UnboundedSender<Event>,
+    event_sender: EventBus,
     config: SyncConfig,
 }
 
 impl AsyncDataRefresher {
     /// Create a new async data refresher
-    pub fn new(client: Arc<MantraDexClient>, event_sender: mpsc::UnboundedSender<Event>) -> Self {
+    pub fn new(client: Arc<MantraDexClient>, event_sender: EventBus) -> Self {
         Self {
             client,
             event_sender,
@@ -600,10 +897,10 @@ impl AsyncDataRefresher {
         Fut: std::future::Future<Output = Result<T, Error>>,
     {
         // Send start event
-        let _ = self.event_sender.send(Event::BlockchainProgress {
+        let _ = self.event_sender.send(TxEvent::BlockchainProgress {
             operation: operation_name.to_string(),
             status: "Starting operation...".to_string(),
-            progress: Some(0.1),
+            progress: None,
         });
 
         let start_time = std::time::Instant::now();
@@ -612,14 +909,14 @@ impl AsyncDataRefresher {
         for attempt in 0..=self.config.retry_attempts {
             if attempt > 0 {
                 // Send retry event
-                let _ = self.event_sender.send(Event::BlockchainProgress {
+                let _ = self.event_sender.send(TxEvent::BlockchainProgress {
                     operation: operation_name.to_string(),
                     status: format!(
                         "Retrying... (attempt {} of {})",
                         attempt + 1,
                         self.config.retry_attempts + 1
                     ),
-                    progress: Some(0.3 + (attempt as f32 * 0.2)),
+                    progress: None,
                 });
 
                 tokio::time::sleep(self.config.retry_delay).await;
@@ -628,7 +925,7 @@ impl AsyncDataRefresher {
             match tokio::time::timeout(self.config.network_timeout, operation()).await {
                 Ok(Ok(result)) => {
                     // Send success event
-                    let _ = self.event_sender.send(Event::BlockchainSuccess {
+                    let _ = self.event_sender.send(TxEvent::BlockchainSuccess {
                         operation: operation_name.to_string(),
                         result: format!("Operation completed in {:?}", start_time.elapsed()),
                         transaction_hash: None,
@@ -648,7 +945,7 @@ impl AsyncDataRefresher {
         let error = last_error.unwrap_or_else(|| Error::Rpc("Unknown error".to_string()));
 
         // Send error event
-        let _ = self.event_sender.send(Event::BlockchainError {
+        let _ = self.event_sender.send(TxEvent::BlockchainError {
             operation: operation_name.to_string(),
             error: error.to_string(),
         });
@@ -669,7 +966,10 @@ impl AsyncDataRefresher {
     pub async fn refresh_pool_data(&self) -> Result<(), Error> {
         self.execute_operation("pool_data_refresh", || {
             let client = Arc::clone(&self.client);
-            async move { client.get_pools(Some(50)).await.map(|_| ()) }
+            async move {
+                let cursor = PageCursor::first(50);
+                client.get_pools(&cursor).await.map(|_| ())
+            }
         })
         .await
     }
@@ -726,7 +1026,7 @@ pub struct BackgroundTaskCoordinator {
 impl BackgroundTaskCoordinator {
     /// Create a new background task coordinator
     pub fn new(
-        event_sender: mpsc::UnboundedSender<Event>,
+        event_sender: EventBus,
         client: Arc<MantraDexClient>,
         config: Option<SyncConfig>,
     ) -> Self {
@@ -761,6 +1061,16 @@ impl BackgroundTaskCoordinator {
         self.sync_manager.set_wallet_address(address);
     }
 
+    /// Reprioritize background tasks for the screen being shown
+    pub fn set_active_screen(&mut self, screen: Screen) {
+        self.sync_manager.set_active_screen(screen);
+    }
+
+    /// Pause non-essential background tasks while the TUI is idle
+    pub fn set_idle(&mut self, idle: bool) {
+        self.sync_manager.set_idle(idle);
+    }
+
     /// Get data refresher for manual refresh operations
     pub fn get_data_refresher(&self) -> &AsyncDataRefresher {
         &self.data_refresher
@@ -802,6 +1112,152 @@ mod tests {
         assert!(true);
     }
 
+    #[test]
+    fn test_task_queue_prioritizes_active_screen() {
+        let now = Instant::now();
+        let mut queue = TaskQueue::new(Screen::Dashboard, now);
+        assert_eq!(queue.next_due(now, false), Some(SyncTask::Balances));
+
+        queue.reprioritize(Screen::Pools);
+        assert_eq!(queue.priority(SyncTask::Pools), Some(TaskPriority::High));
+        assert_eq!(queue.priority(SyncTask::Balances), Some(TaskPriority::Low));
+        assert_eq!(queue.next_due(now, false), Some(SyncTask::Pools));
+
+        // A completed task waits for its interval
+        let config = SyncConfig::default();
+        queue.complete(SyncTask::Pools, now, &config);
+        assert_eq!(
+            queue.deadline(SyncTask::Pools),
+            Some(now + config.pool_data_refresh_interval)
+        );
+        assert_eq!(queue.next_due(now, false), Some(SyncTask::NetworkInfo));
+    }
+
+    #[test]
+    fn test_task_queue_follows_chain_events() {
+        let now = Instant::now();
+        let config = SyncConfig::default();
+        let mut queue = TaskQueue::new(Screen::Dashboard, now);
+        queue.set_subscribed(true, now);
+        assert!(queue.is_subscribed());
+
+        // Balances wait for an event or the fallback interval, other tasks poll as usual
+        queue.complete(SyncTask::Balances, now, &config);
+        queue.complete(SyncTask::NetworkInfo, now, &config);
+        assert_eq!(
+            queue.deadline(SyncTask::Balances),
+            Some(now + config.subscription_fallback_interval)
+        );
+        assert_eq!(
+            queue.deadline(SyncTask::NetworkInfo),
+            Some(now + config.network_info_interval)
+        );
+
+        let later = now + Duration::from_secs(5);
+        queue.expedite(SyncTask::Balances, later);
+        assert_eq!(queue.deadline(SyncTask::Balances), Some(later));
+
+        // Dropping the stream makes the tasks following it due, as events may have been missed
+        queue.complete(SyncTask::Balances, later, &config);
+        queue.complete(SyncTask::Pools, later, &config);
+        queue.set_subscribed(false, later);
+        assert_eq!(queue.deadline(SyncTask::Balances), Some(later));
+        assert_eq!(queue.deadline(SyncTask::Pools), Some(later));
+        queue.complete(SyncTask::Pools, later, &config);
+        assert_eq!(
+            queue.deadline(SyncTask::Pools),
+            Some(later + config.pool_data_refresh_interval)
+        );
+    }
+
+    #[test]
+    fn test_task_queue_orders_equal_priority_by_deadline() {
+        let now = Instant::now();
+        let config = SyncConfig::default();
+        let mut queue = TaskQueue::new(Screen::Settings, now);
+        for task in SyncTask::ALL {
+            queue.complete(task, now, &config);
+        }
+
+        // Transactions ran late and are now due after prices, at the same priority
+        queue.complete(
+            SyncTask::Transactions,
+            now + Duration::from_secs(8),
+            &config,
+        );
+        assert_eq!(
+            queue.next_deadline(false),
+            Some(now + config.price_update_interval)
+        );
+        let later = now + Duration::from_secs(20);
+        assert_eq!(queue.next_due(later, false), Some(SyncTask::Prices));
+        queue.complete(SyncTask::Prices, later, &config);
+        assert_eq!(queue.next_due(later, false), Some(SyncTask::Transactions));
+    }
+
+    #[test]
+    fn test_task_queue_pauses_low_priority_when_degraded() {
+        let now = Instant::now();
+        let config = SyncConfig::default();
+        let mut queue = TaskQueue::new(Screen::Pools, now);
+        queue.complete(SyncTask::Pools, now, &config);
+        queue.complete(SyncTask::NetworkInfo, now, &config);
+
+        assert_eq!(queue.next_due(now, true), None);
+        assert_eq!(
+            queue.next_deadline(true),
+            Some(now + config.network_info_interval)
+        );
+
+        // Low priority tasks resume once the network recovers
+        assert!(queue.next_due(now, false).is_some());
+        assert_eq!(queue.next_deadline(false), Some(now));
+    }
+
+    #[test]
+    fn test_task_queue_runs_only_high_priority_while_idle() {
+        let now = Instant::now();
+        let config = SyncConfig::default();
+        let mut queue = TaskQueue::new(Screen::Pools, now);
+        queue.complete(SyncTask::Pools, now, &config);
+        queue.set_idle(true);
+
+        // Network info is normal priority everywhere, so it waits
+        assert_eq!(queue.next_due(now, false), None);
+        let pools_due = now + config.pool_data_refresh_interval;
+        assert_eq!(queue.next_deadline(false), Some(pools_due));
+        assert_eq!(queue.next_due(pools_due, false), Some(SyncTask::Pools));
+
+        // Tasks that came due while idle run at once when input resumes
+        queue.set_idle(false);
+        assert_eq!(queue.next_due(now, false), Some(SyncTask::NetworkInfo));
+    }
+
+    #[test]
+    fn test_activity_tracker_idles_after_timeout_or_focus_loss() {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(60);
+        let mut activity = ActivityTracker::new(timeout, start);
+
+        assert!(!activity.is_idle(start + Duration::from_secs(59)));
+        assert!(activity.is_idle(start + timeout));
+        assert_eq!(activity.tick_rate(start + timeout), IDLE_TICK_RATE);
+
+        let later = start + Duration::from_secs(90);
+        activity.record_input(later);
+        assert!(!activity.is_idle(later));
+        assert_eq!(activity.tick_rate(later), ACTIVE_TICK_RATE);
+
+        activity.set_focused(false, later);
+        assert!(activity.is_idle(later));
+        activity.set_focused(true, later + timeout);
+        assert!(!activity.is_idle(later + timeout));
+
+        // Without a timeout only focus loss makes it idle
+        activity.set_idle_timeout(Duration::ZERO);
+        assert!(!activity.is_idle(later + Duration::from_secs(3600)));
+    }
+
     #[test]
     fn test_operation_result() {
         let success_result = OperationResult::success(Duration::from_secs(1));
@@ -4,7 +4,7 @@
 //! keyboard navigation, tab order, and focus state across all TUI components.
 //! It ensures consistent navigation behavior and accessibility compliance.
 
-use crate::tui::events::{Event, FocusDirection, FocusableComponent};
+use crate::tui::events::{FocusDirection, FocusableComponent, UiEvent};
 use std::collections::HashMap;
 
 /// Focus manager handles focus state and navigation across components
@@ -220,25 +220,25 @@ impl FocusManager {
     }
 
     /// Handle focus-related events
-    pub fn handle_event(&mut self, event: &Event) -> Option<FocusableComponent> {
+    pub fn handle_event(&mut self, event: &UiEvent) -> Option<FocusableComponent> {
         match event {
-            Event::FocusNext => self.focus_next(),
-            Event::FocusPrevious => self.focus_previous(),
-            Event::FocusFirst => self.focus_first(),
-            Event::FocusLast => self.focus_last(),
-            Event::MoveFocus(direction) => self.move_focus(direction.clone()),
-            Event::SetFocus(component) => {
+            UiEvent::FocusNext => self.focus_next(),
+            UiEvent::FocusPrevious => self.focus_previous(),
+            UiEvent::FocusFirst => self.focus_first(),
+            UiEvent::FocusLast => self.focus_last(),
+            UiEvent::MoveFocus(direction) => self.move_focus(direction.clone()),
+            UiEvent::SetFocus(component) => {
                 if self.set_focus(component.clone()) {
                     Some(component.clone())
                 } else {
                     None
                 }
             }
-            Event::ClearFocus => {
+            UiEvent::ClearFocus => {
                 self.clear_focus();
                 None
             }
-            Event::Escape => {
+            UiEvent::Escape => {
                 // Escape key can be used to return to previous focus or clear focus
                 self.return_to_previous().or_else(|| {
                     self.clear_focus();
@@ -532,15 +532,15 @@ mod tests {
 
         // Test event handling
         assert_eq!(
-            manager.handle_event(&Event::FocusNext),
+            manager.handle_event(&UiEvent::FocusNext),
             Some(components[0].clone())
         );
         assert_eq!(
-            manager.handle_event(&Event::FocusNext),
+            manager.handle_event(&UiEvent::FocusNext),
             Some(components[1].clone())
         );
         assert_eq!(
-            manager.handle_event(&Event::FocusPrevious),
+            manager.handle_event(&UiEvent::FocusPrevious),
             Some(components[0].clone())
         );
     }
@@ -0,0 +1,238 @@
+//! Per-screen data loading with stale-while-revalidate semantics
+//!
+//! Each screen declares the data it shows in [`DataKey::for_screen`]. When a
+//! screen is shown the app renders whatever it has cached straight away, then
+//! asks the [`DataLoader`] which of the screen's data is stale and revalidates
+//! it in the background through `DataRefresh` events. The screen is updated
+//! again as each piece arrives. A refresh marks the screen's data stale first,
+//! so everything it shows is fetched again.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::tui::app::Screen;
+
+/// A piece of data one or more screens show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataKey {
+    Balances,
+    Pools,
+    NetworkInfo,
+    AssetDecimals,
+    /// Execution report and earn opportunities of the active wallet
+    Analytics,
+    EpochCalendar,
+    ScheduledTxs,
+    /// Recurring swaps and their upcoming executions
+    DcaPlans,
+    /// Transactions the active wallet sent, synced into the local history
+    TxHistory,
+    /// LP positions of the active wallet
+    LpPositions,
+    /// Holdings of the active wallet valued at pool prices
+    Portfolio,
+}
+
+impl DataKey {
+    /// Every piece of data
+    pub const ALL: [DataKey; 11] = [
+        DataKey::Balances,
+        DataKey::Pools,
+        DataKey::NetworkInfo,
+        DataKey::AssetDecimals,
+        DataKey::Analytics,
+        DataKey::EpochCalendar,
+        DataKey::ScheduledTxs,
+        DataKey::DcaPlans,
+        DataKey::TxHistory,
+        DataKey::LpPositions,
+        DataKey::Portfolio,
+    ];
+
+    /// Data `screen` shows, in the order it is revalidated
+    pub fn for_screen(screen: Screen) -> &'static [DataKey] {
+        match screen {
+            Screen::Dashboard => &[
+                DataKey::AssetDecimals,
+                DataKey::Balances,
+                DataKey::NetworkInfo,
+                DataKey::Pools,
+                DataKey::Portfolio,
+            ],
+            Screen::Pools | Screen::Admin => &[DataKey::Pools],
+            Screen::Swap => &[DataKey::Pools, DataKey::DcaPlans],
+            Screen::Liquidity => &[DataKey::Pools, DataKey::LpPositions],
+            Screen::Analytics => &[DataKey::Analytics],
+            Screen::Rewards => &[DataKey::EpochCalendar, DataKey::ScheduledTxs],
+            Screen::TransactionDetails => &[DataKey::TxHistory],
+            _ => &[],
+        }
+    }
+
+    /// Data type of the key's `DataRefresh` events
+    pub fn data_type(&self) -> &'static str {
+        match self {
+            DataKey::Balances => "balances",
+            DataKey::Pools => "pools",
+            DataKey::NetworkInfo => "network_info",
+            DataKey::AssetDecimals => "asset_decimals",
+            DataKey::Analytics => "analytics",
+            DataKey::EpochCalendar => "epoch_calendar",
+            DataKey::ScheduledTxs => "scheduled_txs",
+            DataKey::DcaPlans => "dca_plans",
+            DataKey::TxHistory => "tx_history",
+            DataKey::LpPositions => "lp_positions",
+            DataKey::Portfolio => "portfolio",
+        }
+    }
+
+    /// The key whose `DataRefresh` events carry `data_type`
+    pub fn from_data_type(data_type: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|key| key.data_type() == data_type)
+    }
+
+    /// How long loaded data is served without revalidating
+    pub fn max_age(&self) -> Duration {
+        match self {
+            DataKey::Balances | DataKey::ScheduledTxs | DataKey::DcaPlans => {
+                Duration::from_secs(30)
+            }
+            DataKey::Pools
+            | DataKey::EpochCalendar
+            | DataKey::TxHistory
+            | DataKey::LpPositions
+            | DataKey::Portfolio => Duration::from_secs(60),
+            DataKey::NetworkInfo => Duration::from_secs(45),
+            DataKey::Analytics => Duration::from_secs(300),
+            DataKey::AssetDecimals => Duration::from_secs(3600),
+        }
+    }
+}
+
+/// When a piece of data was last loaded, and whether it is being loaded
+#[derive(Debug, Clone, Copy, Default)]
+struct LoadState {
+    loaded_at: Option<Instant>,
+    in_flight: bool,
+}
+
+/// Tracks the freshness of every piece of data the screens show
+#[derive(Debug, Clone, Default)]
+pub struct DataLoader {
+    states: HashMap<DataKey, LoadState>,
+}
+
+impl DataLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Data `screen` shows that should be revalidated at `now`
+    ///
+    /// Data is stale if it was never loaded or is older than its
+    /// [`DataKey::max_age`]; data already being loaded is left out.
+    pub fn stale(&self, screen: Screen, now: Instant) -> Vec<DataKey> {
+        DataKey::for_screen(screen)
+            .iter()
+            .copied()
+            .filter(|key| {
+                let state = self.states.get(key).copied().unwrap_or_default();
+                !state.in_flight
+                    && state
+                        .loaded_at
+                        .is_none_or(|loaded_at| now.duration_since(loaded_at) >= key.max_age())
+            })
+            .collect()
+    }
+
+    /// Record that `key` is being loaded
+    pub fn begin(&mut self, key: DataKey) {
+        self.states.entry(key).or_default().in_flight = true;
+    }
+
+    /// Record that loading `key` finished at `now`; failed data stays stale
+    pub fn finish(&mut self, key: DataKey, now: Instant, success: bool) {
+        let state = self.states.entry(key).or_default();
+        state.in_flight = false;
+        if success {
+            state.loaded_at = Some(now);
+        }
+    }
+
+    /// Mark the data `screen` shows stale, so it is revalidated next
+    pub fn invalidate(&mut self, screen: Screen) {
+        for key in DataKey::for_screen(screen) {
+            self.states.entry(*key).or_default().loaded_at = None;
+        }
+    }
+
+    /// Mark all data stale, e.g. after switching networks
+    pub fn invalidate_all(&mut self) {
+        for state in self.states.values_mut() {
+            state.loaded_at = None;
+        }
+    }
+
+    /// Whether `key` is being loaded
+    pub fn is_loading(&self, key: DataKey) -> bool {
+        self.states.get(&key).is_some_and(|state| state.in_flight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loader_revalidates_stale_data_once() {
+        let mut loader = DataLoader::new();
+        let start = Instant::now();
+
+        // Nothing is cached yet
+        assert_eq!(
+            loader.stale(Screen::Swap, start),
+            vec![DataKey::Pools, DataKey::DcaPlans]
+        );
+        loader.begin(DataKey::Pools);
+        assert_eq!(loader.stale(Screen::Swap, start), vec![DataKey::DcaPlans]);
+        assert!(loader.is_loading(DataKey::Pools));
+
+        // Fresh data is served as is, by every screen that shows it
+        loader.finish(DataKey::Pools, start, true);
+        assert!(loader.stale(Screen::Admin, start).is_empty());
+        assert_eq!(
+            loader.stale(Screen::Pools, start + DataKey::Pools.max_age()),
+            vec![DataKey::Pools]
+        );
+
+        // A refresh revalidates even fresh data
+        loader.invalidate(Screen::Pools);
+        assert_eq!(loader.stale(Screen::Pools, start), vec![DataKey::Pools]);
+    }
+
+    #[test]
+    fn test_failed_load_stays_stale() {
+        let mut loader = DataLoader::new();
+        let now = Instant::now();
+        loader.begin(DataKey::EpochCalendar);
+        loader.finish(DataKey::EpochCalendar, now, false);
+        loader.finish(DataKey::ScheduledTxs, now, true);
+        assert_eq!(
+            loader.stale(Screen::Rewards, now),
+            vec![DataKey::EpochCalendar]
+        );
+        assert!(loader.stale(Screen::Settings, now).is_empty());
+    }
+
+    #[test]
+    fn test_data_type_round_trip() {
+        for key in DataKey::ALL {
+            assert_eq!(DataKey::from_data_type(key.data_type()), Some(key));
+        }
+        assert_eq!(DataKey::from_data_type("prices"), None);
+    }
+}
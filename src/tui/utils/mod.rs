@@ -6,8 +6,10 @@
 pub mod async_ops;
 pub mod focus_manager;
 pub mod formatting;
+pub mod loader;
 pub mod logger;
 pub mod responsive;
+pub mod session;
 pub mod validation;
 
 pub use async_ops::*;
@@ -0,0 +1,420 @@
+//! Session Recording and Replay
+//!
+//! This module provides an opt-in recorder that writes user input events and the
+//! resulting application state to a JSON Lines file, and a replayer that feeds a
+//! recorded session back into the TUI event loop. Recordings are meant to be
+//! attached to bug reports, so sensitive input (passwords, mnemonics) is never
+//! written to disk.
+
+use crate::tui::events::{Event, EventBus, FocusDirection, UiEvent};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Current recording format version
+pub const SESSION_FORMAT_VERSION: u32 = 1;
+
+/// User input captured in a session recording
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum RecordedInput {
+    Char(char),
+    /// A keystroke typed into a sensitive field; the actual value is never stored
+    Redacted,
+    Backspace,
+    Delete,
+    Enter,
+    Escape,
+    Tab,
+    BackTab,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Insert,
+    F(u8),
+    Ctrl(char),
+    Alt(char),
+    Refresh,
+    Help,
+    ContextAction,
+    Paste(String),
+    /// Pasted text into a sensitive field; only the length is kept
+    RedactedPaste(usize),
+    Quit,
+}
+
+impl RecordedInput {
+    /// Convert an application event into a recordable input
+    ///
+    /// Returns `None` for internal events (blockchain results, custom events, etc.)
+    /// which are produced by the application itself and will be regenerated on replay.
+    pub fn from_event(event: &Event, sensitive: bool) -> Option<Self> {
+        let Event::Ui(event) = event else {
+            return None;
+        };
+        let input = match event {
+            UiEvent::Char(_) if sensitive => Self::Redacted,
+            UiEvent::Char(c) => Self::Char(*c),
+            UiEvent::Paste(text) if sensitive => Self::RedactedPaste(text.chars().count()),
+            UiEvent::Paste(text) => Self::Paste(text.clone()),
+            UiEvent::Backspace => Self::Backspace,
+            UiEvent::Delete => Self::Delete,
+            UiEvent::Enter => Self::Enter,
+            UiEvent::Escape => Self::Escape,
+            UiEvent::Tab => Self::Tab,
+            UiEvent::BackTab => Self::BackTab,
+            UiEvent::MoveFocus(FocusDirection::Up) => Self::Up,
+            UiEvent::MoveFocus(FocusDirection::Down) => Self::Down,
+            UiEvent::MoveFocus(FocusDirection::Left) => Self::Left,
+            UiEvent::MoveFocus(FocusDirection::Right) => Self::Right,
+            UiEvent::FocusFirst => Self::Home,
+            UiEvent::FocusLast => Self::End,
+            UiEvent::PageUp => Self::PageUp,
+            UiEvent::PageDown => Self::PageDown,
+            UiEvent::Insert => Self::Insert,
+            UiEvent::F(n) => Self::F(*n),
+            UiEvent::Ctrl(c) => Self::Ctrl(*c),
+            UiEvent::Alt(c) => Self::Alt(*c),
+            UiEvent::Refresh => Self::Refresh,
+            UiEvent::Help => Self::Help,
+            UiEvent::ContextAction => Self::ContextAction,
+            UiEvent::Quit => Self::Quit,
+            _ => return None,
+        };
+        Some(input)
+    }
+
+    /// Convert the recorded input back into an application event
+    ///
+    /// Redacted inputs cannot be reproduced and return `None`.
+    pub fn to_event(&self) -> Option<UiEvent> {
+        let event = match self {
+            Self::Char(c) => UiEvent::Char(*c),
+            Self::Redacted | Self::RedactedPaste(_) => return None,
+            Self::Paste(text) => UiEvent::Paste(text.clone()),
+            Self::Backspace => UiEvent::Backspace,
+            Self::Delete => UiEvent::Delete,
+            Self::Enter => UiEvent::Enter,
+            Self::Escape => UiEvent::Escape,
+            Self::Tab => UiEvent::Tab,
+            Self::BackTab => UiEvent::BackTab,
+            Self::Up => UiEvent::MoveFocus(FocusDirection::Up),
+            Self::Down => UiEvent::MoveFocus(FocusDirection::Down),
+            Self::Left => UiEvent::MoveFocus(FocusDirection::Left),
+            Self::Right => UiEvent::MoveFocus(FocusDirection::Right),
+            Self::Home => UiEvent::FocusFirst,
+            Self::End => UiEvent::FocusLast,
+            Self::PageUp => UiEvent::PageUp,
+            Self::PageDown => UiEvent::PageDown,
+            Self::Insert => UiEvent::Insert,
+            Self::F(n) => UiEvent::F(*n),
+            Self::Ctrl(c) => UiEvent::Ctrl(*c),
+            Self::Alt(c) => UiEvent::Alt(*c),
+            Self::Refresh => UiEvent::Refresh,
+            Self::Help => UiEvent::Help,
+            Self::ContextAction => UiEvent::ContextAction,
+            Self::Quit => UiEvent::Quit,
+        };
+        Some(event)
+    }
+
+    /// Whether this input was redacted at record time
+    pub fn is_redacted(&self) -> bool {
+        matches!(self, Self::Redacted | Self::RedactedPaste(_))
+    }
+}
+
+/// Snapshot of the application state after an input was handled
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    pub screen: String,
+    pub navigation_mode: String,
+    pub wizard_step: Option<String>,
+    pub modal_open: bool,
+    pub status_message: Option<String>,
+    pub error_message: Option<String>,
+}
+
+/// A single line in a session recording
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SessionEntry {
+    /// First line of every recording
+    Header {
+        version: u32,
+        started_at: String,
+        sdk_version: String,
+        network: String,
+    },
+    /// A user input and the state it produced
+    Input {
+        elapsed_ms: u64,
+        input: RecordedInput,
+        state: StateSnapshot,
+    },
+}
+
+/// Writes a session recording to disk
+pub struct SessionRecorder {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    started: Instant,
+    entries_written: usize,
+}
+
+impl std::fmt::Debug for SessionRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionRecorder")
+            .field("path", &self.path)
+            .field("entries_written", &self.entries_written)
+            .finish()
+    }
+}
+
+impl SessionRecorder {
+    /// Create a new recording at `path`, truncating any existing file
+    pub fn create(path: &Path, network: &str) -> Result<Self, Error> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+
+        let mut recorder = Self {
+            writer: BufWriter::new(file),
+            path: path.to_path_buf(),
+            started: Instant::now(),
+            entries_written: 0,
+        };
+
+        recorder.write_entry(&SessionEntry::Header {
+            version: SESSION_FORMAT_VERSION,
+            started_at: chrono::Utc::now().to_rfc3339(),
+            sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+            network: network.to_string(),
+        })?;
+
+        Ok(recorder)
+    }
+
+    /// Record an input together with the state it produced
+    pub fn record(&mut self, input: RecordedInput, state: StateSnapshot) -> Result<(), Error> {
+        let elapsed_ms = self.started.elapsed().as_millis() as u64;
+        self.write_entry(&SessionEntry::Input {
+            elapsed_ms,
+            input,
+            state,
+        })
+    }
+
+    /// Path of the recording file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of entries written so far (including the header)
+    pub fn entries_written(&self) -> usize {
+        self.entries_written
+    }
+
+    fn write_entry(&mut self, entry: &SessionEntry) -> Result<(), Error> {
+        let line = serde_json::to_string(entry)?;
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        // Flush every entry so the recording survives crashes, which is exactly when it's needed
+        self.writer.flush()?;
+        self.entries_written += 1;
+        Ok(())
+    }
+}
+
+/// Loads a session recording and replays it into the TUI event loop
+#[derive(Debug, Clone)]
+pub struct SessionReplayer {
+    entries: Vec<SessionEntry>,
+}
+
+impl SessionReplayer {
+    /// Load a recording from disk
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file = File::open(path)?;
+        let mut entries = Vec::new();
+
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: SessionEntry = serde_json::from_str(&line).map_err(|e| {
+                Error::Other(format!(
+                    "Invalid session recording at line {}: {}",
+                    index + 1,
+                    e
+                ))
+            })?;
+            entries.push(entry);
+        }
+
+        match entries.first() {
+            Some(SessionEntry::Header { version, .. }) if *version <= SESSION_FORMAT_VERSION => {}
+            Some(SessionEntry::Header { version, .. }) => {
+                return Err(Error::Other(format!(
+                    "Unsupported session recording version {} (max supported: {})",
+                    version, SESSION_FORMAT_VERSION
+                )));
+            }
+            _ => {
+                return Err(Error::Other(
+                    "Session recording is missing its header".to_string(),
+                ))
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// All entries in the recording, including the header
+    pub fn entries(&self) -> &[SessionEntry] {
+        &self.entries
+    }
+
+    /// Number of recorded inputs that were redacted and cannot be replayed
+    pub fn redacted_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(
+                |entry| matches!(entry, SessionEntry::Input { input, .. } if input.is_redacted()),
+            )
+            .count()
+    }
+
+    /// Events to replay together with the delay to wait before each one
+    ///
+    /// `speed` scales the original timing (2.0 replays twice as fast). Redacted inputs
+    /// are skipped, but their delay is kept so the surrounding timing stays realistic.
+    pub fn schedule(&self, speed: f64) -> Vec<(Duration, UiEvent)> {
+        let speed = if speed > 0.0 { speed } else { 1.0 };
+        let mut schedule = Vec::new();
+        let mut last_ms = 0u64;
+        let mut carried = Duration::ZERO;
+
+        for entry in &self.entries {
+            if let SessionEntry::Input {
+                elapsed_ms, input, ..
+            } = entry
+            {
+                let delta = elapsed_ms.saturating_sub(last_ms);
+                last_ms = *elapsed_ms;
+                let delay = carried + Duration::from_secs_f64(delta as f64 / 1000.0 / speed);
+
+                match input.to_event() {
+                    Some(event) => {
+                        schedule.push((delay, event));
+                        carried = Duration::ZERO;
+                    }
+                    None => carried = delay,
+                }
+            }
+        }
+
+        schedule
+    }
+
+    /// Spawn a task that sends the recorded events into the application event channel
+    pub fn spawn(&self, event_bus: EventBus, speed: f64) -> tokio::task::JoinHandle<()> {
+        let schedule = self.schedule(speed);
+        tokio::spawn(async move {
+            for (delay, event) in schedule {
+                tokio::time::sleep(delay).await;
+                if event_bus.send(event).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::events::TxEvent;
+
+    #[test]
+    fn test_sensitive_input_is_redacted() {
+        assert_eq!(
+            RecordedInput::from_event(&UiEvent::Char('x').into(), true),
+            Some(RecordedInput::Redacted)
+        );
+        assert_eq!(
+            RecordedInput::from_event(&UiEvent::Paste("secret words".to_string()).into(), true),
+            Some(RecordedInput::RedactedPaste(12))
+        );
+        assert_eq!(
+            RecordedInput::from_event(&UiEvent::Char('x').into(), false),
+            Some(RecordedInput::Char('x'))
+        );
+        // Navigation keys are safe to record even in sensitive fields
+        assert_eq!(
+            RecordedInput::from_event(&UiEvent::Enter.into(), true),
+            Some(RecordedInput::Enter)
+        );
+    }
+
+    #[test]
+    fn test_internal_events_are_not_recorded() {
+        let event = TxEvent::BlockchainError {
+            operation: "swap".to_string(),
+            error: "failed".to_string(),
+        };
+        assert_eq!(RecordedInput::from_event(&event.into(), false), None);
+        assert_eq!(
+            RecordedInput::from_event(&UiEvent::Custom("tick".to_string()).into(), false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_record_and_replay_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("mantra-session-{}", std::process::id()));
+        let path = dir.join("session.jsonl");
+
+        let mut recorder = SessionRecorder::create(&path, "mantra-dukong").unwrap();
+        recorder
+            .record(RecordedInput::Tab, StateSnapshot::default())
+            .unwrap();
+        recorder
+            .record(RecordedInput::Redacted, StateSnapshot::default())
+            .unwrap();
+        recorder
+            .record(RecordedInput::Char('a'), StateSnapshot::default())
+            .unwrap();
+        assert_eq!(recorder.entries_written(), 4);
+        drop(recorder);
+
+        let replayer = SessionReplayer::load(&path).unwrap();
+        assert_eq!(replayer.redacted_count(), 1);
+
+        let events: Vec<UiEvent> = replayer
+            .schedule(1.0)
+            .into_iter()
+            .map(|(_, event)| event)
+            .collect();
+        assert_eq!(events, vec![UiEvent::Tab, UiEvent::Char('a')]);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
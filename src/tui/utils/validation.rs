@@ -3,6 +3,10 @@
 //! This module provides validation functions for common input types
 //! in the MANTRA DEX TUI application.
 
+use cosmwasm_std::Decimal;
+
+use crate::amount::{self, NumberLocale};
+
 /// Validate a wallet address
 pub fn validate_address(address: &str) -> Result<(), String> {
     if address.starts_with("mantra") && address.len() >= 40 {
@@ -12,13 +16,15 @@ pub fn validate_address(address: &str) -> Result<(), String> {
     }
 }
 
-/// Validate a numeric amount
-pub fn validate_amount(amount: &str) -> Result<f64, String> {
-    match amount.parse::<f64>() {
-        Ok(value) if value >= 0.0 => Ok(value),
-        Ok(_) => Err("Amount must be positive".to_string()),
-        Err(_) => Err("Invalid number format".to_string()),
+/// Validate a numeric amount typed with the separators of `locale`
+pub fn validate_amount(amount: &str, locale: NumberLocale) -> Result<Decimal, String> {
+    if amount.trim_start().starts_with('-') {
+        return Err("Amount must be positive".to_string());
     }
+    amount::parse_amount(amount, locale).map_err(|_| match locale {
+        NumberLocale::Point => "Invalid amount format (e.g. 1,234.56)".to_string(),
+        NumberLocale::Comma => "Invalid amount format (e.g. 1.234,56)".to_string(),
+    })
 }
 
 /// Validate a pool ID
@@ -60,10 +66,15 @@ mod tests {
 
     #[test]
     fn test_validate_amount() {
-        assert!(validate_amount("100.5").is_ok());
-        assert!(validate_amount("0").is_ok());
-        assert!(validate_amount("-10").is_err());
-        assert!(validate_amount("not_a_number").is_err());
+        assert!(validate_amount("100.5", NumberLocale::Point).is_ok());
+        assert!(validate_amount("0", NumberLocale::Point).is_ok());
+        assert!(validate_amount("-10", NumberLocale::Point).is_err());
+        assert!(validate_amount("not_a_number", NumberLocale::Point).is_err());
+        assert_eq!(
+            validate_amount("1.234,5", NumberLocale::Comma),
+            Ok(Decimal::percent(123_450))
+        );
+        assert!(validate_amount("1,5", NumberLocale::Point).is_err());
     }
 
     #[test]
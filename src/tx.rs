@@ -0,0 +1,336 @@
+//! Signer-agnostic transaction construction
+//!
+//! The client signs with a local [`MantraWallet`](crate::wallet::MantraWallet),
+//! but every step up to the signature is available on its own, so a service can
+//! build transactions with this crate and sign them elsewhere, e.g. with a
+//! hardware wallet or a remote signer:
+//!
+//! 1. [`TxBuilder`] turns messages, a fee and the signer's account into an [`UnsignedTx`]
+//!    ([`MantraDexClient::prepare_tx`](crate::MantraDexClient::prepare_tx) does
+//!    this with the account, gas and fee queried from the chain).
+//! 2. [`UnsignedTx::sign_bytes`] gives the exact bytes to sign in the chosen
+//!    [`SignMode`]: the protobuf `SignDoc` for `SIGN_MODE_DIRECT`, or the sorted
+//!    `StdSignDoc` JSON for `SIGN_MODE_LEGACY_AMINO_JSON`.
+//! 3. [`UnsignedTx::into_signed`] attaches the secp256k1 signature of those
+//!    bytes and returns the transaction ready to broadcast.
+//!
+//! Amino JSON needs a JSON form of each message; it is known for contract
+//! executions and bank sends, the messages this crate produces.
+
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::bank::v1beta1::MsgSend;
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::tendermint::chain::Id;
+use cosmrs::tx::{self, AuthInfo, Body, Fee, ModeInfo, Raw, SignDoc, SignerInfo};
+use cosmrs::Any;
+use prost::Message;
+use serde_json::{json, Value};
+
+use crate::canonical;
+use crate::error::Error;
+
+/// Type URL of a CosmWasm contract execution
+const MSG_EXECUTE_CONTRACT: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+/// Type URL of a bank send
+const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+/// How the signer signs a transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SignMode {
+    /// `SIGN_MODE_DIRECT`: the protobuf encoded `SignDoc`
+    #[default]
+    Direct,
+    /// `SIGN_MODE_LEGACY_AMINO_JSON`: the sorted JSON `StdSignDoc`, e.g. for Ledger devices
+    LegacyAmino,
+}
+
+impl SignMode {
+    fn mode_info(self) -> ModeInfo {
+        ModeInfo::single(match self {
+            SignMode::Direct => tx::SignMode::Direct,
+            SignMode::LegacyAmino => tx::SignMode::LegacyAminoJson,
+        })
+    }
+}
+
+/// Builds an [`UnsignedTx`] from messages, a fee and the signer's account
+#[derive(Debug, Clone)]
+pub struct TxBuilder {
+    chain_id: String,
+    msgs: Vec<Any>,
+    memo: String,
+    timeout_height: u32,
+    fee: Option<Fee>,
+    signer: Option<(PublicKey, u64, u64)>,
+    sign_mode: SignMode,
+}
+
+impl TxBuilder {
+    pub fn new(chain_id: impl Into<String>) -> Self {
+        Self {
+            chain_id: chain_id.into(),
+            msgs: Vec::new(),
+            memo: String::new(),
+            timeout_height: 0,
+            fee: None,
+            signer: None,
+            sign_mode: SignMode::default(),
+        }
+    }
+
+    /// Add a message to the transaction
+    pub fn with_message(mut self, msg: Any) -> Self {
+        self.msgs.push(msg);
+        self
+    }
+
+    /// Add messages to the transaction, in order
+    pub fn with_messages(mut self, msgs: impl IntoIterator<Item = Any>) -> Self {
+        self.msgs.extend(msgs);
+        self
+    }
+
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+
+    /// Set the last block the transaction may be included in, 0 for none
+    pub fn with_timeout_height(mut self, timeout_height: u32) -> Self {
+        self.timeout_height = timeout_height;
+        self
+    }
+
+    pub fn with_fee(mut self, fee: Fee) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Set the signer's public key and the account number and sequence of its account
+    pub fn with_signer(
+        mut self,
+        public_key: PublicKey,
+        account_number: u64,
+        sequence: u64,
+    ) -> Self {
+        self.signer = Some((public_key, account_number, sequence));
+        self
+    }
+
+    pub fn with_sign_mode(mut self, sign_mode: SignMode) -> Self {
+        self.sign_mode = sign_mode;
+        self
+    }
+
+    /// Assemble the unsigned transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Tx`] if there are no messages, the fee or signer is
+    /// missing, or the chain ID is invalid
+    pub fn build(self) -> Result<UnsignedTx, Error> {
+        if self.msgs.is_empty() {
+            return Err(Error::Tx(
+                "A transaction needs at least one message".to_string(),
+            ));
+        }
+        let fee = self
+            .fee
+            .ok_or_else(|| Error::Tx("A transaction needs a fee".to_string()))?;
+        let (public_key, account_number, sequence) = self
+            .signer
+            .ok_or_else(|| Error::Tx("A transaction needs a signer".to_string()))?;
+        Id::try_from(self.chain_id.as_str())
+            .map_err(|e| Error::Tx(format!("Invalid chain ID: {}", e)))?;
+
+        let signer_info = SignerInfo {
+            public_key: Some(public_key.into()),
+            mode_info: self.sign_mode.mode_info(),
+            sequence,
+        };
+        Ok(UnsignedTx {
+            body: Body::new(self.msgs, self.memo, self.timeout_height),
+            auth_info: signer_info.auth_info(fee),
+            chain_id: self.chain_id,
+            account_number,
+            sequence,
+            sign_mode: self.sign_mode,
+        })
+    }
+}
+
+/// A transaction with everything but its signature
+#[derive(Debug, Clone)]
+pub struct UnsignedTx {
+    pub body: Body,
+    pub auth_info: AuthInfo,
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub sign_mode: SignMode,
+}
+
+impl UnsignedTx {
+    /// Bytes the signer signs, in the transaction's sign mode
+    ///
+    /// The signature is the 64-byte secp256k1 signature of the SHA-256 of these bytes.
+    pub fn sign_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.sign_mode {
+            SignMode::Direct => direct_sign_bytes(
+                &self.body,
+                &self.auth_info,
+                &self.chain_id,
+                self.account_number,
+            ),
+            SignMode::LegacyAmino => amino_sign_bytes(
+                &self.body,
+                &self.auth_info.fee,
+                &self.chain_id,
+                self.account_number,
+                self.sequence,
+            ),
+        }
+    }
+
+    /// Attach `signature` of [`sign_bytes`](Self::sign_bytes), giving the transaction to broadcast
+    pub fn into_signed(self, signature: &[u8]) -> Result<Raw, Error> {
+        let tx_raw = TxRaw {
+            body_bytes: self
+                .body
+                .into_bytes()
+                .map_err(|e| Error::Tx(format!("Failed to encode transaction body: {}", e)))?,
+            auth_info_bytes: self
+                .auth_info
+                .into_bytes()
+                .map_err(|e| Error::Tx(format!("Failed to encode auth info: {}", e)))?,
+            signatures: vec![signature.to_vec()],
+        };
+        Ok(tx_raw.into())
+    }
+
+    /// Sign with a local key
+    pub fn sign(self, signing_key: &SigningKey) -> Result<Raw, Error> {
+        let signature = signing_key
+            .sign(&self.sign_bytes()?)
+            .map_err(|e| Error::Tx(format!("Failed to sign transaction: {}", e)))?;
+        self.into_signed(&signature.to_vec())
+    }
+}
+
+/// Bytes signed in `SIGN_MODE_DIRECT`: the protobuf encoded `SignDoc`
+pub fn direct_sign_bytes(
+    body: &Body,
+    auth_info: &AuthInfo,
+    chain_id: &str,
+    account_number: u64,
+) -> Result<Vec<u8>, Error> {
+    let chain_id =
+        Id::try_from(chain_id).map_err(|e| Error::Tx(format!("Invalid chain ID: {}", e)))?;
+    SignDoc::new(body, auth_info, &chain_id, account_number)
+        .and_then(SignDoc::into_bytes)
+        .map_err(|e| Error::Tx(format!("Failed to create sign doc: {}", e)))
+}
+
+/// Bytes signed in `SIGN_MODE_LEGACY_AMINO_JSON`: the `StdSignDoc` as sorted, compact JSON
+///
+/// # Errors
+///
+/// Returns [`Error::Tx`] if a message has no known amino JSON form
+pub fn amino_sign_bytes(
+    body: &Body,
+    fee: &Fee,
+    chain_id: &str,
+    account_number: u64,
+    sequence: u64,
+) -> Result<Vec<u8>, Error> {
+    let msgs = body
+        .messages
+        .iter()
+        .map(amino_json)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut amino_fee = json!({
+        "amount": amino_coins(fee.amount.iter().map(|coin| ProtoCoin {
+            denom: coin.denom.to_string(),
+            amount: coin.amount.to_string(),
+        })),
+        "gas": fee.gas_limit.to_string(),
+    });
+    if let Some(payer) = &fee.payer {
+        amino_fee["payer"] = json!(payer.to_string());
+    }
+    if let Some(granter) = &fee.granter {
+        amino_fee["granter"] = json!(granter.to_string());
+    }
+
+    let mut doc = json!({
+        "account_number": account_number.to_string(),
+        "chain_id": chain_id,
+        "fee": amino_fee,
+        "memo": body.memo,
+        "msgs": msgs,
+        "sequence": sequence.to_string(),
+    });
+    let timeout_height = body.timeout_height.value();
+    if timeout_height > 0 {
+        doc["timeout_height"] = json!(timeout_height.to_string());
+    }
+
+    // Canonical JSON sorts object keys; escape like Go's encoding/json, which the chain uses
+    let sorted = canonical::to_string(&doc)?
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026");
+    Ok(sorted.into_bytes())
+}
+
+/// Amino JSON form of a message, as it appears in a `StdSignDoc`
+///
+/// # Errors
+///
+/// Returns [`Error::Tx`] for message types other than contract executions and bank sends
+pub fn amino_json(msg: &Any) -> Result<Value, Error> {
+    let decode_error =
+        |e: prost::DecodeError| Error::Tx(format!("Invalid {}: {}", msg.type_url, e));
+    match msg.type_url.as_str() {
+        MSG_EXECUTE_CONTRACT => {
+            let execute = MsgExecuteContract::decode(msg.value.as_slice()).map_err(decode_error)?;
+            let contract_msg: Value = serde_json::from_slice(&execute.msg)?;
+            Ok(json!({
+                "type": "wasm/MsgExecuteContract",
+                "value": {
+                    "sender": execute.sender,
+                    "contract": execute.contract,
+                    "msg": contract_msg,
+                    "funds": amino_coins(execute.funds),
+                },
+            }))
+        }
+        MSG_SEND => {
+            let send = MsgSend::decode(msg.value.as_slice()).map_err(decode_error)?;
+            Ok(json!({
+                "type": "cosmos-sdk/MsgSend",
+                "value": {
+                    "from_address": send.from_address,
+                    "to_address": send.to_address,
+                    "amount": amino_coins(send.amount),
+                },
+            }))
+        }
+        other => Err(Error::Tx(format!(
+            "No amino JSON form for {}; sign it in direct mode",
+            other
+        ))),
+    }
+}
+
+fn amino_coins(coins: impl IntoIterator<Item = ProtoCoin>) -> Value {
+    coins
+        .into_iter()
+        .map(|coin| json!({ "amount": coin.amount, "denom": coin.denom }))
+        .collect()
+}
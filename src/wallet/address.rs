@@ -0,0 +1,78 @@
+//! Converting addresses between bech32 prefixes
+//!
+//! Cosmos chains that derive keys on coin type 118, as MANTRA does, give the
+//! same key the same 20-byte account; only the bech32 prefix differs. Swapping
+//! the prefix of a `mantra1...` address gives the address of the same key on
+//! such a chain, e.g. `osmo1...` on Osmosis, which is where IBC transfers from
+//! the wallet can be received and refunded.
+//!
+//! Chains deriving keys on another coin type, like the Ethermint chains on 60,
+//! give the same mnemonic a different account: a converted address is valid
+//! there but belongs to no key the wallet holds.
+
+use cosmrs::AccountId;
+use std::str::FromStr;
+
+use super::ACCOUNT_PREFIX;
+use crate::error::Error;
+
+/// Parse a bech32 account address of any prefix
+///
+/// # Errors
+///
+/// Returns [`Error::Wallet`] if `address` isn't a valid bech32 address
+pub fn parse_address(address: &str) -> Result<AccountId, Error> {
+    AccountId::from_str(address.trim())
+        .map_err(|e| Error::Wallet(format!("Invalid address '{}': {}", address, e)))
+}
+
+/// Check that `address` is a valid bech32 address with the given prefix
+///
+/// # Errors
+///
+/// Returns [`Error::Wallet`] if `address` is invalid or has another prefix
+pub fn validate_address(address: &str, prefix: &str) -> Result<AccountId, Error> {
+    let account = parse_address(address)?;
+    if account.prefix() != prefix {
+        return Err(Error::Wallet(format!(
+            "Address '{}' has the '{}' prefix; expected '{}'",
+            address.trim(),
+            account.prefix(),
+            prefix
+        )));
+    }
+    Ok(account)
+}
+
+/// Address of the same account under another bech32 prefix
+///
+/// # Errors
+///
+/// Returns [`Error::Wallet`] if `address` is invalid or `prefix` isn't a valid
+/// bech32 prefix (lowercase letters and digits)
+pub fn convert_prefix(address: &str, prefix: &str) -> Result<String, Error> {
+    let account = parse_address(address)?;
+    let prefix = prefix.trim();
+    if prefix.is_empty()
+        || !prefix
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        return Err(Error::Wallet(format!(
+            "Invalid bech32 prefix '{}': use lowercase letters and digits",
+            prefix
+        )));
+    }
+    AccountId::new(prefix, &account.to_bytes())
+        .map(|converted| converted.to_string())
+        .map_err(|e| Error::Wallet(format!("Invalid bech32 prefix '{}': {}", prefix, e)))
+}
+
+/// MANTRA address of an account given under any prefix
+///
+/// # Errors
+///
+/// Returns [`Error::Wallet`] if `address` isn't a valid bech32 address
+pub fn to_mantra(address: &str) -> Result<String, Error> {
+    convert_prefix(address, ACCOUNT_PREFIX)
+}
@@ -8,36 +8,64 @@ use cosmrs::{
 };
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::error::Error;
+use crate::secret::SecretString;
+
+// Converting addresses between bech32 prefixes
+pub mod address;
 
 // Storage module for wallet persistence
 pub mod storage;
 pub use storage::*;
 
+// Multisig accounts and collecting their members' signatures
+pub mod multisig;
+pub use multisig::{MultisigAccount, MultisigTx, PartialSignature};
+
+// UI-independent wallet setup wizard shared by the TUI and CLI
+pub mod wizard;
+
 /// HD Path prefix for Cosmos chains (BIP-44)
 const HD_PATH_PREFIX: &str = "m/44'/118'/0'/0/";
 
+/// Bech32 prefix of MANTRA account addresses
+pub const ACCOUNT_PREFIX: &str = "mantra";
+
 /// Mantra wallet for managing key and signing transactions
+///
+/// A watch-only wallet holds just an address: it can be queried like any other
+/// wallet, but everything that needs a signature fails with [`Error::Wallet`].
 pub struct MantraWallet {
-    /// The signing account
-    signing_account: cosmrs::crypto::secp256k1::SigningKey,
+    /// The signing key, or the watched address
+    key: WalletKey,
     /// The account prefix (mantra)
     account_prefix: String,
 }
 
+/// What a wallet holds to act for its account
+enum WalletKey {
+    /// The account's signing key
+    Signing(SigningKey),
+    /// Only the account's address
+    WatchOnly(AccountId),
+}
+
 // Note: MantraWallet intentionally does not implement Clone for security reasons
 // The signing key should not be easily duplicated
 
 impl std::fmt::Debug for MantraWallet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MantraWallet")
-            .field("account_prefix", &self.account_prefix)
-            .field(
-                "public_key",
-                &hex::encode(self.signing_account.public_key().to_bytes()),
-            )
-            .finish()
+        let mut debug = f.debug_struct("MantraWallet");
+        debug.field("account_prefix", &self.account_prefix);
+        match &self.key {
+            WalletKey::Signing(key) => {
+                debug.field("public_key", &hex::encode(key.public_key().to_bytes()))
+            }
+            WalletKey::WatchOnly(address) => debug.field("watch_only", &address.as_ref()),
+        };
+        debug.finish()
     }
 }
 
@@ -46,8 +74,11 @@ impl std::fmt::Debug for MantraWallet {
 pub struct WalletInfo {
     /// The wallet address
     pub address: String,
-    /// The public key as hex
+    /// The public key as hex; empty for a watch-only wallet
     pub public_key: String,
+    /// Whether the wallet only watches its address and can't sign
+    #[serde(default)]
+    pub watch_only: bool,
 }
 
 impl MantraWallet {
@@ -56,8 +87,8 @@ impl MantraWallet {
         let mnemonic = Mnemonic::from_str(mnemonic)
             .map_err(|e| Error::Wallet(format!("Invalid mnemonic: {}", e)))?;
 
-        let seed = mnemonic.to_seed("");
-        let seed = bip32::Seed::new(seed);
+        let seed_bytes = Zeroizing::new(mnemonic.to_seed(""));
+        let seed = bip32::Seed::new(*seed_bytes);
 
         let path = format!("{}{}", HD_PATH_PREFIX, account_index);
         let path = DerivationPath::from_str(&path)
@@ -66,18 +97,36 @@ impl MantraWallet {
         let derived_key = bip32::XPrv::derive_from_path(seed.as_bytes(), &path)
             .map_err(|e| Error::Wallet(format!("Key derivation error: {}", e)))?;
 
-        let derived_key_bytes = derived_key.to_bytes();
-        let signing_account = SigningKey::from_slice(&derived_key_bytes)
+        let derived_key_bytes = Zeroizing::new(derived_key.to_bytes());
+        let signing_account = SigningKey::from_slice(derived_key_bytes.as_slice())
             .map_err(|e| Error::Wallet(format!("Failed to create signing account: {}", e)))?;
 
         Ok(Self {
-            signing_account,
-            account_prefix: "mantra".to_string(),
+            key: WalletKey::Signing(signing_account),
+            account_prefix: ACCOUNT_PREFIX.to_string(),
         })
     }
 
-    /// Generate a new random wallet
-    pub fn generate() -> Result<(Self, String), Error> {
+    /// Create a watch-only wallet for a MANTRA address
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if `address` isn't a valid MANTRA address
+    pub fn watch_only(address: &str) -> Result<Self, Error> {
+        let account = address::validate_address(address, ACCOUNT_PREFIX)?;
+        Ok(Self {
+            key: WalletKey::WatchOnly(account),
+            account_prefix: ACCOUNT_PREFIX.to_string(),
+        })
+    }
+
+    /// Whether the wallet only watches its address and can't sign
+    pub fn is_watch_only(&self) -> bool {
+        matches!(self.key, WalletKey::WatchOnly(_))
+    }
+
+    /// Generate a new random wallet, returned with its mnemonic
+    pub fn generate() -> Result<(Self, SecretString), Error> {
         use rand::{thread_rng, RngCore};
 
         // Generate 16 bytes (128 bits) of entropy for a 12-word mnemonic
@@ -85,30 +134,60 @@ impl MantraWallet {
         thread_rng().fill_bytes(&mut entropy);
 
         let mnemonic = Mnemonic::from_entropy(&entropy)
-            .map_err(|e| Error::Wallet(format!("Failed to generate mnemonic: {}", e)))?;
+            .map_err(|e| Error::Wallet(format!("Failed to generate mnemonic: {}", e)));
+        entropy.zeroize();
 
-        let phrase = mnemonic.to_string();
-        let wallet = Self::from_mnemonic(&phrase, 0)?;
+        let phrase = SecretString::new(mnemonic?.to_string());
+        let wallet = Self::from_mnemonic(phrase.expose_secret(), 0)?;
 
         Ok((wallet, phrase))
     }
 
     /// Get the wallet's address
     pub fn address(&self) -> Result<AccountId, Error> {
-        self.signing_account
-            .public_key()
-            .account_id(&self.account_prefix)
-            .map_err(|e| Error::Wallet(format!("Failed to get account ID: {}", e)))
+        match &self.key {
+            WalletKey::Signing(key) => key
+                .public_key()
+                .account_id(&self.account_prefix)
+                .map_err(|e| Error::Wallet(format!("Failed to get account ID: {}", e))),
+            WalletKey::WatchOnly(address) => Ok(address.clone()),
+        }
+    }
+
+    /// Address of the wallet's account on a chain with another bech32 prefix
+    ///
+    /// See [`address`] for which chains share the account.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if `prefix` isn't a valid bech32 prefix
+    pub fn address_with_prefix(&self, prefix: &str) -> Result<String, Error> {
+        address::convert_prefix(self.address()?.as_ref(), prefix)
     }
 
     /// Get the public key
-    pub fn public_key(&self) -> PublicKey {
-        self.signing_account.public_key()
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] for a watch-only wallet, which has no key
+    pub fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.signing_key()?.public_key())
     }
 
     /// Get access to the signing key
-    pub fn signing_key(&self) -> &SigningKey {
-        &self.signing_account
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] for a watch-only wallet, which has no key
+    pub fn signing_key(&self) -> Result<&SigningKey, Error> {
+        match &self.key {
+            WalletKey::Signing(key) => Ok(key),
+            WalletKey::WatchOnly(address) => Err(Error::Wallet(format!(
+                "Wallet {} is watch-only and cannot sign transactions; \
+                 load it from its mnemonic to sign",
+                address
+            ))),
+        }
     }
 
     /// Sign a transaction doc
@@ -117,7 +196,7 @@ impl MantraWallet {
             .into_bytes()
             .map_err(|e| Error::Wallet(format!("Failed to convert sign doc to bytes: {}", e)))?;
         let signature = self
-            .signing_account
+            .signing_key()?
             .sign(&sign_doc_bytes)
             .map_err(|e| Error::Wallet(format!("Signing error: {}", e)))?;
         Ok(signature)
@@ -151,7 +230,7 @@ impl MantraWallet {
         let tx_body = body_builder.finish();
 
         // Create signer info with sequence number
-        let signer_info = SignerInfo::single_direct(Some(self.public_key()), sequence);
+        let signer_info = SignerInfo::single_direct(Some(self.public_key()?), sequence);
 
         // Create auth info with fee and signer info
         let auth_info = signer_info.auth_info(fee);
@@ -165,7 +244,7 @@ impl MantraWallet {
 
         // Sign the transaction
         sign_doc
-            .sign(&self.signing_account)
+            .sign(self.signing_key()?)
             .map_err(|e| Error::Wallet(format!("Failed to sign transaction: {}", e)))
     }
 
@@ -173,7 +252,11 @@ impl MantraWallet {
     pub fn info(&self) -> WalletInfo {
         WalletInfo {
             address: self.address().unwrap().to_string(),
-            public_key: self.signing_account.public_key().to_string(),
+            public_key: self
+                .public_key()
+                .map(|key| key.to_string())
+                .unwrap_or_default(),
+            watch_only: self.is_watch_only(),
         }
     }
 
@@ -0,0 +1,427 @@
+//! Multisig accounts, which a threshold of their members' keys sign for
+//!
+//! 1. [`MultisigAccount::new`] derives the account's address from its members'
+//!    public keys and threshold, as `mantrachaind keys add --multisig` does.
+//! 2. A member builds a [`MultisigTx`] for the account
+//!    ([`MantraDexClient::prepare_multisig_tx`](crate::MantraDexClient::prepare_multisig_tx)
+//!    queries the account and sets the gas and fee) and shares it as JSON.
+//! 3. Each member who agrees signs it with [`MultisigTx::sign_partial`] and
+//!    returns the [`PartialSignature`].
+//! 4. Once the threshold is met, [`MultisigTx::combine`] assembles the
+//!    transaction to broadcast.
+//!
+//! Members sign in `SIGN_MODE_LEGACY_AMINO_JSON`: unlike direct mode, its sign
+//! bytes don't depend on which members end up signing.
+
+use base64::{engine::general_purpose, Engine};
+use bip32::secp256k1::ecdsa::signature::Verifier;
+use cosmrs::crypto::secp256k1::{Signature, VerifyingKey};
+use cosmrs::crypto::{CompactBitArray, LegacyAminoMultisig, PublicKey};
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::crypto::multisig::v1beta1::MultiSignature;
+use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::tendermint::crypto::{default::Sha256, Sha256 as _};
+use cosmrs::tx::{self, AuthInfo, Body, Fee, ModeInfo, Raw, SignerInfo};
+use cosmrs::{AccountId, Any, Denom};
+use cosmwasm_std::{Coin, Uint128};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::{MantraWallet, ACCOUNT_PREFIX};
+use crate::canonical;
+use crate::error::Error;
+
+/// Amino prefix of a `tendermint/PubKeyMultisigThreshold` key
+const AMINO_MULTISIG_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+
+/// Amino prefix of a `tendermint/PubKeySecp256k1` key
+const AMINO_SECP256K1_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+
+/// An account controlled by `threshold` of its members' keys
+///
+/// Members are sorted by address, so the account's address doesn't depend on
+/// the order their keys are given in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "MultisigAccountJson")]
+pub struct MultisigAccount {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+}
+
+#[derive(Deserialize)]
+struct MultisigAccountJson {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+}
+
+impl TryFrom<MultisigAccountJson> for MultisigAccount {
+    type Error = Error;
+
+    fn try_from(json: MultisigAccountJson) -> Result<Self, Error> {
+        Self::new(json.threshold, json.public_keys)
+    }
+}
+
+impl MultisigAccount {
+    /// Create the account signed for by `threshold` of `public_keys`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if a key isn't a secp256k1 key or is repeated,
+    /// or the threshold is zero or larger than the number of keys
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>) -> Result<Self, Error> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(Error::Wallet(format!(
+                "A multisig threshold must be between 1 and the number of keys ({}), got {}",
+                public_keys.len(),
+                threshold
+            )));
+        }
+
+        let mut members = public_keys
+            .into_iter()
+            .map(|key| {
+                let address = key.account_id(ACCOUNT_PREFIX).map_err(|_| {
+                    Error::Wallet(format!(
+                        "Multisig members need secp256k1 keys, got {}",
+                        key.type_url()
+                    ))
+                })?;
+                Ok((address.to_bytes(), key))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        members.sort_by(|(a, _), (b, _)| a.cmp(b));
+        if members.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::Wallet(
+                "A key appears more than once in the multisig".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            threshold,
+            public_keys: members.into_iter().map(|(_, key)| key).collect(),
+        })
+    }
+
+    /// Number of signatures a transaction needs
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Members' public keys, sorted by address
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// The account's address: the first 20 bytes of the SHA-256 of its amino encoded key
+    pub fn address(&self) -> Result<AccountId, Error> {
+        let mut amino = AMINO_MULTISIG_PREFIX.to_vec();
+        amino.push(0x08);
+        encode_uvarint(self.threshold as u64, &mut amino);
+        for key in &self.public_keys {
+            let key_bytes = key.to_bytes();
+            let mut member = AMINO_SECP256K1_PREFIX.to_vec();
+            encode_uvarint(key_bytes.len() as u64, &mut member);
+            member.extend_from_slice(&key_bytes);
+
+            amino.push(0x12);
+            encode_uvarint(member.len() as u64, &mut amino);
+            amino.extend_from_slice(&member);
+        }
+
+        let hash = Sha256::digest(&amino);
+        AccountId::new(ACCOUNT_PREFIX, &hash[..20])
+            .map_err(|e| Error::Wallet(format!("Failed to get account ID: {}", e)))
+    }
+
+    /// Build a `MsgExecuteContract` sent from the account
+    pub fn execute_contract_msg<T: Serialize>(
+        &self,
+        contract_addr: &str,
+        msg: &T,
+        funds: &[Coin],
+    ) -> Result<Any, Error> {
+        let execute_msg = MsgExecuteContract {
+            sender: self.address()?.to_string(),
+            contract: contract_addr.to_string(),
+            msg: canonical::to_vec(msg)?,
+            funds: funds
+                .iter()
+                .map(|coin| ProtoCoin {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount.to_string(),
+                })
+                .collect(),
+        };
+        Ok(Any {
+            type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+            value: execute_msg.encode_to_vec(),
+        })
+    }
+
+    /// Position of `public_key` among the members, if it is one
+    pub fn member_index(&self, public_key: &PublicKey) -> Option<usize> {
+        self.public_keys.iter().position(|key| key == public_key)
+    }
+
+    /// Signer info of the account, with the `signers` members set in `bitarray` signing
+    fn signer_info(&self, sequence: u64, signers: usize, bitarray: CompactBitArray) -> SignerInfo {
+        SignerInfo {
+            public_key: Some(
+                LegacyAminoMultisig {
+                    threshold: self.threshold,
+                    public_keys: self.public_keys.clone(),
+                }
+                .into(),
+            ),
+            mode_info: ModeInfo::Multi(tx::mode_info::Multi {
+                bitarray,
+                mode_infos: vec![ModeInfo::single(tx::SignMode::LegacyAminoJson); signers],
+            }),
+            sequence,
+        }
+    }
+}
+
+/// A member's signature of a [`MultisigTx`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialSignature {
+    /// The signing member's public key
+    pub public_key: PublicKey,
+    /// The 64-byte secp256k1 signature, base64 encoded
+    pub signature: String,
+}
+
+/// A transaction from a multisig account, passed between its members to collect signatures
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultisigTx {
+    /// The account sending the transaction
+    pub account: MultisigAccount,
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    /// The protobuf encoded transaction body, base64 encoded
+    pub body: String,
+    pub fee: Vec<Coin>,
+    pub gas_limit: u64,
+}
+
+impl MultisigTx {
+    /// Create the transaction of `body` from `account`, paying `fee`
+    pub fn new(
+        account: MultisigAccount,
+        body: &Body,
+        fee: &Fee,
+        chain_id: impl Into<String>,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Self, Error> {
+        let body = body
+            .clone()
+            .into_bytes()
+            .map_err(|e| Error::Tx(format!("Failed to encode transaction body: {}", e)))?;
+        Ok(Self {
+            account,
+            chain_id: chain_id.into(),
+            account_number,
+            sequence,
+            body: general_purpose::STANDARD.encode(body),
+            fee: fee
+                .amount
+                .iter()
+                .map(|coin| Coin {
+                    denom: coin.denom.to_string(),
+                    amount: Uint128::new(coin.amount),
+                })
+                .collect(),
+            gas_limit: fee.gas_limit,
+        })
+    }
+
+    /// The decoded transaction body
+    pub fn body(&self) -> Result<Body, Error> {
+        let bytes = general_purpose::STANDARD
+            .decode(&self.body)
+            .map_err(|e| Error::Tx(format!("Invalid transaction body: {}", e)))?;
+        let proto = cosmrs::proto::cosmos::tx::v1beta1::TxBody::decode(bytes.as_slice())
+            .map_err(|e| Error::Tx(format!("Invalid transaction body: {}", e)))?;
+        Body::try_from(proto).map_err(|e| Error::Tx(format!("Invalid transaction body: {}", e)))
+    }
+
+    /// The transaction fee
+    pub fn tx_fee(&self) -> Result<Fee, Error> {
+        let amount = self
+            .fee
+            .iter()
+            .map(|coin| {
+                Ok(cosmrs::Coin {
+                    denom: Denom::from_str(&coin.denom)
+                        .map_err(|e| Error::Tx(format!("Invalid fee denom: {}", e)))?,
+                    amount: coin.amount.u128(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Fee {
+            amount,
+            gas_limit: self.gas_limit,
+            payer: None,
+            granter: None,
+        })
+    }
+
+    /// Bytes each member signs: the sorted amino JSON `StdSignDoc`
+    ///
+    /// Being JSON, they are also what a member reviews before signing.
+    pub fn sign_bytes(&self) -> Result<Vec<u8>, Error> {
+        crate::tx::amino_sign_bytes(
+            &self.body()?,
+            &self.tx_fee()?,
+            &self.chain_id,
+            self.account_number,
+            self.sequence,
+        )
+    }
+
+    /// Sign the transaction as one of the account's members
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if the wallet is watch-only or isn't a member
+    pub fn sign_partial(&self, wallet: &MantraWallet) -> Result<PartialSignature, Error> {
+        let public_key = wallet.public_key()?;
+        if self.account.member_index(&public_key).is_none() {
+            return Err(Error::Wallet(format!(
+                "{} is not a member of multisig {}",
+                wallet.address()?,
+                self.account.address()?
+            )));
+        }
+
+        let signature = wallet
+            .signing_key()?
+            .sign(&self.sign_bytes()?)
+            .map_err(|e| Error::Wallet(format!("Signing error: {}", e)))?;
+        Ok(PartialSignature {
+            public_key,
+            signature: general_purpose::STANDARD.encode(signature.to_bytes()),
+        })
+    }
+
+    /// Check `partial` is a member's valid signature of this transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if the key isn't a member's or the signature doesn't match
+    pub fn verify(&self, partial: &PartialSignature) -> Result<(), Error> {
+        self.verified_signature(partial, &self.sign_bytes()?)
+            .map(|_| ())
+    }
+
+    /// Index of the signing member and the raw signature, once verified
+    fn verified_signature(
+        &self,
+        partial: &PartialSignature,
+        sign_bytes: &[u8],
+    ) -> Result<(usize, Vec<u8>), Error> {
+        let index = self
+            .account
+            .member_index(&partial.public_key)
+            .ok_or_else(|| {
+                Error::Wallet(format!(
+                    "Signature from {}, which is not a member of the multisig",
+                    partial.public_key.to_json()
+                ))
+            })?;
+        let bytes = general_purpose::STANDARD
+            .decode(&partial.signature)
+            .map_err(|e| Error::Wallet(format!("Invalid signature encoding: {}", e)))?;
+        let signature = Signature::from_slice(&bytes)
+            .map_err(|e| Error::Wallet(format!("Invalid signature: {}", e)))?;
+        let verifying_key = VerifyingKey::from_sec1_bytes(&partial.public_key.to_bytes())
+            .map_err(|e| Error::Wallet(format!("Invalid public key: {}", e)))?;
+        verifying_key.verify(sign_bytes, &signature).map_err(|_| {
+            Error::Wallet(format!(
+                "Signature from member {} does not match this transaction",
+                index + 1
+            ))
+        })?;
+        Ok((index, bytes))
+    }
+
+    /// Combine members' signatures into the transaction to broadcast
+    ///
+    /// Every signature is verified; repeated signatures from a member count once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Wallet`] if a signature is invalid or fewer members than
+    /// the threshold signed
+    pub fn combine(&self, signatures: &[PartialSignature]) -> Result<Raw, Error> {
+        let sign_bytes = self.sign_bytes()?;
+        let mut signed = signatures
+            .iter()
+            .map(|partial| self.verified_signature(partial, &sign_bytes))
+            .collect::<Result<Vec<_>, Error>>()?;
+        signed.sort_by_key(|(index, _)| *index);
+        signed.dedup_by_key(|(index, _)| *index);
+        if signed.len() < self.account.threshold as usize {
+            return Err(Error::Wallet(format!(
+                "{} of {} required signatures collected",
+                signed.len(),
+                self.account.threshold
+            )));
+        }
+
+        let indices: Vec<usize> = signed.iter().map(|(index, _)| *index).collect();
+        let signatures = signed.into_iter().map(|(_, bytes)| bytes).collect();
+        self.raw(&indices, signatures)
+    }
+
+    /// The transaction with placeholder signatures from the first `threshold`
+    /// members, to simulate before anyone signs
+    pub(crate) fn draft(&self) -> Result<(Body, AuthInfo, Vec<u8>), Error> {
+        let indices: Vec<usize> = (0..self.account.threshold as usize).collect();
+        let signatures = vec![vec![0u8; 64]; indices.len()];
+        let auth_info = self.auth_info(&indices)?;
+        let signature = MultiSignature { signatures }.encode_to_vec();
+        Ok((self.body()?, auth_info, signature))
+    }
+
+    fn auth_info(&self, indices: &[usize]) -> Result<AuthInfo, Error> {
+        let members = self.account.public_keys.len();
+        let mut elems = vec![0u8; members.div_ceil(8)];
+        for index in indices {
+            elems[index / 8] |= 0x80 >> (index % 8);
+        }
+        let bitarray = CompactBitArray::new((members % 8) as u32, elems);
+        Ok(self
+            .account
+            .signer_info(self.sequence, indices.len(), bitarray)
+            .auth_info(self.tx_fee()?))
+    }
+
+    fn raw(&self, indices: &[usize], signatures: Vec<Vec<u8>>) -> Result<Raw, Error> {
+        let tx_raw = TxRaw {
+            body_bytes: general_purpose::STANDARD
+                .decode(&self.body)
+                .map_err(|e| Error::Tx(format!("Invalid transaction body: {}", e)))?,
+            auth_info_bytes: self
+                .auth_info(indices)?
+                .into_bytes()
+                .map_err(|e| Error::Tx(format!("Failed to encode auth info: {}", e)))?,
+            signatures: vec![MultiSignature { signatures }.encode_to_vec()],
+        };
+        Ok(tx_raw.into())
+    }
+}
+
+/// Append `value` as a protobuf varint
+fn encode_uvarint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
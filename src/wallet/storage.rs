@@ -4,20 +4,33 @@ use argon2::{
     password_hash::{rand_core::RngCore, SaltString},
     Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
 };
+use cosmwasm_std::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::error::Error;
+use crate::schema::{self, Format, Schema};
+use crate::secret::SecretString;
+use crate::wallet::{MantraWallet, MultisigAccount};
+
+/// Format of wallet files
+const WALLET_SCHEMA: Schema = Schema::new("wallet", Format::Json, &[schema::add_version]);
 
 /// Encrypted wallet data stored on disk
+///
+/// Watch-only wallets have no password or mnemonic, leaving those fields empty.
 #[derive(Serialize, Deserialize)]
 struct EncryptedWalletData {
     /// Argon2 hash parameters and salt
+    #[serde(default)]
     password_hash: String,
     /// Encrypted mnemonic (AES-256-GCM)
+    #[serde(default)]
     encrypted_mnemonic: Vec<u8>,
     /// Nonce used for encryption
+    #[serde(default)]
     nonce: Vec<u8>,
     /// Wallet metadata
     metadata: WalletMetadata,
@@ -30,6 +43,40 @@ pub struct WalletMetadata {
     pub address: String,
     pub created_at: String,
     pub last_accessed: Option<String>,
+    /// Settings applied while this wallet is active
+    #[serde(default)]
+    pub defaults: WalletDefaults,
+    /// Whether the wallet only watches its address, holding no mnemonic
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub watch_only: bool,
+    /// Members and threshold of a multisig account, which is also watch-only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multisig: Option<MultisigAccount>,
+}
+
+/// Per-wallet defaults, applied when the wallet is active and overridable per command
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WalletDefaults {
+    /// Maximum slippage for swaps that don't specify one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slippage: Option<Decimal>,
+    /// Gas price in the native denom, instead of the network default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<f64>,
+    /// Memo attached to transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    /// Pools preferred when choosing a pool for a pair automatically
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub preferred_pools: Vec<String>,
+}
+
+impl WalletDefaults {
+    /// Whether no default is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
 }
 
 /// Main wallet storage manager
@@ -41,8 +88,11 @@ pub struct WalletStorage {
 impl WalletStorage {
     /// Create a new WalletStorage instance
     pub fn new() -> Result<Self, Error> {
-        let storage_dir = Self::get_storage_directory()?;
+        Self::with_directory(Self::get_storage_directory()?)
+    }
 
+    /// Create a WalletStorage that keeps wallets in `storage_dir`
+    pub fn with_directory(storage_dir: PathBuf) -> Result<Self, Error> {
         // Create directory if it doesn't exist
         if !storage_dir.exists() {
             fs::create_dir_all(&storage_dir)
@@ -152,6 +202,9 @@ impl WalletStorage {
             address: address.to_string(),
             created_at: chrono::Utc::now().to_rfc3339(),
             last_accessed: None,
+            defaults: WalletDefaults::default(),
+            watch_only: false,
+            multisig: None,
         };
 
         // Create encrypted wallet data
@@ -162,19 +215,93 @@ impl WalletStorage {
             metadata,
         };
 
-        // Serialize and save to file
-        let wallet_json = serde_json::to_string_pretty(&wallet_data)
-            .map_err(|e| Error::Wallet(format!("Failed to serialize wallet data: {}", e)))?;
+        let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
+        self.write_wallet_file(&wallet_path, &wallet_data)
+    }
+
+    /// Save an address as a watch-only wallet, with no mnemonic or password
+    ///
+    /// Returns [`Error::Wallet`] if `address` isn't a MANTRA address or a wallet
+    /// named `name` already exists.
+    pub fn save_watch_only_wallet(&self, name: &str, address: &str) -> Result<(), Error> {
+        self.save_address_only(name, address, None)
+    }
+
+    /// Save a multisig account as a watch-only wallet, keeping its members and threshold
+    ///
+    /// Returns [`Error::Wallet`] if a wallet named `name` already exists.
+    pub fn save_multisig_wallet(&self, name: &str, account: &MultisigAccount) -> Result<(), Error> {
+        self.save_address_only(name, account.address()?.as_ref(), Some(account.clone()))
+    }
+
+    /// Members and threshold of a saved multisig wallet
+    ///
+    /// Returns [`Error::Wallet`] if the wallet isn't a multisig account.
+    pub fn multisig_account(&self, name: &str) -> Result<MultisigAccount, Error> {
+        self.wallet_metadata(name)?
+            .multisig
+            .ok_or_else(|| Error::Wallet(format!("Wallet '{}' is not a multisig account", name)))
+    }
 
+    fn save_address_only(
+        &self,
+        name: &str,
+        address: &str,
+        multisig: Option<MultisigAccount>,
+    ) -> Result<(), Error> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(Error::Wallet(format!("Invalid wallet name '{}'", name)));
+        }
+        let address = MantraWallet::watch_only(address)?.address()?.to_string();
         let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
-        fs::write(&wallet_path, wallet_json)
-            .map_err(|e| Error::Wallet(format!("Failed to write wallet file: {}", e)))?;
+        if wallet_path.exists() {
+            return Err(Error::Wallet(format!("Wallet '{}' already exists", name)));
+        }
 
-        Ok(())
+        let wallet_data = EncryptedWalletData {
+            password_hash: String::new(),
+            encrypted_mnemonic: Vec::new(),
+            nonce: Vec::new(),
+            metadata: WalletMetadata {
+                name: name.to_string(),
+                address,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                last_accessed: None,
+                defaults: WalletDefaults::default(),
+                watch_only: true,
+                multisig,
+            },
+        };
+        self.write_wallet_file(&wallet_path, &wallet_data)
+    }
+
+    /// Get the metadata of a saved wallet
+    pub fn wallet_metadata(&self, name: &str) -> Result<WalletMetadata, Error> {
+        let wallet_path = self.wallet_path(name)?;
+        Ok(self.load_wallet_file(&wallet_path)?.metadata)
+    }
+
+    /// Open a saved watch-only wallet, which needs no password
+    ///
+    /// Returns [`Error::Wallet`] if the wallet holds a mnemonic; load those with
+    /// [`load_wallet`](Self::load_wallet).
+    pub fn load_watch_only_wallet(&self, name: &str) -> Result<MantraWallet, Error> {
+        let metadata = self.wallet_metadata(name)?;
+        if !metadata.watch_only {
+            return Err(Error::Wallet(format!(
+                "Wallet '{}' is not watch-only; unlock it with its password",
+                name
+            )));
+        }
+        let wallet = MantraWallet::watch_only(&metadata.address)?;
+        self.update_last_accessed(name)?;
+        Ok(wallet)
     }
 
-    /// Load and decrypt a wallet
-    pub fn load_wallet(&self, name: &str, password: &str) -> Result<String, Error> {
+    /// Load and decrypt a wallet, returning its mnemonic
+    ///
+    /// Returns [`Error::Wallet`] for a watch-only wallet, which has no mnemonic.
+    pub fn load_wallet(&self, name: &str, password: &str) -> Result<SecretString, Error> {
         let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
 
         if !wallet_path.exists() {
@@ -182,6 +309,12 @@ impl WalletStorage {
         }
 
         let wallet_data = self.load_wallet_file(&wallet_path)?;
+        if wallet_data.metadata.watch_only {
+            return Err(Error::Wallet(format!(
+                "Wallet '{}' is watch-only: it has no mnemonic and cannot sign transactions",
+                name
+            )));
+        }
 
         // Verify password
         let parsed_hash = PasswordHash::new(&wallet_data.password_hash)
@@ -203,7 +336,12 @@ impl WalletStorage {
             .map_err(|e| Error::Wallet(format!("Failed to decrypt mnemonic: {}", e)))?;
 
         let mnemonic = String::from_utf8(decrypted_bytes)
-            .map_err(|e| Error::Wallet(format!("Invalid mnemonic data: {}", e)))?;
+            .map(SecretString::new)
+            .map_err(|e| {
+                let message = format!("Invalid mnemonic data: {}", e);
+                e.into_bytes().zeroize();
+                Error::Wallet(message)
+            })?;
 
         // Update last accessed time
         self.update_last_accessed(name)?;
@@ -225,15 +363,77 @@ impl WalletStorage {
         Ok(())
     }
 
-    /// Load wallet file and deserialize
-    fn load_wallet_file(&self, path: &std::path::Path) -> Result<EncryptedWalletData, Error> {
-        let wallet_content = fs::read_to_string(path)
-            .map_err(|e| Error::Wallet(format!("Failed to read wallet file: {}", e)))?;
+    /// Get the defaults stored for a wallet
+    pub fn wallet_defaults(&self, name: &str) -> Result<WalletDefaults, Error> {
+        let wallet_path = self.wallet_path(name)?;
+        Ok(self.load_wallet_file(&wallet_path)?.metadata.defaults)
+    }
+
+    /// Replace the defaults stored for a wallet
+    ///
+    /// Defaults are kept in the unencrypted metadata, so no password is needed.
+    pub fn set_wallet_defaults(&self, name: &str, defaults: WalletDefaults) -> Result<(), Error> {
+        let wallet_path = self.wallet_path(name)?;
+        let mut wallet_data = self.load_wallet_file(&wallet_path)?;
+        wallet_data.metadata.defaults = defaults;
+        self.write_wallet_file(&wallet_path, &wallet_data)
+    }
+
+    /// Saved file of a wallet, for moving it to another machine
+    ///
+    /// The mnemonic stays encrypted with the wallet's password.
+    pub fn export_wallet(&self, name: &str) -> Result<serde_json::Value, Error> {
+        let wallet_data = self.load_wallet_file(&self.wallet_path(name)?)?;
+        schema::to_value(&WALLET_SCHEMA, &wallet_data)
+    }
+
+    /// Save a wallet file produced by [`export_wallet`](Self::export_wallet) as `name`
+    ///
+    /// Returns `false`, leaving the saved wallet alone, if a wallet of that name
+    /// exists and `overwrite` isn't set.
+    pub fn import_wallet(
+        &self,
+        name: &str,
+        wallet: serde_json::Value,
+        overwrite: bool,
+    ) -> Result<bool, Error> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(Error::Wallet(format!("Invalid wallet name '{}'", name)));
+        }
+        let mut wallet_data: EncryptedWalletData = schema::from_value(&WALLET_SCHEMA, wallet)
+            .map_err(|e| Error::Wallet(format!("Invalid wallet '{}': {}", name, e)))?;
+        let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
+        if wallet_path.exists() && !overwrite {
+            return Ok(false);
+        }
+        wallet_data.metadata.name = name.to_string();
+        self.write_wallet_file(&wallet_path, &wallet_data)?;
+        Ok(true)
+    }
 
-        let wallet_data: EncryptedWalletData = serde_json::from_str(&wallet_content)
-            .map_err(|e| Error::Wallet(format!("Failed to parse wallet file: {}", e)))?;
+    /// Path of an existing wallet file
+    fn wallet_path(&self, name: &str) -> Result<PathBuf, Error> {
+        let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
+        if !wallet_path.exists() {
+            return Err(Error::Wallet(format!("Wallet '{}' not found", name)));
+        }
+        Ok(wallet_path)
+    }
 
-        Ok(wallet_data)
+    /// Serialize wallet data and write it to disk
+    fn write_wallet_file(
+        &self,
+        path: &std::path::Path,
+        wallet_data: &EncryptedWalletData,
+    ) -> Result<(), Error> {
+        schema::write(&WALLET_SCHEMA, path, wallet_data)
+            .map_err(|e| Error::Wallet(format!("Failed to write wallet file: {}", e)))
+    }
+
+    /// Load wallet file and deserialize, migrating it if an older release wrote it
+    fn load_wallet_file(&self, path: &std::path::Path) -> Result<EncryptedWalletData, Error> {
+        schema::read(&WALLET_SCHEMA, path)
+            .map_err(|e| Error::Wallet(format!("Failed to load wallet file: {}", e)))
     }
 
     /// Derive encryption key from password hash
@@ -243,7 +443,7 @@ impl WalletStorage {
     ) -> Result<GenericArray<u8, aes_gcm::aes::cipher::typenum::U32>, Error> {
         // Use the first 32 bytes of the password hash as the key
         let hash_bytes = password_hash.as_bytes();
-        let mut key_bytes = [0u8; 32];
+        let mut key_bytes = Zeroizing::new([0u8; 32]);
 
         if hash_bytes.len() >= 32 {
             key_bytes.copy_from_slice(&hash_bytes[..32]);
@@ -251,7 +451,7 @@ impl WalletStorage {
             key_bytes[..hash_bytes.len()].copy_from_slice(hash_bytes);
         }
 
-        Ok(*GenericArray::from_slice(&key_bytes))
+        Ok(*GenericArray::from_slice(key_bytes.as_slice()))
     }
 
     /// Update last accessed time for a wallet
@@ -260,14 +460,7 @@ impl WalletStorage {
         let mut wallet_data = self.load_wallet_file(&wallet_path)?;
 
         wallet_data.metadata.last_accessed = Some(chrono::Utc::now().to_rfc3339());
-
-        let wallet_json = serde_json::to_string_pretty(&wallet_data)
-            .map_err(|e| Error::Wallet(format!("Failed to serialize wallet data: {}", e)))?;
-
-        fs::write(&wallet_path, wallet_json)
-            .map_err(|e| Error::Wallet(format!("Failed to update wallet file: {}", e)))?;
-
-        Ok(())
+        self.write_wallet_file(&wallet_path, &wallet_data)
     }
 
     /// Validate password strength
@@ -0,0 +1,381 @@
+//! Wallet Setup Wizard State Machine
+//!
+//! This module holds the step-by-step wallet setup flow (network selection,
+//! generate/import, security acknowledgement, encrypted save) independently of any
+//! user interface, so both the TUI wizard and the prompt-based CLI drive the same logic.
+//! Mnemonics and passwords are held as [`SecretString`]s, scrubbed when the state drops.
+
+use crate::error::Error;
+use crate::secret::SecretString;
+use crate::wallet::MantraWallet;
+
+/// Wizard steps for first-time setup
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WizardStep {
+    Welcome,
+    NetworkSelection,
+    WalletSetup,
+    SecurityWarning,
+    WalletSave,
+    Confirmation,
+    Complete,
+}
+
+impl WizardStep {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Welcome,
+            Self::NetworkSelection,
+            Self::WalletSetup,
+            Self::SecurityWarning,
+            Self::WalletSave,
+            Self::Confirmation,
+            Self::Complete,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Welcome => "Welcome to MANTRA DEX",
+            Self::NetworkSelection => "Network Configuration",
+            Self::WalletSetup => "Wallet Setup",
+            Self::SecurityWarning => "Security Information",
+            Self::WalletSave => "Save Wallet",
+            Self::Confirmation => "Confirm Settings",
+            Self::Complete => "Setup Complete",
+        }
+    }
+
+    pub fn step_number(&self) -> (usize, usize) {
+        let all = Self::all();
+        let current = all.iter().position(|&s| s == *self).unwrap() + 1;
+        (current, all.len())
+    }
+}
+
+/// Network environment options
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NetworkEnvironment {
+    Mainnet,
+    Testnet,
+}
+
+impl NetworkEnvironment {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "Mainnet (Production)",
+            Self::Testnet => "Testnet (Development)",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "Real transactions with real assets. Use with caution.",
+            Self::Testnet => "Test environment with fake assets. Safe for learning.",
+        }
+    }
+}
+
+/// Wallet setup wizard state
+#[derive(Debug, Clone)]
+pub struct WizardState {
+    /// Current wizard step
+    pub current_step: WizardStep,
+    /// Selected network environment
+    pub selected_network: NetworkEnvironment,
+    /// Mnemonic input for wallet setup
+    pub mnemonic_input: SecretString,
+    /// Whether user has acknowledged security warnings
+    pub security_acknowledged: bool,
+    /// Whether user wants to create new wallet or import existing
+    pub import_existing: bool,
+    /// Generated mnemonic (if creating new wallet)
+    pub generated_mnemonic: Option<SecretString>,
+    /// Whether to show the wizard
+    pub show_wizard: bool,
+    /// Wallet save fields
+    pub wallet_name: String,
+    pub save_password: SecretString,
+    pub save_password_confirm: SecretString,
+    /// Whether user wants to save the wallet
+    pub save_wallet: bool,
+    /// Whether wallet save modal is currently shown
+    pub show_save_modal: bool,
+    /// Validation errors for wallet save form
+    pub wallet_save_errors: Vec<String>,
+    /// Current focused field in wallet save step
+    pub wallet_save_focus: WalletSaveField,
+}
+
+/// Fields that can be focused in the wallet save step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WalletSaveField {
+    SaveToggle,
+    WalletName,
+    Password,
+    PasswordConfirm,
+}
+
+impl WalletSaveField {
+    /// Whether the field masks what is typed into it
+    pub fn is_sensitive(self) -> bool {
+        matches!(self, Self::Password | Self::PasswordConfirm)
+    }
+}
+
+impl Default for WizardState {
+    fn default() -> Self {
+        Self {
+            current_step: WizardStep::Welcome,
+            selected_network: NetworkEnvironment::Testnet,
+            mnemonic_input: SecretString::default(),
+            security_acknowledged: false,
+            import_existing: true,
+            generated_mnemonic: None,
+            show_wizard: true,
+            wallet_name: String::new(),
+            save_password: SecretString::default(),
+            save_password_confirm: SecretString::default(),
+            save_wallet: true, // Default to saving wallet for convenience
+            show_save_modal: false,
+            wallet_save_errors: Vec::new(),
+            wallet_save_focus: WalletSaveField::SaveToggle,
+        }
+    }
+}
+
+impl WizardState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_step(&mut self) {
+        self.current_step = match self.current_step {
+            WizardStep::Welcome => WizardStep::NetworkSelection,
+            WizardStep::NetworkSelection => WizardStep::WalletSetup,
+            WizardStep::WalletSetup => WizardStep::SecurityWarning,
+            WizardStep::SecurityWarning => WizardStep::WalletSave,
+            WizardStep::WalletSave => WizardStep::Confirmation,
+            WizardStep::Confirmation => WizardStep::Complete,
+            WizardStep::Complete => WizardStep::Complete,
+        };
+    }
+
+    pub fn previous_step(&mut self) {
+        self.current_step = match self.current_step {
+            WizardStep::Welcome => WizardStep::Welcome,
+            WizardStep::NetworkSelection => WizardStep::Welcome,
+            WizardStep::WalletSetup => WizardStep::NetworkSelection,
+            WizardStep::SecurityWarning => WizardStep::WalletSetup,
+            WizardStep::WalletSave => WizardStep::SecurityWarning,
+            WizardStep::Confirmation => WizardStep::WalletSave,
+            WizardStep::Complete => WizardStep::Confirmation,
+        };
+    }
+
+    pub fn toggle_network(&mut self) {
+        self.selected_network = match self.selected_network {
+            NetworkEnvironment::Mainnet => NetworkEnvironment::Testnet,
+            NetworkEnvironment::Testnet => NetworkEnvironment::Mainnet,
+        };
+    }
+
+    /// Switch between importing a mnemonic and generating a new one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a new mnemonic can't be generated; the wizard then
+    /// stays in import mode, so setup never continues without a fresh mnemonic
+    pub fn toggle_wallet_mode(&mut self) -> Result<(), Error> {
+        if self.import_existing {
+            // Generate new mnemonic when switching to create mode
+            let (_, mnemonic) = MantraWallet::generate()?;
+            self.generated_mnemonic = Some(mnemonic);
+        }
+        self.import_existing = !self.import_existing;
+        Ok(())
+    }
+
+    /// Mnemonic the wizard will use, depending on whether the user imports or generates
+    pub fn current_mnemonic(&self) -> Option<&str> {
+        if self.import_existing {
+            Some(self.mnemonic_input.expose_secret().trim()).filter(|m| !m.is_empty())
+        } else {
+            self.generated_mnemonic
+                .as_ref()
+                .map(SecretString::expose_secret)
+        }
+    }
+
+    /// Whether typed characters go into a masked input: the imported mnemonic or a password
+    pub fn is_sensitive_input_focused(&self) -> bool {
+        match self.current_step {
+            WizardStep::WalletSetup => self.import_existing,
+            WizardStep::WalletSave => self.wallet_save_focus.is_sensitive(),
+            _ => false,
+        }
+    }
+
+    pub fn finish_wizard(&mut self) {
+        self.show_wizard = false;
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn can_proceed(&self) -> bool {
+        match self.current_step {
+            WizardStep::Welcome => true,
+            WizardStep::NetworkSelection => true,
+            WizardStep::WalletSetup => {
+                if self.import_existing {
+                    !self.mnemonic_input.is_empty()
+                        && self
+                            .mnemonic_input
+                            .expose_secret()
+                            .split_whitespace()
+                            .count()
+                            >= 12
+                } else {
+                    true // Generated mnemonic is always valid
+                }
+            }
+            WizardStep::SecurityWarning => self.security_acknowledged,
+            WizardStep::WalletSave => {
+                if self.save_wallet {
+                    self.validate_wallet_save().is_empty()
+                } else {
+                    true // Can skip saving
+                }
+            }
+            WizardStep::Confirmation => true,
+            WizardStep::Complete => true,
+        }
+    }
+
+    /// Validate wallet save form and return errors
+    pub fn validate_wallet_save(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        // Validate wallet name
+        if self.wallet_name.trim().is_empty() {
+            errors.push("Wallet name is required".to_string());
+        } else if self.wallet_name.trim().len() < 3 {
+            errors.push("Wallet name must be at least 3 characters".to_string());
+        } else if self.wallet_name.contains('/') || self.wallet_name.contains('\\') {
+            errors.push("Wallet name cannot contain path separators".to_string());
+        }
+
+        // Validate password strength
+        if let Err(validation_error) = crate::wallet::WalletStorage::default()
+            .validate_password(self.save_password.expose_secret())
+        {
+            errors.push(format!("Password error: {}", validation_error));
+        }
+
+        // Validate password confirmation
+        if self.save_password != self.save_password_confirm {
+            errors.push("Passwords do not match".to_string());
+        }
+
+        errors
+    }
+
+    /// Toggle wallet save option
+    pub fn toggle_save_wallet(&mut self) {
+        self.save_wallet = !self.save_wallet;
+        if !self.save_wallet {
+            // Clear form when disabling save
+            self.wallet_name.clear();
+            self.save_password.clear();
+            self.save_password_confirm.clear();
+            self.wallet_save_errors.clear();
+        }
+    }
+
+    /// Clear sensitive wallet save data from memory
+    pub fn clear_wallet_save_data(&mut self) {
+        self.save_password.clear();
+        self.save_password_confirm.clear();
+    }
+
+    /// Navigate to next field in wallet save step
+    pub fn wallet_save_focus_next(&mut self) {
+        if !self.save_wallet {
+            // If save wallet is disabled, only toggle field is available
+            self.wallet_save_focus = WalletSaveField::SaveToggle;
+            return;
+        }
+
+        self.wallet_save_focus = match self.wallet_save_focus {
+            WalletSaveField::SaveToggle => WalletSaveField::WalletName,
+            WalletSaveField::WalletName => WalletSaveField::Password,
+            WalletSaveField::Password => WalletSaveField::PasswordConfirm,
+            WalletSaveField::PasswordConfirm => WalletSaveField::SaveToggle,
+        };
+    }
+
+    /// Navigate to previous field in wallet save step
+    pub fn wallet_save_focus_previous(&mut self) {
+        if !self.save_wallet {
+            // If save wallet is disabled, only toggle field is available
+            self.wallet_save_focus = WalletSaveField::SaveToggle;
+            return;
+        }
+
+        self.wallet_save_focus = match self.wallet_save_focus {
+            WalletSaveField::SaveToggle => WalletSaveField::PasswordConfirm,
+            WalletSaveField::WalletName => WalletSaveField::SaveToggle,
+            WalletSaveField::Password => WalletSaveField::WalletName,
+            WalletSaveField::PasswordConfirm => WalletSaveField::Password,
+        };
+    }
+
+    /// Handle character input for the currently focused field
+    pub fn wallet_save_handle_char(&mut self, c: char) {
+        match self.wallet_save_focus {
+            WalletSaveField::SaveToggle => {
+                // Handle toggle with space or y/n
+                if c == ' ' || c == 'y' || c == 'Y' {
+                    self.save_wallet = true;
+                } else if c == 'n' || c == 'N' {
+                    self.save_wallet = false;
+                }
+            }
+            WalletSaveField::WalletName => {
+                if c.is_alphanumeric() || c == '_' || c == '-' || c == ' ' {
+                    self.wallet_name.push(c);
+                }
+            }
+            WalletSaveField::Password => {
+                if c.is_ascii_graphic() || c == ' ' {
+                    self.save_password.push(c);
+                }
+            }
+            WalletSaveField::PasswordConfirm => {
+                if c.is_ascii_graphic() || c == ' ' {
+                    self.save_password_confirm.push(c);
+                }
+            }
+        }
+    }
+
+    /// Handle backspace for the currently focused field
+    pub fn wallet_save_handle_backspace(&mut self) {
+        match self.wallet_save_focus {
+            WalletSaveField::SaveToggle => {
+                // No backspace handling for toggle
+            }
+            WalletSaveField::WalletName => {
+                self.wallet_name.pop();
+            }
+            WalletSaveField::Password => {
+                self.save_password.pop();
+            }
+            WalletSaveField::PasswordConfirm => {
+                self.save_password_confirm.pop();
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use cosmrs::tendermint::abci::Event;
+use cosmwasm_std::{Coin, Decimal};
+use mantra_dex_sdk::activity::{activity_alerts, balance_drops, fee_collector_address};
+use mantra_dex_sdk::ActivityAlert;
+
+const TREASURY: &str = "mantra1treasury";
+const POOL_MANAGER: &str = "mantra1poolmanager";
+
+fn expected() -> BTreeSet<String> {
+    [fee_collector_address(), POOL_MANAGER.to_string()]
+        .into_iter()
+        .collect()
+}
+
+fn transfer(sender: &str, recipient: &str, amount: &str) -> Event {
+    Event::new(
+        "transfer",
+        [
+            ("recipient", recipient),
+            ("sender", sender),
+            ("amount", amount),
+        ],
+    )
+}
+
+#[test]
+fn test_fee_collector_address() {
+    // Module accounts are the first 20 bytes of the sha256 of their name
+    assert_eq!(
+        fee_collector_address(),
+        "mantra17xpfvakm2amg962yls6f84z3kell8c5lmjfyg8"
+    );
+}
+
+#[test]
+fn test_outgoing_transfer_alerts() {
+    let events = vec![
+        // The fee, a swap through the pool manager and an incoming payment are expected
+        transfer(TREASURY, &fee_collector_address(), "5000uom"),
+        transfer(TREASURY, POOL_MANAGER, "1000uom"),
+        transfer("mantra1payer", TREASURY, "300uom"),
+        transfer(TREASURY, "mantra1stranger", "900000uom,10uusdc"),
+    ];
+
+    let alerts = activity_alerts(TREASURY, 42, "ABCD", &events, &expected());
+    assert_eq!(
+        alerts,
+        vec![ActivityAlert::OutgoingTransfer {
+            address: TREASURY.to_string(),
+            recipient: "mantra1stranger".to_string(),
+            amount: "900000uom,10uusdc".to_string(),
+            tx_hash: "ABCD".to_string(),
+            height: 42,
+        }]
+    );
+    assert_eq!(alerts[0].address(), TREASURY);
+    assert_eq!(
+        alerts[0].to_string(),
+        "mantra1treasury sent 900000uom,10uusdc to unexpected recipient mantra1stranger (tx ABCD)"
+    );
+
+    // Other addresses' transfers are not reported
+    assert!(activity_alerts("mantra1payer", 42, "ABCD", &events[..2], &expected()).is_empty());
+}
+
+#[test]
+fn test_authz_grant_alerts() {
+    let events = vec![Event::new(
+        "cosmos.authz.v1beta1.EventGrant",
+        [
+            ("granter", "\"mantra1treasury\""),
+            ("grantee", "\"mantra1bot\""),
+            ("msg_type_url", "\"/cosmos.bank.v1beta1.MsgSend\""),
+        ],
+    )];
+
+    let alerts = activity_alerts(TREASURY, 7, "EF01", &events, &expected());
+    assert_eq!(
+        alerts,
+        vec![ActivityAlert::AuthzGrant {
+            address: TREASURY.to_string(),
+            grantee: "mantra1bot".to_string(),
+            msg_type_url: Some("/cosmos.bank.v1beta1.MsgSend".to_string()),
+            tx_hash: "EF01".to_string(),
+            height: 7,
+        }]
+    );
+
+    let json = serde_json::to_value(&alerts[0]).unwrap();
+    assert_eq!(json["alert"], "authz_grant");
+    assert_eq!(json["grantee"], "mantra1bot");
+    assert!(activity_alerts("mantra1bot", 7, "EF01", &events, &expected()).is_empty());
+}
+
+#[test]
+fn test_balance_drops() {
+    let previous = vec![
+        Coin::new(1_000u128, "uom"),
+        Coin::new(500u128, "uusdc"),
+        Coin::new(100u128, "uatom"),
+    ];
+    let current = vec![
+        // 10% less stays under the 20% threshold
+        Coin::new(900u128, "uom"),
+        Coin::new(300u128, "uusdc"),
+    ];
+
+    let alerts = balance_drops(TREASURY, &previous, &current, Decimal::percent(20));
+    assert_eq!(
+        alerts,
+        vec![
+            ActivityAlert::BalanceDrop {
+                address: TREASURY.to_string(),
+                previous: Coin::new(500u128, "uusdc"),
+                current: Coin::new(300u128, "uusdc"),
+            },
+            ActivityAlert::BalanceDrop {
+                address: TREASURY.to_string(),
+                previous: Coin::new(100u128, "uatom"),
+                current: Coin::new(0u128, "uatom"),
+            },
+        ]
+    );
+    assert_ne!(alerts[0].key(), alerts[1].key());
+
+    // Growing balances never alert
+    assert!(balance_drops(TREASURY, &current, &previous, Decimal::zero()).is_empty());
+}
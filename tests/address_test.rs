@@ -0,0 +1,59 @@
+use cosmrs::AccountId;
+use mantra_dex_sdk::wallet::address::{convert_prefix, to_mantra, validate_address};
+use mantra_dex_sdk::{Error, MantraWallet, SkipIbcInfo};
+
+fn account(prefix: &str) -> String {
+    AccountId::new(prefix, &[7u8; 20]).unwrap().to_string()
+}
+
+#[test]
+fn test_convert_prefix_round_trip() {
+    let mantra = account("mantra");
+    let osmo = convert_prefix(&mantra, "osmo").unwrap();
+
+    assert_eq!(osmo, account("osmo"));
+    assert_eq!(to_mantra(&osmo).unwrap(), mantra);
+}
+
+#[test]
+fn test_convert_prefix_rejects_invalid_input() {
+    assert!(matches!(
+        convert_prefix("mantra1notanaddress", "osmo"),
+        Err(Error::Wallet(_))
+    ));
+    assert!(matches!(
+        convert_prefix(&account("mantra"), "Osmo"),
+        Err(Error::Wallet(_))
+    ));
+    assert!(matches!(
+        convert_prefix(&account("mantra"), ""),
+        Err(Error::Wallet(_))
+    ));
+}
+
+#[test]
+fn test_validate_address_checks_prefix() {
+    assert!(validate_address(&account("mantra"), "mantra").is_ok());
+    assert!(matches!(
+        validate_address(&account("cosmos"), "mantra"),
+        Err(Error::Wallet(_))
+    ));
+}
+
+#[test]
+fn test_wallet_address_with_prefix() {
+    let wallet = MantraWallet::watch_only(&account("mantra")).unwrap();
+    assert_eq!(
+        wallet.address_with_prefix("cosmos").unwrap(),
+        account("cosmos")
+    );
+}
+
+#[test]
+fn test_ibc_info_prefills_own_account() {
+    let info = SkipIbcInfo::to_own_account("channel-0", &account("mantra"), "osmo").unwrap();
+
+    assert_eq!(info.source_channel, "channel-0");
+    assert_eq!(info.receiver, account("osmo"));
+    assert_eq!(info.recover_address, Some(account("mantra")));
+}
@@ -0,0 +1,103 @@
+use cosmwasm_std::{Addr, Coin, Uint128};
+use mantra_dex_sdk::alerts::{emission_rates, farm_alerts};
+use mantra_dex_sdk::FarmAlert;
+use mantra_dex_std::farm_manager::{Curve, Farm, Position};
+
+const LP: &str = "factory/pool/p1.LP";
+
+fn farm(identifier: &str, emission_rate: u128, start_epoch: u64, end_epoch: u64) -> Farm {
+    Farm {
+        identifier: identifier.to_string(),
+        owner: Addr::unchecked("mantra1owner"),
+        lp_denom: LP.to_string(),
+        farm_asset: Coin::new(1_000_000u128, "uom"),
+        claimed_amount: Uint128::zero(),
+        emission_rate: Uint128::new(emission_rate),
+        curve: Curve::Linear,
+        start_epoch,
+        preliminary_end_epoch: end_epoch,
+    }
+}
+
+fn position(identifier: &str, open: bool) -> Position {
+    Position {
+        identifier: identifier.to_string(),
+        lp_asset: Coin::new(5_000u128, LP),
+        unlocking_duration: 86_400,
+        open,
+        expiring_at: None,
+        receiver: Addr::unchecked("mantra1me"),
+    }
+}
+
+#[test]
+fn test_farm_closing_soon() {
+    // Epoch 10: f1 pays in epochs 10 and 11, f2 long after, f3 hasn't started
+    let farms = [
+        farm("f1", 1_000, 5, 12),
+        farm("f2", 1_000, 5, 40),
+        farm("f3", 1_000, 11, 12),
+    ];
+    let alerts = farm_alerts(&farms, &[], 10, None, 2);
+    assert_eq!(
+        alerts,
+        vec![FarmAlert::FarmClosingSoon {
+            farm_id: "f1".to_string(),
+            lp_denom: LP.to_string(),
+            last_epoch: 11,
+            epochs_left: 2,
+        }]
+    );
+    assert_eq!(
+        alerts[0].to_string(),
+        "Farm f1 closes after epoch 11 (2 epochs of rewards left)"
+    );
+
+    // An ended farm raises nothing
+    assert!(farm_alerts(&farms[..1], &[], 12, None, 2).is_empty());
+}
+
+#[test]
+fn test_emission_rate_changed() {
+    let before = emission_rates(&[farm("f1", 1_000, 5, 40), farm("f2", 1_000, 5, 40)]);
+    let farms = [farm("f1", 400, 5, 40), farm("f2", 1_000, 5, 40)];
+
+    assert!(farm_alerts(&farms, &[], 10, None, 2).is_empty());
+    let alerts = farm_alerts(&farms, &[], 10, Some(&before), 2);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(
+        alerts[0].to_string(),
+        "Farm f1 now emits 400uom per epoch, was 1000uom"
+    );
+    // A later change is a different alert
+    let later = farm_alerts(&[farm("f1", 300, 5, 40)], &[], 10, Some(&before), 2);
+    assert_ne!(alerts[0].key(), later[0].key());
+}
+
+#[test]
+fn test_position_expiring_uses_latest_farm() {
+    let positions = [position("u-1", true), position("u-2", false)];
+
+    // f2 keeps paying the LP token, so the position is fine
+    let farms = [farm("f1", 1_000, 5, 11), farm("f2", 1_000, 5, 30)];
+    let alerts = farm_alerts(&farms, &positions, 10, None, 2);
+    assert!(alerts
+        .iter()
+        .all(|alert| !matches!(alert, FarmAlert::PositionExpiring { .. })));
+
+    let farms = [farm("f1", 1_000, 5, 11), farm("f2", 1_000, 5, 12)];
+    let expiring: Vec<FarmAlert> = farm_alerts(&farms, &positions, 10, None, 2)
+        .into_iter()
+        .filter(|alert| matches!(alert, FarmAlert::PositionExpiring { .. }))
+        .collect();
+    // Closed positions are not watched
+    assert_eq!(
+        expiring,
+        vec![FarmAlert::PositionExpiring {
+            position_id: "u-1".to_string(),
+            lp_asset: Coin::new(5_000u128, LP),
+            last_epoch: 11,
+            epochs_left: 2,
+        }]
+    );
+}
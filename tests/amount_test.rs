@@ -0,0 +1,111 @@
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_sdk::amount::{canonicalize_amount, format_amount, parse_amount, parse_base_units};
+use mantra_dex_sdk::{Error, NumberLocale, Preferences};
+use std::str::FromStr;
+
+#[test]
+fn test_parse_amount_per_locale() {
+    let expected = Decimal::from_str("1234.56").unwrap();
+    for input in ["1234.56", "1,234.56", "1 234.56", "1_234.56", "1'234.56"] {
+        assert_eq!(parse_amount(input, NumberLocale::Point).unwrap(), expected);
+    }
+    for input in [
+        "1234,56",
+        "1.234,56",
+        "1 234,56",
+        "1\u{a0}234,56",
+        "1_234,56",
+    ] {
+        assert_eq!(parse_amount(input, NumberLocale::Comma).unwrap(), expected);
+    }
+    assert_eq!(
+        parse_amount(",5", NumberLocale::Comma).unwrap(),
+        Decimal::percent(50)
+    );
+    assert_eq!(
+        canonicalize_amount(" 12.345.678,9 ", NumberLocale::Comma).unwrap(),
+        "12345678.9"
+    );
+}
+
+#[test]
+fn test_parse_amount_rejects_ambiguous_input() {
+    for (input, locale) in [
+        // A grouping separator that doesn't split thousands is likely a decimal point
+        ("1,5", NumberLocale::Point),
+        ("1.5", NumberLocale::Comma),
+        ("1234,567.8", NumberLocale::Point),
+        ("1.234.5", NumberLocale::Point),
+        ("1,234,", NumberLocale::Point),
+        ("1.234,5,6", NumberLocale::Comma),
+        ("-1", NumberLocale::Point),
+        ("1e6", NumberLocale::Point),
+        ("", NumberLocale::Point),
+        (".", NumberLocale::Point),
+    ] {
+        assert!(
+            matches!(parse_amount(input, locale), Err(Error::Other(_))),
+            "{:?} was accepted",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_base_units() {
+    assert_eq!(
+        parse_base_units("1,000,000", 0, NumberLocale::Point).unwrap(),
+        Uint128::new(1_000_000)
+    );
+    assert_eq!(
+        parse_base_units("1,5", 6, NumberLocale::Comma).unwrap(),
+        Uint128::new(1_500_000)
+    );
+    assert_eq!(
+        parse_base_units("0.000001", 6, NumberLocale::Point).unwrap(),
+        Uint128::new(1)
+    );
+    assert_eq!(
+        parse_base_units("2.50", 1, NumberLocale::Point).unwrap(),
+        Uint128::new(25)
+    );
+    assert!(parse_base_units("0.0000001", 6, NumberLocale::Point).is_err());
+    assert!(parse_base_units("1.5", 0, NumberLocale::Point).is_err());
+}
+
+#[test]
+fn test_format_amount() {
+    let amount = Decimal::from_str("1234567.891").unwrap();
+    assert_eq!(format_amount(amount, NumberLocale::Point), "1,234,567.891");
+    assert_eq!(format_amount(amount, NumberLocale::Comma), "1.234.567,891");
+    assert_eq!(
+        format_amount(Decimal::percent(50), NumberLocale::Comma),
+        "0,5"
+    );
+
+    // What is shown reads back as the same amount
+    for locale in [NumberLocale::Point, NumberLocale::Comma] {
+        assert_eq!(
+            parse_amount(&format_amount(amount, locale), locale).unwrap(),
+            amount
+        );
+    }
+}
+
+#[test]
+fn test_number_locale_preference() {
+    assert_eq!(
+        NumberLocale::from_locale_name("de_DE.UTF-8"),
+        NumberLocale::Comma
+    );
+    assert_eq!(
+        NumberLocale::from_locale_name("en_US.UTF-8"),
+        NumberLocale::Point
+    );
+    assert_eq!(NumberLocale::from_locale_name("C"), NumberLocale::Point);
+
+    let mut preferences = Preferences::default();
+    preferences.set("number_locale", "comma").unwrap();
+    assert_eq!(preferences.number_locale(), NumberLocale::Comma);
+    assert!(preferences.set("number_locale", "dot-ish").is_err());
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use mantra_dex_sdk::{
+    analytics::{candles, execution_by_pair, volume_by_denom, SwapFill},
+    bridge::DexEvent,
+    Coin, Decimal, Uint128,
+};
+
+fn swap_event(height: u64, offer: (&str, &str), ask: (&str, &str), fee: &str) -> DexEvent {
+    let attributes: BTreeMap<String, String> = [
+        ("_contract_address", "mantra1poolmanager"),
+        ("action", "swap"),
+        ("pool_identifier", "o.uom.uusdc.pool"),
+        ("offer_denom", offer.0),
+        ("offer_amount", offer.1),
+        ("ask_denom", ask.0),
+        ("return_amount", ask.1),
+        ("swap_fee_amount", fee),
+        ("protocol_fee_amount", fee),
+        ("burn_fee_amount", "0"),
+        ("extra_fees_amount", "0"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect();
+
+    DexEvent {
+        height,
+        tx_hash: format!("TX{}", height),
+        contract: "mantra1poolmanager".to_string(),
+        event_type: "wasm".to_string(),
+        action: Some("swap".to_string()),
+        attributes,
+    }
+}
+
+#[test]
+fn test_swap_fill_from_event() {
+    let fill = SwapFill::from_event(&swap_event(10, ("uom", "1000"), ("uusdc", "2000"), "5"))
+        .expect("swap event should parse");
+
+    assert_eq!(fill.height, 10);
+    assert_eq!(fill.tx_hash, "TX10");
+    assert_eq!(fill.pool_id.as_deref(), Some("o.uom.uusdc.pool"));
+    assert_eq!(fill.offer_amount, Uint128::new(1000));
+    assert_eq!(fill.return_amount, Uint128::new(2000));
+    assert_eq!(fill.fee_amount, Uint128::new(10));
+
+    let mut other = swap_event(10, ("uom", "1000"), ("uusdc", "2000"), "5");
+    other.action = Some("provide_liquidity".to_string());
+    assert!(SwapFill::from_event(&other).is_none());
+
+    let mut incomplete = swap_event(10, ("uom", "1000"), ("uusdc", "2000"), "5");
+    incomplete.attributes.remove("return_amount");
+    assert!(SwapFill::from_event(&incomplete).is_none());
+}
+
+#[test]
+fn test_execution_by_pair_weights_by_volume() {
+    let fills: Vec<SwapFill> = [
+        swap_event(10, ("uom", "1000"), ("uusdc", "2000"), "5"),
+        swap_event(20, ("uom", "3000"), ("uusdc", "3000"), "5"),
+        swap_event(15, ("uusdc", "500"), ("uom", "250"), "1"),
+    ]
+    .iter()
+    .filter_map(SwapFill::from_event)
+    .collect();
+
+    let pairs = execution_by_pair(&fills);
+    assert_eq!(pairs.len(), 2);
+
+    let sells = &pairs[0];
+    assert_eq!(
+        (sells.offer_denom.as_str(), sells.ask_denom.as_str()),
+        ("uom", "uusdc")
+    );
+    assert_eq!(sells.trades, 2);
+    assert_eq!(sells.total_offer, Uint128::new(4000));
+    assert_eq!(sells.total_return, Uint128::new(5000));
+    assert_eq!(sells.total_fees, Uint128::new(20));
+    assert_eq!(sells.average_price, Decimal::from_ratio(5u128, 4u128));
+    assert_eq!(sells.height_range, (10, 20));
+
+    let buys = &pairs[1];
+    assert_eq!(
+        (buys.offer_denom.as_str(), buys.ask_denom.as_str()),
+        ("uusdc", "uom")
+    );
+    assert_eq!(buys.average_price, Decimal::percent(50));
+
+    assert!(execution_by_pair(&[]).is_empty());
+}
+
+fn fill(height: u64, offer: (&str, &str), ask: (&str, &str)) -> SwapFill {
+    SwapFill::from_event(&swap_event(height, offer, ask, "0")).unwrap()
+}
+
+#[test]
+fn test_candles_price_both_directions() {
+    let fills = vec![
+        // Sells of OM at 2, 3 and 1.5 USDC, then a buy at 2.5 USDC per OM
+        fill(101, ("uom", "100"), ("uusdc", "200")),
+        fill(105, ("uom", "100"), ("uusdc", "300")),
+        fill(99, ("uom", "200"), ("uusdc", "300")),
+        fill(150, ("uusdc", "500"), ("uom", "200")),
+        fill(151, ("uatom", "1"), ("uom", "1")),
+    ];
+
+    let candles = candles(&fills, "uom", "uusdc", 100);
+    assert_eq!(candles.len(), 2);
+
+    assert_eq!(candles[0].start_height, 0);
+    assert_eq!(candles[0].trades, 1);
+    assert_eq!(candles[0].open, Decimal::percent(150));
+
+    let candle = &candles[1];
+    assert_eq!(candle.start_height, 100);
+    assert_eq!(candle.interval_blocks, 100);
+    assert_eq!(candle.open, Decimal::percent(200));
+    assert_eq!(candle.high, Decimal::percent(300));
+    assert_eq!(candle.low, Decimal::percent(200));
+    assert_eq!(candle.close, Decimal::percent(250));
+    assert_eq!(candle.volume, Uint128::new(400));
+    assert_eq!(candle.trades, 3);
+}
+
+#[test]
+fn test_volume_by_denom() {
+    let fills = vec![
+        fill(1, ("uusdc", "500"), ("uom", "200")),
+        fill(2, ("uom", "100"), ("uusdc", "200")),
+        fill(3, ("uom", "50"), ("uusdc", "100")),
+    ];
+
+    assert_eq!(
+        volume_by_denom(&fills),
+        vec![Coin::new(150u128, "uom"), Coin::new(500u128, "uusdc")]
+    );
+}
@@ -0,0 +1,52 @@
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::batch::{parse_batch, BatchOp};
+use mantra_dex_sdk::Error;
+
+#[test]
+fn test_parse_batch() {
+    let ops = parse_batch(
+        r#"[
+            {"type": "claim_rewards"},
+            {"type": "withdraw_liquidity", "pool_id": "p1", "lp_amount": "1000"},
+            {"type": "swap", "pool_id": "p1", "offer_asset": {"denom": "uom", "amount": "500"},
+             "ask_asset_denom": "uusdc", "max_slippage": "0.01"},
+            {"type": "provide_liquidity", "pool_id": "p2",
+             "assets": [{"denom": "uom", "amount": "10"}, {"denom": "uusdc", "amount": "20"}]}
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ops,
+        vec![
+            BatchOp::ClaimRewards { until_epoch: None },
+            BatchOp::WithdrawLiquidity {
+                pool_id: "p1".to_string(),
+                lp_amount: Uint128::new(1_000),
+            },
+            BatchOp::Swap {
+                pool_id: "p1".to_string(),
+                offer_asset: Coin::new(500u128, "uom"),
+                ask_asset_denom: "uusdc".to_string(),
+                max_slippage: Some(Decimal::percent(1)),
+            },
+            BatchOp::ProvideLiquidity {
+                pool_id: "p2".to_string(),
+                assets: vec![Coin::new(10u128, "uom"), Coin::new(20u128, "uusdc")],
+                liquidity_max_slippage: None,
+                swap_max_slippage: None,
+            },
+        ]
+    );
+    assert_eq!(ops[2].to_string(), "Swap 500uom for uusdc in pool p1");
+    assert_eq!(ops[3].to_string(), "Provide 10uom, 20uusdc to pool p2");
+}
+
+#[test]
+fn test_parse_batch_rejects_empty_or_unknown() {
+    assert!(matches!(parse_batch("[]"), Err(Error::Config(_))));
+    assert!(matches!(
+        parse_batch(r#"[{"type": "stake"}]"#),
+        Err(Error::Config(_))
+    ));
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use cosmrs::tendermint::abci::Event;
+use mantra_dex_sdk::{
+    bridge::{extract_dex_events, BridgeSink, DexEvent, EventBridge},
+    MantraDexClient, MantraNetworkConfig,
+};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+const POOL_MANAGER: &str = "mantra1poolmanager";
+
+fn swap_events() -> Vec<Event> {
+    vec![
+        Event::new(
+            "message",
+            [("action", "/cosmwasm.wasm.v1.MsgExecuteContract")],
+        ),
+        Event::new(
+            "wasm",
+            [
+                ("_contract_address", POOL_MANAGER),
+                ("action", "swap"),
+                ("offer_amount", "1000"),
+            ],
+        ),
+        Event::new(
+            "wasm",
+            [
+                ("_contract_address", "mantra1other"),
+                ("action", "transfer"),
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn test_extract_dex_events_filters_by_contract() {
+    let events = extract_dex_events(42, "ABCD", &swap_events(), &[POOL_MANAGER.to_string()]);
+
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.height, 42);
+    assert_eq!(event.tx_hash, "ABCD");
+    assert_eq!(event.contract, POOL_MANAGER);
+    assert_eq!(event.event_type, "wasm");
+    assert_eq!(event.action.as_deref(), Some("swap"));
+    assert_eq!(event.attributes["offer_amount"], "1000");
+}
+
+#[test]
+fn test_bridge_sink_parsing() {
+    assert_eq!(
+        "https://example.com/hook".parse::<BridgeSink>().unwrap(),
+        BridgeSink::Http("https://example.com/hook".to_string())
+    );
+    assert_eq!(
+        "nats://localhost:4222/dex/events"
+            .parse::<BridgeSink>()
+            .unwrap(),
+        BridgeSink::Nats {
+            address: "localhost:4222".to_string(),
+            subject: "dex.events".to_string(),
+        }
+    );
+    assert!("nats://localhost:4222".parse::<BridgeSink>().is_err());
+    assert!("mqtt://localhost/topic".parse::<BridgeSink>().is_err());
+}
+
+#[tokio::test]
+async fn test_bridge_forwards_to_nats() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        socket.write_all(b"INFO {}\r\n").await.unwrap();
+
+        let mut reader = BufReader::new(socket);
+        let mut connect = String::new();
+        reader.read_line(&mut connect).await.unwrap();
+        let mut publish = String::new();
+        reader.read_line(&mut publish).await.unwrap();
+
+        let length: usize = publish.trim().rsplit(' ').next().unwrap().parse().unwrap();
+        let mut payload = vec![0u8; length];
+        reader.read_exact(&mut payload).await.unwrap();
+        (connect, publish, payload)
+    });
+
+    let client = Arc::new(
+        MantraDexClient::new(MantraNetworkConfig::default())
+            .await
+            .unwrap(),
+    );
+    let bridge = EventBridge::new(
+        client,
+        vec![BridgeSink::Nats {
+            address,
+            subject: "dex.events".to_string(),
+        }],
+    );
+
+    let event = extract_dex_events(7, "FF", &swap_events(), &[POOL_MANAGER.to_string()])
+        .pop()
+        .unwrap();
+    bridge.forward(&event).await.unwrap();
+
+    let (connect, publish, payload) = server.await.unwrap();
+    assert!(connect.starts_with("CONNECT "));
+    assert!(publish.starts_with("PUB dex.events "));
+    let received: DexEvent = serde_json::from_slice(&payload).unwrap();
+    assert_eq!(received, event);
+}
@@ -0,0 +1,142 @@
+mod utils;
+
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::bulk::{execute_isolated, plan_bulk, BulkLeg, BulkOutcome, SkippedPool};
+use mantra_dex_sdk::{BulkAction, BulkReport, Error};
+use utils::test_utils::om_usdc_pool;
+
+fn leg(id: &str) -> BulkLeg {
+    BulkLeg {
+        pool_id: id.to_string(),
+        lp_denom: format!("factory/pool/{}.LP", id),
+        lp_balance: Uint128::new(100),
+        lp_amount: Uint128::new(50),
+        assets: vec![Coin::new(50_000u128, "uom")],
+    }
+}
+
+#[test]
+fn test_plan_withdraw_share_of_positions() {
+    let mut paused = om_usdc_pool("p3", 1_000_000, 2_000_000);
+    paused.pool_info.status.withdrawals_enabled = false;
+    let positions = [
+        (om_usdc_pool("p1", 1_000_000, 2_000_000), Uint128::new(100)),
+        (om_usdc_pool("p2", 1_000_000, 2_000_000), Uint128::zero()),
+        (paused, Uint128::new(100)),
+    ];
+
+    let plan = plan_bulk(BulkAction::Withdraw, Decimal::percent(50), &positions, &[]).unwrap();
+    assert_eq!(plan.legs.len(), 1);
+    assert_eq!(plan.legs[0].lp_amount, Uint128::new(50));
+    // 5% of the pool
+    assert_eq!(
+        plan.legs[0].assets,
+        vec![
+            Coin::new(50_000u128, "uom"),
+            Coin::new(100_000u128, "uusdc")
+        ]
+    );
+    assert_eq!(
+        plan.skipped,
+        vec![
+            SkippedPool {
+                pool_id: "p2".to_string(),
+                reason: "No position".to_string(),
+            },
+            SkippedPool {
+                pool_id: "p3".to_string(),
+                reason: "Pool has withdrawals disabled".to_string(),
+            },
+        ]
+    );
+
+    assert!(matches!(
+        plan_bulk(BulkAction::Withdraw, Decimal::percent(150), &positions, &[]),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_plan_provide_pays_from_balances_in_order() {
+    let positions = [
+        (om_usdc_pool("p1", 1_000_000, 2_000_000), Uint128::new(100)),
+        (om_usdc_pool("p2", 1_000_000, 2_000_000), Uint128::new(200)),
+        (om_usdc_pool("p3", 1_000_000, 2_000_000), Uint128::new(100)),
+    ];
+    // Enough for p1 and p3, each needing 10,000 uom and 20,000 uusdc
+    let balances = [Coin::new(25_000u128, "uom"), Coin::new(45_000u128, "uusdc")];
+
+    let plan = plan_bulk(
+        BulkAction::Provide,
+        Decimal::percent(10),
+        &positions,
+        &balances,
+    )
+    .unwrap();
+    let pools: Vec<&str> = plan.legs.iter().map(|leg| leg.pool_id.as_str()).collect();
+    assert_eq!(pools, ["p1", "p3"]);
+    assert!(plan.legs.iter().all(|leg| leg.lp_amount.is_zero()));
+    assert_eq!(
+        plan.total_assets(),
+        vec![Coin::new(20_000u128, "uom"), Coin::new(40_000u128, "uusdc")]
+    );
+    assert_eq!(plan.skipped[0].pool_id, "p2");
+    assert!(plan.skipped[0].reason.starts_with("Insufficient uom"));
+    assert_eq!(plan.transactions(1), 2);
+}
+
+#[tokio::test]
+async fn test_execute_isolated_retries_failed_batch_per_pool() {
+    let legs: Vec<BulkLeg> = ["p1", "p2", "p3", "p4"].into_iter().map(leg).collect();
+    let mut sent: Vec<Vec<String>> = Vec::new();
+
+    let results = execute_isolated(&legs, 2, |batch: Vec<BulkLeg>| {
+        let ids: Vec<String> = batch.iter().map(|leg| leg.pool_id.clone()).collect();
+        sent.push(ids.clone());
+        async move {
+            if ids.iter().any(|id| id == "p2") {
+                return Err(Error::Tx("pool paused".to_string()));
+            }
+            Ok(TxResponse {
+                txhash: ids.join("+"),
+                height: 7,
+                ..Default::default()
+            })
+        }
+    })
+    .await;
+
+    assert_eq!(
+        sent,
+        [vec!["p1", "p2"], vec!["p1"], vec!["p2"], vec!["p3", "p4"]]
+    );
+    let outcomes: Vec<&BulkOutcome> = results.iter().map(|result| &result.outcome).collect();
+    assert_eq!(
+        outcomes[0],
+        &BulkOutcome::Executed {
+            txhash: "p1".to_string(),
+            height: 7
+        }
+    );
+    assert!(matches!(outcomes[1], BulkOutcome::Failed { error } if error.contains("pool paused")));
+    assert_eq!(
+        outcomes[3],
+        &BulkOutcome::Executed {
+            txhash: "p3+p4".to_string(),
+            height: 7
+        }
+    );
+
+    let report = BulkReport {
+        action: BulkAction::Withdraw,
+        share: Decimal::percent(50),
+        results,
+        skipped: vec![],
+    };
+    assert_eq!((report.executed(), report.failed()), (3, 1));
+    assert_eq!(
+        report.to_string(),
+        "Withdrew 50% in 3 of 4 pools, 1 failed: 150000uom"
+    );
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use cosmwasm_std::Decimal;
+use mantra_dex_sdk::canonical;
+use mantra_dex_std::pool_manager::ExecuteMsg;
+use serde_json::{json, Value};
+
+/// Canonical text of the double with the given bits
+fn number(bits: u64) -> String {
+    canonical::canonicalize(&json!(f64::from_bits(bits)))
+}
+
+#[test]
+fn test_numbers_match_rfc_8785_vectors() {
+    // Appendix B of RFC 8785
+    let vectors = [
+        (0x0000000000000000, "0"),
+        (0x8000000000000000, "0"),
+        (0x0000000000000001, "5e-324"),
+        (0x8000000000000001, "-5e-324"),
+        (0x7fefffffffffffff, "1.7976931348623157e+308"),
+        (0xffefffffffffffff, "-1.7976931348623157e+308"),
+        (0x4340000000000000, "9007199254740992"),
+        (0xc340000000000000, "-9007199254740992"),
+        (0x4430000000000000, "295147905179352830000"),
+        (0x44b52d02c7e14af5, "9.999999999999997e+22"),
+        (0x44b52d02c7e14af6, "1e+23"),
+        (0x44b52d02c7e14af7, "1.0000000000000001e+23"),
+        (0x444b1ae4d6e2ef4e, "999999999999999700000"),
+        (0x444b1ae4d6e2ef4f, "999999999999999900000"),
+        (0x444b1ae4d6e2ef50, "1e+21"),
+        (0x3eb0c6f7a0b5ed8c, "9.999999999999997e-7"),
+        (0x3eb0c6f7a0b5ed8d, "0.000001"),
+        (0x41b3de4355555553, "333333333.3333332"),
+        (0x41b3de4355555554, "333333333.33333325"),
+        (0x41b3de4355555555, "333333333.3333333"),
+        (0x41b3de4355555556, "333333333.3333334"),
+        (0x41b3de4355555557, "333333333.33333343"),
+        (0xbecbf647612f3696, "-0.0000033333333333333333"),
+        (0x43143ff3c1cb0959, "1424953923781206.2"),
+    ];
+    for (bits, expected) in vectors {
+        assert_eq!(number(bits), expected, "double {:#018x}", bits);
+    }
+
+    // Integers are written exactly
+    assert_eq!(
+        canonical::canonicalize(&json!(u64::MAX)),
+        "18446744073709551615"
+    );
+    assert_eq!(canonical::canonicalize(&json!(-42)), "-42");
+}
+
+#[test]
+fn test_rfc_8785_sample() {
+    let input: Value = serde_json::from_str(
+        r#"{
+            "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+            "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+            "literals": [null, true, false]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        canonical::canonicalize(&input),
+        r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+    );
+}
+
+#[test]
+fn test_members_are_sorted_by_utf16_code_units() {
+    let input = json!({
+        "\u{20ac}": "Euro Sign",
+        "\r": "Carriage Return",
+        "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+        "1": "One",
+        "\u{1f600}": "Emoji: Grinning Face",
+        "\u{80}": "Control",
+        "\u{f6}": "Latin Small Letter O With Diaeresis",
+    });
+    assert_eq!(
+        canonical::canonicalize(&input),
+        "{\"\\r\":\"Carriage Return\",\"1\":\"One\",\"\u{80}\":\"Control\",\
+         \"\u{f6}\":\"Latin Small Letter O With Diaeresis\",\"\u{20ac}\":\"Euro Sign\",\
+         \"\u{1f600}\":\"Emoji: Grinning Face\",\
+         \"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+    );
+}
+
+#[test]
+fn test_field_order_does_not_change_the_bytes() {
+    let mut forward = HashMap::new();
+    let mut backward = HashMap::new();
+    for i in 0..32 {
+        forward.insert(format!("k{}", i), i);
+    }
+    for i in (0..32).rev() {
+        backward.insert(format!("k{}", i), i);
+    }
+    assert_eq!(
+        canonical::to_vec(&forward).unwrap(),
+        canonical::to_vec(&backward).unwrap()
+    );
+    assert_eq!(
+        canonical::to_string(&json!({ "b": [1, { "d": 1, "c": 2 }], "a": " x " })).unwrap(),
+        r#"{"a":" x ","b":[1,{"c":2,"d":1}]}"#
+    );
+}
+
+#[test]
+fn test_swap_message_golden_vector() {
+    let swap = ExecuteMsg::Swap {
+        ask_asset_denom: "uusdc".to_string(),
+        belief_price: None,
+        max_slippage: Some(Decimal::percent(1)),
+        receiver: None,
+        pool_identifier: "o.uom.uusdc".to_string(),
+    };
+    assert_eq!(
+        canonical::to_string(&swap).unwrap(),
+        r#"{"swap":{"ask_asset_denom":"uusdc","belief_price":null,"max_slippage":"0.01","pool_identifier":"o.uom.uusdc","receiver":null}}"#
+    );
+    assert_eq!(
+        hex::encode(canonical::digest(&swap).unwrap()),
+        "71be65e78ce38825a574554b8e6be4585314f43f911fd7cc8bff0defd4ebf451"
+    );
+}
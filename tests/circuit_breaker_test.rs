@@ -0,0 +1,202 @@
+use mantra_dex_sdk::circuit_breaker::{CircuitBreaker, CircuitEvent, CircuitState};
+use mantra_dex_sdk::config::{
+    CircuitBreakerConfig, EndpointHint, EndpointSelectionConfig, MantraNetworkConfig,
+};
+use mantra_dex_sdk::{Error, MantraDexClient};
+use std::time::Duration;
+
+fn breaker(endpoints: &[&str], failure_threshold: u32, cooldown_secs: u64) -> CircuitBreaker {
+    CircuitBreaker::new(
+        endpoints.iter().map(|e| e.to_string()).collect(),
+        CircuitBreakerConfig {
+            failure_threshold,
+            cooldown_secs,
+        },
+    )
+}
+
+#[test]
+fn test_circuit_opens_after_threshold_and_routes_to_fallback() {
+    let breaker = breaker(&["primary", "fallback"], 3, 60);
+    let mut events = breaker.subscribe();
+
+    for _ in 0..2 {
+        assert_eq!(breaker.acquire().unwrap(), 0);
+        breaker.record_failure(0);
+    }
+    assert_eq!(breaker.health()[0].state, CircuitState::Closed);
+    assert_eq!(breaker.health()[0].consecutive_failures, 2);
+
+    breaker.record_failure(0);
+    assert_eq!(breaker.health()[0].state, CircuitState::Open);
+    assert_eq!(
+        events.try_recv().unwrap(),
+        CircuitEvent {
+            endpoint: "primary".to_string(),
+            from: CircuitState::Closed,
+            to: CircuitState::Open,
+        }
+    );
+
+    // The primary is skipped during the cool-down
+    assert_eq!(breaker.acquire().unwrap(), 1);
+}
+
+#[test]
+fn test_success_resets_failure_count() {
+    let breaker = breaker(&["primary"], 2, 60);
+
+    breaker.record_failure(0);
+    breaker.record_success(0);
+    breaker.record_failure(0);
+
+    assert_eq!(breaker.health()[0].state, CircuitState::Closed);
+    assert_eq!(breaker.health()[0].consecutive_failures, 1);
+}
+
+#[test]
+fn test_half_open_after_cooldown() {
+    let breaker = breaker(&["primary", "fallback"], 1, 0);
+    let mut events = breaker.subscribe();
+
+    breaker.record_failure(0);
+    assert_eq!(events.try_recv().unwrap().to, CircuitState::Open);
+
+    // With no cool-down the primary is probed again right away
+    assert_eq!(breaker.acquire().unwrap(), 0);
+    assert_eq!(events.try_recv().unwrap().to, CircuitState::HalfOpen);
+
+    // A failed probe opens the circuit again
+    breaker.record_failure(0);
+    assert_eq!(events.try_recv().unwrap().to, CircuitState::Open);
+
+    // A successful probe closes it
+    assert_eq!(breaker.acquire().unwrap(), 0);
+    breaker.record_success(0);
+    assert_eq!(events.try_recv().unwrap().to, CircuitState::HalfOpen);
+    assert_eq!(events.try_recv().unwrap().to, CircuitState::Closed);
+}
+
+#[test]
+fn test_all_endpoints_open() {
+    let breaker = breaker(&["primary", "fallback"], 1, 60);
+    breaker.record_failure(0);
+    breaker.record_failure(1);
+
+    assert!(matches!(breaker.acquire(), Err(Error::Network(_))));
+}
+
+#[tokio::test]
+async fn test_client_falls_back_to_next_endpoint() {
+    let config = MantraNetworkConfig {
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        fallback_rpc_urls: vec!["http://127.0.0.1:2".to_string()],
+        circuit_breaker: CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown_secs: 60,
+        },
+        ..Default::default()
+    };
+    let client = MantraDexClient::new(config).await.unwrap();
+    let mut events = client.circuit_breaker().subscribe();
+
+    // Both endpoints are unreachable: each call trips one circuit
+    assert!(matches!(
+        client.get_last_block_height().await,
+        Err(Error::Rpc(_))
+    ));
+    assert_eq!(events.try_recv().unwrap().endpoint, "http://127.0.0.1:1");
+
+    assert!(matches!(
+        client.get_last_block_height().await,
+        Err(Error::Rpc(_))
+    ));
+    assert_eq!(events.try_recv().unwrap().endpoint, "http://127.0.0.1:2");
+
+    // With every circuit open, requests are short-circuited
+    assert!(matches!(
+        client.get_last_block_height().await,
+        Err(Error::Network(_))
+    ));
+}
+
+#[test]
+fn test_prefers_fastest_healthy_endpoint() {
+    let breaker = breaker(&["primary", "fallback", "backup"], 1, 60)
+        .with_selection(&EndpointSelectionConfig::default());
+
+    // Unmeasured endpoints keep their configured order
+    assert_eq!(breaker.acquire().unwrap(), 0);
+
+    breaker.record_latency(0, Duration::from_millis(300));
+    breaker.record_latency(1, Duration::from_millis(50));
+    assert_eq!(breaker.preference_order(), vec![1, 0, 2]);
+    assert_eq!(breaker.acquire().unwrap(), 1);
+    assert_eq!(breaker.health()[1].latency_ms, Some(50));
+
+    // Latency is averaged, so one slow sample doesn't flip the order
+    breaker.record_latency(1, Duration::from_millis(500));
+    assert_eq!(breaker.health()[1].latency_ms, Some(185));
+    assert_eq!(breaker.acquire().unwrap(), 1);
+
+    // The fastest endpoint is skipped while its circuit is open
+    breaker.record_failure(1);
+    assert_eq!(breaker.acquire().unwrap(), 0);
+}
+
+#[test]
+fn test_weights_and_region_hints() {
+    let hint = |url: &str, region: &str| EndpointHint {
+        url: url.to_string(),
+        weight: 1.0,
+        region: Some(region.to_string()),
+    };
+    let mut selection = EndpointSelectionConfig {
+        region: Some("eu-west".to_string()),
+        hints: vec![hint("primary", "us-east"), hint("fallback", "eu-west")],
+        ..EndpointSelectionConfig::default()
+    };
+    let hinted = breaker(&["primary", "fallback"], 1, 60).with_selection(&selection);
+
+    // Before any measurement the endpoint in the client's region goes first
+    assert_eq!(hinted.acquire().unwrap(), 1);
+    assert_eq!(hinted.health()[1].region.as_deref(), Some("eu-west"));
+
+    // More than twice as fast outweighs the region preference
+    hinted.record_latency(0, Duration::from_millis(40));
+    hinted.record_latency(1, Duration::from_millis(100));
+    assert_eq!(hinted.acquire().unwrap(), 0);
+
+    // Without latency awareness only the weights count
+    selection.latency_aware = false;
+    let breaker = breaker(&["primary", "fallback"], 1, 60).with_selection(&selection);
+    breaker.record_latency(0, Duration::from_millis(40));
+    breaker.record_latency(1, Duration::from_millis(100));
+    assert_eq!(breaker.acquire().unwrap(), 1);
+}
+
+#[test]
+fn test_avoids_endpoints_lagging_behind() {
+    let breaker = breaker(&["primary", "fallback"], 1, 60)
+        .with_selection(&EndpointSelectionConfig::default());
+    breaker.record_latency(0, Duration::from_millis(20));
+    breaker.record_latency(1, Duration::from_millis(80));
+    assert_eq!(breaker.active(), Some(0));
+
+    // Within the allowed lag the faster endpoint is kept
+    breaker.record_block_height(0, 995);
+    breaker.record_block_height(1, 1_000);
+    assert_eq!(breaker.acquire().unwrap(), 0);
+
+    // Falling further behind moves requests to the endpoint that keeps up
+    breaker.record_block_height(0, 980);
+    let health = breaker.health();
+    assert!(health[0].lagging && !health[0].active);
+    assert!(!health[1].lagging && health[1].active);
+    assert_eq!(health[0].block_height, Some(980));
+    assert_eq!(breaker.acquire().unwrap(), 1);
+
+    // A lagging endpoint still beats none at all
+    breaker.record_failure(1);
+    assert_eq!(breaker.acquire().unwrap(), 0);
+}
@@ -0,0 +1,102 @@
+use cosmwasm_std::Coin;
+use mantra_dex_sdk::claim::chunk_end;
+use mantra_dex_sdk::{ClaimChunk, ClaimReport, Error};
+
+/// Gas of claiming up to `epoch` when 10 epochs are pending from epoch 1, at
+/// 100k gas per epoch over a 50k base
+fn claim_gas(epoch: u64) -> u64 {
+    50_000 + 100_000 * epoch.min(10)
+}
+
+fn chunk(until_epoch: u64, rewards: Vec<Coin>) -> ClaimChunk {
+    ClaimChunk {
+        until_epoch,
+        gas: claim_gas(until_epoch),
+        rewards,
+        txhash: format!("TX{}", until_epoch),
+        height: 100 + until_epoch as i64,
+    }
+}
+
+#[tokio::test]
+async fn test_chunk_end_whole_range_when_it_fits() {
+    let mut simulated = Vec::new();
+    let end = chunk_end(1, 10, 2_000_000, |epoch| {
+        simulated.push(epoch);
+        async move { Ok(claim_gas(epoch)) }
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(end, (10, 1_050_000));
+    assert_eq!(simulated, vec![10], "one simulation when everything fits");
+}
+
+#[tokio::test]
+async fn test_chunk_end_bisects_to_gas_budget() {
+    let end = chunk_end(1, 10, 450_000, |epoch| async move { Ok(claim_gas(epoch)) })
+        .await
+        .unwrap();
+    assert_eq!(end, (4, 450_000));
+
+    // Simulations running out of gas count as too large
+    let end = chunk_end(1, 10, 700_000, |epoch| async move {
+        if epoch > 8 {
+            Err(Error::Contract(
+                "Transaction simulation failed: out of gas in location: wasm contract".to_string(),
+            ))
+        } else {
+            Ok(claim_gas(epoch))
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(end, (6, 650_000));
+}
+
+#[tokio::test]
+async fn test_chunk_end_errors() {
+    let result = chunk_end(1, 10, 100_000, |epoch| async move { Ok(claim_gas(epoch)) }).await;
+    assert!(matches!(result, Err(Error::Tx(_))));
+
+    // Other simulation failures aren't taken for a full chunk
+    let result = chunk_end(1, 10, 450_000, |_| async {
+        Err(Error::Rpc("connection refused".to_string()))
+    })
+    .await;
+    assert!(matches!(result, Err(Error::Rpc(_))));
+}
+
+#[test]
+fn test_claim_report_totals() {
+    let report = ClaimReport {
+        chunks: vec![
+            chunk(
+                4,
+                vec![Coin::new(700u128, "uom"), Coin::new(20u128, "uusdc")],
+            ),
+            chunk(8, vec![Coin::new(300u128, "uom")]),
+        ],
+        error: None,
+    };
+
+    assert_eq!(
+        report.total_claimed(),
+        vec![Coin::new(1_000u128, "uom"), Coin::new(20u128, "uusdc")]
+    );
+    assert_eq!(report.total_gas(), 450_000 + 850_000);
+    assert_eq!(
+        report.to_string(),
+        "Claimed 1000uom, 20uusdc in 2 transactions"
+    );
+
+    let stopped = ClaimReport {
+        chunks: vec![chunk(4, vec![Coin::new(700u128, "uom")])],
+        error: Some("Transaction check failed: insufficient fees".to_string()),
+    };
+    assert_eq!(
+        stopped.to_string(),
+        "Claimed 700uom in 1 transaction, then stopped: Transaction check failed: insufficient fees"
+    );
+    assert_eq!(ClaimReport::default().to_string(), "No rewards claimed");
+}
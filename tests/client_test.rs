@@ -1,6 +1,13 @@
 mod utils;
 
-use mantra_dex_sdk::{MantraDexClient, MantraWallet};
+use std::sync::Arc;
+
+use cosmwasm_std::Uint128;
+use mantra_dex_sdk::config::{AssetFilterConfig, Config, PricingConfig, RoutingConfig};
+use mantra_dex_sdk::{
+    MantraDexClient, MantraNetworkConfig, MantraWallet, PageCursor, SwapRequest, TradeValueLimit,
+    TxProgress, TxStage,
+};
 use utils::test_utils::{
     create_test_client, create_test_network_config, get_or_create_test_pool_id, load_test_config,
 };
@@ -73,6 +80,102 @@ async fn test_client_without_wallet() {
     assert!(wallet_result.is_err(), "Wallet should not be available");
 }
 
+#[test]
+fn test_tx_stages_report_increasing_progress() {
+    let fractions: Vec<f32> = TxStage::ALL.iter().map(|stage| stage.fraction()).collect();
+    assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(TxStage::Built.step(), 1);
+    assert_eq!(TxStage::Included.fraction(), 1.0);
+}
+
+#[tokio::test]
+async fn test_clients_share_tx_progress_channel() {
+    let (sender, _) = tokio::sync::broadcast::channel(8);
+    let first = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_tx_progress(sender.clone())
+        .build()
+        .await
+        .unwrap();
+    let second = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_tx_progress(sender.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let mut first_updates = first.subscribe_tx_progress();
+    let mut second_updates = second.subscribe_tx_progress();
+    let update = TxProgress {
+        id: 1,
+        stage: TxStage::Signed,
+        tx_hash: None,
+    };
+    sender.send(update.clone()).unwrap();
+
+    assert_eq!(first_updates.try_recv().unwrap(), update);
+    assert_eq!(second_updates.try_recv().unwrap(), update);
+}
+
+#[tokio::test]
+async fn test_builder_applies_saved_settings() {
+    let saved = Config {
+        asset_filter: AssetFilterConfig {
+            denied_denoms: vec!["ubad".to_string()],
+            ..Default::default()
+        },
+        routing: RoutingConfig {
+            excluded_pools: vec!["o.uom.uusdc.thin".to_string()],
+            ..Default::default()
+        },
+        pricing: PricingConfig {
+            usd_denoms: vec!["uusdc".to_string()],
+            max_trade_value: Some(TradeValueLimit::new(Uint128::new(1_000), "usd")),
+        },
+        ..Default::default()
+    };
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_settings(&saved)
+        .with_usd_denoms(vec!["uusdt".to_string()])
+        .with_rpc_url("http://localhost:26657")
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(client.asset_filter(), &saved.asset_filter);
+    assert_eq!(client.routing(), &saved.routing);
+    assert_eq!(
+        client.trade_value_limit(),
+        saved.pricing.max_trade_value.as_ref()
+    );
+    // Settings set later take precedence
+    assert_eq!(client.usd_denoms(), ["uusdt".to_string()]);
+    assert_eq!(client.config().rpc_url, "http://localhost:26657");
+    assert!(client.external_indexer().is_none());
+}
+
+#[tokio::test]
+async fn test_to_builder_keeps_settings_but_not_wallet() {
+    let (sender, _) = tokio::sync::broadcast::channel(8);
+    let (wallet, _) = MantraWallet::generate().unwrap();
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_wallet(wallet)
+        .with_tx_progress(sender.clone())
+        .with_simulate_only(true)
+        .with_usd_denoms(vec!["uusdc".to_string()])
+        .build()
+        .await
+        .unwrap();
+    assert!(!client.is_read_only());
+
+    let rebuilt = client.to_builder().build().await.unwrap();
+    assert!(rebuilt.is_read_only());
+    assert!(rebuilt.is_simulate_only());
+    assert_eq!(rebuilt.usd_denoms(), client.usd_denoms());
+    assert!(Arc::ptr_eq(rebuilt.gas_history(), client.gas_history()));
+    assert_eq!(sender.receiver_count(), 0);
+    let _updates = rebuilt.subscribe_tx_progress();
+    assert_eq!(sender.receiver_count(), 1);
+}
+
 #[tokio::test]
 async fn test_client_query_pool() {
     let client = create_test_client().await;
@@ -105,7 +208,10 @@ async fn test_client_query_pools() {
     let client = create_test_client().await;
 
     // Query all pools
-    let pools_result = client.get_pools(Some(10)).await;
+    let pools_result = client
+        .get_pools(&PageCursor::first(10))
+        .await
+        .map(|page| page.items);
 
     // This should succeed if the RPC works
     if let Ok(pools) = pools_result {
@@ -127,7 +233,11 @@ async fn test_client_query_pools() {
                         println!("Successfully created/found pool: {}", pool_id);
 
                         // Query pools again to verify
-                        let updated_pools = client.get_pools(Some(10)).await.unwrap();
+                        let updated_pools = client
+                            .get_pools(&PageCursor::first(10))
+                            .await
+                            .map(|page| page.items)
+                            .unwrap();
                         assert!(
                             !updated_pools.is_empty(),
                             "Should have at least one pool after creation"
@@ -224,6 +334,92 @@ async fn test_client_simulate_swap() {
     }
 }
 
+#[tokio::test]
+async fn test_client_simulate_many_preserves_order() {
+    let client = create_test_client().await;
+    let test_config = load_test_config();
+
+    let pool_id = match get_or_create_test_pool_id(&client).await {
+        Some(pool_id) => pool_id,
+        None => {
+            println!("Warning: Could not get or create OM/USDY pool for simulation");
+            return;
+        }
+    };
+
+    let uom_denom = test_config.tokens["uom"].denom.clone().unwrap();
+    let uusdy_denom = test_config.tokens["uusdy"].denom.clone().unwrap();
+
+    let amounts = [1_000_000u128, 2_000_000, 3_000_000];
+    let mut requests: Vec<SwapRequest> = amounts
+        .iter()
+        .map(|amount| {
+            SwapRequest::new(
+                pool_id.clone(),
+                cosmwasm_std::Coin {
+                    denom: uom_denom.clone(),
+                    amount: cosmwasm_std::Uint128::from(*amount),
+                },
+                uusdy_denom.clone(),
+            )
+        })
+        .collect();
+    // A failing request must not affect the others
+    requests.insert(
+        1,
+        SwapRequest::new(
+            "nonexistent.pool",
+            cosmwasm_std::Coin {
+                denom: uom_denom.clone(),
+                amount: cosmwasm_std::Uint128::from(1_000_000u128),
+            },
+            uusdy_denom.clone(),
+        ),
+    );
+
+    let results = client.simulate_many(requests).await;
+    assert_eq!(results.len(), 4);
+    assert!(results[1].is_err(), "Nonexistent pool should fail");
+
+    let returns: Vec<_> = [&results[0], &results[2], &results[3]]
+        .iter()
+        .map(|result| result.as_ref().expect("simulation failed").return_amount)
+        .collect();
+    assert!(
+        returns[0] <= returns[1] && returns[1] <= returns[2],
+        "Results should follow request order: {:?}",
+        returns
+    );
+}
+
+#[tokio::test]
+async fn test_client_pools_stream_matches_get_pools() {
+    use futures::{StreamExt, TryStreamExt};
+
+    let client = create_test_client().await;
+
+    let pools = client
+        .get_pools(&PageCursor::first(10))
+        .await
+        .expect("Failed to get pools")
+        .items;
+    // A small page size forces the stream across several pages
+    let streamed: Vec<_> = client
+        .pools_stream(3)
+        .take(pools.len())
+        .try_collect()
+        .await
+        .expect("Failed to stream pools");
+
+    let ids = |pools: &[mantra_dex_sdk::mantra_dex_std::pool_manager::PoolInfoResponse]| {
+        pools
+            .iter()
+            .map(|pool| pool.pool_info.pool_identifier.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&streamed), ids(&pools));
+}
+
 #[tokio::test]
 async fn test_client_get_last_block_height() {
     let client = create_test_client().await;
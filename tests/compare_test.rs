@@ -0,0 +1,170 @@
+mod utils;
+
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_sdk::{
+    analytics::SwapFill,
+    compare::{self, PoolRanking, SECONDS_PER_DAY},
+    earn::{PriceBook, YieldContext},
+};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use utils::test_utils::pool;
+
+/// 1 OM = 2 USDC, 1% swap fee and 0.5% protocol fee
+fn om_usdc() -> PoolInfoResponse {
+    let mut pool = pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uom", 1_000_000, 6), ("uusdc", 2_000_000, 6)],
+        Decimal::percent(1),
+    );
+    pool.pool_info.pool_fees.protocol_fee.share = Decimal::permille(5);
+    pool
+}
+
+fn fill(offer_denom: &str, offer_amount: u128) -> SwapFill {
+    SwapFill {
+        height: 1,
+        tx_hash: "AB".to_string(),
+        pool_id: Some("p1".to_string()),
+        offer_denom: offer_denom.to_string(),
+        ask_denom: "uom".to_string(),
+        offer_amount: Uint128::new(offer_amount),
+        return_amount: Uint128::zero(),
+        fee_amount: Uint128::zero(),
+    }
+}
+
+#[test]
+fn test_compare_values_pool_in_quote() {
+    let pools = [om_usdc()];
+    let context = YieldContext {
+        prices: PriceBook::new(&pools),
+        farms: &[],
+        current_epoch: 0,
+        epoch_seconds: 0,
+        window_seconds: SECONDS_PER_DAY / 2,
+    };
+    let fills = [fill("uusdc", 60_000), fill("uom", 20_000)];
+
+    let comparison = compare::compare(
+        &context,
+        &pools[0],
+        &fills,
+        None,
+        Some(Uint128::new(250)),
+        "uusdc",
+    );
+
+    assert_eq!(comparison.pool_id, "p1");
+    assert_eq!(comparison.swap_fee, Decimal::percent(1));
+    assert_eq!(comparison.total_fee, Decimal::permille(15));
+    assert_eq!(comparison.tvl, Some(Uint128::new(4_000_000)));
+    // 100_000 uusdc traded in half a day
+    assert_eq!(comparison.volume_24h, Some(Uint128::new(200_000)));
+    // 1_000 uusdc of fees every half day on 4_000_000 of liquidity
+    assert_eq!(comparison.fee_apr, Decimal::from_ratio(1_825u128, 10_000u128));
+    assert_eq!(comparison.incentive_apr, Decimal::zero());
+    assert_eq!(comparison.price, Some(Decimal::percent(200)));
+
+    let position = comparison.position.unwrap();
+    assert_eq!(position.share, Decimal::percent(25));
+    assert_eq!(position.value, Some(Uint128::new(1_000_000)));
+}
+
+#[test]
+fn test_compare_without_price_for_quote() {
+    let pools = [om_usdc()];
+    let context = YieldContext {
+        prices: PriceBook::new(&pools),
+        farms: &[],
+        current_epoch: 0,
+        epoch_seconds: 0,
+        window_seconds: 0,
+    };
+
+    let comparison = compare::compare(
+        &context,
+        &pools[0],
+        &[fill("uusdc", 1_000)],
+        None,
+        Some(Uint128::new(2_000)),
+        "uatom",
+    );
+
+    // Nothing links the pool to the quote, but fees and yields don't need it
+    assert_eq!(comparison.tvl, None);
+    assert_eq!(comparison.volume_24h, None);
+    assert_eq!(comparison.total_fee, Decimal::permille(15));
+    assert_eq!(comparison.price, Some(Decimal::percent(200)));
+    // A balance above the supply is capped at the whole pool
+    let position = comparison.position.unwrap();
+    assert_eq!(position.share, Decimal::one());
+    assert_eq!(position.value, None);
+}
+
+#[test]
+fn test_pool_tvl_values_each_reserve() {
+    let pools = [om_usdc()];
+    let prices = PriceBook::new(&pools);
+
+    let tvl = compare::pool_tvl(&prices, &pools[0], "uusdc");
+    assert_eq!(tvl.pool_id, "p1");
+    assert_eq!(tvl.quote, "uusdc");
+    let values: Vec<_> = tvl.reserves.iter().map(|reserve| reserve.value).collect();
+    assert_eq!(
+        values,
+        [Some(Uint128::new(2_000_000)), Some(Uint128::new(2_000_000))]
+    );
+    assert_eq!(tvl.tvl, Some(Uint128::new(4_000_000)));
+
+    // Reserves stay listed when nothing prices them, but the total is unknown
+    let unpriced = compare::pool_tvl(&prices, &pools[0], "uatom");
+    assert_eq!(unpriced.reserves.len(), 2);
+    assert_eq!(unpriced.tvl, None);
+}
+
+#[test]
+fn test_rank_pools() {
+    let pools = [om_usdc()];
+    let context = YieldContext {
+        prices: PriceBook::new(&pools),
+        farms: &[],
+        current_epoch: 0,
+        epoch_seconds: 0,
+        window_seconds: SECONDS_PER_DAY,
+    };
+    let base = compare::compare(&context, &pools[0], &[], None, None, "uusdc");
+    let with = |pool_id: &str, tvl: Option<u128>, volume: Option<u128>, apr: u64| {
+        let mut comparison = base.clone();
+        comparison.pool_id = pool_id.to_string();
+        comparison.tvl = tvl.map(Uint128::new);
+        comparison.volume_24h = volume.map(Uint128::new);
+        comparison.fee_apr = Decimal::percent(apr);
+        comparison
+    };
+    let mut comparisons = vec![
+        with("unpriced", None, None, 50),
+        with("small", Some(100), Some(900), 10),
+        with("large", Some(1_000), Some(300), 20),
+    ];
+    let order = |comparisons: &[compare::PoolComparison]| {
+        comparisons
+            .iter()
+            .map(|comparison| comparison.pool_id.clone())
+            .collect::<Vec<_>>()
+    };
+
+    compare::rank(&mut comparisons, PoolRanking::Tvl);
+    assert_eq!(order(&comparisons), ["large", "small", "unpriced"]);
+    compare::rank(&mut comparisons, PoolRanking::Volume24h);
+    assert_eq!(order(&comparisons), ["small", "large", "unpriced"]);
+    compare::rank(&mut comparisons, PoolRanking::Apr);
+    assert_eq!(order(&comparisons), ["unpriced", "large", "small"]);
+
+    assert_eq!("TVL".parse::<PoolRanking>().unwrap(), PoolRanking::Tvl);
+    assert_eq!(
+        "volume".parse::<PoolRanking>().unwrap(),
+        PoolRanking::Volume24h
+    );
+    assert!("liquidity".parse::<PoolRanking>().is_err());
+}
@@ -1,6 +1,15 @@
 mod utils;
 
-use mantra_dex_sdk::config::{MantraNetworkConfig, NetworkConstants};
+use std::time::Duration;
+
+use cosmwasm_std::Coin;
+use mantra_dex_sdk::config::{
+    AssetFilterConfig, BroadcastRetryConfig, Config, ExpertModeConfig, FeeToken,
+    MantraNetworkConfig, NetworkConstants, OperationClass, OperationTimeouts, PricingConfig,
+    Priority, PriorityFeeConfig,
+};
+use mantra_dex_sdk::congestion::{self, Congestion, InclusionLatencies};
+use mantra_dex_sdk::{Error, MantraDexClient};
 use utils::test_utils::{create_test_network_config, load_contract_addresses, load_test_config};
 
 #[test]
@@ -148,3 +157,330 @@ fn test_default_network_config() {
         "Default native denom should be uom"
     );
 }
+
+#[test]
+fn test_default_operation_timeouts() {
+    let timeouts = MantraNetworkConfig::default().timeouts;
+    assert_eq!(timeouts, OperationTimeouts::default());
+    assert_eq!(
+        timeouts.for_class(OperationClass::Query),
+        Duration::from_secs(30)
+    );
+    assert_eq!(
+        timeouts.for_class(OperationClass::Inclusion),
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn test_operation_timeouts_deserialize_with_defaults() {
+    let config = MantraNetworkConfig::default();
+    let mut value = serde_json::to_value(&config).unwrap();
+
+    // Configs written before timeouts existed still load
+    value.as_object_mut().unwrap().remove("timeouts");
+    let loaded: MantraNetworkConfig = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(loaded.timeouts, OperationTimeouts::default());
+
+    // Missing classes fall back to their defaults
+    value["timeouts"] = serde_json::json!({ "simulate_secs": 5 });
+    let loaded: MantraNetworkConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        loaded.timeouts.for_class(OperationClass::Simulate),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        loaded.timeouts.for_class(OperationClass::Broadcast),
+        Duration::from_secs(30)
+    );
+}
+
+#[test]
+fn test_priority_fee_multiplier_follows_priority() {
+    let mut priority_fee = MantraNetworkConfig::default().priority_fee;
+    assert_eq!(priority_fee, PriorityFeeConfig::default());
+    assert_eq!(priority_fee.fee_multiplier(true), 1.0);
+
+    priority_fee.priority = Priority::High;
+    assert_eq!(priority_fee.fee_multiplier(false), 1.5);
+
+    priority_fee.priority = Priority::Auto;
+    assert_eq!(priority_fee.fee_multiplier(false), 1.0);
+    assert_eq!(priority_fee.fee_multiplier(true), 1.5);
+
+    assert_eq!(" HIGH ".parse::<Priority>().unwrap(), Priority::High);
+    assert!(matches!(
+        "urgent".parse::<Priority>(),
+        Err(Error::Config(_))
+    ));
+
+    // Configs written before priority fees existed still load
+    let mut value = serde_json::to_value(MantraNetworkConfig::default()).unwrap();
+    value.as_object_mut().unwrap().remove("priority_fee");
+    let loaded: MantraNetworkConfig = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(loaded.priority_fee, PriorityFeeConfig::default());
+
+    value["priority_fee"] = serde_json::json!({ "priority": "auto", "multiplier": 2.0 });
+    let loaded: MantraNetworkConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(loaded.priority_fee.priority, Priority::Auto);
+    assert_eq!(loaded.priority_fee.fee_multiplier(true), 2.0);
+    assert_eq!(loaded.priority_fee.congested_mempool_txs, 1_000);
+}
+
+#[test]
+fn test_congestion_from_mempool_or_inclusion_latency() {
+    let config = PriorityFeeConfig::default();
+    assert!(!Congestion::default().is_congested(&config));
+
+    let busy_mempool = Congestion {
+        mempool_txs: Some(1_000),
+        inclusion_latency: None,
+    };
+    assert!(busy_mempool.is_congested(&config));
+
+    let latencies = InclusionLatencies::new();
+    assert_eq!(latencies.median(), None);
+    for secs in [2, 20, 30] {
+        latencies.record(Duration::from_secs(secs));
+    }
+    let slow_inclusion = Congestion {
+        mempool_txs: Some(10),
+        inclusion_latency: latencies.median(),
+    };
+    assert_eq!(
+        slow_inclusion.inclusion_latency,
+        Some(Duration::from_secs(20))
+    );
+    assert!(slow_inclusion.is_congested(&config));
+
+    // Only the latest inclusions count
+    for _ in 0..10 {
+        latencies.record(Duration::from_secs(3));
+    }
+    assert_eq!(latencies.median(), Some(Duration::from_secs(3)));
+
+    let response = serde_json::json!({ "result": { "n_txs": "1523", "total": "1523" } });
+    assert_eq!(congestion::parse_unconfirmed_txs(&response), Some(1523));
+    assert_eq!(
+        congestion::parse_unconfirmed_txs(&serde_json::json!({})),
+        None
+    );
+}
+
+#[test]
+fn test_broadcast_retry_backoff_doubles_up_to_the_limit() {
+    let retry = MantraNetworkConfig::default().broadcast_retry;
+    assert_eq!(retry, BroadcastRetryConfig::default());
+    assert_eq!(retry.backoff(0), Duration::from_millis(500));
+    assert_eq!(retry.backoff(2), Duration::from_secs(2));
+    assert_eq!(retry.backoff(10), Duration::from_secs(8));
+    assert_eq!(retry.backoff(100), Duration::from_secs(8));
+
+    // Configs written before retries existed still load
+    let mut value = serde_json::to_value(MantraNetworkConfig::default()).unwrap();
+    value.as_object_mut().unwrap().remove("broadcast_retry");
+    let loaded: MantraNetworkConfig = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(loaded.broadcast_retry, BroadcastRetryConfig::default());
+
+    value["broadcast_retry"] = serde_json::json!({ "max_retries": 0 });
+    let loaded: MantraNetworkConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(loaded.broadcast_retry.max_retries, 0);
+    assert_eq!(loaded.broadcast_retry.initial_backoff_ms, 500);
+}
+
+#[test]
+fn test_pricing_config_parses() {
+    let pricing: PricingConfig = toml::from_str(
+        r#"
+usd_denoms = ["uusdc"]
+max_trade_value = { max_value = "1000000000", denom = "usd" }
+"#,
+    )
+    .unwrap();
+    assert_eq!(pricing.usd_denoms, vec!["uusdc".to_string()]);
+    let limit = pricing.max_trade_value.unwrap();
+    assert_eq!(limit.max_value.u128(), 1_000_000_000);
+    assert_eq!(limit.denom, "usd");
+
+    // Configs without the section have no stablecoins and no limit
+    assert_eq!(
+        toml::from_str::<PricingConfig>("").unwrap(),
+        PricingConfig::default()
+    );
+}
+
+#[test]
+fn test_expert_mode_skips_only_known_low_risk_trades() {
+    let mut expert_mode = ExpertModeConfig::default();
+    assert!(!expert_mode.skips_confirmation(Some(10.0), Some(0.1)));
+
+    expert_mode.enabled = true;
+    assert!(expert_mode.skips_confirmation(Some(10.0), Some(0.1)));
+    assert!(!expert_mode.skips_confirmation(Some(50.0), Some(0.1)));
+    assert!(!expert_mode.skips_confirmation(Some(10.0), Some(0.5)));
+
+    // Trades that can't be valued or simulated are always confirmed
+    assert!(!expert_mode.skips_confirmation(None, Some(0.1)));
+    assert!(!expert_mode.skips_confirmation(Some(10.0), None));
+
+    // Configs written before expert mode existed still load
+    let mut value = serde_json::to_value(Config::new()).unwrap();
+    value.as_object_mut().unwrap().remove("expert_mode");
+    let loaded: Config = serde_json::from_value(value).unwrap();
+    assert_eq!(loaded.expert_mode, ExpertModeConfig::default());
+}
+
+/// The default network, also accepting fees in uusdc at 0.02
+fn network_with_fee_tokens(fee_denom: Option<&str>) -> MantraNetworkConfig {
+    MantraNetworkConfig {
+        fee_denom: fee_denom.map(str::to_string),
+        fee_tokens: vec![FeeToken {
+            denom: "uusdc".to_string(),
+            gas_price: 0.02,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_fee_token_follows_the_network_profile() {
+    let native = network_with_fee_tokens(None).fee_token().unwrap();
+    assert_eq!(native.denom, "uom");
+    assert_eq!(native.gas_price, 0.01);
+
+    let usdc = network_with_fee_tokens(Some("uusdc")).fee_token().unwrap();
+    assert_eq!(usdc.denom, "uusdc");
+    assert_eq!(usdc.gas_price, 0.02);
+    assert_eq!(
+        network_with_fee_tokens(None).fee_denoms(),
+        vec!["uom", "uusdc"]
+    );
+
+    // A denom the network doesn't accept fees in
+    assert!(matches!(
+        network_with_fee_tokens(Some("uatom")).fee_token(),
+        Err(Error::Config(_))
+    ));
+
+    let config: MantraNetworkConfig = toml::from_str(
+        r#"
+        network_name = "mantra-dukong"
+        chain_id = "mantra-dukong-1"
+        rpc_url = "http://localhost:26657"
+        gas_price = 0.01
+        gas_adjustment = 1.5
+        native_denom = "uom"
+        fee_denom = "uusdc"
+        fee_tokens = [{ denom = "uusdc", gas_price = 0.02 }]
+
+        [contracts]
+        pool_manager = "mantra1pool"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.fee_token().unwrap().denom, "uusdc");
+}
+
+#[test]
+fn test_fee_funds_check_names_held_alternatives() {
+    let config = network_with_fee_tokens(None);
+    let fee = Coin::new(5_000u128, "uom");
+
+    assert!(config
+        .check_fee_funds(&fee, &[Coin::new(5_000u128, "uom")])
+        .is_ok());
+
+    let Err(Error::Wallet(message)) =
+        config.check_fee_funds(&fee, &[Coin::new(1_000u128, "uusdc")])
+    else {
+        panic!("a wallet without uom can't pay the fee");
+    };
+    assert!(message.contains("uom"));
+    assert!(message.contains("also accepts fees in uusdc"));
+
+    let Err(Error::Wallet(message)) = config.check_fee_funds(&fee, &[]) else {
+        panic!("an empty wallet can't pay the fee");
+    };
+    assert!(!message.contains("also accepts"));
+}
+
+#[test]
+fn test_asset_filter_denylist() {
+    let filter = AssetFilterConfig {
+        denied_denoms: vec!["factory/mantra1scam/*".to_string(), "ubad".to_string()],
+        denied_pools: vec!["p2".to_string()],
+        ..Default::default()
+    };
+
+    assert!(filter.allows_denom("uom"));
+    assert!(!filter.allows_denom("ubad"));
+    assert!(!filter.allows_denom("factory/mantra1scam/moon"));
+    assert!(filter.allows_denom("factory/mantra1other/moon"));
+
+    assert!(filter.allows_pool("p1", &["uom", "uusdc"]));
+    assert!(!filter.allows_pool("p2", &["uom", "uusdc"]));
+    assert!(!filter.allows_pool("p3", &["uom", "factory/mantra1scam/moon"]));
+}
+
+#[test]
+fn test_asset_filter_strict_mode() {
+    let filter = AssetFilterConfig {
+        denied_denoms: vec!["uusdt".to_string()],
+        strict: true,
+        allowed_denoms: vec!["uom".to_string(), "uusdc".to_string(), "uusdt".to_string()],
+        allowed_pools: vec!["p9".to_string()],
+        ..Default::default()
+    };
+
+    assert!(filter.allows_denom("uom"));
+    assert!(!filter.allows_denom("uatom"));
+    // The denylist wins over the allowlist
+    assert!(!filter.allows_denom("uusdt"));
+
+    assert!(filter.allows_pool("p1", &["uom", "uusdc"]));
+    assert!(!filter.allows_pool("p2", &["uom", "uatom"]));
+    assert!(filter.allows_pool("p9", &["uom", "uatom"]));
+    assert!(!filter.allows_pool("p9", &["uom", "uusdt"]));
+
+    // Configs written before the filter existed still load, filtering nothing
+    let mut value = serde_json::to_value(Config::new()).unwrap();
+    value.as_object_mut().unwrap().remove("asset_filter");
+    let loaded: Config = serde_json::from_value(value).unwrap();
+    assert!(loaded.asset_filter.is_empty());
+}
+
+#[tokio::test]
+async fn test_client_rejects_filtered_denoms() {
+    let filter = AssetFilterConfig {
+        denied_denoms: vec!["ubad".to_string()],
+        ..Default::default()
+    };
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_asset_filter(filter.clone())
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(client.asset_filter(), &filter);
+    assert!(client.check_denoms_allowed(&["uom", "uusdc"]).is_ok());
+    let error = client.check_denoms_allowed(&["uom", "ubad"]).unwrap_err();
+    assert!(matches!(error, Error::Policy(_)));
+}
+
+#[tokio::test]
+async fn test_client_applies_operation_timeout() {
+    let mut config = MantraNetworkConfig::default();
+    config.timeouts.query_secs = 0;
+    let client = MantraDexClient::new(config).await.unwrap();
+
+    let result: Result<(), Error> = client
+        .with_timeout(OperationClass::Query, std::future::pending())
+        .await;
+    assert!(matches!(result, Err(Error::Timeout(_))));
+
+    // Other classes keep their own limits
+    let result = client
+        .with_timeout(OperationClass::Simulate, async { Ok(1) })
+        .await;
+    assert_eq!(result.unwrap(), 1);
+}
@@ -0,0 +1,96 @@
+use mantra_dex_sdk::contract_schema::{parse_response, query_name};
+use mantra_dex_sdk::error::{Error, ErrorClass};
+use mantra_dex_sdk::mantra_dex_std::pool_manager::{PoolsResponse, QueryMsg};
+use mantra_dex_sdk::{ContractVersion, SchemaMismatch};
+use serde::Deserialize;
+
+const POOL_MANAGER: &str = "mantra1poolmanager";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    owner: String,
+}
+
+fn mismatch(error: Error) -> SchemaMismatch {
+    match error {
+        Error::SchemaMismatch(mismatch) => *mismatch,
+        other => panic!("expected a schema mismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_query_name() {
+    let msg = QueryMsg::Pools {
+        pool_identifier: None,
+        start_after: None,
+        limit: Some(10),
+    };
+    assert_eq!(query_name(&msg), "pools");
+    assert_eq!(query_name(&QueryMsg::Config {}), "config");
+}
+
+#[test]
+fn test_missing_nested_field() {
+    // A pool whose info lost its identifier, as a contract upgrade renaming it would return
+    let data = br#"{"pools":[{"pool_info":{"asset_denoms":["uom","uusdc"]},"total_share":{"denom":"lp","amount":"1"}}]}"#;
+    let error = parse_response::<PoolsResponse>(POOL_MANAGER, "pools", data).unwrap_err();
+    assert_eq!(error.kind(), "SchemaMismatch");
+    assert_eq!(error.class(), ErrorClass::Internal);
+
+    let mismatch = mismatch(error);
+    assert_eq!(mismatch.contract, POOL_MANAGER);
+    assert_eq!(mismatch.query, "pools");
+    assert_eq!(
+        mismatch.field.as_deref(),
+        Some("pools[0].pool_info.pool_identifier")
+    );
+    assert_eq!(mismatch.reason, "missing field `pool_identifier`");
+}
+
+#[test]
+fn test_mismatched_fields() {
+    let error = parse_response::<Config>(POOL_MANAGER, "config", br#"{"owner":1}"#).unwrap_err();
+    let wrong_type = mismatch(error);
+    assert_eq!(wrong_type.field.as_deref(), Some("owner"));
+    assert!(wrong_type.reason.starts_with("invalid type: integer `1`"));
+
+    let error = parse_response::<Config>(POOL_MANAGER, "config", br#"{"owner":"a","admin":"b"}"#)
+        .unwrap_err();
+    assert_eq!(mismatch(error).field.as_deref(), Some("admin"));
+
+    let error = parse_response::<Config>(POOL_MANAGER, "config", br#"{}"#).unwrap_err();
+    assert_eq!(mismatch(error).field.as_deref(), Some("owner"));
+}
+
+#[test]
+fn test_invalid_json_is_not_a_mismatch() {
+    let error = parse_response::<Config>(POOL_MANAGER, "config", b"{\"owner\":").unwrap_err();
+    assert!(matches!(error, Error::Serialization(_)));
+    let config = parse_response::<Config>(POOL_MANAGER, "config", br#"{"owner":"a"}"#).unwrap();
+    assert_eq!(config.owner, "a");
+}
+
+#[test]
+fn test_mismatch_reports_contract_version() {
+    let error = parse_response::<Config>(POOL_MANAGER, "config", br#"{}"#).unwrap_err();
+    let version = ContractVersion {
+        contract: "crates.io:pool-manager".to_string(),
+        version: "2.0.0".to_string(),
+    };
+    let error = Error::SchemaMismatch(Box::new(
+        mismatch(error).with_contract_version(Some(version)),
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Schema mismatch: contract mantra1poolmanager (crates.io:pool-manager 2.0.0) answered \
+         `config` with an unexpected response at field `owner`: missing field `owner`"
+    );
+
+    let json = error.to_json();
+    assert_eq!(json["error"]["kind"], "SchemaMismatch");
+    let details = &json["error"]["schema_mismatch"];
+    assert_eq!(details["contract"], POOL_MANAGER);
+    assert_eq!(details["field"], "owner");
+    assert_eq!(details["contract_version"]["version"], "2.0.0");
+}
@@ -0,0 +1,130 @@
+mod utils;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::convert::{PriceBook, USD};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use utils::test_utils::pool;
+
+/// 1 OM = 2 USDC, 1 ATOM = 4 OM, 1 OSMO = 0.5 ATOM, and USDC/USDT at par
+fn pools() -> Vec<PoolInfoResponse> {
+    vec![
+        pool(
+            "om-usdc",
+            PoolType::ConstantProduct,
+            &[("uom", 1_000_000, 6), ("uusdc", 2_000_000, 6)],
+            Decimal::percent(1),
+        ),
+        pool(
+            "atom-om",
+            PoolType::ConstantProduct,
+            &[("uatom", 1_000_000, 6), ("uom", 4_000_000, 6)],
+            Decimal::percent(1),
+        ),
+        pool(
+            "osmo-atom",
+            PoolType::ConstantProduct,
+            &[("uosmo", 2_000_000, 6), ("uatom", 1_000_000, 6)],
+            Decimal::percent(1),
+        ),
+        pool(
+            "usdc-usdt",
+            PoolType::StableSwap { amp: 100 },
+            &[("uusdc", 5_000_000, 6), ("uusdt", 5_000_000, 6)],
+            Decimal::percent(1),
+        ),
+    ]
+}
+
+#[test]
+fn test_convert_routes_through_shortest_path() {
+    let pools = pools();
+    let prices = PriceBook::new(&pools);
+
+    let conversion = prices
+        .convert(Uint128::new(1_000), "uosmo", "uusdc")
+        .unwrap();
+    // 1 OSMO = 0.5 ATOM = 2 OM = 4 USDC
+    assert_eq!(conversion.to, Coin::new(4_000u128, "uusdc"));
+    assert_eq!(conversion.price(), Decimal::percent(400));
+    assert_eq!(conversion.path(), vec!["uosmo", "uatom", "uom", "uusdc"]);
+    let pools_used: Vec<&str> = conversion
+        .hops
+        .iter()
+        .map(|hop| hop.pool_id.as_str())
+        .collect();
+    assert_eq!(pools_used, vec!["osmo-atom", "atom-om", "om-usdc"]);
+
+    // A direct pool is preferred over any route
+    let direct = prices.convert(Uint128::new(10), "uom", "uusdc").unwrap();
+    assert_eq!(direct.hops.len(), 1);
+    assert_eq!(direct.to.amount, Uint128::new(20));
+}
+
+#[test]
+fn test_convert_respects_max_hops() {
+    let pools = pools();
+    let prices = PriceBook::new(&pools).with_max_hops(2);
+
+    assert!(prices
+        .convert(Uint128::new(1_000), "uosmo", "uusdc")
+        .is_none());
+    assert!(prices
+        .convert(Uint128::new(1_000), "uatom", "uusdc")
+        .is_some());
+    assert!(prices
+        .convert(Uint128::new(1_000), "uom", "uunknown")
+        .is_none());
+}
+
+#[test]
+fn test_convert_same_denom() {
+    let prices = PriceBook::new(&[]);
+    let conversion = prices.convert(Uint128::new(42), "uom", "uom").unwrap();
+
+    assert_eq!(conversion.to, Coin::new(42u128, "uom"));
+    assert!(conversion.hops.is_empty());
+}
+
+#[test]
+fn test_convert_to_and_from_usd() {
+    let pools = pools();
+    let usd_denoms = vec!["uusdt".to_string(), "uusdc".to_string()];
+    let prices = PriceBook::new(&pools).with_usd_denoms(&usd_denoms);
+
+    // 1 ATOM = 8 USDC; USDC is closer than USDT, which comes first
+    let conversion = prices
+        .convert(Uint128::new(5_000_000), "uatom", USD)
+        .unwrap();
+    assert_eq!(conversion.to, Coin::new(40_000_000u128, USD));
+    assert_eq!(conversion.path(), vec!["uatom", "uom", "uusdc", USD]);
+
+    // Stablecoins are taken at par
+    let stable = prices.convert(Uint128::new(7), "uusdc", USD).unwrap();
+    assert!(stable.hops.is_empty());
+    assert_eq!(stable.to.amount, Uint128::new(7));
+
+    // 100 USD buys 50 OM
+    let om = prices
+        .convert(Uint128::new(100_000_000), USD, "uom")
+        .unwrap();
+    assert_eq!(om.to, Coin::new(50_000_000u128, "uom"));
+
+    // Without stablecoins nothing is priced in USD
+    assert!(PriceBook::new(&pools).price("uom", USD).is_none());
+}
+
+#[test]
+fn test_usd_adjusts_for_stablecoin_decimals() {
+    let pools = vec![pool(
+        "om-dai",
+        PoolType::ConstantProduct,
+        &[("uom", 1_000_000, 6), ("adai", 3 * 10u128.pow(18), 18)],
+        Decimal::percent(1),
+    )];
+    let usd_denoms = vec!["adai".to_string()];
+    let prices = PriceBook::new(&pools).with_usd_denoms(&usd_denoms);
+
+    // 1 OM = 3 DAI = 3 USD
+    let conversion = prices.convert(Uint128::new(1_000_000), "uom", USD).unwrap();
+    assert_eq!(conversion.to.amount, Uint128::new(3_000_000));
+}
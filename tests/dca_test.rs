@@ -0,0 +1,239 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_sdk::dca::parse_interval;
+use mantra_dex_sdk::{DcaBook, DcaEnd, DcaOutcome, DcaStatus, NewDcaPlan};
+
+fn noon() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap()
+}
+
+/// 100 USDC for OM every 6 hours
+fn usdc_to_om(end: DcaEnd) -> NewDcaPlan {
+    NewDcaPlan {
+        pool_id: "p1".to_string(),
+        offer_asset: Coin::new(100_000_000u128, "uusdc"),
+        ask_asset_denom: "uom".to_string(),
+        interval_secs: 6 * 3600,
+        max_slippage: None,
+        end,
+        start_at: None,
+    }
+}
+
+fn swapped(n: u64) -> DcaOutcome {
+    DcaOutcome::Swapped {
+        tx_hash: format!("HASH{}", n),
+        height: n,
+    }
+}
+
+#[test]
+fn test_parse_interval() {
+    assert_eq!(parse_interval("6h").unwrap(), 21_600);
+    assert_eq!(parse_interval("30m").unwrap(), 1_800);
+    assert_eq!(parse_interval(" 1d ").unwrap(), 86_400);
+    assert_eq!(parse_interval("2w").unwrap(), 1_209_600);
+
+    assert!(parse_interval("6").is_err());
+    assert!(parse_interval("h").is_err());
+    assert!(parse_interval("6y").is_err());
+    assert!(parse_interval("0m").is_err());
+    assert!(parse_interval("").is_err());
+}
+
+#[test]
+fn test_plan_completes_after_max_executions() {
+    let mut book = DcaBook::new();
+    let id = book
+        .add(
+            "main",
+            usdc_to_om(DcaEnd {
+                max_executions: Some(2),
+                until: None,
+            }),
+            noon(),
+        )
+        .unwrap();
+    book.add("other", usdc_to_om(DcaEnd::default()), noon())
+        .unwrap();
+
+    // Due right away when no start is given, for its own wallet only
+    assert_eq!(book.due("main", noon()).len(), 1);
+    assert_eq!(
+        book.get(id).unwrap().upcoming(5, noon()),
+        vec![noon(), noon() + Duration::hours(6)]
+    );
+
+    book.record(id, swapped(1), noon()).unwrap();
+    assert!(book.due("main", noon()).is_empty());
+    assert_eq!(book.get(id).unwrap().next_at, noon() + Duration::hours(6));
+
+    // A failed swap counts towards nothing but is kept in the history
+    let later = noon() + Duration::hours(6);
+    book.record(
+        id,
+        DcaOutcome::Failed {
+            reason: "out of funds".to_string(),
+        },
+        later,
+    )
+    .unwrap();
+    assert_eq!(book.get(id).unwrap().status, DcaStatus::Active);
+    book.record(id, swapped(2), later + Duration::hours(6))
+        .unwrap();
+
+    let plan = book.get(id).unwrap();
+    assert_eq!(plan.status, DcaStatus::Completed);
+    assert_eq!(plan.swaps(), 2);
+    assert_eq!(plan.executions.len(), 3);
+    assert_eq!(plan.spent(), Uint128::new(200_000_000));
+    assert!(plan.upcoming(5, later).is_empty());
+}
+
+#[test]
+fn test_unconfirmed_swap_is_not_sent_again() {
+    let mut book = DcaBook::new();
+    let id = book
+        .add("main", usdc_to_om(DcaEnd::default()), noon())
+        .unwrap();
+    book.record(
+        id,
+        DcaOutcome::Unconfirmed {
+            tx_hash: "ab12".to_string(),
+            reason: "not included within 60 seconds".to_string(),
+        },
+        noon(),
+    )
+    .unwrap();
+
+    assert!(book.due("main", noon()).is_empty());
+    let plan = book.get(id).unwrap();
+    assert_eq!(plan.next_at, noon() + Duration::hours(6));
+    assert_eq!(plan.executions.len(), 1);
+}
+
+#[test]
+fn test_missed_executions_are_skipped_and_until_ends_the_plan() {
+    let mut book = DcaBook::new();
+    let until = noon() + Duration::days(1);
+    let id = book
+        .add(
+            "main",
+            usdc_to_om(DcaEnd {
+                max_executions: None,
+                until: Some(until),
+            }),
+            noon(),
+        )
+        .unwrap();
+
+    // The runner was down for 13 hours: one swap, then back on the 6 hour grid
+    let late = noon() + Duration::hours(13);
+    assert_eq!(book.due("main", late).len(), 1);
+    book.record(id, swapped(1), late).unwrap();
+    let plan = book.get(id).unwrap();
+    assert_eq!(plan.next_at, noon() + Duration::hours(18));
+    assert_eq!(plan.upcoming(5, late), vec![noon() + Duration::hours(18)]);
+
+    // Nothing runs from the end time on, and the plan then completes
+    assert!(book.due("main", until).is_empty());
+    assert!(book
+        .complete_ended(until - Duration::hours(1))
+        .unwrap()
+        .is_empty());
+    let completed = book.complete_ended(until).unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].status, DcaStatus::Completed);
+}
+
+#[test]
+fn test_pause_resume_and_cancel() {
+    let mut book = DcaBook::new();
+    let start = noon() + Duration::hours(1);
+    let id = book
+        .add(
+            "main",
+            NewDcaPlan {
+                start_at: Some(start),
+                ..usdc_to_om(DcaEnd::default())
+            },
+            noon(),
+        )
+        .unwrap();
+    assert!(book.due("main", noon()).is_empty());
+
+    book.pause(id).unwrap();
+    assert!(book.pause(id).is_err());
+    assert!(book.due("main", start).is_empty());
+    assert!(book.get(id).unwrap().upcoming(3, start).is_empty());
+
+    // Resuming after a missed execution runs it right away
+    let resumed_at = start + Duration::hours(2);
+    book.resume(id, resumed_at).unwrap();
+    assert!(book.resume(id, resumed_at).is_err());
+    assert_eq!(book.due("main", resumed_at).len(), 1);
+
+    book.cancel(id).unwrap();
+    assert_eq!(book.get(id).unwrap().status, DcaStatus::Cancelled);
+    assert!(book.cancel(id).is_err());
+    assert!(book.resume(id, resumed_at).is_err());
+    assert!(book.pause(42).is_err());
+}
+
+#[test]
+fn test_plans_are_validated() {
+    let mut book = DcaBook::new();
+    assert!(book
+        .add(
+            "main",
+            NewDcaPlan {
+                interval_secs: 10,
+                ..usdc_to_om(DcaEnd::default())
+            },
+            noon(),
+        )
+        .is_err());
+    assert!(book
+        .add(
+            "main",
+            NewDcaPlan {
+                offer_asset: Coin::new(0u128, "uusdc"),
+                ..usdc_to_om(DcaEnd::default())
+            },
+            noon(),
+        )
+        .is_err());
+    assert!(book.plans().is_empty());
+}
+
+#[test]
+fn test_dca_book_persists_across_opens() {
+    let path = std::env::temp_dir().join(format!("mantra-dex-dca-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut runner = DcaBook::open(&path).unwrap();
+    let mut editor = DcaBook::open(&path).unwrap();
+    let id = editor
+        .add("main", usdc_to_om(DcaEnd::default()), noon())
+        .unwrap();
+
+    // A pause from another process is seen before executing
+    runner.reload().unwrap();
+    assert_eq!(runner.due("main", noon()).len(), 1);
+    editor.pause(id).unwrap();
+    runner.reload().unwrap();
+    assert!(runner.due("main", noon()).is_empty());
+
+    // Executions recorded by the runner survive a restart
+    editor.resume(id, noon()).unwrap();
+    runner.record(id, swapped(7), noon()).unwrap();
+    let reopened = DcaBook::open(&path).unwrap();
+    assert_eq!(reopened.plans(), runner.plans());
+    assert_eq!(reopened.get(id).unwrap().executions[0].outcome, swapped(7));
+    assert_eq!(
+        reopened.get(id).unwrap().next_at,
+        noon() + Duration::hours(6)
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
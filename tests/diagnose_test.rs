@@ -0,0 +1,63 @@
+use mantra_dex_sdk::{Diagnosis, Error, FailureCause};
+
+#[test]
+fn test_sdk_codes_take_precedence_over_the_log() {
+    assert_eq!(
+        Diagnosis::new("sdk", 11, "out of gas in location: WriteFlat").cause,
+        FailureCause::OutOfGas
+    );
+    assert_eq!(
+        Diagnosis::new("sdk", 13, "insufficient fees; got: 10uom required: 500uom").cause,
+        FailureCause::InsufficientFee
+    );
+    assert_eq!(
+        Diagnosis::new("sdk", 32, "account sequence mismatch, expected 7, got 6").cause,
+        FailureCause::SequenceMismatch
+    );
+    assert_eq!(
+        Diagnosis::new("sdk", 5, "spendable balance 10uom is smaller than 20uom").cause,
+        FailureCause::InsufficientFunds
+    );
+}
+
+#[test]
+fn test_contract_errors_are_read_from_the_log() {
+    let slippage = Diagnosis::new(
+        "wasm",
+        5,
+        "failed to execute message; message index: 0: Max spread assertion: execute wasm \
+         contract failed",
+    );
+    assert_eq!(slippage.cause, FailureCause::SlippageExceeded);
+    assert!(slippage
+        .retry_command()
+        .unwrap()
+        .contains("--auto-retry-slippage"));
+
+    assert_eq!(
+        Diagnosis::new("wasm", 5, "Pool o.uom.uusdc: swaps are disabled").cause,
+        FailureCause::PoolUnavailable
+    );
+    assert_eq!(
+        Diagnosis::new("wasm", 5, "execute wasm contract failed: Invalid epoch").cause,
+        FailureCause::ContractError
+    );
+    assert_eq!(Diagnosis::new("bank", 99, "").cause, FailureCause::Unknown);
+    assert!(!FailureCause::Unknown.suggestions().is_empty());
+}
+
+#[test]
+fn test_error_messages_are_diagnosed() {
+    let diagnosis = Diagnosis::from_message(
+        "Failed to provide liquidity: Transaction broadcast error: Response { code: Err(13), \
+         data: b\"\", log: \"insufficient fee\", codespace: \"sdk\", hash: ABC }",
+    );
+    assert_eq!(diagnosis.cause, FailureCause::InsufficientFee);
+    assert_eq!(diagnosis.codespace.as_deref(), Some("sdk"));
+    assert_eq!(diagnosis.code, Some(13));
+
+    let diagnosis =
+        Diagnosis::from_error(&Error::Contract("Slippage tolerance exceeded".to_string()));
+    assert_eq!(diagnosis.cause, FailureCause::SlippageExceeded);
+    assert_eq!(diagnosis.code, None);
+}
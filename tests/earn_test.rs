@@ -0,0 +1,158 @@
+mod utils;
+
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use mantra_dex_sdk::{
+    analytics::SwapFill,
+    earn::{self, pool_price, PriceBook, YieldContext, SECONDS_PER_YEAR},
+    EarnOpportunity,
+};
+use mantra_dex_std::{
+    farm_manager::{Curve, Farm},
+    pool_manager::{PoolInfoResponse, PoolType},
+};
+use utils::test_utils::pool;
+
+/// 1 OM = 2 USDC
+fn om_usdc() -> PoolInfoResponse {
+    pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uom", 1_000_000, 6), ("uusdc", 2_000_000, 6)],
+        Decimal::percent(1),
+    )
+}
+
+fn farm(id: &str, start_epoch: u64, emission_rate: u128) -> Farm {
+    Farm {
+        identifier: id.to_string(),
+        owner: Addr::unchecked("mantra1owner"),
+        lp_denom: "factory/pool/p1.LP".to_string(),
+        farm_asset: Coin::new(1_000_000u128, "uom"),
+        claimed_amount: Uint128::zero(),
+        emission_rate: Uint128::new(emission_rate),
+        curve: Curve::Linear,
+        start_epoch,
+        preliminary_end_epoch: start_epoch + 10,
+    }
+}
+
+fn fill(offer_denom: &str, offer_amount: u128) -> SwapFill {
+    SwapFill {
+        height: 1,
+        tx_hash: "AB".to_string(),
+        pool_id: Some("p1".to_string()),
+        offer_denom: offer_denom.to_string(),
+        ask_denom: "uom".to_string(),
+        offer_amount: Uint128::new(offer_amount),
+        return_amount: Uint128::zero(),
+        fee_amount: Uint128::zero(),
+    }
+}
+
+#[test]
+fn test_pool_price() {
+    let constant_product = om_usdc();
+    assert_eq!(
+        pool_price(&constant_product, "uom", "uusdc"),
+        Some(Decimal::percent(200))
+    );
+    assert_eq!(
+        pool_price(&constant_product, "uusdc", "uom"),
+        Some(Decimal::percent(50))
+    );
+    assert_eq!(pool_price(&constant_product, "uatom", "uom"), None);
+
+    // Stable swap pools trade at par, adjusted for decimals
+    let stable = pool(
+        "s1",
+        PoolType::StableSwap { amp: 100 },
+        &[("uusdc", 1_000, 6), ("aeth", 5, 18)],
+        Decimal::percent(1),
+    );
+    assert_eq!(
+        pool_price(&stable, "aeth", "uusdc"),
+        Some(Decimal::from_ratio(1u128, 10u128.pow(12)))
+    );
+}
+
+#[test]
+fn test_price_book_routes_through_intermediate_asset() {
+    let pools = [
+        om_usdc(),
+        pool(
+            "p2",
+            PoolType::ConstantProduct,
+            &[("uusdc", 2_000_000, 6), ("uatom", 500_000, 6)],
+            Decimal::percent(1),
+        ),
+    ];
+    let prices = PriceBook::new(&pools);
+
+    assert_eq!(prices.price("uusdc", "uom"), Some(Decimal::percent(50)));
+    assert_eq!(prices.price("uatom", "uom"), Some(Decimal::percent(200)));
+    assert_eq!(prices.price("uosmo", "uom"), None);
+}
+
+#[test]
+fn test_opportunity_combines_fees_and_incentives() {
+    let pools = [om_usdc()];
+    let farms = [farm("active", 3, 1_000), farm("upcoming", 8, 1_000_000)];
+    let context = YieldContext {
+        prices: PriceBook::new(&pools),
+        farms: &farms,
+        current_epoch: 5,
+        epoch_seconds: SECONDS_PER_YEAR / 100,
+        window_seconds: SECONDS_PER_YEAR / 100,
+    };
+    let holding = Coin::new(1_000u128, "uom");
+
+    // Pool worth 2_000_000 uom; 1% of 100_000 uusdc in fees is 500 uom per window
+    let opportunity = context
+        .opportunity(
+            &pools[0],
+            &holding,
+            &[fill("uusdc", 100_000)],
+            Some(Uint128::new(500)),
+        )
+        .unwrap();
+
+    assert_eq!(opportunity.tvl, Uint128::new(2_000_000));
+    assert_eq!(opportunity.fee_apr, Decimal::permille(25));
+    // 1_000 uom per epoch paid to the half of the pool that is staked
+    assert_eq!(opportunity.incentive_apr, Decimal::percent(10));
+    assert_eq!(opportunity.farms, vec!["active".to_string()]);
+    assert_eq!(opportunity.pairing, vec![Coin::new(2_000u128, "uusdc")]);
+    // The position is worth 2_000 uom including the pairing
+    assert_eq!(opportunity.projected_return, Uint128::new(250));
+
+    assert!(context
+        .opportunity(&pools[0], &Coin::new(1_000u128, "uatom"), &[], None)
+        .is_none());
+}
+
+#[test]
+fn test_rank_orders_by_total_apr() {
+    let opportunity = |pool_id: &str, fee: u64, incentive: u64| EarnOpportunity {
+        asset: "uom".to_string(),
+        pool_id: pool_id.to_string(),
+        deposit: Uint128::new(1_000),
+        pairing: vec![],
+        tvl: Uint128::new(1_000_000),
+        fee_apr: Decimal::percent(fee),
+        incentive_apr: Decimal::percent(incentive),
+        farms: vec![],
+        projected_return: Uint128::zero(),
+    };
+    let mut opportunities = vec![
+        opportunity("fees", 8, 0),
+        opportunity("farmed", 2, 10),
+        opportunity("quiet", 1, 0),
+    ];
+
+    earn::rank(&mut opportunities);
+    let ranked: Vec<&str> = opportunities
+        .iter()
+        .map(|opportunity| opportunity.pool_id.as_str())
+        .collect();
+    assert_eq!(ranked, vec!["farmed", "fees", "quiet"]);
+}
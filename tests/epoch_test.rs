@@ -0,0 +1,67 @@
+use chrono::{Duration, TimeZone, Utc};
+use cosmwasm_std::{Addr, Coin, Uint128};
+use mantra_dex_sdk::epoch::{calendar, format_countdown};
+use mantra_dex_sdk::EpochCalendar;
+use mantra_dex_std::farm_manager::{Curve, Farm};
+
+const DAY: u64 = 86_400;
+
+fn farm(identifier: &str, start_epoch: u64, preliminary_end_epoch: u64) -> Farm {
+    Farm {
+        identifier: identifier.to_string(),
+        owner: Addr::unchecked("mantra1owner"),
+        lp_denom: "factory/pool/p1.LP".to_string(),
+        farm_asset: Coin::new(1_000_000u128, "uom"),
+        claimed_amount: Uint128::zero(),
+        emission_rate: Uint128::new(1_000),
+        curve: Curve::Linear,
+        start_epoch,
+        preliminary_end_epoch,
+    }
+}
+
+#[test]
+fn test_epoch_calendar_windows() {
+    let start = Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+    let calendar = EpochCalendar::new(40, start, DAY);
+
+    let next = calendar.window(41);
+    assert_eq!(next.start, start + Duration::days(1));
+    assert_eq!(next.end, start + Duration::days(2));
+    assert_eq!(calendar.window(38).start, start - Duration::days(2));
+
+    assert_eq!(calendar.epoch_at(start), 40);
+    assert_eq!(calendar.epoch_at(start + Duration::days(3)), 43);
+    assert_eq!(calendar.epoch_at(start - Duration::seconds(1)), 39);
+
+    let now = start + Duration::days(2) + Duration::hours(18);
+    assert_eq!(calendar.time_until_next(now), Duration::hours(6));
+    let upcoming: Vec<u64> = calendar.upcoming(now, 3).iter().map(|w| w.id).collect();
+    assert_eq!(upcoming, vec![42, 43, 44]);
+}
+
+#[test]
+fn test_calendar_marks_farm_starts_and_ends() {
+    let start = Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+    let epochs = EpochCalendar::new(40, start, DAY);
+    let farms = [farm("f1", 41, 43), farm("f2", 30, 41), farm("f3", 50, 60)];
+
+    let entries = calendar(&epochs, start, 4, &farms);
+    assert_eq!(entries.len(), 4);
+    // f2 pays in epoch 40 for the last time, f1 in epochs 41 and 42
+    assert_eq!(entries[0].farms_ending, vec!["f2".to_string()]);
+    assert_eq!(entries[1].farms_starting, vec!["f1".to_string()]);
+    assert_eq!(entries[2].farms_ending, vec!["f1".to_string()]);
+    assert!(entries[3].farms_starting.is_empty() && entries[3].farms_ending.is_empty());
+}
+
+#[test]
+fn test_format_countdown() {
+    assert_eq!(
+        format_countdown(Duration::seconds(2 * 86_400 + 3 * 3_600 + 15 * 60)),
+        "2d 03h 15m"
+    );
+    assert_eq!(format_countdown(Duration::minutes(90)), "1h 30m");
+    assert_eq!(format_countdown(Duration::seconds(245)), "4m 05s");
+    assert_eq!(format_countdown(Duration::seconds(-5)), "0m 00s");
+}
@@ -0,0 +1,91 @@
+use mantra_dex_sdk::error::{Error, ErrorClass};
+
+#[test]
+fn test_error_class_exit_codes_are_stable() {
+    assert_eq!(ErrorClass::Internal.exit_code(), 1);
+    assert_eq!(ErrorClass::User.exit_code(), 2);
+    assert_eq!(ErrorClass::Network.exit_code(), 3);
+    assert_eq!(ErrorClass::ChainRejection.exit_code(), 4);
+    assert_eq!(ErrorClass::PolicyViolation.exit_code(), 5);
+}
+
+#[test]
+fn test_error_classification() {
+    let cases = [
+        (Error::Rpc("down".to_string()), ErrorClass::Network),
+        (Error::Timeout("slow".to_string()), ErrorClass::Network),
+        (Error::Network("offline".to_string()), ErrorClass::Network),
+        (
+            Error::Unconfirmed {
+                tx_hash: "ab12".to_string(),
+                reason: "not included within 60 seconds".to_string(),
+            },
+            ErrorClass::Network,
+        ),
+        (
+            Error::TxSimulation("out of gas".to_string()),
+            ErrorClass::ChainRejection,
+        ),
+        (
+            Error::Contract("pool not found".to_string()),
+            ErrorClass::ChainRejection,
+        ),
+        (Error::Wallet("no wallet".to_string()), ErrorClass::User),
+        (Error::Config("bad network".to_string()), ErrorClass::User),
+        (
+            Error::Policy("trade too large".to_string()),
+            ErrorClass::PolicyViolation,
+        ),
+        (
+            Error::Storage("database is locked".to_string()),
+            ErrorClass::Internal,
+        ),
+        (Error::Other("boom".to_string()), ErrorClass::Internal),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(error.class(), expected, "{}", error);
+        assert_eq!(error.exit_code(), expected.exit_code());
+    }
+}
+
+#[test]
+fn test_retryable_broadcast_errors() {
+    let retryable = [
+        Error::Contract(
+            "Transaction check failed: account sequence mismatch, expected 8, got 7: \
+             incorrect account sequence"
+                .to_string(),
+        ),
+        Error::Contract("Transaction check failed: mempool is full".to_string()),
+    ];
+    for error in retryable {
+        assert!(error.is_retryable_broadcast(), "{}", error);
+    }
+
+    let final_errors = [
+        Error::Contract("Transaction check failed: insufficient fee".to_string()),
+        Error::Contract("Max spread assertion".to_string()),
+        Error::Policy("trade too large".to_string()),
+        Error::Rpc("connection refused".to_string()),
+        Error::Timeout("broadcast took too long".to_string()),
+        Error::Unconfirmed {
+            tx_hash: "ab12".to_string(),
+            reason: "not included within 60 seconds".to_string(),
+        },
+    ];
+    for error in final_errors {
+        assert!(!error.is_retryable_broadcast(), "{}", error);
+    }
+}
+
+#[test]
+fn test_error_json_shape() {
+    let error = Error::Rpc("connection refused".to_string());
+    let json = error.to_json();
+
+    assert_eq!(json["error"]["class"], "network");
+    assert_eq!(json["error"]["kind"], "Rpc");
+    assert_eq!(json["error"]["exit_code"], 3);
+    assert_eq!(json["error"]["message"], "RPC error: connection refused");
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::Any;
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_sdk::{
+    gas::{operation_kind, MIN_CALIBRATION_SAMPLES},
+    Error, GasEstimate, GasHistory, GasSample, MantraDexClient, MantraNetworkConfig,
+};
+use prost::Message;
+
+fn sample(simulated: u64, gas_used: u64) -> GasSample {
+    GasSample {
+        simulated,
+        gas_wanted: simulated * 2,
+        gas_used,
+        out_of_gas: false,
+    }
+}
+
+fn execute_msg(msg: &str) -> Any {
+    Any {
+        type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+        value: MsgExecuteContract {
+            sender: "mantra1sender".to_string(),
+            contract: "mantra1contract".to_string(),
+            msg: msg.as_bytes().to_vec(),
+            funds: vec![],
+        }
+        .encode_to_vec(),
+    }
+}
+
+#[test]
+fn test_gas_limit_uses_default_adjustment_without_history() {
+    let history = GasHistory::default();
+    for _ in 1..MIN_CALIBRATION_SAMPLES {
+        history.record("swap", sample(100_000, 100_000));
+    }
+
+    assert_eq!(history.calibrated_adjustment("swap"), None);
+    assert_eq!(history.gas_limit("swap", 100_000, 1.5, 2_000_000), 150_000);
+}
+
+#[test]
+fn test_gas_limit_calibrates_from_history() {
+    let history = GasHistory::default();
+    for used in [101_000, 102_000, 103_000, 104_000, 110_000] {
+        history.record("swap", sample(100_000, used));
+    }
+
+    // The highest ratio plus a 5% margin, well below the configured adjustment
+    let adjustment = history.calibrated_adjustment("swap").unwrap();
+    assert!((adjustment - 1.155).abs() < 1e-9);
+    assert_eq!(history.gas_limit("swap", 200_000, 1.5, 2_000_000), 231_000);
+
+    // Other kinds keep the default, and limits never exceed the cap
+    assert_eq!(
+        history.gas_limit("provide_liquidity", 100_000, 1.5, 2_000_000),
+        150_000
+    );
+    assert_eq!(
+        history.gas_limit("swap", 2_000_000, 1.5, 2_000_000),
+        2_000_000
+    );
+}
+
+#[test]
+fn test_out_of_gas_raises_calibration() {
+    let history = GasHistory::default();
+    for _ in 0..MIN_CALIBRATION_SAMPLES {
+        history.record("swap", sample(100_000, 100_000));
+    }
+    let before = history.calibrated_adjustment("swap").unwrap();
+
+    history.record(
+        "swap",
+        GasSample {
+            simulated: 100_000,
+            gas_wanted: 110_000,
+            gas_used: 110_000,
+            out_of_gas: true,
+        },
+    );
+
+    let after = history.calibrated_adjustment("swap").unwrap();
+    assert!(
+        after > 1.1 * 1.25,
+        "{} should cover the failed limit",
+        after
+    );
+    assert!(after > before);
+
+    let stats = history.kind_stats("swap").unwrap();
+    assert_eq!(stats.samples, MIN_CALIBRATION_SAMPLES + 1);
+    assert_eq!(stats.out_of_gas, 1);
+}
+
+#[test]
+fn test_gas_history_keeps_recent_samples() {
+    let history = GasHistory::new(3);
+    for used in 1..=5 {
+        history.record("swap", sample(10, used));
+    }
+
+    let used: Vec<u64> = history
+        .samples("swap")
+        .iter()
+        .map(|sample| sample.gas_used)
+        .collect();
+    assert_eq!(used, vec![3, 4, 5]);
+}
+
+#[test]
+fn test_gas_history_persists_to_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("gas_history.json");
+
+    let history = GasHistory::open(&path).unwrap();
+    assert!(history.stats().is_empty());
+    history.record("swap", sample(100_000, 120_000));
+
+    let reopened = GasHistory::open(&path).unwrap();
+    assert_eq!(reopened.samples("swap"), vec![sample(100_000, 120_000)]);
+}
+
+#[test]
+fn test_operation_kind_names_execute_messages() {
+    assert_eq!(
+        operation_kind(&[execute_msg(r#"{"swap":{"ask_asset_denom":"uom"}}"#)]),
+        "swap"
+    );
+    assert_eq!(
+        operation_kind(&[
+            execute_msg(r#"{"provide_liquidity":{}}"#),
+            Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: vec![],
+            },
+        ]),
+        "provide_liquidity+/cosmos.bank.v1beta1.MsgSend"
+    );
+}
+
+#[tokio::test]
+async fn test_clients_share_gas_history() {
+    let history = Arc::new(GasHistory::default());
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_gas_history(history.clone())
+        .build()
+        .await
+        .unwrap();
+
+    history.record("swap", sample(100_000, 100_000));
+    assert_eq!(client.gas_history().samples("swap").len(), 1);
+}
+
+#[test]
+fn test_gas_estimate_adjustment() {
+    let estimate = GasEstimate {
+        kind: "swap".to_string(),
+        simulated: 200_000,
+        gas_limit: 300_000,
+        fee: Coin {
+            denom: "uom".to_string(),
+            amount: Uint128::new(4_500),
+        },
+    };
+    assert!((estimate.adjustment() - 1.5).abs() < 1e-9);
+
+    let empty = GasEstimate {
+        simulated: 0,
+        gas_limit: 0,
+        ..estimate
+    };
+    assert_eq!(empty.adjustment(), 1.0);
+}
+
+#[tokio::test]
+async fn test_estimate_gas_needs_a_wallet() {
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_gas_adjustment(1.8)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(client.config().gas_adjustment, 1.8);
+
+    let result = client
+        .estimate_gas(vec![execute_msg(r#"{"swap":{}}"#)])
+        .await;
+    assert!(matches!(result, Err(Error::Wallet(_))));
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use mantra_dex_sdk::{
+    bridge::DexEvent, HistoryEntry, HistoryFilter, OperationType, PageCursor, TxHistory, TxRecord,
+};
+
+const ADDRESS: &str = "mantra1trader";
+
+fn record(height: u64, action: &str, pool_id: &str) -> TxRecord {
+    let tx_hash = format!("HASH{}", height);
+    let attributes = BTreeMap::from([
+        ("action".to_string(), action.to_string()),
+        ("pool_identifier".to_string(), pool_id.to_string()),
+    ]);
+    TxRecord {
+        height,
+        tx_hash: tx_hash.clone(),
+        success: true,
+        events: vec![DexEvent {
+            height,
+            tx_hash,
+            contract: "mantra1poolmanager".to_string(),
+            event_type: "wasm".to_string(),
+            action: Some(action.to_string()),
+            attributes,
+        }],
+    }
+}
+
+fn entries(records: Vec<TxRecord>) -> Vec<HistoryEntry> {
+    records
+        .into_iter()
+        .map(|record| HistoryEntry::from_record(record, None))
+        .collect()
+}
+
+#[test]
+fn test_entries_are_classified_by_action() {
+    let entry = HistoryEntry::from_record(record(10, "provide_liquidity", "o.uom.uusdc"), None);
+    assert_eq!(entry.operation, OperationType::ProvideLiquidity);
+    assert_eq!(entry.pool_id.as_deref(), Some("o.uom.uusdc"));
+    assert_eq!(
+        OperationType::from_action("claim"),
+        OperationType::ClaimRewards
+    );
+    assert_eq!(
+        "withdraw-liquidity".parse::<OperationType>().unwrap(),
+        OperationType::WithdrawLiquidity
+    );
+    assert!("transfer".parse::<OperationType>().is_err());
+}
+
+#[test]
+fn test_history_pages_newest_first_with_filter() {
+    let history = TxHistory::new();
+    let added = history
+        .record(
+            ADDRESS,
+            entries(vec![
+                record(1, "swap", "p1"),
+                record(2, "swap", "p2"),
+                record(3, "provide_liquidity", "p1"),
+                record(4, "swap", "p1"),
+            ]),
+            5,
+        )
+        .unwrap();
+    assert_eq!(added, 4);
+    assert_eq!(history.synced_height(ADDRESS), 5);
+
+    // Recording a known transaction again doesn't duplicate it
+    assert_eq!(
+        history
+            .record(ADDRESS, entries(vec![record(4, "swap", "p1")]), 6)
+            .unwrap(),
+        0
+    );
+    assert_eq!(history.len(ADDRESS), 4);
+
+    let swaps = HistoryFilter::default().with_operation(OperationType::Swap);
+    let first = history.entries(ADDRESS, &swaps, &PageCursor::first(2));
+    let heights: Vec<u64> = first.items.iter().map(|entry| entry.height).collect();
+    assert_eq!(heights, vec![4, 2]);
+    let second = history.entries(ADDRESS, &swaps, first.next_page().unwrap());
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.items[0].height, 1);
+    assert!(!second.has_next());
+
+    let pool = HistoryFilter::default()
+        .with_pool("p1")
+        .with_heights(Some(2), None);
+    let heights: Vec<u64> = history
+        .entries(ADDRESS, &pool, &PageCursor::default())
+        .items
+        .iter()
+        .map(|entry| entry.height)
+        .collect();
+    assert_eq!(heights, vec![4, 3]);
+    assert!(history.is_empty("mantra1other"));
+}
+
+#[test]
+fn test_history_persists_across_opens() {
+    let path = std::env::temp_dir().join(format!("tx_history_test_{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let history = TxHistory::open(&path).unwrap();
+    history
+        .record(ADDRESS, entries(vec![record(7, "create_pool", "p3")]), 9)
+        .unwrap();
+
+    let reopened = TxHistory::open(&path).unwrap();
+    assert_eq!(reopened.synced_height(ADDRESS), 9);
+    let page = reopened.entries(ADDRESS, &HistoryFilter::default(), &PageCursor::default());
+    assert_eq!(page.items[0].operation, OperationType::CreatePool);
+    std::fs::remove_file(&path).unwrap();
+}
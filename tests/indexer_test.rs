@@ -0,0 +1,221 @@
+use futures::future::BoxFuture;
+use mantra_dex_sdk::{
+    indexer::{self, Indexer, RestIndexer, TxRecord},
+    ownership::DenomHolder,
+    Coin, Error, IndexerConfig, MantraDexClient, MantraNetworkConfig, SwapFill, Uint128,
+};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+fn fill(height: u64, offer: (&str, u128), ask: (&str, u128)) -> SwapFill {
+    SwapFill {
+        height,
+        tx_hash: format!("TX{}", height),
+        pool_id: Some("p1".to_string()),
+        offer_denom: offer.0.to_string(),
+        ask_denom: ask.0.to_string(),
+        offer_amount: Uint128::new(offer.1),
+        return_amount: Uint128::new(ask.1),
+        fee_amount: Uint128::zero(),
+    }
+}
+
+/// Serve one HTTP request with `body`, returning the request head
+async fn serve_once(body: String) -> (String, tokio::task::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/api/", listener.local_addr().unwrap());
+
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+        }
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8(request).unwrap()
+    });
+    (url, server)
+}
+
+#[test]
+fn test_rest_indexer_endpoints() {
+    let indexer = RestIndexer::new("https://indexer.example.com/v1").unwrap();
+
+    assert_eq!(
+        indexer
+            .endpoint(
+                &["pools", "o.uom/uusdc", "swaps"],
+                &[("from_height", Some("10".to_string())), ("to_height", None)],
+            )
+            .as_str(),
+        "https://indexer.example.com/v1/pools/o.uom%2Fuusdc/swaps?from_height=10"
+    );
+    assert_eq!(
+        indexer.endpoint(&["txs"], &[("to_height", None)]).as_str(),
+        "https://indexer.example.com/v1/txs"
+    );
+    assert!(RestIndexer::new("not a url").is_err());
+}
+
+#[test]
+fn test_indexer_from_config() {
+    assert!(indexer::from_config(&IndexerConfig::default())
+        .unwrap()
+        .is_none());
+
+    let config = IndexerConfig {
+        url: Some("https://indexer.example.com".to_string()),
+        api_key: Some("secret".to_string()),
+    };
+    assert!(indexer::from_config(&config).unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_rest_indexer_reads_bare_responses() {
+    let fills = vec![fill(7, ("uom", 100), ("uusdc", 200))];
+    let (url, server) = serve_once(serde_json::to_string(&fills).unwrap()).await;
+    let indexer = RestIndexer::new(&url)
+        .unwrap()
+        .with_api_key("secret".to_string());
+
+    let received = indexer
+        .address_swaps("mantra1trader", Some(5), None)
+        .await
+        .unwrap();
+    assert_eq!(received, fills);
+
+    let request = server.await.unwrap();
+    assert!(request.starts_with("GET /api/swaps?address=mantra1trader&from_height=5 HTTP/1.1"));
+    assert!(request
+        .to_lowercase()
+        .contains("authorization: bearer secret"));
+}
+
+#[tokio::test]
+async fn test_rest_indexer_reads_wrapped_responses() {
+    let txs = vec![TxRecord {
+        height: 9,
+        tx_hash: "AB".to_string(),
+        success: true,
+        events: vec![],
+    }];
+    let (url, server) = serve_once(serde_json::json!({ "data": txs }).to_string()).await;
+    let indexer = RestIndexer::new(&url).unwrap();
+
+    assert_eq!(
+        indexer
+            .address_txs("mantra1trader", None, Some(20))
+            .await
+            .unwrap(),
+        txs
+    );
+    assert!(server
+        .await
+        .unwrap()
+        .starts_with("GET /api/txs?address=mantra1trader&to_height=20 HTTP/1.1"));
+}
+
+#[tokio::test]
+async fn test_rest_indexer_reads_lp_holders() {
+    let holders = vec![DenomHolder {
+        address: "mantra1lp".to_string(),
+        amount: Uint128::new(1_000),
+    }];
+    let (url, server) = serve_once(serde_json::to_string(&holders).unwrap()).await;
+    let indexer = RestIndexer::new(&url).unwrap();
+
+    assert_eq!(
+        indexer
+            .lp_holders("p1", "factory/pool/p1.LP")
+            .await
+            .unwrap(),
+        holders
+    );
+    assert!(server
+        .await
+        .unwrap()
+        .starts_with("GET /api/pools/p1/lp_holders HTTP/1.1"));
+    // Indexers that don't track balances refuse
+    assert!(FixedIndexer(Vec::new())
+        .lp_holders("p1", "factory/pool/p1.LP")
+        .await
+        .is_err());
+}
+
+/// Indexer serving fixed swaps
+#[derive(Debug)]
+struct FixedIndexer(Vec<SwapFill>);
+
+impl Indexer for FixedIndexer {
+    fn address_swaps<'a>(
+        &'a self,
+        _address: &'a str,
+        _from_height: Option<u64>,
+        _to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>> {
+        Box::pin(async move { Ok(self.0.clone()) })
+    }
+
+    fn pool_swaps<'a>(
+        &'a self,
+        _pool_id: &'a str,
+        _from_height: Option<u64>,
+        _to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<SwapFill>, Error>> {
+        Box::pin(async move { Ok(self.0.clone()) })
+    }
+
+    fn address_txs<'a>(
+        &'a self,
+        _address: &'a str,
+        _from_height: Option<u64>,
+        _to_height: Option<u64>,
+    ) -> BoxFuture<'a, Result<Vec<TxRecord>, Error>> {
+        Box::pin(async move { Ok(Vec::new()) })
+    }
+}
+
+#[tokio::test]
+async fn test_client_reads_history_through_indexer() {
+    let indexer = FixedIndexer(vec![
+        fill(1, ("uom", 100), ("uusdc", 200)),
+        fill(2, ("uom", 300), ("uusdc", 600)),
+    ]);
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_indexer(Some(Arc::new(indexer)))
+        .build()
+        .await
+        .unwrap();
+    assert!(client.external_indexer().is_some());
+
+    let report = client
+        .execution_report("mantra1trader", None, None)
+        .await
+        .unwrap();
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].trades, 2);
+    assert_eq!(report[0].total_offer, Uint128::new(400));
+
+    // Default candle and volume queries are derived from the pool's swaps
+    let volume = client
+        .indexer()
+        .pool_volume("p1", None, None)
+        .await
+        .unwrap();
+    assert_eq!(volume, vec![Coin::new(400u128, "uom")]);
+    let candles = client
+        .indexer()
+        .candles("p1", "uom", "uusdc", 10, None, None)
+        .await
+        .unwrap();
+    assert_eq!(candles.len(), 1);
+    assert_eq!(candles[0].trades, 2);
+}
@@ -0,0 +1,156 @@
+mod utils;
+
+use cosmwasm_std::{Coin, Decimal, Uint128, Uint256};
+use mantra_dex_sdk::{
+    mantra_dex_std::fee::Fee,
+    math::{simulate_swap, stable_swap_in_pool},
+    PageCursor, PoolType,
+};
+use utils::test_utils::{create_test_client, pool};
+
+fn offer(amount: u128, denom: &str) -> Coin {
+    Coin::new(amount, denom)
+}
+
+#[test]
+fn test_constant_product_swap() {
+    let mut xyk = pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uom", 1_000_000, 6), ("uusdc", 2_000_000, 6)],
+        Decimal::zero(),
+    );
+
+    // 2_000_000 - 2_000_000 * 1_000_000 / 1_001_000, rounded down
+    let simulation = simulate_swap(&xyk, &offer(1_000, "uom"), "uusdc").unwrap();
+    assert_eq!(simulation.return_amount, Uint128::new(1_998));
+    assert_eq!(simulation.slippage_amount, Uint128::new(2));
+    assert_eq!(simulation.swap_fee_amount, Uint128::zero());
+
+    // Each fee is taken from the return amount
+    xyk.pool_info.pool_fees.swap_fee = Fee {
+        share: Decimal::percent(1),
+    };
+    xyk.pool_info.pool_fees.protocol_fee = Fee {
+        share: Decimal::permille(5),
+    };
+    xyk.pool_info.pool_fees.extra_fees = vec![Fee {
+        share: Decimal::permille(1),
+    }];
+    let simulation = simulate_swap(&xyk, &offer(1_000, "uom"), "uusdc").unwrap();
+    assert_eq!(simulation.swap_fee_amount, Uint128::new(19));
+    assert_eq!(simulation.protocol_fee_amount, Uint128::new(9));
+    assert_eq!(simulation.extra_fees_amount, Uint128::new(1));
+    assert_eq!(simulation.return_amount, Uint128::new(1_969));
+
+    assert!(simulate_swap(&xyk, &offer(1_000, "uom"), "uatom").is_none());
+    assert!(simulate_swap(&xyk, &offer(1_000, "uom"), "uom").is_none());
+}
+
+#[test]
+fn test_stable_swap() {
+    let balanced = pool(
+        "p1",
+        PoolType::StableSwap { amp: 100 },
+        &[("uusdc", 1_000_000_000, 6), ("uusdt", 1_000_000_000, 6)],
+        Decimal::zero(),
+    );
+
+    // A small trade in a balanced pool is close to 1:1
+    let simulation = simulate_swap(&balanced, &offer(1_000_000, "uusdc"), "uusdt").unwrap();
+    assert!(simulation.return_amount <= Uint128::new(1_000_000));
+    assert!(simulation.return_amount >= Uint128::new(999_000));
+
+    // Much less slippage than a constant product pool of the same depth
+    let xyk = pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uusdc", 1_000_000_000, 6), ("uusdt", 1_000_000_000, 6)],
+        Decimal::zero(),
+    );
+    let large = offer(100_000_000, "uusdc");
+    let stable_return = simulate_swap(&balanced, &large, "uusdt")
+        .unwrap()
+        .return_amount;
+    let xyk_return = simulate_swap(&xyk, &large, "uusdt").unwrap().return_amount;
+    assert!(stable_return > xyk_return);
+    assert!(stable_return < large.amount);
+
+    // Decimals are normalised: 1 USDC (6) for about 1 DAI (18)
+    let mixed = pool(
+        "p1",
+        PoolType::StableSwap { amp: 100 },
+        &[
+            ("uusdc", 1_000_000_000, 6),
+            ("adai", 1_000_000_000_000_000_000_000, 18),
+        ],
+        Decimal::zero(),
+    );
+    let simulation = simulate_swap(&mixed, &offer(1_000_000, "uusdc"), "adai").unwrap();
+    assert!(simulation.return_amount <= Uint128::new(1_000_000_000_000_000_000));
+    assert!(simulation.return_amount >= Uint128::new(999_000_000_000_000_000));
+
+    // Pools of more than two assets count every asset in the swap equation
+    let three_assets = pool(
+        "p1",
+        PoolType::StableSwap { amp: 100 },
+        &[
+            ("uusdc", 1_000_000_000, 6),
+            ("uusdt", 1_000_000_000, 6),
+            ("udai", 1_000_000_000, 6),
+        ],
+        Decimal::zero(),
+    );
+    let small = offer(1_000_000, "uusdc");
+    let simulation = simulate_swap(&three_assets, &small, "uusdt").unwrap();
+    let (expected, _) = stable_swap_in_pool(
+        3,
+        Uint128::new(1_000_000_000),
+        Uint128::new(1_000_000_000),
+        small.amount,
+        100,
+        6,
+        6,
+    )
+    .unwrap();
+    assert_eq!(Uint256::from(simulation.return_amount), expected);
+    assert!(simulation.return_amount >= Uint128::new(999_000));
+    assert_ne!(
+        simulation.return_amount,
+        simulate_swap(&balanced, &small, "uusdt")
+            .unwrap()
+            .return_amount
+    );
+    assert!(
+        stable_swap_in_pool(1, Uint128::one(), Uint128::one(), Uint128::one(), 100, 6, 6).is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_local_math_matches_chain_simulation() {
+    let client = create_test_client().await;
+    let pools = client
+        .get_pools(&PageCursor::first(100))
+        .await
+        .map(|page| page.items)
+        .unwrap();
+
+    for pool in &pools {
+        let info = &pool.pool_info;
+        let offer_asset = Coin {
+            denom: info.asset_denoms[0].clone(),
+            amount: info.assets[0].amount / Uint128::new(100),
+        };
+        let ask_denom = &info.asset_denoms[1];
+
+        let Some(local) = simulate_swap(pool, &offer_asset, ask_denom) else {
+            continue;
+        };
+        let chain = client
+            .simulate_swap(&info.pool_identifier, offer_asset, ask_denom)
+            .await
+            .unwrap();
+
+        assert_eq!(local, chain, "pool {}", info.pool_identifier);
+    }
+}
@@ -4,7 +4,7 @@ use std::time::Duration;
 use tokio::time::timeout;
 
 mod utils;
-use mantra_dex_sdk::Error;
+use mantra_dex_sdk::{Error, PageCursor};
 use utils::test_utils::*;
 
 /// Comprehensive MCP integration test that calls all available tools in proper order
@@ -105,7 +105,10 @@ async fn test_pool_query_tools(client: &mantra_dex_sdk::client::MantraDexClient)
 
     // Test get_pools
     println!("  Testing get_pools...");
-    let pools_result = client.get_pools(None).await;
+    let pools_result = client
+        .get_pools(&PageCursor::default())
+        .await
+        .map(|page| page.items);
     match pools_result {
         Ok(pools) => {
             println!(
@@ -436,7 +439,8 @@ async fn test_mcp_tools_performance() {
                 }
             }
             "get_pools" => {
-                let r = timeout(operation_timeout, client.get_pools(None)).await;
+                let cursor = PageCursor::default();
+                let r = timeout(operation_timeout, client.get_pools(&cursor)).await;
                 match r {
                     Ok(Ok(_)) => Ok(Ok(())),
                     Ok(Err(e)) => Ok(Err(e)),
@@ -0,0 +1,85 @@
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use mantra_dex_sdk::{
+    middleware::{AuditEvent, ProgressReporter},
+    AuditLog, Error, Middleware, TxContext, TxProgress, TxStage,
+};
+use tokio::sync::broadcast;
+
+fn tx(id: u64) -> TxContext {
+    TxContext {
+        id,
+        kind: "pool_manager.swap".to_string(),
+        msgs: vec![],
+        signer: "mantra1signer".to_string(),
+        simulated_gas: Some(150_000),
+        gas_limit: Some(210_000),
+        tx_hash: None,
+        force: false,
+    }
+}
+
+#[test]
+fn test_progress_reporter_sends_each_stage() {
+    let reporter = ProgressReporter::new(broadcast::channel(8).0);
+    let mut progress = reporter.subscribe();
+
+    let mut tx = tx(7);
+    reporter.on_stage(&tx, TxStage::Signed);
+    tx.tx_hash = Some("AB12".to_string());
+    reporter.on_stage(&tx, TxStage::Broadcast);
+
+    assert_eq!(
+        progress.try_recv().unwrap(),
+        TxProgress {
+            id: 7,
+            stage: TxStage::Signed,
+            tx_hash: None,
+        }
+    );
+    assert_eq!(
+        progress.try_recv().unwrap().tx_hash.as_deref(),
+        Some("AB12")
+    );
+}
+
+#[test]
+fn test_audit_log_records_outcomes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("logs").join("audit.log");
+    let log = AuditLog::open(&path).unwrap();
+
+    let mut included = tx(1);
+    included.tx_hash = Some("AB12".to_string());
+    log.after_broadcast(
+        &included,
+        &TxResponse {
+            height: 42,
+            gas_used: 180_000,
+            ..Default::default()
+        },
+    );
+    log.on_error(&tx(2), &Error::Policy("Trade too large".to_string()));
+    drop(log);
+
+    // Reopening appends rather than truncating
+    AuditLog::open(&path)
+        .unwrap()
+        .on_error(&tx(3), &Error::Other("Insufficient funds".to_string()));
+
+    let entries = AuditLog::read(&path).unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].event, AuditEvent::Included);
+    assert_eq!(entries[0].tx_hash.as_deref(), Some("AB12"));
+    assert_eq!(entries[0].height, Some(42));
+    assert_eq!(entries[0].gas_used, Some(180_000));
+    assert_eq!(entries[0].gas_limit, Some(210_000));
+    assert_eq!(entries[1].event, AuditEvent::Failed);
+    assert_eq!(entries[1].tx_id, 2);
+    assert!(entries[1]
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("Trade too large"));
+    assert_eq!(entries[2].kind, "pool_manager.swap");
+    assert_eq!(entries[2].signer, "mantra1signer");
+}
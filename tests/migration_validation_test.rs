@@ -1,5 +1,5 @@
 use cosmwasm_std::{Coin, Decimal, Uint128};
-use mantra_dex_sdk::{Error, MantraDexClient, MantraWallet};
+use mantra_dex_sdk::{Error, MantraDexClient, MantraWallet, PageCursor};
 use std::str::FromStr;
 use std::time::Instant;
 
@@ -179,7 +179,10 @@ async fn test_dependency_compatibility() {
         println!("✓ Wallet integration with new dependencies successful");
 
         // Test basic query functionality with new dependencies using the client with wallet
-        let pools_result = client_with_wallet.get_pools(Some(1)).await;
+        let pools_result = client_with_wallet
+            .get_pools(&PageCursor::first(1))
+            .await
+            .map(|page| page.items);
 
         match pools_result {
             Ok(_) => {
@@ -197,7 +200,11 @@ async fn test_dependency_compatibility() {
     }
 
     // This code will only run if no wallet is configured
-    let pools_result = create_test_client().await.get_pools(Some(1)).await;
+    let pools_result = create_test_client()
+        .await
+        .get_pools(&PageCursor::first(1))
+        .await
+        .map(|page| page.items);
 
     match pools_result {
         Ok(_) => {
@@ -235,7 +242,7 @@ async fn test_performance_regression() {
 
     // Test query performance
     let start = Instant::now();
-    let _result = client.get_pools(Some(10)).await;
+    let _result = client.get_pools(&PageCursor::first(10)).await;
     let query_time = start.elapsed();
 
     println!("Query time: {:?}", query_time);
@@ -384,7 +391,10 @@ async fn test_pool_status_handling_migration() {
     }
 
     // Test pool status extraction from real pools
-    let pools_result = client.get_pools(Some(5)).await;
+    let pools_result = client
+        .get_pools(&PageCursor::first(5))
+        .await
+        .map(|page| page.items);
 
     match pools_result {
         Ok(pools) => {
@@ -0,0 +1,197 @@
+use cosmrs::tx::{Body, Fee, ModeInfo, SignMode};
+use cosmrs::{Coin as CosmosCoin, Denom, Tx};
+use cosmwasm_std::Coin;
+use mantra_dex_sdk::wallet::WalletStorage;
+use mantra_dex_sdk::{Error, MantraWallet, MultisigAccount, MultisigTx, PartialSignature};
+use serde_json::json;
+use std::str::FromStr;
+
+const CHAIN_ID: &str = "mantra-dukong-1";
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+                        abandon abandon abandon abandon abandon about";
+
+fn members() -> Vec<MantraWallet> {
+    (0..3)
+        .map(|index| MantraWallet::from_mnemonic(MNEMONIC, index).unwrap())
+        .collect()
+}
+
+fn two_of_three(members: &[MantraWallet]) -> MultisigAccount {
+    let keys = members.iter().map(|m| m.public_key().unwrap()).collect();
+    MultisigAccount::new(2, keys).unwrap()
+}
+
+fn multisig_tx(account: &MultisigAccount) -> MultisigTx {
+    let msg = account
+        .execute_contract_msg(
+            "mantra1contract",
+            &json!({ "swap": { "ask_asset_denom": "uusdc" } }),
+            &[Coin::new(1_000u128, "uom")],
+        )
+        .unwrap();
+    let fee = Fee::from_amount_and_gas(
+        CosmosCoin {
+            denom: Denom::from_str("uom").unwrap(),
+            amount: 5_000,
+        },
+        200_000u64,
+    );
+    MultisigTx::new(
+        account.clone(),
+        &Body::new(vec![msg], "treasury", 0u32),
+        &fee,
+        CHAIN_ID,
+        7,
+        3,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_multisig_address() {
+    let members = members();
+    let account = two_of_three(&members);
+    let address = account.address().unwrap();
+    assert_eq!(address.prefix(), "mantra");
+    assert_eq!(account.threshold(), 2);
+    assert_eq!(account.public_keys().len(), 3);
+
+    // Members are sorted, so the order the keys are given in doesn't matter
+    let mut keys: Vec<_> = members.iter().map(|m| m.public_key().unwrap()).collect();
+    keys.reverse();
+    assert_eq!(MultisigAccount::new(2, keys.clone()).unwrap(), account);
+
+    // The threshold is part of the address
+    let one_of_three = MultisigAccount::new(1, keys.clone()).unwrap();
+    assert_ne!(one_of_three.address().unwrap(), address);
+    for member in &members {
+        assert_ne!(member.address().unwrap(), address);
+    }
+
+    assert!(matches!(
+        MultisigAccount::new(0, keys.clone()),
+        Err(Error::Wallet(_))
+    ));
+    assert!(matches!(
+        MultisigAccount::new(4, keys.clone()),
+        Err(Error::Wallet(_))
+    ));
+    keys.push(keys[0]);
+    assert!(matches!(
+        MultisigAccount::new(2, keys),
+        Err(Error::Wallet(_))
+    ));
+}
+
+#[test]
+fn test_sign_partial_and_combine() {
+    let members = members();
+    let account = two_of_three(&members);
+    let tx = multisig_tx(&account);
+
+    // The transaction travels between members as JSON
+    let tx: MultisigTx = serde_json::from_str(&serde_json::to_string(&tx).unwrap()).unwrap();
+    let sign_doc: serde_json::Value = serde_json::from_slice(&tx.sign_bytes().unwrap()).unwrap();
+    assert_eq!(sign_doc["account_number"], "7");
+    assert_eq!(sign_doc["sequence"], "3");
+    assert_eq!(sign_doc["memo"], "treasury");
+    assert_eq!(
+        sign_doc["msgs"][0]["value"]["sender"],
+        account.address().unwrap().to_string()
+    );
+
+    let first = tx.sign_partial(&members[0]).unwrap();
+    let third = tx.sign_partial(&members[2]).unwrap();
+    tx.verify(&first).unwrap();
+    let first: PartialSignature =
+        serde_json::from_str(&serde_json::to_string(&first).unwrap()).unwrap();
+
+    // One signature, even given twice, is below the threshold
+    assert!(matches!(
+        tx.combine(&[first.clone(), first.clone()]),
+        Err(Error::Wallet(_))
+    ));
+
+    let raw = tx.combine(&[third, first]).unwrap();
+    let signed = Tx::from_bytes(&raw.to_bytes().unwrap()).unwrap();
+    assert_eq!(signed.body.memo, "treasury");
+    assert_eq!(signed.auth_info.fee.gas_limit, 200_000);
+    assert_eq!(signed.signatures.len(), 1);
+
+    let signer = &signed.auth_info.signer_infos[0];
+    assert_eq!(signer.sequence, 3);
+    let multisig_key = signer
+        .public_key
+        .as_ref()
+        .and_then(|key| key.legacy_amino_multisig())
+        .unwrap();
+    assert_eq!(multisig_key.threshold, 2);
+    assert_eq!(multisig_key.public_keys, account.public_keys());
+    match &signer.mode_info {
+        ModeInfo::Multi(multi) => {
+            assert_eq!(
+                multi.mode_infos,
+                vec![ModeInfo::single(SignMode::LegacyAminoJson); 2]
+            );
+        }
+        other => panic!("Expected multisig mode info, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_partial_signatures() {
+    let members = members();
+    let account = two_of_three(&members);
+    let tx = multisig_tx(&account);
+
+    // Not a member
+    let (outsider, _) = MantraWallet::generate().unwrap();
+    assert!(matches!(
+        tx.sign_partial(&outsider),
+        Err(Error::Wallet(_))
+    ));
+
+    // A signature of a different transaction
+    let mut other = tx.clone();
+    other.sequence += 1;
+    let stale = other.sign_partial(&members[1]).unwrap();
+    assert!(matches!(tx.verify(&stale), Err(Error::Wallet(_))));
+    let valid = tx.sign_partial(&members[0]).unwrap();
+    assert!(matches!(
+        tx.combine(&[valid, stale]),
+        Err(Error::Wallet(_))
+    ));
+
+    // Watch-only members can't sign
+    let address = members[0].address().unwrap().to_string();
+    let watching = MantraWallet::watch_only(&address).unwrap();
+    assert!(matches!(
+        tx.sign_partial(&watching),
+        Err(Error::Wallet(_))
+    ));
+}
+
+#[test]
+fn test_multisig_wallet_storage() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = WalletStorage::with_directory(dir.path().to_path_buf()).unwrap();
+    let account = two_of_three(&members());
+
+    storage.save_multisig_wallet("treasury", &account).unwrap();
+    assert_eq!(storage.multisig_account("treasury").unwrap(), account);
+
+    let metadata = storage.wallet_metadata("treasury").unwrap();
+    assert!(metadata.watch_only);
+    assert_eq!(metadata.address, account.address().unwrap().to_string());
+
+    // The account is viewed like any watch-only wallet
+    let wallet = storage.load_watch_only_wallet("treasury").unwrap();
+    assert_eq!(wallet.address().unwrap(), account.address().unwrap());
+
+    let address = account.address().unwrap().to_string();
+    storage.save_watch_only_wallet("watched", &address).unwrap();
+    assert!(matches!(
+        storage.multisig_account("watched"),
+        Err(Error::Wallet(_))
+    ));
+}
@@ -0,0 +1,138 @@
+mod utils;
+
+use chrono::{TimeZone, Utc};
+use cosmwasm_std::{Coin, Decimal};
+use mantra_dex_sdk::{NewOrder, OrderBook, OrderStatus, PriceCondition};
+use utils::test_utils::om_usdc_pool;
+
+fn sell_om(condition: PriceCondition) -> NewOrder {
+    NewOrder {
+        pool_id: "p1".to_string(),
+        offer_asset: Coin::new(1_000_000u128, "uom"),
+        ask_asset_denom: "uusdc".to_string(),
+        condition,
+        max_slippage: None,
+        expires_at: None,
+    }
+}
+
+#[test]
+fn test_price_condition_parse_and_display() {
+    let take_profit: PriceCondition = ">=2.5".parse().unwrap();
+    assert_eq!(
+        take_profit,
+        PriceCondition::AtLeast(Decimal::from_ratio(5u128, 2u128))
+    );
+    assert_eq!(take_profit.to_string(), ">=2.5");
+    assert_eq!(
+        " <= 0.8 ".parse::<PriceCondition>().unwrap(),
+        PriceCondition::AtMost(Decimal::percent(80))
+    );
+
+    assert!("2.5".parse::<PriceCondition>().is_err());
+    assert!(">=cheap".parse::<PriceCondition>().is_err());
+    assert!("<=0".parse::<PriceCondition>().is_err());
+}
+
+#[test]
+fn test_order_triggers_at_spot_price() {
+    let mut book = OrderBook::new();
+    let take_profit = book.add("main", sell_om(">=3".parse().unwrap())).unwrap();
+    let stop_loss = book.add("main", sell_om("<=1".parse().unwrap())).unwrap();
+    let take_profit = book.get(take_profit).unwrap();
+    let stop_loss = book.get(stop_loss).unwrap();
+
+    // 1 OM = 2 USDC, 3 USDC and 1 USDC
+    let at_two = om_usdc_pool("p1", 1_000_000, 2_000_000);
+    let at_three = om_usdc_pool("p1", 1_000_000, 3_000_000);
+    let at_one = om_usdc_pool("p1", 1_000_000, 1_000_000);
+
+    assert_eq!(take_profit.triggered_by(&at_two), None);
+    assert_eq!(stop_loss.triggered_by(&at_two), None);
+    assert_eq!(
+        take_profit.triggered_by(&at_three),
+        Some(Decimal::from_ratio(3u128, 1u128))
+    );
+    assert_eq!(stop_loss.triggered_by(&at_one), Some(Decimal::one()));
+}
+
+#[test]
+fn test_order_book_cancel_and_expire() {
+    let mut book = OrderBook::new();
+    let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+    let first = book.add("main", sell_om(">=3".parse().unwrap())).unwrap();
+    let expiring = book
+        .add(
+            "main",
+            NewOrder {
+                expires_at: Some(now),
+                ..sell_om("<=1".parse().unwrap())
+            },
+        )
+        .unwrap();
+    book.add("other", sell_om(">=3".parse().unwrap())).unwrap();
+    assert_eq!((first, expiring), (1, 2));
+    assert_eq!(book.pending("main").len(), 2);
+
+    assert!(book
+        .expire(now - chrono::Duration::seconds(1))
+        .unwrap()
+        .is_empty());
+    let expired = book.expire(now).unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, expiring);
+    assert_eq!(book.get(expiring).unwrap().status, OrderStatus::Expired);
+
+    book.cancel(first).unwrap();
+    assert!(book.pending("main").is_empty());
+    assert_eq!(book.get(first).unwrap().status, OrderStatus::Cancelled);
+
+    // Only pending orders can be cancelled
+    assert!(book.cancel(first).is_err());
+    assert!(book.cancel(expiring).is_err());
+    assert!(book.cancel(42).is_err());
+}
+
+#[test]
+fn test_unconfirmed_order_is_not_sent_again() {
+    let mut book = OrderBook::new();
+    let id = book.add("main", sell_om(">=3".parse().unwrap())).unwrap();
+    let status = OrderStatus::Unconfirmed {
+        tx_hash: "ab12".to_string(),
+        reason: "not included within 60 seconds".to_string(),
+    };
+    book.set_status(id, status.clone()).unwrap();
+
+    assert!(book.pending("main").is_empty());
+    assert!(book.cancel(id).is_err());
+    assert_eq!(
+        status.to_string(),
+        "unconfirmed ab12: not included within 60 seconds"
+    );
+}
+
+#[test]
+fn test_order_book_persists_across_opens() {
+    let path = std::env::temp_dir().join(format!("mantra-dex-orders-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut watcher = OrderBook::open(&path).unwrap();
+    let mut editor = OrderBook::open(&path).unwrap();
+    let id = editor.add("main", sell_om(">=3".parse().unwrap())).unwrap();
+
+    // A cancellation from another process is seen before executing
+    watcher.reload().unwrap();
+    assert_eq!(watcher.pending("main").len(), 1);
+    editor.cancel(id).unwrap();
+    watcher.reload().unwrap();
+    assert!(watcher.pending("main").is_empty());
+
+    let reopened = OrderBook::open(&path).unwrap();
+    assert_eq!(reopened.orders(), watcher.orders());
+    assert_eq!(
+        reopened.get(id).unwrap().condition,
+        PriceCondition::AtLeast(Decimal::from_ratio(3u128, 1u128))
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+
+use mantra_dex_sdk::output::{json_to_yaml, render};
+use mantra_dex_sdk::{Error, OutputFormat};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Balance {
+    wallet: String,
+    denom: String,
+    amount: u128,
+    #[serde(skip)]
+    note: &'static str,
+}
+
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}{} ({})",
+            self.wallet, self.amount, self.denom, self.note
+        )
+    }
+}
+
+fn balance() -> Balance {
+    Balance {
+        wallet: "trader".to_string(),
+        denom: "uom".to_string(),
+        amount: 1_500_000,
+        note: "spendable",
+    }
+}
+
+#[test]
+fn test_output_format_parsing() {
+    assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+    assert_eq!(
+        " JSON ".parse::<OutputFormat>().unwrap(),
+        OutputFormat::Json
+    );
+    assert_eq!("yml".parse::<OutputFormat>().unwrap(), OutputFormat::Yaml);
+    assert!(matches!(
+        "xml".parse::<OutputFormat>(),
+        Err(Error::Config(_))
+    ));
+
+    assert_eq!(OutputFormat::default(), OutputFormat::Text);
+    assert_eq!(OutputFormat::Yaml.to_string(), "yaml");
+    assert!(OutputFormat::Json.is_structured());
+    assert!(!OutputFormat::Text.is_structured());
+}
+
+#[test]
+fn test_render_text_ends_with_newline() {
+    let text = render(&balance(), OutputFormat::Text).unwrap();
+    assert_eq!(text, "trader: 1500000uom (spendable)\n");
+}
+
+#[test]
+fn test_render_json_keeps_field_order() {
+    let json = render(&balance(), OutputFormat::Json).unwrap();
+    assert_eq!(
+        json,
+        "{\n  \"wallet\": \"trader\",\n  \"denom\": \"uom\",\n  \"amount\": 1500000\n}\n"
+    );
+}
+
+#[test]
+fn test_render_yaml() {
+    let yaml = render(&balance(), OutputFormat::Yaml).unwrap();
+    assert_eq!(yaml, "---\nwallet: trader\ndenom: uom\namount: 1500000\n");
+}
+
+#[test]
+fn test_json_to_yaml_nested() {
+    let yaml =
+        json_to_yaml(r#"{"pools": [{"id": "o.uom.uusdc", "fee": "0.003"}], "synced": true}"#)
+            .unwrap();
+    assert_eq!(
+        yaml,
+        "---\npools:\n  - id: o.uom.uusdc\n    fee: \"0.003\"\nsynced: true"
+    );
+
+    assert!(json_to_yaml("[1, 2").is_err());
+}
@@ -0,0 +1,117 @@
+use cosmrs::tendermint::abci::Event;
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_sdk::ownership::{
+    creator_from_events, gini, DenomHolder, LpConcentration, TOP_LP_HOLDERS,
+};
+
+const POOL_MANAGER: &str = "mantra1poolmanager";
+const CREATOR: &str = "mantra1creator";
+
+#[test]
+fn test_creator_from_events_skips_pool_manager() {
+    let events = vec![
+        Event::new(
+            "message",
+            [
+                ("action", "/cosmwasm.wasm.v1.MsgExecuteContract"),
+                ("sender", CREATOR),
+            ],
+        ),
+        Event::new("message", [("sender", POOL_MANAGER)]),
+        Event::new(
+            "create_denom",
+            [
+                ("creator", POOL_MANAGER),
+                (
+                    "new_token_denom",
+                    "factory/mantra1poolmanager/o.uom.uusdc.LP",
+                ),
+            ],
+        ),
+    ];
+    assert_eq!(
+        creator_from_events(&events, POOL_MANAGER).as_deref(),
+        Some(CREATOR)
+    );
+
+    let dispatched_only = vec![Event::new("message", [("sender", POOL_MANAGER)])];
+    assert_eq!(creator_from_events(&dispatched_only, POOL_MANAGER), None);
+}
+
+fn holder(address: &str, amount: u128) -> DenomHolder {
+    DenomHolder {
+        address: address.to_string(),
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn test_lp_concentration() {
+    let holders = vec![
+        holder("mantra1a", 100),
+        holder("mantra1b", 600),
+        holder(POOL_MANAGER, 1_000),
+        holder("mantra1c", 300),
+        holder("mantra1d", 0),
+    ];
+    let concentration =
+        LpConcentration::from_holders(&holders, Uint128::new(2_000), &[POOL_MANAGER], 2);
+
+    // The pool manager and empty balances don't count
+    assert_eq!(concentration.holders, 3);
+    let top: Vec<&str> = concentration
+        .top_holders
+        .iter()
+        .map(|holder| holder.address.as_str())
+        .collect();
+    assert_eq!(top, vec!["mantra1b", "mantra1c"]);
+    assert_eq!(concentration.largest_share(), Decimal::percent(30));
+    assert_eq!(concentration.top_share(), Decimal::percent(45));
+    // Sorted 100, 300, 600: (2 * 2_500 - 4 * 1_000) / (3 * 1_000)
+    assert_eq!(concentration.gini, Decimal::from_ratio(1u128, 3u128));
+}
+
+#[test]
+fn test_lp_concentration_of_empty_pool() {
+    let concentration = LpConcentration::from_holders(&[], Uint128::zero(), &[], 5);
+    assert_eq!(concentration.holders, 0);
+    assert!(concentration.top_holders.is_empty());
+    assert_eq!(concentration.largest_share(), Decimal::zero());
+    assert_eq!(concentration.gini, Decimal::zero());
+}
+
+#[test]
+fn test_gini() {
+    let equal = [Uint128::new(500); 4];
+    assert_eq!(gini(&equal), Decimal::zero());
+    assert_eq!(gini(&[Uint128::new(42)]), Decimal::zero());
+
+    // One holder with everything among n tends to (n - 1) / n
+    let whale = [
+        Uint128::zero(),
+        Uint128::new(1),
+        Uint128::new(1),
+        Uint128::new(1_000_000),
+    ];
+    assert!(gini(&whale) > Decimal::percent(66));
+    assert!(gini(&whale) < Decimal::from_ratio(2u128, 3u128));
+}
+
+#[test]
+fn test_dominant_holder() {
+    let total = Uint128::new(1_000);
+    let dominated = LpConcentration::from_holders(
+        &[holder("mantra1whale", 800), holder("mantra1minnow", 200)],
+        total,
+        &[],
+        TOP_LP_HOLDERS,
+    );
+    let whale = dominated.dominant_holder(Decimal::percent(50)).unwrap();
+    assert_eq!(whale.address, "mantra1whale");
+    assert_eq!(whale.share, Decimal::percent(80));
+    assert!(dominated.dominant_holder(Decimal::percent(90)).is_none());
+
+    // A sole provider isn't reported as dominant
+    let sole = LpConcentration::from_holders(&[holder("mantra1whale", 1_000)], total, &[], 10);
+    assert!(sole.dominant_holder(Decimal::percent(50)).is_none());
+}
@@ -0,0 +1,74 @@
+use mantra_dex_sdk::{pagination::collect_all, Error, PageCursor, Paginated};
+
+/// Pages of the ids 1..=total, as a contract keyed by id would return them
+fn page(cursor: PageCursor, total: u32) -> Result<Paginated<u32>, Error> {
+    let start = cursor
+        .start_after
+        .as_deref()
+        .map_or(1, |key| key.parse::<u32>().unwrap() + 1);
+    let items = (start..=total).take(cursor.limit as usize).collect();
+    Ok(Paginated::from_page(items, cursor, u32::to_string))
+}
+
+#[test]
+fn test_cursor_pages_forward_and_back() {
+    let first = page(PageCursor::first(2), 5).unwrap();
+    assert_eq!(first.items, vec![1, 2]);
+    assert!(first.cursor.is_first());
+    assert!(first.prev_page().is_none());
+
+    let second = page(first.next_page().unwrap().clone(), 5).unwrap();
+    assert_eq!(second.items, vec![3, 4]);
+    let last = page(second.next_page().unwrap().clone(), 5).unwrap();
+    assert_eq!(last.items, vec![5]);
+    assert!(!last.has_next());
+
+    // Going back retraces the pages that led here
+    let back = page(last.prev_page().unwrap(), 5).unwrap();
+    assert_eq!(back.items, vec![3, 4]);
+    assert_eq!(
+        page(back.prev_page().unwrap(), 5).unwrap().items,
+        vec![1, 2]
+    );
+
+    // A cursor started from a known key has no history
+    let resumed = PageCursor::after("2", 2);
+    assert!(resumed.prev().is_none());
+    assert_eq!(page(resumed, 5).unwrap().items, vec![3, 4]);
+}
+
+#[test]
+fn test_filtered_page_keeps_next_cursor() {
+    let page = page(PageCursor::first(3), 10)
+        .unwrap()
+        .retain(|id| id % 2 == 0);
+    assert_eq!(page.items, vec![2]);
+    // The next page still starts after the last id the contract returned
+    assert_eq!(page.next_page().unwrap().start_after.as_deref(), Some("3"));
+}
+
+#[tokio::test]
+async fn test_collect_all_stops_at_limit() {
+    let all = collect_all(PageCursor::first(3), None, |cursor| async move {
+        page(cursor, 7)
+    })
+    .await
+    .unwrap();
+    assert_eq!(all, (1..=7).collect::<Vec<_>>());
+
+    let mut fetched = 0;
+    let some = collect_all(PageCursor::first(3), Some(4), |cursor| {
+        fetched += 1;
+        async move { page(cursor, 100) }
+    })
+    .await
+    .unwrap();
+    assert_eq!(some, vec![1, 2, 3, 4]);
+    assert_eq!(fetched, 2);
+
+    let error = collect_all(PageCursor::default(), None, |_| async {
+        Err::<Paginated<u32>, _>(Error::Other("query failed".to_string()))
+    })
+    .await;
+    assert!(error.is_err());
+}
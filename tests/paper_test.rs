@@ -0,0 +1,150 @@
+mod utils;
+
+use cosmwasm_std::{Coin, Int128, Uint128};
+use mantra_dex_sdk::{convert::PriceBook, error::Error, PaperPortfolio, PaperTradeKind};
+use mantra_dex_std::pool_manager::SimulationResponse;
+use utils::test_utils::om_usdc_pool;
+
+const POOL_ID: &str = "o.uom.uusdc";
+
+fn simulation(return_amount: u128) -> SimulationResponse {
+    SimulationResponse {
+        return_amount: Uint128::new(return_amount),
+        slippage_amount: Uint128::zero(),
+        swap_fee_amount: Uint128::zero(),
+        protocol_fee_amount: Uint128::zero(),
+        burn_fee_amount: Uint128::zero(),
+        extra_fees_amount: Uint128::zero(),
+    }
+}
+
+#[test]
+fn test_swap_moves_virtual_balances() {
+    let mut portfolio = PaperPortfolio::new(vec![Coin::new(1_000u128, "uom")]);
+
+    let trade = portfolio
+        .swap(
+            POOL_ID,
+            Coin::new(100u128, "uom"),
+            "uusdc",
+            &simulation(390),
+        )
+        .unwrap();
+    assert_eq!(trade.kind, PaperTradeKind::Swap);
+    assert_eq!(trade.received, vec![Coin::new(390u128, "uusdc")]);
+
+    assert_eq!(portfolio.balance("uom"), Uint128::new(900));
+    assert_eq!(portfolio.balance("uusdc"), Uint128::new(390));
+    assert_eq!(portfolio.trades().len(), 1);
+    assert_eq!(portfolio.starting_balances(), [Coin::new(1_000u128, "uom")]);
+}
+
+#[test]
+fn test_trade_beyond_balance_is_refused() {
+    let mut portfolio = PaperPortfolio::new(vec![Coin::new(50u128, "uom")]);
+
+    let result = portfolio.swap(
+        POOL_ID,
+        Coin::new(100u128, "uom"),
+        "uusdc",
+        &simulation(390),
+    );
+    assert!(matches!(result, Err(Error::Wallet(_))));
+    assert_eq!(portfolio.balances(), vec![Coin::new(50u128, "uom")]);
+    assert!(portfolio.trades().is_empty());
+}
+
+#[test]
+fn test_provide_and_withdraw_liquidity_at_pool_ratio() {
+    let pool = om_usdc_pool(POOL_ID, 1_100, 4_400);
+    let lp_denom = pool.pool_info.lp_denom.clone();
+    let starting = vec![Coin::new(1_000u128, "uom"), Coin::new(1_000u128, "uusdc")];
+    let mut portfolio = PaperPortfolio::new(starting.clone());
+
+    // 110 uom buys a tenth of the supply; only the matching 440 uusdc is spent
+    let trade = portfolio
+        .provide_liquidity(
+            &pool,
+            &[Coin::new(110u128, "uom"), Coin::new(1_000u128, "uusdc")],
+        )
+        .unwrap();
+    assert_eq!(
+        trade.spent,
+        vec![Coin::new(110u128, "uom"), Coin::new(440u128, "uusdc")]
+    );
+    assert_eq!(portfolio.balance(&lp_denom), Uint128::new(100));
+    assert_eq!(portfolio.balance("uusdc"), Uint128::new(560));
+
+    portfolio
+        .withdraw_liquidity(&pool, Uint128::new(100))
+        .unwrap();
+    assert_eq!(portfolio.balances(), starting);
+    assert!(matches!(
+        portfolio.withdraw_liquidity(&pool, Uint128::new(1)),
+        Err(Error::Wallet(_))
+    ));
+}
+
+#[test]
+fn test_provide_liquidity_needs_every_asset() {
+    let mut portfolio = PaperPortfolio::new(vec![Coin::new(1_000u128, "uom")]);
+    assert!(matches!(
+        portfolio.provide_liquidity(
+            &om_usdc_pool(POOL_ID, 1_100, 4_400),
+            &[Coin::new(110u128, "uom")]
+        ),
+        Err(Error::Other(_))
+    ));
+}
+
+#[test]
+fn test_valuation_measures_pnl_against_holding() {
+    let pool = om_usdc_pool(POOL_ID, 1_100, 4_400);
+    let pools = [pool.clone()];
+    let prices = PriceBook::new(&pools);
+    let mut portfolio = PaperPortfolio::new(vec![
+        Coin::new(1_000u128, "uom"),
+        Coin::new(1_000u128, "uusdc"),
+    ]);
+
+    // Sold 100 uom worth 400 uusdc for 390
+    portfolio
+        .swap(
+            POOL_ID,
+            Coin::new(100u128, "uom"),
+            "uusdc",
+            &simulation(390),
+        )
+        .unwrap();
+    portfolio
+        .provide_liquidity(
+            &pool,
+            &[Coin::new(110u128, "uom"), Coin::new(440u128, "uusdc")],
+        )
+        .unwrap();
+
+    let valuation = portfolio.valuation(&pools, &prices, "uusdc");
+    assert_eq!(valuation.starting_value, Some(Uint128::new(5_000)));
+    assert_eq!(valuation.value, Some(Uint128::new(4_990)));
+    assert_eq!(valuation.pnl, Some(Int128::new(-10)));
+    assert_eq!(valuation.positions.len(), 1);
+    assert_eq!(valuation.positions[0].value, Some(Uint128::new(880)));
+}
+
+#[test]
+fn test_save_and_load_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("paper_portfolio.json");
+    let mut portfolio = PaperPortfolio::new(vec![Coin::new(1_000u128, "uom")]);
+    portfolio
+        .swap(
+            POOL_ID,
+            Coin::new(100u128, "uom"),
+            "uusdc",
+            &simulation(390),
+        )
+        .unwrap();
+
+    portfolio.save(&path).unwrap();
+    assert_eq!(PaperPortfolio::load(&path).unwrap(), portfolio);
+}
@@ -0,0 +1,140 @@
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::{
+    error::{Error, ErrorClass},
+    policy::{DEFAULT_MAX_RESERVE_FRACTION, DEFAULT_MAX_SLIPPAGE_RETRIES},
+    MantraDexClient, MantraNetworkConfig, SlippageBumpPolicy, SwapRequest, TradeSizeGuard,
+    TradeValueLimit,
+};
+
+#[test]
+fn test_trade_size_guard_defaults() {
+    let guard = TradeSizeGuard::default();
+    assert!(guard.enabled);
+    assert_eq!(guard.max_reserve_fraction, DEFAULT_MAX_RESERVE_FRACTION);
+    assert_eq!(DEFAULT_MAX_RESERVE_FRACTION, Decimal::percent(10));
+}
+
+#[test]
+fn test_trade_size_guard_allows_small_trades() {
+    let guard = TradeSizeGuard::default();
+    assert!(guard
+        .check("p1", "uusdc", Uint128::new(100), Uint128::new(1_000))
+        .is_ok());
+}
+
+#[test]
+fn test_trade_size_guard_blocks_large_trades() {
+    let guard = TradeSizeGuard::default();
+    let error = guard
+        .check("p1", "uusdc", Uint128::new(101), Uint128::new(1_000))
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Policy(_)));
+    assert_eq!(error.class(), ErrorClass::PolicyViolation);
+    assert!(error.to_string().contains("p1"));
+}
+
+#[test]
+fn test_trade_size_guard_blocks_empty_reserve() {
+    let guard = TradeSizeGuard::new(Decimal::percent(50));
+    assert!(guard
+        .check("p1", "uusdc", Uint128::zero(), Uint128::zero())
+        .is_err());
+}
+
+#[test]
+fn test_trade_size_guard_custom_and_disabled() {
+    let guard = TradeSizeGuard::new(Decimal::percent(50));
+    assert!(guard
+        .check("p1", "uusdc", Uint128::new(400), Uint128::new(1_000))
+        .is_ok());
+
+    let guard = TradeSizeGuard::disabled();
+    assert!(guard
+        .check("p1", "uusdc", Uint128::new(1_000), Uint128::new(1_000))
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_client_trade_size_guard_builder() {
+    let client = MantraDexClient::new(MantraNetworkConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(*client.trade_size_guard(), TradeSizeGuard::default());
+
+    let client = MantraDexClient::builder(MantraNetworkConfig::default())
+        .with_trade_size_guard(TradeSizeGuard::disabled())
+        .build()
+        .await
+        .unwrap();
+    assert!(!client.trade_size_guard().enabled);
+}
+
+#[test]
+fn test_slippage_bump_steps_up_to_ceiling() {
+    let policy = SlippageBumpPolicy::default();
+    assert_eq!(policy.max_retries, DEFAULT_MAX_SLIPPAGE_RETRIES);
+
+    let next = |previous| policy.next_slippage(previous, Decimal::zero()).unwrap();
+    assert_eq!(next(Decimal::percent(1)), Decimal::percent(2));
+    assert_eq!(next(Decimal::permille(45)), Decimal::percent(5));
+}
+
+#[test]
+fn test_slippage_bump_follows_simulation() {
+    let policy = SlippageBumpPolicy::new(Decimal::permille(5), Decimal::percent(3));
+    assert_eq!(
+        policy
+            .next_slippage(Decimal::percent(1), Decimal::percent(2))
+            .unwrap(),
+        Decimal::percent(2)
+    );
+
+    let error = policy
+        .next_slippage(Decimal::percent(1), Decimal::percent(4))
+        .unwrap_err();
+    assert_eq!(error.class(), ErrorClass::PolicyViolation);
+}
+
+#[test]
+fn test_slippage_bump_refuses_at_ceiling() {
+    let policy = SlippageBumpPolicy::default();
+    let error = policy
+        .next_slippage(Decimal::percent(5), Decimal::zero())
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Policy(_)));
+    assert!(error.to_string().contains("5%"));
+}
+
+#[test]
+fn test_slippage_exceeded_detection() {
+    let error = Error::TxSimulation("Slippage limit exceeded: execute wasm contract failed".into());
+    assert!(error.is_slippage_exceeded());
+    assert!(Error::Contract("Max spread assertion".into()).is_slippage_exceeded());
+
+    // Only chain rejections count, not local validation mentioning slippage
+    assert!(!Error::Other("Invalid slippage exceeded".into()).is_slippage_exceeded());
+    assert!(!Error::Contract("insufficient funds".into()).is_slippage_exceeded());
+}
+
+#[test]
+fn test_swap_request_slippage_builder() {
+    let offer = cosmwasm_std::Coin::new(100u128, "uom");
+    let request = SwapRequest::new("p1", offer, "uusdc");
+    assert_eq!(request.max_slippage, None);
+
+    let request = request.with_max_slippage(Decimal::percent(2));
+    assert_eq!(request.max_slippage, Some(Decimal::percent(2)));
+}
+
+#[test]
+fn test_trade_value_limit() {
+    let limit = TradeValueLimit::new(Uint128::new(100_000_000), "usd");
+    let offer = Coin::new(40_000_000u128, "uom");
+
+    assert!(limit.check(&offer, Uint128::new(100_000_000)).is_ok());
+    let error = limit.check(&offer, Uint128::new(100_000_001)).unwrap_err();
+    assert!(matches!(error, Error::Policy(_)));
+    assert!(error.to_string().contains("40000000uom"));
+}
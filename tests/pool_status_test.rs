@@ -1,6 +1,6 @@
 mod utils;
 
-use mantra_dex_sdk::{client::PoolStatus, Error};
+use mantra_dex_sdk::{client::PoolStatus, Error, PageCursor};
 use utils::test_utils::{create_test_client, get_or_create_test_pool_id};
 
 /// Test pool status enum functionality
@@ -321,7 +321,11 @@ async fn test_multiple_pools_status() {
     let client = create_test_client().await;
 
     // Get list of pools
-    match client.get_pools(Some(5)).await {
+    match client
+        .get_pools(&PageCursor::first(5))
+        .await
+        .map(|page| page.items)
+    {
         Ok(pools) => {
             println!("Found {} pools for status testing", pools.len());
 
@@ -434,7 +438,11 @@ async fn test_pool_status_mapping_available_and_disabled() {
     let client = create_test_client().await;
 
     // Get list of pools to test status mapping
-    match client.get_pools(Some(10)).await {
+    match client
+        .get_pools(&PageCursor::first(10))
+        .await
+        .map(|page| page.items)
+    {
         Ok(pools) => {
             println!("Testing pool status mapping on {} pools", pools.len());
 
@@ -501,7 +509,11 @@ async fn test_pool_status_operation_flags() {
     let client = create_test_client().await;
 
     // Get pools and analyze their operation flags
-    match client.get_pools(Some(10)).await {
+    match client
+        .get_pools(&PageCursor::first(10))
+        .await
+        .map(|page| page.items)
+    {
         Ok(pools) => {
             println!("Analyzing operation flags for {} pools", pools.len());
 
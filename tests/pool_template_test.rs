@@ -0,0 +1,73 @@
+use cosmwasm_std::Decimal;
+use mantra_dex_sdk::mantra_dex_std::pool_manager::PoolType;
+use mantra_dex_sdk::{Error, PoolTemplate};
+
+#[test]
+fn test_template_total_fees() {
+    let totals: Vec<Decimal> = PoolTemplate::ALL
+        .iter()
+        .map(|template| template.spec().total_fee())
+        .collect();
+    assert_eq!(
+        totals,
+        vec![
+            Decimal::from_ratio(1u128, 10_000u128),
+            Decimal::permille(3),
+            Decimal::percent(1),
+        ]
+    );
+    for template in PoolTemplate::ALL {
+        template.spec().pool_fees().unwrap();
+    }
+}
+
+#[test]
+fn test_template_pool_types() {
+    assert_eq!(
+        PoolTemplate::Stable.spec().pool_type,
+        PoolType::StableSwap { amp: 100 }
+    );
+    assert_eq!(
+        PoolTemplate::Exotic.spec().pool_type,
+        PoolType::ConstantProduct
+    );
+}
+
+#[test]
+fn test_template_names() {
+    for template in PoolTemplate::ALL {
+        assert_eq!(
+            template.to_string().parse::<PoolTemplate>().unwrap(),
+            template
+        );
+    }
+    assert_eq!(
+        "Volatile".parse::<PoolTemplate>().unwrap(),
+        PoolTemplate::Volatile
+    );
+    assert!(matches!(
+        "curve".parse::<PoolTemplate>(),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_template_overrides_are_validated() {
+    let spec = PoolTemplate::Volatile
+        .spec()
+        .with_swap_fee(Decimal::percent(1))
+        .with_pool_type(PoolType::StableSwap { amp: 50 });
+    assert_eq!(spec.total_fee(), Decimal::from_ratio(106u128, 10_000u128));
+    let pool_fees = spec.pool_fees().unwrap();
+    assert_eq!(pool_fees.swap_fee.share, Decimal::percent(1));
+    assert_eq!(
+        pool_fees.protocol_fee.share,
+        Decimal::from_ratio(6u128, 10_000u128)
+    );
+
+    let excessive = spec.with_burn_fee(Decimal::percent(19));
+    assert!(matches!(
+        excessive.pool_fees(),
+        Err(Error::FeeValidation(_))
+    ));
+}
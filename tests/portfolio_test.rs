@@ -0,0 +1,97 @@
+mod utils;
+
+use chrono::Utc;
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::{convert::PriceBook, positions::lp_positions, PortfolioValuation};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use utils::test_utils::pool;
+
+/// 1 uom = 4 uusdc, and a pool the wallet holds no LP tokens of
+fn pools() -> Vec<PoolInfoResponse> {
+    vec![
+        pool(
+            "o.uom.uusdc",
+            PoolType::ConstantProduct,
+            &[("uom", 1_100, 6), ("uusdc", 4_400, 6)],
+            Decimal::permille(3),
+        ),
+        pool(
+            "o.uom.uatom",
+            PoolType::ConstantProduct,
+            &[("uom", 1_000, 6), ("uatom", 500, 6)],
+            Decimal::permille(3),
+        ),
+    ]
+}
+
+fn valuation(pools: &[PoolInfoResponse]) -> PortfolioValuation {
+    let prices = PriceBook::new(pools);
+    let balances = vec![
+        Coin::new(250u128, "uom"),
+        Coin::new(100u128, "uusdc"),
+        Coin::new(100u128, "factory/pool/o.uom.uusdc.LP"),
+        Coin::new(7u128, "ibc/UNLISTED"),
+    ];
+    let positions = lp_positions(pools, &balances, &[], &[], &prices, "uusdc");
+    PortfolioValuation::new(
+        "mantra1holder",
+        balances,
+        positions,
+        vec![Coin::new(50u128, "uom")],
+        &prices,
+        "uusdc",
+        Utc::now(),
+    )
+}
+
+#[test]
+fn test_portfolio_values_balances_positions_and_rewards() {
+    let pools = pools();
+    let portfolio = valuation(&pools);
+
+    // 250 uom at 4 uusdc and 100 uusdc
+    assert_eq!(portfolio.balances_value(), Uint128::new(1_100));
+    // A tenth of the pool: 110 uom and 440 uusdc
+    assert_eq!(portfolio.positions.len(), 1);
+    assert_eq!(portfolio.positions[0].pool_id, "o.uom.uusdc");
+    assert_eq!(portfolio.positions_value(), Uint128::new(880));
+    assert_eq!(portfolio.rewards_value(), Uint128::new(200));
+    assert_eq!(portfolio.total_value(), Uint128::new(2_180));
+}
+
+#[test]
+fn test_portfolio_counts_lp_tokens_once() {
+    let pools = pools();
+    let portfolio = valuation(&pools);
+
+    // The LP tokens are valued by their position, not as a balance
+    assert!(portfolio
+        .balances
+        .iter()
+        .all(|holding| !holding.coin.denom.starts_with("factory/pool/")));
+    assert_eq!(portfolio.balances.len(), 3);
+}
+
+#[test]
+fn test_portfolio_lists_unpriced_holdings() {
+    let pools = pools();
+    let portfolio = valuation(&pools);
+
+    assert_eq!(portfolio.unpriced(), vec!["ibc/UNLISTED"]);
+    let unlisted = portfolio
+        .balances
+        .iter()
+        .find(|holding| holding.coin.denom == "ibc/UNLISTED")
+        .unwrap();
+    assert_eq!(unlisted.value, None);
+
+    // Without the uom/uusdc pool nothing but uusdc is priced, and the LP
+    // tokens of a pool that isn't listed are a plain balance
+    let portfolio = valuation(&pools[1..]);
+    assert_eq!(portfolio.balances_value(), Uint128::new(100));
+    assert!(portfolio.positions.is_empty());
+    assert_eq!(
+        portfolio.unpriced(),
+        vec!["factory/pool/o.uom.uusdc.LP", "ibc/UNLISTED", "uom"]
+    );
+}
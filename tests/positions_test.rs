@@ -0,0 +1,296 @@
+mod utils;
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use cosmwasm_std::{Addr, Coin, Decimal, Int128, Uint128};
+use mantra_dex_sdk::{
+    bridge::DexEvent,
+    convert::PriceBook,
+    epoch::EpochCalendar,
+    error::Error,
+    gas::GasEstimate,
+    history::{HistoryEntry, OperationType},
+    positions::{
+        lp_position, position_openings, FarmPosition, LiquidityChange, LpBalance, LpLock,
+        WithdrawalPreview,
+    },
+};
+use mantra_dex_std::farm_manager::Position;
+use utils::test_utils::om_usdc_pool;
+
+const POOL_ID: &str = "o.uom.uusdc";
+
+fn event(height: u64, attributes: &[(&str, &str)]) -> DexEvent {
+    let attributes: BTreeMap<String, String> = attributes
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .chain([("pool_identifier".to_string(), POOL_ID.to_string())])
+        .collect();
+    DexEvent {
+        height,
+        tx_hash: format!("HASH{}", height),
+        contract: "mantra1poolmanager".to_string(),
+        event_type: "wasm".to_string(),
+        action: attributes.get("action").cloned(),
+        attributes,
+    }
+}
+
+/// 300 uom and 300 uusdc provided at a 1:1 price for 150 LP tokens, then 50 withdrawn
+fn changes() -> Vec<LiquidityChange> {
+    [
+        event(
+            1,
+            &[
+                ("action", "provide_liquidity"),
+                ("assets", "200uom, 200uusdc"),
+                ("share", "100"),
+            ],
+        ),
+        event(
+            2,
+            &[
+                ("action", "provide_liquidity"),
+                ("assets", "100uom, 100uusdc"),
+                ("share", "50"),
+            ],
+        ),
+        event(
+            3,
+            &[
+                ("action", "withdraw_liquidity"),
+                ("refund_assets", "60uom, 210uusdc"),
+                ("withdrawn_share", "50"),
+            ],
+        ),
+        event(4, &[("action", "swap"), ("offer_amount", "10")]),
+    ]
+    .iter()
+    .filter_map(LiquidityChange::from_event)
+    .collect()
+}
+
+#[test]
+fn test_liquidity_changes_are_read_from_events() {
+    let changes = changes();
+    assert_eq!(changes.len(), 3);
+    assert!(changes[0].provided);
+    assert_eq!(
+        changes[0].assets,
+        vec![Coin::new(200u128, "uom"), Coin::new(200u128, "uusdc")]
+    );
+    assert!(!changes[2].provided);
+    assert_eq!(changes[2].lp_amount, Uint128::new(50));
+}
+
+#[test]
+fn test_position_splits_pnl_into_impermanent_loss_and_fees() {
+    let pool = om_usdc_pool(POOL_ID, 1_100, 4_400);
+    let pools = [pool.clone()];
+    let prices = PriceBook::new(&pools);
+    let position = lp_position(&pool, Uint128::new(100), &changes(), &prices, "uusdc");
+
+    assert_eq!(position.share, Decimal::percent(10));
+    assert_eq!(
+        position.assets,
+        vec![Coin::new(110u128, "uom"), Coin::new(440u128, "uusdc")]
+    );
+    // The withdrawal burned a third of the LP tokens and of the cost basis
+    assert_eq!(
+        position.deposited,
+        Some(vec![Coin::new(200u128, "uom"), Coin::new(200u128, "uusdc")])
+    );
+    // 1 uom = 4 uusdc now
+    assert_eq!(position.value, Some(Uint128::new(880)));
+    assert_eq!(position.deposit_value, Some(Uint128::new(1_000)));
+    // A fourfold price move loses a fifth against holding
+    assert_eq!(position.impermanent_loss, Some(Decimal::percent(20)));
+    // Without fees the position would be worth 800
+    assert_eq!(position.fees_earned, Some(Uint128::new(80)));
+    assert_eq!(position.pnl(), Some(Int128::new(-120)));
+}
+
+#[test]
+fn test_position_without_full_history_has_no_cost_basis() {
+    let pool = om_usdc_pool(POOL_ID, 1_100, 4_400);
+    let pools = [pool.clone()];
+    let prices = PriceBook::new(&pools);
+    // More LP tokens are held than the history accounts for
+    let position = lp_position(&pool, Uint128::new(150), &changes(), &prices, "uusdc");
+    assert_eq!(position.deposited, None);
+    assert_eq!(position.value, Some(Uint128::new(1_320)));
+    assert_eq!(position.impermanent_loss, None);
+    assert_eq!(position.pnl(), None);
+}
+
+fn farm_position(identifier: &str, amount: u128, open: bool, expiring_at: Option<u64>) -> Position {
+    Position {
+        identifier: identifier.to_string(),
+        lp_asset: Coin::new(amount, "factory/pool/uLP"),
+        unlocking_duration: 86_400,
+        open,
+        expiring_at,
+        receiver: Addr::unchecked("mantra1owner"),
+    }
+}
+
+#[test]
+fn test_lp_locks_report_when_they_unlock() {
+    let open = LpLock::from_position(&farm_position("u-1", 40, true, Some(1_000)));
+    assert!(!open.is_unlocking());
+    assert_eq!(
+        open.unlock_status(0),
+        "locked while the farm position is open, then for 1 day"
+    );
+
+    let closed = LpLock::from_position(&farm_position("u-2", 60, false, Some(1_700_000_000)));
+    assert!(closed.is_unlocking());
+    assert!(!closed.is_unlocked(1_699_999_999));
+    assert_eq!(closed.unlock_status(0), "unlocks at 2023-11-14 22:13 UTC");
+    assert!(closed.is_unlocked(1_700_000_000));
+}
+
+#[test]
+fn test_withdrawals_beyond_the_liquid_balance_are_refused() {
+    let balance = LpBalance {
+        lp_denom: "factory/pool/uLP".to_string(),
+        liquid: Uint128::new(50),
+        locks: vec![
+            LpLock::from_position(&farm_position("u-1", 40, true, None)),
+            LpLock::from_position(&farm_position("u-2", 60, false, Some(1_700_000_000))),
+        ],
+    };
+    assert_eq!(balance.locked(), Uint128::new(100));
+    assert_eq!(balance.total(), Uint128::new(150));
+    assert!(balance.check_withdrawal(Uint128::new(50)).is_ok());
+
+    match balance.check_withdrawal(Uint128::new(51)) {
+        Err(Error::Wallet(message)) => assert_eq!(
+            message,
+            "Cannot withdraw 51factory/pool/uLP: only 50 is liquid, 100 is locked in farm \
+             positions; the next unlocks at 2023-11-14 22:13 UTC"
+        ),
+        other => panic!("expected a wallet error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_withdrawal_preview_refunds_the_share_of_the_reserves() {
+    let pool = om_usdc_pool(POOL_ID, 1_100, 4_400);
+
+    // A third of the LP supply, rounded down like the pool manager does
+    let preview = WithdrawalPreview::new(&pool, Uint128::new(333)).unwrap();
+    assert_eq!(preview.pool_id, POOL_ID);
+    assert_eq!(
+        preview.lp_amount,
+        Coin::new(333u128, format!("factory/pool/{}.LP", POOL_ID))
+    );
+    assert_eq!(preview.share, Decimal::permille(333));
+    assert_eq!(
+        preview.assets,
+        vec![Coin::new(366u128, "uom"), Coin::new(1_465u128, "uusdc")]
+    );
+    assert_eq!(preview.gas, None);
+    assert_eq!(preview.net_amount("uom"), Int128::new(366));
+
+    // The transaction fee comes out of the refund paid in its denom
+    let preview = preview.with_gas(GasEstimate {
+        kind: "withdraw_liquidity".to_string(),
+        simulated: 200_000,
+        gas_limit: 280_000,
+        fee: Coin::new(7u128, "uom"),
+    });
+    assert_eq!(preview.net_amount("uom"), Int128::new(359));
+    assert_eq!(preview.net_amount("uusdc"), Int128::new(1_465));
+    assert_eq!(preview.net_amount("uatom"), Int128::zero());
+
+    // Each asset is rounded down on its own
+    let dust = WithdrawalPreview::new(&pool, Uint128::one()).unwrap();
+    assert_eq!(
+        dust.assets,
+        vec![Coin::new(1u128, "uom"), Coin::new(4u128, "uusdc")]
+    );
+
+    let all = WithdrawalPreview::new(&pool, Uint128::new(1_000)).unwrap();
+    assert_eq!(all.share, Decimal::one());
+    assert_eq!(all.assets, pool.pool_info.assets);
+
+    assert!(WithdrawalPreview::new(&pool, Uint128::zero()).is_none());
+    assert!(WithdrawalPreview::new(&pool, Uint128::new(1_001)).is_none());
+}
+
+#[test]
+fn test_position_splits_liquid_and_locked_tokens() {
+    let pool = om_usdc_pool(POOL_ID, 1_100, 4_400);
+    let pools = [pool.clone()];
+    let prices = PriceBook::new(&pools);
+    let position =
+        lp_position(&pool, Uint128::new(150), &changes(), &prices, "uusdc").with_locks(vec![
+            LpLock::from_position(&farm_position("u-1", 100, true, None)),
+        ]);
+    assert_eq!(position.lp_amount, Uint128::new(150));
+    assert_eq!(position.locked_amount, Uint128::new(100));
+    assert_eq!(position.liquid_amount(), Uint128::new(50));
+    assert_eq!(position.locks.len(), 1);
+}
+
+#[test]
+fn test_farm_positions_show_their_start_and_unlock_epochs() {
+    // Daily epochs, epoch 100 starting at 1,700,000,000
+    let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    let calendar = EpochCalendar::new(100, start, 86_400);
+    let opened = event(
+        10,
+        &[("action", "open_position"), ("position_identifier", "u-1")],
+    );
+    let history = [
+        HistoryEntry {
+            height: 10,
+            tx_hash: "HASH10".to_string(),
+            timestamp: DateTime::<Utc>::from_timestamp(1_700_000_000 + 2 * 86_400 + 60, 0),
+            success: true,
+            operation: OperationType::Farm,
+            pool_id: None,
+            events: vec![opened],
+        },
+        HistoryEntry {
+            height: 11,
+            tx_hash: "HASH11".to_string(),
+            timestamp: DateTime::<Utc>::from_timestamp(1_700_000_000, 0),
+            success: false,
+            operation: OperationType::Farm,
+            pool_id: None,
+            events: vec![event(
+                11,
+                &[("action", "open_position"), ("position_identifier", "u-2")],
+            )],
+        },
+    ];
+    let openings = position_openings(&history);
+    assert_eq!(openings.keys().collect::<Vec<_>>(), ["u-1"]);
+
+    let open = FarmPosition::new(
+        &farm_position("u-1", 40, true, None),
+        &calendar,
+        openings.get("u-1").copied(),
+    );
+    assert_eq!(open.start_epoch, Some(102));
+    assert_eq!(open.unlock_epoch, None);
+    assert_eq!(open.status(start), "open");
+
+    let unlocks_at = 1_700_000_000 + 5 * 86_400;
+    let closed = FarmPosition::new(
+        &farm_position("u-2", 60, false, Some(unlocks_at)),
+        &calendar,
+        None,
+    );
+    assert_eq!(closed.start_epoch, None);
+    assert_eq!(closed.unlock_epoch, Some(105));
+    assert_eq!(closed.status(start), "unlocking");
+    assert_eq!(
+        closed.status(DateTime::<Utc>::from_timestamp(unlocks_at as i64, 0).unwrap()),
+        "unlocked"
+    );
+}
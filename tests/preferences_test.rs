@@ -0,0 +1,110 @@
+use cosmwasm_std::Decimal;
+use mantra_dex_sdk::config::Config;
+use mantra_dex_sdk::preferences::{Preferences, PreferencesStore, Theme};
+use mantra_dex_sdk::wallet::WalletDefaults;
+
+#[test]
+fn test_set_and_unset_preferences() {
+    let mut preferences = Preferences::default();
+
+    preferences.set("default_slippage", "0.01").unwrap();
+    preferences.set("display_denom", "usd").unwrap();
+    preferences.set("theme", "high_contrast").unwrap();
+    preferences.set("pool_refresh_secs", "15").unwrap();
+    preferences
+        .set("favorite_pools", r#"["p.1", "p.2"]"#)
+        .unwrap();
+    preferences.set("expert_mode.enabled", "true").unwrap();
+    assert_eq!(preferences.default_slippage, Some(Decimal::percent(1)));
+    assert_eq!(preferences.display_denom.as_deref(), Some("usd"));
+    assert_eq!(preferences.theme, Theme::HighContrast);
+    assert_eq!(preferences.pool_refresh_secs, 15);
+    assert_eq!(preferences.favorite_pools, vec!["p.1", "p.2"]);
+    assert!(preferences.expert_mode.enabled);
+
+    preferences.unset("default_slippage").unwrap();
+    preferences.unset("expert_mode.enabled").unwrap();
+    assert_eq!(preferences.default_slippage, None);
+    assert!(!preferences.expert_mode.enabled);
+
+    // Unknown keys and values of the wrong type leave the preferences as they were
+    let before = preferences.clone();
+    assert!(preferences.set("colour", "blue").is_err());
+    assert!(preferences.set("pool_refresh_secs", "often").is_err());
+    assert!(preferences.set("theme", "neon").is_err());
+    assert_eq!(preferences, before);
+}
+
+#[test]
+fn test_favorites_and_wallet_defaults() {
+    let mut preferences = Preferences {
+        default_slippage: Some(Decimal::percent(2)),
+        ..Preferences::default()
+    };
+
+    assert!(preferences.toggle_favorite("p.1"));
+    assert!(preferences.is_favorite("p.1"));
+    assert!(!preferences.toggle_favorite("p.1"));
+    assert!(preferences.favorite_pools.is_empty());
+
+    // The wallet's own slippage wins over the preference
+    let filled = preferences.fill_wallet_defaults(WalletDefaults::default());
+    assert_eq!(filled.slippage, Some(Decimal::percent(2)));
+    let own = WalletDefaults {
+        slippage: Some(Decimal::percent(5)),
+        ..WalletDefaults::default()
+    };
+    assert_eq!(
+        preferences.fill_wallet_defaults(own).slippage,
+        Some(Decimal::percent(5))
+    );
+}
+
+#[test]
+fn test_store_migrates_legacy_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let legacy = dir.path().join("config.toml");
+    let mut content = toml::to_string(&Config::default()).unwrap();
+    content.push_str(
+        "\n[expert_mode]\nenabled = true\nmax_trade_value_usd = 20.0\n\
+         max_price_impact_percent = 1.0\n",
+    );
+    std::fs::write(&legacy, content).unwrap();
+
+    let path = dir.path().join("preferences.toml");
+    let store = PreferencesStore::open(&path, &legacy).unwrap();
+    assert!(store.get().expert_mode.enabled);
+    assert_eq!(store.get().expert_mode.max_trade_value_usd, 20.0);
+    assert!(path.exists());
+
+    // Saving the config again drops the migrated settings
+    let config = Config::load(&legacy).unwrap();
+    assert!(!toml::to_string(&config).unwrap().contains("expert_mode"));
+}
+
+#[test]
+fn test_store_shares_changes_between_processes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("preferences.toml");
+    let missing = dir.path().join("config.toml");
+
+    let mut cli = PreferencesStore::open(&path, &missing).unwrap();
+    let mut tui = PreferencesStore::open(&path, &missing).unwrap();
+    let mut changes = tui.subscribe();
+    assert_eq!(tui.get(), &Preferences::default());
+    assert!(!path.exists());
+
+    assert!(cli
+        .update(|preferences| {
+            preferences.toggle_favorite("p.1");
+        })
+        .unwrap());
+    assert!(!cli
+        .update(|preferences| preferences.decimal_precision = 6)
+        .unwrap());
+
+    assert!(tui.reload().unwrap());
+    assert_eq!(tui.get().favorite_pools, vec!["p.1"]);
+    assert_eq!(changes.try_recv().unwrap().favorite_pools, vec!["p.1"]);
+    assert!(!tui.reload().unwrap());
+}
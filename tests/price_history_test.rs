@@ -0,0 +1,197 @@
+mod utils;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use cosmwasm_std::Decimal;
+use mantra_dex_sdk::config::PriceHistoryConfig;
+use mantra_dex_sdk::price_history::{self, PricePoint};
+use mantra_dex_sdk::{MemoryStorage, PriceHistory, SharedStorage};
+use utils::test_utils::om_usdc_pool;
+
+fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 10, 17, hour, minute, 0).unwrap()
+}
+
+fn settings(interval_secs: u64, retention_days: u64) -> PriceHistoryConfig {
+    PriceHistoryConfig {
+        interval_secs: Some(interval_secs),
+        retention_days: Some(retention_days),
+        pools: Vec::new(),
+    }
+}
+
+#[test]
+fn test_record_samples_each_pool_once_per_interval() {
+    let mut history = PriceHistory::new().with_settings(&settings(300, 7));
+    assert_eq!(history.interval(), Duration::from_secs(300));
+
+    let pools = [
+        om_usdc_pool("o.uom.uusdc", 1_000, 2_000),
+        om_usdc_pool("o.empty", 0, 0),
+    ];
+    // The empty pool can't be priced
+    assert_eq!(history.record(&pools, at(12, 0)).unwrap(), 1);
+    assert_eq!(history.record(&pools, at(12, 4)).unwrap(), 0);
+    assert_eq!(history.record(&pools, at(12, 5)).unwrap(), 1);
+
+    let series = history.series("o.uom.uusdc").unwrap();
+    assert_eq!(
+        (series.base.as_str(), series.quote.as_str()),
+        ("uom", "uusdc")
+    );
+    assert_eq!(
+        series.points,
+        vec![
+            PricePoint {
+                at: at(12, 0),
+                price: Decimal::from_ratio(2u128, 1u128),
+            },
+            PricePoint {
+                at: at(12, 5),
+                price: Decimal::from_ratio(2u128, 1u128),
+            },
+        ]
+    );
+    assert_eq!(history.pool_ids(), vec!["o.uom.uusdc"]);
+}
+
+#[test]
+fn test_record_only_configured_pools_and_drops_old_samples() {
+    let mut history = PriceHistory::new().with_settings(&PriceHistoryConfig {
+        pools: vec!["o.uom.uusdc".to_string()],
+        ..settings(60, 1)
+    });
+    let pools = [
+        om_usdc_pool("o.uom.uusdc", 1_000, 2_000),
+        om_usdc_pool("o.other", 1, 1),
+    ];
+    history.record(&pools, at(0, 0)).unwrap();
+    assert_eq!(history.pool_ids(), vec!["o.uom.uusdc"]);
+
+    // A day later the first sample is past the retention
+    let next_day = at(0, 0) + chrono::Duration::days(1) + chrono::Duration::minutes(1);
+    history.record(&pools, next_day).unwrap();
+    let points = &history.series("o.uom.uusdc").unwrap().points;
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].at, next_day);
+}
+
+#[test]
+fn test_candles_aggregate_ohlc_in_either_direction() {
+    let mut history = PriceHistory::new().with_settings(&settings(60, 7));
+    // uom at 2, 4, 1 and 5 uusdc, the last in the next hour
+    for (time, usdc_reserve) in [
+        (at(12, 10), 2_000),
+        (at(12, 20), 4_000),
+        (at(12, 50), 1_000),
+        (at(13, 5), 5_000),
+    ] {
+        history
+            .record(&[om_usdc_pool("o.uom.uusdc", 1_000, usdc_reserve)], time)
+            .unwrap();
+    }
+
+    let hourly = history
+        .candles(
+            "o.uom.uusdc",
+            "uom",
+            at(0, 0),
+            at(23, 0),
+            Duration::from_secs(3_600),
+        )
+        .unwrap();
+    assert_eq!(hourly.len(), 2);
+    assert_eq!(hourly[0].start, at(12, 0));
+    assert_eq!(
+        (
+            hourly[0].open,
+            hourly[0].high,
+            hourly[0].low,
+            hourly[0].close
+        ),
+        (
+            Decimal::from_ratio(2u128, 1u128),
+            Decimal::from_ratio(4u128, 1u128),
+            Decimal::one(),
+            Decimal::one()
+        )
+    );
+    assert_eq!(hourly[0].samples, 3);
+    assert_eq!(hourly[1].start, at(13, 0));
+    assert_eq!(hourly[1].close, Decimal::from_ratio(5u128, 1u128));
+
+    // Priced the other way, over a window leaving out the first sample
+    let inverted = history
+        .candles(
+            "o.uom.uusdc",
+            "uusdc",
+            at(12, 15),
+            at(13, 0),
+            Duration::from_secs(3_600),
+        )
+        .unwrap();
+    assert_eq!(inverted.len(), 1);
+    assert_eq!(inverted[0].open, Decimal::percent(25));
+    assert_eq!(inverted[0].high, Decimal::one());
+    assert_eq!(inverted[0].samples, 2);
+
+    assert!(history
+        .candles(
+            "o.uom.uusdc",
+            "uatom",
+            at(0, 0),
+            at(23, 0),
+            Duration::from_secs(60)
+        )
+        .is_err());
+    assert!(history
+        .candles(
+            "o.missing",
+            "uom",
+            at(0, 0),
+            at(23, 0),
+            Duration::from_secs(60)
+        )
+        .is_err());
+}
+
+#[test]
+fn test_candles_skip_periods_without_samples() {
+    let points: Vec<PricePoint> = [(at(10, 0), 1u64), (at(10, 30), 3), (at(12, 45), 2)]
+        .into_iter()
+        .map(|(at, price)| PricePoint {
+            at,
+            price: Decimal::from_ratio(price, 1u128),
+        })
+        .collect();
+    let candles = price_history::candles(&points, Duration::from_secs(3_600));
+    assert_eq!(
+        candles
+            .iter()
+            .map(|candle| (candle.start, candle.samples))
+            .collect::<Vec<_>>(),
+        vec![(at(10, 0), 2), (at(12, 0), 1)]
+    );
+}
+
+#[test]
+fn test_history_is_shared_through_storage() {
+    let storage: SharedStorage = Arc::new(MemoryStorage::new());
+    let mut recorder = PriceHistory::open_in(storage.clone()).unwrap();
+    recorder
+        .record(&[om_usdc_pool("o.uom.uusdc", 1_000, 3_000)], at(9, 0))
+        .unwrap();
+
+    let mut viewer = PriceHistory::open_in(storage.clone()).unwrap();
+    assert_eq!(viewer.series("o.uom.uusdc").unwrap().points.len(), 1);
+    assert_eq!(storage.keys().unwrap(), vec![PriceHistory::STORAGE_KEY]);
+
+    // A later sample by another handle shows up after a reload
+    recorder
+        .record(&[om_usdc_pool("o.uom.uusdc", 1_000, 3_000)], at(10, 0))
+        .unwrap();
+    viewer.reload().unwrap();
+    assert_eq!(viewer.series("o.uom.uusdc").unwrap().points.len(), 2);
+}
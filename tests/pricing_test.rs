@@ -0,0 +1,84 @@
+mod utils;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::pricing::{self, impact_level};
+use mantra_dex_sdk::risk::RiskLevel;
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use utils::test_utils::pool;
+
+/// 1 OM = 2 USDC
+fn om_usdc() -> PoolInfoResponse {
+    pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uom", 1_000_000, 6), ("uusdc", 2_000_000, 6)],
+        Decimal::percent(1),
+    )
+}
+
+#[test]
+fn test_constant_product_price_impact() {
+    let pool = om_usdc();
+    assert_eq!(
+        pricing::spot_price(&pool, "uom", "uusdc"),
+        Some(Decimal::percent(200))
+    );
+    assert_eq!(pricing::spot_price(&pool, "uom", "uatom"), None);
+
+    // 1% of the reserve returns 19_801 before fees against 20_000 at the spot price
+    let small = pricing::price_impact(&pool, &Coin::new(10_000u128, "uom"), "uusdc").unwrap();
+    assert_eq!(small.price_impact, Decimal::from_ratio(199u128, 20_000u128));
+    assert_eq!(small.return_amount + small.fee_amount, Uint128::new(19_801));
+    assert_eq!(
+        small.execution_price,
+        Decimal::from_ratio(small.return_amount, 10_000u128)
+    );
+    assert!(small.execution_price < small.spot_price);
+    assert_eq!(small.level(), None);
+    assert!(small.warning().is_none());
+
+    // 10% of the reserve moves the price a lot
+    let large = pricing::price_impact(&pool, &Coin::new(100_000u128, "uom"), "uusdc").unwrap();
+    assert_eq!(large.price_impact, Decimal::from_ratio(18_182u128, 200_000u128));
+    assert_eq!(large.level(), Some(RiskLevel::Danger));
+    assert!(large.warning().unwrap().starts_with("Very high price impact: 9.09%"));
+
+    assert!(pricing::price_impact(&pool, &Coin::new(0u128, "uom"), "uusdc").is_none());
+}
+
+#[test]
+fn test_stable_swap_spot_price() {
+    let balanced = pool(
+        "p1",
+        PoolType::StableSwap { amp: 100 },
+        &[("uusdc", 1_000_000_000_000, 6), ("uusdt", 1_000_000_000_000, 6)],
+        Decimal::percent(1),
+    );
+    let spot = pricing::spot_price(&balanced, "uusdc", "uusdt").unwrap();
+    assert!(spot.abs_diff(Decimal::one()) < Decimal::from_ratio(1u128, 1_000_000u128));
+
+    // A stable pool absorbs a trade of 1% of its reserves with little impact
+    let impact = pricing::price_impact(
+        &balanced,
+        &Coin::new(10_000_000_000u128, "uusdc"),
+        "uusdt",
+    )
+    .unwrap();
+    assert!(impact.price_impact < Decimal::permille(1));
+
+    // An imbalanced pool prices its scarce asset above par
+    let imbalanced = pool(
+        "p1",
+        PoolType::StableSwap { amp: 100 },
+        &[("uusdc", 1_900_000_000_000, 6), ("uusdt", 100_000_000_000, 6)],
+        Decimal::percent(1),
+    );
+    assert!(pricing::spot_price(&imbalanced, "uusdc", "uusdt").unwrap() < Decimal::one());
+}
+
+#[test]
+fn test_impact_levels() {
+    assert_eq!(impact_level(Decimal::permille(5)), None);
+    assert_eq!(impact_level(Decimal::percent(1)), Some(RiskLevel::Caution));
+    assert_eq!(impact_level(Decimal::percent(5)), Some(RiskLevel::Danger));
+}
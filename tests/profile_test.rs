@@ -0,0 +1,121 @@
+use mantra_dex_sdk::config::Config;
+use mantra_dex_sdk::preferences::Preferences;
+use mantra_dex_sdk::profile::{Profile, ProfilePaths};
+use mantra_dex_sdk::wallet::WalletStorage;
+use mantra_dex_sdk::SecretString;
+use std::path::Path;
+
+const ARCHIVE_PASSWORD: &str = "Archive-Passw0rd!";
+const WALLET_PASSWORD: &str = "Wallet-Passw0rd!";
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+                        abandon abandon abandon abandon abandon about";
+
+fn paths(root: &Path) -> ProfilePaths {
+    ProfilePaths {
+        config: root.join("config.toml"),
+        preferences: root.join("preferences.toml"),
+        wallets: root.join("wallets"),
+    }
+}
+
+fn set_up(root: &Path) -> ProfilePaths {
+    let paths = paths(root);
+    let config = Config {
+        mnemonic: Some(SecretString::new(MNEMONIC.to_string())),
+        ..Config::default()
+    };
+    config.save(&paths.config).unwrap();
+    let mut preferences = Preferences::default();
+    preferences.toggle_favorite("p.1");
+    preferences.save(&paths.preferences).unwrap();
+    WalletStorage::with_directory(paths.wallets.clone())
+        .unwrap()
+        .save_wallet("main", MNEMONIC, WALLET_PASSWORD, "mantra1main")
+        .unwrap();
+    paths
+}
+
+#[test]
+fn test_profile_round_trip() {
+    let source = tempfile::tempdir().unwrap();
+    let target = tempfile::tempdir().unwrap();
+    let archive = source.path().join("profile.mdx");
+
+    let profile = Profile::collect(&set_up(source.path()), true).unwrap();
+    assert!(profile.config.is_some());
+    assert_eq!(profile.wallets.len(), 1);
+    profile.export(&archive, ARCHIVE_PASSWORD).unwrap();
+
+    // Nothing readable is left in the archive
+    let content = std::fs::read_to_string(&archive).unwrap();
+    assert!(!content.contains("p.1") && !content.contains("mantra1main"));
+    assert!(Profile::import(&archive, "Wrong-Passw0rd!").is_err());
+
+    let imported = Profile::import(&archive, ARCHIVE_PASSWORD).unwrap();
+    let target_paths = paths(target.path());
+    let summary = imported.restore(&target_paths, false).unwrap();
+    assert_eq!(
+        summary.restored,
+        vec!["configuration", "preferences", "wallet 'main'"]
+    );
+    assert!(summary.skipped.is_empty());
+
+    let preferences = Preferences::load(&target_paths.preferences).unwrap();
+    assert_eq!(preferences.favorite_pools, vec!["p.1"]);
+    let storage = WalletStorage::with_directory(target_paths.wallets.clone()).unwrap();
+    assert_eq!(
+        storage
+            .load_wallet("main", WALLET_PASSWORD)
+            .unwrap()
+            .expose_secret(),
+        MNEMONIC
+    );
+
+    // A second import leaves what is already there unless told otherwise
+    let again = imported.restore(&target_paths, false).unwrap();
+    assert!(again.restored.is_empty());
+    assert_eq!(again.skipped.len(), 3);
+    let overwritten = imported.restore(&target_paths, true).unwrap();
+    assert_eq!(overwritten.restored.len(), 3);
+}
+
+#[test]
+fn test_profile_without_wallets() {
+    let source = tempfile::tempdir().unwrap();
+    let profile = Profile::collect(&set_up(source.path()), false).unwrap();
+    assert!(profile.wallets.is_empty());
+
+    // The configured mnemonic goes with the wallets
+    assert!(profile.config.as_ref().unwrap().mnemonic.is_none());
+    let json = serde_json::to_string(&profile).unwrap();
+    assert!(!json.contains(MNEMONIC));
+
+    // Nothing saved yet gives an empty profile
+    let empty = tempfile::tempdir().unwrap();
+    let profile = Profile::collect(&paths(empty.path()), true).unwrap();
+    assert!(profile.config.is_none() && profile.preferences.is_none());
+    assert!(profile.wallets.is_empty());
+}
+
+#[test]
+fn test_import_wallet_rejects_unsafe_names() {
+    let source = tempfile::tempdir().unwrap();
+    let paths = set_up(source.path());
+    let storage = WalletStorage::with_directory(paths.wallets).unwrap();
+    let wallet = storage.export_wallet("main").unwrap();
+
+    assert!(storage
+        .import_wallet("../escape", wallet.clone(), true)
+        .is_err());
+    assert!(!storage
+        .import_wallet("main", wallet.clone(), false)
+        .unwrap());
+    assert!(storage.import_wallet("copy", wallet, false).unwrap());
+    let names: Vec<String> = storage
+        .list_wallets()
+        .unwrap()
+        .into_iter()
+        .map(|metadata| metadata.name)
+        .collect();
+    assert!(names.contains(&"copy".to_string()));
+}
@@ -0,0 +1,93 @@
+use chrono::{Duration, TimeZone, Utc};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::{Error, FeeBreakdown, MantraWallet, Quote};
+use mantra_dex_std::pool_manager::SimulationResponse;
+
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+const ASK: &str = "factory/mantra1x/uusdc";
+
+fn quote() -> Quote {
+    let quoted_at = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+    let simulation = SimulationResponse {
+        return_amount: Uint128::new(1_000_000),
+        slippage_amount: Uint128::new(2_000),
+        swap_fee_amount: Uint128::new(3_000),
+        protocol_fee_amount: Uint128::new(1_000),
+        burn_fee_amount: Uint128::zero(),
+        extra_fees_amount: Uint128::zero(),
+    };
+    Quote {
+        pool_id: "o.uom.uusdc".to_string(),
+        offer_asset: Coin::new(2_000_000u128, "uom"),
+        ask_asset_denom: ASK.to_string(),
+        expected_return: simulation.return_amount,
+        fees: FeeBreakdown::from_simulation(&simulation, ASK),
+        max_slippage: None,
+        price_tolerance: Decimal::percent(1),
+        quoted_at,
+        quoted_height: 100,
+        valid_until: quoted_at + Duration::seconds(30),
+        valid_until_height: 105,
+        signer: None,
+        signature: None,
+    }
+}
+
+#[test]
+fn test_quote_fee_breakdown() {
+    let quote = quote();
+    assert_eq!(quote.fees.total_fees(), Uint128::new(4_000));
+    assert_eq!(quote.fees.slippage, Coin::new(2_000u128, ASK));
+}
+
+#[test]
+fn test_quote_expiry() {
+    let quote = quote();
+    let now = quote.quoted_at + Duration::seconds(10);
+    assert!(quote.check_expiry(now, 105).is_ok());
+    // Either limit expires the quote
+    assert!(quote.is_expired(now, 106));
+    assert!(quote.is_expired(quote.valid_until + Duration::seconds(1), 101));
+    assert!(matches!(
+        quote.check_expiry(now, 106),
+        Err(Error::Policy(_))
+    ));
+}
+
+#[test]
+fn test_quote_price_tolerance() {
+    let quote = quote();
+    assert_eq!(quote.min_return(), Uint128::new(990_000));
+    assert!(quote.check_price(Uint128::new(990_000)).is_ok());
+    assert!(quote.check_price(Uint128::new(1_200_000)).is_ok());
+    assert!(matches!(
+        quote.check_price(Uint128::new(989_999)),
+        Err(Error::Policy(_))
+    ));
+}
+
+#[test]
+fn test_quote_signature() {
+    let wallet = MantraWallet::from_mnemonic(MNEMONIC, 0).unwrap();
+    let other = MantraWallet::from_mnemonic(MNEMONIC, 1).unwrap();
+
+    let mut quote = quote();
+    assert!(quote.verify(&wallet).is_err());
+    quote.sign(&wallet).unwrap();
+    assert_eq!(
+        quote.signer.as_deref(),
+        Some(wallet.address().unwrap().as_ref())
+    );
+    quote.verify(&wallet).unwrap();
+    assert!(quote.verify(&other).is_err());
+
+    // A quote survives a round trip through JSON, but not tampering
+    let json = serde_json::to_string(&quote).unwrap();
+    let parsed: Quote = serde_json::from_str(&json).unwrap();
+    parsed.verify(&wallet).unwrap();
+
+    let mut tampered = parsed;
+    tampered.valid_until_height += 100;
+    assert!(matches!(tampered.verify(&wallet), Err(Error::Policy(_))));
+}
@@ -0,0 +1,37 @@
+use mantra_dex_sdk::RateLimiter;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_rate_limiter_allows_a_burst_then_refills() {
+    // One request a second, bursts of three
+    let limiter = RateLimiter::new(60, 3).unwrap();
+    let start = Instant::now();
+
+    for _ in 0..3 {
+        assert!(limiter.check_at("client-a", start).is_ok());
+    }
+    let wait = limiter.check_at("client-a", start).unwrap_err();
+    assert!(wait > Duration::from_millis(900) && wait <= Duration::from_secs(1));
+
+    // Other clients have their own bucket
+    assert!(limiter.check_at("client-b", start).is_ok());
+    assert_eq!(limiter.tracked_clients(), 2);
+
+    // A second later one more request is allowed, and only one
+    let later = start + Duration::from_secs(1);
+    assert!(limiter.check_at("client-a", later).is_ok());
+    assert!(limiter.check_at("client-a", later).is_err());
+
+    // Idle time never builds up more than a burst
+    let much_later = start + Duration::from_secs(3600);
+    for _ in 0..3 {
+        assert!(limiter.check_at("client-a", much_later).is_ok());
+    }
+    assert!(limiter.check_at("client-a", much_later).is_err());
+}
+
+#[test]
+fn test_rate_limiter_rejects_zero_limits() {
+    assert!(RateLimiter::new(0, 10).is_err());
+    assert!(RateLimiter::new(60, 0).is_err());
+}
@@ -0,0 +1,142 @@
+mod utils;
+
+use chrono::{NaiveTime, TimeZone, Utc, Weekday};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::report::{
+    PortfolioSnapshot, PortfolioSummary, ReportFormat, ReportSchedule, DEFAULT_MARKDOWN_TEMPLATE,
+};
+use utils::test_utils::om_usdc_pool;
+
+fn balances() -> Vec<Coin> {
+    vec![
+        Coin::new(1_000u128, "uom"),
+        Coin::new(500u128, "uusdc"),
+        Coin::new(7u128, "uunknown"),
+    ]
+}
+
+#[test]
+fn test_report_schedule_parsing() {
+    let eight = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+    assert_eq!(
+        "daily@08:00".parse::<ReportSchedule>().unwrap(),
+        ReportSchedule::Daily(eight)
+    );
+    assert_eq!(
+        "weekly@08:00".parse::<ReportSchedule>().unwrap(),
+        ReportSchedule::Weekly(Weekday::Mon, eight)
+    );
+    assert_eq!(
+        "Fri@17:30".parse::<ReportSchedule>().unwrap(),
+        ReportSchedule::Weekly(Weekday::Fri, NaiveTime::from_hms_opt(17, 30, 0).unwrap())
+    );
+    assert!("daily".parse::<ReportSchedule>().is_err());
+    assert!("daily@25:00".parse::<ReportSchedule>().is_err());
+    assert!("monthly@08:00".parse::<ReportSchedule>().is_err());
+}
+
+#[test]
+fn test_report_schedule_next_after() {
+    // A Wednesday
+    let now = Utc.with_ymd_and_hms(2024, 5, 15, 9, 0, 0).unwrap();
+
+    let daily: ReportSchedule = "daily@08:00".parse().unwrap();
+    assert_eq!(
+        daily.next_after(now),
+        Utc.with_ymd_and_hms(2024, 5, 16, 8, 0, 0).unwrap()
+    );
+    let later_today: ReportSchedule = "daily@18:00".parse().unwrap();
+    assert_eq!(
+        later_today.next_after(now),
+        Utc.with_ymd_and_hms(2024, 5, 15, 18, 0, 0).unwrap()
+    );
+
+    let friday: ReportSchedule = "fri@08:00".parse().unwrap();
+    assert_eq!(
+        friday.next_after(now),
+        Utc.with_ymd_and_hms(2024, 5, 17, 8, 0, 0).unwrap()
+    );
+    // Already past this week's slot
+    let wednesday: ReportSchedule = "wed@08:00".parse().unwrap();
+    assert_eq!(
+        wednesday.next_after(now),
+        Utc.with_ymd_and_hms(2024, 5, 22, 8, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_summary_values_holdings_and_price_moves() {
+    let yesterday = Utc.with_ymd_and_hms(2024, 5, 14, 8, 0, 0).unwrap();
+    let today = Utc.with_ymd_and_hms(2024, 5, 15, 8, 0, 0).unwrap();
+
+    // 1 OM = 2 USDC, then 1 OM = 2.2 USDC
+    let previous = PortfolioSnapshot::new(
+        balances(),
+        &[om_usdc_pool("p1", 1_000_000, 2_000_000)],
+        "uusdc",
+        yesterday,
+    );
+    let current = PortfolioSnapshot::new(
+        balances(),
+        &[om_usdc_pool("p1", 1_000_000, 2_200_000)],
+        "uusdc",
+        today,
+    );
+    assert_eq!(previous.total_value(), Uint128::new(2_500));
+    assert_eq!(current.total_value(), Uint128::new(2_700));
+    assert_eq!(current.holdings[2].value, None);
+
+    let summary = PortfolioSummary::new(
+        "mantra1holder",
+        current.clone(),
+        Some(&previous),
+        vec![Coin::new(42u128, "uom")],
+        Decimal::percent(5),
+    );
+    assert_eq!(summary.previous_value, Some(Uint128::new(2_500)));
+    assert_eq!(summary.price_moves.len(), 1);
+    assert_eq!(summary.price_moves[0].denom, "uom");
+    assert_eq!(summary.price_moves[0].change(), Decimal::percent(10));
+    assert!(summary.price_moves[0].is_rise());
+
+    // A 10% move is below a 20% threshold
+    let quiet = PortfolioSummary::new(
+        "mantra1holder",
+        current,
+        Some(&previous),
+        vec![],
+        Decimal::percent(20),
+    );
+    assert!(quiet.price_moves.is_empty());
+}
+
+#[test]
+fn test_summary_rendering() {
+    let today = Utc.with_ymd_and_hms(2024, 5, 15, 8, 0, 0).unwrap();
+    let snapshot = PortfolioSnapshot::new(
+        balances(),
+        &[om_usdc_pool("p1", 1_000_000, 2_000_000)],
+        "uusdc",
+        today,
+    );
+    let summary = PortfolioSummary::new(
+        "mantra1holder",
+        snapshot,
+        None,
+        vec![Coin::new(42u128, "uom")],
+        Decimal::percent(5),
+    );
+
+    let markdown = summary.render(ReportFormat::Markdown, DEFAULT_MARKDOWN_TEMPLATE);
+    assert!(markdown.starts_with("# Portfolio summary for mantra1holder\n"));
+    assert!(markdown.contains("**Total value:** 2500 uusdc"));
+    assert!(markdown.contains("n/a (first summary)"));
+    assert!(markdown.contains("- 1000 uom (2000 uusdc)"));
+    assert!(markdown.contains("- 7 uunknown (unpriced)"));
+    assert!(markdown.contains("- 42 uom"));
+    assert!(markdown.contains("_No notable price moves_"));
+    assert!(!markdown.contains("{{"));
+
+    let html = summary.render(ReportFormat::Html, "<b>{{address}}</b>{{rewards}}");
+    assert_eq!(html, "<b>mantra1holder</b><ul>\n<li>42 uom</li>\n</ul>");
+}
@@ -0,0 +1,185 @@
+mod utils;
+
+use cosmwasm_std::Decimal;
+use mantra_dex_sdk::{
+    ownership::{DenomHolder, LpConcentration},
+    risk::{concentration_flags, liquidity_flags, DenomMetadata, DenomProbe},
+    RiskFlag, RiskLevel, RiskReport, RiskThresholds,
+};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use utils::test_utils::pool;
+
+const SCAM: &str = "factory/mantra1creator/scam";
+
+fn pool_with_depth() -> PoolInfoResponse {
+    pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uom", 5_000_000_000, 6), ("uusdc", 5_000_000_000, 6)],
+        Decimal::zero(),
+    )
+}
+
+#[test]
+fn test_liquidity_flags_use_asset_decimals() {
+    let thresholds = RiskThresholds::default();
+
+    // 5000 OM but only 500 of an 18-decimal token
+    let shallow = pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[
+            ("uom", 5_000_000_000, 6),
+            ("aeth", 500 * 10u128.pow(18), 18),
+        ],
+        Decimal::zero(),
+    );
+    assert_eq!(
+        liquidity_flags(&shallow, &thresholds),
+        vec![RiskFlag::LowLiquidity {
+            pool_id: "p1".to_string(),
+            denom: "aeth".to_string(),
+            reserve: (500 * 10u128.pow(18)).into(),
+        }]
+    );
+
+    let deep = pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[
+            ("uom", 5_000_000_000, 6),
+            ("aeth", 5_000 * 10u128.pow(18), 18),
+        ],
+        Decimal::zero(),
+    );
+    assert!(liquidity_flags(&deep, &thresholds).is_empty());
+}
+
+#[test]
+fn test_denom_probe_flags() {
+    let thresholds = RiskThresholds::default();
+
+    let missing = DenomProbe {
+        metadata: DenomMetadata::Missing,
+        age_blocks: Some(10),
+        ..DenomProbe::unchecked(SCAM)
+    };
+    assert_eq!(
+        missing.flags(&thresholds),
+        vec![
+            RiskFlag::MissingMetadata {
+                denom: SCAM.to_string()
+            },
+            RiskFlag::NewDenom {
+                denom: SCAM.to_string(),
+                age_blocks: 10
+            },
+        ]
+    );
+
+    // Claiming a reserved symbol is dangerous, whatever its case
+    let impersonating = DenomProbe {
+        metadata: DenomMetadata::Present {
+            symbol: "usdc".to_string(),
+        },
+        age_blocks: Some(thresholds.new_denom_blocks),
+        ..DenomProbe::unchecked(SCAM)
+    };
+    let flags = impersonating.flags(&thresholds);
+    assert_eq!(flags.len(), 1);
+    assert_eq!(flags[0].level(), RiskLevel::Danger);
+
+    let established = DenomProbe {
+        metadata: DenomMetadata::Present {
+            symbol: "MEME".to_string(),
+        },
+        age_blocks: Some(thresholds.new_denom_blocks * 2),
+        ..DenomProbe::unchecked(SCAM)
+    };
+    assert!(established.flags(&thresholds).is_empty());
+    assert!(DenomProbe::unchecked("uom").flags(&thresholds).is_empty());
+}
+
+#[test]
+fn test_pool_report_combines_flags() {
+    let thresholds = RiskThresholds::default();
+    let pool = pool(
+        "p1",
+        PoolType::ConstantProduct,
+        &[("uom", 5_000_000_000, 6), (SCAM, 100_000_000, 6)],
+        Decimal::zero(),
+    );
+    let probes = [
+        DenomProbe::unchecked("uom"),
+        DenomProbe {
+            denylisted: true,
+            ..DenomProbe::unchecked(SCAM)
+        },
+    ];
+
+    let report = RiskReport::for_pool(&pool, &probes, Some(1), &thresholds);
+    assert_eq!(report.level(), Some(RiskLevel::Danger));
+    assert_eq!(
+        report.warnings(),
+        vec![
+            format!("{} is denylisted", SCAM),
+            "Pool p1 has a single liquidity provider".to_string(),
+            format!("Pool p1 holds only 100000000 {} (base units)", SCAM),
+        ]
+    );
+
+    let clean = RiskReport::for_pool(
+        &pool_with_depth(),
+        &[DenomProbe::unchecked("uom")],
+        Some(12),
+        &thresholds,
+    );
+    assert!(clean.is_clean());
+    assert_eq!(clean.level(), None);
+}
+
+#[test]
+fn test_dominant_liquidity_provider_flag() {
+    let thresholds = RiskThresholds::default();
+    let holders = [
+        DenomHolder {
+            address: "mantra1whale".to_string(),
+            amount: 725u128.into(),
+        },
+        DenomHolder {
+            address: "mantra1minnow".to_string(),
+            amount: 275u128.into(),
+        },
+    ];
+    let concentration = LpConcentration::from_holders(&holders, 1_000u128.into(), &[], 10);
+
+    let flags = concentration_flags("p1", &concentration, &thresholds);
+    assert_eq!(flags.len(), 1);
+    assert_eq!(flags[0].level(), RiskLevel::Caution);
+    assert_eq!(
+        flags[0].to_string(),
+        "mantra1whale holds 72.5% of pool p1's liquidity; its withdrawal would drain the pool"
+    );
+
+    // Without the whale, the minnow holds 27.5% next to another provider
+    let spread_holders = [
+        holders[1].clone(),
+        DenomHolder {
+            address: "mantra1other".to_string(),
+            amount: 275u128.into(),
+        },
+    ];
+    let spread = LpConcentration::from_holders(&spread_holders, 1_000u128.into(), &[], 10);
+    assert!(concentration_flags("p1", &spread, &thresholds).is_empty());
+}
+
+#[test]
+fn test_risk_thresholds_deserialize_with_defaults() {
+    let thresholds: RiskThresholds = toml::from_str("min_reserve = 10").unwrap();
+    assert_eq!(thresholds.min_reserve, 10);
+    assert_eq!(
+        thresholds.new_denom_blocks,
+        RiskThresholds::default().new_denom_blocks
+    );
+    assert!(thresholds.reserved_symbols.contains(&"OM".to_string()));
+}
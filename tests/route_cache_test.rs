@@ -0,0 +1,69 @@
+mod utils;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::route_cache::{size_bucket, RouteCache, RouteCacheConfig, RouteKey};
+use utils::test_utils::om_usdc_pool;
+
+fn key(amount: u128) -> RouteKey {
+    RouteKey::new(&Coin::new(amount, "uom"), "uusdc")
+}
+
+#[test]
+fn test_swaps_of_similar_size_share_a_route() {
+    assert_eq!(size_bucket(Uint128::zero()), 0);
+    assert_eq!(size_bucket(Uint128::new(1)), 1);
+    assert_eq!(size_bucket(Uint128::new(1_024)), 11);
+    assert_eq!(size_bucket(Uint128::new(2_047)), 11);
+    assert_eq!(key(1_500), key(1_900));
+    assert_ne!(key(1_500), key(2_048));
+    assert_ne!(
+        key(1_500),
+        RouteKey::new(&Coin::new(1_500u128, "uusdc"), "uom")
+    );
+
+    let cache = RouteCache::new(RouteCacheConfig::default());
+    cache.insert(key(1_500), &om_usdc_pool("p1", 1_000_000, 4_000_000), None);
+    assert_eq!(cache.get(&key(1_900)).unwrap().pool_id, "p1");
+    assert_eq!(cache.get(&key(4_096)), None);
+}
+
+#[test]
+fn test_routes_are_dropped_when_their_pool_moves() {
+    let cache = RouteCache::new(RouteCacheConfig {
+        reserve_threshold: Decimal::percent(1),
+        ..RouteCacheConfig::default()
+    });
+    cache.insert(key(1_500), &om_usdc_pool("p1", 1_000_000, 4_000_000), None);
+    cache.insert(key(5_000), &om_usdc_pool("p1", 1_000_000, 4_000_000), None);
+    cache.insert(
+        key(100_000),
+        &om_usdc_pool("p2", 1_000_000, 4_000_000),
+        Some("p2".to_string()),
+    );
+    assert_eq!(cache.len(), 3);
+
+    // Moves within the threshold keep the routes
+    assert_eq!(cache.observe(&om_usdc_pool("p1", 1_010_000, 3_960_400)), 0);
+    assert_eq!(cache.len(), 3);
+
+    // A larger move drops every route through the pool, and only those
+    assert_eq!(cache.observe(&om_usdc_pool("p1", 1_020_000, 3_921_600)), 2);
+    assert_eq!(cache.get(&key(1_500)), None);
+    let route = cache.get(&key(100_000)).unwrap();
+    assert_eq!(route.pinned_pool.as_deref(), Some("p2"));
+    assert_eq!(cache.pools().into_iter().collect::<Vec<_>>(), ["p2"]);
+
+    cache.invalidate_pool("p2");
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_expired_routes_are_not_served() {
+    let cache = RouteCache::new(RouteCacheConfig {
+        ttl_secs: 0,
+        ..RouteCacheConfig::default()
+    });
+    cache.insert(key(1_500), &om_usdc_pool("p1", 1_000_000, 4_000_000), None);
+    assert_eq!(cache.get(&key(1_500)), None);
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,170 @@
+mod utils;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::router::{self, AssetGraph, DEFAULT_MAX_HOPS};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType, SwapOperation};
+use utils::test_utils::pool;
+
+/// OM trades against USDC directly in a shallow pool, and through ATOM in deep ones
+fn pools() -> Vec<PoolInfoResponse> {
+    vec![
+        pool(
+            "om.usdc",
+            PoolType::ConstantProduct,
+            &[("uom", 100_000, 6), ("uusdc", 200_000, 6)],
+            Decimal::percent(1),
+        ),
+        pool(
+            "om.atom",
+            PoolType::ConstantProduct,
+            &[("uom", 100_000_000, 6), ("uatom", 100_000_000, 6)],
+            Decimal::percent(1),
+        ),
+        pool(
+            "atom.usdc",
+            PoolType::ConstantProduct,
+            &[("uatom", 100_000_000, 6), ("uusdc", 200_000_000, 6)],
+            Decimal::percent(1),
+        ),
+    ]
+}
+
+#[test]
+fn test_direct_route_for_small_swaps() {
+    let pools = pools();
+    let graph = AssetGraph::new(&pools);
+    assert_eq!(
+        graph.denoms().collect::<Vec<_>>(),
+        vec!["uatom", "uom", "uusdc"]
+    );
+
+    let route = router::find_best_route(
+        &graph,
+        &Coin::new(100u128, "uom"),
+        "uusdc",
+        DEFAULT_MAX_HOPS,
+    )
+    .unwrap();
+    assert_eq!(route.path(), vec!["uom", "uusdc"]);
+    assert_eq!(route.hops.len(), 1);
+    assert_eq!(route.return_amount, route.hops[0].return_amount);
+    assert_eq!(route.price_impact, route.hops[0].price_impact);
+}
+
+#[test]
+fn test_multi_hop_route_through_deeper_pools() {
+    let pools = pools();
+    let graph = AssetGraph::new(&pools);
+    let offer = Coin::new(50_000u128, "uom");
+
+    let route = router::find_best_route(&graph, &offer, "uusdc", DEFAULT_MAX_HOPS).unwrap();
+    assert_eq!(route.path(), vec!["uom", "uatom", "uusdc"]);
+    assert_eq!(route.offer_asset, offer);
+    assert_eq!(
+        route.hops[1].offer_asset.amount,
+        route.hops[0].return_amount
+    );
+    assert_eq!(route.return_amount, route.hops[1].return_amount);
+    assert_eq!(
+        route.operations(),
+        vec![
+            SwapOperation::MantraSwap {
+                token_in_denom: "uom".to_string(),
+                token_out_denom: "uatom".to_string(),
+                pool_identifier: "om.atom".to_string(),
+            },
+            SwapOperation::MantraSwap {
+                token_in_denom: "uatom".to_string(),
+                token_out_denom: "uusdc".to_string(),
+                pool_identifier: "atom.usdc".to_string(),
+            },
+        ]
+    );
+
+    // The direct pool is the only option when routes may not go through another denom
+    let direct = router::find_best_route(&graph, &offer, "uusdc", 1).unwrap();
+    assert_eq!(direct.path(), vec!["uom", "uusdc"]);
+    assert!(direct.return_amount < route.return_amount);
+    assert!(direct.price_impact > route.price_impact);
+}
+
+#[test]
+fn test_candidate_routes_ranked_by_output() {
+    let pools = pools();
+    let graph = AssetGraph::new(&pools);
+    let offer = Coin::new(50_000u128, "uom");
+
+    let routes = router::find_routes(&graph, &offer, "uusdc", DEFAULT_MAX_HOPS, 5);
+    let paths: Vec<Vec<&str>> = routes.iter().map(|route| route.path()).collect();
+    assert_eq!(
+        paths,
+        vec![vec!["uom", "uatom", "uusdc"], vec!["uom", "uusdc"]]
+    );
+    assert!(routes[0].return_amount > routes[1].return_amount);
+    assert_eq!(
+        Some(&routes[0]),
+        router::find_best_route(&graph, &offer, "uusdc", DEFAULT_MAX_HOPS).as_ref()
+    );
+    assert!(routes[1].warning().is_some(), "the direct pool is shallow");
+
+    let best = router::find_routes(&graph, &offer, "uusdc", DEFAULT_MAX_HOPS, 1);
+    assert_eq!(best.len(), 1);
+    assert_eq!(best[0].path(), vec!["uom", "uatom", "uusdc"]);
+}
+
+#[test]
+fn test_minimum_receive() {
+    let pools = pools();
+    let graph = AssetGraph::new(&pools);
+    let route = router::find_best_route(
+        &graph,
+        &Coin::new(50_000u128, "uom"),
+        "uusdc",
+        DEFAULT_MAX_HOPS,
+    )
+    .unwrap();
+
+    assert_eq!(route.minimum_receive(Decimal::zero()), route.return_amount);
+    assert_eq!(
+        route.minimum_receive(Decimal::percent(1)),
+        route.return_amount.mul_floor(Decimal::percent(99))
+    );
+    assert_eq!(route.minimum_receive(Decimal::one()), Uint128::zero());
+}
+
+#[test]
+fn test_no_route() {
+    let mut pools = pools();
+    pools.push(pool(
+        "osmo.usdt",
+        PoolType::ConstantProduct,
+        &[("uosmo", 1_000_000, 6), ("uusdt", 1_000_000, 6)],
+        Decimal::percent(1),
+    ));
+    let graph = AssetGraph::new(&pools);
+    let offer = Coin::new(1_000u128, "uom");
+
+    assert!(router::find_best_route(&graph, &offer, "uusdt", DEFAULT_MAX_HOPS).is_none());
+    assert!(router::find_best_route(&graph, &offer, "uom", DEFAULT_MAX_HOPS).is_none());
+    assert!(router::find_best_route(&graph, &offer, "uusdc", 0).is_none());
+    assert!(
+        router::find_best_route(&graph, &Coin::new(0u128, "uom"), "uusdc", DEFAULT_MAX_HOPS)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_pools_with_swaps_disabled_are_skipped() {
+    let mut pools = pools();
+    pools[1].pool_info.status.swaps_enabled = false;
+    let graph = AssetGraph::new(&pools);
+
+    let route = router::find_best_route(
+        &graph,
+        &Coin::new(50_000u128, "uom"),
+        "uusdc",
+        DEFAULT_MAX_HOPS,
+    )
+    .unwrap();
+    assert_eq!(route.path(), vec!["uom", "uusdc"]);
+}
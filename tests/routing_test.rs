@@ -0,0 +1,289 @@
+mod utils;
+
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_sdk::{
+    mantra_dex_std::{fee::Fee, pool_manager::SimulationResponse},
+    routing::{
+        best_preferred_quote, best_quote, choose_route, plan_split, pool_has_pair, pool_liquidity,
+        pool_matches_definition, rank_quotes, PoolCurve,
+    },
+    DepthCurve, PinnedRoute, PoolQuote, PoolSelection, PoolType, RoutingConfig,
+};
+use utils::test_utils::om_usdc_pool;
+
+fn quote(pool_id: &str, return_amount: u128, ask_liquidity: u128) -> PoolQuote {
+    PoolQuote {
+        pool_id: pool_id.to_string(),
+        simulation: SimulationResponse {
+            return_amount: Uint128::new(return_amount),
+            slippage_amount: Uint128::zero(),
+            swap_fee_amount: Uint128::zero(),
+            protocol_fee_amount: Uint128::zero(),
+            burn_fee_amount: Uint128::zero(),
+            extra_fees_amount: Uint128::zero(),
+        },
+        ask_liquidity: Uint128::new(ask_liquidity),
+    }
+}
+
+#[test]
+fn test_pool_has_pair() {
+    let pool = om_usdc_pool("o.uom.uusdc", 1_000, 2_000);
+
+    assert!(pool_has_pair(&pool, "uom", "uusdc"));
+    assert!(pool_has_pair(&pool, "uusdc", "uom"));
+    assert!(!pool_has_pair(&pool, "uom", "uatom"));
+    assert!(!pool_has_pair(&pool, "uom", "uom"));
+}
+
+#[test]
+fn test_pool_liquidity() {
+    let pool = om_usdc_pool("o.uom.uusdc", 1_000, 2_000);
+
+    assert_eq!(pool_liquidity(&pool, "uusdc"), Uint128::new(2_000));
+    assert_eq!(pool_liquidity(&pool, "uatom"), Uint128::zero());
+}
+
+#[test]
+fn test_best_quote_prefers_highest_output() {
+    let quotes = vec![quote("p1", 90, 10_000), quote("p2", 100, 1_000)];
+    assert_eq!(best_quote(&quotes).unwrap().pool_id, "p2");
+}
+
+#[test]
+fn test_best_quote_breaks_ties_on_liquidity_then_order() {
+    let quotes = vec![
+        quote("p1", 100, 1_000),
+        quote("p2", 100, 5_000),
+        quote("p3", 100, 5_000),
+    ];
+    assert_eq!(best_quote(&quotes).unwrap().pool_id, "p2");
+    assert!(best_quote(&[]).is_none());
+}
+
+#[test]
+fn test_rank_quotes() {
+    let quotes = vec![
+        quote("p1", 90, 1_000),
+        quote("p2", 100, 1_000),
+        quote("p3", 100, 5_000),
+    ];
+    let ids = |ranked: Vec<PoolQuote>| -> Vec<String> {
+        ranked.into_iter().map(|quote| quote.pool_id).collect()
+    };
+
+    assert_eq!(ids(rank_quotes(&quotes, None)), ["p3", "p2", "p1"]);
+    // A chosen pool leads even with a lower output
+    assert_eq!(ids(rank_quotes(&quotes, Some("p1"))), ["p1", "p3", "p2"]);
+    assert_eq!(ids(rank_quotes(&quotes, Some("p9"))), ["p3", "p2", "p1"]);
+}
+
+#[test]
+fn test_best_preferred_quote() {
+    let quotes = vec![
+        quote("p1", 90, 1_000),
+        quote("p2", 100, 1_000),
+        quote("p3", 95, 1_000),
+    ];
+
+    let preferred = vec!["p1".to_string(), "p3".to_string()];
+    assert_eq!(
+        best_preferred_quote(&quotes, &preferred).unwrap().pool_id,
+        "p3"
+    );
+
+    // Falls back to every pool when no preferred pool has a quote
+    let preferred = vec!["p9".to_string()];
+    assert_eq!(
+        best_preferred_quote(&quotes, &preferred).unwrap().pool_id,
+        "p2"
+    );
+    assert_eq!(best_preferred_quote(&quotes, &[]).unwrap().pool_id, "p2");
+}
+
+#[test]
+fn test_choose_route_honors_exclusions_and_pins() {
+    let quotes = vec![
+        quote("p1", 90, 1_000),
+        quote("p2", 100, 1_000),
+        quote("p3", 95, 1_000),
+    ];
+
+    // Excluded pools are never picked
+    let mut routing = RoutingConfig {
+        excluded_pools: vec!["p2".to_string()],
+        ..RoutingConfig::default()
+    };
+    let route = choose_route(&quotes, &routing, &[], "uom", "uusdc").unwrap();
+    assert_eq!(route.quote.pool_id, "p3");
+    assert!(!route.pin_is_stale());
+
+    // A pinned pool is used in either direction, reporting the better pool
+    routing.excluded_pools.clear();
+    routing.pinned_routes.push(PinnedRoute {
+        denom_a: "uusdc".to_string(),
+        denom_b: "uom".to_string(),
+        pool_id: "p3".to_string(),
+    });
+    let route = choose_route(&quotes, &routing, &[], "uom", "uusdc").unwrap();
+    assert_eq!(route.quote.pool_id, "p3");
+    assert!(route.is_pinned() && route.pin_is_stale());
+    assert_eq!(route.better.unwrap().pool_id, "p2");
+
+    // Still optimal once the better pool is excluded
+    routing.excluded_pools.push("p2".to_string());
+    let route = choose_route(&quotes, &routing, &[], "uom", "uusdc").unwrap();
+    assert!(route.is_pinned() && !route.pin_is_stale());
+
+    // A pinned pool that can't quote falls back to the best remaining one
+    routing.pinned_routes[0].pool_id = "p9".to_string();
+    let route = choose_route(&quotes, &routing, &[], "uom", "uusdc").unwrap();
+    assert_eq!(route.quote.pool_id, "p3");
+    assert!(!route.is_pinned() && route.pin_is_stale());
+
+    // Pins only apply to their pair
+    let route = choose_route(&quotes, &routing, &[], "uom", "uatom").unwrap();
+    assert!(route.pinned_pool.is_none() && !route.pin_is_stale());
+
+    routing.excluded_pools = vec!["p1".into(), "p2".into(), "p3".into()];
+    assert!(choose_route(&quotes, &routing, &[], "uom", "uusdc").is_none());
+}
+
+#[test]
+fn test_pool_selection_defaults_to_best() {
+    assert_eq!(PoolSelection::default(), PoolSelection::Best);
+    assert_eq!(
+        PoolSelection::pool("p1"),
+        PoolSelection::Pool("p1".to_string())
+    );
+}
+
+fn curve(pool_id: &str, returns: &[u128]) -> PoolCurve {
+    PoolCurve {
+        pool_id: pool_id.to_string(),
+        returns: returns.iter().map(|r| Uint128::new(*r)).collect(),
+    }
+}
+
+#[test]
+fn test_plan_split_spreads_across_pools() {
+    // Two identical pools with diminishing returns: an even split is best
+    let curves = vec![
+        curve("p1", &[100, 190, 270, 340]),
+        curve("p2", &[100, 190, 270, 340]),
+    ];
+    let plan = plan_split("uom", "uusdc", Uint128::new(1_000), 4, &curves);
+
+    assert_eq!(plan.legs.len(), 2);
+    assert_eq!(plan.total_offer, Uint128::new(1_000));
+    assert!(plan
+        .legs
+        .iter()
+        .all(|leg| leg.offer_amount == Uint128::new(500)));
+    assert_eq!(plan.expected_return(), Uint128::new(380));
+    assert_eq!(plan.single_pool_return, Uint128::new(340));
+    assert_eq!(plan.improvement(), Uint128::new(40));
+}
+
+#[test]
+fn test_plan_split_keeps_small_orders_in_one_pool() {
+    // A deep pool with near-linear returns takes everything
+    let curves = vec![
+        curve("deep", &[100, 200, 300]),
+        curve("shallow", &[90, 150, 180]),
+    ];
+    let plan = plan_split("uom", "uusdc", Uint128::new(1_000), 3, &curves);
+
+    assert_eq!(plan.legs.len(), 1);
+    assert_eq!(plan.legs[0].pool_id, "deep");
+    // Rounding leftovers are assigned to the largest leg
+    assert_eq!(plan.legs[0].offer_amount, Uint128::new(1_000));
+    assert_eq!(plan.improvement(), Uint128::zero());
+}
+
+#[test]
+fn test_plan_split_respects_short_curves() {
+    // p2 could only quote one chunk, p1 none beyond two
+    let curves = vec![curve("p1", &[100, 150]), curve("p2", &[80])];
+    let plan = plan_split("uom", "uusdc", Uint128::new(900), 4, &curves);
+
+    assert_eq!(plan.total_offer, Uint128::new(675));
+    assert_eq!(plan.expected_return(), Uint128::new(230));
+    assert_eq!(plan.single_pool_return, Uint128::zero());
+
+    let plan = plan.with_max_slippage(Decimal::percent(1));
+    assert!(plan
+        .legs
+        .iter()
+        .all(|leg| leg.max_slippage == Some(Decimal::percent(1))));
+}
+
+#[test]
+fn test_pool_matches_definition() {
+    let existing = om_usdc_pool("o.uom.uusdc", 1_000, 2_000);
+    let fees = existing.pool_info.pool_fees.clone();
+    let denoms = vec!["uusdc".to_string(), "uom".to_string()];
+
+    assert!(pool_matches_definition(
+        &existing,
+        &denoms,
+        &PoolType::ConstantProduct,
+        &fees
+    ));
+    assert!(!pool_matches_definition(
+        &existing,
+        &denoms,
+        &PoolType::StableSwap { amp: 100 },
+        &fees
+    ));
+
+    let mut other_fees = fees.clone();
+    other_fees.swap_fee = Fee {
+        share: Decimal::percent(1),
+    };
+    assert!(!pool_matches_definition(
+        &existing,
+        &denoms,
+        &PoolType::ConstantProduct,
+        &other_fees
+    ));
+
+    let three_assets = vec!["uom".to_string(), "uusdc".to_string(), "uatom".to_string()];
+    assert!(!pool_matches_definition(
+        &existing,
+        &three_assets,
+        &PoolType::ConstantProduct,
+        &fees
+    ));
+}
+
+#[test]
+fn test_depth_curve_from_samples() {
+    let samples = [(100u128, 200u128), (200, 380), (300, 510)]
+        .map(|(offer, ret)| (Uint128::new(offer), Uint128::new(ret)));
+    let curve = DepthCurve::from_samples("p1", "uom", "uusdc", samples);
+
+    assert_eq!(curve.points.len(), 3);
+    assert_eq!(curve.points[0].price, Decimal::from_ratio(2u128, 1u128));
+    assert_eq!(curve.points[0].price_impact, Decimal::zero());
+    assert_eq!(curve.points[1].price, Decimal::from_ratio(19u128, 10u128));
+    assert_eq!(curve.points[1].price_impact, Decimal::percent(5));
+    assert_eq!(curve.points[2].price_impact, Decimal::percent(15));
+
+    assert_eq!(
+        curve.max_offer_within(Decimal::percent(10)),
+        Some(Uint128::new(200))
+    );
+    assert_eq!(
+        curve.max_offer_within(Decimal::zero()),
+        Some(Uint128::new(100))
+    );
+
+    assert_eq!(
+        curve.to_csv(),
+        "offer_amount,return_amount,price,price_impact\n\
+         100,200,2,0\n\
+         200,380,1.9,0.05\n\
+         300,510,1.7,0.15\n"
+    );
+}
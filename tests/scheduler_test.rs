@@ -0,0 +1,121 @@
+use chrono::{TimeZone, Utc};
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_sdk::scheduler::ChainState;
+use mantra_dex_sdk::{Schedule, ScheduleStatus, ScheduledAction, Trigger};
+
+fn state(height: u64, epoch: Option<u64>) -> ChainState {
+    ChainState {
+        time: Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap(),
+        height,
+        epoch,
+    }
+}
+
+fn swap() -> ScheduledAction {
+    ScheduledAction::Swap {
+        pool_id: "p1".to_string(),
+        offer_asset: Coin::new(1_000_000u128, "uom"),
+        ask_asset_denom: "uusdc".to_string(),
+        max_slippage: None,
+        min_return: Some(Uint128::new(990_000)),
+    }
+}
+
+#[test]
+fn test_trigger_parse_and_display() {
+    assert_eq!(
+        "height:120".parse::<Trigger>().unwrap(),
+        Trigger::Height(120)
+    );
+    assert_eq!("epoch:7".parse::<Trigger>().unwrap(), Trigger::Epoch(7));
+
+    let at: Trigger = "2026-10-17T08:00:00+02:00".parse().unwrap();
+    assert_eq!(
+        at,
+        Trigger::At(Utc.with_ymd_and_hms(2026, 10, 17, 6, 0, 0).unwrap())
+    );
+    assert_eq!(at.to_string().parse::<Trigger>().unwrap(), at);
+    assert_eq!(Trigger::Epoch(7).to_string(), "epoch:7");
+
+    assert!("height:soon".parse::<Trigger>().is_err());
+    assert!("tomorrow".parse::<Trigger>().is_err());
+}
+
+#[test]
+fn test_trigger_is_due() {
+    let now = state(100, Some(5));
+    assert!(Trigger::Height(100).is_due(&now));
+    assert!(!Trigger::Height(101).is_due(&now));
+    assert!(Trigger::Epoch(5).is_due(&now));
+    assert!(!Trigger::Epoch(6).is_due(&now));
+    assert!(Trigger::At(now.time).is_due(&now));
+    assert!(!Trigger::At(now.time + chrono::Duration::seconds(1)).is_due(&now));
+
+    // Without an epoch manager, epoch triggers never fire
+    assert!(!Trigger::Epoch(0).is_due(&state(100, None)));
+}
+
+#[test]
+fn test_schedule_due_and_cancel() {
+    let mut schedule = Schedule::new();
+    let swap_id = schedule.add("main", swap(), Trigger::Height(100)).unwrap();
+    let claim_id = schedule
+        .add(
+            "main",
+            ScheduledAction::ClaimRewards { until_epoch: None },
+            Trigger::Epoch(6),
+        )
+        .unwrap();
+    schedule.add("other", swap(), Trigger::Height(50)).unwrap();
+    assert_eq!((swap_id, claim_id), (1, 2));
+
+    let due: Vec<u64> = schedule
+        .due("main", &state(100, Some(5)))
+        .iter()
+        .map(|tx| tx.id)
+        .collect();
+    assert_eq!(due, vec![swap_id]);
+
+    schedule.cancel(swap_id).unwrap();
+    assert_eq!(schedule.due("main", &state(100, Some(6))).len(), 1);
+    assert_eq!(
+        schedule.get(swap_id).unwrap().status,
+        ScheduleStatus::Cancelled
+    );
+
+    // Only pending transactions can be cancelled
+    assert!(schedule.cancel(swap_id).is_err());
+    assert!(schedule.cancel(42).is_err());
+}
+
+#[test]
+fn test_schedule_persists_across_opens() {
+    let path =
+        std::env::temp_dir().join(format!("mantra-dex-schedule-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut runner = Schedule::open(&path).unwrap();
+    let mut editor = Schedule::open(&path).unwrap();
+    let id = editor.add("main", swap(), Trigger::Height(100)).unwrap();
+
+    // A cancellation from another process is seen before executing
+    runner.reload().unwrap();
+    assert_eq!(runner.due("main", &state(100, None)).len(), 1);
+    editor.cancel(id).unwrap();
+    runner.reload().unwrap();
+    assert!(runner.due("main", &state(100, None)).is_empty());
+
+    runner
+        .set_status(
+            id,
+            ScheduleStatus::Failed {
+                reason: "test".to_string(),
+            },
+        )
+        .unwrap();
+    let reopened = Schedule::open(&path).unwrap();
+    assert_eq!(reopened.txs(), runner.txs());
+    assert_eq!(reopened.get(id).unwrap().action, swap());
+
+    std::fs::remove_file(&path).unwrap();
+}
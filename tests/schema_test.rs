@@ -0,0 +1,129 @@
+use mantra_dex_sdk::config::Config;
+use mantra_dex_sdk::error::Error;
+use mantra_dex_sdk::gas::{GasHistory, GasSample};
+use mantra_dex_sdk::scheduler::Schedule;
+use mantra_dex_sdk::schema::{self, backup_path, version_of, Format, Schema};
+use serde_json::{json, Value};
+
+/// Version 1 renamed `slip` to `slippage`
+fn rename_slip(value: &mut Value) -> Result<(), Error> {
+    let fields = value
+        .as_object_mut()
+        .ok_or_else(|| Error::Config("expected an object".to_string()))?;
+    if let Some(slip) = fields.remove("slip") {
+        fields.insert("slippage".to_string(), slip);
+    }
+    Ok(())
+}
+
+/// Version 2 turned `slippage` from a percentage into a fraction
+fn slippage_fraction(value: &mut Value) -> Result<(), Error> {
+    if let Some(percent) = value.get("slippage").and_then(Value::as_f64) {
+        value["slippage"] = json!(percent / 100.0);
+    }
+    Ok(())
+}
+
+const TEST_SCHEMA: Schema = Schema::new("test", Format::Json, &[rename_slip, slippage_fraction]);
+
+#[test]
+fn test_migrate_applies_missing_steps() {
+    assert_eq!(TEST_SCHEMA.current_version(), 2);
+
+    let mut unversioned = json!({ "slip": 1.0 });
+    assert_eq!(TEST_SCHEMA.migrate(&mut unversioned).unwrap(), 0);
+    assert_eq!(unversioned, json!({ "slippage": 0.01, "version": 2 }));
+
+    let mut v1 = json!({ "slippage": 5.0, "version": 1 });
+    assert_eq!(TEST_SCHEMA.migrate(&mut v1).unwrap(), 1);
+    assert_eq!(v1, json!({ "slippage": 0.05, "version": 2 }));
+
+    let mut current = json!({ "slippage": 0.5, "version": 2 });
+    assert_eq!(TEST_SCHEMA.migrate(&mut current).unwrap(), 2);
+    assert_eq!(current["slippage"], json!(0.5));
+
+    // Files from a newer release are refused rather than misread
+    let mut newer = json!({ "slippage": 0.5, "version": 3 });
+    assert!(TEST_SCHEMA.migrate(&mut newer).is_err());
+
+    let mut not_an_object = json!([1, 2]);
+    assert!(TEST_SCHEMA.migrate(&mut not_an_object).is_err());
+}
+
+#[test]
+fn test_read_backs_up_and_rewrites_old_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("settings.json");
+    let original = r#"{ "slip": 2.0 }"#;
+    std::fs::write(&path, original).unwrap();
+
+    let read: Value = schema::read(&TEST_SCHEMA, &path).unwrap();
+    assert_eq!(read, json!({ "slippage": 0.02 }));
+
+    let backup = backup_path(&path, 0);
+    assert_eq!(backup, dir.path().join("settings.json.v0.bak"));
+    assert_eq!(std::fs::read_to_string(&backup).unwrap(), original);
+    let rewritten: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(version_of(&rewritten), 2);
+
+    // Writing stamps the current version
+    schema::write(&TEST_SCHEMA, &path, &json!({ "slippage": 0.03 })).unwrap();
+    let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written, json!({ "slippage": 0.03, "version": 2 }));
+}
+
+#[test]
+fn test_unversioned_gas_history_and_schedule_still_load() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let gas_path = dir.path().join("gas_history.json");
+    std::fs::write(
+        &gas_path,
+        r#"{ "swap": [{ "simulated": 100, "gas_wanted": 140, "gas_used": 120 }] }"#,
+    )
+    .unwrap();
+    let history = GasHistory::open(&gas_path).unwrap();
+    assert_eq!(
+        history.samples("swap"),
+        vec![GasSample {
+            simulated: 100,
+            gas_wanted: 140,
+            gas_used: 120,
+            out_of_gas: false,
+        }]
+    );
+    assert!(backup_path(&gas_path, 0).exists());
+
+    let schedule_path = dir.path().join("scheduled_txs.json");
+    std::fs::write(&schedule_path, "[]").unwrap();
+    let schedule = Schedule::open(&schedule_path).unwrap();
+    assert!(schedule.txs().is_empty());
+    let rewritten: Value =
+        serde_json::from_str(&std::fs::read_to_string(&schedule_path).unwrap()).unwrap();
+    assert_eq!(rewritten, json!({ "txs": [], "version": 1 }));
+}
+
+#[test]
+fn test_config_carries_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+
+    Config::default().save(&path).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.lines().any(|line| line == "version = 1"));
+    Config::load(&path).unwrap();
+    assert!(!backup_path(&path, 0).exists());
+
+    // A config saved before versioning is migrated in place
+    let unversioned: String = content
+        .lines()
+        .filter(|line| *line != "version = 1")
+        .map(|line| format!("{}\n", line))
+        .collect();
+    std::fs::write(&path, &unversioned).unwrap();
+    Config::load(&path).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(backup_path(&path, 0)).unwrap(),
+        unversioned
+    );
+}
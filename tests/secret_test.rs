@@ -0,0 +1,63 @@
+use mantra_dex_sdk::config::Config;
+use mantra_dex_sdk::secret::SecretString;
+use mantra_dex_sdk::wallet::wizard::WizardState;
+
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+                        abandon abandon abandon abandon abandon about";
+
+#[test]
+fn test_secret_is_redacted_when_printed() {
+    let secret = SecretString::from(MNEMONIC);
+    assert_eq!(secret.to_string(), "[REDACTED]");
+    assert!(!format!("{:?}", secret).contains("abandon"));
+    assert_eq!(secret.expose_secret(), MNEMONIC);
+
+    // Structures holding secrets don't leak them through Debug either
+    let mut wizard = WizardState::new();
+    wizard.mnemonic_input = secret.clone();
+    wizard.save_password = "Sup3r-Secret-Pass".into();
+    let printed = format!("{:?}", wizard);
+    assert!(!printed.contains("abandon") && !printed.contains("Sup3r"));
+
+    let config = Config {
+        mnemonic: Some(secret),
+        ..Config::default()
+    };
+    assert!(!format!("{:?}", config).contains("abandon"));
+}
+
+#[test]
+fn test_secret_editing() {
+    let mut secret = SecretString::default();
+    for c in "pässwörd".chars() {
+        secret.push(c);
+    }
+    secret.push_str(" and more to grow the buffer past its first allocation ......");
+    assert!(secret.expose_secret().starts_with("pässwörd and more"));
+
+    let mut secret = SecretString::from("pässwörd");
+    assert_eq!(secret.pop(), Some('d'));
+    assert_eq!(secret.remove(1), Some('ä'));
+    secret.insert(1, 'a');
+    secret.insert(100, '!');
+    assert_eq!(secret.expose_secret(), "passwör!");
+    assert_eq!(secret.char_count(), 8);
+    assert_eq!(secret.remove(8), None);
+
+    secret.clear();
+    assert!(secret.is_empty());
+    assert_eq!(secret.pop(), None);
+}
+
+#[test]
+fn test_secret_serializes_as_plain_string() {
+    let config = Config {
+        mnemonic: Some(SecretString::from(MNEMONIC)),
+        ..Config::default()
+    };
+    let saved = toml::to_string(&config).unwrap();
+    assert!(saved.contains(MNEMONIC));
+
+    let loaded: Config = toml::from_str(&saved).unwrap();
+    assert_eq!(loaded.mnemonic.unwrap().expose_secret(), MNEMONIC);
+}
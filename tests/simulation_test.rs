@@ -0,0 +1,206 @@
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::tx::v1beta1::{AuthInfo, Fee, Tx, TxBody};
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::proto::tendermint::abci::{Event, EventAttribute};
+use cosmrs::Any;
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_sdk::simulation::{
+    balance_changes, is_simulated, message_events, resulting_balances, MessageEvent,
+    SIMULATED_TX_INFO, TX_TYPE_URL,
+};
+use mantra_dex_sdk::{BalanceChange, DryRunReport, Error};
+use prost::Message;
+use serde_json::json;
+
+const ME: &str = "mantra1me";
+const POOL: &str = "mantra1pool";
+
+fn event(kind: &str, attributes: &[(&str, &str)]) -> Event {
+    Event {
+        r#type: kind.to_string(),
+        attributes: attributes
+            .iter()
+            .map(|(key, value)| EventAttribute {
+                key: key.to_string(),
+                value: value.to_string(),
+                index: true,
+            })
+            .collect(),
+    }
+}
+
+fn swap_events() -> Vec<Event> {
+    vec![
+        // Fee deduction by the ante handler
+        event("coin_spent", &[("spender", ME), ("amount", "5000uom")]),
+        event(
+            "coin_spent",
+            &[
+                ("spender", ME),
+                ("amount", "1000000uom"),
+                ("msg_index", "0"),
+            ],
+        ),
+        event(
+            "coin_received",
+            &[
+                ("receiver", POOL),
+                ("amount", "1000000uom"),
+                ("msg_index", "0"),
+            ],
+        ),
+        event(
+            "coin_received",
+            &[
+                ("receiver", ME),
+                ("amount", "990000factory/mantra1x/uusdc,10uom"),
+                ("msg_index", "0"),
+            ],
+        ),
+        event("wasm", &[("action", "swap"), ("msg_index", "0")]),
+    ]
+}
+
+#[test]
+fn test_balance_changes_skip_fee_and_other_accounts() {
+    let changes = balance_changes(&swap_events(), ME);
+    assert_eq!(
+        changes,
+        vec![
+            BalanceChange {
+                denom: "factory/mantra1x/uusdc".to_string(),
+                spent: Uint128::zero(),
+                received: Uint128::new(990_000),
+            },
+            BalanceChange {
+                denom: "uom".to_string(),
+                spent: Uint128::new(1_000_000),
+                received: Uint128::new(10),
+            },
+        ]
+    );
+    assert!(balance_changes(&swap_events(), "mantra1other").is_empty());
+}
+
+#[test]
+fn test_resulting_balances() {
+    let balances = [Coin::new(3_000_000u128, "uom"), Coin::new(7u128, "uatom")];
+    let changes = balance_changes(&swap_events(), ME);
+    let fee = Coin::new(5_000u128, "uom");
+
+    assert_eq!(
+        resulting_balances(&balances, &changes, Some(&fee)),
+        vec![
+            Coin::new(990_000u128, "factory/mantra1x/uusdc"),
+            Coin::new(7u128, "uatom"),
+            Coin::new(1_995_010u128, "uom"),
+        ]
+    );
+
+    // Spending a whole balance leaves no empty entry behind
+    let all_in = [Coin::new(1_000_000u128, "uom")];
+    let resulting = resulting_balances(&all_in, &changes, None);
+    assert_eq!(resulting[1], Coin::new(10u128, "uom"));
+    assert!(resulting_balances(&all_in, &changes[1..], Some(&fee))
+        .iter()
+        .all(|coin| coin.denom != "uom"));
+}
+
+#[test]
+fn test_is_simulated() {
+    let mut tx = TxResponse::default();
+    assert!(!is_simulated(&tx));
+    tx.info = SIMULATED_TX_INFO.to_string();
+    assert!(is_simulated(&tx));
+}
+
+#[test]
+fn test_message_events_skip_ante_handler() {
+    let events = message_events(&swap_events());
+    assert_eq!(events.len(), 4);
+    assert_eq!(
+        events[3],
+        MessageEvent {
+            msg_index: 0,
+            kind: "wasm".to_string(),
+            attributes: vec![("action".to_string(), "swap".to_string())],
+        }
+    );
+    assert_eq!(events[3].to_string(), "[msg 0] wasm\n    action = swap");
+}
+
+fn simulated_swap() -> TxResponse {
+    let swap = MsgExecuteContract {
+        sender: ME.to_string(),
+        contract: POOL.to_string(),
+        msg: br#"{"swap":{"ask_asset_denom":"uusdc"}}"#.to_vec(),
+        funds: vec![ProtoCoin {
+            denom: "uom".to_string(),
+            amount: "1000000".to_string(),
+        }],
+    };
+    let tx = Tx {
+        body: Some(TxBody {
+            messages: vec![Any {
+                type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+                value: swap.encode_to_vec(),
+            }],
+            ..Default::default()
+        }),
+        auth_info: Some(AuthInfo {
+            fee: Some(Fee {
+                amount: vec![ProtoCoin {
+                    denom: "uom".to_string(),
+                    amount: "5000".to_string(),
+                }],
+                gas_limit: 250_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        signatures: vec![],
+    };
+    TxResponse {
+        info: SIMULATED_TX_INFO.to_string(),
+        gas_wanted: 250_000,
+        gas_used: 180_000,
+        tx: Some(Any {
+            type_url: TX_TYPE_URL.to_string(),
+            value: tx.encode_to_vec(),
+        }),
+        events: swap_events(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_dry_run_report_decodes_simulated_tx() {
+    let report = DryRunReport::from_response(&simulated_swap(), ME).unwrap();
+
+    assert_eq!(report.messages.len(), 1);
+    assert_eq!(report.messages[0]["type"], "wasm/MsgExecuteContract");
+    assert_eq!(
+        report.messages[0]["value"]["msg"],
+        json!({"swap": {"ask_asset_denom": "uusdc"}})
+    );
+    assert_eq!(report.fee, vec![Coin::new(5_000u128, "uom")]);
+    assert_eq!((report.gas_used, report.gas_limit), (180_000, 250_000));
+    assert_eq!(report.balance_changes, balance_changes(&swap_events(), ME));
+    assert_eq!(report.events.len(), 4);
+
+    let printed = report.to_string();
+    assert!(printed.contains("Gas: 180000 estimated, limit 250000, fee 5000uom"));
+    assert!(printed.contains("+990000 factory/mantra1x/uusdc"));
+    assert!(printed.contains("-1000000 uom"));
+}
+
+#[test]
+fn test_dry_run_report_needs_simulated_tx() {
+    let mut tx = simulated_swap();
+    tx.info = String::new();
+    assert!(matches!(
+        DryRunReport::from_response(&tx, ME),
+        Err(Error::Tx(_))
+    ));
+}
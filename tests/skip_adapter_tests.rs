@@ -1,6 +1,7 @@
 use cosmwasm_std::{Coin, Uint128};
 use mantra_dex_sdk::{
-    MantraDexClient, MantraNetworkConfig, MantraWallet, SkipAsset, SkipRoute, SkipSwapOperation,
+    MantraDexClient, MantraNetworkConfig, MantraWallet, PageCursor, SkipAsset, SkipRoute,
+    SkipSwapOperation,
 };
 use serde::Deserialize;
 use std::env;
@@ -314,7 +315,11 @@ async fn test_execute_skip_swap() {
         .expect("Failed to setup test client");
 
     // Get real pools first to use actual pool data
-    let pools = match client.get_pools(Some(10)).await {
+    let pools = match client
+        .get_pools(&PageCursor::first(10))
+        .await
+        .map(|page| page.items)
+    {
         Ok(pools) if !pools.is_empty() => pools,
         Ok(_) => {
             println!("No pools found on testnet, skipping on-chain swap test");
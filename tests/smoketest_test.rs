@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use mantra_dex_sdk::{SmokeReport, StepResult, StepStatus};
+
+fn report() -> SmokeReport {
+    let mut report = SmokeReport::new("mantra-dukong");
+    report.push(StepResult::passed(
+        "swap",
+        Duration::from_millis(1500),
+        "pool o.uom.uusdc",
+    ));
+    report.push(
+        StepResult::failed(
+            "provide_liquidity",
+            Duration::from_millis(250),
+            "Pool <p1> has no \"uusdc\" reserve",
+        )
+        .with_tx_hash("ABC123"),
+    );
+    report.push(StepResult::skipped("claim_rewards", "no rewards pending"));
+    report
+}
+
+#[test]
+fn test_report_counts_steps() {
+    let report = report();
+    assert_eq!(report.count(StepStatus::Passed), 1);
+    assert_eq!(report.count(StepStatus::Failed), 1);
+    assert_eq!(report.count(StepStatus::Skipped), 1);
+    assert!(!report.passed());
+    assert_eq!(report.duration(), Duration::from_millis(1750));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json["steps"][1]["status"], "failed");
+    assert_eq!(json["steps"][1]["tx_hash"], "ABC123");
+    assert!(json["steps"][0].get("tx_hash").is_none());
+}
+
+#[test]
+fn test_junit_report_escapes_details() {
+    let xml = report().to_junit();
+    assert!(xml.contains(
+        "<testsuite name=\"mantra-dex-smoketest\" tests=\"3\" failures=\"1\" skipped=\"1\" \
+         time=\"1.750\""
+    ));
+    assert!(xml.contains("<property name=\"network\" value=\"mantra-dukong\"/>"));
+    assert!(xml.contains("<failure message=\"Pool &lt;p1&gt; has no &quot;uusdc&quot; reserve\">"));
+    assert!(xml.contains("<skipped message=\"no rewards pending\"/>"));
+    assert!(xml.contains("<system-out>tx: ABC123</system-out>"));
+    assert_eq!(xml.matches("<testcase ").count(), 3);
+    assert_eq!(xml.matches("</testcase>").count(), 3);
+}
@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use cosmwasm_std::Coin;
+use mantra_dex_sdk::config::{StorageBackend, StorageConfig};
+use mantra_dex_sdk::error::Error;
+use mantra_dex_sdk::schema::{Format, Schema};
+use mantra_dex_sdk::storage;
+use mantra_dex_sdk::{
+    DcaBook, DcaEnd, Document, FileStorage, MemoryStorage, NewDcaPlan, NewOrder, OrderBook,
+    SharedStorage, Storage,
+};
+use serde_json::{json, Value};
+
+/// Version 1 renamed `slip` to `slippage`
+fn rename_slip(value: &mut Value) -> Result<(), Error> {
+    let fields = value
+        .as_object_mut()
+        .ok_or_else(|| Error::Config("expected an object".to_string()))?;
+    if let Some(slip) = fields.remove("slip") {
+        fields.insert("slippage".to_string(), slip);
+    }
+    Ok(())
+}
+
+const TEST_SCHEMA: Schema = Schema::new("test", Format::Json, &[rename_slip]);
+
+fn sell_om() -> NewOrder {
+    NewOrder {
+        pool_id: "p1".to_string(),
+        offer_asset: Coin::new(1_000_000u128, "uom"),
+        ask_asset_denom: "uusdc".to_string(),
+        condition: ">=3".parse().unwrap(),
+        max_slippage: None,
+        expires_at: None,
+    }
+}
+
+/// Put, get, list and remove through any backend
+fn round_trip(storage: &dyn Storage) {
+    assert_eq!(storage.get("a.json").unwrap(), None);
+    storage.put("b.json", "{}").unwrap();
+    storage.put("a.json", "1").unwrap();
+    storage.put("a.json", "2").unwrap();
+    assert_eq!(storage.get("a.json").unwrap().as_deref(), Some("2"));
+    assert_eq!(storage.keys().unwrap(), vec!["a.json", "b.json"]);
+
+    storage.remove("a.json").unwrap();
+    storage.remove("missing.json").unwrap();
+    assert_eq!(storage.get("a.json").unwrap(), None);
+    assert_eq!(storage.keys().unwrap(), vec!["b.json"]);
+}
+
+#[test]
+fn test_memory_and_file_storage_round_trip() {
+    round_trip(&MemoryStorage::new());
+
+    let dir = tempfile::tempdir().unwrap();
+    let files = FileStorage::new(dir.path().join("state"));
+    assert!(files.keys().unwrap().is_empty());
+    round_trip(&files);
+    assert_eq!(
+        files.file_path("b.json"),
+        Some(dir.path().join("state").join("b.json"))
+    );
+    assert!(files.modified("b.json").is_some());
+
+    // Keys never reach outside the directory
+    assert!(files.put("../escape.json", "{}").is_err());
+    assert!(files.get("..").is_err());
+    assert!(files.put("", "{}").is_err());
+}
+
+#[test]
+fn test_document_read_migrates_and_keeps_a_backup() {
+    let storage: SharedStorage = Arc::new(MemoryStorage::new());
+    let document = Document::new(storage.clone(), "settings.json");
+    assert_eq!(document.read::<Value>(&TEST_SCHEMA).unwrap(), None);
+    assert_eq!(document.path(), None);
+
+    let original = r#"{ "slip": 2.0 }"#;
+    storage.put("settings.json", original).unwrap();
+    let read: Value = document.read(&TEST_SCHEMA).unwrap().unwrap();
+    assert_eq!(read, json!({ "slippage": 2.0 }));
+
+    // The original is kept and the document stored back in the current format
+    assert_eq!(
+        storage.get("settings.json.v0.bak").unwrap().as_deref(),
+        Some(original)
+    );
+    let stored: Value =
+        serde_json::from_str(&storage.get("settings.json").unwrap().unwrap()).unwrap();
+    assert_eq!(stored, json!({ "slippage": 2.0, "version": 1 }));
+
+    document
+        .write(&TEST_SCHEMA, &json!({ "slippage": 0.5 }))
+        .unwrap();
+    let read: Value = document.read(&TEST_SCHEMA).unwrap().unwrap();
+    assert_eq!(read, json!({ "slippage": 0.5 }));
+    assert_eq!(
+        storage.keys().unwrap(),
+        vec!["settings.json", "settings.json.v0.bak"]
+    );
+}
+
+#[test]
+fn test_subsystems_share_one_storage() {
+    let storage: SharedStorage = Arc::new(MemoryStorage::new());
+    let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+
+    let mut orders = OrderBook::open_in(storage.clone()).unwrap();
+    let mut plans = DcaBook::open_in(storage.clone()).unwrap();
+    let order = orders.add("main", sell_om()).unwrap();
+    plans
+        .add(
+            "main",
+            NewDcaPlan {
+                pool_id: "p1".to_string(),
+                offer_asset: Coin::new(100_000_000u128, "uusdc"),
+                ask_asset_denom: "uom".to_string(),
+                interval_secs: 3600,
+                max_slippage: None,
+                end: DcaEnd::default(),
+                start_at: None,
+            },
+            now,
+        )
+        .unwrap();
+
+    // Each keeps its own document
+    assert_eq!(
+        storage.keys().unwrap(),
+        vec![DcaBook::STORAGE_KEY, OrderBook::STORAGE_KEY]
+    );
+
+    // Other handles on the same storage see the changes after a reload
+    let mut watcher = OrderBook::open_in(storage.clone()).unwrap();
+    assert_eq!(watcher.pending("main").len(), 1);
+    orders.cancel(order).unwrap();
+    watcher.reload().unwrap();
+    assert!(watcher.pending("main").is_empty());
+    assert_eq!(DcaBook::open_in(storage).unwrap().due("main", now).len(), 1);
+}
+
+#[test]
+fn test_open_picks_the_configured_backend() {
+    let dir = tempfile::tempdir().unwrap();
+    let files = storage::open(&StorageConfig {
+        backend: StorageBackend::Files,
+        path: Some(dir.path().to_path_buf()),
+        max_connections: None,
+    })
+    .unwrap();
+    files.put("a.json", "{}").unwrap();
+    assert!(dir.path().join("a.json").exists());
+
+    let memory = storage::open(&StorageConfig {
+        backend: StorageBackend::Memory,
+        ..StorageConfig::default()
+    })
+    .unwrap();
+    assert!(memory.keys().unwrap().is_empty());
+
+    assert_ne!(
+        storage::default_path(StorageBackend::Sqlite),
+        storage::default_path(StorageBackend::Sled)
+    );
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn test_sqlite_storage_persists_and_migrates_once() {
+    use mantra_dex_sdk::storage::SqliteStorage;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("state.sqlite3");
+    let storage = SqliteStorage::open(&path, 2).unwrap();
+    assert_eq!(storage.version().unwrap(), 1);
+    round_trip(&storage);
+    assert!(storage.modified("b.json").is_some());
+    assert_eq!(storage.modified("a.json"), None);
+
+    // Several handles write to the same database
+    let shared: SharedStorage = Arc::new(storage);
+    let mut first = OrderBook::open_in(shared.clone()).unwrap();
+    first.add("main", sell_om()).unwrap();
+    drop(shared);
+
+    let reopened = SqliteStorage::open(&path, 1).unwrap();
+    assert_eq!(reopened.version().unwrap(), 1);
+    let orders = OrderBook::open_in(Arc::new(reopened)).unwrap();
+    assert_eq!(orders.pending("main").len(), 1);
+}
+
+#[cfg(feature = "sled")]
+#[test]
+fn test_sled_storage_persists() {
+    use mantra_dex_sdk::storage::SledStorage;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("state.sled");
+    {
+        let storage = SledStorage::open(&path).unwrap();
+        round_trip(&storage);
+        let mut orders = OrderBook::open_in(Arc::new(storage)).unwrap();
+        orders.add("main", sell_om()).unwrap();
+    }
+
+    let orders = OrderBook::open_in(Arc::new(SledStorage::open(&path).unwrap())).unwrap();
+    assert_eq!(orders.pending("main").len(), 1);
+}
@@ -0,0 +1,49 @@
+use futures::StreamExt;
+use mantra_dex_sdk::{Error, MantraDexClient, MantraNetworkConfig};
+
+async fn client_without_farm_manager() -> MantraDexClient {
+    let mut config = MantraNetworkConfig::default();
+    config.contracts.farm_manager = None;
+    MantraDexClient::new(config).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_farm_streams_require_farm_manager() {
+    let client = client_without_farm_manager().await;
+
+    let farms: Vec<_> = client.farms_stream(None, 10).collect().await;
+    assert_eq!(farms.len(), 1);
+    assert!(matches!(farms[0], Err(Error::Other(_))));
+
+    let positions: Vec<_> = client
+        .positions_stream(None, Some(true), 10)
+        .collect()
+        .await;
+    assert_eq!(positions.len(), 1);
+    assert!(positions[0].is_err());
+
+    let claims: Vec<_> = client
+        .rewards_history_stream("mantra1address", 10)
+        .collect()
+        .await;
+    assert_eq!(claims.len(), 1);
+    assert!(claims[0].is_err());
+}
+
+#[tokio::test]
+async fn test_streams_are_lazy() {
+    // Creating a stream must not issue any request until it is polled
+    let config = MantraNetworkConfig {
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        ..Default::default()
+    };
+    let client = MantraDexClient::new(config).await.unwrap();
+
+    let stream = client.pools_stream(10);
+    drop(stream);
+
+    // Polling surfaces the connection failure as a single error item
+    let pools: Vec<_> = client.pools_stream(10).collect().await;
+    assert_eq!(pools.len(), 1);
+    assert!(matches!(pools[0], Err(Error::Rpc(_))));
+}
@@ -0,0 +1,108 @@
+use cosmrs::tendermint::abci::Event;
+use mantra_dex_sdk::subscriptions::websocket_url;
+use mantra_dex_sdk::{
+    ChainEvent, Error, EventSubscriptions, MantraDexClient, MantraNetworkConfig, SubscriptionFilter,
+};
+
+const POOL_MANAGER: &str = "mantra1poolmanager";
+const WALLET: &str = "mantra1wallet";
+
+fn swap_tx() -> ChainEvent {
+    ChainEvent::Tx {
+        height: 42,
+        tx_hash: "ABCD".to_string(),
+        events: vec![
+            Event::new("message", [("sender", WALLET)]),
+            Event::new(
+                "wasm",
+                [("_contract_address", POOL_MANAGER), ("action", "swap")],
+            ),
+        ],
+    }
+}
+
+#[test]
+fn test_websocket_url() {
+    assert_eq!(
+        websocket_url("https://rpc.dukong.mantrachain.io/").unwrap(),
+        "wss://rpc.dukong.mantrachain.io/websocket"
+    );
+    assert_eq!(
+        websocket_url("http://localhost:26657").unwrap(),
+        "ws://localhost:26657/websocket"
+    );
+    assert_eq!(
+        websocket_url("ws://localhost:26657/websocket").unwrap(),
+        "ws://localhost:26657/websocket"
+    );
+    assert!(matches!(
+        websocket_url("localhost:26657"),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_filter_queries() {
+    assert_eq!(
+        SubscriptionFilter::new()
+            .queries()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["tm.event = 'NewBlock'"]
+    );
+
+    let queries: Vec<String> = SubscriptionFilter::new()
+        .with_address(WALLET)
+        .with_contract(POOL_MANAGER)
+        .with_contract(POOL_MANAGER)
+        .queries()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        queries,
+        vec![
+            "tm.event = 'NewBlock'".to_string(),
+            format!("tm.event = 'Tx' AND message.sender = '{}'", WALLET),
+            format!("tm.event = 'Tx' AND transfer.recipient = '{}'", WALLET),
+            format!(
+                "tm.event = 'Tx' AND wasm._contract_address = '{}'",
+                POOL_MANAGER
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_chain_event_matching() {
+    let tx = swap_tx();
+    assert_eq!(tx.height(), 42);
+    assert!(tx.involves_address(WALLET));
+    assert!(!tx.involves_address("mantra1other"));
+    assert!(tx.involves_contract(POOL_MANAGER));
+    assert!(!tx.involves_contract("mantra1farmmanager"));
+
+    let block = ChainEvent::NewBlock { height: 43 };
+    assert_eq!(block.height(), 43);
+    assert!(!block.involves_contract(POOL_MANAGER));
+}
+
+#[tokio::test]
+async fn test_subscribe_fails_without_websocket() {
+    let filter = SubscriptionFilter::new().with_contract(POOL_MANAGER);
+    assert!(matches!(
+        EventSubscriptions::connect("ws://127.0.0.1:1/websocket", &filter).await,
+        Err(Error::Network(_))
+    ));
+
+    let config = MantraNetworkConfig {
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        ..Default::default()
+    };
+    let client = MantraDexClient::new(config).await.unwrap();
+    assert!(matches!(
+        client.subscribe_events(&filter).await,
+        Err(Error::Network(_))
+    ));
+}
@@ -1,6 +1,7 @@
 mod utils;
 
 use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_sdk::PageCursor;
 use utils::test_utils::{create_test_client, get_or_create_test_pool_id, load_test_config};
 
 #[tokio::test]
@@ -9,7 +10,11 @@ async fn test_list_all_pools() {
     let client = create_test_client().await;
 
     // Get all pools (no limit)
-    match client.get_pools(Some(100)).await {
+    match client
+        .get_pools(&PageCursor::first(100))
+        .await
+        .map(|page| page.items)
+    {
         Ok(pools) => {
             println!("Found {} pools:", pools.len());
             for pool in pools {
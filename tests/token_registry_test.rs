@@ -0,0 +1,106 @@
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use mantra_dex_sdk::token_registry::{
+    fallback_symbol, IbcTrace, TokenMetadata, TokenRegistry, TokenSource, DEFAULT_DECIMALS,
+};
+
+fn unit(denom: &str, exponent: u32) -> DenomUnit {
+    DenomUnit {
+        denom: denom.to_string(),
+        exponent,
+        aliases: vec![],
+    }
+}
+
+#[test]
+fn test_fallback_symbol() {
+    assert_eq!(fallback_symbol("uom"), "OM");
+    assert_eq!(fallback_symbol("factory/mantra1creator/uUSDC"), "USDC");
+    assert_eq!(fallback_symbol("factory/mantra1creator/aUSDY"), "aUSDY");
+    assert_eq!(fallback_symbol("u"), "U");
+}
+
+#[test]
+fn test_bank_metadata_uses_display_exponent() {
+    let metadata = Metadata {
+        description: String::new(),
+        denom_units: vec![unit("uom", 0), unit("mom", 3), unit("om", 6)],
+        base: "uom".to_string(),
+        display: "om".to_string(),
+        name: "MANTRA".to_string(),
+        symbol: "OM".to_string(),
+        uri: String::new(),
+        uri_hash: String::new(),
+    };
+
+    let token = TokenMetadata::from_bank_metadata("uom", &metadata).unwrap();
+    assert_eq!(token.symbol, "OM");
+    assert_eq!(token.decimals, 6);
+    assert_eq!(token.name.as_deref(), Some("MANTRA"));
+    assert_eq!(token.source, TokenSource::Bank);
+}
+
+#[test]
+fn test_bank_metadata_without_symbol_or_display() {
+    let metadata = Metadata {
+        denom_units: vec![unit("uatom", 0)],
+        base: "uatom".to_string(),
+        ..Default::default()
+    };
+    assert!(TokenMetadata::from_bank_metadata("uatom", &metadata).is_none());
+}
+
+#[test]
+fn test_derived_sources() {
+    let trace = IbcTrace {
+        path: "transfer/channel-0".to_string(),
+        base_denom: "uatom".to_string(),
+    };
+    let ibc = TokenMetadata::derived("ibc/ABC", Some(trace.clone()), None);
+    assert_eq!(ibc.source, TokenSource::IbcTrace);
+    assert_eq!(ibc.symbol, "ATOM");
+    assert_eq!(ibc.decimals, DEFAULT_DECIMALS);
+    assert_eq!(ibc.ibc_trace, Some(trace));
+
+    let factory = TokenMetadata::derived("factory/mantra1creator/uUSDC", None, Some(6));
+    assert_eq!(factory.source, TokenSource::Factory);
+    assert_eq!(factory.symbol, "USDC");
+
+    let pool = TokenMetadata::derived("aevmos", None, Some(18));
+    assert_eq!(pool.source, TokenSource::Pool);
+    assert_eq!(pool.decimals, 18);
+
+    let unknown = TokenMetadata::derived("uunknown", None, None);
+    assert_eq!(unknown.source, TokenSource::Fallback);
+}
+
+#[test]
+fn test_registry_skips_fallback_tokens() {
+    let registry = TokenRegistry::new();
+    registry.insert([
+        TokenMetadata::derived("aevmos", None, Some(18)),
+        TokenMetadata::derived("uunknown", None, None),
+    ]);
+
+    assert!(registry.contains("aevmos"));
+    assert_eq!(registry.decimals("aevmos"), 18);
+    assert!(!registry.contains("uunknown"));
+    assert_eq!(registry.symbol("uunknown"), "UNKNOWN");
+    assert_eq!(registry.decimals("uunknown"), DEFAULT_DECIMALS);
+}
+
+#[test]
+fn test_registry_persists_to_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("token_registry.json");
+
+    let registry = TokenRegistry::open(&path).unwrap();
+    registry.insert([TokenMetadata::derived(
+        "factory/mantra1creator/uUSDC",
+        None,
+        Some(6),
+    )]);
+
+    let reopened = TokenRegistry::open(&path).unwrap();
+    assert_eq!(reopened.tokens(), registry.tokens());
+    assert_eq!(reopened.symbol("factory/mantra1creator/uUSDC"), "USDC");
+}
@@ -0,0 +1,150 @@
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::tendermint::chain::Id;
+use cosmrs::tx::{self, Fee, MessageExt, ModeInfo, SignDoc};
+use cosmrs::{Any, Coin, Denom};
+use mantra_dex_sdk::tx::{amino_json, SignMode, TxBuilder};
+use mantra_dex_sdk::MantraWallet;
+use std::str::FromStr;
+
+const CHAIN_ID: &str = "mantra-dukong-1";
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+                        abandon abandon abandon abandon abandon about";
+
+fn execute_msg(sender: &str, msg: &str, funds: Vec<ProtoCoin>) -> Any {
+    Any {
+        type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+        value: MsgExecuteContract {
+            sender: sender.to_string(),
+            contract: "mantra1contract".to_string(),
+            msg: msg.as_bytes().to_vec(),
+            funds,
+        }
+        .to_bytes()
+        .unwrap(),
+    }
+}
+
+fn fee() -> Fee {
+    Fee::from_amount_and_gas(
+        Coin {
+            amount: 5_000,
+            denom: Denom::from_str("uom").unwrap(),
+        },
+        200_000u64,
+    )
+}
+
+#[test]
+fn test_direct_sign_bytes_match_sign_doc() {
+    let wallet = MantraWallet::from_mnemonic(MNEMONIC, 0).unwrap();
+    let sender = wallet.address().unwrap().to_string();
+    let unsigned = TxBuilder::new(CHAIN_ID)
+        .with_message(execute_msg(&sender, r#"{"swap":{}}"#, vec![]))
+        .with_memo("external")
+        .with_fee(fee())
+        .with_signer(wallet.public_key().unwrap(), 7, 3)
+        .build()
+        .unwrap();
+
+    let sign_doc = SignDoc::new(
+        &unsigned.body,
+        &unsigned.auth_info,
+        &Id::try_from(CHAIN_ID).unwrap(),
+        7,
+    )
+    .unwrap();
+    assert_eq!(
+        unsigned.sign_bytes().unwrap(),
+        sign_doc.clone().into_bytes().unwrap()
+    );
+
+    // Signing elsewhere gives the same transaction as signing locally
+    let signature = wallet.sign_doc(sign_doc.clone()).unwrap();
+    let external = unsigned.clone().into_signed(&signature.to_vec()).unwrap();
+    let local = sign_doc.sign(wallet.signing_key().unwrap()).unwrap();
+    assert_eq!(external.to_bytes().unwrap(), local.to_bytes().unwrap());
+    assert_eq!(
+        unsigned
+            .sign(wallet.signing_key().unwrap())
+            .unwrap()
+            .to_bytes()
+            .unwrap(),
+        local.to_bytes().unwrap()
+    );
+}
+
+#[test]
+fn test_amino_sign_bytes() {
+    let wallet = MantraWallet::from_mnemonic(MNEMONIC, 0).unwrap();
+    let funds = vec![ProtoCoin {
+        denom: "uom".to_string(),
+        amount: "1000".to_string(),
+    }];
+    let unsigned = TxBuilder::new(CHAIN_ID)
+        .with_message(execute_msg(
+            "mantra1sender",
+            r#"{"swap":{"max_spread":"0.01","ask_asset_denom":"a<b"}}"#,
+            funds,
+        ))
+        .with_fee(fee())
+        .with_signer(wallet.public_key().unwrap(), 7, 3)
+        .with_sign_mode(SignMode::LegacyAmino)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        unsigned.auth_info.signer_infos[0].mode_info,
+        ModeInfo::single(tx::SignMode::LegacyAminoJson)
+    );
+    // Keys sorted at every level, no whitespace, HTML characters escaped
+    let expected = concat!(
+        r#"{"account_number":"7","chain_id":"mantra-dukong-1","#,
+        r#""fee":{"amount":[{"amount":"5000","denom":"uom"}],"gas":"200000"},"#,
+        r#""memo":"","msgs":[{"type":"wasm/MsgExecuteContract","value":{"#,
+        r#""contract":"mantra1contract","funds":[{"amount":"1000","denom":"uom"}],"#,
+        r#""msg":{"swap":{"ask_asset_denom":"a\u003cb","max_spread":"0.01"}},"#,
+        r#""sender":"mantra1sender"}}],"sequence":"3"}"#,
+    );
+    assert_eq!(
+        String::from_utf8(unsigned.sign_bytes().unwrap()).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_builder_requires_messages_fee_and_signer() {
+    let wallet = MantraWallet::from_mnemonic(MNEMONIC, 0).unwrap();
+    let msg = execute_msg("mantra1sender", "{}", vec![]);
+
+    assert!(TxBuilder::new(CHAIN_ID)
+        .with_fee(fee())
+        .with_signer(wallet.public_key().unwrap(), 0, 0)
+        .build()
+        .is_err());
+    assert!(TxBuilder::new(CHAIN_ID)
+        .with_message(msg.clone())
+        .with_signer(wallet.public_key().unwrap(), 0, 0)
+        .build()
+        .is_err());
+    assert!(TxBuilder::new(CHAIN_ID)
+        .with_message(msg.clone())
+        .with_fee(fee())
+        .build()
+        .is_err());
+    assert!(TxBuilder::new("not a chain id!")
+        .with_message(msg)
+        .with_fee(fee())
+        .with_signer(wallet.public_key().unwrap(), 0, 0)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_amino_json_rejects_unknown_messages() {
+    let unknown = Any {
+        type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+        value: vec![],
+    };
+    assert!(amino_json(&unknown).is_err());
+}
@@ -2,7 +2,11 @@ use config::{Config as ConfigLoader, File};
 use cosmwasm_std::{Coin, Decimal, Uint128};
 use mantra_dex_sdk::{
     config::{ContractAddresses, MantraNetworkConfig, NetworkConstants},
-    MantraDexClient, MantraWallet,
+    MantraDexClient, MantraWallet, PageCursor,
+};
+use mantra_dex_std::{
+    fee::{Fee, PoolFee},
+    pool_manager::{PoolInfo, PoolInfoResponse, PoolStatus, PoolType},
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -101,10 +105,18 @@ pub mod test_utils {
                     network_name: network_constants.network_name.clone(),
                     chain_id: network_constants.chain_id.clone(),
                     rpc_url: network_constants.default_rpc.clone(),
+                    fallback_rpc_urls: Vec::new(),
                     gas_price: network_constants.default_gas_price,
                     gas_adjustment: network_constants.default_gas_adjustment,
                     native_denom: network_constants.native_denom.clone(),
+                    fee_denom: network_constants.fee_denom.clone(),
+                    fee_tokens: network_constants.fee_tokens.clone(),
                     contracts: Default::default(),
+                    timeouts: Default::default(),
+                    circuit_breaker: Default::default(),
+                    endpoint_selection: Default::default(),
+                    broadcast_retry: Default::default(),
+                    priority_fee: Default::default(),
                 }
             });
 
@@ -213,7 +225,10 @@ pub mod test_utils {
         );
 
         // First, try to find an existing pool
-        let pools = client.get_pools(Some(100)).await?;
+        let pools = client
+            .get_pools(&PageCursor::first(100))
+            .await
+            .map(|page| page.items)?;
         for pool in pools {
             if pool.pool_info.assets.iter().any(|a| a.denom == uom_denom)
                 && pool.pool_info.assets.iter().any(|a| a.denom == uusdy_denom)
@@ -298,6 +313,53 @@ pub mod test_utils {
         Ok(actual_pool_id)
     }
 
+    /// A pool of `assets` (denom, amount, decimals) with 1,000 LP tokens that charges `swap_fee`
+    #[allow(dead_code)]
+    pub fn pool(
+        pool_id: &str,
+        pool_type: PoolType,
+        assets: &[(&str, u128, u8)],
+        swap_fee: Decimal,
+    ) -> PoolInfoResponse {
+        let lp_denom = format!("factory/pool/{}.LP", pool_id);
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: pool_id.to_string(),
+                asset_denoms: assets.iter().map(|(denom, ..)| denom.to_string()).collect(),
+                lp_denom: lp_denom.clone(),
+                asset_decimals: assets.iter().map(|(.., decimals)| *decimals).collect(),
+                assets: assets
+                    .iter()
+                    .map(|(denom, amount, _)| Coin::new(*amount, *denom))
+                    .collect(),
+                pool_type,
+                pool_fees: PoolFee {
+                    protocol_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    swap_fee: Fee { share: swap_fee },
+                    burn_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    extra_fees: vec![],
+                },
+                status: PoolStatus::default(),
+            },
+            total_share: Coin::new(1_000u128, lp_denom),
+        }
+    }
+
+    /// A constant product pool of `uom` uom and `uusdc` uusdc
+    #[allow(dead_code)]
+    pub fn om_usdc_pool(pool_id: &str, uom: u128, uusdc: u128) -> PoolInfoResponse {
+        pool(
+            pool_id,
+            PoolType::ConstantProduct,
+            &[("uom", uom, 6), ("uusdc", uusdc, 6)],
+            Decimal::permille(3),
+        )
+    }
+
     /// Check if we should execute write operations (create pools, swaps, etc.)
     #[allow(dead_code)]
     pub fn should_execute_writes() -> bool {
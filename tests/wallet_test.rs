@@ -1,6 +1,9 @@
 mod utils;
 
-use mantra_dex_sdk::{Error, MantraWallet};
+use mantra_dex_sdk::wallet::WalletStorage;
+use mantra_dex_sdk::{
+    Access, Decimal, Error, MantraDexClient, MantraNetworkConfig, MantraWallet, WalletDefaults,
+};
 use utils::test_utils::{create_test_client, create_test_wallet, load_test_config};
 
 #[tokio::test]
@@ -34,7 +37,7 @@ async fn test_wallet_generate() {
 
     // Check mnemonic
     assert!(!mnemonic.is_empty(), "Mnemonic should not be empty");
-    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let words: Vec<&str> = mnemonic.expose_secret().split_whitespace().collect();
     assert_eq!(words.len(), 12, "Mnemonic should have 12 words");
 
     // Check wallet address
@@ -49,8 +52,8 @@ async fn test_wallet_generate() {
     );
 
     // Confirm we can recreate the wallet from the generated mnemonic
-    let recreated_wallet =
-        MantraWallet::from_mnemonic(&mnemonic, 0).expect("Failed to recreate wallet from mnemonic");
+    let recreated_wallet = MantraWallet::from_mnemonic(mnemonic.expose_secret(), 0)
+        .expect("Failed to recreate wallet from mnemonic");
 
     let recreated_address = recreated_wallet
         .address()
@@ -139,3 +142,143 @@ async fn test_wallet_get_balances() {
     let balances = client.get_balances().await.unwrap();
     println!("Balances: {:?}", balances);
 }
+
+#[tokio::test]
+async fn test_client_without_wallet_is_read_only() {
+    let client = MantraDexClient::new(MantraNetworkConfig::default())
+        .await
+        .unwrap();
+    assert!(client.is_read_only());
+    assert!(client.require(Access::ReadOnly).is_ok());
+    assert!(matches!(
+        client.require(Access::Signing),
+        Err(Error::Wallet(_))
+    ));
+
+    let (wallet, _) = MantraWallet::generate().expect("Failed to generate wallet");
+    let client = client.with_wallet(wallet);
+    assert!(!client.is_read_only());
+    assert!(client.require(Access::Signing).is_ok());
+}
+
+#[test]
+fn test_wallet_defaults_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = WalletStorage::with_directory(dir.path().to_path_buf()).unwrap();
+    let (wallet, mnemonic) = MantraWallet::generate().expect("Failed to generate wallet");
+    let address = wallet.address().unwrap().to_string();
+    storage
+        .save_wallet(
+            "trading",
+            mnemonic.expose_secret(),
+            "Sup3r-Secret-Pass",
+            &address,
+        )
+        .unwrap();
+
+    // New wallets start without defaults
+    assert!(storage.wallet_defaults("trading").unwrap().is_empty());
+
+    let defaults = WalletDefaults {
+        slippage: Some(Decimal::percent(2)),
+        gas_price: Some(0.025),
+        memo: Some("desk".to_string()),
+        preferred_pools: vec!["o.uom.uusdc.pool".to_string()],
+    };
+    storage
+        .set_wallet_defaults("trading", defaults.clone())
+        .unwrap();
+    assert_eq!(storage.wallet_defaults("trading").unwrap(), defaults);
+
+    // The mnemonic is still readable after the metadata update
+    assert_eq!(
+        storage.load_wallet("trading", "Sup3r-Secret-Pass").unwrap(),
+        mnemonic
+    );
+
+    assert!(matches!(
+        storage.wallet_defaults("missing"),
+        Err(Error::Wallet(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_watch_only_wallet_cannot_sign() {
+    let (signer, _) = MantraWallet::generate().expect("Failed to generate wallet");
+    let address = signer.address().unwrap().to_string();
+
+    let wallet = MantraWallet::watch_only(&address).unwrap();
+    assert!(wallet.is_watch_only());
+    assert!(!signer.is_watch_only());
+    assert_eq!(wallet.address().unwrap().to_string(), address);
+
+    let info = wallet.info();
+    assert!(info.watch_only);
+    assert!(info.public_key.is_empty());
+
+    let fee = wallet.create_default_fee(300_000).unwrap();
+    match wallet.sign_tx(1, 0, "mantra-dukong", fee, Vec::new(), None, None) {
+        Err(Error::Wallet(msg)) => assert!(msg.contains("watch-only"), "{}", msg),
+        other => panic!("Expected a watch-only error, got {:?}", other.map(|_| ())),
+    }
+    assert!(matches!(wallet.public_key(), Err(Error::Wallet(_))));
+
+    let client = MantraDexClient::new(MantraNetworkConfig::default())
+        .await
+        .unwrap()
+        .with_wallet(wallet);
+    assert!(client.is_read_only());
+    assert!(client.require(Access::ReadOnly).is_ok());
+    assert!(matches!(
+        client.require(Access::Signing),
+        Err(Error::Wallet(_))
+    ));
+
+    // Only MANTRA addresses can be watched
+    assert!(matches!(
+        MantraWallet::watch_only("not an address"),
+        Err(Error::Wallet(_))
+    ));
+    match MantraWallet::watch_only("cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu") {
+        Err(Error::Wallet(msg)) => assert!(msg.contains("prefix"), "{}", msg),
+        other => panic!("Expected a prefix error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_watch_only_wallet_storage() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = WalletStorage::with_directory(dir.path().to_path_buf()).unwrap();
+    let (signer, _) = MantraWallet::generate().expect("Failed to generate wallet");
+    let address = signer.address().unwrap().to_string();
+
+    storage.save_watch_only_wallet("treasury", &address).unwrap();
+    assert!(matches!(
+        storage.save_watch_only_wallet("treasury", &address),
+        Err(Error::Wallet(_))
+    ));
+    assert!(matches!(
+        storage.save_watch_only_wallet("other", "not an address"),
+        Err(Error::Wallet(_))
+    ));
+
+    let wallets = storage.list_wallets().unwrap();
+    assert_eq!(wallets.len(), 1);
+    assert!(wallets[0].watch_only);
+    assert_eq!(wallets[0].address, address);
+
+    let wallet = storage.load_watch_only_wallet("treasury").unwrap();
+    assert!(wallet.is_watch_only());
+    assert_eq!(wallet.address().unwrap().to_string(), address);
+    assert!(storage
+        .wallet_metadata("treasury")
+        .unwrap()
+        .last_accessed
+        .is_some());
+
+    // There is no mnemonic to decrypt
+    match storage.load_wallet("treasury", "any-password") {
+        Err(Error::Wallet(msg)) => assert!(msg.contains("watch-only"), "{}", msg),
+        other => panic!("Expected a watch-only error, got {:?}", other.map(|_| ())),
+    }
+}
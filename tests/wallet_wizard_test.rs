@@ -0,0 +1,81 @@
+use mantra_dex_sdk::wallet::wizard::{WizardState, WizardStep};
+
+const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+#[test]
+fn test_wizard_steps_advance_in_order() {
+    let mut wizard = WizardState::new();
+    let mut visited = vec![wizard.current_step];
+
+    while wizard.current_step != WizardStep::Complete {
+        wizard.next_step();
+        visited.push(wizard.current_step);
+    }
+
+    assert_eq!(visited, WizardStep::all());
+
+    // Complete is terminal
+    wizard.next_step();
+    assert_eq!(wizard.current_step, WizardStep::Complete);
+}
+
+#[test]
+fn test_wizard_import_requires_twelve_words() {
+    let mut wizard = WizardState::new();
+    wizard.current_step = WizardStep::WalletSetup;
+    assert!(wizard.import_existing);
+    assert!(!wizard.can_proceed());
+    assert_eq!(wizard.current_mnemonic(), None);
+
+    wizard.mnemonic_input = "abandon abandon".into();
+    assert!(!wizard.can_proceed());
+
+    wizard.mnemonic_input = TEST_MNEMONIC.into();
+    assert!(wizard.can_proceed());
+    assert_eq!(wizard.current_mnemonic(), Some(TEST_MNEMONIC));
+}
+
+#[test]
+fn test_wizard_generate_mode_provides_mnemonic() {
+    let mut wizard = WizardState::new();
+    wizard.toggle_wallet_mode().unwrap();
+
+    assert!(!wizard.import_existing);
+    let mnemonic = wizard.current_mnemonic().expect("generated mnemonic");
+    assert_eq!(mnemonic.split_whitespace().count(), 12);
+}
+
+#[test]
+fn test_wizard_security_acknowledgement_gate() {
+    let mut wizard = WizardState::new();
+    wizard.current_step = WizardStep::SecurityWarning;
+    assert!(!wizard.can_proceed());
+
+    wizard.security_acknowledged = true;
+    assert!(wizard.can_proceed());
+}
+
+#[test]
+fn test_wizard_wallet_save_validation() {
+    let mut wizard = WizardState::new();
+    wizard.current_step = WizardStep::WalletSave;
+    assert!(wizard.save_wallet);
+
+    wizard.wallet_name = "my-wallet".to_string();
+    wizard.save_password = "Str0ng!Password".into();
+    wizard.save_password_confirm = "Different!Pass1".into();
+    assert!(wizard
+        .validate_wallet_save()
+        .contains(&"Passwords do not match".to_string()));
+    assert!(!wizard.can_proceed());
+
+    wizard.save_password_confirm = "Str0ng!Password".into();
+    assert!(wizard.validate_wallet_save().is_empty());
+    assert!(wizard.can_proceed());
+
+    // Opting out of saving clears the form and skips validation
+    wizard.toggle_save_wallet();
+    assert!(wizard.save_password.is_empty());
+    assert!(wizard.can_proceed());
+}